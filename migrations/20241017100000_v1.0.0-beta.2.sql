@@ -0,0 +1,4 @@
+-- Add web page screenshot trackers utility.
+INSERT INTO utils (id, handle, name, keywords, parent_id)
+VALUES (13, 'web_scraping__screenshots', 'Screenshot trackers',
+        'web scraping crawl spider scraper scrape screenshot visual image tracker track', 10);
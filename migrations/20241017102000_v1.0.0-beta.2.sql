@@ -0,0 +1,2 @@
+-- Append correlation ID (None) to all existing captured responder requests.
+UPDATE user_data_webhooks_responders_history SET data = data || '\x00'::bytea;
@@ -0,0 +1,2 @@
+-- Add optional user notes to the web page tracker revisions.
+ALTER TABLE user_data_web_scraping_trackers_history ADD COLUMN note TEXT;
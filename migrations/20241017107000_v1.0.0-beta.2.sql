@@ -0,0 +1,2 @@
+-- Append adaptive interval (None) to all existing web page tracker job configs.
+UPDATE user_data_web_scraping_trackers SET job_config = job_config || '\x00'::bytea WHERE job_config IS NOT NULL;
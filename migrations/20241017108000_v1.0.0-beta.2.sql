@@ -0,0 +1,9 @@
+-- Append notification cooldown setting (None) to all existing web page trackers.
+UPDATE user_data_web_scraping_trackers SET data = data || '\x00'::bytea;
+
+-- Table to store the time of the last notification sent for web page trackers.
+CREATE TABLE IF NOT EXISTS user_data_web_scraping_trackers_notifications
+(
+    tracker_id       UUID PRIMARY KEY NOT NULL REFERENCES user_data_web_scraping_trackers (id) ON DELETE CASCADE,
+    last_notified_at TIMESTAMPTZ      NOT NULL
+);
@@ -0,0 +1,15 @@
+-- Track the number of failed delivery attempts for every notification.
+ALTER TABLE notifications ADD COLUMN IF NOT EXISTS attempts INTEGER NOT NULL DEFAULT 0;
+
+-- Table to store notifications that couldn't be delivered after all attempts.
+CREATE TABLE IF NOT EXISTS notifications_dead_letters
+(
+    id           UUID PRIMARY KEY NOT NULL,
+    user_id      UUID REFERENCES users (id) ON DELETE CASCADE,
+    destination  BYTEA            NOT NULL,
+    content      BYTEA            NOT NULL,
+    scheduled_at TIMESTAMPTZ      NOT NULL,
+    failed_at    TIMESTAMPTZ      NOT NULL,
+    attempts     INTEGER          NOT NULL,
+    last_error   TEXT             NOT NULL
+);
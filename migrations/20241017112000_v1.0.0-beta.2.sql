@@ -0,0 +1,5 @@
+-- Mark notifications that should be delivered even during user's quiet hours.
+ALTER TABLE notifications ADD COLUMN IF NOT EXISTS urgent BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Append urgent notifications setting (None) to all existing web page trackers.
+UPDATE user_data_web_scraping_trackers SET data = data || '\x00'::bytea;
@@ -0,0 +1,11 @@
+-- Table to store inbound emails received by the users' disposable inboxes.
+CREATE TABLE IF NOT EXISTS user_data_webhooks_inbound_emails
+(
+    id          UUID PRIMARY KEY NOT NULL,
+    user_id     UUID             NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    sender      TEXT             NOT NULL,
+    recipient   TEXT             NOT NULL,
+    subject     TEXT             NOT NULL,
+    body        TEXT             NOT NULL,
+    received_at TIMESTAMPTZ      NOT NULL
+);
@@ -0,0 +1,8 @@
+-- Table to store webhooks that web page tracker change notifications are delivered to. Webhook
+-- secret is stored encrypted.
+CREATE TABLE IF NOT EXISTS user_data_web_scraping_trackers_webhooks
+(
+    tracker_id UUID PRIMARY KEY NOT NULL REFERENCES user_data_web_scraping_trackers (id) ON DELETE CASCADE,
+    url        TEXT             NOT NULL,
+    secret     BYTEA            NOT NULL
+);
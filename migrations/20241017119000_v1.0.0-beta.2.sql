@@ -0,0 +1,2 @@
+-- Append cookies capture setting (None) to all existing web page trackers.
+UPDATE user_data_web_scraping_trackers SET data = data || '\x00'::bytea;
@@ -0,0 +1,9 @@
+-- Append revisions dedup window setting (None) to all existing web page trackers.
+UPDATE user_data_web_scraping_trackers SET data = data || '\x00'::bytea;
+
+-- Store hashes of the web page tracker revisions data to detect returns to recent states.
+ALTER TABLE user_data_web_scraping_trackers_history ADD COLUMN content_hash BYTEA;
+UPDATE user_data_web_scraping_trackers_history SET content_hash = sha256(data);
+ALTER TABLE user_data_web_scraping_trackers_history ALTER COLUMN content_hash SET NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_user_data_web_scraping_trackers_history_content_hash
+    ON user_data_web_scraping_trackers_history (tracker_id, content_hash);
@@ -0,0 +1,5 @@
+-- Append resources truncation flag (false) to all existing web page resources tracker revisions.
+UPDATE user_data_web_scraping_trackers_history
+SET data         = data || '\x00'::bytea,
+    content_hash = sha256(data || '\x00'::bytea)
+WHERE tracker_id IN (SELECT id FROM user_data_web_scraping_trackers WHERE kind = '\x00'::bytea);
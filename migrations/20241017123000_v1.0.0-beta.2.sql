@@ -0,0 +1,3 @@
+-- Track who and when acknowledged the web page tracker revisions.
+ALTER TABLE user_data_web_scraping_trackers_history ADD COLUMN acknowledged_by UUID REFERENCES users(id) ON DELETE SET NULL;
+ALTER TABLE user_data_web_scraping_trackers_history ADD COLUMN acknowledged_at TIMESTAMPTZ;
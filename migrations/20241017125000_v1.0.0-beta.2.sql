@@ -0,0 +1,9 @@
+-- Append max total retry duration (None) to the job config of all existing web page trackers.
+UPDATE user_data_web_scraping_trackers SET job_config = job_config || '\x00'::bytea WHERE job_config IS NOT NULL;
+
+-- Retry state now includes the time the first retry was scheduled at. Instead of rewriting the
+-- serialized job metadata, remove all scheduler jobs and unlink trackers from them: the unique jobs
+-- are re-created and the trackers are re-scheduled by the scheduler on startup.
+UPDATE user_data_web_scraping_trackers SET job_id = NULL WHERE job_id IS NOT NULL;
+DELETE FROM scheduler_notifications;
+DELETE FROM scheduler_jobs;
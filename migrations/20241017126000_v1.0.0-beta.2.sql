@@ -0,0 +1,25 @@
+-- Chain web page tracker revisions with hashes to detect tampering with the stored history. Every
+-- revision stores the chain hash of the previous revision and its own chain hash calculated as
+-- `sha256(previous_hash || sha256(data))`. The first revision in the chain has an empty previous hash.
+ALTER TABLE user_data_web_scraping_trackers_history ADD COLUMN previous_hash BYTEA;
+ALTER TABLE user_data_web_scraping_trackers_history ADD COLUMN chain_hash BYTEA;
+
+WITH RECURSIVE ordered AS (
+    SELECT id, tracker_id, data, ROW_NUMBER() OVER (PARTITION BY tracker_id ORDER BY created_at, id) AS position
+    FROM user_data_web_scraping_trackers_history
+), chain AS (
+    SELECT id, tracker_id, position, ''::bytea AS previous_hash, sha256(''::bytea || sha256(data)) AS chain_hash
+    FROM ordered
+    WHERE position = 1
+    UNION ALL
+    SELECT ordered.id, ordered.tracker_id, ordered.position, chain.chain_hash, sha256(chain.chain_hash || sha256(ordered.data))
+    FROM ordered
+    INNER JOIN chain ON ordered.tracker_id = chain.tracker_id AND ordered.position = chain.position + 1
+)
+UPDATE user_data_web_scraping_trackers_history AS history
+SET previous_hash = chain.previous_hash, chain_hash = chain.chain_hash
+FROM chain
+WHERE history.id = chain.id;
+
+ALTER TABLE user_data_web_scraping_trackers_history ALTER COLUMN previous_hash SET NOT NULL;
+ALTER TABLE user_data_web_scraping_trackers_history ALTER COLUMN chain_hash SET NOT NULL;
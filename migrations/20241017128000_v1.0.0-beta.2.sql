@@ -0,0 +1,2 @@
+-- Append fault setting (None) to all existing webhook responders.
+UPDATE user_data_webhooks_responders SET settings = settings || '\x00'::bytea;
@@ -0,0 +1,3 @@
+-- Add JWT utility.
+INSERT INTO utils (id, handle, name, keywords, parent_id)
+VALUES (14, 'web_security__jwt', 'JWT', 'jwt json web token decode inspect verify jwks claims web security', 7);
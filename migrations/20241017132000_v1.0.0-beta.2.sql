@@ -0,0 +1,11 @@
+-- Table to store audit log of the actions performed on behalf of the users (e.g. by the operators
+-- that impersonate users for support).
+CREATE TABLE IF NOT EXISTS user_audit_log
+(
+    id         UUID PRIMARY KEY NOT NULL,
+    user_id    UUID             NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    operator   TEXT,
+    action     TEXT             NOT NULL,
+    created_at TIMESTAMPTZ      NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_user_audit_log_user_id ON user_audit_log (user_id, created_at);
@@ -0,0 +1,7 @@
+-- Table to store the revisions web page trackers compute changes against instead of the previous
+-- revision (baseline).
+CREATE TABLE IF NOT EXISTS user_data_web_scraping_trackers_baselines
+(
+    tracker_id  UUID PRIMARY KEY NOT NULL REFERENCES user_data_web_scraping_trackers (id) ON DELETE CASCADE,
+    revision_id UUID             NOT NULL REFERENCES user_data_web_scraping_trackers_history (id) ON DELETE CASCADE
+);
@@ -0,0 +1,4 @@
+-- Append forward targets setting (None) to all existing webhook responders.
+UPDATE user_data_webhooks_responders SET settings = settings || '\x00'::bytea;
+-- Append forward results (None) to all existing captured responder requests.
+UPDATE user_data_webhooks_responders_history SET data = data || '\x00'::bytea;
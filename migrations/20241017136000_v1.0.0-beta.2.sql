@@ -0,0 +1,7 @@
+-- Add web page DNS records trackers utility.
+INSERT INTO utils (id, handle, name, keywords, parent_id)
+VALUES (15, 'web_scraping__dns', 'DNS trackers',
+        'web scraping dns domain record a aaaa cname mx ns txt tracker track', 10);
+
+-- Append DNS record types (None) to the settings of all existing web page trackers.
+UPDATE user_data_web_scraping_trackers SET data = data || '\x00'::bytea;
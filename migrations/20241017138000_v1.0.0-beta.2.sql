@@ -0,0 +1,13 @@
+-- Table to store public status pages that aggregate the latest status of the selected web page
+-- status trackers.
+CREATE TABLE IF NOT EXISTS user_data_web_scraping_status_pages
+(
+    id          UUID PRIMARY KEY NOT NULL,
+    user_id     UUID             NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    name        TEXT             NOT NULL COLLATE case_insensitive,
+    token       TEXT             NOT NULL UNIQUE,
+    tracker_ids UUID[]           NOT NULL,
+    created_at  TIMESTAMPTZ      NOT NULL,
+    updated_at  TIMESTAMPTZ      NOT NULL,
+    UNIQUE (name, user_id)
+);
@@ -0,0 +1,4 @@
+-- Append JSON capture setting (false) to all existing webhook responders.
+UPDATE user_data_webhooks_responders SET settings = settings || '\x00'::bytea;
+-- Append JSON validity and pretty-printed JSON body (None) to all existing captured responder requests.
+UPDATE user_data_webhooks_responders_history SET data = data || '\x00\x00'::bytea;
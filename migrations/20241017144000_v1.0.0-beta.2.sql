@@ -0,0 +1,10 @@
+-- Table to store web page tracker scrape jobs submitted to the web scraper in the asynchronous
+-- mode, until the web scraper delivers their results to the callback.
+CREATE TABLE IF NOT EXISTS user_data_web_scraping_pending_scrapes
+(
+    job_id     TEXT PRIMARY KEY NOT NULL,
+    user_id    UUID             NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    tracker_id UUID             NOT NULL REFERENCES user_data_web_scraping_trackers (id) ON DELETE CASCADE,
+    token      TEXT             NOT NULL,
+    created_at TIMESTAMPTZ      NOT NULL
+);
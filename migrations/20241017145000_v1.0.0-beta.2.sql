@@ -0,0 +1,5 @@
+-- Append store diffs flag (None) to the settings of all existing web page trackers.
+UPDATE user_data_web_scraping_trackers SET data = data || '\x00'::bytea;
+
+-- Intermediate web page tracker revisions can be stored as deltas against the previous revision.
+ALTER TABLE user_data_web_scraping_trackers_history ADD COLUMN delta BOOLEAN NOT NULL DEFAULT FALSE;
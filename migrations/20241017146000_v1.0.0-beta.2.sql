@@ -0,0 +1,10 @@
+-- Append initial response setting (None) to all existing webhook responders.
+UPDATE user_data_webhooks_responders SET settings = settings || '\x00'::bytea;
+
+-- Table to store the number of requests received by responders with initial response.
+CREATE TABLE IF NOT EXISTS user_data_webhooks_responders_counters
+(
+    responder_id UUID PRIMARY KEY NOT NULL REFERENCES user_data_webhooks_responders (id) ON DELETE CASCADE,
+    user_id      UUID             NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    requests     BIGINT           NOT NULL
+);
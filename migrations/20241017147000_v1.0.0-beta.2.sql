@@ -0,0 +1,5 @@
+-- Append content assertions setting (None) to all existing web page trackers.
+UPDATE user_data_web_scraping_trackers SET data = data || '\x00'::bytea;
+
+-- Result of the web page content tracker assertions for the revision, if tracker has assertions.
+ALTER TABLE user_data_web_scraping_trackers_history ADD COLUMN assertions_passed BOOLEAN;
@@ -0,0 +1,4 @@
+-- Previous web page tracker webhook secret that is still used to sign notification payloads during
+-- the secret rotation grace period.
+ALTER TABLE user_data_web_scraping_trackers_webhooks ADD COLUMN previous_secret BYTEA;
+ALTER TABLE user_data_web_scraping_trackers_webhooks ADD COLUMN previous_secret_expires_at TIMESTAMPTZ;
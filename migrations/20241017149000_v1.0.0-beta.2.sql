@@ -0,0 +1,13 @@
+-- Table to store base paths reserved by users to group responders under.
+CREATE TABLE IF NOT EXISTS user_data_webhooks_responders_base_paths
+(
+    id         UUID PRIMARY KEY NOT NULL,
+    user_id    UUID             NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    path       TEXT             NOT NULL,
+    created_at TIMESTAMPTZ      NOT NULL,
+    UNIQUE (path, user_id)
+);
+
+-- Base path responder location path is relative to, if any.
+ALTER TABLE user_data_webhooks_responders ADD COLUMN base_path TEXT;
+ALTER TABLE user_data_webhooks_responders ADD FOREIGN KEY (base_path, user_id) REFERENCES user_data_webhooks_responders_base_paths (path, user_id) ON DELETE CASCADE;
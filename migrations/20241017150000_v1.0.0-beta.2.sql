@@ -0,0 +1,6 @@
+-- Time when the user last viewed the web page tracker history, used to detect idle trackers.
+CREATE TABLE IF NOT EXISTS user_data_web_scraping_trackers_views
+(
+    tracker_id     UUID PRIMARY KEY NOT NULL REFERENCES user_data_web_scraping_trackers (id) ON DELETE CASCADE,
+    last_viewed_at TIMESTAMPTZ      NOT NULL
+);
@@ -0,0 +1,2 @@
+-- Append request method and request body settings (None) to all existing web page trackers.
+UPDATE user_data_web_scraping_trackers SET data = data || '\x0000'::bytea;
@@ -0,0 +1,3 @@
+-- Encrypted custom HTTP headers (e.g. `Authorization`) sent along with every notification delivered
+-- to the web page tracker webhook.
+ALTER TABLE user_data_web_scraping_trackers_webhooks ADD COLUMN headers BYTEA;
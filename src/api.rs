@@ -2,9 +2,12 @@ use crate::{
     database::Database,
     network::{DnsResolver, EmailTransport, Network},
     search::SearchIndex,
+    security::secrets::{create_secrets_provider, SecretsProvider},
+    utils::web_scraping::WebScraperCircuitBreaker,
     Config,
 };
 use handlebars::Handlebars;
+use std::sync::Arc;
 
 pub struct Api<DR: DnsResolver, ET: EmailTransport> {
     pub db: Database,
@@ -12,6 +15,9 @@ pub struct Api<DR: DnsResolver, ET: EmailTransport> {
     pub config: Config,
     pub network: Network<DR, ET>,
     pub templates: Handlebars<'static>,
+    pub secrets: Arc<dyn SecretsProvider>,
+    /// Circuit breaker shared by all calls to the web scraper component.
+    pub web_scraper_circuit_breaker: WebScraperCircuitBreaker,
 }
 
 impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
@@ -24,6 +30,10 @@ impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
         templates: Handlebars<'static>,
     ) -> Self {
         Self {
+            secrets: create_secrets_provider(config.security.secrets_provider.as_ref()),
+            web_scraper_circuit_breaker: WebScraperCircuitBreaker::new(
+                config.components.web_scraper.circuit_breaker,
+            ),
             config,
             db: database,
             search_index,
@@ -2,19 +2,23 @@ mod components_config;
 mod database_config;
 mod raw_config;
 mod scheduler_jobs_config;
+mod secrets_provider_config;
 mod security_config;
 mod smtp_catch_all_config;
 mod smtp_config;
 mod subscriptions_config;
 mod utils_config;
 
+use crate::security::secrets::{resolve_secret, SecretsProvider};
+use std::borrow::Cow;
 use url::Url;
 
 pub use self::{
-    components_config::ComponentsConfig,
+    components_config::{ComponentsConfig, WebScraperCircuitBreakerConfig},
     database_config::DatabaseConfig,
     raw_config::RawConfig,
     scheduler_jobs_config::SchedulerJobsConfig,
+    secrets_provider_config::SecretsProviderConfig,
     security_config::SecurityConfig,
     smtp_catch_all_config::SmtpCatchAllConfig,
     smtp_config::SmtpConfig,
@@ -22,9 +26,12 @@ pub use self::{
         SubscriptionCertificatesConfig, SubscriptionConfig, SubscriptionWebScrapingConfig,
         SubscriptionWebSecurityConfig, SubscriptionWebhooksConfig, SubscriptionsConfig,
     },
-    utils_config::UtilsConfig,
+    utils_config::{UtilsConfig, WebPageTrackerDefaultsConfig},
 };
 
+#[cfg(test)]
+pub use self::{components_config::WebScraperConfig, utils_config::UtilsWebScrapingConfig};
+
 /// Secutils.dev user agent name used for all HTTP requests.
 pub static SECUTILS_USER_AGENT: &str =
     concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
@@ -34,6 +41,10 @@ pub static SECUTILS_USER_AGENT: &str =
 pub struct Config {
     /// External/public URL through which service is being accessed.
     pub public_url: Url,
+    /// Indicates whether the server should run without making any outbound network requests
+    /// (e.g. for security audits or demos in air-gapped environments). Internal components
+    /// required for authentication are still reachable.
+    pub safe_mode: bool,
     /// Database configuration.
     pub db: DatabaseConfig,
     /// Security configuration (session, built-in users etc.).
@@ -50,6 +61,34 @@ pub struct Config {
     pub subscriptions: SubscriptionsConfig,
 }
 
+impl Config {
+    /// Resolves secret references (`secret://name`) used in the config secrets (passwords and
+    /// keys) to the actual values with the specified provider.
+    pub fn resolve_secrets(mut self, provider: &dyn SecretsProvider) -> anyhow::Result<Self> {
+        let resolve = |value: &mut String| -> anyhow::Result<()> {
+            if let Cow::Owned(secret) = resolve_secret(provider, None, value)? {
+                *value = secret;
+            }
+            Ok(())
+        };
+
+        if let Some(ref mut password) = self.db.password {
+            resolve(password)?;
+        }
+        if let Some(ref mut smtp) = self.smtp {
+            resolve(&mut smtp.password)?;
+        }
+        if let Some(ref mut jwt_secret) = self.security.jwt_secret {
+            resolve(jwt_secret)?;
+        }
+        if let Some(ref mut encryption_key) = self.security.encryption_key {
+            resolve(encryption_key)?;
+        }
+
+        Ok(self)
+    }
+}
+
 impl AsRef<Config> for Config {
     fn as_ref(&self) -> &Config {
         self
@@ -60,6 +99,7 @@ impl From<RawConfig> for Config {
     fn from(raw_config: RawConfig) -> Self {
         Self {
             public_url: raw_config.public_url,
+            safe_mode: raw_config.safe_mode,
             db: raw_config.db,
             security: raw_config.security,
             smtp: raw_config.smtp,
@@ -73,7 +113,10 @@ impl From<RawConfig> for Config {
 
 #[cfg(test)]
 mod tests {
-    use crate::config::{Config, RawConfig, SmtpCatchAllConfig, SmtpConfig};
+    use crate::{
+        config::{Config, RawConfig, SmtpCatchAllConfig, SmtpConfig},
+        security::secrets::tests::MockSecretsProvider,
+    };
     use insta::assert_debug_snapshot;
     use regex::Regex;
     use url::Url;
@@ -91,6 +134,8 @@ mod tests {
                 recipient: "test@secutils.dev".to_string(),
                 text_matcher: Regex::new(r"test").unwrap(),
             }),
+            pool_size: None,
+            concurrency: None,
         });
 
         assert_debug_snapshot!(Config::from(raw_config), @r###"
@@ -112,6 +157,7 @@ mod tests {
                 query: None,
                 fragment: None,
             },
+            safe_mode: false,
             db: DatabaseConfig {
                 name: "secutils",
                 host: "localhost",
@@ -122,16 +168,34 @@ mod tests {
             security: SecurityConfig {
                 session_cookie_name: "id",
                 jwt_secret: None,
+                encryption_key: None,
+                secrets_provider: None,
                 operators: None,
                 preconfigured_users: None,
             },
             utils: UtilsConfig {
                 webhook_url_type: Subdomain,
+                responder_templates_dir: None,
+                max_inbound_emails: None,
+                webhook_custom_domains: {},
+                web_scraping: UtilsWebScrapingConfig {
+                    defaults: WebPageTrackerDefaultsConfig {
+                        schedule: None,
+                        retry_strategy: None,
+                        notifications: false,
+                        data_policy: None,
+                    },
+                    webhook_allowed_domains: None,
+                    max_diff_size: None,
+                    sub_fetch_concurrency: None,
+                    async_scrapes: false,
+                    webhook_secret_grace_period: None,
+                },
             },
             smtp: Some(
                 SmtpConfig {
                     username: "test@secutils.dev",
-                    password: "password",
+                    password: "[REDACTED]",
                     address: "smtp.secutils.dev",
                     catch_all: Some(
                         SmtpCatchAllConfig {
@@ -141,6 +205,8 @@ mod tests {
                             ),
                         },
                     ),
+                    pool_size: None,
+                    concurrency: None,
                 },
             ),
             components: ComponentsConfig {
@@ -195,6 +261,10 @@ mod tests {
                     query: None,
                     fragment: None,
                 },
+                web_scraper: WebScraperConfig {
+                    retries: 0,
+                    circuit_breaker: None,
+                },
                 search_index_version: 4,
             },
             scheduler: SchedulerJobsConfig {
@@ -289,6 +359,11 @@ mod tests {
                         },
                     },
                 },
+                web_page_trackers_fetch_concurrency: 5,
+                web_page_trackers_fetch_host_interval: 2s,
+                web_page_trackers_fetch_max_jobs: 500,
+                notifications_send_batch_size: 10,
+                notifications_send_concurrency: 5,
             },
             subscriptions: SubscriptionsConfig {
                 manage_url: None,
@@ -324,6 +399,10 @@ mod tests {
                         tracker_revisions: 30,
                         tracker_schedules: None,
                         min_schedule_interval: 10s,
+                        tracker_resources: 1000,
+                        tracker_captured_headers: 100,
+                        tracker_urls: 10,
+                        tracker_idle_period: None,
                     },
                     certificates: SubscriptionCertificatesConfig {
                         private_keys: 100,
@@ -348,6 +427,10 @@ mod tests {
                         tracker_revisions: 30,
                         tracker_schedules: None,
                         min_schedule_interval: 10s,
+                        tracker_resources: 1000,
+                        tracker_captured_headers: 100,
+                        tracker_urls: 10,
+                        tracker_idle_period: None,
                     },
                     certificates: SubscriptionCertificatesConfig {
                         private_keys: 100,
@@ -372,6 +455,10 @@ mod tests {
                         tracker_revisions: 30,
                         tracker_schedules: None,
                         min_schedule_interval: 10s,
+                        tracker_resources: 1000,
+                        tracker_captured_headers: 100,
+                        tracker_urls: 10,
+                        tracker_idle_period: None,
                     },
                     certificates: SubscriptionCertificatesConfig {
                         private_keys: 100,
@@ -396,6 +483,10 @@ mod tests {
                         tracker_revisions: 30,
                         tracker_schedules: None,
                         min_schedule_interval: 10s,
+                        tracker_resources: 1000,
+                        tracker_captured_headers: 100,
+                        tracker_urls: 10,
+                        tracker_idle_period: None,
                     },
                     certificates: SubscriptionCertificatesConfig {
                         private_keys: 100,
@@ -411,4 +502,42 @@ mod tests {
         }
         "###);
     }
+
+    #[test]
+    fn can_resolve_secrets() -> anyhow::Result<()> {
+        let mut raw_config = RawConfig::default();
+        raw_config.db.password = Some("secret://db-password".to_string());
+        raw_config.security.jwt_secret = Some("plain-jwt-secret".to_string());
+        raw_config.security.encryption_key = Some("secret://encryption-key".to_string());
+        raw_config.smtp = Some(SmtpConfig {
+            username: "test@secutils.dev".to_string(),
+            password: "secret://smtp-password".to_string(),
+            address: "smtp.secutils.dev".to_string(),
+            catch_all: None,
+            pool_size: None,
+            concurrency: None,
+        });
+
+        let provider = MockSecretsProvider::default()
+            .with_secret("db-password", "db")
+            .with_secret("encryption-key", "key")
+            .with_secret("smtp-password", "smtp");
+        let config = Config::from(raw_config.clone()).resolve_secrets(&provider)?;
+        assert_eq!(config.db.password.as_deref(), Some("db"));
+        assert_eq!(
+            config.security.jwt_secret.as_deref(),
+            Some("plain-jwt-secret")
+        );
+        assert_eq!(config.security.encryption_key.as_deref(), Some("key"));
+        assert_eq!(
+            config.smtp.map(|smtp| smtp.password).as_deref(),
+            Some("smtp")
+        );
+
+        // Config cannot be used if any of the referenced secrets is missing.
+        let provider = MockSecretsProvider::default().with_secret("db-password", "db");
+        assert!(Config::from(raw_config).resolve_secrets(&provider).is_err());
+
+        Ok(())
+    }
 }
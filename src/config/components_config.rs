@@ -1,6 +1,14 @@
+mod web_scraper_circuit_breaker_config;
+mod web_scraper_config;
+
 use serde_derive::{Deserialize, Serialize};
 use url::Url;
 
+pub use self::{
+    web_scraper_circuit_breaker_config::WebScraperCircuitBreakerConfig,
+    web_scraper_config::WebScraperConfig,
+};
+
 /// Configuration for the main Secutils.dev components.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct ComponentsConfig {
@@ -10,6 +18,9 @@ pub struct ComponentsConfig {
     pub kratos_admin_url: Url,
     /// The URL to access the Web Scraper component.
     pub web_scraper_url: Url,
+    /// Configuration for the retries and circuit breaking of the Web Scraper component calls.
+    #[serde(default, skip_serializing_if = "WebScraperConfig::is_empty")]
+    pub web_scraper: WebScraperConfig,
     /// The current version of the search index component (typically incremented with Tantivy
     /// upgrades when there are breaking changes in the data or schema format).
     pub search_index_version: u16,
@@ -24,6 +35,7 @@ impl Default for ComponentsConfig {
                 .expect("Cannot parse Kratos Admin URL parameter."),
             web_scraper_url: Url::parse("http://localhost:7272")
                 .expect("Cannot parse Web Scraper URL parameter."),
+            web_scraper: WebScraperConfig::default(),
             search_index_version: 4,
         }
     }
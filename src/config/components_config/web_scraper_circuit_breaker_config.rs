@@ -0,0 +1,58 @@
+use serde_derive::{Deserialize, Serialize};
+use serde_with::{serde_as, DurationMilliSeconds};
+use std::time::Duration;
+
+/// Configuration for the circuit breaker around the web scraper component calls.
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct WebScraperCircuitBreakerConfig {
+    /// The number of consecutive web scraper failures after which the circuit opens and the
+    /// following calls fail immediately without reaching out to the web scraper.
+    pub failure_threshold: u32,
+    /// The time the circuit stays open before a single probe call is allowed to check whether the
+    /// web scraper has recovered.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub cooldown: Duration,
+}
+
+impl Default for WebScraperCircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(60),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::WebScraperCircuitBreakerConfig;
+    use insta::assert_toml_snapshot;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization_and_default() {
+        assert_toml_snapshot!(WebScraperCircuitBreakerConfig::default(), @r###"
+        failure_threshold = 5
+        cooldown = 60000
+        "###);
+    }
+
+    #[test]
+    fn deserialization() {
+        let config: WebScraperCircuitBreakerConfig = toml::from_str(
+            r#"
+        failure_threshold = 3
+        cooldown = 30000
+    "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            WebScraperCircuitBreakerConfig {
+                failure_threshold: 3,
+                cooldown: Duration::from_secs(30),
+            }
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::config::WebScraperCircuitBreakerConfig;
+use serde_derive::{Deserialize, Serialize};
+
+/// Configuration for the calls to the web scraper component.
+#[derive(Deserialize, Serialize, Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct WebScraperConfig {
+    /// The number of times the web scraper call is retried if it fails unexpectedly (e.g. the web
+    /// scraper is unreachable or responds with a server error). Errors caused by the request
+    /// itself (e.g. invalid page URL or script) are never retried.
+    #[serde(default)]
+    pub retries: u32,
+    /// Optional configuration for the circuit breaker that makes web scraper calls fail fast
+    /// while the web scraper is unavailable. Circuit breaking is disabled if not specified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub circuit_breaker: Option<WebScraperCircuitBreakerConfig>,
+}
+
+impl WebScraperConfig {
+    /// Checks whether the config doesn't define anything beyond the default values.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{WebScraperCircuitBreakerConfig, WebScraperConfig};
+    use insta::assert_toml_snapshot;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization_and_default() {
+        let config = WebScraperConfig::default();
+        assert!(config.is_empty());
+        assert_toml_snapshot!(config, @"retries = 0");
+
+        assert_toml_snapshot!(WebScraperConfig {
+            retries: 2,
+            circuit_breaker: Some(WebScraperCircuitBreakerConfig::default()),
+        }, @r###"
+        retries = 2
+
+        [circuit_breaker]
+        failure_threshold = 5
+        cooldown = 60000
+        "###);
+    }
+
+    #[test]
+    fn deserialization() {
+        let config: WebScraperConfig = toml::from_str("").unwrap();
+        assert!(config.is_empty());
+
+        let config: WebScraperConfig = toml::from_str(
+            r#"
+        retries = 1
+        circuit_breaker = { failure_threshold = 3, cooldown = 30000 }
+    "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            WebScraperConfig {
+                retries: 1,
+                circuit_breaker: Some(WebScraperCircuitBreakerConfig {
+                    failure_threshold: 3,
+                    cooldown: Duration::from_secs(30),
+                }),
+            }
+        );
+    }
+}
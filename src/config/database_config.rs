@@ -1,7 +1,9 @@
+use crate::logging::REDACTED_VALUE;
 use serde_derive::{Deserialize, Serialize};
+use std::fmt::{self, Debug, Formatter};
 
 /// Configuration for the database connection.
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Clone)]
 pub struct DatabaseConfig {
     /// Name of the database to connect to.
     pub name: String,
@@ -27,6 +29,19 @@ impl Default for DatabaseConfig {
     }
 }
 
+// Password is never included into the debug output since the config is logged at startup.
+impl Debug for DatabaseConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DatabaseConfig")
+            .field("name", &self.name)
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| REDACTED_VALUE))
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::config::DatabaseConfig;
@@ -74,7 +89,7 @@ mod tests {
             port: 5432,
             username: "postgres",
             password: Some(
-                "password",
+                "[REDACTED]",
             ),
         }
         "###);
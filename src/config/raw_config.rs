@@ -2,6 +2,7 @@ use crate::config::{
     database_config::DatabaseConfig, utils_config::UtilsConfig, ComponentsConfig,
     SchedulerJobsConfig, SecurityConfig, SmtpConfig, SubscriptionsConfig,
 };
+use anyhow::bail;
 use figment::{providers, providers::Format, value, Figment, Metadata, Profile, Provider};
 use serde_derive::{Deserialize, Serialize};
 use url::Url;
@@ -13,6 +14,9 @@ pub struct RawConfig {
     pub port: u16,
     /// External/public URL through which service is being accessed.
     pub public_url: Url,
+    /// Indicates whether the server should run without making any outbound network requests.
+    #[serde(default)]
+    pub safe_mode: bool,
     /// Database configuration.
     pub db: DatabaseConfig,
     /// Security configuration (session, built-in users etc.).
@@ -30,15 +34,106 @@ pub struct RawConfig {
 }
 
 impl RawConfig {
-    /// Reads the configuration from the file (TOML) and merges it with the default values.
+    /// Reads the configuration from the file (TOML) and merges it with the default values. String
+    /// values in the file can reference environment variables as `${ENV_VAR}` or
+    /// `${ENV_VAR:-default}`, and these references are resolved at load time. Use `$${` to include
+    /// a literal `${` into the value.
     pub fn read_from_file(path: &str) -> anyhow::Result<Self> {
+        let mut file_config = providers::Toml::file(path).data()?;
+        for value in file_config.values_mut().flat_map(|dict| dict.values_mut()) {
+            interpolate_env_vars(value, &|name| std::env::var(name).ok())?;
+        }
+
         Ok(Figment::from(RawConfig::default())
-            .merge(providers::Toml::file(path))
+            .merge(providers::Serialized::defaults(
+                file_config.remove(&Profile::Default).unwrap_or_default(),
+            ))
             .merge(providers::Env::prefixed("SECUTILS_").split("__"))
             .extract()?)
     }
 }
 
+/// Recursively resolves environment variable references in all string values.
+fn interpolate_env_vars(
+    value: &mut value::Value,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> anyhow::Result<()> {
+    match value {
+        value::Value::String(_, string) => {
+            *string = interpolate_env_vars_in_string(string, lookup)?;
+        }
+        value::Value::Dict(_, dict) => {
+            for value in dict.values_mut() {
+                interpolate_env_vars(value, lookup)?;
+            }
+        }
+        value::Value::Array(_, array) => {
+            for value in array.iter_mut() {
+                interpolate_env_vars(value, lookup)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Replaces `${ENV_VAR}` and `${ENV_VAR:-default}` references in the string with the values of
+/// the environment variables. The default value is used if the variable is unset or empty, and
+/// `$${` is replaced with a literal `${`. Errors never include the config value itself, since it
+/// might contain secrets.
+fn interpolate_env_vars_in_string(
+    input: &str,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> anyhow::Result<String> {
+    let mut output = String::with_capacity(input.len());
+    let mut remaining = input;
+    while let Some(start) = remaining.find("${") {
+        // Escaped reference (`$${`) is kept as a literal `${`.
+        if remaining[..start].ends_with('$') {
+            output.push_str(&remaining[..start - 1]);
+            output.push_str("${");
+            remaining = &remaining[start + 2..];
+            continue;
+        }
+
+        output.push_str(&remaining[..start]);
+
+        let reference = &remaining[start + 2..];
+        let Some(end) = reference.find('}') else {
+            bail!("Config value has unterminated environment variable reference.");
+        };
+
+        let (name, default_value) = match reference[..end].split_once(":-") {
+            Some((name, default_value)) => (name, Some(default_value)),
+            None => (&reference[..end], None),
+        };
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || char == '_')
+        {
+            bail!("Config value references environment variable with invalid name.");
+        }
+
+        match (lookup(name), default_value) {
+            (Some(value), Some(default_value)) if value.is_empty() => {
+                output.push_str(default_value)
+            }
+            (Some(value), _) => output.push_str(&value),
+            (None, Some(default_value)) => output.push_str(default_value),
+            (None, None) => bail!(
+                "Config value references environment variable `{name}` that is not set and doesn't have a default value."
+            ),
+        }
+
+        remaining = &reference[end + 1..];
+    }
+    output.push_str(remaining);
+
+    Ok(output)
+}
+
 impl Default for RawConfig {
     fn default() -> Self {
         let port = 7070;
@@ -47,6 +142,7 @@ impl Default for RawConfig {
             db: DatabaseConfig::default(),
             public_url: Url::parse(&format!("http://localhost:{port}"))
                 .expect("Cannot parse public URL parameter."),
+            safe_mode: false,
             security: SecurityConfig::default(),
             components: ComponentsConfig::default(),
             scheduler: SchedulerJobsConfig::default(),
@@ -69,8 +165,11 @@ impl Provider for RawConfig {
 
 #[cfg(test)]
 mod tests {
-    use crate::config::RawConfig;
+    use super::{interpolate_env_vars, interpolate_env_vars_in_string};
+    use crate::config::{RawConfig, SmtpConfig};
+    use figment::value::{Dict, Value};
     use insta::{assert_debug_snapshot, assert_toml_snapshot};
+    use std::collections::HashMap;
     use url::Url;
 
     #[test]
@@ -82,6 +181,7 @@ mod tests {
         assert_toml_snapshot!(default_config, @r###"
         port = 7070
         public_url = 'http://localhost:7070/'
+        safe_mode = false
 
         [db]
         name = 'secutils'
@@ -102,6 +202,11 @@ mod tests {
         web_page_trackers_schedule = '0 * * * * * *'
         web_page_trackers_fetch = '0 * * * * * *'
         notifications_send = '0/30 * * * * * *'
+        web_page_trackers_fetch_concurrency = 5
+        web_page_trackers_fetch_host_interval = 2000
+        web_page_trackers_fetch_max_jobs = 500
+        notifications_send_batch_size = 10
+        notifications_send_concurrency = 5
 
         [subscriptions]
         feature_overview_url = 'http://localhost:7272/'
@@ -116,6 +221,9 @@ mod tests {
         trackers = 100
         tracker_revisions = 30
         min_schedule_interval = 10000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [subscriptions.basic.certificates]
         private_keys = 100
@@ -135,6 +243,9 @@ mod tests {
         trackers = 100
         tracker_revisions = 30
         min_schedule_interval = 10000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [subscriptions.standard.certificates]
         private_keys = 100
@@ -154,6 +265,9 @@ mod tests {
         trackers = 100
         tracker_revisions = 30
         min_schedule_interval = 10000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [subscriptions.professional.certificates]
         private_keys = 100
@@ -173,6 +287,9 @@ mod tests {
         trackers = 100
         tracker_revisions = 30
         min_schedule_interval = 10000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [subscriptions.ultimate.certificates]
         private_keys = 100
@@ -218,6 +335,11 @@ mod tests {
         web_page_trackers_schedule = '0 * * * * * *'
         web_page_trackers_fetch = '0 * * * * * *'
         notifications_send = '0/30 * * * * * *'
+        web_page_trackers_fetch_concurrency = 5
+        web_page_trackers_fetch_host_interval = 2000
+        web_page_trackers_fetch_max_jobs = 500
+        notifications_send_batch_size = 10
+        notifications_send_concurrency = 5
 
         [subscriptions]
         feature_overview_url = 'http://localhost:7272/'
@@ -233,6 +355,9 @@ mod tests {
         trackers = 1
         tracker_revisions = 11
         min_schedule_interval = 10_000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [subscriptions.basic.web_security]
         policies = 10
@@ -254,6 +379,9 @@ mod tests {
         trackers = 2
         tracker_revisions = 22
         min_schedule_interval = 20_000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [subscriptions.standard.web_security]
         policies = 1000
@@ -275,6 +403,9 @@ mod tests {
         trackers = 3
         tracker_revisions = 33
         min_schedule_interval = 30_000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [subscriptions.professional.web_security]
         policies = 1000
@@ -296,6 +427,9 @@ mod tests {
         trackers = 4
         tracker_revisions = 44
         min_schedule_interval = 40_000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [subscriptions.ultimate.web_security]
         policies = 1000
@@ -331,18 +465,21 @@ mod tests {
                 query: None,
                 fragment: None,
             },
+            safe_mode: false,
             db: DatabaseConfig {
                 name: "secutils",
                 host: "localhost",
                 port: 5432,
                 username: "postgres",
                 password: Some(
-                    "password",
+                    "[REDACTED]",
                 ),
             },
             security: SecurityConfig {
                 session_cookie_name: "id2",
                 jwt_secret: None,
+                encryption_key: None,
+                secrets_provider: None,
                 operators: None,
                 preconfigured_users: Some(
                     {
@@ -405,6 +542,10 @@ mod tests {
                     query: None,
                     fragment: None,
                 },
+                web_scraper: WebScraperConfig {
+                    retries: 0,
+                    circuit_breaker: None,
+                },
                 search_index_version: 3,
             },
             scheduler: SchedulerJobsConfig {
@@ -499,6 +640,11 @@ mod tests {
                         },
                     },
                 },
+                web_page_trackers_fetch_concurrency: 5,
+                web_page_trackers_fetch_host_interval: 2s,
+                web_page_trackers_fetch_max_jobs: 500,
+                notifications_send_batch_size: 10,
+                notifications_send_concurrency: 5,
             },
             subscriptions: SubscriptionsConfig {
                 manage_url: None,
@@ -534,6 +680,10 @@ mod tests {
                         tracker_revisions: 11,
                         tracker_schedules: None,
                         min_schedule_interval: 10s,
+                        tracker_resources: 1000,
+                        tracker_captured_headers: 100,
+                        tracker_urls: 10,
+                        tracker_idle_period: None,
                     },
                     certificates: SubscriptionCertificatesConfig {
                         private_keys: 1,
@@ -562,6 +712,10 @@ mod tests {
                         tracker_revisions: 22,
                         tracker_schedules: None,
                         min_schedule_interval: 20s,
+                        tracker_resources: 1000,
+                        tracker_captured_headers: 100,
+                        tracker_urls: 10,
+                        tracker_idle_period: None,
                     },
                     certificates: SubscriptionCertificatesConfig {
                         private_keys: 2,
@@ -590,6 +744,10 @@ mod tests {
                         tracker_revisions: 33,
                         tracker_schedules: None,
                         min_schedule_interval: 30s,
+                        tracker_resources: 1000,
+                        tracker_captured_headers: 100,
+                        tracker_urls: 10,
+                        tracker_idle_period: None,
                     },
                     certificates: SubscriptionCertificatesConfig {
                         private_keys: 3,
@@ -618,6 +776,10 @@ mod tests {
                         tracker_revisions: 44,
                         tracker_schedules: None,
                         min_schedule_interval: 40s,
+                        tracker_resources: 1000,
+                        tracker_captured_headers: 100,
+                        tracker_urls: 10,
+                        tracker_idle_period: None,
                     },
                     certificates: SubscriptionCertificatesConfig {
                         private_keys: 4,
@@ -632,9 +794,147 @@ mod tests {
             },
             utils: UtilsConfig {
                 webhook_url_type: Subdomain,
+                responder_templates_dir: None,
+                max_inbound_emails: None,
+                webhook_custom_domains: {},
+                web_scraping: UtilsWebScrapingConfig {
+                    defaults: WebPageTrackerDefaultsConfig {
+                        schedule: None,
+                        retry_strategy: None,
+                        notifications: false,
+                        data_policy: None,
+                    },
+                    webhook_allowed_domains: None,
+                    max_diff_size: None,
+                    sub_fetch_concurrency: None,
+                    async_scrapes: false,
+                    webhook_secret_grace_period: None,
+                },
             },
             smtp: None,
         }
         "###);
     }
+
+    #[test]
+    fn interpolates_env_vars() -> anyhow::Result<()> {
+        let env = HashMap::from([
+            ("SMTP_PASSWORD", "secret"),
+            ("SMTP_HOST", "smtp.secutils.dev"),
+            ("EMPTY_VAR", ""),
+        ]);
+        let lookup = |name: &str| env.get(name).map(|value| value.to_string());
+
+        assert_eq!(
+            interpolate_env_vars_in_string("no references", &lookup)?,
+            "no references"
+        );
+        assert_eq!(
+            interpolate_env_vars_in_string("${SMTP_PASSWORD}", &lookup)?,
+            "secret"
+        );
+        assert_eq!(
+            interpolate_env_vars_in_string("smtp://${SMTP_HOST}:${SMTP_PORT:-587}/", &lookup)?,
+            "smtp://smtp.secutils.dev:587/"
+        );
+        assert_eq!(
+            interpolate_env_vars_in_string("${SMTP_HOST:-localhost}", &lookup)?,
+            "smtp.secutils.dev"
+        );
+        assert_eq!(
+            interpolate_env_vars_in_string("${EMPTY_VAR:-default}", &lookup)?,
+            "default"
+        );
+        assert_eq!(interpolate_env_vars_in_string("${EMPTY_VAR}", &lookup)?, "");
+        assert_eq!(
+            interpolate_env_vars_in_string("${UNKNOWN_VAR:-}", &lookup)?,
+            ""
+        );
+        assert_eq!(
+            interpolate_env_vars_in_string("$${SMTP_PASSWORD}", &lookup)?,
+            "${SMTP_PASSWORD}"
+        );
+        assert_eq!(
+            interpolate_env_vars_in_string("pa$$word-$${UNKNOWN_VAR}-${SMTP_HOST}", &lookup)?,
+            "pa$$word-${UNKNOWN_VAR}-smtp.secutils.dev"
+        );
+
+        let mut value = Value::from(Dict::from([
+            ("password".to_string(), Value::from("${SMTP_PASSWORD}")),
+            (
+                "hosts".to_string(),
+                Value::from(vec![Value::from("${SMTP_HOST}"), Value::from(1)]),
+            ),
+        ]));
+        interpolate_env_vars(&mut value, &lookup)?;
+        assert_eq!(
+            value,
+            Value::from(Dict::from([
+                ("password".to_string(), Value::from("secret")),
+                (
+                    "hosts".to_string(),
+                    Value::from(vec![Value::from("smtp.secutils.dev"), Value::from(1)]),
+                ),
+            ]))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_interpolate_missing_or_invalid_env_vars() {
+        let lookup = |_: &str| None;
+
+        assert_debug_snapshot!(
+            interpolate_env_vars_in_string("${SMTP_PASSWORD}", &lookup).unwrap_err(),
+            @r###""Config value references environment variable `SMTP_PASSWORD` that is not set and doesn't have a default value.""###
+        );
+        assert_debug_snapshot!(
+            interpolate_env_vars_in_string("${SMTP_PASSWORD", &lookup).unwrap_err(),
+            @r###""Config value has unterminated environment variable reference.""###
+        );
+        assert_debug_snapshot!(
+            interpolate_env_vars_in_string("${SMTP PASSWORD}", &lookup).unwrap_err(),
+            @r###""Config value references environment variable with invalid name.""###
+        );
+    }
+
+    #[test]
+    fn redacts_secrets_in_debug_output() {
+        let mut config = RawConfig::default();
+        config.db.password = Some("db-password".to_string());
+        config.security.jwt_secret = Some("jwt-secret".to_string());
+        config.security.encryption_key = Some("encryption-key".to_string());
+        config.smtp = Some(SmtpConfig {
+            username: "test@secutils.dev".to_string(),
+            password: "smtp-password".to_string(),
+            address: "smtp.secutils.dev".to_string(),
+            catch_all: None,
+            pool_size: None,
+            concurrency: None,
+        });
+
+        let debug_output = format!("{config:?}");
+        for secret in [
+            "db-password",
+            "jwt-secret",
+            "encryption-key",
+            "smtp-password",
+        ] {
+            assert!(!debug_output.contains(secret), "{secret} isn't redacted");
+        }
+
+        assert_debug_snapshot!(config.smtp, @r###"
+        Some(
+            SmtpConfig {
+                username: "test@secutils.dev",
+                password: "[REDACTED]",
+                address: "smtp.secutils.dev",
+                catch_all: None,
+                pool_size: None,
+                concurrency: None,
+            },
+        )
+        "###);
+    }
 }
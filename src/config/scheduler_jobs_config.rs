@@ -1,7 +1,7 @@
 use cron::Schedule;
 use serde_derive::{Deserialize, Serialize};
-use serde_with::{serde_as, DisplayFromStr};
-use std::str::FromStr;
+use serde_with::{serde_as, DisplayFromStr, DurationMilliSeconds};
+use std::{str::FromStr, time::Duration};
 
 /// Configuration for the Secutils.dev scheduler jobs.
 #[serde_as]
@@ -16,6 +16,21 @@ pub struct SchedulerJobsConfig {
     /// The schedule to use for the `NotificationsSend` job.
     #[serde_as(as = "DisplayFromStr")]
     pub notifications_send: Schedule,
+    /// The maximum number of web page trackers the `WebPageTrackersFetch` job fetches concurrently.
+    pub web_page_trackers_fetch_concurrency: usize,
+    /// The minimum interval between consecutive fetches of the web page trackers that target the
+    /// same host.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub web_page_trackers_fetch_host_interval: Duration,
+    /// The soft cap on the number of pending web page tracker jobs the `WebPageTrackersFetch` job
+    /// processes within a single run. The most overdue jobs are processed first, and the rest are
+    /// postponed till the next run.
+    pub web_page_trackers_fetch_max_jobs: usize,
+    /// The number of pending notifications the `NotificationsSend` job sends at once.
+    pub notifications_send_batch_size: usize,
+    /// The maximum number of notifications the `NotificationsSend` job sends concurrently through
+    /// every delivery channel (email or webhook).
+    pub notifications_send_concurrency: usize,
 }
 
 impl Default for SchedulerJobsConfig {
@@ -27,6 +42,11 @@ impl Default for SchedulerJobsConfig {
                 .expect("Cannot parse web page trackers fetch job schedule."),
             notifications_send: Schedule::from_str("0/30 * * * * * *")
                 .expect("Cannot parse notifications send job schedule."),
+            web_page_trackers_fetch_concurrency: 5,
+            web_page_trackers_fetch_host_interval: Duration::from_secs(2),
+            web_page_trackers_fetch_max_jobs: 500,
+            notifications_send_batch_size: 10,
+            notifications_send_concurrency: 5,
         }
     }
 }
@@ -42,6 +62,11 @@ mod tests {
         web_page_trackers_schedule = '0 * * * * * *'
         web_page_trackers_fetch = '0 * * * * * *'
         notifications_send = '0/30 * * * * * *'
+        web_page_trackers_fetch_concurrency = 5
+        web_page_trackers_fetch_host_interval = 2000
+        web_page_trackers_fetch_max_jobs = 500
+        notifications_send_batch_size = 10
+        notifications_send_concurrency = 5
         "###);
     }
 
@@ -52,6 +77,11 @@ mod tests {
         web_page_trackers_schedule = '0 * * * * * *'
         web_page_trackers_fetch = '0 * * * * * *'
         notifications_send = '0/30 * * * * * *'
+        web_page_trackers_fetch_concurrency = 5
+        web_page_trackers_fetch_host_interval = 2000
+        web_page_trackers_fetch_max_jobs = 500
+        notifications_send_batch_size = 10
+        notifications_send_concurrency = 5
     "#,
         )
         .unwrap();
@@ -0,0 +1,69 @@
+use serde_derive::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Configuration of the provider that resolves secret references (`secret://name`) used in the
+/// config and web page tracker credentials.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum SecretsProviderConfig {
+    /// Secrets are read from the environment variables, the name of the variable is the secret
+    /// name in upper case prefixed with the specified prefix (e.g. `SECUTILS_SECRET_SMTP_PASSWORD`).
+    Env { prefix: String },
+    /// Secrets are read from the files in the specified directory (e.g. mounted Kubernetes or
+    /// Docker secrets), the secret name is the path of the file relative to the directory.
+    File { dir: PathBuf },
+}
+
+impl Default for SecretsProviderConfig {
+    fn default() -> Self {
+        Self::Env {
+            prefix: "SECUTILS_SECRET_".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::SecretsProviderConfig;
+    use insta::assert_toml_snapshot;
+    use std::path::PathBuf;
+
+    #[test]
+    fn serialization_and_default() {
+        assert_toml_snapshot!(SecretsProviderConfig::default(), @r###"
+        type = 'env'
+        prefix = 'SECUTILS_SECRET_'
+        "###);
+
+        assert_toml_snapshot!(SecretsProviderConfig::File { dir: PathBuf::from("/run/secrets") }, @r###"
+        type = 'file'
+        dir = '/run/secrets'
+        "###);
+    }
+
+    #[test]
+    fn deserialization() {
+        let config: SecretsProviderConfig = toml::from_str(
+            r#"
+        type = 'env'
+        prefix = 'SECUTILS_SECRET_'
+    "#,
+        )
+        .unwrap();
+        assert_eq!(config, SecretsProviderConfig::default());
+
+        let config: SecretsProviderConfig = toml::from_str(
+            r#"
+        type = 'file'
+        dir = '/run/secrets'
+    "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            SecretsProviderConfig::File {
+                dir: PathBuf::from("/run/secrets")
+            }
+        );
+    }
+}
@@ -1,6 +1,9 @@
-use crate::users::SubscriptionTier;
+use crate::{config::SecretsProviderConfig, logging::REDACTED_VALUE, users::SubscriptionTier};
 use serde_derive::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Debug, Formatter},
+};
 
 /// Describes the preconfigured user configuration.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
@@ -12,13 +15,20 @@ pub struct PreconfiguredUserConfig {
 }
 
 /// Configuration for the SMTP functionality.
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
 pub struct SecurityConfig {
     /// Name of the session cookie used by the authentication component.
     pub session_cookie_name: String,
     /// Secret key used to sign JWT tokens used for HTTP authentication. If not provided, HTTP
     /// authentication will be disabled.
     pub jwt_secret: Option<String>,
+    /// Hex-encoded 256-bit key used to encrypt sensitive user data at rest (e.g. webhook secrets).
+    /// If not provided, features that require encryption will be disabled.
+    pub encryption_key: Option<String>,
+    /// Provider that resolves secret references (`secret://name`) used in the config and web page
+    /// tracker credentials. If not provided, secrets are read from the environment variables.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secrets_provider: Option<SecretsProviderConfig>,
     /// List of user or service account identifiers that should be treated as operators, if specified.
     pub operators: Option<HashSet<String>>,
     /// List of the preconfigured users, if specified.
@@ -30,19 +40,42 @@ impl Default for SecurityConfig {
         Self {
             session_cookie_name: "id".to_string(),
             jwt_secret: None,
+            encryption_key: None,
+            secrets_provider: None,
             preconfigured_users: None,
             operators: None,
         }
     }
 }
 
+// Secrets are never included into the debug output since the config is logged at startup.
+impl Debug for SecurityConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecurityConfig")
+            .field("session_cookie_name", &self.session_cookie_name)
+            .field(
+                "jwt_secret",
+                &self.jwt_secret.as_ref().map(|_| REDACTED_VALUE),
+            )
+            .field(
+                "encryption_key",
+                &self.encryption_key.as_ref().map(|_| REDACTED_VALUE),
+            )
+            .field("secrets_provider", &self.secrets_provider)
+            .field("operators", &self.operators)
+            .field("preconfigured_users", &self.preconfigured_users)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        config::{security_config::PreconfiguredUserConfig, SecurityConfig},
+        config::{security_config::PreconfiguredUserConfig, SecretsProviderConfig, SecurityConfig},
         users::SubscriptionTier,
     };
     use insta::assert_toml_snapshot;
+    use std::path::PathBuf;
 
     #[test]
     fn serialization_and_default() {
@@ -51,6 +84,10 @@ mod tests {
         let config = SecurityConfig {
             session_cookie_name: "id".to_string(),
             jwt_secret: Some("3024bf8975b03b84e405f36a7bacd1c1".to_string()),
+            encryption_key: Some(
+                "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a".to_string(),
+            ),
+            secrets_provider: None,
             operators: Some(["test@secutils.dev".to_string()].into_iter().collect()),
             preconfigured_users: Some(
                 [(
@@ -68,6 +105,7 @@ mod tests {
         assert_toml_snapshot!(config, @r###"
         session_cookie_name = 'id'
         jwt_secret = '3024bf8975b03b84e405f36a7bacd1c1'
+        encryption_key = '4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a'
         operators = ['test@secutils.dev']
         [preconfigured_users."test@secutils.dev"]
         handle = 'test-handle'
@@ -89,6 +127,8 @@ mod tests {
             SecurityConfig {
                 session_cookie_name: "id".to_string(),
                 jwt_secret: None,
+                encryption_key: None,
+                secrets_provider: None,
                 preconfigured_users: None,
                 operators: None,
             }
@@ -98,8 +138,13 @@ mod tests {
             r#"
         session_cookie_name = 'id'
         jwt_secret = '3024bf8975b03b84e405f36a7bacd1c1'
+        encryption_key = '4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a'
         operators = ['test@secutils.dev']
 
+        [secrets_provider]
+        type = 'file'
+        dir = '/run/secrets'
+
         [preconfigured_users."test@secutils.dev"]
         handle = 'test-handle'
         tier = 'basic'
@@ -111,6 +156,9 @@ mod tests {
             config,
             SecurityConfig {
                 jwt_secret: Some("3024bf8975b03b84e405f36a7bacd1c1".to_string()),
+                encryption_key: Some(
+                    "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a".to_string()
+                ),
                 preconfigured_users: Some(
                     [(
                         "test@secutils.dev".to_string(),
@@ -123,6 +171,9 @@ mod tests {
                     .collect(),
                 ),
                 operators: Some(["test@secutils.dev".to_string()].into_iter().collect()),
+                secrets_provider: Some(SecretsProviderConfig::File {
+                    dir: PathBuf::from("/run/secrets")
+                }),
                 ..Default::default()
             }
         );
@@ -1,8 +1,9 @@
-use crate::config::SmtpCatchAllConfig;
+use crate::{config::SmtpCatchAllConfig, logging::REDACTED_VALUE};
 use serde_derive::{Deserialize, Serialize};
+use std::fmt::{self, Debug, Formatter};
 
 /// Configuration for the SMTP functionality.
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Clone)]
 pub struct SmtpConfig {
     /// Username to use to authenticate to the SMTP server.
     pub username: String,
@@ -12,6 +13,46 @@ pub struct SmtpConfig {
     pub address: String,
     /// Optional configuration for catch-all email recipient (used for troubleshooting only).
     pub catch_all: Option<SmtpCatchAllConfig>,
+    /// Maximum number of connections to the SMTP server kept open in the connection pool, if
+    /// specified. Defaults to `DEFAULT_SMTP_POOL_SIZE`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_size: Option<u32>,
+    /// Maximum number of emails that can be sent concurrently, if specified. Defaults to
+    /// `DEFAULT_SMTP_CONCURRENCY`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<usize>,
+}
+
+/// Default maximum number of connections to the SMTP server kept open in the connection pool.
+const DEFAULT_SMTP_POOL_SIZE: u32 = 5;
+
+/// Default maximum number of emails that can be sent concurrently.
+const DEFAULT_SMTP_CONCURRENCY: usize = 5;
+
+impl SmtpConfig {
+    /// Returns the maximum number of connections to the SMTP server kept open in the pool.
+    pub fn pool_size(&self) -> u32 {
+        self.pool_size.unwrap_or(DEFAULT_SMTP_POOL_SIZE).max(1)
+    }
+
+    /// Returns the maximum number of emails that can be sent concurrently.
+    pub fn concurrency(&self) -> usize {
+        self.concurrency.unwrap_or(DEFAULT_SMTP_CONCURRENCY).max(1)
+    }
+}
+
+// Password is never included into the debug output since the config is logged at startup.
+impl Debug for SmtpConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmtpConfig")
+            .field("username", &self.username)
+            .field("password", &REDACTED_VALUE)
+            .field("address", &self.address)
+            .field("catch_all", &self.catch_all)
+            .field("pool_size", &self.pool_size)
+            .field("concurrency", &self.concurrency)
+            .finish()
+    }
 }
 
 #[cfg(test)]
@@ -27,6 +68,8 @@ mod tests {
             password: "password".to_string(),
             address: "smtp.secutils.dev".to_string(),
             catch_all: None,
+            pool_size: None,
+            concurrency: None,
         };
         assert_toml_snapshot!(config, @r###"
         username = 'test@secutils.dev'
@@ -42,11 +85,15 @@ mod tests {
                 recipient: "test@secutils.dev".to_string(),
                 text_matcher: Regex::new(r"test").unwrap(),
             }),
+            pool_size: Some(10),
+            concurrency: Some(3),
         };
         assert_toml_snapshot!(config, @r###"
         username = 'test@secutils.dev'
         password = 'password'
         address = 'smtp.secutils.dev'
+        pool_size = 10
+        concurrency = 3
 
         [catch_all]
         recipient = 'test@secutils.dev'
@@ -61,6 +108,8 @@ mod tests {
         username = 'test@secutils.dev'
         password = 'password'
         address = 'smtp.secutils.dev'
+        pool_size = 10
+        concurrency = 3
 
         [catch_all]
         recipient = 'test@secutils.dev'
@@ -71,7 +120,7 @@ mod tests {
         assert_debug_snapshot!(config, @r###"
         SmtpConfig {
             username: "test@secutils.dev",
-            password: "password",
+            password: "[REDACTED]",
             address: "smtp.secutils.dev",
             catch_all: Some(
                 SmtpCatchAllConfig {
@@ -81,7 +130,35 @@ mod tests {
                     ),
                 },
             ),
+            pool_size: Some(
+                10,
+            ),
+            concurrency: Some(
+                3,
+            ),
         }
         "###);
     }
+
+    #[test]
+    fn pool_size_and_concurrency() {
+        let config = SmtpConfig {
+            username: "test@secutils.dev".to_string(),
+            password: "password".to_string(),
+            address: "smtp.secutils.dev".to_string(),
+            catch_all: None,
+            pool_size: None,
+            concurrency: None,
+        };
+        assert_eq!(config.pool_size(), 5);
+        assert_eq!(config.concurrency(), 5);
+
+        let config = SmtpConfig {
+            pool_size: Some(10),
+            concurrency: Some(0),
+            ..config
+        };
+        assert_eq!(config.pool_size(), 10);
+        assert_eq!(config.concurrency(), 1);
+    }
 }
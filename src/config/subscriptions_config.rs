@@ -73,6 +73,9 @@ mod tests {
         trackers = 100
         tracker_revisions = 30
         min_schedule_interval = 10000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [basic.certificates]
         private_keys = 100
@@ -92,6 +95,9 @@ mod tests {
         trackers = 100
         tracker_revisions = 30
         min_schedule_interval = 10000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [standard.certificates]
         private_keys = 100
@@ -111,6 +117,9 @@ mod tests {
         trackers = 100
         tracker_revisions = 30
         min_schedule_interval = 10000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [professional.certificates]
         private_keys = 100
@@ -130,6 +139,9 @@ mod tests {
         trackers = 100
         tracker_revisions = 30
         min_schedule_interval = 10000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [ultimate.certificates]
         private_keys = 100
@@ -162,6 +174,9 @@ mod tests {
         trackers = 100
         tracker_revisions = 30
         min_schedule_interval = 10000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [basic.certificates]
         private_keys = 100
@@ -181,6 +196,9 @@ mod tests {
         trackers = 100
         tracker_revisions = 30
         min_schedule_interval = 10000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [standard.certificates]
         private_keys = 100
@@ -200,6 +218,9 @@ mod tests {
         trackers = 100
         tracker_revisions = 30
         min_schedule_interval = 10000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [professional.certificates]
         private_keys = 100
@@ -219,6 +240,9 @@ mod tests {
         trackers = 100
         tracker_revisions = 30
         min_schedule_interval = 10000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [ultimate.certificates]
         private_keys = 100
@@ -249,6 +273,9 @@ mod tests {
         tracker_revisions = 11
         tracker_schedules = ["@", "@daily", "@weekly", "@monthly"]
         min_schedule_interval = 10_000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [basic.certificates]
         private_keys = 1
@@ -271,6 +298,9 @@ mod tests {
         tracker_revisions = 22
         tracker_schedules = ["@", "@hourly", "@daily", "@weekly", "@monthly"]
         min_schedule_interval = 20_000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [standard.web_security]
         policies = 1000
@@ -292,6 +322,9 @@ mod tests {
         trackers = 3
         tracker_revisions = 33
         min_schedule_interval = 30_000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [professional.web_security]
         policies = 1000
@@ -312,6 +345,9 @@ mod tests {
         trackers = 4
         tracker_revisions = 44
         min_schedule_interval = 40_000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
 
         [ultimate.web_security]
         policies = 1000
@@ -350,6 +386,10 @@ mod tests {
                             .collect()
                         ),
                         min_schedule_interval: Duration::from_secs(10),
+                        tracker_resources: 1000,
+                        tracker_captured_headers: 100,
+                        tracker_urls: 10,
+                        tracker_idle_period: None,
                     },
                     web_security: SubscriptionWebSecurityConfig {
                         policies: 10,
@@ -391,6 +431,10 @@ mod tests {
                             .collect()
                         ),
                         min_schedule_interval: Duration::from_secs(20),
+                        tracker_resources: 1000,
+                        tracker_captured_headers: 100,
+                        tracker_urls: 10,
+                        tracker_idle_period: None,
                     },
                     web_security: SubscriptionWebSecurityConfig::default(),
                     certificates: SubscriptionCertificatesConfig {
@@ -419,6 +463,10 @@ mod tests {
                         tracker_revisions: 33,
                         tracker_schedules: None,
                         min_schedule_interval: Duration::from_secs(30),
+                        tracker_resources: 1000,
+                        tracker_captured_headers: 100,
+                        tracker_urls: 10,
+                        tracker_idle_period: None,
                     },
                     web_security: SubscriptionWebSecurityConfig::default(),
                     certificates: SubscriptionCertificatesConfig {
@@ -440,6 +488,10 @@ mod tests {
                         tracker_revisions: 44,
                         tracker_schedules: None,
                         min_schedule_interval: Duration::from_secs(40),
+                        tracker_resources: 1000,
+                        tracker_captured_headers: 100,
+                        tracker_urls: 10,
+                        tracker_idle_period: None,
                     },
                     web_security: SubscriptionWebSecurityConfig::default(),
                     certificates: SubscriptionCertificatesConfig {
@@ -479,6 +531,10 @@ mod tests {
                         .collect(),
                     ),
                     min_schedule_interval: Duration::from_secs(10),
+                    tracker_resources: 1000,
+                    tracker_captured_headers: 100,
+                    tracker_urls: 10,
+                    tracker_idle_period: None,
                 },
                 web_security: SubscriptionWebSecurityConfig {
                     policies: 10,
@@ -520,6 +576,10 @@ mod tests {
                         .collect(),
                     ),
                     min_schedule_interval: Duration::from_secs(20),
+                    tracker_resources: 1000,
+                    tracker_captured_headers: 100,
+                    tracker_urls: 10,
+                    tracker_idle_period: None,
                 },
                 web_security: SubscriptionWebSecurityConfig::default(),
                 certificates: SubscriptionCertificatesConfig {
@@ -548,6 +608,10 @@ mod tests {
                     tracker_revisions: 33,
                     tracker_schedules: None,
                     min_schedule_interval: Duration::from_secs(30),
+                    tracker_resources: 1000,
+                    tracker_captured_headers: 100,
+                    tracker_urls: 10,
+                    tracker_idle_period: None,
                 },
                 web_security: SubscriptionWebSecurityConfig::default(),
                 certificates: SubscriptionCertificatesConfig {
@@ -569,6 +633,10 @@ mod tests {
                     tracker_revisions: 44,
                     tracker_schedules: None,
                     min_schedule_interval: Duration::from_secs(40),
+                    tracker_resources: 1000,
+                    tracker_captured_headers: 100,
+                    tracker_urls: 10,
+                    tracker_idle_period: None,
                 },
                 web_security: SubscriptionWebSecurityConfig::default(),
                 certificates: SubscriptionCertificatesConfig {
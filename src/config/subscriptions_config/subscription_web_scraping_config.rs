@@ -14,6 +14,20 @@ pub struct SubscriptionWebScrapingConfig {
     /// The minimum interval between two consequent scheduled tracker checks.
     #[serde_as(as = "DurationMilliSeconds<u64>")]
     pub min_schedule_interval: Duration,
+    /// The maximum number of resources (scripts and styles) that resources trackers retain per
+    /// revision. Extra resources are dropped, and the revision is marked as truncated.
+    pub tracker_resources: usize,
+    /// The maximum number of captured response headers (e.g., `Set-Cookie`) that content trackers
+    /// retain per revision. Extra headers are dropped, and the revision is marked as truncated.
+    pub tracker_captured_headers: usize,
+    /// The maximum number of URLs a single multi-URL content tracker can monitor, including the
+    /// main tracker URL.
+    pub tracker_urls: usize,
+    /// Optional period after which scheduled trackers that have had neither changes nor user
+    /// activity are automatically disabled. Trackers are never disabled if not specified.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracker_idle_period: Option<Duration>,
 }
 
 impl Default for SubscriptionWebScrapingConfig {
@@ -25,6 +39,10 @@ impl Default for SubscriptionWebScrapingConfig {
             tracker_schedules: None,
             // Default to 10 seconds.
             min_schedule_interval: Duration::from_secs(10),
+            tracker_resources: 1000,
+            tracker_captured_headers: 100,
+            tracker_urls: 10,
+            tracker_idle_period: None,
         }
     }
 }
@@ -33,6 +51,7 @@ impl Default for SubscriptionWebScrapingConfig {
 mod tests {
     use crate::config::SubscriptionWebScrapingConfig;
     use insta::assert_toml_snapshot;
+    use std::time::Duration;
 
     #[test]
     fn serialization_and_default() {
@@ -41,6 +60,26 @@ mod tests {
         trackers = 100
         tracker_revisions = 30
         min_schedule_interval = 10000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
+        "###);
+    }
+
+    #[test]
+    fn serialization_with_idle_period() {
+        let config = SubscriptionWebScrapingConfig {
+            tracker_idle_period: Some(Duration::from_secs(30 * 24 * 3600)),
+            ..Default::default()
+        };
+        assert_toml_snapshot!(config, @r###"
+        trackers = 100
+        tracker_revisions = 30
+        min_schedule_interval = 10000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
+        tracker_idle_period = 2592000000
         "###);
     }
 
@@ -51,9 +90,32 @@ mod tests {
         trackers = 100
         tracker_revisions = 30
         min_schedule_interval = 10_000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
     "#,
         )
         .unwrap();
         assert_eq!(config, SubscriptionWebScrapingConfig::default());
+
+        let config: SubscriptionWebScrapingConfig = toml::from_str(
+            r#"
+        trackers = 100
+        tracker_revisions = 30
+        min_schedule_interval = 10_000
+        tracker_resources = 1000
+        tracker_captured_headers = 100
+        tracker_urls = 10
+        tracker_idle_period = 2_592_000_000
+    "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            SubscriptionWebScrapingConfig {
+                tracker_idle_period: Some(Duration::from_secs(30 * 24 * 3600)),
+                ..Default::default()
+            }
+        );
     }
 }
@@ -1,25 +1,58 @@
+mod utils_web_scraping_config;
+mod web_page_tracker_defaults_config;
+
 use crate::server::WebhookUrlType;
 use serde_derive::{Deserialize, Serialize};
+use std::{collections::HashMap, path::PathBuf};
+
+pub use self::{
+    utils_web_scraping_config::UtilsWebScrapingConfig,
+    web_page_tracker_defaults_config::WebPageTrackerDefaultsConfig,
+};
 
 /// Configuration for the JS runtime (Deno).
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct UtilsConfig {
     /// Describes the preferred way to construct webhook URLs.
     pub webhook_url_type: WebhookUrlType,
+    /// Optional path to the directory with the templates that webhook responders can serve as
+    /// response body. Responders can't reference files outside of this directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub responder_templates_dir: Option<PathBuf>,
+    /// Optional maximum number of inbound emails retained per user in the disposable inbox. The
+    /// oldest emails are removed once the limit is reached. Inbound email intake is disabled if
+    /// not specified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_inbound_emails: Option<usize>,
+    /// Custom domains that serve webhook responders of a particular user, where the key is the
+    /// lowercase domain name (e.g. `hooks.example.com`) and the value is the user handle.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub webhook_custom_domains: HashMap<String, String>,
+    /// Configuration for the web scraping utilities.
+    #[serde(default, skip_serializing_if = "UtilsWebScrapingConfig::is_empty")]
+    pub web_scraping: UtilsWebScrapingConfig,
 }
 
 impl Default for UtilsConfig {
     fn default() -> Self {
         Self {
             webhook_url_type: WebhookUrlType::Subdomain,
+            responder_templates_dir: None,
+            max_inbound_emails: None,
+            webhook_custom_domains: HashMap::new(),
+            web_scraping: UtilsWebScrapingConfig::default(),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{config::UtilsConfig, server::WebhookUrlType};
+    use crate::{
+        config::{UtilsConfig, UtilsWebScrapingConfig, WebPageTrackerDefaultsConfig},
+        server::WebhookUrlType,
+    };
     use insta::assert_toml_snapshot;
+    use std::{collections::HashMap, path::PathBuf};
 
     #[test]
     fn serialization_and_default() {
@@ -32,7 +65,48 @@ mod tests {
         assert_eq!(
             config,
             UtilsConfig {
-                webhook_url_type: WebhookUrlType::Path
+                webhook_url_type: WebhookUrlType::Path,
+                responder_templates_dir: None,
+                max_inbound_emails: None,
+                webhook_custom_domains: HashMap::new(),
+                web_scraping: Default::default(),
+            }
+        );
+
+        let config: UtilsConfig = toml::from_str(
+            r#"
+        webhook_url_type = 'path'
+        responder_templates_dir = '/etc/secutils/templates'
+        max_inbound_emails = 50
+
+        [webhook_custom_domains]
+        'hooks.example.com' = 'handle'
+
+        [web_scraping.defaults]
+        schedule = '0 0 * * * *'
+        "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            UtilsConfig {
+                webhook_url_type: WebhookUrlType::Path,
+                responder_templates_dir: Some(PathBuf::from("/etc/secutils/templates")),
+                max_inbound_emails: Some(50),
+                webhook_custom_domains: [("hooks.example.com".to_string(), "handle".to_string())]
+                    .into_iter()
+                    .collect(),
+                web_scraping: UtilsWebScrapingConfig {
+                    defaults: WebPageTrackerDefaultsConfig {
+                        schedule: Some("0 0 * * * *".to_string()),
+                        ..Default::default()
+                    },
+                    webhook_allowed_domains: None,
+                    max_diff_size: None,
+                    sub_fetch_concurrency: None,
+                    async_scrapes: false,
+                    webhook_secret_grace_period: None,
+                },
             }
         );
     }
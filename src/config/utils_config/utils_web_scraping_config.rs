@@ -0,0 +1,208 @@
+use crate::config::WebPageTrackerDefaultsConfig;
+use serde_derive::{Deserialize, Serialize};
+use serde_with::{serde_as, DurationMilliSeconds};
+use std::{collections::HashSet, time::Duration};
+use url::Url;
+
+/// Default maximum number of concurrent requests a web page tracker sends within a single run.
+const DEFAULT_SUB_FETCH_CONCURRENCY: usize = 2;
+
+/// Configuration for the web scraping utilities.
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct UtilsWebScrapingConfig {
+    /// Defaults applied to the newly created web page trackers if the user doesn't specify the
+    /// corresponding values explicitly.
+    #[serde(default)]
+    pub defaults: WebPageTrackerDefaultsConfig,
+    /// Optional list of lowercase domain names (e.g. `hooks.slack.com`) that web page tracker
+    /// notification webhooks can be delivered to, including their subdomains. Webhooks can be
+    /// delivered to any public domain if not specified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_allowed_domains: Option<HashSet<String>>,
+    /// Optional maximum size of the web page content diff, in bytes. Diffs that exceed this size
+    /// are truncated in API responses and notifications, while revisions are stored in full.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_diff_size: Option<usize>,
+    /// Optional default maximum number of concurrent requests a web page tracker sends within a
+    /// single run when it needs to fetch multiple URLs. Trackers can override it individually.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_fetch_concurrency: Option<usize>,
+    /// Indicates whether long-running scrapes (e.g. web page screenshots) should be submitted to
+    /// the web scraper as asynchronous jobs. The web scraper delivers the results of such jobs to
+    /// the callback endpoint instead of holding the request until the scrape completes.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub async_scrapes: bool,
+    /// Optional grace period during which the previous web page tracker webhook secret is still
+    /// used to sign notification payloads after the secret is rotated, so that the receivers can
+    /// switch to the new secret without rejecting notifications. If not specified, the previous
+    /// secret stops being used immediately.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_secret_grace_period: Option<Duration>,
+}
+
+impl UtilsWebScrapingConfig {
+    /// Checks whether the config doesn't define anything beyond the default values.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Returns the default maximum number of concurrent requests a web page tracker sends within a
+    /// single run, never less than one.
+    pub fn sub_fetch_concurrency(&self) -> usize {
+        self.sub_fetch_concurrency
+            .unwrap_or(DEFAULT_SUB_FETCH_CONCURRENCY)
+            .max(1)
+    }
+
+    /// Checks whether notification webhooks can be delivered to the specified URL.
+    pub fn is_webhook_url_allowed(&self, url: &Url) -> bool {
+        let Some(ref allowed_domains) = self.webhook_allowed_domains else {
+            return true;
+        };
+
+        let Some(domain) = url.domain() else {
+            return false;
+        };
+
+        let domain = domain.to_lowercase();
+        let domain = domain.trim_end_matches('.');
+        allowed_domains.iter().any(|allowed_domain| {
+            domain == allowed_domain
+                || domain
+                    .strip_suffix(allowed_domain.as_str())
+                    .is_some_and(|subdomain| subdomain.ends_with('.'))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{UtilsWebScrapingConfig, WebPageTrackerDefaultsConfig};
+    use crate::scheduler::SchedulerJobRetryStrategy;
+    use insta::assert_toml_snapshot;
+    use std::time::Duration;
+    use url::Url;
+
+    #[test]
+    fn serialization_and_default() {
+        let config = UtilsWebScrapingConfig::default();
+        assert!(config.is_empty());
+        assert_toml_snapshot!(config, @r###"
+        [defaults]
+        notifications = false
+        "###);
+    }
+
+    #[test]
+    fn deserialization() {
+        let config: UtilsWebScrapingConfig = toml::from_str(
+            r#"
+        [defaults]
+        schedule = '0 0 * * * *'
+        retry_strategy = { type = 'constant', interval = 60000, maxAttempts = 3 }
+        "#,
+        )
+        .unwrap();
+        assert!(!config.is_empty());
+        assert_eq!(
+            config,
+            UtilsWebScrapingConfig {
+                defaults: WebPageTrackerDefaultsConfig {
+                    schedule: Some("0 0 * * * *".to_string()),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(60),
+                        max_attempts: 3,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    data_policy: None,
+                },
+                webhook_allowed_domains: None,
+                max_diff_size: None,
+                sub_fetch_concurrency: None,
+                async_scrapes: false,
+                webhook_secret_grace_period: None,
+            }
+        );
+
+        let config: UtilsWebScrapingConfig = toml::from_str(
+            r#"
+        webhook_allowed_domains = ['hooks.slack.com']
+        max_diff_size = 102400
+        sub_fetch_concurrency = 5
+        async_scrapes = true
+        webhook_secret_grace_period = 3600000
+        "#,
+        )
+        .unwrap();
+        assert!(!config.is_empty());
+        assert_eq!(
+            config,
+            UtilsWebScrapingConfig {
+                defaults: Default::default(),
+                webhook_allowed_domains: Some(
+                    ["hooks.slack.com".to_string()].into_iter().collect()
+                ),
+                max_diff_size: Some(102400),
+                sub_fetch_concurrency: Some(5),
+                async_scrapes: true,
+                webhook_secret_grace_period: Some(Duration::from_secs(3600)),
+            }
+        );
+    }
+
+    #[test]
+    fn properly_checks_webhook_urls() -> anyhow::Result<()> {
+        let config = UtilsWebScrapingConfig::default();
+        assert!(config.is_webhook_url_allowed(&Url::parse("https://secutils.dev/webhook")?));
+
+        let config = UtilsWebScrapingConfig {
+            webhook_allowed_domains: Some(
+                ["hooks.slack.com".to_string(), "example.com".to_string()]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+        for url in [
+            "https://hooks.slack.com/services/xxx",
+            "https://HOOKS.slack.com/services/xxx",
+            "https://hooks.slack.com./services/xxx",
+            "https://example.com/webhook",
+            "http://notifications.example.com:8080/webhook",
+        ] {
+            assert!(config.is_webhook_url_allowed(&Url::parse(url)?), "{url}");
+        }
+        for url in [
+            "https://secutils.dev/webhook",
+            "https://slack.com/webhook",
+            "https://evil-hooks.slack.com.attacker.dev/webhook",
+            "https://notexample.com/webhook",
+            "https://127.0.0.1/webhook",
+        ] {
+            assert!(!config.is_webhook_url_allowed(&Url::parse(url)?), "{url}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn sub_fetch_concurrency() {
+        let config = UtilsWebScrapingConfig::default();
+        assert_eq!(config.sub_fetch_concurrency(), 2);
+
+        let config = UtilsWebScrapingConfig {
+            sub_fetch_concurrency: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(config.sub_fetch_concurrency(), 5);
+
+        let config = UtilsWebScrapingConfig {
+            sub_fetch_concurrency: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(config.sub_fetch_concurrency(), 1);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{scheduler::SchedulerJobRetryStrategy, utils::web_scraping::WebPageTrackerDataPolicy};
+use serde_derive::{Deserialize, Serialize};
+
+/// Operator-defined defaults for the newly created web page trackers. Values explicitly provided
+/// by the user always take precedence.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct WebPageTrackerDefaultsConfig {
+    /// Default schedule for the trackers created without a job config. Trackers aren't scheduled
+    /// by default if not specified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+    /// Default retry strategy for the scheduled trackers that don't define one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_strategy: Option<SchedulerJobRetryStrategy>,
+    /// Indicates whether the trackers scheduled with the default schedule should send
+    /// notifications.
+    #[serde(default)]
+    pub notifications: bool,
+    /// Default data policy (classification and retention) for the trackers that don't define one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_policy: Option<WebPageTrackerDataPolicy>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::WebPageTrackerDefaultsConfig,
+        scheduler::SchedulerJobRetryStrategy,
+        utils::web_scraping::{WebPageTrackerDataClassification, WebPageTrackerDataPolicy},
+    };
+    use insta::assert_toml_snapshot;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() {
+        assert_toml_snapshot!(WebPageTrackerDefaultsConfig::default(), @"notifications = false");
+        assert_toml_snapshot!(WebPageTrackerDefaultsConfig {
+            schedule: Some("0 0 * * * *".to_string()),
+            retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                interval: Duration::from_secs(60),
+                max_attempts: 3,
+                max_total_retry_duration: None,
+            }),
+            notifications: true,
+            data_policy: Some(WebPageTrackerDataPolicy {
+                classification: WebPageTrackerDataClassification::Internal,
+                retention: Some(Duration::from_secs(86400)),
+            }),
+        }, @r###"
+        schedule = '0 0 * * * *'
+        notifications = true
+
+        [retry_strategy]
+        type = 'constant'
+        interval = 60000
+        maxAttempts = 3
+
+        [data_policy]
+        classification = 'internal'
+        retention = 86400000
+        "###);
+    }
+
+    #[test]
+    fn deserialization() {
+        let config: WebPageTrackerDefaultsConfig = toml::from_str(
+            r#"
+        schedule = '0 0 * * * *'
+        notifications = true
+
+        [data_policy]
+        classification = 'public'
+        retention = 86400000
+        "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            WebPageTrackerDefaultsConfig {
+                schedule: Some("0 0 * * * *".to_string()),
+                retry_strategy: None,
+                notifications: true,
+                data_policy: Some(WebPageTrackerDataPolicy {
+                    classification: WebPageTrackerDataClassification::Public,
+                    retention: Some(Duration::from_secs(86400)),
+                }),
+            }
+        );
+        assert_eq!(
+            toml::from_str::<WebPageTrackerDefaultsConfig>("").unwrap(),
+            WebPageTrackerDefaultsConfig::default()
+        );
+    }
+}
@@ -1,3 +1,4 @@
+mod error_code;
 mod error_kind;
 
 use actix_web::{http::StatusCode, HttpResponse, HttpResponseBuilder, ResponseError};
@@ -5,13 +6,14 @@ use anyhow::anyhow;
 use serde_json::json;
 use std::fmt::{Debug, Display, Formatter};
 
-pub use error_kind::ErrorKind;
+pub use self::{error_code::ErrorCode, error_kind::ErrorKind};
 
 /// Secutils.dev native error type.
 #[derive(thiserror::Error)]
 pub struct Error {
     pub root_cause: anyhow::Error,
     kind: ErrorKind,
+    code: ErrorCode,
 }
 
 impl Error {
@@ -20,6 +22,7 @@ impl Error {
         Self {
             root_cause,
             kind: ErrorKind::ClientError,
+            code: ErrorCode::InvalidInput,
         }
     }
 
@@ -31,6 +34,7 @@ impl Error {
         Self {
             root_cause: anyhow!(message),
             kind: ErrorKind::ClientError,
+            code: ErrorCode::InvalidInput,
         }
     }
 
@@ -39,8 +43,24 @@ impl Error {
         Self {
             root_cause: anyhow!("Access Forbidden"),
             kind: ErrorKind::AccessForbidden,
+            code: ErrorCode::AccessForbidden,
         }
     }
+
+    /// Sets a specific code for the client error, client errors have `InvalidInput` code by
+    /// default. The code of other errors cannot be changed.
+    pub fn with_code(self, code: ErrorCode) -> Self {
+        match self.kind {
+            ErrorKind::ClientError => Self { code, ..self },
+            ErrorKind::AccessForbidden | ErrorKind::Unknown => self,
+        }
+    }
+
+    /// Returns the code of the error.
+    #[allow(dead_code)]
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
 }
 
 impl Display for Error {
@@ -69,7 +89,8 @@ impl ResponseError for Error {
             "message": match self.kind {
                 ErrorKind::ClientError | ErrorKind::AccessForbidden => self.root_cause.to_string(),
                 ErrorKind::Unknown => "Internal Server Error".to_string(),
-            }
+            },
+            "code": self.code
         }))
     }
 }
@@ -79,13 +100,14 @@ impl From<anyhow::Error> for Error {
         err.downcast::<Error>().unwrap_or_else(|root_cause| Error {
             root_cause,
             kind: ErrorKind::Unknown,
+            code: ErrorCode::Internal,
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Error, ErrorKind};
+    use super::{Error, ErrorCode, ErrorKind};
     use actix_web::{body::MessageBody, http::StatusCode, ResponseError};
     use anyhow::anyhow;
     use bytes::Bytes;
@@ -108,12 +130,15 @@ mod tests {
             Response HTTP/1.1 400 Bad Request
               headers:
                 "content-type": "application/json"
-              body: Sized(20)
+              body: Sized(42)
             ,
         }
         "###);
         let body = error_response.into_body().try_into_bytes().unwrap();
-        assert_eq!(body, Bytes::from_static(b"{\"message\":\"Uh oh.\"}"));
+        assert_eq!(
+            body,
+            Bytes::from_static(b"{\"code\":\"invalidInput\",\"message\":\"Uh oh.\"}")
+        );
 
         let error = Error::client_with_root_cause(anyhow!("Something sensitive").context("Uh oh."));
 
@@ -135,12 +160,45 @@ mod tests {
             Response HTTP/1.1 400 Bad Request
               headers:
                 "content-type": "application/json"
-              body: Sized(20)
+              body: Sized(42)
             ,
         }
         "###);
         let body = error_response.into_body().try_into_bytes().unwrap();
-        assert_eq!(body, Bytes::from_static(b"{\"message\":\"Uh oh.\"}"));
+        assert_eq!(
+            body,
+            Bytes::from_static(b"{\"code\":\"invalidInput\",\"message\":\"Uh oh.\"}")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_create_client_errors_with_code() -> anyhow::Result<()> {
+        let error = Error::client("Uh oh.");
+        assert_eq!(error.code(), ErrorCode::InvalidInput);
+
+        let error = Error::client("Uh oh.").with_code(ErrorCode::TrackerNotFound);
+        assert_eq!(error.kind, ErrorKind::ClientError);
+        assert_eq!(error.code(), ErrorCode::TrackerNotFound);
+        assert_eq!(error.status_code(), StatusCode::BAD_REQUEST);
+
+        let body = error.error_response().into_body().try_into_bytes().unwrap();
+        assert_eq!(
+            body,
+            Bytes::from_static(b"{\"code\":\"trackerNotFound\",\"message\":\"Uh oh.\"}")
+        );
+
+        // Code is preserved when the error is wrapped.
+        let error = Error::from(anyhow!(error).context("Wrapped"));
+        assert_eq!(error.code(), ErrorCode::TrackerNotFound);
+
+        // Code of non-client errors cannot be changed.
+        let error = Error::access_forbidden().with_code(ErrorCode::TrackerNotFound);
+        assert_eq!(error.code(), ErrorCode::AccessForbidden);
+
+        let error = Error::from(anyhow!("Something sensitive")).with_code(ErrorCode::InvalidInput);
+        assert_eq!(error.code(), ErrorCode::Internal);
 
         Ok(())
     }
@@ -162,14 +220,14 @@ mod tests {
             Response HTTP/1.1 403 Forbidden
               headers:
                 "content-type": "application/json"
-              body: Sized(30)
+              body: Sized(55)
             ,
         }
         "###);
         let body = error_response.into_body().try_into_bytes().unwrap();
         assert_eq!(
             body,
-            Bytes::from_static(b"{\"message\":\"Access Forbidden\"}")
+            Bytes::from_static(b"{\"code\":\"accessForbidden\",\"message\":\"Access Forbidden\"}")
         );
 
         Ok(())
@@ -192,14 +250,14 @@ mod tests {
             Response HTTP/1.1 500 Internal Server Error
               headers:
                 "content-type": "application/json"
-              body: Sized(35)
+              body: Sized(53)
             ,
         }
         "###);
         let body = error_response.into_body().try_into_bytes().unwrap();
         assert_eq!(
             body,
-            Bytes::from_static(b"{\"message\":\"Internal Server Error\"}")
+            Bytes::from_static(b"{\"code\":\"internal\",\"message\":\"Internal Server Error\"}")
         );
 
         Ok(())
@@ -232,12 +290,15 @@ mod tests {
             Response HTTP/1.1 400 Bad Request
               headers:
                 "content-type": "application/json"
-              body: Sized(19)
+              body: Sized(41)
             ,
         }
         "###);
         let body = error_response.into_body().try_into_bytes().unwrap();
-        assert_eq!(body, Bytes::from_static(b"{\"message\":\"Three\"}"));
+        assert_eq!(
+            body,
+            Bytes::from_static(b"{\"code\":\"invalidInput\",\"message\":\"Three\"}")
+        );
 
         Ok(())
     }
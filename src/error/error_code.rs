@@ -0,0 +1,67 @@
+use serde::Serialize;
+
+/// Describes a stable, machine-readable code of the Secutils.dev specific error that API clients
+/// can rely on to handle errors programmatically.
+#[derive(Serialize, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum ErrorCode {
+    /// The request contains invalid or inconsistent parameters.
+    InvalidInput,
+    /// The web page tracker doesn't exist.
+    TrackerNotFound,
+    /// The web page tracker revision doesn't exist.
+    TrackerRevisionNotFound,
+    /// The responder doesn't exist.
+    ResponderNotFound,
+    /// The private key doesn't exist.
+    PrivateKeyNotFound,
+    /// The certificate template doesn't exist.
+    CertificateTemplateNotFound,
+    /// The content security policy doesn't exist.
+    ContentSecurityPolicyNotFound,
+    /// The notification doesn't exist.
+    NotificationNotFound,
+    /// The status page doesn't exist.
+    StatusPageNotFound,
+    /// The entity conflicts with another existing entity (e.g. has the same name).
+    AlreadyExists,
+    /// The request exceeds one of the limits (e.g. defined by the subscription).
+    LimitExceeded,
+    /// The schedule, retry strategy, or adaptive interval of the job isn't valid.
+    InvalidSchedule,
+    /// The script is empty, cannot be compiled, or isn't supported.
+    InvalidScript,
+    /// The URL isn't valid or isn't publicly reachable.
+    InvalidUrl,
+    /// The action isn't allowed for the user (e.g. requires operator privileges or is not
+    /// available for the user's subscription).
+    NotAllowed,
+    /// The remote server or an external service (e.g. web scraper) failed to process the request.
+    UpstreamError,
+    /// The user doesn't have privileges to access the resource.
+    AccessForbidden,
+    /// The action requires outbound network access that is disabled since the server runs in safe
+    /// mode.
+    SafeMode,
+    /// Unknown error.
+    Internal,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ErrorCode;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(ErrorCode::InvalidInput, @r###""invalidInput""###);
+        assert_json_snapshot!(ErrorCode::TrackerNotFound, @r###""trackerNotFound""###);
+        assert_json_snapshot!(ErrorCode::LimitExceeded, @r###""limitExceeded""###);
+        assert_json_snapshot!(ErrorCode::InvalidSchedule, @r###""invalidSchedule""###);
+        assert_json_snapshot!(ErrorCode::AccessForbidden, @r###""accessForbidden""###);
+        assert_json_snapshot!(ErrorCode::SafeMode, @r###""safeMode""###);
+        assert_json_snapshot!(ErrorCode::Internal, @r###""internal""###);
+
+        Ok(())
+    }
+}
@@ -1,9 +1,12 @@
+mod js_code_cache;
 mod js_runtime_config;
+mod js_script_diagnostic;
 mod script_termination_reason;
 
 use crate::js_runtime::script_termination_reason::ScriptTerminationReason;
-use anyhow::{bail, Context};
-use deno_core::{serde_v8, v8, PollEventLoopOptions, RuntimeOptions};
+use anyhow::{anyhow, bail, Context};
+use deno_core::{error::JsError, serde_v8, v8, PollEventLoopOptions, RuntimeOptions};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
     sync::{
@@ -13,21 +16,34 @@ use std::{
     time::{Duration, Instant},
 };
 
-pub use self::js_runtime_config::JsRuntimeConfig;
+pub use self::{
+    js_code_cache::JsCodeCache, js_runtime_config::JsRuntimeConfig,
+    js_script_diagnostic::JsScriptDiagnostic,
+};
 
 /// Defines a maximum interval on which script is checked for timeout.
 const SCRIPT_TIMEOUT_CHECK_INTERVAL: Duration = Duration::from_secs(2);
 
+/// Globals that user scripts aren't allowed to use, since they either expose runtime internals or
+/// allow executing arbitrary code that cannot be checked statically.
+const DISALLOWED_SCRIPT_GLOBALS: [&str; 3] = ["Deno", "eval", "Function"];
+
 /// An abstraction over the V8/Deno runtime that allows any utilities to execute custom user
 /// JavaScript scripts.
 pub struct JsRuntime {
     inner_runtime: deno_core::JsRuntime,
     max_user_script_execution_time: Duration,
+    code_cache: Arc<JsCodeCache>,
 }
 
 impl JsRuntime {
-    /// Creates a new instance of the runtime.
+    /// Creates a new instance of the runtime that shares the process-wide code cache.
     pub fn new(config: &JsRuntimeConfig) -> Self {
+        Self::with_code_cache(config, JsCodeCache::global())
+    }
+
+    /// Creates a new instance of the runtime with the specified code cache.
+    pub fn with_code_cache(config: &JsRuntimeConfig, code_cache: Arc<JsCodeCache>) -> Self {
         Self {
             inner_runtime: deno_core::JsRuntime::new(RuntimeOptions {
                 create_params: Some(
@@ -36,6 +52,7 @@ impl JsRuntime {
                 ..Default::default()
             }),
             max_user_script_execution_time: config.max_user_script_execution_time,
+            code_cache,
         }
     }
 
@@ -131,14 +148,11 @@ impl JsRuntime {
         };
 
         // Retrieve the result `Promise`.
-        let script_result_promise = self
-            .inner_runtime
-            .execute_script("<anon>", js_code.into())
-            .map_err(|err| {
-                timeout_token.swap(true, Ordering::Relaxed);
-                self.inner_runtime.v8_isolate().cancel_terminate_execution();
-                handle_error(err)
-            })?;
+        let script_result_promise = self.run_script(&js_code.into()).map_err(|err| {
+            timeout_token.swap(true, Ordering::Relaxed);
+            self.inner_runtime.v8_isolate().cancel_terminate_execution();
+            handle_error(err)
+        })?;
 
         // Wait for the promise to resolve.
         let resolve = self.inner_runtime.resolve(script_result_promise);
@@ -161,12 +175,140 @@ impl JsRuntime {
             .map(|result| (result, now.elapsed()))
             .with_context(|| "Error deserializing script result")
     }
+
+    /// Statically checks the user script without executing it and returns all found problems. The
+    /// script is treated as a body of an async function, the same way user scripts of the web page
+    /// trackers are, and hence it's expected to return a value.
+    pub fn lint_script(&mut self, js_code: &str) -> anyhow::Result<Vec<JsScriptDiagnostic>> {
+        // Compile the script without running it, user script starts on the second line of the
+        // wrapper code, so line numbers should be adjusted.
+        {
+            let scope = &mut self.inner_runtime.handle_scope();
+            let try_catch = &mut v8::TryCatch::new(scope);
+
+            let Some(source) =
+                v8::String::new(try_catch, &format!("(async () => {{\n{js_code}\n}});"))
+            else {
+                bail!("Cannot create script source.");
+            };
+
+            if v8::Script::compile(try_catch, source, None).is_none() {
+                let Some(message) = try_catch.message() else {
+                    bail!("Cannot retrieve script compilation error.");
+                };
+
+                // Script that cannot be compiled cannot be reliably analyzed any further.
+                return Ok(vec![JsScriptDiagnostic {
+                    message: message.get(try_catch).to_rust_string_lossy(try_catch),
+                    line_number: message
+                        .get_line_number(try_catch)
+                        .map(|line_number| line_number.saturating_sub(1).max(1)),
+                    column_number: Some(message.get_start_column() + 1),
+                }]);
+            }
+        }
+
+        let globals_regex = Regex::new(&format!(
+            r"(?:^|[^.\w$])({})\b",
+            DISALLOWED_SCRIPT_GLOBALS.join("|")
+        ))?;
+        let mut diagnostics = vec![];
+        for (line_index, line) in js_code.lines().enumerate() {
+            for captures in globals_regex.captures_iter(line) {
+                let Some(global) = captures.get(1) else {
+                    continue;
+                };
+
+                diagnostics.push(JsScriptDiagnostic {
+                    message: format!("Script cannot use `{}`.", global.as_str()),
+                    line_number: Some(line_index + 1),
+                    column_number: Some(line[..global.start()].chars().count() + 1),
+                });
+            }
+        }
+
+        if !Regex::new(r"\breturn\b")?.is_match(js_code) {
+            diagnostics.push(JsScriptDiagnostic {
+                message: "Script doesn't return any value.".to_string(),
+                line_number: None,
+                column_number: None,
+            });
+        }
+
+        Ok(diagnostics)
+    }
+
+    /// Compiles and runs the script in the current isolate, reusing the V8 code cache of the
+    /// previous compilations of the identical script, if available.
+    fn run_script(&mut self, js_code: &str) -> anyhow::Result<v8::Global<v8::Value>> {
+        let cache_key = JsCodeCache::key(js_code);
+        let cached_data = self.code_cache.get(&cache_key);
+
+        let scope = &mut self.inner_runtime.handle_scope();
+        let try_catch = &mut v8::TryCatch::new(scope);
+
+        let Some(source) = v8::String::new(try_catch, js_code) else {
+            bail!("Cannot create script source.");
+        };
+
+        let script = match cached_data.as_deref() {
+            Some(cached_data) => v8::script_compiler::compile(
+                try_catch,
+                &mut v8::script_compiler::Source::new_with_cached_data(
+                    source,
+                    None,
+                    v8::script_compiler::CachedData::new(cached_data),
+                ),
+                v8::script_compiler::CompileOptions::ConsumeCodeCache,
+                v8::script_compiler::NoCacheReason::NoReason,
+            ),
+            None => v8::Script::compile(try_catch, source, None),
+        };
+
+        let Some(script) = script else {
+            return Err(Self::exception_to_error(try_catch));
+        };
+
+        let Some(result) = script.run(try_catch) else {
+            return Err(Self::exception_to_error(try_catch));
+        };
+
+        // Produce code cache only after the script has run, so that it includes functions that
+        // were lazily compiled during execution.
+        if cached_data.is_none() {
+            if let Some(code_cache) = script.get_unbound_script(try_catch).create_code_cache() {
+                self.code_cache.insert(cache_key, code_cache.to_vec());
+
+                let stats = self.code_cache.stats();
+                log::debug!(
+                    "Compiled and cached user script (cache entries: {}, hits: {}, misses: {}).",
+                    stats.entries,
+                    stats.hits,
+                    stats.misses
+                );
+            }
+        }
+
+        Ok(v8::Global::new(try_catch, result))
+    }
+
+    /// Converts the exception caught while compiling or running the script to an error.
+    fn exception_to_error(try_catch: &mut v8::TryCatch<v8::HandleScope>) -> anyhow::Error {
+        match try_catch.exception() {
+            Some(exception) if !try_catch.has_terminated() => {
+                JsError::from_v8_exception(try_catch, exception).into()
+            }
+            _ => anyhow!("Script execution was terminated."),
+        }
+    }
 }
 #[cfg(test)]
 pub mod tests {
-    use super::{JsRuntime, JsRuntimeConfig};
+    use super::{JsCodeCache, JsRuntime, JsRuntimeConfig, JsScriptDiagnostic};
+    use crate::js_runtime::js_code_cache::JsCodeCacheStats;
     use deno_core::error::JsError;
     use serde::{Deserialize, Serialize};
+    use std::sync::Arc;
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn can_execute_scripts() -> anyhow::Result<()> {
@@ -225,6 +367,124 @@ pub mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn compiles_identical_scripts_only_once() -> anyhow::Result<()> {
+        let config = JsRuntimeConfig {
+            max_heap_size: 10 * 1024 * 1024,
+            max_user_script_execution_time: std::time::Duration::from_secs(5),
+        };
+
+        let code_cache = Arc::new(JsCodeCache::new(10));
+        let js_code = r#"(async () => {{ return context.map((item) => item * 2); }})();"#;
+
+        // Every execution uses a fresh runtime, the same way trackers and responders do.
+        for _ in 0..3 {
+            let mut runtime = JsRuntime::with_code_cache(&config, code_cache.clone());
+            let (result, _) = runtime
+                .execute_script::<Vec<usize>>(js_code, Some(vec![1, 2, 3]))
+                .await?;
+            assert_eq!(result, vec![2, 4, 6]);
+        }
+
+        assert_eq!(
+            code_cache.stats(),
+            JsCodeCacheStats {
+                entries: 1,
+                hits: 2,
+                misses: 1
+            }
+        );
+
+        // Different script is compiled separately.
+        let mut runtime = JsRuntime::with_code_cache(&config, code_cache.clone());
+        let (result, _) = runtime
+            .execute_script::<usize>(r#"(async () => {{ return 42; }})();"#, None::<()>)
+            .await?;
+        assert_eq!(result, 42);
+
+        assert_eq!(
+            code_cache.stats(),
+            JsCodeCacheStats {
+                entries: 2,
+                hits: 2,
+                misses: 2
+            }
+        );
+
+        // Scripts that fail to compile aren't cached.
+        let mut runtime = JsRuntime::with_code_cache(&config, code_cache.clone());
+        let result = runtime
+            .execute_script::<usize>(r#"(async () => {{ return 42; }}"#, None::<()>)
+            .await
+            .unwrap_err()
+            .downcast::<JsError>()?;
+        assert!(result.exception_message.starts_with("Uncaught SyntaxError"));
+        assert_eq!(code_cache.stats().entries, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn can_lint_scripts() -> anyhow::Result<()> {
+        let config = JsRuntimeConfig {
+            max_heap_size: 10 * 1024 * 1024,
+            max_user_script_execution_time: std::time::Duration::from_secs(5),
+        };
+
+        let mut runtime = JsRuntime::new(&config);
+
+        // Valid script.
+        assert!(runtime
+            .lint_script("const title = document.title;\nreturn title;")?
+            .is_empty());
+
+        // Syntax error.
+        assert_eq!(
+            runtime.lint_script("const title = document.title;\nreturn title +;")?,
+            vec![JsScriptDiagnostic {
+                message: "Uncaught SyntaxError: Unexpected token ';'".to_string(),
+                line_number: Some(2),
+                column_number: Some(15),
+            }]
+        );
+
+        // Missing return.
+        assert_eq!(
+            runtime.lint_script("const title = document.title;")?,
+            vec![JsScriptDiagnostic {
+                message: "Script doesn't return any value.".to_string(),
+                line_number: None,
+                column_number: None,
+            }]
+        );
+
+        // Disallowed globals, but not properties with the same name.
+        assert_eq!(
+            runtime.lint_script(
+                "const title = eval('document.title');\nreturn [title, Deno.core, obj.eval];"
+            )?,
+            vec![
+                JsScriptDiagnostic {
+                    message: "Script cannot use `eval`.".to_string(),
+                    line_number: Some(1),
+                    column_number: Some(15),
+                },
+                JsScriptDiagnostic {
+                    message: "Script cannot use `Deno`.".to_string(),
+                    line_number: Some(2),
+                    column_number: Some(16),
+                }
+            ]
+        );
+
+        // Script isn't executed.
+        assert!(runtime
+            .lint_script("while (true) {}\nreturn 1;")?
+            .is_empty());
+
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn can_limit_execution_time() -> anyhow::Result<()> {
         let config = JsRuntimeConfig {
@@ -0,0 +1,184 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock},
+};
+
+/// Defines the maximum number of compiled scripts kept in the process-wide code cache.
+const MAX_CODE_CACHE_ENTRIES: usize = 1000;
+
+/// Hit/miss statistics of the code cache.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct JsCodeCacheStats {
+    /// Number of compiled scripts currently kept in the cache.
+    pub entries: usize,
+    /// Number of script executions that reused previously compiled code.
+    pub hits: usize,
+    /// Number of script executions that required a full compilation.
+    pub misses: usize,
+}
+
+/// A bounded, thread-safe LRU cache of the V8 code cache data for the compiled user scripts. The
+/// cache is keyed by the SHA-256 hash of the script source, so that repeated executions of the
+/// identical scripts in the fresh isolates can skip compilation.
+pub struct JsCodeCache {
+    capacity: usize,
+    state: Mutex<JsCodeCacheState>,
+}
+
+#[derive(Default)]
+struct JsCodeCacheState {
+    entries: HashMap<[u8; 32], JsCodeCacheEntry>,
+    clock: u64,
+    stats: JsCodeCacheStats,
+}
+
+struct JsCodeCacheEntry {
+    data: Arc<[u8]>,
+    last_used: u64,
+}
+
+impl JsCodeCache {
+    /// Creates a new code cache that holds at most `capacity` compiled scripts.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(JsCodeCacheState::default()),
+        }
+    }
+
+    /// Returns a process-wide instance of the code cache.
+    pub fn global() -> Arc<Self> {
+        static CODE_CACHE: OnceLock<Arc<JsCodeCache>> = OnceLock::new();
+        CODE_CACHE
+            .get_or_init(|| Arc::new(Self::new(MAX_CODE_CACHE_ENTRIES)))
+            .clone()
+    }
+
+    /// Computes the cache key for the specified script source.
+    pub fn key(js_code: &str) -> [u8; 32] {
+        openssl::sha::sha256(js_code.as_bytes())
+    }
+
+    /// Returns the code cache data for the specified key, if any, and marks it as recently used.
+    pub fn get(&self, key: &[u8; 32]) -> Option<Arc<[u8]>> {
+        let mut state = self.lock_state();
+        state.clock += 1;
+
+        let clock = state.clock;
+        let data = state.entries.get_mut(key).map(|entry| {
+            entry.last_used = clock;
+            entry.data.clone()
+        });
+
+        if data.is_some() {
+            state.stats.hits += 1;
+        } else {
+            state.stats.misses += 1;
+        }
+
+        data
+    }
+
+    /// Stores the code cache data for the specified key, evicting the least recently used entry if
+    /// the cache is full.
+    pub fn insert(&self, key: [u8; 32], data: impl Into<Arc<[u8]>>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut state = self.lock_state();
+        state.clock += 1;
+
+        if !state.entries.contains_key(&key) && state.entries.len() >= self.capacity {
+            let least_recently_used_key = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| *key);
+            if let Some(least_recently_used_key) = least_recently_used_key {
+                state.entries.remove(&least_recently_used_key);
+            }
+        }
+
+        let last_used = state.clock;
+        state.entries.insert(
+            key,
+            JsCodeCacheEntry {
+                data: data.into(),
+                last_used,
+            },
+        );
+    }
+
+    /// Returns the size and hit/miss statistics of the cache.
+    pub fn stats(&self) -> JsCodeCacheStats {
+        let state = self.lock_state();
+        JsCodeCacheStats {
+            entries: state.entries.len(),
+            ..state.stats
+        }
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, JsCodeCacheState> {
+        // Cache state is always consistent, so it's safe to recover from a poisoned lock.
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsCodeCache, JsCodeCacheStats};
+
+    #[test]
+    fn can_cache_code() {
+        let cache = JsCodeCache::new(2);
+
+        let key_one = JsCodeCache::key("one");
+        assert!(cache.get(&key_one).is_none());
+
+        cache.insert(key_one, vec![1, 2, 3]);
+        assert_eq!(cache.get(&key_one).as_deref(), Some([1, 2, 3].as_slice()));
+
+        // Replaces existing entry.
+        cache.insert(key_one, vec![4, 5, 6]);
+        assert_eq!(cache.get(&key_one).as_deref(), Some([4, 5, 6].as_slice()));
+        assert_eq!(
+            cache.stats(),
+            JsCodeCacheStats {
+                entries: 1,
+                hits: 2,
+                misses: 1
+            }
+        );
+    }
+
+    #[test]
+    fn evicts_least_recently_used_code() {
+        let cache = JsCodeCache::new(2);
+
+        let [key_one, key_two, key_three] = ["one", "two", "three"].map(JsCodeCache::key);
+        cache.insert(key_one, vec![1]);
+        cache.insert(key_two, vec![2]);
+
+        // Touch the first entry, so that the second one becomes least recently used.
+        assert!(cache.get(&key_one).is_some());
+
+        cache.insert(key_three, vec![3]);
+        assert_eq!(cache.stats().entries, 2);
+        assert!(cache.get(&key_one).is_some());
+        assert!(cache.get(&key_two).is_none());
+        assert!(cache.get(&key_three).is_some());
+    }
+
+    #[test]
+    fn does_not_cache_code_with_zero_capacity() {
+        let cache = JsCodeCache::new(0);
+
+        let key = JsCodeCache::key("one");
+        cache.insert(key, vec![1]);
+        assert!(cache.get(&key).is_none());
+        assert_eq!(cache.stats().entries, 0);
+    }
+}
@@ -0,0 +1,48 @@
+use serde::Serialize;
+
+/// Describes a problem found in the user script by the static check.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct JsScriptDiagnostic {
+    /// Problem description.
+    pub message: String,
+    /// Line number within the script where problem is found, if available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_number: Option<usize>,
+    /// Column number within the script where problem is found, if available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column_number: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsScriptDiagnostic;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(JsScriptDiagnostic {
+            message: "Script doesn't return any value.".to_string(),
+            line_number: None,
+            column_number: None,
+        }, @r###"
+        {
+          "message": "Script doesn't return any value."
+        }
+        "###);
+
+        assert_json_snapshot!(JsScriptDiagnostic {
+            message: "Uncaught SyntaxError: Unexpected end of input".to_string(),
+            line_number: Some(2),
+            column_number: Some(7),
+        }, @r###"
+        {
+          "message": "Uncaught SyntaxError: Unexpected end of input",
+          "lineNumber": 2,
+          "columnNumber": 7
+        }
+        "###);
+
+        Ok(())
+    }
+}
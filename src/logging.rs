@@ -1,9 +1,13 @@
 mod job_log_context;
+mod log_redaction;
 mod metrics_context;
 mod user_log_context;
 mod utils_resource_log_context;
 
 pub use self::{
-    job_log_context::JobLogContext, metrics_context::MetricsContext,
-    user_log_context::UserLogContext, utils_resource_log_context::UtilsResourceLogContext,
+    job_log_context::JobLogContext,
+    log_redaction::{redact_url, truncate_for_log, REDACTED_VALUE},
+    metrics_context::MetricsContext,
+    user_log_context::UserLogContext,
+    utils_resource_log_context::UtilsResourceLogContext,
 };
@@ -19,6 +19,10 @@ pub struct MetricsContext {
     #[serde_as(as = "Option<DurationNanoSeconds<u64>>")]
     #[serde(skip_serializing_if = "Option::is_none")]
     job_execution_time: Option<Duration>,
+
+    /// A number of pending jobs the scheduler cannot process within a single run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduler_behind_jobs: Option<usize>,
 }
 
 impl MetricsContext {
@@ -45,6 +49,14 @@ impl MetricsContext {
             ..self
         }
     }
+
+    /// Adds a number of pending jobs the scheduler cannot process within a single run.
+    pub fn with_scheduler_behind_jobs(self, scheduler_behind_jobs: usize) -> Self {
+        Self {
+            scheduler_behind_jobs: Some(scheduler_behind_jobs),
+            ..self
+        }
+    }
 }
 
 #[cfg(test)]
@@ -61,12 +73,14 @@ mod tests {
         let metrics = MetricsContext::default()
             .with_script_execution_time(Duration::from_secs(1))
             .with_job_execution_time(Duration::from_secs(2))
-            .with_job_retries(3);
+            .with_job_retries(3)
+            .with_scheduler_behind_jobs(4);
         assert_json_snapshot!(metrics, @r###"
         {
           "script_execution_time": 1000000000,
           "job_retries": 3,
-          "job_execution_time": 2000000000
+          "job_execution_time": 2000000000,
+          "scheduler_behind_jobs": 4
         }
         "###);
 
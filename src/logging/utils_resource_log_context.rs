@@ -19,7 +19,7 @@ pub struct UtilsResourceLogContext<'n> {
 
 impl Responder {
     /// Returns context used for the structured logging.
-    pub fn log_context(&self) -> UtilsResourceLogContext {
+    pub fn log_context(&self) -> UtilsResourceLogContext<'_> {
         UtilsResourceLogContext {
             resource: UtilsResource::WebhooksResponders,
             resource_id: self.id,
@@ -30,11 +30,13 @@ impl Responder {
 
 impl<Tag: WebPageTrackerTag> WebPageTracker<Tag> {
     /// Returns context used for the structured logging.
-    pub fn log_context(&self) -> UtilsResourceLogContext {
+    pub fn log_context(&self) -> UtilsResourceLogContext<'_> {
         UtilsResourceLogContext {
             resource: match Tag::KIND {
                 WebPageTrackerKind::WebPageResources => UtilsResource::WebScrapingResources,
                 WebPageTrackerKind::WebPageContent => UtilsResource::WebScrapingContent,
+                WebPageTrackerKind::WebPageScreenshot => UtilsResource::WebScrapingScreenshots,
+                WebPageTrackerKind::WebPageDnsRecords => UtilsResource::WebScrapingDnsRecords,
             },
             resource_id: self.id,
             resource_name: self.name.as_str(),
@@ -122,6 +122,7 @@ mod tests {
                     },
                     is_activated: false,
                     is_operator: false,
+                    impersonator: None,
                 },
             }
         }
@@ -203,6 +204,7 @@ mod tests {
                     content: Some(WebPageResourceContent { data, size }),
                     url: None,
                     diff_status: None,
+                    category: None,
                 },
             }
         }
@@ -213,6 +215,7 @@ mod tests {
                     content: None,
                     url: Some(url),
                     diff_status: None,
+                    category: None,
                 },
             }
         }
@@ -258,6 +261,7 @@ mod tests {
     pub fn mock_config() -> anyhow::Result<Config> {
         Ok(Config {
             public_url: Url::parse("https://secutils.dev")?,
+            safe_mode: false,
             db: DatabaseConfig::default(),
             utils: UtilsConfig::default(),
             smtp: Some(SmtpConfig {
@@ -265,12 +269,19 @@ mod tests {
                 password: "password".to_string(),
                 address: "localhost".to_string(),
                 catch_all: None,
+                pool_size: None,
+                concurrency: None,
             }),
             components: ComponentsConfig::default(),
             scheduler: SchedulerJobsConfig {
                 web_page_trackers_schedule: Schedule::try_from("0 * 0 * * * *")?,
                 web_page_trackers_fetch: Schedule::try_from("0 * 1 * * * *")?,
                 notifications_send: Schedule::try_from("0 * 2 * * * *")?,
+                web_page_trackers_fetch_concurrency: 5,
+                web_page_trackers_fetch_host_interval: Duration::from_millis(100),
+                web_page_trackers_fetch_max_jobs: 500,
+                notifications_send_batch_size: 10,
+                notifications_send_concurrency: 5,
             },
             security: SecurityConfig::default(),
             subscriptions: SubscriptionsConfig {
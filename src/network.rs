@@ -7,7 +7,11 @@ pub use self::{
     email_transport::{EmailTransport, EmailTransportError},
     ip_addr_ext::IpAddrExt,
 };
-use std::net::IpAddr;
+use crate::error::{Error as SecutilsError, ErrorCode};
+use anyhow::bail;
+use lettre::Message;
+use std::{net::IpAddr, sync::Arc};
+use tokio::sync::Semaphore;
 use url::{Host, Url};
 
 /// Network utilities.
@@ -15,6 +19,10 @@ use url::{Host, Url};
 pub struct Network<DR: DnsResolver, ET: EmailTransport> {
     pub resolver: DR,
     pub email_transport: ET,
+    /// Limits the number of emails that can be sent concurrently.
+    email_concurrency_limit: Arc<Semaphore>,
+    /// Indicates whether all outbound network requests should be rejected.
+    safe_mode: bool,
 }
 
 impl<DR: DnsResolver, ET: EmailTransport> Network<DR, ET> {
@@ -23,9 +31,52 @@ impl<DR: DnsResolver, ET: EmailTransport> Network<DR, ET> {
         Self {
             resolver,
             email_transport,
+            email_concurrency_limit: Arc::new(Semaphore::new(Semaphore::MAX_PERMITS)),
+            safe_mode: false,
         }
     }
 
+    /// Makes network reject all outbound requests and DNS lookups if safe mode is enabled.
+    pub fn with_safe_mode(self, safe_mode: bool) -> Self {
+        Self { safe_mode, ..self }
+    }
+
+    /// Checks whether outbound network requests are allowed, and returns a user-facing error if
+    /// the server runs in safe mode.
+    pub fn check_outbound_access(&self) -> anyhow::Result<()> {
+        if self.safe_mode {
+            bail!(SecutilsError::client(
+                "Outbound network requests are disabled since the server runs in safe mode."
+            )
+            .with_code(ErrorCode::SafeMode));
+        }
+
+        Ok(())
+    }
+
+    /// Limits the number of emails that can be sent concurrently.
+    pub fn with_email_concurrency(self, concurrency: usize) -> Self {
+        Self {
+            email_concurrency_limit: Arc::new(Semaphore::new(
+                concurrency.clamp(1, Semaphore::MAX_PERMITS),
+            )),
+            ..self
+        }
+    }
+
+    /// Sends email using configured email transport, waiting if the concurrency limit is reached.
+    pub async fn send_email(&self, message: Message) -> anyhow::Result<()>
+    where
+        ET::Error: EmailTransportError,
+    {
+        self.check_outbound_access()?;
+
+        let _permit = self.email_concurrency_limit.acquire().await?;
+        self.email_transport.send(message).await?;
+
+        Ok(())
+    }
+
     /// Checks if provided URL is a publicly accessible web URL.
     pub async fn is_public_web_url(&self, url: &Url) -> bool {
         if url.scheme() != "http" && url.scheme() != "https" {
@@ -34,6 +85,11 @@ impl<DR: DnsResolver, ET: EmailTransport> Network<DR, ET> {
 
         // Checks if the specific hostname is a domain and public (not pointing to the local network).
         match url.host() {
+            // Domains cannot be resolved without reaching out to the external DNS servers.
+            Some(Host::Domain(domain)) if self.safe_mode => {
+                log::warn!("Cannot resolve domain ({domain}) to IP in safe mode.");
+                false
+            }
             Some(Host::Domain(domain)) => match self.resolver.lookup_ip(domain).await {
                 Ok(lookup) => lookup.iter().all(|ip| IpAddrExt::is_global(&ip)),
                 Err(err) => {
@@ -51,8 +107,9 @@ impl<DR: DnsResolver, ET: EmailTransport> Network<DR, ET> {
 #[cfg(test)]
 pub mod tests {
     use super::Network;
-    use lettre::transport::stub::AsyncStubTransport;
-    use std::net::Ipv4Addr;
+    use crate::error::{Error as SecutilsError, ErrorCode};
+    use lettre::{transport::stub::AsyncStubTransport, Message};
+    use std::{net::Ipv4Addr, time::Duration};
     use trust_dns_resolver::{
         error::{ResolveError, ResolveErrorKind},
         proto::rr::{rdata::A, RData, Record},
@@ -62,6 +119,102 @@ pub mod tests {
 
     pub use super::dns_resolver::tests::*;
 
+    #[tokio::test]
+    async fn limits_email_concurrency() -> anyhow::Result<()> {
+        let network = Network::new(MockResolver::new(), AsyncStubTransport::new_ok())
+            .with_email_concurrency(2);
+        let message = || {
+            Message::builder()
+                .from("dev@secutils.dev".parse().unwrap())
+                .to("user@secutils.dev".parse().unwrap())
+                .subject("subject")
+                .body("body".to_string())
+                .unwrap()
+        };
+
+        // Occupy all available slots to make sure that no more emails can be sent.
+        let permits = network.email_concurrency_limit.acquire_many(2).await?;
+        assert_eq!(network.email_concurrency_limit.available_permits(), 0);
+
+        let send_handles = (0..3)
+            .map(|_| {
+                let network = network.clone();
+                let message = message();
+                tokio::spawn(async move { network.send_email(message).await })
+            })
+            .collect::<Vec<_>>();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(network.email_transport.messages().await.is_empty());
+
+        // Once slots are released, all pending emails are sent.
+        drop(permits);
+        for handle in send_handles {
+            handle.await??;
+        }
+        assert_eq!(network.email_transport.messages().await.len(), 3);
+        assert_eq!(network.email_concurrency_limit.available_permits(), 2);
+
+        // Concurrency can't be lower than 1.
+        let network = Network::new(MockResolver::new(), AsyncStubTransport::new_ok())
+            .with_email_concurrency(0);
+        network.send_email(message()).await?;
+        assert_eq!(network.email_transport.messages().await.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_outbound_requests_in_safe_mode() -> anyhow::Result<()> {
+        let network = Network::new(
+            MockResolver::new_with_records::<1>(vec![Record::from_rdata(
+                Name::new(),
+                300,
+                RData::A(A(Ipv4Addr::new(172, 32, 0, 2))),
+            )]),
+            AsyncStubTransport::new_ok(),
+        );
+        assert!(network.check_outbound_access().is_ok());
+
+        let network = network.with_safe_mode(true);
+        let error = network
+            .check_outbound_access()
+            .unwrap_err()
+            .downcast::<SecutilsError>()?;
+        assert_eq!(error.code(), ErrorCode::SafeMode);
+        assert_eq!(
+            error.to_string(),
+            "Outbound network requests are disabled since the server runs in safe mode."
+        );
+
+        // Emails aren't sent.
+        let message = Message::builder()
+            .from("dev@secutils.dev".parse()?)
+            .to("user@secutils.dev".parse()?)
+            .subject("subject")
+            .body("body".to_string())?;
+        let error = network
+            .send_email(message)
+            .await
+            .unwrap_err()
+            .downcast::<SecutilsError>()?;
+        assert_eq!(error.code(), ErrorCode::SafeMode);
+        assert!(network.email_transport.messages().await.is_empty());
+
+        // Domains aren't resolved, but IP addresses are still checked.
+        assert!(
+            !network
+                .is_public_web_url(&Url::parse("https://secutils.dev/my-page")?)
+                .await
+        );
+        assert!(
+            network
+                .is_public_web_url(&Url::parse("https://217.88.39.143/my-page")?)
+                .await
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn correctly_checks_public_web_urls() -> anyhow::Result<()> {
         let public_network = Network::new(
@@ -2,13 +2,20 @@ use futures::future::BoxFuture;
 use trust_dns_resolver::{
     config::{ResolverConfig, ResolverOpts},
     error::ResolveError,
+    lookup::Lookup,
     lookup_ip::LookupIp,
+    proto::rr::RecordType,
     TokioAsyncResolver,
 };
 
 /// Trait describing a facade for a `AsyncResolver` from `trust-dns-resolver`.
 pub trait DnsResolver: Sync + Send + 'static {
     fn lookup_ip<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<LookupIp, ResolveError>>;
+    fn lookup<'a>(
+        &'a self,
+        name: &'a str,
+        record_type: RecordType,
+    ) -> BoxFuture<'a, Result<Lookup, ResolveError>>;
 }
 
 /// A wrapper around `TokioAsyncResolver` from `trust-dns-resolver`.
@@ -29,6 +36,14 @@ impl DnsResolver for TokioDnsResolver {
     fn lookup_ip<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<LookupIp, ResolveError>> {
         Box::pin(self.inner.lookup_ip(name))
     }
+
+    fn lookup<'a>(
+        &'a self,
+        name: &'a str,
+        record_type: RecordType,
+    ) -> BoxFuture<'a, Result<Lookup, ResolveError>> {
+        Box::pin(self.inner.lookup(name, record_type))
+    }
 }
 
 #[cfg(test)]
@@ -64,6 +79,25 @@ pub mod tests {
                 )))
             }))
         }
+
+        fn lookup<'a>(
+            &'a self,
+            _: &'a str,
+            record_type: RecordType,
+        ) -> BoxFuture<'a, Result<Lookup, ResolveError>> {
+            Box::pin(futures::future::ready(if let Some(err) = &self.error {
+                Err(err.clone())
+            } else {
+                Ok(Lookup::new_with_max_ttl(
+                    Query::query(Name::new(), record_type),
+                    self.records
+                        .iter()
+                        .filter(|record| record.record_type() == record_type)
+                        .cloned()
+                        .collect(),
+                ))
+            }))
+        }
     }
 
     impl MockResolver {
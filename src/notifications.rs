@@ -4,8 +4,13 @@ mod email;
 mod notification;
 mod notification_content;
 mod notification_content_template;
+mod notification_dead_letter;
 mod notification_destination;
 mod notification_id;
+mod notification_preview;
+mod webhook_payload;
+mod webhook_retry;
+mod webhook_signature;
 
 pub use self::{
     email::{
@@ -15,6 +20,19 @@ pub use self::{
     notification::Notification,
     notification_content::NotificationContent,
     notification_content_template::NotificationContentTemplate,
+    notification_dead_letter::NotificationDeadLetter,
     notification_destination::NotificationDestination,
     notification_id::NotificationId,
+    notification_preview::NotificationPreview,
+    webhook_payload::webhook_notification_payload,
+    webhook_retry::{
+        webhook_retry_delay, WebhookDeliveryError, MAX_WEBHOOK_NOTIFICATION_DELIVERY_ATTEMPTS,
+    },
+    webhook_signature::{
+        sign_webhook_payload, WEBHOOK_SIGNATURES_SEPARATOR, WEBHOOK_SIGNATURE_HEADER,
+        WEBHOOK_TIMESTAMP_HEADER,
+    },
 };
+
+#[cfg(test)]
+pub use self::webhook_signature::verify_webhook_payload;
@@ -1,23 +1,73 @@
 use crate::{
     api::Api,
+    error::{Error as SecutilsError, ErrorCode},
     network::{DnsResolver, EmailTransport, EmailTransportError},
     notifications::{
+        sign_webhook_payload, webhook_notification_payload, webhook_retry_delay,
         EmailNotificationAttachmentDisposition, EmailNotificationContent, Notification,
-        NotificationContent, NotificationDestination, NotificationId,
+        NotificationContent, NotificationDeadLetter, NotificationDestination, NotificationId,
+        WebhookDeliveryError, MAX_WEBHOOK_NOTIFICATION_DELIVERY_ATTEMPTS,
+        WEBHOOK_SIGNATURES_SEPARATOR, WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER,
     },
+    security::encryption,
+    users::UserId,
 };
 use anyhow::{anyhow, bail, Context};
-use futures::{pin_mut, StreamExt};
+use futures::{future::join_all, pin_mut, StreamExt};
 use lettre::{
     message::{header::ContentType, Attachment, MultiPart, SinglePart},
     Message,
 };
-use std::cmp;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, RETRY_AFTER};
+use std::{cmp, time::Duration};
 use time::OffsetDateTime;
+use tokio::sync::{Semaphore, SemaphorePermit};
+use uuid::Uuid;
 
 /// Defines a maximum number of notifications that can be retrieved from the database at once.
 const MAX_NOTIFICATIONS_PAGE_SIZE: usize = 100;
 
+/// Defines a maximum number of attempts to deliver a notification before it's moved to the dead
+/// letters.
+const MAX_NOTIFICATION_DELIVERY_ATTEMPTS: u32 = 3;
+
+/// Defines a timeout for a single webhook notification delivery request.
+const WEBHOOK_NOTIFICATION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Limits the number of notifications that can be sent concurrently through every delivery
+/// channel (email or webhook).
+struct NotificationChannelLimits {
+    email: Semaphore,
+    webhook: Semaphore,
+}
+
+impl NotificationChannelLimits {
+    /// Creates limits that allow `concurrency` notifications to be sent concurrently through
+    /// every channel.
+    fn new(concurrency: usize) -> Self {
+        let concurrency = cmp::max(concurrency, 1);
+        Self {
+            email: Semaphore::new(concurrency),
+            webhook: Semaphore::new(concurrency),
+        }
+    }
+
+    /// Waits until a notification can be sent to the specified destination. Notifications that
+    /// are logged in the server log aren't limited.
+    async fn acquire(
+        &self,
+        destination: &NotificationDestination,
+    ) -> anyhow::Result<Option<SemaphorePermit<'_>>> {
+        let semaphore = match destination {
+            NotificationDestination::User(_) | NotificationDestination::Email(_) => &self.email,
+            NotificationDestination::TrackerWebhook { .. } => &self.webhook,
+            NotificationDestination::ServerLog => return Ok(None),
+        };
+
+        Ok(Some(semaphore.acquire().await?))
+    }
+}
+
 /// Describes the API to work with notifications.
 pub struct NotificationsApi<'a, DR: DnsResolver, ET: EmailTransport> {
     api: &'a Api<DR, ET>,
@@ -38,15 +88,36 @@ where
         destination: NotificationDestination,
         content: NotificationContent,
         scheduled_at: OffsetDateTime,
+    ) -> anyhow::Result<NotificationId> {
+        self.schedule_notification_with_urgency(destination, content, scheduled_at, false)
+            .await
+    }
+
+    /// Schedules a new notification. Urgent notifications are sent even during user's quiet hours.
+    pub async fn schedule_notification_with_urgency(
+        &self,
+        destination: NotificationDestination,
+        content: NotificationContent,
+        scheduled_at: OffsetDateTime,
+        urgent: bool,
     ) -> anyhow::Result<NotificationId> {
         self.api
             .db
-            .insert_notification(&Notification::new(destination, content, scheduled_at))
+            .insert_notification(
+                &Notification::new(destination, content, scheduled_at).set_urgent(urgent),
+            )
             .await
     }
 
-    /// Sends pending notifications. The max number to send is limited by `limit`.
+    /// Sends pending notifications. The max number to send is limited by `limit`. Notifications
+    /// are sent in batches of the configured size, and notifications within a batch are sent
+    /// concurrently, bounded by the configured per-channel concurrency limit. Failure to process
+    /// a single notification doesn't prevent other notifications in the batch from being sent.
     pub async fn send_pending_notifications(&self, limit: usize) -> anyhow::Result<usize> {
+        let config = &self.api.config.scheduler;
+        let batch_size = cmp::max(config.notifications_send_batch_size, 1);
+        let channel_limits = &NotificationChannelLimits::new(config.notifications_send_concurrency);
+
         let pending_notification_ids = self.api.db.get_notification_ids(
             OffsetDateTime::now_utc(),
             cmp::min(MAX_NOTIFICATIONS_PAGE_SIZE, limit),
@@ -54,18 +125,37 @@ where
         pin_mut!(pending_notification_ids);
 
         let mut sent_notifications = 0;
-        while let Some(notification_id) = pending_notification_ids.next().await {
-            if let Some(notification) = self.api.db.get_notification(notification_id?).await? {
-                let notification_id = notification.id;
-                if let Err(err) = self.send_notification(notification).await {
-                    log::error!(
-                        "Failed to send notification {}: {:?}",
+        loop {
+            let batch_limit = cmp::min(batch_size, limit.saturating_sub(sent_notifications));
+            let mut batch = Vec::with_capacity(batch_limit);
+            while batch.len() < batch_limit {
+                match pending_notification_ids.next().await {
+                    Some(notification_id) => batch.push(notification_id?),
+                    None => break,
+                }
+            }
+
+            if batch.is_empty() {
+                break;
+            }
+
+            let batch_results = join_all(batch.into_iter().map(|notification_id| async move {
+                (
+                    notification_id,
+                    self.send_pending_notification(notification_id, channel_limits)
+                        .await,
+                )
+            }))
+            .await;
+            for (notification_id, result) in batch_results {
+                match result {
+                    Ok(true) => sent_notifications += 1,
+                    Ok(false) => {}
+                    Err(err) => log::error!(
+                        "Failed to process pending notification {}: {:?}",
                         *notification_id,
                         err
-                    );
-                } else {
-                    sent_notifications += 1;
-                    self.api.db.remove_notification(notification_id).await?;
+                    ),
                 }
             }
 
@@ -77,6 +167,170 @@ where
         Ok(sent_notifications)
     }
 
+    /// Sends a single pending notification, and returns `true` if it was sent.
+    async fn send_pending_notification(
+        &self,
+        notification_id: NotificationId,
+        channel_limits: &NotificationChannelLimits,
+    ) -> anyhow::Result<bool> {
+        let Some(notification) = self.api.db.get_notification(notification_id).await? else {
+            return Ok(false);
+        };
+
+        // Non-urgent notifications are deferred until user's quiet hours end.
+        if let Some(quiet_hours_end) = self.get_quiet_hours_end(&notification).await? {
+            self.api
+                .db
+                .update_notification_scheduled_at(notification_id, quiet_hours_end)
+                .await?;
+            return Ok(false);
+        }
+
+        let _permit = channel_limits.acquire(&notification.destination).await?;
+        if let Err(err) = self.send_notification(notification.clone()).await {
+            log::error!(
+                "Failed to send notification {}: {:?}",
+                *notification_id,
+                err
+            );
+            self.handle_failed_notification(notification, err).await?;
+            Ok(false)
+        } else {
+            self.api.db.remove_notification(notification_id).await?;
+            Ok(true)
+        }
+    }
+
+    /// Returns all notifications that couldn't be delivered to the specified user.
+    pub async fn get_dead_letters(
+        &self,
+        user_id: UserId,
+    ) -> anyhow::Result<Vec<NotificationDeadLetter>> {
+        self.api.db.get_notification_dead_letters(user_id).await
+    }
+
+    /// Re-attempts delivery of the notification that previously couldn't be delivered to the
+    /// specified user. The dead letter is removed if notification is delivered successfully.
+    pub async fn retry_dead_letter(&self, user_id: UserId, id: Uuid) -> anyhow::Result<()> {
+        let Some(dead_letter) = self
+            .api
+            .db
+            .get_notification_dead_letter(user_id, id)
+            .await?
+        else {
+            bail!(SecutilsError::client(format!(
+                "Notification dead letter ('{id}') is not found."
+            ))
+            .with_code(ErrorCode::NotificationNotFound));
+        };
+
+        let notification = Notification::new(
+            dead_letter.destination.clone(),
+            dead_letter.content.clone(),
+            dead_letter.scheduled_at,
+        );
+        if let Err(err) = self.send_notification(notification).await {
+            self.api
+                .db
+                .update_notification_dead_letter(&NotificationDeadLetter {
+                    failed_at: OffsetDateTime::now_utc(),
+                    attempts: dead_letter.attempts + 1,
+                    last_error: format!("{err:#}"),
+                    ..dead_letter
+                })
+                .await?;
+            bail!(SecutilsError::client_with_root_cause(err.context(format!(
+                "Notification dead letter ('{id}') couldn't be delivered."
+            )))
+            .with_code(ErrorCode::UpstreamError));
+        }
+
+        self.api
+            .db
+            .remove_notification_dead_letter(user_id, id)
+            .await
+    }
+
+    /// Returns the time at which user's quiet hours end, if the notification isn't urgent and
+    /// should be deferred.
+    async fn get_quiet_hours_end(
+        &self,
+        notification: &Notification,
+    ) -> anyhow::Result<Option<OffsetDateTime>> {
+        let NotificationDestination::User(user_id) = notification.destination else {
+            return Ok(None);
+        };
+
+        if notification.urgent {
+            return Ok(None);
+        }
+
+        Ok(self
+            .api
+            .users()
+            .get_quiet_hours(user_id)
+            .await?
+            .and_then(|quiet_hours| quiet_hours.window_end(OffsetDateTime::now_utc())))
+    }
+
+    /// Records failed delivery attempt, and moves notification to the dead letters if the maximum
+    /// number of delivery attempts is reached. Webhook notifications have their own attempts limit
+    /// and are re-scheduled with exponential backoff, respecting `Retry-After` of the receiver.
+    async fn handle_failed_notification(
+        &self,
+        notification: Notification,
+        err: anyhow::Error,
+    ) -> anyhow::Result<()> {
+        let attempts = self
+            .api
+            .db
+            .increment_notification_attempts(notification.id)
+            .await?;
+        if let NotificationDestination::TrackerWebhook { .. } = notification.destination {
+            if attempts < MAX_WEBHOOK_NOTIFICATION_DELIVERY_ATTEMPTS {
+                let retry_after = err
+                    .downcast_ref::<WebhookDeliveryError>()
+                    .and_then(|err| err.retry_after);
+                let delay = webhook_retry_delay(attempts, retry_after, &mut rand::thread_rng());
+                log::warn!(
+                    "Webhook notification {} will be re-attempted in {}s.",
+                    *notification.id,
+                    delay.as_secs()
+                );
+                return self
+                    .api
+                    .db
+                    .update_notification_scheduled_at(
+                        notification.id,
+                        OffsetDateTime::now_utc() + delay,
+                    )
+                    .await;
+            }
+        } else if attempts < MAX_NOTIFICATION_DELIVERY_ATTEMPTS {
+            return Ok(());
+        }
+
+        log::error!(
+            "Notification {} couldn't be delivered after {attempts} attempts and will be moved to dead letters.",
+            *notification.id
+        );
+        self.api
+            .db
+            .move_notification_to_dead_letters(
+                notification.id,
+                &NotificationDeadLetter {
+                    id: Uuid::now_v7(),
+                    destination: notification.destination,
+                    content: notification.content,
+                    scheduled_at: notification.scheduled_at,
+                    failed_at: OffsetDateTime::now_utc(),
+                    attempts,
+                    last_error: format!("{err:#}"),
+                },
+            )
+            .await
+    }
+
     /// Sends notification and removes it from the database, if it was sent successfully.
     async fn send_notification(&self, notification: Notification) -> anyhow::Result<()> {
         match notification.destination {
@@ -105,6 +359,129 @@ where
             NotificationDestination::ServerLog => {
                 log::info!("Sending notification: {:?}", notification);
             }
+            NotificationDestination::TrackerWebhook { tracker_id, .. } => {
+                self.send_webhook_notification(
+                    tracker_id,
+                    notification.content.into_email(self.api).await?,
+                    notification.scheduled_at,
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sends notification to the webhook configured for the web page tracker. The payload is
+    /// signed with the webhook secret, see `webhook_signature` module for the signing scheme.
+    async fn send_webhook_notification(
+        &self,
+        tracker_id: Uuid,
+        email: EmailNotificationContent,
+        timestamp: OffsetDateTime,
+    ) -> anyhow::Result<()> {
+        let Some(webhook) = self
+            .api
+            .web_scraping_system()
+            .get_web_page_tracker_webhook(tracker_id)
+            .await?
+        else {
+            log::warn!(
+                "Webhook for web page tracker ({tracker_id}) is not found, notification will be discarded."
+            );
+            return Ok(());
+        };
+
+        // Allowed domains might have changed since the webhook was configured.
+        if !self
+            .api
+            .config
+            .utils
+            .web_scraping
+            .is_webhook_url_allowed(&webhook.url)
+        {
+            bail!(
+                "Webhook URL domain for web page tracker ({tracker_id}) isn't allowed by the server configuration: {}.",
+                webhook.url
+            );
+        }
+
+        let Some(ref encryption_key) = self.api.config.security.encryption_key else {
+            bail!("Encryption key is not configured.");
+        };
+
+        // During the secret rotation grace period, the payload is signed with both the new and the
+        // previous secrets, so that receivers that haven't switched to the new secret yet can still
+        // verify it.
+        let now = OffsetDateTime::now_utc();
+        let mut secrets = vec![encryption::decrypt(encryption_key, &webhook.secret)
+            .context("Cannot decrypt webhook secret.")?];
+        if let Some(previous_secret) = webhook.active_previous_secret(now) {
+            secrets.push(
+                encryption::decrypt(encryption_key, previous_secret)
+                    .context("Cannot decrypt previous webhook secret.")?,
+            );
+        }
+
+        // Custom headers (e.g. `Authorization`) may contain credentials of the webhook receiver, so
+        // their values are never logged.
+        let mut headers = HeaderMap::new();
+        for (name, value) in webhook.decrypt_headers(encryption_key)? {
+            headers.append(
+                HeaderName::from_bytes(name.as_bytes())?,
+                HeaderValue::from_str(&value)
+                    .with_context(|| format!("Invalid webhook header ('{name}') value."))?,
+            );
+        }
+
+        let payload =
+            serde_json::to_vec(&webhook_notification_payload(tracker_id, &email, timestamp))?;
+
+        self.api.network.check_outbound_access()?;
+
+        // Webhook domain might have started resolving to a non-public address since the webhook
+        // was configured.
+        if !self.api.network.is_public_web_url(&webhook.url).await {
+            bail!(
+                "Webhook URL for web page tracker ({tracker_id}) isn't a publicly accessible web URL: {}.",
+                webhook.url
+            );
+        }
+
+        let signed_at = now.unix_timestamp();
+        let signature = secrets
+            .iter()
+            .map(|secret| sign_webhook_payload(secret, signed_at, &payload))
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .join(WEBHOOK_SIGNATURES_SEPARATOR);
+        // Redirects aren't followed, so that the webhook cannot be used to reach non-public
+        // addresses.
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .timeout(WEBHOOK_NOTIFICATION_TIMEOUT)
+            .build()?;
+        let response = client
+            .post(webhook.url)
+            .headers(headers)
+            .header(CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_TIMESTAMP_HEADER, signed_at)
+            .header(WEBHOOK_SIGNATURE_HEADER, signature)
+            .body(payload)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok());
+            return Err(WebhookDeliveryError::new(
+                status.as_u16(),
+                retry_after,
+                OffsetDateTime::now_utc(),
+            )
+            .into());
         }
 
         Ok(())
@@ -184,7 +561,7 @@ where
             None => message_builder.body(email.text)?,
         };
 
-        self.api.network.email_transport.send(message).await?;
+        self.api.network.send_email(message).await?;
 
         Ok(())
     }
@@ -204,17 +581,58 @@ where
 mod tests {
     use crate::{
         config::{SmtpCatchAllConfig, SmtpConfig},
+        error::Error as SecutilsError,
+        network::Network,
         notifications::{
-            EmailNotificationAttachment, EmailNotificationContent, Notification,
-            NotificationContent, NotificationDestination,
+            verify_webhook_payload, EmailNotificationAttachment, EmailNotificationContent,
+            Notification, NotificationContent, NotificationContentTemplate,
+            NotificationDestination, WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER,
+        },
+        security::encryption,
+        tests::{
+            mock_api, mock_api_with_config, mock_api_with_network, mock_config,
+            mock_network_with_records, mock_user, MockResolver,
+        },
+        users::{UserData, UserDataNamespace, UserQuietHours},
+        utils::web_scraping::{
+            tests::MockWebPageTrackerBuilder, WebPageContentTrackerTag, WebPageTrackerWebhook,
         },
-        tests::{mock_api, mock_api_with_config, mock_config, mock_user},
     };
+    use httpmock::{prelude::HttpMockRequest, MockServer};
     use insta::assert_debug_snapshot;
+    use lettre::transport::stub::AsyncStubTransport;
+    use serde_json::json;
     use sqlx::PgPool;
-    use time::OffsetDateTime;
+    use std::{net::Ipv4Addr, sync::OnceLock};
+    use time::{Duration, OffsetDateTime, UtcOffset};
+    use trust_dns_resolver::{
+        proto::rr::{rdata::A, RData, Record},
+        Name,
+    };
+    use url::Url;
     use uuid::uuid;
 
+    fn is_signed_with(request: &HttpMockRequest, secret: &[u8]) -> bool {
+        let header = |name: &str| {
+            request.headers.as_ref().and_then(|headers| {
+                headers
+                    .iter()
+                    .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+                    .map(|(_, value)| value.as_str())
+            })
+        };
+
+        let (Some(timestamp), Some(signature), Some(body)) = (
+            header(WEBHOOK_TIMESTAMP_HEADER).and_then(|value| value.parse::<i64>().ok()),
+            header(WEBHOOK_SIGNATURE_HEADER),
+            request.body.as_ref(),
+        ) else {
+            return false;
+        };
+
+        verify_webhook_payload(secret, timestamp, body, signature).unwrap_or_default()
+    }
+
     #[sqlx::test]
     async fn properly_schedules_notification(pool: PgPool) -> anyhow::Result<()> {
         let mock_user = mock_user()?;
@@ -261,6 +679,7 @@ mod tests {
                     "abc",
                 ),
                 scheduled_at: 2000-01-01 10:00:00.0 +00:00:00,
+                urgent: false,
             },
         )
         "###);
@@ -279,6 +698,7 @@ mod tests {
                     "abc",
                 ),
                 scheduled_at: 2000-01-01 10:00:00.0 +00:00:00,
+                urgent: false,
             },
         )
         "###);
@@ -287,6 +707,719 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn properly_defers_non_urgent_notifications_during_quiet_hours(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let mock_user = mock_user()?;
+        let api = mock_api(pool).await?;
+        api.db.upsert_user(&mock_user).await?;
+
+        let now = OffsetDateTime::now_utc();
+        api.users()
+            .set_data(
+                UserDataNamespace::QuietHours,
+                UserData::new(
+                    mock_user.id,
+                    serde_json::to_vec(&UserQuietHours {
+                        start: (now - Duration::HOUR).time(),
+                        end: (now + Duration::HOUR).time(),
+                        timezone: UtcOffset::UTC,
+                    })?,
+                    now,
+                ),
+            )
+            .await?;
+        let quiet_hours_end = api
+            .users()
+            .get_quiet_hours(mock_user.id)
+            .await?
+            .and_then(|quiet_hours| quiet_hours.window_end(now))
+            .unwrap();
+        assert!(quiet_hours_end > now);
+
+        let non_urgent_id = api
+            .notifications()
+            .schedule_notification(
+                NotificationDestination::User(mock_user.id),
+                NotificationContent::Text("non-urgent".to_string()),
+                now,
+            )
+            .await?;
+        let urgent_id = api
+            .notifications()
+            .schedule_notification_with_urgency(
+                NotificationDestination::User(mock_user.id),
+                NotificationContent::Text("urgent".to_string()),
+                now,
+                true,
+            )
+            .await?;
+
+        // Only urgent notification should be sent, non-urgent one should be deferred.
+        assert_eq!(api.notifications().send_pending_notifications(10).await?, 1);
+        assert!(api.db.get_notification(urgent_id).await?.is_none());
+        assert_eq!(
+            api.db
+                .get_notification(non_urgent_id)
+                .await?
+                .map(|notification| notification.scheduled_at),
+            Some(quiet_hours_end)
+        );
+
+        // Deferred notification isn't sent until quiet hours end.
+        assert_eq!(api.notifications().send_pending_notifications(10).await?, 0);
+        assert!(api.db.get_notification(non_urgent_id).await?.is_some());
+
+        let messages = api.network.email_transport.messages().await;
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].1.contains("urgent"));
+        assert!(!messages[0].1.contains("non-urgent"));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_sends_signed_webhook_notifications(pool: PgPool) -> anyhow::Result<()> {
+        fn has_valid_signature(request: &HttpMockRequest) -> bool {
+            is_signed_with(request, b"my-webhook-secret")
+                && !is_signed_with(request, b"wrong-webhook-secret")
+        }
+
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.security.encryption_key =
+            Some("4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a".to_string());
+        let api = mock_api_with_config(pool, config).await?;
+
+        let mock_user = mock_user()?;
+        api.db.upsert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+        api.db
+            .web_scraping(mock_user.id)
+            .upsert_web_page_tracker_webhook(
+                tracker.id,
+                &WebPageTrackerWebhook {
+                    url: Url::parse(&format!("http://localhost:{}/webhook", server.port()))?,
+                    secret: encryption::encrypt(
+                        "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a",
+                        b"my-webhook-secret",
+                    )?,
+                    previous_secret: None,
+                    previous_secret_expires_at: None,
+                    headers: None,
+                },
+            )
+            .await?;
+
+        let webhook_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/webhook")
+                .header("Content-Type", "application/json")
+                .json_body(json!({
+                    "trackerId": "00000000-0000-0000-0000-000000000002",
+                    "subject": "[NO SUBJECT]",
+                    "text": "abc",
+                    "timestamp": 946720800
+                }))
+                .matches(has_valid_signature);
+            then.status(200);
+        });
+
+        api.notifications()
+            .schedule_notification(
+                NotificationDestination::TrackerWebhook {
+                    user_id: mock_user.id,
+                    tracker_id: tracker.id,
+                },
+                NotificationContent::Text("abc".to_string()),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        assert_eq!(api.notifications().send_pending_notifications(3).await?, 1);
+        assert!(api.db.get_notification(1.try_into()?).await?.is_none());
+        assert!(api.network.email_transport.messages().await.is_empty());
+
+        webhook_mock.assert();
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_sends_webhook_notifications_with_custom_headers(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        fn has_valid_signature(request: &HttpMockRequest) -> bool {
+            is_signed_with(request, b"my-webhook-secret")
+        }
+
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.security.encryption_key =
+            Some("4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a".to_string());
+        let api = mock_api_with_config(pool, config).await?;
+
+        let mock_user = mock_user()?;
+        api.db.upsert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+        let headers = vec![
+            ("Authorization".to_string(), "Bearer my-token".to_string()),
+            ("X-Custom-Header".to_string(), "custom-value".to_string()),
+        ];
+        api.db
+            .web_scraping(mock_user.id)
+            .upsert_web_page_tracker_webhook(
+                tracker.id,
+                &WebPageTrackerWebhook {
+                    url: Url::parse(&format!("http://localhost:{}/webhook", server.port()))?,
+                    secret: encryption::encrypt(
+                        "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a",
+                        b"my-webhook-secret",
+                    )?,
+                    previous_secret: None,
+                    previous_secret_expires_at: None,
+                    headers: Some(encryption::encrypt(
+                        "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a",
+                        &serde_json::to_vec(&headers)?,
+                    )?),
+                },
+            )
+            .await?;
+
+        let webhook_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/webhook")
+                .header("Authorization", "Bearer my-token")
+                .header("X-Custom-Header", "custom-value")
+                .header("Content-Type", "application/json")
+                .json_body(json!({
+                    "trackerId": "00000000-0000-0000-0000-000000000002",
+                    "subject": "[NO SUBJECT]",
+                    "text": "abc",
+                    "timestamp": 946720800
+                }))
+                .matches(has_valid_signature);
+            then.status(200);
+        });
+
+        api.notifications()
+            .schedule_notification(
+                NotificationDestination::TrackerWebhook {
+                    user_id: mock_user.id,
+                    tracker_id: tracker.id,
+                },
+                NotificationContent::Text("abc".to_string()),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        assert_eq!(api.notifications().send_pending_notifications(3).await?, 1);
+        assert!(api.db.get_notification(1.try_into()?).await?.is_none());
+
+        webhook_mock.assert();
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_signs_webhook_notifications_after_secret_rotation(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        static ROTATED_SECRET: OnceLock<String> = OnceLock::new();
+        fn is_signed_with_rotated_secret(request: &HttpMockRequest) -> bool {
+            ROTATED_SECRET
+                .get()
+                .map(|secret| is_signed_with(request, secret.as_bytes()))
+                .unwrap_or_default()
+        }
+
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.security.encryption_key =
+            Some("4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a".to_string());
+        config.utils.web_scraping.webhook_secret_grace_period =
+            Some(std::time::Duration::from_secs(3600));
+        let api = mock_api_with_config(pool, config).await?;
+
+        let mock_user = mock_user()?;
+        api.db.upsert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+        api.db
+            .web_scraping(mock_user.id)
+            .upsert_web_page_tracker_webhook(
+                tracker.id,
+                &WebPageTrackerWebhook {
+                    url: Url::parse(&format!("http://localhost:{}/webhook", server.port()))?,
+                    secret: encryption::encrypt(
+                        "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a",
+                        b"my-webhook-secret",
+                    )?,
+                    previous_secret: None,
+                    previous_secret_expires_at: None,
+                    headers: None,
+                },
+            )
+            .await?;
+
+        let secrets = api
+            .web_scraping(&mock_user)
+            .rotate_web_page_tracker_webhook_secrets()
+            .await?;
+        assert_eq!(secrets.len(), 1);
+        ROTATED_SECRET.set(secrets[0].secret.clone()).unwrap();
+
+        // During the grace period, payload is signed with both the new and the previous secrets.
+        let mut grace_period_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/webhook")
+                .matches(is_signed_with_rotated_secret)
+                .matches(|request| is_signed_with(request, b"my-webhook-secret"));
+            then.status(200);
+        });
+
+        let notifications = api.notifications();
+        let schedule_notification = || {
+            notifications.schedule_notification(
+                NotificationDestination::TrackerWebhook {
+                    user_id: mock_user.id,
+                    tracker_id: tracker.id,
+                },
+                NotificationContent::Text("abc".to_string()),
+                OffsetDateTime::from_unix_timestamp(946720800).unwrap(),
+            )
+        };
+
+        schedule_notification().await?;
+        assert_eq!(api.notifications().send_pending_notifications(3).await?, 1);
+        grace_period_mock.assert();
+        grace_period_mock.delete();
+
+        // Once the grace period ends, the previous secret no longer validates the payload.
+        let webhook = api
+            .web_scraping_system()
+            .get_web_page_tracker_webhook(tracker.id)
+            .await?
+            .unwrap();
+        assert!(webhook.previous_secret.is_some());
+        api.db
+            .web_scraping(mock_user.id)
+            .upsert_web_page_tracker_webhook(
+                tracker.id,
+                &WebPageTrackerWebhook {
+                    previous_secret_expires_at: Some(
+                        OffsetDateTime::now_utc() - Duration::seconds(1),
+                    ),
+                    ..webhook
+                },
+            )
+            .await?;
+
+        let webhook_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/webhook")
+                .matches(is_signed_with_rotated_secret)
+                .matches(|request| !is_signed_with(request, b"my-webhook-secret"));
+            then.status(200);
+        });
+
+        schedule_notification().await?;
+        assert_eq!(api.notifications().send_pending_notifications(3).await?, 1);
+        webhook_mock.assert();
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn retries_webhook_notifications_with_backoff(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.security.encryption_key =
+            Some("4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a".to_string());
+        let api = mock_api_with_config(pool, config).await?;
+
+        let mock_user = mock_user()?;
+        api.db.upsert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+        api.db
+            .web_scraping(mock_user.id)
+            .upsert_web_page_tracker_webhook(
+                tracker.id,
+                &WebPageTrackerWebhook {
+                    url: Url::parse(&format!("http://localhost:{}/webhook", server.port()))?,
+                    secret: encryption::encrypt(
+                        "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a",
+                        b"my-webhook-secret",
+                    )?,
+                    previous_secret: None,
+                    previous_secret_expires_at: None,
+                    headers: None,
+                },
+            )
+            .await?;
+
+        let notification_id = api
+            .notifications()
+            .schedule_notification(
+                NotificationDestination::TrackerWebhook {
+                    user_id: mock_user.id,
+                    tracker_id: tracker.id,
+                },
+                NotificationContent::Text("abc".to_string()),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        // Sends pending notifications and returns the time the notification is re-scheduled at.
+        let api = &api;
+        let send_and_get_scheduled_at = move || async move {
+            let sent_at = OffsetDateTime::now_utc();
+            assert_eq!(api.notifications().send_pending_notifications(3).await?, 0);
+            let notification = api
+                .db
+                .get_notification(notification_id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("Notification is not found."))?;
+
+            // Make notification immediately available for the next attempt.
+            api.db
+                .update_notification_scheduled_at(
+                    notification_id,
+                    OffsetDateTime::from_unix_timestamp(946720800)?,
+                )
+                .await?;
+
+            Ok::<_, anyhow::Error>((
+                notification.scheduled_at - sent_at,
+                OffsetDateTime::now_utc() - sent_at,
+            ))
+        };
+
+        // 1st attempt: receiver fails, notification is re-scheduled with [15s, 30s] backoff.
+        let mut webhook_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/webhook");
+            then.status(500);
+        });
+        let (delay, elapsed) = send_and_get_scheduled_at().await?;
+        assert!(delay >= Duration::seconds(15) && delay <= Duration::seconds(30) + elapsed);
+        webhook_mock.assert();
+        webhook_mock.delete();
+
+        // 2nd attempt: receiver is rate limited, notification is re-scheduled respecting
+        // `Retry-After` that exceeds [30s, 60s] backoff.
+        webhook_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/webhook");
+            then.status(429).header("Retry-After", "120");
+        });
+        let (delay, elapsed) = send_and_get_scheduled_at().await?;
+        assert!(delay >= Duration::seconds(120) && delay <= Duration::seconds(120) + elapsed);
+        webhook_mock.assert();
+        webhook_mock.delete();
+
+        // 3rd attempt: receiver is unavailable, `Retry-After` shorter than [60s, 120s] backoff is
+        // ignored.
+        webhook_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/webhook");
+            then.status(503).header("Retry-After", "1");
+        });
+        let (delay, elapsed) = send_and_get_scheduled_at().await?;
+        assert!(delay >= Duration::seconds(60) && delay <= Duration::seconds(120) + elapsed);
+        webhook_mock.assert();
+        webhook_mock.delete();
+
+        // 4th attempt: receiver recovers and notification is delivered.
+        webhook_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/webhook");
+            then.status(200);
+        });
+        assert_eq!(api.notifications().send_pending_notifications(3).await?, 1);
+        assert!(api.db.get_notification(notification_id).await?.is_none());
+        assert!(api
+            .notifications()
+            .get_dead_letters(mock_user.id)
+            .await?
+            .is_empty());
+        webhook_mock.assert();
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn moves_undeliverable_webhook_notifications_to_dead_letters(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.security.encryption_key =
+            Some("4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a".to_string());
+        let api = mock_api_with_config(pool, config).await?;
+
+        let mock_user = mock_user()?;
+        api.db.upsert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+        api.db
+            .web_scraping(mock_user.id)
+            .upsert_web_page_tracker_webhook(
+                tracker.id,
+                &WebPageTrackerWebhook {
+                    url: Url::parse(&format!("http://localhost:{}/webhook", server.port()))?,
+                    secret: encryption::encrypt(
+                        "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a",
+                        b"my-webhook-secret",
+                    )?,
+                    previous_secret: None,
+                    previous_secret_expires_at: None,
+                    headers: None,
+                },
+            )
+            .await?;
+
+        let webhook_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/webhook");
+            then.status(500);
+        });
+
+        let notification_id = api
+            .notifications()
+            .schedule_notification(
+                NotificationDestination::TrackerWebhook {
+                    user_id: mock_user.id,
+                    tracker_id: tracker.id,
+                },
+                NotificationContent::Text("abc".to_string()),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        // Webhook notifications are re-attempted more times than other notifications.
+        for _ in 0..4 {
+            assert_eq!(api.notifications().send_pending_notifications(3).await?, 0);
+            assert!(api.db.get_notification(notification_id).await?.is_some());
+            assert!(api
+                .notifications()
+                .get_dead_letters(mock_user.id)
+                .await?
+                .is_empty());
+
+            api.db
+                .update_notification_scheduled_at(
+                    notification_id,
+                    OffsetDateTime::from_unix_timestamp(946720800)?,
+                )
+                .await?;
+        }
+
+        assert_eq!(api.notifications().send_pending_notifications(3).await?, 0);
+        assert!(api.db.get_notification(notification_id).await?.is_none());
+
+        let dead_letters = api.notifications().get_dead_letters(mock_user.id).await?;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].attempts, 5);
+        assert_eq!(
+            dead_letters[0].last_error,
+            "Webhook receiver responded with 500 HTTP status."
+        );
+        webhook_mock.assert_hits(5);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn blocks_webhook_notifications_to_not_allowed_domains(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.security.encryption_key =
+            Some("4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a".to_string());
+        config.utils.web_scraping.webhook_allowed_domains =
+            Some(["hooks.slack.com".to_string()].into_iter().collect());
+        let api = mock_api_with_config(pool, config).await?;
+
+        let mock_user = mock_user()?;
+        api.db.upsert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+        api.db
+            .web_scraping(mock_user.id)
+            .upsert_web_page_tracker_webhook(
+                tracker.id,
+                &WebPageTrackerWebhook {
+                    url: Url::parse(&format!("http://localhost:{}/webhook", server.port()))?,
+                    secret: encryption::encrypt(
+                        "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a",
+                        b"my-webhook-secret",
+                    )?,
+                    previous_secret: None,
+                    previous_secret_expires_at: None,
+                    headers: None,
+                },
+            )
+            .await?;
+
+        let webhook_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/webhook");
+            then.status(200);
+        });
+
+        let notification_id = api
+            .notifications()
+            .schedule_notification(
+                NotificationDestination::TrackerWebhook {
+                    user_id: mock_user.id,
+                    tracker_id: tracker.id,
+                },
+                NotificationContent::Text("abc".to_string()),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        assert_eq!(api.notifications().send_pending_notifications(3).await?, 0);
+        let notification = api.db.get_notification(notification_id).await?.unwrap();
+        assert!(notification.scheduled_at > OffsetDateTime::from_unix_timestamp(946720800)?);
+        webhook_mock.assert_hits(0);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn blocks_webhook_notifications_to_non_public_addresses(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut api = mock_api_with_network(
+            pool,
+            mock_network_with_records::<1>(vec![Record::from_rdata(
+                Name::new(),
+                300,
+                RData::A(A(Ipv4Addr::new(127, 0, 0, 1))),
+            )]),
+        )
+        .await?;
+        api.config.security.encryption_key =
+            Some("4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a".to_string());
+
+        let mock_user = mock_user()?;
+        api.db.upsert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+        api.db
+            .web_scraping(mock_user.id)
+            .upsert_web_page_tracker_webhook(
+                tracker.id,
+                &WebPageTrackerWebhook {
+                    url: Url::parse(&format!("http://localhost:{}/webhook", server.port()))?,
+                    secret: encryption::encrypt(
+                        "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a",
+                        b"my-webhook-secret",
+                    )?,
+                    previous_secret: None,
+                    previous_secret_expires_at: None,
+                    headers: None,
+                },
+            )
+            .await?;
+
+        let webhook_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/webhook");
+            then.status(200);
+        });
+
+        // Webhook domain now resolves to a local address.
+        let notification_id = api
+            .notifications()
+            .schedule_notification(
+                NotificationDestination::TrackerWebhook {
+                    user_id: mock_user.id,
+                    tracker_id: tracker.id,
+                },
+                NotificationContent::Text("abc".to_string()),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        assert_eq!(api.notifications().send_pending_notifications(3).await?, 0);
+        assert!(api.db.get_notification(notification_id).await?.is_some());
+        webhook_mock.assert_hits(0);
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn properly_sends_all_pending_notifications(pool: PgPool) -> anyhow::Result<()> {
         let mock_user = mock_user()?;
@@ -474,6 +1607,59 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn properly_sends_template_notifications_with_text_alternative(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let mock_user = mock_user()?;
+        let api = mock_api(pool).await?;
+        api.db.upsert_user(&mock_user).await?;
+
+        api.notifications()
+            .schedule_notification(
+                NotificationDestination::Email("some@secutils.dev".to_string()),
+                NotificationContent::Template(
+                    NotificationContentTemplate::WebPageContentTrackerChanges {
+                        tracker_name: "tracker".to_string(),
+                        content: Ok("some-content".to_string()),
+                    },
+                ),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        assert_eq!(api.notifications().send_pending_notifications(3).await?, 1);
+
+        let messages = api.network.email_transport.messages().await;
+        assert_eq!(messages.len(), 1);
+
+        // Both plain-text and HTML alternatives should include the tracker name, the content and
+        // the link to the tracker.
+        let (_, message) = &messages[0];
+        assert!(message.contains("Content-Type: multipart/alternative;"));
+
+        let text_part_start = message
+            .find("Content-Type: text/plain; charset=utf-8")
+            .unwrap();
+        let html_part_start = message
+            .find("Content-Type: text/html; charset=utf-8")
+            .unwrap();
+        assert!(text_part_start < html_part_start);
+
+        let (text_part, html_part) = (
+            &message[text_part_start..html_part_start],
+            &message[html_part_start..],
+        );
+        assert!(text_part.contains(
+            "\"tracker\" tracker detected content changes.\r\n\r\nCurrent content:\r\nsome-content\r\n\r\nVisit https://secutils.dev/ws/web_scraping__content to learn more."
+        ));
+        assert!(!text_part.contains("<p>"));
+        assert!(html_part.contains("some-content"));
+        assert!(html_part.contains("<p>"));
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn properly_sends_pending_notifications_in_batches(pool: PgPool) -> anyhow::Result<()> {
         let mock_user = mock_user()?;
@@ -535,6 +1721,87 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn sends_pending_notifications_with_bounded_concurrency(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.security.encryption_key =
+            Some("4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a".to_string());
+        config.scheduler.notifications_send_batch_size = 4;
+        config.scheduler.notifications_send_concurrency = 2;
+        let api = mock_api_with_config(pool, config).await?;
+
+        let mock_user = mock_user()?;
+        api.db.upsert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+        api.db
+            .web_scraping(mock_user.id)
+            .upsert_web_page_tracker_webhook(
+                tracker.id,
+                &WebPageTrackerWebhook {
+                    url: Url::parse(&format!("http://localhost:{}/webhook", server.port()))?,
+                    secret: encryption::encrypt(
+                        "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a",
+                        b"my-webhook-secret",
+                    )?,
+                    previous_secret: None,
+                    previous_secret_expires_at: None,
+                    headers: None,
+                },
+            )
+            .await?;
+
+        let webhook_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/webhook");
+            then.status(200)
+                .delay(std::time::Duration::from_millis(300));
+        });
+
+        for n in 0..8 {
+            api.notifications()
+                .schedule_notification(
+                    NotificationDestination::TrackerWebhook {
+                        user_id: mock_user.id,
+                        tracker_id: tracker.id,
+                    },
+                    NotificationContent::Text(format!("{n}")),
+                    OffsetDateTime::from_unix_timestamp(946720800 + n)?,
+                )
+                .await?;
+        }
+
+        // 8 notifications are sent in 2 batches of 4, 2 notifications at a time.
+        let send_start = std::time::Instant::now();
+        assert_eq!(api.notifications().send_pending_notifications(10).await?, 8);
+        let send_elapsed = send_start.elapsed();
+        assert!(send_elapsed >= std::time::Duration::from_millis(1200));
+        assert!(send_elapsed < std::time::Duration::from_millis(2400));
+
+        webhook_mock.assert_hits(8);
+        for n in 0..8 {
+            assert!(api
+                .db
+                .get_notification((n + 1).try_into()?)
+                .await?
+                .is_none());
+        }
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn sends_email_notifications_respecting_catch_all_filter(
         pool: PgPool,
@@ -816,4 +2083,189 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn does_not_send_notifications_in_safe_mode(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.security.encryption_key =
+            Some("4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a".to_string());
+        let mut api = mock_api_with_config(pool, config).await?;
+        api.network = api.network.clone().with_safe_mode(true);
+
+        let mock_user = mock_user()?;
+        api.db.upsert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+        api.db
+            .web_scraping(mock_user.id)
+            .upsert_web_page_tracker_webhook(
+                tracker.id,
+                &WebPageTrackerWebhook {
+                    url: Url::parse(&format!("http://localhost:{}/webhook", server.port()))?,
+                    secret: encryption::encrypt(
+                        "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a",
+                        b"my-webhook-secret",
+                    )?,
+                    previous_secret: None,
+                    previous_secret_expires_at: None,
+                    headers: None,
+                },
+            )
+            .await?;
+
+        let webhook_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/webhook");
+            then.status(200);
+        });
+
+        let webhook_notification_id = api
+            .notifications()
+            .schedule_notification(
+                NotificationDestination::TrackerWebhook {
+                    user_id: mock_user.id,
+                    tracker_id: tracker.id,
+                },
+                NotificationContent::Text("abc".to_string()),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+        let email_notification_id = api
+            .notifications()
+            .schedule_notification(
+                NotificationDestination::User(mock_user.id),
+                NotificationContent::Text("abc".to_string()),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        // Neither webhook nor email notifications are sent, but they stay in the queue.
+        assert_eq!(api.notifications().send_pending_notifications(3).await?, 0);
+        assert!(api
+            .db
+            .get_notification(webhook_notification_id)
+            .await?
+            .is_some());
+        assert!(api
+            .db
+            .get_notification(email_notification_id)
+            .await?
+            .is_some());
+        assert!(api.network.email_transport.messages().await.is_empty());
+        webhook_mock.assert_hits(0);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn moves_undeliverable_notifications_to_dead_letters(pool: PgPool) -> anyhow::Result<()> {
+        let mock_user = mock_user()?;
+        let api = mock_api_with_network(
+            pool,
+            Network::new(MockResolver::new(), AsyncStubTransport::new_error()),
+        )
+        .await?;
+        api.db.upsert_user(&mock_user).await?;
+
+        let notification_id = api
+            .notifications()
+            .schedule_notification(
+                NotificationDestination::User(mock_user.id),
+                NotificationContent::Text("abc".to_string()),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .await?;
+
+        // Notification stays in the queue until the maximum number of attempts is reached.
+        for _ in 0..2 {
+            assert_eq!(api.notifications().send_pending_notifications(3).await?, 0);
+            assert!(api.db.get_notification(notification_id).await?.is_some());
+            assert!(api
+                .notifications()
+                .get_dead_letters(mock_user.id)
+                .await?
+                .is_empty());
+        }
+
+        assert_eq!(api.notifications().send_pending_notifications(3).await?, 0);
+        assert!(api.db.get_notification(notification_id).await?.is_none());
+
+        let dead_letters = api.notifications().get_dead_letters(mock_user.id).await?;
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(
+            dead_letters[0].destination,
+            NotificationDestination::User(mock_user.id)
+        );
+        assert_eq!(
+            dead_letters[0].content,
+            NotificationContent::Text("abc".to_string())
+        );
+        assert_eq!(
+            dead_letters[0].scheduled_at,
+            OffsetDateTime::from_unix_timestamp(946720800)?
+        );
+        assert_eq!(dead_letters[0].attempts, 3);
+        assert!(!dead_letters[0].last_error.is_empty());
+
+        // Retry fails with the same transport.
+        let retry_error = api
+            .notifications()
+            .retry_dead_letter(mock_user.id, dead_letters[0].id)
+            .await
+            .unwrap_err()
+            .downcast::<SecutilsError>()?;
+        assert_eq!(
+            retry_error.to_string(),
+            format!(
+                "Notification dead letter ('{}') couldn't be delivered.",
+                dead_letters[0].id
+            )
+        );
+        assert_eq!(
+            api.notifications().get_dead_letters(mock_user.id).await?[0].attempts,
+            4
+        );
+
+        // Other users cannot retry dead letters they don't own.
+        let retry_error = api
+            .notifications()
+            .retry_dead_letter(
+                uuid!("00000000-0000-0000-0000-000000000002").into(),
+                dead_letters[0].id,
+            )
+            .await
+            .unwrap_err()
+            .downcast::<SecutilsError>()?;
+        assert_eq!(
+            retry_error.to_string(),
+            format!(
+                "Notification dead letter ('{}') is not found.",
+                dead_letters[0].id
+            )
+        );
+
+        // Retry succeeds once transport is fixed.
+        let api = mock_api(api.db.pool.clone()).await?;
+        api.notifications()
+            .retry_dead_letter(mock_user.id, dead_letters[0].id)
+            .await?;
+        assert!(api
+            .notifications()
+            .get_dead_letters(mock_user.id)
+            .await?
+            .is_empty());
+        assert_eq!(api.network.email_transport.messages().await.len(), 1);
+
+        Ok(())
+    }
 }
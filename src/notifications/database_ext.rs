@@ -1,16 +1,23 @@
 mod raw_notification;
+mod raw_notification_dead_letter;
 
 use crate::{
     database::Database,
     notifications::{
-        database_ext::raw_notification::RawNotification, Notification, NotificationId,
+        database_ext::{
+            raw_notification::RawNotification,
+            raw_notification_dead_letter::RawNotificationDeadLetter,
+        },
+        Notification, NotificationDeadLetter, NotificationId,
     },
+    users::UserId,
 };
 use anyhow::bail;
 use async_stream::try_stream;
 use futures::Stream;
 use sqlx::{query, query_as, query_scalar};
 use time::OffsetDateTime;
+use uuid::Uuid;
 
 /// Extends primary database with the notification-related methods.
 impl Database {
@@ -22,7 +29,7 @@ impl Database {
         let id = *id;
         query_as!(
             RawNotification,
-            r#"SELECT * FROM notifications WHERE id = $1"#,
+            r#"SELECT id, destination, content, scheduled_at, urgent FROM notifications WHERE id = $1"#,
             id
         )
         .fetch_optional(&self.pool)
@@ -42,10 +49,11 @@ impl Database {
 
         let raw_notification = RawNotification::try_from(notification)?;
         let id = query_scalar!(
-            r#"INSERT INTO notifications (destination, content, scheduled_at) VALUES ($1, $2, $3) RETURNING id"#,
+            r#"INSERT INTO notifications (destination, content, scheduled_at, urgent) VALUES ($1, $2, $3, $4) RETURNING id"#,
             raw_notification.destination,
             raw_notification.content,
-            raw_notification.scheduled_at
+            raw_notification.scheduled_at,
+            raw_notification.urgent
         ).fetch_one(&self.pool)
         .await?;
 
@@ -65,6 +73,23 @@ impl Database {
         Ok(())
     }
 
+    /// Re-schedules notification to be sent at the specified time.
+    pub async fn update_notification_scheduled_at(
+        &self,
+        id: NotificationId,
+        scheduled_at: OffsetDateTime,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"UPDATE notifications SET scheduled_at = $2 WHERE id = $1"#,
+            *id,
+            scheduled_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
     /// Retrieves a list of notification IDs that are scheduled at or before specified date.
     pub fn get_notification_ids(
         &self,
@@ -95,13 +120,150 @@ impl Database {
             }
         }
     }
+
+    /// Increments the number of failed delivery attempts for the notification and returns the
+    /// updated number.
+    pub async fn increment_notification_attempts(&self, id: NotificationId) -> anyhow::Result<u32> {
+        let attempts = query_scalar!(
+            r#"UPDATE notifications SET attempts = attempts + 1 WHERE id = $1 RETURNING attempts"#,
+            *id
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(attempts.try_into()?)
+    }
+
+    /// Moves notification that couldn't be delivered to the dead letters.
+    pub async fn move_notification_to_dead_letters(
+        &self,
+        id: NotificationId,
+        dead_letter: &NotificationDeadLetter,
+    ) -> anyhow::Result<()> {
+        let raw_dead_letter = RawNotificationDeadLetter::try_from(dead_letter)?;
+
+        let mut tx = self.pool.begin().await?;
+        // Don't link dead letter to the user if the user no longer exists.
+        query!(
+            r#"
+INSERT INTO notifications_dead_letters (id, user_id, destination, content, scheduled_at, failed_at, attempts, last_error)
+VALUES ($1, (SELECT id FROM users WHERE id = $2), $3, $4, $5, $6, $7, $8)
+            "#,
+            raw_dead_letter.id,
+            raw_dead_letter.user_id,
+            raw_dead_letter.destination,
+            raw_dead_letter.content,
+            raw_dead_letter.scheduled_at,
+            raw_dead_letter.failed_at,
+            raw_dead_letter.attempts,
+            raw_dead_letter.last_error
+        )
+        .execute(&mut *tx)
+        .await?;
+        query!(r#"DELETE FROM notifications WHERE id = $1"#, *id)
+            .execute(&mut *tx)
+            .await?;
+
+        Ok(tx.commit().await?)
+    }
+
+    /// Retrieves all notification dead letters for the specified user.
+    pub async fn get_notification_dead_letters(
+        &self,
+        user_id: UserId,
+    ) -> anyhow::Result<Vec<NotificationDeadLetter>> {
+        let raw_dead_letters = query_as!(
+            RawNotificationDeadLetter,
+            r#"
+SELECT id, user_id, destination, content, scheduled_at, failed_at, attempts, last_error
+FROM notifications_dead_letters
+WHERE user_id = $1
+ORDER BY failed_at
+            "#,
+            *user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut dead_letters = vec![];
+        for raw_dead_letter in raw_dead_letters {
+            dead_letters.push(NotificationDeadLetter::try_from(raw_dead_letter)?);
+        }
+
+        Ok(dead_letters)
+    }
+
+    /// Retrieves notification dead letter for the specified user using dead letter ID.
+    pub async fn get_notification_dead_letter(
+        &self,
+        user_id: UserId,
+        id: Uuid,
+    ) -> anyhow::Result<Option<NotificationDeadLetter>> {
+        query_as!(
+            RawNotificationDeadLetter,
+            r#"
+SELECT id, user_id, destination, content, scheduled_at, failed_at, attempts, last_error
+FROM notifications_dead_letters
+WHERE user_id = $1 AND id = $2
+            "#,
+            *user_id,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .map(NotificationDeadLetter::try_from)
+        .transpose()
+    }
+
+    /// Updates delivery attempt details of the notification dead letter.
+    pub async fn update_notification_dead_letter(
+        &self,
+        dead_letter: &NotificationDeadLetter,
+    ) -> anyhow::Result<()> {
+        let raw_dead_letter = RawNotificationDeadLetter::try_from(dead_letter)?;
+        query!(
+            r#"
+UPDATE notifications_dead_letters
+SET failed_at = $2, attempts = $3, last_error = $4
+WHERE id = $1
+            "#,
+            raw_dead_letter.id,
+            raw_dead_letter.failed_at,
+            raw_dead_letter.attempts,
+            raw_dead_letter.last_error
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Removes notification dead letter for the specified user using dead letter ID.
+    pub async fn remove_notification_dead_letter(
+        &self,
+        user_id: UserId,
+        id: Uuid,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"DELETE FROM notifications_dead_letters WHERE user_id = $1 AND id = $2"#,
+            *user_id,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
         database::Database,
-        notifications::{Notification, NotificationContent, NotificationDestination},
+        notifications::{
+            Notification, NotificationContent, NotificationDeadLetter, NotificationDestination,
+        },
+        tests::mock_user,
     };
     use futures::StreamExt;
     use insta::assert_debug_snapshot;
@@ -146,6 +308,7 @@ mod tests {
                     "abc",
                 ),
                 scheduled_at: 2000-01-01 10:00:00.0 +00:00:00,
+                urgent: false,
             },
         )
         "###);
@@ -164,6 +327,7 @@ mod tests {
                     "abc",
                 ),
                 scheduled_at: 2000-01-01 10:00:00.0 +00:00:00,
+                urgent: false,
             },
         )
         "###);
@@ -277,4 +441,73 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn can_move_notifications_to_dead_letters(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+        let user = mock_user()?;
+        db.insert_user(&user).await?;
+
+        let notification_id = db
+            .insert_notification(&Notification::new(
+                NotificationDestination::User(user.id),
+                NotificationContent::Text("abc".to_string()),
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            ))
+            .await?;
+        assert_eq!(
+            db.increment_notification_attempts(notification_id).await?,
+            1
+        );
+        assert_eq!(
+            db.increment_notification_attempts(notification_id).await?,
+            2
+        );
+
+        let dead_letter = NotificationDeadLetter {
+            id: uuid!("00000000-0000-0000-0000-000000000002"),
+            destination: NotificationDestination::User(user.id),
+            content: NotificationContent::Text("abc".to_string()),
+            scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            failed_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            attempts: 2,
+            last_error: "Uh oh.".to_string(),
+        };
+        db.move_notification_to_dead_letters(notification_id, &dead_letter)
+            .await?;
+
+        assert!(db.get_notification(notification_id).await?.is_none());
+        assert_eq!(
+            db.get_notification_dead_letters(user.id).await?,
+            vec![dead_letter.clone()]
+        );
+        assert_eq!(
+            db.get_notification_dead_letter(user.id, dead_letter.id)
+                .await?,
+            Some(dead_letter.clone())
+        );
+        assert!(db
+            .get_notification_dead_letters(uuid!("00000000-0000-0000-0000-000000000003").into())
+            .await?
+            .is_empty());
+
+        let dead_letter = NotificationDeadLetter {
+            failed_at: OffsetDateTime::from_unix_timestamp(946720820)?,
+            attempts: 3,
+            last_error: "Uh oh, again.".to_string(),
+            ..dead_letter
+        };
+        db.update_notification_dead_letter(&dead_letter).await?;
+        assert_eq!(
+            db.get_notification_dead_letter(user.id, dead_letter.id)
+                .await?,
+            Some(dead_letter.clone())
+        );
+
+        db.remove_notification_dead_letter(user.id, dead_letter.id)
+            .await?;
+        assert!(db.get_notification_dead_letters(user.id).await?.is_empty());
+
+        Ok(())
+    }
 }
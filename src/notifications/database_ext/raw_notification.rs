@@ -7,6 +7,7 @@ pub(super) struct RawNotification {
     pub destination: Vec<u8>,
     pub content: Vec<u8>,
     pub scheduled_at: OffsetDateTime,
+    pub urgent: bool,
 }
 
 impl TryFrom<RawNotification> for Notification {
@@ -18,6 +19,7 @@ impl TryFrom<RawNotification> for Notification {
             destination: postcard::from_bytes(&raw_notification.destination)?,
             content: postcard::from_bytes(&raw_notification.content)?,
             scheduled_at: raw_notification.scheduled_at,
+            urgent: raw_notification.urgent,
         })
     }
 }
@@ -31,6 +33,7 @@ impl TryFrom<&Notification> for RawNotification {
             destination: postcard::to_stdvec(&notification.destination)?,
             content: postcard::to_stdvec(&notification.content)?,
             scheduled_at: notification.scheduled_at,
+            urgent: notification.urgent,
         })
     }
 }
@@ -50,6 +53,7 @@ mod tests {
                 destination: vec![0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
                 content: vec![0, 3, 97, 98, 99],
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                urgent: false,
             })?,
             Notification {
                 id: 1.try_into()?,
@@ -58,6 +62,7 @@ mod tests {
                 ),
                 content: NotificationContent::Text("abc".to_string()),
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                urgent: false,
             }
         );
 
@@ -74,12 +79,14 @@ mod tests {
                 ),
                 content: NotificationContent::Text("abc".to_string()),
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                urgent: false,
             })?,
             RawNotification {
                 id: 1,
                 destination: vec![0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
                 content: vec![0, 3, 97, 98, 99],
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                urgent: false,
             }
         );
 
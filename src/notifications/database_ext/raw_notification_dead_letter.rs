@@ -0,0 +1,134 @@
+use crate::notifications::{NotificationDeadLetter, NotificationDestination};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub(super) struct RawNotificationDeadLetter {
+    pub id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub destination: Vec<u8>,
+    pub content: Vec<u8>,
+    pub scheduled_at: OffsetDateTime,
+    pub failed_at: OffsetDateTime,
+    pub attempts: i32,
+    pub last_error: String,
+}
+
+impl TryFrom<RawNotificationDeadLetter> for NotificationDeadLetter {
+    type Error = anyhow::Error;
+
+    fn try_from(raw_dead_letter: RawNotificationDeadLetter) -> Result<Self, Self::Error> {
+        Ok(NotificationDeadLetter {
+            id: raw_dead_letter.id,
+            destination: postcard::from_bytes(&raw_dead_letter.destination)?,
+            content: postcard::from_bytes(&raw_dead_letter.content)?,
+            scheduled_at: raw_dead_letter.scheduled_at,
+            failed_at: raw_dead_letter.failed_at,
+            attempts: raw_dead_letter.attempts.try_into()?,
+            last_error: raw_dead_letter.last_error,
+        })
+    }
+}
+
+impl TryFrom<&NotificationDeadLetter> for RawNotificationDeadLetter {
+    type Error = anyhow::Error;
+
+    fn try_from(dead_letter: &NotificationDeadLetter) -> Result<Self, Self::Error> {
+        Ok(RawNotificationDeadLetter {
+            id: dead_letter.id,
+            user_id: match dead_letter.destination {
+                NotificationDestination::User(user_id)
+                | NotificationDestination::TrackerWebhook { user_id, .. } => Some(*user_id),
+                _ => None,
+            },
+            destination: postcard::to_stdvec(&dead_letter.destination)?,
+            content: postcard::to_stdvec(&dead_letter.content)?,
+            scheduled_at: dead_letter.scheduled_at,
+            failed_at: dead_letter.failed_at,
+            attempts: dead_letter.attempts.try_into()?,
+            last_error: dead_letter.last_error.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RawNotificationDeadLetter;
+    use crate::notifications::{
+        NotificationContent, NotificationDeadLetter, NotificationDestination,
+    };
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn can_convert_to_dead_letter() -> anyhow::Result<()> {
+        assert_eq!(
+            NotificationDeadLetter::try_from(RawNotificationDeadLetter {
+                id: uuid!("00000000-0000-0000-0000-000000000002"),
+                user_id: Some(uuid!("00000000-0000-0000-0000-000000000001")),
+                destination: vec![0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+                content: vec![0, 3, 97, 98, 99],
+                scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                failed_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+                attempts: 3,
+                last_error: "Uh oh.".to_string(),
+            })?,
+            NotificationDeadLetter {
+                id: uuid!("00000000-0000-0000-0000-000000000002"),
+                destination: NotificationDestination::User(
+                    uuid!("00000000-0000-0000-0000-000000000001").into()
+                ),
+                content: NotificationContent::Text("abc".to_string()),
+                scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                failed_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+                attempts: 3,
+                last_error: "Uh oh.".to_string(),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_convert_to_raw_dead_letter() -> anyhow::Result<()> {
+        assert_eq!(
+            RawNotificationDeadLetter::try_from(&NotificationDeadLetter {
+                id: uuid!("00000000-0000-0000-0000-000000000002"),
+                destination: NotificationDestination::User(
+                    uuid!("00000000-0000-0000-0000-000000000001").into()
+                ),
+                content: NotificationContent::Text("abc".to_string()),
+                scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                failed_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+                attempts: 3,
+                last_error: "Uh oh.".to_string(),
+            })?,
+            RawNotificationDeadLetter {
+                id: uuid!("00000000-0000-0000-0000-000000000002"),
+                user_id: Some(uuid!("00000000-0000-0000-0000-000000000001")),
+                destination: vec![0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+                content: vec![0, 3, 97, 98, 99],
+                scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                failed_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+                attempts: 3,
+                last_error: "Uh oh.".to_string(),
+            }
+        );
+
+        assert_eq!(
+            RawNotificationDeadLetter::try_from(&NotificationDeadLetter {
+                id: uuid!("00000000-0000-0000-0000-000000000002"),
+                destination: NotificationDestination::ServerLog,
+                content: NotificationContent::Text("abc".to_string()),
+                scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                failed_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+                attempts: 3,
+                last_error: "Uh oh.".to_string(),
+            })?
+            .user_id,
+            None
+        );
+
+        Ok(())
+    }
+}
@@ -12,6 +12,8 @@ pub struct Notification {
     pub content: NotificationContent,
     /// The time at which the notification is scheduled to be sent, in UTC.
     pub scheduled_at: OffsetDateTime,
+    /// Indicates whether the notification should be sent even during user's quiet hours.
+    pub urgent: bool,
 }
 
 impl Notification {
@@ -26,8 +28,15 @@ impl Notification {
             destination,
             content,
             scheduled_at,
+            urgent: false,
         }
     }
+
+    /// Marks the notification as urgent, so that it's sent even during user's quiet hours.
+    pub fn set_urgent(mut self, urgent: bool) -> Self {
+        self.urgent = urgent;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -52,8 +61,20 @@ mod tests {
                 ),
                 content: NotificationContent::Text("abc".to_string()),
                 scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                urgent: false,
             }
         );
+
+        assert!(
+            Notification::new(
+                NotificationDestination::User(uuid!("00000000-0000-0000-0000-000000000001").into()),
+                NotificationContent::Text("abc".to_string()),
+                OffsetDateTime::from_unix_timestamp(946720800)?
+            )
+            .set_urgent(true)
+            .urgent
+        );
+
         Ok(())
     }
 }
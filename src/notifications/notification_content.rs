@@ -1,9 +1,14 @@
 use crate::{
     api::Api,
     network::{DnsResolver, EmailTransport},
-    notifications::{EmailNotificationContent, NotificationContentTemplate},
+    notifications::{
+        webhook_notification_payload, EmailNotificationContent, NotificationContentTemplate,
+        NotificationPreview,
+    },
 };
 use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
 
 /// Describes the content of a notification.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -28,6 +33,27 @@ impl NotificationContent {
             NotificationContent::Template(template) => template.compile_to_email(api).await?,
         })
     }
+
+    /// Consumes notification content and renders it exactly as it would be delivered to the user
+    /// email and, if specified, to the webhook of the web page tracker, without delivering it.
+    pub async fn into_preview<DR: DnsResolver, ET: EmailTransport>(
+        self,
+        api: &Api<DR, ET>,
+        webhook_tracker_id: Option<Uuid>,
+    ) -> anyhow::Result<NotificationPreview> {
+        let email = self.into_email(api).await?;
+        let webhook_payload = webhook_tracker_id.map(|tracker_id| {
+            webhook_notification_payload(tracker_id, &email, OffsetDateTime::now_utc())
+        });
+
+        Ok(NotificationPreview {
+            subject: email.subject,
+            text: email.text,
+            html: email.html,
+            webhook_payload,
+            webhook_headers: None,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -1,19 +1,47 @@
 mod account_activation;
 mod account_recovery;
+mod web_page_content_tracker_assertions;
 mod web_page_content_tracker_changes;
+mod web_page_content_tracker_response_changes;
+mod web_page_dns_records_tracker_changes;
 mod web_page_resources_tracker_changes;
+mod web_page_resources_tracker_size_budget;
+mod web_page_screenshot_tracker_changes;
+mod web_page_tracker_changes_summary;
+mod web_page_tracker_idle_deactivation;
 
 use crate::{
     api::Api,
     network::{DnsResolver, EmailTransport},
     notifications::EmailNotificationContent,
+    users::UserId,
+    utils::web_scraping::WebPageTrackerKind,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::time::Duration;
+use time::OffsetDateTime;
 use uuid::Uuid;
 
 pub const SECUTILS_LOGO_BYTES: &[u8] =
     include_bytes!("../../assets/logo/secutils-logo-with-text.png");
 
+/// Renders the HTML email template and its plain-text alternative (`{template_name}.txt`) using
+/// the same data. Returns plain-text and HTML versions of the email, in that order.
+fn render_email_templates<DR: DnsResolver, ET: EmailTransport>(
+    api: &Api<DR, ET>,
+    template_name: &str,
+    data: &JsonValue,
+) -> anyhow::Result<(String, String)> {
+    let text = api
+        .templates
+        .render(&format!("{template_name}.txt"), data)?;
+    Ok((
+        text.trim_end().to_string(),
+        api.templates.render(template_name, data)?,
+    ))
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum NotificationContentTemplate {
     AccountActivation {
@@ -31,6 +59,40 @@ pub enum NotificationContentTemplate {
         tracker_name: String,
         content: Result<String, String>,
     },
+    WebPageScreenshotTrackerChanges {
+        tracker_name: String,
+        content: Result<String, String>,
+    },
+    WebPageDnsRecordsTrackerChanges {
+        tracker_name: String,
+        content: Result<String, String>,
+    },
+    WebPageTrackerChangesSummary {
+        user_id: UserId,
+        tracker_id: Uuid,
+        tracker_kind: WebPageTrackerKind,
+        tracker_name: String,
+        since: OffsetDateTime,
+    },
+    WebPageResourcesTrackerSizeBudgetExceeded {
+        tracker_name: String,
+        total_size: usize,
+        previous_total_size: Option<usize>,
+    },
+    WebPageContentTrackerResponseChanges {
+        tracker_name: String,
+        changes: String,
+        content: String,
+    },
+    WebPageContentTrackerAssertions {
+        tracker_name: String,
+        passed: bool,
+    },
+    WebPageTrackerIdleDeactivation {
+        tracker_kind: WebPageTrackerKind,
+        tracker_name: String,
+        idle_period: Duration,
+    },
 }
 
 impl NotificationContentTemplate {
@@ -59,17 +121,103 @@ impl NotificationContentTemplate {
             } => {
                 web_page_content_tracker_changes::compile_to_email(api, tracker_name, content).await
             }
+            NotificationContentTemplate::WebPageScreenshotTrackerChanges {
+                tracker_name,
+                content,
+            } => {
+                web_page_screenshot_tracker_changes::compile_to_email(api, tracker_name, content)
+                    .await
+            }
+            NotificationContentTemplate::WebPageDnsRecordsTrackerChanges {
+                tracker_name,
+                content,
+            } => {
+                web_page_dns_records_tracker_changes::compile_to_email(api, tracker_name, content)
+                    .await
+            }
+            NotificationContentTemplate::WebPageTrackerChangesSummary {
+                user_id,
+                tracker_id,
+                tracker_kind,
+                tracker_name,
+                since,
+            } => {
+                web_page_tracker_changes_summary::compile_to_email(
+                    api,
+                    *user_id,
+                    *tracker_id,
+                    *tracker_kind,
+                    tracker_name,
+                    *since,
+                )
+                .await
+            }
+            NotificationContentTemplate::WebPageResourcesTrackerSizeBudgetExceeded {
+                tracker_name,
+                total_size,
+                previous_total_size,
+            } => {
+                web_page_resources_tracker_size_budget::compile_to_email(
+                    api,
+                    tracker_name,
+                    *total_size,
+                    *previous_total_size,
+                )
+                .await
+            }
+            NotificationContentTemplate::WebPageContentTrackerResponseChanges {
+                tracker_name,
+                changes,
+                content,
+            } => {
+                web_page_content_tracker_response_changes::compile_to_email(
+                    api,
+                    tracker_name,
+                    changes,
+                    content,
+                )
+                .await
+            }
+            NotificationContentTemplate::WebPageContentTrackerAssertions {
+                tracker_name,
+                passed,
+            } => {
+                web_page_content_tracker_assertions::compile_to_email(api, tracker_name, *passed)
+                    .await
+            }
+            NotificationContentTemplate::WebPageTrackerIdleDeactivation {
+                tracker_kind,
+                tracker_name,
+                idle_period,
+            } => {
+                web_page_tracker_idle_deactivation::compile_to_email(
+                    api,
+                    *tracker_kind,
+                    tracker_name,
+                    *idle_period,
+                )
+                .await
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{notifications::NotificationContentTemplate, tests::mock_api};
+    use crate::{
+        notifications::NotificationContentTemplate,
+        tests::{mock_api, mock_user},
+        utils::web_scraping::{
+            WebPageContentTrackerTag, WebPageDataRevision, WebPageTracker, WebPageTrackerKind,
+            WebPageTrackerSettings,
+        },
+    };
     use insta::assert_debug_snapshot;
     use itertools::Itertools;
     use sqlx::PgPool;
-    use uuid::uuid;
+    use std::time::Duration;
+    use time::OffsetDateTime;
+    use uuid::{uuid, Uuid};
 
     #[sqlx::test]
     async fn can_compile_account_activation_template_to_email(pool: PgPool) -> anyhow::Result<()> {
@@ -235,6 +383,118 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn can_compile_resources_tracker_size_budget_template_to_email(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let mut template = NotificationContentTemplate::WebPageResourcesTrackerSizeBudgetExceeded {
+            tracker_name: "tracker".to_string(),
+            total_size: 2048,
+            previous_total_size: Some(1024),
+        }
+        .compile_to_email(&api)
+        .await?;
+        template
+            .attachments
+            .as_mut()
+            .unwrap()
+            .iter_mut()
+            .for_each(|a| {
+                a.content = a.content.len().to_be_bytes().iter().cloned().collect_vec();
+            });
+
+        assert_debug_snapshot!(template, @r###"
+        EmailNotificationContent {
+            subject: "[Secutils.dev] Size budget exceeded: \"tracker\"",
+            text: "\"tracker\" tracker detected that resources exceed the size budget: total size is 2048 bytes (previously 1024 bytes). Visit https://secutils.dev/ws/web_scraping__resources to learn more.",
+            html: Some(
+                "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n  <title>\"tracker\" tracker detected that resources exceed the size budget</title>\n  <meta charset=\"utf-8\">\n  <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n  <style>\n    body {\n      font-family: Arial, sans-serif;\n      background-color: #f1f1f1;\n      margin: 0;\n      padding: 0;\n    }\n    .container {\n      max-width: 600px;\n      margin: 0 auto;\n      background-color: #fff;\n      padding: 20px;\n      border-radius: 5px;\n      box-shadow: 0 0 10px rgba(0, 0, 0, 0.1);\n    }\n    h1 {\n      font-size: 24px;\n      margin-top: 0;\n    }\n    p {\n      font-size: 16px;\n      line-height: 1.5;\n      margin-bottom: 20px;\n    }\n    .navigate-link {\n      display: block;\n      width: 250px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      text-decoration: none;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n    .numeric-code {\n      display: block;\n      width: 100px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n  </style>\n</head>\n<body>\n<div class=\"container\">\n  <h1>\"tracker\" tracker detected that resources exceed the size budget</h1>\n  <p>Total size of resources: <b>2048 bytes</b> (previously 1024 bytes).</p>\n  <p>To learn more, visit the <b>Resources trackers</b> page:</p>\n  <a class=\"navigate-link\" href=\"https://secutils.dev/ws/web_scraping__resources\">Web Scraping → Resources trackers</a>\n  <p>If the button above doesn't work, you can navigate to the following URL directly: </p>\n  <p>https://secutils.dev/ws/web_scraping__resources</p>\n  <a href=\"https://secutils.dev/\"><img src=\"cid:secutils-logo\" alt=\"Secutils.dev logo\" width=\"89\" height=\"14\" /></a>\n</div>\n</body>\n</html>\n",
+            ),
+            attachments: Some(
+                [
+                    EmailNotificationAttachment {
+                        disposition: Inline(
+                            "secutils-logo",
+                        ),
+                        content_type: "image/png",
+                        content: [
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            15,
+                            165,
+                        ],
+                    },
+                ],
+            ),
+        }
+        "###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_compile_content_tracker_response_changes_template_to_email(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let mut template = NotificationContentTemplate::WebPageContentTrackerResponseChanges {
+            tracker_name: "tracker".to_string(),
+            changes: "status changed from 200 to 503, body changed".to_string(),
+            content: r#"{"status":503,"body":"down"}"#.to_string(),
+        }
+        .compile_to_email(&api)
+        .await?;
+        template
+            .attachments
+            .as_mut()
+            .unwrap()
+            .iter_mut()
+            .for_each(|a| {
+                a.content = a.content.len().to_be_bytes().iter().cloned().collect_vec();
+            });
+
+        assert_debug_snapshot!(template, @r###"
+        EmailNotificationContent {
+            subject: "[Secutils.dev] Change detected: \"tracker\"",
+            text: "\"tracker\" tracker detected response changes: status changed from 200 to 503, body changed.\n\nCurrent response:\n{\"status\":503,\"body\":\"down\"}\n\nVisit https://secutils.dev/ws/web_scraping__content to learn more.",
+            html: Some(
+                "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n  <title>\"tracker\" tracker detected response changes</title>\n  <meta charset=\"utf-8\">\n  <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n  <style>\n    body {\n      font-family: Arial, sans-serif;\n      background-color: #f1f1f1;\n      margin: 0;\n      padding: 0;\n    }\n    .container {\n      max-width: 600px;\n      margin: 0 auto;\n      background-color: #fff;\n      padding: 20px;\n      border-radius: 5px;\n      box-shadow: 0 0 10px rgba(0, 0, 0, 0.1);\n    }\n    h1 {\n      font-size: 24px;\n      margin-top: 0;\n    }\n    p {\n      font-size: 16px;\n      line-height: 1.5;\n      margin-bottom: 20px;\n    }\n    .navigate-link {\n      display: block;\n      width: 250px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      text-decoration: none;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n    .numeric-code {\n      display: block;\n      width: 100px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n  </style>\n</head>\n<body>\n<div class=\"container\">\n  <h1>\"tracker\" tracker detected response changes</h1>\n  <p>Changes: <b>status changed from 200 to 503, body changed</b>.</p>\n  <p>Current response: {&quot;status&quot;:503,&quot;body&quot;:&quot;down&quot;}</p>\n  <p>To learn more, visit the <b>Content trackers</b> page:</p>\n  <a class=\"navigate-link\" href=\"https://secutils.dev/ws/web_scraping__content\">Web Scraping → Content trackers</a>\n  <p>If the button above doesn't work, you can navigate to the following URL directly: </p>\n  <p>https://secutils.dev/ws/web_scraping__content</p>\n  <a href=\"https://secutils.dev/\"><img src=\"cid:secutils-logo\" alt=\"Secutils.dev logo\" width=\"89\" height=\"14\" /></a>\n</div>\n</body>\n</html>\n",
+            ),
+            attachments: Some(
+                [
+                    EmailNotificationAttachment {
+                        disposition: Inline(
+                            "secutils-logo",
+                        ),
+                        content_type: "image/png",
+                        content: [
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            15,
+                            165,
+                        ],
+                    },
+                ],
+            ),
+        }
+        "###
+        );
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn can_compile_resources_tracker_changes_error_template_to_email(
         pool: PgPool,
@@ -314,7 +574,7 @@ mod tests {
         assert_debug_snapshot!(template, @r###"
         EmailNotificationContent {
             subject: "[Secutils.dev] Change detected: \"tracker\"",
-            text: "\"tracker\" tracker detected content changes. Visit https://secutils.dev/ws/web_scraping__content to learn more.",
+            text: "\"tracker\" tracker detected content changes.\n\nCurrent content:\ncontent\n\nVisit https://secutils.dev/ws/web_scraping__content to learn more.",
             html: Some(
                 "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n  <title>\"tracker\" tracker detected content changes</title>\n  <meta charset=\"utf-8\">\n  <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n  <style>\n    body {\n      font-family: Arial, sans-serif;\n      background-color: #f1f1f1;\n      margin: 0;\n      padding: 0;\n    }\n    .container {\n      max-width: 600px;\n      margin: 0 auto;\n      background-color: #fff;\n      padding: 20px;\n      border-radius: 5px;\n      box-shadow: 0 0 10px rgba(0, 0, 0, 0.1);\n    }\n    h1 {\n      font-size: 24px;\n      margin-top: 0;\n    }\n    p {\n      font-size: 16px;\n      line-height: 1.5;\n      margin-bottom: 20px;\n    }\n    .navigate-link {\n      display: block;\n      width: 250px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      text-decoration: none;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n    .numeric-code {\n      display: block;\n      width: 100px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n  </style>\n</head>\n<body>\n<div class=\"container\">\n  <h1>\"tracker\" tracker detected content changes</h1>\n  <p>Current content: content</p>\n  <p>To learn more, visit the <b>Content trackers</b> page:</p>\n  <a class=\"navigate-link\" href=\"https://secutils.dev/ws/web_scraping__content\">Web Scraping → Content trackers</a>\n  <p>If the button above doesn't work, you can navigate to the following URL directly: </p>\n  <p>https://secutils.dev/ws/web_scraping__content</p>\n  <a href=\"https://secutils.dev/\"><img src=\"cid:secutils-logo\" alt=\"Secutils.dev logo\" width=\"89\" height=\"14\" /></a>\n</div>\n</body>\n</html>\n",
             ),
@@ -399,4 +659,327 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn can_compile_content_tracker_assertions_template_to_email(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let mut template = NotificationContentTemplate::WebPageContentTrackerAssertions {
+            tracker_name: "tracker".to_string(),
+            passed: false,
+        }
+        .compile_to_email(&api)
+        .await?;
+        template
+            .attachments
+            .as_mut()
+            .unwrap()
+            .iter_mut()
+            .for_each(|a| {
+                a.content = a.content.len().to_be_bytes().iter().cloned().collect_vec();
+            });
+
+        assert_debug_snapshot!(template, @r###"
+        EmailNotificationContent {
+            subject: "[Secutils.dev] Assertions failed: \"tracker\"",
+            text: "\"tracker\" tracker content assertions failed: web page content no longer meets one or more content assertions. Visit https://secutils.dev/ws/web_scraping__content to learn more.",
+            html: Some(
+                "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n  <title>\"tracker\" tracker content assertions failed</title>\n  <meta charset=\"utf-8\">\n  <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n  <style>\n    body {\n      font-family: Arial, sans-serif;\n      background-color: #f1f1f1;\n      margin: 0;\n      padding: 0;\n    }\n    .container {\n      max-width: 600px;\n      margin: 0 auto;\n      background-color: #fff;\n      padding: 20px;\n      border-radius: 5px;\n      box-shadow: 0 0 10px rgba(0, 0, 0, 0.1);\n    }\n    h1 {\n      font-size: 24px;\n      margin-top: 0;\n    }\n    p {\n      font-size: 16px;\n      line-height: 1.5;\n      margin-bottom: 20px;\n    }\n    .navigate-link {\n      display: block;\n      width: 250px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      text-decoration: none;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n    .numeric-code {\n      display: block;\n      width: 100px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n  </style>\n</head>\n<body>\n<div class=\"container\">\n  <h1>\"tracker\" tracker content assertions failed</h1>\n  <p>Web page content no longer meets one or more content assertions.</p>\n  <p>To learn more, visit the <b>Content trackers</b> page:</p>\n  <a class=\"navigate-link\" href=\"https://secutils.dev/ws/web_scraping__content\">Web Scraping → Content trackers</a>\n  <p>If the button above doesn't work, you can navigate to the following URL directly: </p>\n  <p>https://secutils.dev/ws/web_scraping__content</p>\n  <a href=\"https://secutils.dev/\"><img src=\"cid:secutils-logo\" alt=\"Secutils.dev logo\" width=\"89\" height=\"14\" /></a>\n</div>\n</body>\n</html>\n",
+            ),
+            attachments: Some(
+                [
+                    EmailNotificationAttachment {
+                        disposition: Inline(
+                            "secutils-logo",
+                        ),
+                        content_type: "image/png",
+                        content: [
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            15,
+                            165,
+                        ],
+                    },
+                ],
+            ),
+        }
+        "###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_compile_tracker_idle_deactivation_template_to_email(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let mut template = NotificationContentTemplate::WebPageTrackerIdleDeactivation {
+            tracker_kind: WebPageTrackerKind::WebPageResources,
+            tracker_name: "tracker".to_string(),
+            idle_period: Duration::from_secs(30 * 24 * 3600),
+        }
+        .compile_to_email(&api)
+        .await?;
+        template
+            .attachments
+            .as_mut()
+            .unwrap()
+            .iter_mut()
+            .for_each(|a| {
+                a.content = a.content.len().to_be_bytes().iter().cloned().collect_vec();
+            });
+
+        assert_debug_snapshot!(template, @r###"
+        EmailNotificationContent {
+            subject: "[Secutils.dev] Tracker disabled due to inactivity: \"tracker\"",
+            text: "\"tracker\" tracker has had neither changes nor any activity for 30days, so it has been automatically disabled. To keep tracking the web page, set the tracker schedule again at https://secutils.dev/ws/web_scraping__resources.",
+            html: Some(
+                "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n  <title>\"tracker\" tracker disabled due to inactivity</title>\n  <meta charset=\"utf-8\">\n  <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n  <style>\n    body {\n      font-family: Arial, sans-serif;\n      background-color: #f1f1f1;\n      margin: 0;\n      padding: 0;\n    }\n    .container {\n      max-width: 600px;\n      margin: 0 auto;\n      background-color: #fff;\n      padding: 20px;\n      border-radius: 5px;\n      box-shadow: 0 0 10px rgba(0, 0, 0, 0.1);\n    }\n    h1 {\n      font-size: 24px;\n      margin-top: 0;\n    }\n    p {\n      font-size: 16px;\n      line-height: 1.5;\n      margin-bottom: 20px;\n    }\n    .navigate-link {\n      display: block;\n      width: 250px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      text-decoration: none;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n    .numeric-code {\n      display: block;\n      width: 100px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n  </style>\n</head>\n<body>\n<div class=\"container\">\n  <h1>\"tracker\" tracker disabled due to inactivity</h1>\n  <p>The tracker has had neither changes nor any activity for 30days, so it has been automatically disabled.</p>\n  <p>To keep tracking the web page, set the tracker schedule again on the <b>Resources trackers</b> page:</p>\n  <a class=\"navigate-link\" href=\"https://secutils.dev/ws/web_scraping__resources\">Web Scraping → Resources trackers</a>\n  <p>If the button above doesn't work, you can navigate to the following URL directly: </p>\n  <p>https://secutils.dev/ws/web_scraping__resources</p>\n  <a href=\"https://secutils.dev/\"><img src=\"cid:secutils-logo\" alt=\"Secutils.dev logo\" width=\"89\" height=\"14\" /></a>\n</div>\n</body>\n</html>\n",
+            ),
+            attachments: Some(
+                [
+                    EmailNotificationAttachment {
+                        disposition: Inline(
+                            "secutils-logo",
+                        ),
+                        content_type: "image/png",
+                        content: [
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            15,
+                            165,
+                        ],
+                    },
+                ],
+            ),
+        }
+        "###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_compile_screenshot_tracker_changes_template_to_email(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let mut template = NotificationContentTemplate::WebPageScreenshotTrackerChanges {
+            tracker_name: "tracker".to_string(),
+            content: Ok("12.50%".to_string()),
+        }
+        .compile_to_email(&api)
+        .await?;
+        template
+            .attachments
+            .as_mut()
+            .unwrap()
+            .iter_mut()
+            .for_each(|a| {
+                a.content = a.content.len().to_be_bytes().iter().cloned().collect_vec();
+            });
+
+        assert_debug_snapshot!(template, @r###"
+        EmailNotificationContent {
+            subject: "[Secutils.dev] Change detected: \"tracker\"",
+            text: "\"tracker\" tracker detected visual changes (12.50% of the page changed). Visit https://secutils.dev/ws/web_scraping__screenshots to learn more.",
+            html: Some(
+                "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n  <title>\"tracker\" tracker detected visual changes</title>\n  <meta charset=\"utf-8\">\n  <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n  <style>\n    body {\n      font-family: Arial, sans-serif;\n      background-color: #f1f1f1;\n      margin: 0;\n      padding: 0;\n    }\n    .container {\n      max-width: 600px;\n      margin: 0 auto;\n      background-color: #fff;\n      padding: 20px;\n      border-radius: 5px;\n      box-shadow: 0 0 10px rgba(0, 0, 0, 0.1);\n    }\n    h1 {\n      font-size: 24px;\n      margin-top: 0;\n    }\n    p {\n      font-size: 16px;\n      line-height: 1.5;\n      margin-bottom: 20px;\n    }\n    .navigate-link {\n      display: block;\n      width: 250px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      text-decoration: none;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n    .numeric-code {\n      display: block;\n      width: 100px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n  </style>\n</head>\n<body>\n<div class=\"container\">\n  <h1>\"tracker\" tracker detected visual changes</h1>\n  <p>Share of the page that changed visually: 12.50%</p>\n  <p>To learn more, visit the <b>Screenshot trackers</b> page:</p>\n  <a class=\"navigate-link\" href=\"https://secutils.dev/ws/web_scraping__screenshots\">Web Scraping → Screenshot trackers</a>\n  <p>If the button above doesn't work, you can navigate to the following URL directly: </p>\n  <p>https://secutils.dev/ws/web_scraping__screenshots</p>\n  <a href=\"https://secutils.dev/\"><img src=\"cid:secutils-logo\" alt=\"Secutils.dev logo\" width=\"89\" height=\"14\" /></a>\n</div>\n</body>\n</html>\n",
+            ),
+            attachments: Some(
+                [
+                    EmailNotificationAttachment {
+                        disposition: Inline(
+                            "secutils-logo",
+                        ),
+                        content_type: "image/png",
+                        content: [
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            15,
+                            165,
+                        ],
+                    },
+                ],
+            ),
+        }
+        "###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_compile_dns_records_tracker_changes_template_to_email(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let mut template = NotificationContentTemplate::WebPageDnsRecordsTrackerChanges {
+            tracker_name: "tracker".to_string(),
+            content: Ok("1 added, 1 removed".to_string()),
+        }
+        .compile_to_email(&api)
+        .await?;
+        template
+            .attachments
+            .as_mut()
+            .unwrap()
+            .iter_mut()
+            .for_each(|a| {
+                a.content = a.content.len().to_be_bytes().iter().cloned().collect_vec();
+            });
+
+        assert_debug_snapshot!(template, @r###"
+        EmailNotificationContent {
+            subject: "[Secutils.dev] Change detected: \"tracker\"",
+            text: "\"tracker\" tracker detected DNS records changes (1 added, 1 removed). Visit https://secutils.dev/ws/web_scraping__dns to learn more.",
+            html: Some(
+                "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n  <title>\"tracker\" tracker detected DNS records changes</title>\n  <meta charset=\"utf-8\">\n  <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n  <style>\n    body {\n      font-family: Arial, sans-serif;\n      background-color: #f1f1f1;\n      margin: 0;\n      padding: 0;\n    }\n    .container {\n      max-width: 600px;\n      margin: 0 auto;\n      background-color: #fff;\n      padding: 20px;\n      border-radius: 5px;\n      box-shadow: 0 0 10px rgba(0, 0, 0, 0.1);\n    }\n    h1 {\n      font-size: 24px;\n      margin-top: 0;\n    }\n    p {\n      font-size: 16px;\n      line-height: 1.5;\n      margin-bottom: 20px;\n    }\n    .navigate-link {\n      display: block;\n      width: 250px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      text-decoration: none;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n    .numeric-code {\n      display: block;\n      width: 100px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n  </style>\n</head>\n<body>\n<div class=\"container\">\n  <h1>\"tracker\" tracker detected DNS records changes</h1>\n  <p>Changed DNS records: 1 added, 1 removed</p>\n  <p>To learn more, visit the <b>DNS trackers</b> page:</p>\n  <a class=\"navigate-link\" href=\"https://secutils.dev/ws/web_scraping__dns\">Web Scraping → DNS trackers</a>\n  <p>If the button above doesn't work, you can navigate to the following URL directly: </p>\n  <p>https://secutils.dev/ws/web_scraping__dns</p>\n  <a href=\"https://secutils.dev/\"><img src=\"cid:secutils-logo\" alt=\"Secutils.dev logo\" width=\"89\" height=\"14\" /></a>\n</div>\n</body>\n</html>\n",
+            ),
+            attachments: Some(
+                [
+                    EmailNotificationAttachment {
+                        disposition: Inline(
+                            "secutils-logo",
+                        ),
+                        content_type: "image/png",
+                        content: [
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            15,
+                            165,
+                        ],
+                    },
+                ],
+            ),
+        }
+        "###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_compile_tracker_changes_summary_template_to_email(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let user = mock_user()?;
+        api.db.insert_user(&user).await?;
+
+        let tracker = WebPageTracker::<WebPageContentTrackerTag> {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "tracker".to_string(),
+            url: "https://secutils.dev".parse()?,
+            settings: WebPageTrackerSettings {
+                revisions: 3,
+                delay: Default::default(),
+                notification_cooldown: Some(Duration::from_secs(3600)),
+                ..Default::default()
+            },
+            user_id: user.id,
+            job_id: None,
+            job_config: None,
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            meta: None,
+        };
+        api.db
+            .web_scraping(user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+
+        // Only revisions created after the last notification should be counted.
+        for (index, timestamp) in [946720800, 946720900, 946721000].into_iter().enumerate() {
+            api.db
+                .web_scraping(user.id)
+                .insert_web_page_tracker_history_revision::<WebPageContentTrackerTag>(
+                    &WebPageDataRevision {
+                        id: Uuid::now_v7(),
+                        tracker_id: tracker.id,
+                        data: format!("content-{index}"),
+                        created_at: OffsetDateTime::from_unix_timestamp(timestamp)?,
+                        note: None,
+                        acknowledgment: None,
+                        diff_stats: None,
+                        assertions_passed: None,
+                    },
+                )
+                .await?;
+        }
+
+        let mut template = NotificationContentTemplate::WebPageTrackerChangesSummary {
+            user_id: user.id,
+            tracker_id: tracker.id,
+            tracker_kind: WebPageTrackerKind::WebPageContent,
+            tracker_name: "tracker".to_string(),
+            since: OffsetDateTime::from_unix_timestamp(946720850)?,
+        }
+        .compile_to_email(&api)
+        .await?;
+        template
+            .attachments
+            .as_mut()
+            .unwrap()
+            .iter_mut()
+            .for_each(|a| {
+                a.content = a.content.len().to_be_bytes().iter().cloned().collect_vec();
+            });
+
+        assert_debug_snapshot!(template, @r###"
+        EmailNotificationContent {
+            subject: "[Secutils.dev] Changes detected: \"tracker\"",
+            text: "\"tracker\" tracker detected 2 changes since the last notification. Visit https://secutils.dev/ws/web_scraping__content to learn more.",
+            html: Some(
+                "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n  <title>\"tracker\" tracker detected 2 changes</title>\n  <meta charset=\"utf-8\">\n  <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n  <style>\n    body {\n      font-family: Arial, sans-serif;\n      background-color: #f1f1f1;\n      margin: 0;\n      padding: 0;\n    }\n    .container {\n      max-width: 600px;\n      margin: 0 auto;\n      background-color: #fff;\n      padding: 20px;\n      border-radius: 5px;\n      box-shadow: 0 0 10px rgba(0, 0, 0, 0.1);\n    }\n    h1 {\n      font-size: 24px;\n      margin-top: 0;\n    }\n    p {\n      font-size: 16px;\n      line-height: 1.5;\n      margin-bottom: 20px;\n    }\n    .navigate-link {\n      display: block;\n      width: 250px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      text-decoration: none;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n    .numeric-code {\n      display: block;\n      width: 100px;\n      margin: auto;\n      padding: 10px 20px;\n      text-align: center;\n      color: #5e1d3f;\n      background-color: #fed047;\n      border-radius: 5px;\n      font-weight: bold;\n    }\n  </style>\n</head>\n<body>\n<div class=\"container\">\n  <h1>\"tracker\" tracker detected 2 changes</h1>\n  <p>The tracker detected 2 changes since the last notification.</p>\n  <p>To learn more, visit the <b>Content trackers</b> page:</p>\n  <a class=\"navigate-link\" href=\"https://secutils.dev/ws/web_scraping__content\">Web Scraping → Content trackers</a>\n  <p>If the button above doesn't work, you can navigate to the following URL directly: </p>\n  <p>https://secutils.dev/ws/web_scraping__content</p>\n  <a href=\"https://secutils.dev/\"><img src=\"cid:secutils-logo\" alt=\"Secutils.dev logo\" width=\"89\" height=\"14\" /></a>\n</div>\n</body>\n</html>\n",
+            ),
+            attachments: Some(
+                [
+                    EmailNotificationAttachment {
+                        disposition: Inline(
+                            "secutils-logo",
+                        ),
+                        content_type: "image/png",
+                        content: [
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            0,
+                            15,
+                            165,
+                        ],
+                    },
+                ],
+            ),
+        }
+        "###
+        );
+
+        Ok(())
+    }
 }
@@ -2,8 +2,8 @@ use crate::{
     api::Api,
     network::{DnsResolver, EmailTransport},
     notifications::{
-        notification_content_template::SECUTILS_LOGO_BYTES, EmailNotificationAttachment,
-        EmailNotificationContent,
+        notification_content_template::{render_email_templates, SECUTILS_LOGO_BYTES},
+        EmailNotificationAttachment, EmailNotificationContent,
     },
 };
 use serde_json::json;
@@ -27,21 +27,24 @@ pub async fn compile_to_email<DR: DnsResolver, ET: EmailTransport>(
         urlencoding::encode(&flow_id.as_hyphenated().to_string())
     );
 
+    let (text, html) = render_email_templates(
+        api,
+        "account_activation_email",
+        &json!({
+            "encoded_activation_link": encoded_activation_link,
+            "encoded_activation_code": encoded_code,
+            "home_link": api.config.public_url.as_str()
+        }),
+    )?;
+
     Ok(EmailNotificationContent::html_with_attachments(
         "Activate your Secutils.dev account",
-        format!("To activate your Secutils.dev account, please use the following code: {encoded_code}. Alternatively, navigate to the following URL in your browser: {encoded_activation_link}"),
-        api.templates.render(
-            "account_activation_email", 
-            &json!({
-                "encoded_activation_link": encoded_activation_link,
-                "encoded_activation_code": encoded_code,
-                "home_link": api.config.public_url.as_str()
-            })
-        )?,
+        text,
+        html,
         vec![EmailNotificationAttachment::inline(
             "secutils-logo",
             "image/png",
             SECUTILS_LOGO_BYTES.to_vec(),
-        )]
+        )],
     ))
 }
@@ -0,0 +1,44 @@
+use crate::{
+    api::Api,
+    network::{DnsResolver, EmailTransport},
+    notifications::{
+        notification_content_template::{render_email_templates, SECUTILS_LOGO_BYTES},
+        EmailNotificationAttachment, EmailNotificationContent,
+    },
+};
+use serde_json::json;
+
+/// Compiles web page content tracker assertions template as an email.
+pub async fn compile_to_email<DR: DnsResolver, ET: EmailTransport>(
+    api: &Api<DR, ET>,
+    tracker_name: &str,
+    passed: bool,
+) -> anyhow::Result<EmailNotificationContent> {
+    let back_link = format!("{}ws/web_scraping__content", api.config.public_url);
+
+    let (text, html) = render_email_templates(
+        api,
+        "web_page_content_tracker_assertions_email",
+        &json!({
+            "tracker_name": tracker_name,
+            "passed": passed,
+            "back_link": back_link,
+            "home_link": api.config.public_url.as_str(),
+        }),
+    )?;
+
+    Ok(EmailNotificationContent::html_with_attachments(
+        if passed {
+            format!("[Secutils.dev] Assertions passed: \"{}\"", tracker_name)
+        } else {
+            format!("[Secutils.dev] Assertions failed: \"{}\"", tracker_name)
+        },
+        text,
+        html,
+        vec![EmailNotificationAttachment::inline(
+            "secutils-logo",
+            "image/png",
+            SECUTILS_LOGO_BYTES.to_vec(),
+        )],
+    ))
+}
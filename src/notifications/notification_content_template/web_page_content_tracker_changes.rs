@@ -2,8 +2,8 @@ use crate::{
     api::Api,
     network::{DnsResolver, EmailTransport},
     notifications::{
-        notification_content_template::SECUTILS_LOGO_BYTES, EmailNotificationAttachment,
-        EmailNotificationContent,
+        notification_content_template::{render_email_templates, SECUTILS_LOGO_BYTES},
+        EmailNotificationAttachment, EmailNotificationContent,
     },
 };
 use serde_json::json;
@@ -15,14 +15,11 @@ pub async fn compile_to_email<DR: DnsResolver, ET: EmailTransport>(
     content: &Result<String, String>,
 ) -> anyhow::Result<EmailNotificationContent> {
     let back_link = format!("{}ws/web_scraping__content", api.config.public_url);
-    let (subject, text, html) = match content {
+    let (subject, (text, html)) = match content {
         Ok(content) => (
             format!("[Secutils.dev] Change detected: \"{}\"", tracker_name),
-            format!(
-                "\"{}\" tracker detected content changes. Visit {} to learn more.",
-                tracker_name, back_link
-            ),
-            api.templates.render(
+            render_email_templates(
+                api,
                 "web_page_content_tracker_changes_email",
                 &json!({
                     "tracker_name": tracker_name,
@@ -34,20 +31,17 @@ pub async fn compile_to_email<DR: DnsResolver, ET: EmailTransport>(
         ),
         Err(error_message) => (
             format!("[Secutils.dev] Check failed: \"{}\"", tracker_name),
-            format!(
-                "\"{}\" tracker failed to check for content changes due to the following error: {error_message}. Visit {} to learn more.",
-                tracker_name, back_link
-            ),
-            api.templates.render(
+            render_email_templates(
+                api,
                 "web_page_content_tracker_changes_error_email",
                 &json!({
-                "tracker_name": tracker_name,
-                "error_message": error_message,
-                "back_link": back_link,
-                "home_link": api.config.public_url.as_str(),
-            }),
-            )?
-        )
+                    "tracker_name": tracker_name,
+                    "error_message": error_message,
+                    "back_link": back_link,
+                    "home_link": api.config.public_url.as_str(),
+                }),
+            )?,
+        ),
     };
 
     Ok(EmailNotificationContent::html_with_attachments(
@@ -0,0 +1,48 @@
+use crate::{
+    api::Api,
+    network::{DnsResolver, EmailTransport},
+    notifications::{
+        notification_content_template::{render_email_templates, SECUTILS_LOGO_BYTES},
+        EmailNotificationAttachment, EmailNotificationContent,
+    },
+    utils::web_scraping::web_page_content_truncate_diff,
+};
+use serde_json::json;
+use std::borrow::Cow;
+
+/// Compiles web page content tracker response changes template as an email.
+pub async fn compile_to_email<DR: DnsResolver, ET: EmailTransport>(
+    api: &Api<DR, ET>,
+    tracker_name: &str,
+    changes: &str,
+    content: &str,
+) -> anyhow::Result<EmailNotificationContent> {
+    let back_link = format!("{}ws/web_scraping__content", api.config.public_url);
+    let changes = match api.config.utils.web_scraping.max_diff_size {
+        Some(max_diff_size) => web_page_content_truncate_diff(changes, max_diff_size),
+        None => Cow::Borrowed(changes),
+    };
+
+    let (text, html) = render_email_templates(
+        api,
+        "web_page_content_tracker_response_changes_email",
+        &json!({
+            "tracker_name": tracker_name,
+            "changes": changes,
+            "content": content,
+            "back_link": back_link,
+            "home_link": api.config.public_url.as_str(),
+        }),
+    )?;
+
+    Ok(EmailNotificationContent::html_with_attachments(
+        format!("[Secutils.dev] Change detected: \"{}\"", tracker_name),
+        text,
+        html,
+        vec![EmailNotificationAttachment::inline(
+            "secutils-logo",
+            "image/png",
+            SECUTILS_LOGO_BYTES.to_vec(),
+        )],
+    ))
+}
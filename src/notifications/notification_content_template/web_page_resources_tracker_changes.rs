@@ -2,8 +2,8 @@ use crate::{
     api::Api,
     network::{DnsResolver, EmailTransport},
     notifications::{
-        notification_content_template::SECUTILS_LOGO_BYTES, EmailNotificationAttachment,
-        EmailNotificationContent,
+        notification_content_template::{render_email_templates, SECUTILS_LOGO_BYTES},
+        EmailNotificationAttachment, EmailNotificationContent,
     },
 };
 use serde_json::json;
@@ -16,31 +16,25 @@ pub async fn compile_to_email<DR: DnsResolver, ET: EmailTransport>(
 ) -> anyhow::Result<EmailNotificationContent> {
     let back_link = format!("{}ws/web_scraping__resources", api.config.public_url);
 
-    let (subject, text, html) = match content {
-      Ok(changes_count) =>    (
-          format!("[Secutils.dev] Change detected: \"{}\"", tracker_name),
-          format!(
-              "\"{}\" tracker detected {} changes in resources. Visit {} to learn more.",
-              tracker_name, changes_count, back_link
-          ),
-          api.templates.render(
-              "web_page_resources_tracker_changes_email",
-              &json!({
+    let (subject, (text, html)) = match content {
+        Ok(changes_count) => (
+            format!("[Secutils.dev] Change detected: \"{}\"", tracker_name),
+            render_email_templates(
+                api,
+                "web_page_resources_tracker_changes_email",
+                &json!({
                     "tracker_name": tracker_name,
                     "changes_count": changes_count,
                     "back_link": back_link,
                     "home_link": api.config.public_url.as_str(),
                 }),
-          )?,
-      ),
+            )?,
+        ),
 
-        Err(error_message) =>  (
+        Err(error_message) => (
             format!("[Secutils.dev] Check failed: \"{}\"", tracker_name),
-            format!(
-                "\"{}\" tracker failed to check for changes in resources due to the following error: {error_message}. Visit {} to learn more.",
-                tracker_name, back_link
-            ),
-            api.templates.render(
+            render_email_templates(
+                api,
                 "web_page_resources_tracker_changes_error_email",
                 &json!({
                     "tracker_name": tracker_name,
@@ -48,8 +42,8 @@ pub async fn compile_to_email<DR: DnsResolver, ET: EmailTransport>(
                     "back_link": back_link,
                     "home_link": api.config.public_url.as_str(),
                 }),
-            )?
-        )
+            )?,
+        ),
     };
 
     Ok(EmailNotificationContent::html_with_attachments(
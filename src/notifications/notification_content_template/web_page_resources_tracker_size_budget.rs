@@ -0,0 +1,42 @@
+use crate::{
+    api::Api,
+    network::{DnsResolver, EmailTransport},
+    notifications::{
+        notification_content_template::{render_email_templates, SECUTILS_LOGO_BYTES},
+        EmailNotificationAttachment, EmailNotificationContent,
+    },
+};
+use serde_json::json;
+
+/// Compiles web page resources tracker size budget template as an email.
+pub async fn compile_to_email<DR: DnsResolver, ET: EmailTransport>(
+    api: &Api<DR, ET>,
+    tracker_name: &str,
+    total_size: usize,
+    previous_total_size: Option<usize>,
+) -> anyhow::Result<EmailNotificationContent> {
+    let back_link = format!("{}ws/web_scraping__resources", api.config.public_url);
+
+    let (text, html) = render_email_templates(
+        api,
+        "web_page_resources_tracker_size_budget_email",
+        &json!({
+            "tracker_name": tracker_name,
+            "total_size": total_size,
+            "previous_total_size": previous_total_size,
+            "back_link": back_link,
+            "home_link": api.config.public_url.as_str(),
+        }),
+    )?;
+
+    Ok(EmailNotificationContent::html_with_attachments(
+        format!("[Secutils.dev] Size budget exceeded: \"{}\"", tracker_name),
+        text,
+        html,
+        vec![EmailNotificationAttachment::inline(
+            "secutils-logo",
+            "image/png",
+            SECUTILS_LOGO_BYTES.to_vec(),
+        )],
+    ))
+}
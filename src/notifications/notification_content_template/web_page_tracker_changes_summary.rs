@@ -0,0 +1,63 @@
+use crate::{
+    api::Api,
+    network::{DnsResolver, EmailTransport},
+    notifications::{
+        notification_content_template::{render_email_templates, SECUTILS_LOGO_BYTES},
+        EmailNotificationAttachment, EmailNotificationContent,
+    },
+    users::UserId,
+    utils::web_scraping::WebPageTrackerKind,
+};
+use serde_json::json;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Compiles web page tracker changes summary template as an email.
+pub async fn compile_to_email<DR: DnsResolver, ET: EmailTransport>(
+    api: &Api<DR, ET>,
+    user_id: UserId,
+    tracker_id: Uuid,
+    tracker_kind: WebPageTrackerKind,
+    tracker_name: &str,
+    since: OffsetDateTime,
+) -> anyhow::Result<EmailNotificationContent> {
+    let (page_path, page_name) = match tracker_kind {
+        WebPageTrackerKind::WebPageResources => ("web_scraping__resources", "Resources trackers"),
+        WebPageTrackerKind::WebPageContent => ("web_scraping__content", "Content trackers"),
+        WebPageTrackerKind::WebPageScreenshot => {
+            ("web_scraping__screenshots", "Screenshot trackers")
+        }
+        WebPageTrackerKind::WebPageDnsRecords => ("web_scraping__dns", "DNS trackers"),
+    };
+    let back_link = format!("{}ws/{page_path}", api.config.public_url);
+
+    // Count all changes that were recorded since the last notification.
+    let changes_count = api
+        .db
+        .web_scraping(user_id)
+        .count_web_page_tracker_history_revisions(tracker_id, since)
+        .await?;
+
+    let (text, html) = render_email_templates(
+        api,
+        "web_page_tracker_changes_summary_email",
+        &json!({
+            "tracker_name": tracker_name,
+            "changes_count": changes_count,
+            "page_name": page_name,
+            "back_link": back_link,
+            "home_link": api.config.public_url.as_str(),
+        }),
+    )?;
+
+    Ok(EmailNotificationContent::html_with_attachments(
+        format!("[Secutils.dev] Changes detected: \"{}\"", tracker_name),
+        text,
+        html,
+        vec![EmailNotificationAttachment::inline(
+            "secutils-logo",
+            "image/png",
+            SECUTILS_LOGO_BYTES.to_vec(),
+        )],
+    ))
+}
@@ -0,0 +1,55 @@
+use crate::{
+    api::Api,
+    network::{DnsResolver, EmailTransport},
+    notifications::{
+        notification_content_template::{render_email_templates, SECUTILS_LOGO_BYTES},
+        EmailNotificationAttachment, EmailNotificationContent,
+    },
+    utils::web_scraping::WebPageTrackerKind,
+};
+use serde_json::json;
+use std::time::Duration;
+
+/// Compiles web page tracker idle deactivation template as an email.
+pub async fn compile_to_email<DR: DnsResolver, ET: EmailTransport>(
+    api: &Api<DR, ET>,
+    tracker_kind: WebPageTrackerKind,
+    tracker_name: &str,
+    idle_period: Duration,
+) -> anyhow::Result<EmailNotificationContent> {
+    let (page_path, page_name) = match tracker_kind {
+        WebPageTrackerKind::WebPageResources => ("web_scraping__resources", "Resources trackers"),
+        WebPageTrackerKind::WebPageContent => ("web_scraping__content", "Content trackers"),
+        WebPageTrackerKind::WebPageScreenshot => {
+            ("web_scraping__screenshots", "Screenshot trackers")
+        }
+        WebPageTrackerKind::WebPageDnsRecords => ("web_scraping__dns", "DNS trackers"),
+    };
+    let back_link = format!("{}ws/{page_path}", api.config.public_url);
+
+    let (text, html) = render_email_templates(
+        api,
+        "web_page_tracker_idle_deactivation_email",
+        &json!({
+            "tracker_name": tracker_name,
+            "idle_period": humantime::format_duration(idle_period).to_string(),
+            "page_name": page_name,
+            "back_link": back_link,
+            "home_link": api.config.public_url.as_str(),
+        }),
+    )?;
+
+    Ok(EmailNotificationContent::html_with_attachments(
+        format!(
+            "[Secutils.dev] Tracker disabled due to inactivity: \"{}\"",
+            tracker_name
+        ),
+        text,
+        html,
+        vec![EmailNotificationAttachment::inline(
+            "secutils-logo",
+            "image/png",
+            SECUTILS_LOGO_BYTES.to_vec(),
+        )],
+    ))
+}
@@ -0,0 +1,66 @@
+use crate::notifications::{NotificationContent, NotificationDestination};
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Defines a notification that couldn't be delivered after all attempts.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDeadLetter {
+    /// Unique id of the dead letter.
+    pub id: Uuid,
+    /// The destination of the notification.
+    #[serde(skip_serializing)]
+    pub destination: NotificationDestination,
+    /// The content of the notification.
+    pub content: NotificationContent,
+    /// The time at which the notification was scheduled to be sent, in UTC.
+    #[serde(with = "time::serde::timestamp")]
+    pub scheduled_at: OffsetDateTime,
+    /// The time at which the last delivery attempt failed, in UTC.
+    #[serde(with = "time::serde::timestamp")]
+    pub failed_at: OffsetDateTime,
+    /// The number of failed delivery attempts.
+    pub attempts: u32,
+    /// The error of the last failed delivery attempt.
+    pub last_error: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::notifications::{
+        NotificationContent, NotificationDeadLetter, NotificationDestination,
+    };
+    use insta::assert_json_snapshot;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let dead_letter = NotificationDeadLetter {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            destination: NotificationDestination::User(
+                uuid!("00000000-0000-0000-0000-000000000002").into(),
+            ),
+            content: NotificationContent::Text("abc".to_string()),
+            scheduled_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            failed_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            attempts: 3,
+            last_error: "SMTP is not configured.".to_string(),
+        };
+        assert_json_snapshot!(dead_letter, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "content": {
+            "Text": "abc"
+          },
+          "scheduledAt": 946720800,
+          "failedAt": 946720810,
+          "attempts": 3,
+          "lastError": "SMTP is not configured."
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use crate::users::UserId;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// Defines a notification destination.
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
@@ -10,6 +11,8 @@ pub enum NotificationDestination {
     Email(String),
     /// Notification will be logged in the server log.
     ServerLog,
+    /// Notification will be delivered to the webhook configured for the user's web page tracker.
+    TrackerWebhook { user_id: UserId, tracker_id: Uuid },
 }
 
 #[cfg(test)]
@@ -33,6 +36,16 @@ mod tests {
             postcard::to_stdvec(&NotificationDestination::ServerLog)?,
             vec![2]
         );
+        assert_eq!(
+            postcard::to_stdvec(&NotificationDestination::TrackerWebhook {
+                user_id: uuid!("00000000-0000-0000-0000-000000000001").into(),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002")
+            })?,
+            vec![
+                3, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 16, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 0, 0, 2
+            ]
+        );
         Ok(())
     }
 
@@ -52,6 +65,16 @@ mod tests {
             postcard::from_bytes::<NotificationDestination>(&[2])?,
             NotificationDestination::ServerLog
         );
+        assert_eq!(
+            postcard::from_bytes::<NotificationDestination>(&[
+                3, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 16, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 0, 0, 2
+            ])?,
+            NotificationDestination::TrackerWebhook {
+                user_id: uuid!("00000000-0000-0000-0000-000000000001").into(),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002")
+            }
+        );
         Ok(())
     }
 }
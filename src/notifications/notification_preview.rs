@@ -0,0 +1,70 @@
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+/// Defines a rendered notification that would be delivered through every configured channel.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationPreview {
+    /// Subject of the email notification.
+    pub subject: String,
+    /// Plain-text version of the email notification.
+    pub text: String,
+    /// HTML version of the email notification, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+    /// Payload delivered to the web page tracker webhook, if the webhook is configured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_payload: Option<JsonValue>,
+    /// Custom HTTP headers delivered to the web page tracker webhook along with the payload, if
+    /// any. Header values are redacted since they may contain credentials.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_headers: Option<Vec<(String, String)>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::notifications::NotificationPreview;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(NotificationPreview {
+            subject: "subject".to_string(),
+            text: "text".to_string(),
+            html: None,
+            webhook_payload: None,
+            webhook_headers: None,
+        }, @r###"
+        {
+          "subject": "subject",
+          "text": "text"
+        }
+        "###);
+
+        assert_json_snapshot!(NotificationPreview {
+            subject: "subject".to_string(),
+            text: "text".to_string(),
+            html: Some("html".to_string()),
+            webhook_payload: Some(json!({ "subject": "subject" })),
+            webhook_headers: Some(vec![("Authorization".to_string(), "[REDACTED]".to_string())]),
+        }, @r###"
+        {
+          "subject": "subject",
+          "text": "text",
+          "html": "html",
+          "webhookPayload": {
+            "subject": "subject"
+          },
+          "webhookHeaders": [
+            [
+              "Authorization",
+              "[REDACTED]"
+            ]
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+use crate::notifications::EmailNotificationContent;
+use serde_json::{json, Value as JsonValue};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Builds the JSON payload of the notification delivered to the web page tracker webhook.
+pub fn webhook_notification_payload(
+    tracker_id: Uuid,
+    email: &EmailNotificationContent,
+    timestamp: OffsetDateTime,
+) -> JsonValue {
+    json!({
+        "trackerId": tracker_id,
+        "subject": email.subject,
+        "text": email.text,
+        "timestamp": timestamp.unix_timestamp(),
+    })
+}
@@ -0,0 +1,165 @@
+use rand::Rng;
+use std::time::Duration;
+use time::{format_description::well_known::Rfc2822, OffsetDateTime};
+
+/// Defines a maximum number of attempts to deliver a webhook notification before it's moved to
+/// the dead letters.
+pub const MAX_WEBHOOK_NOTIFICATION_DELIVERY_ATTEMPTS: u32 = 5;
+
+/// Delay before the first retry of the failed webhook notification delivery, every subsequent
+/// retry doubles the delay.
+const WEBHOOK_RETRY_BASE_DELAY: Duration = Duration::from_secs(30);
+
+/// Maximum delay between webhook notification delivery attempts, including the delay requested by
+/// the receiver via `Retry-After` header.
+const WEBHOOK_RETRY_MAX_DELAY: Duration = Duration::from_secs(3600);
+
+/// Error returned when webhook receiver responds with non-successful HTTP status.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("Webhook receiver responded with {status} HTTP status.")]
+pub struct WebhookDeliveryError {
+    /// HTTP status code returned by the webhook receiver.
+    pub status: u16,
+    /// Delay requested by the webhook receiver via `Retry-After` header (only for `429` and `503`
+    /// HTTP statuses).
+    pub retry_after: Option<Duration>,
+}
+
+impl WebhookDeliveryError {
+    /// Creates a new error for the specified HTTP status and raw `Retry-After` header value.
+    /// `Retry-After` is only respected for `429 Too Many Requests` and `503 Service Unavailable`
+    /// responses, and can be either a number of seconds or an HTTP date.
+    pub fn new(status: u16, retry_after: Option<&str>, now: OffsetDateTime) -> Self {
+        let retry_after = retry_after
+            .filter(|_| status == 429 || status == 503)
+            .and_then(|retry_after| {
+                let retry_after = retry_after.trim();
+                if let Ok(seconds) = retry_after.parse::<u64>() {
+                    return Some(Duration::from_secs(seconds));
+                }
+
+                let retry_at = OffsetDateTime::parse(retry_after, &Rfc2822).ok()?;
+                Some((retry_at - now).try_into().unwrap_or(Duration::ZERO))
+            });
+
+        Self {
+            status,
+            retry_after,
+        }
+    }
+}
+
+/// Calculates the delay before the next attempt to deliver a webhook notification after the
+/// specified number of failed attempts. The delay grows exponentially with every attempt and
+/// includes random jitter (the delay is picked within `[delay / 2, delay]` range) so that
+/// retries of the notifications that failed at the same time don't hit the receiver at once. The
+/// delay is never shorter than the delay requested by the receiver via `Retry-After` header.
+pub fn webhook_retry_delay(
+    failed_attempts: u32,
+    retry_after: Option<Duration>,
+    rng: &mut impl Rng,
+) -> Duration {
+    let backoff = WEBHOOK_RETRY_BASE_DELAY
+        .checked_mul(2u32.saturating_pow(failed_attempts.saturating_sub(1)))
+        .unwrap_or(WEBHOOK_RETRY_MAX_DELAY)
+        .min(WEBHOOK_RETRY_MAX_DELAY);
+    let backoff = rng.gen_range(backoff / 2..=backoff);
+
+    retry_after
+        .map_or(backoff, |retry_after| retry_after.max(backoff))
+        .min(WEBHOOK_RETRY_MAX_DELAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{webhook_retry_delay, WebhookDeliveryError};
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::time::Duration;
+    use time::OffsetDateTime;
+
+    #[test]
+    fn properly_calculates_retry_delay() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            for (failed_attempts, min, max) in [
+                (1, 15, 30),
+                (2, 30, 60),
+                (3, 60, 120),
+                (4, 120, 240),
+                (7, 960, 1920),
+                (8, 1800, 3600),
+                (100, 1800, 3600),
+            ] {
+                let delay = webhook_retry_delay(failed_attempts, None, &mut rng);
+                assert!(
+                    delay >= Duration::from_secs(min) && delay <= Duration::from_secs(max),
+                    "Attempt {failed_attempts}: {delay:?}"
+                );
+            }
+
+            // Retry-After is respected, but capped by the maximum delay.
+            assert_eq!(
+                webhook_retry_delay(1, Some(Duration::from_secs(120)), &mut rng),
+                Duration::from_secs(120)
+            );
+            assert_eq!(
+                webhook_retry_delay(1, Some(Duration::from_secs(7200)), &mut rng),
+                Duration::from_secs(3600)
+            );
+
+            // Backoff wins if it's longer than Retry-After.
+            assert!(
+                webhook_retry_delay(4, Some(Duration::from_secs(1)), &mut rng)
+                    >= Duration::from_secs(120)
+            );
+        }
+    }
+
+    #[test]
+    fn properly_parses_retry_after() -> anyhow::Result<()> {
+        // Sun, 06 Nov 1994 08:49:37 GMT
+        let now = OffsetDateTime::from_unix_timestamp(784111777)?;
+
+        assert_eq!(
+            WebhookDeliveryError::new(429, Some("120"), now),
+            WebhookDeliveryError {
+                status: 429,
+                retry_after: Some(Duration::from_secs(120))
+            }
+        );
+        assert_eq!(
+            WebhookDeliveryError::new(503, Some("Sun, 06 Nov 1994 08:50:37 GMT"), now),
+            WebhookDeliveryError {
+                status: 503,
+                retry_after: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            WebhookDeliveryError::new(503, Some("Sun, 06 Nov 1994 08:48:37 GMT"), now),
+            WebhookDeliveryError {
+                status: 503,
+                retry_after: Some(Duration::ZERO)
+            }
+        );
+        assert_eq!(
+            WebhookDeliveryError::new(503, Some("soon"), now),
+            WebhookDeliveryError {
+                status: 503,
+                retry_after: None
+            }
+        );
+        assert_eq!(
+            WebhookDeliveryError::new(500, Some("120"), now),
+            WebhookDeliveryError {
+                status: 500,
+                retry_after: None
+            }
+        );
+        assert_eq!(
+            WebhookDeliveryError::new(429, None, now).to_string(),
+            "Webhook receiver responded with 429 HTTP status."
+        );
+
+        Ok(())
+    }
+}
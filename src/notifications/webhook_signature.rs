@@ -0,0 +1,172 @@
+//! Webhook notifications are signed so that the receiver can verify that the payload was sent by
+//! Secutils.dev and wasn't modified or replayed. The signing scheme mirrors the one used by GitHub:
+//!
+//! 1. Secutils.dev includes the current Unix timestamp (in seconds) into the
+//!    `X-Secutils-Timestamp` header.
+//! 2. The signed content is the timestamp, followed by the `.` character, followed by the raw
+//!    request body: `{timestamp}.{body}`.
+//! 3. The signature is the hex-encoded HMAC-SHA256 of the signed content computed with the webhook
+//!    secret as the key, prefixed with `sha256=`, and included into the `X-Secutils-Signature`
+//!    header: `X-Secutils-Signature: sha256=5257a869...`.
+//! 4. While the previous webhook secret is still active after the secret rotation, the payload is
+//!    additionally signed with the previous secret, and the header includes comma-separated
+//!    signatures, the one computed with the new secret first:
+//!    `X-Secutils-Signature: sha256=5257a869...,sha256=9a0f4c12...`.
+//!
+//! To verify the payload, the receiver should compute the signature the same way, compare it with
+//! every signature from the header using a constant-time comparison, and reject requests with
+//! timestamps that are too far from the current time (e.g. more than 5 minutes) to prevent replay
+//! attacks.
+
+use anyhow::Context;
+use openssl::{hash::MessageDigest, memcmp, pkey::PKey, sign::Signer};
+
+/// Name of the HTTP header that contains webhook payload signature.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Secutils-Signature";
+
+/// Name of the HTTP header that contains the Unix timestamp the webhook payload was signed at.
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Secutils-Timestamp";
+
+/// Separator of the webhook payload signatures if the payload is signed with multiple secrets.
+pub const WEBHOOK_SIGNATURES_SEPARATOR: &str = ",";
+
+/// Prefix of the webhook payload signature that identifies the signature algorithm.
+const WEBHOOK_SIGNATURE_PREFIX: &str = "sha256=";
+
+/// Computes the signature of the webhook payload sent at the specified Unix timestamp.
+pub fn sign_webhook_payload(
+    secret: &[u8],
+    timestamp: i64,
+    payload: &[u8],
+) -> anyhow::Result<String> {
+    let key = PKey::hmac(secret)?;
+    let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+    signer.update(format!("{timestamp}.").as_bytes())?;
+    signer.update(payload)?;
+
+    Ok(format!(
+        "{WEBHOOK_SIGNATURE_PREFIX}{}",
+        hex::encode(
+            signer
+                .sign_to_vec()
+                .context("Failed to sign webhook payload.")?
+        )
+    ))
+}
+
+/// Checks if any of the signatures matches the webhook payload sent at the specified Unix
+/// timestamp. The signature header value can include multiple comma-separated signatures.
+#[allow(dead_code)]
+pub fn verify_webhook_payload(
+    secret: &[u8],
+    timestamp: i64,
+    payload: &[u8],
+    signature: &str,
+) -> anyhow::Result<bool> {
+    let expected_signature = sign_webhook_payload(secret, timestamp, payload)?;
+    Ok(signature
+        .split(WEBHOOK_SIGNATURES_SEPARATOR)
+        .map(str::trim)
+        .any(|signature| {
+            expected_signature.len() == signature.len()
+                && memcmp::eq(expected_signature.as_bytes(), signature.as_bytes())
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sign_webhook_payload, verify_webhook_payload};
+
+    #[test]
+    fn can_sign_payload() -> anyhow::Result<()> {
+        let signature = sign_webhook_payload(b"my-webhook-secret", 946720800, b"{}")?;
+        assert!(signature.starts_with("sha256="));
+        assert_eq!(signature.len(), 7 + 64);
+
+        // Signature should be deterministic.
+        assert_eq!(
+            sign_webhook_payload(b"my-webhook-secret", 946720800, b"{}")?,
+            signature
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_verify_payload() -> anyhow::Result<()> {
+        let signature = sign_webhook_payload(b"my-webhook-secret", 946720800, br#"{"a":1}"#)?;
+        assert!(verify_webhook_payload(
+            b"my-webhook-secret",
+            946720800,
+            br#"{"a":1}"#,
+            &signature
+        )?);
+
+        // Wrong secret.
+        assert!(!verify_webhook_payload(
+            b"wrong-webhook-secret",
+            946720800,
+            br#"{"a":1}"#,
+            &signature
+        )?);
+
+        // Different timestamp (e.g. replayed payload).
+        assert!(!verify_webhook_payload(
+            b"my-webhook-secret",
+            946720801,
+            br#"{"a":1}"#,
+            &signature
+        )?);
+
+        // Modified payload.
+        assert!(!verify_webhook_payload(
+            b"my-webhook-secret",
+            946720800,
+            br#"{"a":2}"#,
+            &signature
+        )?);
+
+        // Malformed signature.
+        assert!(!verify_webhook_payload(
+            b"my-webhook-secret",
+            946720800,
+            br#"{"a":1}"#,
+            "sha256="
+        )?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_verify_payload_with_multiple_signatures() -> anyhow::Result<()> {
+        let signature = [
+            sign_webhook_payload(b"my-new-webhook-secret", 946720800, br#"{"a":1}"#)?,
+            sign_webhook_payload(b"my-old-webhook-secret", 946720800, br#"{"a":1}"#)?,
+        ]
+        .join(",");
+
+        for secret in [b"my-new-webhook-secret", b"my-old-webhook-secret"] {
+            assert!(verify_webhook_payload(
+                secret,
+                946720800,
+                br#"{"a":1}"#,
+                &signature
+            )?);
+        }
+
+        assert!(!verify_webhook_payload(
+            b"wrong-webhook-secret",
+            946720800,
+            br#"{"a":1}"#,
+            &signature
+        )?);
+        assert!(!verify_webhook_payload(
+            b"my-old-webhook-secret",
+            946720800,
+            br#"{"a":2}"#,
+            &signature
+        )?);
+
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ mod database_ext;
 mod job_ext;
 mod schedule_ext;
 mod scheduler_job;
+mod scheduler_job_adaptive_interval;
 mod scheduler_job_config;
 mod scheduler_job_metadata;
 mod scheduler_job_retry_state;
@@ -20,6 +21,7 @@ use tokio_cron_scheduler::{
 
 pub use self::{
     schedule_ext::ScheduleExt, scheduler_job::SchedulerJob,
+    scheduler_job_adaptive_interval::SchedulerJobAdaptiveInterval,
     scheduler_job_config::SchedulerJobConfig, scheduler_job_metadata::SchedulerJobMetadata,
     scheduler_job_retry_state::SchedulerJobRetryState,
     scheduler_job_retry_strategy::SchedulerJobRetryStrategy,
@@ -28,11 +30,12 @@ use crate::{
     api::Api,
     network::{DnsResolver, EmailTransport, EmailTransportError},
     scheduler::scheduler_jobs::{
-        NotificationsSendJob, WebPageTrackersFetchJob, WebPageTrackersScheduleJob,
-        WebPageTrackersTriggerJob,
+        NotificationsSendJob, WebPageTrackersScheduleJob, WebPageTrackersTriggerJob,
     },
 };
 
+pub(crate) use self::scheduler_jobs::WebPageTrackersFetchJob;
+
 /// Defines a maximum number of jobs that can be retrieved from the database at once.
 const MAX_JOBS_PAGE_SIZE: usize = 1000;
 
@@ -115,6 +118,17 @@ where
                 .await?;
         }
 
+        // Then, make sure that every tracker has a job and there are no orphaned tracker jobs left
+        // after a crash or a manual database change.
+        let (created_jobs, removed_jobs) = WebPageTrackersScheduleJob::reconcile(
+            scheduler.api.clone(),
+            &scheduler.inner_scheduler,
+        )
+        .await?;
+        log::info!(
+            "Reconciled web page tracker jobs on startup: {created_jobs} created, {removed_jobs} removed."
+        );
+
         scheduler.inner_scheduler.start().await?;
         Ok(scheduler)
     }
@@ -202,7 +216,8 @@ pub mod tests {
     use crate::{
         config::{Config, DatabaseConfig},
         scheduler::{
-            scheduler_job::SchedulerJob, Scheduler, SchedulerJobConfig, SchedulerJobMetadata,
+            scheduler_job::SchedulerJob, scheduler_jobs::WebPageTrackersScheduleJob, Scheduler,
+            SchedulerJobConfig, SchedulerJobMetadata,
         },
         tests::{mock_api_with_config, mock_config, mock_user},
         utils::web_scraping::{
@@ -325,13 +340,13 @@ pub mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 1,
                     delay: Default::default(),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "1 2 3 4 5 6 2030".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -343,13 +358,13 @@ pub mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 1,
                     delay: Default::default(),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "1 2 3 4 5 6 2030".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -431,6 +446,84 @@ pub mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn reconciles_tracker_jobs_on_start(pool: PgPool) -> anyhow::Result<()> {
+        let mock_config = mock_scheduler_config(&pool).await?;
+        let user = mock_user()?;
+        let api = Arc::new(mock_api_with_config(pool, mock_config).await?);
+
+        // Create user and tracker that doesn't have a job yet.
+        api.db.upsert_user(user.clone()).await?;
+        let tracker = api
+            .web_scraping(&user)
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "tracker-one".to_string(),
+                url: "https://localhost:1234/my/app?q=2".parse()?,
+                settings: WebPageTrackerSettings {
+                    revisions: 1,
+                    delay: Default::default(),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "1 2 3 4 5 6 2030".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+        assert!(tracker.job_id.is_none());
+
+        // Add job registration that isn't linked to any tracker.
+        let orphaned_job_id = uuid!("00000000-0000-0000-0000-000000000001");
+        mock_upsert_scheduler_job(
+            &api.db,
+            &mock_scheduler_job(
+                orphaned_job_id,
+                SchedulerJob::WebPageTrackersTrigger {
+                    kind: WebPageTrackerKind::WebPageContent,
+                },
+                "1 2 3 4 5 6 2030",
+            ),
+        )
+        .await?;
+
+        let mut scheduler = Scheduler::start(api.clone()).await?;
+
+        // Tracker should have been scheduled right away.
+        let job_id = api
+            .web_scraping(&user)
+            .get_content_tracker(tracker.id)
+            .await?
+            .and_then(|tracker| tracker.job_id)
+            .ok_or_else(|| anyhow!("Tracker job wasn't created."))?;
+        assert!(scheduler
+            .inner_scheduler
+            .next_tick_for_job(job_id)
+            .await?
+            .is_some());
+
+        // Orphaned job should have been removed.
+        assert!(mock_get_scheduler_job(&api.db, orphaned_job_id)
+            .await?
+            .is_none());
+
+        // Reconciliation is idempotent and doesn't touch already scheduled trackers.
+        assert_eq!(
+            WebPageTrackersScheduleJob::reconcile(api.clone(), &scheduler.inner_scheduler).await?,
+            (0, 0)
+        );
+        assert_eq!(
+            api.web_scraping(&user)
+                .get_content_tracker(tracker.id)
+                .await?
+                .and_then(|tracker| tracker.job_id),
+            Some(job_id)
+        );
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn schedules_unique_jobs_if_not_started(pool: PgPool) -> anyhow::Result<()> {
         let mock_config = mock_scheduler_config(&pool).await?;
@@ -32,18 +32,29 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> SchedulerApiExt<'a, DR, ET> {
                 )
             })?;
 
-        let retry_attempts = retry
-            .map(|retry_state| retry_state.attempts)
-            .unwrap_or_default();
-        // Check if retry is possible.
+        let now = OffsetDateTime::now_utc();
+        let (retry_attempts, retry_started_at) = retry
+            .map(|retry_state| (retry_state.attempts, retry_state.started_at))
+            .unwrap_or((0, now));
+        let retry_interval = retry_strategy.interval(retry_attempts);
+        // Check if retry is possible, both in terms of attempts and total retry duration.
         let retry_state = if retry_attempts >= retry_strategy.max_attempts() {
             log::warn!(
                 "Retry limit reached ('{}') for a scheduler job ('{job_id}').",
                 retry_attempts
             );
             None
+        } else if let Some(max_total_retry_duration) = retry_strategy
+            .max_total_retry_duration()
+            .filter(|max_duration| now.add(retry_interval) - retry_started_at > *max_duration)
+        {
+            log::warn!(
+                "Retry duration limit reached ('{}') for a scheduler job ('{job_id}') after {} attempts.",
+                humantime::format_duration(max_total_retry_duration),
+                retry_attempts
+            );
+            None
         } else {
-            let retry_interval = retry_strategy.interval(retry_attempts);
             log::debug!(
                 "Scheduling a retry for job ('{job_id}') in {}.",
                 humantime::format_duration(retry_interval),
@@ -51,7 +62,8 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> SchedulerApiExt<'a, DR, ET> {
 
             Some(SchedulerJobRetryState {
                 attempts: retry_attempts + 1,
-                next_at: OffsetDateTime::now_utc().add(retry_interval),
+                next_at: now.add(retry_interval),
+                started_at: retry_started_at,
             })
         };
 
@@ -70,7 +82,7 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> SchedulerApiExt<'a, DR, ET> {
 
 impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
     /// Returns an API to work with scheduler jobs.
-    pub fn scheduler(&self) -> SchedulerApiExt<DR, ET> {
+    pub fn scheduler(&self) -> SchedulerApiExt<'_, DR, ET> {
         SchedulerApiExt::new(self)
     }
 }
@@ -80,12 +92,15 @@ mod tests {
     use crate::{
         scheduler::{
             database_ext::RawSchedulerJobStoredData, SchedulerJob, SchedulerJobMetadata,
-            SchedulerJobRetryStrategy,
+            SchedulerJobRetryState, SchedulerJobRetryStrategy,
         },
         tests::{mock_api, mock_upsert_scheduler_job},
     };
     use sqlx::PgPool;
-    use std::{ops::Add, time::Duration};
+    use std::{
+        ops::{Add, Sub},
+        time::Duration,
+    };
     use time::OffsetDateTime;
     use uuid::uuid;
 
@@ -120,6 +135,7 @@ mod tests {
                 &SchedulerJobRetryStrategy::Constant {
                     interval: Duration::from_secs(120),
                     max_attempts: 2,
+                    max_total_retry_duration: None,
                 },
             )
             .await?
@@ -133,6 +149,7 @@ mod tests {
                 &SchedulerJobRetryStrategy::Constant {
                     interval: Duration::from_secs(120),
                     max_attempts: 2,
+                    max_total_retry_duration: None,
                 },
             )
             .await?
@@ -146,10 +163,95 @@ mod tests {
                 &SchedulerJobRetryStrategy::Constant {
                     interval: Duration::from_secs(120),
                     max_attempts: 2,
+                    max_total_retry_duration: None,
+                },
+            )
+            .await?;
+        assert!(retry_state.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn stops_retrying_when_retry_duration_is_exhausted(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let scheduler = api.scheduler();
+
+        let job_id = uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
+        let job = RawSchedulerJobStoredData {
+            id: job_id,
+            last_updated: Some(946720800),
+            last_tick: Some(946720700),
+            next_tick: Some(946720900),
+            count: Some(3),
+            job_type: 3,
+            extra: Some(SchedulerJobMetadata::new(SchedulerJob::NotificationsSend).try_into()?),
+            ran: Some(true),
+            stopped: Some(false),
+            schedule: None,
+            repeating: None,
+            time_offset_seconds: Some(0),
+            repeated_every: None,
+        };
+
+        mock_upsert_scheduler_job(&api.db, &job).await?;
+
+        let retry_strategy = SchedulerJobRetryStrategy::Constant {
+            interval: Duration::from_secs(120),
+            max_attempts: 10,
+            max_total_retry_duration: Some(Duration::from_secs(300)),
+        };
+
+        // The first retry starts the retry duration budget.
+        let now = OffsetDateTime::now_utc();
+        let retry_state = scheduler
+            .schedule_retry(job_id, &retry_strategy)
+            .await?
+            .unwrap();
+        assert_eq!(retry_state.attempts, 1);
+        assert!(retry_state.started_at >= now);
+        assert!(retry_state.next_at >= now.add(Duration::from_secs(120)));
+
+        // The next retry still fits into the budget, and keeps the original start time.
+        let started_at = now.sub(Duration::from_secs(100));
+        api.db
+            .update_scheduler_job_meta(
+                job_id,
+                SchedulerJobMetadata {
+                    job_type: SchedulerJob::NotificationsSend,
+                    retry: Some(SchedulerJobRetryState {
+                        started_at,
+                        ..retry_state
+                    }),
+                },
+            )
+            .await?;
+        let retry_state = scheduler
+            .schedule_retry(job_id, &retry_strategy)
+            .await?
+            .unwrap();
+        assert_eq!(retry_state.attempts, 2);
+        assert_eq!(retry_state.started_at, started_at);
+
+        // The next retry would exceed the budget, even though attempts aren't exhausted yet.
+        api.db
+            .update_scheduler_job_meta(
+                job_id,
+                SchedulerJobMetadata {
+                    job_type: SchedulerJob::NotificationsSend,
+                    retry: Some(SchedulerJobRetryState {
+                        started_at: now.sub(Duration::from_secs(250)),
+                        ..retry_state
+                    }),
                 },
             )
             .await?;
+        let retry_state = scheduler.schedule_retry(job_id, &retry_strategy).await?;
         assert!(retry_state.is_none());
+        assert_eq!(
+            api.db.get_scheduler_job_meta(job_id).await?,
+            Some(SchedulerJobMetadata::new(SchedulerJob::NotificationsSend))
+        );
 
         Ok(())
     }
@@ -7,6 +7,7 @@ use anyhow::{anyhow, bail};
 use async_stream::try_stream;
 use futures::Stream;
 use sqlx::{query, query_as};
+use time::OffsetDateTime;
 use uuid::Uuid;
 
 /// Extends primary database with the Scheduler-related methods.
@@ -46,6 +47,27 @@ impl Database {
         Ok(())
     }
 
+    /// Updates the time of the next job run in the `scheduler_jobs` table using Job ID.
+    pub async fn update_scheduler_job_next_tick(
+        &self,
+        id: Uuid,
+        next_tick: OffsetDateTime,
+    ) -> anyhow::Result<()> {
+        let result = query!(
+            r#"UPDATE scheduler_jobs SET next_tick = $2 WHERE id = $1"#,
+            id,
+            next_tick.unix_timestamp()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            bail!(format!("A scheduler job ('{id}') doesn't exist."));
+        }
+
+        Ok(())
+    }
+
     /// Updates `stopped` job value to the `scheduler_jobs` table.
     pub async fn reset_scheduler_job_state(&self, id: Uuid, stopped: bool) -> anyhow::Result<()> {
         let metadata = self
@@ -187,6 +209,7 @@ pub mod tests {
                         retry: Some(SchedulerJobRetryState {
                             attempts: 5,
                             next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                            started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                         }),
                     }
                     .try_into()?,
@@ -234,6 +257,7 @@ pub mod tests {
                 retry: Some(SchedulerJobRetryState {
                     attempts: 5,
                     next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 }),
             }
         );
@@ -299,6 +323,7 @@ pub mod tests {
                 retry: Some(SchedulerJobRetryState {
                     attempts: 5,
                     next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 }),
             },
         )
@@ -310,6 +335,7 @@ pub mod tests {
                 retry: Some(SchedulerJobRetryState {
                     attempts: 10,
                     next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 }),
             },
         )
@@ -324,6 +350,7 @@ pub mod tests {
                 retry: Some(SchedulerJobRetryState {
                     attempts: 5,
                     next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 }),
             }
         );
@@ -337,6 +364,7 @@ pub mod tests {
                 retry: Some(SchedulerJobRetryState {
                     attempts: 10,
                     next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 }),
             }
         );
@@ -352,6 +380,7 @@ pub mod tests {
                 retry: Some(SchedulerJobRetryState {
                     attempts: 5,
                     next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 }),
             }
         );
@@ -391,6 +420,55 @@ pub mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn can_update_scheduler_job_next_tick(pool: PgPool) -> anyhow::Result<()> {
+        let db = Database::create(pool).await?;
+
+        let job_id = uuid!("00000000-0000-0000-0000-000000000001");
+        mock_upsert_scheduler_job(
+            &db,
+            &RawSchedulerJobStoredData {
+                id: job_id,
+                last_updated: None,
+                last_tick: None,
+                next_tick: Some(946720900),
+                count: Some(0),
+                job_type: 0,
+                extra: Some(
+                    SchedulerJobMetadata::new(SchedulerJob::WebPageTrackersSchedule).try_into()?,
+                ),
+                ran: Some(true),
+                stopped: Some(false),
+                schedule: None,
+                repeating: None,
+                time_offset_seconds: Some(0),
+                repeated_every: None,
+            },
+        )
+        .await?;
+
+        db.update_scheduler_job_next_tick(job_id, OffsetDateTime::from_unix_timestamp(946820900)?)
+            .await?;
+        assert_eq!(
+            mock_get_scheduler_job(&db, job_id)
+                .await?
+                .unwrap()
+                .next_tick,
+            Some(946820900)
+        );
+
+        // Non-existent job.
+        assert!(db
+            .update_scheduler_job_next_tick(
+                uuid!("00000000-0000-0000-0000-000000000002"),
+                OffsetDateTime::from_unix_timestamp(946820900)?
+            )
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn can_update_and_retrieve_scheduler_job_metadata(pool: PgPool) -> anyhow::Result<()> {
         let db = Database::create(pool).await?;
@@ -424,6 +502,7 @@ pub mod tests {
                     retry: Some(SchedulerJobRetryState {
                         attempts: 5,
                         next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                        started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                     }),
                 })?),
                 ran: Some(true),
@@ -454,6 +533,7 @@ pub mod tests {
                 retry: Some(SchedulerJobRetryState {
                     attempts: 5,
                     next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 }),
             }
         );
@@ -465,6 +545,7 @@ pub mod tests {
                 retry: Some(SchedulerJobRetryState {
                     attempts: 5,
                     next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 }),
             },
         )
@@ -484,6 +565,7 @@ pub mod tests {
                 retry: Some(SchedulerJobRetryState {
                     attempts: 5,
                     next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 }),
             }
         );
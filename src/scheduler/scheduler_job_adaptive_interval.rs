@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DurationMilliSeconds};
+use std::{iter, time::Duration};
+use time::OffsetDateTime;
+
+/// Defines bounds for the interval between job runs that is automatically tuned based on how
+/// often the job target changes: the job runs more often when the target changes frequently,
+/// and backs off when the target is stable.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SchedulerJobAdaptiveInterval {
+    /// The minimum interval between job runs.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub min_interval: Duration,
+    /// The maximum interval between job runs.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub max_interval: Duration,
+}
+
+impl SchedulerJobAdaptiveInterval {
+    /// Calculates the interval until the next job run based on the timestamps of the recently
+    /// detected changes. The job runs twice as often as the target changes on average, where the
+    /// average also accounts for the time passed since the last change, so that the interval
+    /// keeps growing while the target stays unchanged. If there are no known changes yet, the
+    /// minimum interval is used.
+    pub fn interval(&self, changes: &[OffsetDateTime], now: OffsetDateTime) -> Duration {
+        let mut changes = changes.to_vec();
+        changes.sort();
+
+        let Some(last_change) = changes.last() else {
+            return self.min_interval;
+        };
+
+        let intervals = changes
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .chain(iter::once(now - *last_change))
+            .map(|interval| Duration::try_from(interval).unwrap_or_default())
+            .collect::<Vec<_>>();
+        let average_interval = intervals.iter().sum::<Duration>() / intervals.len() as u32;
+
+        (average_interval / 2)
+            .min(self.max_interval)
+            .max(self.min_interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SchedulerJobAdaptiveInterval;
+    use std::{ops::Sub, time::Duration};
+    use time::OffsetDateTime;
+
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    #[test]
+    fn uses_min_interval_without_changes() {
+        let adaptive_interval = SchedulerJobAdaptiveInterval {
+            min_interval: Duration::from_secs(600),
+            max_interval: HOUR * 24,
+        };
+
+        assert_eq!(
+            adaptive_interval.interval(&[], OffsetDateTime::now_utc()),
+            Duration::from_secs(600)
+        );
+    }
+
+    #[test]
+    fn shrinks_interval_for_frequently_changing_targets() {
+        let adaptive_interval = SchedulerJobAdaptiveInterval {
+            min_interval: Duration::from_secs(600),
+            max_interval: HOUR * 24,
+        };
+
+        // Changes every 2 hours, last change was 1 hour ago: (2h + 2h + 2h + 1h) / 4 / 2.
+        let now = OffsetDateTime::now_utc();
+        let changes = [
+            now.sub(HOUR * 7),
+            now.sub(HOUR * 5),
+            now.sub(HOUR * 3),
+            now.sub(HOUR),
+        ];
+        assert_eq!(
+            adaptive_interval.interval(&changes, now),
+            Duration::from_secs(3150)
+        );
+
+        // Interval shrinks when changes become more frequent, but stays within bounds.
+        let changes = [
+            now.sub(HOUR * 7),
+            now.sub(HOUR * 5),
+            now.sub(HOUR * 3),
+            now.sub(HOUR),
+            now.sub(Duration::from_secs(1200)),
+            now.sub(Duration::from_secs(600)),
+            now.sub(Duration::from_secs(60)),
+        ];
+        assert!(adaptive_interval.interval(&changes, now) < Duration::from_secs(3150));
+
+        let changes = (1..=10)
+            .map(|minutes| now.sub(Duration::from_secs(minutes * 60)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            adaptive_interval.interval(&changes, now),
+            Duration::from_secs(600)
+        );
+    }
+
+    #[test]
+    fn grows_interval_for_stable_targets() {
+        let adaptive_interval = SchedulerJobAdaptiveInterval {
+            min_interval: Duration::from_secs(600),
+            max_interval: HOUR * 24,
+        };
+
+        // Changes every 2 hours, but the last change was 9 hours ago: (2h + 2h + 2h + 9h) / 4 / 2.
+        let now = OffsetDateTime::now_utc();
+        let changes = [
+            now.sub(HOUR * 15),
+            now.sub(HOUR * 13),
+            now.sub(HOUR * 11),
+            now.sub(HOUR * 9),
+        ];
+        assert_eq!(
+            adaptive_interval.interval(&changes, now),
+            Duration::from_secs(6750)
+        );
+
+        // The longer the target stays unchanged, the larger the interval, but it never exceeds
+        // the maximum interval.
+        assert_eq!(
+            adaptive_interval.interval(&[now.sub(HOUR * 10)], now),
+            HOUR * 5
+        );
+        assert_eq!(
+            adaptive_interval.interval(&[now.sub(HOUR * 24 * 30)], now),
+            HOUR * 24
+        );
+    }
+}
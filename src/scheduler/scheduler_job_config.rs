@@ -1,4 +1,4 @@
-use crate::scheduler::SchedulerJobRetryStrategy;
+use crate::scheduler::{SchedulerJobAdaptiveInterval, SchedulerJobRetryStrategy};
 use serde::{Deserialize, Serialize};
 
 /// Represents a job configuration that can be scheduled.
@@ -13,4 +13,8 @@ pub struct SchedulerJobConfig {
     /// Indicates whether the job result should result into a notification. If retry strategy is
     /// defined, the error notification will be sent only if the job fails after all the retries.
     pub notifications: bool,
+    /// Defines bounds for the interval between job runs that should be adjusted based on how often
+    /// the job target changes. The schedule defines the most frequent job runs possible.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adaptive_interval: Option<SchedulerJobAdaptiveInterval>,
 }
@@ -78,9 +78,10 @@ mod tests {
                 retry: Some(SchedulerJobRetryState {
                     attempts: 10,
                     next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 })
             })?,
-            vec![1, 1, 10, 160, 31, 1, 10, 0, 0, 0, 0, 0, 0]
+            vec![1, 1, 10, 160, 31, 1, 10, 0, 0, 0, 0, 0, 0, 160, 31, 1, 9, 58, 20, 0, 0, 0, 0]
         );
 
         assert_eq!(
@@ -93,9 +94,10 @@ mod tests {
                 retry: Some(SchedulerJobRetryState {
                     attempts: 10,
                     next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 })
             })?,
-            vec![3, 1, 10, 160, 31, 1, 10, 0, 0, 0, 0, 0, 0]
+            vec![3, 1, 10, 160, 31, 1, 10, 0, 0, 0, 0, 0, 0, 160, 31, 1, 9, 58, 20, 0, 0, 0, 0]
         );
 
         Ok(())
@@ -109,12 +111,16 @@ mod tests {
         );
 
         assert_eq!(
-            SchedulerJobMetadata::try_from([1, 1, 10, 160, 31, 1, 10, 0, 0, 0, 0, 0, 0].as_ref())?,
+            SchedulerJobMetadata::try_from(
+                [1, 1, 10, 160, 31, 1, 10, 0, 0, 0, 0, 0, 0, 160, 31, 1, 9, 58, 20, 0, 0, 0, 0]
+                    .as_ref()
+            )?,
             SchedulerJobMetadata {
                 job_type: SchedulerJob::WebPageTrackersSchedule,
                 retry: Some(SchedulerJobRetryState {
                     attempts: 10,
                     next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 })
             }
         );
@@ -125,12 +131,16 @@ mod tests {
         );
 
         assert_eq!(
-            SchedulerJobMetadata::try_from([3, 1, 10, 160, 31, 1, 10, 0, 0, 0, 0, 0, 0].as_ref())?,
+            SchedulerJobMetadata::try_from(
+                [3, 1, 10, 160, 31, 1, 10, 0, 0, 0, 0, 0, 0, 160, 31, 1, 9, 58, 20, 0, 0, 0, 0]
+                    .as_ref()
+            )?,
             SchedulerJobMetadata {
                 job_type: SchedulerJob::NotificationsSend,
                 retry: Some(SchedulerJobRetryState {
                     attempts: 10,
                     next_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    started_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                 })
             }
         );
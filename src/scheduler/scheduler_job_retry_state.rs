@@ -8,4 +8,6 @@ pub struct SchedulerJobRetryState {
     pub attempts: u32,
     /// The time at which the job will be retried.
     pub next_at: OffsetDateTime,
+    /// The time at which the first retry was scheduled, used to limit the total retry duration.
+    pub started_at: OffsetDateTime,
 }
@@ -13,6 +13,9 @@ pub enum SchedulerJobRetryStrategy {
         #[serde_as(as = "DurationMilliSeconds<u64>")]
         interval: Duration,
         max_attempts: u32,
+        #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_total_retry_duration: Option<Duration>,
     },
     /// The job will be retried with an exponential interval (1s -> 2s -> 4s -> 8s).
     #[serde(rename_all = "camelCase")]
@@ -23,6 +26,9 @@ pub enum SchedulerJobRetryStrategy {
         #[serde_as(as = "DurationMilliSeconds<u64>")]
         max_interval: Duration,
         max_attempts: u32,
+        #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_total_retry_duration: Option<Duration>,
     },
     /// The job will be retried with a linear interval (1s -> 2s -> 3s).
     #[serde(rename_all = "camelCase")]
@@ -34,6 +40,9 @@ pub enum SchedulerJobRetryStrategy {
         #[serde_as(as = "DurationMilliSeconds<u64>")]
         max_interval: Duration,
         max_attempts: u32,
+        #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_total_retry_duration: Option<Duration>,
     },
 }
 
@@ -74,6 +83,24 @@ impl SchedulerJobRetryStrategy {
         }
     }
 
+    /// Returns the maximum total time that can be spent on retries since the first failure, if any.
+    pub fn max_total_retry_duration(&self) -> Option<Duration> {
+        match self {
+            Self::Constant {
+                max_total_retry_duration,
+                ..
+            }
+            | Self::Exponential {
+                max_total_retry_duration,
+                ..
+            }
+            | Self::Linear {
+                max_total_retry_duration,
+                ..
+            } => *max_total_retry_duration,
+        }
+    }
+
     /// Returns the minimum retry interval.
     pub fn min_interval(&self) -> &Duration {
         match self {
@@ -99,6 +126,7 @@ mod tests {
             SchedulerJobRetryStrategy::Constant {
                 interval: Duration::from_secs(1),
                 max_attempts: 10,
+                max_total_retry_duration: None,
             }
             .max_attempts(),
             10
@@ -109,6 +137,7 @@ mod tests {
                 multiplier: 2,
                 max_interval: Duration::from_secs(10),
                 max_attempts: 15,
+                max_total_retry_duration: None,
             }
             .max_attempts(),
             15
@@ -119,18 +148,55 @@ mod tests {
                 increment: Duration::from_secs(1),
                 max_interval: Duration::from_secs(10),
                 max_attempts: 20,
+                max_total_retry_duration: None,
             }
             .max_attempts(),
             20
         );
     }
 
+    #[test]
+    fn properly_detects_max_total_retry_duration() {
+        assert_eq!(
+            SchedulerJobRetryStrategy::Constant {
+                interval: Duration::from_secs(1),
+                max_attempts: 10,
+                max_total_retry_duration: None,
+            }
+            .max_total_retry_duration(),
+            None
+        );
+        assert_eq!(
+            SchedulerJobRetryStrategy::Exponential {
+                initial_interval: Duration::from_secs(1),
+                multiplier: 2,
+                max_interval: Duration::from_secs(10),
+                max_attempts: 15,
+                max_total_retry_duration: Some(Duration::from_secs(60)),
+            }
+            .max_total_retry_duration(),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            SchedulerJobRetryStrategy::Linear {
+                initial_interval: Duration::from_secs(1),
+                increment: Duration::from_secs(1),
+                max_interval: Duration::from_secs(10),
+                max_attempts: 20,
+                max_total_retry_duration: Some(Duration::from_secs(120)),
+            }
+            .max_total_retry_duration(),
+            Some(Duration::from_secs(120))
+        );
+    }
+
     #[test]
     fn properly_detects_min_interval() {
         assert_eq!(
             SchedulerJobRetryStrategy::Constant {
                 interval: Duration::from_secs(1),
                 max_attempts: 10,
+                max_total_retry_duration: None,
             }
             .min_interval(),
             &Duration::from_secs(1)
@@ -141,6 +207,7 @@ mod tests {
                 multiplier: 2,
                 max_interval: Duration::from_secs(10),
                 max_attempts: 15,
+                max_total_retry_duration: None,
             }
             .min_interval(),
             &Duration::from_secs(2)
@@ -151,6 +218,7 @@ mod tests {
                 increment: Duration::from_secs(1),
                 max_interval: Duration::from_secs(10),
                 max_attempts: 20,
+                max_total_retry_duration: None,
             }
             .min_interval(),
             &Duration::from_secs(3)
@@ -162,6 +230,7 @@ mod tests {
         let retry_strategy = SchedulerJobRetryStrategy::Constant {
             interval: Duration::from_secs(1),
             max_attempts: 10,
+            max_total_retry_duration: None,
         };
         assert_eq!(retry_strategy.interval(0), Duration::from_secs(1));
         assert_eq!(retry_strategy.interval(1), Duration::from_secs(1));
@@ -176,6 +245,7 @@ mod tests {
             increment: Duration::from_secs(1),
             max_interval: Duration::from_secs(5),
             max_attempts: 10,
+            max_total_retry_duration: None,
         };
         assert_eq!(retry_strategy.interval(0), Duration::from_secs(1));
         assert_eq!(retry_strategy.interval(1), Duration::from_secs(2));
@@ -195,6 +265,7 @@ mod tests {
             multiplier: 2,
             max_interval: Duration::from_secs(100),
             max_attempts: 10,
+            max_total_retry_duration: None,
         };
         assert_eq!(retry_strategy.interval(0), Duration::from_secs(1));
         assert_eq!(retry_strategy.interval(1), Duration::from_secs(2));
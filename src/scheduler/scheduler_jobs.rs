@@ -1,5 +1,6 @@
 mod notifications_send_job;
 mod web_page_trackers_fetch_job;
+mod web_page_trackers_fetch_slots;
 mod web_page_trackers_schedule_job;
 mod web_page_trackers_trigger_job;
 
@@ -1,20 +1,38 @@
 use crate::{
     api::Api,
+    config::SchedulerJobsConfig,
     error::Error as SecutilsError,
     logging::{JobLogContext, MetricsContext, UserLogContext},
     network::{DnsResolver, EmailTransport, EmailTransportError},
     notifications::{NotificationContent, NotificationContentTemplate, NotificationDestination},
     scheduler::{
         database_ext::RawSchedulerJobStoredData, job_ext::JobExt, scheduler_job::SchedulerJob,
+        scheduler_jobs::web_page_trackers_fetch_slots::WebPageTrackersFetchSlots,
     },
-    utils::web_scraping::{WebPageTracker, WebPageTrackerTag},
+    users::{User, UserId},
+    utils::web_scraping::{
+        WebPageContentResponse, WebPageContentStatuses, WebPageContentStructure,
+        WebPageContentTrackerTag, WebPageDataRevision, WebPageDnsRecordDiffStatus,
+        WebPageDnsRecordsTrackerTag, WebPageResourcesTrackerTag, WebPageScreenshotTrackerTag,
+        WebPageTracker, WebPageTrackerKind, WebPageTrackerPendingScrape, WebPageTrackerTag,
+        WebScraperCallbackResult, WebScraperScreenshotResponse,
+    },
+};
+use anyhow::anyhow;
+use cron::Schedule;
+use futures::{future::try_join_all, pin_mut, Future, Stream, StreamExt};
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+    time::Instant,
 };
-use futures::{pin_mut, StreamExt};
-use std::{sync::Arc, time::Instant};
 use time::OffsetDateTime;
 use tokio_cron_scheduler::{Job, JobScheduler};
 use uuid::Uuid;
 
+/// Pending tracker along with the key it's grouped by and the associated data (e.g. job ID).
+type PendingTracker<K, Tag, T> = (K, (WebPageTracker<Tag>, T));
+
 /// The job executes every minute by default to check if there are any trackers to fetch resources for.
 pub(crate) struct WebPageTrackersFetchJob;
 impl WebPageTrackersFetchJob {
@@ -68,8 +86,26 @@ impl WebPageTrackersFetchJob {
     where
         ET::Error: EmailTransportError,
     {
-        Self::fetch_resources(api.clone(), scheduler.clone()).await?;
-        Self::fetch_content(api, scheduler).await?;
+        // If there are more pending jobs than can be processed within a single run, process only
+        // the most overdue ones and postpone the rest till the next run.
+        let overdue_jobs = api.web_scraping_system().get_overdue_tracker_jobs().await?;
+        let max_jobs = api.config.scheduler.web_page_trackers_fetch_max_jobs;
+        if overdue_jobs.len() > max_jobs {
+            let behind_jobs = overdue_jobs.len() - max_jobs;
+            log::warn!(
+                metrics:serde = MetricsContext::default().with_scheduler_behind_jobs(behind_jobs);
+                "Scheduler is behind by {behind_jobs} jobs, only {max_jobs} most overdue jobs will be processed."
+            );
+        }
+        let jobs_to_fetch = overdue_jobs
+            .into_iter()
+            .take(max_jobs)
+            .collect::<HashSet<_>>();
+
+        Self::fetch_resources(api.clone(), scheduler.clone(), &jobs_to_fetch).await?;
+        Self::fetch_content(api.clone(), scheduler.clone(), &jobs_to_fetch).await?;
+        Self::fetch_screenshots(api.clone(), scheduler.clone(), &jobs_to_fetch).await?;
+        Self::fetch_dns_records(api, scheduler, &jobs_to_fetch).await?;
 
         Ok(())
     }
@@ -77,256 +113,713 @@ impl WebPageTrackersFetchJob {
     async fn fetch_resources<DR: DnsResolver, ET: EmailTransport>(
         api: Arc<Api<DR, ET>>,
         scheduler: JobScheduler,
+        jobs_to_fetch: &HashSet<Uuid>,
     ) -> anyhow::Result<()>
     where
         ET::Error: EmailTransportError,
     {
-        // Fetch all resources trackers jobs that are pending processing.
-        let web_scraping_system = api.web_scraping_system();
-        let pending_trackers = web_scraping_system.get_pending_resources_trackers();
-        pin_mut!(pending_trackers);
+        // Fetch all resources trackers jobs that are pending processing, and process them
+        // grouped by the target host to not overload any single host.
+        let pending_trackers = Self::collect_pending_trackers(
+            &api,
+            &scheduler,
+            jobs_to_fetch,
+            api.web_scraping_system().get_pending_resources_trackers(),
+        )
+        .await?;
+        for pending_trackers in Self::batch_by_dependencies(pending_trackers) {
+            Self::fetch_by_host(
+                &api.config.scheduler,
+                pending_trackers,
+                |(tracker, job_id)| Self::fetch_resources_tracker(&api, tracker, job_id),
+            )
+            .await?;
+        }
 
-        while let Some(tracker) = pending_trackers.next().await {
-            let Some((tracker, job_id)) =
-                Self::validate_tracker(&api, &scheduler, tracker?).await?
-            else {
-                continue;
-            };
+        Ok(())
+    }
 
-            // Check if resources has changed, comparing new revision to the latest existing one.
-            let fetch_start = Instant::now();
-            let Some(user) = api.users().get(tracker.user_id).await? else {
-                log::error!(
-                    user:serde = UserLogContext::new(tracker.user_id),
-                    util:serde = tracker.log_context();
-                    "Cannot find the user for the tracker."
-                );
-                continue;
-            };
+    async fn fetch_resources_tracker<DR: DnsResolver, ET: EmailTransport>(
+        api: &Api<DR, ET>,
+        tracker: WebPageTracker<WebPageResourcesTrackerTag>,
+        job_id: Uuid,
+    ) -> anyhow::Result<()>
+    where
+        ET::Error: EmailTransportError,
+    {
+        // Check if resources has changed, comparing new revision to the latest existing one.
+        let fetch_start = Instant::now();
+        let Some(user) = api.users().get(tracker.user_id).await? else {
+            log::error!(
+                user:serde = UserLogContext::new(tracker.user_id),
+                util:serde = tracker.log_context();
+                "Cannot find the user for the tracker."
+            );
+            return Ok(());
+        };
 
-            // Create a new revision and retrieve a diff if any changes from the previous version are
-            // detected. If there are any changes and the tracker hasn't opted out of notifications,
-            // schedule a notification about the detected changes.
-            let new_revision_with_diff = match api
-                .web_scraping(&user)
-                .create_resources_tracker_revision(tracker.id)
-                .await
-            {
-                Ok(new_revision_with_diff) => new_revision_with_diff,
-                Err(err) => {
-                    let execution_time = fetch_start.elapsed();
-                    log::error!(
-                        user:serde = UserLogContext::new(tracker.user_id),
-                        util:serde = tracker.log_context(),
-                        metrics:serde = MetricsContext::default().with_job_execution_time(execution_time);
-                        "Failed to create web page tracker history revision: {err:?}"
-                    );
+        if !Self::check_precondition(api, &user, &tracker, job_id).await? {
+            return Ok(());
+        }
 
-                    // Check if the tracker has a retry strategy.
-                    let retry_strategy = tracker
-                        .job_config
-                        .as_ref()
-                        .and_then(|job_config| job_config.retry_strategy);
-                    let retry_state = if let Some(retry_strategy) = retry_strategy {
-                        api.scheduler()
-                            .schedule_retry(job_id, &retry_strategy)
-                            .await?
-                    } else {
-                        None
-                    };
-
-                    if let Some(retry) = retry_state {
-                        log::warn!(
-                            user:serde = UserLogContext::new(tracker.user_id),
-                            util:serde = tracker.log_context(),
-                            metrics:serde = MetricsContext::default().with_job_retries(retry.attempts);
-                            "Scheduled a retry to create web page resources tracker history revision at {}.",
-                            retry.next_at,
-                        );
-                    } else {
-                        // Notify user about the error and re-schedule the job.
-                        let tracker_name = tracker.name.clone();
-                        Self::try_notify_user(
-                            &api,
-                            tracker,
-                            NotificationContentTemplate::WebPageResourcesTrackerChanges {
-                                tracker_name,
-                                content: Err(err
-                                    .downcast::<SecutilsError>()
-                                    .map(|err| format!("{}", err))
-                                    .unwrap_or_else(|_| "Unknown error".to_string())),
-                            },
-                        )
-                        .await;
-                        api.db.reset_scheduler_job_state(job_id, false).await?;
-                    }
+        // Create a new revision and retrieve a diff if any changes from the previous version are
+        // detected. If there are any changes and the tracker hasn't opted out of notifications,
+        // schedule a notification about the detected changes.
+        let new_revision_with_diff = match api
+            .web_scraping(&user)
+            .create_resources_tracker_revision(tracker.id)
+            .await
+        {
+            Ok(new_revision_with_diff) => new_revision_with_diff,
+            Err(err) => {
+                return Self::handle_revision_error(api, tracker, job_id, fetch_start, err).await;
+            }
+        };
 
-                    continue;
-                }
-            };
+        let execution_time = fetch_start.elapsed();
+        log::info!(
+            user:serde = UserLogContext::new(tracker.user_id),
+            util:serde = tracker.log_context(),
+            metrics:serde = MetricsContext::default().with_job_execution_time(execution_time);
+            "Successfully created web page tracker history revision in {}.",
+            humantime::format_duration(execution_time)
+        );
 
-            let execution_time = fetch_start.elapsed();
-            log::info!(
-                user:serde = UserLogContext::new(tracker.user_id),
-                util:serde = tracker.log_context(),
-                metrics:serde = MetricsContext::default().with_job_execution_time(execution_time);
-                "Successfully created web page tracker history revision in {}.",
-                humantime::format_duration(execution_time)
-            );
+        Self::schedule_adaptive_tick(api, &tracker, job_id).await;
 
-            let enable_notifications = tracker
-                .job_config
-                .as_ref()
-                .map(|job_config| job_config.notifications)
-                .unwrap_or_default();
-            if enable_notifications {
-                if let Some(new_revision_with_diff) = new_revision_with_diff {
-                    let changes_count = new_revision_with_diff
-                        .data
-                        .scripts
-                        .iter()
-                        .filter(|resource| resource.diff_status.is_some())
-                        .chain(
-                            new_revision_with_diff
-                                .data
-                                .styles
-                                .iter()
-                                .filter(|resource| resource.diff_status.is_some()),
-                        )
-                        .count();
-                    let tracker_name = tracker.name.clone();
-                    Self::try_notify_user(
-                        &api,
-                        tracker,
-                        NotificationContentTemplate::WebPageResourcesTrackerChanges {
-                            tracker_name,
-                            content: Ok(changes_count),
-                        },
+        let enable_notifications = tracker
+            .job_config
+            .as_ref()
+            .map(|job_config| job_config.notifications)
+            .unwrap_or_default();
+        if enable_notifications {
+            if let Some(new_revision_with_diff) = new_revision_with_diff {
+                let changes_count = new_revision_with_diff
+                    .data
+                    .scripts
+                    .iter()
+                    .filter(|resource| resource.diff_status.is_some())
+                    .chain(
+                        new_revision_with_diff
+                            .data
+                            .styles
+                            .iter()
+                            .filter(|resource| resource.diff_status.is_some()),
                     )
-                    .await;
+                    .count();
+                let tracker_name = tracker.name.clone();
+
+                // Check if the resources exceed the size budget, if it's configured.
+                let size_budget_template = tracker
+                    .settings
+                    .size_budget
+                    .zip(new_revision_with_diff.data.sizes)
+                    .filter(|(size_budget, sizes)| size_budget.is_exceeded(sizes))
+                    .map(|(_, sizes)| {
+                        NotificationContentTemplate::WebPageResourcesTrackerSizeBudgetExceeded {
+                            tracker_name: tracker_name.clone(),
+                            total_size: sizes.total,
+                            previous_total_size: sizes.previous_total,
+                        }
+                    });
+                if let Some(size_budget_template) = size_budget_template {
+                    Self::try_notify_user(api, tracker.clone(), size_budget_template).await;
                 }
-            }
 
-            api.db.reset_scheduler_job_state(job_id, false).await?;
+                Self::try_notify_user(
+                    api,
+                    tracker,
+                    NotificationContentTemplate::WebPageResourcesTrackerChanges {
+                        tracker_name,
+                        content: Ok(changes_count),
+                    },
+                )
+                .await;
+            }
         }
 
+        api.db.reset_scheduler_job_state(job_id, false).await?;
+
         Ok(())
     }
 
     async fn fetch_content<DR: DnsResolver, ET: EmailTransport>(
         api: Arc<Api<DR, ET>>,
         scheduler: JobScheduler,
+        jobs_to_fetch: &HashSet<Uuid>,
     ) -> anyhow::Result<()>
     where
         ET::Error: EmailTransportError,
     {
-        // Fetch all content trackers jobs that are pending processing.
-        let web_scraping_system = api.web_scraping_system();
-        let pending_trackers = web_scraping_system.get_pending_content_trackers();
-        pin_mut!(pending_trackers);
+        // Fetch all content trackers jobs that are pending processing, and process them
+        // grouped by the target host to not overload any single host.
+        let pending_trackers = Self::collect_pending_trackers(
+            &api,
+            &scheduler,
+            jobs_to_fetch,
+            api.web_scraping_system().get_pending_content_trackers(),
+        )
+        .await?;
+        for pending_trackers in Self::batch_by_dependencies(pending_trackers) {
+            Self::fetch_by_host(
+                &api.config.scheduler,
+                pending_trackers,
+                |(tracker, job_id)| Self::fetch_content_tracker(&api, tracker, job_id),
+            )
+            .await?;
+        }
 
-        while let Some(tracker) = pending_trackers.next().await {
-            let Some((tracker, job_id)) =
-                Self::validate_tracker(&api, &scheduler, tracker?).await?
-            else {
-                continue;
-            };
+        Ok(())
+    }
 
-            // Try to create a new revision. If a revision is returned that means that tracker
-            // detected changes.
-            let fetch_start = Instant::now();
-            let Some(user) = api.users().get(tracker.user_id).await? else {
-                log::error!(
-                    user:serde = UserLogContext::new(tracker.user_id),
-                    util:serde = tracker.log_context();
-                    "Cannot find the user for the tracker."
-                );
-                continue;
-            };
+    async fn fetch_content_tracker<DR: DnsResolver, ET: EmailTransport>(
+        api: &Api<DR, ET>,
+        tracker: WebPageTracker<WebPageContentTrackerTag>,
+        job_id: Uuid,
+    ) -> anyhow::Result<()>
+    where
+        ET::Error: EmailTransportError,
+    {
+        // Try to create a new revision. If a revision is returned that means that tracker
+        // detected changes.
+        let fetch_start = Instant::now();
+        let Some(user) = api.users().get(tracker.user_id).await? else {
+            log::error!(
+                user:serde = UserLogContext::new(tracker.user_id),
+                util:serde = tracker.log_context();
+                "Cannot find the user for the tracker."
+            );
+            return Ok(());
+        };
 
-            let new_revision = match api
-                .web_scraping(&user)
-                .create_content_tracker_revision(tracker.id)
-                .await
+        if !Self::check_precondition(api, &user, &tracker, job_id).await? {
+            return Ok(());
+        }
+
+        // Unless requested otherwise, the very first revision is recorded silently as a baseline.
+        let is_silent_revision = !tracker.settings.notify_on_first
+            && api
+                .db
+                .web_scraping(user.id)
+                .count_web_page_tracker_history_revisions(tracker.id, OffsetDateTime::UNIX_EPOCH)
+                .await?
+                == 0;
+
+        // Remember whether content assertions passed for the latest revision, if any, to notify
+        // user only when assertions start or stop passing.
+        let previous_assertions_passed = if tracker.settings.assertions.is_some() {
+            api.db
+                .web_scraping(user.id)
+                .get_web_page_tracker_history::<WebPageContentTrackerTag>(tracker.id)
+                .await?
+                .last()
+                .and_then(|revision| revision.assertions_passed)
+        } else {
+            None
+        };
+
+        let new_revision = match api
+            .web_scraping(&user)
+            .create_content_tracker_revision(tracker.id)
+            .await
+        {
+            Ok(new_revision) => new_revision,
+            Err(err) => {
+                return Self::handle_revision_error(api, tracker, job_id, fetch_start, err).await;
+            }
+        };
+
+        let execution_time = fetch_start.elapsed();
+        log::info!(
+            user:serde = UserLogContext::new(tracker.user_id),
+            util:serde = tracker.log_context(),
+            metrics:serde = MetricsContext::default().with_job_execution_time(execution_time);
+            "Successfully created web page tracker history revision in {}.",
+            humantime::format_duration(execution_time)
+        );
+
+        Self::schedule_adaptive_tick(api, &tracker, job_id).await;
+
+        // Assertions are treated as passing if there is no previous result, so that the user is
+        // notified even if they fail for the very first revision.
+        let assertions_template = new_revision
+            .as_ref()
+            .and_then(|revision| revision.assertions_passed)
+            .filter(|passed| *passed != previous_assertions_passed.unwrap_or(true))
+            .map(
+                |passed| NotificationContentTemplate::WebPageContentTrackerAssertions {
+                    tracker_name: tracker.name.clone(),
+                    passed,
+                },
+            );
+        if let Some(assertions_template) = assertions_template {
+            Self::try_notify_user(api, tracker.clone(), assertions_template).await;
+        }
+
+        if let Some(revision) = new_revision.filter(|_| !is_silent_revision) {
+            // For the trackers that capture JSON API responses, monitor multiple URLs, or track
+            // structured content, summarize what exactly has changed.
+            let response_changes = match Self::get_response_changes(api, &tracker, &revision).await
             {
-                Ok(new_revision) => new_revision,
+                Ok(response_changes) => response_changes,
                 Err(err) => {
-                    let execution_time = fetch_start.elapsed();
                     log::error!(
                         user:serde = UserLogContext::new(tracker.user_id),
-                        util:serde = tracker.log_context(),
-                        metrics:serde = MetricsContext::default().with_job_execution_time(execution_time);
-                        "Failed to create web page tracker history revision: {err:?}"
+                        util:serde = tracker.log_context();
+                        "Failed to summarize web page response changes: {err:?}"
                     );
+                    None
+                }
+            };
 
-                    // Check if the tracker has a retry strategy.
-                    let retry_strategy = tracker
-                        .job_config
-                        .as_ref()
-                        .and_then(|job_config| job_config.retry_strategy);
-                    let retry_state = if let Some(retry_strategy) = retry_strategy {
-                        api.scheduler()
-                            .schedule_retry(job_id, &retry_strategy)
-                            .await?
-                    } else {
-                        None
-                    };
-
-                    if let Some(retry) = retry_state {
-                        log::warn!(
-                            user:serde = UserLogContext::new(tracker.user_id),
-                            util:serde = tracker.log_context(),
-                            metrics:serde = MetricsContext::default().with_job_retries(retry.attempts);
-                            "Scheduled a retry to create web page content tracker history revision at {}.",
-                            retry.next_at,
-                        );
-                    } else {
-                        // Notify user about the error and re-schedule the job.
-                        let tracker_name = tracker.name.clone();
-                        Self::try_notify_user(
-                            &api,
-                            tracker,
-                            NotificationContentTemplate::WebPageContentTrackerChanges {
-                                tracker_name,
-                                content: Err(err
-                                    .downcast::<SecutilsError>()
-                                    .map(|err| format!("{}", err))
-                                    .unwrap_or_else(|_| "Unknown error".to_string())),
-                            },
-                        )
-                        .await;
-                        api.db.reset_scheduler_job_state(job_id, false).await?;
+            let tracker_name = tracker.name.clone();
+            Self::try_notify_user(
+                api,
+                tracker,
+                if let Some(changes) = response_changes {
+                    NotificationContentTemplate::WebPageContentTrackerResponseChanges {
+                        tracker_name,
+                        changes,
+                        content: revision.data,
+                    }
+                } else {
+                    NotificationContentTemplate::WebPageContentTrackerChanges {
+                        tracker_name,
+                        content: Ok(revision.data),
                     }
+                },
+            )
+            .await;
+        }
 
-                    continue;
-                }
-            };
+        api.db.reset_scheduler_job_state(job_id, false).await?;
 
-            let execution_time = fetch_start.elapsed();
-            log::info!(
+        Ok(())
+    }
+
+    async fn fetch_screenshots<DR: DnsResolver, ET: EmailTransport>(
+        api: Arc<Api<DR, ET>>,
+        scheduler: JobScheduler,
+        jobs_to_fetch: &HashSet<Uuid>,
+    ) -> anyhow::Result<()>
+    where
+        ET::Error: EmailTransportError,
+    {
+        // Fetch all screenshot trackers jobs that are pending processing, and process them
+        // grouped by the target host to not overload any single host.
+        let pending_trackers = Self::collect_pending_trackers(
+            &api,
+            &scheduler,
+            jobs_to_fetch,
+            api.web_scraping_system().get_pending_screenshot_trackers(),
+        )
+        .await?;
+        for pending_trackers in Self::batch_by_dependencies(pending_trackers) {
+            Self::fetch_by_host(
+                &api.config.scheduler,
+                pending_trackers,
+                |(tracker, job_id)| Self::fetch_screenshot_tracker(&api, tracker, job_id),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_screenshot_tracker<DR: DnsResolver, ET: EmailTransport>(
+        api: &Api<DR, ET>,
+        tracker: WebPageTracker<WebPageScreenshotTrackerTag>,
+        job_id: Uuid,
+    ) -> anyhow::Result<()>
+    where
+        ET::Error: EmailTransportError,
+    {
+        // Try to create a new revision. If a revision is returned that means that tracker
+        // detected visual changes that exceed the threshold.
+        let fetch_start = Instant::now();
+        let Some(user) = api.users().get(tracker.user_id).await? else {
+            log::error!(
                 user:serde = UserLogContext::new(tracker.user_id),
-                util:serde = tracker.log_context(),
-                metrics:serde = MetricsContext::default().with_job_execution_time(execution_time);
-                "Successfully created web page tracker history revision in {}.",
-                humantime::format_duration(execution_time)
+                util:serde = tracker.log_context();
+                "Cannot find the user for the tracker."
             );
+            return Ok(());
+        };
+
+        if !Self::check_precondition(api, &user, &tracker, job_id).await? {
+            return Ok(());
+        }
+
+        let new_revision = match api
+            .web_scraping(&user)
+            .create_screenshot_tracker_revision(tracker.id)
+            .await
+        {
+            Ok(new_revision) => new_revision,
+            Err(err) => {
+                return Self::handle_revision_error(api, tracker, job_id, fetch_start, err).await;
+            }
+        };
+
+        let execution_time = fetch_start.elapsed();
+        log::info!(
+            user:serde = UserLogContext::new(tracker.user_id),
+            util:serde = tracker.log_context(),
+            metrics:serde = MetricsContext::default().with_job_execution_time(execution_time);
+            "Successfully created web page tracker history revision in {}.",
+            humantime::format_duration(execution_time)
+        );
+
+        Self::schedule_adaptive_tick(api, &tracker, job_id).await;
+
+        if let Some(revision) = new_revision {
+            let tracker_name = tracker.name.clone();
+            Self::try_notify_user(
+                api,
+                tracker,
+                NotificationContentTemplate::WebPageScreenshotTrackerChanges {
+                    tracker_name,
+                    content: Ok(format!(
+                        "{:.2}%",
+                        revision.data.diff.unwrap_or_default() * 100.0
+                    )),
+                },
+            )
+            .await;
+        }
+
+        api.db.reset_scheduler_job_state(job_id, false).await?;
+
+        Ok(())
+    }
+
+    /// Completes the asynchronous scrape job of the web page screenshot tracker once the web scraper
+    /// delivers its result, and notifies the user about the detected changes or scrape errors.
+    pub async fn complete_screenshot_tracker_scrape<DR: DnsResolver, ET: EmailTransport>(
+        api: &Api<DR, ET>,
+        user: &User,
+        pending_scrape: WebPageTrackerPendingScrape,
+        result: WebScraperCallbackResult<WebScraperScreenshotResponse>,
+    ) -> anyhow::Result<()>
+    where
+        ET::Error: EmailTransportError,
+    {
+        let web_scraping = api.web_scraping(user);
+        let tracker = web_scraping
+            .get_screenshot_tracker(pending_scrape.tracker_id)
+            .await?;
+        let new_revision = match web_scraping
+            .complete_screenshot_tracker_scrape(&pending_scrape, result)
+            .await
+        {
+            Ok(new_revision) => new_revision,
+            Err(err) => {
+                log::error!(
+                    user:serde = UserLogContext::new(user.id);
+                    "Failed to complete web page screenshot tracker scrape job ('{}'): {err:?}",
+                    pending_scrape.job_id
+                );
+
+                // If the tracker still exists, the user should know that the scrape failed.
+                let Some(tracker) = tracker else {
+                    return Err(err);
+                };
 
-            if let Some(revision) = new_revision {
                 let tracker_name = tracker.name.clone();
                 Self::try_notify_user(
-                    &api,
+                    api,
                     tracker,
-                    NotificationContentTemplate::WebPageContentTrackerChanges {
+                    NotificationContentTemplate::WebPageScreenshotTrackerChanges {
                         tracker_name,
-                        content: Ok(revision.data),
+                        content: Err(err
+                            .downcast::<SecutilsError>()
+                            .map(|err| format!("{}", err))
+                            .unwrap_or_else(|_| "Unknown error".to_string())),
                     },
                 )
                 .await;
+
+                return Ok(());
+            }
+        };
+
+        if let (Some(tracker), Some(revision)) = (tracker, new_revision) {
+            log::info!(
+                user:serde = UserLogContext::new(user.id),
+                util:serde = tracker.log_context();
+                "Successfully completed web page screenshot tracker scrape job ('{}').",
+                pending_scrape.job_id
+            );
+
+            let tracker_name = tracker.name.clone();
+            Self::try_notify_user(
+                api,
+                tracker,
+                NotificationContentTemplate::WebPageScreenshotTrackerChanges {
+                    tracker_name,
+                    content: Ok(format!(
+                        "{:.2}%",
+                        revision.data.diff.unwrap_or_default() * 100.0
+                    )),
+                },
+            )
+            .await;
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_dns_records<DR: DnsResolver, ET: EmailTransport>(
+        api: Arc<Api<DR, ET>>,
+        scheduler: JobScheduler,
+        jobs_to_fetch: &HashSet<Uuid>,
+    ) -> anyhow::Result<()>
+    where
+        ET::Error: EmailTransportError,
+    {
+        // Fetch all DNS records trackers jobs that are pending processing, and process them
+        // grouped by the target host to not overload any single host.
+        let pending_trackers = Self::collect_pending_trackers(
+            &api,
+            &scheduler,
+            jobs_to_fetch,
+            api.web_scraping_system().get_pending_dns_records_trackers(),
+        )
+        .await?;
+        for pending_trackers in Self::batch_by_dependencies(pending_trackers) {
+            Self::fetch_by_host(
+                &api.config.scheduler,
+                pending_trackers,
+                |(tracker, job_id)| Self::fetch_dns_records_tracker(&api, tracker, job_id),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_dns_records_tracker<DR: DnsResolver, ET: EmailTransport>(
+        api: &Api<DR, ET>,
+        tracker: WebPageTracker<WebPageDnsRecordsTrackerTag>,
+        job_id: Uuid,
+    ) -> anyhow::Result<()>
+    where
+        ET::Error: EmailTransportError,
+    {
+        // Try to create a new revision. If a revision is returned that means that tracker
+        // detected changes in the DNS records.
+        let fetch_start = Instant::now();
+        let Some(user) = api.users().get(tracker.user_id).await? else {
+            log::error!(
+                user:serde = UserLogContext::new(tracker.user_id),
+                util:serde = tracker.log_context();
+                "Cannot find the user for the tracker."
+            );
+            return Ok(());
+        };
+
+        if !Self::check_precondition(api, &user, &tracker, job_id).await? {
+            return Ok(());
+        }
+
+        let new_revision = match api
+            .web_scraping(&user)
+            .create_dns_records_tracker_revision(tracker.id)
+            .await
+        {
+            Ok(new_revision) => new_revision,
+            Err(err) => {
+                return Self::handle_revision_error(api, tracker, job_id, fetch_start, err).await;
+            }
+        };
+
+        let execution_time = fetch_start.elapsed();
+        log::info!(
+            user:serde = UserLogContext::new(tracker.user_id),
+            util:serde = tracker.log_context(),
+            metrics:serde = MetricsContext::default().with_job_execution_time(execution_time);
+            "Successfully created web page tracker history revision in {}.",
+            humantime::format_duration(execution_time)
+        );
+
+        Self::schedule_adaptive_tick(api, &tracker, job_id).await;
+
+        if let Some(revision) = new_revision {
+            let tracker_name = tracker.name.clone();
+            Self::try_notify_user(
+                api,
+                tracker,
+                NotificationContentTemplate::WebPageDnsRecordsTrackerChanges {
+                    tracker_name,
+                    content: Ok(format!(
+                        "{} added, {} removed",
+                        revision
+                            .data
+                            .records
+                            .iter()
+                            .filter(|record| record.diff_status
+                                == Some(WebPageDnsRecordDiffStatus::Added))
+                            .count(),
+                        revision
+                            .data
+                            .records
+                            .iter()
+                            .filter(|record| record.diff_status
+                                == Some(WebPageDnsRecordDiffStatus::Removed))
+                            .count()
+                    )),
+                },
+            )
+            .await;
+        }
+
+        api.db.reset_scheduler_job_state(job_id, false).await?;
+
+        Ok(())
+    }
+
+    /// Collects all pending trackers from the specified stream, skipping trackers that cannot be
+    /// fetched or whose jobs aren't selected for the current run, and pairs every tracker with its
+    /// job ID, target host and the ID of its owner.
+    async fn collect_pending_trackers<DR: DnsResolver, ET: EmailTransport, Tag: WebPageTrackerTag>(
+        api: &Api<DR, ET>,
+        scheduler: &JobScheduler,
+        jobs_to_fetch: &HashSet<Uuid>,
+        pending_trackers: impl Stream<Item = anyhow::Result<WebPageTracker<Tag>>>,
+    ) -> anyhow::Result<Vec<((String, UserId), (WebPageTracker<Tag>, Uuid))>>
+    where
+        ET::Error: EmailTransportError,
+    {
+        pin_mut!(pending_trackers);
+
+        let mut trackers = vec![];
+        while let Some(tracker) = pending_trackers.next().await {
+            let tracker = tracker?;
+            if let Some(job_id) = tracker.job_id {
+                if !jobs_to_fetch.contains(&job_id) {
+                    continue;
+                }
+            }
+
+            if let Some((tracker, job_id)) = Self::validate_tracker(api, scheduler, tracker).await?
+            {
+                let host = tracker.url.host_str().unwrap_or_default().to_string();
+                trackers.push(((host, tracker.user_id), (tracker, job_id)));
+            }
+        }
+
+        Ok(trackers)
+    }
+
+    /// Splits pending trackers into consecutive batches, so that trackers that depend on other
+    /// pending trackers (directly or through the precondition) are fetched only after all their
+    /// dependencies are fetched. Dependencies that aren't pending don't affect the order.
+    fn batch_by_dependencies<K, T, Tag: WebPageTrackerTag>(
+        mut pending_trackers: Vec<PendingTracker<K, Tag, T>>,
+    ) -> Vec<Vec<PendingTracker<K, Tag, T>>> {
+        let mut batches = vec![];
+        while !pending_trackers.is_empty() {
+            let pending_tracker_ids = pending_trackers
+                .iter()
+                .map(|(_, (tracker, _))| tracker.id)
+                .collect::<HashSet<_>>();
+            let (batch, blocked_trackers): (Vec<_>, Vec<_>) =
+                pending_trackers.into_iter().partition(|(_, (tracker, _))| {
+                    tracker
+                        .settings
+                        .depends_on
+                        .into_iter()
+                        .chain(
+                            tracker
+                                .settings
+                                .precondition
+                                .as_ref()
+                                .map(|precondition| precondition.tracker_id),
+                        )
+                        .all(|dependency_id| !pending_tracker_ids.contains(&dependency_id))
+                });
+
+            // Dependency cycles are rejected when trackers are saved, but if there are any, fetch
+            // the remaining trackers in a single batch instead of skipping them.
+            if batch.is_empty() {
+                batches.push(blocked_trackers);
+                break;
             }
 
-            api.db.reset_scheduler_job_state(job_id, false).await?;
+            batches.push(batch);
+            pending_trackers = blocked_trackers;
         }
 
+        batches
+    }
+
+    /// Checks whether the tracker precondition is met. If it's not, the tracker run is skipped and
+    /// its job is reset to run on the next schedule.
+    async fn check_precondition<DR: DnsResolver, ET: EmailTransport, Tag: WebPageTrackerTag>(
+        api: &Api<DR, ET>,
+        user: &User,
+        tracker: &WebPageTracker<Tag>,
+        job_id: Uuid,
+    ) -> anyhow::Result<bool> {
+        if api
+            .web_scraping(user)
+            .is_web_page_tracker_precondition_met(tracker)
+            .await?
+        {
+            return Ok(true);
+        }
+
+        log::info!(
+            user:serde = UserLogContext::new(tracker.user_id),
+            util:serde = tracker.log_context();
+            "Skipping web page tracker run since its precondition isn't met."
+        );
+        api.db.reset_scheduler_job_state(job_id, false).await?;
+
+        Ok(false)
+    }
+
+    /// Fetches items grouped by the target host. Items that target the same host are fetched
+    /// sequentially with at least the configured interval between the consecutive fetches, while
+    /// items that target different hosts are fetched concurrently, bounded by the configured
+    /// concurrency limit. Concurrency slots are distributed across the users that own the items in
+    /// a round-robin fashion, and hosts of the same user wait for a slot in a FIFO order, so that
+    /// every user and host make progress even if some have many more pending items than others.
+    async fn fetch_by_host<T, F, Fut>(
+        config: &SchedulerJobsConfig,
+        items: Vec<((String, UserId), T)>,
+        fetch: F,
+    ) -> anyhow::Result<()>
+    where
+        F: Fn(T) -> Fut,
+        Fut: Future<Output = anyhow::Result<()>>,
+    {
+        let mut hosts = BTreeMap::<_, Vec<_>>::new();
+        for ((host, user_id), item) in items {
+            hosts.entry(host).or_default().push((user_id, item));
+        }
+
+        let (fetch, host_interval) = (&fetch, config.web_page_trackers_fetch_host_interval);
+        let fetch_slots =
+            WebPageTrackersFetchSlots::new(config.web_page_trackers_fetch_concurrency);
+        let fetch_slots = &fetch_slots;
+        try_join_all(hosts.into_values().map(|items| async move {
+            let mut last_fetch_start: Option<Instant> = None;
+            for (user_id, item) in items {
+                if let Some(last_fetch_start) = last_fetch_start {
+                    tokio::time::sleep(host_interval.saturating_sub(last_fetch_start.elapsed()))
+                        .await;
+                }
+
+                let _slot = fetch_slots.acquire(user_id).await?;
+                last_fetch_start = Some(Instant::now());
+                fetch(item).await?;
+            }
+
+            Ok::<_, anyhow::Error>(())
+        }))
+        .await?;
+
         Ok(())
     }
 
@@ -351,18 +844,228 @@ impl WebPageTrackersFetchJob {
             log::warn!(
                 user:serde = UserLogContext::new(tracker.user_id),
                 util:serde = tracker.log_context(),
-                job:serde = JobLogContext::new(job_id);
-                "Found a pending web page tracker that doesn't support tracking, the job will be removed."
+                job:serde = JobLogContext::new(job_id);
+                "Found a pending web page tracker that doesn't support tracking, the job will be removed."
+            );
+
+            scheduler.remove(&job_id).await?;
+            api.web_scraping_system()
+                .update_web_page_tracker_job(tracker.id, None)
+                .await?;
+            return Ok(None);
+        }
+
+        Ok(Some((tracker, job_id)))
+    }
+
+    /// If the content tracker captures JSON API responses, monitors status of multiple URLs, or
+    /// tracks content in the structured format, returns a summary of the changes between the
+    /// specified revision and the revision that precedes it (e.g. `status changed from 200 to 503,
+    /// body changed`, `https://secutils.dev/api: 200 → 503`, or `Added Disallow: /admin`).
+    async fn get_response_changes<DR: DnsResolver, ET: EmailTransport>(
+        api: &Api<DR, ET>,
+        tracker: &WebPageTracker<WebPageContentTrackerTag>,
+        revision: &WebPageDataRevision<WebPageContentTrackerTag>,
+    ) -> anyhow::Result<Option<String>> {
+        let monitors_multiple_urls =
+            tracker.settings.status_only && tracker.settings.additional_urls.is_some();
+        if tracker.settings.capture_response.is_none()
+            && tracker.settings.content_format.is_none()
+            && !monitors_multiple_urls
+        {
+            return Ok(None);
+        }
+
+        let web_scraping = api.db.web_scraping(tracker.user_id);
+        let revisions = web_scraping
+            .get_web_page_tracker_history::<WebPageContentTrackerTag>(tracker.id)
+            .await?;
+        let baseline_revision_id = web_scraping
+            .get_web_page_tracker_baseline_revision_id(tracker.id)
+            .await?;
+
+        // Changes are described relative to the baseline revision, if it's pinned.
+        let previous_revisions = revisions
+            .iter()
+            .take_while(|previous_revision| previous_revision.id != revision.id);
+        let Some(previous_revision) = previous_revisions
+            .clone()
+            .find(|previous_revision| Some(previous_revision.id) == baseline_revision_id)
+            .or_else(|| previous_revisions.last())
+        else {
+            return Ok(None);
+        };
+
+        if let Some(format) = tracker.settings.content_format {
+            // Content that cannot be parsed is reported as is, without a summary.
+            return Ok(
+                WebPageContentStructure::parse(format, &previous_revision.data)
+                    .zip(WebPageContentStructure::parse(format, &revision.data))
+                    .and_then(|(previous_structure, structure)| {
+                        structure.describe_changes(&previous_structure)
+                    }),
+            );
+        }
+
+        if monitors_multiple_urls {
+            let previous_statuses =
+                serde_json::from_str::<WebPageContentStatuses>(&previous_revision.data)?;
+            let statuses = serde_json::from_str::<WebPageContentStatuses>(&revision.data)?;
+            return Ok(statuses.describe_changes(&previous_statuses));
+        }
+
+        let previous_response =
+            serde_json::from_str::<WebPageContentResponse>(&previous_revision.data)?;
+        let response = serde_json::from_str::<WebPageContentResponse>(&revision.data)?;
+        Ok(response.describe_changes(&previous_response))
+    }
+
+    /// If the tracker job has an adaptive interval configured, postpones the next job run based on
+    /// how often the tracked web page has changed recently. The next run is aligned with the
+    /// tracker schedule, since the schedule defines the most frequent runs possible.
+    async fn schedule_adaptive_tick<DR: DnsResolver, ET: EmailTransport, Tag: WebPageTrackerTag>(
+        api: &Api<DR, ET>,
+        tracker: &WebPageTracker<Tag>,
+        job_id: Uuid,
+    ) {
+        let Some((schedule, adaptive_interval)) =
+            tracker.job_config.as_ref().and_then(|job_config| {
+                Some((job_config.schedule.as_str(), job_config.adaptive_interval?))
+            })
+        else {
+            return;
+        };
+
+        let next_tick = async {
+            let changes = api
+                .db
+                .web_scraping(tracker.user_id)
+                .get_web_page_tracker_history::<Tag>(tracker.id)
+                .await?
+                .into_iter()
+                .map(|revision| revision.created_at)
+                .collect::<Vec<_>>();
+            let now = OffsetDateTime::now_utc();
+            let earliest_next_tick = now + adaptive_interval.interval(&changes, now);
+            let earliest_next_tick =
+                chrono::DateTime::from_timestamp(earliest_next_tick.unix_timestamp(), 0)
+                    .ok_or_else(|| anyhow!("Invalid next tick: {earliest_next_tick}."))?;
+            let next_tick = Schedule::try_from(schedule)?
+                .after(&earliest_next_tick)
+                .next()
+                .ok_or_else(|| anyhow!("Schedule doesn't have upcoming occurrences."))?;
+            let next_tick = OffsetDateTime::from_unix_timestamp(next_tick.timestamp())?;
+
+            api.db
+                .update_scheduler_job_next_tick(job_id, next_tick)
+                .await?;
+
+            Ok::<_, anyhow::Error>(next_tick)
+        };
+
+        match next_tick.await {
+            Ok(next_tick) => log::debug!(
+                user:serde = UserLogContext::new(tracker.user_id),
+                util:serde = tracker.log_context(),
+                job:serde = JobLogContext::new(job_id);
+                "Scheduled next web page tracker run at {next_tick} based on the adaptive interval."
+            ),
+            Err(err) => log::error!(
+                user:serde = UserLogContext::new(tracker.user_id),
+                util:serde = tracker.log_context(),
+                job:serde = JobLogContext::new(job_id);
+                "Failed to schedule next web page tracker run based on the adaptive interval: {err:?}"
+            ),
+        }
+    }
+
+    /// Handles the failure to create a new revision of the web page tracker. If the tracker has a
+    /// retry strategy, schedules a retry, otherwise notifies the user about the error and
+    /// re-schedules the job.
+    async fn handle_revision_error<DR: DnsResolver, ET: EmailTransport, Tag: WebPageTrackerTag>(
+        api: &Api<DR, ET>,
+        tracker: WebPageTracker<Tag>,
+        job_id: Uuid,
+        fetch_start: Instant,
+        err: anyhow::Error,
+    ) -> anyhow::Result<()>
+    where
+        ET::Error: EmailTransportError,
+    {
+        let execution_time = fetch_start.elapsed();
+        log::error!(
+            user:serde = UserLogContext::new(tracker.user_id),
+            util:serde = tracker.log_context(),
+            metrics:serde = MetricsContext::default().with_job_execution_time(execution_time);
+            "Failed to create web page tracker history revision: {err:?}"
+        );
+
+        // Check if the tracker has a retry strategy.
+        let retry_strategy = tracker
+            .job_config
+            .as_ref()
+            .and_then(|job_config| job_config.retry_strategy);
+        let retry_state = if let Some(retry_strategy) = retry_strategy {
+            api.scheduler()
+                .schedule_retry(job_id, &retry_strategy)
+                .await?
+        } else {
+            None
+        };
+
+        let tracker_kind = match Tag::KIND {
+            WebPageTrackerKind::WebPageResources => "resources",
+            WebPageTrackerKind::WebPageContent => "content",
+            WebPageTrackerKind::WebPageScreenshot => "screenshot",
+            WebPageTrackerKind::WebPageDnsRecords => "DNS records",
+        };
+        if let Some(retry) = retry_state {
+            log::warn!(
+                user:serde = UserLogContext::new(tracker.user_id),
+                util:serde = tracker.log_context(),
+                metrics:serde = MetricsContext::default().with_job_retries(retry.attempts);
+                "Scheduled a retry to create web page {tracker_kind} tracker history revision at {}.",
+                retry.next_at,
             );
-
-            scheduler.remove(&job_id).await?;
-            api.web_scraping_system()
-                .update_web_page_tracker_job(tracker.id, None)
-                .await?;
-            return Ok(None);
+            return Ok(());
         }
 
-        Ok(Some((tracker, job_id)))
+        // Notify user about the error and re-schedule the job.
+        let tracker_name = tracker.name.clone();
+        let error = err
+            .downcast::<SecutilsError>()
+            .map(|err| format!("{}", err))
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        let template = match Tag::KIND {
+            WebPageTrackerKind::WebPageResources => {
+                NotificationContentTemplate::WebPageResourcesTrackerChanges {
+                    tracker_name,
+                    content: Err(error),
+                }
+            }
+            WebPageTrackerKind::WebPageContent => {
+                NotificationContentTemplate::WebPageContentTrackerChanges {
+                    tracker_name,
+                    content: Err(error),
+                }
+            }
+            WebPageTrackerKind::WebPageScreenshot => {
+                NotificationContentTemplate::WebPageScreenshotTrackerChanges {
+                    tracker_name,
+                    content: Err(error),
+                }
+            }
+            WebPageTrackerKind::WebPageDnsRecords => {
+                NotificationContentTemplate::WebPageDnsRecordsTrackerChanges {
+                    tracker_name,
+                    content: Err(error),
+                }
+            }
+        };
+        Self::try_notify_user(api, tracker, template).await;
+        api.db.reset_scheduler_job_state(job_id, false).await?;
+
+        Ok(())
     }
 
     async fn try_notify_user<DR: DnsResolver, ET: EmailTransport, Tag: WebPageTrackerTag>(
@@ -381,20 +1084,129 @@ impl WebPageTrackersFetchJob {
             return;
         }
 
-        let notification_schedule_result = api
-            .notifications()
-            .schedule_notification(
-                NotificationDestination::User(tracker.user_id),
-                NotificationContent::Template(template),
-                OffsetDateTime::now_utc(),
-            )
-            .await;
-        if let Err(err) = notification_schedule_result {
-            log::error!(
-                user:serde = UserLogContext::new(tracker.user_id),
-                util:serde = tracker.log_context();
-                "Failed to schedule a notification for web page tracker: {err:?}."
-            );
+        // Notification cooldown applies only to the change notifications, errors are always
+        // reported immediately.
+        let is_change_notification = matches!(
+            template,
+            NotificationContentTemplate::WebPageResourcesTrackerChanges { content: Ok(_), .. }
+                | NotificationContentTemplate::WebPageContentTrackerChanges { content: Ok(_), .. }
+                | NotificationContentTemplate::WebPageScreenshotTrackerChanges {
+                    content: Ok(_),
+                    ..
+                }
+                | NotificationContentTemplate::WebPageDnsRecordsTrackerChanges {
+                    content: Ok(_),
+                    ..
+                }
+        );
+        let notification_cooldown = tracker
+            .settings
+            .notification_cooldown
+            .filter(|_| is_change_notification);
+
+        let now = OffsetDateTime::now_utc();
+        let (template, scheduled_at) = if let Some(notification_cooldown) = notification_cooldown {
+            let last_notified_at = match api
+                .web_scraping_system()
+                .get_web_page_tracker_last_notified_at(tracker.id)
+                .await
+            {
+                Ok(last_notified_at) => last_notified_at,
+                Err(err) => {
+                    log::error!(
+                        user:serde = UserLogContext::new(tracker.user_id),
+                        util:serde = tracker.log_context();
+                        "Failed to retrieve last notification time for web page tracker: {err:?}."
+                    );
+                    None
+                }
+            };
+
+            match last_notified_at {
+                // Summary notification is already scheduled and will include this change.
+                Some(last_notified_at) if last_notified_at > now => {
+                    log::debug!(
+                        user:serde = UserLogContext::new(tracker.user_id),
+                        util:serde = tracker.log_context();
+                        "Web page tracker change will be included into the summary notification scheduled at {last_notified_at}."
+                    );
+                    return;
+                }
+                // The cooldown is still active, schedule a summary notification for when it ends.
+                Some(last_notified_at) if now < last_notified_at + notification_cooldown => (
+                    NotificationContentTemplate::WebPageTrackerChangesSummary {
+                        user_id: tracker.user_id,
+                        tracker_id: tracker.id,
+                        tracker_kind: Tag::KIND,
+                        tracker_name: tracker.name.clone(),
+                        since: last_notified_at,
+                    },
+                    last_notified_at + notification_cooldown,
+                ),
+                _ => (template, now),
+            }
+        } else {
+            (template, now)
+        };
+
+        // Notification is additionally delivered to the tracker webhook, if it's configured.
+        let webhook_destination = match api
+            .web_scraping_system()
+            .get_web_page_tracker_webhook(tracker.id)
+            .await
+        {
+            Ok(webhook) => webhook.map(|_| NotificationDestination::TrackerWebhook {
+                user_id: tracker.user_id,
+                tracker_id: tracker.id,
+            }),
+            Err(err) => {
+                log::error!(
+                    user:serde = UserLogContext::new(tracker.user_id),
+                    util:serde = tracker.log_context();
+                    "Failed to retrieve webhook for web page tracker: {err:?}."
+                );
+                None
+            }
+        };
+
+        for destination in [
+            Some(NotificationDestination::User(tracker.user_id)),
+            webhook_destination,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            let notification_schedule_result = api
+                .notifications()
+                .schedule_notification_with_urgency(
+                    destination,
+                    NotificationContent::Template(template.clone()),
+                    scheduled_at,
+                    tracker.settings.urgent_notifications,
+                )
+                .await;
+            if let Err(err) = notification_schedule_result {
+                log::error!(
+                    user:serde = UserLogContext::new(tracker.user_id),
+                    util:serde = tracker.log_context();
+                    "Failed to schedule a notification for web page tracker: {err:?}."
+                );
+                return;
+            }
+        }
+
+        if notification_cooldown.is_some() {
+            if let Err(err) = api
+                .web_scraping_system()
+                .set_web_page_tracker_last_notified_at(tracker.id, scheduled_at)
+                .await
+            {
+                log::error!(
+                    user:serde = UserLogContext::new(tracker.user_id),
+                    util:serde = tracker.log_context();
+                    "Failed to update last notification time for web page tracker: {err:?}."
+                );
+            }
         }
     }
 }
@@ -403,25 +1215,32 @@ impl WebPageTrackersFetchJob {
 mod tests {
     use super::WebPageTrackersFetchJob;
     use crate::{
+        config::SchedulerJobsConfig,
+        notifications::{NotificationContent, NotificationContentTemplate},
         scheduler::{
             scheduler_job::SchedulerJob, scheduler_jobs::WebPageTrackersTriggerJob,
             SchedulerJobConfig, SchedulerJobRetryStrategy,
         },
         tests::{
             mock_api_with_config, mock_config, mock_get_scheduler_job, mock_schedule_in_sec,
-            mock_schedule_in_secs, mock_scheduler, mock_scheduler_job, mock_user,
+            mock_schedule_in_secs, mock_scheduler, mock_scheduler_job, mock_upsert_scheduler_job,
+            mock_user,
         },
+        users::UserId,
         utils::web_scraping::{
             tests::{
-                WebPageTrackerCreateParams, WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME,
+                MockWebPageTrackerBuilder, WebPageTrackerCreateParams,
+                WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME,
                 WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME,
             },
+            WebPageContentAssertion, WebPageContentAssertionOperator, WebPageContentFingerprint,
             WebPageContentTrackerTag, WebPageDataRevision, WebPageResource, WebPageResourceContent,
-            WebPageResourceContentData, WebPageResourcesData, WebPageResourcesTrackerTag,
-            WebPageTracker, WebPageTrackerKind, WebPageTrackerSettings, WebScraperContentRequest,
-            WebScraperContentRequestScripts, WebScraperContentResponse, WebScraperErrorResponse,
-            WebScraperResource, WebScraperResourcesRequest, WebScraperResourcesRequestScripts,
-            WebScraperResourcesResponse,
+            WebPageResourceContentData, WebPageResourcesData, WebPageResourcesSizeBudget,
+            WebPageResourcesTrackerTag, WebPageTracker, WebPageTrackerKind,
+            WebPageTrackerPrecondition, WebPageTrackerPreconditionOperator, WebPageTrackerSettings,
+            WebScraperContentRequest, WebScraperContentRequestScripts, WebScraperContentResponse,
+            WebScraperErrorResponse, WebScraperResource, WebScraperResourcesRequest,
+            WebScraperResourcesRequestScripts, WebScraperResourcesResponse,
         },
     };
     use cron::Schedule;
@@ -429,7 +1248,15 @@ mod tests {
     use httpmock::MockServer;
     use insta::assert_debug_snapshot;
     use sqlx::PgPool;
-    use std::{default::Default, ops::Add, sync::Arc, time::Duration};
+    use std::{
+        default::Default,
+        ops::Add,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+        time::{Duration, Instant},
+    };
     use time::OffsetDateTime;
     use url::Url;
     use uuid::{uuid, Uuid};
@@ -535,13 +1362,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 0,
                     delay: Default::default(),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -567,13 +1394,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 0,
                     delay: Default::default(),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -657,11 +1484,13 @@ mod tests {
                         .into_iter()
                         .collect(),
                 ),
+                ..Default::default()
             },
             job_config: Some(SchedulerJobConfig {
                 schedule: tracker_schedule,
                 retry_strategy: None,
                 notifications: true,
+                adaptive_interval: None,
             }),
             user_id: user.id,
             job_id: Some(trigger_job_id),
@@ -838,11 +1667,13 @@ mod tests {
                         .into_iter()
                         .collect(),
                 ),
+                ..Default::default()
             },
             job_config: Some(SchedulerJobConfig {
                 schedule: tracker_schedule,
                 retry_strategy: None,
                 notifications: false,
+                adaptive_interval: None,
             }),
             user_id: user.id,
             job_id: Some(trigger_job_id),
@@ -872,6 +1703,7 @@ mod tests {
         let content = WebScraperContentResponse {
             timestamp: OffsetDateTime::from_unix_timestamp(946720800)?,
             content: "some-content".to_string(),
+            cookies: None,
         };
 
         let content_mock = server.mock(|when, then| {
@@ -948,7 +1780,200 @@ mod tests {
     }
 
     #[sqlx::test]
-    async fn schedules_notification_when_resources_change(pool: PgPool) -> anyhow::Result<()> {
+    async fn schedules_notification_when_resources_change(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        config.scheduler.web_page_trackers_fetch =
+            Schedule::try_from(mock_schedule_in_sec(3).as_str())?;
+
+        let server = MockServer::start();
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let mut scheduler = mock_scheduler(&pool).await?;
+
+        let user = mock_user()?;
+        let api = Arc::new(mock_api_with_config(pool, config).await?);
+
+        // Make sure that the tracker is only run once during a single minute (2 seconds after the
+        // current second).
+        let tracker_schedule = mock_schedule_in_sec(1);
+
+        // Create user, tracker and tracker job.
+        api.db.upsert_user(user.clone()).await?;
+
+        let trigger_job_id = scheduler
+            .add(
+                WebPageTrackersTriggerJob::create(
+                    api.clone(),
+                    tracker_schedule.clone(),
+                    WebPageTrackerKind::WebPageResources,
+                )
+                .await?,
+            )
+            .await?;
+        let tracker = WebPageTracker::<WebPageResourcesTrackerTag> {
+            id: Uuid::now_v7(),
+            name: "tracker-one".to_string(),
+            url: "https://localhost:1234/my/app?q=2".parse()?,
+            settings: WebPageTrackerSettings {
+                revisions: 2,
+                delay: Duration::from_secs(2),
+                ..Default::default()
+            },
+            job_config: Some(SchedulerJobConfig {
+                schedule: tracker_schedule,
+                retry_strategy: None,
+                notifications: true,
+                adaptive_interval: None,
+            }),
+            user_id: user.id,
+            job_id: Some(trigger_job_id),
+            // Preserve timestamp only up to seconds.
+            created_at: OffsetDateTime::from_unix_timestamp(
+                OffsetDateTime::now_utc().unix_timestamp(),
+            )?,
+            // Preserve timestamp only up to seconds.
+            updated_at: OffsetDateTime::from_unix_timestamp(
+                OffsetDateTime::now_utc().unix_timestamp(),
+            )?,
+            meta: None,
+        };
+
+        // Insert tracker directly to DB to bypass schedule validation.
+        api.db
+            .web_scraping(user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+        api.db
+            .web_scraping(user.id)
+            .insert_web_page_tracker_history_revision::<WebPageResourcesTrackerTag>(
+                &WebPageDataRevision {
+                    id: uuid!("00000000-0000-0000-0000-000000000001"),
+                    tracker_id: tracker.id,
+                    created_at: OffsetDateTime::from_unix_timestamp(946720700)?,
+                    data: WebPageResourcesData {
+                        scripts: vec![],
+                        styles: vec![],
+                        categories: None,
+                        sizes: None,
+                        truncated: false,
+                    },
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
+                },
+            )
+            .await?;
+
+        // Schedule fetch job
+        scheduler
+            .add(WebPageTrackersFetchJob::create(api.clone()).await?)
+            .await?;
+
+        // Create a mock
+        let resources = WebScraperResourcesResponse {
+            timestamp: OffsetDateTime::from_unix_timestamp(946720800)?,
+            scripts: vec![WebScraperResource {
+                url: Some(Url::parse("http://localhost:1234/script.js")?),
+                content: Some(WebPageResourceContent {
+                    data: WebPageResourceContentData::Sha1("some-digest".to_string()),
+                    size: 123,
+                }),
+            }],
+            styles: vec![WebScraperResource {
+                url: Some(Url::parse("http://localhost:1234/style.css")?),
+                content: Some(WebPageResourceContent {
+                    data: WebPageResourceContentData::Sha1("some-other-digest".to_string()),
+                    size: 321,
+                }),
+            }],
+        };
+
+        let resources_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/resources")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperResourcesRequest::with_default_parameters(&tracker.url)
+                            .set_delay(Duration::from_millis(2000)),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&resources);
+        });
+
+        // Start scheduler and wait for a few seconds, then stop it.
+        scheduler.start().await?;
+
+        while api
+            .db
+            .get_notification_ids(
+                OffsetDateTime::now_utc().add(Duration::from_secs(3600 * 24 * 365)),
+                10,
+            )
+            .collect::<Vec<_>>()
+            .await
+            .is_empty()
+        {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        scheduler.shutdown().await?;
+
+        resources_mock.assert();
+
+        let mut notification_ids = api
+            .db
+            .get_notification_ids(
+                OffsetDateTime::now_utc().add(Duration::from_secs(3600 * 24 * 365)),
+                10,
+            )
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(notification_ids.len(), 1);
+
+        let notification = api.db.get_notification(notification_ids.remove(0)?).await?;
+        assert_debug_snapshot!(notification.map(|notification| (notification.destination, notification.content)), @r###"
+        Some(
+            (
+                User(
+                    UserId(
+                        00000000-0000-0000-0000-000000000001,
+                    ),
+                ),
+                Template(
+                    WebPageResourcesTrackerChanges {
+                        tracker_name: "tracker-one",
+                        content: Ok(
+                            2,
+                        ),
+                    },
+                ),
+            ),
+        )
+        "###);
+
+        assert_eq!(
+            api.web_scraping(&user)
+                .get_resources_tracker_history(tracker.id, Default::default())
+                .await?
+                .len(),
+            2
+        );
+        assert!(!mock_get_scheduler_job(&api.db, trigger_job_id)
+            .await?
+            .and_then(|job| job.stopped)
+            .unwrap_or_default());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn schedules_notification_when_resources_exceed_size_budget(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
         let mut config = mock_config()?;
         config.scheduler.web_page_trackers_fetch =
             Schedule::try_from(mock_schedule_in_sec(3).as_str())?;
@@ -985,13 +2010,17 @@ mod tests {
             settings: WebPageTrackerSettings {
                 revisions: 2,
                 delay: Duration::from_secs(2),
-                scripts: Default::default(),
-                headers: Default::default(),
+                size_budget: Some(WebPageResourcesSizeBudget {
+                    max_total_size: Some(300),
+                    max_growth: None,
+                }),
+                ..Default::default()
             },
             job_config: Some(SchedulerJobConfig {
                 schedule: tracker_schedule,
                 retry_strategy: None,
                 notifications: true,
+                adaptive_interval: None,
             }),
             user_id: user.id,
             job_id: Some(trigger_job_id),
@@ -1019,9 +2048,24 @@ mod tests {
                     tracker_id: tracker.id,
                     created_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                     data: WebPageResourcesData {
-                        scripts: vec![],
+                        scripts: vec![WebPageResource {
+                            url: Some(Url::parse("http://localhost:1234/script.js")?),
+                            content: Some(WebPageResourceContent {
+                                data: WebPageResourceContentData::Sha1("old-digest".to_string()),
+                                size: 100,
+                            }),
+                            diff_status: None,
+                            category: None,
+                        }],
                         styles: vec![],
+                        categories: None,
+                        sizes: None,
+                        truncated: false,
                     },
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -1076,7 +2120,8 @@ mod tests {
             )
             .collect::<Vec<_>>()
             .await
-            .is_empty()
+            .len()
+            < 2
         {
             tokio::time::sleep(Duration::from_millis(100)).await;
         }
@@ -1085,7 +2130,7 @@ mod tests {
 
         resources_mock.assert();
 
-        let mut notification_ids = api
+        let notification_ids = api
             .db
             .get_notification_ids(
                 OffsetDateTime::now_utc().add(Duration::from_secs(3600 * 24 * 365)),
@@ -1093,17 +2138,31 @@ mod tests {
             )
             .collect::<Vec<_>>()
             .await;
-        assert_eq!(notification_ids.len(), 1);
-
-        let notification = api.db.get_notification(notification_ids.remove(0)?).await?;
-        assert_debug_snapshot!(notification.map(|notification| (notification.destination, notification.content)), @r###"
-        Some(
-            (
-                User(
-                    UserId(
-                        00000000-0000-0000-0000-000000000001,
-                    ),
+        assert_eq!(notification_ids.len(), 2);
+
+        let mut notifications = vec![];
+        for notification_id in notification_ids {
+            notifications.push(
+                api.db
+                    .get_notification(notification_id?)
+                    .await?
+                    .map(|notification| notification.content),
+            );
+        }
+        assert_debug_snapshot!(notifications, @r###"
+        [
+            Some(
+                Template(
+                    WebPageResourcesTrackerSizeBudgetExceeded {
+                        tracker_name: "tracker-one",
+                        total_size: 444,
+                        previous_total_size: Some(
+                            100,
+                        ),
+                    },
                 ),
+            ),
+            Some(
                 Template(
                     WebPageResourcesTrackerChanges {
                         tracker_name: "tracker-one",
@@ -1113,7 +2172,7 @@ mod tests {
                     },
                 ),
             ),
-        )
+        ]
         "###);
 
         assert_eq!(
@@ -1171,13 +2230,13 @@ mod tests {
             settings: WebPageTrackerSettings {
                 revisions: 2,
                 delay: Duration::from_secs(2),
-                scripts: Default::default(),
-                headers: Default::default(),
+                ..Default::default()
             },
             job_config: Some(SchedulerJobConfig {
                 schedule: tracker_schedule,
                 retry_strategy: None,
                 notifications: true,
+                adaptive_interval: None,
             }),
             user_id: user.id,
             job_id: Some(trigger_job_id),
@@ -1207,7 +2266,14 @@ mod tests {
                     data: WebPageResourcesData {
                         scripts: vec![],
                         styles: vec![],
+                        categories: None,
+                        sizes: None,
+                        truncated: false,
                     },
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -1338,16 +2404,17 @@ mod tests {
             settings: WebPageTrackerSettings {
                 revisions: 2,
                 delay: Duration::from_secs(2),
-                scripts: Default::default(),
-                headers: Default::default(),
+                ..Default::default()
             },
             job_config: Some(SchedulerJobConfig {
                 schedule: tracker_schedule,
                 retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
                     interval: Duration::from_secs(1),
                     max_attempts: 1,
+                    max_total_retry_duration: None,
                 }),
                 notifications: true,
+                adaptive_interval: None,
             }),
             user_id: user.id,
             job_id: Some(trigger_job_id),
@@ -1377,7 +2444,14 @@ mod tests {
                     data: WebPageResourcesData {
                         scripts: vec![],
                         styles: vec![],
+                        categories: None,
+                        sizes: None,
+                        truncated: false,
                     },
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -1543,16 +2617,17 @@ mod tests {
             settings: WebPageTrackerSettings {
                 revisions: 2,
                 delay: Duration::from_secs(2),
-                scripts: Default::default(),
-                headers: Default::default(),
+                ..Default::default()
             },
             job_config: Some(SchedulerJobConfig {
                 schedule: tracker_schedule,
                 retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
                     interval: Duration::from_secs(1),
                     max_attempts: 1,
+                    max_total_retry_duration: None,
                 }),
                 notifications: true,
+                adaptive_interval: None,
             }),
             user_id: user.id,
             job_id: Some(trigger_job_id),
@@ -1582,7 +2657,14 @@ mod tests {
                     data: WebPageResourcesData {
                         scripts: vec![],
                         styles: vec![],
+                        categories: None,
+                        sizes: None,
+                        truncated: false,
                     },
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -1783,13 +2865,13 @@ mod tests {
             settings: WebPageTrackerSettings {
                 revisions: 2,
                 delay: Duration::from_secs(2),
-                scripts: Default::default(),
-                headers: Default::default(),
+                ..Default::default()
             },
             job_config: Some(SchedulerJobConfig {
                 schedule: tracker_schedule,
                 retry_strategy: None,
                 notifications: true,
+                adaptive_interval: None,
             }),
             user_id: user.id,
             job_id: Some(trigger_job_id),
@@ -1804,111 +2886,478 @@ mod tests {
             meta: None,
         };
 
-        // Insert tracker directly to DB to bypass schedule validation.
+        // Insert tracker directly to DB to bypass schedule validation.
+        api.db
+            .web_scraping(user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+        api.db
+            .web_scraping(user.id)
+            .insert_web_page_tracker_history_revision::<WebPageContentTrackerTag>(
+                &WebPageDataRevision {
+                    id: uuid!("00000000-0000-0000-0000-000000000001"),
+                    tracker_id: tracker.id,
+                    created_at: OffsetDateTime::from_unix_timestamp(946720700)?,
+                    data: "some-content".to_string(),
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
+                },
+            )
+            .await?;
+
+        // Schedule fetch job
+        scheduler
+            .add(WebPageTrackersFetchJob::create(api.clone()).await?)
+            .await?;
+
+        // Create a mock
+        let content = WebScraperContentResponse {
+            timestamp: OffsetDateTime::from_unix_timestamp(946720800)?,
+            content: "other-content".to_string(),
+            cookies: None,
+        };
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperContentRequest::with_default_parameters(&tracker.url)
+                            .set_delay(Duration::from_millis(2000))
+                            .set_previous_content("some-content"),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content);
+        });
+
+        // Start scheduler and wait for a few seconds, then stop it.
+        scheduler.start().await?;
+
+        while api
+            .db
+            .get_notification_ids(
+                OffsetDateTime::now_utc().add(Duration::from_secs(3600 * 24 * 365)),
+                10,
+            )
+            .collect::<Vec<_>>()
+            .await
+            .is_empty()
+        {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        scheduler.shutdown().await?;
+
+        content_mock.assert();
+
+        let mut notification_ids = api
+            .db
+            .get_notification_ids(
+                OffsetDateTime::now_utc().add(Duration::from_secs(3600 * 24 * 365)),
+                10,
+            )
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(notification_ids.len(), 1);
+
+        let notification = api.db.get_notification(notification_ids.remove(0)?).await?;
+        assert_debug_snapshot!(notification.map(|notification| (notification.destination, notification.content)), @r###"
+        Some(
+            (
+                User(
+                    UserId(
+                        00000000-0000-0000-0000-000000000001,
+                    ),
+                ),
+                Template(
+                    WebPageContentTrackerChanges {
+                        tracker_name: "tracker-one",
+                        content: Ok(
+                            "other-content",
+                        ),
+                    },
+                ),
+            ),
+        )
+        "###);
+
+        assert_eq!(
+            api.web_scraping(&user)
+                .get_content_tracker_history(tracker.id, Default::default())
+                .await?
+                .len(),
+            2
+        );
+        assert!(!mock_get_scheduler_job(&api.db, trigger_job_id)
+            .await?
+            .and_then(|job| job.stopped)
+            .unwrap_or_default());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn does_not_notify_about_first_content_revision(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        let server = MockServer::start();
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let scheduler = mock_scheduler(&pool).await?;
+
+        let user = mock_user()?;
+        let api = Arc::new(mock_api_with_config(pool, config).await?);
+        api.db.upsert_user(user.clone()).await?;
+
+        let tracker_schedule = mock_schedule_in_sec(1);
+        let mut trackers = vec![];
+        for notify_on_first in [false, true] {
+            let trigger_job_id = scheduler
+                .add(
+                    WebPageTrackersTriggerJob::create(
+                        api.clone(),
+                        tracker_schedule.clone(),
+                        WebPageTrackerKind::WebPageContent,
+                    )
+                    .await?,
+                )
+                .await?;
+            let tracker = WebPageTracker::<WebPageContentTrackerTag> {
+                id: Uuid::now_v7(),
+                name: format!("tracker-{notify_on_first}"),
+                url: "https://localhost:1234/my/app?q=2".parse()?,
+                settings: WebPageTrackerSettings {
+                    revisions: 2,
+                    delay: Duration::from_secs(2),
+                    notify_on_first,
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: tracker_schedule.clone(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+                user_id: user.id,
+                job_id: Some(trigger_job_id),
+                // Preserve timestamp only up to seconds.
+                created_at: OffsetDateTime::from_unix_timestamp(
+                    OffsetDateTime::now_utc().unix_timestamp(),
+                )?,
+                // Preserve timestamp only up to seconds.
+                updated_at: OffsetDateTime::from_unix_timestamp(
+                    OffsetDateTime::now_utc().unix_timestamp(),
+                )?,
+                meta: None,
+            };
+            api.db
+                .web_scraping(user.id)
+                .insert_web_page_tracker(&tracker)
+                .await?;
+            trackers.push((tracker, trigger_job_id));
+        }
+
+        let get_notifications_count = || async {
+            api.db
+                .get_notification_ids(
+                    OffsetDateTime::now_utc().add(Duration::from_secs(3600 * 24 * 365)),
+                    10,
+                )
+                .collect::<Vec<_>>()
+                .await
+                .len()
+        };
+
+        // The first revision of the tracker with default settings is recorded silently.
+        let (silent_tracker, silent_tracker_job_id) = trackers.remove(0);
+        let mut content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&WebScraperContentResponse {
+                    timestamp: OffsetDateTime::from_unix_timestamp(946720800).unwrap(),
+                    content: "some-content".to_string(),
+                    cookies: None,
+                });
+        });
+        WebPageTrackersFetchJob::fetch_content_tracker(
+            &api,
+            silent_tracker.clone(),
+            silent_tracker_job_id,
+        )
+        .await?;
+        assert_eq!(get_notifications_count().await, 0);
+
+        // The first revision of the tracker that requested it triggers notification.
+        let (tracker, tracker_job_id) = trackers.remove(0);
+        WebPageTrackersFetchJob::fetch_content_tracker(&api, tracker, tracker_job_id).await?;
+        assert_eq!(get_notifications_count().await, 1);
+        content_mock.assert_hits(2);
+        content_mock.delete();
+
+        // Subsequent changes trigger notifications.
+        content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&WebScraperContentResponse {
+                    timestamp: OffsetDateTime::from_unix_timestamp(946720900).unwrap(),
+                    content: "other-content".to_string(),
+                    cookies: None,
+                });
+        });
+        WebPageTrackersFetchJob::fetch_content_tracker(
+            &api,
+            silent_tracker.clone(),
+            silent_tracker_job_id,
+        )
+        .await?;
+        assert_eq!(get_notifications_count().await, 2);
+        content_mock.assert();
+
+        assert_eq!(
+            api.web_scraping(&user)
+                .get_content_tracker_history(silent_tracker.id, Default::default())
+                .await?
+                .len(),
+            2
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn notifies_about_content_fingerprint_changes(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let scheduler = mock_scheduler(&pool).await?;
+
+        let user = mock_user()?;
+        let api = Arc::new(mock_api_with_config(pool, mock_config()?).await?);
+        api.db.upsert_user(user.clone()).await?;
+
+        let tracker_schedule = mock_schedule_in_sec(1);
+        let trigger_job_id = scheduler
+            .add(
+                WebPageTrackersTriggerJob::create(
+                    api.clone(),
+                    tracker_schedule.clone(),
+                    WebPageTrackerKind::WebPageContent,
+                )
+                .await?,
+            )
+            .await?;
+
+        // Insert tracker directly to DB to bypass public URL validation.
+        let mut tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            Uuid::now_v7(),
+            "favicon",
+            &server.url("/favicon.ico"),
+            3,
+        )?
+        .with_job_config(SchedulerJobConfig {
+            schedule: tracker_schedule,
+            retry_strategy: None,
+            notifications: true,
+            adaptive_interval: None,
+        })
+        .with_job_id(trigger_job_id)
+        .build();
+        tracker.user_id = user.id;
+        tracker.settings.capture_fingerprint = true;
+        api.db
+            .web_scraping(user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+
+        let get_notifications_count = || async {
+            api.db
+                .get_notification_ids(
+                    OffsetDateTime::now_utc().add(Duration::from_secs(3600 * 24 * 365)),
+                    10,
+                )
+                .collect::<Vec<_>>()
+                .await
+                .len()
+        };
+
+        // The first fingerprint is recorded silently, and the same favicon doesn't produce a new
+        // revision.
+        let mut favicon_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/favicon.ico");
+            then.status(200)
+                .header("Content-Type", "image/x-icon")
+                .body([0u8, 1, 2, 3]);
+        });
+        for _ in 0..2 {
+            WebPageTrackersFetchJob::fetch_content_tracker(&api, tracker.clone(), trigger_job_id)
+                .await?;
+        }
+        assert_eq!(get_notifications_count().await, 0);
+        favicon_mock.assert_hits(2);
+        favicon_mock.delete();
+
+        // Changed favicon bytes produce a new revision and notification.
+        let favicon_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/favicon.ico");
+            then.status(200)
+                .header("Content-Type", "image/x-icon")
+                .body([0u8, 1, 2, 4]);
+        });
+        WebPageTrackersFetchJob::fetch_content_tracker(&api, tracker.clone(), trigger_job_id)
+            .await?;
+        assert_eq!(get_notifications_count().await, 1);
+        favicon_mock.assert();
+
+        let history = api
+            .web_scraping(&user)
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?
+            .into_iter()
+            .map(|revision| Ok(serde_json::from_str(&revision.data)?))
+            .collect::<anyhow::Result<Vec<WebPageContentFingerprint>>>()?;
+        assert_eq!(
+            history,
+            vec![
+                WebPageContentFingerprint::new(&[0, 1, 2, 3], Some("image/x-icon")),
+                WebPageContentFingerprint::new(&[0, 1, 2, 4], Some("image/x-icon"))
+            ]
+        );
+        assert_ne!(history[0].sha256, history[1].sha256);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn notifies_about_content_assertions_transitions(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let scheduler = mock_scheduler(&pool).await?;
+
+        let user = mock_user()?;
+        let api = Arc::new(mock_api_with_config(pool, config).await?);
+        api.db.upsert_user(user.clone()).await?;
+
+        let tracker_schedule = mock_schedule_in_sec(1);
+        let trigger_job_id = scheduler
+            .add(
+                WebPageTrackersTriggerJob::create(
+                    api.clone(),
+                    tracker_schedule.clone(),
+                    WebPageTrackerKind::WebPageContent,
+                )
+                .await?,
+            )
+            .await?;
+
+        let mut tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            Uuid::now_v7(),
+            "shop",
+            "https://secutils.dev/shop",
+            5,
+        )?
+        .with_job_config(SchedulerJobConfig {
+            schedule: tracker_schedule,
+            retry_strategy: None,
+            notifications: true,
+            adaptive_interval: None,
+        })
+        .with_job_id(trigger_job_id)
+        .build();
+        tracker.user_id = user.id;
+        tracker.settings.assertions = Some(vec![
+            WebPageContentAssertion {
+                operator: WebPageContentAssertionOperator::Contains,
+                value: "Add to cart".to_string(),
+            },
+            WebPageContentAssertion {
+                operator: WebPageContentAssertionOperator::NotMatches,
+                value: "(?i)error".to_string(),
+            },
+        ]);
         api.db
             .web_scraping(user.id)
             .insert_web_page_tracker(&tracker)
             .await?;
-        api.db
-            .web_scraping(user.id)
-            .insert_web_page_tracker_history_revision::<WebPageContentTrackerTag>(
-                &WebPageDataRevision {
-                    id: uuid!("00000000-0000-0000-0000-000000000001"),
-                    tracker_id: tracker.id,
-                    created_at: OffsetDateTime::from_unix_timestamp(946720700)?,
-                    data: "some-content".to_string(),
-                },
-            )
-            .await?;
-
-        // Schedule fetch job
-        scheduler
-            .add(WebPageTrackersFetchJob::create(api.clone()).await?)
-            .await?;
 
-        // Create a mock
-        let content = WebScraperContentResponse {
-            timestamp: OffsetDateTime::from_unix_timestamp(946720800)?,
-            content: "other-content".to_string(),
+        let get_assertions_notifications = || async {
+            let notification_ids = api
+                .db
+                .get_notification_ids(
+                    OffsetDateTime::now_utc().add(Duration::from_secs(3600 * 24 * 365)),
+                    100,
+                )
+                .collect::<Vec<_>>()
+                .await;
+            let mut notifications = vec![];
+            for notification_id in notification_ids {
+                let notification = api.db.get_notification(notification_id?).await?;
+                if let Some(NotificationContent::Template(
+                    NotificationContentTemplate::WebPageContentTrackerAssertions { passed, .. },
+                )) = notification.map(|notification| notification.content)
+                {
+                    notifications.push(passed);
+                }
+            }
+            anyhow::Ok(notifications)
         };
-        let content_mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/api/web_page/content")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperContentRequest::with_default_parameters(&tracker.url)
-                            .set_delay(Duration::from_millis(2000))
-                            .set_previous_content("some-content"),
-                    )
-                    .unwrap(),
-                );
-            then.status(200)
-                .header("Content-Type", "application/json")
-                .json_body_obj(&content);
-        });
-
-        // Start scheduler and wait for a few seconds, then stop it.
-        scheduler.start().await?;
 
-        while api
-            .db
-            .get_notification_ids(
-                OffsetDateTime::now_utc().add(Duration::from_secs(3600 * 24 * 365)),
-                10,
-            )
-            .collect::<Vec<_>>()
-            .await
-            .is_empty()
+        let contents = [
+            // The first revision passes assertions and is recorded silently.
+            "<button>Add to cart</button>",
+            // Assertions start failing.
+            "<p>Internal Server Error</p>",
+            // Assertions keep failing, no new assertions notifications.
+            "<p>Sold out</p>",
+            // Assertions pass again.
+            "<button>Add to cart</button><p>Only 2 left</p>",
+        ];
+        let expected_notifications = [vec![], vec![false], vec![false], vec![false, true]];
+        for (index, (content, expected_notifications)) in
+            contents.iter().zip(expected_notifications).enumerate()
         {
-            tokio::time::sleep(Duration::from_millis(100)).await;
-        }
-
-        scheduler.shutdown().await?;
-
-        content_mock.assert();
-
-        let mut notification_ids = api
-            .db
-            .get_notification_ids(
-                OffsetDateTime::now_utc().add(Duration::from_secs(3600 * 24 * 365)),
-                10,
-            )
-            .collect::<Vec<_>>()
-            .await;
-        assert_eq!(notification_ids.len(), 1);
+            let mut content_mock = server.mock(|when, then| {
+                when.method(httpmock::Method::POST)
+                    .path("/api/web_page/content");
+                then.status(200)
+                    .header("Content-Type", "application/json")
+                    .json_body_obj(&WebScraperContentResponse {
+                        timestamp: OffsetDateTime::from_unix_timestamp(
+                            946720800 + index as i64 * 100,
+                        )
+                        .unwrap(),
+                        content: content.to_string(),
+                        cookies: None,
+                    });
+            });
+            WebPageTrackersFetchJob::fetch_content_tracker(&api, tracker.clone(), trigger_job_id)
+                .await?;
+            content_mock.assert();
+            content_mock.delete();
 
-        let notification = api.db.get_notification(notification_ids.remove(0)?).await?;
-        assert_debug_snapshot!(notification.map(|notification| (notification.destination, notification.content)), @r###"
-        Some(
-            (
-                User(
-                    UserId(
-                        00000000-0000-0000-0000-000000000001,
-                    ),
-                ),
-                Template(
-                    WebPageContentTrackerChanges {
-                        tracker_name: "tracker-one",
-                        content: Ok(
-                            "other-content",
-                        ),
-                    },
-                ),
-            ),
-        )
-        "###);
+            assert_eq!(
+                get_assertions_notifications().await?,
+                expected_notifications
+            );
+        }
 
+        let history = api
+            .web_scraping(&user)
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?
+            .into_iter()
+            .map(|revision| revision.assertions_passed)
+            .collect::<Vec<_>>();
         assert_eq!(
-            api.web_scraping(&user)
-                .get_content_tracker_history(tracker.id, Default::default())
-                .await?
-                .len(),
-            2
+            history,
+            vec![Some(true), Some(false), Some(false), Some(true)]
         );
-        assert!(!mock_get_scheduler_job(&api.db, trigger_job_id)
-            .await?
-            .and_then(|job| job.stopped)
-            .unwrap_or_default());
 
         Ok(())
     }
@@ -1953,13 +3402,13 @@ mod tests {
             settings: WebPageTrackerSettings {
                 revisions: 2,
                 delay: Duration::from_secs(2),
-                scripts: Default::default(),
-                headers: Default::default(),
+                ..Default::default()
             },
             job_config: Some(SchedulerJobConfig {
                 schedule: tracker_schedule,
                 retry_strategy: None,
                 notifications: true,
+                adaptive_interval: None,
             }),
             user_id: user.id,
             job_id: Some(trigger_job_id),
@@ -1987,6 +3436,10 @@ mod tests {
                     tracker_id: tracker.id,
                     created_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                     data: "some-content".to_string(),
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -2118,16 +3571,17 @@ mod tests {
             settings: WebPageTrackerSettings {
                 revisions: 2,
                 delay: Duration::from_secs(2),
-                scripts: Default::default(),
-                headers: Default::default(),
+                ..Default::default()
             },
             job_config: Some(SchedulerJobConfig {
                 schedule: tracker_schedule,
                 retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
                     interval: Duration::from_secs(1),
                     max_attempts: 1,
+                    max_total_retry_duration: None,
                 }),
                 notifications: true,
+                adaptive_interval: None,
             }),
             user_id: user.id,
             job_id: Some(trigger_job_id),
@@ -2155,6 +3609,10 @@ mod tests {
                     tracker_id: tracker.id,
                     created_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                     data: "some-content".to_string(),
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -2321,16 +3779,17 @@ mod tests {
             settings: WebPageTrackerSettings {
                 revisions: 2,
                 delay: Duration::from_secs(2),
-                scripts: Default::default(),
-                headers: Default::default(),
+                ..Default::default()
             },
             job_config: Some(SchedulerJobConfig {
                 schedule: tracker_schedule,
                 retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
                     interval: Duration::from_secs(1),
                     max_attempts: 1,
+                    max_total_retry_duration: None,
                 }),
                 notifications: true,
+                adaptive_interval: None,
             }),
             user_id: user.id,
             job_id: Some(trigger_job_id),
@@ -2358,6 +3817,10 @@ mod tests {
                     tracker_id: tracker.id,
                     created_at: OffsetDateTime::from_unix_timestamp(946720700)?,
                     data: "some-content".to_string(),
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -2428,6 +3891,7 @@ mod tests {
         let content = WebScraperContentResponse {
             timestamp: OffsetDateTime::from_unix_timestamp(946720800)?,
             content: "other-content".to_string(),
+            cookies: None,
         };
         let content_mock = server.mock(|when, then| {
             when.method(httpmock::Method::POST)
@@ -2507,4 +3971,373 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn throttles_notifications_with_cooldown(pool: PgPool) -> anyhow::Result<()> {
+        let user = mock_user()?;
+        let api = mock_api_with_config(pool, mock_config()?).await?;
+        api.db.upsert_user(user.clone()).await?;
+
+        let tracker = WebPageTracker::<WebPageContentTrackerTag> {
+            id: Uuid::now_v7(),
+            name: "tracker-one".to_string(),
+            url: "https://localhost:1234/my/app?q=2".parse()?,
+            settings: WebPageTrackerSettings {
+                revisions: 2,
+                delay: Duration::from_secs(2),
+                notification_cooldown: Some(Duration::from_secs(3600)),
+                ..Default::default()
+            },
+            job_config: Some(SchedulerJobConfig {
+                schedule: "0 0 * * * *".to_string(),
+                retry_strategy: None,
+                notifications: true,
+                adaptive_interval: None,
+            }),
+            user_id: user.id,
+            job_id: None,
+            // Preserve timestamp only up to seconds.
+            created_at: OffsetDateTime::from_unix_timestamp(
+                OffsetDateTime::now_utc().unix_timestamp(),
+            )?,
+            // Preserve timestamp only up to seconds.
+            updated_at: OffsetDateTime::from_unix_timestamp(
+                OffsetDateTime::now_utc().unix_timestamp(),
+            )?,
+            meta: None,
+        };
+        api.db
+            .web_scraping(user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+
+        // Report three changes in a row: the first one is sent immediately, and the rest are
+        // combined into a single summary notification sent once the cooldown ends.
+        for content in ["content-one", "content-two", "content-three"] {
+            WebPageTrackersFetchJob::try_notify_user(
+                &api,
+                tracker.clone(),
+                NotificationContentTemplate::WebPageContentTrackerChanges {
+                    tracker_name: tracker.name.clone(),
+                    content: Ok(content.to_string()),
+                },
+            )
+            .await;
+        }
+
+        let notification_ids = api
+            .db
+            .get_notification_ids(
+                OffsetDateTime::now_utc().add(Duration::from_secs(3600 * 24 * 365)),
+                10,
+            )
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(notification_ids.len(), 2);
+
+        let immediate_notification = api.db.get_notification(notification_ids[0]).await?.unwrap();
+        assert_eq!(
+            immediate_notification.content,
+            NotificationContent::Template(
+                NotificationContentTemplate::WebPageContentTrackerChanges {
+                    tracker_name: tracker.name.clone(),
+                    content: Ok("content-one".to_string()),
+                }
+            )
+        );
+
+        let summary_notification = api.db.get_notification(notification_ids[1]).await?.unwrap();
+        assert_eq!(
+            summary_notification.scheduled_at - immediate_notification.scheduled_at,
+            Duration::from_secs(3600)
+        );
+        assert_eq!(
+            summary_notification.content,
+            NotificationContent::Template(
+                NotificationContentTemplate::WebPageTrackerChangesSummary {
+                    user_id: user.id,
+                    tracker_id: tracker.id,
+                    tracker_kind: WebPageTrackerKind::WebPageContent,
+                    tracker_name: tracker.name.clone(),
+                    since: immediate_notification.scheduled_at,
+                }
+            )
+        );
+
+        // Errors aren't subject to cooldown.
+        WebPageTrackersFetchJob::try_notify_user(
+            &api,
+            tracker.clone(),
+            NotificationContentTemplate::WebPageContentTrackerChanges {
+                tracker_name: tracker.name.clone(),
+                content: Err("Something went wrong".to_string()),
+            },
+        )
+        .await;
+        assert_eq!(
+            api.db
+                .get_notification_ids(OffsetDateTime::now_utc(), 10)
+                .collect::<Vec<_>>()
+                .await
+                .len(),
+            2
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn skips_trackers_with_unmet_precondition(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api_with_config(pool, mock_config()?).await?;
+        let user = mock_user()?;
+        api.db.upsert_user(user.clone()).await?;
+
+        // Tracker that runs only if the status tracker reports that the page has been updated.
+        let status_tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "status",
+            "https://secutils.dev/status",
+            3,
+        )?
+        .build();
+        let job_id = uuid!("00000000-0000-0000-0000-000000000010");
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "details",
+            "https://secutils.dev/details",
+            3,
+        )?
+        .with_job_id(job_id)
+        .with_precondition(WebPageTrackerPrecondition {
+            tracker_id: status_tracker.id,
+            pointer: Some("/status".to_string()),
+            operator: WebPageTrackerPreconditionOperator::Equals,
+            value: "updated".to_string(),
+        })
+        .build();
+        for tracker in [&status_tracker, &tracker] {
+            api.db
+                .web_scraping(user.id)
+                .insert_web_page_tracker(tracker)
+                .await?;
+        }
+
+        let mut job = mock_scheduler_job(
+            job_id,
+            SchedulerJob::WebPageTrackersTrigger {
+                kind: WebPageTrackerKind::WebPageContent,
+            },
+            "0 0 * * * *",
+        );
+        job.stopped = Some(true);
+        mock_upsert_scheduler_job(&api.db, &job).await?;
+
+        // Status tracker hasn't extracted any content yet.
+        assert!(!WebPageTrackersFetchJob::check_precondition(&api, &user, &tracker, job_id).await?);
+
+        // Job is reset to run on the next schedule.
+        let job = mock_get_scheduler_job(&api.db, job_id).await?;
+        assert_eq!(job.and_then(|job| job.stopped), Some(false));
+
+        // Status tracker extracted content that doesn't meet the precondition.
+        let status_revision = |id: Uuid, timestamp: i64, data: &str| WebPageDataRevision::<
+            WebPageContentTrackerTag,
+        > {
+            id,
+            tracker_id: status_tracker.id,
+            data: data.to_string(),
+            created_at: OffsetDateTime::from_unix_timestamp(timestamp).unwrap(),
+            note: None,
+            acknowledgment: None,
+            diff_stats: None,
+            assertions_passed: None,
+        };
+        api.db
+            .web_scraping(user.id)
+            .insert_web_page_tracker_history_revision(&status_revision(
+                uuid!("00000000-0000-0000-0000-000000000011"),
+                946720800,
+                r#"{"status":"stale"}"#,
+            ))
+            .await?;
+        assert!(!WebPageTrackersFetchJob::check_precondition(&api, &user, &tracker, job_id).await?);
+
+        // Status tracker extracted content that meets the precondition.
+        api.db
+            .web_scraping(user.id)
+            .insert_web_page_tracker_history_revision(&status_revision(
+                uuid!("00000000-0000-0000-0000-000000000012"),
+                946720900,
+                r#"{"status":"updated"}"#,
+            ))
+            .await?;
+        assert!(WebPageTrackersFetchJob::check_precondition(&api, &user, &tracker, job_id).await?);
+
+        // Trackers without precondition are never skipped.
+        assert!(
+            WebPageTrackersFetchJob::check_precondition(&api, &user, &status_tracker, job_id)
+                .await?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn batches_trackers_by_dependencies() -> anyhow::Result<()> {
+        let tracker = |id: Uuid, depends_on: Option<Uuid>| -> anyhow::Result<_> {
+            let mut builder = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+                id,
+                "some-name",
+                "https://secutils.dev",
+                3,
+            )?;
+            if let Some(depends_on) = depends_on {
+                builder = builder.with_dependency(depends_on);
+            }
+            Ok(("secutils.dev".to_string(), (builder.build(), ())))
+        };
+
+        let (id_a, id_b, id_c, id_d, id_e) = (
+            uuid!("00000000-0000-0000-0000-00000000000a"),
+            uuid!("00000000-0000-0000-0000-00000000000b"),
+            uuid!("00000000-0000-0000-0000-00000000000c"),
+            uuid!("00000000-0000-0000-0000-00000000000d"),
+            uuid!("00000000-0000-0000-0000-00000000000e"),
+        );
+
+        // C depends on B that depends on A, D depends on a tracker that isn't pending.
+        let batches = WebPageTrackersFetchJob::batch_by_dependencies(vec![
+            tracker(id_c, Some(id_b))?,
+            tracker(id_b, Some(id_a))?,
+            tracker(id_a, None)?,
+            tracker(id_d, Some(id_e))?,
+        ]);
+        let batches = batches
+            .into_iter()
+            .map(|batch| {
+                batch
+                    .into_iter()
+                    .map(|(_, (tracker, _))| tracker.id)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(batches, vec![vec![id_a, id_d], vec![id_b], vec![id_c]]);
+
+        // Trackers with cyclic dependencies are still fetched.
+        let batches = WebPageTrackersFetchJob::batch_by_dependencies(vec![
+            tracker(id_a, Some(id_b))?,
+            tracker(id_b, Some(id_a))?,
+            tracker(id_c, None)?,
+        ]);
+        let batches = batches
+            .into_iter()
+            .map(|batch| {
+                batch
+                    .into_iter()
+                    .map(|(_, (tracker, _))| tracker.id)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(batches, vec![vec![id_c], vec![id_a, id_b]]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fetches_trackers_grouped_by_host() -> anyhow::Result<()> {
+        let config = SchedulerJobsConfig {
+            web_page_trackers_fetch_concurrency: 2,
+            web_page_trackers_fetch_host_interval: Duration::from_millis(200),
+            ..Default::default()
+        };
+
+        // 4 trackers for each of the 3 hosts.
+        let user_id = mock_user()?.id;
+        let items = (0..12)
+            .map(|index| ((format!("host-{}", index % 3), user_id), index % 3))
+            .collect::<Vec<_>>();
+
+        let (in_flight, max_in_flight, fetches) = (
+            &AtomicUsize::new(0),
+            &AtomicUsize::new(0),
+            &Mutex::new(Vec::new()),
+        );
+        WebPageTrackersFetchJob::fetch_by_host(&config, items, |host| async move {
+            let current_in_flight = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            max_in_flight.fetch_max(current_in_flight, Ordering::SeqCst);
+            fetches.lock().unwrap().push((host, Instant::now()));
+
+            tokio::time::sleep(Duration::from_millis(50)).await;
+
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(())
+        })
+        .await?;
+
+        let fetches = fetches.lock().unwrap();
+        assert_eq!(fetches.len(), 12);
+
+        // Global concurrency limit is honored, but fully used.
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+
+        // Every host gets its first fetch before any host gets a second one.
+        let mut first_hosts = fetches[..3]
+            .iter()
+            .map(|(host, _)| *host)
+            .collect::<Vec<_>>();
+        first_hosts.sort();
+        assert_eq!(first_hosts, vec![0, 1, 2]);
+
+        // Fetches to the same host are spaced by at least the configured interval.
+        for host in 0..3 {
+            let host_fetches = fetches
+                .iter()
+                .filter(|(fetch_host, _)| *fetch_host == host)
+                .map(|(_, started_at)| *started_at)
+                .collect::<Vec<_>>();
+            assert_eq!(host_fetches.len(), 4);
+            for pair in host_fetches.windows(2) {
+                assert!(pair[1].duration_since(pair[0]) >= Duration::from_millis(200));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn distributes_fetch_slots_across_users() -> anyhow::Result<()> {
+        let config = SchedulerJobsConfig {
+            web_page_trackers_fetch_concurrency: 1,
+            web_page_trackers_fetch_host_interval: Duration::ZERO,
+            ..Default::default()
+        };
+
+        // The first user has 4 pending trackers, and the second user has only 2.
+        let (user_a, user_b) = (
+            UserId::from(uuid!("00000000-0000-0000-0000-00000000000a")),
+            UserId::from(uuid!("00000000-0000-0000-0000-00000000000b")),
+        );
+        let items = (0..4)
+            .map(|index| ((format!("a-{index}"), user_a), user_a))
+            .chain((0..2).map(|index| ((format!("b-{index}"), user_b), user_b)))
+            .collect::<Vec<_>>();
+
+        let fetches = &Mutex::new(Vec::new());
+        WebPageTrackersFetchJob::fetch_by_host(&config, items, |user_id| async move {
+            fetches.lock().unwrap().push(user_id);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            Ok(())
+        })
+        .await?;
+
+        // Once both users wait for a slot, slots are given to them in turns.
+        assert_eq!(
+            *fetches.lock().unwrap(),
+            vec![user_a, user_a, user_b, user_a, user_b, user_a]
+        );
+
+        Ok(())
+    }
 }
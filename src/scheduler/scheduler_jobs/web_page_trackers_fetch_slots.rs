@@ -0,0 +1,93 @@
+use crate::users::UserId;
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, PoisonError},
+};
+use tokio::sync::oneshot;
+
+/// Limits the number of concurrent web page tracker fetches and distributes free fetch slots
+/// across users in a round-robin fashion, so that users with many pending trackers cannot occupy
+/// all slots while trackers of other users wait. Fetches of the same user get slots in a FIFO order.
+pub struct WebPageTrackersFetchSlots {
+    state: Mutex<WebPageTrackersFetchSlotsState>,
+}
+
+struct WebPageTrackersFetchSlotsState {
+    /// Number of slots that aren't occupied by any fetch.
+    available: usize,
+    /// Users waiting for a slot, in the order they'll be served, with their pending fetches.
+    waiting_users: VecDeque<(UserId, VecDeque<oneshot::Sender<()>>)>,
+}
+
+/// Slot occupied by a single fetch, it's released as soon as it's dropped.
+pub struct WebPageTrackersFetchSlot<'a> {
+    slots: &'a WebPageTrackersFetchSlots,
+}
+
+impl WebPageTrackersFetchSlots {
+    /// Creates a new fetch slots limiter with the specified number of slots (at least one).
+    pub fn new(slots: usize) -> Self {
+        Self {
+            state: Mutex::new(WebPageTrackersFetchSlotsState {
+                available: slots.max(1),
+                waiting_users: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// Waits until the next free slot is assigned to the fetch of the specified user.
+    pub async fn acquire(&self, user_id: UserId) -> anyhow::Result<WebPageTrackersFetchSlot<'_>> {
+        let receiver = {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            if state.available > 0 && state.waiting_users.is_empty() {
+                state.available -= 1;
+                return Ok(WebPageTrackersFetchSlot { slots: self });
+            }
+
+            let (sender, receiver) = oneshot::channel();
+            match state
+                .waiting_users
+                .iter_mut()
+                .find(|(waiting_user_id, _)| *waiting_user_id == user_id)
+            {
+                Some((_, senders)) => senders.push_back(sender),
+                None => state
+                    .waiting_users
+                    .push_back((user_id, VecDeque::from([sender]))),
+            }
+
+            receiver
+        };
+
+        receiver.await?;
+
+        Ok(WebPageTrackersFetchSlot { slots: self })
+    }
+
+    /// Hands the released slot over to the next waiting user, or returns it to the pool.
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        while let Some((user_id, mut senders)) = state.waiting_users.pop_front() {
+            let sender = senders.pop_front();
+            // User that still has pending fetches is moved to the end of the queue.
+            if !senders.is_empty() {
+                state.waiting_users.push_back((user_id, senders));
+            }
+
+            // Skip fetches that are no longer waiting for a slot.
+            if let Some(sender) = sender {
+                if sender.send(()).is_ok() {
+                    return;
+                }
+            }
+        }
+
+        state.available += 1;
+    }
+}
+
+impl Drop for WebPageTrackersFetchSlot<'_> {
+    fn drop(&mut self) {
+        self.slots.release();
+    }
+}
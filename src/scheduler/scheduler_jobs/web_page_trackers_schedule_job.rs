@@ -1,14 +1,19 @@
 use crate::{
     api::Api,
     logging::UserLogContext,
-    network::{DnsResolver, EmailTransport},
+    network::{DnsResolver, EmailTransport, EmailTransportError},
+    notifications::{NotificationContent, NotificationContentTemplate, NotificationDestination},
     scheduler::{
         database_ext::RawSchedulerJobStoredData, job_ext::JobExt, scheduler_job::SchedulerJob,
         scheduler_jobs::WebPageTrackersTriggerJob,
     },
-    utils::web_scraping::{WebPageTracker, WebPageTrackerTag},
+    utils::web_scraping::{
+        WebPageContentTrackerTag, WebPageDnsRecordsTrackerTag, WebPageResourcesTrackerTag,
+        WebPageScreenshotTrackerTag, WebPageTracker, WebPageTrackerTag,
+    },
 };
 use std::sync::Arc;
+use time::OffsetDateTime;
 use tokio_cron_scheduler::{Job, JobScheduler};
 
 /// The job executes every minute by default to check if there are any trackers to schedule jobs for.
@@ -18,7 +23,10 @@ impl WebPageTrackersScheduleJob {
     pub async fn try_resume<DR: DnsResolver, ET: EmailTransport>(
         api: Arc<Api<DR, ET>>,
         existing_job_data: RawSchedulerJobStoredData,
-    ) -> anyhow::Result<Option<Job>> {
+    ) -> anyhow::Result<Option<Job>>
+    where
+        ET::Error: EmailTransportError,
+    {
         // If the schedule has changed, remove existing job and create a new one.
         let mut new_job = Self::create(api).await?;
         Ok(if new_job.are_schedules_equal(&existing_job_data)? {
@@ -32,7 +40,10 @@ impl WebPageTrackersScheduleJob {
     /// Creates a new `WebPageTrackersSchedule` job.
     pub async fn create<DR: DnsResolver, ET: EmailTransport>(
         api: Arc<Api<DR, ET>>,
-    ) -> anyhow::Result<Job> {
+    ) -> anyhow::Result<Job>
+    where
+        ET::Error: EmailTransportError,
+    {
         let mut job = Job::new_async(
             api.config.scheduler.web_page_trackers_schedule.clone(),
             move |_, scheduler| {
@@ -54,34 +65,232 @@ impl WebPageTrackersScheduleJob {
     async fn execute<DR: DnsResolver, ET: EmailTransport>(
         api: Arc<Api<DR, ET>>,
         scheduler: JobScheduler,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<()>
+    where
+        ET::Error: EmailTransportError,
+    {
+        let disabled_trackers = Self::disable_idle_trackers(&api, &scheduler).await?;
+        if disabled_trackers > 0 {
+            log::info!("Disabled {disabled_trackers} idle web page trackers.");
+        }
+
+        Self::reconcile(api.clone(), &scheduler).await?;
+
+        // Purge revisions of the trackers that have outlived the retention of their data policy.
+        let removed_revisions = api
+            .web_scraping_system()
+            .remove_expired_web_page_tracker_revisions()
+            .await?;
+        if removed_revisions > 0 {
+            log::debug!("Removed {removed_revisions} expired web page tracker revisions.");
+        }
+
+        Ok(())
+    }
+
+    /// Reconciles tracker jobs with the trackers: removes jobs that aren't linked to any tracker
+    /// and schedules jobs for the trackers that don't have them yet. It's safe to call this method
+    /// multiple times. Returns the number of created and removed jobs.
+    pub async fn reconcile<DR: DnsResolver, ET: EmailTransport>(
+        api: Arc<Api<DR, ET>>,
+        scheduler: &JobScheduler,
+    ) -> anyhow::Result<(usize, usize)> {
+        // Repair links between trackers and their jobs first, so that the trackers that lost their
+        // jobs are re-scheduled right away.
+        let removed_jobs = Self::reconcile_trackers_jobs(&api, scheduler).await?;
+
         let web_scraping_system = api.web_scraping_system();
-        Self::schedule_trackers(
+        let mut created_jobs = Self::schedule_trackers(
             api.clone(),
-            &scheduler,
+            scheduler,
             web_scraping_system
                 .get_unscheduled_resources_trackers()
                 .await?,
         )
         .await?;
 
-        Self::schedule_trackers(
+        created_jobs += Self::schedule_trackers(
             api.clone(),
-            &scheduler,
+            scheduler,
             web_scraping_system
                 .get_unscheduled_content_trackers()
                 .await?,
         )
         .await?;
 
-        Ok(())
+        created_jobs += Self::schedule_trackers(
+            api.clone(),
+            scheduler,
+            web_scraping_system
+                .get_unscheduled_screenshot_trackers()
+                .await?,
+        )
+        .await?;
+
+        created_jobs += Self::schedule_trackers(
+            api.clone(),
+            scheduler,
+            web_scraping_system
+                .get_unscheduled_dns_records_trackers()
+                .await?,
+        )
+        .await?;
+
+        Ok((created_jobs, removed_jobs))
+    }
+
+    /// Unlinks trackers from the jobs that no longer exist and removes tracker jobs that aren't
+    /// linked to any tracker. Returns the number of removed jobs.
+    async fn reconcile_trackers_jobs<DR: DnsResolver, ET: EmailTransport>(
+        api: &Api<DR, ET>,
+        scheduler: &JobScheduler,
+    ) -> anyhow::Result<usize> {
+        let web_scraping_system = api.web_scraping_system();
+        let unlinked_trackers = web_scraping_system
+            .unlink_web_page_trackers_with_missing_jobs()
+            .await?;
+        if !unlinked_trackers.is_empty() {
+            log::warn!(
+                "Unlinked {} trackers from missing jobs, trackers will be re-scheduled: {unlinked_trackers:?}.",
+                unlinked_trackers.len()
+            );
+        }
+
+        let unlinked_jobs = web_scraping_system
+            .get_unlinked_web_page_tracker_jobs()
+            .await?;
+        for job_id in &unlinked_jobs {
+            scheduler.remove(job_id).await?;
+            log::warn!("Removed tracker job ('{job_id}') that isn't linked to any tracker.");
+        }
+
+        Ok(unlinked_jobs.len())
+    }
+
+    /// Disables scheduled trackers that have had neither changes nor user activity for longer than
+    /// the idle period configured for the subscription tier of the tracker owner, and notifies the
+    /// owner once. Returns the number of disabled trackers.
+    pub async fn disable_idle_trackers<DR: DnsResolver, ET: EmailTransport>(
+        api: &Api<DR, ET>,
+        scheduler: &JobScheduler,
+    ) -> anyhow::Result<usize>
+    where
+        ET::Error: EmailTransportError,
+    {
+        // Only trackers that have been idle for at least the shortest idle period among all
+        // subscription tiers can be disabled.
+        let subscriptions = &api.config.subscriptions;
+        let Some(min_idle_period) = [
+            &subscriptions.basic,
+            &subscriptions.standard,
+            &subscriptions.professional,
+            &subscriptions.ultimate,
+        ]
+        .into_iter()
+        .filter_map(|config| config.web_scraping.tracker_idle_period)
+        .min() else {
+            return Ok(0);
+        };
+
+        let idle_since = OffsetDateTime::now_utc() - min_idle_period;
+        Ok(
+            Self::disable_idle_trackers_of_kind::<DR, ET, WebPageResourcesTrackerTag>(
+                api, scheduler, idle_since,
+            )
+            .await?
+                + Self::disable_idle_trackers_of_kind::<DR, ET, WebPageContentTrackerTag>(
+                    api, scheduler, idle_since,
+                )
+                .await?
+                + Self::disable_idle_trackers_of_kind::<DR, ET, WebPageScreenshotTrackerTag>(
+                    api, scheduler, idle_since,
+                )
+                .await?
+                + Self::disable_idle_trackers_of_kind::<DR, ET, WebPageDnsRecordsTrackerTag>(
+                    api, scheduler, idle_since,
+                )
+                .await?,
+        )
+    }
+
+    /// Disables idle trackers of the specified kind. Returns the number of disabled trackers.
+    async fn disable_idle_trackers_of_kind<
+        DR: DnsResolver,
+        ET: EmailTransport,
+        Tag: WebPageTrackerTag,
+    >(
+        api: &Api<DR, ET>,
+        scheduler: &JobScheduler,
+        idle_since: OffsetDateTime,
+    ) -> anyhow::Result<usize>
+    where
+        ET::Error: EmailTransportError,
+    {
+        let now = OffsetDateTime::now_utc();
+        let web_scraping_system = api.web_scraping_system();
+
+        let mut disabled_trackers = 0;
+        for (tracker, last_active_at) in web_scraping_system
+            .get_idle_web_page_trackers::<Tag>(idle_since)
+            .await?
+        {
+            let Some(user) = api.users().get(tracker.user_id).await? else {
+                log::error!(
+                    user:serde = UserLogContext::new(tracker.user_id),
+                    util:serde = tracker.log_context();
+                    "Failed to find user for the idle tracker, skipping…"
+                );
+                continue;
+            };
+
+            let features = user.subscription.get_features(&api.config);
+            let Some(idle_period) = features.config.web_scraping.tracker_idle_period else {
+                continue;
+            };
+            if last_active_at > now - idle_period {
+                continue;
+            }
+
+            // Once the tracker is disabled, it's no longer considered idle, so that the user is
+            // notified only once. The user can re-enable the tracker by setting its schedule again.
+            web_scraping_system
+                .disable_web_page_tracker(tracker.id)
+                .await?;
+            if let Some(job_id) = tracker.job_id {
+                scheduler.remove(&job_id).await?;
+            }
+            log::info!(
+                user:serde = user.log_context(),
+                util:serde = tracker.log_context();
+                "Disabled web page tracker that has been idle since {last_active_at}."
+            );
+
+            api.notifications()
+                .schedule_notification(
+                    NotificationDestination::User(user.id),
+                    NotificationContent::Template(
+                        NotificationContentTemplate::WebPageTrackerIdleDeactivation {
+                            tracker_kind: Tag::KIND,
+                            tracker_name: tracker.name.clone(),
+                            idle_period,
+                        },
+                    ),
+                    now,
+                )
+                .await?;
+            disabled_trackers += 1;
+        }
+
+        Ok(disabled_trackers)
     }
 
+    /// Schedules jobs for the trackers that don't have them yet. Returns the number of created
+    /// jobs.
     async fn schedule_trackers<DR: DnsResolver, ET: EmailTransport, Tag: WebPageTrackerTag>(
         api: Arc<Api<DR, ET>>,
         scheduler: &JobScheduler,
         unscheduled_trackers: Vec<WebPageTracker<Tag>>,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<usize> {
         if !unscheduled_trackers.is_empty() {
             log::debug!(
                 "Found {} unscheduled trackers ({:?}).",
@@ -90,6 +299,7 @@ impl WebPageTrackersScheduleJob {
             );
         }
 
+        let mut scheduled_trackers = 0;
         for tracker in unscheduled_trackers {
             if tracker.settings.revisions == 0 {
                 log::error!(
@@ -118,9 +328,10 @@ impl WebPageTrackersScheduleJob {
             api.web_scraping_system()
                 .update_web_page_tracker_job(tracker.id, Some(job_id))
                 .await?;
+            scheduled_trackers += 1;
         }
 
-        Ok(())
+        Ok(scheduled_trackers)
     }
 }
 
@@ -128,17 +339,23 @@ impl WebPageTrackersScheduleJob {
 mod tests {
     use super::WebPageTrackersScheduleJob;
     use crate::{
+        notifications::{NotificationContent, NotificationContentTemplate},
         scheduler::{scheduler_job::SchedulerJob, SchedulerJobConfig, SchedulerJobMetadata},
-        tests::{mock_api_with_config, mock_config, mock_scheduler, mock_scheduler_job, mock_user},
+        tests::{
+            mock_api_with_config, mock_config, mock_get_scheduler_job, mock_scheduler,
+            mock_scheduler_job, mock_upsert_scheduler_job, mock_user, MockWebPageTrackerBuilder,
+        },
         utils::web_scraping::{
-            tests::WebPageTrackerCreateParams, WebPageTrackerKind, WebPageTrackerSettings,
+            tests::WebPageTrackerCreateParams, WebPageResourcesTrackerTag, WebPageTrackerKind,
+            WebPageTrackerSettings,
         },
     };
     use cron::Schedule;
     use futures::StreamExt;
     use insta::assert_debug_snapshot;
     use sqlx::PgPool;
-    use std::{sync::Arc, time::Duration};
+    use std::{ops::Add, sync::Arc, time::Duration};
+    use time::OffsetDateTime;
     use url::Url;
     use uuid::uuid;
 
@@ -249,13 +466,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 1,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "1 2 3 4 5 6 2030".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -267,13 +484,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 1,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "1 2 3 4 5 6 2035".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -285,13 +502,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 1,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "1 2 3 4 5 6 2040".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -408,6 +625,96 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn reconciles_orphaned_trackers_jobs(pool: PgPool) -> anyhow::Result<()> {
+        let mut scheduler = mock_scheduler(&pool).await?;
+
+        let mut config = mock_config()?;
+        config.scheduler.web_page_trackers_schedule = Schedule::try_from("1/1 * * * * *")?;
+
+        let user = mock_user()?;
+        let api = Arc::new(mock_api_with_config(pool, config).await?);
+
+        // Create user and a tracker that is linked to a job that doesn't exist.
+        api.db.upsert_user(user.clone()).await?;
+
+        let web_scraping = api.web_scraping(&user);
+        let tracker = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "tracker-one".to_string(),
+                url: Url::parse("http://localhost:1234/my/app?q=2")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 1,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "1 2 3 4 5 6 2030".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+
+        let missing_job_id = uuid!("00000000-0000-0000-0000-000000000001");
+        api.web_scraping_system()
+            .update_web_page_tracker_job(tracker.id, Some(missing_job_id))
+            .await?;
+
+        // Create a tracker job that isn't linked to any tracker.
+        let orphaned_job_id = uuid!("00000000-0000-0000-0000-000000000002");
+        mock_upsert_scheduler_job(
+            &api.db,
+            &mock_scheduler_job(
+                orphaned_job_id,
+                SchedulerJob::WebPageTrackersTrigger {
+                    kind: WebPageTrackerKind::WebPageResources,
+                },
+                "1 2 3 4 5 6 2035",
+            ),
+        )
+        .await?;
+
+        scheduler
+            .add(WebPageTrackersScheduleJob::create(api.clone()).await?)
+            .await?;
+
+        // Start scheduler and wait until the tracker is re-scheduled and orphaned job is removed.
+        scheduler.start().await?;
+        loop {
+            let job_id = web_scraping
+                .get_resources_tracker(tracker.id)
+                .await?
+                .and_then(|tracker| tracker.job_id);
+            let is_reconciled = job_id.is_some()
+                && job_id != Some(missing_job_id)
+                && mock_get_scheduler_job(&api.db, orphaned_job_id)
+                    .await?
+                    .is_none();
+            if is_reconciled {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        scheduler.shutdown().await?;
+
+        // Tracker should be linked to the newly created job.
+        let job_id = web_scraping
+            .get_resources_tracker(tracker.id)
+            .await?
+            .and_then(|tracker| tracker.job_id)
+            .unwrap();
+        assert!(mock_get_scheduler_job(&api.db, job_id).await?.is_some());
+        assert!(api
+            .web_scraping_system()
+            .get_unlinked_web_page_tracker_jobs()
+            .await?
+            .is_empty());
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn does_not_schedule_trackers_without_schedule(pool: PgPool) -> anyhow::Result<()> {
         let mut scheduler = mock_scheduler(&pool).await?;
@@ -429,8 +736,7 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 1,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: None,
             })
@@ -444,8 +750,7 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 1,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: None,
             })
@@ -515,13 +820,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 0,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "1 2 3 4 5 6 2030".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -533,13 +838,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 0,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "1 2 3 4 5 6 2030".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -586,4 +891,139 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn disables_idle_trackers(pool: PgPool) -> anyhow::Result<()> {
+        let idle_period = Duration::from_secs(30 * 24 * 3600);
+        let mut config = mock_config()?;
+        config
+            .subscriptions
+            .ultimate
+            .web_scraping
+            .tracker_idle_period = Some(idle_period);
+
+        let user = mock_user()?;
+        let api = mock_api_with_config(pool.clone(), config).await?;
+        api.db.insert_user(&user).await?;
+        let scheduler = mock_scheduler(&pool).await?;
+
+        // Both trackers haven't been updated for a long time, but one of them was viewed recently.
+        let idle_tracker = MockWebPageTrackerBuilder::<WebPageResourcesTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "idle",
+            "https://secutils.dev",
+            3,
+        )?
+        .with_schedule("0 0 * * * *")
+        .build();
+        let active_tracker = MockWebPageTrackerBuilder::<WebPageResourcesTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "active",
+            "https://secutils.dev",
+            3,
+        )?
+        .with_schedule("0 0 * * * *")
+        .build();
+        let web_scraping = api.db.web_scraping(user.id);
+        web_scraping.insert_web_page_tracker(&idle_tracker).await?;
+        web_scraping
+            .insert_web_page_tracker(&active_tracker)
+            .await?;
+        web_scraping
+            .set_web_page_tracker_last_viewed_at(active_tracker.id, OffsetDateTime::now_utc())
+            .await?;
+
+        assert_eq!(
+            WebPageTrackersScheduleJob::disable_idle_trackers(&api, &scheduler).await?,
+            1
+        );
+
+        let web_scraping = api.web_scraping(&user);
+        let idle_tracker = web_scraping
+            .get_resources_tracker(idle_tracker.id)
+            .await?
+            .unwrap();
+        assert!(idle_tracker.job_config.is_none());
+        assert!(idle_tracker.job_id.is_none());
+        assert_eq!(
+            web_scraping
+                .get_resources_tracker(active_tracker.id)
+                .await?
+                .unwrap()
+                .job_config,
+            active_tracker.job_config
+        );
+
+        // The user is notified only once, since disabled tracker is no longer considered idle.
+        assert_eq!(
+            WebPageTrackersScheduleJob::disable_idle_trackers(&api, &scheduler).await?,
+            0
+        );
+
+        let notification_ids = api
+            .db
+            .get_notification_ids(OffsetDateTime::now_utc().add(Duration::from_secs(3600)), 10)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(notification_ids.len(), 1);
+        let notification = api
+            .db
+            .get_notification(notification_ids.into_iter().next().unwrap()?)
+            .await?
+            .unwrap();
+        assert_eq!(
+            notification.content,
+            NotificationContent::Template(
+                NotificationContentTemplate::WebPageTrackerIdleDeactivation {
+                    tracker_kind: WebPageTrackerKind::WebPageResources,
+                    tracker_name: "idle".to_string(),
+                    idle_period,
+                }
+            )
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn does_not_disable_idle_trackers_if_idle_period_is_not_configured(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        config.subscriptions.basic.web_scraping.tracker_idle_period =
+            Some(Duration::from_secs(3600));
+
+        // User has the ultimate subscription that doesn't have idle period.
+        let user = mock_user()?;
+        let api = mock_api_with_config(pool.clone(), config).await?;
+        api.db.insert_user(&user).await?;
+        let scheduler = mock_scheduler(&pool).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageResourcesTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "idle",
+            "https://secutils.dev",
+            3,
+        )?
+        .with_schedule("0 0 * * * *")
+        .build();
+        api.db
+            .web_scraping(user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+
+        assert_eq!(
+            WebPageTrackersScheduleJob::disable_idle_trackers(&api, &scheduler).await?,
+            0
+        );
+        assert!(api
+            .web_scraping(&user)
+            .get_resources_tracker(tracker.id)
+            .await?
+            .unwrap()
+            .job_config
+            .is_some());
+
+        Ok(())
+    }
 }
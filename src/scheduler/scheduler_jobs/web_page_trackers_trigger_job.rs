@@ -34,6 +34,14 @@ impl WebPageTrackersTriggerJob {
                 .get_content_tracker_by_job_id(existing_job_data.id)
                 .await?
                 .map(|tracker| (tracker.id, tracker.settings, tracker.job_config)),
+            WebPageTrackerKind::WebPageScreenshot => web_scraping_system
+                .get_screenshot_tracker_by_job_id(existing_job_data.id)
+                .await?
+                .map(|tracker| (tracker.id, tracker.settings, tracker.job_config)),
+            WebPageTrackerKind::WebPageDnsRecords => web_scraping_system
+                .get_dns_records_tracker_by_job_id(existing_job_data.id)
+                .await?
+                .map(|tracker| (tracker.id, tracker.settings, tracker.job_config)),
         }) else {
             log::warn!(
                 job:serde = JobLogContext::new(existing_job_data.id);
@@ -209,13 +217,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 4,
                     delay: Default::default(),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -293,13 +301,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 4,
                     delay: Default::default(),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -377,13 +385,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 4,
                     delay: Default::default(),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "1 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -443,8 +451,7 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 4,
                     delay: Default::default(),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: None,
             })
@@ -501,13 +508,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 0,
                     delay: Default::default(),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
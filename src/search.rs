@@ -1,11 +1,14 @@
 mod api_ext;
 mod search_filter;
 mod search_index;
+mod search_index_document;
+mod search_index_dump;
 mod search_index_initializer;
 mod search_index_schema_fields;
 mod search_item;
 
 pub use self::{
     search_filter::SearchFilter, search_index::SearchIndex,
+    search_index_document::SearchIndexDocument, search_index_dump::SearchIndexDump,
     search_index_initializer::populate_search_index, search_item::SearchItem,
 };
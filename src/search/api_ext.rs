@@ -1,13 +1,16 @@
 use crate::{
     api::Api,
     network::{DnsResolver, EmailTransport},
-    search::{SearchFilter, SearchIndex, SearchItem},
+    search::{SearchFilter, SearchIndex, SearchIndexDump, SearchItem},
 };
 use std::borrow::Cow;
 
+/// Maximum number of documents that can be dumped from the search index at once.
+const MAX_DUMP_PAGE_SIZE: usize = 100;
+
 impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
     /// Returns an API to perform application-wide search.
-    pub fn search(&self) -> SearchApi {
+    pub fn search(&self) -> SearchApi<'_> {
         SearchApi::new(&self.search_index)
     }
 }
@@ -30,6 +33,24 @@ impl<'a> SearchApi<'a> {
         self.search_index.search(filter)
     }
 
+    /// Dumps a page of the search index documents that match the specified filter. Intended for
+    /// debugging only, since it exposes documents of all users with all their stored fields.
+    pub fn dump(
+        &self,
+        filter: SearchFilter<'_, '_>,
+        offset: usize,
+        limit: Option<usize>,
+    ) -> anyhow::Result<SearchIndexDump> {
+        let limit = limit
+            .unwrap_or(MAX_DUMP_PAGE_SIZE)
+            .clamp(1, MAX_DUMP_PAGE_SIZE);
+        let (total, items) = self.search_index.dump(filter, offset, limit)?;
+        Ok(SearchIndexDump {
+            total,
+            documents: items.into_iter().map(Into::into).collect(),
+        })
+    }
+
     /// Adds or updates a search item.
     pub fn upsert<I: AsRef<SearchItem>>(&self, item: I) -> anyhow::Result<()> {
         self.search_index.upsert(item)
@@ -7,13 +7,13 @@ use crate::{
 use anyhow::{bail, Context};
 use std::{collections::HashMap, path::Path, thread, time::Duration};
 use tantivy::{
-    collector::TopDocs,
+    collector::{Count, TopDocs},
     directory::{error::LockError, MmapDirectory},
     error::TantivyError,
-    query::{BooleanQuery, Occur, Query, QueryParser, TermQuery},
+    query::{AllQuery, BooleanQuery, Occur, Query, QueryParser, TermQuery},
     schema::*,
     tokenizer::{LowerCaser, NgramTokenizer, RawTokenizer, TextAnalyzer},
-    Index, IndexReader, IndexWriter, ReloadPolicy,
+    DocAddress, Index, IndexReader, IndexWriter, Order, ReloadPolicy, Searcher,
 };
 use time::OffsetDateTime;
 
@@ -144,6 +144,60 @@ impl SearchIndex {
         self.execute_query(self.search_filter_into_query(filter)?)
     }
 
+    /// Dumps items stored in the `Search` index that match the specified filter, ordered by id.
+    /// Unlike `search`, the user ID in the filter limits dump to the items of that specific user
+    /// only, and the filter without user ID matches items of all users. Returns the total number
+    /// of the matched items along with the requested page of items.
+    pub fn dump(
+        &self,
+        filter: SearchFilter,
+        offset: usize,
+        limit: usize,
+    ) -> anyhow::Result<(usize, Vec<SearchItem>)> {
+        let queries = [
+            filter.user_id.map(|user_id| {
+                Box::new(TermQuery::new(
+                    Term::from_field_text(self.schema_fields.user_id, &user_id.to_string()),
+                    IndexRecordOption::Basic,
+                )) as Box<dyn Query>
+            }),
+            filter.query.map(|query| self.keywords_query(query)),
+            filter.category.map(|category| {
+                Box::new(TermQuery::new(
+                    Term::from_field_text(self.schema_fields.category, category),
+                    IndexRecordOption::Basic,
+                )) as Box<dyn Query>
+            }),
+        ]
+        .into_iter()
+        .filter_map(|query| Some((Occur::Must, query?)))
+        .collect::<Vec<_>>();
+        let query = if queries.is_empty() {
+            Box::new(AllQuery) as Box<dyn Query>
+        } else {
+            Box::new(BooleanQuery::new(queries))
+        };
+
+        let searcher = self.index_reader.searcher();
+        let (total, top_docs) = searcher.search(
+            &query,
+            &(
+                Count,
+                TopDocs::with_limit(limit)
+                    .and_offset(offset)
+                    .order_by_u64_field("id", Order::Asc),
+            ),
+        )?;
+
+        Ok((
+            total,
+            self.retrieve_items(
+                &searcher,
+                top_docs.into_iter().map(|(_, doc_address)| doc_address),
+            )?,
+        ))
+    }
+
     /// Inserts or updates search item in the `Search` index.
     pub fn upsert<I: AsRef<SearchItem>>(&self, item: I) -> anyhow::Result<()> {
         let item = item.as_ref();
@@ -191,29 +245,7 @@ impl SearchIndex {
             public_query
         };
 
-        let keywords_query = search_filter.query.map(|query| {
-            QueryParser::for_index(
-                &self.index,
-                vec![
-                    self.schema_fields.label_ngram,
-                    self.schema_fields.keywords_ngram,
-                ],
-            )
-            .parse_query_lenient(&query.to_lowercase())
-        });
-        let keywords_query = if let Some((keywords_query, errors)) = keywords_query {
-            if !errors.is_empty() {
-                log::warn!(
-                    "Parsed search query with errors ({:?}): {:?}",
-                    search_filter.query,
-                    errors
-                );
-            }
-
-            Some(keywords_query)
-        } else {
-            None
-        };
+        let keywords_query = search_filter.query.map(|query| self.keywords_query(query));
 
         let category_query = search_filter.category.map(|category| {
             Box::new(TermQuery::new(
@@ -235,6 +267,22 @@ impl SearchIndex {
         }
     }
 
+    fn keywords_query(&self, query: &str) -> Box<dyn Query> {
+        let (keywords_query, errors) = QueryParser::for_index(
+            &self.index,
+            vec![
+                self.schema_fields.label_ngram,
+                self.schema_fields.keywords_ngram,
+            ],
+        )
+        .parse_query_lenient(&query.to_lowercase());
+        if !errors.is_empty() {
+            log::warn!("Parsed search query with errors ({query:?}): {errors:?}");
+        }
+
+        keywords_query
+    }
+
     fn acquire_index_writer(&self) -> anyhow::Result<IndexWriter> {
         loop {
             match self.index.writer(15_000_000) {
@@ -259,8 +307,19 @@ impl SearchIndex {
         let collector = TopDocs::with_limit(10000);
         let top_docs = searcher.search(&query, &collector)?;
 
-        let mut found_docs = Vec::with_capacity(top_docs.len());
-        for (_, doc_address) in top_docs.into_iter() {
+        self.retrieve_items(
+            &searcher,
+            top_docs.into_iter().map(|(_, doc_address)| doc_address),
+        )
+    }
+
+    fn retrieve_items(
+        &self,
+        searcher: &Searcher,
+        doc_addresses: impl ExactSizeIterator<Item = DocAddress>,
+    ) -> anyhow::Result<Vec<SearchItem>> {
+        let mut found_docs = Vec::with_capacity(doc_addresses.len());
+        for doc_address in doc_addresses {
             let doc: TantivyDocument = searcher
                 .doc(doc_address)
                 .with_context(|| "Failed to retrieve search hit document.".to_string())?;
@@ -530,14 +589,14 @@ mod tests {
         index.upsert(&public_item)?;
 
         let mut public_items = index.search(SearchFilter::default())?;
-        public_items.sort_by(|item_a, item_b| item_a.id.cmp(&item_b.id));
+        public_items.sort_by_key(|item| item.id);
         assert_eq!(public_items, vec![public_item.clone()]);
 
         let mut public_and_user_items = index.search(
             SearchFilter::default()
                 .with_user_id(uuid!("00000000-0000-0000-0000-000000000003").into()),
         )?;
-        public_and_user_items.sort_by(|item_a, item_b| item_a.id.cmp(&item_b.id));
+        public_and_user_items.sort_by_key(|item| item.id);
         assert_eq!(
             public_and_user_items,
             vec![item_user_3, public_item.clone()]
@@ -547,7 +606,7 @@ mod tests {
             SearchFilter::default()
                 .with_user_id(uuid!("00000000-0000-0000-0000-000000000004").into()),
         )?;
-        public_and_user_items.sort_by(|item_a, item_b| item_a.id.cmp(&item_b.id));
+        public_and_user_items.sort_by_key(|item| item.id);
         assert_eq!(
             public_and_user_items,
             vec![item_user_4, public_item.clone()]
@@ -588,7 +647,7 @@ mod tests {
         index.upsert(&item_2)?;
 
         let mut items = index.search(SearchFilter::default())?;
-        items.sort_by(|item_a, item_b| item_a.id.cmp(&item_b.id));
+        items.sort_by_key(|item| item.id);
         assert_eq!(items, vec![item_1.clone(), item_2.clone()]);
 
         index.remove(item_1.id)?;
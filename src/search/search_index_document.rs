@@ -0,0 +1,102 @@
+use crate::{search::SearchItem, users::UserId};
+use serde::Serialize;
+use std::collections::HashMap;
+use time::OffsetDateTime;
+
+/// Represents a document stored in the `Search` index with all its stored fields, including those
+/// that aren't exposed through the regular search hits (e.g. user scoping). Used for debugging.
+#[derive(Serialize, Debug, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchIndexDocument {
+    pub id: u64,
+    pub label: String,
+    pub category: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<HashMap<String, String>>,
+    #[serde(with = "time::serde::timestamp")]
+    pub timestamp: OffsetDateTime,
+}
+
+impl From<SearchItem> for SearchIndexDocument {
+    fn from(item: SearchItem) -> Self {
+        Self {
+            id: item.id,
+            label: item.label,
+            category: item.category,
+            sub_category: item.sub_category,
+            keywords: item.keywords,
+            user_id: item.user_id,
+            meta: item.meta,
+            timestamp: item.timestamp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::{SearchIndexDocument, SearchItem};
+    use insta::assert_json_snapshot;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let document = SearchIndexDocument::from(SearchItem {
+            id: 1,
+            label: "some-label".to_string(),
+            category: "some-category".to_string(),
+            keywords: None,
+            sub_category: None,
+            user_id: None,
+            meta: None,
+            // January 1, 2010 11:00:00
+            timestamp: OffsetDateTime::from_unix_timestamp(1262340000)?,
+        });
+        assert_json_snapshot!(document, @r###"
+        {
+          "id": 1,
+          "label": "some-label",
+          "category": "some-category",
+          "timestamp": 1262340000
+        }
+        "###);
+
+        let document = SearchIndexDocument::from(SearchItem {
+            id: 1,
+            label: "some-label".to_string(),
+            keywords: Some("some keywords".to_string()),
+            category: "some-category".to_string(),
+            sub_category: Some("some-sub-category".to_string()),
+            user_id: Some(uuid!("00000000-0000-0000-0000-000000000002").into()),
+            meta: Some(
+                [("one".to_string(), "two".to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+            // January 1, 2010 11:00:00
+            timestamp: OffsetDateTime::from_unix_timestamp(1262340000)?,
+        });
+        assert_json_snapshot!(document, @r###"
+        {
+          "id": 1,
+          "label": "some-label",
+          "category": "some-category",
+          "subCategory": "some-sub-category",
+          "keywords": "some keywords",
+          "userId": "00000000-0000-0000-0000-000000000002",
+          "meta": {
+            "one": "two"
+          },
+          "timestamp": 1262340000
+        }
+        "###);
+
+        Ok(())
+    }
+}
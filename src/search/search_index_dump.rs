@@ -0,0 +1,12 @@
+use crate::search::SearchIndexDocument;
+use serde::Serialize;
+
+/// Represents a page of documents dumped from the `Search` index.
+#[derive(Serialize, Debug, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchIndexDump {
+    /// Total number of documents that match the dump filter.
+    pub total: usize,
+    /// Documents on the requested page.
+    pub documents: Vec<SearchIndexDocument>,
+}
@@ -1,7 +1,13 @@
 mod api_ext;
 mod credentials;
+pub mod encryption;
 mod jwt;
 pub mod kratos;
 mod operator;
+pub mod secrets;
+mod user_impersonation;
 
-pub use self::{api_ext::USER_HANDLE_LENGTH_BYTES, credentials::Credentials, operator::Operator};
+pub use self::{
+    api_ext::USER_HANDLE_LENGTH_BYTES, credentials::Credentials, operator::Operator,
+    user_impersonation::UserImpersonation,
+};
@@ -1,25 +1,31 @@
 use crate::{
     api::Api,
+    error::Error as SecutilsError,
     logging::UserLogContext,
     network::{DnsResolver, EmailTransport, EmailTransportError},
     security::{
         credentials::Credentials,
-        jwt::Claims,
+        jwt::{Claims, ClaimsActor},
         kratos::{Identity, Session},
-        Operator,
+        Operator, UserImpersonation,
     },
     users::{User, UserId, UserSignupError, UserSubscription},
 };
 use actix_web::cookie::Cookie;
-use anyhow::{anyhow, Context};
+use anyhow::{anyhow, bail, Context};
 use hex::ToHex;
-use jsonwebtoken::{decode, DecodingKey, Validation};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use rand_core::{OsRng, RngCore};
 use reqwest::StatusCode;
+use std::time::Duration;
+use time::OffsetDateTime;
 use uuid::Uuid;
 
 pub const USER_HANDLE_LENGTH_BYTES: usize = 8;
 
+/// Defines how long the session operator uses to impersonate a user is valid.
+const USER_IMPERSONATION_TTL: Duration = Duration::from_secs(3600);
+
 /// Secutils.dev security controller.
 pub struct SecurityApiExt<'a, DR: DnsResolver, ET: EmailTransport> {
     api: &'a Api<DR, ET>,
@@ -67,13 +73,18 @@ where
 
     /// Authenticates user with the specified credentials.
     pub async fn authenticate(&self, credentials: Credentials) -> anyhow::Result<Option<User>> {
-        let identity = match &credentials {
+        let (identity, impersonator) = match &credentials {
             Credentials::Jwt(token) => {
-                self.get_identity_by_email(&self.get_jwt_claims(token).await?.sub)
-                    .await
+                let claims = self.get_jwt_claims(token).await?;
+                (
+                    self.get_identity_by_email(&claims.sub).await?,
+                    claims.act.map(|actor| actor.sub),
+                )
+            }
+            Credentials::SessionCookie(cookie) => {
+                (self.get_identity_by_cookie(cookie).await?, None)
             }
-            Credentials::SessionCookie(cookie) => self.get_identity_by_cookie(cookie).await,
-        }?;
+        };
 
         let Some(identity) = identity else {
             log::error!(
@@ -95,14 +106,81 @@ where
         };
 
         let operators = self.api.config.security.operators.as_ref();
+        let is_operator = |id: &str| operators.is_some_and(|operators| operators.contains(id));
+
+        // Impersonation session is only valid while the impersonator is still an operator.
+        if let Some(ref impersonator) = impersonator {
+            if !is_operator(impersonator) {
+                log::error!(
+                    user:serde = user.log_context(),
+                    operator:serde = impersonator;
+                    "Impersonation session was issued by a non-operator."
+                );
+                return Ok(None);
+            }
+        }
+
         Ok(Some(User {
             created_at: identity.created_at,
             is_activated: identity.is_activated(),
-            is_operator: operators.map_or(false, |operators| operators.contains(&user.email)),
+            // Impersonated users never have access to the operator functionality.
+            is_operator: impersonator.is_none() && is_operator(&user.email),
+            impersonator,
             ..user
         }))
     }
 
+    /// Issues a time-limited session the operator can use to act on behalf of the user with the
+    /// specified email. Returns `None` if the user doesn't exist.
+    pub async fn impersonate(
+        &self,
+        operator: &Operator,
+        user_email: &str,
+    ) -> anyhow::Result<Option<UserImpersonation>> {
+        let Some(jwt_secret) = self.api.config.security.jwt_secret.as_ref() else {
+            return Err(anyhow!("JWT secret is not configured."));
+        };
+
+        let Some(user) = self.api.users().get_by_email(user_email).await? else {
+            return Ok(None);
+        };
+
+        let operators = self.api.config.security.operators.as_ref();
+        if operators.is_some_and(|operators| operators.contains(&user.email)) {
+            bail!(SecutilsError::client("Operators cannot be impersonated."));
+        }
+
+        let expires_at = OffsetDateTime::now_utc() + USER_IMPERSONATION_TTL;
+        let token = encode(
+            &Header::default(),
+            &Claims {
+                sub: user.email.clone(),
+                exp: expires_at,
+                act: Some(ClaimsActor {
+                    sub: operator.id().to_string(),
+                }),
+            },
+            &EncodingKey::from_secret(jwt_secret.as_bytes()),
+        )?;
+
+        let user = User {
+            impersonator: Some(operator.id().to_string()),
+            ..user
+        };
+        self.api
+            .users()
+            .record_audit_log_entry(&user, "Started impersonation session")
+            .await?;
+
+        log::info!(
+            user:serde = user.log_context(),
+            operator:serde = operator.id();
+            "Operator started impersonation session."
+        );
+
+        Ok(Some(UserImpersonation { token, expires_at }))
+    }
+
     /// Terminates user's subscription, removes Kratos identity, and user information. If the user
     /// or Kratos identity were found, returns the user ID.
     pub async fn terminate(&self, user_email: &str) -> anyhow::Result<Option<UserId>> {
@@ -140,12 +218,19 @@ where
                     .traits
                     .email
             }
-            // For JWT, we treat `sub` claim as an operator identifier.
-            Credentials::Jwt(token) => self.get_jwt_claims(token).await?.sub,
+            // For JWT, we treat `sub` claim as an operator identifier. Impersonation sessions
+            // never grant access to the operator functionality.
+            Credentials::Jwt(token) => {
+                let claims = self.get_jwt_claims(token).await?;
+                if claims.act.is_some() {
+                    return Ok(None);
+                }
+                claims.sub
+            }
         };
 
         let operators = self.api.config.security.operators.as_ref();
-        if operators.map_or(false, |operators| operators.contains(&operator_id)) {
+        if operators.is_some_and(|operators| operators.contains(&operator_id)) {
             Ok(Some(Operator::new(operator_id)))
         } else {
             Ok(None)
@@ -365,7 +450,7 @@ where
     ET::Error: EmailTransportError,
 {
     /// Returns an API to work with security related tasks.
-    pub fn security(&self) -> SecurityApiExt<DR, ET> {
+    pub fn security(&self) -> SecurityApiExt<'_, DR, ET> {
         SecurityApiExt::new(self)
     }
 }
@@ -373,12 +458,17 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{
-        tests::{mock_api, mock_user},
+        security::{Credentials, Operator},
+        tests::{mock_api, mock_api_with_config, mock_config, mock_user},
         users::{SubscriptionTier, UserSubscription},
     };
+    use httpmock::MockServer;
     use insta::assert_debug_snapshot;
+    use serde_json::json;
     use sqlx::PgPool;
+    use std::time::Duration;
     use time::OffsetDateTime;
+    use url::Url;
 
     #[sqlx::test]
     async fn properly_signs_user_up(pool: PgPool) -> anyhow::Result<()> {
@@ -464,4 +554,120 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn can_impersonate_user(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.kratos_admin_url = Url::parse(&server.base_url())?;
+        config.security.jwt_secret = Some("secret".to_string());
+        config.security.operators = Some(["ops@secutils.dev".to_string()].into_iter().collect());
+        let api = mock_api_with_config(pool, config).await?;
+
+        let user = mock_user()?;
+        api.security().signup(&user).await?;
+
+        let operator = Operator::new("ops@secutils.dev");
+        let impersonation = api
+            .security()
+            .impersonate(&operator, &user.email)
+            .await?
+            .unwrap();
+        assert!(impersonation.expires_at > OffsetDateTime::now_utc());
+        assert!(impersonation.expires_at <= OffsetDateTime::now_utc() + Duration::from_secs(3600));
+
+        let identity_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/admin/identities")
+                .query_param("credentials_identifier", &user.email);
+            then.status(200).json_body(json!([{
+                "id": *user.id,
+                "traits": { "email": user.email },
+                "verifiable_addresses": [{ "value": user.email, "verified": true }],
+                "created_at": "2010-01-01T10:00:00Z"
+            }]));
+        });
+
+        // Impersonated user is attributed to the operator and doesn't have operator access.
+        let impersonated_user = api
+            .security()
+            .authenticate(Credentials::Jwt(impersonation.token.clone()))
+            .await?
+            .unwrap();
+        assert_eq!(impersonated_user.id, user.id);
+        assert_eq!(
+            impersonated_user.impersonator.as_deref(),
+            Some("ops@secutils.dev")
+        );
+        assert!(!impersonated_user.is_operator);
+        assert!(api
+            .security()
+            .get_operator(Credentials::Jwt(impersonation.token))
+            .await?
+            .is_none());
+        identity_mock.assert();
+
+        // Impersonated actions are attributed to the operator in the audit log.
+        api.users()
+            .record_audit_log_entry(&impersonated_user, "POST /api/utils/web_scraping/content")
+            .await?;
+        let audit_log = api.users().get_audit_log(user.id).await?;
+        assert_eq!(
+            audit_log
+                .iter()
+                .map(|entry| (
+                    entry.user_id,
+                    entry.operator.as_deref(),
+                    entry.action.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    user.id,
+                    Some("ops@secutils.dev"),
+                    "Started impersonation session"
+                ),
+                (
+                    user.id,
+                    Some("ops@secutils.dev"),
+                    "POST /api/utils/web_scraping/content"
+                )
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn cannot_impersonate_operators_or_unknown_users(pool: PgPool) -> anyhow::Result<()> {
+        let user = mock_user()?;
+
+        let mut config = mock_config()?;
+        config.security.jwt_secret = Some("secret".to_string());
+        config.security.operators = Some(
+            ["ops@secutils.dev".to_string(), user.email.clone()]
+                .into_iter()
+                .collect(),
+        );
+        let api = mock_api_with_config(pool, config).await?;
+        api.security().signup(&user).await?;
+
+        let operator = Operator::new("ops@secutils.dev");
+        assert_eq!(
+            api.security()
+                .impersonate(&operator, &user.email)
+                .await
+                .unwrap_err()
+                .to_string(),
+            "Operators cannot be impersonated."
+        );
+        assert!(api
+            .security()
+            .impersonate(&operator, "unknown@secutils.dev")
+            .await?
+            .is_none());
+        assert!(api.users().get_audit_log(user.id).await?.is_empty());
+
+        Ok(())
+    }
 }
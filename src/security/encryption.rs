@@ -0,0 +1,103 @@
+use anyhow::{bail, Context};
+use openssl::{
+    rand::rand_bytes,
+    symm::{decrypt_aead, encrypt_aead, Cipher},
+};
+
+/// Length of the AES-256-GCM key, in bytes.
+const KEY_LENGTH: usize = 32;
+/// Length of the AES-256-GCM nonce, in bytes.
+const NONCE_LENGTH: usize = 12;
+/// Length of the AES-256-GCM authentication tag, in bytes.
+const TAG_LENGTH: usize = 16;
+
+/// Parses hex-encoded encryption key.
+fn parse_key(key: &str) -> anyhow::Result<Vec<u8>> {
+    let key = hex::decode(key).context("Encryption key should be hex-encoded.")?;
+    if key.len() != KEY_LENGTH {
+        bail!("Encryption key should be {KEY_LENGTH} bytes long.");
+    }
+
+    Ok(key)
+}
+
+/// Encrypts data with the specified hex-encoded 256-bit key using AES-256-GCM. The result is the
+/// random nonce followed by the ciphertext and the authentication tag.
+pub fn encrypt(key: &str, data: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let key = parse_key(key)?;
+
+    let mut nonce = [0; NONCE_LENGTH];
+    rand_bytes(&mut nonce)?;
+
+    let mut tag = [0; TAG_LENGTH];
+    let ciphertext = encrypt_aead(
+        Cipher::aes_256_gcm(),
+        &key,
+        Some(&nonce),
+        &[],
+        data,
+        &mut tag,
+    )?;
+
+    Ok([nonce.as_slice(), ciphertext.as_slice(), tag.as_slice()].concat())
+}
+
+/// Decrypts data previously encrypted with [`encrypt`] using the same key.
+pub fn decrypt(key: &str, data: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let key = parse_key(key)?;
+    if data.len() < NONCE_LENGTH + TAG_LENGTH {
+        bail!("Encrypted data is too short.");
+    }
+
+    let (nonce, data) = data.split_at(NONCE_LENGTH);
+    let (ciphertext, tag) = data.split_at(data.len() - TAG_LENGTH);
+    decrypt_aead(
+        Cipher::aes_256_gcm(),
+        &key,
+        Some(nonce),
+        &[],
+        ciphertext,
+        tag,
+    )
+    .context("Failed to decrypt data.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decrypt, encrypt};
+
+    const KEY: &str = "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a";
+
+    #[test]
+    fn can_encrypt_and_decrypt() -> anyhow::Result<()> {
+        let encrypted = encrypt(KEY, b"my-secret")?;
+        assert_ne!(encrypted, b"my-secret");
+        assert_eq!(encrypted.len(), 12 + 9 + 16);
+        assert_eq!(decrypt(KEY, &encrypted)?, b"my-secret");
+
+        // Every encryption uses a new nonce.
+        assert_ne!(encrypt(KEY, b"my-secret")?, encrypted);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_decrypt_with_wrong_key_or_tampered_data() -> anyhow::Result<()> {
+        let encrypted = encrypt(KEY, b"my-secret")?;
+        assert!(decrypt(&KEY.replace('4', "5"), &encrypted).is_err());
+
+        let mut tampered = encrypted.clone();
+        tampered[15] ^= 1;
+        assert!(decrypt(KEY, &tampered).is_err());
+
+        assert!(decrypt(KEY, &encrypted[..20]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_with_invalid_key() {
+        assert!(encrypt("not-hex", b"my-secret").is_err());
+        assert!(encrypt("4f2a8e3c", b"my-secret").is_err());
+    }
+}
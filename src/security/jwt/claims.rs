@@ -1,23 +1,56 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, TimestampSeconds};
 use time::OffsetDateTime;
 
 /// JWT claims struct.
 #[serde_as]
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct Claims {
     /// User email.
     pub sub: String,
     /// Token expiration time (UTC timestamp).
     #[serde_as(as = "TimestampSeconds<i64>")]
     pub exp: OffsetDateTime,
+    /// Optional party that acts on behalf of the user (RFC 8693), e.g. the operator that
+    /// impersonates the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub act: Option<ClaimsActor>,
+}
+
+/// JWT actor claim (RFC 8693).
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ClaimsActor {
+    /// Identifier of the acting party (e.g. operator ID).
+    pub sub: String,
 }
 
 #[cfg(test)]
 mod test {
-    use crate::security::jwt::Claims;
+    use crate::security::jwt::{Claims, ClaimsActor};
+    use insta::assert_json_snapshot;
     use time::OffsetDateTime;
 
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(Claims {
+            sub: "dev@secutils.dev".to_string(),
+            exp: OffsetDateTime::from_unix_timestamp(1262340000)?,
+            act: Some(ClaimsActor {
+                sub: "ops@secutils.dev".to_string(),
+            }),
+        }, @r###"
+        {
+          "sub": "dev@secutils.dev",
+          "exp": 1262340000,
+          "act": {
+            "sub": "ops@secutils.dev"
+          }
+        }
+        "###);
+
+        Ok(())
+    }
+
     #[test]
     fn deserialization() -> anyhow::Result<()> {
         assert_eq!(
@@ -31,6 +64,25 @@ mod test {
             Claims {
                 sub: "dev@secutils.dev".to_string(),
                 exp: OffsetDateTime::from_unix_timestamp(1262340000)?,
+                act: None,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<Claims>(
+                r#"
+        {
+          "sub": "dev@secutils.dev",
+          "exp": 1262340000,
+          "act": { "sub": "ops@secutils.dev" }
+        }"#
+            )?,
+            Claims {
+                sub: "dev@secutils.dev".to_string(),
+                exp: OffsetDateTime::from_unix_timestamp(1262340000)?,
+                act: Some(ClaimsActor {
+                    sub: "ops@secutils.dev".to_string(),
+                }),
             }
         );
 
@@ -0,0 +1,187 @@
+mod env_secrets_provider;
+mod file_secrets_provider;
+mod secrets_provider;
+
+pub use self::{
+    env_secrets_provider::EnvSecretsProvider, file_secrets_provider::FileSecretsProvider,
+    secrets_provider::SecretsProvider,
+};
+use crate::{config::SecretsProviderConfig, error::Error as SecutilsError};
+use anyhow::bail;
+use std::{borrow::Cow, collections::HashMap, sync::Arc};
+
+/// Prefix of the values that reference a secret instead of containing it inline.
+pub const SECRET_REFERENCE_PREFIX: &str = "secret://";
+
+/// Creates a secrets provider based on the specified config, secrets are read from the
+/// environment variables by default.
+pub fn create_secrets_provider(config: Option<&SecretsProviderConfig>) -> Arc<dyn SecretsProvider> {
+    match config.cloned().unwrap_or_default() {
+        SecretsProviderConfig::Env { prefix } => Arc::new(EnvSecretsProvider::new(prefix)),
+        SecretsProviderConfig::File { dir } => Arc::new(FileSecretsProvider::new(dir)),
+    }
+}
+
+/// Checks whether the secret name consists only of alphanumeric characters, `-`, `_` and `.`, and
+/// hence cannot be used to escape the namespace.
+fn is_valid_secret_name(name: &str) -> bool {
+    !name.is_empty()
+        && name != "."
+        && name != ".."
+        && name
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || matches!(char, '-' | '_' | '.'))
+}
+
+/// If the value is a secret reference (`secret://name`), resolves it to the secret value with the
+/// specified provider, otherwise returns the value as is. Secrets that belong to a namespace (e.g.
+/// the user) are looked up by the `namespace/name` name, so that references cannot reach secrets
+/// outside the namespace.
+pub fn resolve_secret<'v>(
+    provider: &dyn SecretsProvider,
+    namespace: Option<&str>,
+    value: &'v str,
+) -> anyhow::Result<Cow<'v, str>> {
+    let Some(name) = value.strip_prefix(SECRET_REFERENCE_PREFIX) else {
+        return Ok(Cow::Borrowed(value));
+    };
+
+    if !is_valid_secret_name(name) {
+        bail!(SecutilsError::client(format!(
+            "Secret reference `{value}` is not valid."
+        )));
+    }
+
+    let secret_name = match namespace {
+        Some(namespace) => Cow::Owned(format!("{namespace}/{name}")),
+        None => Cow::Borrowed(name),
+    };
+    match provider.get_secret(&secret_name)? {
+        Some(secret) => Ok(Cow::Owned(secret)),
+        None => bail!(SecutilsError::client(format!(
+            "Secret `{name}` is not found."
+        ))),
+    }
+}
+
+/// Resolves all secret references (`secret://name`) among the map values, see [`resolve_secret`].
+/// The map is cloned only if it contains at least one secret reference.
+pub fn resolve_secrets<'m>(
+    provider: &dyn SecretsProvider,
+    namespace: Option<&str>,
+    values: &'m HashMap<String, String>,
+) -> anyhow::Result<Cow<'m, HashMap<String, String>>> {
+    if !values
+        .values()
+        .any(|value| value.starts_with(SECRET_REFERENCE_PREFIX))
+    {
+        return Ok(Cow::Borrowed(values));
+    }
+
+    values
+        .iter()
+        .map(|(key, value)| {
+            Ok((
+                key.clone(),
+                resolve_secret(provider, namespace, value)?.into_owned(),
+            ))
+        })
+        .collect::<anyhow::Result<_>>()
+        .map(Cow::Owned)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{resolve_secret, resolve_secrets, SecretsProvider};
+    use crate::error::Error as SecutilsError;
+    use insta::assert_debug_snapshot;
+    use std::{borrow::Cow, collections::HashMap};
+
+    /// In-memory secrets provider for tests.
+    #[derive(Default)]
+    pub struct MockSecretsProvider {
+        secrets: HashMap<String, String>,
+    }
+
+    impl MockSecretsProvider {
+        pub fn with_secret(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+            self.secrets.insert(name.into(), value.into());
+            self
+        }
+    }
+
+    impl SecretsProvider for MockSecretsProvider {
+        fn get_secret(&self, name: &str) -> anyhow::Result<Option<String>> {
+            Ok(self.secrets.get(name).cloned())
+        }
+    }
+
+    #[test]
+    fn can_resolve_secret_references() -> anyhow::Result<()> {
+        let provider = MockSecretsProvider::default()
+            .with_secret("smtp-password", "password")
+            .with_secret("user/token", "Bearer token");
+
+        // Values that aren't secret references are returned as is.
+        assert!(matches!(
+            resolve_secret(&provider, None, "plain")?,
+            Cow::Borrowed("plain")
+        ));
+
+        assert_eq!(
+            resolve_secret(&provider, None, "secret://smtp-password")?,
+            "password"
+        );
+        assert_eq!(
+            resolve_secret(&provider, Some("user"), "secret://token")?,
+            "Bearer token"
+        );
+
+        // Secrets outside the namespace cannot be referenced.
+        assert_debug_snapshot!(
+            resolve_secret(&provider, Some("user"), "secret://smtp-password")
+                .unwrap_err()
+                .downcast::<SecutilsError>()?,
+            @r###""Secret `smtp-password` is not found.""###
+        );
+        assert_debug_snapshot!(
+            resolve_secret(&provider, Some("other"), "secret://../user/token")
+                .unwrap_err()
+                .downcast::<SecutilsError>()?,
+            @r###""Secret reference `secret://../user/token` is not valid.""###
+        );
+        assert_debug_snapshot!(
+            resolve_secret(&provider, None, "secret://")
+                .unwrap_err()
+                .downcast::<SecutilsError>()?,
+            @r###""Secret reference `secret://` is not valid.""###
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_resolve_secret_references_in_maps() -> anyhow::Result<()> {
+        let provider = MockSecretsProvider::default().with_secret("user/token", "Bearer token");
+
+        let values = HashMap::from([("Cookie".to_string(), "a=b".to_string())]);
+        assert!(matches!(
+            resolve_secrets(&provider, Some("user"), &values)?,
+            Cow::Borrowed(_)
+        ));
+
+        let values = HashMap::from([
+            ("Cookie".to_string(), "a=b".to_string()),
+            ("Authorization".to_string(), "secret://token".to_string()),
+        ]);
+        assert_eq!(
+            resolve_secrets(&provider, Some("user"), &values)?.into_owned(),
+            HashMap::from([
+                ("Cookie".to_string(), "a=b".to_string()),
+                ("Authorization".to_string(), "Bearer token".to_string()),
+            ])
+        );
+
+        Ok(())
+    }
+}
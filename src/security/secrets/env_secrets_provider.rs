@@ -0,0 +1,64 @@
+use crate::security::secrets::SecretsProvider;
+use anyhow::bail;
+use std::env::{self, VarError};
+
+/// Secrets provider that reads secrets from the environment variables. The name of the variable is
+/// the secret name in upper case with all non-alphanumeric characters replaced with `_`, prefixed
+/// with the configured prefix (e.g. `smtp-password` is read from `SECUTILS_SECRET_SMTP_PASSWORD`).
+pub struct EnvSecretsProvider {
+    prefix: String,
+}
+
+impl EnvSecretsProvider {
+    /// Creates a new provider with the specified environment variables prefix.
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+
+    /// Returns the name of the environment variable that stores the secret with the specified name.
+    fn variable_name(&self, name: &str) -> String {
+        format!(
+            "{}{}",
+            self.prefix,
+            name.chars()
+                .map(|char| {
+                    if char.is_ascii_alphanumeric() {
+                        char.to_ascii_uppercase()
+                    } else {
+                        '_'
+                    }
+                })
+                .collect::<String>()
+        )
+    }
+}
+
+impl SecretsProvider for EnvSecretsProvider {
+    fn get_secret(&self, name: &str) -> anyhow::Result<Option<String>> {
+        match env::var(self.variable_name(name)) {
+            Ok(value) => Ok(Some(value)),
+            Err(VarError::NotPresent) => Ok(None),
+            Err(VarError::NotUnicode(_)) => bail!("Secret `{name}` is not a valid UTF-8 string."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EnvSecretsProvider;
+
+    #[test]
+    fn properly_maps_secret_names_to_variables() {
+        let provider = EnvSecretsProvider::new("SECUTILS_SECRET_");
+        assert_eq!(
+            provider.variable_name("smtp-password"),
+            "SECUTILS_SECRET_SMTP_PASSWORD"
+        );
+        assert_eq!(
+            provider.variable_name("00000000-0000-0000-0000-000000000001/api.token"),
+            "SECUTILS_SECRET_00000000_0000_0000_0000_000000000001_API_TOKEN"
+        );
+    }
+}
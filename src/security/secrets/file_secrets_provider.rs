@@ -0,0 +1,58 @@
+use crate::security::secrets::SecretsProvider;
+use std::{io::ErrorKind, path::PathBuf};
+
+/// Secrets provider that reads secrets from the files in the configured directory (e.g. mounted
+/// Kubernetes or Docker secrets). Every `/`-separated segment of the secret name is a path segment
+/// relative to the directory, and trailing line breaks of the file content are ignored.
+pub struct FileSecretsProvider {
+    dir: PathBuf,
+}
+
+impl FileSecretsProvider {
+    /// Creates a new provider that reads secrets from the specified directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl SecretsProvider for FileSecretsProvider {
+    fn get_secret(&self, name: &str) -> anyhow::Result<Option<String>> {
+        let path = name
+            .split('/')
+            .fold(self.dir.clone(), |path, segment| path.join(segment));
+        match std::fs::read_to_string(path) {
+            Ok(value) => Ok(Some(value.trim_end_matches(['\r', '\n']).to_string())),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FileSecretsProvider;
+    use crate::security::secrets::SecretsProvider;
+    use std::fs;
+    use uuid::Uuid;
+
+    #[test]
+    fn can_read_secrets_from_files() -> anyhow::Result<()> {
+        let secrets_dir = std::env::temp_dir().join(format!("secutils-{}", Uuid::now_v7()));
+        fs::create_dir_all(secrets_dir.join("user"))?;
+        fs::write(secrets_dir.join("smtp-password"), "password\n")?;
+        fs::write(secrets_dir.join("user").join("token"), "Bearer token")?;
+
+        let provider = FileSecretsProvider::new(&secrets_dir);
+        assert_eq!(
+            provider.get_secret("smtp-password")?,
+            Some("password".to_string())
+        );
+        assert_eq!(
+            provider.get_secret("user/token")?,
+            Some("Bearer token".to_string())
+        );
+        assert_eq!(provider.get_secret("unknown")?, None);
+
+        Ok(())
+    }
+}
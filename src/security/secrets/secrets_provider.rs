@@ -0,0 +1,7 @@
+/// Provider of the secrets referenced in the config and user data (`secret://name`), allows
+/// keeping plaintext secrets out of the config files and database.
+pub trait SecretsProvider: Send + Sync {
+    /// Returns the value of the secret with the specified name, or `None` if the secret doesn't
+    /// exist. Name consists of `/`-separated segments, e.g. `smtp-password` or `{user_id}/token`.
+    fn get_secret(&self, name: &str) -> anyhow::Result<Option<String>>;
+}
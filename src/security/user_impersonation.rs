@@ -0,0 +1,36 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+
+/// Represents a time-limited session an operator can use to act on behalf of the user.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserImpersonation {
+    /// JSON Web Token that should be used as a bearer token to act on behalf of the user.
+    pub token: String,
+    /// Date and time when the impersonation session expires.
+    #[serde(with = "time::serde::timestamp")]
+    pub expires_at: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::security::UserImpersonation;
+    use insta::assert_json_snapshot;
+    use time::OffsetDateTime;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(UserImpersonation {
+            token: "some-token".to_string(),
+            // January 1, 2010 11:00:00
+            expires_at: OffsetDateTime::from_unix_timestamp(1262340000)?,
+        }, @r###"
+        {
+          "token": "some-token",
+          "expiresAt": 1262340000
+        }
+        "###);
+
+        Ok(())
+    }
+}
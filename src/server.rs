@@ -12,14 +12,16 @@ use crate::{
     network::{Network, TokioDnsResolver},
     scheduler::Scheduler,
     search::{populate_search_index, SearchIndex},
+    security::secrets::create_secrets_provider,
     templates::create_templates,
 };
 use actix_cors::Cors;
 use actix_web::{middleware, web, App, HttpServer, Result};
 use anyhow::Context;
 use lettre::{
-    message::Mailbox, transport::smtp::authentication::Credentials, AsyncSmtpTransport,
-    Tokio1Executor,
+    message::Mailbox,
+    transport::smtp::{authentication::Credentials, PoolConfig},
+    AsyncSmtpTransport, Tokio1Executor,
 };
 use sqlx::postgres::PgPoolOptions;
 use std::{str::FromStr, sync::Arc};
@@ -33,6 +35,11 @@ pub use ui_state::{Status, StatusLevel, SubscriptionState, UiState, WebhookUrlTy
 
 #[tokio::main]
 pub async fn run(config: Config, http_port: u16) -> Result<(), anyhow::Error> {
+    let secrets_provider = create_secrets_provider(config.security.secrets_provider.as_ref());
+    let config = config
+        .resolve_secrets(secrets_provider.as_ref())
+        .context("Cannot resolve config secrets.")?;
+
     let datastore_dir = Directories::ensure_data_dir_exists()?;
     log::info!("Data is available at {}", datastore_dir.as_path().display());
     let search_index = SearchIndex::open_path(datastore_dir.join(format!(
@@ -63,27 +70,38 @@ pub async fn run(config: Config, http_port: u16) -> Result<(), anyhow::Error> {
     )
     .await?;
 
-    let email_transport = if let Some(ref smtp_config) = config.smtp {
+    let network = if let Some(ref smtp_config) = config.smtp {
         if let Some(ref catch_all_config) = smtp_config.catch_all {
             Mailbox::from_str(catch_all_config.recipient.as_str())
                 .with_context(|| "Cannot parse SMTP catch-all recipient.")?;
         }
 
-        AsyncSmtpTransport::<Tokio1Executor>::relay(&smtp_config.address)?
+        // Reuse connections to the SMTP server instead of opening a new one for every email.
+        let email_transport = AsyncSmtpTransport::<Tokio1Executor>::relay(&smtp_config.address)?
             .credentials(Credentials::new(
                 smtp_config.username.clone(),
                 smtp_config.password.clone(),
             ))
-            .build()
+            .pool_config(PoolConfig::new().max_size(smtp_config.pool_size()))
+            .build();
+        Network::new(TokioDnsResolver::create(), email_transport)
+            .with_email_concurrency(smtp_config.concurrency())
     } else {
-        AsyncSmtpTransport::<Tokio1Executor>::unencrypted_localhost()
+        Network::new(
+            TokioDnsResolver::create(),
+            AsyncSmtpTransport::<Tokio1Executor>::unencrypted_localhost(),
+        )
     };
 
+    if config.safe_mode {
+        log::warn!("Server runs in safe mode, all outbound network requests will be rejected.");
+    }
+
     let api = Arc::new(Api::new(
         config.clone(),
         database,
         search_index,
-        Network::new(TokioDnsResolver::create(), email_transport),
+        network.with_safe_mode(config.safe_mode),
         create_templates()?,
     ));
 
@@ -104,18 +122,43 @@ pub async fn run(config: Config, http_port: u16) -> Result<(), anyhow::Error> {
                     .route("/status", web::get().to(handlers::status_get))
                     .route("/status", web::post().to(handlers::status_set))
                     .route("/search", web::post().to(handlers::search))
+                    .route("/search/dump", web::get().to(handlers::search_dump))
                     .route("/send_message", web::post().to(handlers::send_message))
                     .route("/user/data", web::post().to(handlers::user_data_set))
                     .route("/user/data", web::get().to(handlers::user_data_get))
+                    .route(
+                        "/user/display_order/{collection}",
+                        web::get().to(handlers::user_display_order_get),
+                    )
+                    .route(
+                        "/user/display_order/{collection}",
+                        web::post().to(handlers::user_display_order_set),
+                    )
+                    .route(
+                        "/user/display_order/{collection}/{entity_id}/pin",
+                        web::post().to(handlers::user_display_order_pin),
+                    )
                     .route(
                         "/user/subscription",
                         web::post().to(handlers::security_subscription_update),
                     )
+                    .route(
+                        "/webhooks/inbound_emails",
+                        web::post().to(handlers::webhooks_inbound_emails),
+                    )
                     .route(
                         "/webhooks/{user_handle}/{responder_path:.*}",
                         web::route().to(handlers::webhooks_responders),
                     )
                     .route("/webhooks", web::route().to(handlers::webhooks_responders))
+                    .route(
+                        "/web_scraping/scraper_callback",
+                        web::post().to(handlers::web_scraping_scraper_callback),
+                    )
+                    .route(
+                        "/status_pages/{token}",
+                        web::get().to(handlers::web_scraping_status_page_get),
+                    )
                     .route(
                         "/users",
                         web::get().to(handlers::security_users_get_by_email),
@@ -126,14 +169,44 @@ pub async fn run(config: Config, http_port: u16) -> Result<(), anyhow::Error> {
                             .route("/email", web::post().to(handlers::security_users_email))
                             .route("/remove", web::post().to(handlers::security_users_remove))
                             .route("/self", web::get().to(handlers::security_users_get_self))
-                            .route("/{user_id}", web::get().to(handlers::security_users_get)),
+                            .route(
+                                "/impersonate",
+                                web::post().to(handlers::security_users_impersonate),
+                            )
+                            .route("/{user_id}", web::get().to(handlers::security_users_get))
+                            .route(
+                                "/{user_id}/audit_log",
+                                web::get().to(handlers::security_users_audit_log_get),
+                            ),
+                    )
+                    .service(
+                        web::scope("/notifications")
+                            .route(
+                                "/dead_letters",
+                                web::get().to(handlers::notifications_dead_letters_get),
+                            )
+                            .route(
+                                "/dead_letters/{dead_letter_id}/retry",
+                                web::post().to(handlers::notifications_dead_letters_retry),
+                            ),
+                    )
+                    .service(
+                        web::scope("/scheduler")
+                            .route(
+                                "/parse_schedule",
+                                web::post().to(handlers::scheduler_parse_schedule),
+                            )
+                            .route(
+                                "/validate_schedule",
+                                web::post().to(handlers::scheduler_validate_schedule),
+                            ),
                     )
-                    .service(web::scope("/scheduler").route(
-                        "/parse_schedule",
-                        web::post().to(handlers::scheduler_parse_schedule),
-                    ))
                     .service(
                         web::scope("/utils")
+                            .route(
+                                "/web_scraping/{resource}/{resource_id}/history/export",
+                                web::get().to(handlers::web_scraping_history_export),
+                            )
                             .service(
                                 web::resource([
                                     "/{area}/{resource}",
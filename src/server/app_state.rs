@@ -23,6 +23,7 @@ impl<DR: DnsResolver, ET: EmailTransport> AppState<DR, ET> {
             status: RwLock::new(Status {
                 version: env!("CARGO_PKG_VERSION").to_string(),
                 level: StatusLevel::Available,
+                scheduler_behind_jobs: None,
             }),
             api,
         }
@@ -17,6 +17,26 @@ impl FromRequest for User {
             let state = web::Data::<AppState>::extract(&req).await?;
             let credentials = Credentials::extract(&req).await?;
             match state.api.security().authenticate(credentials).await {
+                // Every action performed on behalf of the impersonated user is recorded to the
+                // audit log, and the action isn't allowed if it cannot be recorded.
+                Ok(Some(user)) if user.impersonator.is_some() => {
+                    let action = format!("{} {}", req.method(), req.path());
+                    match state
+                        .api
+                        .users()
+                        .record_audit_log_entry(&user, action)
+                        .await
+                    {
+                        Ok(_) => Ok(user),
+                        Err(err) => {
+                            log::error!(
+                                user:serde = user.log_context();
+                                "Failed to record impersonated user action to the audit log: {err:?}"
+                            );
+                            Err(ErrorInternalServerError(anyhow!("Internal server error")))
+                        }
+                    }
+                }
                 Ok(Some(user)) => Ok(user),
                 Ok(None) => Err(ErrorUnauthorized(anyhow!("Unauthorized"))),
                 Err(err) => {
@@ -1,10 +1,16 @@
+mod notifications_dead_letters_get;
+mod notifications_dead_letters_retry;
 mod scheduler_parse_schedule;
+mod scheduler_validate_schedule;
 mod search;
+mod search_dump;
 mod security_subscription_update;
+mod security_users_audit_log_get;
 mod security_users_email;
 mod security_users_get;
 mod security_users_get_by_email;
 mod security_users_get_self;
+mod security_users_impersonate;
 mod security_users_remove;
 mod security_users_signup;
 mod send_message;
@@ -13,17 +19,34 @@ mod status_set;
 mod ui_state_get;
 mod user_data_get;
 mod user_data_set;
+mod user_display_order_get;
+mod user_display_order_pin;
+mod user_display_order_set;
 mod utils_action;
+mod web_scraping_history_export;
+mod web_scraping_scraper_callback;
+mod web_scraping_status_page_get;
+mod webhooks_inbound_emails;
 mod webhooks_responders;
 
 pub use self::{
-    scheduler_parse_schedule::scheduler_parse_schedule, search::search,
-    security_subscription_update::security_subscription_update,
+    notifications_dead_letters_get::notifications_dead_letters_get,
+    notifications_dead_letters_retry::notifications_dead_letters_retry,
+    scheduler_parse_schedule::scheduler_parse_schedule,
+    scheduler_validate_schedule::scheduler_validate_schedule, search::search,
+    search_dump::search_dump, security_subscription_update::security_subscription_update,
+    security_users_audit_log_get::security_users_audit_log_get,
     security_users_email::security_users_email, security_users_get::security_users_get,
     security_users_get_by_email::security_users_get_by_email,
-    security_users_get_self::security_users_get_self, security_users_remove::security_users_remove,
-    security_users_signup::security_users_signup, send_message::send_message,
-    status_get::status_get, status_set::status_set, ui_state_get::ui_state_get,
-    user_data_get::user_data_get, user_data_set::user_data_set, utils_action::utils_action,
-    webhooks_responders::webhooks_responders,
+    security_users_get_self::security_users_get_self,
+    security_users_impersonate::security_users_impersonate,
+    security_users_remove::security_users_remove, security_users_signup::security_users_signup,
+    send_message::send_message, status_get::status_get, status_set::status_set,
+    ui_state_get::ui_state_get, user_data_get::user_data_get, user_data_set::user_data_set,
+    user_display_order_get::user_display_order_get, user_display_order_pin::user_display_order_pin,
+    user_display_order_set::user_display_order_set, utils_action::utils_action,
+    web_scraping_history_export::web_scraping_history_export,
+    web_scraping_scraper_callback::web_scraping_scraper_callback,
+    web_scraping_status_page_get::web_scraping_status_page_get,
+    webhooks_inbound_emails::webhooks_inbound_emails, webhooks_responders::webhooks_responders,
 };
@@ -0,0 +1,22 @@
+use crate::{
+    logging::UserLogContext,
+    server::{app_state::AppState, http_errors::generic_internal_server_error},
+    users::User,
+};
+use actix_web::{web, HttpResponse, Responder};
+
+pub async fn notifications_dead_letters_get(
+    state: web::Data<AppState>,
+    user: User,
+) -> impl Responder {
+    match state.api.notifications().get_dead_letters(user.id).await {
+        Ok(dead_letters) => HttpResponse::Ok().json(dead_letters),
+        Err(err) => {
+            log::error!(
+                user:serde = UserLogContext::new(user.id);
+                "Failed to retrieve notification dead letters: {err:?}"
+            );
+            generic_internal_server_error()
+        }
+    }
+}
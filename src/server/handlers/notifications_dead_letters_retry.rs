@@ -0,0 +1,28 @@
+use crate::{
+    error::Error as SecutilsError, logging::UserLogContext, server::app_state::AppState,
+    users::User,
+};
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+
+pub async fn notifications_dead_letters_retry(
+    state: web::Data<AppState>,
+    user: User,
+    dead_letter_id: web::Path<Uuid>,
+) -> Result<HttpResponse, SecutilsError> {
+    state
+        .api
+        .notifications()
+        .retry_dead_letter(user.id, *dead_letter_id)
+        .await
+        .map_err(|err| {
+            log::error!(
+                user:serde = UserLogContext::new(user.id);
+                "Failed to retry notification dead letter ({}): {err:?}",
+                *dead_letter_id
+            );
+            SecutilsError::from(err)
+        })?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,223 @@
+use crate::{error::Error as SecutilsError, scheduler::ScheduleExt, server::AppState, users::User};
+use actix_web::{web, HttpResponse};
+use anyhow::anyhow;
+use cron::Schedule;
+use serde_derive::{Deserialize, Serialize};
+use serde_with::{serde_as, DurationMilliSeconds, TimestampSeconds};
+use std::{str::FromStr, time::Duration};
+use time::OffsetDateTime;
+
+/// Default number of the next occurrences to return.
+const DEFAULT_NEXT_OCCURRENCES: usize = 5;
+
+/// Maximum number of the next occurrences that can be requested.
+const MAX_NEXT_OCCURRENCES: usize = 50;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchedulerValidateScheduleParams {
+    pub schedule: String,
+    /// Optional number of the next occurrences to return (5 by default, 50 at most).
+    pub next_occurrences: Option<usize>,
+}
+
+#[serde_as]
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SchedulerValidateScheduleResult {
+    /// Indicates whether the provided schedule is a valid cron expression.
+    pub valid: bool,
+    /// The error that describes why the schedule is not valid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The minimum interval between two consequent scheduled tracker checks.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_interval: Option<Duration>,
+    /// The minimum interval between two consequent scheduled tracker checks allowed for the user.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub min_allowed_interval: Duration,
+    /// Indicates whether the minimum interval between occurrences is allowed for the user.
+    pub satisfies_min_interval: bool,
+    /// The next occurrences of the provided schedule.
+    #[serde_as(as = "Vec<TimestampSeconds<i64>>")]
+    pub next_occurrences: Vec<OffsetDateTime>,
+}
+
+/// Validates the provided schedule and returns the minimum interval between occurrences, the next
+/// occurrences, and whether the schedule can be used for trackers. Unlike
+/// `scheduler_parse_schedule`, it doesn't fail for invalid schedules.
+pub async fn scheduler_validate_schedule(
+    state: web::Data<AppState>,
+    user: User,
+    body_params: web::Json<SchedulerValidateScheduleParams>,
+) -> Result<HttpResponse, SecutilsError> {
+    let features = user.subscription.get_features(&state.config);
+    let min_allowed_interval = features.config.web_scraping.min_schedule_interval;
+
+    let schedule = match Schedule::from_str(&body_params.schedule) {
+        Ok(schedule) => schedule,
+        Err(err) => {
+            return Ok(HttpResponse::Ok().json(SchedulerValidateScheduleResult {
+                valid: false,
+                error: Some(err.to_string()),
+                min_interval: None,
+                min_allowed_interval,
+                satisfies_min_interval: false,
+                next_occurrences: vec![],
+            }));
+        }
+    };
+
+    let min_interval = schedule.min_interval()?;
+    Ok(HttpResponse::Ok().json(SchedulerValidateScheduleResult {
+        valid: true,
+        error: None,
+        min_interval: Some(min_interval),
+        min_allowed_interval,
+        satisfies_min_interval: min_interval >= min_allowed_interval,
+        next_occurrences: schedule
+            .upcoming(chrono::Utc)
+            .take(
+                body_params
+                    .next_occurrences
+                    .unwrap_or(DEFAULT_NEXT_OCCURRENCES)
+                    .min(MAX_NEXT_OCCURRENCES),
+            )
+            .map(|ts| {
+                OffsetDateTime::from_unix_timestamp(ts.timestamp())
+                    .map_err(|_| anyhow!("Failed to calculate next occurrence."))
+            })
+            .collect::<Result<_, _>>()?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::handlers::{
+            scheduler_validate_schedule,
+            scheduler_validate_schedule::{
+                SchedulerValidateScheduleParams, SchedulerValidateScheduleResult,
+            },
+        },
+        tests::{mock_app_state, mock_app_state_with_config, mock_config, mock_user},
+    };
+    use actix_web::{body::MessageBody, web};
+    use sqlx::PgPool;
+    use std::time::Duration;
+    use time::OffsetDateTime;
+
+    #[sqlx::test]
+    async fn reports_invalid_schedule(pool: PgPool) -> anyhow::Result<()> {
+        let app_state = mock_app_state(pool).await?;
+
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        let response = scheduler_validate_schedule(
+            web::Data::new(app_state),
+            user,
+            web::Json(SchedulerValidateScheduleParams {
+                schedule: "0 * * * *".to_string(),
+                next_occurrences: None,
+            }),
+        )
+        .await?;
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().try_into_bytes().unwrap();
+        assert_eq!(
+            serde_json::from_slice::<SchedulerValidateScheduleResult>(&body)?,
+            SchedulerValidateScheduleResult {
+                valid: false,
+                error: Some("Invalid expression: Invalid cron expression.".to_string()),
+                min_interval: None,
+                min_allowed_interval: Duration::from_secs(10),
+                satisfies_min_interval: false,
+                next_occurrences: vec![],
+            }
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn reports_too_frequent_schedule(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        config
+            .subscriptions
+            .ultimate
+            .web_scraping
+            .min_schedule_interval = Duration::from_secs(3600);
+
+        let app_state = mock_app_state_with_config(pool, config).await?;
+
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        let response = scheduler_validate_schedule(
+            web::Data::new(app_state),
+            user,
+            web::Json(SchedulerValidateScheduleParams {
+                schedule: "0 * * * * *".to_string(),
+                next_occurrences: Some(2),
+            }),
+        )
+        .await?;
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().try_into_bytes().unwrap();
+        let result = serde_json::from_slice::<SchedulerValidateScheduleResult>(&body)?;
+        assert!(result.valid);
+        assert_eq!(result.error, None);
+        assert_eq!(result.min_interval, Some(Duration::from_secs(60)));
+        assert_eq!(result.min_allowed_interval, Duration::from_secs(3600));
+        assert!(!result.satisfies_min_interval);
+        assert_eq!(result.next_occurrences.len(), 2);
+        assert_eq!(
+            result.next_occurrences[1] - result.next_occurrences[0],
+            Duration::from_secs(60)
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_validate_schedule(pool: PgPool) -> anyhow::Result<()> {
+        let app_state = mock_app_state(pool).await?;
+
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        let response = scheduler_validate_schedule(
+            web::Data::new(app_state),
+            user,
+            web::Json(SchedulerValidateScheduleParams {
+                schedule: "0 1 2 3 4 Sat 2050/2".to_string(),
+                next_occurrences: Some(3),
+            }),
+        )
+        .await?;
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().try_into_bytes().unwrap();
+        assert_eq!(
+            serde_json::from_slice::<SchedulerValidateScheduleResult>(&body)?,
+            SchedulerValidateScheduleResult {
+                valid: true,
+                error: None,
+                min_interval: Some(Duration::from_secs(189_302_400)),
+                min_allowed_interval: Duration::from_secs(10),
+                satisfies_min_interval: true,
+                next_occurrences: vec![
+                    OffsetDateTime::from_unix_timestamp(2848183260)?,
+                    OffsetDateTime::from_unix_timestamp(3037485660)?,
+                    OffsetDateTime::from_unix_timestamp(3731796060)?,
+                ]
+            }
+        );
+
+        Ok(())
+    }
+}
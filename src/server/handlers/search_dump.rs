@@ -0,0 +1,212 @@
+use crate::{
+    error::Error as SecutilsError, search::SearchFilter, security::Operator,
+    server::app_state::AppState, users::UserId,
+};
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchDumpParams {
+    pub query: Option<String>,
+    pub category: Option<String>,
+    pub user_id: Option<UserId>,
+    #[serde(default)]
+    pub offset: usize,
+    pub limit: Option<usize>,
+}
+
+/// Dumps a page of the documents stored in the search index with all their stored fields, so
+/// that operators can diagnose mis-indexed items.
+pub async fn search_dump(
+    state: web::Data<AppState>,
+    operator: Operator,
+    query_params: web::Query<SearchDumpParams>,
+) -> Result<HttpResponse, SecutilsError> {
+    let mut filter = SearchFilter::default();
+    if let Some(ref query) = query_params.query {
+        filter = filter.with_query(query);
+    }
+    if let Some(ref category) = query_params.category {
+        filter = filter.with_category(category);
+    }
+    if let Some(user_id) = query_params.user_id {
+        filter = filter.with_user_id(user_id);
+    }
+
+    let dump = state
+        .api
+        .search()
+        .dump(filter, query_params.offset, query_params.limit)
+        .map_err(|err| {
+            log::error!(
+                operator:serde = operator.id();
+                "Failed to dump search index: {err:?}"
+            );
+            SecutilsError::from(err)
+        })?;
+
+    Ok(HttpResponse::Ok().json(dump))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SearchDumpParams;
+    use crate::{
+        security::Operator,
+        server::handlers::search_dump,
+        tests::{mock_app_state, MockSearchItemBuilder},
+    };
+    use actix_web::{body::to_bytes, web};
+    use insta::assert_json_snapshot;
+    use sqlx::PgPool;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_dump_search_index(pool: PgPool) -> anyhow::Result<()> {
+        let app_state = web::Data::new(mock_app_state(pool).await?);
+
+        let search = app_state.api.search();
+        search.upsert(
+            MockSearchItemBuilder::new(
+                1,
+                "some-label",
+                "some-category",
+                // January 1, 2000 11:00:00
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
+            .build(),
+        )?;
+        search.upsert(
+            MockSearchItemBuilder::new(
+                2,
+                "other-label",
+                "other-category",
+                // January 1, 2010 11:00:00
+                OffsetDateTime::from_unix_timestamp(1262340000)?,
+            )
+            .set_keywords("some keywords")
+            .set_user_id(uuid!("00000000-0000-0000-0000-000000000003").into())
+            .set_sub_category("some-handle")
+            .build(),
+        )?;
+        search.upsert(
+            MockSearchItemBuilder::new(
+                3,
+                "another-label",
+                "other-category",
+                // January 1, 2010 11:00:00
+                OffsetDateTime::from_unix_timestamp(1262340000)?,
+            )
+            .set_user_id(uuid!("00000000-0000-0000-0000-000000000004").into())
+            .build(),
+        )?;
+
+        let dump = |params: SearchDumpParams| {
+            let app_state = app_state.clone();
+            async move {
+                let response =
+                    search_dump(app_state, Operator::new("operator"), web::Query(params)).await?;
+                assert_eq!(response.status(), 200);
+                let body = to_bytes(response.into_body()).await.unwrap();
+                anyhow::Ok(serde_json::from_slice::<serde_json::Value>(&body)?)
+            }
+        };
+
+        assert_json_snapshot!(dump(SearchDumpParams::default()).await?, @r###"
+        {
+          "documents": [
+            {
+              "category": "some-category",
+              "id": 1,
+              "label": "some-label",
+              "timestamp": 946720800
+            },
+            {
+              "category": "other-category",
+              "id": 2,
+              "keywords": "some keywords",
+              "label": "other-label",
+              "subCategory": "some-handle",
+              "timestamp": 1262340000,
+              "userId": "00000000-0000-0000-0000-000000000003"
+            },
+            {
+              "category": "other-category",
+              "id": 3,
+              "label": "another-label",
+              "timestamp": 1262340000,
+              "userId": "00000000-0000-0000-0000-000000000004"
+            }
+          ],
+          "total": 3
+        }
+        "###);
+
+        assert_json_snapshot!(dump(SearchDumpParams {
+            offset: 1,
+            limit: Some(1),
+            ..Default::default()
+        })
+        .await?, @r###"
+        {
+          "documents": [
+            {
+              "category": "other-category",
+              "id": 2,
+              "keywords": "some keywords",
+              "label": "other-label",
+              "subCategory": "some-handle",
+              "timestamp": 1262340000,
+              "userId": "00000000-0000-0000-0000-000000000003"
+            }
+          ],
+          "total": 3
+        }
+        "###);
+
+        assert_json_snapshot!(dump(SearchDumpParams {
+            user_id: Some(uuid!("00000000-0000-0000-0000-000000000004").into()),
+            ..Default::default()
+        })
+        .await?, @r###"
+        {
+          "documents": [
+            {
+              "category": "other-category",
+              "id": 3,
+              "label": "another-label",
+              "timestamp": 1262340000,
+              "userId": "00000000-0000-0000-0000-000000000004"
+            }
+          ],
+          "total": 1
+        }
+        "###);
+
+        assert_json_snapshot!(dump(SearchDumpParams {
+            query: Some("other".to_string()),
+            category: Some("other-category".to_string()),
+            ..Default::default()
+        })
+        .await?, @r###"
+        {
+          "documents": [
+            {
+              "category": "other-category",
+              "id": 2,
+              "keywords": "some keywords",
+              "label": "other-label",
+              "subCategory": "some-handle",
+              "timestamp": 1262340000,
+              "userId": "00000000-0000-0000-0000-000000000003"
+            }
+          ],
+          "total": 1
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{
+    logging::UserLogContext,
+    security::Operator,
+    server::{http_errors::generic_internal_server_error, AppState},
+    users::UserId,
+};
+use actix_web::{web, Error, HttpResponse, Responder};
+
+pub async fn security_users_audit_log_get(
+    state: web::Data<AppState>,
+    operator: Operator,
+    user_id: web::Path<UserId>,
+) -> impl Responder {
+    Ok::<HttpResponse, Error>(match state.api.users().get_audit_log(*user_id).await {
+        Ok(audit_log) => HttpResponse::Ok().json(audit_log),
+        Err(err) => {
+            log::error!(
+                operator:serde = operator.id(),
+                user:serde = UserLogContext::new(*user_id);
+                "Failed to retrieve user audit log: {err:?}"
+            );
+            generic_internal_server_error()
+        }
+    })
+}
@@ -0,0 +1,36 @@
+use crate::{error::Error as SecutilsError, security::Operator, server::app_state::AppState};
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Deserialize)]
+pub struct ImpersonateParams {
+    pub email: String,
+}
+
+pub async fn security_users_impersonate(
+    state: web::Data<AppState>,
+    body_params: web::Json<ImpersonateParams>,
+    operator: Operator,
+) -> Result<HttpResponse, SecutilsError> {
+    let body_params = body_params.into_inner();
+    if body_params.email.is_empty() {
+        return Ok(
+            HttpResponse::BadRequest().json(json!({ "message": "The email cannot be empty." }))
+        );
+    }
+
+    match state
+        .api
+        .security()
+        .impersonate(&operator, &body_params.email)
+        .await
+    {
+        Ok(Some(impersonation)) => Ok(HttpResponse::Ok().json(impersonation)),
+        Ok(None) => Ok(HttpResponse::NotFound().finish()),
+        Err(err) => {
+            log::error!(operator:serde = operator.id(); "Failed to impersonate user: {err:?}");
+            Err(SecutilsError::from(err))
+        }
+    }
+}
@@ -65,6 +65,7 @@ pub async fn security_users_signup(
         created_at: body_params.identity.created_at,
         is_activated: body_params.identity.is_activated(),
         is_operator: false,
+        impersonator: None,
         subscription,
     };
 
@@ -75,14 +76,14 @@ pub async fn security_users_signup(
         }
         Err(err) => {
             log::error!(operator:serde = operator.id(), user:serde = user.log_context(); "Failed to signup a user: {err:?}");
-            return match err.downcast_ref::<UserSignupError>() {
+            match err.downcast_ref::<UserSignupError>() {
                 Some(err) => match err {
                     UserSignupError::EmailAlreadyRegistered => HttpResponse::BadRequest().json(
                         json!({ "message": "The email address is already registered. Please try signing in or use a different email address." })
                     )
                 },
                 None => generic_internal_server_error(),
-            };
+            }
         }
     }
 }
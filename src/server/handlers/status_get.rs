@@ -1,15 +1,101 @@
 use crate::{error::Error as SecutilsError, server::app_state::AppState};
 use actix_web::{web, HttpResponse};
 use anyhow::anyhow;
-use std::ops::Deref;
 
 pub async fn status_get(state: web::Data<AppState>) -> Result<HttpResponse, SecutilsError> {
-    state
+    let mut status = state
         .status
         .read()
-        .map(|status| HttpResponse::Ok().json(status.deref()))
+        .map(|status| status.clone())
         .map_err(|err| {
             log::error!("Failed to read status: {err}");
             SecutilsError::from(anyhow!("Status is not available."))
-        })
+        })?;
+
+    // Report how many pending jobs the scheduler cannot process within a single run.
+    let overdue_jobs = state
+        .api
+        .web_scraping_system()
+        .get_overdue_tracker_jobs()
+        .await?;
+    status.scheduler_behind_jobs = Some(
+        overdue_jobs
+            .len()
+            .saturating_sub(state.config.scheduler.web_page_trackers_fetch_max_jobs),
+    );
+
+    Ok(HttpResponse::Ok().json(status))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        scheduler::SchedulerJob,
+        server::handlers::status_get,
+        tests::{
+            mock_app_state_with_config, mock_config, mock_scheduler_job, mock_upsert_scheduler_job,
+            mock_user, MockWebPageTrackerBuilder, RawSchedulerJobStoredData,
+        },
+        utils::web_scraping::{WebPageContentTrackerTag, WebPageTrackerKind},
+    };
+    use actix_web::{body::MessageBody, web};
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    #[sqlx::test]
+    async fn reports_scheduler_behind_jobs(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        config.scheduler.web_page_trackers_fetch_max_jobs = 2;
+
+        let app_state = mock_app_state_with_config(pool, config).await?;
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        // Seed more overdue jobs than can be processed within a single run.
+        for n in 0..5 {
+            let job_id = Uuid::parse_str(&format!("67e55044-10b1-426f-9247-bb680e5fe0c{n}"))?;
+            mock_upsert_scheduler_job(
+                &app_state.api.db,
+                &RawSchedulerJobStoredData {
+                    last_tick: Some(946720700 + n),
+                    stopped: Some(true),
+                    ..mock_scheduler_job(
+                        job_id,
+                        SchedulerJob::WebPageTrackersTrigger {
+                            kind: WebPageTrackerKind::WebPageContent,
+                        },
+                        "0 0 * * * *",
+                    )
+                },
+            )
+            .await?;
+
+            app_state
+                .api
+                .db
+                .web_scraping(user.id)
+                .insert_web_page_tracker(
+                    &MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+                        Uuid::parse_str(&format!("77e55044-10b1-426f-9247-bb680e5fe0c{n}"))?,
+                        format!("name_{n}"),
+                        "https://secutils.dev",
+                        3,
+                    )?
+                    .with_schedule("0 0 * * * *")
+                    .with_job_id(job_id)
+                    .build(),
+                )
+                .await?;
+        }
+
+        let response = status_get(web::Data::new(app_state)).await?;
+        assert_eq!(response.status(), 200);
+
+        let body = response.into_body().try_into_bytes().unwrap();
+        let status = serde_json::from_slice::<serde_json::Value>(&body)?;
+        assert_eq!(status["level"], "available");
+        assert_eq!(status["schedulerBehindJobs"], 3);
+
+        Ok(())
+    }
 }
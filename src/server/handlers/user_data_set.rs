@@ -27,6 +27,11 @@ pub async fn user_data_set(
     body_params: web::Json<SetDataBodyParameters>,
     user: User,
 ) -> impl Responder {
+    // Operators that impersonate users cannot change users' account data on their behalf.
+    if user.impersonator.is_some() {
+        return HttpResponse::Forbidden().finish();
+    }
+
     let body_params = body_params.into_inner();
     if body_params.data_value.is_empty() {
         return HttpResponse::Ok().json(json!({ "status": "ok" }));
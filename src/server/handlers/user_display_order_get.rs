@@ -0,0 +1,29 @@
+use crate::{
+    error::Error as SecutilsError,
+    logging::UserLogContext,
+    server::app_state::AppState,
+    users::{User, UserDisplayOrderCollection},
+};
+use actix_web::{web, HttpResponse};
+
+pub async fn user_display_order_get(
+    state: web::Data<AppState>,
+    user: User,
+    collection: web::Path<UserDisplayOrderCollection>,
+) -> Result<HttpResponse, SecutilsError> {
+    let display_order = state
+        .api
+        .users()
+        .get_display_order(user.id, *collection)
+        .await
+        .map_err(|err| {
+            log::error!(
+                user:serde = UserLogContext::new(user.id);
+                "Failed to retrieve display order ({:?}): {err:?}",
+                *collection
+            );
+            SecutilsError::from(err)
+        })?;
+
+    Ok(HttpResponse::Ok().json(display_order))
+}
@@ -0,0 +1,38 @@
+use crate::{
+    error::Error as SecutilsError,
+    logging::UserLogContext,
+    server::app_state::AppState,
+    users::{User, UserDisplayOrderCollection},
+};
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PinBodyParameters {
+    pub pinned: bool,
+}
+
+pub async fn user_display_order_pin(
+    state: web::Data<AppState>,
+    user: User,
+    path: web::Path<(UserDisplayOrderCollection, Uuid)>,
+    body_params: web::Json<PinBodyParameters>,
+) -> Result<HttpResponse, SecutilsError> {
+    let (collection, entity_id) = path.into_inner();
+    let display_order = state
+        .api
+        .users()
+        .set_display_pinned(user.id, collection, entity_id, body_params.pinned)
+        .await
+        .map_err(|err| {
+            log::error!(
+                user:serde = UserLogContext::new(user.id);
+                "Failed to update pinned state of the entity ({collection:?}, {entity_id}): {err:?}"
+            );
+            SecutilsError::from(err)
+        })?;
+
+    Ok(HttpResponse::Ok().json(display_order))
+}
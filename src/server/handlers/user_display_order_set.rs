@@ -0,0 +1,38 @@
+use crate::{
+    error::Error as SecutilsError,
+    logging::UserLogContext,
+    server::app_state::AppState,
+    users::{User, UserDisplayOrderCollection},
+};
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDisplayOrderBodyParameters {
+    pub order: Vec<Uuid>,
+}
+
+pub async fn user_display_order_set(
+    state: web::Data<AppState>,
+    user: User,
+    collection: web::Path<UserDisplayOrderCollection>,
+    body_params: web::Json<SetDisplayOrderBodyParameters>,
+) -> Result<HttpResponse, SecutilsError> {
+    let display_order = state
+        .api
+        .users()
+        .set_display_order(user.id, *collection, body_params.into_inner().order)
+        .await
+        .map_err(|err| {
+            log::error!(
+                user:serde = UserLogContext::new(user.id);
+                "Failed to set display order ({:?}): {err:?}",
+                *collection
+            );
+            SecutilsError::from(err)
+        })?;
+
+    Ok(HttpResponse::Ok().json(display_order))
+}
@@ -141,19 +141,31 @@ pub async fn utils_action(
         return Err(SecutilsError::access_forbidden());
     };
 
+    // Operators that impersonate users cannot modify user data on their behalf.
+    if user.impersonator.is_some() && !action.is_allowed_for_impersonator() {
+        return Err(SecutilsError::access_forbidden());
+    }
+
     let user_id = user.id;
     let params = body_params.map(|body| UtilsActionParams::json(body.into_inner()));
     let action_result = match resource {
         UtilsResource::CertificatesTemplates | UtilsResource::CertificatesPrivateKeys => {
             certificates_handle_action(user, &state.api, action, resource, params).await
         }
-        UtilsResource::WebhooksResponders => {
+        UtilsResource::WebhooksResponders
+        | UtilsResource::WebhooksInboundEmails
+        | UtilsResource::WebhooksResponderBasePaths => {
             webhooks_handle_action(user, &state.api, action, resource, params).await
         }
-        UtilsResource::WebScrapingResources | UtilsResource::WebScrapingContent => {
+        UtilsResource::WebScrapingResources
+        | UtilsResource::WebScrapingContent
+        | UtilsResource::WebScrapingScreenshots
+        | UtilsResource::WebScrapingDnsRecords
+        | UtilsResource::WebScrapingStatusPages => {
             web_scraping_handle_action(user, &state.api, action, resource, params).await
         }
-        UtilsResource::WebSecurityContentSecurityPolicies => {
+        UtilsResource::WebSecurityContentSecurityPolicies
+        | UtilsResource::WebSecurityJsonWebTokens => {
             web_security_handle_action(user, &state.api, action, resource, params).await
         }
     };
@@ -186,18 +198,26 @@ pub async fn utils_action(
 mod tests {
     use super::{extract_action, extract_resource, extract_user, utils_action};
     use crate::{
-        tests::{mock_api, mock_app_state, mock_user, mock_user_with_id},
-        users::{SharedResource, UserShare, UserShareId},
+        security::Operator,
+        tests::{
+            mock_api, mock_app_state, mock_app_state_with_config, mock_config, mock_user,
+            mock_user_with_id,
+        },
+        users::{SharedResource, User, UserShare, UserShareId},
         utils::{
             certificates::{tests::PrivateKeysCreateParams, PrivateKeyAlgorithm},
             UtilsAction, UtilsResource, UtilsResourceOperation,
         },
     };
-    use actix_web::{body::MessageBody, http::Method, test::TestRequest, web};
+    use actix_web::{
+        body::MessageBody, dev::Payload, http::Method, test::TestRequest, web, FromRequest,
+    };
+    use httpmock::MockServer;
     use insta::assert_debug_snapshot;
     use serde_json::json;
     use sqlx::PgPool;
     use time::OffsetDateTime;
+    use url::Url;
     use uuid::uuid;
 
     #[test]
@@ -270,6 +290,24 @@ mod tests {
             ),
             Some(UtilsResource::WebScrapingContent)
         );
+        assert_eq!(
+            extract_resource(
+                &TestRequest::with_uri("https://secutils.dev/api/utils")
+                    .param("area", "web_scraping")
+                    .param("resource", "screenshots")
+                    .to_http_request(),
+            ),
+            Some(UtilsResource::WebScrapingScreenshots)
+        );
+        assert_eq!(
+            extract_resource(
+                &TestRequest::with_uri("https://secutils.dev/api/utils")
+                    .param("area", "web_scraping")
+                    .param("resource", "dns")
+                    .to_http_request(),
+            ),
+            Some(UtilsResource::WebScrapingDnsRecords)
+        );
     }
 
     #[test]
@@ -281,6 +319,8 @@ mod tests {
             UtilsResource::WebhooksResponders,
             UtilsResource::WebScrapingResources,
             UtilsResource::WebScrapingContent,
+            UtilsResource::WebScrapingScreenshots,
+            UtilsResource::WebScrapingDnsRecords,
             UtilsResource::WebSecurityContentSecurityPolicies,
         ] {
             assert!(extract_action(
@@ -311,6 +351,8 @@ mod tests {
             UtilsResource::WebhooksResponders,
             UtilsResource::WebScrapingResources,
             UtilsResource::WebScrapingContent,
+            UtilsResource::WebScrapingScreenshots,
+            UtilsResource::WebScrapingDnsRecords,
             UtilsResource::WebSecurityContentSecurityPolicies,
         ] {
             assert_eq!(
@@ -411,6 +453,21 @@ mod tests {
                 operation: UtilsResourceOperation::CertificatesTemplateGenerate
             })
         );
+
+        assert_eq!(
+            extract_action(
+                &TestRequest::with_uri("https://secutils.dev/api/utils")
+                    .method(Method::POST)
+                    .param("resource_id", resource_id.to_string())
+                    .param("resource_operation", "archive")
+                    .to_http_request(),
+                &resource,
+            ),
+            Some(UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::CertificatesTemplateGenerateArchive
+            })
+        );
     }
 
     #[test]
@@ -482,6 +539,20 @@ mod tests {
                 operation: UtilsResourceOperation::WebhooksRespondersGetStats
             })
         );
+
+        assert_eq!(
+            extract_action(
+                &TestRequest::with_uri("https://secutils.dev/api/utils")
+                    .method(Method::POST)
+                    .param("resource_id", "subdomain_prefix")
+                    .to_http_request(),
+                &resource,
+            ),
+            Some(UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebhooksRespondersCheckSubdomainPrefix
+            })
+        );
     }
 
     #[test]
@@ -577,6 +648,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn can_extract_web_security_json_web_tokens_actions() {
+        let resource = UtilsResource::WebSecurityJsonWebTokens;
+
+        assert_eq!(
+            extract_action(
+                &TestRequest::with_uri("https://secutils.dev/api/utils")
+                    .method(Method::POST)
+                    .param("resource_id", "decode")
+                    .to_http_request(),
+                &resource,
+            ),
+            Some(UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebSecurityJsonWebTokenDecode
+            })
+        );
+    }
+
     #[sqlx::test]
     async fn can_extract_user(pool: PgPool) -> anyhow::Result<()> {
         let resource_id = uuid!("00000000-0000-0000-0000-000000000001");
@@ -810,6 +900,136 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn fail_if_impersonator_executes_destructive_operation(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let app_state = web::Data::new(mock_app_state(pool).await?);
+
+        let user = User {
+            impersonator: Some("operator@secutils.dev".to_string()),
+            ..mock_user()?
+        };
+        app_state.api.db.upsert_user(&user).await?;
+
+        let request = TestRequest::with_uri("https://secutils.dev/api/utils")
+            .method(Method::POST)
+            .param("area", "web_scraping")
+            .param("resource", "content")
+            .param("resource_id", "00000000-0000-0000-0000-000000000001")
+            .param("resource_operation", "clear")
+            .to_http_request();
+        assert_debug_snapshot!(
+            utils_action(app_state.clone(), Some(user.clone()), None, request, None).await,
+            @r###"
+        Err(
+            "Access Forbidden",
+        )
+        "###
+        );
+
+        let request = TestRequest::with_uri("https://secutils.dev/api/utils")
+            .method(Method::POST)
+            .param("area", "webhooks")
+            .param("resource", "responders")
+            .param("resource_id", "00000000-0000-0000-0000-000000000001")
+            .param("resource_operation", "clear")
+            .to_http_request();
+        assert_debug_snapshot!(
+            utils_action(app_state, Some(user), None, request, None).await,
+            @r###"
+        Err(
+            "Access Forbidden",
+        )
+        "###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fail_if_impersonator_updates_resource(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.kratos_admin_url = Url::parse(&server.base_url())?;
+        config.security.jwt_secret = Some("secret".to_string());
+        config.security.operators = Some(["ops@secutils.dev".to_string()].into_iter().collect());
+        let app_state = web::Data::new(mock_app_state_with_config(pool, config).await?);
+
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        let impersonation = app_state
+            .api
+            .security()
+            .impersonate(&Operator::new("ops@secutils.dev"), &user.email)
+            .await?
+            .unwrap();
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/admin/identities")
+                .query_param("credentials_identifier", &user.email);
+            then.status(200).json_body(json!([{
+                "id": *user.id,
+                "traits": { "email": user.email },
+                "verifiable_addresses": [{ "value": user.email, "verified": true }],
+                "created_at": "2010-01-01T10:00:00Z"
+            }]));
+        });
+
+        let request = TestRequest::with_uri(
+            "https://secutils.dev/api/utils/web_scraping/content/00000000-0000-0000-0000-000000000001",
+        )
+        .method(Method::PUT)
+        .app_data(app_state.clone())
+        .insert_header(("Authorization", format!("Bearer {}", impersonation.token)))
+        .param("area", "web_scraping")
+        .param("resource", "content")
+        .param("resource_id", "00000000-0000-0000-0000-000000000001")
+        .to_http_request();
+        let impersonated_user = User::from_request(&request, &mut Payload::None)
+            .await
+            .unwrap();
+        assert_eq!(
+            impersonated_user.impersonator.as_deref(),
+            Some("ops@secutils.dev")
+        );
+
+        assert_debug_snapshot!(
+            utils_action(
+                app_state.clone(),
+                Some(impersonated_user),
+                None,
+                request,
+                Some(web::Json(json!({ "name": "name_two" })))
+            )
+            .await,
+            @r###"
+        Err(
+            "Access Forbidden",
+        )
+        "###
+        );
+
+        // Rejected attempt is still attributed to the operator in the audit log.
+        let audit_log = app_state.api.users().get_audit_log(user.id).await?;
+        assert_eq!(
+            audit_log
+                .iter()
+                .map(|entry| (entry.operator.as_deref(), entry.action.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Some("ops@secutils.dev"), "Started impersonation session"),
+                (
+                    Some("ops@secutils.dev"),
+                    "PUT /api/utils/web_scraping/content/00000000-0000-0000-0000-000000000001"
+                )
+            ]
+        );
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn fail_if_action_parameters_are_invalid(pool: PgPool) -> anyhow::Result<()> {
         let app_state = mock_app_state(pool).await?;
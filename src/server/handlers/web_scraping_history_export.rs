@@ -0,0 +1,289 @@
+use crate::{
+    error::Error as SecutilsError,
+    logging::UserLogContext,
+    server::app_state::AppState,
+    users::User,
+    utils::web_scraping::{WebPageTrackerExportHistoryParams, WebPageTrackerKind},
+};
+use actix_web::{web, HttpResponse};
+use async_stream::try_stream;
+use bytes::Bytes;
+use futures::{
+    stream::{self, LocalBoxStream},
+    StreamExt,
+};
+use uuid::Uuid;
+
+/// Streams all revisions of the web page tracker as newline-delimited JSON (JSONL), one revision
+/// per line, so that arbitrarily large histories can be exported without buffering.
+pub async fn web_scraping_history_export(
+    state: web::Data<AppState>,
+    user: User,
+    path: web::Path<(String, Uuid)>,
+    query: web::Query<WebPageTrackerExportHistoryParams>,
+) -> Result<HttpResponse, SecutilsError> {
+    let (resource, tracker_id) = path.into_inner();
+    let tracker_kind = match resource.as_str() {
+        "resources" => WebPageTrackerKind::WebPageResources,
+        "content" => WebPageTrackerKind::WebPageContent,
+        "screenshots" => WebPageTrackerKind::WebPageScreenshot,
+        "dns" => WebPageTrackerKind::WebPageDnsRecords,
+        _ => return Ok(HttpResponse::NotFound().finish()),
+    };
+
+    let (api, user_id, params) = (state.api.clone(), user.id, query.into_inner());
+    let mut lines: LocalBoxStream<'static, anyhow::Result<Bytes>> = try_stream! {
+        let web_scraping = api.web_scraping(&user);
+        let tracker_lines = match tracker_kind {
+            WebPageTrackerKind::WebPageResources => {
+                web_scraping.export_resources_tracker_history(tracker_id, params).await?
+            }
+            WebPageTrackerKind::WebPageContent => {
+                web_scraping.export_content_tracker_history(tracker_id, params).await?
+            }
+            WebPageTrackerKind::WebPageScreenshot => {
+                web_scraping.export_screenshot_tracker_history(tracker_id, params).await?
+            }
+            WebPageTrackerKind::WebPageDnsRecords => {
+                web_scraping.export_dns_records_tracker_history(tracker_id, params).await?
+            }
+        };
+
+        for await line in tracker_lines {
+            yield line?;
+        }
+    }
+    .boxed_local();
+
+    // Wait for the first line before responding, so that errors that happen before any data is
+    // streamed (e.g. tracker doesn't exist) are reported with a proper status code.
+    let first_line = match lines.next().await {
+        Some(Err(err)) => {
+            log::error!(
+                user:serde = UserLogContext::new(user_id);
+                "Failed to export web page tracker ({tracker_id}) history: {err:?}"
+            );
+            return Err(SecutilsError::from(err));
+        }
+        first_line => first_line,
+    };
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/x-ndjson")
+        .streaming(stream::iter(first_line).chain(lines)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        scheduler::SchedulerJobConfig,
+        server::handlers::web_scraping_history_export,
+        tests::{mock_app_state, mock_user},
+        utils::web_scraping::{
+            tests::WebPageTrackerCreateParams, WebPageContentTrackerTag, WebPageDataRevision,
+            WebPageTrackerExportHistoryParams, WebPageTrackerSettings,
+        },
+    };
+    use actix_web::{body::to_bytes, web, ResponseError};
+    use bytes::Bytes;
+    use serde_json::{json, Value as JsonValue};
+    use sqlx::PgPool;
+    use std::time::Duration;
+    use time::OffsetDateTime;
+    use url::Url;
+    use uuid::{uuid, Uuid};
+
+    fn parse_lines(body: &Bytes) -> anyhow::Result<Vec<JsonValue>> {
+        let body = std::str::from_utf8(body)?;
+        assert!(body.ends_with('\n'));
+
+        // Every line should be a valid standalone JSON document.
+        Ok(body
+            .lines()
+            .map(serde_json::from_str::<JsonValue>)
+            .collect::<Result<_, _>>()?)
+    }
+
+    #[sqlx::test]
+    async fn can_export_history(pool: PgPool) -> anyhow::Result<()> {
+        let app_state = mock_app_state(pool).await?;
+
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        let tracker = app_state
+            .api
+            .web_scraping(&user)
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+        for (index, data) in ["some-data", "other-data", "some-other-data"]
+            .into_iter()
+            .enumerate()
+        {
+            app_state
+                .api
+                .db
+                .web_scraping(user.id)
+                .insert_web_page_tracker_history_revision::<WebPageContentTrackerTag>(
+                    &WebPageDataRevision {
+                        id: Uuid::from_u128(index as u128 + 1),
+                        tracker_id: tracker.id,
+                        data: data.to_string(),
+                        created_at: OffsetDateTime::from_unix_timestamp(
+                            946720800 + index as i64 * 100,
+                        )?,
+                        note: None,
+                        acknowledgment: None,
+                        diff_stats: None,
+                        assertions_passed: None,
+                    },
+                )
+                .await?;
+        }
+
+        let app_state = web::Data::new(app_state);
+        let response = web_scraping_history_export(
+            app_state.clone(),
+            user.clone(),
+            web::Path::from(("content".to_string(), tracker.id)),
+            web::Query(WebPageTrackerExportHistoryParams::default()),
+        )
+        .await?;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "application/x-ndjson"
+        );
+
+        let body = to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(
+            parse_lines(&body)?,
+            vec![
+                json!({
+                    "id": "00000000-0000-0000-0000-000000000001",
+                    "data": "some-data",
+                    "createdAt": 946720800
+                }),
+                json!({
+                    "id": "00000000-0000-0000-0000-000000000002",
+                    "data": "other-data",
+                    "createdAt": 946720900
+                }),
+                json!({
+                    "id": "00000000-0000-0000-0000-000000000003",
+                    "data": "some-other-data",
+                    "createdAt": 946721000
+                }),
+            ]
+        );
+
+        let response = web_scraping_history_export(
+            app_state,
+            user,
+            web::Path::from(("content".to_string(), tracker.id)),
+            web::Query(WebPageTrackerExportHistoryParams {
+                calculate_diff: true,
+            }),
+        )
+        .await?;
+        assert_eq!(response.status(), 200);
+
+        let body = to_bytes(response.into_body()).await.unwrap();
+        let lines = parse_lines(&body)?;
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0]["data"], "some-data");
+        for (line, (previous_data, data)) in lines.iter().skip(1).zip([
+            ("some-data", "other-data"),
+            ("other-data", "some-other-data"),
+        ]) {
+            let diff = line["data"].as_str().unwrap();
+            assert!(diff.contains(&format!("-{previous_data}")));
+            assert!(diff.contains(&format!("+{data}")));
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_export_empty_history(pool: PgPool) -> anyhow::Result<()> {
+        let app_state = mock_app_state(pool).await?;
+
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        let tracker = app_state
+            .api
+            .web_scraping(&user)
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: None,
+            })
+            .await?;
+
+        let response = web_scraping_history_export(
+            web::Data::new(app_state),
+            user,
+            web::Path::from(("content".to_string(), tracker.id)),
+            web::Query(WebPageTrackerExportHistoryParams::default()),
+        )
+        .await?;
+        assert_eq!(response.status(), 200);
+        assert!(to_bytes(response.into_body()).await.unwrap().is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_to_export_history_of_unknown_tracker(pool: PgPool) -> anyhow::Result<()> {
+        let app_state = web::Data::new(mock_app_state(pool).await?);
+
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        let tracker_id = uuid!("00000000-0000-0000-0000-000000000001");
+        let err = web_scraping_history_export(
+            app_state.clone(),
+            user.clone(),
+            web::Path::from(("content".to_string(), tracker_id)),
+            web::Query(WebPageTrackerExportHistoryParams::default()),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.status_code(), 400);
+        assert_eq!(
+            err.to_string(),
+            "Web page tracker ('00000000-0000-0000-0000-000000000001') is not found."
+        );
+
+        let response = web_scraping_history_export(
+            app_state,
+            user,
+            web::Path::from(("unknown".to_string(), tracker_id)),
+            web::Query(WebPageTrackerExportHistoryParams::default()),
+        )
+        .await?;
+        assert_eq!(response.status(), 404);
+
+        Ok(())
+    }
+}
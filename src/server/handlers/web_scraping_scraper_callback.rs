@@ -0,0 +1,192 @@
+use crate::{
+    error::Error as SecutilsError,
+    logging::UserLogContext,
+    scheduler::WebPageTrackersFetchJob,
+    server::app_state::AppState,
+    utils::web_scraping::{WebScraperCallbackResult, WebScraperScreenshotResponse},
+};
+use actix_web::{web, HttpRequest, HttpResponse};
+
+/// Name of the header the web scraper passes the scrape job callback token in.
+const SCRAPER_CALLBACK_TOKEN_HEADER: &str = "x-scraper-callback-token";
+
+/// Receives the result of the asynchronous scrape job from the web scraper. This handler doesn't
+/// require authentication, the callback token issued for the scrape job grants access to it.
+pub async fn web_scraping_scraper_callback(
+    state: web::Data<AppState>,
+    request: HttpRequest,
+    result: web::Json<WebScraperCallbackResult<WebScraperScreenshotResponse>>,
+) -> Result<HttpResponse, SecutilsError> {
+    let result = result.into_inner();
+    let Some((user_id, pending_scrape)) = state
+        .api
+        .web_scraping_system()
+        .get_pending_scrape(&result.job_id)
+        .await?
+    else {
+        log::error!(
+            "Received a callback for unknown scrape job ('{}').",
+            result.job_id
+        );
+        return Ok(HttpResponse::NotFound().finish());
+    };
+
+    let token = request
+        .headers()
+        .get(SCRAPER_CALLBACK_TOKEN_HEADER)
+        .and_then(|token| token.to_str().ok());
+    if token != Some(pending_scrape.token.as_str()) {
+        log::error!(
+            user:serde = UserLogContext::new(user_id);
+            "Received a callback with invalid token for scrape job ('{}').", pending_scrape.job_id
+        );
+        return Ok(HttpResponse::Unauthorized().finish());
+    }
+
+    let Some(user) = state.api.users().get(user_id).await? else {
+        log::error!(
+            user:serde = UserLogContext::new(user_id);
+            "Failed to find user for scrape job ('{}').", pending_scrape.job_id
+        );
+        return Ok(HttpResponse::NotFound().finish());
+    };
+
+    WebPageTrackersFetchJob::complete_screenshot_tracker_scrape(
+        &state.api,
+        &user,
+        pending_scrape,
+        result,
+    )
+    .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::handlers::web_scraping_scraper_callback,
+        tests::{mock_app_state_with_config, mock_config, mock_user},
+        utils::web_scraping::{
+            tests::MockWebPageTrackerBuilder, WebPageScreenshotTrackerTag,
+            WebScraperCallbackResult, WebScraperScreenshotResponse,
+        },
+    };
+    use actix_web::{test::TestRequest, web};
+    use httpmock::MockServer;
+    use sqlx::PgPool;
+    use time::OffsetDateTime;
+    use url::Url;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_complete_async_screenshot_scrape(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+        config.utils.web_scraping.async_scrapes = true;
+
+        let app_state = web::Data::new(mock_app_state_with_config(pool, config).await?);
+
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageScreenshotTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        app_state
+            .api
+            .db
+            .web_scraping(user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+
+        let scraper_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/screenshot")
+                .body_contains("https://secutils.dev/api/web_scraping/scraper_callback");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body(serde_json::json!({ "jobId": "job-1" }));
+        });
+
+        let web_scraping = app_state.api.web_scraping(&user);
+        let revision = web_scraping
+            .create_screenshot_tracker_revision(tracker.id)
+            .await?;
+        assert!(revision.is_none());
+        scraper_mock.assert();
+
+        let (user_id, pending_scrape) = app_state
+            .api
+            .web_scraping_system()
+            .get_pending_scrape("job-1")
+            .await?
+            .unwrap();
+        assert_eq!(user_id, user.id);
+        assert_eq!(pending_scrape.tracker_id, tracker.id);
+
+        let callback_result = || WebScraperCallbackResult {
+            job_id: "job-1".to_string(),
+            response: Some(WebScraperScreenshotResponse {
+                timestamp: OffsetDateTime::from_unix_timestamp(946720800).unwrap(),
+                screenshot: vec![1, 2, 3],
+            }),
+            error: None,
+        };
+
+        // Callback with invalid token is rejected.
+        let response = web_scraping_scraper_callback(
+            app_state.clone(),
+            TestRequest::default()
+                .insert_header(("x-scraper-callback-token", "wrong-token"))
+                .to_http_request(),
+            web::Json(callback_result()),
+        )
+        .await?;
+        assert_eq!(response.status(), 401);
+        assert!(web_scraping
+            .get_screenshot_tracker_history(tracker.id, Default::default())
+            .await?
+            .is_empty());
+
+        let response = web_scraping_scraper_callback(
+            app_state.clone(),
+            TestRequest::default()
+                .insert_header(("x-scraper-callback-token", pending_scrape.token.as_str()))
+                .to_http_request(),
+            web::Json(callback_result()),
+        )
+        .await?;
+        assert_eq!(response.status(), 204);
+
+        let history = web_scraping
+            .get_screenshot_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].data.image, vec![1, 2, 3]);
+
+        // Result of the scrape job can only be delivered once.
+        assert!(app_state
+            .api
+            .web_scraping_system()
+            .get_pending_scrape("job-1")
+            .await?
+            .is_none());
+        let response = web_scraping_scraper_callback(
+            app_state,
+            TestRequest::default()
+                .insert_header(("x-scraper-callback-token", pending_scrape.token.as_str()))
+                .to_http_request(),
+            web::Json(callback_result()),
+        )
+        .await?;
+        assert_eq!(response.status(), 404);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{error::Error as SecutilsError, logging::UserLogContext, server::app_state::AppState};
+use actix_web::{web, HttpResponse};
+
+/// Renders public status page with the specified token. This handler doesn't require
+/// authentication, the token itself grants access to the status page.
+pub async fn web_scraping_status_page_get(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+) -> Result<HttpResponse, SecutilsError> {
+    let token = path.into_inner();
+    let Some((user_id, status_page)) = state
+        .api
+        .web_scraping_system()
+        .get_status_page_by_token(&token)
+        .await?
+    else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+
+    let Some(user) = state.api.users().get(user_id).await? else {
+        log::error!(
+            user:serde = UserLogContext::new(user_id);
+            "Failed to find user for status page ({}).", status_page.id
+        );
+        return Ok(HttpResponse::NotFound().finish());
+    };
+
+    let html = state
+        .api
+        .web_scraping(&user)
+        .render_status_page(&status_page)
+        .await
+        .map_err(|err| {
+            log::error!(
+                user:serde = UserLogContext::new(user.id);
+                "Failed to render status page ({}): {err:?}", status_page.id
+            );
+            SecutilsError::from(err)
+        })?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(html))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        server::handlers::web_scraping_status_page_get,
+        tests::{mock_app_state, mock_user},
+        utils::web_scraping::{
+            tests::MockWebPageTrackerBuilder, WebPageContentStatus, WebPageContentTrackerTag,
+            WebPageDataRevision, WebPageStatusPage,
+        },
+    };
+    use actix_web::{body::to_bytes, web};
+    use sqlx::PgPool;
+    use std::time::Duration;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[sqlx::test]
+    async fn can_render_status_page(pool: PgPool) -> anyhow::Result<()> {
+        let app_state = web::Data::new(mock_app_state(pool).await?);
+
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        let mut tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "API",
+            "https://secutils.dev/api",
+            3,
+        )?
+        .build();
+        tracker.settings.status_only = true;
+
+        let web_scraping = app_state.api.db.web_scraping(user.id);
+        web_scraping.insert_web_page_tracker(&tracker).await?;
+        web_scraping
+            .insert_web_page_tracker_history_revision::<WebPageContentTrackerTag>(
+                &WebPageDataRevision {
+                    id: uuid!("00000000-0000-0000-0000-000000000002"),
+                    tracker_id: tracker.id,
+                    data: serde_json::to_string(&WebPageContentStatus {
+                        status: 200,
+                        latency: Duration::from_millis(100),
+                    })?,
+                    created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
+                },
+            )
+            .await?;
+        web_scraping
+            .insert_web_page_status_page(&WebPageStatusPage {
+                id: uuid!("00000000-0000-0000-0000-000000000003"),
+                name: "Secutils.dev Status".to_string(),
+                token: "some-token".to_string(),
+                tracker_ids: vec![tracker.id],
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                updated_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            })
+            .await?;
+
+        let response = web_scraping_status_page_get(
+            app_state.clone(),
+            web::Path::from("some-token".to_string()),
+        )
+        .await?;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "text/html; charset=utf-8"
+        );
+
+        let body = to_bytes(response.into_body()).await.unwrap();
+        let body = std::str::from_utf8(&body)?;
+        assert!(body.contains("<h1>Secutils.dev Status</h1>"));
+        assert!(body.contains("<h2>API</h2>"));
+        assert!(body.contains("Up (HTTP 200) since 2000-01-01T10:00:00Z"));
+
+        let response =
+            web_scraping_status_page_get(app_state, web::Path::from("unknown-token".to_string()))
+                .await?;
+        assert_eq!(response.status(), 404);
+
+        Ok(())
+    }
+}
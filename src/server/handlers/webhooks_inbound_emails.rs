@@ -0,0 +1,104 @@
+use crate::{
+    error::Error as SecutilsError, logging::UserLogContext, security::Operator,
+    server::app_state::AppState, utils::webhooks::InboundEmailCreateParams,
+};
+use actix_web::{web, HttpResponse};
+
+/// Receives an inbound email parsed by the mail transfer agent and stores it for the user the
+/// recipient address belongs to (`<user-handle>[+<tag>]@<domain>`).
+pub async fn webhooks_inbound_emails(
+    state: web::Data<AppState>,
+    operator: Operator,
+    params: web::Json<InboundEmailCreateParams>,
+) -> Result<HttpResponse, SecutilsError> {
+    let params = params.into_inner();
+    let Some(user_handle) = params.recipient_handle() else {
+        log::error!(operator:serde = operator.id(); "Failed to parse inbound email recipient.");
+        return Ok(HttpResponse::NotFound().finish());
+    };
+
+    let Some(user) = state.api.users().get_by_handle(&user_handle).await? else {
+        log::error!(
+            operator:serde = operator.id();
+            "Failed to find user by handle (`{user_handle}`) for inbound email."
+        );
+        return Ok(HttpResponse::NotFound().finish());
+    };
+
+    let email = state
+        .api
+        .webhooks(&user)
+        .create_inbound_email(params)
+        .await
+        .map_err(|err| {
+            log::error!(
+                operator:serde = operator.id(),
+                user:serde = UserLogContext::new(user.id);
+                "Failed to store inbound email: {err:?}"
+            );
+            SecutilsError::from(err)
+        })?;
+
+    log::info!(
+        operator:serde = operator.id(),
+        user:serde = UserLogContext::new(user.id);
+        "Successfully stored inbound email ({}).", email.id
+    );
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        security::Operator,
+        server::handlers::webhooks_inbound_emails,
+        tests::{mock_app_state_with_config, mock_config, mock_user},
+        utils::webhooks::InboundEmailCreateParams,
+    };
+    use actix_web::web;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn can_receive_inbound_email(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        config.utils.max_inbound_emails = Some(10);
+        let app_state = web::Data::new(mock_app_state_with_config(pool, config).await?);
+
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        let response = webhooks_inbound_emails(
+            app_state.clone(),
+            Operator::new("operator"),
+            web::Json(InboundEmailCreateParams {
+                from: "sender@example.com".to_string(),
+                to: format!("{}+tag@secutils.dev", user.handle),
+                subject: "Subject".to_string(),
+                body: "Body".to_string(),
+            }),
+        )
+        .await?;
+        assert_eq!(response.status(), 204);
+
+        let emails = app_state.api.webhooks(&user).get_inbound_emails().await?;
+        assert_eq!(emails.len(), 1);
+        assert_eq!(emails[0].from, "sender@example.com");
+        assert_eq!(emails[0].subject, "Subject");
+
+        let response = webhooks_inbound_emails(
+            app_state,
+            Operator::new("operator"),
+            web::Json(InboundEmailCreateParams {
+                from: "sender@example.com".to_string(),
+                to: "unknown@secutils.dev".to_string(),
+                subject: "Subject".to_string(),
+                body: "Body".to_string(),
+            }),
+        )
+        .await?;
+        assert_eq!(response.status(), 404);
+
+        Ok(())
+    }
+}
@@ -1,29 +1,44 @@
 use crate::{
-    config::Config,
     error::Error as SecutilsError,
     js_runtime::{JsRuntime, JsRuntimeConfig},
     logging::{MetricsContext, UtilsResourceLogContext},
     server::app_state::AppState,
     utils::{
-        webhooks::{ResponderScriptContext, ResponderScriptResult, RespondersRequestCreateParams},
+        webhooks::{
+            ResponderEchoTransformContext, ResponderEchoTransformResult, ResponderFault,
+            ResponderMatch, ResponderScriptContext, ResponderScriptResult,
+            RespondersRequestCreateParams,
+        },
         UtilsResource,
     },
 };
 use actix_web::{
-    body::MessageBody,
+    body::{BodyStream, BoxBody, MessageBody, SizedStream},
     http::{
-        header::{HeaderName, HeaderValue},
+        header::{HeaderName, HeaderValue, CONTENT_TYPE},
         StatusCode,
     },
     web, HttpRequest, HttpResponse,
 };
-use anyhow::bail;
 use bytes::Bytes;
-use serde::Deserialize;
-use std::{borrow::Cow, collections::HashMap};
+use futures::StreamExt;
+use serde::{de::IgnoredAny, Deserialize};
+use std::{borrow::Cow, collections::HashMap, io, time::Duration};
+use uuid::Uuid;
 
 const X_REPLACED_PATH_HEADER_NAME: &str = "x-replaced-path";
 
+/// Name of the header with the request correlation ID that is returned with every response.
+const X_SECUTILS_REQUEST_ID_HEADER_NAME: &str = "x-secutils-request-id";
+
+/// Maximum length of the request correlation ID that can be supplied by the client.
+const MAX_REQUEST_ID_LENGTH: usize = 100;
+
+/// Delay before the responder that simulates a network failure drops the connection. It gives the
+/// server a chance to flush already written part of the response, and is deliberately short so
+/// that faulty responders can't hold workers.
+const RESPONDER_FAULT_FLUSH_DELAY: Duration = Duration::from_millis(100);
+
 #[derive(Deserialize)]
 pub struct PathParams {
     pub user_handle: Option<String>,
@@ -44,22 +59,6 @@ pub async fn webhooks_responders(
         connection_info.host().to_string()
     };
 
-    // Extract user handle either from path or from the request headers.
-    let (user_handle, subdomain_prefix) = if let Some(user_handle) = &path_params.user_handle {
-        (user_handle.as_str(), None)
-    } else {
-        match parse_webhook_host(&state.config, &request_host) {
-            Ok((user_handle, subdomain_prefix)) => (user_handle, subdomain_prefix),
-            Err(err) => {
-                log::error!(
-                    "Failed to extract user handle and subdomain prefix from the request host ({:?}): {err:?}",
-                    request_host
-                );
-                return Ok(HttpResponse::NotFound().finish());
-            }
-        }
-    };
-
     // Extract responder path either from path or from the request headers.
     let mut responder_path = if let Some(responder_path) = path_params.responder_path {
         format!("/{}", responder_path)
@@ -85,21 +84,6 @@ pub async fn webhooks_responders(
         }
     };
 
-    // Try to retrieve use by the handle.
-    let user = match state.api.users().get_by_handle(user_handle).await {
-        Ok(Some(user)) => user,
-        Ok(None) => {
-            log::error!("Failed to find user by the handle ({user_handle}).");
-            return Ok(HttpResponse::NotFound().finish());
-        }
-        Err(err) => {
-            log::error!(
-                "Failed to retrieve user by handle ({user_handle}) due to unexpected error: {err:?}"
-            );
-            return Ok(HttpResponse::InternalServerError().finish());
-        }
-    };
-
     // Make sure path doesn't end with trailing slash as it's not allowed.
     if responder_path.len() > 1 && responder_path.ends_with('/') {
         responder_path.pop();
@@ -109,7 +93,6 @@ pub async fn webhooks_responders(
         Ok(responder_method) => responder_method,
         Err(err) => {
             log::error!(
-                user:serde = user.log_context();
                 "Failed to parse HTTP method ({}) into responder method: {err:?}",
                 request.method()
             );
@@ -117,28 +100,34 @@ pub async fn webhooks_responders(
         }
     };
 
-    // Try to retrieve responder by the name.
-    let webhooks = state.api.webhooks(&user);
-    let responder = match webhooks
-        .find_responder(subdomain_prefix, &responder_path, responder_method)
+    // Resolve responder and its owner using any of the supported webhook URL schemes.
+    let ResponderMatch {
+        user, responder, ..
+    } = match state
+        .api
+        .webhooks_system()
+        .resolve_responder(
+            &request_host,
+            path_params.user_handle.as_deref(),
+            &responder_path,
+            responder_method,
+        )
         .await
     {
-        Ok(Some(responder)) => responder,
+        Ok(Some(responder_match)) => responder_match,
         Ok(None) => {
             log::error!(
-                user:serde = user.log_context();
-               "User doesn't have an HTTP responder ({} {subdomain_prefix:?} {responder_path}) configured.",
+                "Failed to find an HTTP responder ({} {request_host} {responder_path}).",
                 request.method().as_str()
             );
             return Ok(HttpResponse::NotFound().finish());
         }
         Err(err) => {
             log::error!(
-                user:serde = user.log_context();
-                "Failed to retrieve HTTP responder ({} {subdomain_prefix:?} {responder_path}): {err:?}.",
+                "Failed to resolve HTTP responder ({} {request_host} {responder_path}) due to unexpected error: {err:?}",
                 request.method().as_str()
             );
-            return Ok(HttpResponse::NotFound().finish());
+            return Ok(HttpResponse::InternalServerError().finish());
         }
     };
 
@@ -146,12 +135,37 @@ pub async fn webhooks_responders(
         log::error!(
             user:serde = user.log_context(),
             util:serde = responder.log_context();
-             "User has an HTTP responder ({} {subdomain_prefix:?} {responder_path}) configured, but it is disabled.",
+             "User has an HTTP responder ({} {request_host} {responder_path}) configured, but it is disabled.",
             request.method().as_str(),
         );
         return Ok(HttpResponse::NotFound().finish());
     }
 
+    let webhooks = state.api.webhooks(&user);
+
+    // Echo correlation ID supplied by the client, or generate a new one.
+    let request_id = request
+        .headers()
+        .get(X_SECUTILS_REQUEST_ID_HEADER_NAME)
+        .and_then(|header_value| header_value.to_str().ok())
+        .filter(|request_id| !request_id.is_empty() && request_id.len() <= MAX_REQUEST_ID_LENGTH)
+        .map(|request_id| request_id.to_string())
+        .unwrap_or_else(|| Uuid::now_v7().to_string());
+    let request_id_header = match HeaderValue::from_str(&request_id) {
+        Ok(request_id_header) => (
+            HeaderName::from_static(X_SECUTILS_REQUEST_ID_HEADER_NAME),
+            request_id_header,
+        ),
+        Err(err) => {
+            log::error!(
+                user:serde = user.log_context(),
+                util:serde = responder.log_context();
+                "Failed to parse request ID `{request_id}` for the HTTP responder: {err:?}"
+            );
+            return Ok(HttpResponse::InternalServerError().finish());
+        }
+    };
+
     let headers = request
         .headers()
         .iter()
@@ -183,6 +197,7 @@ pub async fn webhooks_responders(
                 } else {
                     Some(Cow::Borrowed(&payload))
                 },
+                correlation_id: Some(Cow::Borrowed(&request_id)),
             },
         )
         .await?;
@@ -195,12 +210,94 @@ pub async fn webhooks_responders(
         resource_name: responder_name.as_str(),
     };
 
+    // If responder has an initial response, count received requests to check if it should still
+    // be sent instead of the regular response.
+    let initial_response = match responder.settings.initial_response {
+        Some(initial_response) => {
+            let requests = webhooks
+                .increment_responder_requests_counter(responder.id)
+                .await?;
+            (requests <= initial_response.requests as u64).then_some(initial_response)
+        }
+        None => None,
+    };
+
+    // If responder is configured to serve body from a file, re-read it on every request to pick
+    // up any changes.
+    let default_body = match responder.settings.body_file {
+        Some(ref body_file) => {
+            let body_file_content = match webhooks.resolve_responder_body_file(body_file) {
+                Ok(body_file_path) => tokio::fs::read(body_file_path)
+                    .await
+                    .map_err(anyhow::Error::from),
+                Err(err) => Err(err),
+            };
+            match body_file_content {
+                Ok(body_file_content) => Some(Bytes::from(body_file_content)),
+                Err(err) => {
+                    log::error!(
+                        user:serde = user.log_context(),
+                        util:serde = responder_log_context;
+                        "Failed to read body file ('{body_file}') for the HTTP responder: {err:?}"
+                    );
+                    return Ok(HttpResponse::InternalServerError()
+                        .insert_header(request_id_header)
+                        .finish());
+                }
+            }
+        }
+        None => responder.settings.body.map(Bytes::from),
+    };
+
+    // If responder body is a template, render it for every request to generate fresh data.
+    let default_body = match (responder.settings.body_template, default_body) {
+        (Some(body_template), Some(body)) => {
+            let rendered_body = std::str::from_utf8(&body)
+                .map_err(anyhow::Error::from)
+                .and_then(|template| body_template.render(template));
+            match rendered_body {
+                Ok(rendered_body) => Some(Bytes::from(rendered_body)),
+                Err(err) => {
+                    log::error!(
+                        user:serde = user.log_context(),
+                        util:serde = responder_log_context;
+                        "Failed to render body template for the HTTP responder: {err:?}"
+                    );
+                    return Ok(HttpResponse::InternalServerError()
+                        .insert_header(request_id_header)
+                        .finish());
+                }
+            }
+        }
+        (_, default_body) => default_body,
+    };
+
+    // Configure JavaScript runtime based on user's subscription level/overrides.
+    let subscription_config = user
+        .subscription
+        .get_features(&state.config)
+        .config
+        .webhooks;
+    let js_runtime_config = JsRuntimeConfig {
+        max_heap_size: subscription_config.js_runtime_heap_size,
+        max_user_script_execution_time: subscription_config.js_runtime_script_execution_time,
+    };
+    let query = web::Query::<HashMap<String, String>>::from_query(request.query_string())
+        .unwrap()
+        .into_inner();
+
     // Check if body is supposed to be a JavaScript code.
-    let (status_code, headers, body) = match &responder.settings.script {
-        Some(script) => {
-            let query = web::Query::<HashMap<String, String>>::from_query(request.query_string())
-                .unwrap()
-                .into_inner();
+    let (status_code, headers, body) = match (
+        initial_response,
+        &responder.settings.script,
+        &responder.settings.echo_transform,
+    ) {
+        (Some(initial_response), _, _) => (
+            initial_response.status_code,
+            initial_response.headers,
+            initial_response.body.map(Bytes::from),
+        ),
+        (None, Some(script), _) => {
             let js_script_context = ResponderScriptContext {
                 client_address: request.peer_addr(),
                 method: request.method().as_str(),
@@ -217,18 +314,6 @@ pub async fn webhooks_responders(
                 body: &payload,
             };
 
-            // Configure JavaScript runtime based on user's subscription level/overrides.
-            let subscription_config = user
-                .subscription
-                .get_features(&state.config)
-                .config
-                .webhooks;
-            let js_runtime_config = JsRuntimeConfig {
-                max_heap_size: subscription_config.js_runtime_heap_size,
-                max_user_script_execution_time: subscription_config
-                    .js_runtime_script_execution_time,
-            };
-
             let js_code = format!(r#"(async (globalThis) => {{ return {script}; }})(globalThis);"#);
             let override_result = match JsRuntime::new(&js_runtime_config)
                 .execute_script::<Option<ResponderScriptResult>>(js_code, Some(js_script_context))
@@ -249,7 +334,9 @@ pub async fn webhooks_responders(
                         util:serde = responder_log_context;
                         "Failed to execute responder user script: {err:?}"
                     );
-                    return Ok(HttpResponse::InternalServerError().body(err.to_string()));
+                    return Ok(HttpResponse::InternalServerError()
+                        .insert_header(request_id_header)
+                        .body(err.to_string()));
                 }
             };
 
@@ -261,19 +348,94 @@ pub async fn webhooks_responders(
                     .headers
                     .map(|headers| headers.into_iter().collect())
                     .or(responder.settings.headers),
-                override_result
-                    .body
-                    .map(|override_body| override_body.boxed())
-                    .or_else(|| responder.settings.body.map(|body| body.boxed())),
+                override_result.body.or(default_body),
             )
         }
-        None => (
+        (None, None, Some(echo_transform)) => {
+            let js_transform_context = ResponderEchoTransformContext {
+                client_address: request.peer_addr(),
+                method: request.method().as_str(),
+                headers: request
+                    .headers()
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.to_str().unwrap_or_default()))
+                    .collect(),
+                path: responder_path.as_str(),
+                query: query
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v.as_str()))
+                    .collect(),
+                body: ResponderEchoTransformContext::parse_body(&payload),
+            };
+
+            let js_code = format!("(async (context) => {{\n{echo_transform}\n}})(context);");
+            let transform_result = match JsRuntime::new(&js_runtime_config)
+                .execute_script::<Option<ResponderEchoTransformResult>>(
+                    js_code,
+                    Some(js_transform_context),
+                )
+                .await
+            {
+                Ok((transform_result, execution_time)) => {
+                    log::info!(
+                        user:serde = user.log_context(),
+                        util:serde = responder_log_context,
+                        metrics:serde = MetricsContext::default().with_script_execution_time(execution_time);
+                        "Executed responder echo transform in {execution_time:.2?}.",
+                    );
+                    transform_result.unwrap_or_default()
+                }
+                Err(err) => {
+                    log::error!(
+                        user:serde = user.log_context(),
+                        util:serde = responder_log_context;
+                        "Failed to execute responder echo transform: {err:?}"
+                    );
+                    return Ok(HttpResponse::InternalServerError()
+                        .insert_header(request_id_header)
+                        .body(err.to_string()));
+                }
+            };
+
+            let transform_body = match transform_result.body_bytes() {
+                Ok(transform_body) => transform_body,
+                Err(err) => {
+                    log::error!(
+                        user:serde = user.log_context(),
+                        util:serde = responder_log_context;
+                        "Failed to serialize responder echo transform body: {err:?}"
+                    );
+                    return Ok(HttpResponse::InternalServerError()
+                        .insert_header(request_id_header)
+                        .finish());
+                }
+            };
+
+            // Echo the request body back if transform didn't produce any.
+            (
+                transform_result
+                    .status_code
+                    .unwrap_or(responder.settings.status_code),
+                transform_result
+                    .headers
+                    .map(|headers| headers.into_iter().collect())
+                    .or(responder.settings.headers),
+                transform_body.or_else(|| (!payload.is_empty()).then(|| payload.clone())),
+            )
+        }
+        (None, None, None) => (
             responder.settings.status_code,
             responder.settings.headers,
-            responder.settings.body.map(|body| body.boxed()),
+            default_body,
         ),
     };
 
+    // Simulate network latency, if configured.
+    if let Some(latency) = responder.settings.latency {
+        let delay = latency.sample(&mut rand::thread_rng());
+        tokio::time::sleep(delay).await;
+    }
+
     // Prepare response, set response status code.
     let status_code = match StatusCode::from_u16(status_code) {
         Ok(status_code) => status_code,
@@ -283,7 +445,9 @@ pub async fn webhooks_responders(
                 util:serde = responder_log_context;
                 "Failed to parse status code for the HTTP responder: {err:?}",
             );
-            return Ok(HttpResponse::InternalServerError().finish());
+            return Ok(HttpResponse::InternalServerError()
+                .insert_header(request_id_header)
+                .finish());
         }
     };
 
@@ -303,7 +467,9 @@ pub async fn webhooks_responders(
                     util:serde = responder_log_context;
                     "Failed to parse header name `{header_name}` for the HTTP responder: {err:?}"
                 );
-                return Ok(HttpResponse::InternalServerError().finish());
+                return Ok(HttpResponse::InternalServerError()
+                    .insert_header(request_id_header)
+                    .finish());
             }
             (_, Err(err)) => {
                 log::error!(
@@ -311,66 +477,130 @@ pub async fn webhooks_responders(
                     util:serde = responder_log_context;
                     "Failed to parse header value `{header_value}` for the HTTP responder: {err:?}"
                 );
-                return Ok(HttpResponse::InternalServerError().finish());
+                return Ok(HttpResponse::InternalServerError()
+                    .insert_header(request_id_header)
+                    .finish());
             }
         }
     }
 
-    // Prepare response, set response body.
-    Ok(if let Some(body) = body {
-        response.set_body(body)
-    } else {
+    // If the user hasn't configured content type explicitly, try to infer it from the body.
+    if let Some(content_type) = body
+        .as_ref()
+        .filter(|_| !response.headers().contains_key(CONTENT_TYPE))
+        .and_then(|body| infer_content_type(body))
+    {
         response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    }
+
+    // Request ID header always takes precedence over the headers configured by the user.
+    let (request_id_header_name, request_id_header_value) = request_id_header;
+    response
+        .headers_mut()
+        .insert(request_id_header_name, request_id_header_value);
+
+    // Prepare response, set response body and simulate network failure, if configured.
+    Ok(match (responder.settings.fault, body) {
+        (Some(fault), body) => response.set_body(faulty_body(fault, body.unwrap_or_default())),
+        (None, Some(body)) => response.set_body(body.boxed()),
+        (None, None) => response,
     })
 }
 
-/// Parses the host that webhook was access through to determine user handle and subdomain prefix.
-pub fn parse_webhook_host<'s>(
-    config: &Config,
-    webhook_host: &'s str,
-) -> anyhow::Result<(&'s str, Option<&'s str>)> {
-    let Some(public_host) = config.public_url.host_str() else {
-        bail!(SecutilsError::client(
-            "Public URL doesn't have a host, cannot extract responder subdomain prefix."
-        ));
+/// Builds a response body that simulates the specified network failure: the body stream yields
+/// the part of the body that the fault allows and then fails, which makes the server drop the
+/// connection.
+fn faulty_body(fault: ResponderFault, body: Bytes) -> BoxBody {
+    let chunk = match fault {
+        ResponderFault::ResetAfterHeaders => Bytes::new(),
+        // Nothing to truncate, send the body as is.
+        ResponderFault::TruncateBody { at } if at >= body.len() => return body.boxed(),
+        ResponderFault::TruncateBody { at } => body.slice(..at),
     };
 
-    // First remove the public URL host from the request host to keep only user-specific part.
-    let Some(webhook_subdomain) = webhook_host.strip_suffix(&format!(".webhooks.{}", public_host))
-    else {
-        bail!(SecutilsError::client(format!(
-            "Failed to extract base host from the webhook host ({webhook_host})."
-        )));
-    };
+    let stream = futures::stream::iter((!chunk.is_empty()).then(|| Ok::<_, io::Error>(chunk)))
+        .chain(futures::stream::once(async {
+            tokio::time::sleep(RESPONDER_FAULT_FLUSH_DELAY).await;
+            Err(io::Error::new(
+                io::ErrorKind::ConnectionReset,
+                "Responder simulated network failure.",
+            ))
+        }));
 
-    // Next separate user handle part from the rest of the subdomain, e.g.,:
-    // abc-user-handle.secutils.dev -> (user-handle, Some("abc"))
-    Ok(match webhook_subdomain.rsplit_once('-') {
-        // No custom subdomain, just user handle.
-        None => (webhook_subdomain, None),
-        Some((subdomain_prefix, user_handle)) => (user_handle, Some(subdomain_prefix)),
-    })
+    // Advertise the full body length, so that the client expects more data than it receives.
+    if body.is_empty() {
+        BodyStream::new(stream).boxed()
+    } else {
+        SizedStream::new(body.len() as u64, stream).boxed()
+    }
+}
+
+/// Infers content type of the responder body: JSON, HTML, XML or plain text. Returns `None` for
+/// empty or non-UTF-8 bodies.
+fn infer_content_type(body: &[u8]) -> Option<&'static str> {
+    let body = std::str::from_utf8(body).ok()?.trim_start();
+    if body.is_empty() {
+        return None;
+    }
+
+    if body.starts_with(['{', '[']) && serde_json::from_str::<IgnoredAny>(body).is_ok() {
+        return Some("application/json");
+    }
+
+    if body.starts_with("<?xml") {
+        return Some("application/xml");
+    }
+
+    if body.starts_with('<') {
+        return Some("text/html; charset=utf-8");
+    }
+
+    Some("text/plain; charset=utf-8")
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{parse_webhook_host, webhooks_responders};
+    use super::{infer_content_type, webhooks_responders, X_SECUTILS_REQUEST_ID_HEADER_NAME};
     use crate::{
         server::handlers::webhooks_responders::PathParams,
-        tests::{mock_app_state, mock_config, mock_user},
+        tests::{mock_app_state, mock_app_state_with_config, mock_config, mock_user},
         utils::webhooks::{
             tests::{RespondersCreateParams, RespondersUpdateParams},
-            ResponderLocation, ResponderMethod, ResponderPathType, ResponderSettings,
+            ResponderBodyTemplate, ResponderFault, ResponderInitialResponse, ResponderLocation,
+            ResponderMethod, ResponderPathType, ResponderSettings,
         },
     };
     use actix_web::{
-        body::MessageBody, dev::Payload, http::Method, test::TestRequest, web, FromRequest,
+        body::{BodySize, MessageBody},
+        dev::Payload,
+        http::{
+            header::{HeaderValue, CONTENT_TYPE},
+            Method, StatusCode,
+        },
+        test::TestRequest,
+        web, FromRequest, HttpResponse,
     };
     use bytes::Bytes;
     use insta::assert_debug_snapshot;
     use serde_json::json;
     use sqlx::PgPool;
-    use std::{borrow::Cow, default::Default};
+    use std::{borrow::Cow, default::Default, fs};
+    use uuid::Uuid;
+
+    /// Removes request ID header from the response since generated IDs aren't stable.
+    fn take_request_id(response: &mut HttpResponse) -> Option<HeaderValue> {
+        response
+            .headers_mut()
+            .remove(X_SECUTILS_REQUEST_ID_HEADER_NAME)
+            .next()
+    }
+
+    /// Removes inferred content type header from the response to keep snapshots stable.
+    fn take_content_type(response: &mut HttpResponse) -> Option<HeaderValue> {
+        response.headers_mut().remove(CONTENT_TYPE).next()
+    }
 
     #[sqlx::test]
     async fn can_handle_request_with_path_url_type(pool: PgPool) -> anyhow::Result<()> {
@@ -390,6 +620,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/one/two".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -399,6 +630,14 @@ mod tests {
                     body: Some("body".to_string()),
                     headers: Some(vec![("key".to_string(), "value".to_string())]),
                     script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
             })
             .await?;
@@ -416,7 +655,7 @@ mod tests {
             .await
             .unwrap();
         let app_state = web::Data::new(app_state);
-        let response = webhooks_responders(
+        let mut response = webhooks_responders(
             app_state.clone(),
             request,
             Bytes::from_static(b"incoming-body"),
@@ -424,6 +663,11 @@ mod tests {
         )
         .await
         .unwrap();
+        assert!(take_request_id(&mut response).is_some());
+        assert_eq!(
+            take_content_type(&mut response),
+            Some(HeaderValue::from_static("text/plain; charset=utf-8"))
+        );
         assert_debug_snapshot!(response, @r###"
         HttpResponse {
             error: None,
@@ -477,6 +721,107 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn returns_and_stores_request_id(pool: PgPool) -> anyhow::Result<()> {
+        let app_state = mock_app_state(pool).await?;
+
+        // Insert user into the database.
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        // Insert responders data.
+        let responder = app_state
+            .api
+            .webhooks(&user)
+            .create_responder(RespondersCreateParams {
+                name: "name_one".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/one/two".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Any,
+                enabled: true,
+                settings: ResponderSettings {
+                    requests_to_track: 3,
+                    status_code: 200,
+                    body: Some("body".to_string()),
+                    headers: Some(vec![("key".to_string(), "value".to_string())]),
+                    script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
+                },
+            })
+            .await?;
+
+        let app_state = web::Data::new(app_state);
+
+        // 1. Request ID is generated if not supplied by the client.
+        let request = TestRequest::with_uri(
+            "https://secutils.dev/api/webhooks/devhandle00000000000000000000000000000001/one/two",
+        )
+        .param("user_handle", "devhandle00000000000000000000000000000001")
+        .param("responder_path", "one/two")
+        .to_http_request();
+        let path = web::Path::<PathParams>::from_request(&request, &mut Payload::None)
+            .await
+            .unwrap();
+        let mut response = webhooks_responders(app_state.clone(), request, Bytes::new(), path)
+            .await
+            .unwrap();
+        let generated_request_id = take_request_id(&mut response).unwrap();
+        assert!(!generated_request_id.is_empty());
+
+        let responder_requests = app_state
+            .api
+            .webhooks(&user)
+            .get_responder_requests(responder.id)
+            .await?;
+        assert_eq!(responder_requests.len(), 1);
+        assert_eq!(
+            responder_requests[0].correlation_id.as_deref(),
+            Some(generated_request_id.to_str()?)
+        );
+
+        // 2. Request ID supplied by the client is echoed.
+        let request = TestRequest::with_uri(
+            "https://secutils.dev/api/webhooks/devhandle00000000000000000000000000000001/one/two",
+        )
+        .insert_header((X_SECUTILS_REQUEST_ID_HEADER_NAME, "my-request-id"))
+        .param("user_handle", "devhandle00000000000000000000000000000001")
+        .param("responder_path", "one/two")
+        .to_http_request();
+        let path = web::Path::<PathParams>::from_request(&request, &mut Payload::None)
+            .await
+            .unwrap();
+        let mut response = webhooks_responders(app_state.clone(), request, Bytes::new(), path)
+            .await
+            .unwrap();
+        assert_eq!(
+            take_request_id(&mut response),
+            Some(HeaderValue::from_static("my-request-id"))
+        );
+
+        let responder_requests = app_state
+            .api
+            .webhooks(&user)
+            .get_responder_requests(responder.id)
+            .await?;
+        assert_eq!(responder_requests.len(), 2);
+        assert!(responder_requests
+            .iter()
+            .any(|request| request.correlation_id.as_deref() == Some("my-request-id")));
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn can_handle_request_with_subdomain_url_type(pool: PgPool) -> anyhow::Result<()> {
         let app_state = mock_app_state(pool).await?;
@@ -495,6 +840,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/one/two".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -504,6 +850,14 @@ mod tests {
                     body: Some("body".to_string()),
                     headers: Some(vec![("key".to_string(), "value".to_string())]),
                     script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
             })
             .await?;
@@ -517,9 +871,14 @@ mod tests {
             .await
             .unwrap();
         let app_state = web::Data::new(app_state);
-        let response = webhooks_responders(app_state.clone(), request, Bytes::new(), path)
+        let mut response = webhooks_responders(app_state.clone(), request, Bytes::new(), path)
             .await
             .unwrap();
+        assert!(take_request_id(&mut response).is_some());
+        assert_eq!(
+            take_content_type(&mut response),
+            Some(HeaderValue::from_static("text/plain; charset=utf-8"))
+        );
         assert_debug_snapshot!(response, @r###"
         HttpResponse {
             error: None,
@@ -549,6 +908,91 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn can_handle_request_with_custom_domain(pool: PgPool) -> anyhow::Result<()> {
+        let user = mock_user()?;
+
+        let mut config = mock_config()?;
+        config.utils.webhook_custom_domains =
+            [("hooks.example.com".to_string(), user.handle.clone())]
+                .into_iter()
+                .collect();
+        let app_state = mock_app_state_with_config(pool, config).await?;
+
+        // Insert user into the database.
+        app_state.api.db.upsert_user(&user).await?;
+
+        // Insert responders data.
+        let responder = app_state
+            .api
+            .webhooks(&user)
+            .create_responder(RespondersCreateParams {
+                name: "name_one".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/one/two".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Any,
+                enabled: true,
+                settings: ResponderSettings {
+                    requests_to_track: 3,
+                    status_code: 200,
+                    body: Some("body".to_string()),
+                    headers: None,
+                    script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
+                },
+            })
+            .await?;
+
+        let request = TestRequest::with_uri("https://hooks.example.com/one/two?query=value")
+            .insert_header(("x-replaced-path", "/one/two"))
+            .insert_header(("x-forwarded-host", "hooks.example.com"))
+            .to_http_request();
+        let path = web::Path::<PathParams>::from_request(&request, &mut Payload::None)
+            .await
+            .unwrap();
+        let app_state = web::Data::new(app_state);
+        let response = webhooks_responders(app_state.clone(), request, Bytes::new(), path)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().try_into_bytes().unwrap();
+        assert_eq!(body, Bytes::from_static(b"body"));
+
+        let responder_requests = app_state
+            .api
+            .webhooks(&user)
+            .get_responder_requests(responder.id)
+            .await?;
+        assert_eq!(responder_requests.len(), 1);
+
+        // Unknown custom domain.
+        let request = TestRequest::with_uri("https://hooks.example.org/one/two")
+            .insert_header(("x-replaced-path", "/one/two"))
+            .insert_header(("x-forwarded-host", "hooks.example.org"))
+            .to_http_request();
+        let path = web::Path::<PathParams>::from_request(&request, &mut Payload::None)
+            .await
+            .unwrap();
+        let response = webhooks_responders(app_state.clone(), request, Bytes::new(), path)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn can_handle_request_with_subdomain_url_type_for_root_path(
         pool: PgPool,
@@ -569,6 +1013,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -578,6 +1023,14 @@ mod tests {
                     body: Some("body".to_string()),
                     headers: Some(vec![("key".to_string(), "value".to_string())]),
                     script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
             })
             .await?;
@@ -595,9 +1048,14 @@ mod tests {
             .await
             .unwrap();
         let app_state = web::Data::new(app_state);
-        let response = webhooks_responders(app_state.clone(), request, Bytes::new(), path)
+        let mut response = webhooks_responders(app_state.clone(), request, Bytes::new(), path)
             .await
             .unwrap();
+        assert!(take_request_id(&mut response).is_some());
+        assert_eq!(
+            take_content_type(&mut response),
+            Some(HeaderValue::from_static("text/plain; charset=utf-8"))
+        );
         assert_debug_snapshot!(response, @r###"
         HttpResponse {
             error: None,
@@ -643,6 +1101,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/one/two".to_string(),
                     subdomain_prefix: Some("abc".to_string()),
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -652,6 +1111,14 @@ mod tests {
                     body: Some("body".to_string()),
                     headers: Some(vec![("key".to_string(), "value".to_string())]),
                     script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
             })
             .await?;
@@ -664,6 +1131,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/one/two".to_string(),
                     subdomain_prefix: Some("cba".to_string()),
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -673,6 +1141,14 @@ mod tests {
                     body: Some("body-two".to_string()),
                     headers: Some(vec![("key-2".to_string(), "value-2".to_string())]),
                     script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
             })
             .await?;
@@ -686,9 +1162,14 @@ mod tests {
             .await
             .unwrap();
         let app_state = web::Data::new(app_state);
-        let response = webhooks_responders(app_state.clone(), request, Bytes::new(), path)
+        let mut response = webhooks_responders(app_state.clone(), request, Bytes::new(), path)
             .await
             .unwrap();
+        assert!(take_request_id(&mut response).is_some());
+        assert_eq!(
+            take_content_type(&mut response),
+            Some(HeaderValue::from_static("text/plain; charset=utf-8"))
+        );
         assert_debug_snapshot!(response, @r###"
         HttpResponse {
             error: None,
@@ -723,9 +1204,14 @@ mod tests {
         let path = web::Path::<PathParams>::from_request(&request, &mut Payload::None)
             .await
             .unwrap();
-        let response = webhooks_responders(app_state.clone(), request, Bytes::new(), path)
+        let mut response = webhooks_responders(app_state.clone(), request, Bytes::new(), path)
             .await
             .unwrap();
+        assert!(take_request_id(&mut response).is_some());
+        assert_eq!(
+            take_content_type(&mut response),
+            Some(HeaderValue::from_static("text/plain; charset=utf-8"))
+        );
         assert_debug_snapshot!(response, @r###"
         HttpResponse {
             error: None,
@@ -773,7 +1259,8 @@ mod tests {
                     location: ResponderLocation {
                         path_type: ResponderPathType::Exact,
                         path: "/one/two".to_string(),
-                        subdomain_prefix: None
+                        subdomain_prefix: None,
+                        base_path: None,
                     },
                     method: ResponderMethod::Any,
                     enabled: true,
@@ -785,6 +1272,14 @@ mod tests {
                         script: Some(
                             "(() => { return { statusCode: 300, headers: { one: `two` }, body: Deno.core.encode(JSON.stringify(context)) }; })()".to_string(),
                         ),
+                        body_file: None,
+                        echo_transform: None,
+                        latency: None,
+                        fault: None,
+                        body_template: None,
+                        forward_to: None,
+                        capture_json: false,
+                        initial_response: None,
                     },
                 },
             )
@@ -800,7 +1295,7 @@ mod tests {
             .await
             .unwrap();
         let app_state = web::Data::new(app_state);
-        let response = webhooks_responders(
+        let mut response = webhooks_responders(
             app_state.clone(),
             request,
             Bytes::from_static(b"incoming-body"),
@@ -808,6 +1303,11 @@ mod tests {
         )
         .await
         .unwrap();
+        assert!(take_request_id(&mut response).is_some());
+        assert_eq!(
+            take_content_type(&mut response),
+            Some(HeaderValue::from_static("application/json"))
+        );
         assert_debug_snapshot!(response, @r###"
         HttpResponse {
             error: None,
@@ -848,6 +1348,294 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn can_handle_responders_with_echo_transform(pool: PgPool) -> anyhow::Result<()> {
+        let app_state = mock_app_state(pool).await?;
+
+        // Insert user into the database.
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        // Insert responders data.
+        let responders = [
+            ("identity", "return { body: context.body };"),
+            (
+                "uppercase",
+                "return { statusCode: 201, body: { ...context.body, name: context.body.name.toUpperCase() } };",
+            ),
+        ];
+        for (name, echo_transform) in responders {
+            app_state
+                .api
+                .webhooks(&user)
+                .create_responder(RespondersCreateParams {
+                    name: name.to_string(),
+                    location: ResponderLocation {
+                        path_type: ResponderPathType::Exact,
+                        path: format!("/{name}"),
+                        subdomain_prefix: None,
+                        base_path: None,
+                    },
+                    method: ResponderMethod::Any,
+                    enabled: true,
+                    settings: ResponderSettings {
+                        requests_to_track: 3,
+                        status_code: 200,
+                        body: None,
+                        headers: None,
+                        script: None,
+                        body_file: None,
+                        echo_transform: Some(echo_transform.to_string()),
+                        latency: None,
+                        fault: None,
+                        body_template: None,
+                        forward_to: None,
+                        capture_json: false,
+                        initial_response: None,
+                    },
+                })
+                .await?;
+        }
+
+        let app_state = web::Data::new(app_state);
+        let mut responses = vec![];
+        for name in ["identity", "uppercase"] {
+            let request = TestRequest::with_uri(&format!(
+                "https://devhandle00000000000000000000000000000001.webhooks.secutils.dev/{name}"
+            ))
+            .method(Method::POST)
+            .insert_header(("x-replaced-path", format!("/{name}")))
+            .insert_header((
+                "x-forwarded-host",
+                "devhandle00000000000000000000000000000001.webhooks.secutils.dev",
+            ))
+            .to_http_request();
+            let path = web::Path::<PathParams>::from_request(&request, &mut Payload::None)
+                .await
+                .unwrap();
+            let mut response = webhooks_responders(
+                app_state.clone(),
+                request,
+                Bytes::from_static(br#"{ "name": "value", "count": 1 }"#),
+                path,
+            )
+            .await
+            .unwrap();
+            assert!(take_request_id(&mut response).is_some());
+
+            let status = response.status();
+            let body = response.into_body().try_into_bytes().unwrap();
+            responses.push((status, serde_json::from_slice::<serde_json::Value>(&body)?));
+        }
+
+        assert_eq!(
+            responses,
+            vec![
+                (StatusCode::OK, json!({ "name": "value", "count": 1 })),
+                (StatusCode::CREATED, json!({ "name": "VALUE", "count": 1 }))
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn infers_content_type_if_not_configured(pool: PgPool) -> anyhow::Result<()> {
+        let app_state = mock_app_state(pool).await?;
+
+        // Insert user into the database.
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        // Insert responders data.
+        let responders = [
+            ("json", r#"{ "key": "value" }"#, None),
+            ("html", "<!DOCTYPE html><html><body>Hi</body></html>", None),
+            (
+                "explicit",
+                r#"{ "key": "value" }"#,
+                Some(vec![("Content-Type".to_string(), "text/csv".to_string())]),
+            ),
+        ];
+        for (name, body, headers) in responders {
+            app_state
+                .api
+                .webhooks(&user)
+                .create_responder(RespondersCreateParams {
+                    name: name.to_string(),
+                    location: ResponderLocation {
+                        path_type: ResponderPathType::Exact,
+                        path: format!("/{name}"),
+                        subdomain_prefix: None,
+                        base_path: None,
+                    },
+                    method: ResponderMethod::Any,
+                    enabled: true,
+                    settings: ResponderSettings {
+                        requests_to_track: 0,
+                        status_code: 200,
+                        body: Some(body.to_string()),
+                        headers,
+                        script: None,
+                        body_file: None,
+                        echo_transform: None,
+                        latency: None,
+                        fault: None,
+                        body_template: None,
+                        forward_to: None,
+                        capture_json: false,
+                        initial_response: None,
+                    },
+                })
+                .await?;
+        }
+
+        let app_state = web::Data::new(app_state);
+        for (name, expected_content_type) in [
+            ("json", "application/json"),
+            ("html", "text/html; charset=utf-8"),
+            ("explicit", "text/csv"),
+        ] {
+            let request = TestRequest::with_uri(&format!(
+                "https://secutils.dev/api/webhooks/devhandle00000000000000000000000000000001/{name}"
+            ))
+            .param("user_handle", "devhandle00000000000000000000000000000001")
+            .param("responder_path", name)
+            .to_http_request();
+            let path = web::Path::<PathParams>::from_request(&request, &mut Payload::None)
+                .await
+                .unwrap();
+            let response = webhooks_responders(app_state.clone(), request, Bytes::new(), path)
+                .await
+                .unwrap();
+            assert_eq!(
+                response.headers().get(CONTENT_TYPE),
+                Some(&HeaderValue::from_static(expected_content_type))
+            );
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_serve_body_from_file(pool: PgPool) -> anyhow::Result<()> {
+        let templates_dir = std::env::temp_dir().join(format!("secutils-{}", Uuid::now_v7()));
+        fs::create_dir_all(&templates_dir)?;
+        fs::write(templates_dir.join("demo.json"), r#"{ "key": "value" }"#)?;
+
+        let mut config = mock_config()?;
+        config.utils.responder_templates_dir = Some(templates_dir.clone());
+        let app_state = mock_app_state_with_config(pool, config).await?;
+
+        // Insert user into the database.
+        let mut user = mock_user()?;
+        user.is_operator = true;
+        app_state.api.db.upsert_user(&user).await?;
+
+        // Insert responders data.
+        app_state
+            .api
+            .webhooks(&user)
+            .create_responder(RespondersCreateParams {
+                name: "name_one".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/one".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Any,
+                enabled: true,
+                settings: ResponderSettings {
+                    requests_to_track: 0,
+                    status_code: 200,
+                    body: None,
+                    headers: None,
+                    script: None,
+                    body_file: Some("demo.json".to_string()),
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
+                },
+            })
+            .await?;
+
+        let app_state = web::Data::new(app_state);
+        let send_request = || async {
+            let request = TestRequest::with_uri(
+                "https://secutils.dev/api/webhooks/devhandle00000000000000000000000000000001/one",
+            )
+            .param("user_handle", "devhandle00000000000000000000000000000001")
+            .param("responder_path", "one")
+            .to_http_request();
+            let path = web::Path::<PathParams>::from_request(&request, &mut Payload::None)
+                .await
+                .unwrap();
+            webhooks_responders(app_state.clone(), request, Bytes::new(), path)
+                .await
+                .unwrap()
+        };
+
+        let response = send_request().await;
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE),
+            Some(&HeaderValue::from_static("application/json"))
+        );
+        assert_eq!(
+            response.into_body().try_into_bytes().unwrap(),
+            Bytes::from_static(br#"{ "key": "value" }"#)
+        );
+
+        // File should be re-read on every request.
+        fs::write(templates_dir.join("demo.json"), "<h1>Hello</h1>")?;
+        let response = send_request().await;
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE),
+            Some(&HeaderValue::from_static("text/html; charset=utf-8"))
+        );
+        assert_eq!(
+            response.into_body().try_into_bytes().unwrap(),
+            Bytes::from_static(b"<h1>Hello</h1>")
+        );
+
+        // Missing file should result in an error.
+        fs::remove_dir_all(templates_dir)?;
+        let response = send_request().await;
+        assert_eq!(response.status(), 500);
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_infer_content_type() {
+        assert_eq!(
+            infer_content_type(br#"  [1, 2, 3]"#),
+            Some("application/json")
+        );
+        assert_eq!(
+            infer_content_type(br#"{ "broken": json"#),
+            Some("text/plain; charset=utf-8")
+        );
+        assert_eq!(
+            infer_content_type(b"<h1>Hello</h1>"),
+            Some("text/html; charset=utf-8")
+        );
+        assert_eq!(
+            infer_content_type(br#"<?xml version="1.0"?><note/>"#),
+            Some("application/xml")
+        );
+        assert_eq!(
+            infer_content_type(b"Hello"),
+            Some("text/plain; charset=utf-8")
+        );
+        assert_eq!(infer_content_type(b"   "), None);
+        assert_eq!(infer_content_type(&[0xff, 0xfe]), None);
+    }
+
     #[sqlx::test]
     async fn properly_handles_non_existent_or_inactive_responders(
         pool: PgPool,
@@ -917,6 +1705,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/one/two".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: false,
@@ -926,6 +1715,14 @@ mod tests {
                     body: Some("body".to_string()),
                     headers: Some(vec![("key".to_string(), "value".to_string())]),
                     script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
             })
             .await?;
@@ -971,7 +1768,7 @@ mod tests {
             .await?;
 
         // 4. Active responder.
-        let response = webhooks_responders(
+        let mut response = webhooks_responders(
             app_state.clone(),
             request.clone(),
             Bytes::new(),
@@ -981,6 +1778,11 @@ mod tests {
         )
         .await
         .unwrap();
+        assert!(take_request_id(&mut response).is_some());
+        assert_eq!(
+            take_content_type(&mut response),
+            Some(HeaderValue::from_static("text/plain; charset=utf-8"))
+        );
         assert_debug_snapshot!(response, @r###"
         HttpResponse {
             error: None,
@@ -1010,26 +1812,265 @@ mod tests {
         Ok(())
     }
 
-    #[tokio::test]
-    async fn can_parse_webhook_hosts() -> anyhow::Result<()> {
-        let test_cases = [
-            ("a-handle.webhooks.secutils.dev", ("handle", Some("a"))),
-            (
-                "my-sub-handle.webhooks.secutils.dev",
-                ("handle", Some("my-sub")),
-            ),
-            ("abc-handle.webhooks.secutils.dev", ("handle", Some("abc"))),
-            (
-                "a1-b-d-com-handle.webhooks.secutils.dev",
-                ("handle", Some("a1-b-d-com")),
-            ),
-            ("handle.webhooks.secutils.dev", ("handle", None)),
-        ];
+    #[sqlx::test]
+    async fn can_simulate_network_failures(pool: PgPool) -> anyhow::Result<()> {
+        let app_state = web::Data::new(mock_app_state(pool).await?);
+
+        // Insert user into the database.
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        let webhooks = app_state.api.webhooks(&user);
+        for (path, fault) in [
+            ("/reset", ResponderFault::ResetAfterHeaders),
+            ("/truncate", ResponderFault::TruncateBody { at: 5 }),
+            ("/truncate-noop", ResponderFault::TruncateBody { at: 100 }),
+        ] {
+            webhooks
+                .create_responder(RespondersCreateParams {
+                    name: path.to_string(),
+                    location: ResponderLocation {
+                        path_type: ResponderPathType::Exact,
+                        path: path.to_string(),
+                        subdomain_prefix: None,
+                        base_path: None,
+                    },
+                    method: ResponderMethod::Any,
+                    enabled: true,
+                    settings: ResponderSettings {
+                        requests_to_track: 0,
+                        status_code: 200,
+                        body: Some("Hello, world!".to_string()),
+                        headers: None,
+                        script: None,
+                        body_file: None,
+                        echo_transform: None,
+                        latency: None,
+                        fault: Some(fault),
+                        body_template: None,
+                        forward_to: None,
+                        capture_json: false,
+                        initial_response: None,
+                    },
+                })
+                .await?;
+        }
+
+        let send_request = |path: &'static str| {
+            let app_state = app_state.clone();
+            async move {
+                let request = TestRequest::with_uri(&format!(
+                    "https://secutils.dev/api/webhooks/devhandle00000000000000000000000000000001{path}"
+                ))
+                .param("user_handle", "devhandle00000000000000000000000000000001")
+                .param("responder_path", &path[1..])
+                .to_http_request();
+                let path = web::Path::<PathParams>::from_request(&request, &mut Payload::None)
+                    .await
+                    .unwrap();
+                webhooks_responders(app_state, request, Bytes::new(), path)
+                    .await
+                    .unwrap()
+            }
+        };
+
+        // Headers are sent, but the body stream fails right away.
+        let response = send_request("/reset").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body().size(), BodySize::Sized(13));
+        let mut body = std::pin::pin!(response.into_body());
+        let chunk = futures::future::poll_fn(|cx| body.as_mut().poll_next(cx)).await;
+        assert!(matches!(chunk, Some(Err(_))));
+
+        // Full body length is advertised, but only part of the body is sent before failure.
+        let response = send_request("/truncate").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body().size(), BodySize::Sized(13));
+        let mut body = std::pin::pin!(response.into_body());
+        let chunk = futures::future::poll_fn(|cx| body.as_mut().poll_next(cx)).await;
+        assert_eq!(chunk.unwrap().unwrap(), Bytes::from_static(b"Hello"));
+        let chunk = futures::future::poll_fn(|cx| body.as_mut().poll_next(cx)).await;
+        assert!(matches!(chunk, Some(Err(_))));
+
+        // Body that is shorter than the truncation point is sent as is.
+        let response = send_request("/truncate-noop").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().try_into_bytes().unwrap();
+        assert_eq!(body, Bytes::from_static(b"Hello, world!"));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_render_body_template(pool: PgPool) -> anyhow::Result<()> {
+        let app_state = web::Data::new(mock_app_state(pool).await?);
+
+        // Insert user into the database.
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        let webhooks = app_state.api.webhooks(&user);
+        for (path, seed) in [("/random", None), ("/seeded", Some(42))] {
+            webhooks
+                .create_responder(RespondersCreateParams {
+                    name: path.to_string(),
+                    location: ResponderLocation {
+                        path_type: ResponderPathType::Exact,
+                        path: path.to_string(),
+                        subdomain_prefix: None,
+                        base_path: None,
+                    },
+                    method: ResponderMethod::Any,
+                    enabled: true,
+                    settings: ResponderSettings {
+                        requests_to_track: 0,
+                        status_code: 200,
+                        body: Some(
+                            r#"{ "id": "{{faker.uuid}}", "email": "{{faker.email}}", "createdAt": "{{faker.dateTime}}" }"#
+                                .to_string(),
+                        ),
+                        headers: None,
+                        script: None,
+                        body_file: None,
+                        echo_transform: None,
+                        latency: None,
+                        fault: None,
+                        body_template: Some(ResponderBodyTemplate { seed }),
+                        forward_to: None,
+                        capture_json: false,
+                        initial_response: None,
+                    },
+                })
+                .await?;
+        }
 
-        let config = mock_config()?;
-        for (webhook_host, expected_result) in test_cases {
-            assert_eq!(parse_webhook_host(&config, webhook_host)?, expected_result);
+        let send_request = |path: &'static str| {
+            let app_state = app_state.clone();
+            async move {
+                let request = TestRequest::with_uri(&format!(
+                    "https://secutils.dev/api/webhooks/devhandle00000000000000000000000000000001{path}"
+                ))
+                .param("user_handle", "devhandle00000000000000000000000000000001")
+                .param("responder_path", &path[1..])
+                .to_http_request();
+                let path = web::Path::<PathParams>::from_request(&request, &mut Payload::None)
+                    .await
+                    .unwrap();
+                let response = webhooks_responders(app_state, request, Bytes::new(), path)
+                    .await
+                    .unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+                assert_eq!(
+                    response.headers().get(CONTENT_TYPE),
+                    Some(&HeaderValue::from_static("application/json"))
+                );
+
+                let body = response.into_body().try_into_bytes().unwrap();
+                serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+            }
+        };
+
+        // Generated data is well-formed and varies across requests.
+        let (one, two) = (send_request("/random").await, send_request("/random").await);
+        for body in [&one, &two] {
+            assert!(body["id"].as_str().unwrap().parse::<Uuid>().is_ok());
+            assert!(body["email"].as_str().unwrap().contains('@'));
+            assert!(body["createdAt"].as_str().unwrap().ends_with('Z'));
         }
+        assert_ne!(one["id"], two["id"]);
+
+        // Seeded generator returns the same data for every request.
+        assert_eq!(send_request("/seeded").await, send_request("/seeded").await);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_send_initial_response_for_first_requests(pool: PgPool) -> anyhow::Result<()> {
+        let app_state = web::Data::new(mock_app_state(pool).await?);
+
+        // Insert user into the database.
+        let user = mock_user()?;
+        app_state.api.db.upsert_user(&user).await?;
+
+        let webhooks = app_state.api.webhooks(&user);
+        let responder = webhooks
+            .create_responder(RespondersCreateParams {
+                name: "flaky".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/flaky".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Any,
+                enabled: true,
+                settings: ResponderSettings {
+                    requests_to_track: 0,
+                    status_code: 200,
+                    body: Some("ok".to_string()),
+                    headers: None,
+                    script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: Some(ResponderInitialResponse {
+                        requests: 2,
+                        status_code: 503,
+                        body: Some("unavailable".to_string()),
+                        headers: Some(vec![("Retry-After".to_string(), "1".to_string())]),
+                    }),
+                },
+            })
+            .await?;
+
+        let send_request = || {
+            let app_state = app_state.clone();
+            async move {
+                let request = TestRequest::with_uri(
+                    "https://secutils.dev/api/webhooks/devhandle00000000000000000000000000000001/flaky",
+                )
+                .param("user_handle", "devhandle00000000000000000000000000000001")
+                .param("responder_path", "flaky")
+                .to_http_request();
+                let path = web::Path::<PathParams>::from_request(&request, &mut Payload::None)
+                    .await
+                    .unwrap();
+                let response = webhooks_responders(app_state, request, Bytes::new(), path)
+                    .await
+                    .unwrap();
+                let status = response.status();
+                let retry_after = response.headers().get("retry-after").cloned();
+                let body = response.into_body().try_into_bytes().unwrap();
+                (status, retry_after, body)
+            }
+        };
+
+        // The first requests get the initial response, and the following ones the regular one.
+        for _ in 0..2 {
+            let (status, retry_after, body) = send_request().await;
+            assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+            assert_eq!(retry_after, Some(HeaderValue::from_static("1")));
+            assert_eq!(body, Bytes::from_static(b"unavailable"));
+        }
+        for _ in 0..2 {
+            let (status, retry_after, body) = send_request().await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(retry_after, None);
+            assert_eq!(body, Bytes::from_static(b"ok"));
+        }
+
+        // Once the counter is reset, the initial response is sent again.
+        webhooks
+            .reset_responder_requests_counter(responder.id)
+            .await?;
+        let (status, _, body) = send_request().await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body, Bytes::from_static(b"unavailable"));
 
         Ok(())
     }
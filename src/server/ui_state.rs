@@ -61,6 +61,7 @@ mod tests {
             status: &Status {
                 version: "1.0.0-alpha.4".to_string(),
                 level: StatusLevel::Available,
+                scheduler_behind_jobs: None,
             },
             user: Some(user),
             subscription: Some(SubscriptionState {
@@ -153,6 +154,7 @@ mod tests {
             status: &Status {
                 version: "1.0.0-alpha.4".to_string(),
                 level: StatusLevel::Available,
+                scheduler_behind_jobs: None,
             },
             user: None,
             subscription: Default::default(),
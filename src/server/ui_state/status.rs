@@ -2,9 +2,14 @@ use crate::server::StatusLevel;
 use serde::Serialize;
 
 #[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Status {
     pub version: String,
     pub level: StatusLevel,
+    /// Number of the pending scheduler jobs that exceed the number of jobs the scheduler can
+    /// process within a single run, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduler_behind_jobs: Option<usize>,
 }
 
 #[cfg(test)]
@@ -17,6 +22,7 @@ mod tests {
         assert_json_snapshot!(Status {
             version: "1.0.0-alpha.4".to_string(),
             level: StatusLevel::Available,
+            scheduler_behind_jobs: None,
         }, @r###"
         {
           "version": "1.0.0-alpha.4",
@@ -24,6 +30,18 @@ mod tests {
         }
         "###);
 
+        assert_json_snapshot!(Status {
+            version: "1.0.0-alpha.4".to_string(),
+            level: StatusLevel::Available,
+            scheduler_behind_jobs: Some(3),
+        }, @r###"
+        {
+          "version": "1.0.0-alpha.4",
+          "level": "available",
+          "schedulerBehindJobs": 3
+        }
+        "###);
+
         Ok(())
     }
 }
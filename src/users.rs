@@ -1,10 +1,15 @@
 pub mod api_ext;
 mod database_ext;
 mod user;
+mod user_audit_log_entry;
 mod user_data;
 mod user_data_key;
 mod user_data_namespace;
+mod user_display_order;
+mod user_display_order_collection;
 mod user_id;
+mod user_quiet_hours;
+mod user_redaction_patterns;
 mod user_settings;
 mod user_share;
 mod user_subscription;
@@ -12,10 +17,15 @@ mod user_subscription;
 pub use self::{
     api_ext::errors::UserSignupError,
     user::User,
+    user_audit_log_entry::UserAuditLogEntry,
     user_data::UserData,
     user_data_key::UserDataKey,
     user_data_namespace::UserDataNamespace,
+    user_display_order::{UserDisplayOrder, MAX_USER_DISPLAY_ORDER_SIZE},
+    user_display_order_collection::UserDisplayOrderCollection,
     user_id::UserId,
+    user_quiet_hours::UserQuietHours,
+    user_redaction_patterns::UserRedactionPatterns,
     user_settings::{UserSettings, UserSettingsSetter},
     user_share::{ClientUserShare, SharedResource, UserShare, UserShareId},
     user_subscription::{
@@ -1,13 +1,19 @@
 use crate::{
     api::Api,
+    error::Error as SecutilsError,
     network::{DnsResolver, EmailTransport},
     users::{
-        DictionaryDataUserDataSetter, SharedResource, User, UserData, UserDataKey,
-        UserDataNamespace, UserId, UserSettingsSetter, UserShare, UserShareId,
+        DictionaryDataUserDataSetter, SharedResource, User, UserAuditLogEntry, UserData,
+        UserDataKey, UserDataNamespace, UserDisplayOrder, UserDisplayOrderCollection, UserId,
+        UserQuietHours, UserRedactionPatterns, UserSettingsSetter, UserShare, UserShareId,
+        MAX_USER_DISPLAY_ORDER_SIZE,
     },
 };
 use anyhow::{bail, Context};
 use serde::Deserialize;
+use std::collections::HashSet;
+use time::OffsetDateTime;
+use uuid::Uuid;
 
 pub mod errors;
 pub mod user_data_setters;
@@ -58,9 +64,114 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> UsersApi<'a, DR, ET> {
         let user_data_key = user_data_key.into();
         match user_data_key.namespace {
             UserDataNamespace::UserSettings => self.set_user_settings_data(user_data).await,
+            UserDataNamespace::RedactionPatterns => {
+                self.set_redaction_patterns_data(user_data).await
+            }
+            UserDataNamespace::QuietHours => self.set_quiet_hours_data(user_data).await,
+            UserDataNamespace::DisplayOrder => bail!(SecutilsError::client(
+                "Display order can only be updated with the dedicated API."
+            )),
         }
     }
 
+    /// Retrieves display order of the entities in the specified collection for the user with the
+    /// specified id.
+    pub async fn get_display_order(
+        &self,
+        user_id: UserId,
+        collection: UserDisplayOrderCollection,
+    ) -> anyhow::Result<UserDisplayOrder> {
+        Ok(self
+            .get_data::<UserDisplayOrder>(
+                user_id,
+                (UserDataNamespace::DisplayOrder, collection.as_ref()),
+            )
+            .await?
+            .map(|display_order| display_order.value)
+            .unwrap_or_default())
+    }
+
+    /// Sets custom display order of the entities in the specified collection. Pinned entities are
+    /// preserved.
+    pub async fn set_display_order(
+        &self,
+        user_id: UserId,
+        collection: UserDisplayOrderCollection,
+        order: Vec<Uuid>,
+    ) -> anyhow::Result<UserDisplayOrder> {
+        if order.len() > MAX_USER_DISPLAY_ORDER_SIZE {
+            bail!(SecutilsError::client(format!(
+                "Display order cannot include more than {MAX_USER_DISPLAY_ORDER_SIZE} entities."
+            )));
+        }
+
+        let mut unique_ids = HashSet::with_capacity(order.len());
+        if let Some(duplicate_id) = order.iter().find(|id| !unique_ids.insert(**id)) {
+            bail!(SecutilsError::client(format!(
+                "Display order cannot include the same entity ('{duplicate_id}') more than once."
+            )));
+        }
+
+        let display_order = UserDisplayOrder {
+            order,
+            ..self.get_display_order(user_id, collection).await?
+        };
+        self.save_display_order(user_id, collection, display_order)
+            .await
+    }
+
+    /// Pins or unpins the entity with the specified ID in the specified collection.
+    pub async fn set_display_pinned(
+        &self,
+        user_id: UserId,
+        collection: UserDisplayOrderCollection,
+        id: Uuid,
+        pinned: bool,
+    ) -> anyhow::Result<UserDisplayOrder> {
+        let mut display_order = self.get_display_order(user_id, collection).await?;
+        let is_pinned = display_order.pinned.contains(&id);
+        if pinned == is_pinned {
+            return Ok(display_order);
+        }
+
+        if pinned {
+            if display_order.pinned.len() >= MAX_USER_DISPLAY_ORDER_SIZE {
+                bail!(SecutilsError::client(format!(
+                    "Cannot pin more than {MAX_USER_DISPLAY_ORDER_SIZE} entities."
+                )));
+            }
+            display_order.pinned.push(id);
+        } else {
+            display_order.pinned.retain(|pinned_id| *pinned_id != id);
+        }
+
+        self.save_display_order(user_id, collection, display_order)
+            .await
+    }
+
+    /// Retrieves quiet hours configured for the user with the specified id.
+    pub async fn get_quiet_hours(&self, user_id: UserId) -> anyhow::Result<Option<UserQuietHours>> {
+        Ok(self
+            .get_data::<UserQuietHours>(user_id, UserDataNamespace::QuietHours)
+            .await?
+            .map(|quiet_hours| quiet_hours.value))
+    }
+
+    /// Retrieves and compiles account-wide redaction patterns for the user with the specified id.
+    pub async fn get_redaction_patterns(
+        &self,
+        user_id: UserId,
+    ) -> anyhow::Result<Option<UserRedactionPatterns>> {
+        let Some(patterns) = self
+            .get_data::<Vec<String>>(user_id, UserDataNamespace::RedactionPatterns)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        UserRedactionPatterns::compile(&patterns.value)
+    }
+
     /// Retrieves the user share by the specified ID.
     pub async fn get_user_share(&self, id: UserShareId) -> anyhow::Result<Option<UserShare>> {
         self.api.db.get_user_share(id).await
@@ -88,6 +199,52 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> UsersApi<'a, DR, ET> {
         self.api.db.remove_user_share(id).await
     }
 
+    /// Retrieves audit log entries for the user with the specified ID.
+    pub async fn get_audit_log(&self, user_id: UserId) -> anyhow::Result<Vec<UserAuditLogEntry>> {
+        self.api.db.get_user_audit_log(user_id).await
+    }
+
+    /// Records the action performed on behalf of the specified user to the user audit log. If the
+    /// user is impersonated, the entry is attributed to the impersonating operator.
+    pub async fn record_audit_log_entry(
+        &self,
+        user: &User,
+        action: impl Into<String>,
+    ) -> anyhow::Result<()> {
+        self.api
+            .db
+            .insert_user_audit_log_entry(&UserAuditLogEntry {
+                id: Uuid::now_v7(),
+                user_id: user.id,
+                operator: user.impersonator.clone(),
+                action: action.into(),
+                created_at: OffsetDateTime::now_utc(),
+            })
+            .await
+    }
+
+    async fn save_display_order(
+        &self,
+        user_id: UserId,
+        collection: UserDisplayOrderCollection,
+        display_order: UserDisplayOrder,
+    ) -> anyhow::Result<UserDisplayOrder> {
+        let user_data_key = (UserDataNamespace::DisplayOrder, collection.as_ref());
+        if display_order.is_empty() {
+            self.api.db.remove_user_data(user_id, user_data_key).await?;
+        } else {
+            self.api
+                .db
+                .upsert_user_data(
+                    user_data_key,
+                    UserData::new(user_id, display_order.clone(), OffsetDateTime::now_utc()),
+                )
+                .await?;
+        }
+
+        Ok(display_order)
+    }
+
     async fn set_user_settings_data(
         &self,
         serialized_user_data: UserData<Vec<u8>>,
@@ -109,11 +266,83 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> UsersApi<'a, DR, ET> {
         )
         .await
     }
+
+    async fn set_redaction_patterns_data(
+        &self,
+        serialized_user_data: UserData<Vec<u8>>,
+    ) -> anyhow::Result<()> {
+        let patterns = serde_json::from_slice::<Vec<String>>(&serialized_user_data.value)
+            .with_context(|| "Cannot deserialize new redaction patterns data".to_string())?;
+
+        // Make sure patterns are valid and can be compiled before storing them.
+        if UserRedactionPatterns::compile(&patterns)?.is_none() {
+            return self
+                .api
+                .db
+                .remove_user_data(
+                    serialized_user_data.user_id,
+                    UserDataNamespace::RedactionPatterns,
+                )
+                .await;
+        }
+
+        self.api
+            .db
+            .upsert_user_data(
+                UserDataNamespace::RedactionPatterns,
+                UserData::new(
+                    serialized_user_data.user_id,
+                    patterns,
+                    serialized_user_data.timestamp,
+                ),
+            )
+            .await
+    }
+
+    async fn set_quiet_hours_data(
+        &self,
+        serialized_user_data: UserData<Vec<u8>>,
+    ) -> anyhow::Result<()> {
+        let quiet_hours =
+            match serde_json::from_slice::<Option<UserQuietHours>>(&serialized_user_data.value) {
+                Ok(quiet_hours) => quiet_hours,
+                Err(err) => bail!(SecutilsError::client_with_root_cause(
+                    anyhow::Error::from(err).context("Quiet hours are not valid.")
+                )),
+            };
+
+        // Empty value means that quiet hours should be disabled.
+        let Some(quiet_hours) = quiet_hours else {
+            return self
+                .api
+                .db
+                .remove_user_data(serialized_user_data.user_id, UserDataNamespace::QuietHours)
+                .await;
+        };
+
+        if quiet_hours.start == quiet_hours.end {
+            bail!(SecutilsError::client(
+                "Quiet hours start and end time cannot be the same."
+            ));
+        }
+
+        self.api
+            .db
+            .upsert_user_data(
+                UserDataNamespace::QuietHours,
+                UserData::new(
+                    serialized_user_data.user_id,
+                    quiet_hours,
+                    serialized_user_data.timestamp,
+                ),
+            )
+            .await
+    }
 }
 
 impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
     /// Returns an API to work with users.
-    pub fn users(&self) -> UsersApi<DR, ET> {
+    pub fn users(&self) -> UsersApi<'_, DR, ET> {
         UsersApi::new(self)
     }
 }
@@ -1,11 +1,18 @@
 mod raw_user;
+mod raw_user_audit_log_entry;
 mod raw_user_data;
 mod raw_user_share;
 
-use self::{raw_user::RawUser, raw_user_data::RawUserData, raw_user_share::RawUserShare};
+use self::{
+    raw_user::RawUser, raw_user_audit_log_entry::RawUserAuditLogEntry, raw_user_data::RawUserData,
+    raw_user_share::RawUserShare,
+};
 use crate::{
     database::Database,
-    users::{SharedResource, User, UserData, UserDataKey, UserId, UserShare, UserShareId},
+    users::{
+        SharedResource, User, UserAuditLogEntry, UserData, UserDataKey, UserId, UserShare,
+        UserShareId,
+    },
 };
 use anyhow::bail;
 use serde::{Deserialize, Serialize};
@@ -349,6 +356,51 @@ RETURNING id as "id!", user_id as "user_id!", resource as "resource!", created_a
         .map(UserShare::try_from)
         .transpose()
     }
+
+    /// Retrieves all audit log entries for the specified user, sorted by creation time.
+    pub async fn get_user_audit_log(
+        &self,
+        user_id: UserId,
+    ) -> anyhow::Result<Vec<UserAuditLogEntry>> {
+        Ok(query_as!(
+            RawUserAuditLogEntry,
+            r#"
+SELECT id, user_id, operator, action, created_at
+FROM user_audit_log
+WHERE user_id = $1
+ORDER BY created_at, id
+                "#,
+            *user_id
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(UserAuditLogEntry::from)
+        .collect())
+    }
+
+    /// Inserts entry to the `user_audit_log` table.
+    pub async fn insert_user_audit_log_entry(
+        &self,
+        entry: &UserAuditLogEntry,
+    ) -> anyhow::Result<()> {
+        let raw_entry = RawUserAuditLogEntry::from(entry);
+        query!(
+            r#"
+INSERT INTO user_audit_log (id, user_id, operator, action, created_at)
+VALUES ($1, $2, $3, $4, $5)
+        "#,
+            raw_entry.id,
+            raw_entry.user_id,
+            raw_entry.operator,
+            raw_entry.action,
+            raw_entry.created_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -432,6 +484,7 @@ mod tests {
             created_at: 2000-01-01 10:00:00.0 +00:00:00,
             is_activated: false,
             is_operator: false,
+            impersonator: None,
             subscription: UserSubscription {
                 tier: Ultimate,
                 started_at: 2000-01-01 10:00:01.0 +00:00:00,
@@ -458,6 +511,7 @@ mod tests {
             created_at: 2010-01-01 10:00:00.0 +00:00:00,
             is_activated: false,
             is_operator: false,
+            impersonator: None,
             subscription: UserSubscription {
                 tier: Standard,
                 started_at: 2010-01-01 10:00:00.0 +00:00:00,
@@ -484,6 +538,7 @@ mod tests {
             created_at: 2000-01-01 10:00:00.0 +00:00:00,
             is_activated: false,
             is_operator: false,
+            impersonator: None,
             subscription: UserSubscription {
                 tier: Professional,
                 started_at: 2000-01-01 10:00:00.0 +00:00:00,
@@ -540,6 +595,7 @@ mod tests {
                 created_at: 2000-01-01 10:00:00.0 +00:00:00,
                 is_activated: false,
                 is_operator: false,
+                impersonator: None,
                 subscription: UserSubscription {
                     tier: Professional,
                     started_at: 2000-01-01 10:00:00.0 +00:00:00,
@@ -594,6 +650,7 @@ mod tests {
                 created_at: 2000-01-01 10:00:00.0 +00:00:00,
                 is_activated: false,
                 is_operator: false,
+                impersonator: None,
                 subscription: UserSubscription {
                     tier: Professional,
                     started_at: 2000-01-01 10:00:00.0 +00:00:00,
@@ -641,6 +698,7 @@ mod tests {
                 created_at: 2000-01-01 10:00:00.0 +00:00:00,
                 is_activated: false,
                 is_operator: false,
+                impersonator: None,
                 subscription: UserSubscription {
                     tier: Ultimate,
                     started_at: 2000-01-01 10:00:01.0 +00:00:00,
@@ -703,6 +761,7 @@ mod tests {
                 created_at: 2000-01-01 10:00:00.0 +00:00:00,
                 is_activated: false,
                 is_operator: false,
+                impersonator: None,
                 subscription: UserSubscription {
                     tier: Ultimate,
                     started_at: 2000-01-01 10:00:01.0 +00:00:00,
@@ -743,6 +802,7 @@ mod tests {
                 created_at: 2010-01-01 10:00:00.0 +00:00:00,
                 is_activated: false,
                 is_operator: false,
+                impersonator: None,
                 subscription: UserSubscription {
                     tier: Basic,
                     started_at: 2010-01-01 10:00:00.0 +00:00:00,
@@ -29,6 +29,7 @@ impl<'u> TryFrom<RawUser<'u>> for User {
             is_activated: false,
             // Operator status will be retrieved from the configuration.
             is_operator: false,
+            impersonator: None,
             subscription: UserSubscription {
                 tier: u8::try_from(raw_user.subscription_tier)?.try_into()?,
                 started_at: raw_user.subscription_started_at,
@@ -92,6 +93,7 @@ mod tests {
             created_at: 2000-01-01 10:00:00.0 +00:00:00,
             is_activated: false,
             is_operator: false,
+            impersonator: None,
             subscription: UserSubscription {
                 tier: Ultimate,
                 started_at: 2000-01-01 10:00:01.0 +00:00:00,
@@ -124,6 +126,7 @@ mod tests {
             created_at: 2000-01-01 10:00:00.0 +00:00:00,
             is_activated: false,
             is_operator: false,
+            impersonator: None,
             subscription: UserSubscription {
                 tier: Professional,
                 started_at: 2000-01-01 10:00:01.0 +00:00:00,
@@ -0,0 +1,36 @@
+use crate::users::UserAuditLogEntry;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub(super) struct RawUserAuditLogEntry {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub operator: Option<String>,
+    pub action: String,
+    pub created_at: OffsetDateTime,
+}
+
+impl From<RawUserAuditLogEntry> for UserAuditLogEntry {
+    fn from(raw_entry: RawUserAuditLogEntry) -> Self {
+        UserAuditLogEntry {
+            id: raw_entry.id,
+            user_id: raw_entry.user_id.into(),
+            operator: raw_entry.operator,
+            action: raw_entry.action,
+            created_at: raw_entry.created_at,
+        }
+    }
+}
+
+impl From<&UserAuditLogEntry> for RawUserAuditLogEntry {
+    fn from(entry: &UserAuditLogEntry) -> Self {
+        RawUserAuditLogEntry {
+            id: entry.id,
+            user_id: *entry.user_id,
+            operator: entry.operator.clone(),
+            action: entry.action.clone(),
+            created_at: entry.created_at,
+        }
+    }
+}
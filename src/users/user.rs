@@ -15,6 +15,9 @@ pub struct User {
     /// Indicates whether the user has access to the operator functionality.
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     pub is_operator: bool,
+    /// ID of the operator that acts on behalf of the user, if the user is impersonated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impersonator: Option<String>,
     pub subscription: UserSubscription,
 }
 
@@ -0,0 +1,53 @@
+use crate::users::UserId;
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Represents an entry of the user audit log that records actions performed on behalf of the user
+/// (e.g. by the operator that impersonates the user).
+#[derive(Serialize, Debug, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserAuditLogEntry {
+    /// Unique ID of the audit log entry.
+    pub id: Uuid,
+    /// ID of the user the action was performed on behalf of.
+    #[serde(skip_serializing)]
+    pub user_id: UserId,
+    /// ID of the operator that performed the action, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operator: Option<String>,
+    /// Description of the performed action (e.g. `POST /api/utils/web_scraping/content`).
+    pub action: String,
+    /// Date and time when the action was performed.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::users::{UserAuditLogEntry, UserId};
+    use insta::assert_json_snapshot;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(UserAuditLogEntry {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            user_id: UserId::new(),
+            operator: Some("ops@secutils.dev".to_string()),
+            action: "GET /api/ui/state".to_string(),
+            // January 1, 2010 11:00:00
+            created_at: OffsetDateTime::from_unix_timestamp(1262340000)?,
+        }, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "operator": "ops@secutils.dev",
+          "action": "GET /api/ui/state",
+          "createdAt": 1262340000
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -4,12 +4,18 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "camelCase")]
 pub enum UserDataNamespace {
     UserSettings,
+    RedactionPatterns,
+    QuietHours,
+    DisplayOrder,
 }
 
 impl AsRef<str> for UserDataNamespace {
     fn as_ref(&self) -> &str {
         match self {
             UserDataNamespace::UserSettings => "userSettings",
+            UserDataNamespace::RedactionPatterns => "redactionPatterns",
+            UserDataNamespace::QuietHours => "quietHours",
+            UserDataNamespace::DisplayOrder => "displayOrder",
         }
     }
 }
@@ -22,6 +28,12 @@ mod tests {
     #[test]
     fn proper_str_reference() -> anyhow::Result<()> {
         assert_eq!(UserDataNamespace::UserSettings.as_ref(), "userSettings");
+        assert_eq!(
+            UserDataNamespace::RedactionPatterns.as_ref(),
+            "redactionPatterns"
+        );
+        assert_eq!(UserDataNamespace::QuietHours.as_ref(), "quietHours");
+        assert_eq!(UserDataNamespace::DisplayOrder.as_ref(), "displayOrder");
 
         Ok(())
     }
@@ -30,6 +42,9 @@ mod tests {
     fn serialization() -> anyhow::Result<()> {
         insta::with_settings!({ sort_maps => true }, {
             assert_json_snapshot!(UserDataNamespace::UserSettings, @r###""userSettings""###);
+            assert_json_snapshot!(UserDataNamespace::RedactionPatterns, @r###""redactionPatterns""###);
+            assert_json_snapshot!(UserDataNamespace::QuietHours, @r###""quietHours""###);
+            assert_json_snapshot!(UserDataNamespace::DisplayOrder, @r###""displayOrder""###);
         });
 
         Ok(())
@@ -41,6 +56,18 @@ mod tests {
             serde_json::from_str::<UserDataNamespace>(r#""userSettings""#)?,
             UserDataNamespace::UserSettings
         );
+        assert_eq!(
+            serde_json::from_str::<UserDataNamespace>(r#""redactionPatterns""#)?,
+            UserDataNamespace::RedactionPatterns
+        );
+        assert_eq!(
+            serde_json::from_str::<UserDataNamespace>(r#""quietHours""#)?,
+            UserDataNamespace::QuietHours
+        );
+        assert_eq!(
+            serde_json::from_str::<UserDataNamespace>(r#""displayOrder""#)?,
+            UserDataNamespace::DisplayOrder
+        );
 
         Ok(())
     }
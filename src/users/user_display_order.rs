@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Maximum number of entities that can be explicitly ordered or pinned within a single collection.
+pub const MAX_USER_DISPLAY_ORDER_SIZE: usize = 1000;
+
+/// Defines the order in which the user entities of a particular collection (e.g. responders) are
+/// displayed. Pinned entities are displayed first, followed by the entities in the custom order.
+/// Entities that aren't explicitly ordered are displayed last, in their original order.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDisplayOrder {
+    /// IDs of the entities in the order they should be displayed in.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub order: Vec<Uuid>,
+    /// IDs of the pinned entities.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pinned: Vec<Uuid>,
+}
+
+impl UserDisplayOrder {
+    /// Sorts the specified entities according to the display order, keeping the relative order of
+    /// the entities that have the same position.
+    pub fn sort<T>(&self, entities: &mut [T], entity_id: impl Fn(&T) -> Uuid) {
+        entities.sort_by_cached_key(|entity| {
+            let id = entity_id(entity);
+            (
+                !self.pinned.contains(&id),
+                self.order
+                    .iter()
+                    .position(|ordered_id| *ordered_id == id)
+                    .unwrap_or(usize::MAX),
+            )
+        });
+    }
+
+    /// Returns `true` if there is no custom order and no pinned entities.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty() && self.pinned.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::users::UserDisplayOrder;
+    use insta::assert_json_snapshot;
+    use uuid::{uuid, Uuid};
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(UserDisplayOrder::default(), @"{}");
+        assert_json_snapshot!(UserDisplayOrder {
+            order: vec![uuid!("00000000-0000-0000-0000-000000000002")],
+            pinned: vec![uuid!("00000000-0000-0000-0000-000000000001")],
+        }, @r###"
+        {
+          "order": [
+            "00000000-0000-0000-0000-000000000002"
+          ],
+          "pinned": [
+            "00000000-0000-0000-0000-000000000001"
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<UserDisplayOrder>("{}")?,
+            UserDisplayOrder::default()
+        );
+        assert_eq!(
+            serde_json::from_str::<UserDisplayOrder>(
+                r#"{ "order": ["00000000-0000-0000-0000-000000000002"], "pinned": ["00000000-0000-0000-0000-000000000001"] }"#
+            )?,
+            UserDisplayOrder {
+                order: vec![uuid!("00000000-0000-0000-0000-000000000002")],
+                pinned: vec![uuid!("00000000-0000-0000-0000-000000000001")],
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sorts_entities() -> anyhow::Result<()> {
+        let ids = (1..=5).map(Uuid::from_u128).collect::<Vec<_>>();
+
+        // No custom order.
+        let mut entities = ids.clone();
+        UserDisplayOrder::default().sort(&mut entities, |id| *id);
+        assert_eq!(entities, ids);
+
+        // Custom order, unordered entities go last in the original order.
+        let mut entities = ids.clone();
+        UserDisplayOrder {
+            order: vec![ids[3], ids[1]],
+            pinned: vec![],
+        }
+        .sort(&mut entities, |id| *id);
+        assert_eq!(entities, vec![ids[3], ids[1], ids[0], ids[2], ids[4]]);
+
+        // Pinned entities go first, and respect custom order.
+        let mut entities = ids.clone();
+        UserDisplayOrder {
+            order: vec![ids[3], ids[1], ids[4]],
+            pinned: vec![ids[4], ids[2]],
+        }
+        .sort(&mut entities, |id| *id);
+        assert_eq!(entities, vec![ids[4], ids[2], ids[3], ids[1], ids[0]]);
+
+        Ok(())
+    }
+}
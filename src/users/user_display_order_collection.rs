@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a collection of the user entities that can be reordered and pinned in the UI.
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Copy, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum UserDisplayOrderCollection {
+    Responders,
+    WebPageResourcesTrackers,
+    WebPageContentTrackers,
+    WebPageScreenshotTrackers,
+    WebPageDnsRecordsTrackers,
+}
+
+impl AsRef<str> for UserDisplayOrderCollection {
+    fn as_ref(&self) -> &str {
+        match self {
+            UserDisplayOrderCollection::Responders => "responders",
+            UserDisplayOrderCollection::WebPageResourcesTrackers => "webPageResourcesTrackers",
+            UserDisplayOrderCollection::WebPageContentTrackers => "webPageContentTrackers",
+            UserDisplayOrderCollection::WebPageScreenshotTrackers => "webPageScreenshotTrackers",
+            UserDisplayOrderCollection::WebPageDnsRecordsTrackers => "webPageDnsRecordsTrackers",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::users::UserDisplayOrderCollection;
+
+    #[test]
+    fn proper_str_reference() -> anyhow::Result<()> {
+        for collection in [
+            UserDisplayOrderCollection::Responders,
+            UserDisplayOrderCollection::WebPageResourcesTrackers,
+            UserDisplayOrderCollection::WebPageContentTrackers,
+            UserDisplayOrderCollection::WebPageScreenshotTrackers,
+            UserDisplayOrderCollection::WebPageDnsRecordsTrackers,
+        ] {
+            assert_eq!(
+                serde_json::to_string(&collection)?,
+                format!("\"{}\"", collection.as_ref())
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<UserDisplayOrderCollection>(r#""responders""#)?,
+            UserDisplayOrderCollection::Responders
+        );
+        assert_eq!(
+            serde_json::from_str::<UserDisplayOrderCollection>(r#""webPageContentTrackers""#)?,
+            UserDisplayOrderCollection::WebPageContentTrackers
+        );
+        assert!(serde_json::from_str::<UserDisplayOrderCollection>(r#""unknown""#).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime, Time, UtcOffset};
+
+time::serde::format_description!(quiet_hours_time, Time, "[hour]:[minute]");
+time::serde::format_description!(
+    quiet_hours_timezone,
+    UtcOffset,
+    "[offset_hour sign:mandatory]:[offset_minute]"
+);
+
+/// Defines a daily window during which non-urgent notifications for the user are deferred until
+/// the window ends.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserQuietHours {
+    /// The local time at which quiet hours start, e.g. `22:00`.
+    #[serde(with = "quiet_hours_time")]
+    pub start: Time,
+    /// The local time at which quiet hours end, e.g. `07:30`. If it's earlier than the start time,
+    /// the window spans midnight.
+    #[serde(with = "quiet_hours_time")]
+    pub end: Time,
+    /// The UTC offset of the user's timezone, e.g. `+02:00`.
+    #[serde(with = "quiet_hours_timezone")]
+    pub timezone: UtcOffset,
+}
+
+impl UserQuietHours {
+    /// Returns the time at which the quiet hours window that includes the specified time ends, or
+    /// `None` if the specified time is outside of the quiet hours.
+    pub fn window_end(&self, at: OffsetDateTime) -> Option<OffsetDateTime> {
+        let local_at = at.to_offset(self.timezone);
+        let local_time = local_at.time();
+        let is_active = if self.start <= self.end {
+            local_time >= self.start && local_time < self.end
+        } else {
+            local_time >= self.start || local_time < self.end
+        };
+
+        if !is_active {
+            return None;
+        }
+
+        let window_end = local_at.replace_time(self.end);
+        Some(
+            if window_end <= local_at {
+                window_end + Duration::DAY
+            } else {
+                window_end
+            }
+            .to_offset(UtcOffset::UTC),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserQuietHours;
+    use insta::assert_json_snapshot;
+    use time::macros::{datetime, offset, time};
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(UserQuietHours {
+            start: time!(22:00),
+            end: time!(07:30),
+            timezone: offset!(+2),
+        }, @r###"
+        {
+          "start": "22:00",
+          "end": "07:30",
+          "timezone": "+02:00"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<UserQuietHours>(
+                r#"{ "start": "22:00", "end": "07:30", "timezone": "-05:30" }"#
+            )?,
+            UserQuietHours {
+                start: time!(22:00),
+                end: time!(07:30),
+                timezone: offset!(-5:30),
+            }
+        );
+
+        assert!(serde_json::from_str::<UserQuietHours>(
+            r#"{ "start": "25:00", "end": "07:30", "timezone": "+00:00" }"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<UserQuietHours>(
+            r#"{ "start": "22:00", "end": "07:30", "timezone": "Europe/Berlin" }"#
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn calculates_window_end() -> anyhow::Result<()> {
+        // Window within a single day.
+        let quiet_hours = UserQuietHours {
+            start: time!(01:00),
+            end: time!(06:00),
+            timezone: offset!(+2),
+        };
+        assert_eq!(
+            quiet_hours.window_end(datetime!(2000-01-01 22:59 UTC)),
+            None
+        );
+        assert_eq!(
+            quiet_hours.window_end(datetime!(2000-01-01 23:00 UTC)),
+            Some(datetime!(2000-01-02 04:00 UTC))
+        );
+        assert_eq!(
+            quiet_hours.window_end(datetime!(2000-01-02 03:59 UTC)),
+            Some(datetime!(2000-01-02 04:00 UTC))
+        );
+        assert_eq!(
+            quiet_hours.window_end(datetime!(2000-01-02 04:00 UTC)),
+            None
+        );
+
+        // Window that spans midnight.
+        let quiet_hours = UserQuietHours {
+            start: time!(22:00),
+            end: time!(07:00),
+            timezone: offset!(UTC),
+        };
+        assert_eq!(
+            quiet_hours.window_end(datetime!(2000-01-01 21:59 UTC)),
+            None
+        );
+        assert_eq!(
+            quiet_hours.window_end(datetime!(2000-01-01 22:00 UTC)),
+            Some(datetime!(2000-01-02 07:00 UTC))
+        );
+        assert_eq!(
+            quiet_hours.window_end(datetime!(2000-01-02 03:00 UTC)),
+            Some(datetime!(2000-01-02 07:00 UTC))
+        );
+        assert_eq!(
+            quiet_hours.window_end(datetime!(2000-01-02 07:00 UTC)),
+            None
+        );
+
+        // Empty window.
+        let quiet_hours = UserQuietHours {
+            start: time!(22:00),
+            end: time!(22:00),
+            timezone: offset!(UTC),
+        };
+        assert_eq!(
+            quiet_hours.window_end(datetime!(2000-01-01 22:00 UTC)),
+            None
+        );
+
+        Ok(())
+    }
+}
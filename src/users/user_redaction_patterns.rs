@@ -0,0 +1,175 @@
+use crate::error::{Error as SecutilsError, ErrorCode};
+use anyhow::{anyhow, bail};
+use regex::{bytes::Regex as BytesRegex, Regex};
+use std::borrow::Cow;
+
+/// Maximum number of the redaction patterns that user can define.
+pub const MAX_USER_REDACTION_PATTERNS: usize = 20;
+
+/// Maximum length of a single redaction pattern.
+const MAX_USER_REDACTION_PATTERN_LENGTH: usize = 500;
+
+/// Maximum size of the compiled redaction patterns (1MB), to limit the cost of applying them.
+const MAX_USER_REDACTION_PATTERNS_SIZE: usize = 1024 * 1024;
+
+/// Value that replaces every substring that matches any of the redaction patterns.
+pub const USER_REDACTED_VALUE: &str = "[REDACTED]";
+
+/// Account-wide regular expressions compiled into a single matcher that are applied to the
+/// captured data (e.g. webhook responder requests or web page tracker revisions) before it's
+/// persisted.
+#[derive(Debug, Clone)]
+pub struct UserRedactionPatterns {
+    text: Regex,
+    binary: BytesRegex,
+}
+
+impl UserRedactionPatterns {
+    /// Validates and compiles the specified patterns. Returns `None` if there are no patterns.
+    pub fn compile<P: AsRef<str>>(patterns: &[P]) -> anyhow::Result<Option<Self>> {
+        if patterns.is_empty() {
+            return Ok(None);
+        }
+
+        if patterns.len() > MAX_USER_REDACTION_PATTERNS {
+            bail!(SecutilsError::client(format!(
+                "Redaction patterns cannot have more than {MAX_USER_REDACTION_PATTERNS} entries."
+            ))
+            .with_code(ErrorCode::LimitExceeded));
+        }
+
+        for pattern in patterns {
+            let pattern = pattern.as_ref();
+            if pattern.is_empty() {
+                bail!(SecutilsError::client("Redaction pattern cannot be empty."));
+            }
+
+            if pattern.len() > MAX_USER_REDACTION_PATTERN_LENGTH {
+                bail!(SecutilsError::client(format!(
+                    "Redaction pattern cannot be longer than {MAX_USER_REDACTION_PATTERN_LENGTH} characters."
+                )));
+            }
+
+            if let Err(err) = Regex::new(pattern) {
+                bail!(SecutilsError::client_with_root_cause(
+                    anyhow!("Failed to parse redaction pattern `{pattern}`: {err}")
+                        .context("Redaction patterns must be valid regular expressions.")
+                ));
+            }
+        }
+
+        let combined_pattern = patterns
+            .iter()
+            .map(|pattern| format!("(?:{})", pattern.as_ref()))
+            .collect::<Vec<_>>()
+            .join("|");
+        let (text, binary) = match (
+            regex::RegexBuilder::new(&combined_pattern)
+                .size_limit(MAX_USER_REDACTION_PATTERNS_SIZE)
+                .build(),
+            regex::bytes::RegexBuilder::new(&combined_pattern)
+                .size_limit(MAX_USER_REDACTION_PATTERNS_SIZE)
+                .build(),
+        ) {
+            (Ok(text), Ok(binary)) => (text, binary),
+            (Err(err), _) | (_, Err(err)) => {
+                bail!(SecutilsError::client_with_root_cause(
+                    anyhow!("Failed to compile redaction patterns: {err}")
+                        .context("Redaction patterns are too complex.")
+                ));
+            }
+        };
+
+        Ok(Some(Self { text, binary }))
+    }
+
+    /// Masks all substrings of the text that match any of the redaction patterns.
+    pub fn redact_text<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        self.text.replace_all(text, USER_REDACTED_VALUE)
+    }
+
+    /// Masks all byte sequences that match any of the redaction patterns.
+    pub fn redact_bytes<'t>(&self, bytes: &'t [u8]) -> Cow<'t, [u8]> {
+        self.binary
+            .replace_all(bytes, USER_REDACTED_VALUE.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UserRedactionPatterns, MAX_USER_REDACTION_PATTERNS};
+    use crate::error::Error as SecutilsError;
+    use insta::assert_debug_snapshot;
+    use std::borrow::Cow;
+
+    fn compile_and_fail(patterns: &[&str]) -> SecutilsError {
+        UserRedactionPatterns::compile(patterns)
+            .unwrap_err()
+            .downcast::<SecutilsError>()
+            .unwrap()
+    }
+
+    #[test]
+    fn can_redact_text_and_bytes() -> anyhow::Result<()> {
+        let patterns =
+            UserRedactionPatterns::compile(&[r"\b(?:\d[ -]?){13,16}\b", r"secret-\w+"])?.unwrap();
+
+        assert_eq!(
+            patterns.redact_text("card 4111 1111 1111 1111, token secret-abc, id 42"),
+            "card [REDACTED], token [REDACTED], id 42"
+        );
+        assert_eq!(
+            patterns.redact_bytes(b"{\"card\":\"4111111111111111\",\"key\":\"secret-xyz\"}"),
+            Cow::Borrowed(b"{\"card\":\"[REDACTED]\",\"key\":\"[REDACTED]\"}".as_slice())
+        );
+
+        // Nothing to redact.
+        assert!(matches!(
+            patterns.redact_text("nothing to see here"),
+            Cow::Borrowed("nothing to see here")
+        ));
+        assert!(matches!(
+            patterns.redact_bytes(b"nothing to see here"),
+            Cow::Borrowed(b"nothing to see here")
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn returns_none_for_empty_patterns() -> anyhow::Result<()> {
+        assert!(UserRedactionPatterns::compile::<&str>(&[])?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_for_invalid_patterns() -> anyhow::Result<()> {
+        assert_debug_snapshot!(
+            compile_and_fail(&[""]),
+            @r###""Redaction pattern cannot be empty.""###
+        );
+
+        assert_debug_snapshot!(
+            compile_and_fail(&[&"a".repeat(501)]),
+            @r###""Redaction pattern cannot be longer than 500 characters.""###
+        );
+
+        assert_debug_snapshot!(
+            compile_and_fail(&["a"; MAX_USER_REDACTION_PATTERNS + 1]),
+            @r###""Redaction patterns cannot have more than 20 entries.""###
+        );
+
+        assert_debug_snapshot!(
+            compile_and_fail(&["(unclosed"]),
+            @r###"
+        Error {
+            context: "Redaction patterns must be valid regular expressions.",
+            source: "Failed to parse redaction pattern `(unclosed`: regex parse error:\n    (unclosed\n    ^\nerror: unclosed group",
+        }
+        "###
+        );
+
+        Ok(())
+    }
+}
@@ -122,6 +122,10 @@ mod test {
                     .collect(),
                 ),
                 min_schedule_interval: Duration::from_secs(10),
+                tracker_resources: 1000,
+                tracker_captured_headers: 100,
+                tracker_urls: 10,
+                tracker_idle_period: None,
             },
             web_security: SubscriptionWebSecurityConfig {
                 policies: 10,
@@ -67,6 +67,10 @@ mod test {
                     .collect(),
                 ),
                 min_schedule_interval: Duration::from_secs(10),
+                tracker_resources: 1000,
+                tracker_captured_headers: 100,
+                tracker_urls: 10,
+                tracker_idle_period: None,
             },
             web_security: SubscriptionWebSecurityConfig {
                 policies: 10,
@@ -109,6 +113,10 @@ mod test {
                     .collect(),
                 ),
                 min_schedule_interval: Duration::from_secs(20),
+                tracker_resources: 1000,
+                tracker_captured_headers: 100,
+                tracker_urls: 10,
+                tracker_idle_period: None,
             },
             web_security: SubscriptionWebSecurityConfig::default(),
             certificates: SubscriptionCertificatesConfig {
@@ -138,6 +146,10 @@ mod test {
                 tracker_revisions: 33,
                 tracker_schedules: None,
                 min_schedule_interval: Duration::from_secs(30),
+                tracker_resources: 1000,
+                tracker_captured_headers: 100,
+                tracker_urls: 10,
+                tracker_idle_period: None,
             },
             web_security: SubscriptionWebSecurityConfig::default(),
             certificates: SubscriptionCertificatesConfig {
@@ -26,7 +26,7 @@ impl<'a> UtilsApiExt<'a> {
 
 impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
     /// Returns an API to retrieve available utils.
-    pub fn utils(&self) -> UtilsApiExt {
+    pub fn utils(&self) -> UtilsApiExt<'_> {
         UtilsApiExt::new(&self.db)
     }
 }
@@ -127,6 +127,15 @@ mod tests {
                                 ],
                             ),
                         },
+                        Util {
+                            id: 14,
+                            handle: "web_security__jwt",
+                            name: "JWT",
+                            keywords: Some(
+                                "jwt json web token decode inspect verify jwks claims web security",
+                            ),
+                            utils: None,
+                        },
                     ],
                 ),
             },
@@ -155,6 +164,24 @@ mod tests {
                             ),
                             utils: None,
                         },
+                        Util {
+                            id: 13,
+                            handle: "web_scraping__screenshots",
+                            name: "Screenshot trackers",
+                            keywords: Some(
+                                "web scraping crawl spider scraper scrape screenshot visual image tracker track",
+                            ),
+                            utils: None,
+                        },
+                        Util {
+                            id: 15,
+                            handle: "web_scraping__dns",
+                            name: "DNS trackers",
+                            keywords: Some(
+                                "web scraping dns domain record a aaaa cname mx ns txt tracker track",
+                            ),
+                            utils: None,
+                        },
                     ],
                 ),
             },
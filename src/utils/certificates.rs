@@ -1,5 +1,6 @@
 mod certificate_templates;
 mod database_ext;
+mod export_artifact;
 mod export_format;
 mod private_keys;
 mod x509;
@@ -20,6 +21,7 @@ use serde_json::json;
 
 pub use self::{
     certificate_templates::{CertificateAttributes, CertificateTemplate},
+    export_artifact::ExportArtifact,
     export_format::ExportFormat,
     private_keys::{PrivateKey, PrivateKeyAlgorithm, PrivateKeyEllipticCurve, PrivateKeySize},
     x509::{ExtendedKeyUsage, KeyUsage, SignatureAlgorithm, Version},
@@ -148,6 +150,21 @@ pub async fn certificates_handle_action<DR: DnsResolver, ET: EmailTransport>(
                 .generate_self_signed_certificate(user.id, resource_id, extract_params(params)?)
                 .await?,
         ),
+        (
+            UtilsResource::CertificatesTemplates,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::CertificatesTemplateGenerateArchive,
+            },
+        ) => UtilsActionResult::json(
+            certificates
+                .generate_self_signed_certificate_archive(
+                    user.id,
+                    resource_id,
+                    extract_params(params)?,
+                )
+                .await?,
+        ),
 
         _ => Err(SecutilsError::client("Invalid resource or action.").into()),
     }
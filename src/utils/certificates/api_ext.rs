@@ -2,6 +2,7 @@ mod private_keys_create_params;
 mod private_keys_export_params;
 mod private_keys_update_params;
 mod templates_create_params;
+mod templates_generate_archive_params;
 mod templates_generate_params;
 mod templates_update_params;
 
@@ -10,18 +11,19 @@ pub use self::{
     private_keys_export_params::PrivateKeysExportParams,
     private_keys_update_params::PrivateKeysUpdateParams,
     templates_create_params::TemplatesCreateParams,
+    templates_generate_archive_params::TemplatesGenerateArchiveParams,
     templates_generate_params::TemplatesGenerateParams,
     templates_update_params::TemplatesUpdateParams,
 };
 use crate::{
     api::Api,
-    error::Error as SecutilsError,
+    error::{Error as SecutilsError, ErrorCode},
     network::{DnsResolver, EmailTransport},
     users::{SharedResource, UserId, UserShare},
     utils::{
         certificates::{
-            CertificateTemplate, ExportFormat, ExtendedKeyUsage, KeyUsage, PrivateKey,
-            PrivateKeyAlgorithm, SignatureAlgorithm,
+            CertificateTemplate, ExportArtifact, ExportFormat, ExtendedKeyUsage, KeyUsage,
+            PrivateKey, PrivateKeyAlgorithm, SignatureAlgorithm,
         },
         utils_action_validation::MAX_UTILS_ENTITY_NAME_LENGTH,
     },
@@ -42,6 +44,7 @@ use openssl::{
     x509::{extension, X509Builder, X509NameBuilder, X509},
 };
 use std::{
+    collections::BTreeSet,
     io::{Cursor, Write},
     time::Instant,
 };
@@ -128,9 +131,10 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> CertificatesApiExt<'a, DR, ET> {
         }
 
         let Some(private_key) = self.get_private_key(user_id, id).await? else {
-            bail!(SecutilsError::client(format!(
-                "Private key ('{id}') is not found."
-            )));
+            bail!(
+                SecutilsError::client(format!("Private key ('{id}') is not found."))
+                    .with_code(ErrorCode::PrivateKeyNotFound)
+            );
         };
 
         // If name update is needed, extract it from parameters.
@@ -199,9 +203,10 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> CertificatesApiExt<'a, DR, ET> {
         params: PrivateKeysExportParams,
     ) -> anyhow::Result<Vec<u8>> {
         let Some(private_key) = self.get_private_key(user_id, id).await? else {
-            bail!(SecutilsError::client(format!(
-                "Private key ('{id}') is not found."
-            )));
+            bail!(
+                SecutilsError::client(format!("Private key ('{id}') is not found."))
+                    .with_code(ErrorCode::PrivateKeyNotFound)
+            );
         };
 
         // Try to decrypt private key using the provided passphrase.
@@ -300,9 +305,10 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> CertificatesApiExt<'a, DR, ET> {
         }
 
         let Some(certificate_template) = self.get_certificate_template(user_id, id).await? else {
-            bail!(SecutilsError::client(format!(
-                "Certificate template ('{id}') is not found."
-            )));
+            bail!(
+                SecutilsError::client(format!("Certificate template ('{id}') is not found."))
+                    .with_code(ErrorCode::CertificateTemplateNotFound)
+            );
         };
 
         // Preserve timestamp only up to seconds.
@@ -352,12 +358,92 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> CertificatesApiExt<'a, DR, ET> {
         template_id: Uuid,
         params: TemplatesGenerateParams,
     ) -> anyhow::Result<Vec<u8>> {
+        let (certificate_template, certificate, private_key) =
+            self.generate_key_pair(user_id, template_id).await?;
+        Ok(match params.format {
+            ExportFormat::Pem => Self::export_key_pair_to_pem_archive(
+                certificate,
+                private_key,
+                params.passphrase.as_deref(),
+            )?,
+            ExportFormat::Pkcs8 => {
+                Self::export_private_key_to_pkcs8(private_key, params.passphrase.as_deref())?
+            }
+            ExportFormat::Pkcs12 => Self::export_key_pair_to_pkcs12(
+                &certificate_template.name,
+                &private_key,
+                &certificate,
+                params.passphrase.as_deref(),
+            )?,
+        })
+    }
+
+    /// Generates private key and certificate pair from the certificate template and bundles the
+    /// selected artifacts into a ZIP archive, optionally with a README that describes every file.
+    pub async fn generate_self_signed_certificate_archive(
+        &self,
+        user_id: UserId,
+        template_id: Uuid,
+        params: TemplatesGenerateArchiveParams,
+    ) -> anyhow::Result<Vec<u8>> {
+        if params.artifacts.is_empty() {
+            bail!(SecutilsError::client(
+                "At least one certificate artifact should be selected."
+            ));
+        }
+
+        let (certificate_template, certificate, private_key) =
+            self.generate_key_pair(user_id, template_id).await?;
+
+        let artifacts = params.artifacts.into_iter().collect::<BTreeSet<_>>();
+        let passphrase = params.passphrase.as_deref();
+
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        for artifact in &artifacts {
+            zip.start_file(artifact.file_name(), options)?;
+            zip.write_all(&match artifact {
+                ExportArtifact::Certificate | ExportArtifact::Chain => certificate.to_pem()?,
+                ExportArtifact::PrivateKey => {
+                    Self::export_private_key_to_pem(private_key.clone(), passphrase)?
+                }
+                ExportArtifact::Pkcs12 => Self::export_key_pair_to_pkcs12(
+                    &certificate_template.name,
+                    &private_key,
+                    &certificate,
+                    passphrase,
+                )?,
+            })?;
+        }
+
+        if params.readme {
+            zip.start_file("README.txt", options)?;
+            zip.write_all(
+                Self::create_archive_readme(
+                    &certificate_template,
+                    &artifacts,
+                    passphrase.is_some(),
+                )
+                .as_bytes(),
+            )?;
+        }
+
+        Ok(zip.finish()?.into_inner())
+    }
+
+    /// Generates private key and certificate pair from the certificate template.
+    async fn generate_key_pair(
+        &self,
+        user_id: UserId,
+        template_id: Uuid,
+    ) -> anyhow::Result<(CertificateTemplate, X509, PKey<Private>)> {
         let Some(certificate_template) =
             self.get_certificate_template(user_id, template_id).await?
         else {
             bail!(SecutilsError::client(format!(
                 "Certificate template ('{template_id}') is not found."
-            )));
+            ))
+            .with_code(ErrorCode::CertificateTemplateNotFound));
         };
 
         // Create X509 certificate builder pre-filled with the specified template properties.
@@ -375,23 +461,11 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> CertificatesApiExt<'a, DR, ET> {
             )?,
         )?;
 
-        let certificate = certificate_builder.build();
-        Ok(match params.format {
-            ExportFormat::Pem => Self::export_key_pair_to_pem_archive(
-                certificate,
-                private_key,
-                params.passphrase.as_deref(),
-            )?,
-            ExportFormat::Pkcs8 => {
-                Self::export_private_key_to_pkcs8(private_key, params.passphrase.as_deref())?
-            }
-            ExportFormat::Pkcs12 => Self::export_key_pair_to_pkcs12(
-                &certificate_template.name,
-                &private_key,
-                &certificate,
-                params.passphrase.as_deref(),
-            )?,
-        })
+        Ok((
+            certificate_template,
+            certificate_builder.build(),
+            private_key,
+        ))
     }
 
     /// Retrieves all certificate templates that belong to the specified user.
@@ -431,7 +505,8 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> CertificatesApiExt<'a, DR, ET> {
         {
             bail!(SecutilsError::client(format!(
                 "Certificate template ('{template_id}') is not found."
-            )));
+            ))
+            .with_code(ErrorCode::CertificateTemplateNotFound));
         }
 
         // Create new user share.
@@ -569,6 +644,37 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> CertificatesApiExt<'a, DR, ET> {
         Ok(zip_buffer[..size].to_vec())
     }
 
+    /// Creates a README for the certificate archive that describes every included artifact. It
+    /// intentionally doesn't include any key material or passphrases.
+    fn create_archive_readme(
+        certificate_template: &CertificateTemplate,
+        artifacts: &BTreeSet<ExportArtifact>,
+        is_encrypted: bool,
+    ) -> String {
+        let mut readme = format!(
+            "Certificate and private key generated from the \"{}\" certificate template.\n\nFiles:\n",
+            certificate_template.name
+        );
+        for artifact in artifacts {
+            readme.push_str(&format!(
+                "  - {}: {}\n",
+                artifact.file_name(),
+                artifact.description()
+            ));
+        }
+
+        if is_encrypted
+            && (artifacts.contains(&ExportArtifact::PrivateKey)
+                || artifacts.contains(&ExportArtifact::Pkcs12))
+        {
+            readme.push_str(
+                "\nPrivate key is protected with the passphrase specified during generation.\n",
+            );
+        }
+
+        readme
+    }
+
     fn export_private_key_to_pkcs8(
         private_key: PKey<Private>,
         passphrase: Option<&str>,
@@ -784,7 +890,7 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> CertificatesApiExt<'a, DR, ET> {
 
 impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
     /// Returns an API to work with certificates utility.
-    pub fn certificates(&self) -> CertificatesApiExt<DR, ET> {
+    pub fn certificates(&self) -> CertificatesApiExt<'_, DR, ET> {
         CertificatesApiExt::new(self)
     }
 }
@@ -797,17 +903,20 @@ mod tests {
         utils::certificates::{
             api_ext::{
                 PrivateKeysExportParams, PrivateKeysUpdateParams, TemplatesCreateParams,
-                TemplatesGenerateParams, TemplatesUpdateParams,
+                TemplatesGenerateArchiveParams, TemplatesGenerateParams, TemplatesUpdateParams,
             },
-            CertificateAttributes, ExportFormat, ExtendedKeyUsage, KeyUsage, PrivateKeyAlgorithm,
-            PrivateKeyEllipticCurve, PrivateKeySize, SignatureAlgorithm, Version,
+            CertificateAttributes, ExportArtifact, ExportFormat, ExtendedKeyUsage, KeyUsage,
+            PrivateKeyAlgorithm, PrivateKeyEllipticCurve, PrivateKeySize, SignatureAlgorithm,
+            Version,
         },
     };
-    use insta::assert_debug_snapshot;
+    use insta::{assert_debug_snapshot, assert_snapshot};
     use lettre::transport::stub::AsyncStubTransport;
-    use openssl::{hash::MessageDigest, pkcs12::Pkcs12};
+    use openssl::{hash::MessageDigest, pkcs12::Pkcs12, pkey::PKey, x509::X509};
     use sqlx::PgPool;
+    use std::io::{Cursor, Read};
     use time::OffsetDateTime;
+    use zip::ZipArchive;
 
     fn get_mock_certificate_attributes() -> anyhow::Result<CertificateAttributes> {
         Ok(CertificateAttributes {
@@ -2056,6 +2165,139 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn correctly_generates_x509_certificate_archive(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let certificate_template = api
+            .certificates()
+            .create_certificate_template(
+                mock_user.id,
+                TemplatesCreateParams {
+                    template_name: "ct".to_string(),
+                    attributes: get_mock_certificate_attributes()?,
+                },
+            )
+            .await?;
+
+        let archive = api
+            .certificates()
+            .generate_self_signed_certificate_archive(
+                mock_user.id,
+                certificate_template.id,
+                TemplatesGenerateArchiveParams {
+                    artifacts: vec![
+                        ExportArtifact::Pkcs12,
+                        ExportArtifact::PrivateKey,
+                        ExportArtifact::Certificate,
+                        ExportArtifact::Chain,
+                    ],
+                    passphrase: Some("secret-passphrase".to_string()),
+                    readme: true,
+                },
+            )
+            .await?;
+
+        let mut archive = ZipArchive::new(Cursor::new(archive))?;
+        assert_eq!(
+            archive.file_names().collect::<Vec<_>>(),
+            vec![
+                "certificate.crt",
+                "chain.pem",
+                "private_key.pem",
+                "certificate.p12",
+                "README.txt"
+            ]
+        );
+        let mut read_file = |name: &str| -> anyhow::Result<Vec<u8>> {
+            let mut content = vec![];
+            archive.by_name(name)?.read_to_end(&mut content)?;
+            Ok(content)
+        };
+
+        let certificate = X509::from_pem(&read_file("certificate.crt")?)?;
+        assert_eq!(
+            X509::from_pem(&read_file("chain.pem")?)?.to_der()?,
+            certificate.to_der()?
+        );
+
+        let private_key = PKey::private_key_from_pem_passphrase(
+            &read_file("private_key.pem")?,
+            b"secret-passphrase",
+        )?;
+        assert_eq!(
+            certificate.public_key()?.public_key_to_der()?,
+            private_key.public_key_to_der()?
+        );
+
+        let imported_key_pair =
+            Pkcs12::from_der(&read_file("certificate.p12")?)?.parse2("secret-passphrase")?;
+        assert_eq!(
+            imported_key_pair.cert.unwrap().to_der()?,
+            certificate.to_der()?
+        );
+
+        // README describes the files, but doesn't leak any secrets.
+        let readme = String::from_utf8(read_file("README.txt")?)?;
+        assert!(!readme.contains("secret-passphrase"));
+        assert_snapshot!(readme, @r###"
+        Certificate and private key generated from the "ct" certificate template.
+
+        Files:
+          - certificate.crt: X.509 certificate in PEM format.
+          - chain.pem: Certificate chain in PEM format (the certificate is self-signed, so the chain consists of the certificate only).
+          - private_key.pem: Private key in PEM-encoded PKCS #8 format.
+          - certificate.p12: Private key and certificate bundled into a PKCS #12 archive.
+
+        Private key is protected with the passphrase specified during generation.
+        "###);
+
+        // Only selected artifacts are included.
+        let archive = api
+            .certificates()
+            .generate_self_signed_certificate_archive(
+                mock_user.id,
+                certificate_template.id,
+                TemplatesGenerateArchiveParams {
+                    artifacts: vec![ExportArtifact::Certificate],
+                    passphrase: None,
+                    readme: false,
+                },
+            )
+            .await?;
+        assert_eq!(
+            ZipArchive::new(Cursor::new(archive))?
+                .file_names()
+                .collect::<Vec<_>>(),
+            vec!["certificate.crt"]
+        );
+
+        // At least one artifact should be selected.
+        assert_debug_snapshot!(
+            api.certificates()
+                .generate_self_signed_certificate_archive(
+                    mock_user.id,
+                    certificate_template.id,
+                    TemplatesGenerateArchiveParams {
+                        artifacts: vec![],
+                        passphrase: None,
+                        readme: true,
+                    },
+                )
+                .await,
+            @r###"
+        Err(
+            "At least one certificate artifact should be selected.",
+        )
+        "###
+        );
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn properly_shares_certificate_template(pool: PgPool) -> anyhow::Result<()> {
         let api = mock_api(pool).await?;
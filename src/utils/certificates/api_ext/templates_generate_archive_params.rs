@@ -0,0 +1,57 @@
+use crate::utils::certificates::ExportArtifact;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplatesGenerateArchiveParams {
+    pub artifacts: Vec<ExportArtifact>,
+    pub passphrase: Option<String>,
+    #[serde(default)]
+    pub readme: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::certificates::{api_ext::TemplatesGenerateArchiveParams, ExportArtifact};
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<TemplatesGenerateArchiveParams>(
+                r#"
+        {
+            "artifacts": ["certificate"]
+        }
+                  "#
+            )?,
+            TemplatesGenerateArchiveParams {
+                artifacts: vec![ExportArtifact::Certificate],
+                passphrase: None,
+                readme: false,
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<TemplatesGenerateArchiveParams>(
+                r#"
+        {
+            "artifacts": ["certificate", "chain", "privateKey", "pkcs12"],
+            "passphrase": "phrase",
+            "readme": true
+        }
+                  "#
+            )?,
+            TemplatesGenerateArchiveParams {
+                artifacts: vec![
+                    ExportArtifact::Certificate,
+                    ExportArtifact::Chain,
+                    ExportArtifact::PrivateKey,
+                    ExportArtifact::Pkcs12
+                ],
+                passphrase: Some("phrase".to_string()),
+                readme: true,
+            }
+        );
+
+        Ok(())
+    }
+}
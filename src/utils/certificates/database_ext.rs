@@ -6,7 +6,7 @@ mod raw_private_key_algorithm;
 use self::raw_private_key::RawPrivateKey;
 use crate::{
     database::Database,
-    error::Error as SecutilsError,
+    error::{Error as SecutilsError, ErrorCode},
     users::UserId,
     utils::certificates::{
         database_ext::raw_certificate_template::RawCertificateTemplate, CertificateTemplate,
@@ -83,6 +83,7 @@ VALUES ( $1, $2, $3, $4, $5, $6, $7, $8 )
                     "Private key ('{}') already exists.",
                     private_key.name
                 )))
+                .with_code(ErrorCode::AlreadyExists)
             } else {
                 SecutilsError::from(anyhow!(err).context(format!(
                     "Couldn't create private key ('{}') due to unknown reason.",
@@ -123,7 +124,8 @@ WHERE user_id = $1 AND id = $2
                     bail!(SecutilsError::client(format!(
                         "A private key ('{}') doesn't exist.",
                         private_key.name
-                    )));
+                    ))
+                    .with_code(ErrorCode::PrivateKeyNotFound));
                 }
             }
             Err(err) => {
@@ -136,6 +138,7 @@ WHERE user_id = $1 AND id = $2
                         "Private key ('{}') already exists.",
                         private_key.name
                     )))
+                    .with_code(ErrorCode::AlreadyExists)
                 } else {
                     SecutilsError::from(anyhow!(err).context(format!(
                         "Couldn't update private key ('{}') due to unknown reason.",
@@ -243,6 +246,7 @@ VALUES ( $1, $2, $3, $4, $5, $6 )
                     "Certificate template ('{}') already exists.",
                     certificate_template.name
                 )))
+                .with_code(ErrorCode::AlreadyExists)
             } else {
                 SecutilsError::from(anyhow!(err).context(format!(
                     "Couldn't create certificate template ('{}') due to unknown reason.",
@@ -282,7 +286,8 @@ WHERE user_id = $1 AND id = $2
                     bail!(SecutilsError::client(format!(
                         "A certificate template ('{}') doesn't exist.",
                         certificate_template.name
-                    )));
+                    ))
+                    .with_code(ErrorCode::CertificateTemplateNotFound));
                 }
             }
             Err(err) => {
@@ -295,6 +300,7 @@ WHERE user_id = $1 AND id = $2
                         "Certificate template ('{}') already exists.",
                         certificate_template.name
                     )))
+                    .with_code(ErrorCode::AlreadyExists)
                 } else {
                     SecutilsError::from(anyhow!(err).context(format!(
                         "Couldn't update certificate template ('{}') due to unknown reason.",
@@ -356,7 +362,7 @@ ORDER BY updated_at
 
 impl Database {
     /// Returns a database extension for the certificate utility-related operations.
-    pub fn certificates(&self) -> CertificatesDatabaseExt {
+    pub fn certificates(&self) -> CertificatesDatabaseExt<'_> {
         CertificatesDatabaseExt::new(&self.pool)
     }
 }
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+/// Defines an artifact that can be included into the archive with the generated certificate and
+/// private key.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportArtifact {
+    /// X.509 certificate in PEM format.
+    Certificate,
+    /// Certificate chain in PEM format. Since generated certificates are self-signed, the chain
+    /// consists of the certificate only.
+    Chain,
+    /// Private key in PEM-encoded PKCS #8 format.
+    PrivateKey,
+    /// Private key and certificate bundled into a PKCS #12 archive.
+    Pkcs12,
+}
+
+impl ExportArtifact {
+    /// Returns the name of the file the artifact is stored to within the archive.
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::Certificate => "certificate.crt",
+            Self::Chain => "chain.pem",
+            Self::PrivateKey => "private_key.pem",
+            Self::Pkcs12 => "certificate.p12",
+        }
+    }
+
+    /// Returns a human-readable description of the artifact.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Certificate => "X.509 certificate in PEM format.",
+            Self::Chain => "Certificate chain in PEM format (the certificate is self-signed, so the chain consists of the certificate only).",
+            Self::PrivateKey => "Private key in PEM-encoded PKCS #8 format.",
+            Self::Pkcs12 => "Private key and certificate bundled into a PKCS #12 archive.",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::certificates::ExportArtifact;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(ExportArtifact::Certificate, @r###""certificate""###);
+        assert_json_snapshot!(ExportArtifact::Chain, @r###""chain""###);
+        assert_json_snapshot!(ExportArtifact::PrivateKey, @r###""privateKey""###);
+        assert_json_snapshot!(ExportArtifact::Pkcs12, @r###""pkcs12""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<ExportArtifact>(r#""certificate""#)?,
+            ExportArtifact::Certificate
+        );
+        assert_eq!(
+            serde_json::from_str::<ExportArtifact>(r#""chain""#)?,
+            ExportArtifact::Chain
+        );
+        assert_eq!(
+            serde_json::from_str::<ExportArtifact>(r#""privateKey""#)?,
+            ExportArtifact::PrivateKey
+        );
+        assert_eq!(
+            serde_json::from_str::<ExportArtifact>(r#""pkcs12""#)?,
+            ExportArtifact::Pkcs12
+        );
+
+        Ok(())
+    }
+}
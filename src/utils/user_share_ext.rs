@@ -21,7 +21,11 @@ impl UserShare {
                         },
                     ) => {
                         template_id == resource_id
-                            && operation == &UtilsResourceOperation::CertificatesTemplateGenerate
+                            && matches!(
+                                operation,
+                                UtilsResourceOperation::CertificatesTemplateGenerate
+                                    | UtilsResourceOperation::CertificatesTemplateGenerateArchive
+                            )
                     }
                     _ => false,
                 }
@@ -159,6 +163,10 @@ mod tests {
                 resource_id: Some(template_id),
                 operation: UtilsResourceOperation::CertificatesTemplateGenerate,
             },
+            UtilsAction::Execute {
+                resource_id: Some(template_id),
+                operation: UtilsResourceOperation::CertificatesTemplateGenerateArchive,
+            },
         ];
         for action in authorized_actions.iter() {
             assert!(user_share.is_action_authorized(action, &UtilsResource::CertificatesTemplates));
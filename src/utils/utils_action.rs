@@ -37,6 +37,41 @@ impl UtilsAction {
             UtilsAction::Execute { operation, .. } => operation.requires_params(),
         }
     }
+
+    /// Returns true if the action can be performed by an operator that impersonates the user.
+    /// Operators can only read user data, and execute operations that don't modify user data or
+    /// reveal user secrets.
+    pub fn is_allowed_for_impersonator(&self) -> bool {
+        match self {
+            UtilsAction::List | UtilsAction::Get { .. } => true,
+            UtilsAction::Create
+            | UtilsAction::Update { .. }
+            | UtilsAction::Delete { .. }
+            | UtilsAction::Share { .. }
+            | UtilsAction::Unshare { .. } => false,
+            UtilsAction::Execute { operation, .. } => matches!(
+                operation,
+                UtilsResourceOperation::CertificatesTemplateGenerate
+                    | UtilsResourceOperation::CertificatesTemplateGenerateArchive
+                    | UtilsResourceOperation::WebhooksRespondersGetHistory
+                    | UtilsResourceOperation::WebhooksRespondersGetStats
+                    | UtilsResourceOperation::WebhooksRespondersCheckSubdomainPrefix
+                    | UtilsResourceOperation::WebScrapingGetHistory
+                    | UtilsResourceOperation::WebScrapingGetRevision
+                    | UtilsResourceOperation::WebScrapingVerifyHistoryIntegrity
+                    | UtilsResourceOperation::WebScrapingGetEffectiveSettings
+                    | UtilsResourceOperation::WebScrapingPreviewNotification
+                    | UtilsResourceOperation::WebScrapingTestScript
+                    | UtilsResourceOperation::WebScrapingLintScript
+                    | UtilsResourceOperation::WebScrapingDiffTrackers
+                    | UtilsResourceOperation::WebScrapingAnalyzeNoise
+                    | UtilsResourceOperation::WebScrapingSimulateDiff
+                    | UtilsResourceOperation::WebScrapingGetChangesFeed
+                    | UtilsResourceOperation::WebSecurityContentSecurityPolicySerialize
+                    | UtilsResourceOperation::WebSecurityJsonWebTokenDecode
+            ),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -92,4 +127,44 @@ mod tests {
         }
         .requires_params());
     }
+
+    #[test]
+    fn properly_checks_if_action_is_allowed_for_impersonator() {
+        let resource_id = uuid!("00000000-0000-0000-0000-000000000001");
+        assert!(UtilsAction::List.is_allowed_for_impersonator());
+        assert!(UtilsAction::Get { resource_id }.is_allowed_for_impersonator());
+        assert!(!UtilsAction::Create.is_allowed_for_impersonator());
+        assert!(!UtilsAction::Update { resource_id }.is_allowed_for_impersonator());
+        assert!(!UtilsAction::Delete { resource_id }.is_allowed_for_impersonator());
+        assert!(!UtilsAction::Share { resource_id }.is_allowed_for_impersonator());
+        assert!(!UtilsAction::Unshare { resource_id }.is_allowed_for_impersonator());
+
+        for operation in [
+            UtilsResourceOperation::WebhooksRespondersGetHistory,
+            UtilsResourceOperation::WebScrapingGetHistory,
+            UtilsResourceOperation::WebScrapingGetChangesFeed,
+            UtilsResourceOperation::WebSecurityJsonWebTokenDecode,
+        ] {
+            assert!(UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation,
+            }
+            .is_allowed_for_impersonator());
+        }
+
+        for operation in [
+            UtilsResourceOperation::CertificatesPrivateKeyExport,
+            UtilsResourceOperation::WebhooksRespondersClearHistory,
+            UtilsResourceOperation::WebhooksRespondersResetCounter,
+            UtilsResourceOperation::WebScrapingClearHistory,
+            UtilsResourceOperation::WebScrapingSetWebhook,
+            UtilsResourceOperation::WebScrapingRotateWebhookSecrets,
+        ] {
+            assert!(!UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation,
+            }
+            .is_allowed_for_impersonator());
+        }
+    }
 }
@@ -3,9 +3,15 @@ pub enum UtilsResource {
     CertificatesTemplates,
     CertificatesPrivateKeys,
     WebhooksResponders,
+    WebhooksInboundEmails,
+    WebhooksResponderBasePaths,
     WebScrapingResources,
     WebScrapingContent,
+    WebScrapingScreenshots,
+    WebScrapingDnsRecords,
+    WebScrapingStatusPages,
     WebSecurityContentSecurityPolicies,
+    WebSecurityJsonWebTokens,
 }
 
 impl From<UtilsResource> for (&str, &str) {
@@ -14,9 +20,15 @@ impl From<UtilsResource> for (&str, &str) {
             UtilsResource::CertificatesTemplates => ("certificates", "templates"),
             UtilsResource::CertificatesPrivateKeys => ("certificates", "private_keys"),
             UtilsResource::WebhooksResponders => ("webhooks", "responders"),
+            UtilsResource::WebhooksInboundEmails => ("webhooks", "inbound_emails"),
+            UtilsResource::WebhooksResponderBasePaths => ("webhooks", "base_paths"),
             UtilsResource::WebScrapingResources => ("web_scraping", "resources"),
             UtilsResource::WebScrapingContent => ("web_scraping", "content"),
+            UtilsResource::WebScrapingScreenshots => ("web_scraping", "screenshots"),
+            UtilsResource::WebScrapingDnsRecords => ("web_scraping", "dns"),
+            UtilsResource::WebScrapingStatusPages => ("web_scraping", "status_pages"),
             UtilsResource::WebSecurityContentSecurityPolicies => ("web_security", "csp"),
+            UtilsResource::WebSecurityJsonWebTokens => ("web_security", "jwt"),
         }
     }
 }
@@ -29,9 +41,15 @@ impl TryFrom<(&str, &str)> for UtilsResource {
             ("certificates", "templates") => Ok(UtilsResource::CertificatesTemplates),
             ("certificates", "private_keys") => Ok(UtilsResource::CertificatesPrivateKeys),
             ("webhooks", "responders") => Ok(UtilsResource::WebhooksResponders),
+            ("webhooks", "inbound_emails") => Ok(UtilsResource::WebhooksInboundEmails),
+            ("webhooks", "base_paths") => Ok(UtilsResource::WebhooksResponderBasePaths),
             ("web_scraping", "resources") => Ok(UtilsResource::WebScrapingResources),
             ("web_scraping", "content") => Ok(UtilsResource::WebScrapingContent),
+            ("web_scraping", "screenshots") => Ok(UtilsResource::WebScrapingScreenshots),
+            ("web_scraping", "dns") => Ok(UtilsResource::WebScrapingDnsRecords),
+            ("web_scraping", "status_pages") => Ok(UtilsResource::WebScrapingStatusPages),
             ("web_security", "csp") => Ok(UtilsResource::WebSecurityContentSecurityPolicies),
+            ("web_security", "jwt") => Ok(UtilsResource::WebSecurityJsonWebTokens),
             _ => Err(()),
         }
     }
@@ -55,6 +73,14 @@ mod tests {
             UtilsResource::try_from(("webhooks", "responders")),
             Ok(UtilsResource::WebhooksResponders)
         );
+        assert_eq!(
+            UtilsResource::try_from(("webhooks", "inbound_emails")),
+            Ok(UtilsResource::WebhooksInboundEmails)
+        );
+        assert_eq!(
+            UtilsResource::try_from(("webhooks", "base_paths")),
+            Ok(UtilsResource::WebhooksResponderBasePaths)
+        );
         assert_eq!(
             UtilsResource::try_from(("web_scraping", "resources")),
             Ok(UtilsResource::WebScrapingResources)
@@ -63,17 +89,39 @@ mod tests {
             UtilsResource::try_from(("web_scraping", "content")),
             Ok(UtilsResource::WebScrapingContent)
         );
+        assert_eq!(
+            UtilsResource::try_from(("web_scraping", "screenshots")),
+            Ok(UtilsResource::WebScrapingScreenshots)
+        );
+        assert_eq!(
+            UtilsResource::try_from(("web_scraping", "dns")),
+            Ok(UtilsResource::WebScrapingDnsRecords)
+        );
+        assert_eq!(
+            UtilsResource::try_from(("web_scraping", "status_pages")),
+            Ok(UtilsResource::WebScrapingStatusPages)
+        );
         assert_eq!(
             UtilsResource::try_from(("web_security", "csp")),
             Ok(UtilsResource::WebSecurityContentSecurityPolicies)
         );
+        assert_eq!(
+            UtilsResource::try_from(("web_security", "jwt")),
+            Ok(UtilsResource::WebSecurityJsonWebTokens)
+        );
 
         assert!(UtilsResource::try_from(("certificates_", "templates")).is_err());
         assert!(UtilsResource::try_from(("certificates_", "private_keys")).is_err());
         assert!(UtilsResource::try_from(("webhooks", "_responders")).is_err());
+        assert!(UtilsResource::try_from(("webhooks", "_inbound_emails")).is_err());
+        assert!(UtilsResource::try_from(("webhooks", "_base_paths")).is_err());
         assert!(UtilsResource::try_from(("web_scraping", "_resources")).is_err());
         assert!(UtilsResource::try_from(("web_scraping", "_content")).is_err());
+        assert!(UtilsResource::try_from(("web_scraping", "_screenshots")).is_err());
+        assert!(UtilsResource::try_from(("web_scraping", "_dns")).is_err());
+        assert!(UtilsResource::try_from(("web_scraping", "_status_pages")).is_err());
         assert!(UtilsResource::try_from(("web_security", "_csp")).is_err());
+        assert!(UtilsResource::try_from(("web_security", "_jwt")).is_err());
     }
 
     #[test]
@@ -91,6 +139,14 @@ mod tests {
             ResourceTuple::from(UtilsResource::WebhooksResponders),
             ("webhooks", "responders")
         );
+        assert_eq!(
+            ResourceTuple::from(UtilsResource::WebhooksInboundEmails),
+            ("webhooks", "inbound_emails")
+        );
+        assert_eq!(
+            ResourceTuple::from(UtilsResource::WebhooksResponderBasePaths),
+            ("webhooks", "base_paths")
+        );
         assert_eq!(
             ResourceTuple::from(UtilsResource::WebScrapingResources),
             ("web_scraping", "resources")
@@ -99,9 +155,25 @@ mod tests {
             ResourceTuple::from(UtilsResource::WebScrapingContent),
             ("web_scraping", "content")
         );
+        assert_eq!(
+            ResourceTuple::from(UtilsResource::WebScrapingScreenshots),
+            ("web_scraping", "screenshots")
+        );
+        assert_eq!(
+            ResourceTuple::from(UtilsResource::WebScrapingDnsRecords),
+            ("web_scraping", "dns")
+        );
+        assert_eq!(
+            ResourceTuple::from(UtilsResource::WebScrapingStatusPages),
+            ("web_scraping", "status_pages")
+        );
         assert_eq!(
             ResourceTuple::from(UtilsResource::WebSecurityContentSecurityPolicies),
             ("web_security", "csp")
         );
+        assert_eq!(
+            ResourceTuple::from(UtilsResource::WebSecurityJsonWebTokens),
+            ("web_security", "jwt")
+        );
     }
 }
@@ -6,13 +6,35 @@ use actix_web::http::Method;
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum UtilsResourceOperation {
     CertificatesTemplateGenerate,
+    CertificatesTemplateGenerateArchive,
     CertificatesPrivateKeyExport,
     WebhooksRespondersGetHistory,
     WebhooksRespondersClearHistory,
     WebhooksRespondersGetStats,
+    WebhooksRespondersCheckSubdomainPrefix,
+    WebhooksRespondersMigrateUrlType,
+    WebhooksRespondersResetCounter,
     WebScrapingGetHistory,
     WebScrapingClearHistory,
+    WebScrapingGetRevision,
+    WebScrapingSetRevisionNote,
+    WebScrapingAcknowledgeRevision,
+    WebScrapingSetBaselineRevision,
+    WebScrapingVerifyHistoryIntegrity,
+    WebScrapingGetEffectiveSettings,
+    WebScrapingSetWebhook,
+    WebScrapingRotateWebhookSecrets,
+    WebScrapingPreviewNotification,
+    WebScrapingTestScript,
+    WebScrapingLintScript,
+    WebScrapingImportTrackers,
+    WebScrapingCreateWellKnownTrackers,
+    WebScrapingDiffTrackers,
+    WebScrapingAnalyzeNoise,
+    WebScrapingSimulateDiff,
+    WebScrapingGetChangesFeed,
     WebSecurityContentSecurityPolicySerialize,
+    WebSecurityJsonWebTokenDecode,
 }
 
 impl UtilsResourceOperation {
@@ -21,9 +43,26 @@ impl UtilsResourceOperation {
         matches!(
             self,
             Self::CertificatesTemplateGenerate
+                | Self::CertificatesTemplateGenerateArchive
                 | Self::CertificatesPrivateKeyExport
+                | Self::WebhooksRespondersCheckSubdomainPrefix
+                | Self::WebhooksRespondersMigrateUrlType
                 | Self::WebScrapingGetHistory
+                | Self::WebScrapingGetRevision
+                | Self::WebScrapingSetRevisionNote
+                | Self::WebScrapingAcknowledgeRevision
+                | Self::WebScrapingSetBaselineRevision
+                | Self::WebScrapingSetWebhook
+                | Self::WebScrapingPreviewNotification
+                | Self::WebScrapingTestScript
+                | Self::WebScrapingLintScript
+                | Self::WebScrapingImportTrackers
+                | Self::WebScrapingCreateWellKnownTrackers
+                | Self::WebScrapingDiffTrackers
+                | Self::WebScrapingSimulateDiff
+                | Self::WebScrapingGetChangesFeed
                 | Self::WebSecurityContentSecurityPolicySerialize
+                | Self::WebSecurityJsonWebTokenDecode
         )
     }
 }
@@ -44,6 +83,11 @@ impl TryFrom<(&UtilsResource, &str, &Method)> for UtilsResourceOperation {
             UtilsResource::CertificatesTemplates if operation == "generate" => {
                 Ok(UtilsResourceOperation::CertificatesTemplateGenerate)
             }
+            UtilsResource::CertificatesTemplates
+                if operation == "archive" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::CertificatesTemplateGenerateArchive)
+            }
 
             // Webhooks custom actions.
             UtilsResource::WebhooksResponders if operation == "history" => {
@@ -55,23 +99,165 @@ impl TryFrom<(&UtilsResource, &str, &Method)> for UtilsResourceOperation {
             UtilsResource::WebhooksResponders if operation == "stats" && method == Method::GET => {
                 Ok(UtilsResourceOperation::WebhooksRespondersGetStats)
             }
+            UtilsResource::WebhooksResponders
+                if operation == "subdomain_prefix" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebhooksRespondersCheckSubdomainPrefix)
+            }
+            UtilsResource::WebhooksResponders
+                if operation == "migrate_url_type" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebhooksRespondersMigrateUrlType)
+            }
+            UtilsResource::WebhooksResponders
+                if operation == "reset_counter" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebhooksRespondersResetCounter)
+            }
 
             // Web scraping custom actions.
-            UtilsResource::WebScrapingResources | UtilsResource::WebScrapingContent
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords
                 if operation == "history" =>
             {
                 Ok(UtilsResourceOperation::WebScrapingGetHistory)
             }
-            UtilsResource::WebScrapingResources | UtilsResource::WebScrapingContent
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords
                 if operation == "clear" =>
             {
                 Ok(UtilsResourceOperation::WebScrapingClearHistory)
             }
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords
+                if operation == "revision" =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingGetRevision)
+            }
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords
+                if operation == "note" =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingSetRevisionNote)
+            }
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords
+                if operation == "acknowledge" =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingAcknowledgeRevision)
+            }
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords
+                if operation == "baseline" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingSetBaselineRevision)
+            }
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords
+                if operation == "integrity" =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingVerifyHistoryIntegrity)
+            }
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords
+                if operation == "effective_settings" && method == Method::GET =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingGetEffectiveSettings)
+            }
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords
+                if operation == "webhook" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingSetWebhook)
+            }
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords
+                if operation == "rotate_webhook_secrets" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingRotateWebhookSecrets)
+            }
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords
+                if operation == "preview_notification" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingPreviewNotification)
+            }
+            UtilsResource::WebScrapingResources | UtilsResource::WebScrapingContent
+                if operation == "test_script" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingTestScript)
+            }
+            UtilsResource::WebScrapingResources | UtilsResource::WebScrapingContent
+                if operation == "lint_script" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingLintScript)
+            }
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords
+                if operation == "import" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingImportTrackers)
+            }
+            UtilsResource::WebScrapingContent
+                if operation == "well_known" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingCreateWellKnownTrackers)
+            }
+            UtilsResource::WebScrapingResources | UtilsResource::WebScrapingContent
+                if operation == "diff" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingDiffTrackers)
+            }
+            UtilsResource::WebScrapingContent if operation == "noise" && method == Method::GET => {
+                Ok(UtilsResourceOperation::WebScrapingAnalyzeNoise)
+            }
+            UtilsResource::WebScrapingContent
+                if operation == "simulate_diff" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingSimulateDiff)
+            }
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords
+                if operation == "changes_feed" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebScrapingGetChangesFeed)
+            }
 
             // Web security custom actions.
             UtilsResource::WebSecurityContentSecurityPolicies if operation == "serialize" => {
                 Ok(UtilsResourceOperation::WebSecurityContentSecurityPolicySerialize)
             }
+            UtilsResource::WebSecurityJsonWebTokens
+                if operation == "decode" && method == Method::POST =>
+            {
+                Ok(UtilsResourceOperation::WebSecurityJsonWebTokenDecode)
+            }
 
             _ => Err(()),
         }
@@ -89,17 +275,39 @@ mod tests {
         assert!(UtilsResourceOperation::CertificatesPrivateKeyExport.requires_params());
 
         assert!(UtilsResourceOperation::CertificatesTemplateGenerate.requires_params());
+        assert!(UtilsResourceOperation::CertificatesTemplateGenerateArchive.requires_params());
 
         assert!(!UtilsResourceOperation::WebhooksRespondersGetHistory.requires_params());
         assert!(!UtilsResourceOperation::WebhooksRespondersClearHistory.requires_params());
         assert!(!UtilsResourceOperation::WebhooksRespondersGetStats.requires_params());
+        assert!(UtilsResourceOperation::WebhooksRespondersCheckSubdomainPrefix.requires_params());
+        assert!(UtilsResourceOperation::WebhooksRespondersMigrateUrlType.requires_params());
+        assert!(!UtilsResourceOperation::WebhooksRespondersResetCounter.requires_params());
 
         assert!(UtilsResourceOperation::WebScrapingGetHistory.requires_params());
         assert!(!UtilsResourceOperation::WebScrapingClearHistory.requires_params());
+        assert!(UtilsResourceOperation::WebScrapingGetRevision.requires_params());
+        assert!(UtilsResourceOperation::WebScrapingSetRevisionNote.requires_params());
+        assert!(UtilsResourceOperation::WebScrapingAcknowledgeRevision.requires_params());
+        assert!(UtilsResourceOperation::WebScrapingSetBaselineRevision.requires_params());
+        assert!(!UtilsResourceOperation::WebScrapingVerifyHistoryIntegrity.requires_params());
+        assert!(!UtilsResourceOperation::WebScrapingGetEffectiveSettings.requires_params());
+        assert!(UtilsResourceOperation::WebScrapingSetWebhook.requires_params());
+        assert!(!UtilsResourceOperation::WebScrapingRotateWebhookSecrets.requires_params());
+        assert!(UtilsResourceOperation::WebScrapingPreviewNotification.requires_params());
+        assert!(UtilsResourceOperation::WebScrapingTestScript.requires_params());
+        assert!(UtilsResourceOperation::WebScrapingLintScript.requires_params());
+        assert!(UtilsResourceOperation::WebScrapingImportTrackers.requires_params());
+        assert!(UtilsResourceOperation::WebScrapingCreateWellKnownTrackers.requires_params());
+        assert!(UtilsResourceOperation::WebScrapingDiffTrackers.requires_params());
+        assert!(!UtilsResourceOperation::WebScrapingAnalyzeNoise.requires_params());
+        assert!(UtilsResourceOperation::WebScrapingSimulateDiff.requires_params());
+        assert!(UtilsResourceOperation::WebScrapingGetChangesFeed.requires_params());
 
         assert!(
             UtilsResourceOperation::WebSecurityContentSecurityPolicySerialize.requires_params()
         );
+        assert!(UtilsResourceOperation::WebSecurityJsonWebTokenDecode.requires_params());
     }
 
     #[test]
@@ -134,6 +342,21 @@ mod tests {
         ))
         .is_err());
 
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::CertificatesTemplates,
+                "archive",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::CertificatesTemplateGenerateArchive)
+        );
+        assert!(UtilsResourceOperation::try_from((
+            &UtilsResource::CertificatesTemplates,
+            "archive",
+            &Method::GET
+        ))
+        .is_err());
+
         assert!(UtilsResourceOperation::try_from((
             &UtilsResource::CertificatesPrivateKeys,
             "share",
@@ -172,6 +395,42 @@ mod tests {
             )),
             Ok(UtilsResourceOperation::WebhooksRespondersGetStats)
         );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebhooksResponders,
+                "subdomain_prefix",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebhooksRespondersCheckSubdomainPrefix)
+        );
+        assert!(UtilsResourceOperation::try_from((
+            &UtilsResource::WebhooksResponders,
+            "subdomain_prefix",
+            &Method::GET
+        ))
+        .is_err());
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebhooksResponders,
+                "migrate_url_type",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebhooksRespondersMigrateUrlType)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebhooksResponders,
+                "reset_counter",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebhooksRespondersResetCounter)
+        );
+        assert!(UtilsResourceOperation::try_from((
+            &UtilsResource::WebhooksResponders,
+            "reset_counter",
+            &Method::GET
+        ))
+        .is_err());
 
         assert_eq!(
             UtilsResourceOperation::try_from((
@@ -189,6 +448,78 @@ mod tests {
             )),
             Ok(UtilsResourceOperation::WebScrapingClearHistory)
         );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingResources,
+                "revision",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingGetRevision)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingResources,
+                "note",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingSetRevisionNote)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingResources,
+                "acknowledge",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingAcknowledgeRevision)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingResources,
+                "baseline",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingSetBaselineRevision)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingResources,
+                "integrity",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingVerifyHistoryIntegrity)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingResources,
+                "effective_settings",
+                &Method::GET
+            )),
+            Ok(UtilsResourceOperation::WebScrapingGetEffectiveSettings)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingResources,
+                "webhook",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingSetWebhook)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingResources,
+                "rotate_webhook_secrets",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingRotateWebhookSecrets)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingResources,
+                "preview_notification",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingPreviewNotification)
+        );
         assert_eq!(
             UtilsResourceOperation::try_from((
                 &UtilsResource::WebScrapingContent,
@@ -205,6 +536,348 @@ mod tests {
             )),
             Ok(UtilsResourceOperation::WebScrapingClearHistory)
         );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingContent,
+                "revision",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingGetRevision)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingContent,
+                "note",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingSetRevisionNote)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingContent,
+                "acknowledge",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingAcknowledgeRevision)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingContent,
+                "baseline",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingSetBaselineRevision)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingContent,
+                "integrity",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingVerifyHistoryIntegrity)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingContent,
+                "effective_settings",
+                &Method::GET
+            )),
+            Ok(UtilsResourceOperation::WebScrapingGetEffectiveSettings)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingContent,
+                "webhook",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingSetWebhook)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingContent,
+                "rotate_webhook_secrets",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingRotateWebhookSecrets)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingContent,
+                "preview_notification",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingPreviewNotification)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingScreenshots,
+                "history",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingGetHistory)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingScreenshots,
+                "clear",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingClearHistory)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingScreenshots,
+                "revision",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingGetRevision)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingScreenshots,
+                "note",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingSetRevisionNote)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingScreenshots,
+                "acknowledge",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingAcknowledgeRevision)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingScreenshots,
+                "baseline",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingSetBaselineRevision)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingScreenshots,
+                "integrity",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingVerifyHistoryIntegrity)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingScreenshots,
+                "effective_settings",
+                &Method::GET
+            )),
+            Ok(UtilsResourceOperation::WebScrapingGetEffectiveSettings)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingScreenshots,
+                "webhook",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingSetWebhook)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingScreenshots,
+                "rotate_webhook_secrets",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingRotateWebhookSecrets)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingScreenshots,
+                "preview_notification",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingPreviewNotification)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingDnsRecords,
+                "history",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingGetHistory)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingDnsRecords,
+                "effective_settings",
+                &Method::GET
+            )),
+            Ok(UtilsResourceOperation::WebScrapingGetEffectiveSettings)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingResources,
+                "test_script",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingTestScript)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingContent,
+                "test_script",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingTestScript)
+        );
+        assert!(UtilsResourceOperation::try_from((
+            &UtilsResource::WebScrapingScreenshots,
+            "test_script",
+            &Method::POST
+        ))
+        .is_err());
+        assert!(UtilsResourceOperation::try_from((
+            &UtilsResource::WebScrapingDnsRecords,
+            "test_script",
+            &Method::POST
+        ))
+        .is_err());
+        for resource in [
+            UtilsResource::WebScrapingResources,
+            UtilsResource::WebScrapingContent,
+        ] {
+            assert_eq!(
+                UtilsResourceOperation::try_from((&resource, "lint_script", &Method::POST)),
+                Ok(UtilsResourceOperation::WebScrapingLintScript)
+            );
+        }
+        assert!(UtilsResourceOperation::try_from((
+            &UtilsResource::WebScrapingScreenshots,
+            "lint_script",
+            &Method::POST
+        ))
+        .is_err());
+        for resource in [
+            UtilsResource::WebScrapingResources,
+            UtilsResource::WebScrapingContent,
+        ] {
+            assert_eq!(
+                UtilsResourceOperation::try_from((&resource, "diff", &Method::POST)),
+                Ok(UtilsResourceOperation::WebScrapingDiffTrackers)
+            );
+            assert!(UtilsResourceOperation::try_from((&resource, "diff", &Method::GET)).is_err());
+        }
+        for resource in [
+            UtilsResource::WebScrapingScreenshots,
+            UtilsResource::WebScrapingDnsRecords,
+        ] {
+            assert!(UtilsResourceOperation::try_from((&resource, "diff", &Method::POST)).is_err());
+        }
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingContent,
+                "noise",
+                &Method::GET
+            )),
+            Ok(UtilsResourceOperation::WebScrapingAnalyzeNoise)
+        );
+        assert!(UtilsResourceOperation::try_from((
+            &UtilsResource::WebScrapingContent,
+            "noise",
+            &Method::POST
+        ))
+        .is_err());
+        assert!(UtilsResourceOperation::try_from((
+            &UtilsResource::WebScrapingResources,
+            "noise",
+            &Method::GET
+        ))
+        .is_err());
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingContent,
+                "simulate_diff",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingSimulateDiff)
+        );
+        assert!(UtilsResourceOperation::try_from((
+            &UtilsResource::WebScrapingContent,
+            "simulate_diff",
+            &Method::GET
+        ))
+        .is_err());
+        assert!(UtilsResourceOperation::try_from((
+            &UtilsResource::WebScrapingResources,
+            "simulate_diff",
+            &Method::POST
+        ))
+        .is_err());
+        for resource in [
+            UtilsResource::WebScrapingResources,
+            UtilsResource::WebScrapingContent,
+            UtilsResource::WebScrapingScreenshots,
+            UtilsResource::WebScrapingDnsRecords,
+        ] {
+            assert_eq!(
+                UtilsResourceOperation::try_from((&resource, "changes_feed", &Method::POST)),
+                Ok(UtilsResourceOperation::WebScrapingGetChangesFeed)
+            );
+            assert!(
+                UtilsResourceOperation::try_from((&resource, "changes_feed", &Method::GET))
+                    .is_err()
+            );
+        }
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingResources,
+                "import",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingImportTrackers)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingContent,
+                "import",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingImportTrackers)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingScreenshots,
+                "import",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingImportTrackers)
+        );
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingDnsRecords,
+                "import",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingImportTrackers)
+        );
+        assert!(UtilsResourceOperation::try_from((
+            &UtilsResource::WebScrapingResources,
+            "import",
+            &Method::GET
+        ))
+        .is_err());
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebScrapingContent,
+                "well_known",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebScrapingCreateWellKnownTrackers)
+        );
+        assert!(UtilsResourceOperation::try_from((
+            &UtilsResource::WebScrapingResources,
+            "well_known",
+            &Method::POST
+        ))
+        .is_err());
         assert!(UtilsResourceOperation::try_from((
             &UtilsResource::CertificatesPrivateKeys,
             "history",
@@ -226,5 +899,20 @@ mod tests {
             &Method::POST
         ))
         .is_err());
+
+        assert_eq!(
+            UtilsResourceOperation::try_from((
+                &UtilsResource::WebSecurityJsonWebTokens,
+                "decode",
+                &Method::POST
+            )),
+            Ok(UtilsResourceOperation::WebSecurityJsonWebTokenDecode)
+        );
+        assert!(UtilsResourceOperation::try_from((
+            &UtilsResource::WebSecurityJsonWebTokens,
+            "decode",
+            &Method::GET
+        ))
+        .is_err());
     }
 }
@@ -1,17 +1,51 @@
 mod api_ext;
 mod database_ext;
+mod status_pages;
 mod web_page_trackers;
 
-pub use self::web_page_trackers::{
-    web_page_content_revisions_diff, web_page_resources_revisions_diff, WebPageContentTrackerTag,
-    WebPageDataRevision, WebPageResource, WebPageResourceContent, WebPageResourceContentData,
-    WebPageResourceDiffStatus, WebPageResourcesData, WebPageResourcesTrackerTag, WebPageTracker,
-    WebPageTrackerKind, WebPageTrackerSettings, WebPageTrackerTag, WebScraperContentRequest,
-    WebScraperContentRequestScripts, WebScraperContentResponse, WebScraperErrorResponse,
-    WebScraperResource, WebScraperResourcesRequest, WebScraperResourcesRequestScripts,
-    WebScraperResourcesResponse,
+pub use self::{
+    api_ext::WebPageTrackerExportHistoryParams,
+    status_pages::WebPageStatusPage,
+    web_page_trackers::{
+        web_page_content_revisions_diff, web_page_content_revisions_noise,
+        web_page_content_truncate_diff, web_page_dns_records_revisions_diff,
+        web_page_resources_revisions_diff, web_page_screenshot_revisions_diff,
+        web_page_screenshots_diff, WebPageContentAssertion, WebPageContentAssertionOperator,
+        WebPageContentCookiesCapture, WebPageContentDiffAlgorithm, WebPageContentFingerprint,
+        WebPageContentFormat, WebPageContentNoiseAnalysis, WebPageContentNoiseRegion,
+        WebPageContentResponse, WebPageContentResponseCapture, WebPageContentResponsePart,
+        WebPageContentSimulatedDiff, WebPageContentStatus, WebPageContentStatuses,
+        WebPageContentStructure, WebPageContentTrackerTag, WebPageContentWithCookies,
+        WebPageCookie, WebPageDataRevision, WebPageDataRevisionAcknowledgment,
+        WebPageDataRevisionDiffStats, WebPageDnsRecord, WebPageDnsRecordDiffStatus,
+        WebPageDnsRecordType, WebPageDnsRecordsData, WebPageDnsRecordsTrackerTag, WebPageResource,
+        WebPageResourceCategory, WebPageResourceContent, WebPageResourceContentData,
+        WebPageResourceDiffStatus, WebPageResourceType, WebPageResourcesData,
+        WebPageResourcesSizeBudget, WebPageResourcesSizes, WebPageResourcesTrackerTag,
+        WebPageScreenshotData, WebPageScreenshotTrackerTag, WebPageTracker, WebPageTrackerChange,
+        WebPageTrackerDataClassification, WebPageTrackerDataPolicy,
+        WebPageTrackerEffectiveSettings, WebPageTrackerHistoryIntegrity,
+        WebPageTrackerImportResult, WebPageTrackerKind, WebPageTrackerPendingScrape,
+        WebPageTrackerPrecondition, WebPageTrackerPreconditionOperator,
+        WebPageTrackerScriptTestError, WebPageTrackerScriptTestResult, WebPageTrackerSettings,
+        WebPageTrackerTag, WebPageTrackerWebhook, WebPageTrackerWebhookSecret,
+        WebPageWellKnownFile, WebScraperCallbackResult, WebScraperCircuitBreaker,
+        WebScraperContentRequest, WebScraperContentRequestScripts, WebScraperContentResponse,
+        WebScraperErrorResponse, WebScraperResource, WebScraperResourcesRequest,
+        WebScraperResourcesRequestScripts, WebScraperResourcesResponse,
+        WebScraperScreenshotRequest, WebScraperScreenshotResponse,
+    },
+};
+use self::{
+    api_ext::{
+        WebPageTrackerAcknowledgeRevisionParams, WebPageTrackerGetRevisionParams,
+        WebPageTrackerSetBaselineRevisionParams, WebPageTrackerSetRevisionNoteParams,
+    },
+    web_page_trackers::{
+        WebPageDataRevisionChainLink, WebPageResourceInternal, WebPageResourcesTrackerInternalTag,
+        WebScraperCallback, WebScraperClient, WebScraperJobResponse,
+    },
 };
-use self::web_page_trackers::{WebPageResourceInternal, WebPageResourcesTrackerInternalTag};
 use crate::{
     api::Api,
     error::Error as SecutilsError,
@@ -46,6 +80,12 @@ pub async fn web_scraping_handle_action<DR: DnsResolver, ET: EmailTransport>(
         (UtilsResource::WebScrapingContent, UtilsAction::List) => {
             UtilsActionResult::json(web_scraping.get_content_trackers().await?)
         }
+        (UtilsResource::WebScrapingScreenshots, UtilsAction::List) => {
+            UtilsActionResult::json(web_scraping.get_screenshot_trackers().await?)
+        }
+        (UtilsResource::WebScrapingDnsRecords, UtilsAction::List) => {
+            UtilsActionResult::json(web_scraping.get_dns_records_trackers().await?)
+        }
         (UtilsResource::WebScrapingResources, UtilsAction::Create) => UtilsActionResult::json(
             web_scraping
                 .create_resources_tracker(extract_params(params)?)
@@ -56,6 +96,16 @@ pub async fn web_scraping_handle_action<DR: DnsResolver, ET: EmailTransport>(
                 .create_content_tracker(extract_params(params)?)
                 .await?,
         ),
+        (UtilsResource::WebScrapingScreenshots, UtilsAction::Create) => UtilsActionResult::json(
+            web_scraping
+                .create_screenshot_tracker(extract_params(params)?)
+                .await?,
+        ),
+        (UtilsResource::WebScrapingDnsRecords, UtilsAction::Create) => UtilsActionResult::json(
+            web_scraping
+                .create_dns_records_tracker(extract_params(params)?)
+                .await?,
+        ),
         (UtilsResource::WebScrapingResources, UtilsAction::Update { resource_id }) => {
             web_scraping
                 .update_resources_tracker(resource_id, extract_params(params)?)
@@ -68,47 +118,539 @@ pub async fn web_scraping_handle_action<DR: DnsResolver, ET: EmailTransport>(
                 .await?;
             Ok(UtilsActionResult::empty())
         }
+        (UtilsResource::WebScrapingScreenshots, UtilsAction::Update { resource_id }) => {
+            web_scraping
+                .update_screenshot_tracker(resource_id, extract_params(params)?)
+                .await?;
+            Ok(UtilsActionResult::empty())
+        }
+        (UtilsResource::WebScrapingDnsRecords, UtilsAction::Update { resource_id }) => {
+            web_scraping
+                .update_dns_records_tracker(resource_id, extract_params(params)?)
+                .await?;
+            Ok(UtilsActionResult::empty())
+        }
         (
-            UtilsResource::WebScrapingResources | UtilsResource::WebScrapingContent,
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords,
             UtilsAction::Delete { resource_id },
         ) => {
             web_scraping.remove_web_page_tracker(resource_id).await?;
             Ok(UtilsActionResult::empty())
         }
+        (UtilsResource::WebScrapingStatusPages, UtilsAction::List) => {
+            UtilsActionResult::json(web_scraping.get_status_pages().await?)
+        }
+        (UtilsResource::WebScrapingStatusPages, UtilsAction::Create) => UtilsActionResult::json(
+            web_scraping
+                .create_status_page(extract_params(params)?)
+                .await?,
+        ),
+        (UtilsResource::WebScrapingStatusPages, UtilsAction::Update { resource_id }) => {
+            UtilsActionResult::json(
+                web_scraping
+                    .update_status_page(resource_id, extract_params(params)?)
+                    .await?,
+            )
+        }
+        (UtilsResource::WebScrapingStatusPages, UtilsAction::Delete { resource_id }) => {
+            web_scraping.remove_status_page(resource_id).await?;
+            Ok(UtilsActionResult::empty())
+        }
+        (
+            UtilsResource::WebScrapingResources,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingGetHistory,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .get_resources_tracker_history(resource_id, extract_params(params)?)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingContent,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingGetHistory,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .get_content_tracker_history(resource_id, extract_params(params)?)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingScreenshots,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingGetHistory,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .get_screenshot_tracker_history(resource_id, extract_params(params)?)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingDnsRecords,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingGetHistory,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .get_dns_records_tracker_history(resource_id, extract_params(params)?)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingClearHistory,
+            },
+        ) => {
+            web_scraping
+                .clear_web_page_tracker_history(resource_id)
+                .await?;
+            Ok(UtilsActionResult::empty())
+        }
+        (
+            UtilsResource::WebScrapingResources,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingGetRevision,
+            },
+        ) => {
+            let params = extract_params::<WebPageTrackerGetRevisionParams>(params)?;
+            UtilsActionResult::json(
+                web_scraping
+                    .get_resources_tracker_revision(resource_id, params.revision_id)
+                    .await?,
+            )
+        }
+        (
+            UtilsResource::WebScrapingContent,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingGetRevision,
+            },
+        ) => {
+            let params = extract_params::<WebPageTrackerGetRevisionParams>(params)?;
+            UtilsActionResult::json(
+                web_scraping
+                    .get_content_tracker_revision(resource_id, params.revision_id)
+                    .await?,
+            )
+        }
+        (
+            UtilsResource::WebScrapingScreenshots,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingGetRevision,
+            },
+        ) => {
+            let params = extract_params::<WebPageTrackerGetRevisionParams>(params)?;
+            UtilsActionResult::json(
+                web_scraping
+                    .get_screenshot_tracker_revision(resource_id, params.revision_id)
+                    .await?,
+            )
+        }
+        (
+            UtilsResource::WebScrapingDnsRecords,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingGetRevision,
+            },
+        ) => {
+            let params = extract_params::<WebPageTrackerGetRevisionParams>(params)?;
+            UtilsActionResult::json(
+                web_scraping
+                    .get_dns_records_tracker_revision(resource_id, params.revision_id)
+                    .await?,
+            )
+        }
+        (
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingSetRevisionNote,
+            },
+        ) => {
+            let params = extract_params::<WebPageTrackerSetRevisionNoteParams>(params)?;
+            web_scraping
+                .set_revision_note(resource_id, params.revision_id, params.note)
+                .await?;
+            Ok(UtilsActionResult::empty())
+        }
+        (
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingAcknowledgeRevision,
+            },
+        ) => {
+            let params = extract_params::<WebPageTrackerAcknowledgeRevisionParams>(params)?;
+            UtilsActionResult::json(
+                web_scraping
+                    .acknowledge_revision(resource_id, params.revision_id)
+                    .await?,
+            )
+        }
+        (
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingSetBaselineRevision,
+            },
+        ) => {
+            let params = extract_params::<WebPageTrackerSetBaselineRevisionParams>(params)?;
+            web_scraping
+                .set_baseline_revision(resource_id, params.revision_id)
+                .await?;
+            Ok(UtilsActionResult::empty())
+        }
+        (
+            UtilsResource::WebScrapingResources,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingVerifyHistoryIntegrity,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .verify_history_integrity::<WebPageResourcesTrackerTag>(resource_id)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingContent,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingVerifyHistoryIntegrity,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .verify_history_integrity::<WebPageContentTrackerTag>(resource_id)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingScreenshots,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingVerifyHistoryIntegrity,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .verify_history_integrity::<WebPageScreenshotTrackerTag>(resource_id)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingDnsRecords,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingVerifyHistoryIntegrity,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .verify_history_integrity::<WebPageDnsRecordsTrackerTag>(resource_id)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingResources,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingGetEffectiveSettings,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .get_resources_tracker_effective_settings(resource_id)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingContent,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingGetEffectiveSettings,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .get_content_tracker_effective_settings(resource_id)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingScreenshots,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingGetEffectiveSettings,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .get_screenshot_tracker_effective_settings(resource_id)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingDnsRecords,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingGetEffectiveSettings,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .get_dns_records_tracker_effective_settings(resource_id)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingSetWebhook,
+            },
+        ) => {
+            web_scraping
+                .set_web_page_tracker_webhook(resource_id, extract_params(params)?)
+                .await?;
+            Ok(UtilsActionResult::empty())
+        }
+        (
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords,
+            UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingRotateWebhookSecrets,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .rotate_web_page_tracker_webhook_secrets()
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingResources,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingPreviewNotification,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .preview_tracker_notification::<WebPageResourcesTrackerTag>(
+                    resource_id,
+                    extract_params(params)?,
+                )
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingContent,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingPreviewNotification,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .preview_tracker_notification::<WebPageContentTrackerTag>(
+                    resource_id,
+                    extract_params(params)?,
+                )
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingScreenshots,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingPreviewNotification,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .preview_tracker_notification::<WebPageScreenshotTrackerTag>(
+                    resource_id,
+                    extract_params(params)?,
+                )
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingDnsRecords,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingPreviewNotification,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .preview_tracker_notification::<WebPageDnsRecordsTrackerTag>(
+                    resource_id,
+                    extract_params(params)?,
+                )
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingResources,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingDiffTrackers,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .diff_resources_trackers(resource_id, extract_params(params)?)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingContent,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingDiffTrackers,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .diff_content_trackers(resource_id, extract_params(params)?)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingContent,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebScrapingAnalyzeNoise,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .analyze_content_tracker_noise(resource_id)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingContent,
+            UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingSimulateDiff,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .simulate_content_diff(extract_params(params)?)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingResources
+            | UtilsResource::WebScrapingContent
+            | UtilsResource::WebScrapingScreenshots
+            | UtilsResource::WebScrapingDnsRecords,
+            UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingGetChangesFeed,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .get_changes_feed(extract_params(params)?)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingResources,
+            UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingTestScript,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .test_tracker_script::<WebPageResourcesTrackerTag>(extract_params(params)?)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingContent,
+            UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingTestScript,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .test_tracker_script::<WebPageContentTrackerTag>(extract_params(params)?)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingResources,
+            UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingLintScript,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .lint_tracker_script::<WebPageResourcesTrackerTag>(extract_params(params)?)?,
+        ),
+        (
+            UtilsResource::WebScrapingContent,
+            UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingLintScript,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .lint_tracker_script::<WebPageContentTrackerTag>(extract_params(params)?)?,
+        ),
         (
             UtilsResource::WebScrapingResources,
             UtilsAction::Execute {
-                resource_id: Some(resource_id),
-                operation: UtilsResourceOperation::WebScrapingGetHistory,
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingImportTrackers,
             },
         ) => UtilsActionResult::json(
             web_scraping
-                .get_resources_tracker_history(resource_id, extract_params(params)?)
+                .import_resources_trackers(extract_params(params)?)
                 .await?,
         ),
         (
             UtilsResource::WebScrapingContent,
             UtilsAction::Execute {
-                resource_id: Some(resource_id),
-                operation: UtilsResourceOperation::WebScrapingGetHistory,
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingImportTrackers,
             },
         ) => UtilsActionResult::json(
             web_scraping
-                .get_content_tracker_history(resource_id, extract_params(params)?)
+                .import_content_trackers(extract_params(params)?)
                 .await?,
         ),
         (
-            UtilsResource::WebScrapingResources | UtilsResource::WebScrapingContent,
+            UtilsResource::WebScrapingScreenshots,
             UtilsAction::Execute {
-                resource_id: Some(resource_id),
-                operation: UtilsResourceOperation::WebScrapingClearHistory,
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingImportTrackers,
             },
-        ) => {
+        ) => UtilsActionResult::json(
             web_scraping
-                .clear_web_page_tracker_history(resource_id)
-                .await?;
-            Ok(UtilsActionResult::empty())
-        }
+                .import_screenshot_trackers(extract_params(params)?)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingDnsRecords,
+            UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingImportTrackers,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .import_dns_records_trackers(extract_params(params)?)
+                .await?,
+        ),
+        (
+            UtilsResource::WebScrapingContent,
+            UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingCreateWellKnownTrackers,
+            },
+        ) => UtilsActionResult::json(
+            web_scraping
+                .create_well_known_content_trackers(extract_params(params)?)
+                .await?,
+        ),
         _ => Err(SecutilsError::client("Invalid resource or action.").into()),
     }
 }
@@ -129,15 +671,17 @@ pub mod tests {
                 },
                 web_scraping_handle_action, WebPageContentTrackerTag, WebPageDataRevision,
                 WebPageResourceInternal, WebPageResourcesData, WebPageResourcesTrackerInternalTag,
-                WebPageTracker, WebPageTrackerSettings, WebPageTrackerTag,
+                WebPageTracker, WebPageTrackerPrecondition, WebPageTrackerSettings,
+                WebPageTrackerTag,
             },
             UtilsAction, UtilsActionParams, UtilsResource, UtilsResourceOperation,
         },
     };
+    use image::{ImageFormat, Rgba, RgbaImage};
     use insta::assert_json_snapshot;
     use serde_json::json;
     use sqlx::PgPool;
-    use std::{collections::HashMap, time::Duration};
+    use std::{collections::HashMap, io::Cursor, time::Duration};
     use time::OffsetDateTime;
     use url::Url;
     use uuid::{uuid, Uuid};
@@ -164,8 +708,7 @@ pub mod tests {
                     settings: WebPageTrackerSettings {
                         revisions,
                         delay: Duration::from_millis(2000),
-                        scripts: Default::default(),
-                        headers: Default::default(),
+                        ..Default::default()
                     },
                     created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                     updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
@@ -179,6 +722,7 @@ pub mod tests {
                 schedule: schedule.into(),
                 retry_strategy: None,
                 notifications: false,
+                adaptive_interval: None,
             });
             self
         }
@@ -203,11 +747,41 @@ pub mod tests {
             self
         }
 
+        pub fn with_dependency(mut self, depends_on: Uuid) -> Self {
+            self.tracker.settings.depends_on = Some(depends_on);
+            self
+        }
+
+        pub fn with_precondition(mut self, precondition: WebPageTrackerPrecondition) -> Self {
+            self.tracker.settings.precondition = Some(precondition);
+            self
+        }
+
         pub fn build(self) -> WebPageTracker<Tag> {
             self.tracker
         }
     }
 
+    /// Generates a PNG-encoded image of the specified size filled with white color, except for the
+    /// first `changed_pixels` pixels that are black.
+    pub fn mock_screenshot(
+        width: u32,
+        height: u32,
+        changed_pixels: u32,
+    ) -> anyhow::Result<Vec<u8>> {
+        let mut image = RgbaImage::from_pixel(width, height, Rgba([255, 255, 255, 255]));
+        for (index, pixel) in image.pixels_mut().enumerate() {
+            if index >= changed_pixels as usize {
+                break;
+            }
+            *pixel = Rgba([0, 0, 0, 255]);
+        }
+
+        let mut bytes = Cursor::new(Vec::new());
+        image.write_to(&mut bytes, ImageFormat::Png)?;
+        Ok(bytes.into_inner())
+    }
+
     #[sqlx::test]
     async fn properly_handles_resources_list_operation(pool: PgPool) -> anyhow::Result<()> {
         let api = mock_api(pool).await?;
@@ -232,13 +806,13 @@ pub mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -301,16 +875,17 @@ pub mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
                         interval: Duration::from_secs(1000),
                         max_attempts: 5,
+                        max_total_retry_duration: None,
                     }),
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -366,8 +941,7 @@ pub mod tests {
                 "settings": WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 "jobConfig": SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
@@ -376,8 +950,10 @@ pub mod tests {
                         increment: Duration::from_secs(1),
                         max_interval: Duration::from_secs(200),
                         max_attempts: 10,
+                        max_total_retry_duration: None,
                     }),
                     notifications: true,
+                    adaptive_interval: None,
                 }
             }))),
         )
@@ -424,8 +1000,7 @@ pub mod tests {
                 "settings": WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                  "jobConfig": SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
@@ -434,8 +1009,10 @@ pub mod tests {
                         multiplier: 2,
                         max_interval: Duration::from_secs(200),
                         max_attempts: 10,
+                        max_total_retry_duration: None,
                     }),
                     notifications: true,
+                    adaptive_interval: None,
                 }
             }))),
         )
@@ -479,13 +1056,13 @@ pub mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -506,13 +1083,13 @@ pub mod tests {
                 "settings": WebPageTrackerSettings {
                     revisions: 10,
                     delay: Duration::from_millis(3000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 "jobConfig": SchedulerJobConfig {
                     schedule: "0 1 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: false,
+                    adaptive_interval: None,
                 }
             }))),
         )
@@ -536,13 +1113,13 @@ pub mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 10,
                     delay: Duration::from_millis(3000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 1 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: false,
+                    adaptive_interval: None,
                 }),
                 created_at: tracker.created_at,
                 updated_at: updated_tracker.updated_at,
@@ -568,13 +1145,13 @@ pub mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -592,13 +1169,13 @@ pub mod tests {
                 "settings": WebPageTrackerSettings {
                     revisions: 10,
                     delay: Duration::from_millis(3000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 "jobConfig": SchedulerJobConfig {
                     schedule: "0 1 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: false,
+                    adaptive_interval: None,
                 },
             }))),
         )
@@ -622,13 +1199,13 @@ pub mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 10,
                     delay: Duration::from_millis(3000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 1 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: false,
+                    adaptive_interval: None,
                 }),
                 created_at: tracker.created_at,
                 updated_at: tracker.updated_at,
@@ -653,13 +1230,13 @@ pub mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -700,13 +1277,13 @@ pub mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -748,13 +1325,13 @@ pub mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -766,13 +1343,13 @@ pub mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -791,8 +1368,15 @@ pub mod tests {
                             url: Some(Url::parse("http://localhost:1234/style_one.css")?),
                             content: None,
                         }],
+                        categories: None,
+                        sizes: None,
+                        truncated: false,
                     },
                     created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -811,8 +1395,15 @@ pub mod tests {
                             url: Some(Url::parse("http://localhost:1234/style_two.css")?),
                             content: None,
                         }],
+                        categories: None,
+                        sizes: None,
+                        truncated: false,
                     },
                     created_at: OffsetDateTime::from_unix_timestamp(946720900)?,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -824,6 +1415,10 @@ pub mod tests {
                     tracker_id: content_tracker.id,
                     data: "some-data".to_string(),
                     created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -835,6 +1430,10 @@ pub mod tests {
                     tracker_id: content_tracker.id,
                     data: "other-data".to_string(),
                     created_at: OffsetDateTime::from_unix_timestamp(946720900)?,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -856,7 +1455,7 @@ pub mod tests {
 
         assert_json_snapshot!(
             serde_json::to_string(&action_result.into_inner().unwrap()).unwrap(),
-            @r###""[{\"id\":\"00000000-0000-0000-0000-000000000001\",\"data\":{\"scripts\":[{\"url\":\"http://localhost:1234/script_one.js\"}],\"styles\":[{\"url\":\"http://localhost:1234/style_one.css\"}]},\"createdAt\":946720800},{\"id\":\"00000000-0000-0000-0000-000000000002\",\"data\":{\"scripts\":[{\"url\":\"http://localhost:1234/script_two.js\",\"diffStatus\":\"added\"},{\"url\":\"http://localhost:1234/script_one.js\",\"diffStatus\":\"removed\"}],\"styles\":[{\"url\":\"http://localhost:1234/style_two.css\",\"diffStatus\":\"added\"},{\"url\":\"http://localhost:1234/style_one.css\",\"diffStatus\":\"removed\"}]},\"createdAt\":946720900}]""###
+            @r###""[{\"id\":\"00000000-0000-0000-0000-000000000001\",\"data\":{\"scripts\":[{\"url\":\"http://localhost:1234/script_one.js\"}],\"styles\":[{\"url\":\"http://localhost:1234/style_one.css\"}],\"truncated\":false},\"createdAt\":946720800},{\"id\":\"00000000-0000-0000-0000-000000000002\",\"data\":{\"scripts\":[{\"url\":\"http://localhost:1234/script_two.js\",\"diffStatus\":\"added\"},{\"url\":\"http://localhost:1234/script_one.js\",\"diffStatus\":\"removed\"}],\"styles\":[{\"url\":\"http://localhost:1234/style_two.css\",\"diffStatus\":\"added\"},{\"url\":\"http://localhost:1234/style_one.css\",\"diffStatus\":\"removed\"}],\"truncated\":false},\"createdAt\":946720900}]""###
         );
 
         let action_result = web_scraping_handle_action(
@@ -896,13 +1495,13 @@ pub mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -913,13 +1512,13 @@ pub mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -938,8 +1537,15 @@ pub mod tests {
                             url: Some(Url::parse("http://localhost:1234/style_one.css")?),
                             content: None,
                         }],
+                        categories: None,
+                        sizes: None,
+                        truncated: false,
                     },
                     created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -958,8 +1564,15 @@ pub mod tests {
                             url: Some(Url::parse("http://localhost:1234/style_two.css")?),
                             content: None,
                         }],
+                        categories: None,
+                        sizes: None,
+                        truncated: false,
                     },
                     created_at: OffsetDateTime::from_unix_timestamp(946720900)?,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -971,6 +1584,10 @@ pub mod tests {
                     tracker_id: content_tracker.id,
                     data: "some-data".to_string(),
                     created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -982,6 +1599,10 @@ pub mod tests {
                     tracker_id: content_tracker.id,
                     data: "some-other-data".to_string(),
                     created_at: OffsetDateTime::from_unix_timestamp(946720900)?,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
@@ -1005,7 +1626,8 @@ pub mod tests {
                     content_tracker.id,
                     WebPageContentTrackerGetHistoryParams {
                         refresh: false,
-                        calculate_diff: false
+                        calculate_diff: false,
+                        diff_algorithm: Default::default()
                     }
                 )
                 .await?
@@ -1056,7 +1678,8 @@ pub mod tests {
                 content_tracker.id,
                 WebPageContentTrackerGetHistoryParams {
                     refresh: false,
-                    calculate_diff: false
+                    calculate_diff: false,
+                    diff_algorithm: Default::default()
                 }
             )
             .await?
@@ -1064,4 +1687,327 @@ pub mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn properly_handles_import_trackers_operation(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let action_result = web_scraping_handle_action(
+            mock_user.clone(),
+            &api,
+            UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingImportTrackers,
+            },
+            UtilsResource::WebScrapingScreenshots,
+            Some(UtilsActionParams::json(json!({
+                "source": {
+                    "type": "list",
+                    "urls": "https://secutils.dev/one\nhttps://secutils.dev/one\nnot-a-url"
+                },
+                "settings": {
+                    "revisions": 3,
+                    "delay": 2000
+                }
+            }))),
+        )
+        .await?;
+
+        let tracker = api
+            .web_scraping(&mock_user)
+            .get_screenshot_trackers()
+            .await?
+            .pop()
+            .unwrap();
+        assert_eq!(tracker.name, "https://secutils.dev/one");
+
+        let mut settings = insta::Settings::clone_current();
+        settings.add_filter(&tracker.id.to_string(), "[UUID]");
+        settings.bind(|| {
+            assert_json_snapshot!(
+                serde_json::to_string(&action_result.into_inner().unwrap()).unwrap(),
+                @r###""[{\"url\":\"https://secutils.dev/one\",\"trackerId\":\"[UUID]\"},{\"url\":\"not-a-url\",\"error\":\"Web page URL isn't valid: relative URL without a base.\"}]""###
+            );
+        });
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_handles_create_well_known_trackers_operation(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let action_result = web_scraping_handle_action(
+            mock_user.clone(),
+            &api,
+            UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingCreateWellKnownTrackers,
+            },
+            UtilsResource::WebScrapingContent,
+            Some(UtilsActionParams::json(json!({
+                "baseUrl": "https://secutils.dev",
+                "files": ["securityTxt"]
+            }))),
+        )
+        .await?;
+
+        let tracker = api
+            .web_scraping(&mock_user)
+            .get_content_trackers()
+            .await?
+            .pop()
+            .unwrap();
+        let mut settings = insta::Settings::clone_current();
+        settings.add_filter(&tracker.id.to_string(), "[UUID]");
+        settings.add_filter(
+            &tracker.created_at.unix_timestamp().to_string(),
+            "[TIMESTAMP]",
+        );
+        settings.bind(|| {
+            assert_json_snapshot!(
+                serde_json::to_string(&action_result.into_inner().unwrap()).unwrap(),
+                @r###""[{\"id\":\"[UUID]\",\"name\":\"secutils.dev/.well-known/security.txt\",\"url\":\"https://secutils.dev/.well-known/security.txt\",\"settings\":{\"revisions\":3,\"delay\":0,\"renderJs\":false},\"createdAt\":[TIMESTAMP],\"updatedAt\":[TIMESTAMP]}]""###
+            );
+        });
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_handles_verify_history_integrity_operation(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "name_one",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker_history_revision::<WebPageContentTrackerTag>(
+                &WebPageDataRevision {
+                    id: uuid!("00000000-0000-0000-0000-000000000002"),
+                    tracker_id: tracker.id,
+                    data: "some-data".to_string(),
+                    created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
+                },
+            )
+            .await?;
+
+        let action_result = web_scraping_handle_action(
+            mock_user.clone(),
+            &api,
+            UtilsAction::Execute {
+                resource_id: Some(tracker.id),
+                operation: UtilsResourceOperation::WebScrapingVerifyHistoryIntegrity,
+            },
+            UtilsResource::WebScrapingContent,
+            None,
+        )
+        .await?;
+        assert_json_snapshot!(
+            serde_json::to_string(&action_result.into_inner().unwrap())?,
+            @r###""{\"revisions\":1,\"intact\":true}""###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_handles_analyze_noise_operation(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "name_one",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+        for index in 0..4 {
+            api.db
+                .web_scraping(mock_user.id)
+                .insert_web_page_tracker_history_revision::<WebPageContentTrackerTag>(
+                    &WebPageDataRevision {
+                        id: Uuid::from_u128(0x10 + index),
+                        tracker_id: tracker.id,
+                        data: serde_json::to_string(&format!(
+                            "<h1>Title</h1>\n<span id=\"time\">10:0{index}</span>\n<p>Content</p>\n"
+                        ))?,
+                        created_at: OffsetDateTime::from_unix_timestamp(
+                            946720800 + 100 * index as i64,
+                        )?,
+                        note: None,
+                        acknowledgment: None,
+                        diff_stats: None,
+                        assertions_passed: None,
+                    },
+                )
+                .await?;
+        }
+
+        let action_result = web_scraping_handle_action(
+            mock_user.clone(),
+            &api,
+            UtilsAction::Execute {
+                resource_id: Some(tracker.id),
+                operation: UtilsResourceOperation::WebScrapingAnalyzeNoise,
+            },
+            UtilsResource::WebScrapingContent,
+            None,
+        )
+        .await?;
+        assert_json_snapshot!(
+            serde_json::to_string(&action_result.into_inner().unwrap())?,
+            @r###""{\"revisions\":4,\"noiseRatio\":1.0,\"regions\":[{\"anchor\":\"<h1>Title</h1>\",\"changes\":3,\"frequency\":1.0,\"volatile\":true,\"suggestedSelector\":\"#time\",\"suggestedIgnorePattern\":\"^<span id=\\\"time\\\">.*</span>$\"}]}""###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_handles_simulate_diff_operation(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let action_result = web_scraping_handle_action(
+            mock_user.clone(),
+            &api,
+            UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingSimulateDiff,
+            },
+            UtilsResource::WebScrapingContent,
+            Some(UtilsActionParams::json(json!({
+                "previousContent": "Hello World",
+                "currentContent": "Hello New World"
+            }))),
+        )
+        .await?;
+        let action_result = action_result.into_inner().unwrap();
+        assert_eq!(
+            action_result["diff"],
+            json!("@@ -1 +1 @@\n-Hello World\n+Hello New World\n")
+        );
+        assert_eq!(
+            action_result["diffStats"],
+            json!({ "addedLines": 1, "removedLines": 1, "changedBytes": 26 })
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_handles_get_changes_feed_operation(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        let web_scraping_db = api.db.web_scraping(mock_user.id);
+        web_scraping_db.insert_web_page_tracker(&tracker).await?;
+        web_scraping_db
+            .insert_web_page_tracker_history_revision::<WebPageContentTrackerTag>(
+                &WebPageDataRevision {
+                    id: uuid!("00000000-0000-0000-0000-000000000002"),
+                    tracker_id: tracker.id,
+                    data: "\"some-content\"".to_string(),
+                    created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
+                },
+            )
+            .await?;
+
+        let action_result = web_scraping_handle_action(
+            mock_user.clone(),
+            &api,
+            UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebScrapingGetChangesFeed,
+            },
+            UtilsResource::WebScrapingScreenshots,
+            Some(UtilsActionParams::json(json!({ "limit": 10 }))),
+        )
+        .await?;
+        assert_json_snapshot!(
+            serde_json::to_string(&action_result.into_inner().unwrap())?,
+            @r###""[{\"trackerId\":\"00000000-0000-0000-0000-000000000001\",\"trackerName\":\"some-name\",\"trackerKind\":\"WebPageContent\",\"url\":\"https://secutils.dev/\",\"revisionId\":\"00000000-0000-0000-0000-000000000002\",\"createdAt\":946720800,\"summary\":\"Initial web page content captured.\"}]""###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_handles_get_effective_settings_operation(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "name_one",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+
+        let action_result = web_scraping_handle_action(
+            mock_user.clone(),
+            &api,
+            UtilsAction::Execute {
+                resource_id: Some(tracker.id),
+                operation: UtilsResourceOperation::WebScrapingGetEffectiveSettings,
+            },
+            UtilsResource::WebScrapingContent,
+            None,
+        )
+        .await?;
+        assert_json_snapshot!(
+            serde_json::to_string(&action_result.into_inner().unwrap())?,
+            @r###""{\"settings\":{\"revisions\":3,\"delay\":2000}}""###
+        );
+
+        Ok(())
+    }
 }
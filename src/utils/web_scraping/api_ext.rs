@@ -1,52 +1,192 @@
+mod web_page_content_simulate_diff_params;
+mod web_page_content_tracker_create_well_known_params;
 mod web_page_content_tracker_get_history_params;
+mod web_page_dns_records_tracker_get_history_params;
 mod web_page_resources_tracker_get_history_params;
+mod web_page_screenshot_tracker_get_history_params;
+pub(super) mod web_page_sitemap_parser;
+mod web_page_status_page_create_params;
+mod web_page_status_page_update_params;
+mod web_page_tracker_acknowledge_revision_params;
 mod web_page_tracker_create_params;
+mod web_page_tracker_export_history_params;
+mod web_page_tracker_get_revision_params;
+mod web_page_tracker_import_monitor;
+mod web_page_tracker_import_params;
+mod web_page_tracker_import_source;
+mod web_page_tracker_lint_script_params;
+mod web_page_tracker_preview_notification_params;
+mod web_page_tracker_set_baseline_revision_params;
+mod web_page_tracker_set_revision_note_params;
+mod web_page_tracker_set_webhook_params;
+mod web_page_tracker_test_script_params;
 mod web_page_tracker_update_params;
+mod web_page_trackers_diff_params;
+mod web_page_trackers_get_changes_feed_params;
 
 pub use self::{
+    web_page_content_simulate_diff_params::WebPageContentSimulateDiffParams,
+    web_page_content_tracker_create_well_known_params::WebPageContentTrackerCreateWellKnownParams,
     web_page_content_tracker_get_history_params::WebPageContentTrackerGetHistoryParams,
+    web_page_dns_records_tracker_get_history_params::WebPageDnsRecordsTrackerGetHistoryParams,
     web_page_resources_tracker_get_history_params::WebPageResourcesTrackerGetHistoryParams,
+    web_page_screenshot_tracker_get_history_params::WebPageScreenshotTrackerGetHistoryParams,
+    web_page_status_page_create_params::WebPageStatusPageCreateParams,
+    web_page_status_page_update_params::WebPageStatusPageUpdateParams,
+    web_page_tracker_acknowledge_revision_params::WebPageTrackerAcknowledgeRevisionParams,
     web_page_tracker_create_params::WebPageTrackerCreateParams,
+    web_page_tracker_export_history_params::WebPageTrackerExportHistoryParams,
+    web_page_tracker_get_revision_params::WebPageTrackerGetRevisionParams,
+    web_page_tracker_import_monitor::WebPageTrackerImportMonitor,
+    web_page_tracker_import_params::WebPageTrackerImportParams,
+    web_page_tracker_import_source::WebPageTrackerImportSource,
+    web_page_tracker_lint_script_params::WebPageTrackerLintScriptParams,
+    web_page_tracker_preview_notification_params::WebPageTrackerPreviewNotificationParams,
+    web_page_tracker_set_baseline_revision_params::WebPageTrackerSetBaselineRevisionParams,
+    web_page_tracker_set_revision_note_params::WebPageTrackerSetRevisionNoteParams,
+    web_page_tracker_set_webhook_params::WebPageTrackerSetWebhookParams,
+    web_page_tracker_test_script_params::WebPageTrackerTestScriptParams,
     web_page_tracker_update_params::WebPageTrackerUpdateParams,
+    web_page_trackers_diff_params::WebPageTrackersDiffParams,
+    web_page_trackers_get_changes_feed_params::WebPageTrackersGetChangesFeedParams,
 };
 use crate::{
     api::Api,
-    error::Error as SecutilsError,
+    error::{Error as SecutilsError, ErrorCode},
+    js_runtime::{JsRuntime, JsRuntimeConfig, JsScriptDiagnostic},
+    logging::REDACTED_VALUE,
     network::{DnsResolver, EmailTransport},
-    scheduler::{ScheduleExt, SchedulerJobRetryStrategy},
-    users::User,
+    notifications::{
+        NotificationContent, NotificationContentTemplate, NotificationPreview,
+        WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER,
+    },
+    scheduler::{ScheduleExt, SchedulerJobConfig, SchedulerJobRetryStrategy},
+    security::{encryption, secrets::resolve_secrets},
+    users::{User, UserDisplayOrderCollection, UserId},
     utils::{
         utils_action_validation::MAX_UTILS_ENTITY_NAME_LENGTH,
         web_scraping::{
+            api_ext::web_page_sitemap_parser::WebPageSitemapParser,
             database_ext::WebScrapingDatabaseSystemExt, web_page_content_revisions_diff,
-            web_page_resources_revisions_diff, WebPageContentTrackerTag, WebPageDataRevision,
-            WebPageResource, WebPageResourcesData, WebPageResourcesTrackerInternalTag,
-            WebPageResourcesTrackerTag, WebPageTracker, WebPageTrackerTag,
+            web_page_content_revisions_noise, web_page_dns_records_revisions_diff,
+            web_page_resources_revisions_diff, web_page_screenshot_revisions_diff,
+            web_page_screenshots_diff, WebPageContentDiffAlgorithm, WebPageContentFingerprint,
+            WebPageContentFormat, WebPageContentNoiseAnalysis, WebPageContentResponse,
+            WebPageContentResponseCapture, WebPageContentSimulatedDiff, WebPageContentStatus,
+            WebPageContentStatuses, WebPageContentStructure, WebPageContentTrackerTag,
+            WebPageContentWithCookies, WebPageDataRevision, WebPageDataRevisionAcknowledgment,
+            WebPageDataRevisionChainLink, WebPageDnsRecord, WebPageDnsRecordType,
+            WebPageDnsRecordsData, WebPageDnsRecordsTrackerTag, WebPageResource,
+            WebPageResourceContent, WebPageResourceDiffStatus, WebPageResourceInternal,
+            WebPageResourceType, WebPageResourcesData, WebPageResourcesSizes,
+            WebPageResourcesTrackerInternalTag, WebPageResourcesTrackerTag, WebPageScreenshotData,
+            WebPageScreenshotTrackerTag, WebPageStatusPage, WebPageTracker, WebPageTrackerChange,
+            WebPageTrackerEffectiveSettings, WebPageTrackerHistoryIntegrity,
+            WebPageTrackerImportResult, WebPageTrackerKind, WebPageTrackerPendingScrape,
+            WebPageTrackerScriptTestError, WebPageTrackerScriptTestResult, WebPageTrackerSettings,
+            WebPageTrackerTag, WebPageTrackerWebhook, WebPageTrackerWebhookSecret,
+            WebPageWellKnownFile, WebScraperCallback, WebScraperCallbackResult, WebScraperClient,
             WebScraperContentRequest, WebScraperContentRequestScripts, WebScraperContentResponse,
-            WebScraperErrorResponse, WebScraperResource, WebScraperResourcesRequest,
-            WebScraperResourcesRequestScripts, WebScraperResourcesResponse,
+            WebScraperErrorResponse, WebScraperJobResponse, WebScraperResource,
+            WebScraperResourcesRequest, WebScraperResourcesRequestScripts,
+            WebScraperResourcesResponse, WebScraperScreenshotRequest, WebScraperScreenshotResponse,
         },
     },
 };
 use anyhow::{anyhow, bail};
+use async_stream::try_stream;
+use bytes::Bytes;
 use cron::Schedule;
-use futures::Stream;
-use std::time::Duration;
-use time::OffsetDateTime;
+use deno_core::error::JsError;
+use futures::{
+    pin_mut,
+    stream::{self, LocalBoxStream},
+    Stream, StreamExt, TryStreamExt,
+};
+use rand::{distributions::Alphanumeric, thread_rng, Rng};
+use regex::{Regex, RegexSet};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, CONTENT_LENGTH, CONTENT_TYPE, HOST, RANGE},
+    Method,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
+    iter,
+    time::{Duration, Instant},
+};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+use trust_dns_resolver::{error::ResolveErrorKind, proto::rr::RecordType};
+use url::{Host, Url};
 use uuid::Uuid;
 
 /// Defines a maximum number of jobs that can be retrieved from the database at once.
 const MAX_JOBS_PAGE_SIZE: usize = 1000;
 
+/// Defines a maximum number of web page tracker revisions that can be retrieved from the database
+/// at once during history export.
+const MAX_HISTORY_EXPORT_PAGE_SIZE: usize = 100;
+
 /// Script used to `filter_map` resource that needs to be tracked.
 pub const WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME: &str = "resourceFilterMap";
 
 /// Script used to extract web page content that needs to be tracked.
 pub const WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME: &str = "extractContent";
 
+/// Screenshots are large, so we store only a limited number of them regardless of the subscription.
+const MAX_WEB_PAGE_SCREENSHOT_TRACKER_REVISIONS: usize = 5;
+
+/// Minimum share of the screenshot pixels that should differ from the previous screenshot for the
+/// web page to be considered visually changed (1%).
+const WEB_PAGE_SCREENSHOT_TRACKER_DIFF_THRESHOLD: f32 = 0.01;
+
+/// Web page content tracker in the status-only mode waits up to 30 seconds for the response.
+const WEB_PAGE_STATUS_TRACKER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Maximum length of the note that can be attached to the web page tracker revision.
+const MAX_WEB_PAGE_TRACKER_REVISION_NOTE_LENGTH: usize = 1000;
+
+/// Maximum number of trackers that can be displayed on a single status page.
+const MAX_WEB_PAGE_STATUS_PAGE_TRACKERS: usize = 20;
+
+/// Maximum number of the most recent status changes displayed for every status page tracker.
+const MAX_WEB_PAGE_STATUS_PAGE_HISTORY_SIZE: usize = 10;
+
+/// Length of the random token used to access the status page publicly.
+const WEB_PAGE_STATUS_PAGE_TOKEN_LENGTH: usize = 32;
+
+/// Path of the callback endpoint (relative to the public URL) the web scraper delivers the results
+/// of the asynchronous scrape jobs to.
+const WEB_SCRAPER_CALLBACK_PATH: &str = "api/web_scraping/scraper_callback";
+
+/// Length of the token the web scraper authenticates itself with when it delivers the result of
+/// the asynchronous scrape job.
+const WEB_SCRAPER_CALLBACK_TOKEN_LENGTH: usize = 32;
+
+/// Minimum length of the secret used to sign web page tracker webhook payloads.
+const MIN_WEB_PAGE_TRACKER_WEBHOOK_SECRET_LENGTH: usize = 16;
+
+/// Maximum length of the secret used to sign web page tracker webhook payloads.
+const MAX_WEB_PAGE_TRACKER_WEBHOOK_SECRET_LENGTH: usize = 256;
+
+/// Length of the secret generated when web page tracker webhook secrets are rotated.
+const GENERATED_WEB_PAGE_TRACKER_WEBHOOK_SECRET_LENGTH: usize = 32;
+
+/// Maximum number of custom HTTP headers sent along with web page tracker webhook notifications.
+const MAX_WEB_PAGE_TRACKER_WEBHOOK_HEADERS: usize = 20;
+
+/// Maximum length of the value of the custom HTTP header sent along with web page tracker webhook
+/// notifications.
+const MAX_WEB_PAGE_TRACKER_WEBHOOK_HEADER_VALUE_LENGTH: usize = 4096;
+
 /// We currently wait up to 60 seconds before starting to track web page.
 const MAX_WEB_PAGE_TRACKER_DELAY: Duration = Duration::from_secs(60);
 
+/// Maximum number of concurrent requests web page tracker can send within a single run.
+const MAX_WEB_PAGE_TRACKER_SUB_FETCH_CONCURRENCY: usize = 10;
+
 /// We currently support up to 10 retry attempts for the web page tracker.
 const MAX_WEB_PAGE_TRACKER_RETRY_ATTEMPTS: u32 = 10;
 
@@ -56,6 +196,48 @@ const MIN_WEB_PAGE_TRACKER_RETRY_INTERVAL: Duration = Duration::from_secs(60);
 /// We currently support maximum 12 hours between retry attempts for the web page tracker.
 const MAX_WEB_PAGE_TRACKER_RETRY_INTERVAL: Duration = Duration::from_secs(12 * 3600);
 
+/// We currently support minimum 1 hour retention for the web page tracker revisions.
+const MIN_WEB_PAGE_TRACKER_DATA_RETENTION: Duration = Duration::from_secs(3600);
+
+/// Maximum length of the value that web page tracker precondition compares extracted content with.
+const MAX_WEB_PAGE_TRACKER_PRECONDITION_VALUE_LENGTH: usize = 1000;
+
+/// We currently support up to 10 content assertions for the web page content tracker.
+const MAX_WEB_PAGE_CONTENT_TRACKER_ASSERTIONS: usize = 10;
+
+/// Maximum length of the string or regular expression web page content tracker assertion checks.
+const MAX_WEB_PAGE_CONTENT_TRACKER_ASSERTION_VALUE_LENGTH: usize = 1000;
+
+/// Maximum length of the HTTP method web page content tracker can use to request the web page.
+const MAX_WEB_PAGE_CONTENT_TRACKER_METHOD_LENGTH: usize = 20;
+
+/// Maximum size of the body web page content tracker can send with the request (64 KB).
+const MAX_WEB_PAGE_CONTENT_TRACKER_REQUEST_BODY_SIZE: usize = 64 * 1024;
+
+/// Number of changes the changes feed returns by default.
+const DEFAULT_WEB_PAGE_TRACKER_CHANGES_FEED_LIMIT: usize = 50;
+
+/// Maximum number of changes the changes feed can return at once.
+const MAX_WEB_PAGE_TRACKER_CHANGES_FEED_LIMIT: usize = 500;
+
+/// Maximum number of web page URLs that can be imported as trackers at once.
+const MAX_WEB_PAGE_TRACKER_IMPORT_URLS: usize = 100;
+
+/// Maximum size of the sitemap that can be used to import web page trackers (5 MB).
+const MAX_WEB_PAGE_TRACKER_IMPORT_SITEMAP_SIZE: usize = 5 * 1024 * 1024;
+
+/// Web page tracker import waits up to 30 seconds for the sitemap.
+const WEB_PAGE_TRACKER_IMPORT_SITEMAP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Number of revisions web page content trackers for the well-known files keep by default.
+const WELL_KNOWN_CONTENT_TRACKER_REVISIONS: usize = 3;
+
+/// Maximum total size of the contents the diff can be simulated for (1 MB).
+const MAX_WEB_PAGE_CONTENT_SIMULATION_SIZE: usize = 1024 * 1024;
+
+/// Name of the tracker used in the notification rendered for the simulated diff.
+const WEB_PAGE_CONTENT_SIMULATION_TRACKER_NAME: &str = "Simulated tracker";
+
 pub struct WebScrapingApiExt<'a, 'u, DR: DnsResolver, ET: EmailTransport> {
     api: &'a Api<DR, ET>,
     user: &'u User,
@@ -81,6 +263,20 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebScrapingApiExt<'a, 'u, DR,
         self.get_web_page_trackers().await
     }
 
+    /// Returns all web page screenshot trackers.
+    pub async fn get_screenshot_trackers(
+        &self,
+    ) -> anyhow::Result<Vec<WebPageTracker<WebPageScreenshotTrackerTag>>> {
+        self.get_web_page_trackers().await
+    }
+
+    /// Returns all web page DNS records trackers.
+    pub async fn get_dns_records_trackers(
+        &self,
+    ) -> anyhow::Result<Vec<WebPageTracker<WebPageDnsRecordsTrackerTag>>> {
+        self.get_web_page_trackers().await
+    }
+
     /// Returns web page resources tracker by its ID.
     pub async fn get_resources_tracker(
         &self,
@@ -98,6 +294,22 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebScrapingApiExt<'a, 'u, DR,
         self.get_web_page_tracker(id).await
     }
 
+    /// Returns web page screenshot tracker by its ID.
+    pub async fn get_screenshot_tracker(
+        &self,
+        id: Uuid,
+    ) -> anyhow::Result<Option<WebPageTracker<WebPageScreenshotTrackerTag>>> {
+        self.get_web_page_tracker(id).await
+    }
+
+    /// Returns web page DNS records tracker by its ID.
+    pub async fn get_dns_records_tracker(
+        &self,
+        id: Uuid,
+    ) -> anyhow::Result<Option<WebPageTracker<WebPageDnsRecordsTrackerTag>>> {
+        self.get_web_page_tracker(id).await
+    }
+
     /// Creates a new web page resources tracker.
     pub async fn create_resources_tracker(
         &self,
@@ -126,6 +338,150 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebScrapingApiExt<'a, 'u, DR,
         .await
     }
 
+    /// Creates a new web page screenshot tracker.
+    pub async fn create_screenshot_tracker(
+        &self,
+        params: WebPageTrackerCreateParams,
+    ) -> anyhow::Result<WebPageTracker<WebPageScreenshotTrackerTag>> {
+        self.create_web_page_tracker(
+            params,
+            Some(|tracker: &WebPageTracker<WebPageScreenshotTrackerTag>| {
+                self.validate_web_page_screenshot_tracker(tracker)
+            }),
+        )
+        .await
+    }
+
+    /// Creates a new web page DNS records tracker.
+    pub async fn create_dns_records_tracker(
+        &self,
+        params: WebPageTrackerCreateParams,
+    ) -> anyhow::Result<WebPageTracker<WebPageDnsRecordsTrackerTag>> {
+        self.create_web_page_tracker(
+            params,
+            Some(|tracker: &WebPageTracker<WebPageDnsRecordsTrackerTag>| {
+                self.validate_web_page_dns_records_tracker(tracker)
+            }),
+        )
+        .await
+    }
+
+    /// Creates new web page resources trackers for every web page URL from the import source.
+    pub async fn import_resources_trackers(
+        &self,
+        params: WebPageTrackerImportParams,
+    ) -> anyhow::Result<Vec<WebPageTrackerImportResult>> {
+        self.import_web_page_trackers(
+            params,
+            |tracker: &WebPageTracker<WebPageResourcesTrackerTag>| {
+                self.validate_web_page_resources_tracker(tracker)
+            },
+        )
+        .await
+    }
+
+    /// Creates new web page content trackers for every web page URL from the import source.
+    pub async fn import_content_trackers(
+        &self,
+        params: WebPageTrackerImportParams,
+    ) -> anyhow::Result<Vec<WebPageTrackerImportResult>> {
+        self.import_web_page_trackers(
+            params,
+            |tracker: &WebPageTracker<WebPageContentTrackerTag>| {
+                self.validate_web_page_content_tracker(tracker)
+            },
+        )
+        .await
+    }
+
+    /// Creates new web page screenshot trackers for every web page URL from the import source.
+    pub async fn import_screenshot_trackers(
+        &self,
+        params: WebPageTrackerImportParams,
+    ) -> anyhow::Result<Vec<WebPageTrackerImportResult>> {
+        self.import_web_page_trackers(
+            params,
+            |tracker: &WebPageTracker<WebPageScreenshotTrackerTag>| {
+                self.validate_web_page_screenshot_tracker(tracker)
+            },
+        )
+        .await
+    }
+
+    /// Creates new web page DNS records trackers for every web page URL from the import source.
+    pub async fn import_dns_records_trackers(
+        &self,
+        params: WebPageTrackerImportParams,
+    ) -> anyhow::Result<Vec<WebPageTrackerImportResult>> {
+        self.import_web_page_trackers(
+            params,
+            |tracker: &WebPageTracker<WebPageDnsRecordsTrackerTag>| {
+                self.validate_web_page_dns_records_tracker(tracker)
+            },
+        )
+        .await
+    }
+
+    /// Creates new web page content trackers for the well-known text files (e.g. `security.txt`
+    /// or `robots.txt`) of the specified web site, using settings suitable for plain text files.
+    pub async fn create_well_known_content_trackers(
+        &self,
+        params: WebPageContentTrackerCreateWellKnownParams,
+    ) -> anyhow::Result<Vec<WebPageTracker<WebPageContentTrackerTag>>> {
+        let base_url = params.base_url;
+        if !self.api.network.is_public_web_url(&base_url).await {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker base URL must be either `http` or `https` and have a valid public reachable domain name, but received {base_url}."
+            ))
+            .with_code(ErrorCode::InvalidUrl));
+        }
+
+        let mut files = vec![];
+        for file in params
+            .files
+            .unwrap_or_else(|| WebPageWellKnownFile::ALL.to_vec())
+        {
+            if !files.contains(&file) {
+                files.push(file);
+            }
+        }
+        if files.is_empty() {
+            bail!(SecutilsError::client(
+                "Web page tracker well-known files list cannot be empty."
+            ));
+        }
+
+        // Well-known files are small and rarely change, so only a few revisions are needed.
+        let features = self.user.subscription.get_features(&self.api.config);
+        let revisions = WELL_KNOWN_CONTENT_TRACKER_REVISIONS
+            .min(features.config.web_scraping.tracker_revisions);
+
+        let mut trackers = Vec::with_capacity(files.len());
+        for file in files {
+            let url = base_url.join(file.path())?;
+            let tracker = self
+                .create_content_tracker(WebPageTrackerCreateParams {
+                    name: format!("{}{}", url.host_str().unwrap_or_default(), url.path()),
+                    url,
+                    settings: WebPageTrackerSettings {
+                        revisions,
+                        delay: Duration::ZERO,
+                        // Well-known files are plain text, there is nothing to render.
+                        render_js: false,
+                        // Crawling rules are compared semantically, regardless of their order.
+                        content_format: (file == WebPageWellKnownFile::RobotsTxt)
+                            .then_some(WebPageContentFormat::RobotsTxt),
+                        ..Default::default()
+                    },
+                    job_config: params.job_config.clone(),
+                })
+                .await?;
+            trackers.push(tracker);
+        }
+
+        Ok(trackers)
+    }
+
     /// Updates existing web page resources tracker.
     pub async fn update_resources_tracker(
         &self,
@@ -158,6 +514,38 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebScrapingApiExt<'a, 'u, DR,
         .await
     }
 
+    /// Updates existing web page screenshot tracker.
+    pub async fn update_screenshot_tracker(
+        &self,
+        id: Uuid,
+        params: WebPageTrackerUpdateParams,
+    ) -> anyhow::Result<WebPageTracker<WebPageScreenshotTrackerTag>> {
+        self.update_web_page_tracker(
+            id,
+            params,
+            Some(|tracker: &WebPageTracker<WebPageScreenshotTrackerTag>| {
+                self.validate_web_page_screenshot_tracker(tracker)
+            }),
+        )
+        .await
+    }
+
+    /// Updates existing web page DNS records tracker.
+    pub async fn update_dns_records_tracker(
+        &self,
+        id: Uuid,
+        params: WebPageTrackerUpdateParams,
+    ) -> anyhow::Result<WebPageTracker<WebPageDnsRecordsTrackerTag>> {
+        self.update_web_page_tracker(
+            id,
+            params,
+            Some(|tracker: &WebPageTracker<WebPageDnsRecordsTrackerTag>| {
+                self.validate_web_page_dns_records_tracker(tracker)
+            }),
+        )
+        .await
+    }
+
     /// Removes existing web page resources tracker and all history.
     pub async fn remove_web_page_tracker(&self, id: Uuid) -> anyhow::Result<()> {
         self.api
@@ -175,7 +563,8 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebScrapingApiExt<'a, 'u, DR,
         let Some(tracker) = self.get_resources_tracker(tracker_id).await? else {
             bail!(SecutilsError::client(format!(
                 "Web page tracker ('{tracker_id}') is not found."
-            )));
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
         };
 
         let features = self.user.subscription.get_features(&self.api.config);
@@ -196,7 +585,9 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebScrapingApiExt<'a, 'u, DR,
             };
 
         let scraper_request = WebScraperResourcesRequest::with_default_parameters(&tracker.url)
-            .set_delay(tracker.settings.delay);
+            .set_delay(tracker.settings.delay)
+            .set_ignore_https_errors(tracker.settings.insecure_tls)
+            .set_disable_js(!tracker.settings.render_js);
         let resources_filter_map_script = tracker
             .settings
             .scripts
@@ -210,66 +601,28 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebScrapingApiExt<'a, 'u, DR,
             scraper_request
         };
 
-        let scraper_request = if let Some(headers) = tracker.settings.headers.as_ref() {
+        let headers = self.resolve_tracker_headers(&tracker)?;
+        let scraper_request = if let Some(headers) = headers.as_deref() {
             scraper_request.set_headers(headers)
         } else {
             scraper_request
         };
 
-        let scraper_response = reqwest::Client::new()
-            .post(format!(
-                "{}api/web_page/resources",
-                self.api.config.as_ref().components.web_scraper_url.as_str()
-            ))
-            .json(&scraper_request)
-            .send()
-            .await
-            .map_err(|err| {
-                anyhow!(
-                    "Could not connect to the web scraper service to extract resources for the web tracker ('{}'): {:?}",
-                    tracker.id,
-                    err
-                )
-            })?;
-
-        if !scraper_response.status().is_success() {
-            let is_client_error = scraper_response.status().is_client_error();
-            let scraper_error_response = scraper_response
-                .json::<WebScraperErrorResponse>()
-                .await
-                .map_err(|err| {
-                anyhow!(
-                    "Could not deserialize scraper error response for the web tracker ('{}'): {:?}",
-                    tracker.id,
-                    err
-                )
-            })?;
-            if is_client_error {
-                bail!(SecutilsError::client(scraper_error_response.message));
-            } else {
-                bail!(
-                    "Unexpected scraper error for the web tracker ('{}'): {:?}",
-                    tracker.id,
-                    scraper_error_response.message
-                );
-            }
-        }
-
-        let scraper_response = scraper_response
-            .json::<WebScraperResourcesResponse>()
-            .await
-            .map_err(|err| {
-                anyhow!(
-                    "Could not deserialize scraper response for the web tracker ('{}'): {:?}",
-                    tracker.id,
-                    err
-                )
-            })?;
+        let scraper_response = self
+            .send_tracker_scraper_request::<_, WebScraperResourcesResponse>(
+                &tracker,
+                "api/web_page/resources",
+                &scraper_request,
+            )
+            .await?;
 
         // Check if there is a revision with the same timestamp. If so, drop newly fetched revision.
         let web_scraping = self.api.db.web_scraping(self.user.id);
-        let revisions = web_scraping
-            .get_web_page_tracker_history(tracker.id)
+        let mut revisions = web_scraping
+            .get_web_page_tracker_history::<WebPageResourcesTrackerTag>(tracker.id)
+            .await?;
+        let baseline_revision_id = web_scraping
+            .get_web_page_tracker_baseline_revision_id(tracker.id)
             .await?;
         if revisions
             .iter()
@@ -278,14 +631,58 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebScrapingApiExt<'a, 'u, DR,
             return Ok(None);
         }
 
+        let mut scripts = convert_to_web_page_resources(scraper_response.scripts);
+        let mut styles = convert_to_web_page_resources(scraper_response.styles);
+
+        // Drop resources of the types that aren't tracked, including the ones in the previous
+        // revisions, so that changes to the tracked types don't produce spurious diffs.
+        if let Some(ref resource_types) = tracker.settings.resource_types {
+            if !resource_types.contains(&WebPageResourceType::Script) {
+                scripts.clear();
+            }
+
+            if !resource_types.contains(&WebPageResourceType::Style) {
+                styles.clear();
+            }
+
+            for revision in revisions.iter_mut() {
+                revision.data.retain_types(resource_types);
+            }
+        }
+
+        // Retain only as many resources as the subscription allows, giving priority to scripts.
+        let max_resources = features.config.web_scraping.tracker_resources;
+        let truncated = scripts.len() + styles.len() > max_resources;
+        if truncated {
+            log::warn!(
+                user:serde = self.user.log_context(),
+                util:serde = tracker.log_context();
+                "Web page has more resources than allowed ({max_resources}), truncating the resources."
+            );
+            scripts.truncate(max_resources);
+            styles.truncate(max_resources - scripts.len());
+        }
+
+        let data = WebPageResourcesData {
+            scripts,
+            styles,
+            categories: None,
+            sizes: None,
+            truncated,
+        };
         let new_revision = WebPageDataRevision {
-            id: Uuid::now_v7(),
+            id: WebPageDataRevision::<WebPageResourcesTrackerTag>::derive_id(
+                tracker.id,
+                scraper_response.timestamp,
+                &data,
+            )?,
             tracker_id: tracker.id,
-            data: WebPageResourcesData {
-                scripts: convert_to_web_page_resources(scraper_response.scripts),
-                styles: convert_to_web_page_resources(scraper_response.styles),
-            },
+            data,
             created_at: scraper_response.timestamp,
+            note: None,
+            acknowledgment: None,
+            diff_stats: None,
+            assertions_passed: None,
         };
 
         // Get the latest revision and check if it's different from the new one. If so, we need to
@@ -295,15 +692,60 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebScrapingApiExt<'a, 'u, DR,
                 latest_revision.clone(),
                 new_revision.clone(),
             ])?;
-            let new_revision_with_diff = revisions_with_diff
+            let mut new_revision_with_diff = revisions_with_diff
                 .pop()
                 .ok_or_else(|| anyhow!("Invalid revisions diff result."))?;
 
+            // Changes to the resources with ignored URLs shouldn't produce a new revision, but we
+            // still store the full list of resources if there are other changes.
+            let has_diff =
+                if let Some(ref ignore_resource_urls) = tracker.settings.ignore_resource_urls {
+                    let ignore_resource_urls = RegexSet::new(ignore_resource_urls)?;
+                    new_revision_with_diff
+                        .data
+                        .scripts
+                        .iter()
+                        .chain(new_revision_with_diff.data.styles.iter())
+                        .any(|resource| {
+                            resource.diff_status.is_some()
+                                && !resource
+                                    .url
+                                    .as_ref()
+                                    .is_some_and(|url| ignore_resource_urls.is_match(url.as_str()))
+                        })
+                } else {
+                    new_revision_with_diff.data.has_diff()
+                };
+
             // Return the latest revision back to the queue if it's different from the new one.
-            if !new_revision_with_diff.data.has_diff() {
+            if !has_diff {
                 return Ok(None);
             }
 
+            // Calculate resources sizes to let caller check them against the size budget.
+            if tracker.settings.size_budget.is_some() {
+                new_revision_with_diff.data.sizes = Some(WebPageResourcesSizes {
+                    previous_total: Some(latest_revision.data.calculate_sizes().total),
+                    ..new_revision_with_diff.data.calculate_sizes()
+                });
+            }
+
+            // If the baseline revision is pinned, report changes relative to the baseline instead
+            // of the latest revision.
+            let baseline_revision = revisions.iter().find(|revision| {
+                Some(revision.id) == baseline_revision_id && revision.id != latest_revision.id
+            });
+            if let Some(baseline_revision) = baseline_revision {
+                let baseline_revision_with_diff = web_page_resources_revisions_diff(vec![
+                    baseline_revision.clone(),
+                    new_revision.clone(),
+                ])?
+                .pop()
+                .ok_or_else(|| anyhow!("Invalid revisions diff result."))?;
+                new_revision_with_diff.data.scripts = baseline_revision_with_diff.data.scripts;
+                new_revision_with_diff.data.styles = baseline_revision_with_diff.data.styles;
+            }
+
             Some(new_revision_with_diff)
         } else {
             None
@@ -328,23 +770,43 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebScrapingApiExt<'a, 'u, DR,
                             .into_iter()
                             .map(Into::into)
                             .collect(),
+                        categories: None,
+                        sizes: None,
+                        truncated: new_revision.data.truncated,
                     },
                     created_at: new_revision.created_at,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
             )
             .await?;
 
-        // Enforce revisions limit and displace old ones.
+        // Enforce revisions limit and displace old ones, the baseline revision is never displaced.
         if revisions.len() >= max_revisions {
             let revisions_to_remove = revisions.len() - max_revisions + 1;
-            for revision in revisions.iter().take(revisions_to_remove) {
+            for revision in revisions
+                .iter()
+                .filter(|revision| Some(revision.id) != baseline_revision_id)
+                .take(revisions_to_remove)
+            {
                 web_scraping
                     .remove_web_page_tracker_history_revision(tracker.id, revision.id)
                     .await?;
             }
         }
 
-        Ok(new_revision_with_diff)
+        Ok(new_revision_with_diff.map(|revision| {
+            if tracker.settings.classify_resources {
+                WebPageDataRevision {
+                    data: revision.data.classified(),
+                    ..revision
+                }
+            } else {
+                revision
+            }
+        }))
     }
 
     /// Persists history for the specified web page content tracker.
@@ -355,7 +817,8 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebScrapingApiExt<'a, 'u, DR,
         let Some(tracker) = self.get_content_tracker(tracker_id).await? else {
             bail!(SecutilsError::client(format!(
                 "Web page tracker ('{tracker_id}') is not found."
-            )));
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
         };
 
         // Enforce revisions limit and displace old ones.
@@ -372,84 +835,96 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebScrapingApiExt<'a, 'u, DR,
         let revisions = web_scraping
             .get_web_page_tracker_history::<WebPageContentTrackerTag>(tracker.id)
             .await?;
+        let baseline_revision_id = web_scraping
+            .get_web_page_tracker_baseline_revision_id(tracker.id)
+            .await?;
 
-        let scraper_request = WebScraperContentRequest::with_default_parameters(&tracker.url)
-            .set_delay(tracker.settings.delay);
-        let scraper_request = if let Some(revision) = revisions.last() {
-            scraper_request.set_previous_content(&revision.data)
-        } else {
-            scraper_request
-        };
-
-        let extract_content_script = tracker
+        // In status-only mode we don't need the web scraper, we only check HTTP status of the page.
+        let (scraper_response, status) = if let Some(additional_urls) = tracker
             .settings
-            .scripts
+            .additional_urls
             .as_ref()
-            .and_then(|scripts| scripts.get(WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME));
-        let scraper_request = if let Some(extract_content) = extract_content_script {
-            scraper_request.set_scripts(WebScraperContentRequestScripts {
-                extract_content: Some(extract_content),
-            })
+            .filter(|_| tracker.settings.status_only)
+        {
+            // Every monitored URL is tracked separately, the whole set of statuses is compared as
+            // a single unit.
+            let (timestamp, statuses) = self
+                .fetch_web_page_statuses(&tracker, additional_urls)
+                .await?;
+            (
+                WebScraperContentResponse {
+                    timestamp,
+                    content: serde_json::to_string(&statuses)?,
+                    cookies: None,
+                },
+                None,
+            )
+        } else if tracker.settings.status_only {
+            let (timestamp, status) = self.fetch_web_page_status(&tracker, &tracker.url).await?;
+            (
+                WebScraperContentResponse {
+                    timestamp,
+                    content: serde_json::to_string(&status)?,
+                    cookies: None,
+                },
+                Some(status),
+            )
+        } else if tracker.settings.capture_fingerprint {
+            // In fingerprint capture mode we don't need the web scraper either, only the hash and
+            // metadata of the resource (e.g. favicon) are captured and compared.
+            let (timestamp, fingerprint) = self.fetch_web_page_fingerprint(&tracker).await?;
+            (
+                WebScraperContentResponse {
+                    timestamp,
+                    content: serde_json::to_string(&fingerprint)?,
+                    cookies: None,
+                },
+                None,
+            )
+        } else if let Some(ref capture_response) = tracker.settings.capture_response {
+            // In response capture mode we don't need the web scraper either, HTTP status, headers,
+            // and body of the response are captured and compared as a single unit.
+            let (timestamp, response) = self
+                .fetch_web_page_response(&tracker, capture_response)
+                .await?;
+            (
+                WebScraperContentResponse {
+                    timestamp,
+                    content: serde_json::to_string(&response)?,
+                    cookies: None,
+                },
+                None,
+            )
         } else {
-            scraper_request
-        };
+            let mut scraper_response = self
+                .fetch_web_page_content(&tracker, revisions.last())
+                .await?;
 
-        let scraper_request = if let Some(headers) = tracker.settings.headers.as_ref() {
-            scraper_request.set_headers(headers)
-        } else {
-            scraper_request
+            // Mask sensitive data in the web page content before comparing and persisting it.
+            if let Some(redaction_patterns) = self
+                .api
+                .users()
+                .get_redaction_patterns(self.user.id)
+                .await?
+            {
+                scraper_response.content = redaction_patterns
+                    .redact_text(&scraper_response.content)
+                    .into_owned();
+            }
+
+            // Combine content with the cookies set by the web page, if requested.
+            if let Some(capture_cookies) = tracker.settings.capture_cookies {
+                scraper_response.content = serde_json::to_string(&WebPageContentWithCookies::new(
+                    &scraper_response.content,
+                    scraper_response.cookies.as_deref().unwrap_or_default(),
+                    capture_cookies,
+                    features.config.web_scraping.tracker_captured_headers,
+                ))?;
+            }
+
+            (scraper_response, None)
         };
 
-        let scraper_response = reqwest::Client::new()
-            .post(format!(
-                "{}api/web_page/content",
-                self.api.config.as_ref().components.web_scraper_url.as_str()
-            ))
-            .json(&scraper_request)
-            .send()
-            .await
-            .map_err(|err| {
-                anyhow!(
-                    "Could not connect to the web scraper service to extract content for the web tracker ('{}'): {:?}",
-                    tracker.id,
-                    err
-                )
-            })?;
-
-        if !scraper_response.status().is_success() {
-            let is_client_error = scraper_response.status().is_client_error();
-            let scraper_error_response = scraper_response
-                .json::<WebScraperErrorResponse>()
-                .await
-                .map_err(|err| {
-                anyhow!(
-                    "Could not deserialize scraper error response for the web tracker ('{}'): {:?}",
-                    tracker.id,
-                    err
-                )
-            })?;
-            if is_client_error {
-                bail!(SecutilsError::client(scraper_error_response.message));
-            } else {
-                bail!(
-                    "Unexpected scraper error for the web tracker ('{}'): {:?}",
-                    tracker.id,
-                    scraper_error_response.message
-                );
-            }
-        }
-
-        let scraper_response = scraper_response
-            .json::<WebScraperContentResponse>()
-            .await
-            .map_err(|err| {
-                anyhow!(
-                    "Could not deserialize scraper response for the web tracker ('{}'): {:?}",
-                    tracker.id,
-                    err
-                )
-            })?;
-
         // Check if there is a revision with the same timestamp. If so, drop newly fetched revision.
         if revisions
             .iter()
@@ -458,29 +933,104 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebScrapingApiExt<'a, 'u, DR,
             return Ok(None);
         }
 
-        // Check if content has changed.
+        // Check if content has changed. In status-only mode only status change matters, latency
+        // is recorded, but ignored.
         if let Some(revision) = revisions.last() {
-            if revision.data == scraper_response.content {
+            let has_changed = if let Some(ref status) = status {
+                serde_json::from_str::<WebPageContentStatus>(&revision.data)
+                    .map(|previous_status| previous_status.status != status.status)
+                    .unwrap_or(true)
+            } else if let Some((previous_structure, structure)) =
+                tracker.settings.content_format.and_then(|format| {
+                    Some((
+                        WebPageContentStructure::parse(format, &revision.data)?,
+                        WebPageContentStructure::parse(format, &scraper_response.content)?,
+                    ))
+                })
+            {
+                // Content in the structured format is compared semantically, and falls back to
+                // the plain text comparison if any of the revisions cannot be parsed.
+                structure.describe_changes(&previous_structure).is_some()
+            } else if tracker.settings.status_only {
+                // With additional URLs, only status changes of any of the URLs matter.
+                match (
+                    serde_json::from_str::<WebPageContentStatuses>(&revision.data),
+                    serde_json::from_str::<WebPageContentStatuses>(&scraper_response.content),
+                ) {
+                    (Ok(previous_statuses), Ok(statuses)) => {
+                        statuses.describe_changes(&previous_statuses).is_some()
+                    }
+                    _ => true,
+                }
+            } else {
+                revision.data != scraper_response.content
+            };
+
+            if !has_changed {
+                return Ok(None);
+            }
+        }
+
+        // If requested, check whether content matches any of the recent revisions (e.g. the web
+        // page content returned to one of its previous states).
+        if let Some(dedup_window) = tracker
+            .settings
+            .dedup_window
+            .filter(|_| !tracker.settings.status_only)
+        {
+            if web_scraping
+                .has_recent_web_page_tracker_history_revision::<WebPageContentTrackerTag>(
+                    tracker.id,
+                    &scraper_response.content,
+                    dedup_window,
+                )
+                .await?
+            {
                 return Ok(None);
             }
         }
 
+        let assertions_passed = tracker.settings.assertions.as_ref().map(|assertions| {
+            assertions
+                .iter()
+                .all(|assertion| assertion.is_met(&scraper_response.content))
+        });
         let new_revision = WebPageDataRevision {
-            id: Uuid::now_v7(),
+            id: WebPageDataRevision::<WebPageContentTrackerTag>::derive_id(
+                tracker.id,
+                scraper_response.timestamp,
+                &scraper_response.content,
+            )?,
             tracker_id: tracker.id,
             data: scraper_response.content,
             created_at: scraper_response.timestamp,
+            note: None,
+            acknowledgment: None,
+            diff_stats: None,
+            assertions_passed,
         };
 
-        // Insert new revision.
-        web_scraping
-            .insert_web_page_tracker_history_revision::<WebPageContentTrackerTag>(&new_revision)
-            .await?;
+        // Insert new revision, optionally as a diff against the previous one to save space.
+        if tracker.settings.store_diffs {
+            web_scraping
+                .insert_web_page_tracker_history_revision_as_delta::<WebPageContentTrackerTag>(
+                    &new_revision,
+                )
+                .await?;
+        } else {
+            web_scraping
+                .insert_web_page_tracker_history_revision::<WebPageContentTrackerTag>(&new_revision)
+                .await?;
+        }
 
-        // Enforce revisions limit and displace old ones.
+        // Enforce revisions limit and displace old ones, the baseline revision is never displaced.
         if revisions.len() >= max_revisions {
             let revisions_to_remove = revisions.len() - max_revisions + 1;
-            for revision in revisions.iter().take(revisions_to_remove) {
+            for revision in revisions
+                .iter()
+                .filter(|revision| Some(revision.id) != baseline_revision_id)
+                .take(revisions_to_remove)
+            {
                 web_scraping
                     .remove_web_page_tracker_history_revision(tracker.id, revision.id)
                     .await?;
@@ -490,2288 +1040,10534 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebScrapingApiExt<'a, 'u, DR,
         Ok(Some(new_revision))
     }
 
-    /// Returns all stored webpage resources tracker history.
-    pub async fn get_resources_tracker_history(
+    /// Fetches web page content for the specified content tracker using the web scraper.
+    async fn fetch_web_page_content(
         &self,
-        tracker_id: Uuid,
-        params: WebPageResourcesTrackerGetHistoryParams,
-    ) -> anyhow::Result<Vec<WebPageDataRevision<WebPageResourcesTrackerTag>>> {
-        if params.refresh {
-            self.create_resources_tracker_revision(tracker_id).await?;
-        } else if self.get_resources_tracker(tracker_id).await?.is_none() {
-            bail!(SecutilsError::client(format!(
-                "Web page tracker ('{tracker_id}') is not found."
-            )));
+        tracker: &WebPageTracker<WebPageContentTrackerTag>,
+        previous_revision: Option<&WebPageDataRevision<WebPageContentTrackerTag>>,
+    ) -> anyhow::Result<WebScraperContentResponse> {
+        // Revisions of the trackers that capture cookies also include cookies the web scraper
+        // isn't aware of, only the content should be passed to the scraper.
+        let previous_content = previous_revision.map(|revision| {
+            match tracker.settings.capture_cookies.and_then(|_| {
+                serde_json::from_str::<WebPageContentWithCookies>(&revision.data).ok()
+            }) {
+                Some(revision_data) => Cow::Owned(revision_data.content.to_string()),
+                None => Cow::Borrowed(revision.data.as_str()),
+            }
+        });
+
+        let scraper_request = WebScraperContentRequest::with_default_parameters(&tracker.url)
+            .set_delay(tracker.settings.delay)
+            .set_ignore_https_errors(tracker.settings.insecure_tls)
+            .set_disable_js(!tracker.settings.render_js)
+            .set_capture_cookies(tracker.settings.capture_cookies.is_some());
+        let scraper_request = if let Some(method) = tracker.settings.method.as_deref() {
+            scraper_request.set_method(method)
+        } else {
+            scraper_request
+        };
+        let scraper_request = if let Some(request_body) = tracker.settings.request_body.as_deref() {
+            scraper_request.set_body(request_body)
+        } else {
+            scraper_request
+        };
+        let scraper_request = if let Some(previous_content) = previous_content.as_deref() {
+            scraper_request.set_previous_content(previous_content)
+        } else {
+            scraper_request
+        };
+
+        let extract_content_script = tracker
+            .settings
+            .scripts
+            .as_ref()
+            .and_then(|scripts| scripts.get(WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME));
+        let scraper_request = if let Some(extract_content) = extract_content_script {
+            scraper_request.set_scripts(WebScraperContentRequestScripts {
+                extract_content: Some(extract_content),
+            })
+        } else {
+            scraper_request
+        };
+
+        // If only a part of the content should be captured, ask the server for the specific range
+        // of bytes in addition to the custom headers.
+        let headers = self.resolve_tracker_headers(tracker)?;
+        let headers = if let Some((start, end)) = tracker.settings.byte_range {
+            let mut headers = headers.map(Cow::into_owned).unwrap_or_default();
+            headers.insert(RANGE.to_string(), format!("bytes={start}-{end}"));
+            Some(Cow::Owned(headers))
+        } else {
+            headers
+        };
+        let scraper_request = if let Some(headers) = headers.as_deref() {
+            scraper_request.set_headers(headers)
+        } else {
+            scraper_request
+        };
+
+        let mut scraper_response = self
+            .send_tracker_scraper_request::<_, WebScraperContentResponse>(
+                tracker,
+                "api/web_page/content",
+                &scraper_request,
+            )
+            .await?;
+
+        // Some servers ignore the `Range` header and return the full content, in this case
+        // extract the requested range of bytes from the content manually.
+        if let Some((start, end)) = tracker.settings.byte_range {
+            let range_length = usize::try_from(end - start + 1).unwrap_or(usize::MAX);
+            if scraper_response.content.len() > range_length {
+                log::warn!(
+                    user:serde = self.user.log_context(),
+                    util:serde = tracker.log_context();
+                    "Web page returned more content than requested by the byte range, truncating the content."
+                );
+
+                let content = scraper_response.content.as_bytes();
+                let start = usize::try_from(start)
+                    .unwrap_or(usize::MAX)
+                    .min(content.len());
+                let end = start.saturating_add(range_length).min(content.len());
+                scraper_response.content =
+                    String::from_utf8_lossy(&content[start..end]).into_owned();
+            }
         }
 
-        let revisions = self
-            .api
-            .db
-            .web_scraping(self.user.id)
-            .get_web_page_tracker_history::<WebPageResourcesTrackerInternalTag>(tracker_id)
-            .await?
-            .into_iter()
-            .map(|revision| WebPageDataRevision {
-                id: revision.id,
-                tracker_id: revision.tracker_id,
-                data: WebPageResourcesData {
-                    scripts: revision.data.scripts.into_iter().map(Into::into).collect(),
-                    styles: revision.data.styles.into_iter().map(Into::into).collect(),
-                },
-                created_at: revision.created_at,
+        Ok(scraper_response)
+    }
+
+    /// Fetches HTTP status of the web page for the specified content tracker without fetching its
+    /// content. Redirects aren't followed, so redirect status is recorded as is.
+    async fn fetch_web_page_status(
+        &self,
+        tracker: &WebPageTracker<WebPageContentTrackerTag>,
+        url: &Url,
+    ) -> anyhow::Result<(OffsetDateTime, WebPageContentStatus)> {
+        let timestamp = OffsetDateTime::now_utc();
+        let started_at = Instant::now();
+        let response = self.send_web_page_request(tracker, url).await?;
+
+        Ok((
+            timestamp,
+            WebPageContentStatus {
+                status: response.status().as_u16(),
+                latency: started_at.elapsed(),
+            },
+        ))
+    }
+    /// Fetches HTTP status of the main and every additional URL of the specified content tracker
+    /// without the web scraper. URLs are fetched concurrently, but no more than the tracker
+    /// sub-fetch concurrency at once. The timestamp of the main URL request is used for the
+    /// revision.
+    async fn fetch_web_page_statuses(
+        &self,
+        tracker: &WebPageTracker<WebPageContentTrackerTag>,
+        additional_urls: &[Url],
+    ) -> anyhow::Result<(OffsetDateTime, WebPageContentStatuses)> {
+        let fetches = iter::once(&tracker.url)
+            .chain(additional_urls)
+            .map(|url| async move {
+                let (timestamp, status) = self.fetch_web_page_status(tracker, url).await?;
+                anyhow::Ok((url, timestamp, status))
             })
             .collect::<Vec<_>>();
-        if params.calculate_diff {
-            web_page_resources_revisions_diff(revisions)
+        let responses = stream::iter(fetches)
+            .buffered(self.get_sub_fetch_concurrency(tracker))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        // Responses are buffered in the original order, so the main URL always comes first.
+        let timestamp = responses
+            .first()
+            .map(|(_, timestamp, _)| *timestamp)
+            .unwrap_or_else(OffsetDateTime::now_utc);
+        let statuses = responses
+            .into_iter()
+            .map(|(url, _, status)| (url.to_string(), status))
+            .collect::<BTreeMap<_, _>>();
+
+        Ok((timestamp, WebPageContentStatuses { statuses }))
+    }
+
+    /// Returns the maximum number of concurrent requests the tracker can send within a single run.
+    fn get_sub_fetch_concurrency<Tag: WebPageTrackerTag>(
+        &self,
+        tracker: &WebPageTracker<Tag>,
+    ) -> usize {
+        tracker
+            .settings
+            .sub_fetch_concurrency
+            .unwrap_or_else(|| self.api.config.utils.web_scraping.sub_fetch_concurrency())
+            .max(1)
+    }
+
+    /// Fetches HTTP status, the requested headers, and the body of the web page (usually JSON API)
+    /// for the specified content tracker without the web scraper. Redirects aren't followed, so
+    /// redirect responses are captured as is.
+    async fn fetch_web_page_response(
+        &self,
+        tracker: &WebPageTracker<WebPageContentTrackerTag>,
+        capture: &WebPageContentResponseCapture,
+    ) -> anyhow::Result<(OffsetDateTime, WebPageContentResponse)> {
+        let timestamp = OffsetDateTime::now_utc();
+        let response = self.send_web_page_request(tracker, &tracker.url).await?;
+
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+        let body = response.text().await.map_err(|err| {
+            SecutilsError::client_with_root_cause(
+                anyhow!("Failed to read web page response body: {err:?}")
+                    .context("Web page tracker couldn't read the web page response."),
+            )
+            .with_code(ErrorCode::UpstreamError)
+        })?;
+
+        // Mask sensitive data in the response body before comparing and persisting it.
+        let body = if let Some(redaction_patterns) = self
+            .api
+            .users()
+            .get_redaction_patterns(self.user.id)
+            .await?
+        {
+            redaction_patterns.redact_text(&body).into_owned()
         } else {
-            Ok(revisions)
+            body
+        };
+
+        Ok((
+            timestamp,
+            WebPageContentResponse::new(status, &headers, &body, capture),
+        ))
+    }
+
+    /// Fetches the resource (e.g. favicon) for the specified content tracker without the web
+    /// scraper and calculates its fingerprint. The resource content itself isn't retained.
+    async fn fetch_web_page_fingerprint(
+        &self,
+        tracker: &WebPageTracker<WebPageContentTrackerTag>,
+    ) -> anyhow::Result<(OffsetDateTime, WebPageContentFingerprint)> {
+        let timestamp = OffsetDateTime::now_utc();
+        let response = self.send_web_page_request(tracker, &tracker.url).await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker couldn't fetch the resource to fingerprint, received {status} HTTP status."
+            ))
+            .with_code(ErrorCode::UpstreamError));
         }
+
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(|content_type| content_type.to_string());
+        let content = response.bytes().await.map_err(|err| {
+            SecutilsError::client_with_root_cause(
+                anyhow!("Failed to read web page response body: {err:?}")
+                    .context("Web page tracker couldn't read the web page response."),
+            )
+            .with_code(ErrorCode::UpstreamError)
+        })?;
+
+        Ok((
+            timestamp,
+            WebPageContentFingerprint::new(&content, content_type.as_deref()),
+        ))
     }
 
-    /// Returns all stored webpage content tracker history.
-    pub async fn get_content_tracker_history(
+    /// Returns custom tracker headers with secret references (`secret://name`) resolved to the
+    /// secrets of the tracker owner. Resolved values are never persisted.
+    fn resolve_tracker_headers<'t, Tag: WebPageTrackerTag>(
+        &self,
+        tracker: &'t WebPageTracker<Tag>,
+    ) -> anyhow::Result<Option<Cow<'t, HashMap<String, String>>>> {
+        tracker
+            .settings
+            .headers
+            .as_ref()
+            .map(|headers| {
+                resolve_secrets(
+                    self.api.secrets.as_ref(),
+                    Some(&tracker.user_id.to_string()),
+                    headers,
+                )
+            })
+            .transpose()
+    }
+
+    /// Sends a request (GET, unless tracker overrides the method) with the custom tracker headers
+    /// and body to the specified URL of the content tracker (main or additional one), bypassing the
+    /// web scraper. Redirects aren't followed.
+    async fn send_web_page_request(
+        &self,
+        tracker: &WebPageTracker<WebPageContentTrackerTag>,
+        url: &Url,
+    ) -> anyhow::Result<reqwest::Response> {
+        self.api.network.check_outbound_access()?;
+
+        let headers = if let Some(headers) = self.resolve_tracker_headers(tracker)? {
+            HeaderMap::try_from(&*headers).map_err(|err| {
+                SecutilsError::client_with_root_cause(
+                    anyhow!("Failed to parse headers: {err:?}")
+                        .context("Web page tracker headers are not valid."),
+                )
+            })?
+        } else {
+            HeaderMap::new()
+        };
+
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .timeout(WEB_PAGE_STATUS_TRACKER_TIMEOUT)
+            .danger_accept_invalid_certs(tracker.settings.insecure_tls)
+            .build()?;
+
+        let method = if let Some(method) = tracker.settings.method.as_deref() {
+            Method::from_bytes(method.as_bytes()).map_err(|err| {
+                SecutilsError::client_with_root_cause(
+                    anyhow!("Failed to parse HTTP method: {err:?}")
+                        .context("Web page tracker HTTP method is not valid."),
+                )
+            })?
+        } else {
+            Method::GET
+        };
+        let request = client.request(method, url.clone()).headers(headers);
+        let request = if let Some(request_body) = tracker.settings.request_body.as_ref() {
+            request.body(request_body.clone())
+        } else {
+            request
+        };
+
+        let response = request.send().await.map_err(|err| {
+            SecutilsError::client_with_root_cause(
+                anyhow!("Failed to fetch web page: {err:?}")
+                    .context("Web page tracker couldn't reach the web page."),
+            )
+            .with_code(ErrorCode::UpstreamError)
+        })?;
+
+        Ok(response)
+    }
+
+    /// Persists history for the specified web page screenshot tracker. Returns a new revision only
+    /// if it visually differs from the previous one more than the predefined threshold.
+    pub async fn create_screenshot_tracker_revision(
         &self,
         tracker_id: Uuid,
-        params: WebPageContentTrackerGetHistoryParams,
-    ) -> anyhow::Result<Vec<WebPageDataRevision<WebPageContentTrackerTag>>> {
-        if params.refresh {
-            self.create_content_tracker_revision(tracker_id).await?;
-        } else if self.get_content_tracker(tracker_id).await?.is_none() {
+    ) -> anyhow::Result<Option<WebPageDataRevision<WebPageScreenshotTrackerTag>>> {
+        let Some(tracker) = self.get_screenshot_tracker(tracker_id).await? else {
             bail!(SecutilsError::client(format!(
                 "Web page tracker ('{tracker_id}') is not found."
-            )));
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        };
+
+        if self.get_max_screenshot_tracker_revisions(&tracker) == 0 {
+            return Ok(None);
         }
 
-        let revisions = self
-            .api
-            .db
-            .web_scraping(self.user.id)
-            .get_web_page_tracker_history::<WebPageContentTrackerTag>(tracker_id)
-            .await?;
-        if params.calculate_diff {
-            web_page_content_revisions_diff(revisions)
+        let scraper_request = WebScraperScreenshotRequest::with_default_parameters(&tracker.url)
+            .set_delay(tracker.settings.delay)
+            .set_ignore_https_errors(tracker.settings.insecure_tls)
+            .set_disable_js(!tracker.settings.render_js);
+        let headers = self.resolve_tracker_headers(&tracker)?;
+        let scraper_request = if let Some(headers) = headers.as_deref() {
+            scraper_request.set_headers(headers)
         } else {
-            Ok(revisions)
+            scraper_request
+        };
+
+        // Long-running scrapes are submitted to the web scraper as asynchronous jobs, the revision
+        // is created once the web scraper delivers the screenshot to the callback.
+        if self.api.config.utils.web_scraping.async_scrapes {
+            self.submit_screenshot_tracker_scrape(&tracker, scraper_request)
+                .await?;
+            return Ok(None);
         }
+
+        let scraper_response = self
+            .send_tracker_scraper_request::<_, WebScraperScreenshotResponse>(
+                &tracker,
+                "api/web_page/screenshot",
+                &scraper_request,
+            )
+            .await?;
+
+        self.store_screenshot_tracker_revision(&tracker, scraper_response)
+            .await
     }
 
-    /// Removes all persisted resources for the specified web page resources tracker.
-    pub async fn clear_web_page_tracker_history(&self, tracker_id: Uuid) -> anyhow::Result<()> {
-        self.api
+    /// Processes the result of the asynchronous scrape job of the web page screenshot tracker that
+    /// the web scraper delivered to the callback. The result of every scrape job is processed only
+    /// once. Returns a new revision only if it visually differs from the previous one more than
+    /// the predefined threshold.
+    pub async fn complete_screenshot_tracker_scrape(
+        &self,
+        pending_scrape: &WebPageTrackerPendingScrape,
+        result: WebScraperCallbackResult<WebScraperScreenshotResponse>,
+    ) -> anyhow::Result<Option<WebPageDataRevision<WebPageScreenshotTrackerTag>>> {
+        if !self
+            .api
             .db
             .web_scraping(self.user.id)
-            .clear_web_page_tracker_history(tracker_id)
+            .remove_web_page_tracker_pending_scrape(&pending_scrape.job_id)
+            .await?
+        {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker scrape job ('{}') is not found.",
+                pending_scrape.job_id
+            )));
+        }
+
+        let Some(tracker) = self
+            .get_screenshot_tracker(pending_scrape.tracker_id)
+            .await?
+        else {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{}') is not found.",
+                pending_scrape.tracker_id
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        };
+
+        if let Some(error) = result.error {
+            bail!(SecutilsError::client(error.message).with_code(ErrorCode::UpstreamError));
+        }
+
+        let Some(scraper_response) = result.response else {
+            bail!(SecutilsError::client(format!(
+                "Web scraper didn't deliver the result of the scrape job ('{}').",
+                pending_scrape.job_id
+            ))
+            .with_code(ErrorCode::UpstreamError));
+        };
+
+        // Tracker might have been updated since the scrape job was submitted.
+        if self.get_max_screenshot_tracker_revisions(&tracker) == 0 {
+            return Ok(None);
+        }
+
+        self.store_screenshot_tracker_revision(&tracker, scraper_response)
             .await
     }
 
-    /// Returns all web page trackers.
-    async fn get_web_page_trackers<Tag: WebPageTrackerTag>(
+    /// Submits the scrape job of the web page screenshot tracker to the web scraper in the
+    /// asynchronous mode, and records it as pending until the web scraper delivers the result.
+    async fn submit_screenshot_tracker_scrape(
         &self,
-    ) -> anyhow::Result<Vec<WebPageTracker<Tag>>> {
+        tracker: &WebPageTracker<WebPageScreenshotTrackerTag>,
+        scraper_request: WebScraperScreenshotRequest<'_>,
+    ) -> anyhow::Result<()> {
+        let callback_url = self.api.config.public_url.join(WEB_SCRAPER_CALLBACK_PATH)?;
+        let token = generate_scraper_callback_token();
+        let job_response = self
+            .send_tracker_scraper_request::<_, WebScraperJobResponse>(
+                tracker,
+                "api/web_page/screenshot",
+                &scraper_request.set_callback(WebScraperCallback {
+                    url: &callback_url,
+                    token: &token,
+                }),
+            )
+            .await?;
+
         self.api
             .db
             .web_scraping(self.user.id)
-            .get_web_page_trackers()
+            .insert_web_page_tracker_pending_scrape(&WebPageTrackerPendingScrape {
+                job_id: job_response.job_id,
+                tracker_id: tracker.id,
+                token,
+                created_at: OffsetDateTime::now_utc(),
+            })
             .await
     }
 
-    /// Returns web page tracker by its ID.
-    async fn get_web_page_tracker<Tag: WebPageTrackerTag>(
+    /// Returns the maximum number of revisions web page screenshot tracker can keep. Screenshots
+    /// are heavy, so an additional limit is applied on top of the subscription one.
+    fn get_max_screenshot_tracker_revisions(
         &self,
-        id: Uuid,
-    ) -> anyhow::Result<Option<WebPageTracker<Tag>>> {
-        self.api
-            .db
-            .web_scraping(self.user.id)
-            .get_web_page_tracker(id)
-            .await
+        tracker: &WebPageTracker<WebPageScreenshotTrackerTag>,
+    ) -> usize {
+        let features = self.user.subscription.get_features(&self.api.config);
+        tracker
+            .settings
+            .revisions
+            .min(features.config.web_scraping.tracker_revisions)
+            .min(MAX_WEB_PAGE_SCREENSHOT_TRACKER_REVISIONS)
     }
 
-    /// Creates a new web page tracker.
-    async fn create_web_page_tracker<Tag: WebPageTrackerTag, V>(
+    /// Persists the screenshot captured by the web scraper as a new revision of the web page
+    /// screenshot tracker, if it visually differs from the previous one more than the predefined
+    /// threshold.
+    async fn store_screenshot_tracker_revision(
         &self,
-        params: WebPageTrackerCreateParams,
-        validator: Option<V>,
-    ) -> anyhow::Result<WebPageTracker<Tag>>
-    where
-        V: Fn(&WebPageTracker<Tag>) -> anyhow::Result<()>,
-    {
-        // Preserve timestamp only up to seconds.
-        let created_at =
-            OffsetDateTime::from_unix_timestamp(OffsetDateTime::now_utc().unix_timestamp())?;
-        let tracker = WebPageTracker {
-            id: Uuid::now_v7(),
-            name: params.name,
-            url: params.url,
-            settings: params.settings,
-            user_id: self.user.id,
-            job_id: None,
-            job_config: params.job_config,
-            created_at,
-            updated_at: created_at,
-            meta: None,
-        };
+        tracker: &WebPageTracker<WebPageScreenshotTrackerTag>,
+        scraper_response: WebScraperScreenshotResponse,
+    ) -> anyhow::Result<Option<WebPageDataRevision<WebPageScreenshotTrackerTag>>> {
+        let max_revisions = self.get_max_screenshot_tracker_revisions(tracker);
 
-        self.validate_web_page_tracker(&tracker).await?;
-        // Run custom validator if specified.
-        if let Some(validator) = validator {
-            validator(&tracker)?;
-        }
-
-        self.api
-            .db
-            .web_scraping(self.user.id)
-            .insert_web_page_tracker(&tracker)
+        // Check if there is a revision with the same timestamp. If so, drop newly fetched revision.
+        let web_scraping = self.api.db.web_scraping(self.user.id);
+        let revisions = web_scraping
+            .get_web_page_tracker_history::<WebPageScreenshotTrackerTag>(tracker.id)
             .await?;
-
-        Ok(tracker)
-    }
-
-    /// Updates existing web page tracker.
-    async fn update_web_page_tracker<Tag: WebPageTrackerTag, V>(
-        &self,
-        id: Uuid,
-        params: WebPageTrackerUpdateParams,
-        validator: Option<V>,
-    ) -> anyhow::Result<WebPageTracker<Tag>>
-    where
-        V: Fn(&WebPageTracker<Tag>) -> anyhow::Result<()>,
-    {
-        if params.name.is_none()
-            && params.url.is_none()
-            && params.settings.is_none()
-            && params.job_config.is_none()
+        let baseline_revision_id = web_scraping
+            .get_web_page_tracker_baseline_revision_id(tracker.id)
+            .await?;
+        if revisions
+            .iter()
+            .any(|revision| revision.created_at == scraper_response.timestamp)
         {
-            bail!(SecutilsError::client(format!(
-                "Either new name, url, settings, or job config should be provided ({id})."
-            )));
+            return Ok(None);
         }
 
-        let Some(existing_tracker) = self
-            .api
-            .db
-            .web_scraping(self.user.id)
-            .get_web_page_tracker(id)
-            .await?
-        else {
-            bail!(SecutilsError::client(format!(
-                "Web page tracker ('{id}') is not found."
-            )));
-        };
-
-        let changed_url = params
-            .url
-            .as_ref()
-            .map(|url| url != &existing_tracker.url)
-            .unwrap_or_default();
-
-        let disabled_revisions = params
-            .settings
-            .as_ref()
-            .map(|settings| settings.revisions == 0)
-            .unwrap_or_default();
-
-        let changed_schedule = params
-            .job_config
-            .as_ref()
-            .map(
-                |job_config| match (&existing_tracker.job_config, job_config) {
-                    (Some(existing_job_config), Some(job_config)) => {
-                        job_config.schedule != existing_job_config.schedule
-                    }
-                    _ => true,
-                },
+        // Compare the new screenshot with the latest one and drop it if the visual difference
+        // doesn't exceed the threshold.
+        let diff = if let Some(latest_revision) = revisions.last() {
+            let diff = web_page_screenshots_diff(
+                &latest_revision.data.image,
+                &scraper_response.screenshot,
             )
-            .unwrap_or_default();
+            .map_err(|err| {
+                anyhow!(
+                    "Could not compare screenshots for the web tracker ('{}'): {:?}",
+                    tracker.id,
+                    err
+                )
+            })?;
+            if diff < WEB_PAGE_SCREENSHOT_TRACKER_DIFF_THRESHOLD {
+                return Ok(None);
+            }
 
-        let job_id = if disabled_revisions || changed_schedule {
-            None
+            // If the baseline revision is pinned, report difference from the baseline instead of
+            // the latest revision.
+            let baseline_revision = revisions.iter().find(|revision| {
+                Some(revision.id) == baseline_revision_id && revision.id != latest_revision.id
+            });
+            if let Some(baseline_revision) = baseline_revision {
+                Some(
+                    web_page_screenshots_diff(
+                        &baseline_revision.data.image,
+                        &scraper_response.screenshot,
+                    )
+                    .map_err(|err| {
+                        anyhow!(
+                            "Could not compare screenshots for the web tracker ('{}'): {:?}",
+                            tracker.id,
+                            err
+                        )
+                    })?,
+                )
+            } else {
+                Some(diff)
+            }
         } else {
-            existing_tracker.job_id
+            None
         };
 
-        let tracker = WebPageTracker {
-            name: params.name.unwrap_or(existing_tracker.name),
-            url: params.url.unwrap_or(existing_tracker.url),
-            settings: params.settings.unwrap_or(existing_tracker.settings),
-            job_id,
-            job_config: params.job_config.unwrap_or(existing_tracker.job_config),
-            // Preserve timestamp only up to seconds.
-            updated_at: OffsetDateTime::from_unix_timestamp(
-                OffsetDateTime::now_utc().unix_timestamp(),
+        let data = WebPageScreenshotData {
+            image: scraper_response.screenshot,
+            diff: None,
+        };
+        let new_revision = WebPageDataRevision {
+            id: WebPageDataRevision::<WebPageScreenshotTrackerTag>::derive_id(
+                tracker.id,
+                scraper_response.timestamp,
+                &data,
             )?,
-            ..existing_tracker
+            tracker_id: tracker.id,
+            data,
+            created_at: scraper_response.timestamp,
+            note: None,
+            acknowledgment: None,
+            diff_stats: None,
+            assertions_passed: None,
         };
 
-        self.validate_web_page_tracker(&tracker).await?;
-        if let Some(validator) = validator {
-            validator(&tracker)?;
-        }
-
-        let web_scraping = self.api.db.web_scraping(self.user.id);
-        web_scraping.update_web_page_tracker(&tracker).await?;
+        // Insert new revision.
+        web_scraping
+            .insert_web_page_tracker_history_revision::<WebPageScreenshotTrackerTag>(&new_revision)
+            .await?;
 
-        if changed_url {
-            log::debug!("Web page tracker ('{id}') changed URL, clearing web resources history.");
-            web_scraping.clear_web_page_tracker_history(id).await?;
+        // Enforce revisions limit and displace old ones, the baseline revision is never displaced.
+        if revisions.len() >= max_revisions {
+            let revisions_to_remove = revisions.len() - max_revisions + 1;
+            for revision in revisions
+                .iter()
+                .filter(|revision| Some(revision.id) != baseline_revision_id)
+                .take(revisions_to_remove)
+            {
+                web_scraping
+                    .remove_web_page_tracker_history_revision(tracker.id, revision.id)
+                    .await?;
+            }
         }
 
-        Ok(tracker)
+        // Similar to resources trackers, the very first revision isn't considered as a change.
+        Ok(diff.map(|diff| WebPageDataRevision {
+            data: WebPageScreenshotData {
+                diff: Some(diff),
+                ..new_revision.data
+            },
+            ..new_revision
+        }))
     }
 
-    async fn validate_web_page_tracker<Tag: WebPageTrackerTag>(
+    /// Persists history for the specified web page DNS records tracker. Returns a new revision
+    /// only if the set of the resolved DNS records differs from the previous one.
+    pub async fn create_dns_records_tracker_revision(
         &self,
-        tracker: &WebPageTracker<Tag>,
-    ) -> anyhow::Result<()> {
-        if tracker.name.is_empty() {
-            bail!(SecutilsError::client(
-                "Web page tracker name cannot be empty.",
-            ));
-        }
-
-        if tracker.name.len() > MAX_UTILS_ENTITY_NAME_LENGTH {
+        tracker_id: Uuid,
+    ) -> anyhow::Result<Option<WebPageDataRevision<WebPageDnsRecordsTrackerTag>>> {
+        let Some(tracker) = self.get_dns_records_tracker(tracker_id).await? else {
             bail!(SecutilsError::client(format!(
-                "Web page tracker name cannot be longer than {} characters.",
-                MAX_UTILS_ENTITY_NAME_LENGTH
-            )));
-        }
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        };
 
         let features = self.user.subscription.get_features(&self.api.config);
-        if tracker.settings.revisions > features.config.web_scraping.tracker_revisions {
-            bail!(SecutilsError::client(format!(
-                "Web page tracker revisions count cannot be greater than {}.",
-                features.config.web_scraping.tracker_revisions
-            )));
+        let max_revisions = std::cmp::min(
+            tracker.settings.revisions,
+            features.config.web_scraping.tracker_revisions,
+        );
+        if max_revisions == 0 {
+            return Ok(None);
         }
 
-        if tracker.settings.delay > MAX_WEB_PAGE_TRACKER_DELAY {
+        let Some(domain) = tracker.url.host_str() else {
             bail!(SecutilsError::client(format!(
-                "Web page tracker delay cannot be greater than {}ms.",
-                MAX_WEB_PAGE_TRACKER_DELAY.as_millis()
-            )));
-        }
+                "Web page tracker ('{}') URL doesn't have a domain name.",
+                tracker.id
+            ))
+            .with_code(ErrorCode::InvalidUrl));
+        };
 
-        if let Some(ref scripts) = tracker.settings.scripts {
-            if scripts
-                .iter()
-                .any(|(name, script)| name.is_empty() || script.is_empty())
-            {
-                bail!(SecutilsError::client(
-                    "Web page tracker scripts cannot be empty or have an empty name."
-                ));
-            }
-        }
+        self.api.network.check_outbound_access()?;
 
-        if let Some(job_config) = &tracker.job_config {
-            // Validate that the schedule is a valid cron expression.
-            let schedule = match Schedule::try_from(job_config.schedule.as_str()) {
-                Ok(schedule) => schedule,
+        let mut record_types = tracker
+            .settings
+            .record_types
+            .clone()
+            .unwrap_or_else(|| WebPageDnsRecordType::ALL.to_vec());
+        record_types.sort();
+        record_types.dedup();
+
+        let mut records = vec![];
+        for record_type in record_types {
+            let lookup = match self
+                .api
+                .network
+                .resolver
+                .lookup(domain, record_type.into())
+                .await
+            {
+                Ok(lookup) => lookup,
+                // Missing records of the specific type is a valid state that should be tracked.
+                Err(err) if matches!(err.kind(), ResolveErrorKind::NoRecordsFound { .. }) => {
+                    continue;
+                }
                 Err(err) => {
-                    bail!(SecutilsError::client_with_root_cause(
-                        anyhow!(
-                            "Failed to parse schedule `{}`: {err:?}",
-                            job_config.schedule
-                        )
-                        .context("Web page tracker schedule must be a valid cron expression.")
-                    ));
+                    bail!(SecutilsError::client(format!(
+                        "Cannot resolve {record_type:?} DNS records for the web page tracker ('{}'): {err}",
+                        tracker.id
+                    )));
                 }
             };
 
-            // Check if the interval between next occurrences is greater or equal to minimum
-            // interval defined by the subscription.
-            let min_schedule_interval = schedule.min_interval()?;
-            if min_schedule_interval < features.config.web_scraping.min_schedule_interval {
-                bail!(SecutilsError::client(format!(
-                    "Web page tracker schedule must have at least {} between occurrences, but detected {}.",
-                    humantime::format_duration(features.config.web_scraping.min_schedule_interval),
-                    humantime::format_duration(min_schedule_interval)
-                )));
+            // Lookup may include records of other types (e.g. CNAME records for the A lookup).
+            let mut values = lookup
+                .record_iter()
+                .filter(|record| record.record_type() == RecordType::from(record_type))
+                .filter_map(|record| record.data().map(|data| data.to_string()))
+                .collect::<Vec<_>>();
+            values.sort();
+            values.dedup();
+
+            records.extend(
+                values
+                    .into_iter()
+                    .map(|value| WebPageDnsRecord::new(record_type, value)),
+            );
+        }
+
+        let web_scraping = self.api.db.web_scraping(self.user.id);
+        let revisions = web_scraping
+            .get_web_page_tracker_history::<WebPageDnsRecordsTrackerTag>(tracker.id)
+            .await?;
+        let baseline_revision_id = web_scraping
+            .get_web_page_tracker_baseline_revision_id(tracker.id)
+            .await?;
+
+        // Drop the new revision if the DNS records haven't changed since the latest revision.
+        if let Some(latest_revision) = revisions.last() {
+            if latest_revision.data.records == records {
+                return Ok(None);
             }
+        }
 
-            // Validate retry strategy.
-            if let Some(retry_strategy) = &job_config.retry_strategy {
-                let max_attempts = retry_strategy.max_attempts();
-                if max_attempts == 0 || max_attempts > MAX_WEB_PAGE_TRACKER_RETRY_ATTEMPTS {
-                    bail!(SecutilsError::client(
-                        format!("Web page tracker max retry attempts cannot be zero or greater than {MAX_WEB_PAGE_TRACKER_RETRY_ATTEMPTS}, but received {max_attempts}.")
-                    ));
-                }
+        let data = WebPageDnsRecordsData { records };
+        let created_at = OffsetDateTime::now_utc();
+        let new_revision = WebPageDataRevision {
+            id: WebPageDataRevision::<WebPageDnsRecordsTrackerTag>::derive_id(
+                tracker.id, created_at, &data,
+            )?,
+            tracker_id: tracker.id,
+            data,
+            created_at,
+            note: None,
+            acknowledgment: None,
+            diff_stats: None,
+            assertions_passed: None,
+        };
 
-                let min_interval = *retry_strategy.min_interval();
-                if min_interval < MIN_WEB_PAGE_TRACKER_RETRY_INTERVAL {
-                    bail!(SecutilsError::client(
-                        format!(
-                            "Web page tracker min retry interval cannot be less than {}, but received {}.",
-                            humantime::format_duration(MIN_WEB_PAGE_TRACKER_RETRY_INTERVAL),
-                            humantime::format_duration(min_interval)
-                        )
-                    ));
-                }
-
-                if let SchedulerJobRetryStrategy::Linear { max_interval, .. }
-                | SchedulerJobRetryStrategy::Exponential { max_interval, .. } = retry_strategy
-                {
-                    let max_interval = *max_interval;
-                    if max_interval < MIN_WEB_PAGE_TRACKER_RETRY_INTERVAL {
-                        bail!(SecutilsError::client(
-                            format!(
-                                "Web page tracker retry strategy max interval cannot be less than {}, but received {}.",
-                                humantime::format_duration(MIN_WEB_PAGE_TRACKER_RETRY_INTERVAL),
-                                humantime::format_duration(max_interval)
-                            )
-                        ));
-                    }
+        // Insert new revision.
+        web_scraping
+            .insert_web_page_tracker_history_revision::<WebPageDnsRecordsTrackerTag>(&new_revision)
+            .await?;
 
-                    if max_interval > MAX_WEB_PAGE_TRACKER_RETRY_INTERVAL
-                        || max_interval > min_schedule_interval
-                    {
-                        bail!(SecutilsError::client(
-                            format!(
-                                "Web page tracker retry strategy max interval cannot be greater than {}, but received {}.",
-                                humantime::format_duration(MAX_WEB_PAGE_TRACKER_RETRY_INTERVAL.min(min_schedule_interval)),
-                                humantime::format_duration(max_interval)
-                            )
-                        ));
-                    }
-                }
+        // Enforce revisions limit and displace old ones, the baseline revision is never displaced.
+        if revisions.len() >= max_revisions {
+            let revisions_to_remove = revisions.len() - max_revisions + 1;
+            for revision in revisions
+                .iter()
+                .filter(|revision| Some(revision.id) != baseline_revision_id)
+                .take(revisions_to_remove)
+            {
+                web_scraping
+                    .remove_web_page_tracker_history_revision(tracker.id, revision.id)
+                    .await?;
             }
         }
 
-        if !self.api.network.is_public_web_url(&tracker.url).await {
-            bail!(SecutilsError::client(
-                format!("Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received {}.", tracker.url)
-            ));
-        }
+        // Similar to resources trackers, the very first revision isn't considered as a change. If
+        // the baseline revision is pinned, report difference from the baseline instead of the
+        // latest revision.
+        let Some(latest_revision) = revisions.last() else {
+            return Ok(None);
+        };
+        let previous_revision = revisions
+            .iter()
+            .find(|revision| Some(revision.id) == baseline_revision_id)
+            .unwrap_or(latest_revision);
 
-        Ok(())
+        Ok(
+            web_page_dns_records_revisions_diff(vec![previous_revision.clone(), new_revision])?
+                .pop(),
+        )
     }
 
-    fn validate_web_page_resources_tracker(
+    /// Returns all stored webpage resources tracker history.
+    pub async fn get_resources_tracker_history(
         &self,
-        tracker: &WebPageTracker<WebPageResourcesTrackerTag>,
-    ) -> anyhow::Result<()> {
-        if let Some(ref scripts) = tracker.settings.scripts {
-            if !scripts.is_empty()
-                && !scripts.contains_key(WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME)
-            {
-                bail!(SecutilsError::client(
-                    "Web page tracker contains unrecognized scripts."
-                ));
-            }
+        tracker_id: Uuid,
+        params: WebPageResourcesTrackerGetHistoryParams,
+    ) -> anyhow::Result<Vec<WebPageDataRevision<WebPageResourcesTrackerTag>>> {
+        if params.refresh {
+            self.create_resources_tracker_revision(tracker_id).await?;
         }
 
-        Ok(())
-    }
+        let Some(tracker) = self.get_resources_tracker(tracker_id).await? else {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        };
 
-    fn validate_web_page_content_tracker(
-        &self,
-        tracker: &WebPageTracker<WebPageContentTrackerTag>,
-    ) -> anyhow::Result<()> {
-        if let Some(ref scripts) = tracker.settings.scripts {
-            if !scripts.is_empty()
-                && !scripts.contains_key(WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME)
-            {
-                bail!(SecutilsError::client(
-                    "Web page tracker contains unrecognized scripts."
-                ));
+        let classify_resources = tracker.settings.classify_resources;
+        let resource_types = tracker.settings.resource_types.as_deref();
+        let mut revisions = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker_history::<WebPageResourcesTrackerInternalTag>(tracker_id)
+            .await?
+            .into_iter()
+            .map(|revision| WebPageDataRevision {
+                id: revision.id,
+                tracker_id: revision.tracker_id,
+                data: web_page_resources_data(revision.data, resource_types, classify_resources),
+                created_at: revision.created_at,
+                note: revision.note,
+                acknowledgment: revision.acknowledgment,
+                diff_stats: revision.diff_stats,
+                assertions_passed: revision.assertions_passed,
+            })
+            .collect::<Vec<WebPageDataRevision<WebPageResourcesTrackerTag>>>();
+        self.set_last_viewed_at(tracker_id).await?;
+
+        // Calculate resources sizes for every revision if the tracker has a size budget.
+        if tracker.settings.size_budget.is_some() {
+            let mut previous_total = None;
+            for revision in revisions.iter_mut() {
+                let sizes = WebPageResourcesSizes {
+                    previous_total,
+                    ..revision.data.calculate_sizes()
+                };
+                previous_total = Some(sizes.total);
+                revision.data.sizes = Some(sizes);
             }
         }
 
-        Ok(())
+        if params.calculate_diff {
+            web_page_tracker_revisions_diff(
+                revisions,
+                self.get_baseline_revision_id(tracker_id).await?,
+                web_page_resources_revisions_diff,
+            )
+        } else {
+            Ok(revisions)
+        }
     }
-}
 
-impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
-    /// Returns an API to work with web scraping data.
-    pub fn web_scraping(&'a self, user: &'u User) -> WebScrapingApiExt<'a, 'u, DR, ET> {
-        WebScrapingApiExt::new(self, user)
-    }
-}
+    /// Returns all stored webpage content tracker history.
+    pub async fn get_content_tracker_history(
+        &self,
+        tracker_id: Uuid,
+        params: WebPageContentTrackerGetHistoryParams,
+    ) -> anyhow::Result<Vec<WebPageDataRevision<WebPageContentTrackerTag>>> {
+        if params.refresh {
+            self.create_content_tracker_revision(tracker_id).await?;
+        } else if self.get_content_tracker(tracker_id).await?.is_none() {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        }
 
-pub struct WebScrapingSystemApiExt<'a> {
-    web_scraping_system: WebScrapingDatabaseSystemExt<'a>,
-}
+        let revisions = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker_history::<WebPageContentTrackerTag>(tracker_id)
+            .await?;
+        self.set_last_viewed_at(tracker_id).await?;
 
-impl<'a> WebScrapingSystemApiExt<'a> {
-    /// Creates WebScraping System API.
-    pub fn new<DR: DnsResolver, ET: EmailTransport>(api: &'a Api<DR, ET>) -> Self {
-        Self {
-            web_scraping_system: api.db.web_scraping_system(),
+        if params.calculate_diff {
+            web_page_tracker_revisions_diff(
+                revisions,
+                self.get_baseline_revision_id(tracker_id).await?,
+                |revisions| {
+                    web_page_content_revisions_diff(
+                        revisions,
+                        params.diff_algorithm,
+                        self.api.config.utils.web_scraping.max_diff_size,
+                    )
+                },
+            )
+        } else {
+            Ok(revisions)
         }
     }
 
-    /// Returns all web page resources tracker job references that have jobs that need to be scheduled.
-    pub async fn get_unscheduled_resources_trackers(
+    /// Returns all stored webpage screenshot tracker history.
+    pub async fn get_screenshot_tracker_history(
         &self,
-    ) -> anyhow::Result<Vec<WebPageTracker<WebPageResourcesTrackerTag>>> {
-        self.get_unscheduled_web_page_trackers().await
-    }
+        tracker_id: Uuid,
+        params: WebPageScreenshotTrackerGetHistoryParams,
+    ) -> anyhow::Result<Vec<WebPageDataRevision<WebPageScreenshotTrackerTag>>> {
+        if params.refresh {
+            self.create_screenshot_tracker_revision(tracker_id).await?;
+        } else if self.get_screenshot_tracker(tracker_id).await?.is_none() {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        }
 
-    /// Returns all web page content tracker job references that have jobs that need to be scheduled.
-    pub async fn get_unscheduled_content_trackers(
-        &self,
-    ) -> anyhow::Result<Vec<WebPageTracker<WebPageContentTrackerTag>>> {
-        self.get_unscheduled_web_page_trackers().await
-    }
+        let revisions = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker_history::<WebPageScreenshotTrackerTag>(tracker_id)
+            .await?;
+        self.set_last_viewed_at(tracker_id).await?;
 
-    /// Returns all web page resources trackers that have pending jobs.
-    pub fn get_pending_resources_trackers(
-        &self,
-    ) -> impl Stream<Item = anyhow::Result<WebPageTracker<WebPageResourcesTrackerTag>>> + '_ {
-        self.web_scraping_system
-            .get_pending_web_page_trackers(MAX_JOBS_PAGE_SIZE)
+        if params.calculate_diff {
+            web_page_tracker_revisions_diff(
+                revisions,
+                self.get_baseline_revision_id(tracker_id).await?,
+                web_page_screenshot_revisions_diff,
+            )
+        } else {
+            Ok(revisions)
+        }
     }
 
-    /// Returns all web page resources trackers that have pending jobs.
-    pub fn get_pending_content_trackers(
+    /// Returns all stored webpage DNS records tracker history.
+    pub async fn get_dns_records_tracker_history(
         &self,
-    ) -> impl Stream<Item = anyhow::Result<WebPageTracker<WebPageContentTrackerTag>>> + '_ {
-        self.web_scraping_system
-            .get_pending_web_page_trackers(MAX_JOBS_PAGE_SIZE)
-    }
+        tracker_id: Uuid,
+        params: WebPageDnsRecordsTrackerGetHistoryParams,
+    ) -> anyhow::Result<Vec<WebPageDataRevision<WebPageDnsRecordsTrackerTag>>> {
+        if params.refresh {
+            self.create_dns_records_tracker_revision(tracker_id).await?;
+        } else if self.get_dns_records_tracker(tracker_id).await?.is_none() {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        }
 
-    /// Returns web page resources tracker by the corresponding job ID.
-    pub async fn get_resources_tracker_by_job_id(
-        &self,
-        job_id: Uuid,
-    ) -> anyhow::Result<Option<WebPageTracker<WebPageResourcesTrackerTag>>> {
-        self.get_web_page_tracker_by_job_id(job_id).await
-    }
+        let revisions = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker_history::<WebPageDnsRecordsTrackerTag>(tracker_id)
+            .await?;
+        self.set_last_viewed_at(tracker_id).await?;
 
-    /// Returns web page content tracker by the corresponding job ID.
-    pub async fn get_content_tracker_by_job_id(
-        &self,
-        job_id: Uuid,
-    ) -> anyhow::Result<Option<WebPageTracker<WebPageContentTrackerTag>>> {
-        self.get_web_page_tracker_by_job_id(job_id).await
+        if params.calculate_diff {
+            web_page_tracker_revisions_diff(
+                revisions,
+                self.get_baseline_revision_id(tracker_id).await?,
+                web_page_dns_records_revisions_diff,
+            )
+        } else {
+            Ok(revisions)
+        }
     }
 
-    /// Update resources tracker job ID reference (link or unlink).
-    pub async fn update_web_page_tracker_job(
+    /// Returns the most recent changes detected by all web page trackers of the user, ordered from
+    /// the newest to the oldest.
+    pub async fn get_changes_feed(
         &self,
-        id: Uuid,
-        job_id: Option<Uuid>,
-    ) -> anyhow::Result<()> {
-        self.web_scraping_system
-            .update_web_page_tracker_job(id, job_id)
+        params: WebPageTrackersGetChangesFeedParams,
+    ) -> anyhow::Result<Vec<WebPageTrackerChange>> {
+        let limit = params
+            .limit
+            .unwrap_or(DEFAULT_WEB_PAGE_TRACKER_CHANGES_FEED_LIMIT);
+        if limit == 0 || limit > MAX_WEB_PAGE_TRACKER_CHANGES_FEED_LIMIT {
+            bail!(SecutilsError::client(format!(
+                "Changes feed limit should be between 1 and {MAX_WEB_PAGE_TRACKER_CHANGES_FEED_LIMIT}, but received {limit}."
+            )));
+        }
+
+        self.api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker_changes(params.since.unwrap_or(OffsetDateTime::UNIX_EPOCH), limit)
             .await
     }
 
-    /// Returns all web page tracker job references that have jobs that need to be scheduled.
-    async fn get_unscheduled_web_page_trackers<Tag: WebPageTrackerTag>(
+    /// Compares the latest revisions of two web page resources trackers (e.g. trackers of the same
+    /// web page in different environments). Returns the latest revision of the tracker specified
+    /// in the params with the diff against the latest revision of the tracker with the specified
+    /// ID, or `None` if any of the trackers doesn't have revisions yet.
+    pub async fn diff_resources_trackers(
         &self,
-    ) -> anyhow::Result<Vec<WebPageTracker<Tag>>> {
-        self.web_scraping_system
-            .get_unscheduled_web_page_trackers()
-            .await
+        tracker_id: Uuid,
+        params: WebPageTrackersDiffParams,
+    ) -> anyhow::Result<Option<WebPageDataRevision<WebPageResourcesTrackerTag>>> {
+        let trackers = self
+            .get_web_page_trackers_to_diff::<WebPageResourcesTrackerTag>([
+                tracker_id,
+                params.tracker_id,
+            ])
+            .await?;
+
+        let web_scraping = self.api.db.web_scraping(self.user.id);
+        let mut revisions = Vec::with_capacity(trackers.len());
+        for tracker in trackers {
+            let Some(revision) = web_scraping
+                .get_web_page_tracker_history::<WebPageResourcesTrackerInternalTag>(tracker.id)
+                .await?
+                .pop()
+            else {
+                return Ok(None);
+            };
+
+            revisions.push(WebPageDataRevision {
+                id: revision.id,
+                tracker_id: revision.tracker_id,
+                data: web_page_resources_data(
+                    revision.data,
+                    tracker.settings.resource_types.as_deref(),
+                    tracker.settings.classify_resources,
+                ),
+                created_at: revision.created_at,
+                note: revision.note,
+                acknowledgment: revision.acknowledgment,
+                diff_stats: revision.diff_stats,
+                assertions_passed: revision.assertions_passed,
+            });
+        }
+
+        Ok(web_page_resources_revisions_diff(revisions)?.pop())
     }
 
-    /// Returns web page tracker by the corresponding job ID.
-    async fn get_web_page_tracker_by_job_id<Tag: WebPageTrackerTag>(
+    /// Compares the latest revisions of two web page content trackers (e.g. trackers of the same
+    /// web page in different environments). Returns the latest revision of the tracker specified
+    /// in the params with the diff against the latest revision of the tracker with the specified
+    /// ID, or `None` if any of the trackers doesn't have revisions yet.
+    pub async fn diff_content_trackers(
         &self,
-        job_id: Uuid,
-    ) -> anyhow::Result<Option<WebPageTracker<Tag>>> {
-        self.web_scraping_system
-            .get_web_page_tracker_by_job_id(job_id)
-            .await
-    }
-}
+        tracker_id: Uuid,
+        params: WebPageTrackersDiffParams,
+    ) -> anyhow::Result<Option<WebPageDataRevision<WebPageContentTrackerTag>>> {
+        let trackers = self
+            .get_web_page_trackers_to_diff::<WebPageContentTrackerTag>([
+                tracker_id,
+                params.tracker_id,
+            ])
+            .await?;
 
-impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
-    /// Returns an API to work with web scraping data (as system user).
-    pub fn web_scraping_system(&self) -> WebScrapingSystemApiExt {
-        WebScrapingSystemApiExt::new(self)
+        let web_scraping = self.api.db.web_scraping(self.user.id);
+        let mut revisions = Vec::with_capacity(trackers.len());
+        for tracker in trackers {
+            let Some(revision) = web_scraping
+                .get_web_page_tracker_history::<WebPageContentTrackerTag>(tracker.id)
+                .await?
+                .pop()
+            else {
+                return Ok(None);
+            };
+            revisions.push(revision);
+        }
+
+        Ok(web_page_content_revisions_diff(
+            revisions,
+            params.diff_algorithm,
+            self.api.config.utils.web_scraping.max_diff_size,
+        )?
+        .pop())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::{
-        error::Error as SecutilsError,
-        scheduler::{SchedulerJob, SchedulerJobConfig, SchedulerJobRetryStrategy},
-        tests::{
-            mock_api, mock_api_with_config, mock_api_with_network, mock_config,
-            mock_network_with_records, mock_scheduler_job, mock_upsert_scheduler_job, mock_user,
-            RawSchedulerJobStoredData,
-        },
-        utils::web_scraping::{
-            api_ext::{
-                WebPageContentTrackerGetHistoryParams, WebPageResourcesTrackerGetHistoryParams,
-                WebPageTrackerUpdateParams,
-            },
-            tests::{
-                WebPageTrackerCreateParams, WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME,
-                WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME,
+    /// Calculates the diff between two arbitrary contents the same way it's calculated between the
+    /// content tracker revisions, and renders the notification the tracker would send. Returns
+    /// `None` if the contents are the same and the tracker wouldn't detect any changes.
+    pub async fn simulate_content_diff(
+        &self,
+        params: WebPageContentSimulateDiffParams,
+    ) -> anyhow::Result<Option<WebPageContentSimulatedDiff>> {
+        if params.previous_content.len() + params.current_content.len()
+            > MAX_WEB_PAGE_CONTENT_SIMULATION_SIZE
+        {
+            bail!(SecutilsError::client(format!(
+                "Web page content to simulate diff for cannot be larger than {MAX_WEB_PAGE_CONTENT_SIMULATION_SIZE} bytes."
+            ))
+            .with_code(ErrorCode::LimitExceeded));
+        }
+
+        // Pasted content is treated as the content extracted by the web scraper: valid JSON is
+        // used as is, any other text is treated as a JSON string.
+        let created_at = OffsetDateTime::now_utc();
+        let to_revision = |content: String| -> anyhow::Result<_> {
+            let data = if serde_json::from_str::<JsonValue>(&content).is_ok() {
+                content
+            } else {
+                serde_json::to_string(&content)?
+            };
+            Ok(WebPageDataRevision::<WebPageContentTrackerTag> {
+                id: Uuid::nil(),
+                tracker_id: Uuid::nil(),
+                data,
+                created_at,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            })
+        };
+
+        let previous_revision = to_revision(params.previous_content)?;
+        let current_revision = to_revision(params.current_content)?;
+        if previous_revision.data == current_revision.data {
+            return Ok(None);
+        }
+
+        let notification = NotificationContent::Template(
+            NotificationContentTemplate::WebPageContentTrackerChanges {
+                tracker_name: WEB_PAGE_CONTENT_SIMULATION_TRACKER_NAME.to_string(),
+                content: Ok(current_revision.data.clone()),
             },
-            WebPageContentTrackerTag, WebPageResource, WebPageResourceDiffStatus,
-            WebPageResourcesTrackerTag, WebPageTracker, WebPageTrackerKind, WebPageTrackerSettings,
-            WebScraperContentRequest, WebScraperContentResponse, WebScraperErrorResponse,
-            WebScraperResource, WebScraperResourcesRequest, WebScraperResourcesResponse,
-        },
-    };
-    use actix_web::ResponseError;
-    use futures::StreamExt;
-    use httpmock::MockServer;
-    use insta::assert_debug_snapshot;
-    use sqlx::PgPool;
-    use std::{net::Ipv4Addr, time::Duration};
-    use time::OffsetDateTime;
-    use trust_dns_resolver::{
-        proto::rr::{rdata::A, RData, Record},
-        Name,
-    };
-    use url::Url;
-    use uuid::{uuid, Uuid};
+        )
+        .into_preview(self.api, None)
+        .await?;
 
-    fn get_resources(timestamp: i64, label: &str) -> anyhow::Result<WebScraperResourcesResponse> {
-        Ok(WebScraperResourcesResponse {
-            timestamp: OffsetDateTime::from_unix_timestamp(timestamp)?,
-            scripts: vec![WebScraperResource {
-                url: Some(Url::parse(&format!(
-                    "http://localhost:1234/script_{label}.js"
-                ))?),
-                content: None,
-            }],
-            styles: vec![WebScraperResource {
-                url: Some(Url::parse(&format!(
-                    "http://localhost:1234/style_{label}.css"
-                ))?),
-                content: None,
-            }],
-        })
+        let Some(WebPageDataRevision {
+            data: diff,
+            diff_stats: Some(diff_stats),
+            ..
+        }) = web_page_content_revisions_diff(
+            vec![previous_revision, current_revision],
+            params.diff_algorithm,
+            self.api.config.utils.web_scraping.max_diff_size,
+        )?
+        .pop()
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(WebPageContentSimulatedDiff {
+            diff,
+            diff_stats,
+            notification,
+        }))
     }
 
-    fn get_content(timestamp: i64, label: &str) -> anyhow::Result<WebScraperContentResponse> {
-        Ok(WebScraperContentResponse {
-            timestamp: OffsetDateTime::from_unix_timestamp(timestamp)?,
-            content: label.to_string(),
-        })
+    /// Returns all stored webpage resources tracker history as a stream of JSON lines (JSONL), one
+    /// revision per line.
+    pub async fn export_resources_tracker_history(
+        &self,
+        tracker_id: Uuid,
+        params: WebPageTrackerExportHistoryParams,
+    ) -> anyhow::Result<LocalBoxStream<'a, anyhow::Result<Bytes>>> {
+        let Some(tracker) = self.get_resources_tracker(tracker_id).await? else {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        };
+
+        let classify_resources = tracker.settings.classify_resources;
+        let resource_types = tracker.settings.resource_types;
+        let revisions = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker_history_stream::<WebPageResourcesTrackerInternalTag>(
+                tracker_id,
+                MAX_HISTORY_EXPORT_PAGE_SIZE,
+            )
+            .map(move |revision| {
+                revision.map(|revision| WebPageDataRevision {
+                    id: revision.id,
+                    tracker_id: revision.tracker_id,
+                    data: web_page_resources_data(
+                        revision.data,
+                        resource_types.as_deref(),
+                        classify_resources,
+                    ),
+                    created_at: revision.created_at,
+                    note: revision.note,
+                    acknowledgment: revision.acknowledgment,
+                    diff_stats: revision.diff_stats,
+                    assertions_passed: revision.assertions_passed,
+                })
+            });
+        Ok(web_page_tracker_history_lines(
+            revisions,
+            params
+                .calculate_diff
+                .then_some(web_page_resources_revisions_diff),
+            self.get_baseline_revision_id(tracker_id).await?,
+        ))
     }
 
-    #[sqlx::test]
-    async fn properly_creates_new_web_page_resources_tracker(pool: PgPool) -> anyhow::Result<()> {
-        let api = mock_api(pool).await?;
-        let mock_user = mock_user()?;
-        api.db.insert_user(&mock_user).await?;
+    /// Returns all stored webpage content tracker history as a stream of JSON lines (JSONL), one
+    /// revision per line.
+    pub async fn export_content_tracker_history(
+        &self,
+        tracker_id: Uuid,
+        params: WebPageTrackerExportHistoryParams,
+    ) -> anyhow::Result<LocalBoxStream<'a, anyhow::Result<Bytes>>> {
+        if self.get_content_tracker(tracker_id).await?.is_none() {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        }
 
-        let web_scraper = api.web_scraping(&mock_user);
+        let max_diff_size = self.api.config.utils.web_scraping.max_diff_size;
+        let revisions = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker_history_stream::<WebPageContentTrackerTag>(
+                tracker_id,
+                MAX_HISTORY_EXPORT_PAGE_SIZE,
+            );
+        Ok(web_page_tracker_history_lines(
+            revisions,
+            params.calculate_diff.then_some(move |revisions| {
+                web_page_content_revisions_diff(
+                    revisions,
+                    WebPageContentDiffAlgorithm::Lines,
+                    max_diff_size,
+                )
+            }),
+            self.get_baseline_revision_id(tracker_id).await?,
+        ))
+    }
 
-        let tracker = web_scraper
-            .create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name_one".to_string(),
-                url: Url::parse("http://localhost:1234/my/app?q=2")?,
-                settings: WebPageTrackerSettings {
-                    revisions: 3,
-                    delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
-                },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "@hourly".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(120),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                }),
-            })
-            .await?;
+    /// Returns all stored webpage screenshot tracker history as a stream of JSON lines (JSONL), one
+    /// revision per line.
+    pub async fn export_screenshot_tracker_history(
+        &self,
+        tracker_id: Uuid,
+        params: WebPageTrackerExportHistoryParams,
+    ) -> anyhow::Result<LocalBoxStream<'a, anyhow::Result<Bytes>>> {
+        if self.get_screenshot_tracker(tracker_id).await?.is_none() {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        }
 
-        assert_eq!(
-            tracker,
-            web_scraper
-                .get_resources_tracker(tracker.id)
-                .await?
-                .unwrap()
-        );
+        let revisions = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker_history_stream::<WebPageScreenshotTrackerTag>(
+                tracker_id,
+                MAX_HISTORY_EXPORT_PAGE_SIZE,
+            );
+        Ok(web_page_tracker_history_lines(
+            revisions,
+            params
+                .calculate_diff
+                .then_some(web_page_screenshot_revisions_diff),
+            self.get_baseline_revision_id(tracker_id).await?,
+        ))
+    }
 
-        Ok(())
+    /// Returns all stored webpage DNS records tracker history as a stream of JSON lines (JSONL), one
+    /// revision per line.
+    pub async fn export_dns_records_tracker_history(
+        &self,
+        tracker_id: Uuid,
+        params: WebPageTrackerExportHistoryParams,
+    ) -> anyhow::Result<LocalBoxStream<'a, anyhow::Result<Bytes>>> {
+        if self.get_dns_records_tracker(tracker_id).await?.is_none() {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        }
+
+        let revisions = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker_history_stream::<WebPageDnsRecordsTrackerTag>(
+                tracker_id,
+                MAX_HISTORY_EXPORT_PAGE_SIZE,
+            );
+        Ok(web_page_tracker_history_lines(
+            revisions,
+            params
+                .calculate_diff
+                .then_some(web_page_dns_records_revisions_diff),
+            self.get_baseline_revision_id(tracker_id).await?,
+        ))
     }
 
-    #[sqlx::test]
-    async fn properly_creates_new_web_page_content_tracker(pool: PgPool) -> anyhow::Result<()> {
-        let api = mock_api(pool).await?;
-        let mock_user = mock_user()?;
-        api.db.insert_user(&mock_user).await?;
+    /// Returns all stored webpage resources tracker history as CSV, one row per script or style of
+    /// every revision. If `calculate_diff` is set, the `diff_status` column indicates whether the
+    /// resource was added, removed, or changed since the previous revision.
+    #[allow(dead_code)]
+    pub async fn export_resources_tracker_history_csv(
+        &self,
+        tracker_id: Uuid,
+        params: WebPageResourcesTrackerGetHistoryParams,
+    ) -> anyhow::Result<String> {
+        let revisions = self
+            .get_resources_tracker_history(tracker_id, params)
+            .await?;
+
+        let mut csv = web_page_tracker_history_csv_row([
+            "revision_id",
+            "created_at",
+            "resource_type",
+            "url",
+            "content_digest",
+            "size",
+            "diff_status",
+        ]);
+        for revision in revisions {
+            let revision_id = revision.id.to_string();
+            let created_at = revision.created_at.format(&Rfc3339)?;
+            let resources = iter::repeat("script")
+                .zip(revision.data.scripts)
+                .chain(iter::repeat("style").zip(revision.data.styles));
+            for (resource_type, resource) in resources {
+                let url = resource
+                    .url
+                    .as_ref()
+                    .map(|url| url.as_str())
+                    .unwrap_or_default();
+                let (content_digest, size) = resource
+                    .content
+                    .as_ref()
+                    .map(|content| (content.data.value(), content.size.to_string()))
+                    .unwrap_or_default();
+                let diff_status = match resource.diff_status {
+                    Some(WebPageResourceDiffStatus::Added) => "added",
+                    Some(WebPageResourceDiffStatus::Removed) => "removed",
+                    Some(WebPageResourceDiffStatus::Changed) => "changed",
+                    None => "",
+                };
+                csv.push_str(&web_page_tracker_history_csv_row([
+                    revision_id.as_str(),
+                    created_at.as_str(),
+                    resource_type,
+                    url,
+                    content_digest,
+                    size.as_str(),
+                    diff_status,
+                ]));
+            }
+        }
 
-        let api = api.web_scraping(&mock_user);
+        Ok(csv)
+    }
 
-        let tracker = api
-            .create_content_tracker(WebPageTrackerCreateParams {
-                name: "name_one".to_string(),
-                url: Url::parse("http://localhost:1234/my/app?q=2")?,
-                settings: WebPageTrackerSettings {
-                    revisions: 3,
-                    delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+    /// Returns all stored webpage content tracker history as CSV, one row per revision with the
+    /// SHA-256 hash and the size (in bytes) of the revision content. If `calculate_diff` is set,
+    /// the `diff_status` column indicates whether the content changed since the previous revision,
+    /// or since the baseline revision for the revisions created after the baseline.
+    #[allow(dead_code)]
+    pub async fn export_content_tracker_history_csv(
+        &self,
+        tracker_id: Uuid,
+        params: WebPageContentTrackerGetHistoryParams,
+    ) -> anyhow::Result<String> {
+        // Hash and size describe the revision content, so the content isn't replaced with diffs.
+        let calculate_diff = params.calculate_diff;
+        let revisions = self
+            .get_content_tracker_history(
+                tracker_id,
+                WebPageContentTrackerGetHistoryParams {
+                    calculate_diff: false,
+                    ..params
                 },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "@hourly".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(120),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                }),
-            })
+            )
             .await?;
+        let baseline_revision_id = if calculate_diff {
+            self.get_baseline_revision_id(tracker_id).await?
+        } else {
+            None
+        };
 
-        assert_eq!(tracker, api.get_content_tracker(tracker.id).await?.unwrap());
+        let mut csv = web_page_tracker_history_csv_row([
+            "revision_id",
+            "created_at",
+            "content_hash",
+            "size",
+            "diff_status",
+        ]);
+        let (mut previous_hash, mut baseline_hash) = (None, None);
+        for revision in revisions {
+            let hash = hex::encode(openssl::sha::sha256(revision.data.as_bytes()));
+            let diff_status = match baseline_hash.as_ref().or(previous_hash.as_ref()) {
+                Some(reference_hash) if calculate_diff && reference_hash != &hash => "changed",
+                _ => "",
+            };
+            csv.push_str(&web_page_tracker_history_csv_row([
+                revision.id.to_string().as_str(),
+                revision.created_at.format(&Rfc3339)?.as_str(),
+                hash.as_str(),
+                revision.data.len().to_string().as_str(),
+                diff_status,
+            ]));
+
+            // Once the baseline revision is reached, all subsequent revisions are compared to it.
+            if Some(revision.id) == baseline_revision_id {
+                baseline_hash = Some(hash.clone());
+            }
+            previous_hash = Some(hash);
+        }
 
-        Ok(())
+        Ok(csv)
     }
 
-    #[sqlx::test]
-    async fn properly_validates_web_page_resources_tracker_at_creation(
-        pool: PgPool,
-    ) -> anyhow::Result<()> {
-        let api = mock_api(pool.clone()).await?;
-        let mock_user = mock_user()?;
-        api.db.insert_user(&mock_user).await?;
+    /// Returns a single stored webpage resources tracker revision.
+    pub async fn get_resources_tracker_revision(
+        &self,
+        tracker_id: Uuid,
+        revision_id: Uuid,
+    ) -> anyhow::Result<WebPageDataRevision<WebPageResourcesTrackerTag>> {
+        let revision = self
+            .get_web_page_tracker_revision::<WebPageResourcesTrackerInternalTag>(
+                tracker_id,
+                revision_id,
+            )
+            .await?;
+        let settings = self
+            .get_resources_tracker(tracker_id)
+            .await?
+            .map(|tracker| tracker.settings);
+        Ok(WebPageDataRevision {
+            id: revision.id,
+            tracker_id: revision.tracker_id,
+            data: web_page_resources_data(
+                revision.data,
+                settings
+                    .as_ref()
+                    .and_then(|settings| settings.resource_types.as_deref()),
+                settings
+                    .as_ref()
+                    .is_some_and(|settings| settings.classify_resources),
+            ),
+            created_at: revision.created_at,
+            note: revision.note,
+            acknowledgment: revision.acknowledgment,
+            diff_stats: revision.diff_stats,
+            assertions_passed: revision.assertions_passed,
+        })
+    }
 
-        let web_scraping = api.web_scraping(&mock_user);
+    /// Returns a single stored webpage content tracker revision.
+    pub async fn get_content_tracker_revision(
+        &self,
+        tracker_id: Uuid,
+        revision_id: Uuid,
+    ) -> anyhow::Result<WebPageDataRevision<WebPageContentTrackerTag>> {
+        self.get_web_page_tracker_revision(tracker_id, revision_id)
+            .await
+    }
 
-        let settings = WebPageTrackerSettings {
-            revisions: 3,
-            delay: Duration::from_millis(2000),
-            scripts: Default::default(),
-            headers: Default::default(),
-        };
-        let url = Url::parse("https://secutils.dev")?;
+    /// Returns a single stored webpage screenshot tracker revision.
+    pub async fn get_screenshot_tracker_revision(
+        &self,
+        tracker_id: Uuid,
+        revision_id: Uuid,
+    ) -> anyhow::Result<WebPageDataRevision<WebPageScreenshotTrackerTag>> {
+        self.get_web_page_tracker_revision(tracker_id, revision_id)
+            .await
+    }
 
-        let create_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
-            result.unwrap_err().downcast::<SecutilsError>().unwrap()
-        };
+    /// Returns a single stored webpage DNS records tracker revision.
+    pub async fn get_dns_records_tracker_revision(
+        &self,
+        tracker_id: Uuid,
+        revision_id: Uuid,
+    ) -> anyhow::Result<WebPageDataRevision<WebPageDnsRecordsTrackerTag>> {
+        self.get_web_page_tracker_revision(tracker_id, revision_id)
+            .await
+    }
 
-        // Empty name.
-        assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: None
-            }).await),
-            @r###""Web page tracker name cannot be empty.""###
-        );
+    /// Removes all persisted resources for the specified web page resources tracker.
+    pub async fn clear_web_page_tracker_history(&self, tracker_id: Uuid) -> anyhow::Result<()> {
+        self.api
+            .db
+            .web_scraping(self.user.id)
+            .clear_web_page_tracker_history(tracker_id)
+            .await
+    }
+
+    /// Sets or clears (if `note` is `None` or empty) the note for the specified web page tracker
+    /// revision.
+    pub async fn set_revision_note(
+        &self,
+        tracker_id: Uuid,
+        revision_id: Uuid,
+        note: Option<String>,
+    ) -> anyhow::Result<()> {
+        let note = note
+            .as_deref()
+            .map(str::trim)
+            .filter(|note| !note.is_empty());
+        if let Some(note) = note {
+            if note.chars().count() > MAX_WEB_PAGE_TRACKER_REVISION_NOTE_LENGTH {
+                bail!(SecutilsError::client(format!(
+                    "Web page tracker revision note cannot be longer than {MAX_WEB_PAGE_TRACKER_REVISION_NOTE_LENGTH} characters."
+                )));
+            }
+        }
+
+        let is_updated = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .set_web_page_tracker_history_revision_note(tracker_id, revision_id, note)
+            .await?;
+        if !is_updated {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker revision ('{revision_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerRevisionNotFound));
+        }
+
+        Ok(())
+    }
+
+    /// Acknowledges the specified web page tracker revision on behalf of the current user.
+    /// Re-acknowledging the revision updates the acknowledgment timestamp.
+    pub async fn acknowledge_revision(
+        &self,
+        tracker_id: Uuid,
+        revision_id: Uuid,
+    ) -> anyhow::Result<WebPageDataRevisionAcknowledgment> {
+        let acknowledgment = WebPageDataRevisionAcknowledgment {
+            user_id: self.user.id,
+            acknowledged_at: OffsetDateTime::from_unix_timestamp(
+                OffsetDateTime::now_utc().unix_timestamp(),
+            )?,
+        };
+
+        let is_updated = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .acknowledge_web_page_tracker_history_revision(tracker_id, revision_id, &acknowledgment)
+            .await?;
+        if !is_updated {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker revision ('{revision_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerRevisionNotFound));
+        }
+
+        Ok(acknowledgment)
+    }
+
+    /// Pins the specified web page tracker revision as a baseline, or unpins the current baseline
+    /// if `revision_id` is `None`. While the baseline is pinned, history diffs and change
+    /// notifications for the revisions created after the baseline are computed against the
+    /// baseline instead of the previous revision.
+    pub async fn set_baseline_revision(
+        &self,
+        tracker_id: Uuid,
+        revision_id: Option<Uuid>,
+    ) -> anyhow::Result<()> {
+        let web_scraping = self.api.db.web_scraping(self.user.id);
+        let Some(revision_id) = revision_id else {
+            return web_scraping
+                .remove_web_page_tracker_baseline_revision(tracker_id)
+                .await;
+        };
+
+        let is_updated = web_scraping
+            .upsert_web_page_tracker_baseline_revision(tracker_id, revision_id)
+            .await?;
+        if !is_updated {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker revision ('{revision_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerRevisionNotFound));
+        }
+
+        Ok(())
+    }
+
+    /// Recomputes the hash chain of the specified web page tracker history and reports the first
+    /// revision that breaks it, if any. The oldest retained revision is trusted to link to the
+    /// revisions that were already removed due to the revisions limit, and the revisions removed
+    /// from the middle of the history re-link the chain on removal.
+    pub async fn verify_history_integrity<Tag: WebPageTrackerTag>(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<WebPageTrackerHistoryIntegrity> {
+        if self
+            .get_web_page_tracker::<Tag>(tracker_id)
+            .await?
+            .is_none()
+        {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        }
+
+        let links = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker_history_chain(tracker_id)
+            .await?;
+
+        let mut previous_link: Option<&WebPageDataRevisionChainLink> = None;
+        let broken_revision_id = links.iter().find_map(|link| {
+            let is_linked = previous_link
+                .map(|previous_link| previous_link.chain_hash == link.previous_hash)
+                .unwrap_or(true);
+            previous_link = Some(link);
+            if is_linked && link.is_valid() {
+                None
+            } else {
+                Some(link.id)
+            }
+        });
+
+        Ok(WebPageTrackerHistoryIntegrity {
+            revisions: links.len(),
+            intact: broken_revision_id.is_none(),
+            broken_revision_id,
+        })
+    }
+
+    /// Analyzes the web page content tracker history to find the content regions that change too
+    /// often and are likely to be a noise.
+    pub async fn analyze_content_tracker_noise(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<WebPageContentNoiseAnalysis> {
+        if self.get_content_tracker(tracker_id).await?.is_none() {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        }
+
+        let revisions = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker_history::<WebPageContentTrackerTag>(tracker_id)
+            .await?;
+        web_page_content_revisions_noise(&revisions)
+    }
+
+    /// Returns the settings that are actually applied to the web page resources tracker.
+    pub async fn get_resources_tracker_effective_settings(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<WebPageTrackerEffectiveSettings> {
+        let Some(tracker) = self.get_resources_tracker(tracker_id).await? else {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        };
+
+        self.get_effective_tracker_settings(tracker, None)
+    }
+
+    /// Returns the settings that are actually applied to the web page content tracker.
+    pub async fn get_content_tracker_effective_settings(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<WebPageTrackerEffectiveSettings> {
+        let Some(tracker) = self.get_content_tracker(tracker_id).await? else {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        };
+
+        self.get_effective_tracker_settings(tracker, None)
+    }
+
+    /// Returns the settings that are actually applied to the web page screenshot tracker.
+    pub async fn get_screenshot_tracker_effective_settings(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<WebPageTrackerEffectiveSettings> {
+        let Some(tracker) = self.get_screenshot_tracker(tracker_id).await? else {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        };
+
+        self.get_effective_tracker_settings(
+            tracker,
+            Some(MAX_WEB_PAGE_SCREENSHOT_TRACKER_REVISIONS),
+        )
+    }
+
+    /// Returns the settings that are actually applied to the web page DNS records tracker.
+    pub async fn get_dns_records_tracker_effective_settings(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<WebPageTrackerEffectiveSettings> {
+        let Some(tracker) = self.get_dns_records_tracker(tracker_id).await? else {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        };
+
+        self.get_effective_tracker_settings(tracker, None)
+    }
+
+    /// Sets or removes the webhook that web page tracker change notifications are delivered to.
+    /// Webhook secret is stored encrypted and is used to sign notification payloads, custom headers
+    /// (e.g. `Authorization`) are stored encrypted as well.
+    pub async fn set_web_page_tracker_webhook(
+        &self,
+        tracker_id: Uuid,
+        params: WebPageTrackerSetWebhookParams,
+    ) -> anyhow::Result<()> {
+        let web_scraping = self.api.db.web_scraping(self.user.id);
+        let Some(url) = params.url else {
+            return web_scraping
+                .remove_web_page_tracker_webhook(tracker_id)
+                .await;
+        };
+
+        let Some(ref encryption_key) = self.api.config.security.encryption_key else {
+            bail!(
+                SecutilsError::client("Web page tracker webhooks are not supported.")
+                    .with_code(ErrorCode::NotAllowed)
+            );
+        };
+
+        if !matches!(url.scheme(), "http" | "https")
+            || !self.api.network.is_public_web_url(&url).await
+        {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker webhook URL must be either `http` or `https` and have a valid public reachable domain name, but received {url}."
+            ))
+            .with_code(ErrorCode::InvalidUrl));
+        }
+
+        if !self
+            .api
+            .config
+            .utils
+            .web_scraping
+            .is_webhook_url_allowed(&url)
+        {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker webhook URL domain isn't allowed by the server configuration, but received {url}."
+            ))
+            .with_code(ErrorCode::NotAllowed));
+        }
+
+        let secret = params.secret.unwrap_or_default();
+        let secret_length = secret.chars().count();
+        if !(MIN_WEB_PAGE_TRACKER_WEBHOOK_SECRET_LENGTH
+            ..=MAX_WEB_PAGE_TRACKER_WEBHOOK_SECRET_LENGTH)
+            .contains(&secret_length)
+        {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker webhook secret should be between {MIN_WEB_PAGE_TRACKER_WEBHOOK_SECRET_LENGTH} and {MAX_WEB_PAGE_TRACKER_WEBHOOK_SECRET_LENGTH} characters long."
+            )));
+        }
+
+        // Header values may contain credentials, so they're never included into error messages.
+        let headers = params.headers.unwrap_or_default();
+        if headers.len() > MAX_WEB_PAGE_TRACKER_WEBHOOK_HEADERS {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker webhook cannot have more than {MAX_WEB_PAGE_TRACKER_WEBHOOK_HEADERS} custom headers."
+            )));
+        }
+
+        for (name, value) in &headers {
+            let Ok(header_name) = HeaderName::from_bytes(name.as_bytes()) else {
+                bail!(SecutilsError::client(format!(
+                    "Web page tracker webhook header name is not valid, but received {name}."
+                )));
+            };
+
+            if [CONTENT_TYPE, CONTENT_LENGTH, HOST].contains(&header_name)
+                || header_name
+                    .as_str()
+                    .eq_ignore_ascii_case(WEBHOOK_TIMESTAMP_HEADER)
+                || header_name
+                    .as_str()
+                    .eq_ignore_ascii_case(WEBHOOK_SIGNATURE_HEADER)
+            {
+                bail!(SecutilsError::client(format!(
+                    "Web page tracker webhook header ('{name}') is set by Secutils.dev and cannot be overridden."
+                )));
+            }
+
+            if value.is_empty()
+                || value.len() > MAX_WEB_PAGE_TRACKER_WEBHOOK_HEADER_VALUE_LENGTH
+                || HeaderValue::from_str(value).is_err()
+            {
+                bail!(SecutilsError::client(format!(
+                    "Web page tracker webhook header ('{name}') value should be a valid header value between 1 and {MAX_WEB_PAGE_TRACKER_WEBHOOK_HEADER_VALUE_LENGTH} bytes long."
+                )));
+            }
+        }
+
+        let webhook = WebPageTrackerWebhook {
+            url,
+            secret: encryption::encrypt(encryption_key, secret.as_bytes())?,
+            previous_secret: None,
+            previous_secret_expires_at: None,
+            headers: if headers.is_empty() {
+                None
+            } else {
+                Some(encryption::encrypt(
+                    encryption_key,
+                    &serde_json::to_vec(&headers)?,
+                )?)
+            },
+        };
+        if !web_scraping
+            .upsert_web_page_tracker_webhook(tracker_id, &webhook)
+            .await?
+        {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        }
+
+        Ok(())
+    }
+
+    /// Generates new secrets for the webhooks of all web page trackers of the user. New secrets
+    /// are returned only once and are stored encrypted. The previous secrets stop being used to
+    /// sign notification payloads immediately, or once the configured grace period ends.
+    pub async fn rotate_web_page_tracker_webhook_secrets(
+        &self,
+    ) -> anyhow::Result<Vec<WebPageTrackerWebhookSecret>> {
+        let Some(ref encryption_key) = self.api.config.security.encryption_key else {
+            bail!(
+                SecutilsError::client("Web page tracker webhooks are not supported.")
+                    .with_code(ErrorCode::NotAllowed)
+            );
+        };
+
+        let previous_secret_expires_at = self
+            .api
+            .config
+            .utils
+            .web_scraping
+            .webhook_secret_grace_period
+            .map(|grace_period| OffsetDateTime::now_utc() + grace_period);
+
+        let web_scraping = self.api.db.web_scraping(self.user.id);
+        let mut secrets = vec![];
+        for (tracker_id, webhook) in web_scraping.get_web_page_tracker_webhooks().await? {
+            let secret = generate_webhook_secret();
+            let webhook = WebPageTrackerWebhook {
+                secret: encryption::encrypt(encryption_key, secret.as_bytes())?,
+                previous_secret: previous_secret_expires_at.map(|_| webhook.secret),
+                previous_secret_expires_at,
+                url: webhook.url,
+                headers: webhook.headers,
+            };
+
+            // Tracker might have been removed concurrently, there is nothing to rotate then.
+            if !web_scraping
+                .upsert_web_page_tracker_webhook(tracker_id, &webhook)
+                .await?
+            {
+                continue;
+            }
+
+            self.api
+                .users()
+                .record_audit_log_entry(
+                    self.user,
+                    format!("Rotated web page tracker ('{tracker_id}') webhook secret"),
+                )
+                .await?;
+
+            secrets.push(WebPageTrackerWebhookSecret { tracker_id, secret });
+        }
+
+        Ok(secrets)
+    }
+
+    /// Renders the change notification for the specified web page tracker and a hypothetical
+    /// change exactly as it would be delivered to the user email and the tracker webhook (if
+    /// configured), without delivering it.
+    pub async fn preview_tracker_notification<Tag: WebPageTrackerTag>(
+        &self,
+        tracker_id: Uuid,
+        params: WebPageTrackerPreviewNotificationParams,
+    ) -> anyhow::Result<NotificationPreview> {
+        let Some(tracker) = self.get_web_page_tracker::<Tag>(tracker_id).await? else {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{tracker_id}') is not found."
+            ))
+            .with_code(ErrorCode::TrackerNotFound));
+        };
+
+        let tracker_name = tracker.name;
+        let template = match Tag::KIND {
+            WebPageTrackerKind::WebPageResources => {
+                let Ok(changes) = params.sample_diff.trim().parse::<usize>() else {
+                    bail!(SecutilsError::client(
+                        "Web page resources tracker sample diff should be a number of changed resources."
+                    ));
+                };
+                NotificationContentTemplate::WebPageResourcesTrackerChanges {
+                    tracker_name,
+                    content: Ok(changes),
+                }
+            }
+            WebPageTrackerKind::WebPageContent => {
+                NotificationContentTemplate::WebPageContentTrackerChanges {
+                    tracker_name,
+                    content: Ok(params.sample_diff),
+                }
+            }
+            WebPageTrackerKind::WebPageScreenshot => {
+                NotificationContentTemplate::WebPageScreenshotTrackerChanges {
+                    tracker_name,
+                    content: Ok(params.sample_diff),
+                }
+            }
+            WebPageTrackerKind::WebPageDnsRecords => {
+                NotificationContentTemplate::WebPageDnsRecordsTrackerChanges {
+                    tracker_name,
+                    content: Ok(params.sample_diff),
+                }
+            }
+        };
+
+        // Notifications are delivered to the webhook only if it's configured.
+        let webhook = self
+            .api
+            .web_scraping_system()
+            .get_web_page_tracker_webhook(tracker.id)
+            .await?;
+        let mut preview = NotificationContent::Template(template)
+            .into_preview(self.api, webhook.as_ref().map(|_| tracker.id))
+            .await?;
+
+        // Only names of the custom webhook headers are previewed, values may contain credentials.
+        if let (Some(webhook), Some(encryption_key)) =
+            (webhook, &self.api.config.security.encryption_key)
+        {
+            let headers = webhook.decrypt_headers(encryption_key)?;
+            if !headers.is_empty() {
+                preview.webhook_headers = Some(
+                    headers
+                        .into_iter()
+                        .map(|(name, _)| (name, REDACTED_VALUE.to_string()))
+                        .collect(),
+                );
+            }
+        }
+
+        Ok(preview)
+    }
+
+    /// Returns all status pages of the user.
+    pub async fn get_status_pages(&self) -> anyhow::Result<Vec<WebPageStatusPage>> {
+        self.api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_status_pages()
+            .await
+    }
+
+    /// Creates a status page that displays the specified status trackers.
+    pub async fn create_status_page(
+        &self,
+        params: WebPageStatusPageCreateParams,
+    ) -> anyhow::Result<WebPageStatusPage> {
+        // Preserve timestamp only up to seconds.
+        let created_at =
+            OffsetDateTime::from_unix_timestamp(OffsetDateTime::now_utc().unix_timestamp())?;
+        let status_page = WebPageStatusPage {
+            id: Uuid::now_v7(),
+            name: params.name,
+            token: generate_status_page_token(),
+            tracker_ids: params.tracker_ids,
+            created_at,
+            updated_at: created_at,
+        };
+
+        self.validate_status_page(&status_page).await?;
+
+        self.api
+            .db
+            .web_scraping(self.user.id)
+            .insert_web_page_status_page(&status_page)
+            .await?;
+
+        Ok(status_page)
+    }
+
+    /// Updates status page, and regenerates its token, if requested.
+    pub async fn update_status_page(
+        &self,
+        id: Uuid,
+        params: WebPageStatusPageUpdateParams,
+    ) -> anyhow::Result<WebPageStatusPage> {
+        if params.name.is_none() && params.tracker_ids.is_none() && !params.regenerate_token {
+            bail!(SecutilsError::client(format!(
+                "Either new name, tracker IDs, or token regeneration should be provided ({id})."
+            )));
+        }
+
+        let web_scraping = self.api.db.web_scraping(self.user.id);
+        let Some(existing_status_page) = web_scraping.get_web_page_status_page(id).await? else {
+            bail!(
+                SecutilsError::client(format!("Status page ('{id}') is not found."))
+                    .with_code(ErrorCode::StatusPageNotFound)
+            );
+        };
+
+        let status_page = WebPageStatusPage {
+            name: params.name.unwrap_or(existing_status_page.name),
+            token: if params.regenerate_token {
+                generate_status_page_token()
+            } else {
+                existing_status_page.token
+            },
+            tracker_ids: params
+                .tracker_ids
+                .unwrap_or(existing_status_page.tracker_ids),
+            // Preserve timestamp only up to seconds.
+            updated_at: OffsetDateTime::from_unix_timestamp(
+                OffsetDateTime::now_utc().unix_timestamp(),
+            )?,
+            ..existing_status_page
+        };
+
+        self.validate_status_page(&status_page).await?;
+
+        web_scraping
+            .update_web_page_status_page(&status_page)
+            .await?;
+
+        Ok(status_page)
+    }
+
+    /// Removes status page with the specified ID.
+    pub async fn remove_status_page(&self, id: Uuid) -> anyhow::Result<()> {
+        self.api
+            .db
+            .web_scraping(self.user.id)
+            .remove_web_page_status_page(id)
+            .await
+    }
+
+    /// Renders status page as HTML with the latest status and the recent status changes of every
+    /// status tracker displayed on the page. Trackers that were removed or aren't in status-only
+    /// mode anymore are skipped.
+    pub async fn render_status_page(
+        &self,
+        status_page: &WebPageStatusPage,
+    ) -> anyhow::Result<String> {
+        #[derive(Serialize)]
+        struct StatusPageTrackerStatus {
+            status: u16,
+            up: bool,
+            latency: u128,
+            since: String,
+        }
+
+        #[derive(Serialize)]
+        struct StatusPageTracker<'t> {
+            name: &'t str,
+            current: Option<&'t StatusPageTrackerStatus>,
+            history: &'t [StatusPageTrackerStatus],
+        }
+
+        let web_scraping = self.api.db.web_scraping(self.user.id);
+        let mut trackers = Vec::with_capacity(status_page.tracker_ids.len());
+        for tracker_id in &status_page.tracker_ids {
+            let Some(tracker) = web_scraping
+                .get_web_page_tracker::<WebPageContentTrackerTag>(*tracker_id)
+                .await?
+                .filter(|tracker| tracker.settings.status_only)
+            else {
+                continue;
+            };
+
+            // Status trackers store revisions only when status changes, newest changes first.
+            let history = web_scraping
+                .get_web_page_tracker_history::<WebPageContentTrackerTag>(tracker.id)
+                .await?
+                .into_iter()
+                .rev()
+                .take(MAX_WEB_PAGE_STATUS_PAGE_HISTORY_SIZE)
+                .map(|revision| {
+                    let status = serde_json::from_str::<WebPageContentStatus>(&revision.data)?;
+                    Ok(StatusPageTrackerStatus {
+                        status: status.status,
+                        up: status.status < 400,
+                        latency: status.latency.as_millis(),
+                        since: revision.created_at.format(&Rfc3339)?,
+                    })
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            trackers.push((tracker.name, history));
+        }
+
+        let trackers = trackers
+            .iter()
+            .map(|(name, history)| StatusPageTracker {
+                name,
+                current: history.first(),
+                history,
+            })
+            .collect::<Vec<_>>();
+
+        Ok(self.api.templates.render(
+            "web_page_status_page",
+            &serde_json::json!({ "name": status_page.name, "trackers": trackers }),
+        )?)
+    }
+
+    /// Runs the web page tracker script against the current version of the web page and returns
+    /// either the script output or the script error. Resource filter scripts are executed in the
+    /// JavaScript sandbox for every resource of the web page, while content extraction scripts
+    /// need access to the DOM and hence are executed by the web scraper.
+    pub async fn test_tracker_script<Tag: WebPageTrackerTag>(
+        &self,
+        params: WebPageTrackerTestScriptParams,
+    ) -> anyhow::Result<WebPageTrackerScriptTestResult> {
+        Self::validate_tracker_script_name::<Tag>(&params.script_name)?;
+
+        if params.script.trim().is_empty() {
+            bail!(
+                SecutilsError::client("Web page tracker script cannot be empty.")
+                    .with_code(ErrorCode::InvalidScript)
+            );
+        }
+
+        if !self.api.network.is_public_web_url(&params.url).await {
+            bail!(SecutilsError::client(
+                format!("Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received {}.", params.url)
+            )
+            .with_code(ErrorCode::InvalidUrl));
+        }
+
+        match Tag::KIND {
+            WebPageTrackerKind::WebPageResources => {
+                self.test_resources_tracker_script(&params).await
+            }
+            _ => self.test_content_tracker_script(&params).await,
+        }
+    }
+
+    /// Statically checks the web page tracker script without executing it and returns all found
+    /// problems (syntax errors, missing return, use of disallowed globals).
+    pub fn lint_tracker_script<Tag: WebPageTrackerTag>(
+        &self,
+        params: WebPageTrackerLintScriptParams,
+    ) -> anyhow::Result<Vec<JsScriptDiagnostic>> {
+        Self::validate_tracker_script_name::<Tag>(&params.script_name)?;
+
+        if params.script.trim().is_empty() {
+            bail!(
+                SecutilsError::client("Web page tracker script cannot be empty.")
+                    .with_code(ErrorCode::InvalidScript)
+            );
+        }
+
+        JsRuntime::new(&self.js_runtime_config()).lint_script(&params.script)
+    }
+
+    /// Checks that the tracker of the specified kind supports script with the specified name.
+    fn validate_tracker_script_name<Tag: WebPageTrackerTag>(
+        script_name: &str,
+    ) -> anyhow::Result<()> {
+        let supported_script_name = match Tag::KIND {
+            WebPageTrackerKind::WebPageResources => WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME,
+            WebPageTrackerKind::WebPageContent => WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME,
+            WebPageTrackerKind::WebPageScreenshot => {
+                bail!(SecutilsError::client(
+                    "Web page screenshot tracker doesn't support scripts."
+                )
+                .with_code(ErrorCode::InvalidScript));
+            }
+            WebPageTrackerKind::WebPageDnsRecords => {
+                bail!(SecutilsError::client(
+                    "Web page DNS records tracker doesn't support scripts."
+                )
+                .with_code(ErrorCode::InvalidScript));
+            }
+        };
+
+        if script_name != supported_script_name {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker doesn't support `{script_name}` script."
+            ))
+            .with_code(ErrorCode::InvalidScript));
+        }
+
+        Ok(())
+    }
+
+    /// Returns JavaScript runtime configuration based on user's subscription level/overrides.
+    fn js_runtime_config(&self) -> JsRuntimeConfig {
+        let features = self.user.subscription.get_features(&self.api.config);
+        JsRuntimeConfig {
+            max_heap_size: features.config.webhooks.js_runtime_heap_size,
+            max_user_script_execution_time: features
+                .config
+                .webhooks
+                .js_runtime_script_execution_time,
+        }
+    }
+
+    /// Fetches resources of the web page and runs resource filter script for every resource in the
+    /// JavaScript sandbox.
+    async fn test_resources_tracker_script(
+        &self,
+        params: &WebPageTrackerTestScriptParams,
+    ) -> anyhow::Result<WebPageTrackerScriptTestResult> {
+        #[derive(Serialize)]
+        struct ScriptResource<'r> {
+            #[serde(rename = "type")]
+            resource_type: &'static str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            url: Option<&'r Url>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            content: Option<&'r WebPageResourceContent>,
+        }
+
+        let scraper_request = WebScraperResourcesRequest::with_default_parameters(&params.url);
+        let scraper_request = if let Some(headers) = params.headers.as_ref() {
+            scraper_request.set_headers(headers)
+        } else {
+            scraper_request
+        };
+
+        let scraper_response = match self
+            .send_script_test_scraper_request::<WebScraperResourcesResponse>(
+                "api/web_page/resources",
+                &scraper_request,
+            )
+            .await?
+        {
+            Ok(scraper_response) => scraper_response,
+            Err(error) => {
+                return Ok(WebPageTrackerScriptTestResult {
+                    output: None,
+                    error: Some(error),
+                })
+            }
+        };
+
+        let script_resources = scraper_response
+            .scripts
+            .iter()
+            .map(|resource| ("script", resource))
+            .chain(
+                scraper_response
+                    .styles
+                    .iter()
+                    .map(|resource| ("stylesheet", resource)),
+            )
+            .map(|(resource_type, resource)| ScriptResource {
+                resource_type,
+                url: resource.url.as_ref(),
+                content: resource.content.as_ref(),
+            })
+            .collect::<Vec<_>>();
+
+        // User script starts on the second line of the wrapper code, so error line numbers should
+        // be adjusted.
+        let js_code = format!(
+            "(async (resources) => {{ const results = []; for (const resource of resources) {{ const result = await (async (resource) => {{\n{}\n}})(resource); if (result) {{ results.push(result); }} }} return results; }})(context);",
+            params.script
+        );
+
+        Ok(
+            match JsRuntime::new(&self.js_runtime_config())
+                .execute_script::<JsonValue>(js_code, Some(script_resources))
+                .await
+            {
+                Ok((output, _)) => WebPageTrackerScriptTestResult {
+                    output: Some(output),
+                    error: None,
+                },
+                Err(err) => {
+                    let error = match err.downcast_ref::<JsError>() {
+                        // Termination errors (time or memory limit) are wrapped with context.
+                        Some(js_error) if err.chain().count() == 1 => {
+                            let frame = js_error.frames.first();
+                            WebPageTrackerScriptTestError {
+                                message: js_error.exception_message.clone(),
+                                line_number: frame
+                                    .and_then(|frame| frame.line_number)
+                                    .map(|line_number| (line_number - 1).max(1)),
+                                column_number: frame.and_then(|frame| frame.column_number),
+                            }
+                        }
+                        _ => WebPageTrackerScriptTestError {
+                            message: err.to_string(),
+                            line_number: None,
+                            column_number: None,
+                        },
+                    };
+                    WebPageTrackerScriptTestResult {
+                        output: None,
+                        error: Some(error),
+                    }
+                }
+            },
+        )
+    }
+
+    /// Fetches content of the web page using the content extraction script.
+    async fn test_content_tracker_script(
+        &self,
+        params: &WebPageTrackerTestScriptParams,
+    ) -> anyhow::Result<WebPageTrackerScriptTestResult> {
+        let scraper_request = WebScraperContentRequest::with_default_parameters(&params.url)
+            .set_scripts(WebScraperContentRequestScripts {
+                extract_content: Some(&params.script),
+            });
+        let scraper_request = if let Some(headers) = params.headers.as_ref() {
+            scraper_request.set_headers(headers)
+        } else {
+            scraper_request
+        };
+
+        Ok(
+            match self
+                .send_script_test_scraper_request::<WebScraperContentResponse>(
+                    "api/web_page/content",
+                    &scraper_request,
+                )
+                .await?
+            {
+                // Extracted content is usually a JSON string, but it's not guaranteed.
+                Ok(scraper_response) => WebPageTrackerScriptTestResult {
+                    output: Some(
+                        serde_json::from_str(&scraper_response.content)
+                            .unwrap_or(JsonValue::String(scraper_response.content)),
+                    ),
+                    error: None,
+                },
+                Err(error) => WebPageTrackerScriptTestResult {
+                    output: None,
+                    error: Some(error),
+                },
+            },
+        )
+    }
+
+    /// Sends request to the web scraper on behalf of the tracker. Client errors reported by the
+    /// web scraper are returned as user-facing errors.
+    async fn send_tracker_scraper_request<Tag: WebPageTrackerTag, R: for<'de> Deserialize<'de>>(
+        &self,
+        tracker: &WebPageTracker<Tag>,
+        path: &str,
+        scraper_request: &impl Serialize,
+    ) -> anyhow::Result<R> {
+        match self
+            .post_web_scraper_request::<R>(path, scraper_request)
+            .await
+        {
+            Ok(Ok(scraper_response)) => Ok(scraper_response),
+            Ok(Err(scraper_error_response)) => {
+                bail!(SecutilsError::client(scraper_error_response.message)
+                    .with_code(ErrorCode::UpstreamError))
+            }
+            Err(err) => Err(err.context(format!(
+                "Failed to send web scraper request for the web tracker ('{}').",
+                tracker.id
+            ))),
+        }
+    }
+
+    /// Sends request to the web scraper on behalf of the script test. Client errors reported by
+    /// the web scraper are returned as script errors.
+    async fn send_script_test_scraper_request<R: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        scraper_request: &impl Serialize,
+    ) -> anyhow::Result<Result<R, WebPageTrackerScriptTestError>> {
+        Ok(self
+            .post_web_scraper_request::<R>(path, scraper_request)
+            .await
+            .map_err(|err| err.context("Failed to send web scraper request to test script."))?
+            .map_err(|scraper_error_response| WebPageTrackerScriptTestError {
+                message: scraper_error_response.message,
+                line_number: None,
+                column_number: None,
+            }))
+    }
+
+    /// Sends request to the web scraper, retrying unexpected failures as configured. Calls fail
+    /// immediately while the web scraper circuit is open.
+    async fn post_web_scraper_request<R: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        scraper_request: &impl Serialize,
+    ) -> anyhow::Result<Result<R, WebScraperErrorResponse>> {
+        let client = self.web_scraper_client()?;
+        let circuit_breaker = &self.api.web_scraper_circuit_breaker;
+        let retries = self.api.config.components.web_scraper.retries;
+
+        let mut attempt = 0;
+        loop {
+            circuit_breaker.acquire()?;
+            match client.post::<R>(path, scraper_request).await {
+                Ok(result) => {
+                    circuit_breaker.record_success();
+                    return Ok(result);
+                }
+                Err(err) => {
+                    circuit_breaker.record_failure();
+                    if attempt >= retries {
+                        return Err(err);
+                    }
+
+                    attempt += 1;
+                    log::warn!(
+                        "Web scraper request failed, retrying ({attempt}/{retries}): {err:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Returns a client to interact with the web scraper component, unless the server runs in
+    /// safe mode.
+    fn web_scraper_client(&self) -> anyhow::Result<WebScraperClient> {
+        self.api.network.check_outbound_access()?;
+        Ok(WebScraperClient::new(
+            self.api.config.components.web_scraper_url.clone(),
+        ))
+    }
+
+    /// Returns all web page trackers, in the user's display order.
+    async fn get_web_page_trackers<Tag: WebPageTrackerTag>(
+        &self,
+    ) -> anyhow::Result<Vec<WebPageTracker<Tag>>> {
+        let mut trackers = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_trackers()
+            .await?;
+
+        let collection = match Tag::KIND {
+            WebPageTrackerKind::WebPageResources => {
+                UserDisplayOrderCollection::WebPageResourcesTrackers
+            }
+            WebPageTrackerKind::WebPageContent => {
+                UserDisplayOrderCollection::WebPageContentTrackers
+            }
+            WebPageTrackerKind::WebPageScreenshot => {
+                UserDisplayOrderCollection::WebPageScreenshotTrackers
+            }
+            WebPageTrackerKind::WebPageDnsRecords => {
+                UserDisplayOrderCollection::WebPageDnsRecordsTrackers
+            }
+        };
+        self.api
+            .users()
+            .get_display_order(self.user.id, collection)
+            .await?
+            .sort(&mut trackers, |tracker| tracker.id);
+
+        Ok(trackers)
+    }
+
+    /// Returns web page tracker by its ID.
+    async fn get_web_page_tracker<Tag: WebPageTrackerTag>(
+        &self,
+        id: Uuid,
+    ) -> anyhow::Result<Option<WebPageTracker<Tag>>> {
+        self.api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker(id)
+            .await
+    }
+
+    /// Creates a new web page tracker.
+    async fn create_web_page_tracker<Tag: WebPageTrackerTag, V>(
+        &self,
+        params: WebPageTrackerCreateParams,
+        validator: Option<V>,
+    ) -> anyhow::Result<WebPageTracker<Tag>>
+    where
+        V: Fn(&WebPageTracker<Tag>) -> anyhow::Result<()>,
+    {
+        // Fill in the values the user didn't specify with the operator-configured defaults.
+        let defaults = &self.api.config.utils.web_scraping.defaults;
+        let mut settings = params.settings;
+        if settings.data_policy.is_none() {
+            settings.data_policy = defaults.data_policy;
+        }
+
+        let job_config = match params.job_config {
+            Some(mut job_config) => {
+                if job_config.retry_strategy.is_none() {
+                    job_config.retry_strategy = defaults.retry_strategy;
+                }
+                Some(job_config)
+            }
+            // Trackers that don't keep any revisions aren't scheduled.
+            None if settings.revisions > 0 => {
+                defaults
+                    .schedule
+                    .as_ref()
+                    .map(|schedule| SchedulerJobConfig {
+                        schedule: schedule.clone(),
+                        retry_strategy: defaults.retry_strategy,
+                        notifications: defaults.notifications,
+                        adaptive_interval: None,
+                    })
+            }
+            None => None,
+        };
+
+        // Preserve timestamp only up to seconds.
+        let created_at =
+            OffsetDateTime::from_unix_timestamp(OffsetDateTime::now_utc().unix_timestamp())?;
+        let tracker = WebPageTracker {
+            id: Uuid::now_v7(),
+            name: params.name,
+            url: params.url,
+            settings,
+            user_id: self.user.id,
+            job_id: None,
+            job_config,
+            created_at,
+            updated_at: created_at,
+            meta: None,
+        };
+
+        self.validate_web_page_tracker(&tracker).await?;
+        // Run custom validator if specified.
+        if let Some(validator) = validator {
+            validator(&tracker)?;
+        }
+
+        self.api
+            .db
+            .web_scraping(self.user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+
+        Ok(tracker)
+    }
+
+    /// Creates a new web page tracker for every unique web page URL or monitor from the import
+    /// source. Entries that cannot be tracked don't fail the whole import, instead the reason is
+    /// reported in the result for the specific entry.
+    async fn import_web_page_trackers<Tag: WebPageTrackerTag, V>(
+        &self,
+        params: WebPageTrackerImportParams,
+        validator: V,
+    ) -> anyhow::Result<Vec<WebPageTrackerImportResult>>
+    where
+        V: Fn(&WebPageTracker<Tag>) -> anyhow::Result<()>,
+    {
+        let entries = match &params.source {
+            WebPageTrackerImportSource::Sitemap { url } => {
+                Self::unique_import_urls(self.fetch_sitemap_urls(url).await?)
+            }
+            WebPageTrackerImportSource::List { urls } => Self::unique_import_urls(
+                urls.lines()
+                    .map(|url| url.trim())
+                    .filter(|url| !url.is_empty())
+                    .map(|url| url.to_string()),
+            ),
+            WebPageTrackerImportSource::Monitors { monitors } => {
+                if Tag::KIND != WebPageTrackerKind::WebPageContent {
+                    bail!(SecutilsError::client(
+                        "Only web page content trackers can be imported from monitors."
+                    ));
+                }
+
+                // Monitors for the same URL may have different expectations, import all of them.
+                monitors
+                    .iter()
+                    .map(|monitor| (monitor.url.clone(), Some(monitor.clone())))
+                    .collect()
+            }
+        };
+        if entries.is_empty() {
+            bail!(SecutilsError::client(
+                "Web page tracker import source doesn't contain any URLs."
+            ));
+        }
+
+        if entries.len() > MAX_WEB_PAGE_TRACKER_IMPORT_URLS {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker import source cannot contain more than {} unique URLs, but received {}.",
+                MAX_WEB_PAGE_TRACKER_IMPORT_URLS,
+                entries.len()
+            ))
+            .with_code(ErrorCode::LimitExceeded));
+        }
+
+        let db = self.api.db.web_scraping(self.user.id);
+        let mut trackers_count = db
+            .get_web_page_trackers::<WebPageResourcesTrackerTag>()
+            .await?
+            .len()
+            + db.get_web_page_trackers::<WebPageContentTrackerTag>()
+                .await?
+                .len()
+            + db.get_web_page_trackers::<WebPageScreenshotTrackerTag>()
+                .await?
+                .len()
+            + db.get_web_page_trackers::<WebPageDnsRecordsTrackerTag>()
+                .await?
+                .len();
+        let max_trackers = self
+            .user
+            .subscription
+            .get_features(&self.api.config)
+            .config
+            .web_scraping
+            .trackers;
+
+        let mut results = Vec::with_capacity(entries.len());
+        for (raw_url, monitor) in entries {
+            let url = match Url::parse(&raw_url) {
+                Ok(url) => url,
+                Err(err) => {
+                    results.push(WebPageTrackerImportResult {
+                        url: raw_url,
+                        tracker_id: None,
+                        error: Some(format!("Web page URL isn't valid: {err}.")),
+                    });
+                    continue;
+                }
+            };
+
+            if trackers_count >= max_trackers {
+                results.push(WebPageTrackerImportResult {
+                    url: raw_url,
+                    tracker_id: None,
+                    error: Some(format!(
+                        "Web page trackers count cannot be greater than {max_trackers}."
+                    )),
+                });
+                continue;
+            }
+
+            let create_params = match monitor {
+                Some(monitor) => match Self::monitor_create_params(monitor, url, &params) {
+                    Ok(create_params) => create_params,
+                    Err(err) => {
+                        results.push(WebPageTrackerImportResult {
+                            url: raw_url,
+                            tracker_id: None,
+                            error: Some(err),
+                        });
+                        continue;
+                    }
+                },
+                None => WebPageTrackerCreateParams {
+                    name: url
+                        .as_str()
+                        .chars()
+                        .take(MAX_UTILS_ENTITY_NAME_LENGTH)
+                        .collect(),
+                    url,
+                    settings: params.settings.clone(),
+                    job_config: params.job_config.clone(),
+                },
+            };
+            match self
+                .create_web_page_tracker(create_params, Some(&validator))
+                .await
+            {
+                Ok(tracker) => {
+                    trackers_count += 1;
+                    results.push(WebPageTrackerImportResult {
+                        url: raw_url,
+                        tracker_id: Some(tracker.id),
+                        error: None,
+                    });
+                }
+                Err(err) => {
+                    let err = err.downcast::<SecutilsError>()?;
+                    results.push(WebPageTrackerImportResult {
+                        url: raw_url,
+                        tracker_id: None,
+                        error: Some(err.root_cause.to_string()),
+                    });
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Fetches the XML sitemap and extracts URLs of all web pages listed in it.
+    /// Removes duplicate URLs from the import source, preserving the original order of the URLs.
+    fn unique_import_urls(
+        urls: impl IntoIterator<Item = String>,
+    ) -> Vec<(String, Option<WebPageTrackerImportMonitor>)> {
+        let mut unique_urls = HashSet::new();
+        urls.into_iter()
+            .filter(|url| unique_urls.insert(url.clone()))
+            .map(|url| (url, None))
+            .collect()
+    }
+
+    /// Converts imported monitor to the web page tracker parameters. Returns the reason as an error
+    /// if the monitor cannot be converted.
+    fn monitor_create_params(
+        monitor: WebPageTrackerImportMonitor,
+        url: Url,
+        params: &WebPageTrackerImportParams,
+    ) -> Result<WebPageTrackerCreateParams, String> {
+        let Some(schedule) = monitor.schedule() else {
+            return Err(format!(
+                "Monitor check interval ({}) cannot be converted to a schedule.",
+                humantime::format_duration(monitor.interval)
+            ));
+        };
+
+        if let Some(expected_status) = monitor.expected_status {
+            if !(100..=599).contains(&expected_status) {
+                return Err(format!(
+                    "Monitor expected HTTP status ({expected_status}) isn't valid."
+                ));
+            }
+        }
+
+        // Monitors exist to alert about failures, so notifications are enabled unless job
+        // configuration shared by all imported trackers says otherwise.
+        let job_config = match params.job_config {
+            Some(ref job_config) => SchedulerJobConfig {
+                schedule,
+                ..job_config.clone()
+            },
+            None => SchedulerJobConfig {
+                schedule,
+                retry_strategy: None,
+                notifications: true,
+                adaptive_interval: None,
+            },
+        };
+
+        Ok(WebPageTrackerCreateParams {
+            settings: monitor.settings(&params.settings),
+            name: monitor.name,
+            url,
+            job_config: Some(job_config),
+        })
+    }
+
+    async fn fetch_sitemap_urls(&self, url: &Url) -> anyhow::Result<Vec<String>> {
+        self.api.network.check_outbound_access()?;
+
+        if !self.api.network.is_public_web_url(url).await {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker import sitemap URL must be either `http` or `https` and have a valid public reachable domain name, but received {url}."
+            ))
+            .with_code(ErrorCode::InvalidUrl));
+        }
+
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .timeout(WEB_PAGE_TRACKER_IMPORT_SITEMAP_TIMEOUT)
+            .build()?;
+        let response = client
+            .get(url.clone())
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|err| {
+                SecutilsError::client_with_root_cause(
+                    anyhow!("Failed to fetch sitemap: {err:?}")
+                        .context("Web page tracker import couldn't fetch the sitemap."),
+                )
+                .with_code(ErrorCode::UpstreamError)
+            })?;
+
+        let sitemap = response.bytes().await.map_err(|err| {
+            SecutilsError::client_with_root_cause(
+                anyhow!("Failed to read sitemap: {err:?}")
+                    .context("Web page tracker import couldn't fetch the sitemap."),
+            )
+            .with_code(ErrorCode::UpstreamError)
+        })?;
+        if sitemap.len() > MAX_WEB_PAGE_TRACKER_IMPORT_SITEMAP_SIZE {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker import sitemap cannot be larger than {} bytes.",
+                MAX_WEB_PAGE_TRACKER_IMPORT_SITEMAP_SIZE
+            ))
+            .with_code(ErrorCode::LimitExceeded));
+        }
+
+        WebPageSitemapParser::parse(&sitemap).map_err(|err| {
+            SecutilsError::client_with_root_cause(
+                err.context("Web page tracker import sitemap isn't valid."),
+            )
+            .into()
+        })
+    }
+
+    /// Updates existing web page tracker.
+    async fn update_web_page_tracker<Tag: WebPageTrackerTag, V>(
+        &self,
+        id: Uuid,
+        params: WebPageTrackerUpdateParams,
+        validator: Option<V>,
+    ) -> anyhow::Result<WebPageTracker<Tag>>
+    where
+        V: Fn(&WebPageTracker<Tag>) -> anyhow::Result<()>,
+    {
+        if params.name.is_none()
+            && params.url.is_none()
+            && params.settings.is_none()
+            && params.job_config.is_none()
+        {
+            bail!(SecutilsError::client(format!(
+                "Either new name, url, settings, or job config should be provided ({id})."
+            )));
+        }
+
+        let Some(existing_tracker) = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker(id)
+            .await?
+        else {
+            bail!(
+                SecutilsError::client(format!("Web page tracker ('{id}') is not found."))
+                    .with_code(ErrorCode::TrackerNotFound)
+            );
+        };
+
+        let changed_url = params
+            .url
+            .as_ref()
+            .map(|url| url != &existing_tracker.url)
+            .unwrap_or_default();
+
+        // Responses to requests with a different method or body aren't comparable, the same as
+        // responses from a different URL.
+        let changed_request = params
+            .settings
+            .as_ref()
+            .map(|settings| {
+                settings.method != existing_tracker.settings.method
+                    || settings.request_body != existing_tracker.settings.request_body
+            })
+            .unwrap_or_default();
+
+        let disabled_revisions = params
+            .settings
+            .as_ref()
+            .map(|settings| settings.revisions == 0)
+            .unwrap_or_default();
+
+        let changed_schedule = params
+            .job_config
+            .as_ref()
+            .map(
+                |job_config| match (&existing_tracker.job_config, job_config) {
+                    (Some(existing_job_config), Some(job_config)) => {
+                        job_config.schedule != existing_job_config.schedule
+                    }
+                    _ => true,
+                },
+            )
+            .unwrap_or_default();
+
+        let job_id = if disabled_revisions || changed_schedule {
+            None
+        } else {
+            existing_tracker.job_id
+        };
+
+        let tracker = WebPageTracker {
+            name: params.name.unwrap_or(existing_tracker.name),
+            url: params.url.unwrap_or(existing_tracker.url),
+            settings: params.settings.unwrap_or(existing_tracker.settings),
+            job_id,
+            job_config: params.job_config.unwrap_or(existing_tracker.job_config),
+            // Preserve timestamp only up to seconds.
+            updated_at: OffsetDateTime::from_unix_timestamp(
+                OffsetDateTime::now_utc().unix_timestamp(),
+            )?,
+            ..existing_tracker
+        };
+
+        self.validate_web_page_tracker(&tracker).await?;
+        if let Some(validator) = validator {
+            validator(&tracker)?;
+        }
+
+        let web_scraping = self.api.db.web_scraping(self.user.id);
+        web_scraping.update_web_page_tracker(&tracker).await?;
+
+        if changed_url {
+            log::debug!("Web page tracker ('{id}') changed URL, clearing web resources history.");
+            web_scraping.clear_web_page_tracker_history(id).await?;
+        } else if changed_request {
+            log::debug!(
+                "Web page tracker ('{id}') changed HTTP method or request body, clearing history."
+            );
+            web_scraping.clear_web_page_tracker_history(id).await?;
+        }
+
+        Ok(tracker)
+    }
+
+    /// Retrieves two distinct web page trackers of the same kind that should be compared with each
+    /// other. Trackers of other kinds are treated as not found.
+    async fn get_web_page_trackers_to_diff<Tag: WebPageTrackerTag>(
+        &self,
+        tracker_ids: [Uuid; 2],
+    ) -> anyhow::Result<Vec<WebPageTracker<Tag>>> {
+        if tracker_ids[0] == tracker_ids[1] {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker ('{}') cannot be compared with itself.",
+                tracker_ids[0]
+            )));
+        }
+
+        let web_scraping = self.api.db.web_scraping(self.user.id);
+        let mut trackers = Vec::with_capacity(tracker_ids.len());
+        for tracker_id in tracker_ids {
+            let Some(tracker) = web_scraping.get_web_page_tracker::<Tag>(tracker_id).await? else {
+                bail!(SecutilsError::client(format!(
+                    "Web page tracker ('{tracker_id}') is not found."
+                ))
+                .with_code(ErrorCode::TrackerNotFound));
+            };
+            trackers.push(tracker);
+        }
+
+        Ok(trackers)
+    }
+
+    /// Returns the ID of the revision pinned as a baseline for the specified web page tracker.
+    async fn get_baseline_revision_id(&self, tracker_id: Uuid) -> anyhow::Result<Option<Uuid>> {
+        self.api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker_baseline_revision_id(tracker_id)
+            .await
+    }
+
+    /// Records that the user has viewed the history of the specified web page tracker, so that the
+    /// tracker isn't considered idle.
+    async fn set_last_viewed_at(&self, tracker_id: Uuid) -> anyhow::Result<()> {
+        self.api
+            .db
+            .web_scraping(self.user.id)
+            .set_web_page_tracker_last_viewed_at(tracker_id, OffsetDateTime::now_utc())
+            .await
+    }
+
+    async fn get_web_page_tracker_revision<Tag: WebPageTrackerTag>(
+        &self,
+        tracker_id: Uuid,
+        revision_id: Uuid,
+    ) -> anyhow::Result<WebPageDataRevision<Tag>> {
+        self.api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker_history_revision::<Tag>(tracker_id, revision_id)
+            .await?
+            .ok_or_else(|| {
+                SecutilsError::client(format!(
+                    "Web page tracker revision ('{revision_id}') is not found."
+                ))
+                .with_code(ErrorCode::TrackerRevisionNotFound)
+                .into()
+            })
+    }
+
+    /// Checks whether the precondition of the web page tracker, if any, is met based on the
+    /// latest content extracted by the referenced web page content tracker. The precondition isn't
+    /// met if the referenced tracker doesn't exist or hasn't extracted any content yet.
+    pub async fn is_web_page_tracker_precondition_met<Tag: WebPageTrackerTag>(
+        &self,
+        tracker: &WebPageTracker<Tag>,
+    ) -> anyhow::Result<bool> {
+        let Some(ref precondition) = tracker.settings.precondition else {
+            return Ok(true);
+        };
+
+        let revisions = self
+            .api
+            .db
+            .web_scraping(self.user.id)
+            .get_web_page_tracker_history::<WebPageContentTrackerTag>(precondition.tracker_id)
+            .await?;
+        Ok(revisions
+            .last()
+            .is_some_and(|revision| precondition.is_met(&revision.data)))
+    }
+
+    /// Resolves the web page tracker settings against the limits of the user subscription and
+    /// the optional kind-specific revisions limit, the same way they're applied when the tracker
+    /// creates new revisions.
+    fn get_effective_tracker_settings<Tag: WebPageTrackerTag>(
+        &self,
+        tracker: WebPageTracker<Tag>,
+        max_revisions: Option<usize>,
+    ) -> anyhow::Result<WebPageTrackerEffectiveSettings> {
+        let features = self.user.subscription.get_features(&self.api.config);
+
+        let mut settings = tracker.settings;
+        settings.revisions = settings
+            .revisions
+            .min(features.config.web_scraping.tracker_revisions)
+            .min(max_revisions.unwrap_or(usize::MAX));
+
+        // Trackers that don't keep any revisions aren't scheduled.
+        let min_schedule_interval = match tracker.job_config {
+            Some(job_config) if settings.revisions > 0 => {
+                let schedule = Schedule::try_from(job_config.schedule.as_str())?;
+                Some(
+                    schedule
+                        .min_interval()?
+                        .max(features.config.web_scraping.min_schedule_interval),
+                )
+            }
+            _ => None,
+        };
+
+        Ok(WebPageTrackerEffectiveSettings {
+            settings,
+            min_schedule_interval,
+        })
+    }
+
+    async fn validate_web_page_tracker<Tag: WebPageTrackerTag>(
+        &self,
+        tracker: &WebPageTracker<Tag>,
+    ) -> anyhow::Result<()> {
+        if tracker.name.is_empty() {
+            bail!(SecutilsError::client(
+                "Web page tracker name cannot be empty.",
+            ));
+        }
+
+        if tracker.name.len() > MAX_UTILS_ENTITY_NAME_LENGTH {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker name cannot be longer than {} characters.",
+                MAX_UTILS_ENTITY_NAME_LENGTH
+            )));
+        }
+
+        let features = self.user.subscription.get_features(&self.api.config);
+        if tracker.settings.revisions > features.config.web_scraping.tracker_revisions {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker revisions count cannot be greater than {}.",
+                features.config.web_scraping.tracker_revisions
+            ))
+            .with_code(ErrorCode::LimitExceeded));
+        }
+
+        if tracker.settings.delay > MAX_WEB_PAGE_TRACKER_DELAY {
+            bail!(SecutilsError::client(format!(
+                "Web page tracker delay cannot be greater than {}ms.",
+                MAX_WEB_PAGE_TRACKER_DELAY.as_millis()
+            ))
+            .with_code(ErrorCode::LimitExceeded));
+        }
+
+        if let Some(sub_fetch_concurrency) = tracker.settings.sub_fetch_concurrency {
+            if sub_fetch_concurrency == 0 {
+                bail!(SecutilsError::client(
+                    "Web page tracker sub-fetch concurrency must be greater than 0."
+                ));
+            }
+
+            if sub_fetch_concurrency > MAX_WEB_PAGE_TRACKER_SUB_FETCH_CONCURRENCY {
+                bail!(SecutilsError::client(format!(
+                    "Web page tracker sub-fetch concurrency cannot be greater than {MAX_WEB_PAGE_TRACKER_SUB_FETCH_CONCURRENCY}."
+                ))
+                .with_code(ErrorCode::LimitExceeded));
+            }
+        }
+
+        if let Some(ref scripts) = tracker.settings.scripts {
+            if scripts
+                .iter()
+                .any(|(name, script)| name.is_empty() || script.is_empty())
+            {
+                bail!(SecutilsError::client(
+                    "Web page tracker scripts cannot be empty or have an empty name."
+                )
+                .with_code(ErrorCode::InvalidScript));
+            }
+
+            for (name, script) in scripts {
+                let diagnostics = JsRuntime::new(&self.js_runtime_config()).lint_script(script)?;
+                if let Some(diagnostic) = diagnostics.first() {
+                    let location = match (diagnostic.line_number, diagnostic.column_number) {
+                        (Some(line_number), Some(column_number)) => {
+                            format!(" (line {line_number}, column {column_number})")
+                        }
+                        _ => String::new(),
+                    };
+                    bail!(SecutilsError::client(format!(
+                        "Web page tracker script `{name}` is invalid: {}{location}",
+                        diagnostic.message
+                    ))
+                    .with_code(ErrorCode::InvalidScript));
+                }
+            }
+        }
+
+        if let Some(job_config) = &tracker.job_config {
+            // Validate that the schedule is a valid cron expression.
+            let schedule = match Schedule::try_from(job_config.schedule.as_str()) {
+                Ok(schedule) => schedule,
+                Err(err) => {
+                    bail!(SecutilsError::client_with_root_cause(
+                        anyhow!(
+                            "Failed to parse schedule `{}`: {err:?}",
+                            job_config.schedule
+                        )
+                        .context("Web page tracker schedule must be a valid cron expression.")
+                    )
+                    .with_code(ErrorCode::InvalidSchedule));
+                }
+            };
+
+            // Check if the interval between next occurrences is greater or equal to minimum
+            // interval defined by the subscription.
+            let min_schedule_interval = schedule.min_interval()?;
+            if min_schedule_interval < features.config.web_scraping.min_schedule_interval {
+                bail!(SecutilsError::client(format!(
+                    "Web page tracker schedule must have at least {} between occurrences, but detected {}.",
+                    humantime::format_duration(features.config.web_scraping.min_schedule_interval),
+                    humantime::format_duration(min_schedule_interval)
+                ))
+                .with_code(ErrorCode::InvalidSchedule));
+            }
+
+            // Validate retry strategy.
+            if let Some(retry_strategy) = &job_config.retry_strategy {
+                let max_attempts = retry_strategy.max_attempts();
+                if max_attempts == 0 || max_attempts > MAX_WEB_PAGE_TRACKER_RETRY_ATTEMPTS {
+                    bail!(SecutilsError::client(
+                        format!("Web page tracker max retry attempts cannot be zero or greater than {MAX_WEB_PAGE_TRACKER_RETRY_ATTEMPTS}, but received {max_attempts}.")
+                    )
+                    .with_code(ErrorCode::InvalidSchedule));
+                }
+
+                let min_interval = *retry_strategy.min_interval();
+                if min_interval < MIN_WEB_PAGE_TRACKER_RETRY_INTERVAL {
+                    bail!(SecutilsError::client(
+                        format!(
+                            "Web page tracker min retry interval cannot be less than {}, but received {}.",
+                            humantime::format_duration(MIN_WEB_PAGE_TRACKER_RETRY_INTERVAL),
+                            humantime::format_duration(min_interval)
+                        )
+                    )
+                    .with_code(ErrorCode::InvalidSchedule));
+                }
+
+                // The total retry duration should allow at least one retry and shouldn't overlap
+                // with the next scheduled run.
+                if let Some(max_total_retry_duration) = retry_strategy.max_total_retry_duration() {
+                    if max_total_retry_duration < min_interval
+                        || max_total_retry_duration > min_schedule_interval
+                    {
+                        bail!(SecutilsError::client(
+                            format!(
+                                "Web page tracker max total retry duration cannot be less than {} or greater than {}, but received {}.",
+                                humantime::format_duration(min_interval),
+                                humantime::format_duration(min_schedule_interval),
+                                humantime::format_duration(max_total_retry_duration)
+                            )
+                        )
+                        .with_code(ErrorCode::InvalidSchedule));
+                    }
+                }
+
+                if let SchedulerJobRetryStrategy::Linear { max_interval, .. }
+                | SchedulerJobRetryStrategy::Exponential { max_interval, .. } = retry_strategy
+                {
+                    let max_interval = *max_interval;
+                    if max_interval < MIN_WEB_PAGE_TRACKER_RETRY_INTERVAL {
+                        bail!(SecutilsError::client(
+                            format!(
+                                "Web page tracker retry strategy max interval cannot be less than {}, but received {}.",
+                                humantime::format_duration(MIN_WEB_PAGE_TRACKER_RETRY_INTERVAL),
+                                humantime::format_duration(max_interval)
+                            )
+                        )
+                        .with_code(ErrorCode::InvalidSchedule));
+                    }
+
+                    if max_interval > MAX_WEB_PAGE_TRACKER_RETRY_INTERVAL
+                        || max_interval > min_schedule_interval
+                    {
+                        bail!(SecutilsError::client(
+                            format!(
+                                "Web page tracker retry strategy max interval cannot be greater than {}, but received {}.",
+                                humantime::format_duration(MAX_WEB_PAGE_TRACKER_RETRY_INTERVAL.min(min_schedule_interval)),
+                                humantime::format_duration(max_interval)
+                            )
+                        )
+                        .with_code(ErrorCode::InvalidSchedule));
+                    }
+                }
+            }
+
+            // Validate adaptive interval.
+            if let Some(adaptive_interval) = &job_config.adaptive_interval {
+                if adaptive_interval.min_interval < min_schedule_interval {
+                    bail!(SecutilsError::client(format!(
+                        "Web page tracker adaptive min interval cannot be less than {}, but received {}.",
+                        humantime::format_duration(min_schedule_interval),
+                        humantime::format_duration(adaptive_interval.min_interval)
+                    ))
+                    .with_code(ErrorCode::InvalidSchedule));
+                }
+
+                if adaptive_interval.max_interval < adaptive_interval.min_interval {
+                    bail!(SecutilsError::client(format!(
+                        "Web page tracker adaptive max interval cannot be less than {}, but received {}.",
+                        humantime::format_duration(adaptive_interval.min_interval),
+                        humantime::format_duration(adaptive_interval.max_interval)
+                    ))
+                    .with_code(ErrorCode::InvalidSchedule));
+                }
+            }
+        }
+
+        if tracker.settings.insecure_tls {
+            if !self.user.is_operator {
+                bail!(SecutilsError::client(
+                    "Only operators can disable TLS certificate verification for web page trackers."
+                )
+                .with_code(ErrorCode::NotAllowed));
+            }
+
+            log::warn!(
+                user:serde = self.user.log_context(),
+                util:serde = tracker.log_context();
+                "Web page tracker has TLS certificate verification disabled, the tracked web page can be impersonated."
+            );
+        }
+
+        if let Some(retention) = tracker
+            .settings
+            .data_policy
+            .and_then(|data_policy| data_policy.retention)
+        {
+            if retention < MIN_WEB_PAGE_TRACKER_DATA_RETENTION {
+                bail!(SecutilsError::client(format!(
+                    "Web page tracker data retention cannot be less than {}, but received {}.",
+                    humantime::format_duration(MIN_WEB_PAGE_TRACKER_DATA_RETENTION),
+                    humantime::format_duration(retention)
+                )));
+            }
+        }
+
+        // Make sure that the tracker depends on the existing tracker of the same kind, and that the
+        // dependency chain doesn't loop back.
+        let mut visited_tracker_ids = HashSet::from([tracker.id]);
+        let mut next_dependency_id = tracker.settings.depends_on;
+        while let Some(dependency_id) = next_dependency_id {
+            if !visited_tracker_ids.insert(dependency_id) {
+                bail!(SecutilsError::client(
+                    "Web page tracker dependencies cannot form a cycle."
+                ));
+            }
+
+            let Some(dependency) = self
+                .api
+                .db
+                .web_scraping(self.user.id)
+                .get_web_page_tracker::<Tag>(dependency_id)
+                .await?
+            else {
+                bail!(SecutilsError::client(format!(
+                    "Web page tracker dependency ('{dependency_id}') is not found."
+                ))
+                .with_code(ErrorCode::TrackerNotFound));
+            };
+
+            next_dependency_id = dependency.settings.depends_on;
+        }
+
+        // Make sure that the precondition references the existing content tracker.
+        if let Some(ref precondition) = tracker.settings.precondition {
+            if precondition.tracker_id == tracker.id {
+                bail!(SecutilsError::client(
+                    "Web page tracker precondition cannot reference the tracker itself."
+                ));
+            }
+
+            if precondition
+                .pointer
+                .as_ref()
+                .is_some_and(|pointer| !pointer.starts_with('/'))
+            {
+                bail!(SecutilsError::client(
+                    "Web page tracker precondition pointer must be a valid JSON pointer (e.g. `/status`)."
+                ));
+            }
+
+            if precondition.value.len() > MAX_WEB_PAGE_TRACKER_PRECONDITION_VALUE_LENGTH {
+                bail!(SecutilsError::client(format!(
+                    "Web page tracker precondition value cannot be longer than {MAX_WEB_PAGE_TRACKER_PRECONDITION_VALUE_LENGTH} characters."
+                )));
+            }
+
+            if self
+                .api
+                .db
+                .web_scraping(self.user.id)
+                .get_web_page_tracker::<WebPageContentTrackerTag>(precondition.tracker_id)
+                .await?
+                .is_none()
+            {
+                bail!(SecutilsError::client(format!(
+                    "Web page tracker precondition content tracker ('{}') is not found.",
+                    precondition.tracker_id
+                ))
+                .with_code(ErrorCode::TrackerNotFound));
+            }
+        }
+
+        if !self.api.network.is_public_web_url(&tracker.url).await {
+            bail!(SecutilsError::client(
+                format!("Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received {}.", tracker.url)
+            )
+            .with_code(ErrorCode::InvalidUrl));
+        }
+
+        for url in tracker.settings.additional_urls.iter().flatten() {
+            if !self.api.network.is_public_web_url(url).await {
+                bail!(SecutilsError::client(
+                    format!("Web page tracker additional URL must be either `http` or `https` and have a valid public reachable domain name, but received {url}.")
+                )
+                .with_code(ErrorCode::InvalidUrl));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_web_page_resources_tracker(
+        &self,
+        tracker: &WebPageTracker<WebPageResourcesTrackerTag>,
+    ) -> anyhow::Result<()> {
+        if let Some(ref scripts) = tracker.settings.scripts {
+            if !scripts.is_empty()
+                && !scripts.contains_key(WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME)
+            {
+                bail!(
+                    SecutilsError::client("Web page tracker contains unrecognized scripts.")
+                        .with_code(ErrorCode::InvalidScript)
+                );
+            }
+        }
+
+        if tracker.settings.status_only {
+            bail!(SecutilsError::client(
+                "Web page resources tracker doesn't support status-only mode."
+            ));
+        }
+
+        if tracker.settings.byte_range.is_some() {
+            bail!(SecutilsError::client(
+                "Web page resources tracker doesn't support byte range."
+            ));
+        }
+
+        if tracker.settings.capture_cookies.is_some() {
+            bail!(SecutilsError::client(
+                "Web page resources tracker doesn't support cookies capture."
+            ));
+        }
+
+        if tracker.settings.capture_response.is_some() {
+            bail!(SecutilsError::client(
+                "Web page resources tracker doesn't support response capture."
+            ));
+        }
+
+        if tracker.settings.dedup_window.is_some() {
+            bail!(SecutilsError::client(
+                "Web page resources tracker doesn't support revisions dedup window."
+            ));
+        }
+
+        if let Some(size_budget) = tracker.settings.size_budget {
+            if size_budget.max_total_size.is_none() && size_budget.max_growth.is_none() {
+                bail!(SecutilsError::client(
+                    "Web page resources tracker size budget should define either maximum total size or maximum growth."
+                ));
+            }
+
+            if size_budget.max_total_size == Some(0) || size_budget.max_growth == Some(0) {
+                bail!(SecutilsError::client(
+                    "Web page resources tracker size budget limits should be greater than zero."
+                ));
+            }
+        }
+
+        if let Some(ref resource_types) = tracker.settings.resource_types {
+            if resource_types.is_empty() {
+                bail!(SecutilsError::client(
+                    "Web page resources tracker should track at least one resource type."
+                ));
+            }
+        }
+
+        if tracker.settings.record_types.is_some() {
+            bail!(SecutilsError::client(
+                "Web page resources tracker doesn't support DNS record types."
+            ));
+        }
+
+        if tracker.settings.notify_on_first {
+            bail!(SecutilsError::client(
+                "Web page resources tracker doesn't support first revision notifications."
+            ));
+        }
+
+        if tracker.settings.capture_fingerprint {
+            bail!(SecutilsError::client(
+                "Web page resources tracker doesn't support fingerprint capture."
+            ));
+        }
+
+        if tracker.settings.store_diffs {
+            bail!(SecutilsError::client(
+                "Web page resources tracker doesn't support storing revisions as diffs."
+            ));
+        }
+
+        if tracker.settings.additional_urls.is_some() {
+            bail!(SecutilsError::client(
+                "Web page resources tracker doesn't support additional URLs."
+            ));
+        }
+
+        if tracker.settings.content_format.is_some() {
+            bail!(SecutilsError::client(
+                "Web page resources tracker doesn't support content format."
+            ));
+        }
+
+        if tracker.settings.assertions.is_some() {
+            bail!(SecutilsError::client(
+                "Web page resources tracker doesn't support content assertions."
+            ));
+        }
+
+        if tracker.settings.method.is_some() || tracker.settings.request_body.is_some() {
+            bail!(SecutilsError::client(
+                "Web page resources tracker doesn't support custom HTTP method or request body."
+            ));
+        }
+
+        if let Some(ref ignore_resource_urls) = tracker.settings.ignore_resource_urls {
+            for ignore_resource_url in ignore_resource_urls {
+                if let Err(err) = Regex::new(ignore_resource_url) {
+                    bail!(SecutilsError::client_with_root_cause(
+                        anyhow!("Failed to parse ignored resource URL `{ignore_resource_url}`: {err}")
+                            .context("Web page tracker ignored resource URLs must be valid regular expressions.")
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_web_page_content_tracker(
+        &self,
+        tracker: &WebPageTracker<WebPageContentTrackerTag>,
+    ) -> anyhow::Result<()> {
+        if let Some(ref scripts) = tracker.settings.scripts {
+            if !scripts.is_empty()
+                && !scripts.contains_key(WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME)
+            {
+                bail!(
+                    SecutilsError::client("Web page tracker contains unrecognized scripts.")
+                        .with_code(ErrorCode::InvalidScript)
+                );
+            }
+        }
+
+        if tracker.settings.ignore_resource_urls.is_some() {
+            bail!(SecutilsError::client(
+                "Web page content tracker doesn't support ignored resource URLs."
+            ));
+        }
+
+        if tracker.settings.classify_resources {
+            bail!(SecutilsError::client(
+                "Web page content tracker doesn't support resources classification."
+            ));
+        }
+
+        if tracker.settings.size_budget.is_some() {
+            bail!(SecutilsError::client(
+                "Web page content tracker doesn't support size budget."
+            ));
+        }
+
+        if tracker.settings.resource_types.is_some() {
+            bail!(SecutilsError::client(
+                "Web page content tracker doesn't support resource types."
+            ));
+        }
+
+        if tracker.settings.record_types.is_some() {
+            bail!(SecutilsError::client(
+                "Web page content tracker doesn't support DNS record types."
+            ));
+        }
+
+        if tracker.settings.status_only && tracker.settings.capture_cookies.is_some() {
+            bail!(SecutilsError::client(
+                "Web page content tracker doesn't support cookies capture in status-only mode."
+            ));
+        }
+
+        if let Some(ref capture_response) = tracker.settings.capture_response {
+            if tracker.settings.status_only {
+                bail!(SecutilsError::client(
+                    "Web page content tracker doesn't support response capture in status-only mode."
+                ));
+            }
+
+            if tracker.settings.capture_cookies.is_some() {
+                bail!(SecutilsError::client(
+                    "Web page content tracker doesn't support both response and cookies capture."
+                ));
+            }
+
+            if tracker.settings.byte_range.is_some() {
+                bail!(SecutilsError::client(
+                    "Web page content tracker doesn't support byte range in response capture mode."
+                ));
+            }
+
+            if tracker
+                .settings
+                .scripts
+                .as_ref()
+                .is_some_and(|scripts| !scripts.is_empty())
+            {
+                bail!(SecutilsError::client(
+                    "Web page content tracker doesn't support scripts in response capture mode."
+                )
+                .with_code(ErrorCode::InvalidScript));
+            }
+
+            let features = self.user.subscription.get_features(&self.api.config);
+            let max_headers = features.config.web_scraping.tracker_captured_headers;
+            if capture_response.headers.len() > max_headers {
+                bail!(SecutilsError::client(format!(
+                    "Web page content tracker cannot capture more than {max_headers} response headers."
+                ))
+                .with_code(ErrorCode::LimitExceeded));
+            }
+
+            if let Some(header) = capture_response
+                .headers
+                .iter()
+                .find(|header| HeaderName::from_bytes(header.as_bytes()).is_err())
+            {
+                bail!(SecutilsError::client(format!(
+                    "Web page content tracker response capture header name `{header}` is not valid."
+                )));
+            }
+        }
+
+        if tracker.settings.capture_fingerprint {
+            if tracker.settings.status_only {
+                bail!(SecutilsError::client(
+                    "Web page content tracker doesn't support fingerprint capture in status-only mode."
+                ));
+            }
+
+            if tracker.settings.capture_response.is_some()
+                || tracker.settings.capture_cookies.is_some()
+            {
+                bail!(SecutilsError::client(
+                    "Web page content tracker doesn't support fingerprint capture together with response or cookies capture."
+                ));
+            }
+
+            if tracker.settings.byte_range.is_some() {
+                bail!(SecutilsError::client(
+                    "Web page content tracker doesn't support byte range in fingerprint capture mode."
+                ));
+            }
+
+            if tracker
+                .settings
+                .scripts
+                .as_ref()
+                .is_some_and(|scripts| !scripts.is_empty())
+            {
+                bail!(SecutilsError::client(
+                    "Web page content tracker doesn't support scripts in fingerprint capture mode."
+                )
+                .with_code(ErrorCode::InvalidScript));
+            }
+        }
+
+        if let Some(dedup_window) = tracker.settings.dedup_window {
+            if dedup_window == 0 || dedup_window > tracker.settings.revisions {
+                bail!(SecutilsError::client(format!(
+                    "Web page content tracker revisions dedup window should be between 1 and {}, but received {dedup_window}.",
+                    tracker.settings.revisions
+                )));
+            }
+
+            if tracker.settings.status_only {
+                bail!(SecutilsError::client(
+                    "Web page content tracker doesn't support revisions dedup window in status-only mode."
+                ));
+            }
+        }
+
+        if let Some((start, end)) = tracker.settings.byte_range {
+            if start > end {
+                bail!(SecutilsError::client(format!(
+                    "Web page content tracker byte range start cannot be greater than its end, but received {start}-{end}."
+                )));
+            }
+
+            if tracker.settings.status_only {
+                bail!(SecutilsError::client(
+                    "Web page content tracker doesn't support byte range in status-only mode."
+                ));
+            }
+
+            if tracker.settings.headers.as_ref().is_some_and(|headers| {
+                headers
+                    .keys()
+                    .any(|name| name.eq_ignore_ascii_case(RANGE.as_str()))
+            }) {
+                bail!(SecutilsError::client(
+                    "Web page content tracker cannot have both byte range and `Range` header."
+                ));
+            }
+        }
+
+        if tracker.settings.status_only
+            && tracker
+                .settings
+                .scripts
+                .as_ref()
+                .is_some_and(|scripts| !scripts.is_empty())
+        {
+            bail!(SecutilsError::client(
+                "Web page content tracker doesn't support scripts in status-only mode."
+            )
+            .with_code(ErrorCode::InvalidScript));
+        }
+
+        if let Some(ref additional_urls) = tracker.settings.additional_urls {
+            if !tracker.settings.status_only {
+                bail!(SecutilsError::client(
+                    "Web page content tracker supports additional URLs only in status-only mode."
+                ));
+            }
+
+            if additional_urls.is_empty() {
+                bail!(SecutilsError::client(
+                    "Web page content tracker additional URLs cannot be empty."
+                ));
+            }
+
+            // The main tracker URL counts towards the limit as well.
+            let features = self.user.subscription.get_features(&self.api.config);
+            let max_urls = features.config.web_scraping.tracker_urls;
+            if additional_urls.len() + 1 > max_urls {
+                bail!(SecutilsError::client(format!(
+                    "Web page content tracker cannot monitor more than {max_urls} URLs."
+                ))
+                .with_code(ErrorCode::LimitExceeded));
+            }
+
+            let mut unique_urls = HashSet::from([&tracker.url]);
+            if let Some(url) = additional_urls.iter().find(|url| !unique_urls.insert(url)) {
+                bail!(SecutilsError::client(format!(
+                    "Web page content tracker cannot monitor the same URL more than once, but received {url}."
+                )));
+            }
+        }
+
+        // Content format defines how the content extracted from the web page is compared, so it
+        // doesn't make sense when only the status, response, or fingerprint are captured.
+        if tracker.settings.content_format.is_some()
+            && (tracker.settings.status_only
+                || tracker.settings.capture_response.is_some()
+                || tracker.settings.capture_fingerprint
+                || tracker.settings.capture_cookies.is_some())
+        {
+            bail!(SecutilsError::client(
+                "Web page content tracker doesn't support content format together with status-only mode, or response, fingerprint, or cookies capture."
+            ));
+        }
+
+        if let Some(ref assertions) = tracker.settings.assertions {
+            if assertions.is_empty() || assertions.len() > MAX_WEB_PAGE_CONTENT_TRACKER_ASSERTIONS {
+                bail!(SecutilsError::client(format!(
+                    "Web page content tracker should have at least 1 and at most {MAX_WEB_PAGE_CONTENT_TRACKER_ASSERTIONS} content assertions, but received {}.",
+                    assertions.len()
+                )));
+            }
+
+            for assertion in assertions {
+                if assertion.value.is_empty()
+                    || assertion.value.len() > MAX_WEB_PAGE_CONTENT_TRACKER_ASSERTION_VALUE_LENGTH
+                {
+                    bail!(SecutilsError::client(format!(
+                        "Web page content tracker assertion value cannot be empty or longer than {MAX_WEB_PAGE_CONTENT_TRACKER_ASSERTION_VALUE_LENGTH} characters."
+                    )));
+                }
+
+                if assertion.operator.is_regex() {
+                    if let Err(err) = Regex::new(&assertion.value) {
+                        bail!(SecutilsError::client_with_root_cause(
+                            anyhow!("Failed to parse assertion value `{}`: {err}", assertion.value)
+                                .context("Web page content tracker assertion value must be a valid regular expression.")
+                        ));
+                    }
+                }
+            }
+        }
+
+        let method = if let Some(ref method) = tracker.settings.method {
+            if method.is_empty() || method.len() > MAX_WEB_PAGE_CONTENT_TRACKER_METHOD_LENGTH {
+                bail!(SecutilsError::client(format!(
+                    "Web page content tracker HTTP method cannot be empty or longer than {MAX_WEB_PAGE_CONTENT_TRACKER_METHOD_LENGTH} characters."
+                )));
+            }
+
+            match Method::from_bytes(method.as_bytes()) {
+                Ok(method) => method,
+                Err(err) => bail!(SecutilsError::client_with_root_cause(
+                    anyhow!("Failed to parse HTTP method `{method}`: {err}").context(
+                        "Web page content tracker HTTP method must be a valid HTTP method."
+                    )
+                )),
+            }
+        } else {
+            Method::GET
+        };
+
+        if let Some(ref request_body) = tracker.settings.request_body {
+            if request_body.is_empty()
+                || request_body.len() > MAX_WEB_PAGE_CONTENT_TRACKER_REQUEST_BODY_SIZE
+            {
+                bail!(SecutilsError::client(format!(
+                    "Web page content tracker request body cannot be empty or larger than {MAX_WEB_PAGE_CONTENT_TRACKER_REQUEST_BODY_SIZE} bytes."
+                )));
+            }
+
+            if method == Method::GET || method == Method::HEAD {
+                bail!(SecutilsError::client(format!(
+                    "Web page content tracker cannot send request body with {method} HTTP method."
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_web_page_screenshot_tracker(
+        &self,
+        tracker: &WebPageTracker<WebPageScreenshotTrackerTag>,
+    ) -> anyhow::Result<()> {
+        if tracker.settings.revisions > MAX_WEB_PAGE_SCREENSHOT_TRACKER_REVISIONS {
+            bail!(SecutilsError::client(format!(
+                "Web page screenshot tracker revisions count cannot be greater than {MAX_WEB_PAGE_SCREENSHOT_TRACKER_REVISIONS}."
+            ))
+            .with_code(ErrorCode::LimitExceeded));
+        }
+
+        if let Some(ref scripts) = tracker.settings.scripts {
+            if !scripts.is_empty() {
+                bail!(SecutilsError::client(
+                    "Web page screenshot tracker doesn't support scripts."
+                )
+                .with_code(ErrorCode::InvalidScript));
+            }
+        }
+
+        if tracker.settings.ignore_resource_urls.is_some() {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support ignored resource URLs."
+            ));
+        }
+
+        if tracker.settings.classify_resources {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support resources classification."
+            ));
+        }
+
+        if tracker.settings.capture_cookies.is_some() {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support cookies capture."
+            ));
+        }
+
+        if tracker.settings.capture_response.is_some() {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support response capture."
+            ));
+        }
+
+        if tracker.settings.dedup_window.is_some() {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support revisions dedup window."
+            ));
+        }
+
+        if tracker.settings.size_budget.is_some() {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support size budget."
+            ));
+        }
+
+        if tracker.settings.resource_types.is_some() {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support resource types."
+            ));
+        }
+
+        if tracker.settings.record_types.is_some() {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support DNS record types."
+            ));
+        }
+
+        if tracker.settings.notify_on_first {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support first revision notifications."
+            ));
+        }
+
+        if tracker.settings.capture_fingerprint {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support fingerprint capture."
+            ));
+        }
+
+        if tracker.settings.store_diffs {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support storing revisions as diffs."
+            ));
+        }
+
+        if tracker.settings.additional_urls.is_some() {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support additional URLs."
+            ));
+        }
+
+        if tracker.settings.content_format.is_some() {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support content format."
+            ));
+        }
+
+        if tracker.settings.assertions.is_some() {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support content assertions."
+            ));
+        }
+
+        if tracker.settings.method.is_some() || tracker.settings.request_body.is_some() {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support custom HTTP method or request body."
+            ));
+        }
+
+        if tracker.settings.status_only {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support status-only mode."
+            ));
+        }
+
+        if tracker.settings.byte_range.is_some() {
+            bail!(SecutilsError::client(
+                "Web page screenshot tracker doesn't support byte range."
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn validate_web_page_dns_records_tracker(
+        &self,
+        tracker: &WebPageTracker<WebPageDnsRecordsTrackerTag>,
+    ) -> anyhow::Result<()> {
+        if !matches!(tracker.url.host(), Some(Host::Domain(_))) {
+            bail!(SecutilsError::client(format!(
+                "Web page DNS records tracker URL must have a domain name, but received {}.",
+                tracker.url
+            ))
+            .with_code(ErrorCode::InvalidUrl));
+        }
+
+        if let Some(ref record_types) = tracker.settings.record_types {
+            if record_types.is_empty() {
+                bail!(SecutilsError::client(
+                    "Web page DNS records tracker should track at least one DNS record type."
+                ));
+            }
+        }
+
+        if let Some(ref scripts) = tracker.settings.scripts {
+            if !scripts.is_empty() {
+                bail!(SecutilsError::client(
+                    "Web page DNS records tracker doesn't support scripts."
+                )
+                .with_code(ErrorCode::InvalidScript));
+            }
+        }
+
+        if tracker.settings.headers.is_some() {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support headers."
+            ));
+        }
+
+        if tracker.settings.ignore_resource_urls.is_some() {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support ignored resource URLs."
+            ));
+        }
+
+        if tracker.settings.classify_resources {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support resources classification."
+            ));
+        }
+
+        if tracker.settings.capture_cookies.is_some() {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support cookies capture."
+            ));
+        }
+
+        if tracker.settings.capture_response.is_some() {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support response capture."
+            ));
+        }
+
+        if tracker.settings.dedup_window.is_some() {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support revisions dedup window."
+            ));
+        }
+
+        if tracker.settings.size_budget.is_some() {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support size budget."
+            ));
+        }
+
+        if tracker.settings.resource_types.is_some() {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support resource types."
+            ));
+        }
+
+        if tracker.settings.status_only {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support status-only mode."
+            ));
+        }
+
+        if tracker.settings.byte_range.is_some() {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support byte range."
+            ));
+        }
+
+        if tracker.settings.notify_on_first {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support first revision notifications."
+            ));
+        }
+
+        if tracker.settings.capture_fingerprint {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support fingerprint capture."
+            ));
+        }
+
+        if tracker.settings.store_diffs {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support storing revisions as diffs."
+            ));
+        }
+
+        if tracker.settings.additional_urls.is_some() {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support additional URLs."
+            ));
+        }
+
+        if tracker.settings.content_format.is_some() {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support content format."
+            ));
+        }
+
+        if tracker.settings.assertions.is_some() {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support content assertions."
+            ));
+        }
+
+        if tracker.settings.method.is_some() || tracker.settings.request_body.is_some() {
+            bail!(SecutilsError::client(
+                "Web page DNS records tracker doesn't support custom HTTP method or request body."
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn validate_status_page(&self, status_page: &WebPageStatusPage) -> anyhow::Result<()> {
+        if status_page.name.is_empty() {
+            bail!(SecutilsError::client("Status page name cannot be empty."));
+        }
+
+        if status_page.name.len() > MAX_UTILS_ENTITY_NAME_LENGTH {
+            bail!(SecutilsError::client(format!(
+                "Status page name cannot be longer than {MAX_UTILS_ENTITY_NAME_LENGTH} characters."
+            )));
+        }
+
+        if status_page.tracker_ids.is_empty()
+            || status_page.tracker_ids.len() > MAX_WEB_PAGE_STATUS_PAGE_TRACKERS
+        {
+            bail!(SecutilsError::client(format!(
+                "Status page should display between 1 and {MAX_WEB_PAGE_STATUS_PAGE_TRACKERS} trackers."
+            )));
+        }
+
+        let mut tracker_ids = HashSet::with_capacity(status_page.tracker_ids.len());
+        let web_scraping = self.api.db.web_scraping(self.user.id);
+        for tracker_id in &status_page.tracker_ids {
+            if !tracker_ids.insert(tracker_id) {
+                bail!(SecutilsError::client(format!(
+                    "Status page tracker ('{tracker_id}') is specified more than once."
+                )));
+            }
+
+            let Some(tracker) = web_scraping
+                .get_web_page_tracker::<WebPageContentTrackerTag>(*tracker_id)
+                .await?
+            else {
+                bail!(SecutilsError::client(format!(
+                    "Status page tracker ('{tracker_id}') is not found."
+                ))
+                .with_code(ErrorCode::TrackerNotFound));
+            };
+
+            if !tracker.settings.status_only {
+                bail!(SecutilsError::client(format!(
+                    "Status page tracker ('{}') must be a content tracker in status-only mode.",
+                    tracker.name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
+    /// Returns an API to work with web scraping data.
+    pub fn web_scraping(&'a self, user: &'u User) -> WebScrapingApiExt<'a, 'u, DR, ET> {
+        WebScrapingApiExt::new(self, user)
+    }
+}
+
+/// Generates a random alphanumeric token used to access the status page publicly.
+fn generate_status_page_token() -> String {
+    thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(WEB_PAGE_STATUS_PAGE_TOKEN_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+fn generate_scraper_callback_token() -> String {
+    thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(WEB_SCRAPER_CALLBACK_TOKEN_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+/// Generates a random alphanumeric secret used to sign web page tracker webhook payloads.
+fn generate_webhook_secret() -> String {
+    thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(GENERATED_WEB_PAGE_TRACKER_WEBHOOK_SECRET_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+/// Converts stored web page resources data to its public representation, drops resources of the
+/// types that aren't tracked, and classifies the known third-party resources, if requested.
+fn web_page_resources_data(
+    data: WebPageResourcesData<WebPageResourceInternal>,
+    resource_types: Option<&[WebPageResourceType]>,
+    classify_resources: bool,
+) -> WebPageResourcesData {
+    let mut data = WebPageResourcesData {
+        scripts: data.scripts.into_iter().map(Into::into).collect(),
+        styles: data.styles.into_iter().map(Into::into).collect(),
+        categories: None,
+        sizes: None,
+        truncated: data.truncated,
+    };
+    if let Some(resource_types) = resource_types {
+        data.retain_types(resource_types);
+    }
+
+    if classify_resources {
+        data.classified()
+    } else {
+        data
+    }
+}
+
+/// Calculates the diff between the adjacent web page tracker revisions using the specified `diff`
+/// function. If the baseline revision is specified, the revisions created after the baseline are
+/// compared to the baseline instead of the previous revision.
+fn web_page_tracker_revisions_diff<Tag, Diff>(
+    mut revisions: Vec<WebPageDataRevision<Tag>>,
+    baseline_revision_id: Option<Uuid>,
+    diff: Diff,
+) -> anyhow::Result<Vec<WebPageDataRevision<Tag>>>
+where
+    Tag: WebPageTrackerTag + Clone,
+    Diff: Fn(Vec<WebPageDataRevision<Tag>>) -> anyhow::Result<Vec<WebPageDataRevision<Tag>>>,
+{
+    let Some(baseline_index) = baseline_revision_id.and_then(|baseline_revision_id| {
+        revisions
+            .iter()
+            .position(|revision| revision.id == baseline_revision_id)
+    }) else {
+        return diff(revisions);
+    };
+
+    let revisions_after_baseline = revisions.split_off(baseline_index + 1);
+    let baseline_revision = revisions[baseline_index].clone();
+    let mut revisions_diff = diff(revisions)?;
+    for revision in revisions_after_baseline {
+        revisions_diff.push(
+            diff(vec![baseline_revision.clone(), revision])?
+                .pop()
+                .ok_or_else(|| anyhow!("Failed to calculate tracker revision diff."))?,
+        );
+    }
+
+    Ok(revisions_diff)
+}
+
+/// Serializes every web page tracker revision from the stream into a standalone JSON line. If
+/// `diff` is provided, every revision is replaced with its diff against the previous revision, or
+/// against the baseline revision for the revisions created after the baseline.
+fn web_page_tracker_history_lines<'a, Tag, Diff>(
+    revisions: impl Stream<Item = anyhow::Result<WebPageDataRevision<Tag>>> + 'a,
+    diff: Option<Diff>,
+    baseline_revision_id: Option<Uuid>,
+) -> LocalBoxStream<'a, anyhow::Result<Bytes>>
+where
+    Tag: WebPageTrackerTag + Clone + 'a,
+    Diff: Fn(Vec<WebPageDataRevision<Tag>>) -> anyhow::Result<Vec<WebPageDataRevision<Tag>>> + 'a,
+{
+    try_stream! {
+        pin_mut!(revisions);
+
+        let mut previous_revision: Option<WebPageDataRevision<Tag>> = None;
+        while let Some(revision) = revisions.next().await {
+            let revision = revision?;
+            let line_revision = match (&diff, &previous_revision) {
+                (Some(diff), Some(previous_revision)) => {
+                    diff(vec![previous_revision.clone(), revision.clone()])?
+                        .pop()
+                        .ok_or_else(|| anyhow!("Failed to calculate tracker revision diff."))?
+                }
+                _ => revision.clone(),
+            };
+
+            let mut line = serde_json::to_vec(&line_revision)?;
+            line.push(b'\n');
+            yield Bytes::from(line);
+
+            // Once the baseline revision is reached, all subsequent revisions are compared to it.
+            let is_baseline_reached = previous_revision
+                .as_ref()
+                .is_some_and(|previous_revision| Some(previous_revision.id) == baseline_revision_id);
+            if diff.is_some() && !is_baseline_reached {
+                previous_revision = Some(revision);
+            }
+        }
+    }
+    .boxed_local()
+}
+
+/// Serializes fields into a single CSV row terminated with a line break. Fields that contain
+/// commas, quotes, or line breaks are quoted, and quotes within them are doubled.
+fn web_page_tracker_history_csv_row<'f>(fields: impl IntoIterator<Item = &'f str>) -> String {
+    let mut row = fields
+        .into_iter()
+        .map(|field| {
+            if field.contains([',', '"', '\r', '\n']) {
+                Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+            } else {
+                Cow::Borrowed(field)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push('\n');
+    row
+}
+
+pub struct WebScrapingSystemApiExt<'a> {
+    web_scraping_system: WebScrapingDatabaseSystemExt<'a>,
+}
+
+impl<'a> WebScrapingSystemApiExt<'a> {
+    /// Creates WebScraping System API.
+    pub fn new<DR: DnsResolver, ET: EmailTransport>(api: &'a Api<DR, ET>) -> Self {
+        Self {
+            web_scraping_system: api.db.web_scraping_system(),
+        }
+    }
+
+    /// Returns all web page resources tracker job references that have jobs that need to be scheduled.
+    pub async fn get_unscheduled_resources_trackers(
+        &self,
+    ) -> anyhow::Result<Vec<WebPageTracker<WebPageResourcesTrackerTag>>> {
+        self.get_unscheduled_web_page_trackers().await
+    }
+
+    /// Returns all web page content tracker job references that have jobs that need to be scheduled.
+    pub async fn get_unscheduled_content_trackers(
+        &self,
+    ) -> anyhow::Result<Vec<WebPageTracker<WebPageContentTrackerTag>>> {
+        self.get_unscheduled_web_page_trackers().await
+    }
+
+    /// Returns all web page screenshot tracker job references that have jobs that need to be scheduled.
+    pub async fn get_unscheduled_screenshot_trackers(
+        &self,
+    ) -> anyhow::Result<Vec<WebPageTracker<WebPageScreenshotTrackerTag>>> {
+        self.get_unscheduled_web_page_trackers().await
+    }
+
+    /// Returns all web page DNS records tracker job references that have jobs that need to be scheduled.
+    pub async fn get_unscheduled_dns_records_trackers(
+        &self,
+    ) -> anyhow::Result<Vec<WebPageTracker<WebPageDnsRecordsTrackerTag>>> {
+        self.get_unscheduled_web_page_trackers().await
+    }
+
+    /// Returns all web page resources trackers that have pending jobs.
+    pub fn get_pending_resources_trackers(
+        &self,
+    ) -> impl Stream<Item = anyhow::Result<WebPageTracker<WebPageResourcesTrackerTag>>> + '_ {
+        self.web_scraping_system
+            .get_pending_web_page_trackers(MAX_JOBS_PAGE_SIZE)
+    }
+
+    /// Returns all web page resources trackers that have pending jobs.
+    pub fn get_pending_content_trackers(
+        &self,
+    ) -> impl Stream<Item = anyhow::Result<WebPageTracker<WebPageContentTrackerTag>>> + '_ {
+        self.web_scraping_system
+            .get_pending_web_page_trackers(MAX_JOBS_PAGE_SIZE)
+    }
+
+    /// Returns all web page screenshot trackers that have pending jobs.
+    pub fn get_pending_screenshot_trackers(
+        &self,
+    ) -> impl Stream<Item = anyhow::Result<WebPageTracker<WebPageScreenshotTrackerTag>>> + '_ {
+        self.web_scraping_system
+            .get_pending_web_page_trackers(MAX_JOBS_PAGE_SIZE)
+    }
+
+    /// Returns all web page DNS records trackers that have pending jobs.
+    pub fn get_pending_dns_records_trackers(
+        &self,
+    ) -> impl Stream<Item = anyhow::Result<WebPageTracker<WebPageDnsRecordsTrackerTag>>> + '_ {
+        self.web_scraping_system
+            .get_pending_web_page_trackers(MAX_JOBS_PAGE_SIZE)
+    }
+
+    /// Returns web page resources tracker by the corresponding job ID.
+    pub async fn get_resources_tracker_by_job_id(
+        &self,
+        job_id: Uuid,
+    ) -> anyhow::Result<Option<WebPageTracker<WebPageResourcesTrackerTag>>> {
+        self.get_web_page_tracker_by_job_id(job_id).await
+    }
+
+    /// Returns web page content tracker by the corresponding job ID.
+    pub async fn get_content_tracker_by_job_id(
+        &self,
+        job_id: Uuid,
+    ) -> anyhow::Result<Option<WebPageTracker<WebPageContentTrackerTag>>> {
+        self.get_web_page_tracker_by_job_id(job_id).await
+    }
+
+    /// Returns web page screenshot tracker by the corresponding job ID.
+    pub async fn get_screenshot_tracker_by_job_id(
+        &self,
+        job_id: Uuid,
+    ) -> anyhow::Result<Option<WebPageTracker<WebPageScreenshotTrackerTag>>> {
+        self.get_web_page_tracker_by_job_id(job_id).await
+    }
+
+    /// Returns web page DNS records tracker by the corresponding job ID.
+    pub async fn get_dns_records_tracker_by_job_id(
+        &self,
+        job_id: Uuid,
+    ) -> anyhow::Result<Option<WebPageTracker<WebPageDnsRecordsTrackerTag>>> {
+        self.get_web_page_tracker_by_job_id(job_id).await
+    }
+
+    /// Update resources tracker job ID reference (link or unlink).
+    pub async fn update_web_page_tracker_job(
+        &self,
+        id: Uuid,
+        job_id: Option<Uuid>,
+    ) -> anyhow::Result<()> {
+        self.web_scraping_system
+            .update_web_page_tracker_job(id, job_id)
+            .await
+    }
+
+    /// Unlinks web page trackers from the jobs that no longer exist so that they can be scheduled
+    /// again. Returns IDs of the unlinked trackers.
+    pub async fn unlink_web_page_trackers_with_missing_jobs(&self) -> anyhow::Result<Vec<Uuid>> {
+        self.web_scraping_system
+            .unlink_web_page_trackers_with_missing_jobs()
+            .await
+    }
+
+    /// Returns IDs of the web page tracker trigger jobs that aren't linked to any tracker.
+    pub async fn get_unlinked_web_page_tracker_jobs(&self) -> anyhow::Result<Vec<Uuid>> {
+        self.web_scraping_system
+            .get_unlinked_web_page_tracker_jobs()
+            .await
+    }
+
+    /// Returns IDs of all web page tracker jobs that are pending processing, the most overdue first.
+    pub async fn get_overdue_tracker_jobs(&self) -> anyhow::Result<Vec<Uuid>> {
+        self.web_scraping_system
+            .get_overdue_web_page_tracker_jobs()
+            .await
+    }
+
+    /// Returns the time when the user was last notified about the web page tracker changes.
+    pub async fn get_web_page_tracker_last_notified_at(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<Option<OffsetDateTime>> {
+        self.web_scraping_system
+            .get_web_page_tracker_last_notified_at(tracker_id)
+            .await
+    }
+
+    /// Updates the time when the user was last notified about the web page tracker changes.
+    pub async fn set_web_page_tracker_last_notified_at(
+        &self,
+        tracker_id: Uuid,
+        last_notified_at: OffsetDateTime,
+    ) -> anyhow::Result<()> {
+        self.web_scraping_system
+            .set_web_page_tracker_last_notified_at(tracker_id, last_notified_at)
+            .await
+    }
+
+    /// Returns status page with the specified token along with the ID of the user it belongs to.
+    pub async fn get_status_page_by_token(
+        &self,
+        token: &str,
+    ) -> anyhow::Result<Option<(UserId, WebPageStatusPage)>> {
+        self.web_scraping_system
+            .get_web_page_status_page_by_token(token)
+            .await
+    }
+
+    /// Returns web page tracker scrape job submitted to the web scraper in the asynchronous mode
+    /// along with the ID of the user it belongs to.
+    pub async fn get_pending_scrape(
+        &self,
+        job_id: &str,
+    ) -> anyhow::Result<Option<(UserId, WebPageTrackerPendingScrape)>> {
+        self.web_scraping_system
+            .get_web_page_tracker_pending_scrape(job_id)
+            .await
+    }
+
+    /// Returns the webhook that web page tracker change notifications are delivered to.
+    pub async fn get_web_page_tracker_webhook(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<Option<WebPageTrackerWebhook>> {
+        self.web_scraping_system
+            .get_web_page_tracker_webhook(tracker_id)
+            .await
+    }
+
+    /// Removes revisions of all web page trackers that are older than the retention defined by the
+    /// tracker data policy. Returns the number of removed revisions.
+    pub async fn remove_expired_web_page_tracker_revisions(&self) -> anyhow::Result<u64> {
+        Ok(self
+            .remove_expired_revisions::<WebPageResourcesTrackerTag>()
+            .await?
+            + self
+                .remove_expired_revisions::<WebPageContentTrackerTag>()
+                .await?
+            + self
+                .remove_expired_revisions::<WebPageScreenshotTrackerTag>()
+                .await?
+            + self
+                .remove_expired_revisions::<WebPageDnsRecordsTrackerTag>()
+                .await?)
+    }
+
+    /// Removes revisions of the web page trackers of the specified kind that are older than the
+    /// retention defined by the tracker data policy.
+    async fn remove_expired_revisions<Tag: WebPageTrackerTag>(&self) -> anyhow::Result<u64> {
+        let now = OffsetDateTime::now_utc();
+        let trackers = self
+            .web_scraping_system
+            .get_web_page_trackers_with_history::<Tag>(MAX_JOBS_PAGE_SIZE);
+        pin_mut!(trackers);
+
+        let mut removed_revisions = 0;
+        while let Some(tracker) = trackers.next().await {
+            let tracker = tracker?;
+            let Some(retention) = tracker
+                .settings
+                .data_policy
+                .and_then(|data_policy| data_policy.retention)
+            else {
+                continue;
+            };
+
+            removed_revisions += self
+                .web_scraping_system
+                .remove_web_page_tracker_history_before(tracker.id, now - retention)
+                .await?;
+        }
+
+        Ok(removed_revisions)
+    }
+
+    /// Returns all scheduled web page trackers of the specified kind that have had no activity
+    /// since the specified time, along with the time of their last activity.
+    pub async fn get_idle_web_page_trackers<Tag: WebPageTrackerTag>(
+        &self,
+        idle_since: OffsetDateTime,
+    ) -> anyhow::Result<Vec<(WebPageTracker<Tag>, OffsetDateTime)>> {
+        self.web_scraping_system
+            .get_idle_web_page_trackers(idle_since)
+            .await
+    }
+
+    /// Disables web page tracker so that it's no longer scheduled.
+    pub async fn disable_web_page_tracker(&self, id: Uuid) -> anyhow::Result<()> {
+        self.web_scraping_system.disable_web_page_tracker(id).await
+    }
+
+    /// Returns all web page tracker job references that have jobs that need to be scheduled.
+    async fn get_unscheduled_web_page_trackers<Tag: WebPageTrackerTag>(
+        &self,
+    ) -> anyhow::Result<Vec<WebPageTracker<Tag>>> {
+        self.web_scraping_system
+            .get_unscheduled_web_page_trackers()
+            .await
+    }
+
+    /// Returns web page tracker by the corresponding job ID.
+    async fn get_web_page_tracker_by_job_id<Tag: WebPageTrackerTag>(
+        &self,
+        job_id: Uuid,
+    ) -> anyhow::Result<Option<WebPageTracker<Tag>>> {
+        self.web_scraping_system
+            .get_web_page_tracker_by_job_id(job_id)
+            .await
+    }
+}
+
+impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
+    /// Returns an API to work with web scraping data (as system user).
+    pub fn web_scraping_system(&self) -> WebScrapingSystemApiExt<'_> {
+        WebScrapingSystemApiExt::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::web_page_tracker_history_csv_row;
+    use crate::{
+        config::{WebScraperCircuitBreakerConfig, WebScraperConfig},
+        error::{Error as SecutilsError, ErrorCode},
+        js_runtime::JsScriptDiagnostic,
+        notifications::{
+            NotificationContent, NotificationContentTemplate, NotificationDestination,
+        },
+        scheduler::{
+            SchedulerJob, SchedulerJobAdaptiveInterval, SchedulerJobConfig,
+            SchedulerJobRetryStrategy,
+        },
+        security::{encryption, secrets::tests::MockSecretsProvider},
+        tests::{
+            mock_api, mock_api_with_config, mock_api_with_network, mock_config,
+            mock_network_with_records, mock_scheduler_job, mock_upsert_scheduler_job, mock_user,
+            mock_user_with_id, MockWebPageResourceBuilder, RawSchedulerJobStoredData,
+        },
+        users::{User, UserData, UserDataNamespace, UserDisplayOrderCollection},
+        utils::web_scraping::{
+            api_ext::{
+                WebPageContentSimulateDiffParams, WebPageContentTrackerCreateWellKnownParams,
+                WebPageContentTrackerGetHistoryParams, WebPageDnsRecordsTrackerGetHistoryParams,
+                WebPageResourcesTrackerGetHistoryParams, WebPageScreenshotTrackerGetHistoryParams,
+                WebPageStatusPageCreateParams, WebPageStatusPageUpdateParams,
+                WebPageTrackerImportParams, WebPageTrackerImportSource,
+                WebPageTrackerLintScriptParams, WebPageTrackerPreviewNotificationParams,
+                WebPageTrackerSetWebhookParams, WebPageTrackerTestScriptParams,
+                WebPageTrackerUpdateParams, WebPageTrackersDiffParams,
+                WebPageTrackersGetChangesFeedParams,
+            },
+            tests::{
+                mock_screenshot, MockWebPageTrackerBuilder, WebPageTrackerCreateParams,
+                WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME,
+                WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME,
+            },
+            WebPageContentAssertion, WebPageContentAssertionOperator, WebPageContentCookiesCapture,
+            WebPageContentDiffAlgorithm, WebPageContentFormat, WebPageContentResponse,
+            WebPageContentResponseCapture, WebPageContentResponsePart, WebPageContentStatus,
+            WebPageContentStatuses, WebPageContentTrackerTag, WebPageDataRevision,
+            WebPageDataRevisionDiffStats, WebPageDnsRecord, WebPageDnsRecordDiffStatus,
+            WebPageDnsRecordType, WebPageResource, WebPageResourceCategory,
+            WebPageResourceContentData, WebPageResourceDiffStatus, WebPageResourceType,
+            WebPageResourcesData, WebPageResourcesTrackerTag, WebPageScreenshotTrackerTag,
+            WebPageTracker, WebPageTrackerDataClassification, WebPageTrackerDataPolicy,
+            WebPageTrackerHistoryIntegrity, WebPageTrackerImportResult, WebPageTrackerKind,
+            WebPageTrackerPrecondition, WebPageTrackerPreconditionOperator,
+            WebPageTrackerScriptTestResult, WebPageTrackerSettings, WebPageTrackerWebhook,
+            WebPageWellKnownFile, WebScraperContentRequest, WebScraperContentResponse,
+            WebScraperErrorResponse, WebScraperResource, WebScraperResourcesRequest,
+            WebScraperResourcesResponse, WebScraperScreenshotRequest, WebScraperScreenshotResponse,
+        },
+    };
+    use actix_web::ResponseError;
+    use futures::StreamExt;
+    use httpmock::MockServer;
+    use insta::assert_debug_snapshot;
+    use sqlx::PgPool;
+    use std::{net::Ipv4Addr, sync::Arc, time::Duration};
+    use time::OffsetDateTime;
+    use trust_dns_resolver::{
+        proto::rr::{
+            rdata::{A, TXT},
+            RData, Record,
+        },
+        Name,
+    };
+    use url::Url;
+    use uuid::{uuid, Uuid};
+
+    fn get_resources(timestamp: i64, label: &str) -> anyhow::Result<WebScraperResourcesResponse> {
+        Ok(WebScraperResourcesResponse {
+            timestamp: OffsetDateTime::from_unix_timestamp(timestamp)?,
+            scripts: vec![WebScraperResource {
+                url: Some(Url::parse(&format!(
+                    "http://localhost:1234/script_{label}.js"
+                ))?),
+                content: None,
+            }],
+            styles: vec![WebScraperResource {
+                url: Some(Url::parse(&format!(
+                    "http://localhost:1234/style_{label}.css"
+                ))?),
+                content: None,
+            }],
+        })
+    }
+
+    fn get_content(timestamp: i64, label: &str) -> anyhow::Result<WebScraperContentResponse> {
+        Ok(WebScraperContentResponse {
+            timestamp: OffsetDateTime::from_unix_timestamp(timestamp)?,
+            content: label.to_string(),
+            cookies: None,
+        })
+    }
+
+    #[sqlx::test]
+    async fn properly_creates_new_web_page_resources_tracker(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraper = api.web_scraping(&mock_user);
+
+        let tracker = web_scraper
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("http://localhost:1234/my/app?q=2")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@hourly".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(120),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+
+        assert_eq!(
+            tracker,
+            web_scraper
+                .get_resources_tracker(tracker.id)
+                .await?
+                .unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_creates_new_web_page_content_tracker(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let api = api.web_scraping(&mock_user);
+
+        let tracker = api
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("http://localhost:1234/my/app?q=2")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@hourly".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(120),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+
+        assert_eq!(tracker, api.get_content_tracker(tracker.id).await?.unwrap());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn applies_operator_defaults_to_new_web_page_trackers(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let retry_strategy = SchedulerJobRetryStrategy::Constant {
+            interval: Duration::from_secs(120),
+            max_attempts: 3,
+            max_total_retry_duration: None,
+        };
+        let data_policy = WebPageTrackerDataPolicy {
+            classification: WebPageTrackerDataClassification::Internal,
+            retention: Some(Duration::from_secs(86400)),
+        };
+
+        let mut config = mock_config()?;
+        config.utils.web_scraping.defaults.schedule = Some("0 0 * * * *".to_string());
+        config.utils.web_scraping.defaults.retry_strategy = Some(retry_strategy);
+        config.utils.web_scraping.defaults.data_policy = Some(data_policy);
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let api = api.web_scraping(&mock_user);
+        let settings = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "name_one",
+            "https://secutils.dev",
+            3,
+        )?
+        .build()
+        .settings;
+
+        // Omitted job config and data policy are filled in with the defaults.
+        let tracker = api
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
+                settings: settings.clone(),
+                job_config: None,
+            })
+            .await?;
+        assert_eq!(
+            tracker.job_config,
+            Some(SchedulerJobConfig {
+                schedule: "0 0 * * * *".to_string(),
+                retry_strategy: Some(retry_strategy),
+                notifications: false,
+                adaptive_interval: None,
+            })
+        );
+        assert_eq!(tracker.settings.data_policy, Some(data_policy));
+        assert_eq!(tracker, api.get_content_tracker(tracker.id).await?.unwrap());
+
+        // User-provided values always win.
+        let user_data_policy = WebPageTrackerDataPolicy {
+            classification: WebPageTrackerDataClassification::Public,
+            retention: None,
+        };
+        let tracker = api
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_two".to_string(),
+                url: Url::parse("https://secutils.dev/two")?,
+                settings: WebPageTrackerSettings {
+                    data_policy: Some(user_data_policy),
+                    ..settings.clone()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+        assert_eq!(
+            tracker.job_config,
+            Some(SchedulerJobConfig {
+                schedule: "@daily".to_string(),
+                retry_strategy: Some(retry_strategy),
+                notifications: true,
+                adaptive_interval: None,
+            })
+        );
+        assert_eq!(tracker.settings.data_policy, Some(user_data_policy));
+
+        // Trackers that don't keep any revisions aren't scheduled by default.
+        let tracker = api
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_three".to_string(),
+                url: Url::parse("https://secutils.dev/three")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 0,
+                    ..settings
+                },
+                job_config: None,
+            })
+            .await?;
+        assert_eq!(tracker.job_config, None);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_validates_web_page_resources_tracker_at_creation(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool.clone()).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+
+        let settings = WebPageTrackerSettings {
+            revisions: 3,
+            delay: Duration::from_millis(2000),
+            ..Default::default()
+        };
+        let url = Url::parse("https://secutils.dev")?;
+
+        let create_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
+
+        // Empty name.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: None
+            }).await),
+            @r###""Web page tracker name cannot be empty.""###
+        );
+
+        // Very long name.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "a".repeat(101),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: None
+            }).await),
+            @r###""Web page tracker name cannot be longer than 100 characters.""###
+        );
+
+        // Too many revisions.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    revisions: 31,
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page tracker revisions count cannot be greater than 30.""###
+        );
+
+        // Too long delay.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    delay: Duration::from_secs(61),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page tracker delay cannot be greater than 60000ms.""###
+        );
+
+        // Empty resource filter.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    scripts: Some([(
+                        WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME.to_string(),
+                            "".to_string()
+                        )]
+                        .into_iter()
+                        .collect()
+                    ),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page tracker scripts cannot be empty or have an empty name.""###
+        );
+
+        // Empty resource filter.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    scripts: Some([(
+                        "someScript".to_string(),
+                            "return resource;".to_string()
+                        )]
+                        .into_iter()
+                        .collect()
+                    ),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page tracker contains unrecognized scripts.""###
+        );
+
+        // Script with a syntax error.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    scripts: Some([(
+                        WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME.to_string(),
+                            "const url = resource.url;\nreturn url +;".to_string()
+                        )]
+                        .into_iter()
+                        .collect()
+                    ),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page tracker script `resourceFilterMap` is invalid: Uncaught SyntaxError: Unexpected token ';' (line 2, column 13)""###
+        );
+
+        // Script without return.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    scripts: Some([(
+                        WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME.to_string(),
+                            "const url = resource.url;".to_string()
+                        )]
+                        .into_iter()
+                        .collect()
+                    ),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page tracker script `resourceFilterMap` is invalid: Script doesn't return any value.""###
+        );
+
+        // Invalid ignored resource URL.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    ignore_resource_urls: Some(vec!["(".to_string()]),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###"
+        Error {
+            context: "Web page tracker ignored resource URLs must be valid regular expressions.",
+            source: "Failed to parse ignored resource URL `(`: regex parse error:\n    (\n    ^\nerror: unclosed group",
+        }
+        "###
+        );
+
+        // Invalid schedule.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "-".to_string(),
+                    retry_strategy: None,
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###"
+        Error {
+            context: "Web page tracker schedule must be a valid cron expression.",
+            source: "Failed to parse schedule `-`: Error { kind: Expression(\"Invalid cron expression.\") }",
+        }
+        "###
+        );
+
+        // Invalid schedule interval.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0/5 * * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker schedule must have at least 10s between occurrences, but detected 5s.""###
+        );
+
+        // Too few retry attempts.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(120),
+                        max_attempts: 0,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 0.""###
+        );
+
+        // Too many retry attempts.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(120),
+                        max_attempts: 11,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 11.""###
+        );
+
+        // Too low retry interval.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(30),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker min retry interval cannot be less than 1m, but received 30s.""###
+        );
+
+        // Too low max total retry duration.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(120),
+                        max_attempts: 5,
+                        max_total_retry_duration: Some(Duration::from_secs(60)),
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker max total retry duration cannot be less than 2m or greater than 1day, but received 1m.""###
+        );
+
+        // Too high max total retry duration.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@hourly".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(120),
+                        max_attempts: 5,
+                        max_total_retry_duration: Some(Duration::from_secs(7200)),
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker max total retry duration cannot be less than 2m or greater than 1h, but received 2h.""###
+        );
+
+        // Too low data retention.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    data_policy: Some(WebPageTrackerDataPolicy {
+                        classification: WebPageTrackerDataClassification::Personal,
+                        retention: Some(Duration::from_secs(60)),
+                    }),
+                    ..settings.clone()
+                },
+                job_config: None,
+            }).await),
+            @r###""Web page tracker data retention cannot be less than 1h, but received 1m.""###
+        );
+
+        // Too low max retry interval.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
+                        initial_interval: Duration::from_secs(120),
+                        increment: Duration::from_secs(10),
+                        max_interval: Duration::from_secs(30),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker retry strategy max interval cannot be less than 1m, but received 30s.""###
+        );
+
+        // Too high max retry interval.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@monthly".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
+                        initial_interval: Duration::from_secs(120),
+                        increment: Duration::from_secs(10),
+                        max_interval: Duration::from_secs(13 * 3600),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker retry strategy max interval cannot be greater than 12h, but received 13h.""###
+        );
+
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@hourly".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
+                        initial_interval: Duration::from_secs(120),
+                        increment: Duration::from_secs(10),
+                        max_interval: Duration::from_secs(2 * 3600),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker retry strategy max interval cannot be greater than 1h, but received 2h.""###
+        );
+
+        // Adaptive min interval is less than schedule interval.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@hourly".to_string(),
+                    retry_strategy: None,
+                    notifications: false,
+                    adaptive_interval: Some(SchedulerJobAdaptiveInterval {
+                        min_interval: Duration::from_secs(600),
+                        max_interval: Duration::from_secs(24 * 3600),
+                    }),
+                }),
+            }).await),
+            @r###""Web page tracker adaptive min interval cannot be less than 1h, but received 10m.""###
+        );
+
+        // Adaptive max interval is less than min interval.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@hourly".to_string(),
+                    retry_strategy: None,
+                    notifications: false,
+                    adaptive_interval: Some(SchedulerJobAdaptiveInterval {
+                        min_interval: Duration::from_secs(2 * 3600),
+                        max_interval: Duration::from_secs(3600),
+                    }),
+                }),
+            }).await),
+            @r###""Web page tracker adaptive max interval cannot be less than 2h, but received 1h.""###
+        );
+
+        // Empty resource types.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    resource_types: Some(vec![]),
+                    record_types: None,
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page resources tracker should track at least one resource type.""###
+        );
+
+        // Invalid URL schema.
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: Url::parse("ftp://secutils.dev")?,
+                settings: settings.clone(),
+                job_config: None
+            }).await),
+            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received ftp://secutils.dev/.""###
+        );
+
+        let api_with_local_network = mock_api_with_network(
+            pool,
+            mock_network_with_records::<1>(vec![Record::from_rdata(
+                Name::new(),
+                300,
+                RData::A(A(Ipv4Addr::new(127, 0, 0, 1))),
+            )]),
+        )
+        .await?;
+
+        // Non-public URL.
+        assert_debug_snapshot!(
+            create_and_fail(api_with_local_network.web_scraping(&mock_user).create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: Url::parse("https://127.0.0.1")?,
+                settings: settings.clone(),
+                job_config: None
+            }).await),
+            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received https://127.0.0.1/.""###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_validates_web_page_content_tracker_at_creation(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool.clone()).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let api = api.web_scraping(&mock_user);
+
+        let settings = WebPageTrackerSettings {
+            revisions: 3,
+            delay: Duration::from_millis(2000),
+            ..Default::default()
+        };
+        let url = Url::parse("https://secutils.dev")?;
+
+        let create_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
+
+        // Empty name.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: None
+            }).await),
+            @r###""Web page tracker name cannot be empty.""###
+        );
+
+        // Very long name.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "a".repeat(101),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: None
+            }).await),
+            @r###""Web page tracker name cannot be longer than 100 characters.""###
+        );
+
+        // Too many revisions.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    revisions: 31,
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page tracker revisions count cannot be greater than 30.""###
+        );
+
+        // Too long delay.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    delay: Duration::from_secs(61),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page tracker delay cannot be greater than 60000ms.""###
+        );
+
+        // Zero sub-fetch concurrency.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    sub_fetch_concurrency: Some(0),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page tracker sub-fetch concurrency must be greater than 0.""###
+        );
+
+        // Too high sub-fetch concurrency.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    sub_fetch_concurrency: Some(11),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page tracker sub-fetch concurrency cannot be greater than 10.""###
+        );
+
+        // Empty resource filter.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    scripts: Some([(
+                        WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME.to_string(),
+                            "".to_string()
+                        )]
+                        .into_iter()
+                        .collect()
+                    ),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page tracker scripts cannot be empty or have an empty name.""###
+        );
+
+        // Empty resource filter.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    scripts: Some([(
+                        "someScript".to_string(),
+                            "return resource;".to_string()
+                        )]
+                        .into_iter()
+                        .collect()
+                    ),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page tracker contains unrecognized scripts.""###
+        );
+
+        // Ignored resource URLs aren't supported.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    ignore_resource_urls: Some(vec!["ads".to_string()]),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker doesn't support ignored resource URLs.""###
+        );
+
+        // Scripts aren't supported in status-only mode.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    status_only: true,
+                    scripts: Some([(
+                        WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME.to_string(),
+                            "return document.title;".to_string()
+                        )]
+                        .into_iter()
+                        .collect()
+                    ),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker doesn't support scripts in status-only mode.""###
+        );
+
+        // Byte range must be valid.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    byte_range: Some((10, 5)),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker byte range start cannot be greater than its end, but received 10-5.""###
+        );
+
+        // Byte range isn't supported in status-only mode.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    status_only: true,
+                    byte_range: Some((0, 4095)),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker doesn't support byte range in status-only mode.""###
+        );
+
+        // Byte range cannot be combined with the `Range` header.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    headers: Some([("Range".to_string(), "bytes=0-10".to_string())]
+                        .into_iter()
+                        .collect()),
+                    byte_range: Some((0, 4095)),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker cannot have both byte range and `Range` header.""###
+        );
+
+        // Resources classification isn't supported.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    classify_resources: true,
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker doesn't support resources classification.""###
+        );
+
+        // Response capture isn't supported in status-only mode.
+        let capture_response = Some(WebPageContentResponseCapture {
+            headers: vec!["ETag".to_string()],
+        });
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    status_only: true,
+                    capture_response: capture_response.clone(),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker doesn't support response capture in status-only mode.""###
+        );
+
+        // Fingerprint capture isn't supported in status-only mode.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    status_only: true,
+                    capture_fingerprint: true,
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker doesn't support fingerprint capture in status-only mode.""###
+        );
+
+        // Additional URLs are supported only in status-only mode.
+        let additional_urls = Some(vec![Url::parse("https://secutils.dev/api")?]);
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    additional_urls: additional_urls.clone(),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker supports additional URLs only in status-only mode.""###
+        );
+
+        // The same URL cannot be monitored more than once.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    status_only: true,
+                    additional_urls: Some(vec![url.clone()]),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker cannot monitor the same URL more than once, but received https://secutils.dev/.""###
+        );
+
+        // Number of monitored URLs is limited, including the main one.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    status_only: true,
+                    additional_urls: Some(
+                        (0..10)
+                            .map(|index| Url::parse(&format!("https://secutils.dev/{index}")))
+                            .collect::<Result<_, _>>()?
+                    ),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker cannot monitor more than 10 URLs.""###
+        );
+
+        // Structured content format cannot be combined with status-only mode.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    status_only: true,
+                    content_format: Some(WebPageContentFormat::RobotsTxt),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker doesn't support content format together with status-only mode, or response, fingerprint, or cookies capture.""###
+        );
+
+        // Content assertions list cannot be empty.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    assertions: Some(vec![]),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker should have at least 1 and at most 10 content assertions, but received 0.""###
+        );
+
+        // Content assertion value cannot be too long.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    assertions: Some(vec![WebPageContentAssertion {
+                        operator: WebPageContentAssertionOperator::Contains,
+                        value: "a".repeat(1001),
+                    }]),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker assertion value cannot be empty or longer than 1000 characters.""###
+        );
+
+        // Content assertion value should be a valid regular expression for regex operators.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    assertions: Some(vec![WebPageContentAssertion {
+                        operator: WebPageContentAssertionOperator::Matches,
+                        value: "(error".to_string(),
+                    }]),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###"
+        Error {
+            context: "Web page content tracker assertion value must be a valid regular expression.",
+            source: "Failed to parse assertion value `(error`: regex parse error:\n    (error\n    ^\nerror: unclosed group",
+        }
+        "###
+        );
+
+        // HTTP method cannot be too long.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    method: Some("A".repeat(21)),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker HTTP method cannot be empty or longer than 20 characters.""###
+        );
+
+        // HTTP method should be valid.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    method: Some("BAD METHOD".to_string()),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###"
+        Error {
+            context: "Web page content tracker HTTP method must be a valid HTTP method.",
+            source: "Failed to parse HTTP method `BAD METHOD`: invalid HTTP method",
+        }
+        "###
+        );
+
+        // Request body cannot be too large.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    method: Some("POST".to_string()),
+                    request_body: Some("a".repeat(64 * 1024 + 1)),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker request body cannot be empty or larger than 65536 bytes.""###
+        );
+
+        // Request body cannot be sent with GET method.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    request_body: Some("query=status".to_string()),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker cannot send request body with GET HTTP method.""###
+        );
+
+        // Fingerprint capture cannot be combined with response capture.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    capture_fingerprint: true,
+                    capture_response: capture_response.clone(),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker doesn't support fingerprint capture together with response or cookies capture.""###
+        );
+
+        // Response capture cannot be combined with cookies capture.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    capture_cookies: Some(WebPageContentCookiesCapture::Full),
+                    capture_response: capture_response.clone(),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker doesn't support both response and cookies capture.""###
+        );
+
+        // Scripts aren't supported in response capture mode.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    scripts: Some([(
+                        WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME.to_string(),
+                            "return document.title;".to_string()
+                        )]
+                        .into_iter()
+                        .collect()
+                    ),
+                    capture_response: capture_response.clone(),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker doesn't support scripts in response capture mode.""###
+        );
+
+        // Captured response headers must be valid.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    capture_response: Some(WebPageContentResponseCapture {
+                        headers: vec!["Invalid Header".to_string()],
+                    }),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker response capture header name `Invalid Header` is not valid.""###
+        );
+
+        // Too many captured response headers.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    capture_response: Some(WebPageContentResponseCapture {
+                        headers: (0..101).map(|index| format!("x-header-{index}")).collect(),
+                    }),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker cannot capture more than 100 response headers.""###
+        );
+
+        // Revisions dedup window must be within revisions limit.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    dedup_window: Some(4),
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Web page content tracker revisions dedup window should be between 1 and 3, but received 4.""###
+        );
+
+        // Insecure TLS can only be enabled by operators.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: WebPageTrackerSettings {
+                    insecure_tls: true,
+                    ..settings.clone()
+                },
+                job_config: None
+            }).await),
+            @r###""Only operators can disable TLS certificate verification for web page trackers.""###
+        );
+
+        // Invalid schedule.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "-".to_string(),
+                    retry_strategy: None,
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###"
+        Error {
+            context: "Web page tracker schedule must be a valid cron expression.",
+            source: "Failed to parse schedule `-`: Error { kind: Expression(\"Invalid cron expression.\") }",
+        }
+        "###
+        );
+
+        // Invalid schedule interval.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0/5 * * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker schedule must have at least 10s between occurrences, but detected 5s.""###
+        );
+
+        // Too few retry attempts.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(120),
+                        max_attempts: 0,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 0.""###
+        );
+
+        // Too many retry attempts.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(120),
+                        max_attempts: 11,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 11.""###
+        );
+
+        // Too low retry interval.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(30),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker min retry interval cannot be less than 1m, but received 30s.""###
+        );
+
+        // Too low max retry interval.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
+                        initial_interval: Duration::from_secs(120),
+                        increment: Duration::from_secs(10),
+                        max_interval: Duration::from_secs(30),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker retry strategy max interval cannot be less than 1m, but received 30s.""###
+        );
+
+        // Too high max retry interval.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@monthly".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
+                        initial_interval: Duration::from_secs(120),
+                        increment: Duration::from_secs(10),
+                        max_interval: Duration::from_secs(13 * 3600),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker retry strategy max interval cannot be greater than 12h, but received 13h.""###
+        );
+
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: url.clone(),
+                settings: settings.clone(),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@hourly".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
+                        initial_interval: Duration::from_secs(120),
+                        increment: Duration::from_secs(10),
+                        max_interval: Duration::from_secs(2 * 3600),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            }).await),
+            @r###""Web page tracker retry strategy max interval cannot be greater than 1h, but received 2h.""###
+        );
+
+        // Invalid URL schema.
+        assert_debug_snapshot!(
+            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: Url::parse("ftp://secutils.dev")?,
+                settings: settings.clone(),
+                job_config: None
+            }).await),
+            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received ftp://secutils.dev/.""###
+        );
+
+        let api_with_local_network = mock_api_with_network(
+            pool,
+            mock_network_with_records::<1>(vec![Record::from_rdata(
+                Name::new(),
+                300,
+                RData::A(A(Ipv4Addr::new(127, 0, 0, 1))),
+            )]),
+        )
+        .await?;
+
+        // Non-public URL.
+        assert_debug_snapshot!(
+            create_and_fail(api_with_local_network.web_scraping(&mock_user).create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: Url::parse("https://127.0.0.1")?,
+                settings: settings.clone(),
+                job_config: None
+            }).await),
+            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received https://127.0.0.1/.""###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_updates_web_page_resources_tracker(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("http://localhost:1234/my/app?q=2")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: None,
+            })
+            .await?;
+
+        // Update name.
+        let updated_tracker = web_scraping
+            .update_resources_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    name: Some("name_two".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let expected_tracker = WebPageTracker {
+            name: "name_two".to_string(),
+            updated_at: updated_tracker.updated_at,
+            ..tracker.clone()
+        };
+        assert_eq!(expected_tracker, updated_tracker);
+        assert_eq!(
+            expected_tracker,
+            web_scraping
+                .get_resources_tracker(tracker.id)
+                .await?
+                .unwrap()
+        );
+
+        // Update URL.
+        let updated_tracker = web_scraping
+            .update_resources_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    url: Some("http://localhost:1234/my/app?q=3".parse()?),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let expected_tracker = WebPageTracker {
+            name: "name_two".to_string(),
+            url: "http://localhost:1234/my/app?q=3".parse()?,
+            updated_at: updated_tracker.updated_at,
+            ..tracker.clone()
+        };
+        assert_eq!(expected_tracker, updated_tracker);
+        assert_eq!(
+            expected_tracker,
+            web_scraping
+                .get_resources_tracker(tracker.id)
+                .await?
+                .unwrap()
+        );
+
+        // Update settings.
+        let updated_tracker = web_scraping
+            .update_resources_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    settings: Some(WebPageTrackerSettings {
+                        revisions: 4,
+                        ..tracker.settings.clone()
+                    }),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let expected_tracker = WebPageTracker {
+            name: "name_two".to_string(),
+            url: "http://localhost:1234/my/app?q=3".parse()?,
+            settings: WebPageTrackerSettings {
+                revisions: 4,
+                ..tracker.settings.clone()
+            },
+            updated_at: updated_tracker.updated_at,
+            ..tracker.clone()
+        };
+        assert_eq!(expected_tracker, updated_tracker);
+        assert_eq!(
+            expected_tracker,
+            web_scraping
+                .get_resources_tracker(tracker.id)
+                .await?
+                .unwrap()
+        );
+
+        // Update job config.
+        let updated_tracker = web_scraping
+            .update_resources_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    job_config: Some(Some(SchedulerJobConfig {
+                        schedule: "@hourly".to_string(),
+                        retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                            interval: Duration::from_secs(120),
+                            max_attempts: 5,
+                            max_total_retry_duration: None,
+                        }),
+                        notifications: false,
+                        adaptive_interval: None,
+                    })),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let expected_tracker = WebPageTracker {
+            name: "name_two".to_string(),
+            url: "http://localhost:1234/my/app?q=3".parse()?,
+            settings: WebPageTrackerSettings {
+                revisions: 4,
+                ..tracker.settings.clone()
+            },
+            job_config: Some(SchedulerJobConfig {
+                schedule: "@hourly".to_string(),
+                retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                    interval: Duration::from_secs(120),
+                    max_attempts: 5,
+                    max_total_retry_duration: None,
+                }),
+                notifications: false,
+                adaptive_interval: None,
+            }),
+            updated_at: updated_tracker.updated_at,
+            ..tracker.clone()
+        };
+        assert_eq!(expected_tracker, updated_tracker);
+        assert_eq!(
+            expected_tracker,
+            web_scraping
+                .get_resources_tracker(tracker.id)
+                .await?
+                .unwrap()
+        );
+
+        // Remove job config.
+        let updated_tracker = web_scraping
+            .update_resources_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    job_config: Some(None),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let expected_tracker = WebPageTracker {
+            name: "name_two".to_string(),
+            url: "http://localhost:1234/my/app?q=3".parse()?,
+            settings: WebPageTrackerSettings {
+                revisions: 4,
+                ..tracker.settings.clone()
+            },
+            job_config: None,
+            updated_at: updated_tracker.updated_at,
+            ..tracker.clone()
+        };
+        assert_eq!(expected_tracker, updated_tracker);
+        assert_eq!(
+            expected_tracker,
+            web_scraping
+                .get_resources_tracker(tracker.id)
+                .await?
+                .unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_validates_web_page_resources_tracker_at_update(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool.clone()).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let api = api.web_scraping(&mock_user);
+        let tracker = api
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@hourly".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(120),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+
+        let update_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
+
+        // Empty parameters.
+        let update_result = update_and_fail(
+            api.update_resources_tracker(tracker.id, Default::default())
+                .await,
+        );
+        assert_eq!(
+            update_result.to_string(),
+            format!(
+                "Either new name, url, settings, or job config should be provided ({}).",
+                tracker.id
+            )
+        );
+
+        // Non-existent tracker.
+        let update_result = update_and_fail(
+            api.update_resources_tracker(
+                uuid!("00000000-0000-0000-0000-000000000002"),
+                WebPageTrackerUpdateParams {
+                    name: Some("name".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await,
+        );
+        assert_eq!(
+            update_result.to_string(),
+            "Web page tracker ('00000000-0000-0000-0000-000000000002') is not found."
+        );
+
+        // Empty name.
+        assert_debug_snapshot!(
+            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                name: Some("".to_string()),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker name cannot be empty.""###
+        );
+
+        // Very long name.
+        assert_debug_snapshot!(
+            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                name: Some("a".repeat(101)),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker name cannot be longer than 100 characters.""###
+        );
+
+        // Too many revisions.
+        assert_debug_snapshot!(
+            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                settings: Some(WebPageTrackerSettings {
+                    revisions: 31,
+                    ..tracker.settings.clone()
+                }),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker revisions count cannot be greater than 30.""###
+        );
+
+        // Too long delay.
+        assert_debug_snapshot!(
+            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                settings: Some(WebPageTrackerSettings {
+                    delay: Duration::from_secs(61),
+                    ..tracker.settings.clone()
+                }),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker delay cannot be greater than 60000ms.""###
+        );
+
+        // Empty resource filter.
+        assert_debug_snapshot!(
+            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                settings: Some(WebPageTrackerSettings {
+                    scripts: Some([(
+                        WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME.to_string(),
+                        "".to_string()
+                    )]
+                    .into_iter()
+                    .collect()),
+                   ..tracker.settings.clone()
+                }),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker scripts cannot be empty or have an empty name.""###
+        );
+
+        // Unknown script.
+        assert_debug_snapshot!(
+            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                settings: Some(WebPageTrackerSettings {
+                    scripts: Some([(
+                        "someScript".to_string(),
+                        "return resource;".to_string()
+                    )]
+                    .into_iter()
+                    .collect()),
+                   ..tracker.settings.clone()
+                }),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker contains unrecognized scripts.""###
+        );
+
+        // Invalid schedule.
+        assert_debug_snapshot!(
+            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "-".to_string(),
+                    retry_strategy: None,
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+                ..Default::default()
+            }).await),
+            @r###"
+        Error {
+            context: "Web page tracker schedule must be a valid cron expression.",
+            source: "Failed to parse schedule `-`: Error { kind: Expression(\"Invalid cron expression.\") }",
+        }
+        "###
+        );
+
+        // Invalid schedule interval.
+        assert_debug_snapshot!(
+            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "0/5 * * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker schedule must have at least 10s between occurrences, but detected 5s.""###
+        );
+
+        // Too few retry attempts.
+        assert_debug_snapshot!(
+            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(120),
+                        max_attempts: 0,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 0.""###
+        );
+
+        // Too many retry attempts.
+        assert_debug_snapshot!(
+            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(120),
+                        max_attempts: 11,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 11.""###
+        );
+
+        // Too low retry interval.
+        assert_debug_snapshot!(
+           update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(30),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker min retry interval cannot be less than 1m, but received 30s.""###
+        );
+
+        // Too low max retry interval.
+        assert_debug_snapshot!(
+            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
+                        initial_interval: Duration::from_secs(120),
+                        increment: Duration::from_secs(10),
+                        max_interval: Duration::from_secs(30),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker retry strategy max interval cannot be less than 1m, but received 30s.""###
+        );
+
+        // Too high max retry interval.
+        assert_debug_snapshot!(
+            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "@monthly".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
+                        initial_interval: Duration::from_secs(120),
+                        increment: Duration::from_secs(10),
+                        max_interval: Duration::from_secs(13 * 3600),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker retry strategy max interval cannot be greater than 12h, but received 13h.""###
+        );
+
+        assert_debug_snapshot!(
+            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "@hourly".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
+                        initial_interval: Duration::from_secs(120),
+                        increment: Duration::from_secs(10),
+                        max_interval: Duration::from_secs(2 * 3600),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+               ..Default::default()
+            }).await),
+            @r###""Web page tracker retry strategy max interval cannot be greater than 1h, but received 2h.""###
+        );
+
+        // Invalid URL schema.
+        assert_debug_snapshot!(
+            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                url: Some(Url::parse("ftp://secutils.dev")?),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received ftp://secutils.dev/.""###
+        );
+
+        let api_with_local_network = mock_api_with_network(
+            pool,
+            mock_network_with_records::<1>(vec![Record::from_rdata(
+                Name::new(),
+                300,
+                RData::A(A(Ipv4Addr::new(127, 0, 0, 1))),
+            )]),
+        )
+        .await?;
+
+        // Non-public URL.
+        let web_scraping = api_with_local_network.web_scraping(&mock_user);
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
+                url: Some(Url::parse("https://127.0.0.1")?),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received https://127.0.0.1/.""###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_updates_web_page_content_tracker(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let api = api.web_scraping(&mock_user);
+        let tracker = api
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("http://localhost:1234/my/app?q=2")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: None,
+            })
+            .await?;
+
+        // Update name.
+        let updated_tracker = api
+            .update_content_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    name: Some("name_two".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let expected_tracker = WebPageTracker {
+            name: "name_two".to_string(),
+            updated_at: updated_tracker.updated_at,
+            ..tracker.clone()
+        };
+        assert_eq!(expected_tracker, updated_tracker);
+        assert_eq!(
+            expected_tracker,
+            api.get_content_tracker(tracker.id).await?.unwrap()
+        );
+
+        // Update URL.
+        let updated_tracker = api
+            .update_content_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    url: Some("http://localhost:1234/my/app?q=3".parse()?),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let expected_tracker = WebPageTracker {
+            name: "name_two".to_string(),
+            url: "http://localhost:1234/my/app?q=3".parse()?,
+            updated_at: updated_tracker.updated_at,
+            ..tracker.clone()
+        };
+        assert_eq!(expected_tracker, updated_tracker);
+        assert_eq!(
+            expected_tracker,
+            api.get_content_tracker(tracker.id).await?.unwrap()
+        );
+
+        // Update settings.
+        let updated_tracker = api
+            .update_content_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    settings: Some(WebPageTrackerSettings {
+                        revisions: 4,
+                        ..tracker.settings.clone()
+                    }),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let expected_tracker = WebPageTracker {
+            name: "name_two".to_string(),
+            url: "http://localhost:1234/my/app?q=3".parse()?,
+            settings: WebPageTrackerSettings {
+                revisions: 4,
+                ..tracker.settings.clone()
+            },
+            updated_at: updated_tracker.updated_at,
+            ..tracker.clone()
+        };
+        assert_eq!(expected_tracker, updated_tracker);
+        assert_eq!(
+            expected_tracker,
+            api.get_content_tracker(tracker.id).await?.unwrap()
+        );
+
+        // Update job config.
+        let updated_tracker = api
+            .update_content_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    job_config: Some(Some(SchedulerJobConfig {
+                        schedule: "@hourly".to_string(),
+                        retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                            interval: Duration::from_secs(120),
+                            max_attempts: 5,
+                            max_total_retry_duration: None,
+                        }),
+                        notifications: false,
+                        adaptive_interval: None,
+                    })),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let expected_tracker = WebPageTracker {
+            name: "name_two".to_string(),
+            url: "http://localhost:1234/my/app?q=3".parse()?,
+            settings: WebPageTrackerSettings {
+                revisions: 4,
+                ..tracker.settings.clone()
+            },
+            job_config: Some(SchedulerJobConfig {
+                schedule: "@hourly".to_string(),
+                retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                    interval: Duration::from_secs(120),
+                    max_attempts: 5,
+                    max_total_retry_duration: None,
+                }),
+                notifications: false,
+                adaptive_interval: None,
+            }),
+            updated_at: updated_tracker.updated_at,
+            ..tracker.clone()
+        };
+        assert_eq!(expected_tracker, updated_tracker);
+        assert_eq!(
+            expected_tracker,
+            api.get_content_tracker(tracker.id).await?.unwrap()
+        );
+
+        // Remove job config.
+        let updated_tracker = api
+            .update_content_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    job_config: Some(None),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let expected_tracker = WebPageTracker {
+            name: "name_two".to_string(),
+            url: "http://localhost:1234/my/app?q=3".parse()?,
+            settings: WebPageTrackerSettings {
+                revisions: 4,
+                ..tracker.settings.clone()
+            },
+            job_config: None,
+            updated_at: updated_tracker.updated_at,
+            ..tracker.clone()
+        };
+        assert_eq!(expected_tracker, updated_tracker);
+        assert_eq!(
+            expected_tracker,
+            api.get_content_tracker(tracker.id).await?.unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn returns_error_codes_for_known_failures(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let api = api.web_scraping(&mock_user);
+
+        let create_params =
+            |settings: WebPageTrackerSettings, schedule: Option<&str>| WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: Url::parse("https://secutils.dev").unwrap(),
+                settings,
+                job_config: schedule.map(|schedule| SchedulerJobConfig {
+                    schedule: schedule.to_string(),
+                    retry_strategy: None,
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            };
+        let settings = WebPageTrackerSettings {
+            revisions: 3,
+            delay: Duration::from_millis(2000),
+            ..Default::default()
+        };
+        fn error_code<T: std::fmt::Debug>(result: anyhow::Result<T>) -> ErrorCode {
+            result
+                .unwrap_err()
+                .downcast::<SecutilsError>()
+                .unwrap()
+                .code()
+        }
+
+        assert_eq!(
+            error_code(
+                api.create_content_tracker(create_params(
+                    WebPageTrackerSettings {
+                        revisions: 31,
+                        ..settings.clone()
+                    },
+                    None
+                ))
+                .await
+            ),
+            ErrorCode::LimitExceeded
+        );
+        assert_eq!(
+            error_code(
+                api.create_content_tracker(create_params(settings.clone(), Some("-")))
+                    .await
+            ),
+            ErrorCode::InvalidSchedule
+        );
+        assert_eq!(
+            error_code(
+                api.create_content_tracker(create_params(
+                    WebPageTrackerSettings {
+                        insecure_tls: true,
+                        ..settings.clone()
+                    },
+                    None
+                ))
+                .await
+            ),
+            ErrorCode::NotAllowed
+        );
+        assert_eq!(
+            error_code(
+                api.create_content_tracker(create_params(
+                    WebPageTrackerSettings {
+                        ignore_resource_urls: Some(vec!["ads".to_string()]),
+                        ..settings.clone()
+                    },
+                    None
+                ))
+                .await
+            ),
+            ErrorCode::InvalidInput
+        );
+
+        let tracker_id = uuid!("00000000-0000-0000-0000-000000000001");
+        assert_eq!(
+            error_code(
+                api.update_content_tracker(
+                    tracker_id,
+                    WebPageTrackerUpdateParams {
+                        name: Some("name".to_string()),
+                        ..Default::default()
+                    }
+                )
+                .await
+            ),
+            ErrorCode::TrackerNotFound
+        );
+        assert_eq!(
+            error_code(api.create_content_tracker_revision(tracker_id).await),
+            ErrorCode::TrackerNotFound
+        );
+        assert_eq!(
+            error_code(
+                api.get_content_tracker_revision(tracker_id, Uuid::now_v7())
+                    .await
+            ),
+            ErrorCode::TrackerRevisionNotFound
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_validates_web_page_tracker_dependencies(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let settings = WebPageTrackerSettings {
+            revisions: 3,
+            delay: Duration::from_millis(2000),
+            ..Default::default()
+        };
+        let create_params = |name: &str, depends_on: Option<Uuid>| -> anyhow::Result<_> {
+            Ok(WebPageTrackerCreateParams {
+                name: name.to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    depends_on,
+                    ..settings.clone()
+                },
+                job_config: None,
+            })
+        };
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker_a = web_scraping
+            .create_content_tracker(create_params("a", None)?)
+            .await?;
+        let tracker_b = web_scraping
+            .create_content_tracker(create_params("b", Some(tracker_a.id))?)
+            .await?;
+        let tracker_c = web_scraping
+            .create_content_tracker(create_params("c", Some(tracker_b.id))?)
+            .await?;
+        assert_eq!(tracker_c.settings.depends_on, Some(tracker_b.id));
+
+        let fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
+
+        // Unknown dependency.
+        let unknown_id = uuid!("00000000-0000-0000-0000-000000000001");
+        assert_eq!(
+            fail(
+                web_scraping
+                    .create_content_tracker(create_params("d", Some(unknown_id))?)
+                    .await
+            )
+            .to_string(),
+            "Web page tracker dependency ('00000000-0000-0000-0000-000000000001') is not found."
+        );
+
+        // Dependency of a different kind.
+        let resources_tracker = web_scraping
+            .create_resources_tracker(create_params("resources", None)?)
+            .await?;
+        assert_eq!(
+            fail(
+                web_scraping
+                    .create_content_tracker(create_params("d", Some(resources_tracker.id))?)
+                    .await
+            )
+            .to_string(),
+            format!(
+                "Web page tracker dependency ('{}') is not found.",
+                resources_tracker.id
+            )
+        );
+
+        // Self-dependency.
+        let update_with_dependency = |depends_on: Uuid| WebPageTrackerUpdateParams {
+            settings: Some(WebPageTrackerSettings {
+                depends_on: Some(depends_on),
+                ..settings.clone()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            fail(
+                web_scraping
+                    .update_content_tracker(tracker_a.id, update_with_dependency(tracker_a.id))
+                    .await
+            )
+            .to_string(),
+            "Web page tracker dependencies cannot form a cycle."
+        );
+
+        // Indirect cycle.
+        assert_eq!(
+            fail(
+                web_scraping
+                    .update_content_tracker(tracker_a.id, update_with_dependency(tracker_c.id))
+                    .await
+            )
+            .to_string(),
+            "Web page tracker dependencies cannot form a cycle."
+        );
+
+        // Dependency can be changed as long as there is no cycle.
+        let tracker_c = web_scraping
+            .update_content_tracker(tracker_c.id, update_with_dependency(tracker_a.id))
+            .await?;
+        assert_eq!(tracker_c.settings.depends_on, Some(tracker_a.id));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_validates_web_page_tracker_precondition(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let settings = WebPageTrackerSettings {
+            revisions: 3,
+            delay: Duration::from_millis(2000),
+            ..Default::default()
+        };
+        let precondition = |tracker_id: Uuid| WebPageTrackerPrecondition {
+            tracker_id,
+            pointer: Some("/status".to_string()),
+            operator: WebPageTrackerPreconditionOperator::Equals,
+            value: "updated".to_string(),
+        };
+        let create_params = |name: &str, precondition: Option<WebPageTrackerPrecondition>| {
+            WebPageTrackerCreateParams {
+                name: name.to_string(),
+                url: Url::parse("https://secutils.dev").unwrap(),
+                settings: WebPageTrackerSettings {
+                    precondition,
+                    ..settings.clone()
+                },
+                job_config: None,
+            }
+        };
+        let fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let status_tracker = web_scraping
+            .create_content_tracker(create_params("status", None))
+            .await?;
+
+        // Precondition can reference content tracker from trackers of any kind.
+        let tracker = web_scraping
+            .create_resources_tracker(create_params(
+                "resources",
+                Some(precondition(status_tracker.id)),
+            ))
+            .await?;
+        assert_eq!(
+            tracker.settings.precondition,
+            Some(precondition(status_tracker.id))
+        );
+
+        // Unknown tracker.
+        assert_eq!(
+            fail(
+                web_scraping
+                    .create_content_tracker(create_params(
+                        "content",
+                        Some(precondition(uuid!("00000000-0000-0000-0000-000000000001")))
+                    ))
+                    .await
+            )
+            .to_string(),
+            "Web page tracker precondition content tracker ('00000000-0000-0000-0000-000000000001') is not found."
+        );
+
+        // Only content trackers can be referenced.
+        assert_eq!(
+            fail(
+                web_scraping
+                    .create_content_tracker(create_params(
+                        "content",
+                        Some(precondition(tracker.id))
+                    ))
+                    .await
+            )
+            .to_string(),
+            format!(
+                "Web page tracker precondition content tracker ('{}') is not found.",
+                tracker.id
+            )
+        );
+
+        // Self-reference.
+        assert_eq!(
+            fail(
+                web_scraping
+                    .update_content_tracker(
+                        status_tracker.id,
+                        WebPageTrackerUpdateParams {
+                            settings: Some(WebPageTrackerSettings {
+                                precondition: Some(precondition(status_tracker.id)),
+                                ..settings.clone()
+                            }),
+                            ..Default::default()
+                        }
+                    )
+                    .await
+            )
+            .to_string(),
+            "Web page tracker precondition cannot reference the tracker itself."
+        );
+
+        // Invalid pointer.
+        assert_eq!(
+            fail(
+                web_scraping
+                    .create_content_tracker(create_params(
+                        "content",
+                        Some(WebPageTrackerPrecondition {
+                            pointer: Some("status".to_string()),
+                            ..precondition(status_tracker.id)
+                        })
+                    ))
+                    .await
+            )
+            .to_string(),
+            "Web page tracker precondition pointer must be a valid JSON pointer (e.g. `/status`)."
+        );
+
+        // Too long value.
+        assert_eq!(
+            fail(
+                web_scraping
+                    .create_content_tracker(create_params(
+                        "content",
+                        Some(WebPageTrackerPrecondition {
+                            value: "a".repeat(1001),
+                            ..precondition(status_tracker.id)
+                        })
+                    ))
+                    .await
+            )
+            .to_string(),
+            "Web page tracker precondition value cannot be longer than 1000 characters."
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_validates_web_page_content_tracker_at_update(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool.clone()).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "@hourly".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(120),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+
+        let update_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
+
+        // Empty parameters.
+        let update_result = update_and_fail(
+            web_scraping
+                .update_content_tracker(tracker.id, Default::default())
+                .await,
+        );
+        assert_eq!(
+            update_result.to_string(),
+            format!(
+                "Either new name, url, settings, or job config should be provided ({}).",
+                tracker.id
+            )
+        );
+
+        // Non-existent tracker.
+        let update_result = update_and_fail(
+            web_scraping
+                .update_content_tracker(
+                    uuid!("00000000-0000-0000-0000-000000000002"),
+                    WebPageTrackerUpdateParams {
+                        name: Some("name".to_string()),
+                        ..Default::default()
+                    },
+                )
+                .await,
+        );
+        assert_eq!(
+            update_result.to_string(),
+            "Web page tracker ('00000000-0000-0000-0000-000000000002') is not found."
+        );
+
+        // Empty name.
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                name: Some("".to_string()),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker name cannot be empty.""###
+        );
+
+        // Very long name.
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                name: Some("a".repeat(101)),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker name cannot be longer than 100 characters.""###
+        );
+
+        // Too many revisions.
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                settings: Some(WebPageTrackerSettings {
+                    revisions: 31,
+                    ..tracker.settings.clone()
+                }),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker revisions count cannot be greater than 30.""###
+        );
+
+        // Too long delay.
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                settings: Some(WebPageTrackerSettings {
+                    delay: Duration::from_secs(61),
+                    ..tracker.settings.clone()
+                }),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker delay cannot be greater than 60000ms.""###
+        );
+
+        // Empty resource filter.
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                settings: Some(WebPageTrackerSettings {
+                    scripts: Some([(
+                        WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME.to_string(),
+                        "".to_string()
+                    )]
+                    .into_iter()
+                    .collect()),
+                   ..tracker.settings.clone()
+                }),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker scripts cannot be empty or have an empty name.""###
+        );
+
+        // Unknown script.
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                settings: Some(WebPageTrackerSettings {
+                    scripts: Some([(
+                        "someScript".to_string(),
+                        "return resource;".to_string()
+                    )]
+                    .into_iter()
+                    .collect()),
+                   ..tracker.settings.clone()
+                }),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker contains unrecognized scripts.""###
+        );
+
+        // Invalid schedule.
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "-".to_string(),
+                    retry_strategy: None,
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+                ..Default::default()
+            }).await),
+            @r###"
+        Error {
+            context: "Web page tracker schedule must be a valid cron expression.",
+            source: "Failed to parse schedule `-`: Error { kind: Expression(\"Invalid cron expression.\") }",
+        }
+        "###
+        );
+
+        // Invalid schedule interval.
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "0/5 * * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker schedule must have at least 10s between occurrences, but detected 5s.""###
+        );
+
+        // Too few retry attempts.
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(120),
+                        max_attempts: 0,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 0.""###
+        );
+
+        // Too many retry attempts.
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(120),
+                        max_attempts: 11,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 11.""###
+        );
+
+        // Too low retry interval.
+        assert_debug_snapshot!(
+           update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
+                        interval: Duration::from_secs(30),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker min retry interval cannot be less than 1m, but received 30s.""###
+        );
+
+        // Too low max retry interval.
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "@daily".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
+                        initial_interval: Duration::from_secs(120),
+                        increment: Duration::from_secs(10),
+                        max_interval: Duration::from_secs(30),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker retry strategy max interval cannot be less than 1m, but received 30s.""###
+        );
+
+        // Too high max retry interval.
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "@monthly".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
+                        initial_interval: Duration::from_secs(120),
+                        increment: Duration::from_secs(10),
+                        max_interval: Duration::from_secs(13 * 3600),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker retry strategy max interval cannot be greater than 12h, but received 13h.""###
+        );
+
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                job_config: Some(Some(SchedulerJobConfig {
+                    schedule: "@hourly".to_string(),
+                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
+                        initial_interval: Duration::from_secs(120),
+                        increment: Duration::from_secs(10),
+                        max_interval: Duration::from_secs(2 * 3600),
+                        max_attempts: 5,
+                        max_total_retry_duration: None,
+                    }),
+                    notifications: false,
+                    adaptive_interval: None,
+                })),
+               ..Default::default()
+            }).await),
+            @r###""Web page tracker retry strategy max interval cannot be greater than 1h, but received 2h.""###
+        );
+
+        // Invalid URL schema.
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                url: Some(Url::parse("ftp://secutils.dev")?),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received ftp://secutils.dev/.""###
+        );
+
+        let api_with_local_network = mock_api_with_network(
+            pool,
+            mock_network_with_records::<1>(vec![Record::from_rdata(
+                Name::new(),
+                300,
+                RData::A(A(Ipv4Addr::new(127, 0, 0, 1))),
+            )]),
+        )
+        .await?;
+
+        // Non-public URL.
+        let web_scraping = api_with_local_network.web_scraping(&mock_user);
+        assert_debug_snapshot!(
+            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
+                url: Some(Url::parse("https://127.0.0.1")?),
+                ..Default::default()
+            }).await),
+            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received https://127.0.0.1/.""###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_updates_web_page_resources_job_id_at_update(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+
+        // Set job ID.
+        api.web_scraping_system()
+            .update_web_page_tracker_job(
+                tracker.id,
+                Some(uuid!("00000000-0000-0000-0000-000000000001")),
+            )
+            .await?;
+        assert_eq!(
+            Some(uuid!("00000000-0000-0000-0000-000000000001")),
+            web_scraping
+                .get_resources_tracker(tracker.id)
+                .await?
+                .unwrap()
+                .job_id
+        );
+
+        let updated_tracker = web_scraping
+            .update_resources_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    name: Some("name_two".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let expected_tracker = WebPageTracker {
+            name: "name_two".to_string(),
+            job_id: Some(uuid!("00000000-0000-0000-0000-000000000001")),
+            updated_at: updated_tracker.updated_at,
+            ..tracker.clone()
+        };
+        assert_eq!(expected_tracker, updated_tracker);
+        assert_eq!(
+            expected_tracker,
+            web_scraping
+                .get_resources_tracker(tracker.id)
+                .await?
+                .unwrap()
+        );
+
+        // Change in schedule will reset job ID.
+        let updated_tracker = web_scraping
+            .update_resources_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    job_config: Some(Some(SchedulerJobConfig {
+                        schedule: "0 1 * * * *".to_string(),
+                        retry_strategy: None,
+                        notifications: true,
+                        adaptive_interval: None,
+                    })),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let expected_tracker = WebPageTracker {
+            name: "name_two".to_string(),
+            job_id: None,
+            job_config: Some(SchedulerJobConfig {
+                schedule: "0 1 * * * *".to_string(),
+                retry_strategy: None,
+                notifications: true,
+                adaptive_interval: None,
+            }),
+            updated_at: updated_tracker.updated_at,
+            ..tracker.clone()
+        };
+        assert_eq!(expected_tracker, updated_tracker);
+        assert_eq!(
+            expected_tracker,
+            web_scraping
+                .get_resources_tracker(tracker.id)
+                .await?
+                .unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_updates_web_page_content_job_id_at_update(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+
+        // Set job ID.
+        api.web_scraping_system()
+            .update_web_page_tracker_job(
+                tracker.id,
+                Some(uuid!("00000000-0000-0000-0000-000000000001")),
+            )
+            .await?;
+        assert_eq!(
+            Some(uuid!("00000000-0000-0000-0000-000000000001")),
+            web_scraping
+                .get_content_tracker(tracker.id)
+                .await?
+                .unwrap()
+                .job_id
+        );
+
+        let updated_tracker = web_scraping
+            .update_content_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    name: Some("name_two".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let expected_tracker = WebPageTracker {
+            name: "name_two".to_string(),
+            job_id: Some(uuid!("00000000-0000-0000-0000-000000000001")),
+            updated_at: updated_tracker.updated_at,
+            ..tracker.clone()
+        };
+        assert_eq!(expected_tracker, updated_tracker);
+        assert_eq!(
+            expected_tracker,
+            web_scraping.get_content_tracker(tracker.id).await?.unwrap()
+        );
+
+        // Change in schedule will reset job ID.
+        let updated_tracker = web_scraping
+            .update_content_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    job_config: Some(Some(SchedulerJobConfig {
+                        schedule: "0 1 * * * *".to_string(),
+                        retry_strategy: None,
+                        notifications: true,
+                        adaptive_interval: None,
+                    })),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let expected_tracker = WebPageTracker {
+            name: "name_two".to_string(),
+            job_id: None,
+            job_config: Some(SchedulerJobConfig {
+                schedule: "0 1 * * * *".to_string(),
+                retry_strategy: None,
+                notifications: true,
+                adaptive_interval: None,
+            }),
+            updated_at: updated_tracker.updated_at,
+            ..tracker.clone()
+        };
+        assert_eq!(expected_tracker, updated_tracker);
+        assert_eq!(
+            expected_tracker,
+            web_scraping.get_content_tracker(tracker.id).await?.unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_removes_web_page_trackers(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker_one = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+        let tracker_two = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_two".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: tracker_one.settings.clone(),
+                job_config: tracker_one.job_config.clone(),
+            })
+            .await?;
+
+        assert_eq!(
+            web_scraping.get_resources_trackers().await?,
+            vec![tracker_one.clone(), tracker_two.clone()],
+        );
+
+        web_scraping.remove_web_page_tracker(tracker_one.id).await?;
+
+        assert_eq!(
+            web_scraping.get_resources_trackers().await?,
+            vec![tracker_two.clone()],
+        );
+
+        web_scraping.remove_web_page_tracker(tracker_two.id).await?;
+
+        assert!(web_scraping.get_resources_trackers().await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_returns_resources_trackers_by_id(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        assert!(web_scraping
+            .get_resources_tracker(uuid!("00000000-0000-0000-0000-000000000001"))
+            .await?
+            .is_none());
+
+        let tracker_one = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+        assert_eq!(
+            web_scraping.get_resources_tracker(tracker_one.id).await?,
+            Some(tracker_one.clone()),
+        );
+
+        let tracker_two = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_two".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: tracker_one.settings.clone(),
+                job_config: tracker_one.job_config.clone(),
+            })
+            .await?;
+
+        assert_eq!(
+            web_scraping.get_resources_tracker(tracker_two.id).await?,
+            Some(tracker_two.clone()),
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_returns_content_trackers_by_id(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        assert!(web_scraping
+            .get_content_tracker(uuid!("00000000-0000-0000-0000-000000000001"))
+            .await?
+            .is_none());
+
+        let tracker_one = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+        assert_eq!(
+            web_scraping.get_content_tracker(tracker_one.id).await?,
+            Some(tracker_one.clone()),
+        );
+
+        let tracker_two = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_two".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: tracker_one.settings.clone(),
+                job_config: tracker_one.job_config.clone(),
+            })
+            .await?;
+
+        assert_eq!(
+            web_scraping.get_content_tracker(tracker_two.id).await?,
+            Some(tracker_two.clone()),
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_returns_all_resources_trackers(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        assert!(web_scraping.get_resources_trackers().await?.is_empty(),);
+
+        let tracker_one = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+        assert_eq!(
+            web_scraping.get_resources_trackers().await?,
+            vec![tracker_one.clone()],
+        );
+        let tracker_two = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_two".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: tracker_one.settings.clone(),
+                job_config: tracker_one.job_config.clone(),
+            })
+            .await?;
+
+        assert_eq!(
+            web_scraping.get_resources_trackers().await?,
+            vec![tracker_one.clone(), tracker_two.clone()],
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_returns_all_content_trackers(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        assert!(web_scraping.get_content_trackers().await?.is_empty(),);
+
+        let tracker_one = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+        assert_eq!(
+            web_scraping.get_content_trackers().await?,
+            vec![tracker_one.clone()],
+        );
+        let tracker_two = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_two".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: tracker_one.settings.clone(),
+                job_config: tracker_one.job_config.clone(),
+            })
+            .await?;
+
+        assert_eq!(
+            web_scraping.get_content_trackers().await?,
+            vec![tracker_one.clone(), tracker_two.clone()],
+        );
+
+        // Trackers are returned in the custom display order.
+        api.users()
+            .set_display_order(
+                mock_user.id,
+                UserDisplayOrderCollection::WebPageContentTrackers,
+                vec![tracker_two.id, tracker_one.id],
+            )
+            .await?;
+        assert_eq!(
+            web_scraping.get_content_trackers().await?,
+            vec![tracker_two.clone(), tracker_one.clone()],
+        );
+
+        // Pinned trackers are returned first.
+        api.users()
+            .set_display_pinned(
+                mock_user.id,
+                UserDisplayOrderCollection::WebPageContentTrackers,
+                tracker_one.id,
+                true,
+            )
+            .await?;
+        assert_eq!(
+            web_scraping.get_content_trackers().await?,
+            vec![tracker_one.clone(), tracker_two.clone()],
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_saves_web_page_resources(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker_one = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+        let tracker_two = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_two".to_string(),
+                url: Url::parse("https://secutils.dev/two")?,
+                settings: tracker_one.settings.clone(),
+                job_config: tracker_one.job_config.clone(),
+            })
+            .await?;
+
+        let tracker_one_resources = web_scraping
+            .get_resources_tracker_history(tracker_one.id, Default::default())
+            .await?;
+        let tracker_two_resources = web_scraping
+            .get_resources_tracker_history(tracker_two.id, Default::default())
+            .await?;
+        assert!(tracker_one_resources.is_empty());
+        assert!(tracker_two_resources.is_empty());
+
+        let resources_one = get_resources(946720800, "rev_1")?;
+        let mut resources_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/resources")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperResourcesRequest::with_default_parameters(&tracker_one.url)
+                            .set_delay(Duration::from_millis(2000)),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&resources_one);
+        });
+
+        let diff = web_scraping
+            .create_resources_tracker_revision(tracker_one.id)
+            .await?;
+        assert!(diff.is_none());
+        resources_mock.assert();
+        resources_mock.delete();
+
+        let tracker_one_resources = web_scraping
+            .get_resources_tracker_history(tracker_one.id, Default::default())
+            .await?;
+        let tracker_two_resources = web_scraping
+            .get_resources_tracker_history(tracker_two.id, Default::default())
+            .await?;
+        assert_eq!(tracker_one_resources.len(), 1);
+        assert_eq!(tracker_one_resources[0].tracker_id, tracker_one.id);
+        assert_eq!(
+            tracker_one_resources[0].data.scripts,
+            resources_one
+                .scripts
+                .into_iter()
+                .map(WebPageResource::from)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            tracker_one_resources[0].data.styles,
+            resources_one
+                .styles
+                .into_iter()
+                .map(WebPageResource::from)
+                .collect::<Vec<_>>()
+        );
+        assert!(tracker_two_resources.is_empty());
+
+        let resources_two = get_resources(946720900, "rev_2")?;
+        let mut resources_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/resources")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperResourcesRequest::with_default_parameters(&tracker_one.url)
+                            .set_delay(Duration::from_millis(2000)),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&resources_two);
+        });
+        let diff = web_scraping
+            .create_resources_tracker_revision(tracker_one.id)
+            .await?
+            .unwrap();
+        assert_eq!(
+            diff.created_at,
+            OffsetDateTime::from_unix_timestamp(946720900)?
+        );
+        assert_eq!(
+            diff.data.scripts,
+            vec![
+                WebPageResource {
+                    url: Some(Url::parse("http://localhost:1234/script_rev_2.js")?),
+                    content: None,
+                    diff_status: Some(WebPageResourceDiffStatus::Added),
+                    category: None,
+                },
+                WebPageResource {
+                    url: Some(Url::parse("http://localhost:1234/script_rev_1.js")?),
+                    content: None,
+                    diff_status: Some(WebPageResourceDiffStatus::Removed),
+                    category: None,
+                },
+            ]
+        );
+        resources_mock.assert();
+        resources_mock.delete();
+
+        let tracker_one_resources = web_scraping
+            .get_resources_tracker_history(tracker_one.id, Default::default())
+            .await?;
+        let tracker_two_resources = web_scraping
+            .get_resources_tracker_history(tracker_two.id, Default::default())
+            .await?;
+        assert_eq!(tracker_one_resources.len(), 2);
+        assert!(tracker_two_resources.is_empty());
+
+        let resources_two = get_resources(946720900, "rev_3")?;
+        let resources_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/resources")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperResourcesRequest::with_default_parameters(&tracker_two.url)
+                            .set_delay(Duration::from_millis(2000)),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&resources_two);
+        });
+        let diff = web_scraping
+            .create_resources_tracker_revision(tracker_two.id)
+            .await?;
+        assert!(diff.is_none());
+        resources_mock.assert();
+
+        let tracker_one_resources = web_scraping
+            .get_resources_tracker_history(tracker_one.id, Default::default())
+            .await?;
+        let tracker_two_resources = web_scraping
+            .get_resources_tracker_history(tracker_two.id, Default::default())
+            .await?;
+        assert_eq!(tracker_one_resources.len(), 2);
+        assert_eq!(tracker_two_resources.len(), 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_classifies_web_page_resources(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    classify_resources: true,
+                    ..Default::default()
+                },
+                job_config: None,
+            })
+            .await?;
+
+        let resources = WebScraperResourcesResponse {
+            timestamp: OffsetDateTime::from_unix_timestamp(946720800)?,
+            scripts: [
+                "https://www.google-analytics.com/analytics.js",
+                "https://www.googletagmanager.com/gtm.js?id=GTM-XXXX",
+                "https://connect.facebook.net/en_US/fbevents.js",
+                "https://secutils.dev/app.js",
+            ]
+            .into_iter()
+            .map(|url| {
+                Ok(WebScraperResource {
+                    url: Some(Url::parse(url)?),
+                    content: None,
+                })
+            })
+            .collect::<anyhow::Result<_>>()?,
+            styles: vec![WebScraperResource {
+                url: Some(Url::parse("https://secutils.dev/app.css")?),
+                content: None,
+            }],
+        };
+        let resources_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/resources");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&resources);
+        });
+
+        web_scraping
+            .create_resources_tracker_revision(tracker.id)
+            .await?;
+        resources_mock.assert();
+
+        let history = web_scraping
+            .get_resources_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(history.len(), 1);
+        assert_eq!(
+            history[0]
+                .data
+                .scripts
+                .iter()
+                .map(|resource| resource.category)
+                .collect::<Vec<_>>(),
+            vec![
+                Some(WebPageResourceCategory::Analytics),
+                Some(WebPageResourceCategory::TagManager),
+                Some(WebPageResourceCategory::Advertising),
+                None
+            ]
+        );
+        assert_eq!(history[0].data.styles[0].category, None);
+        assert_eq!(
+            history[0].data.categories,
+            Some(
+                [
+                    (WebPageResourceCategory::Analytics, 1),
+                    (WebPageResourceCategory::Advertising, 1),
+                    (WebPageResourceCategory::TagManager, 1),
+                ]
+                .into_iter()
+                .collect()
+            )
+        );
+
+        // Classification isn't persisted and is only applied if enabled for the tracker.
+        let tracker = web_scraping
+            .update_resources_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    settings: Some(WebPageTrackerSettings {
+                        classify_resources: false,
+                        ..tracker.settings.clone()
+                    }),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let history = web_scraping
+            .get_resources_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(history.len(), 1);
+        assert!(history[0]
+            .data
+            .scripts
+            .iter()
+            .all(|resource| resource.category.is_none()));
+        assert_eq!(history[0].data.categories, None);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_truncates_web_page_resources(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+        config.subscriptions.ultimate.web_scraping.tracker_resources = 4;
+
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: None,
+            })
+            .await?;
 
-        // Very long name.
-        assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "a".repeat(101),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: None
-            }).await),
-            @r###""Web page tracker name cannot be longer than 100 characters.""###
+        let to_scraper_resources = |urls: &[&str]| {
+            urls.iter()
+                .map(|url| {
+                    Ok(WebScraperResource {
+                        url: Some(Url::parse(url)?),
+                        content: None,
+                    })
+                })
+                .collect::<anyhow::Result<Vec<_>>>()
+        };
+        let resources = WebScraperResourcesResponse {
+            timestamp: OffsetDateTime::from_unix_timestamp(946720800)?,
+            scripts: to_scraper_resources(&[
+                "https://secutils.dev/one.js",
+                "https://secutils.dev/two.js",
+                "https://secutils.dev/three.js",
+            ])?,
+            styles: to_scraper_resources(&[
+                "https://secutils.dev/one.css",
+                "https://secutils.dev/two.css",
+            ])?,
+        };
+        let resources_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/resources");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&resources);
+        });
+
+        web_scraping
+            .create_resources_tracker_revision(tracker.id)
+            .await?;
+        resources_mock.assert();
+
+        // Scripts take priority over styles when resources are truncated.
+        let history = web_scraping
+            .get_resources_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(history.len(), 1);
+        assert!(history[0].data.truncated);
+        assert_eq!(
+            history[0]
+                .data
+                .scripts
+                .iter()
+                .chain(history[0].data.styles.iter())
+                .filter_map(|resource| resource.url.as_ref().map(|url| url.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                "https://secutils.dev/one.js",
+                "https://secutils.dev/two.js",
+                "https://secutils.dev/three.js",
+                "https://secutils.dev/one.css"
+            ]
         );
 
-        // Too many revisions.
-        assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_filters_web_page_resources_by_type(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
                 settings: WebPageTrackerSettings {
-                    revisions: 31,
-                    ..settings.clone()
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    resource_types: Some(vec![WebPageResourceType::Script]),
+                    ..Default::default()
                 },
-                job_config: None
-            }).await),
-            @r###""Web page tracker revisions count cannot be greater than 30.""###
+                job_config: None,
+            })
+            .await?;
+
+        let resources_one = get_resources(946720800, "rev_1")?;
+        let mut resources_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/resources");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&resources_one);
+        });
+
+        web_scraping
+            .create_resources_tracker_revision(tracker.id)
+            .await?;
+        resources_mock.assert();
+        resources_mock.delete();
+
+        // Only scripts are stored.
+        let history = web_scraping
+            .get_resources_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(history.len(), 1);
+        assert_eq!(
+            history[0].data.scripts,
+            vec![WebPageResource {
+                url: Some(Url::parse("http://localhost:1234/script_rev_1.js")?),
+                content: None,
+                diff_status: None,
+                category: None,
+            }]
         );
+        assert!(history[0].data.styles.is_empty());
+        assert!(!history[0].data.truncated);
 
-        // Too long delay.
-        assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: WebPageTrackerSettings {
-                    delay: Duration::from_secs(61),
-                    ..settings.clone()
+        // Changes to the styles don't produce a new revision.
+        let resources_two = WebScraperResourcesResponse {
+            scripts: get_resources(946720800, "rev_1")?.scripts,
+            ..get_resources(946720900, "rev_2")?
+        };
+        let mut resources_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/resources");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&resources_two);
+        });
+
+        let diff = web_scraping
+            .create_resources_tracker_revision(tracker.id)
+            .await?;
+        assert!(diff.is_none());
+        resources_mock.assert();
+        resources_mock.delete();
+
+        // Changes to the scripts are reported without styles.
+        let resources_three = get_resources(946721000, "rev_3")?;
+        let resources_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/resources");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&resources_three);
+        });
+
+        let diff = web_scraping
+            .create_resources_tracker_revision(tracker.id)
+            .await?
+            .unwrap();
+        assert_eq!(
+            diff.data.scripts,
+            vec![
+                WebPageResource {
+                    url: Some(Url::parse("http://localhost:1234/script_rev_3.js")?),
+                    content: None,
+                    diff_status: Some(WebPageResourceDiffStatus::Added),
+                    category: None,
                 },
-                job_config: None
-            }).await),
-            @r###""Web page tracker delay cannot be greater than 60000ms.""###
+                WebPageResource {
+                    url: Some(Url::parse("http://localhost:1234/script_rev_1.js")?),
+                    content: None,
+                    diff_status: Some(WebPageResourceDiffStatus::Removed),
+                    category: None,
+                },
+            ]
         );
+        assert!(diff.data.styles.is_empty());
+        resources_mock.assert();
 
-        // Empty resource filter.
-        assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
+        let history = web_scraping
+            .get_resources_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(history.len(), 2);
+        assert!(history
+            .iter()
+            .all(|revision| revision.data.styles.is_empty()));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_forwards_error_if_web_page_resources_extraction_fails(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
                 settings: WebPageTrackerSettings {
-                    scripts: Some([(
-                        WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME.to_string(),
-                            "".to_string()
-                        )]
-                        .into_iter()
-                        .collect()
-                    ),
-                    ..settings.clone()
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
                 },
-                job_config: None
-            }).await),
-            @r###""Web page tracker scripts cannot be empty or have an empty name.""###
-        );
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
 
-        // Empty resource filter.
+        let web_scraper_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/resources")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperResourcesRequest::with_default_parameters(&tracker.url)
+                            .set_delay(Duration::from_millis(2000)),
+                    )
+                    .unwrap(),
+                );
+            then.status(400)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&WebScraperErrorResponse {
+                    message: "some client-error".to_string(),
+                });
+        });
+
+        let scraper_error = web_scraping
+            .get_resources_tracker_history(
+                tracker.id,
+                WebPageResourcesTrackerGetHistoryParams {
+                    refresh: true,
+                    calculate_diff: false,
+                },
+            )
+            .await
+            .unwrap_err()
+            .downcast::<SecutilsError>()
+            .unwrap();
+        assert_eq!(scraper_error.status_code(), 400);
         assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
+            scraper_error,
+            @r###""some client-error""###
+        );
+
+        let tracker_resources = web_scraping
+            .get_resources_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert!(tracker_resources.is_empty());
+        web_scraper_mock.assert();
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_forwards_insecure_tls_setting_to_web_scraper(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
+        let mut mock_user = mock_user()?;
+        mock_user.is_operator = true;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
                 settings: WebPageTrackerSettings {
-                    scripts: Some([(
-                        "someScript".to_string(),
-                            "return resource;".to_string()
-                        )]
-                        .into_iter()
-                        .collect()
-                    ),
-                    ..settings.clone()
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    insecure_tls: true,
+                    ..Default::default()
                 },
-                job_config: None
-            }).await),
-            @r###""Web page tracker contains unrecognized scripts.""###
-        );
-
-        // Invalid schedule.
-        assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "-".to_string(),
-                    retry_strategy: None,
-                    notifications: false,
-                }),
-            }).await),
-            @r###"
-        Error {
-            context: "Web page tracker schedule must be a valid cron expression.",
-            source: "Failed to parse schedule `-`: Error { kind: Expression(\"Invalid cron expression.\") }",
-        }
-        "###
-        );
+                job_config: None,
+            })
+            .await?;
+        assert!(tracker.settings.insecure_tls);
 
-        // Invalid schedule interval.
-        assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0/5 * * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: false,
-                }),
-            }).await),
-            @r###""Web page tracker schedule must have at least 10s between occurrences, but detected 5s.""###
-        );
+        let content = get_content(946720800, "\"rev_1\"")?;
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperContentRequest::with_default_parameters(&tracker.url)
+                            .set_delay(Duration::from_millis(2000))
+                            .set_ignore_https_errors(true),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content);
+        });
 
-        // Too few retry attempts.
-        assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(120),
-                        max_attempts: 0,
-                    }),
-                    notifications: false,
-                }),
-            }).await),
-            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 0.""###
-        );
+        let tracker_history = web_scraping
+            .get_content_tracker_history(
+                tracker.id,
+                WebPageContentTrackerGetHistoryParams {
+                    refresh: true,
+                    calculate_diff: false,
+                    diff_algorithm: Default::default(),
+                },
+            )
+            .await?;
+        assert_eq!(tracker_history.len(), 1);
+        assert_eq!(tracker_history[0].data, content.content);
+        content_mock.assert();
 
-        // Too many retry attempts.
+        // Regular users cannot update tracker with insecure TLS enabled.
+        let regular_user = User {
+            is_operator: false,
+            ..mock_user.clone()
+        };
+        let update_result = api
+            .web_scraping(&regular_user)
+            .update_content_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    name: Some("name_two".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await;
         assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(120),
-                        max_attempts: 11,
-                    }),
-                    notifications: false,
-                }),
-            }).await),
-            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 11.""###
+            update_result.unwrap_err().downcast::<SecutilsError>()?,
+            @r###""Only operators can disable TLS certificate verification for web page trackers.""###
         );
 
-        // Too low retry interval.
-        assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(30),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                }),
-            }).await),
-            @r###""Web page tracker min retry interval cannot be less than 1m, but received 30s.""###
-        );
+        Ok(())
+    }
 
-        // Too low max retry interval.
-        assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
-                        initial_interval: Duration::from_secs(120),
-                        increment: Duration::from_secs(10),
-                        max_interval: Duration::from_secs(30),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                }),
-            }).await),
-            @r###""Web page tracker retry strategy max interval cannot be less than 1m, but received 30s.""###
-        );
+    #[sqlx::test]
+    async fn properly_resolves_secret_references_in_headers(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
 
-        // Too high max retry interval.
-        assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "@monthly".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
-                        initial_interval: Duration::from_secs(120),
-                        increment: Duration::from_secs(10),
-                        max_interval: Duration::from_secs(13 * 3600),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                }),
-            }).await),
-            @r###""Web page tracker retry strategy max interval cannot be greater than 12h, but received 13h.""###
+        let mut api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+        api.secrets = Arc::new(
+            MockSecretsProvider::default()
+                .with_secret(format!("{}/token", *mock_user.id), "Bearer token")
+                .with_secret("token", "Bearer operator-token"),
         );
 
-        assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "@hourly".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
-                        initial_interval: Duration::from_secs(120),
-                        increment: Duration::from_secs(10),
-                        max_interval: Duration::from_secs(2 * 3600),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                }),
-            }).await),
-            @r###""Web page tracker retry strategy max interval cannot be greater than 1h, but received 2h.""###
-        );
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    headers: Some(
+                        [("Authorization".to_string(), "secret://token".to_string())]
+                            .into_iter()
+                            .collect(),
+                    ),
+                    ..Default::default()
+                },
+                job_config: None,
+            })
+            .await?;
 
-        // Invalid URL schema.
-        assert_debug_snapshot!(
-            create_and_fail(web_scraping.create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: Url::parse("ftp://secutils.dev")?,
-                settings: settings.clone(),
-                job_config: None
-            }).await),
-            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received ftp://secutils.dev/.""###
-        );
+        // Only the user's own secret is sent to the web scraper.
+        let content = get_content(946720800, "\"rev_1\"")?;
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperContentRequest::with_default_parameters(&tracker.url)
+                            .set_delay(Duration::from_millis(2000))
+                            .set_headers(
+                                &[("Authorization".to_string(), "Bearer token".to_string())]
+                                    .into_iter()
+                                    .collect(),
+                            ),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content);
+        });
 
-        let api_with_local_network = mock_api_with_network(
-            pool,
-            mock_network_with_records::<1>(vec![Record::from_rdata(
-                Name::new(),
-                300,
-                RData::A(A(Ipv4Addr::new(127, 0, 0, 1))),
-            )]),
-        )
-        .await?;
+        let tracker_history = web_scraping
+            .get_content_tracker_history(
+                tracker.id,
+                WebPageContentTrackerGetHistoryParams {
+                    refresh: true,
+                    calculate_diff: false,
+                    diff_algorithm: Default::default(),
+                },
+            )
+            .await?;
+        assert_eq!(tracker_history.len(), 1);
+        content_mock.assert();
 
-        // Non-public URL.
-        assert_debug_snapshot!(
-            create_and_fail(api_with_local_network.web_scraping(&mock_user).create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: Url::parse("https://127.0.0.1")?,
-                settings: settings.clone(),
-                job_config: None
-            }).await),
-            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received https://127.0.0.1/.""###
+        // Resolved secrets are never persisted.
+        let tracker = web_scraping.get_content_tracker(tracker.id).await?.unwrap();
+        assert_eq!(
+            tracker.settings.headers,
+            Some(
+                [("Authorization".to_string(), "secret://token".to_string())]
+                    .into_iter()
+                    .collect()
+            )
         );
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_validates_web_page_content_tracker_at_creation(
+    async fn properly_forwards_render_js_setting_to_web_scraper(
         pool: PgPool,
     ) -> anyhow::Result<()> {
-        let api = mock_api(pool.clone()).await?;
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
 
-        let api = api.web_scraping(&mock_user);
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    render_js: false,
+                    ..Default::default()
+                },
+                job_config: None,
+            })
+            .await?;
+        assert!(!tracker.settings.render_js);
 
-        let settings = WebPageTrackerSettings {
-            revisions: 3,
-            delay: Duration::from_millis(2000),
-            scripts: Default::default(),
-            headers: Default::default(),
-        };
-        let url = Url::parse("https://secutils.dev")?;
+        let content = get_content(946720800, "\"rev_1\"")?;
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content")
+                .json_body(serde_json::json!({
+                    "url": "https://secutils.dev/one",
+                    "delay": 2000,
+                    "disableJs": true
+                }));
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content);
+        });
 
-        let create_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
-            result.unwrap_err().downcast::<SecutilsError>().unwrap()
-        };
+        let tracker_history = web_scraping
+            .get_content_tracker_history(
+                tracker.id,
+                WebPageContentTrackerGetHistoryParams {
+                    refresh: true,
+                    calculate_diff: false,
+                    diff_algorithm: Default::default(),
+                },
+            )
+            .await?;
+        assert_eq!(tracker_history.len(), 1);
+        assert_eq!(tracker_history[0].data, content.content);
+        content_mock.assert();
 
-        // Empty name.
-        assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: None
-            }).await),
-            @r###""Web page tracker name cannot be empty.""###
-        );
+        Ok(())
+    }
 
-        // Very long name.
-        assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "a".repeat(101),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: None
-            }).await),
-            @r###""Web page tracker name cannot be longer than 100 characters.""###
-        );
+    #[sqlx::test]
+    async fn properly_tests_web_page_tracker_scripts(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
 
-        // Too many revisions.
-        assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: WebPageTrackerSettings {
-                    revisions: 31,
-                    ..settings.clone()
-                },
-                job_config: None
-            }).await),
-            @r###""Web page tracker revisions count cannot be greater than 30.""###
-        );
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
 
-        // Too long delay.
-        assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: WebPageTrackerSettings {
-                    delay: Duration::from_secs(61),
-                    ..settings.clone()
-                },
-                job_config: None
-            }).await),
-            @r###""Web page tracker delay cannot be greater than 60000ms.""###
-        );
+        let url = Url::parse("https://secutils.dev/one")?;
+        let resources_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/resources")
+                .json_body(
+                    serde_json::to_value(WebScraperResourcesRequest::with_default_parameters(&url))
+                        .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&get_resources(946720800, "rev_1").unwrap());
+        });
 
-        // Empty resource filter.
-        assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
+        let web_scraping = api.web_scraping(&mock_user);
+
+        // Valid script.
+        let result = web_scraping
+            .test_tracker_script::<WebPageResourcesTrackerTag>(WebPageTrackerTestScriptParams {
                 url: url.clone(),
-                settings: WebPageTrackerSettings {
-                    scripts: Some([(
-                        WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME.to_string(),
-                            "".to_string()
-                        )]
-                        .into_iter()
-                        .collect()
-                    ),
-                    ..settings.clone()
-                },
-                job_config: None
-            }).await),
-            @r###""Web page tracker scripts cannot be empty or have an empty name.""###
+                script_name: WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME.to_string(),
+                script: "return resource.type === 'script' ? resource.url : null;".to_string(),
+                headers: None,
+            })
+            .await?;
+        assert_eq!(
+            result,
+            WebPageTrackerScriptTestResult {
+                output: Some(serde_json::json!(["http://localhost:1234/script_rev_1.js"])),
+                error: None,
+            }
         );
 
-        // Empty resource filter.
-        assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
+        // Throwing script.
+        let result = web_scraping
+            .test_tracker_script::<WebPageResourcesTrackerTag>(WebPageTrackerTestScriptParams {
                 url: url.clone(),
-                settings: WebPageTrackerSettings {
-                    scripts: Some([(
-                        "someScript".to_string(),
-                            "return resource;".to_string()
-                        )]
-                        .into_iter()
-                        .collect()
+                script_name: WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME.to_string(),
+                script: "const a = 1;\nthrow new Error('Uh oh.');".to_string(),
+                headers: None,
+            })
+            .await?;
+        assert_debug_snapshot!(result, @r###"
+        WebPageTrackerScriptTestResult {
+            output: None,
+            error: Some(
+                WebPageTrackerScriptTestError {
+                    message: "Uncaught (in promise) Error: Uh oh.",
+                    line_number: Some(
+                        2,
+                    ),
+                    column_number: Some(
+                        7,
                     ),
-                    ..settings.clone()
                 },
-                job_config: None
-            }).await),
-            @r###""Web page tracker contains unrecognized scripts.""###
-        );
-
-        // Invalid schedule.
-        assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "-".to_string(),
-                    retry_strategy: None,
-                    notifications: false,
-                }),
-            }).await),
-            @r###"
-        Error {
-            context: "Web page tracker schedule must be a valid cron expression.",
-            source: "Failed to parse schedule `-`: Error { kind: Expression(\"Invalid cron expression.\") }",
+            ),
         }
-        "###
-        );
+        "###);
+        resources_mock.assert_hits(2);
 
-        // Invalid schedule interval.
+        // Unsupported script.
         assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0/5 * * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: false,
-                }),
-            }).await),
-            @r###""Web page tracker schedule must have at least 10s between occurrences, but detected 5s.""###
+            web_scraping
+                .test_tracker_script::<WebPageResourcesTrackerTag>(WebPageTrackerTestScriptParams {
+                    url: url.clone(),
+                    script_name: WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME.to_string(),
+                    script: "return document.title;".to_string(),
+                    headers: None,
+                })
+                .await
+                .unwrap_err()
+                .downcast::<SecutilsError>()?,
+            @r###""Web page tracker doesn't support `extractContent` script.""###
         );
 
-        // Too few retry attempts.
+        // Non-public URL.
         assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(120),
-                        max_attempts: 0,
-                    }),
-                    notifications: false,
-                }),
-            }).await),
-            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 0.""###
+            web_scraping
+                .test_tracker_script::<WebPageContentTrackerTag>(WebPageTrackerTestScriptParams {
+                    url: Url::parse("ftp://secutils.dev")?,
+                    script_name: WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME.to_string(),
+                    script: "return document.title;".to_string(),
+                    headers: None,
+                })
+                .await
+                .unwrap_err()
+                .downcast::<SecutilsError>()?,
+            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received ftp://secutils.dev/.""###
         );
 
-        // Too many retry attempts.
-        assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(120),
-                        max_attempts: 11,
-                    }),
-                    notifications: false,
-                }),
-            }).await),
-            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 11.""###
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_lints_web_page_tracker_scripts(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+
+        // Valid script.
+        assert!(web_scraping
+            .lint_tracker_script::<WebPageContentTrackerTag>(WebPageTrackerLintScriptParams {
+                script_name: WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME.to_string(),
+                script: "return document.title;".to_string(),
+            })?
+            .is_empty());
+
+        // Script with a syntax error.
+        assert_eq!(
+            web_scraping.lint_tracker_script::<WebPageResourcesTrackerTag>(
+                WebPageTrackerLintScriptParams {
+                    script_name: WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME.to_string(),
+                    script: "const a = 1;\nreturn resource +;".to_string(),
+                }
+            )?,
+            vec![JsScriptDiagnostic {
+                message: "Uncaught SyntaxError: Unexpected token ';'".to_string(),
+                line_number: Some(2),
+                column_number: Some(18),
+            }]
         );
 
-        // Too low retry interval.
-        assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(30),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                }),
-            }).await),
-            @r###""Web page tracker min retry interval cannot be less than 1m, but received 30s.""###
+        // Script without return.
+        assert_eq!(
+            web_scraping.lint_tracker_script::<WebPageContentTrackerTag>(
+                WebPageTrackerLintScriptParams {
+                    script_name: WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME.to_string(),
+                    script: "document.title;".to_string(),
+                }
+            )?,
+            vec![JsScriptDiagnostic {
+                message: "Script doesn't return any value.".to_string(),
+                line_number: None,
+                column_number: None,
+            }]
         );
 
-        // Too low max retry interval.
+        // Unsupported script.
         assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
-                        initial_interval: Duration::from_secs(120),
-                        increment: Duration::from_secs(10),
-                        max_interval: Duration::from_secs(30),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                }),
-            }).await),
-            @r###""Web page tracker retry strategy max interval cannot be less than 1m, but received 30s.""###
+            web_scraping
+                .lint_tracker_script::<WebPageContentTrackerTag>(WebPageTrackerLintScriptParams {
+                    script_name: WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME.to_string(),
+                    script: "return resource;".to_string(),
+                })
+                .unwrap_err()
+                .downcast::<SecutilsError>()?,
+            @r###""Web page tracker doesn't support `resourceFilterMap` script.""###
         );
 
-        // Too high max retry interval.
-        assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_saves_web_page_content(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker_one = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
                 job_config: Some(SchedulerJobConfig {
-                    schedule: "@monthly".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
-                        initial_interval: Duration::from_secs(120),
-                        increment: Duration::from_secs(10),
-                        max_interval: Duration::from_secs(13 * 3600),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
                 }),
-            }).await),
-            @r###""Web page tracker retry strategy max interval cannot be greater than 12h, but received 13h.""###
+            })
+            .await?;
+        let tracker_two = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_two".to_string(),
+                url: Url::parse("https://secutils.dev/two")?,
+                settings: tracker_one.settings.clone(),
+                job_config: tracker_one.job_config.clone(),
+            })
+            .await?;
+
+        let tracker_one_history = web_scraping
+            .get_content_tracker_history(tracker_one.id, Default::default())
+            .await?;
+        let tracker_two_history = web_scraping
+            .get_content_tracker_history(tracker_two.id, Default::default())
+            .await?;
+        assert!(tracker_one_history.is_empty());
+        assert!(tracker_two_history.is_empty());
+
+        let content_one = get_content(946720800, "\"rev_1\"")?;
+        let mut content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperContentRequest::with_default_parameters(&tracker_one.url)
+                            .set_delay(Duration::from_millis(2000)),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content_one);
+        });
+
+        let tracker_one_history = web_scraping
+            .get_content_tracker_history(
+                tracker_one.id,
+                WebPageContentTrackerGetHistoryParams {
+                    refresh: true,
+                    calculate_diff: false,
+                    diff_algorithm: Default::default(),
+                },
+            )
+            .await?;
+        let tracker_two_content = web_scraping
+            .get_content_tracker_history(tracker_two.id, Default::default())
+            .await?;
+        assert_eq!(tracker_one_history.len(), 1);
+        assert_eq!(tracker_one_history[0].tracker_id, tracker_one.id);
+        assert_eq!(tracker_one_history[0].data, content_one.content);
+        assert!(tracker_two_content.is_empty());
+
+        content_mock.assert();
+        content_mock.delete();
+
+        let content_two = get_content(946720900, "\"rev_2\"")?;
+        let mut content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperContentRequest::with_default_parameters(&tracker_one.url)
+                            .set_delay(Duration::from_millis(2000))
+                            .set_previous_content("\"rev_1\""),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content_two);
+        });
+        let revision = web_scraping
+            .create_content_tracker_revision(tracker_one.id)
+            .await?
+            .unwrap();
+        assert_eq!(
+            revision.created_at,
+            OffsetDateTime::from_unix_timestamp(946720900)?
         );
+        assert_eq!(revision.data, "\"rev_2\"");
+        content_mock.assert();
+        content_mock.delete();
+
+        let tracker_one_content = web_scraping
+            .get_content_tracker_history(tracker_one.id, Default::default())
+            .await?;
+        let tracker_two_content = web_scraping
+            .get_content_tracker_history(tracker_two.id, Default::default())
+            .await?;
+        assert_eq!(tracker_one_content.len(), 2);
+        assert!(tracker_two_content.is_empty());
+
+        let content_two = get_content(946720900, "\"rev_3\"")?;
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperContentRequest::with_default_parameters(&tracker_two.url)
+                            .set_delay(Duration::from_millis(2000)),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content_two);
+        });
+        let revision = web_scraping
+            .create_content_tracker_revision(tracker_two.id)
+            .await?;
+        assert_eq!(revision.unwrap().data, "\"rev_3\"");
+        content_mock.assert();
+
+        let tracker_one_content = web_scraping
+            .get_content_tracker_history(
+                tracker_one.id,
+                WebPageContentTrackerGetHistoryParams {
+                    refresh: false,
+                    calculate_diff: true,
+                    diff_algorithm: Default::default(),
+                },
+            )
+            .await?;
+        let tracker_two_content = web_scraping
+            .get_content_tracker_history(tracker_two.id, Default::default())
+            .await?;
+        assert_eq!(tracker_one_content.len(), 2);
+        assert_eq!(tracker_two_content.len(), 1);
 
         assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: url.clone(),
-                settings: settings.clone(),
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "@hourly".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
-                        initial_interval: Duration::from_secs(120),
-                        increment: Duration::from_secs(10),
-                        max_interval: Duration::from_secs(2 * 3600),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                }),
-            }).await),
-            @r###""Web page tracker retry strategy max interval cannot be greater than 1h, but received 2h.""###
+            tracker_one_content.into_iter().map(|rev| rev.data).collect::<Vec<_>>(),
+            @r###"
+        [
+            "\"rev_1\"",
+            "@@ -1 +1 @@\n-rev_1\n+rev_2\n",
+        ]
+        "###
         );
-
-        // Invalid URL schema.
         assert_debug_snapshot!(
-            create_and_fail(api.create_content_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: Url::parse("ftp://secutils.dev")?,
-                settings: settings.clone(),
-                job_config: None
-            }).await),
-            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received ftp://secutils.dev/.""###
+            tracker_two_content.into_iter().map(|rev| rev.data).collect::<Vec<_>>(),
+            @r###"
+        [
+            "\"rev_3\"",
+        ]
+        "###
         );
 
-        let api_with_local_network = mock_api_with_network(
-            pool,
-            mock_network_with_records::<1>(vec![Record::from_rdata(
-                Name::new(),
-                300,
-                RData::A(A(Ipv4Addr::new(127, 0, 0, 1))),
-            )]),
-        )
-        .await?;
-
-        // Non-public URL.
-        assert_debug_snapshot!(
-            create_and_fail(api_with_local_network.web_scraping(&mock_user).create_content_tracker(WebPageTrackerCreateParams {
-                name: "name".to_string(),
-                url: Url::parse("https://127.0.0.1")?,
-                settings: settings.clone(),
-                job_config: None
-            }).await),
-            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received https://127.0.0.1/.""###
+        let tracker_one_content = web_scraping
+            .get_content_tracker_history(
+                tracker_one.id,
+                WebPageContentTrackerGetHistoryParams {
+                    refresh: false,
+                    calculate_diff: false,
+                    diff_algorithm: Default::default(),
+                },
+            )
+            .await?;
+        assert_debug_snapshot!(
+            tracker_one_content.into_iter().map(|rev| rev.data).collect::<Vec<_>>(),
+            @r###"
+        [
+            "\"rev_1\"",
+            "\"rev_2\"",
+        ]
+        "###
         );
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_updates_web_page_resources_tracker(pool: PgPool) -> anyhow::Result<()> {
-        let api = mock_api(pool).await?;
+    async fn properly_forwards_error_if_web_page_content_extraction_fails(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
 
         let web_scraping = api.web_scraping(&mock_user);
         let tracker = web_scraping
-            .create_resources_tracker(WebPageTrackerCreateParams {
+            .create_content_tracker(WebPageTrackerCreateParams {
                 name: "name_one".to_string(),
-                url: Url::parse("http://localhost:1234/my/app?q=2")?,
+                url: Url::parse("https://secutils.dev/one")?,
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
-                job_config: None,
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
             })
             .await?;
 
-        // Update name.
-        let updated_tracker = web_scraping
-            .update_resources_tracker(
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperContentRequest::with_default_parameters(&tracker.url)
+                            .set_delay(Duration::from_millis(2000)),
+                    )
+                    .unwrap(),
+                );
+            then.status(400)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&WebScraperErrorResponse {
+                    message: "some client-error".to_string(),
+                });
+        });
+
+        let scraper_error = web_scraping
+            .get_content_tracker_history(
                 tracker.id,
-                WebPageTrackerUpdateParams {
-                    name: Some("name_two".to_string()),
-                    ..Default::default()
+                WebPageContentTrackerGetHistoryParams {
+                    refresh: true,
+                    calculate_diff: false,
+                    diff_algorithm: Default::default(),
                 },
             )
-            .await?;
-        let expected_tracker = WebPageTracker {
-            name: "name_two".to_string(),
-            updated_at: updated_tracker.updated_at,
-            ..tracker.clone()
-        };
-        assert_eq!(expected_tracker, updated_tracker);
-        assert_eq!(
-            expected_tracker,
-            web_scraping
-                .get_resources_tracker(tracker.id)
-                .await?
-                .unwrap()
+            .await
+            .unwrap_err()
+            .downcast::<SecutilsError>()
+            .unwrap();
+        assert_eq!(scraper_error.status_code(), 400);
+        assert_debug_snapshot!(
+            scraper_error,
+            @r###""some client-error""###
         );
 
-        // Update URL.
-        let updated_tracker = web_scraping
-            .update_resources_tracker(
-                tracker.id,
-                WebPageTrackerUpdateParams {
-                    url: Some("http://localhost:1234/my/app?q=3".parse()?),
+        let tracker_content = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert!(tracker_content.is_empty());
+        content_mock.assert();
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_ignores_web_page_resources_with_the_same_timestamp(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
                     ..Default::default()
                 },
-            )
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
             .await?;
-        let expected_tracker = WebPageTracker {
-            name: "name_two".to_string(),
-            url: "http://localhost:1234/my/app?q=3".parse()?,
-            updated_at: updated_tracker.updated_at,
-            ..tracker.clone()
-        };
-        assert_eq!(expected_tracker, updated_tracker);
-        assert_eq!(
-            expected_tracker,
-            web_scraping
-                .get_resources_tracker(tracker.id)
-                .await?
-                .unwrap()
-        );
 
-        // Update settings.
-        let updated_tracker = web_scraping
-            .update_resources_tracker(
-                tracker.id,
-                WebPageTrackerUpdateParams {
-                    settings: Some(WebPageTrackerSettings {
-                        revisions: 4,
-                        ..tracker.settings.clone()
-                    }),
+        let tracker_resources = web_scraping
+            .get_resources_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert!(tracker_resources.is_empty());
+
+        let resources_one = get_resources(946720800, "rev_1")?;
+        let resources_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/resources")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperResourcesRequest::with_default_parameters(&tracker.url)
+                            .set_delay(Duration::from_millis(2000)),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&resources_one);
+        });
+
+        let diff = web_scraping
+            .create_resources_tracker_revision(tracker.id)
+            .await?;
+        assert!(diff.is_none());
+        resources_mock.assert_hits(1);
+
+        let diff = web_scraping
+            .create_resources_tracker_revision(tracker.id)
+            .await?;
+        assert!(diff.is_none());
+        resources_mock.assert_hits(2);
+
+        let tracker_resources = web_scraping
+            .get_resources_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(tracker_resources.len(), 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_ignores_web_page_content_with_the_same_timestamp(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
                     ..Default::default()
                 },
-            )
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
             .await?;
-        let expected_tracker = WebPageTracker {
-            name: "name_two".to_string(),
-            url: "http://localhost:1234/my/app?q=3".parse()?,
-            settings: WebPageTrackerSettings {
-                revisions: 4,
-                ..tracker.settings.clone()
-            },
-            updated_at: updated_tracker.updated_at,
-            ..tracker.clone()
-        };
-        assert_eq!(expected_tracker, updated_tracker);
-        assert_eq!(
-            expected_tracker,
-            web_scraping
-                .get_resources_tracker(tracker.id)
-                .await?
-                .unwrap()
-        );
 
-        // Update job config.
-        let updated_tracker = web_scraping
-            .update_resources_tracker(
-                tracker.id,
-                WebPageTrackerUpdateParams {
-                    job_config: Some(Some(SchedulerJobConfig {
-                        schedule: "@hourly".to_string(),
-                        retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                            interval: Duration::from_secs(120),
-                            max_attempts: 5,
-                        }),
-                        notifications: false,
-                    })),
-                    ..Default::default()
-                },
-            )
+        let tracker_content = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert!(tracker_content.is_empty());
+
+        let content_one = get_content(946720800, "\"rev_1\"")?;
+        let mut content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperContentRequest::with_default_parameters(&tracker.url)
+                            .set_delay(Duration::from_millis(2000)),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content_one);
+        });
+
+        let revision = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await?;
+        assert_eq!(revision.unwrap().data, "\"rev_1\"");
+        content_mock.assert_hits(1);
+
+        content_mock.delete();
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperContentRequest::with_default_parameters(&tracker.url)
+                            .set_delay(Duration::from_millis(2000))
+                            .set_previous_content("\"rev_1\""),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content_one);
+        });
+
+        let revision = web_scraping
+            .create_content_tracker_revision(tracker.id)
             .await?;
-        let expected_tracker = WebPageTracker {
-            name: "name_two".to_string(),
-            url: "http://localhost:1234/my/app?q=3".parse()?,
-            settings: WebPageTrackerSettings {
-                revisions: 4,
-                ..tracker.settings.clone()
-            },
-            job_config: Some(SchedulerJobConfig {
-                schedule: "@hourly".to_string(),
-                retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                    interval: Duration::from_secs(120),
-                    max_attempts: 5,
-                }),
-                notifications: false,
-            }),
-            updated_at: updated_tracker.updated_at,
-            ..tracker.clone()
-        };
-        assert_eq!(expected_tracker, updated_tracker);
-        assert_eq!(
-            expected_tracker,
-            web_scraping
-                .get_resources_tracker(tracker.id)
-                .await?
-                .unwrap()
-        );
+        assert!(revision.is_none());
+        content_mock.assert_hits(1);
 
-        // Remove job config.
-        let updated_tracker = web_scraping
-            .update_resources_tracker(
-                tracker.id,
-                WebPageTrackerUpdateParams {
-                    job_config: Some(None),
-                    ..Default::default()
-                },
-            )
+        let tracker_content = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
             .await?;
-        let expected_tracker = WebPageTracker {
-            name: "name_two".to_string(),
-            url: "http://localhost:1234/my/app?q=3".parse()?,
-            settings: WebPageTrackerSettings {
-                revisions: 4,
-                ..tracker.settings.clone()
-            },
-            job_config: None,
-            updated_at: updated_tracker.updated_at,
-            ..tracker.clone()
-        };
-        assert_eq!(expected_tracker, updated_tracker);
-        assert_eq!(
-            expected_tracker,
-            web_scraping
-                .get_resources_tracker(tracker.id)
-                .await?
-                .unwrap()
-        );
+        assert_eq!(tracker_content.len(), 1);
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_validates_web_page_resources_tracker_at_update(
-        pool: PgPool,
-    ) -> anyhow::Result<()> {
-        let api = mock_api(pool.clone()).await?;
+    async fn properly_ignores_web_page_resources_with_no_diff(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
 
-        let api = api.web_scraping(&mock_user);
-        let tracker = api
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
             .create_resources_tracker(WebPageTrackerCreateParams {
                 name: "name_one".to_string(),
-                url: Url::parse("https://secutils.dev")?,
+                url: Url::parse("https://secutils.dev/one")?,
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
-                    schedule: "@hourly".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(120),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
 
-        let update_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
-            result.unwrap_err().downcast::<SecutilsError>().unwrap()
-        };
+        let tracker_resources = web_scraping
+            .get_resources_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert!(tracker_resources.is_empty());
 
-        // Empty parameters.
-        let update_result = update_and_fail(
-            api.update_resources_tracker(tracker.id, Default::default())
-                .await,
-        );
-        assert_eq!(
-            update_result.to_string(),
-            format!(
-                "Either new name, url, settings, or job config should be provided ({}).",
-                tracker.id
-            )
-        );
+        let resources_one = get_resources(946720800, "rev_1")?;
+        let mut resources_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/resources")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperResourcesRequest::with_default_parameters(&tracker.url)
+                            .set_delay(Duration::from_millis(2000)),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&resources_one);
+        });
 
-        // Non-existent tracker.
-        let update_result = update_and_fail(
-            api.update_resources_tracker(
-                uuid!("00000000-0000-0000-0000-000000000002"),
-                WebPageTrackerUpdateParams {
-                    name: Some("name".to_string()),
-                    ..Default::default()
-                },
-            )
-            .await,
-        );
-        assert_eq!(
-            update_result.to_string(),
-            "Web page tracker ('00000000-0000-0000-0000-000000000002') is not found."
-        );
+        let diff = web_scraping
+            .create_resources_tracker_revision(tracker.id)
+            .await?;
+        assert!(diff.is_none());
+        resources_mock.assert();
+        resources_mock.delete();
 
-        // Empty name.
-        assert_debug_snapshot!(
-            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                name: Some("".to_string()),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker name cannot be empty.""###
-        );
+        let resources_two = get_resources(946720900, "rev_1")?;
+        let resources_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/resources")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperResourcesRequest::with_default_parameters(&tracker.url)
+                            .set_delay(Duration::from_millis(2000)),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&resources_two);
+        });
 
-        // Very long name.
-        assert_debug_snapshot!(
-            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                name: Some("a".repeat(101)),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker name cannot be longer than 100 characters.""###
-        );
+        let diff = web_scraping
+            .create_resources_tracker_revision(tracker.id)
+            .await?;
+        assert!(diff.is_none());
+        resources_mock.assert();
 
-        // Too many revisions.
-        assert_debug_snapshot!(
-            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                settings: Some(WebPageTrackerSettings {
-                    revisions: 31,
-                    ..tracker.settings.clone()
-                }),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker revisions count cannot be greater than 30.""###
-        );
+        let tracker_resources = web_scraping
+            .get_resources_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(tracker_resources.len(), 1);
 
-        // Too long delay.
-        assert_debug_snapshot!(
-            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                settings: Some(WebPageTrackerSettings {
-                    delay: Duration::from_secs(61),
-                    ..tracker.settings.clone()
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_ignores_changes_to_ignored_web_page_resources(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_resources_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ignore_resource_urls: Some(vec!["/ads_\\d+\\.js$".to_string()]),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
                 }),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker delay cannot be greater than 60000ms.""###
-        );
+            })
+            .await?;
 
-        // Empty resource filter.
-        assert_debug_snapshot!(
-            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                settings: Some(WebPageTrackerSettings {
-                    scripts: Some([(
-                        WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME.to_string(),
-                        "".to_string()
-                    )]
-                    .into_iter()
-                    .collect()),
-                   ..tracker.settings.clone()
-                }),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker scripts cannot be empty or have an empty name.""###
-        );
+        let mock_scraper_response = |response: &WebScraperResourcesResponse| {
+            server.mock(|when, then| {
+                when.method(httpmock::Method::POST)
+                    .path("/api/web_page/resources")
+                    .json_body(
+                        serde_json::to_value(
+                            WebScraperResourcesRequest::with_default_parameters(&tracker.url)
+                                .set_delay(Duration::from_millis(2000)),
+                        )
+                        .unwrap(),
+                    );
+                then.status(200)
+                    .header("Content-Type", "application/json")
+                    .json_body_obj(response);
+            })
+        };
 
-        // Unknown script.
-        assert_debug_snapshot!(
-            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                settings: Some(WebPageTrackerSettings {
-                    scripts: Some([(
-                        "someScript".to_string(),
-                        "return resource;".to_string()
-                    )]
-                    .into_iter()
-                    .collect()),
-                   ..tracker.settings.clone()
-                }),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker contains unrecognized scripts.""###
-        );
+        let mut resources_one = get_resources(946720800, "rev_1")?;
+        resources_one.scripts.push(WebScraperResource {
+            url: Some(Url::parse("http://localhost:1234/ads_1.js")?),
+            content: None,
+        });
+        let mut resources_mock = mock_scraper_response(&resources_one);
+        let diff = web_scraping
+            .create_resources_tracker_revision(tracker.id)
+            .await?;
+        assert!(diff.is_none());
+        resources_mock.assert();
+        resources_mock.delete();
 
-        // Invalid schedule.
-        assert_debug_snapshot!(
-            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "-".to_string(),
-                    retry_strategy: None,
-                    notifications: false,
-                })),
-                ..Default::default()
-            }).await),
-            @r###"
-        Error {
-            context: "Web page tracker schedule must be a valid cron expression.",
-            source: "Failed to parse schedule `-`: Error { kind: Expression(\"Invalid cron expression.\") }",
-        }
-        "###
-        );
+        // Only ignored resource has changed.
+        let mut resources_two = get_resources(946720900, "rev_1")?;
+        resources_two.scripts.push(WebScraperResource {
+            url: Some(Url::parse("http://localhost:1234/ads_2.js")?),
+            content: None,
+        });
+        let mut resources_mock = mock_scraper_response(&resources_two);
+        let diff = web_scraping
+            .create_resources_tracker_revision(tracker.id)
+            .await?;
+        assert!(diff.is_none());
+        resources_mock.assert();
+        resources_mock.delete();
 
-        // Invalid schedule interval.
-        assert_debug_snapshot!(
-            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "0/5 * * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: false,
-                })),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker schedule must have at least 10s between occurrences, but detected 5s.""###
-        );
+        let tracker_resources = web_scraping
+            .get_resources_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(tracker_resources.len(), 1);
 
-        // Too few retry attempts.
-        assert_debug_snapshot!(
-            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(120),
-                        max_attempts: 0,
-                    }),
-                    notifications: false,
-                })),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 0.""###
-        );
+        // Both ignored and not ignored resources have changed, full resource list should be stored.
+        let mut resources_three = get_resources(946721000, "rev_2")?;
+        resources_three.scripts.push(WebScraperResource {
+            url: Some(Url::parse("http://localhost:1234/ads_3.js")?),
+            content: None,
+        });
+        let resources_mock = mock_scraper_response(&resources_three);
+        let diff = web_scraping
+            .create_resources_tracker_revision(tracker.id)
+            .await?;
+        assert!(diff.is_some());
+        resources_mock.assert();
 
-        // Too many retry attempts.
-        assert_debug_snapshot!(
-            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(120),
-                        max_attempts: 11,
-                    }),
-                    notifications: false,
-                })),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 11.""###
+        let tracker_resources = web_scraping
+            .get_resources_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(tracker_resources.len(), 2);
+        assert_eq!(
+            tracker_resources[1]
+                .data
+                .scripts
+                .iter()
+                .map(|resource| resource.url.as_ref().map(|url| url.to_string()))
+                .collect::<Vec<_>>(),
+            vec![
+                Some("http://localhost:1234/script_rev_2.js".to_string()),
+                Some("http://localhost:1234/ads_3.js".to_string())
+            ]
         );
 
-        // Too low retry interval.
-        assert_debug_snapshot!(
-           update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(30),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                })),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker min retry interval cannot be less than 1m, but received 30s.""###
-        );
+        Ok(())
+    }
 
-        // Too low max retry interval.
-        assert_debug_snapshot!(
-            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
-                        initial_interval: Duration::from_secs(120),
-                        increment: Duration::from_secs(10),
-                        max_interval: Duration::from_secs(30),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                })),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker retry strategy max interval cannot be less than 1m, but received 30s.""###
-        );
+    #[sqlx::test]
+    async fn properly_tracks_web_page_status_changes(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
 
-        // Too high max retry interval.
-        assert_debug_snapshot!(
-            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "@monthly".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
-                        initial_interval: Duration::from_secs(120),
-                        increment: Duration::from_secs(10),
-                        max_interval: Duration::from_secs(13 * 3600),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                })),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker retry strategy max interval cannot be greater than 12h, but received 13h.""###
-        );
+        // Insert tracker directly to DB to bypass public URL validation.
+        let mut tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "name_one",
+            &server.url("/status"),
+            3,
+        )?
+        .build();
+        tracker.settings.status_only = true;
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
 
-        assert_debug_snapshot!(
-            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "@hourly".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
-                        initial_interval: Duration::from_secs(120),
-                        increment: Duration::from_secs(10),
-                        max_interval: Duration::from_secs(2 * 3600),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                })),
-               ..Default::default()
-            }).await),
-            @r###""Web page tracker retry strategy max interval cannot be greater than 1h, but received 2h.""###
-        );
+        let web_scraping = api.web_scraping(&mock_user);
 
-        // Invalid URL schema.
-        assert_debug_snapshot!(
-            update_and_fail(api.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                url: Some(Url::parse("ftp://secutils.dev")?),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received ftp://secutils.dev/.""###
+        let mut status_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/status");
+            then.status(200).body("body-that-is-not-stored");
+        });
+        let revision = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await?
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<WebPageContentStatus>(&revision.data)?.status,
+            200
         );
 
-        let api_with_local_network = mock_api_with_network(
-            pool,
-            mock_network_with_records::<1>(vec![Record::from_rdata(
-                Name::new(),
-                300,
-                RData::A(A(Ipv4Addr::new(127, 0, 0, 1))),
-            )]),
-        )
-        .await?;
+        // Stable status shouldn't produce a new revision.
+        let revision = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await?;
+        assert!(revision.is_none());
+        status_mock.assert_hits(2);
+        status_mock.delete();
 
-        // Non-public URL.
-        let web_scraping = api_with_local_network.web_scraping(&mock_user);
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_resources_tracker(tracker.id, WebPageTrackerUpdateParams {
-                url: Some(Url::parse("https://127.0.0.1")?),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received https://127.0.0.1/.""###
+        // Status transition should produce a new revision.
+        let status_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/status");
+            then.status(500);
+        });
+        let revision = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await?
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<WebPageContentStatus>(&revision.data)?.status,
+            500
+        );
+        status_mock.assert();
+
+        let tracker_content = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(tracker_content.len(), 2);
+        assert_eq!(
+            tracker_content
+                .iter()
+                .map(|revision| Ok(
+                    serde_json::from_str::<WebPageContentStatus>(&revision.data)?.status
+                ))
+                .collect::<anyhow::Result<Vec<_>>>()?,
+            vec![200, 500]
         );
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_updates_web_page_content_tracker(pool: PgPool) -> anyhow::Result<()> {
+    async fn properly_tracks_status_changes_of_multiple_urls(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
         let api = mock_api(pool).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
 
-        let api = api.web_scraping(&mock_user);
-        let tracker = api
-            .create_content_tracker(WebPageTrackerCreateParams {
-                name: "name_one".to_string(),
-                url: Url::parse("http://localhost:1234/my/app?q=2")?,
-                settings: WebPageTrackerSettings {
-                    revisions: 3,
-                    delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
-                },
-                job_config: None,
-            })
+        // Insert tracker directly to DB to bypass public URL validation.
+        let mut tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "name_one",
+            &server.url("/one"),
+            3,
+        )?
+        .build();
+        tracker.settings.status_only = true;
+        tracker.settings.additional_urls = Some(vec![
+            Url::parse(&server.url("/two"))?,
+            Url::parse(&server.url("/three"))?,
+        ]);
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
             .await?;
 
-        // Update name.
-        let updated_tracker = api
-            .update_content_tracker(
-                tracker.id,
-                WebPageTrackerUpdateParams {
-                    name: Some("name_two".to_string()),
-                    ..Default::default()
-                },
-            )
-            .await?;
-        let expected_tracker = WebPageTracker {
-            name: "name_two".to_string(),
-            updated_at: updated_tracker.updated_at,
-            ..tracker.clone()
-        };
-        assert_eq!(expected_tracker, updated_tracker);
+        let web_scraping = api.web_scraping(&mock_user);
+
+        let one_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/one");
+            then.status(200);
+        });
+        let mut two_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/two");
+            then.status(200);
+        });
+        let three_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/three");
+            then.status(404);
+        });
+        let first_revision = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await?
+            .unwrap();
+        let first_statuses = serde_json::from_str::<WebPageContentStatuses>(&first_revision.data)?;
         assert_eq!(
-            expected_tracker,
-            api.get_content_tracker(tracker.id).await?.unwrap()
+            first_statuses
+                .statuses
+                .iter()
+                .map(|(url, status)| (url.as_str(), status.status))
+                .collect::<Vec<_>>(),
+            vec![
+                (server.url("/one").as_str(), 200),
+                (server.url("/three").as_str(), 404),
+                (server.url("/two").as_str(), 200)
+            ]
         );
 
-        // Update URL.
-        let updated_tracker = api
-            .update_content_tracker(
-                tracker.id,
-                WebPageTrackerUpdateParams {
-                    url: Some("http://localhost:1234/my/app?q=3".parse()?),
-                    ..Default::default()
-                },
-            )
-            .await?;
-        let expected_tracker = WebPageTracker {
-            name: "name_two".to_string(),
-            url: "http://localhost:1234/my/app?q=3".parse()?,
-            updated_at: updated_tracker.updated_at,
-            ..tracker.clone()
-        };
-        assert_eq!(expected_tracker, updated_tracker);
+        // Stable statuses shouldn't produce a new revision.
+        assert!(web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await?
+            .is_none());
+        two_mock.assert_hits(2);
+        two_mock.delete();
+
+        // Status transition of any URL should produce a new revision.
+        let two_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/two");
+            then.status(503);
+        });
+        let revision = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await?
+            .unwrap();
         assert_eq!(
-            expected_tracker,
-            api.get_content_tracker(tracker.id).await?.unwrap()
+            serde_json::from_str::<WebPageContentStatuses>(&revision.data)?
+                .describe_changes(&first_statuses),
+            Some(format!("{}: 200 → 503", server.url("/two")))
         );
+        one_mock.assert_hits(3);
+        two_mock.assert();
+        three_mock.assert_hits(3);
 
-        // Update settings.
-        let updated_tracker = api
-            .update_content_tracker(
-                tracker.id,
-                WebPageTrackerUpdateParams {
-                    settings: Some(WebPageTrackerSettings {
-                        revisions: 4,
-                        ..tracker.settings.clone()
-                    }),
-                    ..Default::default()
-                },
-            )
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn respects_sub_fetch_concurrency_of_multiple_urls(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        // Insert tracker directly to DB to bypass public URL validation.
+        let mut tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "name_one",
+            &server.url("/one"),
+            3,
+        )?
+        .build();
+        tracker.settings.status_only = true;
+        tracker.settings.sub_fetch_concurrency = Some(2);
+        tracker.settings.additional_urls = Some(vec![
+            Url::parse(&server.url("/two"))?,
+            Url::parse(&server.url("/three"))?,
+            Url::parse(&server.url("/four"))?,
+        ]);
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
             .await?;
-        let expected_tracker = WebPageTracker {
-            name: "name_two".to_string(),
-            url: "http://localhost:1234/my/app?q=3".parse()?,
-            settings: WebPageTrackerSettings {
-                revisions: 4,
-                ..tracker.settings.clone()
-            },
-            updated_at: updated_tracker.updated_at,
-            ..tracker.clone()
-        };
-        assert_eq!(expected_tracker, updated_tracker);
+
+        let request_delay = Duration::from_millis(500);
+        let mocks = ["/one", "/two", "/three", "/four"].map(|path| {
+            server.mock(|when, then| {
+                when.method(httpmock::Method::GET).path(path);
+                then.status(200).delay(request_delay);
+            })
+        });
+
+        // Four URLs fetched two at a time take at least two request delays, but less than four.
+        let started_at = std::time::Instant::now();
+        let revision = api
+            .web_scraping(&mock_user)
+            .create_content_tracker_revision(tracker.id)
+            .await?
+            .unwrap();
+        let elapsed = started_at.elapsed();
+        assert!(elapsed >= request_delay * 2, "{elapsed:?}");
+        assert!(elapsed < request_delay * 4, "{elapsed:?}");
+
         assert_eq!(
-            expected_tracker,
-            api.get_content_tracker(tracker.id).await?.unwrap()
+            serde_json::from_str::<WebPageContentStatuses>(&revision.data)?
+                .statuses
+                .len(),
+            4
         );
+        for mock in mocks {
+            mock.assert();
+        }
 
-        // Update job config.
-        let updated_tracker = api
-            .update_content_tracker(
-                tracker.id,
-                WebPageTrackerUpdateParams {
-                    job_config: Some(Some(SchedulerJobConfig {
-                        schedule: "@hourly".to_string(),
-                        retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                            interval: Duration::from_secs(120),
-                            max_attempts: 5,
-                        }),
-                        notifications: false,
-                    })),
-                    ..Default::default()
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_diffs_latest_revisions_of_content_trackers(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping_db = api.db.web_scraping(mock_user.id);
+        let mut trackers = vec![];
+        for (index, name) in ["prod", "staging", "empty"].into_iter().enumerate() {
+            let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+                Uuid::from_u128(index as u128 + 1),
+                name,
+                "https://secutils.dev",
+                3,
+            )?
+            .build();
+            web_scraping_db.insert_web_page_tracker(&tracker).await?;
+            trackers.push(tracker);
+        }
+        let resources_tracker = MockWebPageTrackerBuilder::<WebPageResourcesTrackerTag>::create(
+            Uuid::from_u128(4),
+            "resources",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        web_scraping_db
+            .insert_web_page_tracker(&resources_tracker)
+            .await?;
+
+        for (index, (tracker_id, data, timestamp)) in [
+            (trackers[0].id, "\"Hello World\"", 946720800),
+            (trackers[0].id, "\"Hello New World\"", 946720900),
+            (trackers[1].id, "\"Hello Old World\"", 946720800),
+            (trackers[1].id, "\"Hello Staging World\"", 946720850),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            web_scraping_db
+                .insert_web_page_tracker_history_revision::<WebPageContentTrackerTag>(
+                    &WebPageDataRevision {
+                        id: Uuid::from_u128(index as u128 + 10),
+                        tracker_id,
+                        data: data.to_string(),
+                        created_at: OffsetDateTime::from_unix_timestamp(timestamp)?,
+                        note: None,
+                        acknowledgment: None,
+                        diff_stats: None,
+                        assertions_passed: None,
+                    },
+                )
+                .await?;
+        }
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let diff = web_scraping
+            .diff_content_trackers(
+                trackers[0].id,
+                WebPageTrackersDiffParams {
+                    tracker_id: trackers[1].id,
+                    diff_algorithm: Default::default(),
                 },
             )
-            .await?;
-        let expected_tracker = WebPageTracker {
-            name: "name_two".to_string(),
-            url: "http://localhost:1234/my/app?q=3".parse()?,
-            settings: WebPageTrackerSettings {
-                revisions: 4,
-                ..tracker.settings.clone()
-            },
-            job_config: Some(SchedulerJobConfig {
-                schedule: "@hourly".to_string(),
-                retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                    interval: Duration::from_secs(120),
-                    max_attempts: 5,
-                }),
-                notifications: false,
-            }),
-            updated_at: updated_tracker.updated_at,
-            ..tracker.clone()
-        };
-        assert_eq!(expected_tracker, updated_tracker);
+            .await?
+            .unwrap();
+        assert_eq!(diff.id, Uuid::from_u128(13));
+        assert_eq!(diff.tracker_id, trackers[1].id);
         assert_eq!(
-            expected_tracker,
-            api.get_content_tracker(tracker.id).await?.unwrap()
+            diff.data,
+            "@@ -1 +1 @@\n-Hello New World\n+Hello Staging World\n"
         );
 
-        // Remove job config.
-        let updated_tracker = api
-            .update_content_tracker(
-                tracker.id,
-                WebPageTrackerUpdateParams {
-                    job_config: Some(None),
-                    ..Default::default()
+        let diff = web_scraping
+            .diff_content_trackers(
+                trackers[1].id,
+                WebPageTrackersDiffParams {
+                    tracker_id: trackers[0].id,
+                    diff_algorithm: Default::default(),
                 },
             )
-            .await?;
-        let expected_tracker = WebPageTracker {
-            name: "name_two".to_string(),
-            url: "http://localhost:1234/my/app?q=3".parse()?,
-            settings: WebPageTrackerSettings {
-                revisions: 4,
-                ..tracker.settings.clone()
-            },
-            job_config: None,
-            updated_at: updated_tracker.updated_at,
-            ..tracker.clone()
-        };
-        assert_eq!(expected_tracker, updated_tracker);
+            .await?
+            .unwrap();
+        assert_eq!(diff.id, Uuid::from_u128(11));
         assert_eq!(
-            expected_tracker,
-            api.get_content_tracker(tracker.id).await?.unwrap()
+            diff.data,
+            "@@ -1 +1 @@\n-Hello Staging World\n+Hello New World\n"
         );
 
+        // Nothing to compare if any of the trackers doesn't have revisions yet.
+        assert!(web_scraping
+            .diff_content_trackers(
+                trackers[0].id,
+                WebPageTrackersDiffParams {
+                    tracker_id: trackers[2].id,
+                    diff_algorithm: Default::default(),
+                },
+            )
+            .await?
+            .is_none());
+
+        for (tracker_a, tracker_b, error) in [
+            (
+                trackers[0].id,
+                trackers[0].id,
+                "Web page tracker ('00000000-0000-0000-0000-000000000001') cannot be compared with itself.",
+            ),
+            (
+                trackers[0].id,
+                resources_tracker.id,
+                "Web page tracker ('00000000-0000-0000-0000-000000000004') is not found.",
+            ),
+            (
+                Uuid::from_u128(100),
+                trackers[0].id,
+                "Web page tracker ('00000000-0000-0000-0000-000000000064') is not found.",
+            ),
+        ] {
+            let diff_result = web_scraping
+                .diff_content_trackers(
+                    tracker_a,
+                    WebPageTrackersDiffParams {
+                        tracker_id: tracker_b,
+                        diff_algorithm: Default::default(),
+                    },
+                )
+                .await;
+            assert_eq!(
+                diff_result.unwrap_err().downcast::<SecutilsError>()?.to_string(),
+                error
+            );
+        }
+
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_validates_web_page_content_tracker_at_update(
-        pool: PgPool,
-    ) -> anyhow::Result<()> {
-        let api = mock_api(pool.clone()).await?;
+    async fn properly_returns_changes_feed(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
 
-        let web_scraping = api.web_scraping(&mock_user);
-        let tracker = web_scraping
-            .create_content_tracker(WebPageTrackerCreateParams {
-                name: "name_one".to_string(),
-                url: Url::parse("https://secutils.dev")?,
-                settings: WebPageTrackerSettings {
-                    revisions: 3,
-                    delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+        let web_scraping_db = api.db.web_scraping(mock_user.id);
+        let mut trackers = vec![];
+        for (index, name) in ["one", "two"].into_iter().enumerate() {
+            let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+                Uuid::from_u128(index as u128 + 1),
+                name,
+                &format!("https://secutils.dev/{name}"),
+                3,
+            )?
+            .build();
+            web_scraping_db.insert_web_page_tracker(&tracker).await?;
+            trackers.push(tracker);
+        }
+        let resources_tracker = MockWebPageTrackerBuilder::<WebPageResourcesTrackerTag>::create(
+            Uuid::from_u128(3),
+            "three",
+            "https://secutils.dev/three",
+            3,
+        )?
+        .build();
+        web_scraping_db
+            .insert_web_page_tracker(&resources_tracker)
+            .await?;
+
+        for (index, (tracker_id, timestamp)) in [
+            (trackers[0].id, 946720800),
+            (trackers[1].id, 946720850),
+            (trackers[0].id, 946720900),
+            (trackers[1].id, 946721000),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            web_scraping_db
+                .insert_web_page_tracker_history_revision::<WebPageContentTrackerTag>(
+                    &WebPageDataRevision {
+                        id: Uuid::from_u128(index as u128 + 10),
+                        tracker_id,
+                        data: format!("\"rev_{index}\""),
+                        created_at: OffsetDateTime::from_unix_timestamp(timestamp)?,
+                        note: None,
+                        acknowledgment: None,
+                        diff_stats: None,
+                        assertions_passed: None,
+                    },
+                )
+                .await?;
+        }
+        web_scraping_db
+            .insert_web_page_tracker_history_revision::<WebPageResourcesTrackerTag>(
+                &WebPageDataRevision {
+                    id: Uuid::from_u128(20),
+                    tracker_id: resources_tracker.id,
+                    data: WebPageResourcesData {
+                        scripts: vec![],
+                        styles: vec![],
+                        categories: None,
+                        sizes: None,
+                        truncated: false,
+                    },
+                    created_at: OffsetDateTime::from_unix_timestamp(946720950)?,
+                    note: Some("Expected change".to_string()),
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
                 },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "@hourly".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(120),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                }),
+            )
+            .await?;
+
+        // Changes of all trackers are merged and ordered from the newest to the oldest.
+        let web_scraping = api.web_scraping(&mock_user);
+        let feed = web_scraping.get_changes_feed(Default::default()).await?;
+        assert_eq!(
+            feed.iter()
+                .map(|change| (
+                    change.revision_id,
+                    change.tracker_name.as_str(),
+                    change.created_at.unix_timestamp(),
+                    change.summary.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    Uuid::from_u128(13),
+                    "two",
+                    946721000,
+                    "Web page content has changed."
+                ),
+                (
+                    Uuid::from_u128(20),
+                    "three",
+                    946720950,
+                    "Initial web page resources captured."
+                ),
+                (
+                    Uuid::from_u128(12),
+                    "one",
+                    946720900,
+                    "Web page content has changed."
+                ),
+                (
+                    Uuid::from_u128(11),
+                    "two",
+                    946720850,
+                    "Initial web page content captured."
+                ),
+                (
+                    Uuid::from_u128(10),
+                    "one",
+                    946720800,
+                    "Initial web page content captured."
+                ),
+            ]
+        );
+        assert_eq!(feed[1].tracker_id, resources_tracker.id);
+        assert_eq!(feed[1].tracker_kind, WebPageTrackerKind::WebPageResources);
+        assert_eq!(feed[1].url, resources_tracker.url);
+        assert_eq!(feed[1].note.as_deref(), Some("Expected change"));
+
+        // Only changes detected after `since` are returned.
+        let feed = web_scraping
+            .get_changes_feed(WebPageTrackersGetChangesFeedParams {
+                since: Some(OffsetDateTime::from_unix_timestamp(946720900)?),
+                limit: None,
+            })
+            .await?;
+        assert_eq!(
+            feed.iter()
+                .map(|change| change.revision_id)
+                .collect::<Vec<_>>(),
+            vec![Uuid::from_u128(13), Uuid::from_u128(20)]
+        );
+
+        // Only the most recent changes are returned if limit is specified.
+        let feed = web_scraping
+            .get_changes_feed(WebPageTrackersGetChangesFeedParams {
+                since: None,
+                limit: Some(2),
             })
             .await?;
+        assert_eq!(
+            feed.iter()
+                .map(|change| change.revision_id)
+                .collect::<Vec<_>>(),
+            vec![Uuid::from_u128(13), Uuid::from_u128(20)]
+        );
 
-        let update_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
-            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        // Changes of other users' trackers aren't included.
+        let another_user = mock_user_with_id(uuid!("00000000-0000-0000-0000-000000000002"))?;
+        api.db.insert_user(&another_user).await?;
+        assert!(api
+            .web_scraping(&another_user)
+            .get_changes_feed(Default::default())
+            .await?
+            .is_empty());
+
+        for limit in [0, 501] {
+            assert_eq!(
+                web_scraping
+                    .get_changes_feed(WebPageTrackersGetChangesFeedParams {
+                        since: None,
+                        limit: Some(limit),
+                    })
+                    .await
+                    .unwrap_err()
+                    .downcast::<SecutilsError>()?
+                    .to_string(),
+                format!("Changes feed limit should be between 1 and 500, but received {limit}.")
+            );
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_simulates_content_diff(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let simulate_diff = |diff_algorithm| WebPageContentSimulateDiffParams {
+            previous_content: "<ul><li>One</li><li>Two</li></ul>".to_string(),
+            current_content: "<ul><li>One</li><li class=\"new\">Three</li></ul>".to_string(),
+            diff_algorithm,
         };
 
-        // Empty parameters.
-        let update_result = update_and_fail(
-            web_scraping
-                .update_content_tracker(tracker.id, Default::default())
-                .await,
+        let lines_diff = web_scraping
+            .simulate_content_diff(simulate_diff(WebPageContentDiffAlgorithm::Lines))
+            .await?
+            .unwrap();
+        assert_eq!(
+            lines_diff.diff,
+            "@@ -1 +1 @@\n-<ul><li>One</li><li>Two</li></ul>\n+<ul><li>One</li><li class=\"new\">Three</li></ul>\n"
         );
         assert_eq!(
-            update_result.to_string(),
-            format!(
-                "Either new name, url, settings, or job config should be provided ({}).",
-                tracker.id
-            )
+            lines_diff.notification.subject,
+            "[Secutils.dev] Change detected: \"Simulated tracker\""
         );
+        assert!(lines_diff.notification.webhook_payload.is_none());
 
-        // Non-existent tracker.
-        let update_result = update_and_fail(
-            web_scraping
-                .update_content_tracker(
-                    uuid!("00000000-0000-0000-0000-000000000002"),
-                    WebPageTrackerUpdateParams {
-                        name: Some("name".to_string()),
-                        ..Default::default()
-                    },
-                )
-                .await,
+        // Diff stats and notification don't depend on the algorithm, only the diff itself does.
+        let dom_diff = web_scraping
+            .simulate_content_diff(simulate_diff(WebPageContentDiffAlgorithm::Dom))
+            .await?
+            .unwrap();
+        assert_eq!(
+            dom_diff.diff,
+            "[{\"type\":\"changed\",\"path\":\"/ul[1]/li[2]\",\"before\":\"<li>\",\"after\":\"<li class=\\\"new\\\">\"},{\"type\":\"changed\",\"path\":\"/ul[1]/li[2]/text()[1]\",\"before\":\"Two\",\"after\":\"Three\"}]"
         );
         assert_eq!(
-            update_result.to_string(),
-            "Web page tracker ('00000000-0000-0000-0000-000000000002') is not found."
+            dom_diff.diff_stats,
+            WebPageDataRevisionDiffStats {
+                added_lines: 1,
+                removed_lines: 1,
+                changed_bytes: 80,
+            }
         );
-
-        // Empty name.
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                name: Some("".to_string()),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker name cannot be empty.""###
+        assert_eq!(dom_diff.diff_stats, lines_diff.diff_stats);
+        assert_eq!(dom_diff.notification, lines_diff.notification);
+
+        // DOM diff falls back to the line diff for non-HTML content, valid JSON is used as is.
+        let json_diff = |diff_algorithm| {
+            web_scraping.simulate_content_diff(WebPageContentSimulateDiffParams {
+                previous_content: r#"{"version":1}"#.to_string(),
+                current_content: r#"{"version":2}"#.to_string(),
+                diff_algorithm,
+            })
+        };
+        let lines_json_diff = json_diff(WebPageContentDiffAlgorithm::Lines)
+            .await?
+            .unwrap();
+        assert_eq!(
+            lines_json_diff.diff,
+            "@@ -1,3 +1,3 @@\n {\n-  \"version\": 1\n+  \"version\": 2\n }\n"
         );
-
-        // Very long name.
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                name: Some("a".repeat(101)),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker name cannot be longer than 100 characters.""###
+        assert_eq!(
+            json_diff(WebPageContentDiffAlgorithm::Dom).await?.unwrap(),
+            lines_json_diff
         );
 
-        // Too many revisions.
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                settings: Some(WebPageTrackerSettings {
-                    revisions: 31,
-                    ..tracker.settings.clone()
-                }),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker revisions count cannot be greater than 30.""###
-        );
+        // Same content doesn't produce any diff.
+        assert!(web_scraping
+            .simulate_content_diff(WebPageContentSimulateDiffParams {
+                previous_content: "Hello World".to_string(),
+                current_content: "Hello World".to_string(),
+                diff_algorithm: WebPageContentDiffAlgorithm::Lines,
+            })
+            .await?
+            .is_none());
 
-        // Too long delay.
+        // Content size is limited.
         assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                settings: Some(WebPageTrackerSettings {
-                    delay: Duration::from_secs(61),
-                    ..tracker.settings.clone()
-                }),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker delay cannot be greater than 60000ms.""###
+            web_scraping
+                .simulate_content_diff(WebPageContentSimulateDiffParams {
+                    previous_content: "a".repeat(1024 * 1024),
+                    current_content: "b".to_string(),
+                    diff_algorithm: WebPageContentDiffAlgorithm::Lines,
+                })
+                .await
+                .unwrap_err()
+                .downcast::<SecutilsError>()?
+                .to_string(),
+            @r###""Web page content to simulate diff for cannot be larger than 1048576 bytes.""###
         );
 
-        // Empty resource filter.
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                settings: Some(WebPageTrackerSettings {
-                    scripts: Some([(
-                        WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME.to_string(),
-                        "".to_string()
-                    )]
-                    .into_iter()
-                    .collect()),
-                   ..tracker.settings.clone()
-                }),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker scripts cannot be empty or have an empty name.""###
-        );
+        Ok(())
+    }
 
-        // Unknown script.
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                settings: Some(WebPageTrackerSettings {
-                    scripts: Some([(
-                        "someScript".to_string(),
-                        "return resource;".to_string()
-                    )]
-                    .into_iter()
-                    .collect()),
-                   ..tracker.settings.clone()
-                }),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker contains unrecognized scripts.""###
-        );
+    #[sqlx::test]
+    async fn properly_manages_and_renders_status_pages(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
 
-        // Invalid schedule.
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "-".to_string(),
-                    retry_strategy: None,
-                    notifications: false,
-                })),
-                ..Default::default()
-            }).await),
-            @r###"
-        Error {
-            context: "Web page tracker schedule must be a valid cron expression.",
-            source: "Failed to parse schedule `-`: Error { kind: Expression(\"Invalid cron expression.\") }",
+        // Insert trackers and their history directly to DB to bypass public URL validation.
+        let web_scraping_db = api.db.web_scraping(mock_user.id);
+        let mut trackers = vec![];
+        for (index, (name, status_only)) in [("api", true), ("web", true), ("content", false)]
+            .into_iter()
+            .enumerate()
+        {
+            let mut tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+                Uuid::from_u128(index as u128 + 1),
+                name,
+                "https://secutils.dev",
+                3,
+            )?
+            .build();
+            tracker.settings.status_only = status_only;
+            web_scraping_db.insert_web_page_tracker(&tracker).await?;
+            trackers.push(tracker);
         }
-        "###
-        );
-
-        // Invalid schedule interval.
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "0/5 * * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: false,
-                })),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker schedule must have at least 10s between occurrences, but detected 5s.""###
-        );
-
-        // Too few retry attempts.
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(120),
-                        max_attempts: 0,
-                    }),
-                    notifications: false,
-                })),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 0.""###
-        );
-
-        // Too many retry attempts.
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(120),
-                        max_attempts: 11,
-                    }),
-                    notifications: false,
-                })),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker max retry attempts cannot be zero or greater than 10, but received 11.""###
-        );
-
-        // Too low retry interval.
-        assert_debug_snapshot!(
-           update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
-                        interval: Duration::from_secs(30),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                })),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker min retry interval cannot be less than 1m, but received 30s.""###
-        );
 
-        // Too low max retry interval.
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "@daily".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
-                        initial_interval: Duration::from_secs(120),
-                        increment: Duration::from_secs(10),
-                        max_interval: Duration::from_secs(30),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                })),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker retry strategy max interval cannot be less than 1m, but received 30s.""###
-        );
+        for (index, (tracker_id, status, timestamp)) in [
+            (trackers[0].id, 200, 946720800),
+            (trackers[0].id, 503, 946720900),
+            (trackers[1].id, 301, 946720800),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            web_scraping_db
+                .insert_web_page_tracker_history_revision::<WebPageContentTrackerTag>(
+                    &WebPageDataRevision {
+                        id: Uuid::from_u128(index as u128 + 10),
+                        tracker_id,
+                        data: serde_json::to_string(&WebPageContentStatus {
+                            status,
+                            latency: Duration::from_millis(100),
+                        })?,
+                        created_at: OffsetDateTime::from_unix_timestamp(timestamp)?,
+                        note: None,
+                        acknowledgment: None,
+                        diff_stats: None,
+                        assertions_passed: None,
+                    },
+                )
+                .await?;
+        }
 
-        // Too high max retry interval.
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "@monthly".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
-                        initial_interval: Duration::from_secs(120),
-                        increment: Duration::from_secs(10),
-                        max_interval: Duration::from_secs(13 * 3600),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                })),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker retry strategy max interval cannot be greater than 12h, but received 13h.""###
+        let web_scraping = api.web_scraping(&mock_user);
+        for (name, tracker_ids, error) in [
+            ("", vec![trackers[0].id], "Status page name cannot be empty."),
+            (
+                "Status",
+                vec![],
+                "Status page should display between 1 and 20 trackers.",
+            ),
+            (
+                "Status",
+                vec![trackers[0].id, trackers[0].id],
+                "Status page tracker ('00000000-0000-0000-0000-000000000001') is specified more than once.",
+            ),
+            (
+                "Status",
+                vec![Uuid::from_u128(100)],
+                "Status page tracker ('00000000-0000-0000-0000-000000000064') is not found.",
+            ),
+            (
+                "Status",
+                vec![trackers[2].id],
+                "Status page tracker ('content') must be a content tracker in status-only mode.",
+            ),
+        ] {
+            let create_result = web_scraping
+                .create_status_page(WebPageStatusPageCreateParams {
+                    name: name.to_string(),
+                    tracker_ids,
+                })
+                .await;
+            assert_eq!(
+                create_result
+                    .unwrap_err()
+                    .downcast::<SecutilsError>()?
+                    .to_string(),
+                error
+            );
+        }
+
+        let status_page = web_scraping
+            .create_status_page(WebPageStatusPageCreateParams {
+                name: "Status".to_string(),
+                tracker_ids: vec![trackers[1].id, trackers[0].id],
+            })
+            .await?;
+        assert_eq!(status_page.token.len(), 32);
+        assert!(status_page
+            .token
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric()));
+        assert_eq!(
+            web_scraping.get_status_pages().await?,
+            vec![status_page.clone()]
+        );
+        assert_eq!(
+            api.web_scraping_system()
+                .get_status_page_by_token(&status_page.token)
+                .await?,
+            Some((mock_user.id, status_page.clone()))
         );
 
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                job_config: Some(Some(SchedulerJobConfig {
-                    schedule: "@hourly".to_string(),
-                    retry_strategy: Some(SchedulerJobRetryStrategy::Linear {
-                        initial_interval: Duration::from_secs(120),
-                        increment: Duration::from_secs(10),
-                        max_interval: Duration::from_secs(2 * 3600),
-                        max_attempts: 5,
-                    }),
-                    notifications: false,
-                })),
-               ..Default::default()
-            }).await),
-            @r###""Web page tracker retry strategy max interval cannot be greater than 1h, but received 2h.""###
+        // Trackers should be rendered in the order selected by the user.
+        let html = web_scraping.render_status_page(&status_page).await?;
+        assert!(html.contains("<title>Status</title>"));
+        assert!(html.find("<h2>web</h2>").unwrap() < html.find("<h2>api</h2>").unwrap());
+        assert!(html.contains("Up (HTTP 301) since 2000-01-01T10:00:00Z"));
+        assert!(html.contains("Down (HTTP 503) since 2000-01-01T10:01:40Z"));
+        assert!(html.contains("<td>200</td>"));
+
+        let update_result = web_scraping
+            .update_status_page(status_page.id, Default::default())
+            .await;
+        assert_eq!(
+            update_result
+                .unwrap_err()
+                .downcast::<SecutilsError>()?
+                .to_string(),
+            format!(
+                "Either new name, tracker IDs, or token regeneration should be provided ({}).",
+                status_page.id
+            )
         );
 
-        // Invalid URL schema.
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                url: Some(Url::parse("ftp://secutils.dev")?),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received ftp://secutils.dev/.""###
+        let updated_status_page = web_scraping
+            .update_status_page(
+                status_page.id,
+                WebPageStatusPageUpdateParams {
+                    name: Some("New status".to_string()),
+                    tracker_ids: None,
+                    regenerate_token: true,
+                },
+            )
+            .await?;
+        assert_eq!(updated_status_page.name, "New status");
+        assert_ne!(updated_status_page.token, status_page.token);
+        assert_eq!(updated_status_page.tracker_ids, status_page.tracker_ids);
+
+        // Status page shouldn't be accessible with the old token anymore.
+        let web_scraping_system = api.web_scraping_system();
+        assert!(web_scraping_system
+            .get_status_page_by_token(&status_page.token)
+            .await?
+            .is_none());
+        assert_eq!(
+            web_scraping_system
+                .get_status_page_by_token(&updated_status_page.token)
+                .await?,
+            Some((mock_user.id, updated_status_page.clone()))
         );
 
-        let api_with_local_network = mock_api_with_network(
-            pool,
-            mock_network_with_records::<1>(vec![Record::from_rdata(
-                Name::new(),
-                300,
-                RData::A(A(Ipv4Addr::new(127, 0, 0, 1))),
-            )]),
-        )
-        .await?;
+        // Removed trackers should be skipped.
+        web_scraping.remove_web_page_tracker(trackers[1].id).await?;
+        let html = web_scraping
+            .render_status_page(&updated_status_page)
+            .await?;
+        assert!(html.contains("<title>New status</title>"));
+        assert!(!html.contains("<h2>web</h2>"));
+        assert!(html.contains("<h2>api</h2>"));
 
-        // Non-public URL.
-        let web_scraping = api_with_local_network.web_scraping(&mock_user);
-        assert_debug_snapshot!(
-            update_and_fail(web_scraping.update_content_tracker(tracker.id, WebPageTrackerUpdateParams {
-                url: Some(Url::parse("https://127.0.0.1")?),
-                ..Default::default()
-            }).await),
-            @r###""Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received https://127.0.0.1/.""###
+        web_scraping.remove_status_page(status_page.id).await?;
+        assert!(web_scraping.get_status_pages().await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_tracks_web_page_response_changes(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        // Insert tracker directly to DB to bypass public URL validation.
+        let mut tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "name_one",
+            &server.url("/api"),
+            5,
+        )?
+        .build();
+        tracker.settings.capture_response = Some(WebPageContentResponseCapture {
+            headers: vec!["ETag".to_string()],
+        });
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let mut previous_response = None;
+        for (status, etag, body, changed_parts) in [
+            (200, "v1", r#"{"version":1}"#, None),
+            // Nothing has changed.
+            (200, "v1", r#"{"version":1}"#, Some(vec![])),
+            // Only status has changed.
+            (
+                503,
+                "v1",
+                r#"{"version":1}"#,
+                Some(vec![WebPageContentResponsePart::Status]),
+            ),
+            // Only headers have changed.
+            (
+                503,
+                "v2",
+                r#"{"version":1}"#,
+                Some(vec![WebPageContentResponsePart::Headers]),
+            ),
+            // Only body has changed.
+            (
+                503,
+                "v2",
+                r#"{"version":2}"#,
+                Some(vec![WebPageContentResponsePart::Body]),
+            ),
+            // Everything has changed.
+            (
+                200,
+                "v3",
+                r#"{"version":3}"#,
+                Some(vec![
+                    WebPageContentResponsePart::Status,
+                    WebPageContentResponsePart::Headers,
+                    WebPageContentResponsePart::Body,
+                ]),
+            ),
+        ] {
+            let mut api_mock = server.mock(|when, then| {
+                when.method(httpmock::Method::GET).path("/api");
+                then.status(status)
+                    .header("ETag", etag)
+                    .header("X-Request-Id", "not-captured")
+                    .body(body);
+            });
+
+            let revision = web_scraping
+                .create_content_tracker_revision(tracker.id)
+                .await?;
+            api_mock.assert();
+            api_mock.delete();
+
+            let response = WebPageContentResponse {
+                status,
+                headers: [("etag".to_string(), etag.to_string())]
+                    .into_iter()
+                    .collect(),
+                body: serde_json::from_str(body)?,
+            };
+            if let (Some(previous_response), Some(changed_parts)) =
+                (previous_response.as_ref(), changed_parts)
+            {
+                assert_eq!(response.changed_parts(previous_response), changed_parts);
+                if changed_parts.is_empty() {
+                    assert!(revision.is_none());
+                    continue;
+                }
+            }
+
+            assert_eq!(
+                serde_json::from_str::<WebPageContentResponse>(&revision.unwrap().data)?,
+                response
+            );
+            previous_response = Some(response);
+        }
+
+        let tracker_content = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(
+            tracker_content
+                .iter()
+                .map(|revision| Ok(
+                    serde_json::from_str::<WebPageContentResponse>(&revision.data)?.status
+                ))
+                .collect::<anyhow::Result<Vec<_>>>()?,
+            vec![200, 503, 503, 503, 200]
         );
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_updates_web_page_resources_job_id_at_update(
+    async fn properly_ignores_web_page_content_with_no_diff(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            })
+            .await?;
+
+        let tracker_content = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert!(tracker_content.is_empty());
+
+        let content_one = get_content(946720800, "\"rev_1\"")?;
+        let mut content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperContentRequest::with_default_parameters(&tracker.url)
+                            .set_delay(Duration::from_millis(2000)),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content_one);
+        });
+
+        let revision = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await?;
+        assert_eq!(revision.unwrap().data, "\"rev_1\"");
+        content_mock.assert();
+        content_mock.delete();
+
+        let content_two = get_content(946720900, "\"rev_1\"")?;
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperContentRequest::with_default_parameters(&tracker.url)
+                            .set_delay(Duration::from_millis(2000))
+                            .set_previous_content("\"rev_1\""),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content_two);
+        });
+
+        let revision = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await?;
+        assert!(revision.is_none());
+        content_mock.assert();
+
+        let tracker_content = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(tracker_content.len(), 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_ignores_web_page_content_matching_recent_revisions(
         pool: PgPool,
     ) -> anyhow::Result<()> {
-        let api = mock_api(pool).await?;
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
 
         let web_scraping = api.web_scraping(&mock_user);
         let tracker = web_scraping
-            .create_resources_tracker(WebPageTrackerCreateParams {
+            .create_content_tracker(WebPageTrackerCreateParams {
                 name: "name_one".to_string(),
-                url: Url::parse("https://secutils.dev")?,
+                url: Url::parse("https://secutils.dev/one")?,
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    dedup_window: Some(2),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
 
-        // Set job ID.
-        api.web_scraping_system()
-            .update_web_page_tracker_job(
-                tracker.id,
-                Some(uuid!("00000000-0000-0000-0000-000000000001")),
-            )
+        // Content flaps between two states: A -> B -> A.
+        for (timestamp, content) in [
+            (946720800, "\"rev_a\""),
+            (946720900, "\"rev_b\""),
+            (946721000, "\"rev_a\""),
+        ] {
+            let scraper_content = get_content(timestamp, content)?;
+            let mut content_mock = server.mock(|when, then| {
+                when.method(httpmock::Method::POST)
+                    .path("/api/web_page/content");
+                then.status(200)
+                    .header("Content-Type", "application/json")
+                    .json_body_obj(&scraper_content);
+            });
+
+            web_scraping
+                .create_content_tracker_revision(tracker.id)
+                .await?;
+            content_mock.assert();
+            content_mock.delete();
+        }
+
+        let tracker_content = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
             .await?;
         assert_eq!(
-            Some(uuid!("00000000-0000-0000-0000-000000000001")),
-            web_scraping
-                .get_resources_tracker(tracker.id)
-                .await?
-                .unwrap()
-                .job_id
+            tracker_content
+                .into_iter()
+                .map(|revision| revision.data)
+                .collect::<Vec<_>>(),
+            vec!["\"rev_a\"".to_string(), "\"rev_b\"".to_string()]
         );
 
-        let updated_tracker = web_scraping
-            .update_resources_tracker(
-                tracker.id,
-                WebPageTrackerUpdateParams {
-                    name: Some("name_two".to_string()),
-                    ..Default::default()
-                },
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_redacts_web_page_content(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        api.users()
+            .set_data(
+                UserDataNamespace::RedactionPatterns,
+                UserData::new(
+                    mock_user.id,
+                    serde_json::to_vec(&["token=[a-z0-9]+"])?,
+                    OffsetDateTime::now_utc(),
+                ),
             )
             .await?;
-        let expected_tracker = WebPageTracker {
-            name: "name_two".to_string(),
-            job_id: Some(uuid!("00000000-0000-0000-0000-000000000001")),
-            updated_at: updated_tracker.updated_at,
-            ..tracker.clone()
-        };
-        assert_eq!(expected_tracker, updated_tracker);
-        assert_eq!(
-            expected_tracker,
-            web_scraping
-                .get_resources_tracker(tracker.id)
-                .await?
-                .unwrap()
-        );
 
-        // Change in schedule will reset job ID.
-        let updated_tracker = web_scraping
-            .update_resources_tracker(
-                tracker.id,
-                WebPageTrackerUpdateParams {
-                    job_config: Some(Some(SchedulerJobConfig {
-                        schedule: "0 1 * * * *".to_string(),
-                        retry_strategy: None,
-                        notifications: true,
-                    })),
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
                     ..Default::default()
                 },
-            )
+                job_config: None,
+            })
             .await?;
-        let expected_tracker = WebPageTracker {
-            name: "name_two".to_string(),
-            job_id: None,
-            job_config: Some(SchedulerJobConfig {
-                schedule: "0 1 * * * *".to_string(),
-                retry_strategy: None,
-                notifications: true,
-            }),
-            updated_at: updated_tracker.updated_at,
-            ..tracker.clone()
-        };
-        assert_eq!(expected_tracker, updated_tracker);
-        assert_eq!(
-            expected_tracker,
-            web_scraping
-                .get_resources_tracker(tracker.id)
-                .await?
-                .unwrap()
-        );
+
+        let content = get_content(946720800, "\"url?token=abc123&page=1\"")?;
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content);
+        });
+
+        let revision = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await?;
+        assert_eq!(revision.unwrap().data, "\"url?[REDACTED]&page=1\"");
+        content_mock.assert();
+
+        let tracker_content = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(tracker_content.len(), 1);
+        assert_eq!(tracker_content[0].data, "\"url?[REDACTED]&page=1\"");
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_updates_web_page_content_job_id_at_update(
-        pool: PgPool,
-    ) -> anyhow::Result<()> {
-        let api = mock_api(pool).await?;
+    async fn properly_captures_web_page_content_byte_range(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
 
@@ -2779,1026 +11575,1884 @@ mod tests {
         let tracker = web_scraping
             .create_content_tracker(WebPageTrackerCreateParams {
                 name: "name_one".to_string(),
-                url: Url::parse("https://secutils.dev")?,
+                url: Url::parse("https://secutils.dev/file.pdf")?,
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    headers: Some(
+                        [("cookie".to_string(), "my-cookie".to_string())]
+                            .into_iter()
+                            .collect(),
+                    ),
+                    byte_range: Some((1, 4)),
+                    ..Default::default()
                 },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0 0 * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: true,
-                }),
+                job_config: None,
             })
             .await?;
 
-        // Set job ID.
-        api.web_scraping_system()
-            .update_web_page_tracker_job(
-                tracker.id,
-                Some(uuid!("00000000-0000-0000-0000-000000000001")),
-            )
+        // Server respects the `Range` header.
+        let content = get_content(946720800, "PDF-")?;
+        let mut content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content")
+                .json_body(
+                    serde_json::to_value(
+                        WebScraperContentRequest::with_default_parameters(&tracker.url)
+                            .set_delay(Duration::from_millis(2000))
+                            .set_headers(
+                                &[
+                                    ("cookie".to_string(), "my-cookie".to_string()),
+                                    ("range".to_string(), "bytes=1-4".to_string()),
+                                ]
+                                .into_iter()
+                                .collect(),
+                            ),
+                    )
+                    .unwrap(),
+                );
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content);
+        });
+
+        let revision = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await?;
+        assert_eq!(revision.unwrap().data, "PDF-");
+        content_mock.assert();
+        content_mock.delete();
+
+        // Server ignores the `Range` header and returns the full content.
+        let content = get_content(946720900, "%PDX-1.7 and the rest of the file")?;
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content);
+        });
+
+        let revision = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await?;
+        assert_eq!(revision.unwrap().data, "PDX-");
+        content_mock.assert();
+
+        let tracker_content = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
             .await?;
         assert_eq!(
-            Some(uuid!("00000000-0000-0000-0000-000000000001")),
+            tracker_content
+                .into_iter()
+                .map(|revision| revision.data)
+                .collect::<Vec<_>>(),
+            vec!["PDF-".to_string(), "PDX-".to_string()]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_exports_web_page_tracker_history_as_csv(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping_db = api.db.web_scraping(mock_user.id);
+        let content_tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "one",
+            "https://secutils.dev/one",
+            3,
+        )?
+        .build();
+        web_scraping_db
+            .insert_web_page_tracker(&content_tracker)
+            .await?;
+        let resources_tracker = MockWebPageTrackerBuilder::<WebPageResourcesTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "two",
+            "https://secutils.dev/two",
+            3,
+        )?
+        .build();
+        web_scraping_db
+            .insert_web_page_tracker(&resources_tracker)
+            .await?;
+
+        // Empty history yields only the header row.
+        let web_scraping = api.web_scraping(&mock_user);
+        assert_eq!(
             web_scraping
-                .get_content_tracker(tracker.id)
-                .await?
-                .unwrap()
-                .job_id
+                .export_content_tracker_history_csv(content_tracker.id, Default::default())
+                .await?,
+            "revision_id,created_at,content_hash,size,diff_status\n"
+        );
+        assert_eq!(
+            web_scraping
+                .export_resources_tracker_history_csv(resources_tracker.id, Default::default())
+                .await?,
+            "revision_id,created_at,resource_type,url,content_digest,size,diff_status\n"
         );
 
-        let updated_tracker = web_scraping
-            .update_content_tracker(
-                tracker.id,
-                WebPageTrackerUpdateParams {
-                    name: Some("name_two".to_string()),
-                    ..Default::default()
-                },
-            )
-            .await?;
-        let expected_tracker = WebPageTracker {
-            name: "name_two".to_string(),
-            job_id: Some(uuid!("00000000-0000-0000-0000-000000000001")),
-            updated_at: updated_tracker.updated_at,
-            ..tracker.clone()
+        for (index, (data, timestamp)) in [
+            ("\"rev_1\"", 946720800),
+            ("\"rev_2\"", 946720900),
+            ("\"rev_1\"", 946721000),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            web_scraping_db
+                .insert_web_page_tracker_history_revision::<WebPageContentTrackerTag>(
+                    &WebPageDataRevision {
+                        id: Uuid::from_u128(index as u128 + 10),
+                        tracker_id: content_tracker.id,
+                        data: data.to_string(),
+                        created_at: OffsetDateTime::from_unix_timestamp(timestamp)?,
+                        note: None,
+                        acknowledgment: None,
+                        diff_stats: None,
+                        assertions_passed: None,
+                    },
+                )
+                .await?;
+        }
+
+        let resource = |url: &str, digest: &str, size: usize| {
+            MockWebPageResourceBuilder::with_url(Url::parse(url).unwrap())
+                .set_content(WebPageResourceContentData::Sha1(digest.to_string()), size)
+                .build()
         };
-        assert_eq!(expected_tracker, updated_tracker);
+        for (index, (script_digest, timestamp)) in
+            [("digest-one", 946720800), ("digest-two", 946720900)]
+                .into_iter()
+                .enumerate()
+        {
+            web_scraping_db
+                .insert_web_page_tracker_history_revision::<WebPageResourcesTrackerTag>(
+                    &WebPageDataRevision {
+                        id: Uuid::from_u128(index as u128 + 20),
+                        tracker_id: resources_tracker.id,
+                        data: WebPageResourcesData {
+                            scripts: vec![resource(
+                                "https://secutils.dev/script.js?a=1,2",
+                                script_digest,
+                                10,
+                            )],
+                            styles: vec![resource("https://secutils.dev/style.css", "digest", 5)],
+                            categories: None,
+                            sizes: None,
+                            truncated: false,
+                        },
+                        created_at: OffsetDateTime::from_unix_timestamp(timestamp)?,
+                        note: None,
+                        acknowledgment: None,
+                        diff_stats: None,
+                        assertions_passed: None,
+                    },
+                )
+                .await?;
+        }
+
         assert_eq!(
-            expected_tracker,
-            web_scraping.get_content_tracker(tracker.id).await?.unwrap()
+            web_scraping
+                .export_content_tracker_history_csv(content_tracker.id, Default::default())
+                .await?,
+            "revision_id,created_at,content_hash,size,diff_status\n\
+             00000000-0000-0000-0000-00000000000a,2000-01-01T10:00:00Z,8437abaeef869936165e056abb9c3040e02d1156befe2d080ba82516decf1778,7,\n\
+             00000000-0000-0000-0000-00000000000b,2000-01-01T10:01:40Z,9a6fdd71a1b61b32605ea24b352ff78d9bf8fe715d22e9e1509bac86dfa09c81,7,\n\
+             00000000-0000-0000-0000-00000000000c,2000-01-01T10:03:20Z,8437abaeef869936165e056abb9c3040e02d1156befe2d080ba82516decf1778,7,\n"
+        );
+        assert_eq!(
+            web_scraping
+                .export_content_tracker_history_csv(
+                    content_tracker.id,
+                    WebPageContentTrackerGetHistoryParams {
+                        calculate_diff: true,
+                        ..Default::default()
+                    }
+                )
+                .await?,
+            "revision_id,created_at,content_hash,size,diff_status\n\
+             00000000-0000-0000-0000-00000000000a,2000-01-01T10:00:00Z,8437abaeef869936165e056abb9c3040e02d1156befe2d080ba82516decf1778,7,\n\
+             00000000-0000-0000-0000-00000000000b,2000-01-01T10:01:40Z,9a6fdd71a1b61b32605ea24b352ff78d9bf8fe715d22e9e1509bac86dfa09c81,7,changed\n\
+             00000000-0000-0000-0000-00000000000c,2000-01-01T10:03:20Z,8437abaeef869936165e056abb9c3040e02d1156befe2d080ba82516decf1778,7,changed\n"
         );
 
-        // Change in schedule will reset job ID.
-        let updated_tracker = web_scraping
-            .update_content_tracker(
-                tracker.id,
-                WebPageTrackerUpdateParams {
-                    job_config: Some(Some(SchedulerJobConfig {
-                        schedule: "0 1 * * * *".to_string(),
-                        retry_strategy: None,
-                        notifications: true,
-                    })),
-                    ..Default::default()
-                },
-            )
-            .await?;
-        let expected_tracker = WebPageTracker {
-            name: "name_two".to_string(),
-            job_id: None,
-            job_config: Some(SchedulerJobConfig {
-                schedule: "0 1 * * * *".to_string(),
-                retry_strategy: None,
-                notifications: true,
-            }),
-            updated_at: updated_tracker.updated_at,
-            ..tracker.clone()
-        };
-        assert_eq!(expected_tracker, updated_tracker);
         assert_eq!(
-            expected_tracker,
-            web_scraping.get_content_tracker(tracker.id).await?.unwrap()
+            web_scraping
+                .export_resources_tracker_history_csv(resources_tracker.id, Default::default())
+                .await?,
+            "revision_id,created_at,resource_type,url,content_digest,size,diff_status\n\
+             00000000-0000-0000-0000-000000000014,2000-01-01T10:00:00Z,script,\"https://secutils.dev/script.js?a=1,2\",digest-one,10,\n\
+             00000000-0000-0000-0000-000000000014,2000-01-01T10:00:00Z,style,https://secutils.dev/style.css,digest,5,\n\
+             00000000-0000-0000-0000-000000000015,2000-01-01T10:01:40Z,script,\"https://secutils.dev/script.js?a=1,2\",digest-two,10,\n\
+             00000000-0000-0000-0000-000000000015,2000-01-01T10:01:40Z,style,https://secutils.dev/style.css,digest,5,\n"
+        );
+        assert_eq!(
+            web_scraping
+                .export_resources_tracker_history_csv(
+                    resources_tracker.id,
+                    WebPageResourcesTrackerGetHistoryParams {
+                        refresh: false,
+                        calculate_diff: true,
+                    }
+                )
+                .await?,
+            "revision_id,created_at,resource_type,url,content_digest,size,diff_status\n\
+             00000000-0000-0000-0000-000000000014,2000-01-01T10:00:00Z,script,\"https://secutils.dev/script.js?a=1,2\",digest-one,10,\n\
+             00000000-0000-0000-0000-000000000014,2000-01-01T10:00:00Z,style,https://secutils.dev/style.css,digest,5,\n\
+             00000000-0000-0000-0000-000000000015,2000-01-01T10:01:40Z,script,\"https://secutils.dev/script.js?a=1,2\",digest-two,10,changed\n\
+             00000000-0000-0000-0000-000000000015,2000-01-01T10:01:40Z,style,https://secutils.dev/style.css,digest,5,\n"
+        );
+
+        // Tracker should exist.
+        assert_debug_snapshot!(
+            web_scraping
+                .export_content_tracker_history_csv(uuid!("00000000-0000-0000-0000-000000000003"), Default::default())
+                .await
+                .unwrap_err()
+                .downcast::<SecutilsError>()?,
+            @r###""Web page tracker ('00000000-0000-0000-0000-000000000003') is not found.""###
         );
 
         Ok(())
     }
 
+    #[test]
+    fn properly_serializes_web_page_tracker_history_csv_rows() {
+        assert_eq!(
+            web_page_tracker_history_csv_row(["one", "", "two"]),
+            "one,,two\n"
+        );
+        assert_eq!(
+            web_page_tracker_history_csv_row(["a,b", "say \"hi\"", "line\nbreak"]),
+            "\"a,b\",\"say \"\"hi\"\"\",\"line\nbreak\"\n"
+        );
+    }
+
     #[sqlx::test]
-    async fn properly_removes_web_page_trackers(pool: PgPool) -> anyhow::Result<()> {
+    async fn properly_returns_web_page_tracker_revision(pool: PgPool) -> anyhow::Result<()> {
         let api = mock_api(pool).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
+        let another_user = mock_user_with_id(uuid!("00000000-0000-0000-0000-000000000002"))?;
+        api.db.insert_user(&another_user).await?;
 
         let web_scraping = api.web_scraping(&mock_user);
+        let create_params = |name: &str| WebPageTrackerCreateParams {
+            name: name.to_string(),
+            url: Url::parse("https://secutils.dev/one").unwrap(),
+            settings: WebPageTrackerSettings {
+                revisions: 3,
+                delay: Duration::from_millis(2000),
+                ..Default::default()
+            },
+            job_config: None,
+        };
         let tracker_one = web_scraping
-            .create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name_one".to_string(),
-                url: Url::parse("https://secutils.dev")?,
-                settings: WebPageTrackerSettings {
-                    revisions: 3,
-                    delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
-                },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0 0 * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: true,
-                }),
-            })
+            .create_content_tracker(create_params("name_one"))
             .await?;
         let tracker_two = web_scraping
-            .create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name_two".to_string(),
-                url: Url::parse("https://secutils.dev")?,
-                settings: tracker_one.settings.clone(),
-                job_config: tracker_one.job_config.clone(),
-            })
-            .await?;
+            .create_content_tracker(create_params("name_two"))
+            .await?;
+
+        let revisions = [
+            WebPageDataRevision::<WebPageContentTrackerTag> {
+                id: uuid!("00000000-0000-0000-0000-000000000010"),
+                tracker_id: tracker_one.id,
+                data: "\"rev_1\"".to_string(),
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            },
+            WebPageDataRevision::<WebPageContentTrackerTag> {
+                id: uuid!("00000000-0000-0000-0000-000000000011"),
+                tracker_id: tracker_one.id,
+                data: "\"rev_2\"".to_string(),
+                created_at: OffsetDateTime::from_unix_timestamp(946720900)?,
+                note: Some("note".to_string()),
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            },
+            WebPageDataRevision::<WebPageContentTrackerTag> {
+                id: uuid!("00000000-0000-0000-0000-000000000012"),
+                tracker_id: tracker_two.id,
+                data: "\"rev_3\"".to_string(),
+                created_at: OffsetDateTime::from_unix_timestamp(946721000)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            },
+        ];
+        for revision in revisions.iter() {
+            api.db
+                .web_scraping(mock_user.id)
+                .insert_web_page_tracker_history_revision(revision)
+                .await?;
+        }
 
         assert_eq!(
-            web_scraping.get_resources_trackers().await?,
-            vec![tracker_one.clone(), tracker_two.clone()],
+            web_scraping
+                .get_content_tracker_revision(tracker_one.id, revisions[1].id)
+                .await?,
+            revisions[1]
+        );
+        assert_eq!(
+            web_scraping
+                .get_content_tracker_revision(tracker_two.id, revisions[2].id)
+                .await?,
+            revisions[2]
         );
 
-        web_scraping.remove_web_page_tracker(tracker_one.id).await?;
+        fn get_and_fail<T: std::fmt::Debug>(result: anyhow::Result<T>) -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        }
 
-        assert_eq!(
-            web_scraping.get_resources_trackers().await?,
-            vec![tracker_two.clone()],
+        // Revision of another tracker.
+        assert_debug_snapshot!(
+            get_and_fail(web_scraping.get_content_tracker_revision(tracker_one.id, revisions[2].id).await),
+            @r###""Web page tracker revision ('00000000-0000-0000-0000-000000000012') is not found.""###
+        );
+
+        // Revision of another user.
+        assert_debug_snapshot!(
+            get_and_fail(api.web_scraping(&another_user).get_content_tracker_revision(tracker_one.id, revisions[0].id).await),
+            @r###""Web page tracker revision ('00000000-0000-0000-0000-000000000010') is not found.""###
         );
 
-        web_scraping.remove_web_page_tracker(tracker_two.id).await?;
-
-        assert!(web_scraping.get_resources_trackers().await?.is_empty());
+        // Revision of another tracker kind.
+        assert_debug_snapshot!(
+            get_and_fail(web_scraping.get_screenshot_tracker_revision(tracker_one.id, revisions[0].id).await),
+            @r###""Web page tracker revision ('00000000-0000-0000-0000-000000000010') is not found.""###
+        );
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_returns_resources_trackers_by_id(pool: PgPool) -> anyhow::Result<()> {
+    async fn properly_sets_web_page_tracker_revision_note(pool: PgPool) -> anyhow::Result<()> {
         let api = mock_api(pool).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
+        let another_user = mock_user_with_id(uuid!("00000000-0000-0000-0000-000000000002"))?;
+        api.db.insert_user(&another_user).await?;
 
         let web_scraping = api.web_scraping(&mock_user);
-        assert!(web_scraping
-            .get_resources_tracker(uuid!("00000000-0000-0000-0000-000000000001"))
-            .await?
-            .is_none());
-
-        let tracker_one = web_scraping
-            .create_resources_tracker(WebPageTrackerCreateParams {
+        let tracker = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
                 name: "name_one".to_string(),
-                url: Url::parse("https://secutils.dev")?,
+                url: Url::parse("https://secutils.dev/one")?,
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0 0 * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: true,
-                }),
+                job_config: None,
             })
             .await?;
-        assert_eq!(
-            web_scraping.get_resources_tracker(tracker_one.id).await?,
-            Some(tracker_one.clone()),
-        );
 
-        let tracker_two = web_scraping
-            .create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name_two".to_string(),
-                url: Url::parse("https://secutils.dev")?,
-                settings: tracker_one.settings.clone(),
-                job_config: tracker_one.job_config.clone(),
-            })
+        let revision = WebPageDataRevision::<WebPageContentTrackerTag> {
+            id: uuid!("00000000-0000-0000-0000-000000000010"),
+            tracker_id: tracker.id,
+            data: "\"rev_1\"".to_string(),
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            note: None,
+            acknowledgment: None,
+            diff_stats: None,
+            assertions_passed: None,
+        };
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker_history_revision(&revision)
             .await?;
 
+        // Set note.
+        web_scraping
+            .set_revision_note(
+                tracker.id,
+                revision.id,
+                Some("this was the outage".to_string()),
+            )
+            .await?;
+        let history = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
         assert_eq!(
-            web_scraping.get_resources_tracker(tracker_two.id).await?,
-            Some(tracker_two.clone()),
+            history,
+            vec![WebPageDataRevision {
+                note: Some("this was the outage".to_string()),
+                ..revision.clone()
+            }]
+        );
+
+        // Too long note.
+        let set_and_fail = |result: anyhow::Result<()>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
+        assert_debug_snapshot!(
+            set_and_fail(web_scraping.set_revision_note(tracker.id, revision.id, Some("a".repeat(1001))).await),
+            @r###""Web page tracker revision note cannot be longer than 1000 characters.""###
+        );
+
+        // Revision of another user.
+        assert_debug_snapshot!(
+            set_and_fail(api.web_scraping(&another_user).set_revision_note(tracker.id, revision.id, None).await),
+            @r###""Web page tracker revision ('00000000-0000-0000-0000-000000000010') is not found.""###
         );
+        let history = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(history[0].note, Some("this was the outage".to_string()));
+
+        // Clear note.
+        web_scraping
+            .set_revision_note(tracker.id, revision.id, Some("  ".to_string()))
+            .await?;
+        let history = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(history, vec![revision.clone()]);
+
+        // Clearing history removes notes.
+        web_scraping
+            .set_revision_note(
+                tracker.id,
+                revision.id,
+                Some("this was the outage".to_string()),
+            )
+            .await?;
+        web_scraping
+            .clear_web_page_tracker_history(tracker.id)
+            .await?;
+        assert!(web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?
+            .is_empty());
+
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker_history_revision(&revision)
+            .await?;
+        let history = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(history, vec![revision]);
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_returns_content_trackers_by_id(pool: PgPool) -> anyhow::Result<()> {
+    async fn properly_acknowledges_web_page_tracker_revision(pool: PgPool) -> anyhow::Result<()> {
         let api = mock_api(pool).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
+        let another_user = mock_user_with_id(uuid!("00000000-0000-0000-0000-000000000002"))?;
+        api.db.insert_user(&another_user).await?;
 
         let web_scraping = api.web_scraping(&mock_user);
-        assert!(web_scraping
-            .get_content_tracker(uuid!("00000000-0000-0000-0000-000000000001"))
-            .await?
-            .is_none());
-
-        let tracker_one = web_scraping
+        let tracker = web_scraping
             .create_content_tracker(WebPageTrackerCreateParams {
                 name: "name_one".to_string(),
-                url: Url::parse("https://secutils.dev")?,
+                url: Url::parse("https://secutils.dev/one")?,
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0 0 * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: true,
-                }),
+                job_config: None,
             })
             .await?;
-        assert_eq!(
-            web_scraping.get_content_tracker(tracker_one.id).await?,
-            Some(tracker_one.clone()),
-        );
 
-        let tracker_two = web_scraping
-            .create_content_tracker(WebPageTrackerCreateParams {
-                name: "name_two".to_string(),
-                url: Url::parse("https://secutils.dev")?,
-                settings: tracker_one.settings.clone(),
-                job_config: tracker_one.job_config.clone(),
-            })
+        let revisions = [
+            WebPageDataRevision::<WebPageContentTrackerTag> {
+                id: uuid!("00000000-0000-0000-0000-000000000010"),
+                tracker_id: tracker.id,
+                data: "\"rev_1\"".to_string(),
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            },
+            WebPageDataRevision::<WebPageContentTrackerTag> {
+                id: uuid!("00000000-0000-0000-0000-000000000011"),
+                tracker_id: tracker.id,
+                data: "\"rev_2\"".to_string(),
+                created_at: OffsetDateTime::from_unix_timestamp(946720900)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            },
+        ];
+        for revision in revisions.iter() {
+            api.db
+                .web_scraping(mock_user.id)
+                .insert_web_page_tracker_history_revision(revision)
+                .await?;
+        }
+
+        let before_acknowledgment =
+            OffsetDateTime::from_unix_timestamp(OffsetDateTime::now_utc().unix_timestamp())?;
+        let acknowledgment = web_scraping
+            .acknowledge_revision(tracker.id, revisions[1].id)
             .await?;
+        assert_eq!(acknowledgment.user_id, mock_user.id);
+        assert!(acknowledgment.acknowledged_at >= before_acknowledgment);
+        assert!(acknowledgment.acknowledged_at <= OffsetDateTime::now_utc());
 
+        // Acknowledgment is reflected in history.
+        let history = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].acknowledgment, None);
+        assert_eq!(history[1].acknowledgment, Some(acknowledgment));
         assert_eq!(
-            web_scraping.get_content_tracker(tracker_two.id).await?,
-            Some(tracker_two.clone()),
+            web_scraping
+                .get_content_tracker_revision(tracker.id, revisions[1].id)
+                .await?
+                .acknowledgment,
+            Some(acknowledgment)
+        );
+
+        let acknowledge_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
+
+        // Unknown revision.
+        assert_debug_snapshot!(
+            acknowledge_and_fail(web_scraping.acknowledge_revision(tracker.id, uuid!("00000000-0000-0000-0000-000000000012")).await),
+            @r###""Web page tracker revision ('00000000-0000-0000-0000-000000000012') is not found.""###
+        );
+
+        // Revision of another user.
+        assert_debug_snapshot!(
+            acknowledge_and_fail(api.web_scraping(&another_user).acknowledge_revision(tracker.id, revisions[0].id).await),
+            @r###""Web page tracker revision ('00000000-0000-0000-0000-000000000010') is not found.""###
         );
+        let history = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(history[0].acknowledgment, None);
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_returns_all_resources_trackers(pool: PgPool) -> anyhow::Result<()> {
-        let api = mock_api(pool).await?;
+    async fn properly_diffs_web_page_tracker_revisions_against_baseline(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
 
         let web_scraping = api.web_scraping(&mock_user);
-        assert!(web_scraping.get_resources_trackers().await?.is_empty(),);
-
-        let tracker_one = web_scraping
-            .create_resources_tracker(WebPageTrackerCreateParams {
+        let tracker = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
                 name: "name_one".to_string(),
-                url: Url::parse("https://secutils.dev")?,
+                url: Url::parse("https://secutils.dev/one")?,
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0 0 * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: true,
-                }),
+                job_config: None,
             })
             .await?;
+
+        for (index, data) in ["rev_1", "rev_2", "rev_3"].into_iter().enumerate() {
+            api.db
+                .web_scraping(mock_user.id)
+                .insert_web_page_tracker_history_revision(&WebPageDataRevision::<
+                    WebPageContentTrackerTag,
+                > {
+                    id: Uuid::from_u128(0x10 + index as u128),
+                    tracker_id: tracker.id,
+                    data: format!("\"{data}\""),
+                    created_at: OffsetDateTime::from_unix_timestamp(
+                        946720800 + 100 * index as i64,
+                    )?,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
+                })
+                .await?;
+        }
+
+        let get_history_diff = || async {
+            anyhow::Ok(
+                web_scraping
+                    .get_content_tracker_history(
+                        tracker.id,
+                        WebPageContentTrackerGetHistoryParams {
+                            refresh: false,
+                            calculate_diff: true,
+                            diff_algorithm: Default::default(),
+                        },
+                    )
+                    .await?
+                    .into_iter()
+                    .map(|revision| (revision.id, revision.data))
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        // All revisions after the baseline are compared to the baseline.
+        web_scraping
+            .set_baseline_revision(tracker.id, Some(Uuid::from_u128(0x10)))
+            .await?;
         assert_eq!(
-            web_scraping.get_resources_trackers().await?,
-            vec![tracker_one.clone()],
+            get_history_diff().await?,
+            vec![
+                (Uuid::from_u128(0x10), "\"rev_1\"".to_string()),
+                (
+                    Uuid::from_u128(0x11),
+                    "@@ -1 +1 @@\n-rev_1\n+rev_2\n".to_string()
+                ),
+                (
+                    Uuid::from_u128(0x12),
+                    "@@ -1 +1 @@\n-rev_1\n+rev_3\n".to_string()
+                ),
+            ]
         );
-        let tracker_two = web_scraping
-            .create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name_two".to_string(),
-                url: Url::parse("https://secutils.dev")?,
-                settings: tracker_one.settings.clone(),
-                job_config: tracker_one.job_config.clone(),
-            })
+
+        // Revisions before the baseline are still compared to the previous revision.
+        web_scraping
+            .set_baseline_revision(tracker.id, Some(Uuid::from_u128(0x11)))
+            .await?;
+        assert_eq!(
+            get_history_diff().await?,
+            vec![
+                (Uuid::from_u128(0x10), "\"rev_1\"".to_string()),
+                (
+                    Uuid::from_u128(0x11),
+                    "@@ -1 +1 @@\n-rev_1\n+rev_2\n".to_string()
+                ),
+                (
+                    Uuid::from_u128(0x12),
+                    "@@ -1 +1 @@\n-rev_2\n+rev_3\n".to_string()
+                ),
+            ]
+        );
+
+        // Baseline revision isn't displaced when the revisions limit is reached.
+        web_scraping
+            .set_baseline_revision(tracker.id, Some(Uuid::from_u128(0x10)))
             .await?;
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&get_content(946721100, "\"rev_4\"").unwrap());
+        });
+        let new_revision = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await?
+            .unwrap();
+        content_mock.assert();
+        assert_eq!(
+            get_history_diff().await?,
+            vec![
+                (Uuid::from_u128(0x10), "\"rev_1\"".to_string()),
+                (
+                    Uuid::from_u128(0x12),
+                    "@@ -1 +1 @@\n-rev_1\n+rev_3\n".to_string()
+                ),
+                (new_revision.id, "@@ -1 +1 @@\n-rev_1\n+rev_4\n".to_string()),
+            ]
+        );
 
+        // Once the baseline is unpinned, revisions are compared to the previous ones again.
+        web_scraping.set_baseline_revision(tracker.id, None).await?;
         assert_eq!(
-            web_scraping.get_resources_trackers().await?,
-            vec![tracker_one.clone(), tracker_two.clone()],
+            get_history_diff().await?,
+            vec![
+                (Uuid::from_u128(0x10), "\"rev_1\"".to_string()),
+                (
+                    Uuid::from_u128(0x12),
+                    "@@ -1 +1 @@\n-rev_1\n+rev_3\n".to_string()
+                ),
+                (new_revision.id, "@@ -1 +1 @@\n-rev_3\n+rev_4\n".to_string()),
+            ]
+        );
+
+        // Unknown revision.
+        assert_debug_snapshot!(
+            web_scraping
+                .set_baseline_revision(tracker.id, Some(Uuid::from_u128(0x11)))
+                .await
+                .unwrap_err()
+                .downcast::<SecutilsError>()?,
+            @r###""Web page tracker revision ('00000000-0000-0000-0000-000000000011') is not found.""###
         );
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_returns_all_content_trackers(pool: PgPool) -> anyhow::Result<()> {
-        let api = mock_api(pool).await?;
+    async fn properly_creates_well_known_content_trackers(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool.clone()).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
 
         let web_scraping = api.web_scraping(&mock_user);
-        assert!(web_scraping.get_content_trackers().await?.is_empty(),);
-
-        let tracker_one = web_scraping
-            .create_content_tracker(WebPageTrackerCreateParams {
-                name: "name_one".to_string(),
-                url: Url::parse("https://secutils.dev")?,
-                settings: WebPageTrackerSettings {
-                    revisions: 3,
-                    delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
-                },
+        let trackers = web_scraping
+            .create_well_known_content_trackers(WebPageContentTrackerCreateWellKnownParams {
+                base_url: Url::parse("https://secutils.dev/some/path?query=1")?,
+                files: None,
                 job_config: Some(SchedulerJobConfig {
-                    schedule: "0 0 * * * *".to_string(),
+                    schedule: "0 0 * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
         assert_eq!(
-            web_scraping.get_content_trackers().await?,
-            vec![tracker_one.clone()],
+            trackers
+                .iter()
+                .map(|tracker| (tracker.name.as_str(), tracker.url.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "secutils.dev/.well-known/security.txt",
+                    "https://secutils.dev/.well-known/security.txt"
+                ),
+                ("secutils.dev/robots.txt", "https://secutils.dev/robots.txt"),
+                ("secutils.dev/humans.txt", "https://secutils.dev/humans.txt"),
+            ]
         );
-        let tracker_two = web_scraping
-            .create_content_tracker(WebPageTrackerCreateParams {
-                name: "name_two".to_string(),
-                url: Url::parse("https://secutils.dev")?,
-                settings: tracker_one.settings.clone(),
-                job_config: tracker_one.job_config.clone(),
+        for tracker in &trackers {
+            assert_eq!(
+                tracker.settings,
+                WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::ZERO,
+                    render_js: false,
+                    content_format: tracker
+                        .url
+                        .path()
+                        .ends_with("robots.txt")
+                        .then_some(WebPageContentFormat::RobotsTxt),
+                    ..Default::default()
+                }
+            );
+            assert_eq!(
+                tracker.job_config,
+                Some(SchedulerJobConfig {
+                    schedule: "0 0 * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                })
+            );
+            assert_eq!(
+                web_scraping.get_content_tracker(tracker.id).await?.as_ref(),
+                Some(tracker)
+            );
+        }
+
+        // Only selected files are tracked, revisions are limited by the subscription.
+        let mut config = mock_config()?;
+        config.subscriptions.ultimate.web_scraping.tracker_revisions = 2;
+        let api = mock_api_with_config(pool, config).await?;
+        let web_scraping = api.web_scraping(&mock_user);
+        let trackers = web_scraping
+            .create_well_known_content_trackers(WebPageContentTrackerCreateWellKnownParams {
+                base_url: Url::parse("http://secutils.dev:8080")?,
+                files: Some(vec![
+                    WebPageWellKnownFile::RobotsTxt,
+                    WebPageWellKnownFile::RobotsTxt,
+                ]),
+                job_config: None,
             })
             .await?;
-
+        assert_eq!(trackers.len(), 1);
+        assert_eq!(trackers[0].name, "secutils.dev/robots.txt");
         assert_eq!(
-            web_scraping.get_content_trackers().await?,
-            vec![tracker_one.clone(), tracker_two.clone()],
+            trackers[0].url.as_str(),
+            "http://secutils.dev:8080/robots.txt"
+        );
+        assert_eq!(trackers[0].settings.revisions, 2);
+        assert!(trackers[0].job_config.is_none());
+
+        let create_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
+
+        // Empty files list.
+        let error = create_and_fail(
+            web_scraping
+                .create_well_known_content_trackers(WebPageContentTrackerCreateWellKnownParams {
+                    base_url: Url::parse("https://secutils.dev")?,
+                    files: Some(vec![]),
+                    job_config: None,
+                })
+                .await,
         );
+        assert_debug_snapshot!(error, @r###""Web page tracker well-known files list cannot be empty.""###);
+
+        // Non-public base URL.
+        let error = create_and_fail(
+            web_scraping
+                .create_well_known_content_trackers(WebPageContentTrackerCreateWellKnownParams {
+                    base_url: Url::parse("https://127.0.0.1")?,
+                    files: None,
+                    job_config: None,
+                })
+                .await,
+        );
+        assert_eq!(error.code(), ErrorCode::InvalidUrl);
+        assert_debug_snapshot!(error, @r###""Web page tracker base URL must be either `http` or `https` and have a valid public reachable domain name, but received https://127.0.0.1/.""###);
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_saves_web_page_resources(pool: PgPool) -> anyhow::Result<()> {
-        let server = MockServer::start();
+    async fn properly_imports_web_page_trackers(pool: PgPool) -> anyhow::Result<()> {
         let mut config = mock_config()?;
-        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+        config.subscriptions.ultimate.web_scraping.trackers = 3;
 
         let api = mock_api_with_config(pool, config).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
 
+        let import_params = |urls: &str| WebPageTrackerImportParams {
+            source: WebPageTrackerImportSource::List {
+                urls: urls.to_string(),
+            },
+            settings: WebPageTrackerSettings {
+                revisions: 3,
+                delay: Duration::from_millis(2000),
+                ..Default::default()
+            },
+            job_config: None,
+        };
+
         let web_scraping = api.web_scraping(&mock_user);
-        let tracker_one = web_scraping
-            .create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name_one".to_string(),
-                url: Url::parse("https://secutils.dev/one")?,
-                settings: WebPageTrackerSettings {
-                    revisions: 3,
-                    delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
-                },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0 0 * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: true,
-                }),
-            })
-            .await?;
-        let tracker_two = web_scraping
-            .create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name_two".to_string(),
-                url: Url::parse("https://secutils.dev/two")?,
-                settings: tracker_one.settings.clone(),
-                job_config: tracker_one.job_config.clone(),
-            })
+        let results = web_scraping
+            .import_resources_trackers(import_params(
+                "https://secutils.dev/one\n\n  https://secutils.dev/two  \nhttps://secutils.dev/one\nnot-a-url\nhttps://127.0.0.1/local\nhttps://secutils.dev/three\nhttps://secutils.dev/four",
+            ))
             .await?;
+        assert_debug_snapshot!(results.iter().map(|result| (&result.url, result.tracker_id.is_some(), &result.error)).collect::<Vec<_>>(), @r###"
+        [
+            (
+                "https://secutils.dev/one",
+                true,
+                None,
+            ),
+            (
+                "https://secutils.dev/two",
+                true,
+                None,
+            ),
+            (
+                "not-a-url",
+                false,
+                Some(
+                    "Web page URL isn't valid: relative URL without a base.",
+                ),
+            ),
+            (
+                "https://127.0.0.1/local",
+                false,
+                Some(
+                    "Web page tracker URL must be either `http` or `https` and have a valid public reachable domain name, but received https://127.0.0.1/local.",
+                ),
+            ),
+            (
+                "https://secutils.dev/three",
+                true,
+                None,
+            ),
+            (
+                "https://secutils.dev/four",
+                false,
+                Some(
+                    "Web page trackers count cannot be greater than 3.",
+                ),
+            ),
+        ]
+        "###);
 
-        let tracker_one_resources = web_scraping
-            .get_resources_tracker_history(tracker_one.id, Default::default())
-            .await?;
-        let tracker_two_resources = web_scraping
-            .get_resources_tracker_history(tracker_two.id, Default::default())
+        let mut trackers = web_scraping.get_resources_trackers().await?;
+        trackers.sort_by(|tracker_a, tracker_b| tracker_a.name.cmp(&tracker_b.name));
+        assert_eq!(
+            trackers
+                .iter()
+                .map(|tracker| (tracker.id, tracker.name.as_str(), tracker.url.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    results[0].tracker_id.unwrap(),
+                    "https://secutils.dev/one",
+                    "https://secutils.dev/one"
+                ),
+                (
+                    results[4].tracker_id.unwrap(),
+                    "https://secutils.dev/three",
+                    "https://secutils.dev/three"
+                ),
+                (
+                    results[1].tracker_id.unwrap(),
+                    "https://secutils.dev/two",
+                    "https://secutils.dev/two"
+                ),
+            ]
+        );
+
+        // Limit is shared across all tracker kinds.
+        let results = web_scraping
+            .import_content_trackers(import_params("https://secutils.dev/five"))
             .await?;
-        assert!(tracker_one_resources.is_empty());
-        assert!(tracker_two_resources.is_empty());
+        assert_eq!(
+            results,
+            vec![WebPageTrackerImportResult {
+                url: "https://secutils.dev/five".to_string(),
+                tracker_id: None,
+                error: Some("Web page trackers count cannot be greater than 3.".to_string()),
+            }]
+        );
+        assert!(web_scraping.get_content_trackers().await?.is_empty());
 
-        let resources_one = get_resources(946720800, "rev_1")?;
-        let mut resources_mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/api/web_page/resources")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperResourcesRequest::with_default_parameters(&tracker_one.url)
-                            .set_delay(Duration::from_millis(2000)),
-                    )
-                    .unwrap(),
-                );
-            then.status(200)
-                .header("Content-Type", "application/json")
-                .json_body_obj(&resources_one);
-        });
+        let import_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
 
-        let diff = web_scraping
-            .create_resources_tracker_revision(tracker_one.id)
-            .await?;
-        assert!(diff.is_none());
-        resources_mock.assert();
-        resources_mock.delete();
+        // Empty import source.
+        let error = import_and_fail(
+            web_scraping
+                .import_resources_trackers(import_params("\n  \n"))
+                .await,
+        );
+        assert_eq!(error.code(), ErrorCode::InvalidInput);
+        assert_debug_snapshot!(error, @r###""Web page tracker import source doesn't contain any URLs.""###);
 
-        let tracker_one_resources = web_scraping
-            .get_resources_tracker_history(tracker_one.id, Default::default())
-            .await?;
-        let tracker_two_resources = web_scraping
-            .get_resources_tracker_history(tracker_two.id, Default::default())
-            .await?;
-        assert_eq!(tracker_one_resources.len(), 1);
-        assert_eq!(tracker_one_resources[0].tracker_id, tracker_one.id);
-        assert_eq!(
-            tracker_one_resources[0].data.scripts,
-            resources_one
-                .scripts
-                .into_iter()
-                .map(WebPageResource::from)
-                .collect::<Vec<_>>()
+        // Too many URLs.
+        let urls = (0..101)
+            .map(|index| format!("https://secutils.dev/{index}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let error = import_and_fail(
+            web_scraping
+                .import_resources_trackers(import_params(&urls))
+                .await,
         );
-        assert_eq!(
-            tracker_one_resources[0].data.styles,
-            resources_one
-                .styles
-                .into_iter()
-                .map(WebPageResource::from)
-                .collect::<Vec<_>>()
+        assert_eq!(error.code(), ErrorCode::LimitExceeded);
+        assert_debug_snapshot!(error, @r###""Web page tracker import source cannot contain more than 100 unique URLs, but received 101.""###);
+
+        // Non-public sitemap URL.
+        let error = import_and_fail(
+            web_scraping
+                .import_resources_trackers(WebPageTrackerImportParams {
+                    source: WebPageTrackerImportSource::Sitemap {
+                        url: Url::parse("https://127.0.0.1/sitemap.xml")?,
+                    },
+                    ..import_params("")
+                })
+                .await,
         );
-        assert!(tracker_two_resources.is_empty());
+        assert_eq!(error.code(), ErrorCode::InvalidUrl);
+        assert_debug_snapshot!(error, @r###""Web page tracker import sitemap URL must be either `http` or `https` and have a valid public reachable domain name, but received https://127.0.0.1/sitemap.xml.""###);
 
-        let resources_two = get_resources(946720900, "rev_2")?;
-        let mut resources_mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/api/web_page/resources")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperResourcesRequest::with_default_parameters(&tracker_one.url)
-                            .set_delay(Duration::from_millis(2000)),
-                    )
-                    .unwrap(),
-                );
-            then.status(200)
-                .header("Content-Type", "application/json")
-                .json_body_obj(&resources_two);
-        });
-        let diff = web_scraping
-            .create_resources_tracker_revision(tracker_one.id)
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_imports_web_page_trackers_from_monitors(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let import_params = serde_json::from_str::<WebPageTrackerImportParams>(
+            r#"
+    {
+        "source": {
+            "type": "monitors",
+            "monitors": [
+                {
+                    "name": "Home page",
+                    "url": "https://secutils.dev",
+                    "interval": 300,
+                    "expectedStatus": 200,
+                    "expectedContent": "Secutils.dev"
+                },
+                { "name": "Docs status", "url": "https://secutils.dev/docs", "interval": 60, "expectedStatus": 200 },
+                { "name": "Odd interval", "url": "https://secutils.dev/odd", "interval": 420 },
+                { "name": "Odd status", "url": "https://secutils.dev/odd", "interval": 60, "expectedStatus": 999 },
+                { "name": "Broken URL", "url": "not-a-url", "interval": 60 }
+            ]
+        },
+        "settings": {
+            "revisions": 3,
+            "delay": 0
+        }
+    }
+              "#,
+        )?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let results = web_scraping
+            .import_content_trackers(import_params.clone())
+            .await?;
+        assert_debug_snapshot!(results.iter().map(|result| (&result.url, result.tracker_id.is_some(), &result.error)).collect::<Vec<_>>(), @r###"
+        [
+            (
+                "https://secutils.dev",
+                true,
+                None,
+            ),
+            (
+                "https://secutils.dev/docs",
+                true,
+                None,
+            ),
+            (
+                "https://secutils.dev/odd",
+                false,
+                Some(
+                    "Monitor check interval (7m) cannot be converted to a schedule.",
+                ),
+            ),
+            (
+                "https://secutils.dev/odd",
+                false,
+                Some(
+                    "Monitor expected HTTP status (999) isn't valid.",
+                ),
+            ),
+            (
+                "not-a-url",
+                false,
+                Some(
+                    "Web page URL isn't valid: relative URL without a base.",
+                ),
+            ),
+        ]
+        "###);
+
+        // Content monitor checks whether the expected content is present.
+        let tracker = web_scraping
+            .get_content_tracker(results[0].tracker_id.unwrap())
             .await?
             .unwrap();
+        assert_eq!(tracker.name, "Home page");
+        assert_eq!(tracker.url.as_str(), "https://secutils.dev/");
+        assert!(!tracker.settings.status_only);
+        assert_debug_snapshot!(tracker.settings.scripts, @r###"
+        Some(
+            {
+                "extractContent": "return document.body.innerText.includes(\"Secutils.dev\") ? 'Expected content is present.' : 'Expected content is missing.';",
+            },
+        )
+        "###);
         assert_eq!(
-            diff.created_at,
-            OffsetDateTime::from_unix_timestamp(946720900)?
+            tracker.job_config,
+            Some(SchedulerJobConfig {
+                schedule: "0 */5 * * * *".to_string(),
+                retry_strategy: None,
+                notifications: true,
+                adaptive_interval: None,
+            })
         );
+
+        // Status monitor only tracks HTTP status.
+        let tracker = web_scraping
+            .get_content_tracker(results[1].tracker_id.unwrap())
+            .await?
+            .unwrap();
+        assert_eq!(tracker.name, "Docs status");
+        assert!(tracker.settings.status_only);
+        assert!(tracker.settings.scripts.is_none());
         assert_eq!(
-            diff.data.scripts,
-            vec![
-                WebPageResource {
-                    url: Some(Url::parse("http://localhost:1234/script_rev_2.js")?),
-                    content: None,
-                    diff_status: Some(WebPageResourceDiffStatus::Added),
-                },
-                WebPageResource {
-                    url: Some(Url::parse("http://localhost:1234/script_rev_1.js")?),
-                    content: None,
-                    diff_status: Some(WebPageResourceDiffStatus::Removed),
-                },
-            ]
+            tracker.job_config,
+            Some(SchedulerJobConfig {
+                schedule: "0 */1 * * * *".to_string(),
+                retry_strategy: None,
+                notifications: true,
+                adaptive_interval: None,
+            })
         );
-        resources_mock.assert();
-        resources_mock.delete();
-
-        let tracker_one_resources = web_scraping
-            .get_resources_tracker_history(tracker_one.id, Default::default())
-            .await?;
-        let tracker_two_resources = web_scraping
-            .get_resources_tracker_history(tracker_two.id, Default::default())
-            .await?;
-        assert_eq!(tracker_one_resources.len(), 2);
-        assert!(tracker_two_resources.is_empty());
 
-        let resources_two = get_resources(946720900, "rev_3")?;
-        let resources_mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/api/web_page/resources")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperResourcesRequest::with_default_parameters(&tracker_two.url)
-                            .set_delay(Duration::from_millis(2000)),
-                    )
-                    .unwrap(),
-                );
-            then.status(200)
-                .header("Content-Type", "application/json")
-                .json_body_obj(&resources_two);
-        });
-        let diff = web_scraping
-            .create_resources_tracker_revision(tracker_two.id)
-            .await?;
-        assert!(diff.is_none());
-        resources_mock.assert();
+        assert_eq!(web_scraping.get_content_trackers().await?.len(), 2);
 
-        let tracker_one_resources = web_scraping
-            .get_resources_tracker_history(tracker_one.id, Default::default())
-            .await?;
-        let tracker_two_resources = web_scraping
-            .get_resources_tracker_history(tracker_two.id, Default::default())
-            .await?;
-        assert_eq!(tracker_one_resources.len(), 2);
-        assert_eq!(tracker_two_resources.len(), 1);
+        // Monitors can only be imported as content trackers.
+        let error = web_scraping
+            .import_resources_trackers(import_params)
+            .await
+            .unwrap_err()
+            .downcast::<SecutilsError>()?;
+        assert_eq!(error.code(), ErrorCode::InvalidInput);
+        assert_debug_snapshot!(error, @r###""Only web page content trackers can be imported from monitors.""###);
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_forwards_error_if_web_page_resources_extraction_fails(
+    async fn properly_verifies_web_page_tracker_history_integrity(
         pool: PgPool,
     ) -> anyhow::Result<()> {
-        let server = MockServer::start();
-        let mut config = mock_config()?;
-        config.components.web_scraper_url = Url::parse(&server.base_url())?;
-
-        let api = mock_api_with_config(pool, config).await?;
+        let api = mock_api(pool.clone()).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
 
         let web_scraping = api.web_scraping(&mock_user);
         let tracker = web_scraping
-            .create_resources_tracker(WebPageTrackerCreateParams {
+            .create_content_tracker(WebPageTrackerCreateParams {
                 name: "name_one".to_string(),
                 url: Url::parse("https://secutils.dev/one")?,
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0 0 * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: true,
-                }),
+                job_config: None,
+            })
+            .await?;
+
+        // Empty history is intact.
+        assert_eq!(
+            web_scraping
+                .verify_history_integrity::<WebPageContentTrackerTag>(tracker.id)
+                .await?,
+            WebPageTrackerHistoryIntegrity {
+                revisions: 0,
+                intact: true,
+                broken_revision_id: None,
+            }
+        );
+
+        let revisions = (0..6)
+            .map(|index| {
+                Ok(WebPageDataRevision::<WebPageContentTrackerTag> {
+                    id: Uuid::from_u128(0x10 + index),
+                    tracker_id: tracker.id,
+                    data: format!("\"rev_{index}\""),
+                    created_at: OffsetDateTime::from_unix_timestamp(
+                        946720800 + 100 * index as i64,
+                    )?,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
+                })
             })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        for revision in revisions.iter() {
+            api.db
+                .web_scraping(mock_user.id)
+                .insert_web_page_tracker_history_revision(revision)
+                .await?;
+        }
+
+        let chain = api
+            .db
+            .web_scraping(mock_user.id)
+            .get_web_page_tracker_history_chain(tracker.id)
+            .await?;
+        assert_eq!(chain.len(), 6);
+        assert!(chain[0].previous_hash.is_empty());
+        for (index, link) in chain.iter().enumerate().skip(1) {
+            assert_eq!(link.previous_hash, chain[index - 1].chain_hash);
+        }
+
+        assert_eq!(
+            web_scraping
+                .verify_history_integrity::<WebPageContentTrackerTag>(tracker.id)
+                .await?,
+            WebPageTrackerHistoryIntegrity {
+                revisions: 6,
+                intact: true,
+                broken_revision_id: None,
+            }
+        );
+
+        // Removing the oldest revision (e.g. due to the revisions limit) keeps the chain intact.
+        api.db
+            .web_scraping(mock_user.id)
+            .remove_web_page_tracker_history_revision(tracker.id, revisions[0].id)
             .await?;
+        assert_eq!(
+            web_scraping
+                .verify_history_integrity::<WebPageContentTrackerTag>(tracker.id)
+                .await?,
+            WebPageTrackerHistoryIntegrity {
+                revisions: 5,
+                intact: true,
+                broken_revision_id: None,
+            }
+        );
 
-        let web_scraper_mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/api/web_page/resources")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperResourcesRequest::with_default_parameters(&tracker.url)
-                            .set_delay(Duration::from_millis(2000)),
-                    )
-                    .unwrap(),
-                );
-            then.status(400)
-                .header("Content-Type", "application/json")
-                .json_body_obj(&WebScraperErrorResponse {
-                    message: "some client-error".to_string(),
-                });
-        });
+        // Removing revision in the middle of the chain re-links the following revisions.
+        api.db
+            .web_scraping(mock_user.id)
+            .remove_web_page_tracker_history_revision(tracker.id, revisions[2].id)
+            .await?;
+        assert_eq!(
+            web_scraping
+                .verify_history_integrity::<WebPageContentTrackerTag>(tracker.id)
+                .await?,
+            WebPageTrackerHistoryIntegrity {
+                revisions: 4,
+                intact: true,
+                broken_revision_id: None,
+            }
+        );
 
-        let scraper_error = web_scraping
-            .get_resources_tracker_history(
-                tracker.id,
-                WebPageResourcesTrackerGetHistoryParams {
-                    refresh: true,
-                    calculate_diff: false,
-                },
-            )
-            .await
-            .unwrap_err()
-            .downcast::<SecutilsError>()
-            .unwrap();
-        assert_eq!(scraper_error.status_code(), 400);
-        assert_debug_snapshot!(
-            scraper_error,
-            @r###""some client-error""###
+        // Removing revision in the middle of the chain bypassing the API is detected.
+        sqlx::query("DELETE FROM user_data_web_scraping_trackers_history WHERE id = $1")
+            .bind(revisions[4].id)
+            .execute(&pool)
+            .await?;
+        assert_eq!(
+            web_scraping
+                .verify_history_integrity::<WebPageContentTrackerTag>(tracker.id)
+                .await?,
+            WebPageTrackerHistoryIntegrity {
+                revisions: 3,
+                intact: false,
+                broken_revision_id: Some(revisions[5].id),
+            }
         );
 
-        let tracker_resources = web_scraping
-            .get_resources_tracker_history(tracker.id, Default::default())
+        // Tampering with the revision data is detected.
+        sqlx::query("UPDATE user_data_web_scraping_trackers_history SET data = $1 WHERE id = $2")
+            .bind(postcard::to_stdvec("\"tampered\"")?)
+            .bind(revisions[3].id)
+            .execute(&pool)
             .await?;
-        assert!(tracker_resources.is_empty());
-        web_scraper_mock.assert();
+        assert_eq!(
+            web_scraping
+                .verify_history_integrity::<WebPageContentTrackerTag>(tracker.id)
+                .await?,
+            WebPageTrackerHistoryIntegrity {
+                revisions: 3,
+                intact: false,
+                broken_revision_id: Some(revisions[3].id),
+            }
+        );
+
+        // Integrity of the unknown tracker history can't be verified.
+        let error = web_scraping
+            .verify_history_integrity::<WebPageContentTrackerTag>(Uuid::now_v7())
+            .await
+            .unwrap_err()
+            .downcast::<SecutilsError>()?;
+        assert_eq!(error.code(), ErrorCode::TrackerNotFound);
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_saves_web_page_content(pool: PgPool) -> anyhow::Result<()> {
-        let server = MockServer::start();
+    async fn properly_sets_web_page_tracker_webhook(pool: PgPool) -> anyhow::Result<()> {
+        let encryption_key = "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a";
         let mut config = mock_config()?;
-        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+        config.security.encryption_key = Some(encryption_key.to_string());
+        let api = mock_api_with_config(pool.clone(), config).await?;
 
-        let api = mock_api_with_config(pool, config).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
+        let another_user = mock_user_with_id(uuid!("00000000-0000-0000-0000-000000000002"))?;
+        api.db.insert_user(&another_user).await?;
 
         let web_scraping = api.web_scraping(&mock_user);
-        let tracker_one = web_scraping
+        let tracker = web_scraping
             .create_content_tracker(WebPageTrackerCreateParams {
                 name: "name_one".to_string(),
                 url: Url::parse("https://secutils.dev/one")?,
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0 0 * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: true,
-                }),
-            })
-            .await?;
-        let tracker_two = web_scraping
-            .create_content_tracker(WebPageTrackerCreateParams {
-                name: "name_two".to_string(),
-                url: Url::parse("https://secutils.dev/two")?,
-                settings: tracker_one.settings.clone(),
-                job_config: tracker_one.job_config.clone(),
+                job_config: None,
             })
             .await?;
 
-        let tracker_one_history = web_scraping
-            .get_content_tracker_history(tracker_one.id, Default::default())
-            .await?;
-        let tracker_two_history = web_scraping
-            .get_content_tracker_history(tracker_two.id, Default::default())
-            .await?;
-        assert!(tracker_one_history.is_empty());
-        assert!(tracker_two_history.is_empty());
-
-        let content_one = get_content(946720800, "\"rev_1\"")?;
-        let mut content_mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/api/web_page/content")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperContentRequest::with_default_parameters(&tracker_one.url)
-                            .set_delay(Duration::from_millis(2000)),
-                    )
-                    .unwrap(),
-                );
-            then.status(200)
-                .header("Content-Type", "application/json")
-                .json_body_obj(&content_one);
-        });
-
-        let tracker_one_history = web_scraping
-            .get_content_tracker_history(
-                tracker_one.id,
-                WebPageContentTrackerGetHistoryParams {
-                    refresh: true,
-                    calculate_diff: false,
+        // Set webhook.
+        web_scraping
+            .set_web_page_tracker_webhook(
+                tracker.id,
+                WebPageTrackerSetWebhookParams {
+                    url: Some(Url::parse("https://secutils.dev/webhook")?),
+                    secret: Some("my-webhook-secret".to_string()),
+                    headers: None,
                 },
             )
             .await?;
-        let tracker_two_content = web_scraping
-            .get_content_tracker_history(tracker_two.id, Default::default())
-            .await?;
-        assert_eq!(tracker_one_history.len(), 1);
-        assert_eq!(tracker_one_history[0].tracker_id, tracker_one.id);
-        assert_eq!(tracker_one_history[0].data, content_one.content);
-        assert!(tracker_two_content.is_empty());
-
-        content_mock.assert();
-        content_mock.delete();
-
-        let content_two = get_content(946720900, "\"rev_2\"")?;
-        let mut content_mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/api/web_page/content")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperContentRequest::with_default_parameters(&tracker_one.url)
-                            .set_delay(Duration::from_millis(2000))
-                            .set_previous_content("\"rev_1\""),
-                    )
-                    .unwrap(),
-                );
-            then.status(200)
-                .header("Content-Type", "application/json")
-                .json_body_obj(&content_two);
-        });
-        let revision = web_scraping
-            .create_content_tracker_revision(tracker_one.id)
+        let webhook = api
+            .web_scraping_system()
+            .get_web_page_tracker_webhook(tracker.id)
             .await?
             .unwrap();
+        assert_eq!(webhook.url, Url::parse("https://secutils.dev/webhook")?);
+        // Secret should be stored encrypted.
+        assert_ne!(webhook.secret, b"my-webhook-secret");
         assert_eq!(
-            revision.created_at,
-            OffsetDateTime::from_unix_timestamp(946720900)?
+            encryption::decrypt(encryption_key, &webhook.secret)?,
+            b"my-webhook-secret"
         );
-        assert_eq!(revision.data, "\"rev_2\"");
-        content_mock.assert();
-        content_mock.delete();
+        assert!(webhook.headers.is_none());
 
-        let tracker_one_content = web_scraping
-            .get_content_tracker_history(tracker_one.id, Default::default())
-            .await?;
-        let tracker_two_content = web_scraping
-            .get_content_tracker_history(tracker_two.id, Default::default())
-            .await?;
-        assert_eq!(tracker_one_content.len(), 2);
-        assert!(tracker_two_content.is_empty());
+        let set_and_fail = |result: anyhow::Result<()>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
 
-        let content_two = get_content(946720900, "\"rev_3\"")?;
-        let content_mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/api/web_page/content")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperContentRequest::with_default_parameters(&tracker_two.url)
-                            .set_delay(Duration::from_millis(2000)),
-                    )
-                    .unwrap(),
-                );
-            then.status(200)
-                .header("Content-Type", "application/json")
-                .json_body_obj(&content_two);
-        });
-        let revision = web_scraping
-            .create_content_tracker_revision(tracker_two.id)
-            .await?;
-        assert_eq!(revision.unwrap().data, "\"rev_3\"");
-        content_mock.assert();
+        // Invalid URL.
+        assert_debug_snapshot!(
+            set_and_fail(web_scraping.set_web_page_tracker_webhook(tracker.id, WebPageTrackerSetWebhookParams {
+                url: Some(Url::parse("ftp://secutils.dev/webhook")?),
+                secret: Some("my-webhook-secret".to_string()),
+                headers: None,
+            }).await),
+            @r###""Web page tracker webhook URL must be either `http` or `https` and have a valid public reachable domain name, but received ftp://secutils.dev/webhook.""###
+        );
 
-        let tracker_one_content = web_scraping
-            .get_content_tracker_history(
-                tracker_one.id,
-                WebPageContentTrackerGetHistoryParams {
-                    refresh: false,
-                    calculate_diff: true,
-                },
-            )
-            .await?;
-        let tracker_two_content = web_scraping
-            .get_content_tracker_history(tracker_two.id, Default::default())
-            .await?;
-        assert_eq!(tracker_one_content.len(), 2);
-        assert_eq!(tracker_two_content.len(), 1);
+        // Too short secret.
+        assert_debug_snapshot!(
+            set_and_fail(web_scraping.set_web_page_tracker_webhook(tracker.id, WebPageTrackerSetWebhookParams {
+                url: Some(Url::parse("https://secutils.dev/webhook")?),
+                secret: Some("secret".to_string()),
+                headers: None,
+            }).await),
+            @r###""Web page tracker webhook secret should be between 16 and 256 characters long.""###
+        );
 
+        // Too many headers.
         assert_debug_snapshot!(
-            tracker_one_content.into_iter().map(|rev| rev.data).collect::<Vec<_>>(),
-            @r###"
-        [
-            "\"rev_1\"",
-            "@@ -1 +1 @@\n-rev_1\n+rev_2\n",
-        ]
-        "###
+            set_and_fail(web_scraping.set_web_page_tracker_webhook(tracker.id, WebPageTrackerSetWebhookParams {
+                url: Some(Url::parse("https://secutils.dev/webhook")?),
+                secret: Some("my-webhook-secret".to_string()),
+                headers: Some((0..21).map(|index| (format!("X-Header-{index}"), "value".to_string())).collect()),
+            }).await),
+            @r###""Web page tracker webhook cannot have more than 20 custom headers.""###
         );
+
+        // Invalid header name.
         assert_debug_snapshot!(
-            tracker_two_content.into_iter().map(|rev| rev.data).collect::<Vec<_>>(),
-            @r###"
-        [
-            "\"rev_3\"",
-        ]
-        "###
+            set_and_fail(web_scraping.set_web_page_tracker_webhook(tracker.id, WebPageTrackerSetWebhookParams {
+                url: Some(Url::parse("https://secutils.dev/webhook")?),
+                secret: Some("my-webhook-secret".to_string()),
+                headers: Some(vec![("X Header".to_string(), "value".to_string())]),
+            }).await),
+            @r###""Web page tracker webhook header name is not valid, but received X Header.""###
         );
 
-        let tracker_one_content = web_scraping
-            .get_content_tracker_history(
-                tracker_one.id,
-                WebPageContentTrackerGetHistoryParams {
-                    refresh: false,
-                    calculate_diff: false,
+        // Reserved header.
+        assert_debug_snapshot!(
+            set_and_fail(web_scraping.set_web_page_tracker_webhook(tracker.id, WebPageTrackerSetWebhookParams {
+                url: Some(Url::parse("https://secutils.dev/webhook")?),
+                secret: Some("my-webhook-secret".to_string()),
+                headers: Some(vec![("x-secutils-signature".to_string(), "value".to_string())]),
+            }).await),
+            @r###""Web page tracker webhook header ('x-secutils-signature') is set by Secutils.dev and cannot be overridden.""###
+        );
+        assert_debug_snapshot!(
+            set_and_fail(web_scraping.set_web_page_tracker_webhook(tracker.id, WebPageTrackerSetWebhookParams {
+                url: Some(Url::parse("https://secutils.dev/webhook")?),
+                secret: Some("my-webhook-secret".to_string()),
+                headers: Some(vec![("Content-Type".to_string(), "text/plain".to_string())]),
+            }).await),
+            @r###""Web page tracker webhook header ('Content-Type') is set by Secutils.dev and cannot be overridden.""###
+        );
+
+        // Invalid header value, value isn't included into the error message.
+        assert_debug_snapshot!(
+            set_and_fail(web_scraping.set_web_page_tracker_webhook(tracker.id, WebPageTrackerSetWebhookParams {
+                url: Some(Url::parse("https://secutils.dev/webhook")?),
+                secret: Some("my-webhook-secret".to_string()),
+                headers: Some(vec![("Authorization".to_string(), "Bearer\nmy-token".to_string())]),
+            }).await),
+            @r###""Web page tracker webhook header ('Authorization') value should be a valid header value between 1 and 4096 bytes long.""###
+        );
+        assert_debug_snapshot!(
+            set_and_fail(web_scraping.set_web_page_tracker_webhook(tracker.id, WebPageTrackerSetWebhookParams {
+                url: Some(Url::parse("https://secutils.dev/webhook")?),
+                secret: Some("my-webhook-secret".to_string()),
+                headers: Some(vec![("Authorization".to_string(), "a".repeat(4097))]),
+            }).await),
+            @r###""Web page tracker webhook header ('Authorization') value should be a valid header value between 1 and 4096 bytes long.""###
+        );
+
+        // Tracker of another user.
+        assert_eq!(
+            set_and_fail(
+                api.web_scraping(&another_user)
+                    .set_web_page_tracker_webhook(
+                        tracker.id,
+                        WebPageTrackerSetWebhookParams {
+                            url: Some(Url::parse("https://secutils.dev/webhook-two")?),
+                            secret: Some("my-webhook-secret".to_string()),
+                            headers: None,
+                        }
+                    )
+                    .await
+            )
+            .to_string(),
+            format!("Web page tracker ('{}') is not found.", tracker.id)
+        );
+        api.web_scraping(&another_user)
+            .set_web_page_tracker_webhook(
+                tracker.id,
+                WebPageTrackerSetWebhookParams {
+                    url: None,
+                    secret: None,
+                    headers: None,
+                },
+            )
+            .await?;
+        assert_eq!(
+            api.web_scraping_system()
+                .get_web_page_tracker_webhook(tracker.id)
+                .await?,
+            Some(webhook)
+        );
+
+        // Set webhook with custom headers.
+        let headers = vec![("Authorization".to_string(), "Bearer my-token".to_string())];
+        web_scraping
+            .set_web_page_tracker_webhook(
+                tracker.id,
+                WebPageTrackerSetWebhookParams {
+                    url: Some(Url::parse("https://secutils.dev/webhook")?),
+                    secret: Some("my-webhook-secret".to_string()),
+                    headers: Some(headers.clone()),
+                },
+            )
+            .await?;
+        let webhook = api
+            .web_scraping_system()
+            .get_web_page_tracker_webhook(tracker.id)
+            .await?
+            .unwrap();
+        // Headers should be stored encrypted.
+        assert!(!String::from_utf8_lossy(webhook.headers.as_ref().unwrap()).contains("my-token"));
+        assert_eq!(webhook.decrypt_headers(encryption_key)?, headers);
+
+        // Remove webhook.
+        web_scraping
+            .set_web_page_tracker_webhook(
+                tracker.id,
+                WebPageTrackerSetWebhookParams {
+                    url: None,
+                    secret: None,
+                    headers: None,
                 },
             )
             .await?;
+        assert!(api
+            .web_scraping_system()
+            .get_web_page_tracker_webhook(tracker.id)
+            .await?
+            .is_none());
+
+        // Webhooks aren't supported without encryption key.
+        let api = mock_api(pool).await?;
         assert_debug_snapshot!(
-            tracker_one_content.into_iter().map(|rev| rev.data).collect::<Vec<_>>(),
-            @r###"
-        [
-            "\"rev_1\"",
-            "\"rev_2\"",
-        ]
-        "###
+            set_and_fail(api.web_scraping(&mock_user).set_web_page_tracker_webhook(tracker.id, WebPageTrackerSetWebhookParams {
+                url: Some(Url::parse("https://secutils.dev/webhook")?),
+                secret: Some("my-webhook-secret".to_string()),
+                headers: None,
+            }).await),
+            @r###""Web page tracker webhooks are not supported.""###
         );
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_forwards_error_if_web_page_content_extraction_fails(
-        pool: PgPool,
-    ) -> anyhow::Result<()> {
-        let server = MockServer::start();
+    async fn properly_rotates_web_page_tracker_webhook_secrets(pool: PgPool) -> anyhow::Result<()> {
+        let encryption_key = "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a";
         let mut config = mock_config()?;
-        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+        config.security.encryption_key = Some(encryption_key.to_string());
+        let api = mock_api_with_config(pool.clone(), config).await?;
 
-        let api = mock_api_with_config(pool, config).await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
+        let another_user = mock_user_with_id(uuid!("00000000-0000-0000-0000-000000000002"))?;
+        api.db.insert_user(&another_user).await?;
+
+        let trackers = [
+            (mock_user.id, uuid!("00000000-0000-0000-0000-000000000001")),
+            (mock_user.id, uuid!("00000000-0000-0000-0000-000000000002")),
+            (
+                another_user.id,
+                uuid!("00000000-0000-0000-0000-000000000003"),
+            ),
+        ];
+        for (user_id, tracker_id) in trackers {
+            let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+                tracker_id,
+                "some-name",
+                "https://secutils.dev",
+                3,
+            )?
+            .build();
+            api.db
+                .web_scraping(user_id)
+                .insert_web_page_tracker(&tracker)
+                .await?;
+            api.db
+                .web_scraping(user_id)
+                .upsert_web_page_tracker_webhook(
+                    tracker_id,
+                    &WebPageTrackerWebhook {
+                        url: Url::parse("https://secutils.dev/webhook")?,
+                        secret: encryption::encrypt(encryption_key, b"my-webhook-secret")?,
+                        previous_secret: None,
+                        previous_secret_expires_at: None,
+                        headers: None,
+                    },
+                )
+                .await?;
+        }
 
-        let web_scraping = api.web_scraping(&mock_user);
-        let tracker = web_scraping
-            .create_content_tracker(WebPageTrackerCreateParams {
-                name: "name_one".to_string(),
-                url: Url::parse("https://secutils.dev/one")?,
-                settings: WebPageTrackerSettings {
-                    revisions: 3,
-                    delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
-                },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0 0 * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: true,
-                }),
-            })
+        let secrets = api
+            .web_scraping(&mock_user)
+            .rotate_web_page_tracker_webhook_secrets()
             .await?;
+        assert_eq!(
+            secrets
+                .iter()
+                .map(|secret| secret.tracker_id)
+                .collect::<Vec<_>>(),
+            vec![trackers[0].1, trackers[1].1]
+        );
+        assert_ne!(secrets[0].secret, secrets[1].secret);
 
-        let content_mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/api/web_page/content")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperContentRequest::with_default_parameters(&tracker.url)
-                            .set_delay(Duration::from_millis(2000)),
-                    )
-                    .unwrap(),
-                );
-            then.status(400)
-                .header("Content-Type", "application/json")
-                .json_body_obj(&WebScraperErrorResponse {
-                    message: "some client-error".to_string(),
-                });
-        });
+        for secret in &secrets {
+            let webhook = api
+                .web_scraping_system()
+                .get_web_page_tracker_webhook(secret.tracker_id)
+                .await?
+                .unwrap();
+            assert_eq!(webhook.url, Url::parse("https://secutils.dev/webhook")?);
+            assert_eq!(secret.secret.len(), 32);
+            // Secret should be stored encrypted.
+            assert_ne!(webhook.secret, secret.secret.as_bytes());
+            assert_eq!(
+                encryption::decrypt(encryption_key, &webhook.secret)?,
+                secret.secret.as_bytes()
+            );
+            // Previous secret stops being used immediately without grace period.
+            assert!(webhook.previous_secret.is_none());
+            assert!(webhook.previous_secret_expires_at.is_none());
+        }
 
-        let scraper_error = web_scraping
-            .get_content_tracker_history(
-                tracker.id,
-                WebPageContentTrackerGetHistoryParams {
-                    refresh: true,
-                    calculate_diff: false,
-                },
-            )
-            .await
-            .unwrap_err()
-            .downcast::<SecutilsError>()
+        // Webhooks of other users aren't affected.
+        let webhook = api
+            .web_scraping_system()
+            .get_web_page_tracker_webhook(trackers[2].1)
+            .await?
             .unwrap();
-        assert_eq!(scraper_error.status_code(), 400);
-        assert_debug_snapshot!(
-            scraper_error,
-            @r###""some client-error""###
+        assert_eq!(
+            encryption::decrypt(encryption_key, &webhook.secret)?,
+            b"my-webhook-secret"
         );
 
-        let tracker_content = web_scraping
-            .get_content_tracker_history(tracker.id, Default::default())
-            .await?;
-        assert!(tracker_content.is_empty());
-        content_mock.assert();
+        // Every rotation is recorded to the audit log.
+        let audit_log = api.users().get_audit_log(mock_user.id).await?;
+        assert_eq!(
+            audit_log
+                .iter()
+                .map(|entry| entry.action.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "Rotated web page tracker ('00000000-0000-0000-0000-000000000001') webhook secret",
+                "Rotated web page tracker ('00000000-0000-0000-0000-000000000002') webhook secret"
+            ]
+        );
+        assert!(api.users().get_audit_log(another_user.id).await?.is_empty());
+
+        // Previous secret is kept until the grace period ends, if configured.
+        let mut config = mock_config()?;
+        config.security.encryption_key = Some(encryption_key.to_string());
+        config.utils.web_scraping.webhook_secret_grace_period = Some(Duration::from_secs(3600));
+        let api = mock_api_with_config(pool.clone(), config).await?;
+        let rotated_at = OffsetDateTime::now_utc();
+        let new_secrets = api
+            .web_scraping(&mock_user)
+            .rotate_web_page_tracker_webhook_secrets()
+            .await?;
+        assert_eq!(new_secrets.len(), 2);
+        let webhook = api
+            .web_scraping_system()
+            .get_web_page_tracker_webhook(trackers[0].1)
+            .await?
+            .unwrap();
+        assert_eq!(
+            encryption::decrypt(encryption_key, &webhook.secret)?,
+            new_secrets[0].secret.as_bytes()
+        );
+        assert_eq!(
+            encryption::decrypt(encryption_key, webhook.previous_secret.as_ref().unwrap())?,
+            secrets[0].secret.as_bytes()
+        );
+        assert!(
+            webhook.previous_secret_expires_at.unwrap() >= rotated_at + Duration::from_secs(3600)
+        );
+        assert_eq!(
+            webhook.active_previous_secret(rotated_at),
+            webhook.previous_secret.as_deref()
+        );
+
+        // Webhooks aren't supported without encryption key.
+        let api = mock_api(pool).await?;
+        assert_debug_snapshot!(
+            api.web_scraping(&mock_user)
+                .rotate_web_page_tracker_webhook_secrets()
+                .await
+                .unwrap_err()
+                .downcast::<SecutilsError>()
+                .unwrap(),
+            @r###""Web page tracker webhooks are not supported.""###
+        );
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_ignores_web_page_resources_with_the_same_timestamp(
+    async fn properly_validates_web_page_tracker_webhook_domain(
         pool: PgPool,
     ) -> anyhow::Result<()> {
-        let server = MockServer::start();
         let mut config = mock_config()?;
-        config.components.web_scraper_url = Url::parse(&server.base_url())?;
-
+        config.security.encryption_key =
+            Some("4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a".to_string());
+        config.utils.web_scraping.webhook_allowed_domains =
+            Some(["hooks.slack.com".to_string()].into_iter().collect());
         let api = mock_api_with_config(pool, config).await?;
+
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
 
-        let web_scraping = api.web_scraping(&mock_user);
-        let tracker = web_scraping
-            .create_resources_tracker(WebPageTrackerCreateParams {
-                name: "name_one".to_string(),
-                url: Url::parse("https://secutils.dev/one")?,
-                settings: WebPageTrackerSettings {
-                    revisions: 3,
-                    delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
-                },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0 0 * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: true,
-                }),
-            })
-            .await?;
-
-        let tracker_resources = web_scraping
-            .get_resources_tracker_history(tracker.id, Default::default())
-            .await?;
-        assert!(tracker_resources.is_empty());
-
-        let resources_one = get_resources(946720800, "rev_1")?;
-        let resources_mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/api/web_page/resources")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperResourcesRequest::with_default_parameters(&tracker.url)
-                            .set_delay(Duration::from_millis(2000)),
-                    )
-                    .unwrap(),
-                );
-            then.status(200)
-                .header("Content-Type", "application/json")
-                .json_body_obj(&resources_one);
-        });
-
-        let diff = web_scraping
-            .create_resources_tracker_revision(tracker.id)
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "name_one",
+            "https://secutils.dev/one",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
             .await?;
-        assert!(diff.is_none());
-        resources_mock.assert_hits(1);
 
-        let diff = web_scraping
-            .create_resources_tracker_revision(tracker.id)
-            .await?;
-        assert!(diff.is_none());
-        resources_mock.assert_hits(2);
+        let web_scraping = api.web_scraping(&mock_user);
+        let set_result = web_scraping
+            .set_web_page_tracker_webhook(
+                tracker.id,
+                WebPageTrackerSetWebhookParams {
+                    url: Some(Url::parse("https://secutils.dev/webhook")?),
+                    secret: Some("my-webhook-secret".to_string()),
+                    headers: None,
+                },
+            )
+            .await
+            .unwrap_err()
+            .downcast::<SecutilsError>()?;
+        assert_eq!(set_result.code(), ErrorCode::NotAllowed);
+        assert_debug_snapshot!(
+            set_result,
+            @r###""Web page tracker webhook URL domain isn't allowed by the server configuration, but received https://secutils.dev/webhook.""###
+        );
+        assert!(api
+            .web_scraping_system()
+            .get_web_page_tracker_webhook(tracker.id)
+            .await?
+            .is_none());
 
-        let tracker_resources = web_scraping
-            .get_resources_tracker_history(tracker.id, Default::default())
+        web_scraping
+            .set_web_page_tracker_webhook(
+                tracker.id,
+                WebPageTrackerSetWebhookParams {
+                    url: Some(Url::parse("https://hooks.slack.com/services/xxx")?),
+                    secret: Some("my-webhook-secret".to_string()),
+                    headers: None,
+                },
+            )
             .await?;
-        assert_eq!(tracker_resources.len(), 1);
+        assert_eq!(
+            api.web_scraping_system()
+                .get_web_page_tracker_webhook(tracker.id)
+                .await?
+                .map(|webhook| webhook.url),
+            Some(Url::parse("https://hooks.slack.com/services/xxx")?)
+        );
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_ignores_web_page_content_with_the_same_timestamp(
-        pool: PgPool,
-    ) -> anyhow::Result<()> {
+    async fn properly_previews_tracker_notifications(pool: PgPool) -> anyhow::Result<()> {
         let server = MockServer::start();
         let mut config = mock_config()?;
-        config.components.web_scraper_url = Url::parse(&server.base_url())?;
-
+        config.security.encryption_key =
+            Some("4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a".to_string());
         let api = mock_api_with_config(pool, config).await?;
+
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
 
-        let web_scraping = api.web_scraping(&mock_user);
-        let tracker = web_scraping
-            .create_content_tracker(WebPageTrackerCreateParams {
-                name: "name_one".to_string(),
-                url: Url::parse("https://secutils.dev/one")?,
-                settings: WebPageTrackerSettings {
-                    revisions: 3,
-                    delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
-                },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0 0 * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: true,
-                }),
-            })
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "name_one",
+            "https://secutils.dev/one",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
             .await?;
 
-        let tracker_content = web_scraping
-            .get_content_tracker_history(tracker.id, Default::default())
+        // Only email is previewed if webhook isn't configured.
+        let web_scraping = api.web_scraping(&mock_user);
+        let params = WebPageTrackerPreviewNotificationParams {
+            sample_diff: "-old\n+new".to_string(),
+        };
+        let preview = web_scraping
+            .preview_tracker_notification::<WebPageContentTrackerTag>(tracker.id, params.clone())
             .await?;
-        assert!(tracker_content.is_empty());
-
-        let content_one = get_content(946720800, "\"rev_1\"")?;
-        let mut content_mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/api/web_page/content")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperContentRequest::with_default_parameters(&tracker.url)
-                            .set_delay(Duration::from_millis(2000)),
-                    )
-                    .unwrap(),
-                );
-            then.status(200)
-                .header("Content-Type", "application/json")
-                .json_body_obj(&content_one);
-        });
+        assert!(preview.webhook_payload.is_none());
+        assert!(preview.webhook_headers.is_none());
 
-        let revision = web_scraping
-            .create_content_tracker_revision(tracker.id)
+        // Email preview matches the email that would be sent.
+        let template = NotificationContent::Template(
+            NotificationContentTemplate::WebPageContentTrackerChanges {
+                tracker_name: "name_one".to_string(),
+                content: Ok("-old\n+new".to_string()),
+            },
+        );
+        let email = template.clone().into_email(&api).await?;
+        assert_eq!(preview.subject, email.subject);
+        assert_eq!(preview.text, email.text);
+        assert_eq!(preview.html, email.html);
+        assert!(preview.text.contains("-old\n+new"));
+
+        // Webhook payload preview matches the payload that would be delivered.
+        api.db
+            .web_scraping(mock_user.id)
+            .upsert_web_page_tracker_webhook(
+                tracker.id,
+                &WebPageTrackerWebhook {
+                    url: Url::parse(&format!("http://localhost:{}/webhook", server.port()))?,
+                    secret: encryption::encrypt(
+                        "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a",
+                        b"my-webhook-secret",
+                    )?,
+                    previous_secret: None,
+                    previous_secret_expires_at: None,
+                    headers: Some(encryption::encrypt(
+                        "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a",
+                        br#"[["Authorization","Bearer my-token"]]"#,
+                    )?),
+                },
+            )
             .await?;
-        assert_eq!(revision.unwrap().data, "\"rev_1\"");
-        content_mock.assert_hits(1);
-
-        content_mock.delete();
-        let content_mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/api/web_page/content")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperContentRequest::with_default_parameters(&tracker.url)
-                            .set_delay(Duration::from_millis(2000))
-                            .set_previous_content("\"rev_1\""),
-                    )
-                    .unwrap(),
-                );
-            then.status(200)
-                .header("Content-Type", "application/json")
-                .json_body_obj(&content_one);
+        let preview = web_scraping
+            .preview_tracker_notification::<WebPageContentTrackerTag>(tracker.id, params.clone())
+            .await?;
+        // Values of the custom webhook headers are redacted.
+        assert_eq!(
+            preview.webhook_headers,
+            Some(vec![(
+                "Authorization".to_string(),
+                "[REDACTED]".to_string()
+            )])
+        );
+        let mut webhook_payload = preview.webhook_payload.unwrap();
+        assert_eq!(webhook_payload["trackerId"], tracker.id.to_string());
+        webhook_payload.as_object_mut().unwrap().remove("timestamp");
+
+        let webhook_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/webhook")
+                .json_body_partial(webhook_payload.to_string());
+            then.status(200);
         });
 
-        let revision = web_scraping
-            .create_content_tracker_revision(tracker.id)
-            .await?;
-        assert!(revision.is_none());
-        content_mock.assert_hits(1);
+        // Nothing is delivered when notification is previewed.
+        webhook_mock.assert_hits(0);
 
-        let tracker_content = web_scraping
-            .get_content_tracker_history(tracker.id, Default::default())
+        api.notifications()
+            .schedule_notification(
+                NotificationDestination::TrackerWebhook {
+                    user_id: mock_user.id,
+                    tracker_id: tracker.id,
+                },
+                template,
+                OffsetDateTime::from_unix_timestamp(946720800)?,
+            )
             .await?;
-        assert_eq!(tracker_content.len(), 1);
+        assert_eq!(api.notifications().send_pending_notifications(3).await?, 1);
+        webhook_mock.assert();
+
+        // Sample diff should be valid for the tracker kind.
+        let resources_tracker = MockWebPageTrackerBuilder::<WebPageResourcesTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "name_two",
+            "https://secutils.dev/two",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&resources_tracker)
+            .await?;
+        assert_debug_snapshot!(
+            web_scraping
+                .preview_tracker_notification::<WebPageResourcesTrackerTag>(
+                    resources_tracker.id,
+                    params.clone()
+                )
+                .await
+                .unwrap_err()
+                .downcast::<SecutilsError>()?,
+            @r###""Web page resources tracker sample diff should be a number of changed resources.""###
+        );
+        let preview = web_scraping
+            .preview_tracker_notification::<WebPageResourcesTrackerTag>(
+                resources_tracker.id,
+                WebPageTrackerPreviewNotificationParams {
+                    sample_diff: "3".to_string(),
+                },
+            )
+            .await?;
+        assert_eq!(
+            preview.subject,
+            "[Secutils.dev] Change detected: \"name_two\""
+        );
+
+        // Tracker of a different kind cannot be previewed.
+        let preview_error = web_scraping
+            .preview_tracker_notification::<WebPageScreenshotTrackerTag>(tracker.id, params)
+            .await
+            .unwrap_err()
+            .downcast::<SecutilsError>()?;
+        assert_eq!(preview_error.code(), ErrorCode::TrackerNotFound);
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_ignores_web_page_resources_with_no_diff(pool: PgPool) -> anyhow::Result<()> {
+    async fn properly_ignores_web_page_screenshot_below_diff_threshold(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
         let server = MockServer::start();
         let mut config = mock_config()?;
         config.components.web_scraper_url = Url::parse(&server.base_url())?;
@@ -3809,165 +13463,271 @@ mod tests {
 
         let web_scraping = api.web_scraping(&mock_user);
         let tracker = web_scraping
-            .create_resources_tracker(WebPageTrackerCreateParams {
+            .create_screenshot_tracker(WebPageTrackerCreateParams {
                 name: "name_one".to_string(),
                 url: Url::parse("https://secutils.dev/one")?,
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0 0 * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: true,
-                }),
+                job_config: None,
             })
             .await?;
 
-        let tracker_resources = web_scraping
-            .get_resources_tracker_history(tracker.id, Default::default())
-            .await?;
-        assert!(tracker_resources.is_empty());
-
-        let resources_one = get_resources(946720800, "rev_1")?;
-        let mut resources_mock = server.mock(|when, then| {
+        // The first screenshot is stored, but isn't considered as a change.
+        let mut screenshot_mock = server.mock(|when, then| {
             when.method(httpmock::Method::POST)
-                .path("/api/web_page/resources")
+                .path("/api/web_page/screenshot")
                 .json_body(
                     serde_json::to_value(
-                        WebScraperResourcesRequest::with_default_parameters(&tracker.url)
+                        WebScraperScreenshotRequest::with_default_parameters(&tracker.url)
                             .set_delay(Duration::from_millis(2000)),
                     )
                     .unwrap(),
                 );
             then.status(200)
                 .header("Content-Type", "application/json")
-                .json_body_obj(&resources_one);
+                .json_body_obj(&WebScraperScreenshotResponse {
+                    timestamp: OffsetDateTime::from_unix_timestamp(946720800).unwrap(),
+                    screenshot: mock_screenshot(100, 100, 0).unwrap(),
+                });
         });
+        let revision = web_scraping
+            .create_screenshot_tracker_revision(tracker.id)
+            .await?;
+        assert!(revision.is_none());
+        screenshot_mock.assert();
+        screenshot_mock.delete();
 
-        let diff = web_scraping
-            .create_resources_tracker_revision(tracker.id)
+        // 0.5% of pixels changed, that's below the threshold.
+        let mut screenshot_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/screenshot");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&WebScraperScreenshotResponse {
+                    timestamp: OffsetDateTime::from_unix_timestamp(946720900).unwrap(),
+                    screenshot: mock_screenshot(100, 100, 50).unwrap(),
+                });
+        });
+        let revision = web_scraping
+            .create_screenshot_tracker_revision(tracker.id)
             .await?;
-        assert!(diff.is_none());
-        resources_mock.assert();
-        resources_mock.delete();
+        assert!(revision.is_none());
+        screenshot_mock.assert();
+        screenshot_mock.delete();
 
-        let resources_two = get_resources(946720900, "rev_1")?;
-        let resources_mock = server.mock(|when, then| {
+        // 5% of pixels changed, that's above the threshold.
+        let screenshot_mock = server.mock(|when, then| {
             when.method(httpmock::Method::POST)
-                .path("/api/web_page/resources")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperResourcesRequest::with_default_parameters(&tracker.url)
-                            .set_delay(Duration::from_millis(2000)),
-                    )
-                    .unwrap(),
-                );
+                .path("/api/web_page/screenshot");
             then.status(200)
                 .header("Content-Type", "application/json")
-                .json_body_obj(&resources_two);
+                .json_body_obj(&WebScraperScreenshotResponse {
+                    timestamp: OffsetDateTime::from_unix_timestamp(946721000).unwrap(),
+                    screenshot: mock_screenshot(100, 100, 500).unwrap(),
+                });
         });
+        let revision = web_scraping
+            .create_screenshot_tracker_revision(tracker.id)
+            .await?
+            .unwrap();
+        assert_eq!(revision.data.diff, Some(0.05));
+        assert_eq!(revision.data.image, mock_screenshot(100, 100, 500)?);
+        screenshot_mock.assert();
 
-        let diff = web_scraping
-            .create_resources_tracker_revision(tracker.id)
+        let history = web_scraping
+            .get_screenshot_tracker_history(
+                tracker.id,
+                WebPageScreenshotTrackerGetHistoryParams {
+                    refresh: false,
+                    calculate_diff: true,
+                },
+            )
             .await?;
-        assert!(diff.is_none());
-        resources_mock.assert();
+        assert_eq!(
+            history
+                .into_iter()
+                .map(|revision| revision.data.diff)
+                .collect::<Vec<_>>(),
+            vec![None, Some(0.05)]
+        );
 
-        let tracker_resources = web_scraping
-            .get_resources_tracker_history(tracker.id, Default::default())
-            .await?;
-        assert_eq!(tracker_resources.len(), 1);
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_to_create_web_page_screenshot_tracker_with_too_many_revisions(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let create_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
+
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_screenshot_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 6,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: None,
+            }).await),
+            @r###""Web page screenshot tracker revisions count cannot be greater than 5.""###
+        );
 
         Ok(())
     }
 
     #[sqlx::test]
-    async fn properly_ignores_web_page_content_with_no_diff(pool: PgPool) -> anyhow::Result<()> {
-        let server = MockServer::start();
-        let mut config = mock_config()?;
-        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+    async fn properly_tracks_web_page_dns_records_changes(pool: PgPool) -> anyhow::Result<()> {
+        let mock_txt_api = |value: &'static str| {
+            mock_api_with_network(
+                pool.clone(),
+                mock_network_with_records::<2>(vec![
+                    Record::from_rdata(Name::new(), 300, RData::A(A(Ipv4Addr::new(172, 32, 0, 2)))),
+                    Record::from_rdata(Name::new(), 300, RData::TXT(TXT::new(vec![value.into()]))),
+                ]),
+            )
+        };
 
-        let api = mock_api_with_config(pool, config).await?;
+        let api = mock_txt_api("v=1").await?;
         let mock_user = mock_user()?;
         api.db.insert_user(&mock_user).await?;
 
-        let web_scraping = api.web_scraping(&mock_user);
-        let tracker = web_scraping
-            .create_content_tracker(WebPageTrackerCreateParams {
+        let tracker = api
+            .web_scraping(&mock_user)
+            .create_dns_records_tracker(WebPageTrackerCreateParams {
                 name: "name_one".to_string(),
-                url: Url::parse("https://secutils.dev/one")?,
+                url: Url::parse("https://secutils.dev")?,
                 settings: WebPageTrackerSettings {
                     revisions: 3,
-                    delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    delay: Duration::ZERO,
+                    record_types: Some(vec![WebPageDnsRecordType::Txt]),
+                    ..Default::default()
                 },
-                job_config: Some(SchedulerJobConfig {
-                    schedule: "0 0 * * * *".to_string(),
-                    retry_strategy: None,
-                    notifications: true,
-                }),
+                job_config: None,
             })
             .await?;
 
-        let tracker_content = web_scraping
-            .get_content_tracker_history(tracker.id, Default::default())
-            .await?;
-        assert!(tracker_content.is_empty());
+        // The first revision is stored, but isn't considered as a change.
+        let web_scraping = api.web_scraping(&mock_user);
+        assert!(web_scraping
+            .create_dns_records_tracker_revision(tracker.id)
+            .await?
+            .is_none());
 
-        let content_one = get_content(946720800, "\"rev_1\"")?;
-        let mut content_mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/api/web_page/content")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperContentRequest::with_default_parameters(&tracker.url)
-                            .set_delay(Duration::from_millis(2000)),
-                    )
-                    .unwrap(),
-                );
-            then.status(200)
-                .header("Content-Type", "application/json")
-                .json_body_obj(&content_one);
-        });
+        // Unchanged records don't produce a new revision.
+        assert!(web_scraping
+            .create_dns_records_tracker_revision(tracker.id)
+            .await?
+            .is_none());
+        assert_eq!(
+            web_scraping
+                .get_dns_records_tracker_history(tracker.id, Default::default())
+                .await?
+                .len(),
+            1
+        );
 
+        // Changed TXT record produces a new revision with a diff.
+        let api = mock_txt_api("v=2").await?;
+        let web_scraping = api.web_scraping(&mock_user);
         let revision = web_scraping
-            .create_content_tracker_revision(tracker.id)
+            .create_dns_records_tracker_revision(tracker.id)
+            .await?
+            .unwrap();
+        assert_eq!(
+            revision.data.records,
+            vec![
+                WebPageDnsRecord::new(WebPageDnsRecordType::Txt, "v=2")
+                    .with_diff_status(WebPageDnsRecordDiffStatus::Added),
+                WebPageDnsRecord::new(WebPageDnsRecordType::Txt, "v=1")
+                    .with_diff_status(WebPageDnsRecordDiffStatus::Removed),
+            ]
+        );
+
+        let history = web_scraping
+            .get_dns_records_tracker_history(
+                tracker.id,
+                WebPageDnsRecordsTrackerGetHistoryParams {
+                    refresh: false,
+                    calculate_diff: true,
+                },
+            )
             .await?;
-        assert_eq!(revision.unwrap().data, "\"rev_1\"");
-        content_mock.assert();
-        content_mock.delete();
+        assert_eq!(history.len(), 2);
+        assert_eq!(
+            history[0].data.records,
+            vec![WebPageDnsRecord::new(WebPageDnsRecordType::Txt, "v=1")]
+        );
+        assert_eq!(history[1].data.records, revision.data.records);
 
-        let content_two = get_content(946720900, "\"rev_1\"")?;
-        let content_mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/api/web_page/content")
-                .json_body(
-                    serde_json::to_value(
-                        WebScraperContentRequest::with_default_parameters(&tracker.url)
-                            .set_delay(Duration::from_millis(2000))
-                            .set_previous_content("\"rev_1\""),
-                    )
-                    .unwrap(),
-                );
-            then.status(200)
-                .header("Content-Type", "application/json")
-                .json_body_obj(&content_two);
-        });
+        Ok(())
+    }
 
-        let revision = web_scraping
-            .create_content_tracker_revision(tracker.id)
-            .await?;
-        assert!(revision.is_none());
-        content_mock.assert();
+    #[sqlx::test]
+    async fn fails_to_create_web_page_dns_records_tracker_with_invalid_settings(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
 
-        let tracker_content = web_scraping
-            .get_content_tracker_history(tracker.id, Default::default())
-            .await?;
-        assert_eq!(tracker_content.len(), 1);
+        let web_scraping = api.web_scraping(&mock_user);
+        fn create_and_fail<T: std::fmt::Debug>(result: anyhow::Result<T>) -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        }
+
+        let settings = WebPageTrackerSettings {
+            revisions: 3,
+            delay: Duration::ZERO,
+            ..Default::default()
+        };
+
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_dns_records_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    record_types: Some(vec![]),
+                    ..settings.clone()
+                },
+                job_config: None,
+            }).await),
+            @r###""Web page DNS records tracker should track at least one DNS record type.""###
+        );
+
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_dns_records_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: Url::parse("https://172.32.0.2")?,
+                settings: settings.clone(),
+                job_config: None,
+            }).await),
+            @r###""Web page DNS records tracker URL must have a domain name, but received https://172.32.0.2/.""###
+        );
+
+        assert_debug_snapshot!(
+            create_and_fail(web_scraping.create_content_tracker(WebPageTrackerCreateParams {
+                name: "name".to_string(),
+                url: Url::parse("https://secutils.dev")?,
+                settings: WebPageTrackerSettings {
+                    record_types: Some(vec![WebPageDnsRecordType::A]),
+                    ..settings
+                },
+                job_config: None,
+            }).await),
+            @r###""Web page content tracker doesn't support DNS record types.""###
+        );
 
         Ok(())
     }
@@ -3990,13 +13750,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -4062,13 +13822,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -4136,13 +13896,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -4190,6 +13950,130 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn does_not_call_web_scraper_in_safe_mode(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let mut api = mock_api_with_config(pool, config).await?;
+        api.network = api.network.clone().with_safe_mode(true);
+
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&get_content(946720800, "\"rev_1\"").unwrap());
+        });
+
+        let error = api
+            .web_scraping(&mock_user)
+            .create_content_tracker_revision(tracker.id)
+            .await
+            .unwrap_err()
+            .downcast::<SecutilsError>()?;
+        assert_eq!(error.code(), ErrorCode::SafeMode);
+        assert_debug_snapshot!(
+            error,
+            @r###""Outbound network requests are disabled since the server runs in safe mode.""###
+        );
+        assert!(api
+            .web_scraping(&mock_user)
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?
+            .is_empty());
+        content_mock.assert_hits(0);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn retries_web_scraper_requests_and_fails_fast_when_circuit_is_open(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+        config.components.web_scraper = WebScraperConfig {
+            retries: 1,
+            circuit_breaker: Some(WebScraperCircuitBreakerConfig {
+                failure_threshold: 2,
+                cooldown: Duration::from_secs(600),
+            }),
+        };
+
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+        api.db
+            .web_scraping(mock_user.id)
+            .insert_web_page_tracker(&tracker)
+            .await?;
+
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content");
+            then.status(503)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&WebScraperErrorResponse {
+                    message: "unavailable".to_string(),
+                });
+        });
+
+        // Failed request is retried, and the circuit opens once the threshold is reached.
+        let web_scraping = api.web_scraping(&mock_user);
+        let error = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.root_cause().to_string(),
+            "Unexpected scraper error (503 Service Unavailable): \"unavailable\""
+        );
+        content_mock.assert_hits(2);
+
+        // Circuit is open, so the web scraper isn't called at all.
+        let error = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.root_cause().to_string(),
+            "Web scraper is unavailable, calls are rejected for the next 10m."
+        );
+        content_mock.assert_hits(2);
+        assert!(web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?
+            .is_empty());
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn properly_removes_web_page_content_when_tracker_is_removed(
         pool: PgPool,
@@ -4210,13 +14094,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -4284,13 +14168,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -4381,13 +14265,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -4458,6 +14342,81 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn properly_forwards_web_page_content_request_method_and_body(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut config = mock_config()?;
+        config.components.web_scraper_url = Url::parse(&server.base_url())?;
+
+        let api = mock_api_with_config(pool, config).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let tracker = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/api/status")?,
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    method: Some("POST".to_string()),
+                    request_body: Some("{\"query\":\"status\"}".to_string()),
+                    ..Default::default()
+                },
+                job_config: None,
+            })
+            .await?;
+
+        let content = get_content(946720800, "\"rev_1\"")?;
+        let content_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/web_page/content")
+                .json_body(serde_json::json!({
+                    "url": "https://secutils.dev/api/status",
+                    "delay": 2000,
+                    "method": "POST",
+                    "body": "{\"query\":\"status\"}"
+                }));
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .json_body_obj(&content);
+        });
+
+        let revision = web_scraping
+            .create_content_tracker_revision(tracker.id)
+            .await?;
+        assert!(revision.is_some());
+        content_mock.assert();
+        let tracker_content = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert_eq!(tracker_content.len(), 1);
+
+        // Update request body (content should be removed, the same as for the URL change).
+        web_scraping
+            .update_content_tracker(
+                tracker.id,
+                WebPageTrackerUpdateParams {
+                    settings: Some(WebPageTrackerSettings {
+                        request_body: Some("{\"query\":\"health\"}".to_string()),
+                        ..tracker.settings.clone()
+                    }),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let tracker_content = web_scraping
+            .get_content_tracker_history(tracker.id, Default::default())
+            .await?;
+        assert!(tracker_content.is_empty());
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn properly_resets_web_page_resources_job_id_when_tracker_schedule_changed(
         pool: PgPool,
@@ -4474,13 +14433,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -4512,7 +14471,7 @@ mod tests {
                         scripts: Some(
                             [(
                                 WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME.to_string(),
-                                "some".to_string(),
+                                "return resource;".to_string(),
                             )]
                             .into_iter()
                             .collect(),
@@ -4524,8 +14483,10 @@ mod tests {
                         retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
                             interval: Duration::from_secs(120),
                             max_attempts: 5,
+                            max_total_retry_duration: None,
                         }),
                         notifications: false,
+                        adaptive_interval: None,
                     })),
                 },
             )
@@ -4550,8 +14511,10 @@ mod tests {
                         retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
                             interval: Duration::from_secs(120),
                             max_attempts: 5,
+                            max_total_retry_duration: None,
                         }),
                         notifications: false,
+                        adaptive_interval: None,
                     })),
                     ..Default::default()
                 },
@@ -4586,13 +14549,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -4624,7 +14587,7 @@ mod tests {
                         scripts: Some(
                             [(
                                 WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME.to_string(),
-                                "some".to_string(),
+                                "return document.title;".to_string(),
                             )]
                             .into_iter()
                             .collect(),
@@ -4636,8 +14599,10 @@ mod tests {
                         retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
                             interval: Duration::from_secs(120),
                             max_attempts: 5,
+                            max_total_retry_duration: None,
                         }),
                         notifications: false,
+                        adaptive_interval: None,
                     })),
                 },
             )
@@ -4662,8 +14627,10 @@ mod tests {
                         retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
                             interval: Duration::from_secs(120),
                             max_attempts: 5,
+                            max_total_retry_duration: None,
                         }),
                         notifications: false,
+                        adaptive_interval: None,
                     })),
                     ..Default::default()
                 },
@@ -4698,13 +14665,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -4736,7 +14703,7 @@ mod tests {
                         scripts: Some(
                             [(
                                 WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME.to_string(),
-                                "some".to_string(),
+                                "return resource;".to_string(),
                             )]
                             .into_iter()
                             .collect(),
@@ -4748,8 +14715,10 @@ mod tests {
                         retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
                             interval: Duration::from_secs(120),
                             max_attempts: 5,
+                            max_total_retry_duration: None,
                         }),
                         notifications: false,
+                        adaptive_interval: None,
                     })),
                 },
             )
@@ -4806,13 +14775,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -4844,7 +14813,7 @@ mod tests {
                         scripts: Some(
                             [(
                                 WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME.to_string(),
-                                "some".to_string(),
+                                "return document.title;".to_string(),
                             )]
                             .into_iter()
                             .collect(),
@@ -4856,8 +14825,10 @@ mod tests {
                         retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
                             interval: Duration::from_secs(120),
                             max_attempts: 5,
+                            max_total_retry_duration: None,
                         }),
                         notifications: false,
+                        adaptive_interval: None,
                     })),
                 },
             )
@@ -4924,13 +14895,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -4941,13 +14912,13 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * * *".to_string(),
                     retry_strategy: None,
                     notifications: true,
+                    adaptive_interval: None,
                 }),
             })
             .await?;
@@ -5099,13 +15070,13 @@ mod tests {
                     settings: WebPageTrackerSettings {
                         revisions: 3,
                         delay: Duration::from_millis(2000),
-                        scripts: Default::default(),
-                        headers: Default::default(),
+                        ..Default::default()
                     },
                     job_config: Some(SchedulerJobConfig {
                         schedule: "0 0 * * * *".to_string(),
                         retry_strategy: None,
                         notifications: true,
+                        adaptive_interval: None,
                     }),
                 })
                 .await?;
@@ -5195,13 +15166,13 @@ mod tests {
                     settings: WebPageTrackerSettings {
                         revisions: 3,
                         delay: Duration::from_millis(2000),
-                        scripts: Default::default(),
-                        headers: Default::default(),
+                        ..Default::default()
                     },
                     job_config: Some(SchedulerJobConfig {
                         schedule: "0 0 * * * *".to_string(),
                         retry_strategy: None,
                         notifications: true,
+                        adaptive_interval: None,
                     }),
                 })
                 .await?;
@@ -5248,4 +15219,235 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn properly_removes_expired_web_page_tracker_revisions(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let settings = WebPageTrackerSettings {
+            revisions: 10,
+            delay: Duration::from_millis(2000),
+            ..Default::default()
+        };
+
+        let web_scraping = api.web_scraping(&mock_user);
+        let default_tracker = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_one".to_string(),
+                url: Url::parse("https://secutils.dev/one")?,
+                settings: settings.clone(),
+                job_config: None,
+            })
+            .await?;
+        let classified_tracker = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_two".to_string(),
+                url: Url::parse("https://secutils.dev/two")?,
+                settings: WebPageTrackerSettings {
+                    data_policy: Some(WebPageTrackerDataPolicy {
+                        classification: WebPageTrackerDataClassification::Personal,
+                        retention: Some(Duration::from_secs(3600)),
+                    }),
+                    ..settings.clone()
+                },
+                job_config: None,
+            })
+            .await?;
+        let unrestricted_tracker = web_scraping
+            .create_content_tracker(WebPageTrackerCreateParams {
+                name: "name_three".to_string(),
+                url: Url::parse("https://secutils.dev/three")?,
+                settings: WebPageTrackerSettings {
+                    data_policy: Some(WebPageTrackerDataPolicy {
+                        classification: WebPageTrackerDataClassification::Public,
+                        retention: None,
+                    }),
+                    ..settings.clone()
+                },
+                job_config: None,
+            })
+            .await?;
+
+        // Every tracker has one revision that is 2 hours old and one that is fresh.
+        let now = OffsetDateTime::now_utc();
+        for (index, tracker) in [&default_tracker, &classified_tracker, &unrestricted_tracker]
+            .into_iter()
+            .enumerate()
+        {
+            for (revision_index, created_at) in [now - Duration::from_secs(7200), now]
+                .into_iter()
+                .enumerate()
+            {
+                api.db
+                    .web_scraping(mock_user.id)
+                    .insert_web_page_tracker_history_revision(&WebPageDataRevision::<
+                        WebPageContentTrackerTag,
+                    > {
+                        id: Uuid::from_u128(0x10 * (index as u128 + 1) + revision_index as u128),
+                        tracker_id: tracker.id,
+                        data: format!("\"rev_{revision_index}\""),
+                        created_at,
+                        note: None,
+                        acknowledgment: None,
+                        diff_stats: None,
+                        assertions_passed: None,
+                    })
+                    .await?;
+            }
+        }
+
+        assert_eq!(
+            api.web_scraping_system()
+                .remove_expired_web_page_tracker_revisions()
+                .await?,
+            1
+        );
+
+        let history_ids = |history: Vec<WebPageDataRevision<WebPageContentTrackerTag>>| {
+            history
+                .into_iter()
+                .map(|revision| revision.id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            history_ids(
+                web_scraping
+                    .get_content_tracker_history(
+                        default_tracker.id,
+                        WebPageContentTrackerGetHistoryParams::default()
+                    )
+                    .await?
+            ),
+            vec![Uuid::from_u128(0x10), Uuid::from_u128(0x11)]
+        );
+        assert_eq!(
+            history_ids(
+                web_scraping
+                    .get_content_tracker_history(
+                        classified_tracker.id,
+                        WebPageContentTrackerGetHistoryParams::default()
+                    )
+                    .await?
+            ),
+            vec![Uuid::from_u128(0x21)]
+        );
+        assert_eq!(
+            history_ids(
+                web_scraping
+                    .get_content_tracker_history(
+                        unrestricted_tracker.id,
+                        WebPageContentTrackerGetHistoryParams::default()
+                    )
+                    .await?
+            ),
+            vec![Uuid::from_u128(0x30), Uuid::from_u128(0x31)]
+        );
+
+        // Nothing to remove anymore.
+        assert_eq!(
+            api.web_scraping_system()
+                .remove_expired_web_page_tracker_revisions()
+                .await?,
+            0
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_returns_web_page_tracker_effective_settings(
+        pool: PgPool,
+    ) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        // Trackers might have been created before the subscription limits were lowered.
+        let resources_tracker = MockWebPageTrackerBuilder::<WebPageResourcesTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "name_one",
+            "https://secutils.dev/one",
+            31,
+        )?
+        .with_job_config(SchedulerJobConfig {
+            schedule: "*/5 * * * * *".to_string(),
+            retry_strategy: None,
+            notifications: true,
+            adaptive_interval: None,
+        })
+        .build();
+        let content_tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            "name_two",
+            "https://secutils.dev/two",
+            3,
+        )?
+        .with_job_config(SchedulerJobConfig {
+            schedule: "@hourly".to_string(),
+            retry_strategy: None,
+            notifications: true,
+            adaptive_interval: None,
+        })
+        .build();
+        let screenshot_tracker = MockWebPageTrackerBuilder::<WebPageScreenshotTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000003"),
+            "name_three",
+            "https://secutils.dev/three",
+            31,
+        )?
+        .build();
+        let web_scraping_db = api.db.web_scraping(mock_user.id);
+        web_scraping_db
+            .insert_web_page_tracker(&resources_tracker)
+            .await?;
+        web_scraping_db
+            .insert_web_page_tracker(&content_tracker)
+            .await?;
+        web_scraping_db
+            .insert_web_page_tracker(&screenshot_tracker)
+            .await?;
+
+        let web_scraping = api.web_scraping(&mock_user);
+
+        let effective_settings = web_scraping
+            .get_resources_tracker_effective_settings(resources_tracker.id)
+            .await?;
+        assert_eq!(effective_settings.settings.revisions, 30);
+        assert_eq!(
+            effective_settings.settings.delay,
+            resources_tracker.settings.delay
+        );
+        assert_eq!(
+            effective_settings.min_schedule_interval,
+            Some(Duration::from_secs(10))
+        );
+
+        let effective_settings = web_scraping
+            .get_content_tracker_effective_settings(content_tracker.id)
+            .await?;
+        assert_eq!(effective_settings.settings, content_tracker.settings);
+        assert_eq!(
+            effective_settings.min_schedule_interval,
+            Some(Duration::from_secs(3600))
+        );
+
+        let effective_settings = web_scraping
+            .get_screenshot_tracker_effective_settings(screenshot_tracker.id)
+            .await?;
+        assert_eq!(effective_settings.settings.revisions, 5);
+        assert_eq!(effective_settings.min_schedule_interval, None);
+
+        let error = web_scraping
+            .get_content_tracker_effective_settings(uuid!("00000000-0000-0000-0000-000000000004"))
+            .await
+            .unwrap_err()
+            .downcast::<SecutilsError>()?;
+        assert_eq!(error.code(), ErrorCode::TrackerNotFound);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,54 @@
+use crate::utils::web_scraping::WebPageContentDiffAlgorithm;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageContentSimulateDiffParams {
+    /// Content that plays the role of the previous web page content revision.
+    pub previous_content: String,
+    /// Content that plays the role of the current web page content revision.
+    pub current_content: String,
+    /// Algorithm to use to calculate the diff between the contents.
+    #[serde(default)]
+    pub diff_algorithm: WebPageContentDiffAlgorithm,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::{
+        api_ext::WebPageContentSimulateDiffParams, WebPageContentDiffAlgorithm,
+    };
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageContentSimulateDiffParams>(
+                r#"{ "previousContent": "Hello World", "currentContent": "Hello New World" }"#
+            )?,
+            WebPageContentSimulateDiffParams {
+                previous_content: "Hello World".to_string(),
+                current_content: "Hello New World".to_string(),
+                diff_algorithm: WebPageContentDiffAlgorithm::Lines
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageContentSimulateDiffParams>(
+                r#"
+{
+    "previousContent": "<p>Hello World</p>",
+    "currentContent": "<p>Hello New World</p>",
+    "diffAlgorithm": "dom"
+}
+          "#
+            )?,
+            WebPageContentSimulateDiffParams {
+                previous_content: "<p>Hello World</p>".to_string(),
+                current_content: "<p>Hello New World</p>".to_string(),
+                diff_algorithm: WebPageContentDiffAlgorithm::Dom
+            }
+        );
+
+        Ok(())
+    }
+}
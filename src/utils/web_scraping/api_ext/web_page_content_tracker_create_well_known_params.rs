@@ -0,0 +1,70 @@
+use crate::{scheduler::SchedulerJobConfig, utils::web_scraping::WebPageWellKnownFile};
+use serde::Deserialize;
+use url::Url;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageContentTrackerCreateWellKnownParams {
+    /// Base URL of the web site to track well-known files for (e.g. `https://secutils.dev`).
+    pub base_url: Url,
+    /// Optional list of the well-known files to track, all supported files are tracked if not
+    /// specified.
+    pub files: Option<Vec<WebPageWellKnownFile>>,
+    /// Configuration for a job, if trackers need to be scheduled for automatic change detection.
+    pub job_config: Option<SchedulerJobConfig>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        scheduler::SchedulerJobConfig,
+        utils::web_scraping::{
+            api_ext::WebPageContentTrackerCreateWellKnownParams, WebPageWellKnownFile,
+        },
+    };
+    use url::Url;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageContentTrackerCreateWellKnownParams>(
+                r#"{ "baseUrl": "https://secutils.dev" }"#
+            )?,
+            WebPageContentTrackerCreateWellKnownParams {
+                base_url: Url::parse("https://secutils.dev")?,
+                files: None,
+                job_config: None,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageContentTrackerCreateWellKnownParams>(
+                r#"
+    {
+        "baseUrl": "https://secutils.dev",
+        "files": ["securityTxt", "robotsTxt"],
+        "jobConfig": {
+            "schedule": "0 0 * * *",
+            "notifications": true
+        }
+    }
+              "#
+            )?,
+            WebPageContentTrackerCreateWellKnownParams {
+                base_url: Url::parse("https://secutils.dev")?,
+                files: Some(vec![
+                    WebPageWellKnownFile::SecurityTxt,
+                    WebPageWellKnownFile::RobotsTxt
+                ]),
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            }
+        );
+
+        Ok(())
+    }
+}
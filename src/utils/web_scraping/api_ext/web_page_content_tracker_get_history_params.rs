@@ -1,3 +1,4 @@
+use crate::utils::web_scraping::WebPageContentDiffAlgorithm;
 use serde::Deserialize;
 
 #[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq)]
@@ -7,11 +8,15 @@ pub struct WebPageContentTrackerGetHistoryParams {
     pub refresh: bool,
     #[serde(default)]
     pub calculate_diff: bool,
+    #[serde(default)]
+    pub diff_algorithm: WebPageContentDiffAlgorithm,
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::web_scraping::api_ext::WebPageContentTrackerGetHistoryParams;
+    use crate::utils::web_scraping::{
+        api_ext::WebPageContentTrackerGetHistoryParams, WebPageContentDiffAlgorithm,
+    };
 
     #[test]
     fn deserialization() -> anyhow::Result<()> {
@@ -19,7 +24,8 @@ mod tests {
             serde_json::from_str::<WebPageContentTrackerGetHistoryParams>(r#"{}"#)?,
             WebPageContentTrackerGetHistoryParams {
                 refresh: false,
-                calculate_diff: false
+                calculate_diff: false,
+                diff_algorithm: WebPageContentDiffAlgorithm::Lines
             }
         );
 
@@ -28,13 +34,15 @@ mod tests {
                 r#"
 {
     "refresh": true,
-    "calculateDiff": true
+    "calculateDiff": true,
+    "diffAlgorithm": "dom"
 }
           "#
             )?,
             WebPageContentTrackerGetHistoryParams {
                 refresh: true,
-                calculate_diff: true
+                calculate_diff: true,
+                diff_algorithm: WebPageContentDiffAlgorithm::Dom
             }
         );
 
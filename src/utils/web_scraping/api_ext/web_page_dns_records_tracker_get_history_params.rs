@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageDnsRecordsTrackerGetHistoryParams {
+    #[serde(default)]
+    pub refresh: bool,
+    #[serde(default)]
+    pub calculate_diff: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::WebPageDnsRecordsTrackerGetHistoryParams;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageDnsRecordsTrackerGetHistoryParams>(r#"{}"#)?,
+            WebPageDnsRecordsTrackerGetHistoryParams {
+                refresh: false,
+                calculate_diff: false
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageDnsRecordsTrackerGetHistoryParams>(
+                r#"
+{
+    "refresh": true,
+    "calculateDiff": true
+}
+          "#
+            )?,
+            WebPageDnsRecordsTrackerGetHistoryParams {
+                refresh: true,
+                calculate_diff: true
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageScreenshotTrackerGetHistoryParams {
+    #[serde(default)]
+    pub refresh: bool,
+    #[serde(default)]
+    pub calculate_diff: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::WebPageScreenshotTrackerGetHistoryParams;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageScreenshotTrackerGetHistoryParams>(r#"{}"#)?,
+            WebPageScreenshotTrackerGetHistoryParams {
+                refresh: false,
+                calculate_diff: false
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageScreenshotTrackerGetHistoryParams>(
+                r#"
+{
+    "refresh": true,
+    "calculateDiff": true
+}
+          "#
+            )?,
+            WebPageScreenshotTrackerGetHistoryParams {
+                refresh: true,
+                calculate_diff: true
+            }
+        );
+
+        Ok(())
+    }
+}
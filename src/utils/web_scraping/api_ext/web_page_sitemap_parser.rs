@@ -0,0 +1,126 @@
+use anyhow::anyhow;
+use bytes::Bytes;
+use html5ever::{
+    tendril::{fmt, fmt::Slice, ByteTendril, ReadExt},
+    tokenizer::{
+        BufferQueue, TagKind, Token, TokenSink, TokenSinkResult, Tokenizer, TokenizerOpts,
+    },
+};
+use std::cell::{Cell, RefCell};
+
+/// Parses page URLs from the XML sitemap (https://www.sitemaps.org/protocol.html).
+pub struct WebPageSitemapParser;
+impl WebPageSitemapParser {
+    /// Takes sitemap XML document bytes and returns a list of `<loc>` values found in `<url>`
+    /// tags, in the document order.
+    pub fn parse(sitemap_bytes: &Bytes) -> anyhow::Result<Vec<String>> {
+        let mut chunk = ByteTendril::new();
+        sitemap_bytes.as_bytes().read_to_tendril(&mut chunk)?;
+
+        // Make sure sitemap content is a valid UTF-8 text.
+        let utf8_chunk = chunk
+            .try_reinterpret::<fmt::UTF8>()
+            .map_err(|_| anyhow!("Sitemap content isn't a valid UTF-8 text."))?;
+
+        let input = BufferQueue::default();
+        input.push_back(utf8_chunk);
+
+        // Start tokenizing and collect `<loc>` values of the `<url>` tags.
+        let mut sink = WebPageSitemapTokenSink::default();
+        let tokenizer = Tokenizer::new(&mut sink, TokenizerOpts::default());
+        let _ = tokenizer.feed(&input);
+        tokenizer.end();
+
+        Ok(sink.urls.take())
+    }
+}
+
+/// Serves as a sink for the tokenizer that parses sitemap XML.
+#[derive(Default)]
+struct WebPageSitemapTokenSink {
+    urls: Cell<Vec<String>>,
+    inside_url: Cell<bool>,
+    current_loc: RefCell<Option<String>>,
+}
+
+impl TokenSink for &mut WebPageSitemapTokenSink {
+    type Handle = ();
+
+    fn process_token(&self, token: Token, _: u64) -> TokenSinkResult<Self::Handle> {
+        match token {
+            Token::TagToken(tag) => {
+                let tag_name = (*tag.name).to_ascii_lowercase();
+                match (tag.kind, tag_name.as_str()) {
+                    (TagKind::StartTag, "url") => self.inside_url.set(true),
+                    (TagKind::EndTag, "url") => self.inside_url.set(false),
+                    (TagKind::StartTag, "loc") if self.inside_url.get() => {
+                        self.current_loc.replace(Some(String::new()));
+                    }
+                    (TagKind::EndTag, "loc") => {
+                        if let Some(loc) = self.current_loc.take() {
+                            let loc = loc.trim();
+                            if !loc.is_empty() {
+                                let mut urls = self.urls.take();
+                                urls.push(loc.to_string());
+                                self.urls.set(urls);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Token::CharacterTokens(text) => {
+                if let Some(loc) = self.current_loc.borrow_mut().as_mut() {
+                    loc.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+
+        TokenSinkResult::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageSitemapParser;
+    use bytes::Bytes;
+
+    #[test]
+    fn parses_sitemap() -> anyhow::Result<()> {
+        let sitemap = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+                <url>
+                    <loc>https://secutils.dev/</loc>
+                    <lastmod>2024-10-01</lastmod>
+                </url>
+                <url>
+                    <loc>
+                        https://secutils.dev/docs?page=1&amp;lang=en
+                    </loc>
+                    <changefreq>daily</changefreq>
+                </url>
+                <url><loc></loc></url>
+                <URL><LOC>https://secutils.dev/privacy</LOC></URL>
+                <url>
+                    <loc>https://secutils.dev/</loc>
+                </url>
+                <image><loc>https://secutils.dev/logo.png</loc></image>
+            </urlset>
+        "#;
+
+        assert_eq!(
+            WebPageSitemapParser::parse(&Bytes::from(sitemap))?,
+            vec![
+                "https://secutils.dev/".to_string(),
+                "https://secutils.dev/docs?page=1&lang=en".to_string(),
+                "https://secutils.dev/privacy".to_string(),
+                "https://secutils.dev/".to_string(),
+            ]
+        );
+
+        assert!(WebPageSitemapParser::parse(&Bytes::from("not a sitemap"))?.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageStatusPageCreateParams {
+    /// Arbitrary name of the status page, displayed as the page title.
+    pub name: String,
+    /// IDs of the status trackers to display on the page, in the display order.
+    pub tracker_ids: Vec<Uuid>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::WebPageStatusPageCreateParams;
+    use uuid::uuid;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageStatusPageCreateParams>(
+                r#"
+{
+    "name": "Status",
+    "trackerIds": ["00000000-0000-0000-0000-000000000001"]
+}
+          "#
+            )?,
+            WebPageStatusPageCreateParams {
+                name: "Status".to_string(),
+                tracker_ids: vec![uuid!("00000000-0000-0000-0000-000000000001")],
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct WebPageStatusPageUpdateParams {
+    /// Arbitrary name of the status page, displayed as the page title.
+    pub name: Option<String>,
+    /// IDs of the status trackers to display on the page, in the display order.
+    pub tracker_ids: Option<Vec<Uuid>>,
+    /// Indicates whether the status page token should be regenerated, so that the status page is
+    /// no longer accessible via the previously shared link.
+    pub regenerate_token: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::WebPageStatusPageUpdateParams;
+    use uuid::uuid;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageStatusPageUpdateParams>(r#"{}"#)?,
+            WebPageStatusPageUpdateParams::default()
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageStatusPageUpdateParams>(
+                r#"
+{
+    "name": "Status",
+    "trackerIds": ["00000000-0000-0000-0000-000000000001"],
+    "regenerateToken": true
+}
+          "#
+            )?,
+            WebPageStatusPageUpdateParams {
+                name: Some("Status".to_string()),
+                tracker_ids: Some(vec![uuid!("00000000-0000-0000-0000-000000000001")]),
+                regenerate_token: true,
+            }
+        );
+
+        Ok(())
+    }
+}
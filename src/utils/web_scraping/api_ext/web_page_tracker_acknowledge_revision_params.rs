@@ -0,0 +1,29 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerAcknowledgeRevisionParams {
+    /// ID of the revision to acknowledge.
+    pub revision_id: Uuid,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::WebPageTrackerAcknowledgeRevisionParams;
+    use uuid::uuid;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerAcknowledgeRevisionParams>(
+                r#"{ "revisionId": "00000000-0000-0000-0000-000000000001" }"#
+            )?,
+            WebPageTrackerAcknowledgeRevisionParams {
+                revision_id: uuid!("00000000-0000-0000-0000-000000000001"),
+            }
+        );
+
+        Ok(())
+    }
+}
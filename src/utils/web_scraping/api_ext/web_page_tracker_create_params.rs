@@ -18,7 +18,7 @@ pub struct WebPageTrackerCreateParams {
 #[cfg(test)]
 mod tests {
     use crate::{
-        scheduler::{SchedulerJobConfig, SchedulerJobRetryStrategy},
+        scheduler::{SchedulerJobAdaptiveInterval, SchedulerJobConfig, SchedulerJobRetryStrategy},
         utils::web_scraping::{
             api_ext::{WebPageTrackerCreateParams, WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME},
             WebPageTrackerSettings,
@@ -48,8 +48,7 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 3,
                     delay: Duration::from_millis(2000),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 job_config: None,
             }
@@ -78,9 +77,14 @@ mod tests {
                 "initialInterval": 1234,
                 "multiplier": 2,
                 "maxInterval": 120000,
-                "maxAttempts": 5
+                "maxAttempts": 5,
+                "maxTotalRetryDuration": 600000
             },
-            "notifications": true
+            "notifications": true,
+            "adaptiveInterval": {
+                "minInterval": 3600000,
+                "maxInterval": 86400000
+            }
         }
     }
               "#
@@ -104,7 +108,8 @@ mod tests {
                         [("cookie".to_string(), "my-cookie".to_string())]
                             .into_iter()
                             .collect(),
-                    )
+                    ),
+                    ..Default::default()
                 },
                 job_config: Some(SchedulerJobConfig {
                     schedule: "0 0 * * *".to_string(),
@@ -113,8 +118,13 @@ mod tests {
                         multiplier: 2,
                         max_interval: Duration::from_secs(120),
                         max_attempts: 5,
+                        max_total_retry_duration: Some(Duration::from_secs(600)),
                     }),
                     notifications: true,
+                    adaptive_interval: Some(SchedulerJobAdaptiveInterval {
+                        min_interval: Duration::from_secs(3600),
+                        max_interval: Duration::from_secs(86400),
+                    }),
                 }),
             }
         );
@@ -0,0 +1,38 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerExportHistoryParams {
+    #[serde(default)]
+    pub calculate_diff: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::WebPageTrackerExportHistoryParams;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerExportHistoryParams>(r#"{}"#)?,
+            WebPageTrackerExportHistoryParams {
+                calculate_diff: false
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerExportHistoryParams>(
+                r#"
+{
+    "calculateDiff": true
+}
+          "#
+            )?,
+            WebPageTrackerExportHistoryParams {
+                calculate_diff: true
+            }
+        );
+
+        Ok(())
+    }
+}
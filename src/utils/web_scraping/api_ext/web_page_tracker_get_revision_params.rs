@@ -0,0 +1,29 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerGetRevisionParams {
+    /// ID of the revision to retrieve.
+    pub revision_id: Uuid,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::WebPageTrackerGetRevisionParams;
+    use uuid::uuid;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerGetRevisionParams>(
+                r#"{ "revisionId": "00000000-0000-0000-0000-000000000001" }"#
+            )?,
+            WebPageTrackerGetRevisionParams {
+                revision_id: uuid!("00000000-0000-0000-0000-000000000001"),
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,212 @@
+use crate::utils::web_scraping::{
+    api_ext::WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME, WebPageTrackerSettings,
+};
+use serde::Deserialize;
+use serde_with::{serde_as, DurationSeconds};
+use std::time::Duration;
+
+/// A monitor exported from another uptime or monitoring tool in a generic JSON format:
+///
+/// ```json
+/// {
+///   "name": "Secutils.dev home page",
+///   "url": "https://secutils.dev",
+///   "interval": 300,
+///   "expectedStatus": 200,
+///   "expectedContent": "Secutils.dev"
+/// }
+/// ```
+///
+/// Monitors with the expected content are imported as content trackers that extract whether the
+/// web page contains the expected text, so that tracker reports whenever the text appears or
+/// disappears. Monitors without the expected content are imported as status-only content trackers
+/// that report every change of the web page HTTP status.
+#[serde_as]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerImportMonitor {
+    /// Name of the monitor.
+    pub name: String,
+    /// URL of the monitored web page.
+    pub url: String,
+    /// Interval between the checks, in seconds.
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub interval: Duration,
+    /// HTTP status code the web page is expected to respond with.
+    pub expected_status: Option<u16>,
+    /// Text the web page is expected to contain.
+    pub expected_content: Option<String>,
+}
+
+impl WebPageTrackerImportMonitor {
+    /// Converts the monitor check interval to a cron schedule. Returns `None` if the interval
+    /// cannot be represented by a cron schedule that runs at even intervals (e.g. 7 minutes).
+    pub fn schedule(&self) -> Option<String> {
+        let seconds = self.interval.as_secs();
+        if seconds == 0 || self.interval.subsec_nanos() > 0 {
+            return None;
+        }
+
+        let (minutes, hours) = (seconds / 60, seconds / 3600);
+        if seconds < 60 && 60 % seconds == 0 {
+            Some(format!("*/{seconds} * * * * *"))
+        } else if seconds.is_multiple_of(60) && minutes < 60 && 60 % minutes == 0 {
+            Some(format!("0 */{minutes} * * * *"))
+        } else if seconds.is_multiple_of(3600) && hours < 24 && 24 % hours == 0 {
+            Some(format!("0 0 */{hours} * * *"))
+        } else if seconds == 24 * 3600 {
+            Some("0 0 0 * * *".to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Derives tracker settings for the monitor from the settings shared by all imported trackers.
+    pub fn settings(&self, shared_settings: &WebPageTrackerSettings) -> WebPageTrackerSettings {
+        let Some(ref expected_content) = self.expected_content else {
+            return WebPageTrackerSettings {
+                status_only: true,
+                scripts: None,
+                ..shared_settings.clone()
+            };
+        };
+
+        // Serialization of a string into JSON cannot fail, and JSON string is a valid JS literal.
+        let expected_content =
+            serde_json::to_string(expected_content).unwrap_or_else(|_| "\"\"".to_string());
+        let mut scripts = shared_settings.scripts.clone().unwrap_or_default();
+        scripts.insert(
+            WEB_PAGE_CONTENT_TRACKER_EXTRACT_SCRIPT_NAME.to_string(),
+            format!(
+                "return document.body.innerText.includes({expected_content}) ? 'Expected content is present.' : 'Expected content is missing.';"
+            ),
+        );
+
+        WebPageTrackerSettings {
+            status_only: false,
+            scripts: Some(scripts),
+            ..shared_settings.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::{
+        api_ext::WebPageTrackerImportMonitor, WebPageTrackerSettings,
+    };
+    use std::time::Duration;
+
+    fn monitor(interval: Duration, expected_content: Option<&str>) -> WebPageTrackerImportMonitor {
+        WebPageTrackerImportMonitor {
+            name: "monitor".to_string(),
+            url: "https://secutils.dev".to_string(),
+            interval,
+            expected_status: Some(200),
+            expected_content: expected_content.map(|content| content.to_string()),
+        }
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerImportMonitor>(
+                r#"
+    {
+        "name": "Secutils.dev home page",
+        "url": "https://secutils.dev",
+        "interval": 300,
+        "expectedStatus": 200,
+        "expectedContent": "Secutils.dev"
+    }
+              "#
+            )?,
+            WebPageTrackerImportMonitor {
+                name: "Secutils.dev home page".to_string(),
+                url: "https://secutils.dev".to_string(),
+                interval: Duration::from_secs(300),
+                expected_status: Some(200),
+                expected_content: Some("Secutils.dev".to_string()),
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerImportMonitor>(
+                r#"{ "name": "Status", "url": "https://secutils.dev", "interval": 60 }"#
+            )?,
+            WebPageTrackerImportMonitor {
+                name: "Status".to_string(),
+                url: "https://secutils.dev".to_string(),
+                interval: Duration::from_secs(60),
+                expected_status: None,
+                expected_content: None,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn converts_interval_to_schedule() {
+        for (seconds, schedule) in [
+            (10, Some("*/10 * * * * *")),
+            (60, Some("0 */1 * * * *")),
+            (300, Some("0 */5 * * * *")),
+            (1800, Some("0 */30 * * * *")),
+            (3600, Some("0 0 */1 * * *")),
+            (6 * 3600, Some("0 0 */6 * * *")),
+            (24 * 3600, Some("0 0 0 * * *")),
+            (0, None),
+            (7, None),
+            (90, None),
+            (7 * 60, None),
+            (5 * 3600, None),
+            (2 * 24 * 3600, None),
+        ] {
+            assert_eq!(
+                monitor(Duration::from_secs(seconds), None)
+                    .schedule()
+                    .as_deref(),
+                schedule,
+                "Interval: {seconds}s"
+            );
+        }
+
+        assert!(monitor(Duration::from_millis(1500), None)
+            .schedule()
+            .is_none());
+    }
+
+    #[test]
+    fn derives_settings() {
+        let shared_settings = WebPageTrackerSettings {
+            revisions: 3,
+            delay: Duration::from_millis(2000),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            monitor(Duration::from_secs(60), None).settings(&shared_settings),
+            WebPageTrackerSettings {
+                status_only: true,
+                ..shared_settings.clone()
+            }
+        );
+
+        assert_eq!(
+            monitor(Duration::from_secs(60), Some(r#"Say "hi""#)).settings(&shared_settings),
+            WebPageTrackerSettings {
+                scripts: Some(
+                    [(
+                        "extractContent".to_string(),
+                        r#"return document.body.innerText.includes("Say \"hi\"") ? 'Expected content is present.' : 'Expected content is missing.';"#
+                            .to_string()
+                    )]
+                    .into_iter()
+                    .collect()
+                ),
+                ..shared_settings.clone()
+            }
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    scheduler::SchedulerJobConfig,
+    utils::web_scraping::{api_ext::WebPageTrackerImportSource, WebPageTrackerSettings},
+};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerImportParams {
+    /// Source of the web page URLs to create trackers for.
+    pub source: WebPageTrackerImportSource,
+    /// Settings shared by all imported web page trackers.
+    pub settings: WebPageTrackerSettings,
+    /// Configuration for a job shared by all imported web page trackers, if trackers need to be
+    /// scheduled for automatic change detection.
+    pub job_config: Option<SchedulerJobConfig>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        scheduler::SchedulerJobConfig,
+        utils::web_scraping::{
+            api_ext::{WebPageTrackerImportParams, WebPageTrackerImportSource},
+            WebPageTrackerSettings,
+        },
+    };
+    use std::time::Duration;
+    use url::Url;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerImportParams>(
+                r#"
+    {
+        "source": {
+            "type": "sitemap",
+            "url": "https://secutils.dev/sitemap.xml"
+        },
+        "settings": {
+            "revisions": 3,
+            "delay": 2000
+        },
+        "jobConfig": {
+            "schedule": "0 0 * * *",
+            "notifications": true
+        }
+    }
+              "#
+            )?,
+            WebPageTrackerImportParams {
+                source: WebPageTrackerImportSource::Sitemap {
+                    url: Url::parse("https://secutils.dev/sitemap.xml")?
+                },
+                settings: WebPageTrackerSettings {
+                    revisions: 3,
+                    delay: Duration::from_millis(2000),
+                    ..Default::default()
+                },
+                job_config: Some(SchedulerJobConfig {
+                    schedule: "0 0 * * *".to_string(),
+                    retry_strategy: None,
+                    notifications: true,
+                    adaptive_interval: None,
+                }),
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use crate::utils::web_scraping::api_ext::WebPageTrackerImportMonitor;
+use serde::Deserialize;
+use url::Url;
+
+/// Describes where to take the URLs of the web pages to import trackers for.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum WebPageTrackerImportSource {
+    /// URL of the XML sitemap to extract web page URLs from.
+    Sitemap { url: Url },
+    /// Newline separated list of web page URLs.
+    List { urls: String },
+    /// List of monitors exported from another uptime or monitoring tool, every monitor defines its
+    /// own name, schedule and expectations. Only supported by web page content trackers.
+    Monitors {
+        monitors: Vec<WebPageTrackerImportMonitor>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::{
+        WebPageTrackerImportMonitor, WebPageTrackerImportSource,
+    };
+    use std::time::Duration;
+    use url::Url;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerImportSource>(
+                r#"{ "type": "sitemap", "url": "https://secutils.dev/sitemap.xml" }"#
+            )?,
+            WebPageTrackerImportSource::Sitemap {
+                url: Url::parse("https://secutils.dev/sitemap.xml")?
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerImportSource>(
+                r#"{ "type": "list", "urls": "https://secutils.dev/one\nhttps://secutils.dev/two" }"#
+            )?,
+            WebPageTrackerImportSource::List {
+                urls: "https://secutils.dev/one\nhttps://secutils.dev/two".to_string()
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerImportSource>(
+                r#"{ "type": "monitors", "monitors": [{ "name": "Home", "url": "https://secutils.dev", "interval": 60 }] }"#
+            )?,
+            WebPageTrackerImportSource::Monitors {
+                monitors: vec![WebPageTrackerImportMonitor {
+                    name: "Home".to_string(),
+                    url: "https://secutils.dev".to_string(),
+                    interval: Duration::from_secs(60),
+                    expected_status: None,
+                    expected_content: None,
+                }]
+            }
+        );
+
+        assert!(serde_json::from_str::<WebPageTrackerImportSource>(
+            r#"{ "type": "sitemap", "url": "not-a-url" }"#
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
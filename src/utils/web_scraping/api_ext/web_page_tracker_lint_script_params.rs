@@ -0,0 +1,35 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerLintScriptParams {
+    /// Name of the script to lint (e.g. `resourceFilterMap` or `extractContent`).
+    pub script_name: String,
+    /// Content of the script to lint.
+    pub script: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::WebPageTrackerLintScriptParams;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerLintScriptParams>(
+                r#"
+{
+    "scriptName": "extractContent",
+    "script": "return document.title;"
+}
+          "#
+            )?,
+            WebPageTrackerLintScriptParams {
+                script_name: "extractContent".to_string(),
+                script: "return document.title;".to_string(),
+            }
+        );
+
+        Ok(())
+    }
+}
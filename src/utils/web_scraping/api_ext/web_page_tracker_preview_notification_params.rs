@@ -0,0 +1,32 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerPreviewNotificationParams {
+    /// Hypothetical change to render the notification for. For the content trackers it's the
+    /// content diff, for the resources trackers it's the number of changed resources, for the
+    /// screenshot trackers it's the visual difference, and for the DNS records trackers it's the
+    /// summary of the changed records.
+    pub sample_diff: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::WebPageTrackerPreviewNotificationParams;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerPreviewNotificationParams>(
+                r#"{ "sampleDiff": "-old\n+new" }"#
+            )?,
+            WebPageTrackerPreviewNotificationParams {
+                sample_diff: "-old\n+new".to_string()
+            }
+        );
+
+        assert!(serde_json::from_str::<WebPageTrackerPreviewNotificationParams>(r#"{}"#).is_err());
+
+        Ok(())
+    }
+}
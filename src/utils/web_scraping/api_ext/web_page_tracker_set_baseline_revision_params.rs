@@ -0,0 +1,36 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerSetBaselineRevisionParams {
+    /// ID of the revision to pin as a baseline. If not specified, the baseline is unpinned and
+    /// changes are computed against the previous revision again.
+    #[serde(default)]
+    pub revision_id: Option<Uuid>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::WebPageTrackerSetBaselineRevisionParams;
+    use uuid::uuid;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerSetBaselineRevisionParams>(
+                r#"{ "revisionId": "00000000-0000-0000-0000-000000000001" }"#
+            )?,
+            WebPageTrackerSetBaselineRevisionParams {
+                revision_id: Some(uuid!("00000000-0000-0000-0000-000000000001")),
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerSetBaselineRevisionParams>(r#"{}"#)?,
+            WebPageTrackerSetBaselineRevisionParams { revision_id: None }
+        );
+
+        Ok(())
+    }
+}
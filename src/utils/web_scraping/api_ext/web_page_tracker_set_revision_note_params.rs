@@ -0,0 +1,48 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerSetRevisionNoteParams {
+    /// ID of the revision to set note for.
+    pub revision_id: Uuid,
+    /// Note to attach to the revision, `None` to clear the existing note.
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::WebPageTrackerSetRevisionNoteParams;
+    use uuid::uuid;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerSetRevisionNoteParams>(
+                r#"{ "revisionId": "00000000-0000-0000-0000-000000000001" }"#
+            )?,
+            WebPageTrackerSetRevisionNoteParams {
+                revision_id: uuid!("00000000-0000-0000-0000-000000000001"),
+                note: None
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerSetRevisionNoteParams>(
+                r#"
+{
+    "revisionId": "00000000-0000-0000-0000-000000000001",
+    "note": "this was the outage"
+}
+          "#
+            )?,
+            WebPageTrackerSetRevisionNoteParams {
+                revision_id: uuid!("00000000-0000-0000-0000-000000000001"),
+                note: Some("this was the outage".to_string())
+            }
+        );
+
+        Ok(())
+    }
+}
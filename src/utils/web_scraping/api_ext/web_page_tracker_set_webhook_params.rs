@@ -0,0 +1,99 @@
+use crate::logging::REDACTED_VALUE;
+use serde::Deserialize;
+use std::fmt::{self, Debug, Formatter};
+use url::Url;
+
+#[derive(Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerSetWebhookParams {
+    /// URL of the webhook to deliver change notifications to, `None` to remove the existing
+    /// webhook.
+    #[serde(default)]
+    pub url: Option<Url>,
+    /// Secret used to sign notification payloads delivered to the webhook.
+    #[serde(default)]
+    pub secret: Option<String>,
+    /// Optional custom HTTP headers (e.g. `Authorization`) to send along with every notification
+    /// delivered to the webhook.
+    #[serde(default)]
+    pub headers: Option<Vec<(String, String)>>,
+}
+
+// Secret and header values are never included into the debug output since they may contain
+// credentials of the webhook receiver.
+impl Debug for WebPageTrackerSetWebhookParams {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebPageTrackerSetWebhookParams")
+            .field("url", &self.url)
+            .field("secret", &self.secret.as_ref().map(|_| REDACTED_VALUE))
+            .field(
+                "headers",
+                &self.headers.as_ref().map(|headers| {
+                    headers
+                        .iter()
+                        .map(|(name, _)| (name.as_str(), REDACTED_VALUE))
+                        .collect::<Vec<_>>()
+                }),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::WebPageTrackerSetWebhookParams;
+    use url::Url;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerSetWebhookParams>(r#"{}"#)?,
+            WebPageTrackerSetWebhookParams {
+                url: None,
+                secret: None,
+                headers: None
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerSetWebhookParams>(
+                r#"
+{
+    "url": "https://secutils.dev/webhook",
+    "secret": "my-webhook-secret",
+    "headers": [["Authorization", "Bearer my-token"]]
+}
+          "#
+            )?,
+            WebPageTrackerSetWebhookParams {
+                url: Some(Url::parse("https://secutils.dev/webhook")?),
+                secret: Some("my-webhook-secret".to_string()),
+                headers: Some(vec![(
+                    "Authorization".to_string(),
+                    "Bearer my-token".to_string()
+                )])
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn redacts_secrets_in_debug_output() -> anyhow::Result<()> {
+        let params = WebPageTrackerSetWebhookParams {
+            url: Some(Url::parse("https://secutils.dev/webhook")?),
+            secret: Some("my-webhook-secret".to_string()),
+            headers: Some(vec![(
+                "Authorization".to_string(),
+                "Bearer my-token".to_string(),
+            )]),
+        };
+        let debug = format!("{params:?}");
+        assert!(debug.contains(r#"secret: Some("[REDACTED]")"#));
+        assert!(debug.contains(r#"headers: Some([("Authorization", "[REDACTED]")])"#));
+        assert!(!debug.contains("my-webhook-secret"));
+        assert!(!debug.contains("my-token"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use url::Url;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerTestScriptParams {
+    /// URL of the web page to test script against.
+    pub url: Url,
+    /// Name of the script to test (e.g. `resourceFilterMap` or `extractContent`).
+    pub script_name: String,
+    /// Content of the script to test.
+    pub script: String,
+    /// Optional list of HTTP headers that should be sent with the request to the web page.
+    #[serde(default)]
+    pub headers: Option<HashMap<String, String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::WebPageTrackerTestScriptParams;
+    use url::Url;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerTestScriptParams>(
+                r#"
+{
+    "url": "https://secutils.dev",
+    "scriptName": "resourceFilterMap",
+    "script": "return resource;"
+}
+          "#
+            )?,
+            WebPageTrackerTestScriptParams {
+                url: Url::parse("https://secutils.dev")?,
+                script_name: "resourceFilterMap".to_string(),
+                script: "return resource;".to_string(),
+                headers: None,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerTestScriptParams>(
+                r#"
+{
+    "url": "https://secutils.dev",
+    "scriptName": "extractContent",
+    "script": "return document.title;",
+    "headers": { "cookie": "my-cookie" }
+}
+          "#
+            )?,
+            WebPageTrackerTestScriptParams {
+                url: Url::parse("https://secutils.dev")?,
+                script_name: "extractContent".to_string(),
+                script: "return document.title;".to_string(),
+                headers: Some(
+                    [("cookie".to_string(), "my-cookie".to_string())]
+                        .into_iter()
+                        .collect()
+                ),
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -91,7 +91,8 @@ mod tests {
                         [("cookie".to_string(), "my-cookie".to_string())]
                             .into_iter()
                             .collect(),
-                    )
+                    ),
+                    ..Default::default()
                 }),
                 job_config: None
             }
@@ -161,7 +162,8 @@ mod tests {
                         [("cookie".to_string(), "my-cookie".to_string())]
                             .into_iter()
                             .collect(),
-                    )
+                    ),
+                    ..Default::default()
                 }),
                 job_config: Some(Some(SchedulerJobConfig {
                     schedule: "0 0 * * *".to_string(),
@@ -170,8 +172,10 @@ mod tests {
                         multiplier: 2,
                         max_interval: Duration::from_secs(120),
                         max_attempts: 5,
+                        max_total_retry_duration: None,
                     }),
                     notifications: true,
+                    adaptive_interval: None,
                 })),
             }
         );
@@ -0,0 +1,51 @@
+use crate::utils::web_scraping::WebPageContentDiffAlgorithm;
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackersDiffParams {
+    /// ID of the web page tracker of the same kind to compare the latest revision with.
+    pub tracker_id: Uuid,
+    /// Algorithm to use to calculate the diff between web page content revisions.
+    #[serde(default)]
+    pub diff_algorithm: WebPageContentDiffAlgorithm,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::{
+        api_ext::WebPageTrackersDiffParams, WebPageContentDiffAlgorithm,
+    };
+    use uuid::uuid;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackersDiffParams>(
+                r#"{ "trackerId": "00000000-0000-0000-0000-000000000001" }"#
+            )?,
+            WebPageTrackersDiffParams {
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+                diff_algorithm: WebPageContentDiffAlgorithm::Lines
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackersDiffParams>(
+                r#"
+{
+    "trackerId": "00000000-0000-0000-0000-000000000001",
+    "diffAlgorithm": "dom"
+}
+          "#
+            )?,
+            WebPageTrackersDiffParams {
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+                diff_algorithm: WebPageContentDiffAlgorithm::Dom
+            }
+        );
+
+        Ok(())
+    }
+}
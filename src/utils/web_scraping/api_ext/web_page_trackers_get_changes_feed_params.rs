@@ -0,0 +1,47 @@
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackersGetChangesFeedParams {
+    /// Optional timestamp to return only the changes detected after.
+    #[serde(default, with = "time::serde::timestamp::option")]
+    pub since: Option<OffsetDateTime>,
+    /// Optional maximum number of the most recent changes to return.
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::api_ext::WebPageTrackersGetChangesFeedParams;
+    use time::OffsetDateTime;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackersGetChangesFeedParams>(r#"{}"#)?,
+            WebPageTrackersGetChangesFeedParams {
+                since: None,
+                limit: None
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackersGetChangesFeedParams>(
+                r#"
+{
+    "since": 946720800,
+    "limit": 10
+}
+          "#
+            )?,
+            WebPageTrackersGetChangesFeedParams {
+                since: Some(OffsetDateTime::from_unix_timestamp(946720800)?),
+                limit: Some(10)
+            }
+        );
+
+        Ok(())
+    }
+}
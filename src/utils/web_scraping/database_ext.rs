@@ -1,14 +1,20 @@
+mod raw_web_page_data_delta;
 mod raw_web_page_data_revision;
 mod raw_web_page_tracker;
 
 use crate::{
     database::Database,
-    error::Error as SecutilsError,
-    scheduler::SchedulerJobMetadata,
+    error::{Error as SecutilsError, ErrorCode},
+    scheduler::{SchedulerJob, SchedulerJobMetadata},
     users::UserId,
     utils::web_scraping::{
-        database_ext::raw_web_page_data_revision::RawWebPageDataRevision, WebPageDataRevision,
-        WebPageTracker, WebPageTrackerTag,
+        database_ext::{
+            raw_web_page_data_delta::RawWebPageDataDelta,
+            raw_web_page_data_revision::RawWebPageDataRevision,
+        },
+        WebPageDataRevision, WebPageDataRevisionAcknowledgment, WebPageDataRevisionChainLink,
+        WebPageStatusPage, WebPageTracker, WebPageTrackerChange, WebPageTrackerKind,
+        WebPageTrackerPendingScrape, WebPageTrackerTag, WebPageTrackerWebhook,
     },
 };
 use anyhow::{anyhow, bail};
@@ -19,6 +25,10 @@ use sqlx::{error::ErrorKind as SqlxErrorKind, query, query_as, Pool, Postgres};
 use time::OffsetDateTime;
 use uuid::Uuid;
 
+/// Defines how often the full data of the web page tracker revision is stored when revisions are
+/// stored as deltas: every N-th revision is stored as a full snapshot.
+const WEB_PAGE_TRACKER_HISTORY_SNAPSHOT_INTERVAL: usize = 10;
+
 /// A database extension for the web scraping utility-related operations.
 pub struct WebScrapingDatabaseExt<'pool> {
     pool: &'pool Pool<Postgres>,
@@ -115,6 +125,7 @@ ORDER BY updated_at
                     "Web page tracker ('{}') already exists.",
                     tracker.name
                 )))
+                .with_code(ErrorCode::AlreadyExists)
             } else {
                 SecutilsError::from(anyhow!(err).context(format!(
                     "Couldn't create web page tracker ('{}') due to unknown reason.",
@@ -158,7 +169,8 @@ WHERE user_id = $1 AND id = $2 AND kind = $3
                     bail!(SecutilsError::client(format!(
                         "A web page tracker ('{}') doesn't exist.",
                         tracker.name
-                    )));
+                    ))
+                    .with_code(ErrorCode::TrackerNotFound));
                 }
             }
             Err(err) => {
@@ -171,6 +183,7 @@ WHERE user_id = $1 AND id = $2 AND kind = $3
                         "Web page tracker ('{}') already exists.",
                         tracker.name
                     )))
+                    .with_code(ErrorCode::AlreadyExists)
                 } else {
                     SecutilsError::from(anyhow!(err).context(format!(
                         "Couldn't update web page tracker ('{}') due to unknown reason.",
@@ -208,7 +221,8 @@ WHERE user_id = $1 AND id = $2 AND kind = $3
         let raw_revisions = query_as!(
             RawWebPageDataRevision,
             r#"
-SELECT history.id, history.tracker_id, history.data, history.created_at
+SELECT history.id, history.tracker_id, history.data, history.delta, history.created_at,
+       history.note, history.acknowledged_by, history.acknowledged_at, history.assertions_passed
 FROM user_data_web_scraping_trackers_history as history
 INNER JOIN user_data_web_scraping_trackers as trackers
 ON history.tracker_id = trackers.id
@@ -223,13 +237,194 @@ ORDER BY history.created_at
         .await?;
 
         let mut revisions = vec![];
+        let mut previous_data = None;
         for raw_revision in raw_revisions {
+            let raw_revision = raw_revision.reconstruct(previous_data.as_deref())?;
+            previous_data = Some(raw_revision.data.clone());
             revisions.push(WebPageDataRevision::try_from(raw_revision)?);
         }
 
         Ok(revisions)
     }
 
+    /// Retrieves all tracked revisions for the specified web page tracker page by page, so that
+    /// the whole history doesn't need to be loaded into memory at once.
+    pub fn get_web_page_tracker_history_stream<Tag: WebPageTrackerTag + 'pool>(
+        &self,
+        tracker_id: Uuid,
+        page_size: usize,
+    ) -> impl Stream<Item = anyhow::Result<WebPageDataRevision<Tag>>> + 'pool {
+        let (pool, user_id) = (self.pool, self.user_id);
+        let page_limit = page_size as i64;
+        try_stream! {
+            let kind = Vec::try_from(Tag::KIND)?;
+            let mut last_created_at = OffsetDateTime::UNIX_EPOCH;
+            let mut previous_data: Option<Vec<u8>> = None;
+            loop {
+                let raw_revisions = query_as!(
+                    RawWebPageDataRevision,
+                    r#"
+SELECT history.id, history.tracker_id, history.data, history.delta, history.created_at,
+       history.note, history.acknowledged_by, history.acknowledged_at, history.assertions_passed
+FROM user_data_web_scraping_trackers_history as history
+INNER JOIN user_data_web_scraping_trackers as trackers
+ON history.tracker_id = trackers.id
+WHERE history.user_id = $1 AND history.tracker_id = $2 AND trackers.kind = $3 AND history.created_at > $4
+ORDER BY history.created_at
+LIMIT $5
+                "#,
+                    *user_id,
+                    tracker_id,
+                    kind,
+                    last_created_at,
+                    page_limit
+                )
+                .fetch_all(pool)
+                .await?;
+
+                let is_last_page = raw_revisions.len() < page_size;
+                for raw_revision in raw_revisions {
+                    last_created_at = raw_revision.created_at;
+                    let raw_revision = raw_revision.reconstruct(previous_data.as_deref())?;
+                    previous_data = Some(raw_revision.data.clone());
+                    yield WebPageDataRevision::try_from(raw_revision)?;
+                }
+
+                if is_last_page {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Retrieves a single tracked revision for the specified web page tracker.
+    pub async fn get_web_page_tracker_history_revision<Tag: WebPageTrackerTag>(
+        &self,
+        tracker_id: Uuid,
+        revision_id: Uuid,
+    ) -> anyhow::Result<Option<WebPageDataRevision<Tag>>> {
+        let kind = Vec::try_from(Tag::KIND)?;
+        let raw_revision = query_as!(
+            RawWebPageDataRevision,
+            r#"
+SELECT history.id, history.tracker_id, history.data, history.delta, history.created_at,
+       history.note, history.acknowledged_by, history.acknowledged_at, history.assertions_passed
+FROM user_data_web_scraping_trackers_history as history
+INNER JOIN user_data_web_scraping_trackers as trackers
+ON history.tracker_id = trackers.id
+WHERE history.user_id = $1 AND history.tracker_id = $2 AND history.id = $3 AND trackers.kind = $4
+                "#,
+            *self.user_id,
+            tracker_id,
+            revision_id,
+            kind
+        )
+        .fetch_optional(self.pool)
+        .await?;
+
+        match raw_revision {
+            // Revision stored as a delta can only be reconstructed from the previous revisions.
+            Some(raw_revision) if raw_revision.delta => Ok(self
+                .get_web_page_tracker_history::<Tag>(tracker_id)
+                .await?
+                .into_iter()
+                .find(|revision| revision.id == revision_id)),
+            raw_revision => raw_revision.map(WebPageDataRevision::try_from).transpose(),
+        }
+    }
+
+    /// Retrieves hash chain links for all tracked revisions of the specified web page tracker, in
+    /// the order they were created.
+    pub async fn get_web_page_tracker_history_chain(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<Vec<WebPageDataRevisionChainLink>> {
+        let raw_links = query!(
+            r#"
+SELECT id, data, delta, previous_hash, chain_hash
+FROM user_data_web_scraping_trackers_history
+WHERE user_id = $1 AND tracker_id = $2
+ORDER BY created_at, id
+                "#,
+            *self.user_id,
+            tracker_id
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        // Hash chain is calculated for the full revision data, even if it's stored as a delta.
+        let mut links = Vec::<WebPageDataRevisionChainLink>::with_capacity(raw_links.len());
+        for raw_link in raw_links {
+            let data = if raw_link.delta {
+                let Some(previous_link) = links.last() else {
+                    bail!(
+                        "Web page tracker revision ('{}') is stored as a delta, but the previous revision is missing.",
+                        raw_link.id
+                    );
+                };
+                RawWebPageDataDelta::from_bytes(&raw_link.data)?.apply(&previous_link.data)?
+            } else {
+                raw_link.data
+            };
+
+            links.push(WebPageDataRevisionChainLink {
+                id: raw_link.id,
+                data,
+                previous_hash: raw_link.previous_hash,
+                chain_hash: raw_link.chain_hash,
+            });
+        }
+
+        Ok(links)
+    }
+
+    /// Retrieves the most recent revisions detected after the specified time across all web page
+    /// trackers of the user, ordered from the newest to the oldest.
+    pub async fn get_web_page_tracker_changes(
+        &self,
+        since: OffsetDateTime,
+        limit: usize,
+    ) -> anyhow::Result<Vec<WebPageTrackerChange>> {
+        let records = query!(
+            r#"
+SELECT history.id, history.tracker_id, history.created_at, history.note,
+       trackers.name, trackers.url, trackers.kind,
+       NOT EXISTS(
+           SELECT 1 FROM user_data_web_scraping_trackers_history as previous
+           WHERE previous.tracker_id = history.tracker_id AND previous.created_at < history.created_at
+       ) as "is_initial!"
+FROM user_data_web_scraping_trackers_history as history
+INNER JOIN user_data_web_scraping_trackers as trackers
+ON history.tracker_id = trackers.id
+WHERE history.user_id = $1 AND history.created_at > $2
+ORDER BY history.created_at DESC, history.id DESC
+LIMIT $3
+                "#,
+            *self.user_id,
+            since,
+            limit as i64
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        let mut changes = Vec::with_capacity(records.len());
+        for record in records {
+            let tracker_kind = postcard::from_bytes::<WebPageTrackerKind>(&record.kind)?;
+            changes.push(WebPageTrackerChange {
+                tracker_id: record.tracker_id,
+                tracker_name: record.name,
+                tracker_kind,
+                url: record.url.parse()?,
+                revision_id: record.id,
+                created_at: record.created_at,
+                summary: WebPageTrackerChange::summarize(tracker_kind, record.is_initial),
+                note: record.note,
+            });
+        }
+
+        Ok(changes)
+    }
+
     /// Removes web page tracker history.
     pub async fn clear_web_page_tracker_history(&self, tracker_id: Uuid) -> anyhow::Result<()> {
         query!(
@@ -246,22 +441,95 @@ ORDER BY history.created_at
         Ok(())
     }
 
-    // Inserts web page tracker revision.
+    /// Inserts web page tracker revision and links it to the previous revision of the tracker with
+    /// a hash chain, see `get_web_page_tracker_history_chain`. Inserting the revision with the same
+    /// ID more than once is a no-op, see `WebPageDataRevision::derive_id`.
     pub async fn insert_web_page_tracker_history_revision<Tag: WebPageTrackerTag>(
         &self,
         revision: &WebPageDataRevision<Tag>,
     ) -> anyhow::Result<()> {
         let raw_revision = RawWebPageDataRevision::try_from(revision)?;
+        self.insert_raw_web_page_tracker_history_revision(&raw_revision, None)
+            .await
+    }
+
+    /// Inserts web page tracker revision the same way as `insert_web_page_tracker_history_revision`
+    /// does, but stores its data as a delta against the previous revision. The full data is still
+    /// stored for every `WEB_PAGE_TRACKER_HISTORY_SNAPSHOT_INTERVAL`-th revision, and whenever the
+    /// delta isn't smaller than the full data.
+    pub async fn insert_web_page_tracker_history_revision_as_delta<Tag: WebPageTrackerTag>(
+        &self,
+        revision: &WebPageDataRevision<Tag>,
+    ) -> anyhow::Result<()> {
+        let raw_revision = RawWebPageDataRevision::try_from(revision)?;
+        let system = WebScrapingDatabaseSystemExt::new(self.pool);
+        let raw_history = system
+            .get_raw_web_page_tracker_history(revision.tracker_id)
+            .await?;
+
+        // Store a full snapshot if there are too many revisions stored as deltas since the latest
+        // one, or if the revision isn't the latest one.
+        let deltas = raw_history
+            .iter()
+            .rev()
+            .take_while(|raw_revision| raw_revision.delta)
+            .count();
+        let is_latest = raw_history
+            .last()
+            .map(|previous_revision| previous_revision.created_at < raw_revision.created_at)
+            .unwrap_or_default();
+        let delta = if is_latest && deltas + 1 < WEB_PAGE_TRACKER_HISTORY_SNAPSHOT_INTERVAL {
+            system
+                .reconstruct_raw_web_page_tracker_history(raw_history)?
+                .pop()
+                .map(|previous_revision| {
+                    RawWebPageDataDelta::compute(&previous_revision.data, &raw_revision.data)
+                        .to_bytes()
+                })
+                .transpose()?
+                .filter(|delta| delta.len() < raw_revision.data.len())
+        } else {
+            None
+        };
+
+        self.insert_raw_web_page_tracker_history_revision(&raw_revision, delta)
+            .await
+    }
+
+    /// Inserts raw web page tracker revision, optionally storing its data as the specified delta.
+    /// Content and chain hashes are always calculated for the full revision data.
+    async fn insert_raw_web_page_tracker_history_revision(
+        &self,
+        raw_revision: &RawWebPageDataRevision,
+        delta: Option<Vec<u8>>,
+    ) -> anyhow::Result<()> {
         let result = query!(
             r#"
-    INSERT INTO user_data_web_scraping_trackers_history (user_id, id, tracker_id, data, created_at)
-    VALUES ( $1, $2, $3, $4, $5 )
+    WITH previous AS (
+        SELECT COALESCE((
+            SELECT chain_hash FROM user_data_web_scraping_trackers_history
+            WHERE user_id = $1 AND tracker_id = $3
+            ORDER BY created_at DESC, id DESC
+            LIMIT 1
+        ), ''::bytea) AS chain_hash
+    )
+    INSERT INTO user_data_web_scraping_trackers_history (user_id, id, tracker_id, data, delta, created_at, content_hash, previous_hash, chain_hash, assertions_passed)
+    VALUES (
+        $1, $2, $3, $4, $5, $6, sha256($7),
+        (SELECT chain_hash FROM previous),
+        sha256((SELECT chain_hash FROM previous) || sha256($7)),
+        $8
+    )
+    ON CONFLICT (id) DO NOTHING
             "#,
             *self.user_id,
             raw_revision.id,
             raw_revision.tracker_id,
+            delta.as_ref().unwrap_or(&raw_revision.data),
+            delta.is_some(),
+            raw_revision.created_at,
             raw_revision.data,
-            raw_revision.created_at
+            raw_revision.assertions_passed
         )
         .execute(self.pool)
         .await;
@@ -274,12 +542,13 @@ ORDER BY history.created_at
             bail!(if is_conflict_error {
                 SecutilsError::client_with_root_cause(anyhow!(err).context(format!(
                     "Web page tracker revision ('{}') already exists.",
-                    revision.id
+                    raw_revision.id
                 )))
+                .with_code(ErrorCode::AlreadyExists)
             } else {
                 SecutilsError::from(anyhow!(err).context(format!(
                     "Couldn't create web page tracker revision ('{}') due to unknown reason.",
-                    revision.id
+                    raw_revision.id
                 )))
             });
         }
@@ -287,80 +556,679 @@ ORDER BY history.created_at
         Ok(())
     }
 
-    /// Removes web page tracker history.
+    /// Checks whether any of the specified number of the most recent web page tracker revisions has
+    /// the same data. Only data hashes are compared, so revisions aren't loaded into memory.
+    pub async fn has_recent_web_page_tracker_history_revision<Tag: WebPageTrackerTag>(
+        &self,
+        tracker_id: Uuid,
+        data: &Tag::TrackerData,
+        window: usize,
+    ) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+    SELECT EXISTS (
+        SELECT 1 FROM (
+            SELECT content_hash FROM user_data_web_scraping_trackers_history
+            WHERE user_id = $1 AND tracker_id = $2
+            ORDER BY created_at DESC
+            LIMIT $3
+        ) AS recent
+        WHERE recent.content_hash = sha256($4)
+    ) as "exists!"
+                    "#,
+            *self.user_id,
+            tracker_id,
+            window as i64,
+            postcard::to_stdvec(data)?
+        )
+        .fetch_one(self.pool)
+        .await?;
+
+        Ok(result.exists)
+    }
+
+    /// Sets or clears the note for the web page tracker revision. Returns `false` if the revision
+    /// doesn't exist.
+    pub async fn set_web_page_tracker_history_revision_note(
+        &self,
+        tracker_id: Uuid,
+        id: Uuid,
+        note: Option<&str>,
+    ) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+    UPDATE user_data_web_scraping_trackers_history
+    SET note = $4
+    WHERE user_id = $1 AND tracker_id = $2 AND id = $3
+                    "#,
+            *self.user_id,
+            tracker_id,
+            id,
+            note
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Records acknowledgment of the web page tracker revision. Returns `false` if the revision
+    /// doesn't exist.
+    pub async fn acknowledge_web_page_tracker_history_revision(
+        &self,
+        tracker_id: Uuid,
+        id: Uuid,
+        acknowledgment: &WebPageDataRevisionAcknowledgment,
+    ) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+    UPDATE user_data_web_scraping_trackers_history
+    SET acknowledged_by = $4, acknowledged_at = $5
+    WHERE user_id = $1 AND tracker_id = $2 AND id = $3
+                    "#,
+            *self.user_id,
+            tracker_id,
+            id,
+            *acknowledgment.user_id,
+            acknowledgment.acknowledged_at
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Removes web page tracker history revision and re-links the hash chain of the revisions that
+    /// follow it.
     pub async fn remove_web_page_tracker_history_revision(
         &self,
         tracker_id: Uuid,
         id: Uuid,
     ) -> anyhow::Result<()> {
-        query!(
+        // The next revision might be stored as a delta against the revision that's being removed.
+        let next_revision = query!(
+            r#"
+    SELECT next.id, next.delta
+    FROM user_data_web_scraping_trackers_history as next
+    INNER JOIN user_data_web_scraping_trackers_history as current
+    ON next.tracker_id = current.tracker_id AND (next.created_at, next.id) > (current.created_at, current.id)
+    WHERE current.user_id = $1 AND current.tracker_id = $2 AND current.id = $3
+    ORDER BY next.created_at, next.id
+    LIMIT 1
+                    "#,
+            *self.user_id,
+            tracker_id,
+            id
+        )
+        .fetch_optional(self.pool)
+        .await?;
+        if let Some(next_revision) = next_revision.filter(|revision| revision.delta) {
+            WebScrapingDatabaseSystemExt::new(self.pool)
+                .store_full_web_page_tracker_history_revision(tracker_id, next_revision.id)
+                .await?;
+        }
+
+        let mut tx = self.pool.begin().await?;
+        let removed_revision = query!(
             r#"
     DELETE FROM user_data_web_scraping_trackers_history
     WHERE user_id = $1 AND tracker_id = $2 AND id = $3
+    RETURNING created_at, previous_hash
                     "#,
             *self.user_id,
             tracker_id,
             id
         )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        // Re-link the hash chain of the revisions that follow the removed one, unless it was the
+        // oldest retained revision that's trusted to link to the already removed revisions.
+        if let Some(removed_revision) = removed_revision {
+            query!(
+                r#"
+    WITH RECURSIVE ordered AS (
+        SELECT id, content_hash, ROW_NUMBER() OVER (ORDER BY created_at, id) AS position
+        FROM user_data_web_scraping_trackers_history
+        WHERE user_id = $1 AND tracker_id = $2 AND (created_at, id) > ($3, $4)
+          AND EXISTS (
+              SELECT 1 FROM user_data_web_scraping_trackers_history
+              WHERE user_id = $1 AND tracker_id = $2 AND (created_at, id) < ($3, $4)
+          )
+    ), chain AS (
+        SELECT id, position, $5::bytea AS previous_hash, sha256($5::bytea || content_hash) AS chain_hash
+        FROM ordered
+        WHERE position = 1
+        UNION ALL
+        SELECT ordered.id, ordered.position, chain.chain_hash, sha256(chain.chain_hash || ordered.content_hash)
+        FROM ordered
+        INNER JOIN chain ON ordered.position = chain.position + 1
+    )
+    UPDATE user_data_web_scraping_trackers_history AS history
+    SET previous_hash = chain.previous_hash, chain_hash = chain.chain_hash
+    FROM chain
+    WHERE history.id = chain.id
+                    "#,
+                *self.user_id,
+                tracker_id,
+                removed_revision.created_at,
+                id,
+                removed_revision.previous_hash
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        Ok(tx.commit().await?)
+    }
+
+    /// Counts web page tracker revisions created after the specified time.
+    pub async fn count_web_page_tracker_history_revisions(
+        &self,
+        tracker_id: Uuid,
+        since: OffsetDateTime,
+    ) -> anyhow::Result<usize> {
+        let result = query!(
+            r#"
+    SELECT COUNT(*) as "count!"
+    FROM user_data_web_scraping_trackers_history
+    WHERE user_id = $1 AND tracker_id = $2 AND created_at > $3
+                    "#,
+            *self.user_id,
+            tracker_id,
+            since
+        )
+        .fetch_one(self.pool)
+        .await?;
+
+        Ok(result.count as usize)
+    }
+
+    /// Sets the time when the user last viewed the web page tracker history.
+    pub async fn set_web_page_tracker_last_viewed_at(
+        &self,
+        tracker_id: Uuid,
+        last_viewed_at: OffsetDateTime,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+    INSERT INTO user_data_web_scraping_trackers_views (tracker_id, last_viewed_at)
+    SELECT id, $3 FROM user_data_web_scraping_trackers
+    WHERE user_id = $1 AND id = $2
+    ON CONFLICT(tracker_id) DO UPDATE SET last_viewed_at = EXCLUDED.last_viewed_at
+            "#,
+            *self.user_id,
+            tracker_id,
+            last_viewed_at
+        )
         .execute(self.pool)
         .await?;
 
         Ok(())
     }
-}
 
-/// A database extension for the web scraping utility-related operations performed on behalf of the
-/// system/background jobs.
-pub struct WebScrapingDatabaseSystemExt<'pool> {
-    pool: &'pool Pool<Postgres>,
-}
+    /// Sets the webhook that web page tracker change notifications are delivered to. Returns
+    /// `false` if the tracker doesn't exist.
+    pub async fn upsert_web_page_tracker_webhook(
+        &self,
+        tracker_id: Uuid,
+        webhook: &WebPageTrackerWebhook,
+    ) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+    INSERT INTO user_data_web_scraping_trackers_webhooks (tracker_id, url, secret, previous_secret, previous_secret_expires_at, headers)
+    SELECT id, $3, $4, $5, $6, $7
+    FROM user_data_web_scraping_trackers
+    WHERE user_id = $1 AND id = $2
+    ON CONFLICT(tracker_id) DO UPDATE SET url = EXCLUDED.url, secret = EXCLUDED.secret, previous_secret = EXCLUDED.previous_secret, previous_secret_expires_at = EXCLUDED.previous_secret_expires_at, headers = EXCLUDED.headers
+            "#,
+            *self.user_id,
+            tracker_id,
+            webhook.url.as_str(),
+            webhook.secret,
+            webhook.previous_secret,
+            webhook.previous_secret_expires_at,
+            webhook.headers
+        )
+        .execute(self.pool)
+        .await?;
 
-impl<'pool> WebScrapingDatabaseSystemExt<'pool> {
-    pub fn new(pool: &'pool Pool<Postgres>) -> Self {
-        Self { pool }
+        Ok(result.rows_affected() > 0)
     }
 
-    /// Retrieves all web page trackers that need to be scheduled.
-    pub async fn get_unscheduled_web_page_trackers<Tag: WebPageTrackerTag>(
+    /// Retrieves webhooks of all web page trackers along with the IDs of the trackers they belong
+    /// to.
+    pub async fn get_web_page_tracker_webhooks(
         &self,
-    ) -> anyhow::Result<Vec<WebPageTracker<Tag>>> {
-        let kind = Vec::try_from(Tag::KIND)?;
-        let raw_trackers = query_as!(
-            RawWebPageTracker,
+    ) -> anyhow::Result<Vec<(Uuid, WebPageTrackerWebhook)>> {
+        query!(
             r#"
-SELECT id, name, url, kind, user_id, job_id, job_config, data, created_at, updated_at
-FROM user_data_web_scraping_trackers
-WHERE job_config IS NOT NULL AND job_id IS NULL AND kind = $1
-ORDER BY updated_at
-                "#,
-            kind
+    SELECT webhooks.tracker_id, webhooks.url, webhooks.secret, webhooks.previous_secret, webhooks.previous_secret_expires_at, webhooks.headers
+    FROM user_data_web_scraping_trackers_webhooks AS webhooks
+    INNER JOIN user_data_web_scraping_trackers AS trackers
+    ON webhooks.tracker_id = trackers.id
+    WHERE trackers.user_id = $1
+    ORDER BY webhooks.tracker_id
+            "#,
+            *self.user_id
         )
         .fetch_all(self.pool)
-        .await?;
+        .await?
+        .into_iter()
+        .map(|row| {
+            Ok((
+                row.tracker_id,
+                WebPageTrackerWebhook {
+                    url: row.url.parse()?,
+                    secret: row.secret,
+                    previous_secret: row.previous_secret,
+                    previous_secret_expires_at: row.previous_secret_expires_at,
+                headers: row.headers,
+                },
+            ))
+        })
+        .collect()
+    }
 
-        let mut trackers = vec![];
-        for raw_tracker in raw_trackers {
-            let tracker = WebPageTracker::try_from(raw_tracker)?;
-            // Tracker without revisions shouldn't be scheduled.
-            if tracker.settings.revisions > 0 {
-                trackers.push(tracker);
-            }
-        }
+    /// Removes the webhook that web page tracker change notifications are delivered to.
+    pub async fn remove_web_page_tracker_webhook(&self, tracker_id: Uuid) -> anyhow::Result<()> {
+        query!(
+            r#"
+    DELETE FROM user_data_web_scraping_trackers_webhooks AS webhooks
+    USING user_data_web_scraping_trackers AS trackers
+    WHERE webhooks.tracker_id = trackers.id AND trackers.user_id = $1 AND trackers.id = $2
+            "#,
+            *self.user_id,
+            tracker_id
+        )
+        .execute(self.pool)
+        .await?;
 
-        Ok(trackers)
+        Ok(())
     }
 
-    /// Retrieves all scheduled jobs from `scheduler_jobs` table that are in a `stopped` state.
-    pub fn get_pending_web_page_trackers<'a, Tag: WebPageTrackerTag + 'a>(
-        &'a self,
-        page_size: usize,
-    ) -> impl Stream<Item = anyhow::Result<WebPageTracker<Tag>>> + '_ {
-        let page_limit = page_size as i64;
-        try_stream! {
-            let mut last_created_at = OffsetDateTime::UNIX_EPOCH;
-            let kind = Vec::try_from(Tag::KIND)?;
-            let mut conn = self.pool.acquire().await?;
-            loop {
+    /// Inserts web page tracker scrape job submitted to the web scraper in the asynchronous mode.
+    pub async fn insert_web_page_tracker_pending_scrape(
+        &self,
+        pending_scrape: &WebPageTrackerPendingScrape,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+    INSERT INTO user_data_web_scraping_pending_scrapes (job_id, user_id, tracker_id, token, created_at)
+    VALUES ( $1, $2, $3, $4, $5 )
+            "#,
+            pending_scrape.job_id,
+            *self.user_id,
+            pending_scrape.tracker_id,
+            pending_scrape.token,
+            pending_scrape.created_at
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Removes web page tracker scrape job submitted to the web scraper in the asynchronous mode.
+    /// Returns `false` if the scrape job doesn't exist (e.g. its result was already processed).
+    pub async fn remove_web_page_tracker_pending_scrape(
+        &self,
+        job_id: &str,
+    ) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+    DELETE FROM user_data_web_scraping_pending_scrapes
+    WHERE user_id = $1 AND job_id = $2
+            "#,
+            *self.user_id,
+            job_id
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Retrieves the ID of the web page tracker revision pinned as a baseline, if any.
+    pub async fn get_web_page_tracker_baseline_revision_id(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<Option<Uuid>> {
+        Ok(query!(
+            r#"
+    SELECT baselines.revision_id
+    FROM user_data_web_scraping_trackers_baselines AS baselines
+    INNER JOIN user_data_web_scraping_trackers AS trackers
+    ON baselines.tracker_id = trackers.id
+    WHERE trackers.user_id = $1 AND trackers.id = $2
+            "#,
+            *self.user_id,
+            tracker_id
+        )
+        .fetch_optional(self.pool)
+        .await?
+        .map(|row| row.revision_id))
+    }
+
+    /// Pins the web page tracker revision as a baseline. Returns `false` if the revision doesn't
+    /// exist.
+    pub async fn upsert_web_page_tracker_baseline_revision(
+        &self,
+        tracker_id: Uuid,
+        revision_id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+    INSERT INTO user_data_web_scraping_trackers_baselines (tracker_id, revision_id)
+    SELECT tracker_id, id
+    FROM user_data_web_scraping_trackers_history
+    WHERE user_id = $1 AND tracker_id = $2 AND id = $3
+    ON CONFLICT(tracker_id) DO UPDATE SET revision_id = EXCLUDED.revision_id
+            "#,
+            *self.user_id,
+            tracker_id,
+            revision_id
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Unpins the web page tracker baseline revision.
+    pub async fn remove_web_page_tracker_baseline_revision(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+    DELETE FROM user_data_web_scraping_trackers_baselines AS baselines
+    USING user_data_web_scraping_trackers AS trackers
+    WHERE baselines.tracker_id = trackers.id AND trackers.user_id = $1 AND trackers.id = $2
+            "#,
+            *self.user_id,
+            tracker_id
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves all status pages of the user.
+    pub async fn get_web_page_status_pages(&self) -> anyhow::Result<Vec<WebPageStatusPage>> {
+        Ok(query_as!(
+            WebPageStatusPage,
+            r#"
+    SELECT id, name, token, tracker_ids, created_at, updated_at
+    FROM user_data_web_scraping_status_pages
+    WHERE user_id = $1
+    ORDER BY created_at
+                    "#,
+            *self.user_id
+        )
+        .fetch_all(self.pool)
+        .await?)
+    }
+
+    /// Retrieves status page with the specified ID.
+    pub async fn get_web_page_status_page(
+        &self,
+        id: Uuid,
+    ) -> anyhow::Result<Option<WebPageStatusPage>> {
+        Ok(query_as!(
+            WebPageStatusPage,
+            r#"
+    SELECT id, name, token, tracker_ids, created_at, updated_at
+    FROM user_data_web_scraping_status_pages
+    WHERE user_id = $1 AND id = $2
+                    "#,
+            *self.user_id,
+            id
+        )
+        .fetch_optional(self.pool)
+        .await?)
+    }
+
+    /// Inserts status page.
+    pub async fn insert_web_page_status_page(
+        &self,
+        status_page: &WebPageStatusPage,
+    ) -> anyhow::Result<()> {
+        let result = query!(
+            r#"
+    INSERT INTO user_data_web_scraping_status_pages (user_id, id, name, token, tracker_ids, created_at, updated_at)
+    VALUES ( $1, $2, $3, $4, $5, $6, $7 )
+            "#,
+            *self.user_id,
+            status_page.id,
+            status_page.name,
+            status_page.token,
+            &status_page.tracker_ids,
+            status_page.created_at,
+            status_page.updated_at
+        )
+        .execute(self.pool)
+        .await;
+
+        if let Err(err) = result {
+            let is_conflict_error = err
+                .as_database_error()
+                .map(|db_error| matches!(db_error.kind(), SqlxErrorKind::UniqueViolation))
+                .unwrap_or_default();
+            bail!(if is_conflict_error {
+                SecutilsError::client_with_root_cause(anyhow!(err).context(format!(
+                    "Status page ('{}') already exists.",
+                    status_page.name
+                )))
+                .with_code(ErrorCode::AlreadyExists)
+            } else {
+                SecutilsError::from(anyhow!(err).context(format!(
+                    "Couldn't create status page ('{}') due to unknown reason.",
+                    status_page.name
+                )))
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Updates status page.
+    pub async fn update_web_page_status_page(
+        &self,
+        status_page: &WebPageStatusPage,
+    ) -> anyhow::Result<()> {
+        let result = query!(
+            r#"
+UPDATE user_data_web_scraping_status_pages
+SET name = $3, token = $4, tracker_ids = $5, updated_at = $6
+WHERE user_id = $1 AND id = $2
+        "#,
+            *self.user_id,
+            status_page.id,
+            status_page.name,
+            status_page.token,
+            &status_page.tracker_ids,
+            status_page.updated_at
+        )
+        .execute(self.pool)
+        .await;
+
+        match result {
+            Ok(result) => {
+                if result.rows_affected() == 0 {
+                    bail!(SecutilsError::client(format!(
+                        "A status page ('{}') doesn't exist.",
+                        status_page.name
+                    ))
+                    .with_code(ErrorCode::StatusPageNotFound));
+                }
+            }
+            Err(err) => {
+                let is_conflict_error = err
+                    .as_database_error()
+                    .map(|db_error| matches!(db_error.kind(), SqlxErrorKind::UniqueViolation))
+                    .unwrap_or_default();
+                bail!(if is_conflict_error {
+                    SecutilsError::client_with_root_cause(anyhow!(err).context(format!(
+                        "Status page ('{}') already exists.",
+                        status_page.name
+                    )))
+                    .with_code(ErrorCode::AlreadyExists)
+                } else {
+                    SecutilsError::from(anyhow!(err).context(format!(
+                        "Couldn't update status page ('{}') due to unknown reason.",
+                        status_page.name
+                    )))
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes status page with the specified ID.
+    pub async fn remove_web_page_status_page(&self, id: Uuid) -> anyhow::Result<()> {
+        query!(
+            r#"
+    DELETE FROM user_data_web_scraping_status_pages
+    WHERE user_id = $1 AND id = $2
+                    "#,
+            *self.user_id,
+            id
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// A database extension for the web scraping utility-related operations performed on behalf of the
+/// system/background jobs.
+pub struct WebScrapingDatabaseSystemExt<'pool> {
+    pool: &'pool Pool<Postgres>,
+}
+
+impl<'pool> WebScrapingDatabaseSystemExt<'pool> {
+    pub fn new(pool: &'pool Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    /// Retrieves all web page trackers that need to be scheduled.
+    pub async fn get_unscheduled_web_page_trackers<Tag: WebPageTrackerTag>(
+        &self,
+    ) -> anyhow::Result<Vec<WebPageTracker<Tag>>> {
+        let kind = Vec::try_from(Tag::KIND)?;
+        let raw_trackers = query_as!(
+            RawWebPageTracker,
+            r#"
+SELECT id, name, url, kind, user_id, job_id, job_config, data, created_at, updated_at
+FROM user_data_web_scraping_trackers
+WHERE job_config IS NOT NULL AND job_id IS NULL AND kind = $1
+ORDER BY updated_at
+                "#,
+            kind
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        let mut trackers = vec![];
+        for raw_tracker in raw_trackers {
+            let tracker = WebPageTracker::try_from(raw_tracker)?;
+            // Tracker without revisions shouldn't be scheduled.
+            if tracker.settings.revisions > 0 {
+                trackers.push(tracker);
+            }
+        }
+
+        Ok(trackers)
+    }
+
+    /// Retrieves all scheduled web page trackers of the specified kind that have had neither new
+    /// revisions, nor updates, nor history views since the specified time, along with the time of
+    /// their last activity.
+    pub async fn get_idle_web_page_trackers<Tag: WebPageTrackerTag>(
+        &self,
+        idle_since: OffsetDateTime,
+    ) -> anyhow::Result<Vec<(WebPageTracker<Tag>, OffsetDateTime)>> {
+        let kind = Vec::try_from(Tag::KIND)?;
+        let records = query!(
+            r#"
+SELECT trackers.id, trackers.name, trackers.url, trackers.kind, trackers.user_id, trackers.job_id,
+       trackers.job_config, trackers.data, trackers.created_at, trackers.updated_at,
+       GREATEST(
+           trackers.updated_at,
+           views.last_viewed_at,
+           (SELECT MAX(history.created_at) FROM user_data_web_scraping_trackers_history as history
+            WHERE history.tracker_id = trackers.id)
+       ) as "last_active_at!"
+FROM user_data_web_scraping_trackers as trackers
+LEFT JOIN user_data_web_scraping_trackers_views as views
+ON views.tracker_id = trackers.id
+WHERE trackers.kind = $1 AND trackers.job_config IS NOT NULL AND GREATEST(
+    trackers.updated_at,
+    views.last_viewed_at,
+    (SELECT MAX(history.created_at) FROM user_data_web_scraping_trackers_history as history
+     WHERE history.tracker_id = trackers.id)
+) < $2
+ORDER BY trackers.created_at
+                "#,
+            kind,
+            idle_since
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        let mut trackers = vec![];
+        for record in records {
+            trackers.push((
+                WebPageTracker::<Tag>::try_from(RawWebPageTracker {
+                    id: record.id,
+                    name: record.name,
+                    url: record.url,
+                    kind: record.kind,
+                    job_id: record.job_id,
+                    job_config: record.job_config,
+                    user_id: record.user_id,
+                    data: record.data,
+                    created_at: record.created_at,
+                    updated_at: record.updated_at,
+                })?,
+                record.last_active_at,
+            ));
+        }
+
+        Ok(trackers)
+    }
+
+    /// Disables web page tracker by removing its schedule and unlinking its job.
+    pub async fn disable_web_page_tracker(&self, id: Uuid) -> anyhow::Result<()> {
+        query!(
+            r#"
+    UPDATE user_data_web_scraping_trackers
+    SET job_config = NULL, job_id = NULL
+    WHERE id = $1
+            "#,
+            id
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves all scheduled jobs from `scheduler_jobs` table that are in a `stopped` state.
+    pub fn get_pending_web_page_trackers<'a, Tag: WebPageTrackerTag + 'a>(
+        &'a self,
+        page_size: usize,
+    ) -> impl Stream<Item = anyhow::Result<WebPageTracker<Tag>>> + 'a {
+        let page_limit = page_size as i64;
+        try_stream! {
+            let mut last_created_at = OffsetDateTime::UNIX_EPOCH;
+            let kind = Vec::try_from(Tag::KIND)?;
+            let mut conn = self.pool.acquire().await?;
+            loop {
                  let records = query!(
 r#"
 SELECT trackers.id, trackers.name, trackers.url, trackers.kind, trackers.job_id,
@@ -378,86 +1246,457 @@ LIMIT $3;
             .fetch_all(&mut *conn)
             .await?;
 
-                let is_last_page = records.len() < page_size;
-                let now = OffsetDateTime::now_utc();
-                for record in records {
-                    last_created_at = record.created_at;
+                let is_last_page = records.len() < page_size;
+                let now = OffsetDateTime::now_utc();
+                for record in records {
+                    last_created_at = record.created_at;
+
+                    // Check if the tracker job is pending the retry attempt.
+                    let job_meta = record.extra.map(|extra| SchedulerJobMetadata::try_from(extra.as_slice())).transpose()?;
+                    if let Some(SchedulerJobMetadata { retry: Some(retry), .. }) = job_meta {
+                        if retry.next_at > now {
+                            continue;
+                        }
+                    }
+
+                    yield WebPageTracker::<Tag>::try_from(RawWebPageTracker {
+                        id: record.id,
+                        name: record.name,
+                        url: record.url,
+                        kind: record.kind,
+                        job_id: record.job_id,
+                        job_config: record.job_config,
+                        user_id: record.user_id,
+                        data: record.data,
+                        created_at: record.created_at,
+                        updated_at: record.updated_at
+                    })?;
+                }
+
+                if is_last_page {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Retrieves web page tracker by the specified job ID.
+    pub async fn get_web_page_tracker_by_job_id<Tag: WebPageTrackerTag>(
+        &self,
+        job_id: Uuid,
+    ) -> anyhow::Result<Option<WebPageTracker<Tag>>> {
+        let kind = Vec::try_from(Tag::KIND)?;
+        query_as!(
+            RawWebPageTracker,
+            r#"
+    SELECT id, name, url, kind, user_id, job_id, job_config, data, created_at, updated_at
+    FROM user_data_web_scraping_trackers
+    WHERE job_id = $1 AND kind = $2
+                    "#,
+            job_id,
+            kind
+        )
+        .fetch_optional(self.pool)
+        .await?
+        .map(WebPageTracker::try_from)
+        .transpose()
+    }
+
+    /// Retrieves all web page trackers of the specified kind that have at least one revision in
+    /// their history.
+    pub fn get_web_page_trackers_with_history<'a, Tag: WebPageTrackerTag + 'a>(
+        &'a self,
+        page_size: usize,
+    ) -> impl Stream<Item = anyhow::Result<WebPageTracker<Tag>>> + 'a {
+        let page_limit = page_size as i64;
+        try_stream! {
+            let mut last_created_at = OffsetDateTime::UNIX_EPOCH;
+            let kind = Vec::try_from(Tag::KIND)?;
+            let mut conn = self.pool.acquire().await?;
+            loop {
+                let raw_trackers = query_as!(
+                    RawWebPageTracker,
+r#"
+SELECT trackers.id, trackers.name, trackers.url, trackers.kind, trackers.user_id, trackers.job_id,
+       trackers.job_config, trackers.data, trackers.created_at, trackers.updated_at
+FROM user_data_web_scraping_trackers as trackers
+WHERE trackers.kind = $1 AND trackers.created_at > $2 AND EXISTS (
+    SELECT 1 FROM user_data_web_scraping_trackers_history as history
+    WHERE history.tracker_id = trackers.id
+)
+ORDER BY trackers.created_at
+LIMIT $3;
+"#,
+                    kind, last_created_at, page_limit
+                )
+                .fetch_all(&mut *conn)
+                .await?;
+
+                let is_last_page = raw_trackers.len() < page_size;
+                for raw_tracker in raw_trackers {
+                    last_created_at = raw_tracker.created_at;
+                    yield WebPageTracker::<Tag>::try_from(raw_tracker)?;
+                }
+
+                if is_last_page {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Removes web page tracker revisions created before the specified time. Returns the number of
+    /// removed revisions.
+    pub async fn remove_web_page_tracker_history_before(
+        &self,
+        tracker_id: Uuid,
+        before: OffsetDateTime,
+    ) -> anyhow::Result<u64> {
+        // The oldest remaining revision might be stored as a delta against the removed revisions.
+        let first_revision = query!(
+            r#"
+    SELECT id, delta
+    FROM user_data_web_scraping_trackers_history
+    WHERE tracker_id = $1 AND created_at >= $2
+    ORDER BY created_at, id
+    LIMIT 1
+                    "#,
+            tracker_id,
+            before
+        )
+        .fetch_optional(self.pool)
+        .await?;
+        if let Some(first_revision) = first_revision.filter(|revision| revision.delta) {
+            self.store_full_web_page_tracker_history_revision(tracker_id, first_revision.id)
+                .await?;
+        }
+
+        let result = query!(
+            r#"
+    DELETE FROM user_data_web_scraping_trackers_history
+    WHERE tracker_id = $1 AND created_at < $2
+                    "#,
+            tracker_id,
+            before
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Inserts web page tracker.
+    pub async fn update_web_page_tracker_job(
+        &self,
+        id: Uuid,
+        job_id: Option<Uuid>,
+    ) -> anyhow::Result<()> {
+        let result = query!(
+            r#"
+    UPDATE user_data_web_scraping_trackers
+    SET job_id = $2
+    WHERE id = $1
+            "#,
+            id,
+            job_id
+        )
+        .execute(self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            bail!(
+                SecutilsError::client(format!("A web page tracker ('{id}') doesn't exist.",))
+                    .with_code(ErrorCode::TrackerNotFound)
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Unlinks web page trackers from the jobs that no longer exist in the `scheduler_jobs` table.
+    /// Returns IDs of the unlinked trackers.
+    pub async fn unlink_web_page_trackers_with_missing_jobs(&self) -> anyhow::Result<Vec<Uuid>> {
+        let records = query!(
+            r#"
+    UPDATE user_data_web_scraping_trackers
+    SET job_id = NULL
+    WHERE job_id IS NOT NULL AND NOT EXISTS (
+        SELECT 1 FROM scheduler_jobs WHERE scheduler_jobs.id = user_data_web_scraping_trackers.job_id
+    )
+    RETURNING id
+            "#
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(records.into_iter().map(|record| record.id).collect())
+    }
+
+    /// Retrieves IDs of the web page tracker trigger jobs that aren't linked to any tracker.
+    pub async fn get_unlinked_web_page_tracker_jobs(&self) -> anyhow::Result<Vec<Uuid>> {
+        let records = query!(
+            r#"
+    SELECT id, extra
+    FROM scheduler_jobs
+    WHERE NOT EXISTS (
+        SELECT 1 FROM user_data_web_scraping_trackers
+        WHERE user_data_web_scraping_trackers.job_id = scheduler_jobs.id
+    )
+    ORDER BY id
+            "#
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        let mut job_ids = vec![];
+        for record in records {
+            let Some(extra) = record.extra else {
+                continue;
+            };
 
-                    // Check if the tracker job is pending the retry attempt.
-                    let job_meta = record.extra.map(|extra| SchedulerJobMetadata::try_from(extra.as_slice())).transpose()?;
-                    if let Some(SchedulerJobMetadata { retry: Some(retry), .. }) = job_meta {
-                        if retry.next_at > now {
-                            continue;
-                        }
-                    }
+            if matches!(
+                SchedulerJobMetadata::try_from(extra.as_slice())?.job_type,
+                SchedulerJob::WebPageTrackersTrigger { .. }
+            ) {
+                job_ids.push(record.id);
+            }
+        }
 
-                    yield WebPageTracker::<Tag>::try_from(RawWebPageTracker {
-                        id: record.id,
-                        name: record.name,
-                        url: record.url,
-                        kind: record.kind,
-                        job_id: record.job_id,
-                        job_config: record.job_config,
-                        user_id: record.user_id,
-                        data: record.data,
-                        created_at: record.created_at,
-                        updated_at: record.updated_at
-                    })?;
-                }
+        Ok(job_ids)
+    }
 
-                if is_last_page {
-                    break;
+    /// Retrieves IDs of all web page tracker jobs that are pending processing, the most overdue
+    /// jobs go first. Jobs that are waiting for the next retry attempt aren't included.
+    pub async fn get_overdue_web_page_tracker_jobs(&self) -> anyhow::Result<Vec<Uuid>> {
+        let records = query!(
+            r#"
+    SELECT jobs.id, jobs.extra
+    FROM user_data_web_scraping_trackers as trackers
+    INNER JOIN scheduler_jobs as jobs
+    ON trackers.job_id = jobs.id
+    WHERE jobs.stopped = true
+    ORDER BY jobs.last_tick NULLS FIRST, trackers.created_at
+            "#
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        let now = OffsetDateTime::now_utc();
+        let mut job_ids = vec![];
+        for record in records {
+            let job_meta = record
+                .extra
+                .map(|extra| SchedulerJobMetadata::try_from(extra.as_slice()))
+                .transpose()?;
+            if let Some(SchedulerJobMetadata {
+                retry: Some(retry), ..
+            }) = job_meta
+            {
+                if retry.next_at > now {
+                    continue;
                 }
             }
+
+            job_ids.push(record.id);
         }
+
+        Ok(job_ids)
     }
 
-    /// Retrieves web page tracker by the specified job ID.
-    pub async fn get_web_page_tracker_by_job_id<Tag: WebPageTrackerTag>(
+    /// Retrieves the time when the user was last notified about the web page tracker changes. The
+    /// time can be in the future if the notification is already scheduled.
+    pub async fn get_web_page_tracker_last_notified_at(
         &self,
-        job_id: Uuid,
-    ) -> anyhow::Result<Option<WebPageTracker<Tag>>> {
-        let kind = Vec::try_from(Tag::KIND)?;
-        query_as!(
-            RawWebPageTracker,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<Option<OffsetDateTime>> {
+        Ok(query!(
             r#"
-    SELECT id, name, url, kind, user_id, job_id, job_config, data, created_at, updated_at
-    FROM user_data_web_scraping_trackers
-    WHERE job_id = $1 AND kind = $2
-                    "#,
-            job_id,
-            kind
+    SELECT last_notified_at
+    FROM user_data_web_scraping_trackers_notifications
+    WHERE tracker_id = $1
+            "#,
+            tracker_id
         )
         .fetch_optional(self.pool)
         .await?
-        .map(WebPageTracker::try_from)
-        .transpose()
+        .map(|row| row.last_notified_at))
     }
 
-    /// Inserts web page tracker.
-    pub async fn update_web_page_tracker_job(
+    /// Sets the time when the user was last notified about the web page tracker changes.
+    pub async fn set_web_page_tracker_last_notified_at(
         &self,
-        id: Uuid,
-        job_id: Option<Uuid>,
+        tracker_id: Uuid,
+        last_notified_at: OffsetDateTime,
     ) -> anyhow::Result<()> {
-        let result = query!(
+        query!(
             r#"
-    UPDATE user_data_web_scraping_trackers
-    SET job_id = $2
-    WHERE id = $1
+    INSERT INTO user_data_web_scraping_trackers_notifications (tracker_id, last_notified_at)
+    VALUES ( $1, $2 )
+    ON CONFLICT(tracker_id) DO UPDATE SET last_notified_at = EXCLUDED.last_notified_at
             "#,
-            id,
-            job_id
+            tracker_id,
+            last_notified_at
         )
         .execute(self.pool)
         .await?;
 
-        if result.rows_affected() == 0 {
-            bail!(SecutilsError::client(format!(
-                "A web page tracker ('{id}') doesn't exist.",
-            )));
+        Ok(())
+    }
+
+    /// Retrieves the webhook that web page tracker change notifications are delivered to.
+    pub async fn get_web_page_tracker_webhook(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<Option<WebPageTrackerWebhook>> {
+        query!(
+            r#"
+    SELECT url, secret, previous_secret, previous_secret_expires_at, headers
+    FROM user_data_web_scraping_trackers_webhooks
+    WHERE tracker_id = $1
+            "#,
+            tracker_id
+        )
+        .fetch_optional(self.pool)
+        .await?
+        .map(|row| {
+            Ok(WebPageTrackerWebhook {
+                url: row.url.parse()?,
+                secret: row.secret,
+                previous_secret: row.previous_secret,
+                previous_secret_expires_at: row.previous_secret_expires_at,
+                headers: row.headers,
+            })
+        })
+        .transpose()
+    }
+
+    /// Retrieves web page tracker scrape job submitted to the web scraper in the asynchronous mode
+    /// along with the ID of the user it belongs to.
+    pub async fn get_web_page_tracker_pending_scrape(
+        &self,
+        job_id: &str,
+    ) -> anyhow::Result<Option<(UserId, WebPageTrackerPendingScrape)>> {
+        Ok(query!(
+            r#"
+    SELECT job_id, user_id, tracker_id, token, created_at
+    FROM user_data_web_scraping_pending_scrapes
+    WHERE job_id = $1
+            "#,
+            job_id
+        )
+        .fetch_optional(self.pool)
+        .await?
+        .map(|row| {
+            (
+                UserId::from(row.user_id),
+                WebPageTrackerPendingScrape {
+                    job_id: row.job_id,
+                    tracker_id: row.tracker_id,
+                    token: row.token,
+                    created_at: row.created_at,
+                },
+            )
+        }))
+    }
+
+    /// Retrieves status page with the specified token along with the ID of the user it belongs to.
+    pub async fn get_web_page_status_page_by_token(
+        &self,
+        token: &str,
+    ) -> anyhow::Result<Option<(UserId, WebPageStatusPage)>> {
+        Ok(query!(
+            r#"
+    SELECT user_id, id, name, token, tracker_ids, created_at, updated_at
+    FROM user_data_web_scraping_status_pages
+    WHERE token = $1
+                    "#,
+            token
+        )
+        .fetch_optional(self.pool)
+        .await?
+        .map(|row| {
+            (
+                UserId::from(row.user_id),
+                WebPageStatusPage {
+                    id: row.id,
+                    name: row.name,
+                    token: row.token,
+                    tracker_ids: row.tracker_ids,
+                    created_at: row.created_at,
+                    updated_at: row.updated_at,
+                },
+            )
+        }))
+    }
+    /// Retrieves raw revisions of the specified web page tracker in the order they were created.
+    /// Revisions stored as deltas aren't reconstructed, see
+    /// `reconstruct_raw_web_page_tracker_history`.
+    async fn get_raw_web_page_tracker_history(
+        &self,
+        tracker_id: Uuid,
+    ) -> anyhow::Result<Vec<RawWebPageDataRevision>> {
+        Ok(query_as!(
+            RawWebPageDataRevision,
+            r#"
+SELECT id, tracker_id, data, delta, created_at, note, acknowledged_by, acknowledged_at, assertions_passed
+FROM user_data_web_scraping_trackers_history
+WHERE tracker_id = $1
+ORDER BY created_at, id
+                "#,
+            tracker_id
+        )
+        .fetch_all(self.pool)
+        .await?)
+    }
+
+    /// Reconstructs the full data of the raw revisions stored as deltas. Raw revisions should be
+    /// ordered by the creation time, see `get_raw_web_page_tracker_history`.
+    fn reconstruct_raw_web_page_tracker_history(
+        &self,
+        raw_history: Vec<RawWebPageDataRevision>,
+    ) -> anyhow::Result<Vec<RawWebPageDataRevision>> {
+        let mut reconstructed_history =
+            Vec::<RawWebPageDataRevision>::with_capacity(raw_history.len());
+        for raw_revision in raw_history {
+            let previous_data = reconstructed_history
+                .last()
+                .map(|revision| revision.data.as_slice());
+            let raw_revision = raw_revision.reconstruct(previous_data)?;
+            reconstructed_history.push(raw_revision);
         }
 
+        Ok(reconstructed_history)
+    }
+
+    /// Replaces the delta the specified web page tracker revision is stored as with the full data,
+    /// so that the revision no longer depends on the previous revisions.
+    async fn store_full_web_page_tracker_history_revision(
+        &self,
+        tracker_id: Uuid,
+        revision_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let raw_history = self.get_raw_web_page_tracker_history(tracker_id).await?;
+        let Some(raw_revision) = self
+            .reconstruct_raw_web_page_tracker_history(raw_history)?
+            .into_iter()
+            .find(|raw_revision| raw_revision.id == revision_id)
+        else {
+            return Ok(());
+        };
+
+        query!(
+            r#"
+    UPDATE user_data_web_scraping_trackers_history
+    SET data = $3, delta = FALSE
+    WHERE tracker_id = $1 AND id = $2
+                    "#,
+            tracker_id,
+            revision_id,
+            raw_revision.data
+        )
+        .execute(self.pool)
+        .await?;
+
         Ok(())
     }
 }
@@ -465,13 +1704,13 @@ LIMIT $3;
 impl Database {
     /// Returns a database extension for the web scraping utility-related operations performed on
     /// behalf of the specified user.
-    pub fn web_scraping(&self, user_id: UserId) -> WebScrapingDatabaseExt {
+    pub fn web_scraping(&self, user_id: UserId) -> WebScrapingDatabaseExt<'_> {
         WebScrapingDatabaseExt::new(&self.pool, user_id)
     }
 
     /// Returns a database extension for the web scraping utility-related operations performed on
     /// behalf of the system. This extension SHOULD NOT be used by the end-user triggered actions.
-    pub fn web_scraping_system(&self) -> WebScrapingDatabaseSystemExt {
+    pub fn web_scraping_system(&self) -> WebScrapingDatabaseSystemExt<'_> {
         WebScrapingDatabaseSystemExt::new(&self.pool)
     }
 }
@@ -523,6 +1762,7 @@ mod tests {
                         size: 123,
                     }),
                     diff_status: None,
+                    category: None,
                 }],
                 styles: vec![WebPageResource {
                     url: Some(Url::parse("http://localhost:1234/my/app.css?q=2")?),
@@ -531,8 +1771,16 @@ mod tests {
                         size: 321,
                     }),
                     diff_status: None,
+                    category: None,
                 }],
+                categories: None,
+                sizes: None,
+                truncated: false,
             },
+            note: None,
+            acknowledgment: None,
+            diff_stats: None,
+            assertions_passed: None,
         })
     }
 
@@ -561,8 +1809,10 @@ mod tests {
                 retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
                     interval: Duration::from_secs(120),
                     max_attempts: 5,
+                    max_total_retry_duration: None,
                 }),
                 notifications: true,
+                adaptive_interval: None,
             })
             .build(),
         ];
@@ -1051,7 +2301,7 @@ mod tests {
         let db = Database::create(pool).await?;
         db.insert_user(&user).await?;
 
-        let trackers = vec![
+        let trackers = [
             MockWebPageTrackerBuilder::<WebPageResourcesTrackerTag>::create(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 "some-name",
@@ -1124,13 +2374,229 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn ignores_history_revisions_with_the_same_id(pool: PgPool) -> anyhow::Result<()> {
+        let user = mock_user()?;
+        let db = Database::create(pool).await?;
+        db.insert_user(&user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageResourcesTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+
+        let web_scraping = db.web_scraping(user.id);
+        web_scraping.insert_web_page_tracker(&tracker).await?;
+
+        // Retried attempts to store the same logical revision should converge to a single one.
+        let mut revision = create_resources_revision(Uuid::nil(), tracker.id, 0)?;
+        revision.id = WebPageDataRevision::<WebPageResourcesTrackerTag>::derive_id(
+            revision.tracker_id,
+            revision.created_at,
+            &revision.data,
+        )?;
+        for _ in 0..2 {
+            web_scraping
+                .insert_web_page_tracker_history_revision(&revision)
+                .await?;
+        }
+
+        assert_eq!(
+            web_scraping
+                .get_web_page_tracker_history::<WebPageResourcesTrackerTag>(tracker.id)
+                .await?,
+            vec![revision]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_stream_history_revisions(pool: PgPool) -> anyhow::Result<()> {
+        let user = mock_user()?;
+        let db = Database::create(pool).await?;
+        db.insert_user(&user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageResourcesTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+
+        let web_scraping = db.web_scraping(user.id);
+        web_scraping.insert_web_page_tracker(&tracker).await?;
+
+        // No history yet.
+        let history = web_scraping
+            .get_web_page_tracker_history_stream::<WebPageResourcesTrackerTag>(tracker.id, 2)
+            .collect::<Vec<_>>()
+            .await;
+        assert!(history.is_empty());
+
+        let revisions = (0..5)
+            .map(|index| {
+                create_resources_revision(Uuid::from_u128(index + 1), tracker.id, index as i64)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        for revision in revisions.iter() {
+            web_scraping
+                .insert_web_page_tracker_history_revision(revision)
+                .await?;
+        }
+
+        // Revisions should be returned in order regardless of the page size.
+        for page_size in [1, 2, 5, 10] {
+            let history = web_scraping
+                .get_web_page_tracker_history_stream(tracker.id, page_size)
+                .collect::<Vec<_>>()
+                .await
+                .into_iter()
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            assert_eq!(history, revisions);
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_store_history_revisions_as_deltas(pool: PgPool) -> anyhow::Result<()> {
+        let user = mock_user()?;
+        let db = Database::create(pool.clone()).await?;
+        db.insert_user(&user).await?;
+
+        let tracker = MockWebPageTrackerBuilder::<WebPageContentTrackerTag>::create(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            "some-name",
+            "https://secutils.dev",
+            3,
+        )?
+        .build();
+
+        let web_scraping = db.web_scraping(user.id);
+        web_scraping.insert_web_page_tracker(&tracker).await?;
+
+        // Every revision appends a new line and changes one of the existing lines.
+        let revisions = (0..12)
+            .map(
+                |index| -> anyhow::Result<WebPageDataRevision<WebPageContentTrackerTag>> {
+                    let mut lines = (0..20 + index)
+                        .map(|line| format!("line {line}: {}", "-".repeat(50)))
+                        .collect::<Vec<_>>();
+                    lines[index] = format!("changed line {index}");
+                    Ok(WebPageDataRevision {
+                        id: Uuid::from_u128(index as u128 + 1),
+                        tracker_id: tracker.id,
+                        data: lines.join("\n"),
+                        created_at: OffsetDateTime::from_unix_timestamp(946720800 + index as i64)?,
+                        note: None,
+                        acknowledgment: None,
+                        diff_stats: None,
+                        assertions_passed: None,
+                    })
+                },
+            )
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        for revision in revisions.iter() {
+            web_scraping
+                .insert_web_page_tracker_history_revision_as_delta(revision)
+                .await?;
+        }
+
+        // Full snapshot is stored periodically, and intermediate revisions are stored as deltas.
+        let get_deltas = || async {
+            anyhow::Ok(
+                sqlx::query_scalar::<_, bool>(
+                    "SELECT delta FROM user_data_web_scraping_trackers_history ORDER BY created_at",
+                )
+                .fetch_all(&pool)
+                .await?,
+            )
+        };
+        assert_eq!(
+            get_deltas().await?,
+            [vec![false], vec![true; 9], vec![false, true]].concat()
+        );
+
+        // Full content is reconstructed transparently.
+        assert_eq!(
+            web_scraping
+                .get_web_page_tracker_history::<WebPageContentTrackerTag>(tracker.id)
+                .await?,
+            revisions
+        );
+        for page_size in [1, 5] {
+            let history = web_scraping
+                .get_web_page_tracker_history_stream::<WebPageContentTrackerTag>(
+                    tracker.id, page_size,
+                )
+                .collect::<Vec<_>>()
+                .await
+                .into_iter()
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            assert_eq!(history, revisions);
+        }
+        for revision in revisions.iter() {
+            assert_eq!(
+                web_scraping
+                    .get_web_page_tracker_history_revision::<WebPageContentTrackerTag>(
+                        tracker.id,
+                        revision.id
+                    )
+                    .await?
+                    .as_ref(),
+                Some(revision)
+            );
+        }
+
+        // Hash chain is calculated for the full content.
+        let chain = web_scraping
+            .get_web_page_tracker_history_chain(tracker.id)
+            .await?;
+        assert_eq!(chain.len(), revisions.len());
+        assert!(chain.iter().all(|link| link.is_valid()));
+
+        // Removing revisions that other revisions depend on doesn't break reconstruction.
+        web_scraping
+            .remove_web_page_tracker_history_revision(tracker.id, revisions[0].id)
+            .await?;
+        web_scraping
+            .remove_web_page_tracker_history_revision(tracker.id, revisions[4].id)
+            .await?;
+        db.web_scraping_system()
+            .remove_web_page_tracker_history_before(tracker.id, revisions[3].created_at)
+            .await?;
+        assert_eq!(
+            get_deltas().await?,
+            [vec![false, false], vec![true; 4], vec![false, true]].concat()
+        );
+
+        let remaining_revisions = revisions
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, revision)| (index >= 3 && index != 4).then_some(revision))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            web_scraping
+                .get_web_page_tracker_history::<WebPageContentTrackerTag>(tracker.id)
+                .await?,
+            remaining_revisions
+        );
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn can_remove_history_revisions(pool: PgPool) -> anyhow::Result<()> {
         let user = mock_user()?;
         let db = Database::create(pool).await?;
         db.insert_user(&user).await?;
 
-        let trackers = vec![
+        let trackers = [
             MockWebPageTrackerBuilder::<WebPageResourcesTrackerTag>::create(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 "some-name",
@@ -1152,7 +2618,7 @@ mod tests {
             web_scraping.insert_web_page_tracker(tracker).await?;
         }
 
-        let revisions = vec![
+        let revisions = [
             create_resources_revision(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 trackers[0].id,
@@ -1226,7 +2692,7 @@ mod tests {
         let db = Database::create(pool).await?;
         db.insert_user(&user).await?;
 
-        let trackers = vec![
+        let trackers = [
             MockWebPageTrackerBuilder::<WebPageResourcesTrackerTag>::create(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 "some-name",
@@ -1248,7 +2714,7 @@ mod tests {
             web_scraping.insert_web_page_tracker(tracker).await?;
         }
 
-        let revisions = vec![
+        let revisions = [
             create_resources_revision(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 trackers[0].id,
@@ -1786,6 +3252,7 @@ mod tests {
                             retry: Some(SchedulerJobRetryState {
                                 attempts: 1,
                                 next_at: OffsetDateTime::now_utc().add(Duration::from_secs(3600)),
+                                started_at: OffsetDateTime::now_utc(),
                             }),
                         }
                     } else {
@@ -1826,6 +3293,14 @@ mod tests {
                 .await?;
         }
 
+        // Jobs that are waiting for the next retry attempt aren't overdue.
+        assert_eq!(
+            db.web_scraping_system()
+                .get_overdue_web_page_tracker_jobs()
+                .await?,
+            vec![uuid!("67e55044-10b1-426f-9247-bb680e5fe0c0")]
+        );
+
         let mut pending_trackers = db
             .web_scraping_system()
             .get_pending_web_page_trackers::<WebPageContentTrackerTag>(10)
@@ -1849,6 +3324,7 @@ mod tests {
                 retry: Some(SchedulerJobRetryState {
                     attempts: 1,
                     next_at: OffsetDateTime::now_utc().sub(Duration::from_secs(3600)),
+                    started_at: OffsetDateTime::now_utc().sub(Duration::from_secs(7200)),
                 }),
             },
         )
@@ -0,0 +1,149 @@
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+use similar::{capture_diff_slices, Algorithm, DiffTag};
+
+/// Represents raw data of the web page tracker revision stored as a delta against the raw data of
+/// the previous revision. Data is compared line by line, and lines that haven't changed are
+/// referenced instead of being stored again.
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub(super) struct RawWebPageDataDelta(Vec<RawWebPageDataDeltaOp>);
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+enum RawWebPageDataDeltaOp {
+    /// Copies the specified number of lines of the previous revision starting from the line with
+    /// the specified index.
+    Copy { start: usize, len: usize },
+    /// Inserts data that the previous revision doesn't have.
+    Insert(Vec<u8>),
+}
+
+impl RawWebPageDataDelta {
+    /// Computes delta that transforms `previous` data into `data`.
+    pub fn compute(previous: &[u8], data: &[u8]) -> Self {
+        let previous_lines = split_lines(previous);
+        let lines = split_lines(data);
+
+        let mut ops = vec![];
+        for op in capture_diff_slices(Algorithm::Myers, &previous_lines, &lines) {
+            let (tag, previous_range, range) = op.as_tag_tuple();
+            match tag {
+                DiffTag::Equal => ops.push(RawWebPageDataDeltaOp::Copy {
+                    start: previous_range.start,
+                    len: previous_range.len(),
+                }),
+                DiffTag::Insert | DiffTag::Replace => {
+                    ops.push(RawWebPageDataDeltaOp::Insert(lines[range].concat()))
+                }
+                DiffTag::Delete => {}
+            }
+        }
+
+        Self(ops)
+    }
+
+    /// Reconstructs the data by applying delta to the `previous` data.
+    pub fn apply(&self, previous: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let previous_lines = split_lines(previous);
+
+        let mut data = vec![];
+        for op in &self.0 {
+            match op {
+                RawWebPageDataDeltaOp::Copy { start, len } => {
+                    let Some(lines) = previous_lines.get(*start..start + len) else {
+                        bail!(
+                            "Delta references lines {start}..{} that are out of bounds of the previous data ({} lines).",
+                            start + len,
+                            previous_lines.len()
+                        );
+                    };
+                    for line in lines {
+                        data.extend_from_slice(line);
+                    }
+                }
+                RawWebPageDataDeltaOp::Insert(insert) => data.extend_from_slice(insert),
+            }
+        }
+
+        Ok(data)
+    }
+
+    /// Deserializes delta from the raw bytes.
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        Ok(postcard::from_bytes(bytes)?)
+    }
+
+    /// Serializes delta to the raw bytes.
+    pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(postcard::to_stdvec(self)?)
+    }
+}
+
+/// Splits data into lines, every line keeps its line break, if any.
+fn split_lines(data: &[u8]) -> Vec<&[u8]> {
+    data.split_inclusive(|byte| *byte == b'\n').collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RawWebPageDataDelta, RawWebPageDataDeltaOp};
+
+    #[test]
+    fn can_compute_delta() -> anyhow::Result<()> {
+        let delta = RawWebPageDataDelta::compute(
+            b"one\ntwo\nthree\nfour\nfive",
+            b"one\ntwo\n3\nfour\nfive\nsix",
+        );
+        assert_eq!(
+            delta,
+            RawWebPageDataDelta(vec![
+                RawWebPageDataDeltaOp::Copy { start: 0, len: 2 },
+                RawWebPageDataDeltaOp::Insert(b"3\n".to_vec()),
+                RawWebPageDataDeltaOp::Copy { start: 3, len: 1 },
+                RawWebPageDataDeltaOp::Insert(b"five\nsix".to_vec()),
+            ])
+        );
+
+        assert_eq!(
+            RawWebPageDataDelta::compute(b"", b"one\ntwo"),
+            RawWebPageDataDelta(vec![RawWebPageDataDeltaOp::Insert(b"one\ntwo".to_vec())])
+        );
+        assert_eq!(
+            RawWebPageDataDelta::compute(b"one\ntwo", b""),
+            RawWebPageDataDelta(vec![])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_reconstruct_data() -> anyhow::Result<()> {
+        let revisions = [
+            b"".to_vec(),
+            b"one\ntwo\nthree".to_vec(),
+            b"one\ntwo\nthree\n".to_vec(),
+            b"zero\none\nthree\nfour\n".to_vec(),
+            b"zero\none\r\nthree\nfour\nfive".to_vec(),
+            b"five".to_vec(),
+            b"\n\n\n".to_vec(),
+        ];
+        for (previous, data) in revisions.iter().zip(revisions.iter().skip(1)) {
+            let delta = RawWebPageDataDelta::from_bytes(
+                &RawWebPageDataDelta::compute(previous, data).to_bytes()?,
+            )?;
+            assert_eq!(&delta.apply(previous)?, data);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_apply_delta_to_wrong_data() -> anyhow::Result<()> {
+        let delta = RawWebPageDataDelta::compute(b"one\ntwo\nthree", b"one\ntwo\nfour");
+        assert_eq!(
+            delta.apply(b"one").unwrap_err().to_string(),
+            "Delta references lines 0..2 that are out of bounds of the previous data (1 lines)."
+        );
+
+        Ok(())
+    }
+}
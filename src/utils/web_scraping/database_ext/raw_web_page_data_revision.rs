@@ -1,4 +1,8 @@
-use crate::utils::web_scraping::{WebPageDataRevision, WebPageTrackerTag};
+use crate::utils::web_scraping::{
+    database_ext::raw_web_page_data_delta::RawWebPageDataDelta, WebPageDataRevision,
+    WebPageDataRevisionAcknowledgment, WebPageTrackerTag,
+};
+use anyhow::bail;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
@@ -7,18 +11,63 @@ pub(super) struct RawWebPageDataRevision {
     pub id: Uuid,
     pub tracker_id: Uuid,
     pub data: Vec<u8>,
+    pub delta: bool,
     pub created_at: OffsetDateTime,
+    pub note: Option<String>,
+    pub acknowledged_by: Option<Uuid>,
+    pub acknowledged_at: Option<OffsetDateTime>,
+    pub assertions_passed: Option<bool>,
+}
+
+impl RawWebPageDataRevision {
+    /// Reconstructs the full data of the revision stored as a delta against the full data of the
+    /// previous revision. Revisions that store the full data are returned as is.
+    pub fn reconstruct(self, previous_data: Option<&[u8]>) -> anyhow::Result<Self> {
+        if !self.delta {
+            return Ok(self);
+        }
+
+        let Some(previous_data) = previous_data else {
+            bail!(
+                "Web page tracker revision ('{}') is stored as a delta, but the previous revision is missing.",
+                self.id
+            );
+        };
+
+        Ok(Self {
+            data: RawWebPageDataDelta::from_bytes(&self.data)?.apply(previous_data)?,
+            delta: false,
+            ..self
+        })
+    }
 }
 
 impl<Tag: WebPageTrackerTag> TryFrom<RawWebPageDataRevision> for WebPageDataRevision<Tag> {
     type Error = anyhow::Error;
 
     fn try_from(raw: RawWebPageDataRevision) -> Result<Self, Self::Error> {
+        if raw.delta {
+            bail!(
+                "Web page tracker revision ('{}') is stored as a delta and should be reconstructed first.",
+                raw.id
+            );
+        }
+
         Ok(Self {
             id: raw.id,
             tracker_id: raw.tracker_id,
             data: postcard::from_bytes::<Tag::TrackerData>(&raw.data)?,
             created_at: raw.created_at,
+            note: raw.note,
+            acknowledgment: match (raw.acknowledged_by, raw.acknowledged_at) {
+                (Some(user_id), Some(acknowledged_at)) => Some(WebPageDataRevisionAcknowledgment {
+                    user_id: user_id.into(),
+                    acknowledged_at,
+                }),
+                _ => None,
+            },
+            diff_stats: None,
+            assertions_passed: raw.assertions_passed,
         })
     }
 }
@@ -31,7 +80,16 @@ impl<Tag: WebPageTrackerTag> TryFrom<&WebPageDataRevision<Tag>> for RawWebPageDa
             id: item.id,
             tracker_id: item.tracker_id,
             data: postcard::to_stdvec(&item.data)?,
+            delta: false,
             created_at: item.created_at,
+            note: item.note.clone(),
+            acknowledged_by: item
+                .acknowledgment
+                .map(|acknowledgment| *acknowledgment.user_id),
+            acknowledged_at: item
+                .acknowledgment
+                .map(|acknowledgment| acknowledgment.acknowledged_at),
+            assertions_passed: item.assertions_passed,
         })
     }
 }
@@ -40,8 +98,10 @@ impl<Tag: WebPageTrackerTag> TryFrom<&WebPageDataRevision<Tag>> for RawWebPageDa
 mod tests {
     use super::RawWebPageDataRevision;
     use crate::utils::web_scraping::{
-        WebPageDataRevision, WebPageResource, WebPageResourceContent, WebPageResourceContentData,
-        WebPageResourcesData, WebPageResourcesTrackerTag,
+        database_ext::raw_web_page_data_delta::RawWebPageDataDelta, WebPageContentTrackerTag,
+        WebPageDataRevision, WebPageDataRevisionAcknowledgment, WebPageResource,
+        WebPageResourceContent, WebPageResourceContentData, WebPageResourcesData,
+        WebPageResourcesTrackerTag,
     };
     use time::OffsetDateTime;
     use uuid::uuid;
@@ -56,10 +116,16 @@ mod tests {
                     1, 0, 1, 2, 11, 115, 111, 109, 101, 45, 100, 105, 103, 101, 115, 116, 217, 2,
                     2, 0, 0, 1, 30, 104, 116, 116, 112, 115, 58, 47, 47, 115, 101, 99, 117, 116,
                     105, 108, 115, 46, 100, 101, 118, 47, 115, 99, 114, 105, 112, 116, 46, 106,
-                    115, 1, 1, 11, 115, 111, 109, 101, 45, 100, 105, 103, 101, 115, 116, 123
+                    115, 1, 1, 11, 115, 111, 109, 101, 45, 100, 105, 103, 101, 115, 116, 123, 0
                 ],
+                delta: false,
                 // January 1, 2000 10:00:00
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                note: None,
+                acknowledged_by: Some(uuid!("00000000-0000-0000-0000-000000000003")),
+                // January 1, 2000 11:00:00
+                acknowledged_at: Some(OffsetDateTime::from_unix_timestamp(946724400)?),
+                assertions_passed: None,
             })?,
             WebPageDataRevision {
                 id: uuid!("00000000-0000-0000-0000-000000000001"),
@@ -70,6 +136,7 @@ mod tests {
                             url: None,
                             content: None,
                             diff_status: None,
+                            category: None,
                         },
                         WebPageResource {
                             url: Some("https://secutils.dev/script.js".parse()?),
@@ -78,6 +145,7 @@ mod tests {
                                 size: 123,
                             }),
                             diff_status: None,
+                            category: None,
                         }
                     ],
                     scripts: vec![WebPageResource {
@@ -87,9 +155,20 @@ mod tests {
                             size: 345,
                         }),
                         diff_status: None,
+                        category: None,
                     }],
+                    categories: None,
+                    sizes: None,
+                    truncated: false,
                 },
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                note: None,
+                acknowledgment: Some(WebPageDataRevisionAcknowledgment {
+                    user_id: uuid!("00000000-0000-0000-0000-000000000003").into(),
+                    acknowledged_at: OffsetDateTime::from_unix_timestamp(946724400)?,
+                }),
+                diff_stats: None,
+                assertions_passed: None,
             }
         );
 
@@ -108,6 +187,7 @@ mod tests {
                             url: None,
                             content: None,
                             diff_status: None,
+                            category: None,
                         },
                         WebPageResource {
                             url: Some("https://secutils.dev/script.js".parse()?),
@@ -116,6 +196,7 @@ mod tests {
                                 size: 123,
                             }),
                             diff_status: None,
+                            category: None,
                         }
                     ],
                     scripts: vec![WebPageResource {
@@ -125,9 +206,17 @@ mod tests {
                             size: 345,
                         }),
                         diff_status: None,
+                        category: None,
                     }],
+                    categories: None,
+                    sizes: None,
+                    truncated: false,
                 },
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
             })?,
             RawWebPageDataRevision {
                 id: uuid!("00000000-0000-0000-0000-000000000001"),
@@ -136,13 +225,58 @@ mod tests {
                     1, 1, 2, 11, 115, 111, 109, 101, 45, 100, 105, 103, 101, 115, 116, 217, 2, 2,
                     1, 30, 104, 116, 116, 112, 115, 58, 47, 47, 115, 101, 99, 117, 116, 105, 108,
                     115, 46, 100, 101, 118, 47, 115, 99, 114, 105, 112, 116, 46, 106, 115, 1, 1,
-                    11, 115, 111, 109, 101, 45, 100, 105, 103, 101, 115, 116, 123
+                    11, 115, 111, 109, 101, 45, 100, 105, 103, 101, 115, 116, 123, 0
                 ],
+                delta: false,
                 // January 1, 2000 10:00:00
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                note: None,
+                acknowledged_by: None,
+                acknowledged_at: None,
+                assertions_passed: None,
             }
         );
 
         Ok(())
     }
+
+    #[test]
+    fn can_reconstruct_revision_stored_as_delta() -> anyhow::Result<()> {
+        let previous_data = postcard::to_stdvec("one\ntwo\nthree")?;
+        let data = postcard::to_stdvec("one\ntwo\nthree\nfour")?;
+        let raw_revision = RawWebPageDataRevision {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+            data: RawWebPageDataDelta::compute(&previous_data, &data).to_bytes()?,
+            delta: true,
+            // January 1, 2000 10:00:00
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            note: None,
+            acknowledged_by: None,
+            acknowledged_at: None,
+            assertions_passed: None,
+        };
+
+        // Revision stored as a delta cannot be converted without reconstruction.
+        assert_eq!(
+            WebPageDataRevision::<WebPageContentTrackerTag>::try_from(raw_revision.clone())
+                .unwrap_err()
+                .to_string(),
+            "Web page tracker revision ('00000000-0000-0000-0000-000000000001') is stored as a delta and should be reconstructed first."
+        );
+        assert_eq!(
+            raw_revision.clone().reconstruct(None).unwrap_err().to_string(),
+            "Web page tracker revision ('00000000-0000-0000-0000-000000000001') is stored as a delta, but the previous revision is missing."
+        );
+
+        let reconstructed_revision = raw_revision.reconstruct(Some(&previous_data))?;
+        assert!(!reconstructed_revision.delta);
+        assert_eq!(reconstructed_revision.data, data);
+        assert_eq!(
+            WebPageDataRevision::<WebPageContentTrackerTag>::try_from(reconstructed_revision)?.data,
+            "one\ntwo\nthree\nfour"
+        );
+
+        Ok(())
+    }
 }
@@ -1,6 +1,12 @@
 use crate::{
-    scheduler::{SchedulerJobConfig, SchedulerJobRetryStrategy},
-    utils::web_scraping::{WebPageTracker, WebPageTrackerSettings, WebPageTrackerTag},
+    scheduler::{SchedulerJobAdaptiveInterval, SchedulerJobConfig, SchedulerJobRetryStrategy},
+    utils::web_scraping::{
+        WebPageContentAssertion, WebPageContentAssertionOperator, WebPageContentCookiesCapture,
+        WebPageContentFormat, WebPageContentResponseCapture, WebPageDnsRecordType,
+        WebPageResourceType, WebPageResourcesSizeBudget, WebPageTracker,
+        WebPageTrackerDataClassification, WebPageTrackerDataPolicy, WebPageTrackerPrecondition,
+        WebPageTrackerPreconditionOperator, WebPageTrackerSettings, WebPageTrackerTag,
+    },
 };
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, time::Duration};
@@ -28,10 +34,110 @@ pub(super) struct RawWebPageTrackerData<Tag: WebPageTrackerTag> {
     pub scripts: Option<HashMap<String, String>>,
     pub headers: Option<HashMap<String, String>>,
     pub meta: Option<Tag::TrackerMeta>,
+    pub ignore_resource_urls: Option<Vec<String>>,
+    pub status_only: Option<bool>,
+    pub insecure_tls: Option<bool>,
+    pub notification_cooldown: Option<u64>,
+    pub urgent_notifications: Option<bool>,
+    pub depends_on: Option<Uuid>,
+    pub byte_range: Option<(u64, u64)>,
+    pub precondition: Option<RawWebPageTrackerPrecondition>,
+    pub classify_resources: Option<bool>,
+    pub capture_cookies: Option<RawWebPageContentCookiesCapture>,
+    pub dedup_window: Option<usize>,
+    pub size_budget: Option<RawWebPageResourcesSizeBudget>,
+    pub disable_js: Option<bool>,
+    pub data_policy: Option<RawWebPageTrackerDataPolicy>,
+    pub capture_response: Option<Vec<String>>,
+    pub resource_types: Option<Vec<RawWebPageResourceType>>,
+    pub record_types: Option<Vec<RawWebPageDnsRecordType>>,
+    pub notify_on_first: Option<bool>,
+    pub capture_fingerprint: Option<bool>,
+    pub additional_urls: Option<Vec<String>>,
+    pub content_format: Option<RawWebPageContentFormat>,
+    pub sub_fetch_concurrency: Option<usize>,
+    pub store_diffs: Option<bool>,
+    pub assertions: Option<Vec<RawWebPageContentAssertion>>,
+    pub method: Option<String>,
+    pub request_body: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub(super) struct RawWebPageTrackerPrecondition(
+    Uuid,
+    Option<String>,
+    RawWebPageTrackerPreconditionOperator,
+    String,
+);
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub(super) enum RawWebPageTrackerPreconditionOperator {
+    Equals,
+    NotEquals,
+    Contains,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub(super) struct RawWebPageContentAssertion(RawWebPageContentAssertionOperator, String);
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub(super) enum RawWebPageContentAssertionOperator {
+    Contains,
+    NotContains,
+    Matches,
+    NotMatches,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub(super) enum RawWebPageContentCookiesCapture {
+    Full,
+    Redacted,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub(super) struct RawWebPageResourcesSizeBudget(Option<u64>, Option<u32>);
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub(super) struct RawWebPageTrackerDataPolicy(RawWebPageTrackerDataClassification, Option<u64>);
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub(super) enum RawWebPageTrackerDataClassification {
+    Public,
+    Internal,
+    Confidential,
+    Personal,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub(super) enum RawWebPageResourceType {
+    Script,
+    Style,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub(super) enum RawWebPageDnsRecordType {
+    A,
+    Aaaa,
+    Cname,
+    Mx,
+    Ns,
+    Txt,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub(super) enum RawWebPageContentFormat {
+    RobotsTxt,
+    Sitemap,
 }
 
 #[derive(Serialize, Deserialize)]
-struct RawSchedulerJobConfig(String, Option<RawSchedulerJobRetryStrategy>, bool);
+struct RawSchedulerJobConfig(
+    String,
+    Option<RawSchedulerJobRetryStrategy>,
+    bool,
+    Option<RawSchedulerJobAdaptiveInterval>,
+    Option<Duration>,
+);
 
 #[derive(Serialize, Deserialize)]
 enum RawSchedulerJobRetryStrategy {
@@ -40,6 +146,9 @@ enum RawSchedulerJobRetryStrategy {
     Linear(Duration, Duration, Duration, u32),
 }
 
+#[derive(Serialize, Deserialize)]
+struct RawSchedulerJobAdaptiveInterval(Duration, Duration);
+
 impl<Tag: WebPageTrackerTag> TryFrom<RawWebPageTracker> for WebPageTracker<Tag> {
     type Error = anyhow::Error;
 
@@ -47,8 +156,13 @@ impl<Tag: WebPageTrackerTag> TryFrom<RawWebPageTracker> for WebPageTracker<Tag>
         let raw_data = postcard::from_bytes::<RawWebPageTrackerData<Tag>>(&raw.data)?;
 
         let job_config = if let Some(job_config) = raw.job_config {
-            let RawSchedulerJobConfig(schedule, retry_strategy, notifications) =
-                postcard::from_bytes(&job_config)?;
+            let RawSchedulerJobConfig(
+                schedule,
+                retry_strategy,
+                notifications,
+                adaptive_interval,
+                max_total_retry_duration,
+            ) = postcard::from_bytes(&job_config)?;
             Some(SchedulerJobConfig {
                 schedule,
                 retry_strategy: retry_strategy.map(|retry_strategy| match retry_strategy {
@@ -56,6 +170,7 @@ impl<Tag: WebPageTrackerTag> TryFrom<RawWebPageTracker> for WebPageTracker<Tag>
                         SchedulerJobRetryStrategy::Constant {
                             interval,
                             max_attempts,
+                            max_total_retry_duration,
                         }
                     }
                     RawSchedulerJobRetryStrategy::Exponential(
@@ -68,6 +183,7 @@ impl<Tag: WebPageTrackerTag> TryFrom<RawWebPageTracker> for WebPageTracker<Tag>
                         multiplier,
                         max_interval,
                         max_attempts,
+                        max_total_retry_duration,
                     },
                     RawSchedulerJobRetryStrategy::Linear(
                         initial_interval,
@@ -79,9 +195,18 @@ impl<Tag: WebPageTrackerTag> TryFrom<RawWebPageTracker> for WebPageTracker<Tag>
                         increment,
                         max_interval,
                         max_attempts,
+                        max_total_retry_duration,
                     },
                 }),
                 notifications,
+                adaptive_interval: adaptive_interval.map(
+                    |RawSchedulerJobAdaptiveInterval(min_interval, max_interval)| {
+                        SchedulerJobAdaptiveInterval {
+                            min_interval,
+                            max_interval,
+                        }
+                    },
+                ),
             })
         } else {
             None
@@ -99,6 +224,138 @@ impl<Tag: WebPageTrackerTag> TryFrom<RawWebPageTracker> for WebPageTracker<Tag>
                 delay: Duration::from_millis(raw_data.delay),
                 scripts: raw_data.scripts,
                 headers: raw_data.headers,
+                ignore_resource_urls: raw_data.ignore_resource_urls,
+                status_only: raw_data.status_only.unwrap_or_default(),
+                insecure_tls: raw_data.insecure_tls.unwrap_or_default(),
+                notification_cooldown: raw_data.notification_cooldown.map(Duration::from_millis),
+                urgent_notifications: raw_data.urgent_notifications.unwrap_or_default(),
+                depends_on: raw_data.depends_on,
+                byte_range: raw_data.byte_range,
+                precondition: raw_data.precondition.map(
+                    |RawWebPageTrackerPrecondition(tracker_id, pointer, operator, value)| {
+                        WebPageTrackerPrecondition {
+                            tracker_id,
+                            pointer,
+                            operator: match operator {
+                                RawWebPageTrackerPreconditionOperator::Equals => {
+                                    WebPageTrackerPreconditionOperator::Equals
+                                }
+                                RawWebPageTrackerPreconditionOperator::NotEquals => {
+                                    WebPageTrackerPreconditionOperator::NotEquals
+                                }
+                                RawWebPageTrackerPreconditionOperator::Contains => {
+                                    WebPageTrackerPreconditionOperator::Contains
+                                }
+                            },
+                            value,
+                        }
+                    },
+                ),
+                classify_resources: raw_data.classify_resources.unwrap_or_default(),
+                capture_cookies: raw_data.capture_cookies.map(|capture| match capture {
+                    RawWebPageContentCookiesCapture::Full => WebPageContentCookiesCapture::Full,
+                    RawWebPageContentCookiesCapture::Redacted => {
+                        WebPageContentCookiesCapture::Redacted
+                    }
+                }),
+                dedup_window: raw_data.dedup_window,
+                size_budget: raw_data.size_budget.map(
+                    |RawWebPageResourcesSizeBudget(max_total_size, max_growth)| {
+                        WebPageResourcesSizeBudget {
+                            max_total_size: max_total_size.map(|size| size as usize),
+                            max_growth,
+                        }
+                    },
+                ),
+                render_js: !raw_data.disable_js.unwrap_or_default(),
+                data_policy: raw_data.data_policy.map(
+                    |RawWebPageTrackerDataPolicy(classification, retention)| {
+                        WebPageTrackerDataPolicy {
+                            classification: match classification {
+                                RawWebPageTrackerDataClassification::Public => {
+                                    WebPageTrackerDataClassification::Public
+                                }
+                                RawWebPageTrackerDataClassification::Internal => {
+                                    WebPageTrackerDataClassification::Internal
+                                }
+                                RawWebPageTrackerDataClassification::Confidential => {
+                                    WebPageTrackerDataClassification::Confidential
+                                }
+                                RawWebPageTrackerDataClassification::Personal => {
+                                    WebPageTrackerDataClassification::Personal
+                                }
+                            },
+                            retention: retention.map(Duration::from_millis),
+                        }
+                    },
+                ),
+                capture_response: raw_data
+                    .capture_response
+                    .map(|headers| WebPageContentResponseCapture { headers }),
+                resource_types: raw_data.resource_types.map(|resource_types| {
+                    resource_types
+                        .into_iter()
+                        .map(|resource_type| match resource_type {
+                            RawWebPageResourceType::Script => WebPageResourceType::Script,
+                            RawWebPageResourceType::Style => WebPageResourceType::Style,
+                        })
+                        .collect()
+                }),
+                record_types: raw_data.record_types.map(|record_types| {
+                    record_types
+                        .into_iter()
+                        .map(|record_type| match record_type {
+                            RawWebPageDnsRecordType::A => WebPageDnsRecordType::A,
+                            RawWebPageDnsRecordType::Aaaa => WebPageDnsRecordType::Aaaa,
+                            RawWebPageDnsRecordType::Cname => WebPageDnsRecordType::Cname,
+                            RawWebPageDnsRecordType::Mx => WebPageDnsRecordType::Mx,
+                            RawWebPageDnsRecordType::Ns => WebPageDnsRecordType::Ns,
+                            RawWebPageDnsRecordType::Txt => WebPageDnsRecordType::Txt,
+                        })
+                        .collect()
+                }),
+                notify_on_first: raw_data.notify_on_first.unwrap_or_default(),
+                capture_fingerprint: raw_data.capture_fingerprint.unwrap_or_default(),
+                additional_urls: raw_data
+                    .additional_urls
+                    .map(|urls| {
+                        urls.iter()
+                            .map(|url| url.parse())
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .transpose()?,
+                content_format: raw_data.content_format.map(|format| match format {
+                    RawWebPageContentFormat::RobotsTxt => WebPageContentFormat::RobotsTxt,
+                    RawWebPageContentFormat::Sitemap => WebPageContentFormat::Sitemap,
+                }),
+                sub_fetch_concurrency: raw_data.sub_fetch_concurrency,
+                store_diffs: raw_data.store_diffs.unwrap_or_default(),
+                assertions: raw_data.assertions.map(|assertions| {
+                    assertions
+                        .into_iter()
+                        .map(|RawWebPageContentAssertion(operator, value)| {
+                            WebPageContentAssertion {
+                                operator: match operator {
+                                    RawWebPageContentAssertionOperator::Contains => {
+                                        WebPageContentAssertionOperator::Contains
+                                    }
+                                    RawWebPageContentAssertionOperator::NotContains => {
+                                        WebPageContentAssertionOperator::NotContains
+                                    }
+                                    RawWebPageContentAssertionOperator::Matches => {
+                                        WebPageContentAssertionOperator::Matches
+                                    }
+                                    RawWebPageContentAssertionOperator::NotMatches => {
+                                        WebPageContentAssertionOperator::NotMatches
+                                    }
+                                },
+                                value,
+                            }
+                        })
+                        .collect()
+                }),
+                method: raw_data.method,
+                request_body: raw_data.request_body,
             },
             created_at: raw.created_at,
             updated_at: raw.updated_at,
@@ -117,12 +374,141 @@ impl<Tag: WebPageTrackerTag> TryFrom<&WebPageTracker<Tag>> for RawWebPageTracker
             scripts: item.settings.scripts.clone(),
             headers: item.settings.headers.clone(),
             meta: item.meta.clone(),
+            ignore_resource_urls: item.settings.ignore_resource_urls.clone(),
+            status_only: item.settings.status_only.then_some(true),
+            insecure_tls: item.settings.insecure_tls.then_some(true),
+            notification_cooldown: item
+                .settings
+                .notification_cooldown
+                .map(|cooldown| cooldown.as_millis() as u64),
+            urgent_notifications: item.settings.urgent_notifications.then_some(true),
+            depends_on: item.settings.depends_on,
+            byte_range: item.settings.byte_range,
+            precondition: item.settings.precondition.as_ref().map(|precondition| {
+                RawWebPageTrackerPrecondition(
+                    precondition.tracker_id,
+                    precondition.pointer.clone(),
+                    match precondition.operator {
+                        WebPageTrackerPreconditionOperator::Equals => {
+                            RawWebPageTrackerPreconditionOperator::Equals
+                        }
+                        WebPageTrackerPreconditionOperator::NotEquals => {
+                            RawWebPageTrackerPreconditionOperator::NotEquals
+                        }
+                        WebPageTrackerPreconditionOperator::Contains => {
+                            RawWebPageTrackerPreconditionOperator::Contains
+                        }
+                    },
+                    precondition.value.clone(),
+                )
+            }),
+            classify_resources: item.settings.classify_resources.then_some(true),
+            capture_cookies: item.settings.capture_cookies.map(|capture| match capture {
+                WebPageContentCookiesCapture::Full => RawWebPageContentCookiesCapture::Full,
+                WebPageContentCookiesCapture::Redacted => RawWebPageContentCookiesCapture::Redacted,
+            }),
+            dedup_window: item.settings.dedup_window,
+            size_budget: item.settings.size_budget.map(|size_budget| {
+                RawWebPageResourcesSizeBudget(
+                    size_budget.max_total_size.map(|size| size as u64),
+                    size_budget.max_growth,
+                )
+            }),
+            disable_js: (!item.settings.render_js).then_some(true),
+            data_policy: item.settings.data_policy.map(|data_policy| {
+                RawWebPageTrackerDataPolicy(
+                    match data_policy.classification {
+                        WebPageTrackerDataClassification::Public => {
+                            RawWebPageTrackerDataClassification::Public
+                        }
+                        WebPageTrackerDataClassification::Internal => {
+                            RawWebPageTrackerDataClassification::Internal
+                        }
+                        WebPageTrackerDataClassification::Confidential => {
+                            RawWebPageTrackerDataClassification::Confidential
+                        }
+                        WebPageTrackerDataClassification::Personal => {
+                            RawWebPageTrackerDataClassification::Personal
+                        }
+                    },
+                    data_policy
+                        .retention
+                        .map(|retention| retention.as_millis() as u64),
+                )
+            }),
+            capture_response: item
+                .settings
+                .capture_response
+                .as_ref()
+                .map(|capture| capture.headers.clone()),
+            resource_types: item.settings.resource_types.as_ref().map(|resource_types| {
+                resource_types
+                    .iter()
+                    .map(|resource_type| match resource_type {
+                        WebPageResourceType::Script => RawWebPageResourceType::Script,
+                        WebPageResourceType::Style => RawWebPageResourceType::Style,
+                    })
+                    .collect()
+            }),
+            record_types: item.settings.record_types.as_ref().map(|record_types| {
+                record_types
+                    .iter()
+                    .map(|record_type| match record_type {
+                        WebPageDnsRecordType::A => RawWebPageDnsRecordType::A,
+                        WebPageDnsRecordType::Aaaa => RawWebPageDnsRecordType::Aaaa,
+                        WebPageDnsRecordType::Cname => RawWebPageDnsRecordType::Cname,
+                        WebPageDnsRecordType::Mx => RawWebPageDnsRecordType::Mx,
+                        WebPageDnsRecordType::Ns => RawWebPageDnsRecordType::Ns,
+                        WebPageDnsRecordType::Txt => RawWebPageDnsRecordType::Txt,
+                    })
+                    .collect()
+            }),
+            notify_on_first: item.settings.notify_on_first.then_some(true),
+            capture_fingerprint: item.settings.capture_fingerprint.then_some(true),
+            additional_urls: item
+                .settings
+                .additional_urls
+                .as_ref()
+                .map(|urls| urls.iter().map(|url| url.to_string()).collect()),
+            content_format: item.settings.content_format.map(|format| match format {
+                WebPageContentFormat::RobotsTxt => RawWebPageContentFormat::RobotsTxt,
+                WebPageContentFormat::Sitemap => RawWebPageContentFormat::Sitemap,
+            }),
+            sub_fetch_concurrency: item.settings.sub_fetch_concurrency,
+            store_diffs: item.settings.store_diffs.then_some(true),
+            assertions: item.settings.assertions.as_ref().map(|assertions| {
+                assertions
+                    .iter()
+                    .map(|assertion| {
+                        RawWebPageContentAssertion(
+                            match assertion.operator {
+                                WebPageContentAssertionOperator::Contains => {
+                                    RawWebPageContentAssertionOperator::Contains
+                                }
+                                WebPageContentAssertionOperator::NotContains => {
+                                    RawWebPageContentAssertionOperator::NotContains
+                                }
+                                WebPageContentAssertionOperator::Matches => {
+                                    RawWebPageContentAssertionOperator::Matches
+                                }
+                                WebPageContentAssertionOperator::NotMatches => {
+                                    RawWebPageContentAssertionOperator::NotMatches
+                                }
+                            },
+                            assertion.value.clone(),
+                        )
+                    })
+                    .collect()
+            }),
+            method: item.settings.method.clone(),
+            request_body: item.settings.request_body.clone(),
         };
 
         let job_config = if let Some(SchedulerJobConfig {
             schedule,
             retry_strategy,
             notifications,
+            adaptive_interval,
         }) = &item.job_config
         {
             Some(postcard::to_stdvec(&RawSchedulerJobConfig(
@@ -131,12 +517,14 @@ impl<Tag: WebPageTrackerTag> TryFrom<&WebPageTracker<Tag>> for RawWebPageTracker
                     SchedulerJobRetryStrategy::Constant {
                         interval,
                         max_attempts,
+                        ..
                     } => RawSchedulerJobRetryStrategy::Constant(interval, max_attempts),
                     SchedulerJobRetryStrategy::Exponential {
                         initial_interval,
                         multiplier,
                         max_interval,
                         max_attempts,
+                        ..
                     } => RawSchedulerJobRetryStrategy::Exponential(
                         initial_interval,
                         multiplier,
@@ -148,6 +536,7 @@ impl<Tag: WebPageTrackerTag> TryFrom<&WebPageTracker<Tag>> for RawWebPageTracker
                         increment,
                         max_interval,
                         max_attempts,
+                        ..
                     } => RawSchedulerJobRetryStrategy::Linear(
                         initial_interval,
                         increment,
@@ -156,6 +545,13 @@ impl<Tag: WebPageTrackerTag> TryFrom<&WebPageTracker<Tag>> for RawWebPageTracker
                     ),
                 }),
                 *notifications,
+                adaptive_interval.map(|adaptive_interval| {
+                    RawSchedulerJobAdaptiveInterval(
+                        adaptive_interval.min_interval,
+                        adaptive_interval.max_interval,
+                    )
+                }),
+                retry_strategy.and_then(|retry_strategy| retry_strategy.max_total_retry_duration()),
             ))?)
         } else {
             None
@@ -203,7 +599,10 @@ mod tests {
                 user_id: *mock_user()?.id,
                 job_id: None,
                 job_config: None,
-                data: vec![1, 0, 0, 0, 0],
+                data: vec![
+                    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                    0, 0, 0, 0, 0
+                ],
                 // January 1, 2000 10:00:00
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                 // January 1, 2000 10:00:10
@@ -219,8 +618,7 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 1,
                     delay: Default::default(),
-                    scripts: Default::default(),
-                    headers: Default::default()
+                    ..Default::default()
                 },
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                 updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
@@ -238,13 +636,14 @@ mod tests {
                 job_id: Some(uuid!("00000000-0000-0000-0000-000000000002")),
                 job_config: Some(vec![
                     9, 48, 32, 48, 32, 42, 32, 42, 32, 42, 1, 1, 1, 128, 157, 202, 111, 2, 120, 0,
-                    5, 1
+                    5, 1, 0, 1, 216, 4, 0
                 ]),
                 data: vec![
                     1, 208, 15, 1, 1, 17, 114, 101, 115, 111, 117, 114, 99, 101, 70, 105, 108, 116,
                     101, 114, 77, 97, 112, 16, 114, 101, 116, 117, 114, 110, 32, 114, 101, 115,
                     111, 117, 114, 99, 101, 59, 1, 1, 6, 99, 111, 111, 107, 105, 101, 9, 109, 121,
-                    45, 99, 111, 111, 107, 105, 101, 0
+                    45, 99, 111, 111, 107, 105, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
                 ],
                 // January 1, 2000 10:00:00
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
@@ -264,8 +663,10 @@ mod tests {
                         multiplier: 2,
                         max_interval: Duration::from_secs(120),
                         max_attempts: 5,
+                        max_total_retry_duration: Some(Duration::from_secs(600)),
                     }),
-                    notifications: true
+                    notifications: true,
+                    adaptive_interval: None,
                 }),
                 settings: WebPageTrackerSettings {
                     revisions: 1,
@@ -282,7 +683,8 @@ mod tests {
                         [("cookie".to_string(), "my-cookie".to_string())]
                             .into_iter()
                             .collect()
-                    )
+                    ),
+                    ..Default::default()
                 },
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                 updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
@@ -306,8 +708,7 @@ mod tests {
                 settings: WebPageTrackerSettings {
                     revisions: 1,
                     delay: Default::default(),
-                    scripts: Default::default(),
-                    headers: Default::default(),
+                    ..Default::default()
                 },
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                 updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
@@ -321,7 +722,10 @@ mod tests {
                 user_id: *mock_user()?.id,
                 job_id: None,
                 job_config: None,
-                data: vec![1, 0, 0, 0, 0],
+                data: vec![
+                    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                    0, 0, 0, 0, 0
+                ],
                 // January 1, 2000 10:00:00
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                 // January 1, 2000 10:00:10
@@ -343,8 +747,10 @@ mod tests {
                         multiplier: 2,
                         max_interval: Duration::from_secs(120),
                         max_attempts: 5,
+                        max_total_retry_duration: None,
                     }),
-                    notifications: true
+                    notifications: true,
+                    adaptive_interval: None,
                 }),
                 settings: WebPageTrackerSettings {
                     revisions: 1,
@@ -362,6 +768,7 @@ mod tests {
                             .into_iter()
                             .collect()
                     ),
+                    ..Default::default()
                 },
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                 updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
@@ -376,13 +783,14 @@ mod tests {
                 job_id: Some(uuid!("00000000-0000-0000-0000-000000000002")),
                 job_config: Some(vec![
                     9, 48, 32, 48, 32, 42, 32, 42, 32, 42, 1, 1, 1, 128, 157, 202, 111, 2, 120, 0,
-                    5, 1
+                    5, 1, 0, 0
                 ]),
                 data: vec![
                     1, 208, 15, 1, 1, 17, 114, 101, 115, 111, 117, 114, 99, 101, 70, 105, 108, 116,
                     101, 114, 77, 97, 112, 16, 114, 101, 116, 117, 114, 110, 32, 114, 101, 115,
                     111, 117, 114, 99, 101, 59, 1, 1, 6, 99, 111, 111, 107, 105, 101, 9, 109, 121,
-                    45, 99, 111, 111, 107, 105, 101, 0
+                    45, 99, 111, 111, 107, 105, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
                 ],
                 // January 1, 2000 10:00:00
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
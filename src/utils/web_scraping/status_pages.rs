@@ -0,0 +1,3 @@
+mod web_page_status_page;
+
+pub use self::web_page_status_page::WebPageStatusPage;
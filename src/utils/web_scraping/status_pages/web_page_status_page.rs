@@ -0,0 +1,61 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Represents a public status page that aggregates the latest status and the recent status
+/// history of the selected web page status trackers (content trackers in status-only mode).
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageStatusPage {
+    /// Unique status page ID (UUIDv7).
+    pub id: Uuid,
+    /// Arbitrary name of the status page, displayed as the page title.
+    pub name: String,
+    /// Random token that is used to access the status page publicly.
+    pub token: String,
+    /// IDs of the status trackers displayed on the page, in the display order.
+    pub tracker_ids: Vec<Uuid>,
+    /// Date and time when the status page was created.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+    /// Date and time when the status page was last updated.
+    #[serde(with = "time::serde::timestamp")]
+    pub updated_at: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageStatusPage;
+    use insta::assert_json_snapshot;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageStatusPage {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "Status".to_string(),
+            token: "abcdefghijklmnopqrstuvwxyz012345".to_string(),
+            tracker_ids: vec![
+                uuid!("00000000-0000-0000-0000-000000000002"),
+                uuid!("00000000-0000-0000-0000-000000000003")
+            ],
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+        }, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "name": "Status",
+          "token": "abcdefghijklmnopqrstuvwxyz012345",
+          "trackerIds": [
+            "00000000-0000-0000-0000-000000000002",
+            "00000000-0000-0000-0000-000000000003"
+          ],
+          "createdAt": 946720800,
+          "updatedAt": 946720810
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -1,31 +1,82 @@
 mod web_page_content;
 mod web_page_data_revision;
+mod web_page_data_revision_acknowledgment;
+mod web_page_data_revision_chain_link;
+mod web_page_data_revision_diff_stats;
+mod web_page_dns_records;
 mod web_page_resources;
+mod web_page_screenshot;
 mod web_page_tracker;
+mod web_page_tracker_change;
+mod web_page_tracker_data_classification;
+mod web_page_tracker_data_policy;
+mod web_page_tracker_effective_settings;
+mod web_page_tracker_history_integrity;
+mod web_page_tracker_import_result;
 mod web_page_tracker_kind;
+mod web_page_tracker_pending_scrape;
+mod web_page_tracker_precondition;
+mod web_page_tracker_precondition_operator;
+mod web_page_tracker_script_test_result;
 mod web_page_tracker_settings;
 mod web_page_tracker_tag;
+mod web_page_tracker_webhook;
+mod web_page_tracker_webhook_secret;
 mod web_scraper;
 
 pub use self::{
     web_page_content::{
-        web_page_content_revisions_diff, WebPageContentTrackerTag, WebScraperContentRequest,
+        web_page_content_revisions_diff, web_page_content_revisions_noise,
+        web_page_content_truncate_diff, WebPageContentAssertion, WebPageContentAssertionOperator,
+        WebPageContentCookiesCapture, WebPageContentDiffAlgorithm, WebPageContentFingerprint,
+        WebPageContentFormat, WebPageContentNoiseAnalysis, WebPageContentNoiseRegion,
+        WebPageContentResponse, WebPageContentResponseCapture, WebPageContentResponsePart,
+        WebPageContentSimulatedDiff, WebPageContentStatus, WebPageContentStatuses,
+        WebPageContentStructure, WebPageContentTrackerTag, WebPageContentWithCookies,
+        WebPageCookie, WebPageWellKnownFile, WebScraperContentRequest,
         WebScraperContentRequestScripts, WebScraperContentResponse,
     },
     web_page_data_revision::WebPageDataRevision,
+    web_page_data_revision_acknowledgment::WebPageDataRevisionAcknowledgment,
+    web_page_data_revision_diff_stats::WebPageDataRevisionDiffStats,
+    web_page_dns_records::{
+        web_page_dns_records_revisions_diff, WebPageDnsRecord, WebPageDnsRecordDiffStatus,
+        WebPageDnsRecordType, WebPageDnsRecordsData, WebPageDnsRecordsTrackerTag,
+    },
     web_page_resources::{
-        web_page_resources_revisions_diff, WebPageResource, WebPageResourceContent,
-        WebPageResourceContentData, WebPageResourceDiffStatus, WebPageResourcesData,
-        WebPageResourcesTrackerTag, WebScraperResource, WebScraperResourcesRequest,
-        WebScraperResourcesRequestScripts, WebScraperResourcesResponse,
+        web_page_resources_revisions_diff, WebPageResource, WebPageResourceCategory,
+        WebPageResourceContent, WebPageResourceContentData, WebPageResourceDiffStatus,
+        WebPageResourceType, WebPageResourcesData, WebPageResourcesSizeBudget,
+        WebPageResourcesSizes, WebPageResourcesTrackerTag, WebScraperResource,
+        WebScraperResourcesRequest, WebScraperResourcesRequestScripts, WebScraperResourcesResponse,
+    },
+    web_page_screenshot::{
+        web_page_screenshot_revisions_diff, web_page_screenshots_diff, WebPageScreenshotData,
+        WebPageScreenshotTrackerTag, WebScraperScreenshotRequest, WebScraperScreenshotResponse,
     },
     web_page_tracker::WebPageTracker,
+    web_page_tracker_change::WebPageTrackerChange,
+    web_page_tracker_data_classification::WebPageTrackerDataClassification,
+    web_page_tracker_data_policy::WebPageTrackerDataPolicy,
+    web_page_tracker_effective_settings::WebPageTrackerEffectiveSettings,
+    web_page_tracker_history_integrity::WebPageTrackerHistoryIntegrity,
+    web_page_tracker_import_result::WebPageTrackerImportResult,
     web_page_tracker_kind::WebPageTrackerKind,
+    web_page_tracker_pending_scrape::WebPageTrackerPendingScrape,
+    web_page_tracker_precondition::WebPageTrackerPrecondition,
+    web_page_tracker_precondition_operator::WebPageTrackerPreconditionOperator,
+    web_page_tracker_script_test_result::{
+        WebPageTrackerScriptTestError, WebPageTrackerScriptTestResult,
+    },
     web_page_tracker_settings::WebPageTrackerSettings,
     web_page_tracker_tag::WebPageTrackerTag,
-    web_scraper::WebScraperErrorResponse,
+    web_page_tracker_webhook::WebPageTrackerWebhook,
+    web_page_tracker_webhook_secret::WebPageTrackerWebhookSecret,
+    web_scraper::{WebScraperCallbackResult, WebScraperCircuitBreaker, WebScraperErrorResponse},
 };
 
-pub(in crate::utils::web_scraping) use self::web_page_resources::{
-    WebPageResourceInternal, WebPageResourcesTrackerInternalTag,
+pub(in crate::utils::web_scraping) use self::{
+    web_page_data_revision_chain_link::WebPageDataRevisionChainLink,
+    web_page_resources::{WebPageResourceInternal, WebPageResourcesTrackerInternalTag},
+    web_scraper::{WebScraperCallback, WebScraperClient, WebScraperJobResponse},
 };
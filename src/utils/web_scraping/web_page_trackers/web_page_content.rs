@@ -1,11 +1,52 @@
+mod web_page_content_assertion;
+mod web_page_content_assertion_operator;
+mod web_page_content_cookies_capture;
+mod web_page_content_diff_algorithm;
+mod web_page_content_dom_diff;
+mod web_page_content_fingerprint;
+mod web_page_content_format;
+mod web_page_content_noise_analysis;
+mod web_page_content_noise_region;
+mod web_page_content_response;
+mod web_page_content_response_capture;
+mod web_page_content_response_part;
 mod web_page_content_revisions_diff;
+mod web_page_content_revisions_noise;
+mod web_page_content_simulated_diff;
+mod web_page_content_status;
+mod web_page_content_statuses;
+mod web_page_content_structure;
 mod web_page_content_tracker_tag;
+mod web_page_content_with_cookies;
+mod web_page_cookie;
+mod web_page_well_known_file;
 mod web_scraper_content_request;
 mod web_scraper_content_response;
 
 pub use self::{
-    web_page_content_revisions_diff::web_page_content_revisions_diff,
+    web_page_content_assertion::WebPageContentAssertion,
+    web_page_content_assertion_operator::WebPageContentAssertionOperator,
+    web_page_content_cookies_capture::WebPageContentCookiesCapture,
+    web_page_content_diff_algorithm::WebPageContentDiffAlgorithm,
+    web_page_content_fingerprint::WebPageContentFingerprint,
+    web_page_content_format::WebPageContentFormat,
+    web_page_content_noise_analysis::WebPageContentNoiseAnalysis,
+    web_page_content_noise_region::WebPageContentNoiseRegion,
+    web_page_content_response::WebPageContentResponse,
+    web_page_content_response_capture::WebPageContentResponseCapture,
+    web_page_content_response_part::WebPageContentResponsePart,
+    web_page_content_revisions_diff::{
+        web_page_content_revisions_diff, web_page_content_truncate_diff,
+    },
+    web_page_content_revisions_noise::web_page_content_revisions_noise,
+    web_page_content_simulated_diff::WebPageContentSimulatedDiff,
+    web_page_content_status::WebPageContentStatus,
+    web_page_content_statuses::WebPageContentStatuses,
+    web_page_content_structure::WebPageContentStructure,
     web_page_content_tracker_tag::WebPageContentTrackerTag,
+    web_page_content_with_cookies::WebPageContentWithCookies,
+    web_page_cookie::WebPageCookie,
+    web_page_well_known_file::WebPageWellKnownFile,
     web_scraper_content_request::{WebScraperContentRequest, WebScraperContentRequestScripts},
     web_scraper_content_response::WebScraperContentResponse,
 };
@@ -0,0 +1,100 @@
+use crate::utils::web_scraping::WebPageContentAssertionOperator;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Defines an assertion about the content extracted by the web page content tracker (e.g. the page
+/// should contain "Add to cart", or shouldn't contain "error"). Assertions are evaluated on every
+/// tracker run, and the user is notified when the assertions start or stop passing.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageContentAssertion {
+    /// Operator used to check the extracted content against the expected value.
+    pub operator: WebPageContentAssertionOperator,
+    /// The expected string or regular expression, depending on the operator.
+    pub value: String,
+}
+
+impl WebPageContentAssertion {
+    /// Checks whether the assertion passes for the specified extracted content. Assertions with
+    /// invalid regular expressions never pass.
+    pub fn is_met(&self, content: &str) -> bool {
+        match self.operator {
+            WebPageContentAssertionOperator::Contains => content.contains(&self.value),
+            WebPageContentAssertionOperator::NotContains => !content.contains(&self.value),
+            WebPageContentAssertionOperator::Matches => Regex::new(&self.value)
+                .map(|regex| regex.is_match(content))
+                .unwrap_or_default(),
+            WebPageContentAssertionOperator::NotMatches => Regex::new(&self.value)
+                .map(|regex| !regex.is_match(content))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::{WebPageContentAssertion, WebPageContentAssertionOperator};
+    use insta::assert_json_snapshot;
+
+    fn assertion(
+        operator: WebPageContentAssertionOperator,
+        value: &str,
+    ) -> WebPageContentAssertion {
+        WebPageContentAssertion {
+            operator,
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(assertion(WebPageContentAssertionOperator::NotContains, "error"), @r###"
+        {
+          "operator": "notContains",
+          "value": "error"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageContentAssertion>(
+                r#"{ "operator": "matches", "value": "Add to (cart|basket)" }"#
+            )?,
+            assertion(
+                WebPageContentAssertionOperator::Matches,
+                "Add to (cart|basket)"
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn checks_content() -> anyhow::Result<()> {
+        let contains = assertion(WebPageContentAssertionOperator::Contains, "Add to cart");
+        assert!(contains.is_met("<button>Add to cart</button>"));
+        assert!(!contains.is_met("<button>Sold out</button>"));
+
+        let not_contains = assertion(WebPageContentAssertionOperator::NotContains, "error");
+        assert!(not_contains.is_met("Everything is fine"));
+        assert!(!not_contains.is_met("Internal server error"));
+
+        let matches = assertion(WebPageContentAssertionOperator::Matches, r"v\d+\.\d+");
+        assert!(matches.is_met("Current version: v1.2"));
+        assert!(!matches.is_met("Current version: unknown"));
+
+        let not_matches = assertion(WebPageContentAssertionOperator::NotMatches, r"(?i)error");
+        assert!(not_matches.is_met("Everything is fine"));
+        assert!(!not_matches.is_met("Internal Server ERROR"));
+
+        // Invalid regular expressions never pass.
+        assert!(!assertion(WebPageContentAssertionOperator::Matches, "(").is_met("("));
+        assert!(!assertion(WebPageContentAssertionOperator::NotMatches, "(").is_met("text"));
+
+        Ok(())
+    }
+}
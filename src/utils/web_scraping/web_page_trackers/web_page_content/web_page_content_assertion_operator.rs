@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+/// Defines how the content extracted by the web page content tracker is checked against the
+/// expected value of the assertion.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WebPageContentAssertionOperator {
+    /// The content should contain the expected string.
+    Contains,
+    /// The content should not contain the expected string.
+    NotContains,
+    /// The content should match the expected regular expression.
+    Matches,
+    /// The content should not match the expected regular expression.
+    NotMatches,
+}
+
+impl WebPageContentAssertionOperator {
+    /// Indicates whether the expected value of the assertion is a regular expression.
+    pub fn is_regex(&self) -> bool {
+        matches!(self, Self::Matches | Self::NotMatches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageContentAssertionOperator;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageContentAssertionOperator::Contains, @r###""contains""###);
+        assert_json_snapshot!(WebPageContentAssertionOperator::NotContains, @r###""notContains""###);
+        assert_json_snapshot!(WebPageContentAssertionOperator::Matches, @r###""matches""###);
+        assert_json_snapshot!(WebPageContentAssertionOperator::NotMatches, @r###""notMatches""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageContentAssertionOperator>(r#""contains""#)?,
+            WebPageContentAssertionOperator::Contains
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageContentAssertionOperator>(r#""notContains""#)?,
+            WebPageContentAssertionOperator::NotContains
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageContentAssertionOperator>(r#""matches""#)?,
+            WebPageContentAssertionOperator::Matches
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageContentAssertionOperator>(r#""notMatches""#)?,
+            WebPageContentAssertionOperator::NotMatches
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// Defines how web page content tracker captures cookies set by the web page (`Set-Cookie`
+/// response headers).
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WebPageContentCookiesCapture {
+    /// Cookie names, values, and attributes are captured.
+    Full,
+    /// Only cookie names and attributes are captured, values are redacted.
+    Redacted,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageContentCookiesCapture;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageContentCookiesCapture::Full, @r###""full""###);
+        assert_json_snapshot!(WebPageContentCookiesCapture::Redacted, @r###""redacted""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageContentCookiesCapture>(r#""full""#)?,
+            WebPageContentCookiesCapture::Full
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageContentCookiesCapture>(r#""redacted""#)?,
+            WebPageContentCookiesCapture::Redacted
+        );
+
+        Ok(())
+    }
+}
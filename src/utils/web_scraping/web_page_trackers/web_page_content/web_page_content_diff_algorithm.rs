@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// Defines the algorithm used to calculate the diff between web page content revisions.
+#[derive(Serialize, Deserialize, Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WebPageContentDiffAlgorithm {
+    /// Line-based diff of the (pretty printed) content.
+    #[default]
+    Lines,
+    /// Structural diff of the HTML content that reports added, removed, and changed DOM nodes.
+    /// Falls back to the line-based diff if the content cannot be parsed as HTML.
+    Dom,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageContentDiffAlgorithm;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageContentDiffAlgorithm::Lines, @r###""lines""###);
+        assert_json_snapshot!(WebPageContentDiffAlgorithm::Dom, @r###""dom""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageContentDiffAlgorithm>(r#""lines""#)?,
+            WebPageContentDiffAlgorithm::Lines
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageContentDiffAlgorithm>(r#""dom""#)?,
+            WebPageContentDiffAlgorithm::Dom
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,528 @@
+use html5ever::tokenizer::{
+    states::RawKind, BufferQueue, TagKind, Token, TokenSink, TokenSinkResult, Tokenizer,
+    TokenizerOpts,
+};
+use serde::Serialize;
+use similar::{capture_diff_slices, Algorithm, DiffTag};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+};
+
+/// HTML elements that cannot have any child nodes and don't have a closing tag.
+const VOID_ELEMENTS: [&str; 13] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Describes a single change between two DOM trees. Nodes are identified by XPath-like paths
+/// (e.g. `/html[1]/body[1]/div[2]/text()[1]`), where removed nodes are referenced by their path in
+/// the previous tree and added or changed nodes by their path in the current tree.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum WebPageContentDomChange {
+    /// The node was added, `node` contains its HTML representation.
+    Added { path: String, node: String },
+    /// The node was removed, `node` contains its HTML representation.
+    Removed { path: String, node: String },
+    /// The text of the text node or attributes of the element node were changed. For element
+    /// nodes `before` and `after` contain the start tags of the element.
+    Changed {
+        path: String,
+        before: String,
+        after: String,
+    },
+    /// The remaining changes were omitted since the diff exceeds the maximum size.
+    #[serde(rename_all = "camelCase")]
+    Truncated { remaining_changes: usize },
+}
+
+/// Parses both HTML documents into simplified DOM trees and returns the list of node-level
+/// changes between them. Returns `None` if any of the documents cannot be parsed as HTML.
+pub fn web_page_content_dom_diff(
+    previous_html: &str,
+    current_html: &str,
+) -> Option<Vec<WebPageContentDomChange>> {
+    let previous_nodes = DomNode::parse(previous_html)?;
+    let current_nodes = DomNode::parse(current_html)?;
+
+    let mut changes = vec![];
+    diff_dom_nodes(&previous_nodes, "", &current_nodes, "", &mut changes);
+    Some(changes)
+}
+
+/// Simplified representation of the HTML DOM node that only retains the data relevant for the
+/// diff (comments, doctype, and whitespace-only text nodes are dropped).
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DomNode {
+    Element {
+        name: String,
+        attrs: Vec<(String, String)>,
+        children: Vec<DomNode>,
+    },
+    Text(String),
+}
+
+impl DomNode {
+    /// Parses HTML into a list of the top-level DOM nodes. Returns `None` if the tokenizer
+    /// reports any parse errors or if the HTML doesn't contain any elements.
+    fn parse(html: &str) -> Option<Vec<DomNode>> {
+        let input = BufferQueue::default();
+        input.push_back(html.into());
+
+        let mut sink = DomTreeSink::new();
+        {
+            let tokenizer = Tokenizer::new(&mut sink, TokenizerOpts::default());
+            let _ = tokenizer.feed(&input);
+            tokenizer.end();
+        }
+
+        if sink.has_errors.get() {
+            return None;
+        }
+
+        let nodes = sink.finish();
+        if nodes
+            .iter()
+            .any(|node| matches!(node, DomNode::Element { .. }))
+        {
+            Some(nodes)
+        } else {
+            None
+        }
+    }
+
+    /// Checks whether the node is an element with the specified name.
+    fn is_element(&self, element_name: &str) -> bool {
+        matches!(self, DomNode::Element { name, .. } if name == element_name)
+    }
+
+    /// Returns the key used to match nodes of the previous and current trees.
+    fn key(&self) -> &str {
+        match self {
+            DomNode::Element { name, .. } => name.as_str(),
+            DomNode::Text(_) => "text()",
+        }
+    }
+
+    /// Returns the start tag for the element node or text for the text node.
+    fn start_tag(&self) -> String {
+        match self {
+            DomNode::Element { name, attrs, .. } => {
+                let attrs = attrs
+                    .iter()
+                    .map(|(name, value)| format!(" {name}=\"{}\"", escape_html(value, true)))
+                    .collect::<String>();
+                format!("<{name}{attrs}>")
+            }
+            DomNode::Text(text) => escape_html(text, false),
+        }
+    }
+
+    /// Serializes the node back to HTML.
+    fn to_html(&self) -> String {
+        match self {
+            DomNode::Element { name, .. } if VOID_ELEMENTS.contains(&name.as_str()) => {
+                self.start_tag()
+            }
+            DomNode::Element { name, children, .. } => format!(
+                "{}{}</{name}>",
+                self.start_tag(),
+                children.iter().map(Self::to_html).collect::<String>()
+            ),
+            DomNode::Text(_) => self.start_tag(),
+        }
+    }
+}
+
+/// Calculates the paths of the child nodes based on their position among the siblings of the same
+/// kind.
+fn dom_node_paths(parent_path: &str, nodes: &[DomNode]) -> Vec<String> {
+    let mut counters = HashMap::new();
+    nodes
+        .iter()
+        .map(|node| {
+            let counter = counters.entry(node.key()).or_insert(0);
+            *counter += 1;
+            format!("{parent_path}/{}[{counter}]", node.key())
+        })
+        .collect()
+}
+
+/// Recursively compares two lists of sibling nodes and collects the changes.
+fn diff_dom_nodes(
+    previous_nodes: &[DomNode],
+    previous_parent_path: &str,
+    current_nodes: &[DomNode],
+    current_parent_path: &str,
+    changes: &mut Vec<WebPageContentDomChange>,
+) {
+    let previous_paths = dom_node_paths(previous_parent_path, previous_nodes);
+    let current_paths = dom_node_paths(current_parent_path, current_nodes);
+
+    let previous_keys = previous_nodes.iter().map(DomNode::key).collect::<Vec<_>>();
+    let current_keys = current_nodes.iter().map(DomNode::key).collect::<Vec<_>>();
+    for op in capture_diff_slices(Algorithm::Myers, &previous_keys, &current_keys) {
+        let (tag, previous_range, current_range) = op.as_tag_tuple();
+        if tag == DiffTag::Equal {
+            for (previous_index, current_index) in previous_range.zip(current_range) {
+                diff_dom_node(
+                    &previous_nodes[previous_index],
+                    &previous_paths[previous_index],
+                    &current_nodes[current_index],
+                    &current_paths[current_index],
+                    changes,
+                );
+            }
+            continue;
+        }
+
+        for previous_index in previous_range {
+            changes.push(WebPageContentDomChange::Removed {
+                path: previous_paths[previous_index].clone(),
+                node: previous_nodes[previous_index].to_html(),
+            });
+        }
+
+        for current_index in current_range {
+            changes.push(WebPageContentDomChange::Added {
+                path: current_paths[current_index].clone(),
+                node: current_nodes[current_index].to_html(),
+            });
+        }
+    }
+}
+
+/// Compares two nodes of the same kind and collects the changes.
+fn diff_dom_node(
+    previous_node: &DomNode,
+    previous_path: &str,
+    current_node: &DomNode,
+    current_path: &str,
+    changes: &mut Vec<WebPageContentDomChange>,
+) {
+    match (previous_node, current_node) {
+        (
+            DomNode::Element {
+                attrs: previous_attrs,
+                children: previous_children,
+                ..
+            },
+            DomNode::Element {
+                attrs: current_attrs,
+                children: current_children,
+                ..
+            },
+        ) => {
+            if previous_attrs != current_attrs {
+                changes.push(WebPageContentDomChange::Changed {
+                    path: current_path.to_string(),
+                    before: previous_node.start_tag(),
+                    after: current_node.start_tag(),
+                });
+            }
+
+            diff_dom_nodes(
+                previous_children,
+                previous_path,
+                current_children,
+                current_path,
+                changes,
+            );
+        }
+        (DomNode::Text(previous_text), DomNode::Text(current_text)) => {
+            if previous_text != current_text {
+                changes.push(WebPageContentDomChange::Changed {
+                    path: current_path.to_string(),
+                    before: previous_node.start_tag(),
+                    after: current_node.start_tag(),
+                });
+            }
+        }
+        _ => {
+            changes.push(WebPageContentDomChange::Removed {
+                path: previous_path.to_string(),
+                node: previous_node.to_html(),
+            });
+            changes.push(WebPageContentDomChange::Added {
+                path: current_path.to_string(),
+                node: current_node.to_html(),
+            });
+        }
+    }
+}
+
+/// Escapes special HTML characters in the text or attribute value.
+fn escape_html(value: &str, is_attribute: bool) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' if is_attribute => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Serves as a sink for the tokenizer that builds a simplified DOM tree.
+struct DomTreeSink {
+    /// The stack of the currently open elements, the first element is a synthetic root element.
+    open_elements: RefCell<Vec<DomNode>>,
+    has_errors: Cell<bool>,
+}
+
+impl DomTreeSink {
+    /// Creates new sink.
+    fn new() -> Self {
+        Self {
+            open_elements: RefCell::new(vec![DomNode::Element {
+                name: String::new(),
+                attrs: vec![],
+                children: vec![],
+            }]),
+            has_errors: Cell::new(false),
+        }
+    }
+
+    /// Closes all elements that are still open and returns the top-level nodes.
+    fn finish(self) -> Vec<DomNode> {
+        let mut open_elements = self.open_elements.into_inner();
+        while open_elements.len() > 1 {
+            Self::close_element(&mut open_elements);
+        }
+
+        match open_elements.pop() {
+            Some(DomNode::Element { children, .. }) => Self::normalize_text_nodes(children),
+            _ => vec![],
+        }
+    }
+
+    /// Appends a node to the currently open element.
+    fn append_node(open_elements: &mut [DomNode], node: DomNode) {
+        if let Some(DomNode::Element { children, .. }) = open_elements.last_mut() {
+            children.push(node);
+        }
+    }
+
+    /// Closes the currently open element, normalizes its text nodes, and appends it to its parent.
+    fn close_element(open_elements: &mut Vec<DomNode>) {
+        if let Some(DomNode::Element {
+            name,
+            attrs,
+            children,
+        }) = open_elements.pop()
+        {
+            Self::append_node(
+                open_elements,
+                DomNode::Element {
+                    name,
+                    attrs,
+                    children: Self::normalize_text_nodes(children),
+                },
+            );
+        }
+    }
+
+    /// Collapses whitespace in the text nodes and drops whitespace-only text nodes.
+    fn normalize_text_nodes(nodes: Vec<DomNode>) -> Vec<DomNode> {
+        nodes
+            .into_iter()
+            .filter_map(|node| match node {
+                DomNode::Text(text) => {
+                    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                    (!text.is_empty()).then_some(DomNode::Text(text))
+                }
+                node => Some(node),
+            })
+            .collect()
+    }
+}
+
+impl TokenSink for &mut DomTreeSink {
+    type Handle = ();
+
+    fn process_token(&self, token: Token, _: u64) -> TokenSinkResult<Self::Handle> {
+        let mut open_elements = self.open_elements.borrow_mut();
+        match token {
+            Token::TagToken(tag) if tag.kind == TagKind::StartTag => {
+                let name = (*tag.name).to_ascii_lowercase();
+                let mut attrs = tag
+                    .attrs
+                    .iter()
+                    .map(|attr| (attr.name.local.to_string(), attr.value.to_string()))
+                    .collect::<Vec<_>>();
+                attrs.sort();
+
+                let node = DomNode::Element {
+                    name: name.clone(),
+                    attrs,
+                    children: vec![],
+                };
+                if tag.self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+                    DomTreeSink::append_node(&mut open_elements, node);
+                    return TokenSinkResult::Continue;
+                }
+
+                open_elements.push(node);
+
+                // Content of some elements must be treated as a raw text, and tokenizer needs to
+                // know about that.
+                match name.as_str() {
+                    "script" => return TokenSinkResult::RawData(RawKind::ScriptData),
+                    "style" | "xmp" | "iframe" | "noembed" | "noframes" => {
+                        return TokenSinkResult::RawData(RawKind::Rawtext)
+                    }
+                    "title" | "textarea" => return TokenSinkResult::RawData(RawKind::Rcdata),
+                    _ => {}
+                }
+            }
+            Token::TagToken(tag) => {
+                // Close all elements up to the matching one, end tags without a matching open
+                // element are ignored.
+                let name = (*tag.name).to_ascii_lowercase();
+                let matching_element_index = open_elements
+                    .iter()
+                    .rposition(|node| node.is_element(&name));
+                if let Some(matching_element_index) =
+                    matching_element_index.filter(|index| *index > 0)
+                {
+                    while open_elements.len() > matching_element_index {
+                        DomTreeSink::close_element(&mut open_elements);
+                    }
+                }
+            }
+            Token::CharacterTokens(text) => {
+                if let Some(DomNode::Element { children, .. }) = open_elements.last_mut() {
+                    if let Some(DomNode::Text(previous_text)) = children.last_mut() {
+                        previous_text.push_str(&text);
+                    } else {
+                        children.push(DomNode::Text(text.to_string()));
+                    }
+                }
+            }
+            Token::ParseError(_) => {
+                self.has_errors.set(true);
+            }
+            _ => {}
+        }
+
+        TokenSinkResult::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::web_page_content_dom_diff;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn correctly_calculates_dom_diff() -> anyhow::Result<()> {
+        let previous_html = r#"
+<html>
+  <head><title>Secutils</title></head>
+  <body>
+    <h1 class="title">Hello World</h1>
+    <ul>
+      <li>One</li>
+      <li>Two</li>
+    </ul>
+    <p>Removed paragraph</p>
+  </body>
+</html>"#;
+        let current_html = r#"
+<html>
+  <head><title>Secutils.dev</title></head>
+  <body>
+    <h1 class="title large">Hello World</h1>
+    <ul>
+      <li>One</li>
+      <li>Two</li>
+      <li>Three &amp; Four</li>
+    </ul>
+    <img src="logo.png" alt="Logo">
+  </body>
+</html>"#;
+
+        assert_json_snapshot!(web_page_content_dom_diff(previous_html, current_html), @r###"
+        [
+          {
+            "type": "changed",
+            "path": "/html[1]/head[1]/title[1]/text()[1]",
+            "before": "Secutils",
+            "after": "Secutils.dev"
+          },
+          {
+            "type": "changed",
+            "path": "/html[1]/body[1]/h1[1]",
+            "before": "<h1 class=\"title\">",
+            "after": "<h1 class=\"title large\">"
+          },
+          {
+            "type": "added",
+            "path": "/html[1]/body[1]/ul[1]/li[3]",
+            "node": "<li>Three &amp; Four</li>"
+          },
+          {
+            "type": "removed",
+            "path": "/html[1]/body[1]/p[1]",
+            "node": "<p>Removed paragraph</p>"
+          },
+          {
+            "type": "added",
+            "path": "/html[1]/body[1]/img[1]",
+            "node": "<img alt=\"Logo\" src=\"logo.png\">"
+          }
+        ]
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn correctly_calculates_dom_diff_for_fragments() -> anyhow::Result<()> {
+        assert_json_snapshot!(web_page_content_dom_diff(
+            "<div>One</div><div>Two<br>Three</div><script>if (a < b) {}</script>",
+            "<div>One</div><div>Two<br>Four</div><div>Five</div><script>if (a > b) {}</script>"
+        ), @r###"
+        [
+          {
+            "type": "changed",
+            "path": "/div[2]/text()[2]",
+            "before": "Three",
+            "after": "Four"
+          },
+          {
+            "type": "added",
+            "path": "/div[3]",
+            "node": "<div>Five</div>"
+          },
+          {
+            "type": "changed",
+            "path": "/script[1]/text()[1]",
+            "before": "if (a &lt; b) {}",
+            "after": "if (a &gt; b) {}"
+          }
+        ]
+        "###);
+
+        // Whitespace differences are ignored.
+        assert_json_snapshot!(web_page_content_dom_diff(
+            "<div>\n  <span>One   Two</span>\n</div>",
+            "<div><span>One Two</span></div>"
+        ), @"[]");
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_calculate_dom_diff_for_non_html_content() -> anyhow::Result<()> {
+        assert!(web_page_content_dom_diff("Hello World", "<div>Hello World</div>").is_none());
+        assert!(web_page_content_dom_diff("<div>Hello World</div>", "Hello World").is_none());
+        assert!(web_page_content_dom_diff("<div a=\"1\" a=\"2\"></div>", "<div></div>").is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents fingerprint of the resource (e.g. favicon) tracked by the content tracker in the
+/// fingerprint capture mode. The resource itself isn't stored, only its hash and metadata.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageContentFingerprint {
+    /// Hex-encoded SHA-256 hash of the resource content.
+    pub sha256: String,
+    /// Size of the resource content, in bytes.
+    pub size: usize,
+    /// Value of the `Content-Type` response header, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+}
+
+impl WebPageContentFingerprint {
+    /// Calculates fingerprint of the resource with the specified content and content type.
+    pub fn new(content: &[u8], content_type: Option<&str>) -> Self {
+        Self {
+            sha256: hex::encode(openssl::sha::sha256(content)),
+            size: content.len(),
+            content_type: content_type.map(|content_type| content_type.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageContentFingerprint;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageContentFingerprint::new(b"icon", None), @r###"
+        {
+          "sha256": "c2d4b446a44ce54fab8e01150e24dd24f3d850c7c14dcfe31f6321341dd86874",
+          "size": 4
+        }
+        "###);
+        assert_json_snapshot!(WebPageContentFingerprint::new(b"", Some("image/x-icon")), @r###"
+        {
+          "sha256": "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+          "size": 0,
+          "contentType": "image/x-icon"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageContentFingerprint>(
+                r#"{ "sha256": "abc", "size": 3, "contentType": "image/png" }"#
+            )?,
+            WebPageContentFingerprint {
+                sha256: "abc".to_string(),
+                size: 3,
+                content_type: Some("image/png".to_string()),
+            }
+        );
+
+        Ok(())
+    }
+}
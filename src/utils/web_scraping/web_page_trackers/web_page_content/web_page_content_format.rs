@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// Defines structured text formats the web page content tracker can parse to compare content
+/// semantically, rather than line by line.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WebPageContentFormat {
+    /// Crawling rules for the web robots (`robots.txt`), the order of the rules doesn't matter.
+    RobotsTxt,
+    /// XML sitemap (https://www.sitemaps.org/protocol.html), the order of the URLs doesn't matter.
+    Sitemap,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageContentFormat;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageContentFormat::RobotsTxt, @r###""robotsTxt""###);
+        assert_json_snapshot!(WebPageContentFormat::Sitemap, @r###""sitemap""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageContentFormat>(r#""robotsTxt""#)?,
+            WebPageContentFormat::RobotsTxt
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageContentFormat>(r#""sitemap""#)?,
+            WebPageContentFormat::Sitemap
+        );
+
+        Ok(())
+    }
+}
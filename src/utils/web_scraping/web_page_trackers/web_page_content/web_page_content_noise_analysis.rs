@@ -0,0 +1,53 @@
+use crate::utils::web_scraping::WebPageContentNoiseRegion;
+use serde::Serialize;
+
+/// Result of the analysis of how noisy the changes across the web page content tracker history are.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageContentNoiseAnalysis {
+    /// Number of the analyzed revisions.
+    pub revisions: usize,
+    /// Share of the changed lines that belong to the volatile regions, from `0.0` to `1.0`.
+    pub noise_ratio: f64,
+    /// Regions that changed at least once, the most frequently changed regions go first.
+    pub regions: Vec<WebPageContentNoiseRegion>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageContentNoiseAnalysis;
+    use crate::utils::web_scraping::WebPageContentNoiseRegion;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageContentNoiseAnalysis {
+            revisions: 5,
+            noise_ratio: 0.8,
+            regions: vec![WebPageContentNoiseRegion {
+                anchor: Some("<h1>Title</h1>".to_string()),
+                changes: 4,
+                frequency: 1.0,
+                volatile: true,
+                suggested_selector: Some("#time".to_string()),
+                suggested_ignore_pattern: None,
+            }],
+        }, @r###"
+        {
+          "revisions": 5,
+          "noiseRatio": 0.8,
+          "regions": [
+            {
+              "anchor": "<h1>Title</h1>",
+              "changes": 4,
+              "frequency": 1.0,
+              "volatile": true,
+              "suggestedSelector": "#time"
+            }
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use serde::Serialize;
+
+/// Describes a region of the web page content that changed at least once across the tracker
+/// history. Region is identified by the closest unchanged line that precedes it (anchor).
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageContentNoiseRegion {
+    /// The closest unchanged line that precedes the region, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<String>,
+    /// Number of revisions that changed the region.
+    pub changes: usize,
+    /// Share of the revisions that changed the region, from `0.0` to `1.0`.
+    pub frequency: f64,
+    /// Indicates whether the region changes so often that it's likely a noise.
+    pub volatile: bool,
+    /// The CSS selector of the element that can be excluded from tracking, if can be inferred.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_selector: Option<String>,
+    /// The regular expression that matches all observed values of the region, if can be inferred.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_ignore_pattern: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageContentNoiseRegion;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageContentNoiseRegion {
+            anchor: None,
+            changes: 1,
+            frequency: 0.25,
+            volatile: false,
+            suggested_selector: None,
+            suggested_ignore_pattern: None,
+        }, @r###"
+        {
+          "changes": 1,
+          "frequency": 0.25,
+          "volatile": false
+        }
+        "###);
+
+        assert_json_snapshot!(WebPageContentNoiseRegion {
+            anchor: Some("<h1>Title</h1>".to_string()),
+            changes: 4,
+            frequency: 1.0,
+            volatile: true,
+            suggested_selector: Some("#time".to_string()),
+            suggested_ignore_pattern: Some("^<span id=\"time\">.*</span>$".to_string()),
+        }, @r###"
+        {
+          "anchor": "<h1>Title</h1>",
+          "changes": 4,
+          "frequency": 1.0,
+          "volatile": true,
+          "suggestedSelector": "#time",
+          "suggestedIgnorePattern": "^<span id=\"time\">.*</span>$"
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,268 @@
+use crate::utils::web_scraping::{WebPageContentResponseCapture, WebPageContentResponsePart};
+use itertools::Itertools;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JSONValue;
+use std::collections::BTreeMap;
+
+/// Represents JSON API response captured by the web page content tracker: HTTP status, the
+/// selected response headers, and the response body are tracked together as a single unit.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageContentResponse {
+    /// HTTP status of the response.
+    pub status: u16,
+    /// Captured response headers, keyed by the lowercased header name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// Response body, parsed as JSON if possible.
+    pub body: JSONValue,
+}
+
+impl WebPageContentResponse {
+    /// Captures response with the specified HTTP status, headers, and body. Only the headers
+    /// requested by the capture settings are retained, multiple values of the same header are
+    /// joined with a comma. Body that isn't a valid JSON is captured as a string.
+    pub fn new(
+        status: u16,
+        headers: &HeaderMap,
+        body: &str,
+        capture: &WebPageContentResponseCapture,
+    ) -> Self {
+        let headers = capture
+            .headers
+            .iter()
+            .filter_map(|name| {
+                let values = headers
+                    .get_all(name.as_str())
+                    .iter()
+                    .map(|value| String::from_utf8_lossy(value.as_bytes()))
+                    .collect_vec();
+                if values.is_empty() {
+                    None
+                } else {
+                    Some((name.to_lowercase(), values.join(", ")))
+                }
+            })
+            .collect();
+
+        Self {
+            status,
+            headers,
+            body: serde_json::from_str(body)
+                .unwrap_or_else(|_| JSONValue::String(body.to_string())),
+        }
+    }
+
+    /// Returns parts of the response that have changed compared to the previous response.
+    pub fn changed_parts(&self, previous: &Self) -> Vec<WebPageContentResponsePart> {
+        [
+            (
+                WebPageContentResponsePart::Status,
+                self.status != previous.status,
+            ),
+            (
+                WebPageContentResponsePart::Headers,
+                self.headers != previous.headers,
+            ),
+            (WebPageContentResponsePart::Body, self.body != previous.body),
+        ]
+        .into_iter()
+        .filter_map(|(part, changed)| changed.then_some(part))
+        .collect()
+    }
+
+    /// Returns a human-readable summary of the changes compared to the previous response, e.g.
+    /// `status changed from 200 to 503, body changed`. Returns `None` if nothing has changed.
+    pub fn describe_changes(&self, previous: &Self) -> Option<String> {
+        let changes = self
+            .changed_parts(previous)
+            .into_iter()
+            .map(|part| match part {
+                WebPageContentResponsePart::Status => {
+                    format!("{part} changed from {} to {}", previous.status, self.status)
+                }
+                WebPageContentResponsePart::Headers => {
+                    let changed_headers = self
+                        .headers
+                        .keys()
+                        .chain(previous.headers.keys())
+                        .unique()
+                        .filter(|name| self.headers.get(*name) != previous.headers.get(*name))
+                        .sorted()
+                        .join(", ");
+                    format!("{part} changed ({changed_headers})")
+                }
+                WebPageContentResponsePart::Body => format!("{part} changed"),
+            })
+            .collect_vec();
+
+        if changes.is_empty() {
+            None
+        } else {
+            Some(changes.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageContentResponse;
+    use crate::utils::web_scraping::{WebPageContentResponseCapture, WebPageContentResponsePart};
+    use insta::assert_json_snapshot;
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    fn mock_response(status: u16, etag: &str, body: serde_json::Value) -> WebPageContentResponse {
+        WebPageContentResponse {
+            status,
+            headers: BTreeMap::from_iter([
+                ("content-type".to_string(), "application/json".to_string()),
+                ("etag".to_string(), etag.to_string()),
+            ]),
+            body,
+        }
+    }
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(mock_response(200, "v1", json!({ "version": 1 })), @r###"
+        {
+          "status": 200,
+          "headers": {
+            "content-type": "application/json",
+            "etag": "v1"
+          },
+          "body": {
+            "version": 1
+          }
+        }
+        "###);
+        assert_json_snapshot!(WebPageContentResponse {
+            status: 204,
+            headers: BTreeMap::new(),
+            body: "".into(),
+        }, @r###"
+        {
+          "status": 204,
+          "body": ""
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_captures_response() -> anyhow::Result<()> {
+        let mut headers = HeaderMap::new();
+        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
+        headers.insert("X-Request-Id", HeaderValue::from_static("some-id"));
+        headers.append("Vary", HeaderValue::from_static("Accept"));
+        headers.append("Vary", HeaderValue::from_static("Origin"));
+
+        let capture = WebPageContentResponseCapture {
+            headers: vec![
+                "content-type".to_string(),
+                "VARY".to_string(),
+                "ETag".to_string(),
+            ],
+        };
+        assert_json_snapshot!(WebPageContentResponse::new(200, &headers, r#"{"version": 1}"#, &capture), @r###"
+        {
+          "status": 200,
+          "headers": {
+            "content-type": "application/json",
+            "vary": "Accept, Origin"
+          },
+          "body": {
+            "version": 1
+          }
+        }
+        "###);
+
+        // Non-JSON body is captured as a string.
+        assert_eq!(
+            WebPageContentResponse::new(
+                503,
+                &headers,
+                "Service Unavailable",
+                &WebPageContentResponseCapture::default()
+            ),
+            WebPageContentResponse {
+                status: 503,
+                headers: BTreeMap::new(),
+                body: "Service Unavailable".into(),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_detects_changed_parts() {
+        let previous = mock_response(200, "v1", json!({ "version": 1 }));
+
+        for (current, changed_parts, description) in [
+            (
+                mock_response(200, "v1", json!({ "version": 1 })),
+                vec![],
+                None,
+            ),
+            (
+                mock_response(503, "v1", json!({ "version": 1 })),
+                vec![WebPageContentResponsePart::Status],
+                Some("status changed from 200 to 503"),
+            ),
+            (
+                mock_response(200, "v2", json!({ "version": 1 })),
+                vec![WebPageContentResponsePart::Headers],
+                Some("headers changed (etag)"),
+            ),
+            (
+                mock_response(200, "v1", json!({ "version": 2 })),
+                vec![WebPageContentResponsePart::Body],
+                Some("body changed"),
+            ),
+            (
+                mock_response(503, "v1", json!({ "version": 2 })),
+                vec![
+                    WebPageContentResponsePart::Status,
+                    WebPageContentResponsePart::Body,
+                ],
+                Some("status changed from 200 to 503, body changed"),
+            ),
+            (
+                mock_response(200, "v2", json!({ "version": 2 })),
+                vec![
+                    WebPageContentResponsePart::Headers,
+                    WebPageContentResponsePart::Body,
+                ],
+                Some("headers changed (etag), body changed"),
+            ),
+            (
+                mock_response(500, "v2", json!("Internal Server Error")),
+                vec![
+                    WebPageContentResponsePart::Status,
+                    WebPageContentResponsePart::Headers,
+                    WebPageContentResponsePart::Body,
+                ],
+                Some("status changed from 200 to 500, headers changed (etag), body changed"),
+            ),
+        ] {
+            assert_eq!(current.changed_parts(&previous), changed_parts);
+            assert_eq!(current.describe_changes(&previous).as_deref(), description);
+        }
+
+        // Added and removed headers are reported as well.
+        let mut current = mock_response(200, "v1", json!({ "version": 1 }));
+        current.headers.remove("etag");
+        current
+            .headers
+            .insert("cache-control".to_string(), "no-cache".to_string());
+        assert_eq!(
+            current.describe_changes(&previous).as_deref(),
+            Some("headers changed (cache-control, etag)")
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+/// Defines how web page content tracker captures the response of a JSON API: HTTP status, the
+/// selected response headers, and the response body are captured and diffed together.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageContentResponseCapture {
+    /// Names of the response headers to capture (case-insensitive).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageContentResponseCapture;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageContentResponseCapture::default(), @"{}");
+        assert_json_snapshot!(WebPageContentResponseCapture {
+            headers: vec!["Content-Type".to_string(), "ETag".to_string()]
+        }, @r###"
+        {
+          "headers": [
+            "Content-Type",
+            "ETag"
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageContentResponseCapture>("{}")?,
+            WebPageContentResponseCapture::default()
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageContentResponseCapture>(
+                r#"{ "headers": ["Content-Type", "ETag"] }"#
+            )?,
+            WebPageContentResponseCapture {
+                headers: vec!["Content-Type".to_string(), "ETag".to_string()]
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Describes a part of the JSON API response captured by the web page content tracker.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WebPageContentResponsePart {
+    /// HTTP status of the response.
+    Status,
+    /// Captured response headers.
+    Headers,
+    /// Response body.
+    Body,
+}
+
+impl Display for WebPageContentResponsePart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Status => "status",
+            Self::Headers => "headers",
+            Self::Body => "body",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageContentResponsePart;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageContentResponsePart::Status, @r###""status""###);
+        assert_json_snapshot!(WebPageContentResponsePart::Headers, @r###""headers""###);
+        assert_json_snapshot!(WebPageContentResponsePart::Body, @r###""body""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(WebPageContentResponsePart::Status.to_string(), "status");
+        assert_eq!(WebPageContentResponsePart::Headers.to_string(), "headers");
+        assert_eq!(WebPageContentResponsePart::Body.to_string(), "body");
+    }
+}
@@ -1,10 +1,15 @@
-use crate::utils::web_scraping::{WebPageContentTrackerTag, WebPageDataRevision};
+use super::web_page_content_dom_diff::{web_page_content_dom_diff, WebPageContentDomChange};
+use crate::utils::web_scraping::{
+    WebPageContentDiffAlgorithm, WebPageContentTrackerTag, WebPageDataRevision,
+    WebPageDataRevisionDiffStats,
+};
 use handlebars::JsonRender;
 use serde_json::Value as JSONValue;
-use similar::TextDiff;
+use similar::{ChangeTag, TextDiff};
+use std::borrow::Cow;
 
 /// Pretty prints the web page content revision data.
-fn web_page_content_revision_pretty_print(data: &str) -> anyhow::Result<String> {
+pub(super) fn web_page_content_revision_pretty_print(data: &str) -> anyhow::Result<String> {
     let json_data = serde_json::from_str::<JSONValue>(data)?;
     Ok(
         if json_data.is_object() || json_data.is_array() || json_data.is_null() {
@@ -15,9 +20,104 @@ fn web_page_content_revision_pretty_print(data: &str) -> anyhow::Result<String>
     )
 }
 
-/// Takes multiple web page content revisions and calculates the diff.
+/// Truncates the line diff (or a line-based summary of changes) that exceeds the maximum size, in
+/// bytes, at the line boundary, and appends a marker with the number of the omitted changed lines.
+/// Unchanged context lines and hunk headers aren't counted as changes.
+pub fn web_page_content_truncate_diff(diff: &str, max_size: usize) -> Cow<'_, str> {
+    if diff.len() <= max_size {
+        return Cow::Borrowed(diff);
+    }
+
+    let mut truncated_diff = String::with_capacity(max_size);
+    let mut remaining_changes = 0;
+    let mut is_truncated = false;
+    for line in diff.split_inclusive('\n') {
+        if !is_truncated && truncated_diff.len() + line.len() <= max_size {
+            truncated_diff.push_str(line);
+            continue;
+        }
+
+        is_truncated = true;
+        if !line.trim_end().is_empty() && !line.starts_with(' ') && !line.starts_with("@@") {
+            remaining_changes += 1;
+        }
+    }
+
+    if !truncated_diff.is_empty() && !truncated_diff.ends_with('\n') {
+        truncated_diff.push('\n');
+    }
+    truncated_diff.push_str(&format!(
+        "... diff truncated, {remaining_changes} more changes"
+    ));
+
+    Cow::Owned(truncated_diff)
+}
+
+/// Calculates the structural DOM diff if both web page content revisions are HTML strings. If the
+/// serialized changes exceed the maximum size, the remaining changes are replaced with a marker.
+fn web_page_content_revision_dom_diff(
+    previous_data: &str,
+    current_data: &str,
+    max_size: Option<usize>,
+) -> anyhow::Result<Option<String>> {
+    let (Ok(JSONValue::String(previous_html)), Ok(JSONValue::String(current_html))) = (
+        serde_json::from_str::<JSONValue>(previous_data),
+        serde_json::from_str::<JSONValue>(current_data),
+    ) else {
+        return Ok(None);
+    };
+
+    let Some(mut changes) = web_page_content_dom_diff(&previous_html, &current_html) else {
+        return Ok(None);
+    };
+
+    if let Some(max_size) = max_size {
+        let mut size = 0;
+        let mut changes_to_keep = 0;
+        for change in &changes {
+            size += serde_json::to_string(change)?.len();
+            if size > max_size {
+                break;
+            }
+            changes_to_keep += 1;
+        }
+
+        if changes_to_keep < changes.len() {
+            let remaining_changes = changes.len() - changes_to_keep;
+            changes.truncate(changes_to_keep);
+            changes.push(WebPageContentDomChange::Truncated { remaining_changes });
+        }
+    }
+
+    Ok(Some(serde_json::to_string(&changes)?))
+}
+
+/// Calculates the number of added and removed lines, and their total size in bytes.
+fn web_page_content_diff_stats<'a>(
+    text_diff: &TextDiff<'a, 'a, 'a, str>,
+) -> WebPageDataRevisionDiffStats {
+    text_diff.iter_all_changes().fold(
+        WebPageDataRevisionDiffStats::default(),
+        |mut stats, change| {
+            match change.tag() {
+                ChangeTag::Insert => stats.added_lines += 1,
+                ChangeTag::Delete => stats.removed_lines += 1,
+                ChangeTag::Equal => return stats,
+            }
+            stats.changed_bytes += change.value().len();
+            stats
+        },
+    )
+}
+
+/// Takes multiple web page content revisions and calculates the diff using the specified
+/// algorithm. Every revision except for the first one also gets the stats of the line diff. Diffs
+/// that exceed the maximum size, if specified, are truncated, but the stats always describe the
+/// full diff.
 pub fn web_page_content_revisions_diff(
     revisions: Vec<WebPageDataRevision<WebPageContentTrackerTag>>,
+    algorithm: WebPageContentDiffAlgorithm,
+    max_size: Option<usize>,
 ) -> anyhow::Result<Vec<WebPageDataRevision<WebPageContentTrackerTag>>> {
     if revisions.len() < 2 {
         return Ok(revisions);
@@ -27,15 +127,42 @@ pub fn web_page_content_revisions_diff(
     let mut peekable_revisions = revisions.into_iter().rev().peekable();
     while let Some(current_revision) = peekable_revisions.next() {
         if let Some(previous_revision) = peekable_revisions.peek() {
+            // DOM diff is only possible for HTML content, otherwise fall back to the line diff.
+            let dom_diff = if algorithm == WebPageContentDiffAlgorithm::Dom {
+                web_page_content_revision_dom_diff(
+                    &previous_revision.data,
+                    &current_revision.data,
+                    max_size,
+                )?
+            } else {
+                None
+            };
+
+            // Diff stats are always calculated from the line diff, even if DOM diff is returned.
             let current_value = web_page_content_revision_pretty_print(&current_revision.data)?;
             let previous_value = web_page_content_revision_pretty_print(&previous_revision.data)?;
+            let text_diff = TextDiff::from_lines(&previous_value, &current_value);
+            let diff_stats = web_page_content_diff_stats(&text_diff);
 
-            revisions_diff.push(WebPageDataRevision {
-                data: TextDiff::from_lines(&previous_value, &current_value)
+            let data = if let Some(dom_diff) = dom_diff {
+                dom_diff
+            } else {
+                let line_diff = text_diff
                     .unified_diff()
                     .context_radius(10000)
                     .missing_newline_hint(false)
-                    .to_string(),
+                    .to_string();
+                match max_size {
+                    Some(max_size) => {
+                        web_page_content_truncate_diff(&line_diff, max_size).into_owned()
+                    }
+                    None => line_diff,
+                }
+            };
+
+            revisions_diff.push(WebPageDataRevision {
+                data,
+                diff_stats: Some(diff_stats),
                 ..current_revision
             });
         } else {
@@ -49,7 +176,9 @@ pub fn web_page_content_revisions_diff(
 #[cfg(test)]
 mod tests {
     use crate::utils::web_scraping::{
-        web_page_content_revisions_diff, WebPageContentTrackerTag, WebPageDataRevision,
+        web_page_content_revisions_diff, web_page_content_truncate_diff,
+        WebPageContentCookiesCapture, WebPageContentDiffAlgorithm, WebPageContentTrackerTag,
+        WebPageContentWithCookies, WebPageDataRevision, WebPageDataRevisionDiffStats,
     };
     use insta::assert_debug_snapshot;
     use serde_json::json;
@@ -64,16 +193,25 @@ mod tests {
                 tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
                 data: "\"Hello World\"".to_string(),
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
             },
             WebPageDataRevision::<WebPageContentTrackerTag> {
                 id: uuid!("00000000-0000-0000-0000-000000000002"),
                 tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
                 data: "\"Hello New World\"".to_string(),
                 created_at: OffsetDateTime::from_unix_timestamp(946720801)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
             },
         ];
 
-        let diff = web_page_content_revisions_diff(revisions)?;
+        let diff =
+            web_page_content_revisions_diff(revisions, WebPageContentDiffAlgorithm::Lines, None)?;
         assert_debug_snapshot!(diff, @r###"
         [
             WebPageDataRevision {
@@ -81,12 +219,26 @@ mod tests {
                 tracker_id: 00000000-0000-0000-0000-000000000002,
                 data: "\"Hello World\"",
                 created_at: 2000-01-01 10:00:00.0 +00:00:00,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
             },
             WebPageDataRevision {
                 id: 00000000-0000-0000-0000-000000000002,
                 tracker_id: 00000000-0000-0000-0000-000000000002,
                 data: "@@ -1 +1 @@\n-Hello World\n+Hello New World\n",
                 created_at: 2000-01-01 10:00:01.0 +00:00:00,
+                note: None,
+                acknowledgment: None,
+                diff_stats: Some(
+                    WebPageDataRevisionDiffStats {
+                        added_lines: 1,
+                        removed_lines: 1,
+                        changed_bytes: 26,
+                    },
+                ),
+                assertions_passed: None,
             },
         ]
         "###);
@@ -94,6 +246,75 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn correctly_calculates_web_page_content_diff_for_cookies() -> anyhow::Result<()> {
+        let revision_data = |set_cookie: &str| {
+            serde_json::to_string(&WebPageContentWithCookies::new(
+                "\"Hello World\"",
+                &[set_cookie.to_string()],
+                WebPageContentCookiesCapture::Redacted,
+                10,
+            ))
+        };
+
+        let revisions = vec![
+            WebPageDataRevision::<WebPageContentTrackerTag> {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                data: revision_data("session=abc; Secure; HttpOnly; SameSite=Lax")?,
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            },
+            WebPageDataRevision::<WebPageContentTrackerTag> {
+                id: uuid!("00000000-0000-0000-0000-000000000002"),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                data: revision_data("session=def; Secure; HttpOnly; SameSite=None")?,
+                created_at: OffsetDateTime::from_unix_timestamp(946720801)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            },
+        ];
+
+        let diff =
+            web_page_content_revisions_diff(revisions, WebPageContentDiffAlgorithm::Lines, None)?;
+        assert_eq!(diff.len(), 2);
+        assert_eq!(
+            diff[1].data,
+            [
+                "@@ -1,11 +1,11 @@",
+                " {",
+                "   \"content\": \"Hello World\",",
+                "   \"cookies\": [",
+                "     {",
+                "       \"httpOnly\": true,",
+                "       \"name\": \"session\",",
+                "-      \"sameSite\": \"Lax\",",
+                "+      \"sameSite\": \"None\",",
+                "       \"secure\": true",
+                "     }",
+                "   ]",
+                " }",
+                "",
+            ]
+            .join("\n")
+        );
+        assert_eq!(
+            diff[1].diff_stats,
+            Some(WebPageDataRevisionDiffStats {
+                added_lines: 1,
+                removed_lines: 1,
+                changed_bytes: 51,
+            })
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn correctly_calculates_web_page_content_diff_for_json() -> anyhow::Result<()> {
         let revisions = vec![WebPageDataRevision::<WebPageContentTrackerTag> {
@@ -101,9 +322,14 @@ mod tests {
             tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
             data: json!({ "property": "one", "secondProperty": "two" }).to_string(),
             created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            note: None,
+            acknowledgment: None,
+            diff_stats: None,
+            assertions_passed: None,
         }];
 
-        let diff = web_page_content_revisions_diff(revisions)?;
+        let diff =
+            web_page_content_revisions_diff(revisions, WebPageContentDiffAlgorithm::Lines, None)?;
         assert_debug_snapshot!(diff, @r###"
         [
             WebPageDataRevision {
@@ -111,6 +337,10 @@ mod tests {
                 tracker_id: 00000000-0000-0000-0000-000000000002,
                 data: "{\"property\":\"one\",\"secondProperty\":\"two\"}",
                 created_at: 2000-01-01 10:00:00.0 +00:00:00,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
             },
         ]
         "###);
@@ -121,12 +351,20 @@ mod tests {
                 tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
                 data: json!({ "property": "one", "secondProperty": "two" }).to_string(),
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
             },
             WebPageDataRevision::<WebPageContentTrackerTag> {
                 id: uuid!("00000000-0000-0000-0000-000000000002"),
                 tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
                 data: json!({ "property": "one" }).to_string(),
                 created_at: OffsetDateTime::from_unix_timestamp(946720801)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
             },
             WebPageDataRevision::<WebPageContentTrackerTag> {
                 id: uuid!("00000000-0000-0000-0000-000000000003"),
@@ -135,10 +373,15 @@ mod tests {
                     json!({ "property": "one", "secondProperty": "two", "thirdProperty": "three" })
                         .to_string(),
                 created_at: OffsetDateTime::from_unix_timestamp(946720802)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
             },
         ];
 
-        let diff = web_page_content_revisions_diff(revisions)?;
+        let diff =
+            web_page_content_revisions_diff(revisions, WebPageContentDiffAlgorithm::Lines, None)?;
         assert_debug_snapshot!(diff, @r###"
         [
             WebPageDataRevision {
@@ -146,22 +389,240 @@ mod tests {
                 tracker_id: 00000000-0000-0000-0000-000000000002,
                 data: "{\"property\":\"one\",\"secondProperty\":\"two\"}",
                 created_at: 2000-01-01 10:00:00.0 +00:00:00,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
             },
             WebPageDataRevision {
                 id: 00000000-0000-0000-0000-000000000002,
                 tracker_id: 00000000-0000-0000-0000-000000000002,
                 data: "@@ -1,4 +1,3 @@\n {\n-  \"property\": \"one\",\n-  \"secondProperty\": \"two\"\n+  \"property\": \"one\"\n }\n",
                 created_at: 2000-01-01 10:00:01.0 +00:00:00,
+                note: None,
+                acknowledgment: None,
+                diff_stats: Some(
+                    WebPageDataRevisionDiffStats {
+                        added_lines: 1,
+                        removed_lines: 2,
+                        changed_bytes: 67,
+                    },
+                ),
+                assertions_passed: None,
             },
             WebPageDataRevision {
                 id: 00000000-0000-0000-0000-000000000003,
                 tracker_id: 00000000-0000-0000-0000-000000000002,
                 data: "@@ -1,3 +1,5 @@\n {\n-  \"property\": \"one\"\n+  \"property\": \"one\",\n+  \"secondProperty\": \"two\",\n+  \"thirdProperty\": \"three\"\n }\n",
                 created_at: 2000-01-01 10:00:02.0 +00:00:00,
+                note: None,
+                acknowledgment: None,
+                diff_stats: Some(
+                    WebPageDataRevisionDiffStats {
+                        added_lines: 3,
+                        removed_lines: 1,
+                        changed_bytes: 95,
+                    },
+                ),
+                assertions_passed: None,
             },
         ]
         "###);
 
         Ok(())
     }
+
+    #[test]
+    fn correctly_calculates_web_page_content_dom_diff() -> anyhow::Result<()> {
+        let revisions = vec![
+            WebPageDataRevision::<WebPageContentTrackerTag> {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                data: json!("<ul><li>One</li><li>Two</li></ul>").to_string(),
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            },
+            WebPageDataRevision::<WebPageContentTrackerTag> {
+                id: uuid!("00000000-0000-0000-0000-000000000002"),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                data: json!("<ul><li>One</li><li class=\"new\">Three</li></ul>").to_string(),
+                created_at: OffsetDateTime::from_unix_timestamp(946720801)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            },
+            // Falls back to the line diff if content isn't HTML.
+            WebPageDataRevision::<WebPageContentTrackerTag> {
+                id: uuid!("00000000-0000-0000-0000-000000000003"),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                data: json!("One Three").to_string(),
+                created_at: OffsetDateTime::from_unix_timestamp(946720802)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            },
+        ];
+
+        let diff =
+            web_page_content_revisions_diff(revisions, WebPageContentDiffAlgorithm::Dom, None)?;
+        assert_debug_snapshot!(diff, @r###"
+        [
+            WebPageDataRevision {
+                id: 00000000-0000-0000-0000-000000000001,
+                tracker_id: 00000000-0000-0000-0000-000000000002,
+                data: "\"<ul><li>One</li><li>Two</li></ul>\"",
+                created_at: 2000-01-01 10:00:00.0 +00:00:00,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            },
+            WebPageDataRevision {
+                id: 00000000-0000-0000-0000-000000000002,
+                tracker_id: 00000000-0000-0000-0000-000000000002,
+                data: "[{\"type\":\"changed\",\"path\":\"/ul[1]/li[2]\",\"before\":\"<li>\",\"after\":\"<li class=\\\"new\\\">\"},{\"type\":\"changed\",\"path\":\"/ul[1]/li[2]/text()[1]\",\"before\":\"Two\",\"after\":\"Three\"}]",
+                created_at: 2000-01-01 10:00:01.0 +00:00:00,
+                note: None,
+                acknowledgment: None,
+                diff_stats: Some(
+                    WebPageDataRevisionDiffStats {
+                        added_lines: 1,
+                        removed_lines: 1,
+                        changed_bytes: 80,
+                    },
+                ),
+                assertions_passed: None,
+            },
+            WebPageDataRevision {
+                id: 00000000-0000-0000-0000-000000000003,
+                tracker_id: 00000000-0000-0000-0000-000000000002,
+                data: "@@ -1 +1 @@\n-<ul><li>One</li><li class=\"new\">Three</li></ul>\n+One Three\n",
+                created_at: 2000-01-01 10:00:02.0 +00:00:00,
+                note: None,
+                acknowledgment: None,
+                diff_stats: Some(
+                    WebPageDataRevisionDiffStats {
+                        added_lines: 1,
+                        removed_lines: 1,
+                        changed_bytes: 56,
+                    },
+                ),
+                assertions_passed: None,
+            },
+        ]
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncates_large_web_page_content_diff() -> anyhow::Result<()> {
+        let revision = |id: u128, data: String| -> anyhow::Result<_> {
+            Ok(WebPageDataRevision::<WebPageContentTrackerTag> {
+                id: uuid::Uuid::from_u128(id),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                data,
+                created_at: OffsetDateTime::from_unix_timestamp(946720800 + id as i64)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            })
+        };
+        let lines = |prefix: &str| {
+            (0..1000)
+                .map(|index| format!("{prefix} {index}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let revisions = vec![
+            revision(1, serde_json::to_string(&lines("old"))?)?,
+            revision(2, serde_json::to_string(&lines("new"))?)?,
+        ];
+
+        // Full diff is returned if the maximum size isn't exceeded.
+        let diff = web_page_content_revisions_diff(
+            revisions.clone(),
+            WebPageContentDiffAlgorithm::Lines,
+            Some(100_000),
+        )?;
+        assert_eq!(diff[1].data.lines().count(), 2001);
+
+        let diff = web_page_content_revisions_diff(
+            revisions,
+            WebPageContentDiffAlgorithm::Lines,
+            Some(100),
+        )?;
+        assert_eq!(diff.len(), 2);
+        assert_eq!(
+            diff[1].data,
+            format!(
+                "@@ -1,1000 +1,1000 @@\n{}... diff truncated, 1989 more changes",
+                (0..=10)
+                    .map(|index| format!("-old {index}\n"))
+                    .collect::<String>()
+            )
+        );
+        // Stats still describe the full diff.
+        assert_eq!(
+            diff[1].diff_stats,
+            Some(WebPageDataRevisionDiffStats {
+                added_lines: 1000,
+                removed_lines: 1000,
+                changed_bytes: 15778,
+            })
+        );
+
+        // DOM diff is truncated by changes to remain valid JSON.
+        let list = |prefix: &str| {
+            json!(format!(
+                "<ul>{}</ul>",
+                (0..100)
+                    .map(|index| format!("<li>{prefix} {index}</li>"))
+                    .collect::<String>()
+            ))
+            .to_string()
+        };
+        let revisions = vec![revision(1, list("old"))?, revision(2, list("new"))?];
+        let diff = web_page_content_revisions_diff(
+            revisions,
+            WebPageContentDiffAlgorithm::Dom,
+            Some(300),
+        )?;
+        let changes = serde_json::from_str::<Vec<serde_json::Value>>(&diff[1].data)?;
+        assert_eq!(changes.len(), 4);
+        assert_eq!(
+            changes[0],
+            json!({ "type": "changed", "path": "/ul[1]/li[1]/text()[1]", "before": "old 0", "after": "new 0" })
+        );
+        assert_eq!(
+            changes[3],
+            json!({ "type": "truncated", "remainingChanges": 97 })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncates_diff_at_line_boundary() {
+        let summary = "Added Disallow: /one\nAdded Disallow: /two\nRemoved Disallow: /three";
+        assert_eq!(web_page_content_truncate_diff(summary, 100), summary);
+        assert_eq!(
+            web_page_content_truncate_diff(summary, 30),
+            "Added Disallow: /one\n... diff truncated, 2 more changes"
+        );
+
+        // Context lines and hunk headers aren't counted as changes.
+        let diff = "@@ -1,3 +1,3 @@\n-one\n+two\n three\n@@ -10 +10 @@\n-ten\n";
+        assert_eq!(
+            web_page_content_truncate_diff(diff, 20),
+            "@@ -1,3 +1,3 @@\n... diff truncated, 3 more changes"
+        );
+    }
 }
@@ -0,0 +1,362 @@
+use super::web_page_content_revisions_diff::web_page_content_revision_pretty_print;
+use crate::utils::web_scraping::{
+    WebPageContentNoiseAnalysis, WebPageContentNoiseRegion, WebPageContentTrackerTag,
+    WebPageDataRevision,
+};
+use regex::Regex;
+use similar::{ChangeTag, TextDiff};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+/// Minimal share of the revisions that should change the region for it to be considered volatile.
+const VOLATILE_REGION_FREQUENCY: f64 = 0.8;
+
+/// Minimal number of revisions required to tell volatile regions from the regular changes.
+const VOLATILE_REGION_MIN_REVISIONS: usize = 3;
+
+/// Accumulated changes of a single content region.
+#[derive(Default)]
+struct RegionChanges {
+    /// Number of revisions that changed the region.
+    revisions: usize,
+    /// Total number of added and removed lines within the region.
+    lines: usize,
+    /// All non-empty added and removed lines within the region.
+    values: Vec<String>,
+}
+
+/// Returns the longest common prefix of two strings.
+fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len = a
+        .chars()
+        .zip(b.chars())
+        .take_while(|(a_char, b_char)| a_char == b_char)
+        .map(|(a_char, _)| a_char.len_utf8())
+        .sum::<usize>();
+    &a[..len]
+}
+
+/// Returns the longest common suffix of two strings.
+fn common_suffix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len = a
+        .chars()
+        .rev()
+        .zip(b.chars().rev())
+        .take_while(|(a_char, b_char)| a_char == b_char)
+        .map(|(a_char, _)| a_char.len_utf8())
+        .sum::<usize>();
+    &a[a.len() - len..]
+}
+
+/// Checks whether the character separates the stable and changing parts of the value.
+fn is_value_boundary(char: char) -> bool {
+    char.is_whitespace() || matches!(char, '<' | '>' | '"' | '\'' | '=')
+}
+
+/// Infers the CSS selector of the element if all values belong to the element with the same ID.
+fn suggest_selector(values: &[String]) -> anyhow::Result<Option<String>> {
+    let id_regex = Regex::new(r#"\bid\s*=\s*["']([^"']+)["']"#)?;
+    let mut ids = values.iter().map(|value| {
+        id_regex
+            .captures(value)
+            .and_then(|captures| captures.get(1))
+            .map(|id| id.as_str())
+    });
+
+    let Some(Some(id)) = ids.next() else {
+        return Ok(None);
+    };
+
+    Ok(if ids.all(|other_id| other_id == Some(id)) {
+        Some(format!("#{id}"))
+    } else {
+        None
+    })
+}
+
+/// Infers the regular expression that matches all values, keeping the parts that never change.
+fn suggest_ignore_pattern(values: &[String]) -> Option<String> {
+    let (first_value, other_values) = values.split_first()?;
+    let (prefix, suffix) = other_values.iter().fold(
+        (first_value.as_str(), first_value.as_str()),
+        |(prefix, suffix), value| (common_prefix(prefix, value), common_suffix(suffix, value)),
+    );
+
+    // If values never change, the region was just moved and there is nothing to ignore.
+    if prefix.len() == first_value.len() {
+        return None;
+    }
+
+    // Don't keep characters that are glued to the changing part (e.g. `10:0` from `10:01` and
+    // `10:02`), since they are likely to change as well.
+    let prefix = prefix
+        .char_indices()
+        .rev()
+        .find(|(_, char)| is_value_boundary(*char))
+        .map(|(index, char)| &prefix[..index + char.len_utf8()])
+        .unwrap_or_default();
+    let suffix = suffix
+        .find(is_value_boundary)
+        .map(|index| &suffix[index..])
+        .unwrap_or_default();
+
+    let min_value_len = values
+        .iter()
+        .map(|value| value.len())
+        .min()
+        .unwrap_or_default();
+    let (prefix, suffix) = if prefix.len() + suffix.len() > min_value_len {
+        (prefix, "")
+    } else {
+        (prefix, suffix)
+    };
+
+    // Pattern without any meaningful stable part would match almost anything.
+    if !prefix
+        .chars()
+        .chain(suffix.chars())
+        .any(char::is_alphanumeric)
+    {
+        None
+    } else {
+        Some(format!(
+            "^{}.*{}$",
+            regex::escape(prefix),
+            regex::escape(suffix)
+        ))
+    }
+}
+
+/// Analyzes how often different regions of the web page content change across the revisions
+/// (from the oldest to the newest) to find volatile regions that are likely to be a noise.
+pub fn web_page_content_revisions_noise(
+    revisions: &[WebPageDataRevision<WebPageContentTrackerTag>],
+) -> anyhow::Result<WebPageContentNoiseAnalysis> {
+    let values = revisions
+        .iter()
+        .map(|revision| web_page_content_revision_pretty_print(&revision.data))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    // Every region is identified by the closest non-empty unchanged line that precedes it.
+    let mut regions: Vec<(Option<String>, RegionChanges)> = vec![];
+    let mut regions_indices: HashMap<Option<String>, usize> = HashMap::new();
+    for values_pair in values.windows(2) {
+        let text_diff = TextDiff::from_lines(&values_pair[0], &values_pair[1]);
+
+        let mut changed_regions = HashSet::new();
+        let mut anchor: Option<String> = None;
+        for change in text_diff.iter_all_changes() {
+            let value = change.value().trim();
+            if change.tag() == ChangeTag::Equal {
+                if !value.is_empty() {
+                    anchor = Some(value.to_string());
+                }
+                continue;
+            }
+
+            let region_index = *regions_indices.entry(anchor.clone()).or_insert_with(|| {
+                regions.push((anchor.clone(), RegionChanges::default()));
+                regions.len() - 1
+            });
+
+            let (_, region_changes) = &mut regions[region_index];
+            if changed_regions.insert(region_index) {
+                region_changes.revisions += 1;
+            }
+            region_changes.lines += 1;
+            if !value.is_empty() {
+                region_changes.values.push(value.to_string());
+            }
+        }
+    }
+
+    let transitions = revisions.len().saturating_sub(1).max(1);
+    let changed_lines = regions
+        .iter()
+        .map(|(_, region_changes)| region_changes.lines)
+        .sum::<usize>();
+
+    let mut noisy_lines = 0;
+    let mut noise_regions = Vec::with_capacity(regions.len());
+    for (anchor, region_changes) in regions {
+        let frequency = region_changes.revisions as f64 / transitions as f64;
+        let volatile = revisions.len() >= VOLATILE_REGION_MIN_REVISIONS
+            && frequency >= VOLATILE_REGION_FREQUENCY;
+        let (suggested_selector, suggested_ignore_pattern) = if volatile {
+            noisy_lines += region_changes.lines;
+            (
+                suggest_selector(&region_changes.values)?,
+                suggest_ignore_pattern(&region_changes.values),
+            )
+        } else {
+            (None, None)
+        };
+
+        noise_regions.push(WebPageContentNoiseRegion {
+            anchor,
+            changes: region_changes.revisions,
+            frequency,
+            volatile,
+            suggested_selector,
+            suggested_ignore_pattern,
+        });
+    }
+
+    noise_regions.sort_by_key(|region| Reverse(region.changes));
+
+    Ok(WebPageContentNoiseAnalysis {
+        revisions: revisions.len(),
+        noise_ratio: if changed_lines > 0 {
+            noisy_lines as f64 / changed_lines as f64
+        } else {
+            0.0
+        },
+        regions: noise_regions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{suggest_ignore_pattern, suggest_selector};
+    use crate::utils::web_scraping::{
+        web_page_content_revisions_noise, WebPageContentNoiseAnalysis, WebPageContentNoiseRegion,
+        WebPageContentTrackerTag, WebPageDataRevision,
+    };
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    fn mock_revisions(
+        contents: Vec<String>,
+    ) -> anyhow::Result<Vec<WebPageDataRevision<WebPageContentTrackerTag>>> {
+        contents
+            .into_iter()
+            .enumerate()
+            .map(|(index, content)| {
+                Ok(WebPageDataRevision {
+                    id: Uuid::from_u128(index as u128 + 1),
+                    tracker_id: Uuid::from_u128(0x10),
+                    data: serde_json::to_string(&content)?,
+                    created_at: OffsetDateTime::from_unix_timestamp(
+                        946720800 + 100 * index as i64,
+                    )?,
+                    note: None,
+                    acknowledgment: None,
+                    diff_stats: None,
+                    assertions_passed: None,
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn identifies_volatile_regions() -> anyhow::Result<()> {
+        let revisions = mock_revisions(
+            (1..=5)
+                .map(|index| {
+                    format!(
+                        "<h1>Title</h1>\n<span id=\"time\">10:0{index}</span>\n<div>Stable</div>\n<p>{}</p>\n",
+                        if index < 4 { "Content" } else { "New content" }
+                    )
+                })
+                .collect(),
+        )?;
+
+        assert_eq!(
+            web_page_content_revisions_noise(&revisions)?,
+            WebPageContentNoiseAnalysis {
+                revisions: 5,
+                noise_ratio: 0.8,
+                regions: vec![
+                    WebPageContentNoiseRegion {
+                        anchor: Some("<h1>Title</h1>".to_string()),
+                        changes: 4,
+                        frequency: 1.0,
+                        volatile: true,
+                        suggested_selector: Some("#time".to_string()),
+                        suggested_ignore_pattern: Some("^<span id=\"time\">.*</span>$".to_string()),
+                    },
+                    WebPageContentNoiseRegion {
+                        anchor: Some("<div>Stable</div>".to_string()),
+                        changes: 1,
+                        frequency: 0.25,
+                        volatile: false,
+                        suggested_selector: None,
+                        suggested_ignore_pattern: None,
+                    }
+                ],
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn does_not_report_volatile_regions_for_short_history() -> anyhow::Result<()> {
+        let revisions = mock_revisions(vec![
+            "<h1>Title</h1>\nOne\n".to_string(),
+            "<h1>Title</h1>\nTwo\n".to_string(),
+        ])?;
+
+        assert_eq!(
+            web_page_content_revisions_noise(&revisions)?,
+            WebPageContentNoiseAnalysis {
+                revisions: 2,
+                noise_ratio: 0.0,
+                regions: vec![WebPageContentNoiseRegion {
+                    anchor: Some("<h1>Title</h1>".to_string()),
+                    changes: 1,
+                    frequency: 1.0,
+                    volatile: false,
+                    suggested_selector: None,
+                    suggested_ignore_pattern: None,
+                }],
+            }
+        );
+
+        let revisions = mock_revisions(vec!["<h1>Title</h1>".to_string()])?;
+        assert_eq!(
+            web_page_content_revisions_noise(&revisions)?,
+            WebPageContentNoiseAnalysis {
+                revisions: 1,
+                noise_ratio: 0.0,
+                regions: vec![],
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_suggests_selectors_and_ignore_patterns() -> anyhow::Result<()> {
+        let values = vec![
+            "<b id='visitors'>Visitors: 100</b>".to_string(),
+            "<b id='visitors'>Visitors: 155</b>".to_string(),
+        ];
+        assert_eq!(suggest_selector(&values)?, Some("#visitors".to_string()));
+        assert_eq!(
+            suggest_ignore_pattern(&values),
+            Some("^<b id='visitors'>Visitors: .*</b>$".to_string())
+        );
+
+        let values = vec![
+            "<b id='one'>Updated at 10:01</b>".to_string(),
+            "<i>Updated at 10:02</i>".to_string(),
+        ];
+        assert_eq!(suggest_selector(&values)?, None);
+        assert_eq!(suggest_ignore_pattern(&values), None);
+
+        let values = vec!["token: abc.1".to_string(), "token: xyz.1".to_string()];
+        assert_eq!(suggest_selector(&values)?, None);
+        assert_eq!(
+            suggest_ignore_pattern(&values),
+            Some("^token: .*$".to_string())
+        );
+
+        let values = vec!["Same".to_string(), "Same".to_string()];
+        assert_eq!(suggest_ignore_pattern(&values), None);
+
+        Ok(())
+    }
+}
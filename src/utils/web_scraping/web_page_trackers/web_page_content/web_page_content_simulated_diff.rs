@@ -0,0 +1,60 @@
+use crate::{
+    notifications::NotificationPreview, utils::web_scraping::WebPageDataRevisionDiffStats,
+};
+use serde::Serialize;
+
+/// Result of the diff simulation between two arbitrary web page contents, computed the same way as
+/// the diff between the web page content tracker revisions.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageContentSimulatedDiff {
+    /// Diff between the contents calculated with the requested algorithm.
+    pub diff: String,
+    /// Stats of the line diff between the contents.
+    pub diff_stats: WebPageDataRevisionDiffStats,
+    /// Notification that the content tracker would send for the current content.
+    pub notification: NotificationPreview,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageContentSimulatedDiff;
+    use crate::{
+        notifications::NotificationPreview, utils::web_scraping::WebPageDataRevisionDiffStats,
+    };
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageContentSimulatedDiff {
+            diff: "@@ -1 +1 @@\n-Hello World\n+Hello New World\n".to_string(),
+            diff_stats: WebPageDataRevisionDiffStats {
+                added_lines: 1,
+                removed_lines: 1,
+                changed_bytes: 26,
+            },
+            notification: NotificationPreview {
+                subject: "subject".to_string(),
+                text: "text".to_string(),
+                html: None,
+                webhook_payload: None,
+                webhook_headers: None,
+            },
+        }, @r###"
+        {
+          "diff": "@@ -1 +1 @@\n-Hello World\n+Hello New World\n",
+          "diffStats": {
+            "addedLines": 1,
+            "removedLines": 1,
+            "changedBytes": 26
+          },
+          "notification": {
+            "subject": "subject",
+            "text": "text"
+          }
+        }
+        "###);
+
+        Ok(())
+    }
+}
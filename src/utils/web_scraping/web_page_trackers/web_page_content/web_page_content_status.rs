@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DurationMilliSeconds};
+use std::time::Duration;
+
+/// Represents web page content tracked by the content tracker in the status-only mode.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageContentStatus {
+    /// HTTP status code of the web page response.
+    pub status: u16,
+    /// Time it took to receive the web page response headers.
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub latency: Duration,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageContentStatus;
+    use insta::assert_json_snapshot;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageContentStatus {
+            status: 200,
+            latency: Duration::from_millis(123),
+        }, @r###"
+        {
+          "status": 200,
+          "latency": 123
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageContentStatus>(r#"{ "status": 500, "latency": 321 }"#)?,
+            WebPageContentStatus {
+                status: 500,
+                latency: Duration::from_millis(321),
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use crate::utils::web_scraping::WebPageContentStatus;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Represents web page content tracked by the content tracker in the status-only mode with
+/// additional URLs, the status of every monitored URL is tracked separately.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageContentStatuses {
+    /// Status of every monitored URL, keyed by the URL.
+    pub statuses: BTreeMap<String, WebPageContentStatus>,
+}
+
+impl WebPageContentStatuses {
+    /// Returns a human-readable summary of the URLs whose HTTP status has changed compared to the
+    /// previous revision, one URL per line, e.g. `https://secutils.dev/: 200 → 503`. Latency
+    /// changes are ignored. Returns `None` if status of none of the URLs has changed.
+    pub fn describe_changes(&self, previous: &Self) -> Option<String> {
+        let changes = self
+            .statuses
+            .keys()
+            .chain(previous.statuses.keys())
+            .unique()
+            .sorted()
+            .filter_map(|url| {
+                match (
+                    previous.statuses.get(url).map(|status| status.status),
+                    self.statuses.get(url).map(|status| status.status),
+                ) {
+                    (Some(previous_status), Some(status)) if previous_status != status => {
+                        Some(format!("{url}: {previous_status} → {status}"))
+                    }
+                    (None, Some(status)) => Some(format!("{url}: {status} (new)")),
+                    (Some(_), None) => Some(format!("{url}: removed")),
+                    _ => None,
+                }
+            })
+            .collect_vec();
+
+        if changes.is_empty() {
+            None
+        } else {
+            Some(changes.join("\n"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageContentStatuses;
+    use crate::utils::web_scraping::WebPageContentStatus;
+    use insta::assert_json_snapshot;
+    use std::time::Duration;
+
+    fn mock_statuses(statuses: &[(&str, u16, u64)]) -> WebPageContentStatuses {
+        WebPageContentStatuses {
+            statuses: statuses
+                .iter()
+                .map(|(url, status, latency)| {
+                    (
+                        url.to_string(),
+                        WebPageContentStatus {
+                            status: *status,
+                            latency: Duration::from_millis(*latency),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(mock_statuses(&[
+            ("https://secutils.dev/", 200, 123),
+            ("https://secutils.dev/api", 503, 321),
+        ]), @r###"
+        {
+          "statuses": {
+            "https://secutils.dev/": {
+              "status": 200,
+              "latency": 123
+            },
+            "https://secutils.dev/api": {
+              "status": 503,
+              "latency": 321
+            }
+          }
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn describes_changes() {
+        let previous = mock_statuses(&[
+            ("https://secutils.dev/", 200, 123),
+            ("https://secutils.dev/api", 200, 321),
+            ("https://secutils.dev/docs", 200, 100),
+        ]);
+
+        // Latency changes are ignored.
+        assert_eq!(
+            mock_statuses(&[
+                ("https://secutils.dev/", 200, 456),
+                ("https://secutils.dev/api", 200, 654),
+                ("https://secutils.dev/docs", 200, 200),
+            ])
+            .describe_changes(&previous),
+            None
+        );
+
+        assert_eq!(
+            mock_statuses(&[
+                ("https://secutils.dev/", 200, 123),
+                ("https://secutils.dev/api", 503, 321),
+                ("https://secutils.dev/blog", 404, 100),
+            ])
+            .describe_changes(&previous)
+            .as_deref(),
+            Some(
+                "https://secutils.dev/api: 200 → 503\nhttps://secutils.dev/blog: 404 (new)\nhttps://secutils.dev/docs: removed"
+            )
+        );
+    }
+}
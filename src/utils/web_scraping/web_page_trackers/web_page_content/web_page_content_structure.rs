@@ -0,0 +1,217 @@
+use crate::utils::web_scraping::{
+    api_ext::web_page_sitemap_parser::WebPageSitemapParser, WebPageContentFormat,
+};
+use bytes::Bytes;
+use itertools::Itertools;
+use std::collections::BTreeSet;
+
+/// Represents web page content parsed according to its structured format. Content is represented
+/// as an unordered set of normalized entries (e.g. `robots.txt` rules or sitemap URLs), so that
+/// reordered, but otherwise equivalent content is considered the same.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebPageContentStructure {
+    entries: BTreeSet<String>,
+}
+
+impl WebPageContentStructure {
+    /// Parses web page content tracker revision data according to the specified format. Returns
+    /// `None` if the data cannot be parsed, and the content should be compared as plain text.
+    pub fn parse(format: WebPageContentFormat, data: &str) -> Option<Self> {
+        // Content tracker revision data is a JSON string with the content extracted from the page.
+        let content = serde_json::from_str::<String>(data).ok()?;
+        let entries = match format {
+            WebPageContentFormat::RobotsTxt => parse_robots_txt(&content)?,
+            WebPageContentFormat::Sitemap => parse_sitemap(&content)?,
+        };
+
+        Some(Self { entries })
+    }
+
+    /// Returns a human-readable summary of the entries that were added or removed compared to the
+    /// previous content, one entry per line, e.g. `Added Disallow: /admin (User-agent: *)`.
+    /// Returns `None` if content is semantically the same.
+    pub fn describe_changes(&self, previous: &Self) -> Option<String> {
+        let changes = self
+            .entries
+            .difference(&previous.entries)
+            .map(|entry| format!("Added {entry}"))
+            .chain(
+                previous
+                    .entries
+                    .difference(&self.entries)
+                    .map(|entry| format!("Removed {entry}")),
+            )
+            .collect_vec();
+
+        if changes.is_empty() {
+            None
+        } else {
+            Some(changes.join("\n"))
+        }
+    }
+}
+
+/// Parses `robots.txt` rules (RFC 9309). Every rule is bound to the user agents of the group it
+/// belongs to, sitemap references don't belong to any group.
+fn parse_robots_txt(content: &str) -> Option<BTreeSet<String>> {
+    let mut entries = BTreeSet::new();
+    let mut user_agents = vec![];
+    let mut group_has_rules = false;
+    for line in content.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        // Any line that isn't a `key: value` directive means that content isn't `robots.txt`.
+        let (key, value) = line.split_once(':')?;
+        let (key, value) = (key.trim(), value.trim());
+        if key.is_empty()
+            || !key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return None;
+        }
+
+        let directive = match key.to_ascii_lowercase().as_str() {
+            "user-agent" => {
+                // User agents listed after the rules start a new group.
+                if group_has_rules {
+                    user_agents.clear();
+                    group_has_rules = false;
+                }
+                user_agents.push(value.to_string());
+                continue;
+            }
+            "sitemap" => {
+                entries.insert(format!("Sitemap: {value}"));
+                continue;
+            }
+            "allow" => "Allow",
+            "disallow" => "Disallow",
+            "crawl-delay" => "Crawl-delay",
+            _ => key,
+        };
+
+        group_has_rules = true;
+        if user_agents.is_empty() {
+            entries.insert(format!("{directive}: {value}"));
+        } else {
+            for user_agent in &user_agents {
+                entries.insert(format!("{directive}: {value} (User-agent: {user_agent})"));
+            }
+        }
+    }
+
+    Some(entries)
+}
+
+/// Parses URLs listed in the XML sitemap.
+fn parse_sitemap(content: &str) -> Option<BTreeSet<String>> {
+    if !content.to_ascii_lowercase().contains("<urlset") {
+        return None;
+    }
+
+    let urls = WebPageSitemapParser::parse(&Bytes::copy_from_slice(content.as_bytes())).ok()?;
+    Some(urls.into_iter().map(|url| format!("URL: {url}")).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageContentStructure;
+    use crate::utils::web_scraping::WebPageContentFormat;
+
+    fn parse_robots_txt(content: &str) -> anyhow::Result<Option<WebPageContentStructure>> {
+        Ok(WebPageContentStructure::parse(
+            WebPageContentFormat::RobotsTxt,
+            &serde_json::to_string(content)?,
+        ))
+    }
+
+    #[test]
+    fn ignores_reordered_robots_txt_rules() -> anyhow::Result<()> {
+        let previous = parse_robots_txt(
+            "User-agent: *\nDisallow: /private\nAllow: /public\n\nUser-agent: googlebot\nDisallow: /search\n\nSitemap: https://secutils.dev/sitemap.xml",
+        )?
+        .unwrap();
+        let current = parse_robots_txt(
+            "# Crawling rules\nSitemap: https://secutils.dev/sitemap.xml\n\nuser-agent: googlebot\ndisallow: /search\n\nUser-agent: *\nAllow: /public # public pages\nDisallow: /private",
+        )?
+        .unwrap();
+
+        assert_eq!(current, previous);
+        assert_eq!(current.describe_changes(&previous), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn describes_robots_txt_changes() -> anyhow::Result<()> {
+        let previous = parse_robots_txt(
+            "User-agent: *\nUser-agent: bingbot\nDisallow: /private\n\nUser-agent: googlebot\nDisallow: /search",
+        )?
+        .unwrap();
+        let current = parse_robots_txt(
+            "User-agent: *\nUser-agent: bingbot\nDisallow: /private\nDisallow: /admin\n\nUser-agent: googlebot\nCrawl-delay: 10",
+        )?
+        .unwrap();
+
+        assert_eq!(
+            current.describe_changes(&previous).as_deref(),
+            Some(
+                "Added Crawl-delay: 10 (User-agent: googlebot)\nAdded Disallow: /admin (User-agent: *)\nAdded Disallow: /admin (User-agent: bingbot)\nRemoved Disallow: /search (User-agent: googlebot)"
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn describes_sitemap_changes() -> anyhow::Result<()> {
+        let parse_sitemap = |urls: &[&str]| -> anyhow::Result<_> {
+            Ok(WebPageContentStructure::parse(
+                WebPageContentFormat::Sitemap,
+                &serde_json::to_string(&format!(
+                    r#"<?xml version="1.0" encoding="UTF-8"?><urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">{}</urlset>"#,
+                    urls.iter()
+                        .map(|url| format!("<url><loc>{url}</loc></url>"))
+                        .collect::<String>()
+                ))?,
+            ))
+        };
+
+        let previous =
+            parse_sitemap(&["https://secutils.dev/", "https://secutils.dev/docs"])?.unwrap();
+        let reordered =
+            parse_sitemap(&["https://secutils.dev/docs", "https://secutils.dev/"])?.unwrap();
+        assert_eq!(reordered.describe_changes(&previous), None);
+
+        let current =
+            parse_sitemap(&["https://secutils.dev/", "https://secutils.dev/blog"])?.unwrap();
+        assert_eq!(
+            current.describe_changes(&previous).as_deref(),
+            Some("Added URL: https://secutils.dev/blog\nRemoved URL: https://secutils.dev/docs")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_unstructured_content() -> anyhow::Result<()> {
+        assert!(parse_robots_txt("<html><body>Not found</body></html>")?.is_none());
+        assert!(parse_robots_txt("Disallow /admin")?.is_none());
+        assert!(WebPageContentStructure::parse(
+            WebPageContentFormat::Sitemap,
+            &serde_json::to_string("User-agent: *")?
+        )
+        .is_none());
+        assert!(WebPageContentStructure::parse(
+            WebPageContentFormat::RobotsTxt,
+            r#"{"content":"User-agent: *"}"#
+        )
+        .is_none());
+
+        Ok(())
+    }
+}
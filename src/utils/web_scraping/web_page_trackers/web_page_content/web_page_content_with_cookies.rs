@@ -0,0 +1,158 @@
+use crate::utils::web_scraping::{WebPageContentCookiesCapture, WebPageCookie};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JSONValue;
+
+/// Represents web page content tracked by the content tracker that captures cookies set by the
+/// web page.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageContentWithCookies {
+    /// Web page content extracted by the web scraper.
+    pub content: JSONValue,
+    /// Cookies set by the web page, sorted by name.
+    pub cookies: Vec<WebPageCookie>,
+    /// Indicates whether some of the cookies were dropped since the web page sets more cookies
+    /// than the tracker can retain.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+}
+
+impl WebPageContentWithCookies {
+    /// Combines web page content (JSON string) with the cookies parsed from the `Set-Cookie`
+    /// header values. Cookie values are redacted if requested, and only up to `max_cookies` first
+    /// cookies (sorted by name) are retained.
+    pub fn new(
+        content: &str,
+        set_cookies: &[String],
+        capture: WebPageContentCookiesCapture,
+        max_cookies: usize,
+    ) -> Self {
+        let mut cookies = set_cookies
+            .iter()
+            .filter_map(|set_cookie| WebPageCookie::parse(set_cookie))
+            .map(|cookie| match capture {
+                WebPageContentCookiesCapture::Full => cookie,
+                WebPageContentCookiesCapture::Redacted => cookie.redact(),
+            })
+            .collect::<Vec<_>>();
+        cookies.sort_by(|cookie_a, cookie_b| cookie_a.name.cmp(&cookie_b.name));
+
+        let truncated = cookies.len() > max_cookies;
+        cookies.truncate(max_cookies);
+
+        Self {
+            content: serde_json::from_str(content)
+                .unwrap_or_else(|_| JSONValue::String(content.to_string())),
+            cookies,
+            truncated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageContentWithCookies;
+    use crate::utils::web_scraping::WebPageContentCookiesCapture;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn properly_combines_content_and_cookies() -> anyhow::Result<()> {
+        let set_cookies = vec![
+            "session=abc; Secure; HttpOnly; SameSite=Strict".to_string(),
+            "invalid".to_string(),
+            "analytics=def; Path=/".to_string(),
+        ];
+
+        assert_json_snapshot!(WebPageContentWithCookies::new(
+            r#""some content""#,
+            &set_cookies,
+            WebPageContentCookiesCapture::Full,
+            10
+        ), @r###"
+        {
+          "content": "some content",
+          "cookies": [
+            {
+              "name": "analytics",
+              "value": "def",
+              "path": "/",
+              "secure": false,
+              "httpOnly": false
+            },
+            {
+              "name": "session",
+              "value": "abc",
+              "secure": true,
+              "httpOnly": true,
+              "sameSite": "Strict"
+            }
+          ]
+        }
+        "###);
+
+        assert_json_snapshot!(WebPageContentWithCookies::new(
+            r#"{"title": "Title"}"#,
+            &set_cookies,
+            WebPageContentCookiesCapture::Redacted,
+            10
+        ), @r###"
+        {
+          "content": {
+            "title": "Title"
+          },
+          "cookies": [
+            {
+              "name": "analytics",
+              "path": "/",
+              "secure": false,
+              "httpOnly": false
+            },
+            {
+              "name": "session",
+              "secure": true,
+              "httpOnly": true,
+              "sameSite": "Strict"
+            }
+          ]
+        }
+        "###);
+
+        // Non-JSON content is captured as a string.
+        assert_eq!(
+            WebPageContentWithCookies::new(
+                "some content",
+                &[],
+                WebPageContentCookiesCapture::Full,
+                10
+            ),
+            WebPageContentWithCookies {
+                content: "some content".into(),
+                cookies: vec![],
+                truncated: false,
+            }
+        );
+
+        // Only the allowed number of cookies is retained.
+        assert_json_snapshot!(WebPageContentWithCookies::new(
+            r#""some content""#,
+            &set_cookies,
+            WebPageContentCookiesCapture::Redacted,
+            1
+        ), @r###"
+        {
+          "content": "some content",
+          "cookies": [
+            {
+              "name": "analytics",
+              "path": "/",
+              "secure": false,
+              "httpOnly": false
+            }
+          ],
+          "truncated": true
+        }
+        "###);
+
+        Ok(())
+    }
+}
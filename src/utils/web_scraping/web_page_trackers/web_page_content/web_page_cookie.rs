@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a cookie set by the web page via `Set-Cookie` response header. The `Expires`
+/// attribute isn't captured since it usually changes with every response.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageCookie {
+    /// Name of the cookie.
+    pub name: String,
+    /// Value of the cookie, `None` if the value is redacted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Value of the `Domain` attribute, if specified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// Value of the `Path` attribute, if specified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Value of the `Max-Age` attribute, if specified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_age: Option<String>,
+    /// Indicates whether the cookie has the `Secure` attribute.
+    pub secure: bool,
+    /// Indicates whether the cookie has the `HttpOnly` attribute.
+    pub http_only: bool,
+    /// Value of the `SameSite` attribute, if specified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub same_site: Option<String>,
+}
+
+impl WebPageCookie {
+    /// Parses the value of the `Set-Cookie` header. Returns `None` if the header value doesn't
+    /// contain a valid cookie name-value pair.
+    pub fn parse(set_cookie: &str) -> Option<Self> {
+        let mut parts = set_cookie.split(';');
+        let (name, value) = parts.next()?.split_once('=')?;
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut cookie = Self {
+            name: name.to_string(),
+            value: Some(value.trim().to_string()),
+            domain: None,
+            path: None,
+            max_age: None,
+            secure: false,
+            http_only: false,
+            same_site: None,
+        };
+
+        for attribute in parts {
+            let (attribute_name, attribute_value) = match attribute.split_once('=') {
+                Some((attribute_name, attribute_value)) => (
+                    attribute_name.trim(),
+                    Some(attribute_value.trim().to_string()),
+                ),
+                None => (attribute.trim(), None),
+            };
+
+            match attribute_name.to_ascii_lowercase().as_str() {
+                "domain" => cookie.domain = attribute_value,
+                "path" => cookie.path = attribute_value,
+                "max-age" => cookie.max_age = attribute_value,
+                "secure" => cookie.secure = true,
+                "httponly" => cookie.http_only = true,
+                "samesite" => cookie.same_site = attribute_value,
+                _ => {}
+            }
+        }
+
+        Some(cookie)
+    }
+
+    /// Removes the value of the cookie.
+    pub fn redact(self) -> Self {
+        Self {
+            value: None,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageCookie;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageCookie {
+            name: "session".to_string(),
+            value: None,
+            domain: None,
+            path: None,
+            max_age: None,
+            secure: false,
+            http_only: false,
+            same_site: None,
+        }, @r###"
+        {
+          "name": "session",
+          "secure": false,
+          "httpOnly": false
+        }
+        "###);
+
+        assert_json_snapshot!(WebPageCookie {
+            name: "session".to_string(),
+            value: Some("abc".to_string()),
+            domain: Some("secutils.dev".to_string()),
+            path: Some("/".to_string()),
+            max_age: Some("3600".to_string()),
+            secure: true,
+            http_only: true,
+            same_site: Some("Strict".to_string()),
+        }, @r###"
+        {
+          "name": "session",
+          "value": "abc",
+          "domain": "secutils.dev",
+          "path": "/",
+          "maxAge": "3600",
+          "secure": true,
+          "httpOnly": true,
+          "sameSite": "Strict"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_parse_set_cookie_header() -> anyhow::Result<()> {
+        assert_eq!(
+            WebPageCookie::parse("session=abc"),
+            Some(WebPageCookie {
+                name: "session".to_string(),
+                value: Some("abc".to_string()),
+                domain: None,
+                path: None,
+                max_age: None,
+                secure: false,
+                http_only: false,
+                same_site: None,
+            })
+        );
+
+        assert_eq!(
+            WebPageCookie::parse(
+                "session=abc; Domain=secutils.dev; Path=/; Expires=Wed, 21 Oct 2015 07:28:00 GMT; Max-Age=3600; secure; HttpOnly; SameSite=Lax"
+            ),
+            Some(WebPageCookie {
+                name: "session".to_string(),
+                value: Some("abc".to_string()),
+                domain: Some("secutils.dev".to_string()),
+                path: Some("/".to_string()),
+                max_age: Some("3600".to_string()),
+                secure: true,
+                http_only: true,
+                same_site: Some("Lax".to_string()),
+            })
+        );
+
+        assert_eq!(WebPageCookie::parse("session"), None);
+        assert_eq!(WebPageCookie::parse("=abc; Secure"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_redact_value() -> anyhow::Result<()> {
+        assert_eq!(
+            WebPageCookie::parse("session=abc; Secure").map(WebPageCookie::redact),
+            Some(WebPageCookie {
+                name: "session".to_string(),
+                value: None,
+                domain: None,
+                path: None,
+                max_age: None,
+                secure: true,
+                http_only: false,
+                same_site: None,
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+/// Defines well-known text files that web sites commonly serve at predefined locations, and that
+/// are worth tracking for changes (e.g. security contacts or crawling rules).
+#[allow(clippy::enum_variant_names)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WebPageWellKnownFile {
+    /// Security contacts and policies (RFC 9116), served at `/.well-known/security.txt`.
+    SecurityTxt,
+    /// Crawling rules for the web robots, served at `/robots.txt`.
+    RobotsTxt,
+    /// Information about the people behind the web site, served at `/humans.txt`.
+    HumansTxt,
+}
+
+impl WebPageWellKnownFile {
+    /// List of all supported well-known files.
+    pub const ALL: [WebPageWellKnownFile; 3] = [
+        WebPageWellKnownFile::SecurityTxt,
+        WebPageWellKnownFile::RobotsTxt,
+        WebPageWellKnownFile::HumansTxt,
+    ];
+
+    /// Returns an absolute path of the well-known file relative to the web site root.
+    pub fn path(&self) -> &'static str {
+        match self {
+            Self::SecurityTxt => "/.well-known/security.txt",
+            Self::RobotsTxt => "/robots.txt",
+            Self::HumansTxt => "/humans.txt",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageWellKnownFile;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageWellKnownFile::SecurityTxt, @r###""securityTxt""###);
+        assert_json_snapshot!(WebPageWellKnownFile::RobotsTxt, @r###""robotsTxt""###);
+        assert_json_snapshot!(WebPageWellKnownFile::HumansTxt, @r###""humansTxt""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageWellKnownFile>(r#""securityTxt""#)?,
+            WebPageWellKnownFile::SecurityTxt
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageWellKnownFile>(r#""robotsTxt""#)?,
+            WebPageWellKnownFile::RobotsTxt
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageWellKnownFile>(r#""humansTxt""#)?,
+            WebPageWellKnownFile::HumansTxt
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn returns_path() {
+        assert_eq!(
+            WebPageWellKnownFile::ALL.map(|file| file.path()),
+            ["/.well-known/security.txt", "/robots.txt", "/humans.txt"]
+        );
+    }
+}
@@ -48,6 +48,26 @@ pub struct WebScraperContentRequest<'a> {
     /// Optional content of the web page that has been extracted previously.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub headers: Option<&'a HashMap<String, String>>,
+
+    /// Indicates whether HTTPS errors (e.g. invalid or self-signed certificates) should be ignored.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub ignore_https_errors: bool,
+
+    /// Indicates whether cookies set by the web page (`Set-Cookie` headers) should be returned.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub capture_cookies: bool,
+
+    /// Indicates whether JavaScript execution should be disabled for the web page.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub disable_js: bool,
+
+    /// Optional HTTP method to use to request the web page instead of `GET`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<&'a str>,
+
+    /// Optional body to send with the request to the web page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<&'a str>,
 }
 
 impl<'a> WebScraperContentRequest<'a> {
@@ -62,6 +82,11 @@ impl<'a> WebScraperContentRequest<'a> {
             previous_content: None,
             scripts: Default::default(),
             headers: None,
+            ignore_https_errors: false,
+            capture_cookies: false,
+            disable_js: false,
+            method: None,
+            body: None,
         }
     }
 
@@ -93,6 +118,43 @@ impl<'a> WebScraperContentRequest<'a> {
             ..self
         }
     }
+
+    /// Sets whether HTTPS errors (e.g. invalid or self-signed certificates) should be ignored.
+    pub fn set_ignore_https_errors(self, ignore_https_errors: bool) -> Self {
+        Self {
+            ignore_https_errors,
+            ..self
+        }
+    }
+
+    /// Sets whether cookies set by the web page should be returned.
+    pub fn set_capture_cookies(self, capture_cookies: bool) -> Self {
+        Self {
+            capture_cookies,
+            ..self
+        }
+    }
+
+    /// Sets whether JavaScript execution should be disabled for the web page.
+    pub fn set_disable_js(self, disable_js: bool) -> Self {
+        Self { disable_js, ..self }
+    }
+
+    /// Sets the HTTP method to use to request the web page.
+    pub fn set_method(self, method: &'a str) -> Self {
+        Self {
+            method: Some(method),
+            ..self
+        }
+    }
+
+    /// Sets the body to send with the request to the web page.
+    pub fn set_body(self, body: &'a str) -> Self {
+        Self {
+            body: Some(body),
+            ..self
+        }
+    }
 }
 
 #[cfg(test)]
@@ -117,6 +179,11 @@ mod tests {
                     .into_iter()
                     .collect(),
             ),
+            ignore_https_errors: true,
+            capture_cookies: true,
+            disable_js: true,
+            method: Some("POST"),
+            body: Some("query=status"),
         }, @r###"
         {
           "url": "http://localhost:1234/my/app?q=2",
@@ -129,7 +196,12 @@ mod tests {
           },
           "headers": {
             "cookie": "my-cookie"
-          }
+          },
+          "ignoreHttpsErrors": true,
+          "captureCookies": true,
+          "disableJs": true,
+          "method": "POST",
+          "body": "query=status"
         }
         "###);
 
@@ -165,6 +237,11 @@ mod tests {
         assert!(request.timeout.is_none());
         assert!(request.scripts.is_empty());
         assert!(request.headers.is_none());
+        assert!(!request.ignore_https_errors);
+        assert!(!request.capture_cookies);
+        assert!(!request.disable_js);
+        assert!(request.method.is_none());
+        assert!(request.body.is_none());
 
         Ok(())
     }
@@ -10,6 +10,9 @@ pub struct WebScraperContentResponse {
     pub timestamp: OffsetDateTime,
     /// Extracted web page content.
     pub content: String,
+    /// Values of the `Set-Cookie` headers of the web page response, if requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookies: Option<Vec<String>>,
 }
 
 #[cfg(test)]
@@ -33,6 +36,7 @@ mod tests {
                 // January 1, 2000 11:00:00
                 timestamp: OffsetDateTime::from_unix_timestamp(946720800)?,
                 content: "some-content".to_string(),
+                cookies: None,
             }
         );
 
@@ -45,6 +49,7 @@ mod tests {
             // January 1, 2000 11:00:00
             timestamp: OffsetDateTime::from_unix_timestamp(946720800)?,
             content: "some-content".to_string(),
+            cookies: None,
         }, @r###"
         {
           "timestamp": 946720800,
@@ -1,4 +1,7 @@
-use crate::utils::web_scraping::WebPageTrackerTag;
+use crate::utils::web_scraping::{
+    WebPageDataRevisionAcknowledgment, WebPageDataRevisionDiffStats, WebPageTrackerTag,
+};
+use openssl::sha::Sha256;
 use serde::Serialize;
 use time::OffsetDateTime;
 use uuid::Uuid;
@@ -16,13 +19,53 @@ pub struct WebPageDataRevision<Tag: WebPageTrackerTag> {
     /// Timestamp indicating when data was fetched.
     #[serde(with = "time::serde::timestamp")]
     pub created_at: OffsetDateTime,
+    /// Optional user note attached to the revision.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Optional acknowledgment of the revision by the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acknowledgment: Option<WebPageDataRevisionAcknowledgment>,
+    /// Magnitude of the change compared to the previous revision, only calculated for the web
+    /// page content tracker revisions when diff is requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff_stats: Option<WebPageDataRevisionDiffStats>,
+    /// Indicates whether the content assertions of the web page content tracker passed for this
+    /// revision, only set if the tracker has assertions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assertions_passed: Option<bool>,
+}
+
+impl<Tag: WebPageTrackerTag> WebPageDataRevision<Tag> {
+    /// Derives revision ID (UUIDv7) from the tracker ID, revision timestamp and revision data, so
+    /// that retried attempts to store the same revision converge to a single revision.
+    pub fn derive_id(
+        tracker_id: Uuid,
+        created_at: OffsetDateTime,
+        data: &Tag::TrackerData,
+    ) -> anyhow::Result<Uuid> {
+        let timestamp_nanos = created_at.unix_timestamp_nanos();
+
+        let mut hasher = Sha256::new();
+        hasher.update(tracker_id.as_bytes());
+        hasher.update(&timestamp_nanos.to_be_bytes());
+        hasher.update(&serde_json::to_vec(data)?);
+
+        let mut hash_bytes = [0; 10];
+        hash_bytes.copy_from_slice(&hasher.finish()[..10]);
+
+        Ok(uuid::Builder::from_unix_timestamp_millis(
+            u64::try_from(timestamp_nanos / 1_000_000)?,
+            &hash_bytes,
+        )
+        .into_uuid())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::utils::web_scraping::{
-        WebPageDataRevision, WebPageResource, WebPageResourceContent, WebPageResourceContentData,
-        WebPageResourcesData, WebPageResourcesTrackerTag,
+        WebPageContentTrackerTag, WebPageDataRevision, WebPageResource, WebPageResourceContent,
+        WebPageResourceContentData, WebPageResourcesData, WebPageResourcesTrackerTag,
     };
     use insta::assert_json_snapshot;
     use time::OffsetDateTime;
@@ -45,6 +88,7 @@ mod tests {
                         size: 123
                     }),
                     diff_status: None,
+                    category: None,
                 }],
                 styles: vec![WebPageResource {
                     url: Some(Url::parse("http://localhost:1234/my/app.css?q=2")?),
@@ -53,8 +97,16 @@ mod tests {
                         size: 321
                     }),
                     diff_status: None,
-                }]
-            }
+                    category: None,
+                }],
+                categories: None,
+                sizes: None,
+                truncated: false,
+            },
+            note: Some("this was the outage".to_string()),
+            acknowledgment: None,
+            diff_stats: None,
+            assertions_passed: None,
         }, @r###"
         {
           "id": "00000000-0000-0000-0000-000000000001",
@@ -80,12 +132,61 @@ mod tests {
                   "size": 321
                 }
               }
-            ]
+            ],
+            "truncated": false
           },
-          "createdAt": 946720800
+          "createdAt": 946720800,
+          "note": "this was the outage"
         }
         "###);
 
         Ok(())
     }
+
+    #[test]
+    fn derives_id_from_tracker_timestamp_and_data() -> anyhow::Result<()> {
+        let tracker_id = uuid!("00000000-0000-0000-0000-000000000001");
+        let created_at = OffsetDateTime::from_unix_timestamp(946720800)?;
+        let derive_id = |tracker_id, created_at, data: &str| {
+            WebPageDataRevision::<WebPageContentTrackerTag>::derive_id(
+                tracker_id,
+                created_at,
+                &data.to_string(),
+            )
+        };
+
+        let id = derive_id(tracker_id, created_at, "\"Hello World\"")?;
+        assert_eq!(id.get_version_num(), 7);
+        assert_eq!(
+            id.get_timestamp().map(|timestamp| timestamp.to_unix().0),
+            Some(946720800)
+        );
+
+        // The same logical revision always gets the same ID.
+        assert_eq!(id, derive_id(tracker_id, created_at, "\"Hello World\"")?);
+
+        // Any difference produces a different ID.
+        assert_ne!(
+            id,
+            derive_id(tracker_id, created_at, "\"Hello New World\"")?
+        );
+        assert_ne!(
+            id,
+            derive_id(
+                tracker_id,
+                created_at + time::Duration::milliseconds(1),
+                "\"Hello World\""
+            )?
+        );
+        assert_ne!(
+            id,
+            derive_id(
+                uuid!("00000000-0000-0000-0000-000000000002"),
+                created_at,
+                "\"Hello World\""
+            )?
+        );
+
+        Ok(())
+    }
 }
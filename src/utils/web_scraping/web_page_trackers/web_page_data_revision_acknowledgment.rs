@@ -0,0 +1,38 @@
+use crate::users::UserId;
+use serde::Serialize;
+use time::OffsetDateTime;
+
+/// Describes who and when acknowledged the web page tracker data revision.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageDataRevisionAcknowledgment {
+    /// ID of the user that acknowledged the revision.
+    pub user_id: UserId,
+    /// Timestamp indicating when the revision was acknowledged.
+    #[serde(with = "time::serde::timestamp")]
+    pub acknowledged_at: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageDataRevisionAcknowledgment;
+    use insta::assert_json_snapshot;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageDataRevisionAcknowledgment {
+            user_id: uuid!("00000000-0000-0000-0000-000000000001").into(),
+            // January 1, 2000 10:00:00
+            acknowledged_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        }, @r###"
+        {
+          "userId": "00000000-0000-0000-0000-000000000001",
+          "acknowledgedAt": 946720800
+        }
+        "###);
+
+        Ok(())
+    }
+}
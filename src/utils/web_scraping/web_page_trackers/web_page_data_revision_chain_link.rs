@@ -0,0 +1,75 @@
+use openssl::sha::sha256;
+use uuid::Uuid;
+
+/// Represents a web page tracker revision as a link of the tamper-evident hash chain of the tracker
+/// history: every revision stores the chain hash of the previous revision, and its own chain hash
+/// calculated as `sha256(previous_hash || sha256(data))`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebPageDataRevisionChainLink {
+    /// Unique revision id.
+    pub id: Uuid,
+    /// Full revision data, reconstructed if the revision is stored as a delta.
+    pub data: Vec<u8>,
+    /// Chain hash of the previous revision, empty for the first revision in the chain.
+    pub previous_hash: Vec<u8>,
+    /// Chain hash of the revision.
+    pub chain_hash: Vec<u8>,
+}
+
+impl WebPageDataRevisionChainLink {
+    /// Calculates chain hash for the revision data and chain hash of the previous revision.
+    pub fn calculate_chain_hash(previous_hash: &[u8], data: &[u8]) -> [u8; 32] {
+        sha256(&[previous_hash, &sha256(data)].concat())
+    }
+
+    /// Checks whether the stored chain hash matches the revision data and the previous hash.
+    pub fn is_valid(&self) -> bool {
+        self.chain_hash == Self::calculate_chain_hash(&self.previous_hash, &self.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageDataRevisionChainLink;
+    use uuid::uuid;
+
+    #[test]
+    fn calculates_chain_hash() {
+        assert_eq!(
+            hex::encode(WebPageDataRevisionChainLink::calculate_chain_hash(
+                &[],
+                b"data"
+            )),
+            "464472b56079ded3d359b17935624bdb8487b6a64856090725277ddb5fb5576a"
+        );
+    }
+
+    #[test]
+    fn validates_chain_link() {
+        let previous_hash = WebPageDataRevisionChainLink::calculate_chain_hash(&[], b"one");
+        let link = WebPageDataRevisionChainLink {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            data: b"two".to_vec(),
+            previous_hash: previous_hash.to_vec(),
+            chain_hash: WebPageDataRevisionChainLink::calculate_chain_hash(&previous_hash, b"two")
+                .to_vec(),
+        };
+        assert!(link.is_valid());
+
+        assert!(!WebPageDataRevisionChainLink {
+            data: b"three".to_vec(),
+            ..link.clone()
+        }
+        .is_valid());
+        assert!(!WebPageDataRevisionChainLink {
+            previous_hash: vec![],
+            ..link.clone()
+        }
+        .is_valid());
+        assert!(!WebPageDataRevisionChainLink {
+            chain_hash: previous_hash.to_vec(),
+            ..link
+        }
+        .is_valid());
+    }
+}
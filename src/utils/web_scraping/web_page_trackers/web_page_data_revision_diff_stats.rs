@@ -0,0 +1,37 @@
+use serde::Serialize;
+
+/// Describes the magnitude of the change between the web page tracker revision and the revision
+/// that precedes it.
+#[derive(Debug, Copy, Clone, Serialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageDataRevisionDiffStats {
+    /// Number of lines added in the revision.
+    pub added_lines: usize,
+    /// Number of lines removed in the revision.
+    pub removed_lines: usize,
+    /// Total size of the added and removed lines, in bytes.
+    pub changed_bytes: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageDataRevisionDiffStats;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageDataRevisionDiffStats {
+            added_lines: 12,
+            removed_lines: 3,
+            changed_bytes: 321,
+        }, @r###"
+        {
+          "addedLines": 12,
+          "removedLines": 3,
+          "changedBytes": 321
+        }
+        "###);
+
+        Ok(())
+    }
+}
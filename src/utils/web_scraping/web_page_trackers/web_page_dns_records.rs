@@ -0,0 +1,15 @@
+mod web_page_dns_record;
+mod web_page_dns_record_diff_status;
+mod web_page_dns_record_type;
+mod web_page_dns_records_data;
+mod web_page_dns_records_revisions_diff;
+mod web_page_dns_records_tracker_tag;
+
+pub use self::{
+    web_page_dns_record::WebPageDnsRecord,
+    web_page_dns_record_diff_status::WebPageDnsRecordDiffStatus,
+    web_page_dns_record_type::WebPageDnsRecordType,
+    web_page_dns_records_data::WebPageDnsRecordsData,
+    web_page_dns_records_revisions_diff::web_page_dns_records_revisions_diff,
+    web_page_dns_records_tracker_tag::WebPageDnsRecordsTrackerTag,
+};
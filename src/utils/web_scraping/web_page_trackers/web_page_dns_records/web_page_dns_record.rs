@@ -0,0 +1,78 @@
+use crate::utils::web_scraping::{WebPageDnsRecordDiffStatus, WebPageDnsRecordType};
+use serde::{Deserialize, Serialize};
+
+/// Represents a single resolved DNS record of the web page domain.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageDnsRecord {
+    /// Type of the DNS record.
+    #[serde(rename = "type")]
+    pub record_type: WebPageDnsRecordType,
+    /// Textual representation of the DNS record data (e.g. IP address or text value).
+    pub value: String,
+    /// Diff status of the record, is only calculated on demand and never persisted.
+    #[serde(skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub diff_status: Option<WebPageDnsRecordDiffStatus>,
+}
+
+impl WebPageDnsRecord {
+    /// Creates a new DNS record of the specified type and with the specified value.
+    pub fn new(record_type: WebPageDnsRecordType, value: impl Into<String>) -> Self {
+        Self {
+            record_type,
+            value: value.into(),
+            diff_status: None,
+        }
+    }
+
+    /// Returns the same record, but with the given diff status.
+    pub fn with_diff_status(self, diff_status: WebPageDnsRecordDiffStatus) -> Self {
+        Self {
+            diff_status: Some(diff_status),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::{
+        WebPageDnsRecord, WebPageDnsRecordDiffStatus, WebPageDnsRecordType,
+    };
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageDnsRecord::new(WebPageDnsRecordType::Txt, "v=spf1 -all"), @r###"
+        {
+          "type": "TXT",
+          "value": "v=spf1 -all"
+        }
+        "###);
+        assert_json_snapshot!(
+            WebPageDnsRecord::new(WebPageDnsRecordType::A, "1.1.1.1")
+                .with_diff_status(WebPageDnsRecordDiffStatus::Added),
+            @r###"
+        {
+          "type": "A",
+          "value": "1.1.1.1",
+          "diffStatus": "added"
+        }
+        "###
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageDnsRecord>(
+                r#"{ "type": "A", "value": "1.1.1.1", "diffStatus": "added" }"#
+            )?,
+            WebPageDnsRecord::new(WebPageDnsRecordType::A, "1.1.1.1")
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use serde::Serialize;
+
+/// Represents a DNS record diff status.
+#[derive(Serialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WebPageDnsRecordDiffStatus {
+    /// Indicates that the record was added since last revision.
+    Added,
+    /// Indicates that the record was removed since last revision.
+    Removed,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageDnsRecordDiffStatus;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageDnsRecordDiffStatus::Added, @r###""added""###);
+        assert_json_snapshot!(WebPageDnsRecordDiffStatus::Removed, @r###""removed""###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use trust_dns_resolver::proto::rr::RecordType;
+
+/// Represents a type of the DNS record that can be tracked by the DNS records tracker.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WebPageDnsRecordType {
+    /// IPv4 address record.
+    A,
+    /// IPv6 address record.
+    Aaaa,
+    /// Canonical name record.
+    Cname,
+    /// Mail exchange record.
+    Mx,
+    /// Name server record.
+    Ns,
+    /// Text record.
+    Txt,
+}
+
+impl WebPageDnsRecordType {
+    /// All DNS record types that can be tracked, in the order they are resolved.
+    pub const ALL: [WebPageDnsRecordType; 6] = [
+        WebPageDnsRecordType::A,
+        WebPageDnsRecordType::Aaaa,
+        WebPageDnsRecordType::Cname,
+        WebPageDnsRecordType::Mx,
+        WebPageDnsRecordType::Ns,
+        WebPageDnsRecordType::Txt,
+    ];
+}
+
+impl From<WebPageDnsRecordType> for RecordType {
+    fn from(value: WebPageDnsRecordType) -> Self {
+        match value {
+            WebPageDnsRecordType::A => RecordType::A,
+            WebPageDnsRecordType::Aaaa => RecordType::AAAA,
+            WebPageDnsRecordType::Cname => RecordType::CNAME,
+            WebPageDnsRecordType::Mx => RecordType::MX,
+            WebPageDnsRecordType::Ns => RecordType::NS,
+            WebPageDnsRecordType::Txt => RecordType::TXT,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageDnsRecordType;
+    use insta::assert_json_snapshot;
+    use trust_dns_resolver::proto::rr::RecordType;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageDnsRecordType::A, @r###""A""###);
+        assert_json_snapshot!(WebPageDnsRecordType::Aaaa, @r###""AAAA""###);
+        assert_json_snapshot!(WebPageDnsRecordType::Cname, @r###""CNAME""###);
+        assert_json_snapshot!(WebPageDnsRecordType::Mx, @r###""MX""###);
+        assert_json_snapshot!(WebPageDnsRecordType::Ns, @r###""NS""###);
+        assert_json_snapshot!(WebPageDnsRecordType::Txt, @r###""TXT""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageDnsRecordType>(r#""AAAA""#)?,
+            WebPageDnsRecordType::Aaaa
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageDnsRecordType>(r#""TXT""#)?,
+            WebPageDnsRecordType::Txt
+        );
+        assert!(serde_json::from_str::<WebPageDnsRecordType>(r#""SOA""#).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn converts_to_record_type() {
+        assert_eq!(
+            WebPageDnsRecordType::ALL.map(RecordType::from),
+            [
+                RecordType::A,
+                RecordType::AAAA,
+                RecordType::CNAME,
+                RecordType::MX,
+                RecordType::NS,
+                RecordType::TXT
+            ]
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::utils::web_scraping::WebPageDnsRecord;
+use serde::{Deserialize, Serialize};
+
+/// Represents a set of the DNS records resolved for the web page domain.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageDnsRecordsData {
+    /// List of the resolved DNS records sorted by type and value.
+    pub records: Vec<WebPageDnsRecord>,
+}
+
+impl WebPageDnsRecordsData {
+    /// Returns `true` if any of the records has a diff status, otherwise returns `false`.
+    #[allow(dead_code)]
+    pub fn has_diff(&self) -> bool {
+        self.records
+            .iter()
+            .any(|record| record.diff_status.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::{
+        WebPageDnsRecord, WebPageDnsRecordDiffStatus, WebPageDnsRecordType, WebPageDnsRecordsData,
+    };
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let data = WebPageDnsRecordsData {
+            records: vec![
+                WebPageDnsRecord::new(WebPageDnsRecordType::A, "1.1.1.1"),
+                WebPageDnsRecord::new(WebPageDnsRecordType::Txt, "v=1"),
+            ],
+        };
+        assert_json_snapshot!(data, @r###"
+        {
+          "records": [
+            {
+              "type": "A",
+              "value": "1.1.1.1"
+            },
+            {
+              "type": "TXT",
+              "value": "v=1"
+            }
+          ]
+        }
+        "###);
+
+        assert_eq!(
+            postcard::from_bytes::<WebPageDnsRecordsData>(&postcard::to_stdvec(&data)?)?,
+            data
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn detects_diff() {
+        let mut data = WebPageDnsRecordsData {
+            records: vec![WebPageDnsRecord::new(WebPageDnsRecordType::A, "1.1.1.1")],
+        };
+        assert!(!data.has_diff());
+
+        data.records.push(
+            WebPageDnsRecord::new(WebPageDnsRecordType::A, "2.2.2.2")
+                .with_diff_status(WebPageDnsRecordDiffStatus::Added),
+        );
+        assert!(data.has_diff());
+    }
+}
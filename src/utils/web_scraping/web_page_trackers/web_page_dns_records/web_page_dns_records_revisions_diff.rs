@@ -0,0 +1,139 @@
+use crate::utils::web_scraping::{
+    WebPageDataRevision, WebPageDnsRecord, WebPageDnsRecordDiffStatus, WebPageDnsRecordsData,
+    WebPageDnsRecordsTrackerTag,
+};
+
+/// Takes multiple web page DNS records revisions and updates diff status for records in the
+/// adjacent revisions.
+pub fn web_page_dns_records_revisions_diff(
+    revisions: Vec<WebPageDataRevision<WebPageDnsRecordsTrackerTag>>,
+) -> anyhow::Result<Vec<WebPageDataRevision<WebPageDnsRecordsTrackerTag>>> {
+    // We can only calculate diff if there are at least two revisions.
+    if revisions.len() < 2 {
+        return Ok(revisions);
+    }
+
+    let mut revisions_diff = Vec::with_capacity(revisions.len());
+    let mut peekable_revisions = revisions.into_iter().rev().peekable();
+    while let Some(current_revision) = peekable_revisions.next() {
+        if let Some(previous_revision) = peekable_revisions.peek() {
+            revisions_diff.push(WebPageDataRevision {
+                data: WebPageDnsRecordsData {
+                    records: web_page_dns_records_diff(
+                        &previous_revision.data.records,
+                        current_revision.data.records,
+                    ),
+                },
+                ..current_revision
+            });
+        } else {
+            revisions_diff.push(current_revision);
+        }
+    }
+
+    Ok(revisions_diff.into_iter().rev().collect())
+}
+
+/// Takes two sets of records - previous and current revision - and returns a set of records with
+/// a populated diff status: added or removed. Removed records are appended to the end of the set.
+fn web_page_dns_records_diff(
+    records_from: &[WebPageDnsRecord],
+    records_to: Vec<WebPageDnsRecord>,
+) -> Vec<WebPageDnsRecord> {
+    let is_same_record = |record_a: &WebPageDnsRecord, record_b: &WebPageDnsRecord| {
+        record_a.record_type == record_b.record_type && record_a.value == record_b.value
+    };
+
+    let removed_records = records_from
+        .iter()
+        .filter(|record_from| {
+            !records_to
+                .iter()
+                .any(|record_to| is_same_record(record_from, record_to))
+        })
+        .map(|record| {
+            WebPageDnsRecord::new(record.record_type, record.value.clone())
+                .with_diff_status(WebPageDnsRecordDiffStatus::Removed)
+        })
+        .collect::<Vec<_>>();
+
+    records_to
+        .into_iter()
+        .map(|record_to| {
+            if records_from
+                .iter()
+                .any(|record_from| is_same_record(record_from, &record_to))
+            {
+                record_to
+            } else {
+                record_to.with_diff_status(WebPageDnsRecordDiffStatus::Added)
+            }
+        })
+        .chain(removed_records)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::web_page_dns_records_revisions_diff;
+    use crate::utils::web_scraping::{
+        WebPageDataRevision, WebPageDnsRecord, WebPageDnsRecordDiffStatus, WebPageDnsRecordType,
+        WebPageDnsRecordsData, WebPageDnsRecordsTrackerTag,
+    };
+    use time::OffsetDateTime;
+    use uuid::{uuid, Uuid};
+
+    fn revision(
+        id: Uuid,
+        records: Vec<WebPageDnsRecord>,
+        timestamp: i64,
+    ) -> anyhow::Result<WebPageDataRevision<WebPageDnsRecordsTrackerTag>> {
+        Ok(WebPageDataRevision {
+            id,
+            tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+            data: WebPageDnsRecordsData { records },
+            created_at: OffsetDateTime::from_unix_timestamp(timestamp)?,
+            note: None,
+            acknowledgment: None,
+            diff_stats: None,
+            assertions_passed: None,
+        })
+    }
+
+    #[test]
+    fn correctly_calculates_records_diff() -> anyhow::Result<()> {
+        let revisions = web_page_dns_records_revisions_diff(vec![
+            revision(
+                uuid!("00000000-0000-0000-0000-000000000002"),
+                vec![
+                    WebPageDnsRecord::new(WebPageDnsRecordType::A, "1.1.1.1"),
+                    WebPageDnsRecord::new(WebPageDnsRecordType::Txt, "v=1"),
+                ],
+                946720800,
+            )?,
+            revision(
+                uuid!("00000000-0000-0000-0000-000000000003"),
+                vec![
+                    WebPageDnsRecord::new(WebPageDnsRecordType::A, "1.1.1.1"),
+                    WebPageDnsRecord::new(WebPageDnsRecordType::Txt, "v=2"),
+                ],
+                946720900,
+            )?,
+        ])?;
+
+        assert_eq!(revisions.len(), 2);
+        assert!(!revisions[0].data.has_diff());
+        assert_eq!(
+            revisions[1].data.records,
+            vec![
+                WebPageDnsRecord::new(WebPageDnsRecordType::A, "1.1.1.1"),
+                WebPageDnsRecord::new(WebPageDnsRecordType::Txt, "v=2")
+                    .with_diff_status(WebPageDnsRecordDiffStatus::Added),
+                WebPageDnsRecord::new(WebPageDnsRecordType::Txt, "v=1")
+                    .with_diff_status(WebPageDnsRecordDiffStatus::Removed),
+            ]
+        );
+
+        Ok(())
+    }
+}
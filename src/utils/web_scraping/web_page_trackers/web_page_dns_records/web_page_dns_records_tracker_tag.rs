@@ -0,0 +1,11 @@
+use crate::utils::web_scraping::{WebPageDnsRecordsData, WebPageTrackerKind, WebPageTrackerTag};
+
+/// Struct that represents a tag for the `WebPageTracker` that tracks DNS records of the web page
+/// domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebPageDnsRecordsTrackerTag(());
+impl WebPageTrackerTag for WebPageDnsRecordsTrackerTag {
+    const KIND: WebPageTrackerKind = WebPageTrackerKind::WebPageDnsRecords;
+    type TrackerMeta = ();
+    type TrackerData = WebPageDnsRecordsData;
+}
@@ -1,20 +1,28 @@
 mod web_page_resource;
+mod web_page_resource_category;
 mod web_page_resource_content;
 mod web_page_resource_content_data;
 mod web_page_resource_diff_status;
+mod web_page_resource_type;
 mod web_page_resources_data;
 mod web_page_resources_revisions_diff;
+mod web_page_resources_size_budget;
+mod web_page_resources_sizes;
 mod web_page_resources_tracker_tag;
 mod web_scraper_resources_request;
 mod web_scraper_resources_response;
 
 pub use self::{
     web_page_resource::WebPageResource,
+    web_page_resource_category::WebPageResourceCategory,
     web_page_resource_content::WebPageResourceContent,
     web_page_resource_content_data::WebPageResourceContentData,
     web_page_resource_diff_status::WebPageResourceDiffStatus,
+    web_page_resource_type::WebPageResourceType,
     web_page_resources_data::WebPageResourcesData,
     web_page_resources_revisions_diff::web_page_resources_revisions_diff,
+    web_page_resources_size_budget::WebPageResourcesSizeBudget,
+    web_page_resources_sizes::WebPageResourcesSizes,
     web_page_resources_tracker_tag::WebPageResourcesTrackerTag,
     web_scraper_resources_request::{
         WebScraperResourcesRequest, WebScraperResourcesRequestScripts,
@@ -1,4 +1,6 @@
-use crate::utils::web_scraping::{WebPageResourceContent, WebPageResourceDiffStatus};
+use crate::utils::web_scraping::{
+    WebPageResourceCategory, WebPageResourceContent, WebPageResourceDiffStatus,
+};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
@@ -11,6 +13,10 @@ pub struct WebPageResource {
     pub content: Option<WebPageResourceContent>,
     #[serde(skip_serializing_if = "Option::is_none", skip_deserializing)]
     pub diff_status: Option<WebPageResourceDiffStatus>,
+    /// Category of the known third-party resource (e.g. analytics or advertising script). The
+    /// category isn't persisted and is derived from the resource URL using the bundled ruleset.
+    #[serde(skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub category: Option<WebPageResourceCategory>,
 }
 
 impl WebPageResource {
@@ -22,6 +28,15 @@ impl WebPageResource {
         }
     }
 
+    /// Returns the same resource, but with the category derived from its URL, if any.
+    pub fn classified(self) -> Self {
+        let category = self
+            .url
+            .as_ref()
+            .and_then(WebPageResourceCategory::classify);
+        Self { category, ..self }
+    }
+
     /// Checks if the resource is external (i.e. has URL that's not a data URL or a blob URL).
     pub fn is_external_resource(&self) -> bool {
         self.url
@@ -52,6 +67,7 @@ impl From<WebPageResourceInternal> for WebPageResource {
             url: resource.url,
             content: resource.content,
             diff_status: None,
+            category: None,
         }
     }
 }
@@ -61,8 +77,8 @@ mod tests {
     use crate::{
         tests::MockWebPageResourceBuilder,
         utils::web_scraping::{
-            WebPageResource, WebPageResourceContent, WebPageResourceContentData,
-            WebPageResourceDiffStatus,
+            WebPageResource, WebPageResourceCategory, WebPageResourceContent,
+            WebPageResourceContentData, WebPageResourceDiffStatus,
         },
     };
     use insta::assert_json_snapshot;
@@ -75,6 +91,7 @@ mod tests {
             url: Some(Url::parse("http://localhost:1234/my/app?q=2")?),
             content: Some(WebPageResourceContent { data: WebPageResourceContentData::Sha1("some-digest".to_string()), size: 123 }),
             diff_status: Some(WebPageResourceDiffStatus::Added),
+            category: None,
 
         }, @r###"
         {
@@ -93,6 +110,7 @@ mod tests {
             url: None,
             content: Some(WebPageResourceContent { data: WebPageResourceContentData::Sha1("some-digest".to_string()), size: 123 }),
             diff_status: None,
+            category: None,
         }, @r###"
         {
           "content": {
@@ -108,6 +126,7 @@ mod tests {
             url: None,
             content: None,
             diff_status: None,
+            category: None,
         }, @"{}");
 
         Ok(())
@@ -123,6 +142,7 @@ mod tests {
                 url: Some(Url::parse("https://localhost:1234/my/app?q=2")?),
                 content: Some(WebPageResourceContent { data: WebPageResourceContentData::Sha1("some-digest".to_string()), size: 123 }),
                 diff_status: None,
+                category: None,
             }
         );
 
@@ -137,6 +157,7 @@ mod tests {
                 )?),
                 content: None,
                 diff_status: None,
+                category: None,
             }
         );
 
@@ -152,6 +173,7 @@ mod tests {
                     size: 123
                 }),
                 diff_status: None,
+                category: None,
             }
         );
 
@@ -167,6 +189,7 @@ mod tests {
                 size: 123,
             }),
             diff_status: None,
+            category: None,
         };
         let resource_with_status = WebPageResource {
             url: Some(Url::parse("http://localhost:1234/one")?),
@@ -175,6 +198,7 @@ mod tests {
                 size: 123,
             }),
             diff_status: Some(WebPageResourceDiffStatus::Added),
+            category: None,
         };
 
         assert_eq!(
@@ -262,4 +286,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn returns_classified_resource() -> anyhow::Result<()> {
+        let resource = MockWebPageResourceBuilder::with_url(Url::parse(
+            "https://www.googletagmanager.com/gtm.js?id=GTM-XXXX",
+        )?)
+        .build()
+        .classified();
+        assert_eq!(resource.category, Some(WebPageResourceCategory::TagManager));
+        assert_json_snapshot!(resource, @r###"
+        {
+          "url": "https://www.googletagmanager.com/gtm.js?id=GTM-XXXX",
+          "category": "tagManager"
+        }
+        "###);
+
+        let resource =
+            MockWebPageResourceBuilder::with_url(Url::parse("https://secutils.dev/app.js")?)
+                .build()
+                .classified();
+        assert_eq!(resource.category, None);
+
+        let resource = MockWebPageResourceBuilder::with_content(
+            WebPageResourceContentData::Raw("some-data".to_string()),
+            123,
+        )
+        .build()
+        .classified();
+        assert_eq!(resource.category, None);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,152 @@
+use regex::RegexSet;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+use url::Url;
+
+/// Bundled ruleset that maps URLs of the well-known third-party resources to their categories. The
+/// rules are checked in order and the first matching rule wins.
+const THIRD_PARTY_RESOURCES_RULESET: &str =
+    include_str!("../../../../../assets/web_scraping/third_party_resources.json");
+
+/// Represents a category of the known third-party web page resource (e.g. tracking script).
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum WebPageResourceCategory {
+    /// Web analytics resources (e.g. Google Analytics).
+    Analytics,
+    /// Advertising and conversion tracking resources (e.g. Facebook Pixel).
+    Advertising,
+    /// Tag managers that load other third-party resources (e.g. Google Tag Manager).
+    TagManager,
+    /// Social media widgets and SDKs.
+    Social,
+}
+
+#[derive(Deserialize)]
+struct WebPageResourceCategoryRule {
+    category: WebPageResourceCategory,
+    pattern: String,
+}
+
+/// Compiled version of the bundled third-party resources ruleset.
+struct WebPageResourceCategoryRuleset {
+    patterns: RegexSet,
+    categories: Vec<WebPageResourceCategory>,
+}
+
+impl WebPageResourceCategoryRuleset {
+    fn parse(ruleset: &str) -> anyhow::Result<Self> {
+        let rules = serde_json::from_str::<Vec<WebPageResourceCategoryRule>>(ruleset)?;
+        Ok(Self {
+            patterns: RegexSet::new(rules.iter().map(|rule| rule.pattern.as_str()))?,
+            categories: rules.into_iter().map(|rule| rule.category).collect(),
+        })
+    }
+}
+
+impl WebPageResourceCategory {
+    /// Classifies resource with the specified URL using the bundled ruleset. Returns `None` if the
+    /// URL doesn't match any known third-party resource.
+    pub fn classify(url: &Url) -> Option<Self> {
+        static RULESET: OnceLock<WebPageResourceCategoryRuleset> = OnceLock::new();
+        let ruleset = RULESET.get_or_init(|| {
+            WebPageResourceCategoryRuleset::parse(THIRD_PARTY_RESOURCES_RULESET)
+                .expect("Bundled third-party resources ruleset should be valid.")
+        });
+
+        ruleset
+            .patterns
+            .matches(url.as_str())
+            .iter()
+            .next()
+            .map(|rule_index| ruleset.categories[rule_index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WebPageResourceCategoryRuleset, THIRD_PARTY_RESOURCES_RULESET};
+    use crate::utils::web_scraping::WebPageResourceCategory;
+    use insta::assert_json_snapshot;
+    use url::Url;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageResourceCategory::Analytics, @r###""analytics""###);
+        assert_json_snapshot!(WebPageResourceCategory::Advertising, @r###""advertising""###);
+        assert_json_snapshot!(WebPageResourceCategory::TagManager, @r###""tagManager""###);
+        assert_json_snapshot!(WebPageResourceCategory::Social, @r###""social""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bundled_ruleset_is_valid() -> anyhow::Result<()> {
+        let ruleset = WebPageResourceCategoryRuleset::parse(THIRD_PARTY_RESOURCES_RULESET)?;
+        assert!(!ruleset.categories.is_empty());
+        assert_eq!(ruleset.patterns.len(), ruleset.categories.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn classifies_known_resources() -> anyhow::Result<()> {
+        for (url, category) in [
+            (
+                "https://www.google-analytics.com/analytics.js",
+                WebPageResourceCategory::Analytics,
+            ),
+            (
+                "https://ssl.google-analytics.com/ga.js",
+                WebPageResourceCategory::Analytics,
+            ),
+            (
+                "https://www.googletagmanager.com/gtag/js?id=G-XXXXXXX",
+                WebPageResourceCategory::Analytics,
+            ),
+            (
+                "https://www.googletagmanager.com/gtm.js?id=GTM-XXXXXX",
+                WebPageResourceCategory::TagManager,
+            ),
+            (
+                "https://connect.facebook.net/en_US/fbevents.js",
+                WebPageResourceCategory::Advertising,
+            ),
+            (
+                "https://connect.facebook.net/en_US/sdk.js",
+                WebPageResourceCategory::Social,
+            ),
+            (
+                "https://securepubads.g.doubleclick.net/tag/js/gpt.js",
+                WebPageResourceCategory::Advertising,
+            ),
+        ] {
+            assert_eq!(
+                WebPageResourceCategory::classify(&Url::parse(url)?),
+                Some(category),
+                "{url}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn does_not_classify_unknown_resources() -> anyhow::Result<()> {
+        for url in [
+            "https://secutils.dev/app.js",
+            "https://cdn.example.com/google-analytics.com/analytics.js",
+            "https://google-analytics.com.example.com/analytics.js",
+            "https://connect.facebook.net.example.com/en_US/fbevents.js",
+            "data:text/javascript,alert(1)",
+        ] {
+            assert_eq!(
+                WebPageResourceCategory::classify(&Url::parse(url)?),
+                None,
+                "{url}"
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a type of the web page resource reported by the web scraper.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum WebPageResourceType {
+    /// JavaScript resources (external and inline scripts).
+    Script,
+    /// CSS resources (external and inline styles).
+    Style,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageResourceType;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageResourceType::Script, @r###""script""###);
+        assert_json_snapshot!(WebPageResourceType::Style, @r###""style""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageResourceType>(r#""script""#)?,
+            WebPageResourceType::Script
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageResourceType>(r#""style""#)?,
+            WebPageResourceType::Style
+        );
+        assert!(serde_json::from_str::<WebPageResourceType>(r#""image""#).is_err());
+
+        Ok(())
+    }
+}
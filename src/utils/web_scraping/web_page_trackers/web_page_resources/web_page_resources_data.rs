@@ -1,5 +1,9 @@
-use crate::utils::web_scraping::WebPageResource;
+use crate::utils::web_scraping::{
+    WebPageResource, WebPageResourceCategory, WebPageResourceDiffStatus, WebPageResourceType,
+    WebPageResourcesSizes,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct WebPageResourcesData<
@@ -11,6 +15,17 @@ pub struct WebPageResourcesData<
     /// List of CSS resources.
     #[serde(bound(deserialize = ""))]
     pub styles: Vec<R>,
+    /// Number of the known third-party resources per category, available only if resources were
+    /// classified. It's never persisted and is derived from the resource URLs when requested.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub categories: Option<BTreeMap<WebPageResourceCategory, usize>>,
+    /// Total sizes of the resources per resource type, available only if the tracker has a size
+    /// budget. It's never persisted and is derived from the sizes of the individual resources.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub sizes: Option<WebPageResourcesSizes>,
+    /// Indicates whether some of the resources were dropped since the web page has more resources
+    /// than the tracker can retain.
+    pub truncated: bool,
 }
 
 impl WebPageResourcesData {
@@ -21,13 +36,80 @@ impl WebPageResourcesData {
             .chain(self.styles.iter())
             .any(|resource| resource.diff_status.is_some())
     }
+
+    /// Drops all resources with types that aren't in the specified list.
+    pub fn retain_types(&mut self, resource_types: &[WebPageResourceType]) {
+        if !resource_types.contains(&WebPageResourceType::Script) {
+            self.scripts.clear();
+        }
+
+        if !resource_types.contains(&WebPageResourceType::Style) {
+            self.styles.clear();
+        }
+    }
+
+    /// Calculates total sizes of the scripts and styles. Removed resources aren't counted.
+    pub fn calculate_sizes(&self) -> WebPageResourcesSizes {
+        let total_size = |resources: &[WebPageResource]| {
+            resources
+                .iter()
+                .filter(|resource| resource.diff_status != Some(WebPageResourceDiffStatus::Removed))
+                .filter_map(|resource| resource.content.as_ref())
+                .map(|content| content.size)
+                .sum::<usize>()
+        };
+
+        let scripts = total_size(&self.scripts);
+        let styles = total_size(&self.styles);
+        WebPageResourcesSizes {
+            scripts,
+            styles,
+            total: scripts + styles,
+            previous_total: None,
+        }
+    }
+
+    /// Returns the same data, but with all scripts and styles classified using the bundled ruleset
+    /// of the known third-party resources, and the number of classified resources per category.
+    /// Removed resources aren't counted.
+    pub fn classified(self) -> Self {
+        let scripts = self
+            .scripts
+            .into_iter()
+            .map(WebPageResource::classified)
+            .collect::<Vec<_>>();
+        let styles = self
+            .styles
+            .into_iter()
+            .map(WebPageResource::classified)
+            .collect::<Vec<_>>();
+
+        let mut categories = BTreeMap::new();
+        for category in scripts
+            .iter()
+            .chain(styles.iter())
+            .filter(|resource| resource.diff_status != Some(WebPageResourceDiffStatus::Removed))
+            .filter_map(|resource| resource.category)
+        {
+            *categories.entry(category).or_insert(0) += 1;
+        }
+
+        Self {
+            scripts,
+            styles,
+            categories: Some(categories),
+            sizes: self.sizes,
+            truncated: self.truncated,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::utils::web_scraping::{
-        WebPageResource, WebPageResourceContent, WebPageResourceContentData,
-        WebPageResourceDiffStatus, WebPageResourcesData,
+        WebPageResource, WebPageResourceCategory, WebPageResourceContent,
+        WebPageResourceContentData, WebPageResourceDiffStatus, WebPageResourceType,
+        WebPageResourcesData, WebPageResourcesSizes,
     };
     use insta::assert_json_snapshot;
     use url::Url;
@@ -42,6 +124,7 @@ mod tests {
                     size: 123
                 }),
                 diff_status: None,
+                category: None,
             }],
                 styles: vec![WebPageResource {
                 url: Some(Url::parse("http://localhost:1234/my/app.css?q=2")?),
@@ -50,7 +133,11 @@ mod tests {
                     size: 321
                 }),
                 diff_status: None,
-            }]
+                category: None,
+            }],
+            categories: None,
+            sizes: None,
+            truncated: false,
         }, @r###"
         {
           "scripts": [
@@ -74,7 +161,8 @@ mod tests {
                 "size": 321
               }
             }
-          ]
+          ],
+          "truncated": false
         }
         "###);
 
@@ -92,6 +180,7 @@ mod tests {
                         size: 123
                     }),
                     diff_status: None,
+                    category: None,
                 }],
                 styles: vec![WebPageResource {
                     url: Some(Url::parse("http://localhost:1234/my/app.css?q=2")?),
@@ -100,7 +189,11 @@ mod tests {
                         size: 321
                     }),
                     diff_status: None,
-                }]
+                    category: None,
+                }],
+                categories: None,
+                sizes: None,
+                truncated: false,
             },
             serde_json::from_str(
                 r#"
@@ -126,7 +219,8 @@ mod tests {
                 "size": 321
               }
             }
-          ]
+          ],
+          "truncated": false
         }
         "#
             )?
@@ -145,6 +239,7 @@ mod tests {
                     size: 123,
                 }),
                 diff_status: None,
+                category: None,
             }],
             styles: vec![WebPageResource {
                 url: Some(Url::parse("http://localhost:1234/my/app.css?q=2")?),
@@ -153,7 +248,11 @@ mod tests {
                     size: 321,
                 }),
                 diff_status: None,
+                category: None,
             }],
+            categories: None,
+            sizes: None,
+            truncated: false,
         };
         assert!(!data.has_diff());
 
@@ -166,6 +265,7 @@ mod tests {
                         size: 123,
                     }),
                     diff_status: None,
+                    category: None,
                 },
                 WebPageResource {
                     url: Some(Url::parse("http://localhost:1234/my/app?q=2")?),
@@ -174,6 +274,7 @@ mod tests {
                         size: 123,
                     }),
                     diff_status: Some(WebPageResourceDiffStatus::Added),
+                    category: None,
                 },
             ],
             styles: vec![WebPageResource {
@@ -183,7 +284,11 @@ mod tests {
                     size: 321,
                 }),
                 diff_status: None,
+                category: None,
             }],
+            categories: None,
+            sizes: None,
+            truncated: false,
         };
         assert!(data.has_diff());
 
@@ -196,6 +301,7 @@ mod tests {
                         size: 123,
                     }),
                     diff_status: None,
+                    category: None,
                 },
                 WebPageResource {
                     url: Some(Url::parse("http://localhost:1234/my/app?q=2")?),
@@ -204,6 +310,7 @@ mod tests {
                         size: 123,
                     }),
                     diff_status: None,
+                    category: None,
                 },
             ],
             styles: vec![
@@ -214,6 +321,7 @@ mod tests {
                         size: 321,
                     }),
                     diff_status: None,
+                    category: None,
                 },
                 WebPageResource {
                     url: Some(Url::parse("http://localhost:1234/my/app.css?q=2")?),
@@ -222,11 +330,151 @@ mod tests {
                         size: 321,
                     }),
                     diff_status: Some(WebPageResourceDiffStatus::Removed),
+                    category: None,
                 },
             ],
+            categories: None,
+            sizes: None,
+            truncated: false,
         };
         assert!(data.has_diff());
 
         Ok(())
     }
+
+    #[test]
+    fn classifies_resources() -> anyhow::Result<()> {
+        let resource = |url: &str, diff_status: Option<WebPageResourceDiffStatus>| {
+            Ok::<_, anyhow::Error>(WebPageResource {
+                url: Some(Url::parse(url)?),
+                content: None,
+                diff_status,
+                category: None,
+            })
+        };
+
+        let data = WebPageResourcesData {
+            scripts: vec![
+                resource("https://www.google-analytics.com/analytics.js", None)?,
+                resource("https://www.googletagmanager.com/gtm.js?id=GTM-XXXX", None)?,
+                resource(
+                    "https://connect.facebook.net/en_US/fbevents.js",
+                    Some(WebPageResourceDiffStatus::Added),
+                )?,
+                resource(
+                    "https://ssl.google-analytics.com/ga.js",
+                    Some(WebPageResourceDiffStatus::Removed),
+                )?,
+                resource("https://secutils.dev/app.js", None)?,
+            ],
+            styles: vec![resource("https://secutils.dev/app.css", None)?],
+            categories: None,
+            sizes: None,
+            truncated: false,
+        }
+        .classified();
+
+        assert_eq!(
+            data.scripts
+                .iter()
+                .map(|resource| resource.category)
+                .collect::<Vec<_>>(),
+            vec![
+                Some(WebPageResourceCategory::Analytics),
+                Some(WebPageResourceCategory::TagManager),
+                Some(WebPageResourceCategory::Advertising),
+                Some(WebPageResourceCategory::Analytics),
+                None
+            ]
+        );
+        assert_eq!(data.styles[0].category, None);
+        assert_json_snapshot!(data.categories, @r###"
+        {
+          "analytics": 1,
+          "advertising": 1,
+          "tagManager": 1
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn retains_specified_types() -> anyhow::Result<()> {
+        let resource = |url: &str| -> anyhow::Result<WebPageResource> {
+            Ok(WebPageResource {
+                url: Some(Url::parse(url)?),
+                content: None,
+                diff_status: None,
+                category: None,
+            })
+        };
+        let data = WebPageResourcesData {
+            scripts: vec![resource("https://secutils.dev/app.js")?],
+            styles: vec![resource("https://secutils.dev/app.css")?],
+            categories: None,
+            sizes: None,
+            truncated: false,
+        };
+
+        let mut scripts_only = data.clone();
+        scripts_only.retain_types(&[WebPageResourceType::Script]);
+        assert_eq!(scripts_only.scripts, data.scripts);
+        assert!(scripts_only.styles.is_empty());
+
+        let mut styles_only = data.clone();
+        styles_only.retain_types(&[WebPageResourceType::Style]);
+        assert!(styles_only.scripts.is_empty());
+        assert_eq!(styles_only.styles, data.styles);
+
+        let mut all = data.clone();
+        all.retain_types(&[WebPageResourceType::Style, WebPageResourceType::Script]);
+        assert_eq!(all, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn calculates_sizes() -> anyhow::Result<()> {
+        let resource =
+            |size: usize, diff_status: Option<WebPageResourceDiffStatus>| WebPageResource {
+                url: None,
+                content: Some(WebPageResourceContent {
+                    data: WebPageResourceContentData::Sha1("some-digest".to_string()),
+                    size,
+                }),
+                diff_status,
+                category: None,
+            };
+
+        let data = WebPageResourcesData {
+            scripts: vec![
+                resource(100, None),
+                resource(200, Some(WebPageResourceDiffStatus::Added)),
+                resource(400, Some(WebPageResourceDiffStatus::Removed)),
+                WebPageResource {
+                    url: Some(Url::parse("https://secutils.dev/app.js")?),
+                    content: None,
+                    diff_status: None,
+                    category: None,
+                },
+            ],
+            styles: vec![resource(50, Some(WebPageResourceDiffStatus::Changed))],
+            categories: None,
+            sizes: None,
+            truncated: false,
+        };
+
+        assert_eq!(
+            data.calculate_sizes(),
+            WebPageResourcesSizes {
+                scripts: 300,
+                styles: 50,
+                total: 350,
+                previous_total: None,
+            }
+        );
+
+        Ok(())
+    }
 }
@@ -49,7 +49,14 @@ pub fn web_page_resources_revisions_diff(
                         previous_revision.data.styles.clone(),
                         current_revision.data.styles,
                     )?,
+                    categories: current_revision.data.categories,
+                    sizes: current_revision.data.sizes,
+                    truncated: current_revision.data.truncated,
                 },
+                note: current_revision.note,
+                acknowledgment: current_revision.acknowledgment,
+                diff_stats: current_revision.diff_stats,
+                assertions_passed: current_revision.assertions_passed,
             });
         } else {
             revisions_diff.push(current_revision);
@@ -97,7 +104,7 @@ fn web_page_resources_diff(
             resources_diff.extend(
                 resources_to
                     .into_iter()
-                    .zip_longest(resources_from.into_iter())
+                    .zip_longest(resources_from)
                     .map(update_resource_status),
             )
         } else {
@@ -213,7 +220,7 @@ fn web_page_resources_to_map(resources: Vec<WebPageResource>) -> WebPageResource
     }
 
     // Sort by size to make search more efficient.
-    similarity_hashes.sort_by(|(_, size_a), (_, size_b)| size_a.cmp(size_b));
+    similarity_hashes.sort_by_key(|(_, size)| *size);
 
     WebPageResourcesDiffMap {
         resources: exact_match_resources_map,
@@ -996,7 +1003,14 @@ mod tests {
                 data: WebPageResourcesData {
                     scripts: vec![resource_one_rev_1, resource_two_rev_1, resource_three_rev_1],
                     styles: vec![resource_four_rev_1],
+                    categories: None,
+                    sizes: None,
+                    truncated: false,
                 },
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
             },
             WebPageDataRevision {
                 id: uuid!("00000000-0000-0000-0000-000000000011"),
@@ -1005,7 +1019,14 @@ mod tests {
                 data: WebPageResourcesData {
                     scripts: vec![resource_one_rev_2, resource_two_rev_2, resource_three_rev_2],
                     styles: vec![resource_four_rev_2],
+                    categories: None,
+                    sizes: None,
+                    truncated: false,
                 },
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
             },
             WebPageDataRevision {
                 id: uuid!("00000000-0000-0000-0000-000000000021"),
@@ -1014,7 +1035,14 @@ mod tests {
                 data: WebPageResourcesData {
                     scripts: vec![resource_one_rev_3, resource_two_rev_3, resource_three_rev_3],
                     styles: vec![],
+                    categories: None,
+                    sizes: None,
+                    truncated: false,
                 },
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
             },
         ])?;
 
@@ -1063,7 +1091,8 @@ mod tests {
                   "size": 321
                 }
               }
-            ]
+            ],
+            "truncated": false
           },
           "createdAt": 946720100
         }
@@ -1124,7 +1153,8 @@ mod tests {
                 },
                 "diffStatus": "changed"
               }
-            ]
+            ],
+            "truncated": false
           },
           "createdAt": 946720200
         }
@@ -1185,7 +1215,8 @@ mod tests {
                 },
                 "diffStatus": "removed"
               }
-            ]
+            ],
+            "truncated": false
           },
           "createdAt": 946720300
         }
@@ -0,0 +1,114 @@
+use crate::utils::web_scraping::WebPageResourcesSizes;
+use serde::{Deserialize, Serialize};
+
+/// Defines the performance budget for the total size of the web page resources.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageResourcesSizeBudget {
+    /// Optional maximum total size of all web page resources, in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_total_size: Option<usize>,
+    /// Optional maximum growth of the total size of web page resources between consecutive
+    /// revisions, in percent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_growth: Option<u32>,
+}
+
+impl WebPageResourcesSizeBudget {
+    /// Checks whether the specified web page resources sizes exceed the budget.
+    pub fn is_exceeded(&self, sizes: &WebPageResourcesSizes) -> bool {
+        if self
+            .max_total_size
+            .is_some_and(|max_total_size| sizes.total > max_total_size)
+        {
+            return true;
+        }
+
+        match (self.max_growth, sizes.previous_total) {
+            (Some(max_growth), Some(previous_total)) if sizes.total > previous_total => {
+                (sizes.total - previous_total) as u128 * 100
+                    > previous_total as u128 * max_growth as u128
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::{WebPageResourcesSizeBudget, WebPageResourcesSizes};
+    use insta::assert_json_snapshot;
+
+    fn sizes(total: usize, previous_total: Option<usize>) -> WebPageResourcesSizes {
+        WebPageResourcesSizes {
+            scripts: total,
+            styles: 0,
+            total,
+            previous_total,
+        }
+    }
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageResourcesSizeBudget {
+            max_total_size: Some(1024),
+            max_growth: Some(10),
+        }, @r###"
+        {
+          "maxTotalSize": 1024,
+          "maxGrowth": 10
+        }
+        "###);
+        assert_json_snapshot!(WebPageResourcesSizeBudget {
+            max_total_size: None,
+            max_growth: None,
+        }, @"{}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageResourcesSizeBudget>(
+                r#"{ "maxTotalSize": 1024, "maxGrowth": 10 }"#
+            )?,
+            WebPageResourcesSizeBudget {
+                max_total_size: Some(1024),
+                max_growth: Some(10),
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageResourcesSizeBudget>("{}")?,
+            WebPageResourcesSizeBudget {
+                max_total_size: None,
+                max_growth: None,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn checks_if_budget_is_exceeded() -> anyhow::Result<()> {
+        let budget = WebPageResourcesSizeBudget {
+            max_total_size: Some(1000),
+            max_growth: None,
+        };
+        assert!(!budget.is_exceeded(&sizes(1000, None)));
+        assert!(budget.is_exceeded(&sizes(1001, None)));
+        assert!(budget.is_exceeded(&sizes(1001, Some(1001))));
+
+        let budget = WebPageResourcesSizeBudget {
+            max_total_size: None,
+            max_growth: Some(10),
+        };
+        assert!(!budget.is_exceeded(&sizes(10000, None)));
+        assert!(!budget.is_exceeded(&sizes(110, Some(100))));
+        assert!(budget.is_exceeded(&sizes(111, Some(100))));
+        assert!(!budget.is_exceeded(&sizes(50, Some(100))));
+        assert!(budget.is_exceeded(&sizes(1, Some(0))));
+
+        Ok(())
+    }
+}
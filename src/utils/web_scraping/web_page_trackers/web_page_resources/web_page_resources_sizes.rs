@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+/// Total sizes of the web page resources per resource type, in bytes.
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageResourcesSizes {
+    /// Total size of all JavaScript resources.
+    pub scripts: usize,
+    /// Total size of all CSS resources.
+    pub styles: usize,
+    /// Total size of all resources (page weight).
+    pub total: usize,
+    /// Total size of all resources in the previous revision, if available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_total: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageResourcesSizes;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageResourcesSizes {
+            scripts: 100,
+            styles: 50,
+            total: 150,
+            previous_total: None,
+        }, @r###"
+        {
+          "scripts": 100,
+          "styles": 50,
+          "total": 150
+        }
+        "###);
+        assert_json_snapshot!(WebPageResourcesSizes {
+            scripts: 100,
+            styles: 50,
+            total: 150,
+            previous_total: Some(120),
+        }, @r###"
+        {
+          "scripts": 100,
+          "styles": 50,
+          "total": 150,
+          "previousTotal": 120
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -44,6 +44,14 @@ pub struct WebScraperResourcesRequest<'a> {
     /// Optional content of the web page that has been extracted previously.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub headers: Option<&'a HashMap<String, String>>,
+
+    /// Indicates whether HTTPS errors (e.g. invalid or self-signed certificates) should be ignored.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub ignore_https_errors: bool,
+
+    /// Indicates whether JavaScript execution should be disabled for the web page.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub disable_js: bool,
 }
 
 impl<'a> WebScraperResourcesRequest<'a> {
@@ -57,6 +65,8 @@ impl<'a> WebScraperResourcesRequest<'a> {
             wait_selector: None,
             scripts: Default::default(),
             headers: None,
+            ignore_https_errors: false,
+            disable_js: false,
         }
     }
 
@@ -80,6 +90,19 @@ impl<'a> WebScraperResourcesRequest<'a> {
             ..self
         }
     }
+
+    /// Sets whether HTTPS errors (e.g. invalid or self-signed certificates) should be ignored.
+    pub fn set_ignore_https_errors(self, ignore_https_errors: bool) -> Self {
+        Self {
+            ignore_https_errors,
+            ..self
+        }
+    }
+
+    /// Sets whether JavaScript execution should be disabled for the web page.
+    pub fn set_disable_js(self, disable_js: bool) -> Self {
+        Self { disable_js, ..self }
+    }
 }
 
 #[cfg(test)]
@@ -103,6 +126,8 @@ mod tests {
                     .into_iter()
                     .collect(),
             ),
+            ignore_https_errors: true,
+            disable_js: true,
         }, @r###"
         {
           "url": "http://localhost:1234/my/app?q=2",
@@ -114,7 +139,9 @@ mod tests {
           },
           "headers": {
             "cookie": "my-cookie"
-          }
+          },
+          "ignoreHttpsErrors": true,
+          "disableJs": true
         }
         "###);
 
@@ -149,6 +176,8 @@ mod tests {
         assert!(request.timeout.is_none());
         assert!(request.scripts.is_empty());
         assert!(request.headers.is_none());
+        assert!(!request.ignore_https_errors);
+        assert!(!request.disable_js);
 
         Ok(())
     }
@@ -32,6 +32,7 @@ impl From<WebScraperResource> for WebPageResource {
             url: value.url,
             content: value.content,
             diff_status: None,
+            category: None,
         }
     }
 }
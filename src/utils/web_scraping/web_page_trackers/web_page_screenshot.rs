@@ -0,0 +1,15 @@
+mod web_page_screenshot_data;
+mod web_page_screenshot_revisions_diff;
+mod web_page_screenshot_tracker_tag;
+mod web_scraper_screenshot_request;
+mod web_scraper_screenshot_response;
+
+pub use self::{
+    web_page_screenshot_data::WebPageScreenshotData,
+    web_page_screenshot_revisions_diff::{
+        web_page_screenshot_revisions_diff, web_page_screenshots_diff,
+    },
+    web_page_screenshot_tracker_tag::WebPageScreenshotTrackerTag,
+    web_scraper_screenshot_request::WebScraperScreenshotRequest,
+    web_scraper_screenshot_response::WebScraperScreenshotResponse,
+};
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{base64::Base64, serde_as, Bytes, IfIsHumanReadable};
+
+/// Represents a full page screenshot captured by the web page screenshot tracker.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageScreenshotData {
+    /// PNG-encoded (compressed) screenshot of the web page. It's serialized as a Base64 string for
+    /// the human-readable formats (e.g. JSON), and as raw bytes otherwise (e.g. Postcard).
+    #[serde_as(as = "IfIsHumanReadable<Base64, Bytes>")]
+    pub image: Vec<u8>,
+    /// Share of the screenshot pixels (`0.0` to `1.0`) that differ from the previous screenshot.
+    /// Is only calculated on demand and is always `None` for the persisted revisions.
+    pub diff: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageScreenshotData;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageScreenshotData {
+            image: vec![1, 2, 3],
+            diff: None,
+        }, @r###"
+        {
+          "image": "AQID",
+          "diff": null
+        }
+        "###);
+
+        assert_json_snapshot!(WebPageScreenshotData {
+            image: vec![1, 2, 3],
+            diff: Some(0.5),
+        }, @r###"
+        {
+          "image": "AQID",
+          "diff": 0.5
+        }
+        "###);
+
+        assert_eq!(
+            postcard::to_stdvec(&WebPageScreenshotData {
+                image: vec![1, 2, 3],
+                diff: None,
+            })?,
+            vec![3, 1, 2, 3, 0]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageScreenshotData>(r#"{ "image": "AQID" }"#)?,
+            WebPageScreenshotData {
+                image: vec![1, 2, 3],
+                diff: None,
+            }
+        );
+
+        assert_eq!(
+            postcard::from_bytes::<WebPageScreenshotData>(&[3, 1, 2, 3, 0])?,
+            WebPageScreenshotData {
+                image: vec![1, 2, 3],
+                diff: None,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> anyhow::Result<()> {
+        for data in [
+            WebPageScreenshotData {
+                image: vec![1, 2, 3],
+                diff: None,
+            },
+            WebPageScreenshotData {
+                image: vec![1, 2, 3],
+                diff: Some(0.5),
+            },
+        ] {
+            assert_eq!(
+                postcard::from_bytes::<WebPageScreenshotData>(&postcard::to_stdvec(&data)?)?,
+                data
+            );
+            assert_eq!(
+                serde_json::from_str::<WebPageScreenshotData>(&serde_json::to_string(&data)?)?,
+                data
+            );
+        }
+
+        Ok(())
+    }
+}
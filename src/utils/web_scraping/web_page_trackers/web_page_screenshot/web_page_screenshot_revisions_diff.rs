@@ -0,0 +1,138 @@
+use crate::utils::web_scraping::{WebPageDataRevision, WebPageScreenshotTrackerTag};
+use image::ImageFormat;
+
+/// Maximum difference between the values of the same color channel of two pixels that is still
+/// treated as the same color (compensates for anti-aliasing and compression artifacts).
+const PIXEL_CHANNEL_TOLERANCE: u8 = 8;
+
+/// Compares two PNG-encoded screenshots and returns the share of pixels (`0.0` to `1.0`) that
+/// differ. Screenshots with different dimensions are considered completely different.
+pub fn web_page_screenshots_diff(previous: &[u8], current: &[u8]) -> anyhow::Result<f32> {
+    let previous = image::load_from_memory_with_format(previous, ImageFormat::Png)?.to_rgba8();
+    let current = image::load_from_memory_with_format(current, ImageFormat::Png)?.to_rgba8();
+    if previous.dimensions() != current.dimensions() {
+        return Ok(1.0);
+    }
+
+    let total_pixels = previous.pixels().len();
+    if total_pixels == 0 {
+        return Ok(0.0);
+    }
+
+    let changed_pixels = previous
+        .pixels()
+        .zip(current.pixels())
+        .filter(|(previous_pixel, current_pixel)| {
+            previous_pixel.0.iter().zip(current_pixel.0.iter()).any(
+                |(previous_channel, current_channel)| {
+                    previous_channel.abs_diff(*current_channel) > PIXEL_CHANNEL_TOLERANCE
+                },
+            )
+        })
+        .count();
+
+    Ok(changed_pixels as f32 / total_pixels as f32)
+}
+
+/// Takes multiple web page screenshot revisions and calculates the diff.
+pub fn web_page_screenshot_revisions_diff(
+    revisions: Vec<WebPageDataRevision<WebPageScreenshotTrackerTag>>,
+) -> anyhow::Result<Vec<WebPageDataRevision<WebPageScreenshotTrackerTag>>> {
+    if revisions.len() < 2 {
+        return Ok(revisions);
+    }
+
+    let mut revisions_diff = Vec::with_capacity(revisions.len());
+    let mut peekable_revisions = revisions.into_iter().rev().peekable();
+    while let Some(mut current_revision) = peekable_revisions.next() {
+        if let Some(previous_revision) = peekable_revisions.peek() {
+            current_revision.data.diff = Some(web_page_screenshots_diff(
+                &previous_revision.data.image,
+                &current_revision.data.image,
+            )?);
+        }
+        revisions_diff.push(current_revision);
+    }
+
+    Ok(revisions_diff.into_iter().rev().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{web_page_screenshot_revisions_diff, web_page_screenshots_diff};
+    use crate::utils::web_scraping::{
+        tests::mock_screenshot, WebPageDataRevision, WebPageScreenshotData,
+    };
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn correctly_calculates_screenshots_diff() -> anyhow::Result<()> {
+        assert_eq!(
+            web_page_screenshots_diff(&mock_screenshot(10, 10, 0)?, &mock_screenshot(10, 10, 0)?)?,
+            0.0
+        );
+        assert_eq!(
+            web_page_screenshots_diff(&mock_screenshot(10, 10, 0)?, &mock_screenshot(10, 10, 25)?)?,
+            0.25
+        );
+        assert_eq!(
+            web_page_screenshots_diff(&mock_screenshot(10, 10, 50)?, &mock_screenshot(10, 10, 0)?)?,
+            0.5
+        );
+        assert_eq!(
+            web_page_screenshots_diff(&mock_screenshot(10, 10, 0)?, &mock_screenshot(10, 20, 0)?)?,
+            1.0
+        );
+
+        assert!(web_page_screenshots_diff(&[1, 2, 3], &mock_screenshot(10, 10, 0)?).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn correctly_calculates_revisions_diff() -> anyhow::Result<()> {
+        let revisions = vec![
+            WebPageDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                data: WebPageScreenshotData {
+                    image: mock_screenshot(10, 10, 0)?,
+                    diff: None,
+                },
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            },
+            WebPageDataRevision {
+                id: uuid!("00000000-0000-0000-0000-000000000003"),
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                data: WebPageScreenshotData {
+                    image: mock_screenshot(10, 10, 10)?,
+                    diff: None,
+                },
+                created_at: OffsetDateTime::from_unix_timestamp(946720900)?,
+                note: None,
+                acknowledgment: None,
+                diff_stats: None,
+                assertions_passed: None,
+            },
+        ];
+
+        let diff = web_page_screenshot_revisions_diff(revisions.clone())?
+            .into_iter()
+            .map(|revision| revision.data.diff)
+            .collect::<Vec<_>>();
+        assert_eq!(diff, vec![None, Some(0.1)]);
+
+        let single_revision = vec![revisions[0].clone()];
+        assert_eq!(
+            web_page_screenshot_revisions_diff(single_revision.clone())?,
+            single_revision
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+use crate::utils::web_scraping::{WebPageScreenshotData, WebPageTrackerKind, WebPageTrackerTag};
+
+/// Struct that represents a tag for the `WebPageTracker` that tracks the visual appearance (full
+/// page screenshot) of a web page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebPageScreenshotTrackerTag(());
+impl WebPageTrackerTag for WebPageScreenshotTrackerTag {
+    const KIND: WebPageTrackerKind = WebPageTrackerKind::WebPageScreenshot;
+    type TrackerMeta = ();
+    type TrackerData = WebPageScreenshotData;
+}
@@ -0,0 +1,163 @@
+use crate::utils::web_scraping::WebScraperCallback;
+use serde::Serialize;
+use std::{collections::HashMap, time::Duration};
+use url::Url;
+
+/// Represents request to capture a full page screenshot of a web page.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebScraperScreenshotRequest<'a> {
+    /// URL of the web page to capture screenshot for.
+    pub url: &'a Url,
+
+    /// Number of milliseconds to wait until page enters "idle" state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<usize>,
+
+    /// Number of milliseconds to wait after page enters "idle" state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay: Option<u128>,
+
+    /// Optional CSS selector to wait for before capturing screenshot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_selector: Option<&'a str>,
+
+    /// Optional headers to attach to every request to the tracked web page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<&'a HashMap<String, String>>,
+
+    /// Indicates whether HTTPS errors (e.g. invalid or self-signed certificates) should be ignored.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub ignore_https_errors: bool,
+
+    /// Indicates whether JavaScript execution should be disabled for the web page.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub disable_js: bool,
+
+    /// Optional callback the web scraper should deliver the screenshot to. If specified, the web
+    /// scraper captures the screenshot asynchronously and responds with the scrape job ID only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback: Option<WebScraperCallback<'a>>,
+}
+
+impl<'a> WebScraperScreenshotRequest<'a> {
+    /// Creates request with only the URL of the web page to capture screenshot for, the rest of
+    /// the parameters are omitted.
+    pub fn with_default_parameters(url: &'a Url) -> Self {
+        Self {
+            url,
+            timeout: None,
+            delay: None,
+            wait_selector: None,
+            headers: None,
+            ignore_https_errors: false,
+            disable_js: false,
+            callback: None,
+        }
+    }
+
+    /// Sets the delay to wait after web page enters "idle" state to capture screenshot.
+    pub fn set_delay(self, delay: Duration) -> Self {
+        Self {
+            delay: Some(delay.as_millis()),
+            ..self
+        }
+    }
+
+    /// Sets headers to attach to every request to the tracked web page.
+    pub fn set_headers(self, headers: &'a HashMap<String, String>) -> Self {
+        Self {
+            headers: Some(headers),
+            ..self
+        }
+    }
+
+    /// Sets whether HTTPS errors (e.g. invalid or self-signed certificates) should be ignored.
+    pub fn set_ignore_https_errors(self, ignore_https_errors: bool) -> Self {
+        Self {
+            ignore_https_errors,
+            ..self
+        }
+    }
+
+    /// Sets whether JavaScript execution should be disabled for the web page.
+    pub fn set_disable_js(self, disable_js: bool) -> Self {
+        Self { disable_js, ..self }
+    }
+
+    /// Sets the callback the web scraper should deliver the screenshot to asynchronously.
+    pub fn set_callback(self, callback: WebScraperCallback<'a>) -> Self {
+        Self {
+            callback: Some(callback),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebScraperScreenshotRequest;
+    use crate::utils::web_scraping::WebScraperCallback;
+    use insta::assert_json_snapshot;
+    use std::time::Duration;
+    use url::Url;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebScraperScreenshotRequest {
+            url: &Url::parse("http://localhost:1234/my/app?q=2")?,
+            timeout: Some(100),
+            delay: Some(200),
+            wait_selector: Some("body"),
+            headers: Some(
+                &[("cookie".to_string(), "my-cookie".to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+            ignore_https_errors: true,
+            disable_js: true,
+            callback: Some(WebScraperCallback {
+                url: &Url::parse("https://secutils.dev/api/web_scraping/scraper_callback")?,
+                token: "some-token",
+            }),
+        }, @r###"
+        {
+          "url": "http://localhost:1234/my/app?q=2",
+          "timeout": 100,
+          "delay": 200,
+          "waitSelector": "body",
+          "headers": {
+            "cookie": "my-cookie"
+          },
+          "ignoreHttpsErrors": true,
+          "disableJs": true,
+          "callback": {
+            "url": "https://secutils.dev/api/web_scraping/scraper_callback",
+            "token": "some-token"
+          }
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn serialization_with_default_parameters() -> anyhow::Result<()> {
+        let url = Url::parse("http://localhost:1234/my/app?q=2")?;
+        assert_json_snapshot!(WebScraperScreenshotRequest::with_default_parameters(&url), @r###"
+        {
+          "url": "http://localhost:1234/my/app?q=2"
+        }
+        "###);
+
+        assert_json_snapshot!(WebScraperScreenshotRequest::with_default_parameters(&url)
+            .set_delay(Duration::from_millis(2000)), @r###"
+        {
+          "url": "http://localhost:1234/my/app?q=2",
+          "delay": 2000
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{base64::Base64, serde_as};
+use time::OffsetDateTime;
+
+/// Represents response with the captured web page screenshot.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebScraperScreenshotResponse {
+    /// Timestamp indicating when screenshot was captured.
+    #[serde(with = "time::serde::timestamp")]
+    pub timestamp: OffsetDateTime,
+    /// Base64-encoded PNG screenshot of the web page.
+    #[serde_as(as = "Base64")]
+    pub screenshot: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebScraperScreenshotResponse;
+    use insta::assert_json_snapshot;
+    use time::OffsetDateTime;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebScraperScreenshotResponse>(
+                r#"
+{
+    "timestamp": 946720800,
+    "screenshot": "AQID"
+}
+          "#
+            )?,
+            WebScraperScreenshotResponse {
+                // January 1, 2000 11:00:00
+                timestamp: OffsetDateTime::from_unix_timestamp(946720800)?,
+                screenshot: vec![1, 2, 3],
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebScraperScreenshotResponse {
+            // January 1, 2000 11:00:00
+            timestamp: OffsetDateTime::from_unix_timestamp(946720800)?,
+            screenshot: vec![1, 2, 3],
+        }, @r###"
+        {
+          "timestamp": 946720800,
+          "screenshot": "AQID"
+        }
+        "###);
+
+        Ok(())
+    }
+}
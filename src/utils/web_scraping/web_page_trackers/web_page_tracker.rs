@@ -186,7 +186,9 @@ mod tests {
             retry_strategy: Some(SchedulerJobRetryStrategy::Constant {
                 interval: Duration::from_secs(1000),
                 max_attempts: 10,
+                max_total_retry_duration: None,
             }),
+            adaptive_interval: None,
         })
         .build();
         assert_json_snapshot!(tracker, @r###"
@@ -0,0 +1,107 @@
+use crate::utils::web_scraping::WebPageTrackerKind;
+use serde::Serialize;
+use time::OffsetDateTime;
+use url::Url;
+use uuid::Uuid;
+
+/// Describes a single change (revision) detected by one of the user's web page trackers, used to
+/// build the feed of recent changes across all trackers.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerChange {
+    /// ID of the tracker that detected the change.
+    pub tracker_id: Uuid,
+    /// Name of the tracker that detected the change.
+    pub tracker_name: String,
+    /// Kind of the tracker that detected the change.
+    pub tracker_kind: WebPageTrackerKind,
+    /// URL of the web page the tracker tracks.
+    pub url: Url,
+    /// ID of the tracker revision that captured the change.
+    pub revision_id: Uuid,
+    /// Timestamp indicating when the change was detected.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+    /// Human-readable summary of the change.
+    pub summary: String,
+    /// Optional user note attached to the revision.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl WebPageTrackerChange {
+    /// Returns a human-readable summary of the change captured by the tracker of the specified
+    /// kind. The very first revision of the tracker is a baseline rather than a change.
+    pub fn summarize(tracker_kind: WebPageTrackerKind, is_initial: bool) -> String {
+        let (data, verb) = match tracker_kind {
+            WebPageTrackerKind::WebPageResources => ("resources", "have"),
+            WebPageTrackerKind::WebPageContent => ("content", "has"),
+            WebPageTrackerKind::WebPageScreenshot => ("screenshot", "has"),
+            WebPageTrackerKind::WebPageDnsRecords => ("DNS records", "have"),
+        };
+
+        if is_initial {
+            format!("Initial web page {data} captured.")
+        } else {
+            format!("Web page {data} {verb} changed.")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageTrackerChange;
+    use crate::utils::web_scraping::WebPageTrackerKind;
+    use insta::assert_json_snapshot;
+    use time::OffsetDateTime;
+    use url::Url;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageTrackerChange {
+            tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+            tracker_name: "tk".to_string(),
+            tracker_kind: WebPageTrackerKind::WebPageContent,
+            url: Url::parse("https://secutils.dev")?,
+            revision_id: uuid!("00000000-0000-0000-0000-000000000002"),
+            // January 1, 2000 10:00:00
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            summary: "Web page content has changed.".to_string(),
+            note: Some("Expected change".to_string()),
+        }, @r###"
+        {
+          "trackerId": "00000000-0000-0000-0000-000000000001",
+          "trackerName": "tk",
+          "trackerKind": "WebPageContent",
+          "url": "https://secutils.dev/",
+          "revisionId": "00000000-0000-0000-0000-000000000002",
+          "createdAt": 946720800,
+          "summary": "Web page content has changed.",
+          "note": "Expected change"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn summarize() {
+        assert_eq!(
+            WebPageTrackerChange::summarize(WebPageTrackerKind::WebPageResources, false),
+            "Web page resources have changed."
+        );
+        assert_eq!(
+            WebPageTrackerChange::summarize(WebPageTrackerKind::WebPageContent, false),
+            "Web page content has changed."
+        );
+        assert_eq!(
+            WebPageTrackerChange::summarize(WebPageTrackerKind::WebPageScreenshot, false),
+            "Web page screenshot has changed."
+        );
+        assert_eq!(
+            WebPageTrackerChange::summarize(WebPageTrackerKind::WebPageDnsRecords, true),
+            "Initial web page DNS records captured."
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+/// Defines the classification of the data collected by the web page tracker.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WebPageTrackerDataClassification {
+    /// Data that's publicly available and doesn't require any special handling.
+    Public,
+    /// Data that's intended for internal use only.
+    Internal,
+    /// Sensitive data that should be accessible only to a limited audience.
+    Confidential,
+    /// Data that may include personal information and is subject to privacy regulations.
+    Personal,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageTrackerDataClassification;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageTrackerDataClassification::Public, @r###""public""###);
+        assert_json_snapshot!(WebPageTrackerDataClassification::Internal, @r###""internal""###);
+        assert_json_snapshot!(WebPageTrackerDataClassification::Confidential, @r###""confidential""###);
+        assert_json_snapshot!(WebPageTrackerDataClassification::Personal, @r###""personal""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerDataClassification>(r#""public""#)?,
+            WebPageTrackerDataClassification::Public
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerDataClassification>(r#""internal""#)?,
+            WebPageTrackerDataClassification::Internal
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerDataClassification>(r#""confidential""#)?,
+            WebPageTrackerDataClassification::Confidential
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerDataClassification>(r#""personal""#)?,
+            WebPageTrackerDataClassification::Personal
+        );
+
+        Ok(())
+    }
+}
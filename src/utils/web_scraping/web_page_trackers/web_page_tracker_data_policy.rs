@@ -0,0 +1,75 @@
+use crate::utils::web_scraping::WebPageTrackerDataClassification;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DurationMilliSeconds};
+use std::time::Duration;
+
+/// Defines how the data collected by the web page tracker should be classified and how long it
+/// should be retained.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerDataPolicy {
+    /// Classification of the data collected by the tracker.
+    pub classification: WebPageTrackerDataClassification,
+    /// Optional maximum age of the tracker revisions. Revisions older than this are purged by the
+    /// retention cleanup, even if the tracker hasn't reached the maximum number of revisions yet.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retention: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::{WebPageTrackerDataClassification, WebPageTrackerDataPolicy};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageTrackerDataPolicy {
+            classification: WebPageTrackerDataClassification::Internal,
+            retention: None,
+        }, @r###"
+        {
+          "classification": "internal"
+        }
+        "###);
+        assert_json_snapshot!(WebPageTrackerDataPolicy {
+            classification: WebPageTrackerDataClassification::Personal,
+            retention: Some(Duration::from_secs(86400)),
+        }, @r###"
+        {
+          "classification": "personal",
+          "retention": 86400000
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<WebPageTrackerDataPolicy>(json!({
+                "classification": "internal"
+            }))?,
+            WebPageTrackerDataPolicy {
+                classification: WebPageTrackerDataClassification::Internal,
+                retention: None,
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<WebPageTrackerDataPolicy>(json!({
+                "classification": "personal",
+                "retention": 86400000
+            }))?,
+            WebPageTrackerDataPolicy {
+                classification: WebPageTrackerDataClassification::Personal,
+                retention: Some(Duration::from_secs(86400)),
+            }
+        );
+
+        Ok(())
+    }
+}
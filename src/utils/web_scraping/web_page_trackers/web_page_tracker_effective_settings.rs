@@ -0,0 +1,61 @@
+use crate::utils::web_scraping::WebPageTrackerSettings;
+use serde::Serialize;
+use serde_with::{serde_as, DurationMilliSeconds};
+use std::time::Duration;
+
+/// Describes the settings that are actually applied to the web page tracker, after the tracker
+/// settings are resolved against the limits of the user subscription.
+#[serde_as]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerEffectiveSettings {
+    /// Tracker settings with the subscription limits applied (e.g. the number of revisions).
+    pub settings: WebPageTrackerSettings,
+    /// Minimum interval between two consequent scheduled checks of the tracker, if the tracker is
+    /// scheduled.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_schedule_interval: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::{WebPageTrackerEffectiveSettings, WebPageTrackerSettings};
+    use insta::assert_json_snapshot;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let settings = WebPageTrackerSettings {
+            revisions: 3,
+            delay: Duration::from_millis(2500),
+            ..Default::default()
+        };
+
+        assert_json_snapshot!(WebPageTrackerEffectiveSettings {
+            settings: settings.clone(),
+            min_schedule_interval: None,
+        }, @r###"
+        {
+          "settings": {
+            "revisions": 3,
+            "delay": 2500
+          }
+        }
+        "###);
+        assert_json_snapshot!(WebPageTrackerEffectiveSettings {
+            settings,
+            min_schedule_interval: Some(Duration::from_secs(3600)),
+        }, @r###"
+        {
+          "settings": {
+            "revisions": 3,
+            "delay": 2500
+          },
+          "minScheduleInterval": 3600000
+        }
+        "###);
+
+        Ok(())
+    }
+}
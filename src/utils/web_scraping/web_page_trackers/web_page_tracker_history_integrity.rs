@@ -0,0 +1,51 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Result of the web page tracker history integrity verification.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerHistoryIntegrity {
+    /// Number of the verified revisions.
+    pub revisions: usize,
+    /// Indicates whether the hash chain of the revisions is intact.
+    pub intact: bool,
+    /// ID of the first revision that breaks the hash chain (e.g. its data was altered, or the
+    /// revision preceding it was altered or removed), if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub broken_revision_id: Option<Uuid>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageTrackerHistoryIntegrity;
+    use insta::assert_json_snapshot;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageTrackerHistoryIntegrity {
+            revisions: 3,
+            intact: true,
+            broken_revision_id: None,
+        }, @r###"
+        {
+          "revisions": 3,
+          "intact": true
+        }
+        "###);
+
+        assert_json_snapshot!(WebPageTrackerHistoryIntegrity {
+            revisions: 3,
+            intact: false,
+            broken_revision_id: Some(uuid!("00000000-0000-0000-0000-000000000002")),
+        }, @r###"
+        {
+          "revisions": 3,
+          "intact": false,
+          "brokenRevisionId": "00000000-0000-0000-0000-000000000002"
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Result of the web page tracker import for a single web page URL.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerImportResult {
+    /// URL of the web page as it was provided in the import source.
+    pub url: String,
+    /// ID of the created web page tracker, if import succeeded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracker_id: Option<Uuid>,
+    /// Reason why web page tracker couldn't be created, if import failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageTrackerImportResult;
+    use insta::assert_json_snapshot;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageTrackerImportResult {
+            url: "https://secutils.dev/one".to_string(),
+            tracker_id: Some(uuid!("00000000-0000-0000-0000-000000000001")),
+            error: None,
+        }, @r###"
+        {
+          "url": "https://secutils.dev/one",
+          "trackerId": "00000000-0000-0000-0000-000000000001"
+        }
+        "###);
+
+        assert_json_snapshot!(WebPageTrackerImportResult {
+            url: "not-a-url".to_string(),
+            tracker_id: None,
+            error: Some("Web page URL isn't valid.".to_string()),
+        }, @r###"
+        {
+          "url": "not-a-url",
+          "error": "Web page URL isn't valid."
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
 
 /// Represents type of the web page tracker (e.g. resources, content, etc.).
+#[allow(clippy::enum_variant_names)]
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Hash, Eq)]
 pub enum WebPageTrackerKind {
     WebPageResources,
     WebPageContent,
+    WebPageScreenshot,
+    WebPageDnsRecords,
 }
 
 impl TryFrom<WebPageTrackerKind> for Vec<u8> {
@@ -27,6 +30,14 @@ mod tests {
             vec![0]
         );
         assert_eq!(Vec::try_from(WebPageTrackerKind::WebPageContent)?, vec![1]);
+        assert_eq!(
+            Vec::try_from(WebPageTrackerKind::WebPageScreenshot)?,
+            vec![2]
+        );
+        assert_eq!(
+            Vec::try_from(WebPageTrackerKind::WebPageDnsRecords)?,
+            vec![3]
+        );
 
         Ok(())
     }
@@ -43,7 +54,17 @@ mod tests {
             WebPageTrackerKind::WebPageContent
         );
 
-        assert_debug_snapshot!(postcard::from_bytes::<WebPageTrackerKind>([2].as_ref()), @r###"
+        assert_eq!(
+            postcard::from_bytes::<WebPageTrackerKind>([2].as_ref())?,
+            WebPageTrackerKind::WebPageScreenshot
+        );
+
+        assert_eq!(
+            postcard::from_bytes::<WebPageTrackerKind>([3].as_ref())?,
+            WebPageTrackerKind::WebPageDnsRecords
+        );
+
+        assert_debug_snapshot!(postcard::from_bytes::<WebPageTrackerKind>([4].as_ref()), @r###"
         Err(
             SerdeDeCustom,
         )
@@ -0,0 +1,16 @@
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Describes a scrape job of the web page tracker that was submitted to the web scraper in the
+/// asynchronous mode, and whose result hasn't been delivered to the callback yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebPageTrackerPendingScrape {
+    /// Unique ID of the scrape job assigned by the web scraper.
+    pub job_id: String,
+    /// ID of the tracker the scrape job was submitted for.
+    pub tracker_id: Uuid,
+    /// Token the web scraper should send back along with the result to authenticate itself.
+    pub token: String,
+    /// Date and time when the scrape job was submitted.
+    pub created_at: OffsetDateTime,
+}
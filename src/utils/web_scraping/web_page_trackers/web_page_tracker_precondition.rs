@@ -0,0 +1,175 @@
+use crate::utils::web_scraping::WebPageTrackerPreconditionOperator;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+/// Defines a condition that should be met for the web page tracker to run on schedule. The
+/// condition is checked against the latest content extracted by another web page content tracker.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerPrecondition {
+    /// ID of the web page content tracker whose latest extracted content is checked.
+    pub tracker_id: Uuid,
+    /// Optional JSON pointer (e.g. `/status`) to the value within the extracted content if it's a
+    /// JSON value. If not specified, the whole extracted content is checked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pointer: Option<String>,
+    /// Operator used to compare the referenced value with the expected value.
+    pub operator: WebPageTrackerPreconditionOperator,
+    /// The expected value.
+    pub value: String,
+}
+
+impl WebPageTrackerPrecondition {
+    /// Checks whether the precondition is met for the specified extracted content. If the
+    /// referenced value doesn't exist, the precondition isn't met.
+    pub fn is_met(&self, content: &str) -> bool {
+        // Extracted content is usually a JSON value, but it's not guaranteed.
+        let json_content = serde_json::from_str::<JsonValue>(content).ok();
+        let value = match (&self.pointer, &json_content) {
+            (Some(pointer), Some(json_content)) => json_content.pointer(pointer),
+            (Some(_), None) => None,
+            (None, Some(json_content)) => Some(json_content),
+            (None, None) => return self.compare(content),
+        };
+
+        match value {
+            Some(JsonValue::String(value)) => self.compare(value),
+            Some(value) => self.compare(&value.to_string()),
+            None => false,
+        }
+    }
+
+    fn compare(&self, value: &str) -> bool {
+        match self.operator {
+            WebPageTrackerPreconditionOperator::Equals => value == self.value,
+            WebPageTrackerPreconditionOperator::NotEquals => value != self.value,
+            WebPageTrackerPreconditionOperator::Contains => value.contains(&self.value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::{
+        WebPageTrackerPrecondition, WebPageTrackerPreconditionOperator,
+    };
+    use insta::assert_json_snapshot;
+    use uuid::uuid;
+
+    fn precondition(
+        pointer: Option<&str>,
+        operator: WebPageTrackerPreconditionOperator,
+        value: &str,
+    ) -> WebPageTrackerPrecondition {
+        WebPageTrackerPrecondition {
+            tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+            pointer: pointer.map(|pointer| pointer.to_string()),
+            operator,
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(precondition(None, WebPageTrackerPreconditionOperator::Equals, "updated"), @r###"
+        {
+          "trackerId": "00000000-0000-0000-0000-000000000001",
+          "operator": "equals",
+          "value": "updated"
+        }
+        "###);
+        assert_json_snapshot!(precondition(Some("/status"), WebPageTrackerPreconditionOperator::Contains, "updated"), @r###"
+        {
+          "trackerId": "00000000-0000-0000-0000-000000000001",
+          "pointer": "/status",
+          "operator": "contains",
+          "value": "updated"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerPrecondition>(
+                r#"{ "trackerId": "00000000-0000-0000-0000-000000000001", "operator": "notEquals", "value": "updated" }"#
+            )?,
+            precondition(
+                None,
+                WebPageTrackerPreconditionOperator::NotEquals,
+                "updated"
+            )
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerPrecondition>(
+                r#"{ "trackerId": "00000000-0000-0000-0000-000000000001", "pointer": "/status", "operator": "equals", "value": "updated" }"#
+            )?,
+            precondition(
+                Some("/status"),
+                WebPageTrackerPreconditionOperator::Equals,
+                "updated"
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn checks_whole_content() -> anyhow::Result<()> {
+        let equals = precondition(None, WebPageTrackerPreconditionOperator::Equals, "updated");
+        assert!(equals.is_met("\"updated\""));
+        assert!(equals.is_met("updated"));
+        assert!(!equals.is_met("\"not updated\""));
+
+        let not_equals = precondition(
+            None,
+            WebPageTrackerPreconditionOperator::NotEquals,
+            "updated",
+        );
+        assert!(!not_equals.is_met("\"updated\""));
+        assert!(not_equals.is_met("\"stale\""));
+
+        let contains = precondition(
+            None,
+            WebPageTrackerPreconditionOperator::Contains,
+            "updated",
+        );
+        assert!(contains.is_met("\"Status: updated yesterday\""));
+        assert!(!contains.is_met("\"Status: stale\""));
+
+        // Non-string JSON values are compared using their JSON representation.
+        let equals = precondition(None, WebPageTrackerPreconditionOperator::Equals, "42");
+        assert!(equals.is_met("42"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn checks_content_value_by_pointer() -> anyhow::Result<()> {
+        let equals = precondition(
+            Some("/status"),
+            WebPageTrackerPreconditionOperator::Equals,
+            "updated",
+        );
+        assert!(equals.is_met(r#"{ "status": "updated" }"#));
+        assert!(!equals.is_met(r#"{ "status": "stale" }"#));
+
+        // Missing values never meet the precondition.
+        assert!(!equals.is_met(r#"{ "state": "updated" }"#));
+        assert!(!equals.is_met("updated"));
+
+        let not_equals = precondition(
+            Some("/items/0/version"),
+            WebPageTrackerPreconditionOperator::NotEquals,
+            "1",
+        );
+        assert!(not_equals.is_met(r#"{ "items": [{ "version": 2 }] }"#));
+        assert!(!not_equals.is_met(r#"{ "items": [{ "version": 1 }] }"#));
+        assert!(!not_equals.is_met(r#"{ "items": [] }"#));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// Defines how the value referenced by the web page tracker precondition is compared with the
+/// expected value.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WebPageTrackerPreconditionOperator {
+    /// The value should be equal to the expected value.
+    Equals,
+    /// The value should not be equal to the expected value.
+    NotEquals,
+    /// The value should contain the expected value.
+    Contains,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_scraping::WebPageTrackerPreconditionOperator;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageTrackerPreconditionOperator::Equals, @r###""equals""###);
+        assert_json_snapshot!(WebPageTrackerPreconditionOperator::NotEquals, @r###""notEquals""###);
+        assert_json_snapshot!(WebPageTrackerPreconditionOperator::Contains, @r###""contains""###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerPreconditionOperator>(r#""equals""#)?,
+            WebPageTrackerPreconditionOperator::Equals
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerPreconditionOperator>(r#""notEquals""#)?,
+            WebPageTrackerPreconditionOperator::NotEquals
+        );
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerPreconditionOperator>(r#""contains""#)?,
+            WebPageTrackerPreconditionOperator::Contains
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+/// Result of the web page tracker script test run against the current version of the web page.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerScriptTestResult {
+    /// Output produced by the script, if it completed successfully.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<JsonValue>,
+    /// Error produced by the script, if it failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<WebPageTrackerScriptTestError>,
+}
+
+/// Describes an error produced by the web page tracker script.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerScriptTestError {
+    /// Error message.
+    pub message: String,
+    /// Line number within the script where error occurred, if available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_number: Option<i64>,
+    /// Column number within the script where error occurred, if available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column_number: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WebPageTrackerScriptTestError, WebPageTrackerScriptTestResult};
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageTrackerScriptTestResult {
+            output: Some(json!(["one", "two"])),
+            error: None,
+        }, @r###"
+        {
+          "output": [
+            "one",
+            "two"
+          ]
+        }
+        "###);
+
+        assert_json_snapshot!(WebPageTrackerScriptTestResult {
+            output: None,
+            error: Some(WebPageTrackerScriptTestError {
+                message: "Uncaught Error: Uh oh.".to_string(),
+                line_number: Some(2),
+                column_number: Some(7),
+            }),
+        }, @r###"
+        {
+          "error": {
+            "message": "Uncaught Error: Uh oh.",
+            "lineNumber": 2,
+            "columnNumber": 7
+          }
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -1,6 +1,13 @@
+use crate::utils::web_scraping::{
+    WebPageContentAssertion, WebPageContentCookiesCapture, WebPageContentFormat,
+    WebPageContentResponseCapture, WebPageDnsRecordType, WebPageResourceType,
+    WebPageResourcesSizeBudget, WebPageTrackerDataPolicy, WebPageTrackerPrecondition,
+};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DurationMilliSeconds};
 use std::{collections::HashMap, time::Duration};
+use url::Url;
+use uuid::Uuid;
 
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -17,24 +24,200 @@ pub struct WebPageTrackerSettings {
     /// Optional list of HTTP headers that should be sent with the tracker requests.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub headers: Option<HashMap<String, String>>,
+    /// Optional list of regular expressions for the resource URLs that should be ignored when
+    /// deciding whether web page resources have changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_resource_urls: Option<Vec<String>>,
+    /// Indicates whether web page content tracker should only track HTTP status of the web page
+    /// instead of its content.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub status_only: bool,
+    /// Indicates whether the tracker should skip TLS certificate verification for the tracked web
+    /// page (e.g. for internal services with self-signed certificates). Only operators can enable
+    /// this setting.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub insecure_tls: bool,
+    /// Optional minimum interval between consecutive change notifications for the tracker. Changes
+    /// detected within this interval are still recorded, but reported in a single summary
+    /// notification once the interval elapses.
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_cooldown: Option<Duration>,
+    /// Indicates whether notifications for the tracker are urgent and should be sent even during
+    /// user's quiet hours.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub urgent_notifications: bool,
+    /// Optional ID of another tracker of the same kind this tracker depends on. If both trackers
+    /// are due in the same scheduler tick, this tracker is fetched only after the dependency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<Uuid>,
+    /// Optional inclusive range of bytes (`[start, end]`) of the web page content to capture,
+    /// requested with the HTTP `Range` header. Useful to track only the beginning of large files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub byte_range: Option<(u64, u64)>,
+    /// Optional condition based on the content extracted by another tracker that should be met
+    /// for this tracker to run on schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precondition: Option<WebPageTrackerPrecondition>,
+    /// Indicates whether web page resources tracker should tag the known third-party resources
+    /// (e.g. analytics, advertising, or tag manager scripts) and count them per category.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub classify_resources: bool,
+    /// Optional mode in which web page content tracker captures cookies set by the web page
+    /// (`Set-Cookie` headers) into the revision, so that changes in cookie attributes are tracked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capture_cookies: Option<WebPageContentCookiesCapture>,
+    /// Optional number of the most recent revisions web page content tracker should compare the
+    /// newly fetched content against. If the content matches any of them (e.g. the web page flaps
+    /// between two states), a new revision isn't created. By default, only the latest revision is
+    /// compared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dedup_window: Option<usize>,
+    /// Optional performance budget for the total size of the resources tracked by the web page
+    /// resources tracker. If the budget is exceeded, the tracker sends a separate notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_budget: Option<WebPageResourcesSizeBudget>,
+    /// Indicates whether the web scraper should execute JavaScript on the tracked web page. When
+    /// disabled, the scraper captures the web page as it's served, without running any scripts,
+    /// which is faster and more stable for static content, and helps to detect content that's
+    /// only present when JavaScript is off. Enabled by default.
+    #[serde(
+        default = "WebPageTrackerSettings::default_render_js",
+        skip_serializing_if = "WebPageTrackerSettings::is_default_render_js"
+    )]
+    pub render_js: bool,
+    /// Optional data policy that defines how the data collected by the tracker is classified and
+    /// how long it's retained, overriding the default retention of the tracker revisions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_policy: Option<WebPageTrackerDataPolicy>,
+    /// Optional mode in which web page content tracker captures the response of a JSON API
+    /// (HTTP status, selected headers, and body) as a single unit instead of the web page
+    /// content, so that changes in any part of the response are tracked together.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capture_response: Option<WebPageContentResponseCapture>,
+    /// Optional list of the resource types web page resources tracker should store and diff. If
+    /// not specified, resources of all types are tracked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_types: Option<Vec<WebPageResourceType>>,
+    /// Optional list of the DNS record types web page DNS records tracker should resolve and diff.
+    /// If not specified, records of all supported types are tracked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_types: Option<Vec<WebPageDnsRecordType>>,
+    /// Indicates whether web page content tracker should notify about the very first revision.
+    /// By default, the first revision is recorded silently as a baseline, and only subsequent
+    /// changes trigger notifications.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub notify_on_first: bool,
+    /// Indicates whether web page content tracker should only capture a fingerprint (SHA-256 hash,
+    /// size, and content type) of the resource (e.g. favicon) instead of its content. It's useful
+    /// to detect site takeovers or rebrands without storing binary resources.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub capture_fingerprint: bool,
+    /// Optional list of additional URLs web page content tracker should monitor together with the
+    /// main tracker URL in the status-only mode (e.g. for a "site health" tracker). Status of every
+    /// URL is tracked separately, and change notifications list only the URLs whose status changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_urls: Option<Vec<Url>>,
+    /// Optional structured format of the content web page content tracker extracts (e.g.
+    /// `robots.txt` or XML sitemap). Content in the structured format is compared semantically,
+    /// so that reordered, but equivalent content isn't reported as changed, and change
+    /// notifications list added and removed entries (e.g. `Added Disallow: /admin`). If content
+    /// cannot be parsed, it's compared as plain text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_format: Option<WebPageContentFormat>,
+    /// Optional maximum number of concurrent requests the tracker sends within a single run when
+    /// it needs to fetch multiple URLs (e.g. additional URLs in the status-only mode). If not
+    /// specified, the global default is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_fetch_concurrency: Option<usize>,
+    /// Indicates whether web page content tracker should store intermediate revisions as diffs
+    /// against the previous revision instead of the full content to save space. Full snapshots are
+    /// still stored periodically, and the full content is reconstructed transparently on read.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub store_diffs: bool,
+    /// Optional list of assertions about the content extracted by web page content tracker (e.g.
+    /// the page should contain "Add to cart"). Assertions are evaluated on every run, the result is
+    /// stored with the revision, and the user is notified when assertions start or stop passing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assertions: Option<Vec<WebPageContentAssertion>>,
+    /// Optional HTTP method web page content tracker should use to request the web page instead of
+    /// `GET` (e.g. `POST` or a custom verb), so that the response to a non-`GET` request can be
+    /// tracked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// Optional body web page content tracker should send with the request to the web page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<String>,
+}
+
+impl Default for WebPageTrackerSettings {
+    fn default() -> Self {
+        Self {
+            revisions: 0,
+            delay: Duration::ZERO,
+            scripts: None,
+            headers: None,
+            ignore_resource_urls: None,
+            status_only: false,
+            insecure_tls: false,
+            notification_cooldown: None,
+            urgent_notifications: false,
+            depends_on: None,
+            byte_range: None,
+            precondition: None,
+            classify_resources: false,
+            capture_cookies: None,
+            dedup_window: None,
+            size_budget: None,
+            render_js: Self::default_render_js(),
+            data_policy: None,
+            capture_response: None,
+            resource_types: None,
+            record_types: None,
+            notify_on_first: false,
+            capture_fingerprint: false,
+            additional_urls: None,
+            content_format: None,
+            sub_fetch_concurrency: None,
+            store_diffs: false,
+            assertions: None,
+            method: None,
+            request_body: None,
+        }
+    }
+}
+
+impl WebPageTrackerSettings {
+    fn default_render_js() -> bool {
+        true
+    }
+
+    fn is_default_render_js(render_js: &bool) -> bool {
+        *render_js
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::utils::web_scraping::{
-        api_ext::WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME, WebPageTrackerSettings,
+        api_ext::WEB_PAGE_RESOURCES_TRACKER_FILTER_SCRIPT_NAME, WebPageContentAssertion,
+        WebPageContentAssertionOperator, WebPageContentCookiesCapture, WebPageContentFormat,
+        WebPageContentResponseCapture, WebPageDnsRecordType, WebPageResourceType,
+        WebPageResourcesSizeBudget, WebPageTrackerDataClassification, WebPageTrackerDataPolicy,
+        WebPageTrackerPrecondition, WebPageTrackerPreconditionOperator, WebPageTrackerSettings,
     };
     use insta::assert_json_snapshot;
     use serde_json::json;
     use std::time::Duration;
+    use url::Url;
+    use uuid::uuid;
 
     #[test]
     fn serialization() -> anyhow::Result<()> {
         let settings = WebPageTrackerSettings {
             revisions: 3,
             delay: Duration::from_millis(2500),
-            scripts: Default::default(),
-            headers: Default::default(),
+            ..Default::default()
         };
         assert_json_snapshot!(settings, @r###"
         {
@@ -59,6 +242,48 @@ mod tests {
                     .into_iter()
                     .collect(),
             ),
+            ignore_resource_urls: Some(vec!["ads\\.js$".to_string()]),
+            status_only: true,
+            insecure_tls: true,
+            notification_cooldown: Some(Duration::from_secs(3600)),
+            urgent_notifications: true,
+            depends_on: Some(uuid!("00000000-0000-0000-0000-000000000001")),
+            byte_range: Some((0, 4095)),
+            precondition: Some(WebPageTrackerPrecondition {
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                pointer: Some("/status".to_string()),
+                operator: WebPageTrackerPreconditionOperator::Equals,
+                value: "updated".to_string(),
+            }),
+            classify_resources: true,
+            capture_cookies: Some(WebPageContentCookiesCapture::Redacted),
+            dedup_window: Some(2),
+            size_budget: Some(WebPageResourcesSizeBudget {
+                max_total_size: Some(1024),
+                max_growth: Some(10),
+            }),
+            render_js: false,
+            data_policy: Some(WebPageTrackerDataPolicy {
+                classification: WebPageTrackerDataClassification::Personal,
+                retention: Some(Duration::from_secs(86400)),
+            }),
+            capture_response: Some(WebPageContentResponseCapture {
+                headers: vec!["ETag".to_string()],
+            }),
+            resource_types: Some(vec![WebPageResourceType::Script]),
+            record_types: Some(vec![WebPageDnsRecordType::Txt]),
+            notify_on_first: true,
+            capture_fingerprint: true,
+            additional_urls: Some(vec![Url::parse("https://secutils.dev/health")?]),
+            content_format: Some(WebPageContentFormat::RobotsTxt),
+            sub_fetch_concurrency: Some(2),
+            store_diffs: true,
+            assertions: Some(vec![WebPageContentAssertion {
+                operator: WebPageContentAssertionOperator::NotContains,
+                value: "error".to_string(),
+            }]),
+            method: Some("POST".to_string()),
+            request_body: Some("query=status".to_string()),
         };
         assert_json_snapshot!(settings, @r###"
         {
@@ -69,7 +294,64 @@ mod tests {
           },
           "headers": {
             "cookie": "my-cookie"
-          }
+          },
+          "ignoreResourceUrls": [
+            "ads\\.js$"
+          ],
+          "statusOnly": true,
+          "insecureTls": true,
+          "notificationCooldown": 3600000,
+          "urgentNotifications": true,
+          "dependsOn": "00000000-0000-0000-0000-000000000001",
+          "byteRange": [
+            0,
+            4095
+          ],
+          "precondition": {
+            "trackerId": "00000000-0000-0000-0000-000000000002",
+            "pointer": "/status",
+            "operator": "equals",
+            "value": "updated"
+          },
+          "classifyResources": true,
+          "captureCookies": "redacted",
+          "dedupWindow": 2,
+          "sizeBudget": {
+            "maxTotalSize": 1024,
+            "maxGrowth": 10
+          },
+          "renderJs": false,
+          "dataPolicy": {
+            "classification": "personal",
+            "retention": 86400000
+          },
+          "captureResponse": {
+            "headers": [
+              "ETag"
+            ]
+          },
+          "resourceTypes": [
+            "script"
+          ],
+          "recordTypes": [
+            "TXT"
+          ],
+          "notifyOnFirst": true,
+          "captureFingerprint": true,
+          "additionalUrls": [
+            "https://secutils.dev/health"
+          ],
+          "contentFormat": "robotsTxt",
+          "subFetchConcurrency": 2,
+          "storeDiffs": true,
+          "assertions": [
+            {
+              "operator": "notContains",
+              "value": "error"
+            }
+          ],
+          "method": "POST",
+          "requestBody": "query=status"
         }
         "###);
 
@@ -81,8 +363,7 @@ mod tests {
         let settings = WebPageTrackerSettings {
             revisions: 3,
             delay: Duration::from_millis(2000),
-            scripts: Default::default(),
-            headers: Default::default(),
+            ..Default::default()
         };
         assert_eq!(
             serde_json::from_str::<WebPageTrackerSettings>(
@@ -107,6 +388,48 @@ mod tests {
                     .into_iter()
                     .collect(),
             ),
+            ignore_resource_urls: Some(vec!["ads\\.js$".to_string()]),
+            status_only: true,
+            insecure_tls: true,
+            notification_cooldown: Some(Duration::from_secs(3600)),
+            urgent_notifications: true,
+            depends_on: Some(uuid!("00000000-0000-0000-0000-000000000001")),
+            byte_range: Some((0, 4095)),
+            precondition: Some(WebPageTrackerPrecondition {
+                tracker_id: uuid!("00000000-0000-0000-0000-000000000002"),
+                pointer: Some("/status".to_string()),
+                operator: WebPageTrackerPreconditionOperator::Equals,
+                value: "updated".to_string(),
+            }),
+            classify_resources: true,
+            capture_cookies: Some(WebPageContentCookiesCapture::Redacted),
+            dedup_window: Some(2),
+            size_budget: Some(WebPageResourcesSizeBudget {
+                max_total_size: Some(1024),
+                max_growth: Some(10),
+            }),
+            render_js: false,
+            data_policy: Some(WebPageTrackerDataPolicy {
+                classification: WebPageTrackerDataClassification::Personal,
+                retention: Some(Duration::from_secs(86400)),
+            }),
+            capture_response: Some(WebPageContentResponseCapture {
+                headers: vec!["ETag".to_string()],
+            }),
+            resource_types: Some(vec![WebPageResourceType::Script]),
+            record_types: Some(vec![WebPageDnsRecordType::Txt]),
+            notify_on_first: true,
+            capture_fingerprint: true,
+            additional_urls: Some(vec![Url::parse("https://secutils.dev/health")?]),
+            content_format: Some(WebPageContentFormat::RobotsTxt),
+            sub_fetch_concurrency: Some(2),
+            store_diffs: true,
+            assertions: Some(vec![WebPageContentAssertion {
+                operator: WebPageContentAssertionOperator::NotContains,
+                value: "error".to_string(),
+            }]),
+            method: Some("POST".to_string()),
+            request_body: Some("query=status".to_string()),
         };
         assert_eq!(
             serde_json::from_str::<WebPageTrackerSettings>(
@@ -114,7 +437,38 @@ mod tests {
                     "revisions": 3,
                     "delay": 2000,
                     "scripts": { "resourceFilterMap": "return resource;" },
-                    "headers": { "cookie": "my-cookie" }
+                    "headers": { "cookie": "my-cookie" },
+                    "ignoreResourceUrls": ["ads\\.js$"],
+                    "statusOnly": true,
+                    "insecureTls": true,
+                    "notificationCooldown": 3600000,
+                    "urgentNotifications": true,
+                    "dependsOn": "00000000-0000-0000-0000-000000000001",
+                    "byteRange": [0, 4095],
+                    "precondition": {
+                        "trackerId": "00000000-0000-0000-0000-000000000002",
+                        "pointer": "/status",
+                        "operator": "equals",
+                        "value": "updated"
+                    },
+                    "classifyResources": true,
+                    "captureCookies": "redacted",
+                    "dedupWindow": 2,
+                    "sizeBudget": { "maxTotalSize": 1024, "maxGrowth": 10 },
+                    "renderJs": false,
+                    "dataPolicy": { "classification": "personal", "retention": 86400000 },
+                    "captureResponse": { "headers": ["ETag"] },
+                    "resourceTypes": ["script"],
+                    "recordTypes": ["TXT"],
+                    "notifyOnFirst": true,
+                    "captureFingerprint": true,
+                    "additionalUrls": ["https://secutils.dev/health"],
+                    "contentFormat": "robotsTxt",
+                    "subFetchConcurrency": 2,
+                    "storeDiffs": true,
+                    "assertions": [{ "operator": "notContains", "value": "error" }],
+                    "method": "POST",
+                    "requestBody": "query=status"
                 })
                 .to_string()
             )?,
@@ -123,4 +477,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn default() -> anyhow::Result<()> {
+        let settings = WebPageTrackerSettings::default();
+        assert!(settings.render_js);
+        assert_eq!(
+            serde_json::from_str::<WebPageTrackerSettings>(
+                &json!({ "revisions": 0, "delay": 0 }).to_string()
+            )?,
+            settings
+        );
+
+        Ok(())
+    }
 }
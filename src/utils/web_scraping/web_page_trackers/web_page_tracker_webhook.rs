@@ -0,0 +1,111 @@
+use crate::security::encryption;
+use anyhow::Context;
+use time::OffsetDateTime;
+use url::Url;
+
+/// Describes a webhook that web page tracker change notifications are delivered to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebPageTrackerWebhook {
+    /// The URL of the webhook notifications are POSTed to.
+    pub url: Url,
+    /// The encrypted secret used to sign notification payloads.
+    pub secret: Vec<u8>,
+    /// The encrypted secret the webhook used before the latest secret rotation, if any. It's still
+    /// used to sign notification payloads until the rotation grace period ends.
+    pub previous_secret: Option<Vec<u8>>,
+    /// Date and time when the previous secret stops being used to sign notification payloads.
+    pub previous_secret_expires_at: Option<OffsetDateTime>,
+    /// The encrypted custom HTTP headers (e.g. `Authorization`) sent along with every
+    /// notification, if any.
+    pub headers: Option<Vec<u8>>,
+}
+
+impl WebPageTrackerWebhook {
+    /// Returns the encrypted previous secret if the rotation grace period hasn't ended yet at the
+    /// specified time.
+    pub fn active_previous_secret(&self, now: OffsetDateTime) -> Option<&[u8]> {
+        match (&self.previous_secret, self.previous_secret_expires_at) {
+            (Some(previous_secret), Some(expires_at)) if now < expires_at => Some(previous_secret),
+            _ => None,
+        }
+    }
+
+    /// Decrypts custom HTTP headers sent along with every notification, if any.
+    pub fn decrypt_headers(&self, encryption_key: &str) -> anyhow::Result<Vec<(String, String)>> {
+        let Some(ref headers) = self.headers else {
+            return Ok(vec![]);
+        };
+
+        let headers = encryption::decrypt(encryption_key, headers)
+            .context("Cannot decrypt webhook headers.")?;
+        Ok(serde_json::from_slice(&headers)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{security::encryption, utils::web_scraping::WebPageTrackerWebhook};
+    use time::OffsetDateTime;
+    use url::Url;
+
+    #[test]
+    fn properly_returns_active_previous_secret() -> anyhow::Result<()> {
+        // January 1, 2000 10:00:00
+        let now = OffsetDateTime::from_unix_timestamp(946720800)?;
+        let webhook = WebPageTrackerWebhook {
+            url: Url::parse("https://secutils.dev/webhook")?,
+            secret: vec![1, 2, 3],
+            previous_secret: Some(vec![4, 5, 6]),
+            previous_secret_expires_at: Some(OffsetDateTime::from_unix_timestamp(946720860)?),
+            headers: None,
+        };
+
+        assert_eq!(
+            webhook.active_previous_secret(now),
+            Some([4, 5, 6].as_slice())
+        );
+        assert_eq!(
+            webhook.active_previous_secret(OffsetDateTime::from_unix_timestamp(946720860)?),
+            None
+        );
+        assert_eq!(
+            WebPageTrackerWebhook {
+                previous_secret: None,
+                previous_secret_expires_at: None,
+                ..webhook
+            }
+            .active_previous_secret(now),
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn properly_decrypts_headers() -> anyhow::Result<()> {
+        let encryption_key = "4f2a8e3c1b6d9f0a7e5c3b1d8f6a4e2c0b9d7f5a3e1c8b6d4f2a0e9c7b5d3f1a";
+        let webhook = WebPageTrackerWebhook {
+            url: Url::parse("https://secutils.dev/webhook")?,
+            secret: vec![1, 2, 3],
+            previous_secret: None,
+            previous_secret_expires_at: None,
+            headers: None,
+        };
+        assert!(webhook.decrypt_headers(encryption_key)?.is_empty());
+
+        let headers = vec![("Authorization".to_string(), "Bearer my-token".to_string())];
+        let webhook = WebPageTrackerWebhook {
+            headers: Some(encryption::encrypt(
+                encryption_key,
+                &serde_json::to_vec(&headers)?,
+            )?),
+            ..webhook
+        };
+        assert_eq!(webhook.decrypt_headers(encryption_key)?, headers);
+        assert!(webhook
+            .decrypt_headers("0000000000000000000000000000000000000000000000000000000000000000")
+            .is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Newly generated secret of the web page tracker webhook. It's returned only once, right after
+/// the secret rotation, and is stored encrypted afterwards.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebPageTrackerWebhookSecret {
+    /// ID of the web page tracker the webhook belongs to.
+    pub tracker_id: Uuid,
+    /// The new secret used to sign webhook notification payloads.
+    pub secret: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebPageTrackerWebhookSecret;
+    use insta::assert_json_snapshot;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebPageTrackerWebhookSecret {
+            tracker_id: uuid!("00000000-0000-0000-0000-000000000001"),
+            secret: "my-webhook-secret".to_string(),
+        }, @r###"
+        {
+          "trackerId": "00000000-0000-0000-0000-000000000001",
+          "secret": "my-webhook-secret"
+        }
+        "###);
+
+        Ok(())
+    }
+}
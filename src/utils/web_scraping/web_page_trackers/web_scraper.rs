@@ -1,3 +1,14 @@
+mod web_scraper_callback;
+mod web_scraper_callback_result;
+mod web_scraper_circuit_breaker;
+mod web_scraper_client;
 mod web_scraper_error_response;
+mod web_scraper_job_response;
 
-pub use web_scraper_error_response::WebScraperErrorResponse;
+pub use self::{
+    web_scraper_callback::WebScraperCallback,
+    web_scraper_callback_result::WebScraperCallbackResult,
+    web_scraper_circuit_breaker::WebScraperCircuitBreaker, web_scraper_client::WebScraperClient,
+    web_scraper_error_response::WebScraperErrorResponse,
+    web_scraper_job_response::WebScraperJobResponse,
+};
@@ -0,0 +1,34 @@
+use serde::Serialize;
+use url::Url;
+
+/// Describes where the web scraper should deliver the result of the asynchronous scrape job.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebScraperCallback<'a> {
+    /// URL the web scraper should POST the result of the scrape job to.
+    pub url: &'a Url,
+    /// Token the web scraper should send back along with the result to authenticate itself.
+    pub token: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebScraperCallback;
+    use insta::assert_json_snapshot;
+    use url::Url;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(WebScraperCallback {
+            url: &Url::parse("https://secutils.dev/api/web_scraping/scraper_callback")?,
+            token: "some-token",
+        }, @r###"
+        {
+          "url": "https://secutils.dev/api/web_scraping/scraper_callback",
+          "token": "some-token"
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use crate::utils::web_scraping::WebScraperErrorResponse;
+use serde::{Deserialize, Serialize};
+
+/// Represents the result of the asynchronous scrape job the web scraper delivers to the callback.
+/// The result includes either the scraper response or the error.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebScraperCallbackResult<R> {
+    /// Unique ID of the scrape job assigned by the web scraper.
+    pub job_id: String,
+    /// Web scraper response, if the scrape job succeeded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<R>,
+    /// Web scraper error, if the scrape job failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<WebScraperErrorResponse>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebScraperCallbackResult;
+    use crate::utils::web_scraping::{WebScraperErrorResponse, WebScraperScreenshotResponse};
+    use time::OffsetDateTime;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebScraperCallbackResult<WebScraperScreenshotResponse>>(
+                r#"
+{
+    "jobId": "some-job-id",
+    "response": { "timestamp": 946720800, "screenshot": "AQID" }
+}
+          "#
+            )?,
+            WebScraperCallbackResult {
+                job_id: "some-job-id".to_string(),
+                response: Some(WebScraperScreenshotResponse {
+                    // January 1, 2000 11:00:00
+                    timestamp: OffsetDateTime::from_unix_timestamp(946720800)?,
+                    screenshot: vec![1, 2, 3],
+                }),
+                error: None,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<WebScraperCallbackResult<WebScraperScreenshotResponse>>(
+                r#"
+{
+    "jobId": "some-job-id",
+    "error": { "message": "some-error" }
+}
+          "#
+            )?,
+            WebScraperCallbackResult {
+                job_id: "some-job-id".to_string(),
+                response: None,
+                error: Some(WebScraperErrorResponse {
+                    message: "some-error".to_string(),
+                }),
+            }
+        );
+
+        Ok(())
+    }
+}
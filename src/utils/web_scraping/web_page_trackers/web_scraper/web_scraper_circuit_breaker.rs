@@ -0,0 +1,245 @@
+use crate::config::WebScraperCircuitBreakerConfig;
+use anyhow::bail;
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Circuit breaker that makes web scraper calls fail fast while the web scraper is unavailable,
+/// so that web page trackers don't wait for the full timeout on every call.
+#[derive(Debug)]
+pub struct WebScraperCircuitBreaker {
+    /// Circuit breaker configuration, circuit is always closed if not specified.
+    config: Option<WebScraperCircuitBreakerConfig>,
+    /// Current state of the circuit.
+    state: Mutex<WebScraperCircuitState>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum WebScraperCircuitState {
+    /// Calls are allowed, the number of consecutive failures is tracked.
+    Closed { failures: u32 },
+    /// Calls are rejected until the cooldown expires.
+    Open { until: Instant },
+    /// A single probe call is in flight, other calls are rejected until it completes. If the probe
+    /// call never completes (e.g. it's cancelled), another probe is allowed after the cooldown.
+    HalfOpen { since: Instant },
+}
+
+impl WebScraperCircuitBreaker {
+    /// Creates a new circuit breaker in the closed state.
+    pub fn new(config: Option<WebScraperCircuitBreakerConfig>) -> Self {
+        Self {
+            config,
+            state: Mutex::new(WebScraperCircuitState::Closed { failures: 0 }),
+        }
+    }
+
+    /// Checks whether the web scraper call is allowed, and returns an error if the circuit is open.
+    pub fn acquire(&self) -> anyhow::Result<()> {
+        self.acquire_at(Instant::now())
+    }
+
+    /// Records the web scraper call that completed, even if the web scraper reported a client
+    /// error, and closes the circuit.
+    pub fn record_success(&self) {
+        if self.config.is_some() {
+            *self.lock_state() = WebScraperCircuitState::Closed { failures: 0 };
+        }
+    }
+
+    /// Records the web scraper call that failed unexpectedly, and opens the circuit if the
+    /// threshold of consecutive failures is reached or if the probe call failed.
+    pub fn record_failure(&self) {
+        self.record_failure_at(Instant::now())
+    }
+
+    fn acquire_at(&self, now: Instant) -> anyhow::Result<()> {
+        let Some(config) = self.config else {
+            return Ok(());
+        };
+
+        let mut state = self.lock_state();
+        let retry_in = match *state {
+            WebScraperCircuitState::Closed { .. } => return Ok(()),
+            WebScraperCircuitState::Open { until } if now < until => until - now,
+            WebScraperCircuitState::HalfOpen { since } if now < since + config.cooldown => {
+                since + config.cooldown - now
+            }
+            WebScraperCircuitState::Open { .. } | WebScraperCircuitState::HalfOpen { .. } => {
+                *state = WebScraperCircuitState::HalfOpen { since: now };
+                return Ok(());
+            }
+        };
+
+        // Round the remaining time up to whole seconds to keep the message readable.
+        let retry_in_secs = retry_in.as_secs() + u64::from(retry_in.subsec_nanos() > 0);
+        bail!(
+            "Web scraper is unavailable, calls are rejected for the next {}.",
+            humantime::format_duration(Duration::from_secs(retry_in_secs))
+        );
+    }
+
+    fn record_failure_at(&self, now: Instant) {
+        let Some(config) = self.config else {
+            return;
+        };
+
+        let mut state = self.lock_state();
+        *state = match *state {
+            WebScraperCircuitState::Closed { failures }
+                if failures + 1 < config.failure_threshold =>
+            {
+                WebScraperCircuitState::Closed {
+                    failures: failures + 1,
+                }
+            }
+            WebScraperCircuitState::Closed { .. } | WebScraperCircuitState::HalfOpen { .. } => {
+                log::warn!(
+                    "Web scraper circuit is open for {}.",
+                    humantime::format_duration(config.cooldown)
+                );
+                WebScraperCircuitState::Open {
+                    until: now + config.cooldown,
+                }
+            }
+            // Calls that were allowed before the circuit opened don't extend the cooldown.
+            open @ WebScraperCircuitState::Open { .. } => open,
+        };
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, WebScraperCircuitState> {
+        // The state is always consistent, so it's safe to recover it if the lock is poisoned.
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WebScraperCircuitBreaker, WebScraperCircuitState};
+    use crate::config::WebScraperCircuitBreakerConfig;
+    use std::time::{Duration, Instant};
+
+    fn state(breaker: &WebScraperCircuitBreaker) -> WebScraperCircuitState {
+        *breaker.lock_state()
+    }
+
+    #[test]
+    fn never_opens_if_not_configured() -> anyhow::Result<()> {
+        let breaker = WebScraperCircuitBreaker::new(None);
+        for _ in 0..10 {
+            breaker.acquire()?;
+            breaker.record_failure();
+        }
+        breaker.acquire()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn goes_through_all_states() -> anyhow::Result<()> {
+        let breaker = WebScraperCircuitBreaker::new(Some(WebScraperCircuitBreakerConfig {
+            failure_threshold: 3,
+            cooldown: Duration::from_secs(60),
+        }));
+        let now = Instant::now();
+
+        // Closed: calls are allowed until the threshold of consecutive failures is reached, and
+        // success resets the failures.
+        breaker.acquire_at(now)?;
+        breaker.record_failure_at(now);
+        breaker.acquire_at(now)?;
+        breaker.record_success();
+        assert_eq!(
+            state(&breaker),
+            WebScraperCircuitState::Closed { failures: 0 }
+        );
+        for _ in 0..2 {
+            breaker.acquire_at(now)?;
+            breaker.record_failure_at(now);
+        }
+        assert_eq!(
+            state(&breaker),
+            WebScraperCircuitState::Closed { failures: 2 }
+        );
+
+        // Open: calls are rejected until cooldown expires.
+        breaker.acquire_at(now)?;
+        breaker.record_failure_at(now);
+        assert_eq!(
+            state(&breaker),
+            WebScraperCircuitState::Open {
+                until: now + Duration::from_secs(60)
+            }
+        );
+        assert_eq!(
+            breaker.acquire_at(now).unwrap_err().to_string(),
+            "Web scraper is unavailable, calls are rejected for the next 1m."
+        );
+        assert_eq!(
+            breaker
+                .acquire_at(now + Duration::from_secs(30))
+                .unwrap_err()
+                .to_string(),
+            "Web scraper is unavailable, calls are rejected for the next 30s."
+        );
+
+        // Half-open: a single probe is allowed, and its failure opens the circuit again.
+        let now = now + Duration::from_secs(60);
+        breaker.acquire_at(now)?;
+        assert_eq!(
+            state(&breaker),
+            WebScraperCircuitState::HalfOpen { since: now }
+        );
+        assert!(breaker.acquire_at(now).is_err());
+        breaker.record_failure_at(now);
+        assert_eq!(
+            state(&breaker),
+            WebScraperCircuitState::Open {
+                until: now + Duration::from_secs(60)
+            }
+        );
+        assert!(breaker.acquire_at(now).is_err());
+
+        // Half-open: successful probe closes the circuit.
+        let now = now + Duration::from_secs(60);
+        breaker.acquire_at(now)?;
+        assert!(breaker.acquire_at(now).is_err());
+        breaker.record_success();
+        assert_eq!(
+            state(&breaker),
+            WebScraperCircuitState::Closed { failures: 0 }
+        );
+        breaker.acquire_at(now)?;
+        breaker.acquire_at(now)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn allows_another_probe_if_probe_never_completes() -> anyhow::Result<()> {
+        let breaker = WebScraperCircuitBreaker::new(Some(WebScraperCircuitBreakerConfig {
+            failure_threshold: 1,
+            cooldown: Duration::from_secs(60),
+        }));
+        let now = Instant::now();
+
+        breaker.acquire_at(now)?;
+        breaker.record_failure_at(now);
+
+        let now = now + Duration::from_secs(60);
+        breaker.acquire_at(now)?;
+        assert!(breaker.acquire_at(now + Duration::from_secs(59)).is_err());
+        breaker.acquire_at(now + Duration::from_secs(60))?;
+        assert_eq!(
+            state(&breaker),
+            WebScraperCircuitState::HalfOpen {
+                since: now + Duration::from_secs(60)
+            }
+        );
+
+        Ok(())
+    }
+}
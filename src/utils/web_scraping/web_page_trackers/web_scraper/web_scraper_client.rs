@@ -0,0 +1,134 @@
+use crate::utils::web_scraping::WebScraperErrorResponse;
+use anyhow::{anyhow, bail};
+use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Serialize};
+use std::time::Duration;
+use url::Url;
+
+/// Default timeout for the requests to the web scraper. It should be large enough to cover the
+/// maximum tracker delay and the time needed to load the page.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Client to interact with the web scraper component.
+#[derive(Debug, Clone)]
+pub struct WebScraperClient {
+    /// Base URL of the web scraper component.
+    base_url: Url,
+    /// HTTP client shared by all requests made through this web scraper client.
+    client: reqwest::Client,
+    /// Timeout for every request to the web scraper.
+    timeout: Duration,
+}
+
+impl WebScraperClient {
+    /// Creates a new web scraper client with the specified base URL.
+    pub fn new(base_url: Url) -> Self {
+        Self {
+            base_url,
+            client: reqwest::Client::new(),
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Sends request to the specified web scraper endpoint (relative to the base URL). Client
+    /// errors reported by the web scraper (e.g. invalid page URL or script) are returned as inner
+    /// errors, while any other errors are treated as unexpected.
+    pub async fn post<R: DeserializeOwned>(
+        &self,
+        path: &str,
+        request: &impl Serialize,
+    ) -> anyhow::Result<Result<R, WebScraperErrorResponse>> {
+        let response = self
+            .client
+            .post(format!("{}{path}", self.base_url.as_str()))
+            .timeout(self.timeout)
+            .json(request)
+            .send()
+            .await
+            .map_err(|err| {
+                anyhow!("Could not connect to the web scraper service ({path}): {err:?}")
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_response =
+                response
+                    .json::<WebScraperErrorResponse>()
+                    .await
+                    .map_err(|err| {
+                        anyhow!("Could not deserialize scraper error response ({path}): {err:?}")
+                    })?;
+            return Self::classify_error(status, error_response).map(Err);
+        }
+
+        Ok(Ok(response.json::<R>().await.map_err(|err| {
+            anyhow!("Could not deserialize scraper response ({path}): {err:?}")
+        })?))
+    }
+
+    /// Distinguishes client errors that should be reported to the user from the unexpected errors.
+    fn classify_error(
+        status: StatusCode,
+        error_response: WebScraperErrorResponse,
+    ) -> anyhow::Result<WebScraperErrorResponse> {
+        if status.is_client_error() {
+            Ok(error_response)
+        } else {
+            bail!(
+                "Unexpected scraper error ({status}): {:?}",
+                error_response.message
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebScraperClient;
+    use crate::utils::web_scraping::WebScraperErrorResponse;
+    use reqwest::StatusCode;
+
+    #[test]
+    fn classifies_client_errors() -> anyhow::Result<()> {
+        for status in [
+            StatusCode::BAD_REQUEST,
+            StatusCode::NOT_FOUND,
+            StatusCode::UNPROCESSABLE_ENTITY,
+        ] {
+            assert_eq!(
+                WebScraperClient::classify_error(
+                    status,
+                    WebScraperErrorResponse {
+                        message: "some client error".to_string(),
+                    }
+                )?,
+                WebScraperErrorResponse {
+                    message: "some client error".to_string(),
+                }
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn classifies_unexpected_errors() {
+        for status in [
+            StatusCode::INTERNAL_SERVER_ERROR,
+            StatusCode::BAD_GATEWAY,
+            StatusCode::SERVICE_UNAVAILABLE,
+        ] {
+            let error = WebScraperClient::classify_error(
+                status,
+                WebScraperErrorResponse {
+                    message: "some server error".to_string(),
+                },
+            )
+            .unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                format!("Unexpected scraper error ({status}): \"some server error\"")
+            );
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents response of the web scraper to the asynchronous scrape job submission.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebScraperJobResponse {
+    /// Unique ID of the scrape job assigned by the web scraper.
+    pub job_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebScraperJobResponse;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<WebScraperJobResponse>(r#"{ "jobId": "some-job-id" }"#)?,
+            WebScraperJobResponse {
+                job_id: "some-job-id".to_string(),
+            }
+        );
+
+        Ok(())
+    }
+}
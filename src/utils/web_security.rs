@@ -1,6 +1,7 @@
 mod api_ext;
 mod csp;
 mod database_ext;
+mod jwt;
 
 pub use self::{
     api_ext::ContentSecurityPolicyContent,
@@ -10,6 +11,7 @@ pub use self::{
         ContentSecurityPolicySandboxDirectiveValue, ContentSecurityPolicySource,
         ContentSecurityPolicyTrustedTypesDirectiveValue, ContentSecurityPolicyWebrtcDirectiveValue,
     },
+    jwt::{JsonWebTokenInspection, JsonWebTokenIssue},
 };
 use crate::{
     api::Api,
@@ -116,6 +118,17 @@ pub async fn web_security_handle_action<DR: DnsResolver, ET: EmailTransport>(
                 .await
                 .map(|user_share| user_share.map(ClientUserShare::from))?,
         ),
+        (
+            UtilsResource::WebSecurityJsonWebTokens,
+            UtilsAction::Execute {
+                resource_id: None,
+                operation: UtilsResourceOperation::WebSecurityJsonWebTokenDecode,
+            },
+        ) => UtilsActionResult::json(
+            web_security
+                .decode_json_web_token(extract_params(params)?)
+                .await?,
+        ),
         _ => Err(SecutilsError::client("Invalid resource or action.").into()),
     }
 }
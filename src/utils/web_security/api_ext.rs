@@ -3,31 +3,39 @@ mod content_security_policies_serialize_params;
 mod content_security_policies_update_params;
 pub mod content_security_policy_content;
 mod csp_meta_parser;
+mod json_web_tokens_decode_params;
 
 pub use self::{
     content_security_policies_create_params::ContentSecurityPoliciesCreateParams,
     content_security_policies_serialize_params::ContentSecurityPoliciesSerializeParams,
     content_security_policies_update_params::ContentSecurityPoliciesUpdateParams,
     content_security_policy_content::ContentSecurityPolicyContent,
+    json_web_tokens_decode_params::JsonWebTokensDecodeParams,
 };
 use crate::{
     api::Api,
     config::SECUTILS_USER_AGENT,
-    error::Error as SecutilsError,
+    error::{Error as SecutilsError, ErrorCode},
+    logging::{redact_url, truncate_for_log},
     network::{DnsResolver, EmailTransport},
     users::{SharedResource, UserId, UserShare},
     utils::{
         utils_action_validation::MAX_UTILS_ENTITY_NAME_LENGTH,
         web_security::{
             api_ext::csp_meta_parser::CspMetaParser, ContentSecurityPolicy,
-            ContentSecurityPolicyDirective, ContentSecurityPolicySource,
+            ContentSecurityPolicyDirective, ContentSecurityPolicySource, JsonWebTokenInspection,
+            JsonWebTokenIssue,
         },
     },
 };
 use anyhow::{anyhow, bail};
 use content_security_policy::{Policy, PolicyDisposition, PolicySource};
+use jsonwebtoken::{jwk::JwkSet, DecodingKey, Validation};
+use openssl::base64;
 use reqwest::redirect::Policy as RedirectPolicy;
+use serde_json::Value as JsonValue;
 use time::OffsetDateTime;
+use url::Url;
 use uuid::Uuid;
 
 /// API extension to work with web security utilities.
@@ -78,8 +86,9 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> WebSecurityApiExt<'a, DR, ET> {
             ContentSecurityPolicyContent::Serialized(policy_text) => {
                 let directives = Self::deserialize_directives(&policy_text);
                 log::debug!(
-                    "Deserialized {} content security policy directives: {policy_text}",
-                    directives.len()
+                    "Deserialized {} content security policy directives: {}",
+                    directives.len(),
+                    truncate_for_log(&policy_text)
                 );
                 directives
             }
@@ -88,10 +97,13 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> WebSecurityApiExt<'a, DR, ET> {
                 follow_redirects,
                 source,
             } => {
+                self.api.network.check_outbound_access()?;
+
                 if !self.api.network.is_public_web_url(&url).await {
                     bail!(SecutilsError::client(
                         format!("Remote URL must be either `http` or `https` and have a valid public reachable domain name, but received {url}.")
-                    ));
+                    )
+                    .with_code(ErrorCode::InvalidUrl));
                 }
 
                 let client = reqwest::ClientBuilder::new()
@@ -122,11 +134,13 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> WebSecurityApiExt<'a, DR, ET> {
                     | ContentSecurityPolicySource::ReportOnlyHeader => {
                         let response = client.head(url.as_str()).send().await.map_err(|err| SecutilsError::client_with_root_cause(
                             anyhow!(err).context(format!("Cannot fetch content security policy from a web page ({url}) due to unexpected error.")),
-                        ))?;
+                        )
+                        .with_code(ErrorCode::UpstreamError))?;
 
                         let status = response.status();
                         if status.is_client_error() || status.is_server_error() {
-                            bail!(SecutilsError::client(format!("Cannot fetch content security policy from a web page ({url}), request failed with HTTP status: {status}.")));
+                            bail!(SecutilsError::client(format!("Cannot fetch content security policy from a web page ({url}), request failed with HTTP status: {status}."))
+                                .with_code(ErrorCode::UpstreamError));
                         }
 
                         // Extract all values for the specified header, multiple values are allowed,
@@ -141,6 +155,7 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> WebSecurityApiExt<'a, DR, ET> {
                                         SecutilsError::client(format!(
                                             "Invalid {header_name} header: {header:?}"
                                         ))
+                                        .with_code(ErrorCode::UpstreamError)
                                     })?
                                     .to_string(),
                             );
@@ -149,11 +164,13 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> WebSecurityApiExt<'a, DR, ET> {
                         if header_values.is_empty() {
                             bail!(SecutilsError::client(format!(
                                 "{header_name} header is missing for URL ({url})."
-                            )));
+                            ))
+                            .with_code(ErrorCode::UpstreamError));
                         } else if header_values.len() > 1 {
                             log::warn!(
-                                "{header_name} header has {} values for URL ({url}), only the last will be imported: {header_values:?}",
-                                header_values.len()
+                                "{header_name} header has {} values for URL ({}), only the last will be imported: {header_values:?}",
+                                header_values.len(),
+                                redact_url(&url)
                             );
                         }
 
@@ -162,22 +179,26 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> WebSecurityApiExt<'a, DR, ET> {
                     ContentSecurityPolicySource::Meta => {
                         let response = client.get(url.as_str()).send().await.map_err(|err| SecutilsError::client_with_root_cause(
                             anyhow!(err).context(format!("Cannot fetch content security policy from a web page ({url}) due to unexpected error.")),
-                        ))?;
+                        )
+                        .with_code(ErrorCode::UpstreamError))?;
 
                         let status = response.status();
                         if status.is_client_error() || status.is_server_error() {
-                            bail!(SecutilsError::client(format!("Cannot fetch content security policy from a web page ({url}), request failed with HTTP status: {status}.")));
+                            bail!(SecutilsError::client(format!("Cannot fetch content security policy from a web page ({url}), request failed with HTTP status: {status}."))
+                                .with_code(ErrorCode::UpstreamError));
                         }
 
                         let mut header_values = CspMetaParser::parse(&response.bytes().await?)?;
                         if header_values.is_empty() {
                             bail!(SecutilsError::client(format!(
                                 "CSP `<meta>` tag is missing for URL ({url})."
-                            )));
+                            ))
+                            .with_code(ErrorCode::UpstreamError));
                         } else if header_values.len() > 1 {
                             log::warn!(
-                                "CSP `<meta>` tag has {} values for URL ({url}), only the last will be imported: {header_values:?}",
-                                header_values.len()
+                                "CSP `<meta>` tag has {} values for URL ({}), only the last will be imported: {header_values:?}",
+                                header_values.len(),
+                                redact_url(&url)
                             );
                         }
 
@@ -187,8 +208,10 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> WebSecurityApiExt<'a, DR, ET> {
 
                 let directives = Self::deserialize_directives(&policy_text);
                 log::debug!(
-                    "Fetched and deserialized {} content security policy directives from URL ({url}): {policy_text}",
-                    directives.len()
+                    "Fetched and deserialized {} content security policy directives from URL ({}): {}",
+                    directives.len(),
+                    redact_url(&url),
+                    truncate_for_log(&policy_text)
                 );
                 directives
             }
@@ -236,9 +259,10 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> WebSecurityApiExt<'a, DR, ET> {
             .get_content_security_policy(user_id, id)
             .await?
         else {
-            bail!(SecutilsError::client(format!(
-                "Content security policy ('{id}') is not found."
-            )));
+            bail!(
+                SecutilsError::client(format!("Content security policy ('{id}') is not found."))
+                    .with_code(ErrorCode::ContentSecurityPolicyNotFound)
+            );
         };
 
         let policy = ContentSecurityPolicy {
@@ -276,9 +300,10 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> WebSecurityApiExt<'a, DR, ET> {
             .get_content_security_policy(user_id, id)
             .await?
         else {
-            bail!(SecutilsError::client(format!(
-                "Content security policy ('{id}') is not found."
-            )));
+            bail!(
+                SecutilsError::client(format!("Content security policy ('{id}') is not found."))
+                    .with_code(ErrorCode::ContentSecurityPolicyNotFound)
+            );
         };
 
         Self::serialize_directives(
@@ -328,7 +353,8 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> WebSecurityApiExt<'a, DR, ET> {
         {
             bail!(SecutilsError::client(format!(
                 "Content security policy ('{policy_id}') is not found."
-            )));
+            ))
+            .with_code(ErrorCode::ContentSecurityPolicyNotFound));
         }
 
         // Create new user share.
@@ -369,6 +395,89 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> WebSecurityApiExt<'a, DR, ET> {
         users_api.remove_user_share(user_share.id).await
     }
 
+    /// Decodes JSON Web Token and inspects it for common issues. If secret or JSON Web Key Set URL
+    /// is provided, token signature is verified as well.
+    pub async fn decode_json_web_token(
+        &self,
+        params: JsonWebTokensDecodeParams,
+    ) -> anyhow::Result<JsonWebTokenInspection> {
+        if params.secret.is_some() && params.jwks_url.is_some() {
+            bail!(SecutilsError::client(
+                "JWT can be verified either with a secret or with a JWKS URL, but not both."
+            ));
+        }
+
+        let token = params.token.trim();
+        let (header, claims, signature) = match token.split('.').collect::<Vec<_>>()[..] {
+            [header, claims, signature] => (
+                Self::decode_json_web_token_part(header, "header")?,
+                Self::decode_json_web_token_part(claims, "claims")?,
+                signature,
+            ),
+            _ => bail!(SecutilsError::client(
+                "JWT should consist of three dot-separated parts."
+            )),
+        };
+
+        let mut issues = vec![];
+        if header
+            .get("alg")
+            .and_then(JsonValue::as_str)
+            .is_none_or(|alg| alg.eq_ignore_ascii_case("none"))
+        {
+            issues.push(JsonWebTokenIssue::InsecureAlgorithm);
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let expires_at = Self::decode_json_web_token_timestamp(&claims, "exp")?;
+        match expires_at {
+            Some(expires_at) if expires_at <= now => issues.push(JsonWebTokenIssue::Expired),
+            Some(_) => {}
+            None => issues.push(JsonWebTokenIssue::MissingExpiration),
+        }
+
+        if Self::decode_json_web_token_timestamp(&claims, "nbf")?
+            .is_some_and(|not_before| not_before > now)
+        {
+            issues.push(JsonWebTokenIssue::NotYetValid);
+        }
+
+        let signature_valid = if let Some(ref secret) = params.secret {
+            Some(Self::verify_json_web_token(
+                token,
+                &DecodingKey::from_secret(secret.as_bytes()),
+            ))
+        } else if let Some(ref jwks_url) = params.jwks_url {
+            let jwks = self.fetch_json_web_key_set(jwks_url).await?;
+            let key_id = jsonwebtoken::decode_header(token)
+                .ok()
+                .and_then(|header| header.kid);
+            let jwk = match key_id {
+                Some(ref key_id) => jwks.find(key_id),
+                None => jwks.keys.first(),
+            };
+            Some(
+                jwk.and_then(|jwk| DecodingKey::from_jwk(jwk).ok())
+                    .is_some_and(|key| Self::verify_json_web_token(token, &key)),
+            )
+        } else {
+            None
+        };
+
+        if signature_valid == Some(false) {
+            issues.push(JsonWebTokenIssue::InvalidSignature);
+        }
+
+        Ok(JsonWebTokenInspection {
+            header,
+            claims,
+            signature_present: !signature.is_empty(),
+            signature_valid,
+            expires_at,
+            issues,
+        })
+    }
+
     async fn validate_content_security_policy(
         &self,
         policy: &ContentSecurityPolicy,
@@ -420,6 +529,93 @@ impl<'a, DR: DnsResolver, ET: EmailTransport> WebSecurityApiExt<'a, DR, ET> {
 
         Ok(serialized_directives.join("; "))
     }
+
+    /// Fetches JSON Web Key Set from the specified public URL.
+    async fn fetch_json_web_key_set(&self, url: &Url) -> anyhow::Result<JwkSet> {
+        self.api.network.check_outbound_access()?;
+
+        if !self.api.network.is_public_web_url(url).await {
+            bail!(SecutilsError::client(format!(
+                "JWKS URL must be either `http` or `https` and have a valid public reachable domain name, but received {url}."
+            ))
+            .with_code(ErrorCode::InvalidUrl));
+        }
+
+        let fetch = async {
+            reqwest::ClientBuilder::new()
+                .redirect(RedirectPolicy::none())
+                .user_agent(SECUTILS_USER_AGENT)
+                .build()?
+                .get(url.as_str())
+                .send()
+                .await?
+                .error_for_status()?
+                .json::<JwkSet>()
+                .await
+        };
+
+        fetch.await.map_err(|err| {
+            SecutilsError::client_with_root_cause(
+                anyhow!(err).context(format!("Cannot fetch JWKS ({url}).")),
+            )
+            .with_code(ErrorCode::UpstreamError)
+            .into()
+        })
+    }
+
+    /// Decodes base64url-encoded JSON object that is a part of JSON Web Token (header or claims).
+    fn decode_json_web_token_part(part: &str, part_name: &str) -> anyhow::Result<JsonValue> {
+        // OpenSSL only supports standard base64 alphabet with padding.
+        let mut standard_part = part.replace('-', "+").replace('_', "/");
+        while !standard_part.len().is_multiple_of(4) {
+            standard_part.push('=');
+        }
+
+        match base64::decode_block(&standard_part)
+            .ok()
+            .and_then(|decoded_part| serde_json::from_slice::<JsonValue>(&decoded_part).ok())
+        {
+            Some(decoded_part) if decoded_part.is_object() => Ok(decoded_part),
+            _ => bail!(SecutilsError::client(format!(
+                "JWT {part_name} isn't a valid base64url-encoded JSON object."
+            ))),
+        }
+    }
+
+    /// Decodes JSON Web Token claim that represents a Unix timestamp in seconds (e.g. `exp`).
+    fn decode_json_web_token_timestamp(
+        claims: &JsonValue,
+        claim_name: &str,
+    ) -> anyhow::Result<Option<OffsetDateTime>> {
+        let Some(claim) = claims.get(claim_name) else {
+            return Ok(None);
+        };
+
+        match claim
+            .as_i64()
+            .and_then(|timestamp| OffsetDateTime::from_unix_timestamp(timestamp).ok())
+        {
+            Some(timestamp) => Ok(Some(timestamp)),
+            None => bail!(SecutilsError::client(format!(
+                "JWT `{claim_name}` claim isn't a valid timestamp."
+            ))),
+        }
+    }
+
+    /// Checks whether JSON Web Token signature is valid for the specified key. Time-based claims
+    /// aren't validated since they're reported as token issues instead.
+    fn verify_json_web_token(token: &str, key: &DecodingKey) -> bool {
+        let Ok(header) = jsonwebtoken::decode_header(token) else {
+            return false;
+        };
+
+        let mut validation = Validation::new(header.alg);
+        validation.required_spec_claims.clear();
+        validation.validate_exp = false;
+        validation.validate_nbf = false;
+        validation.validate_aud = false;
+        jsonwebtoken::decode::<JsonValue>(token, key, &validation).is_ok()
+    }
 }
 
 impl<DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
@@ -437,17 +633,21 @@ mod tests {
         utils::web_security::{
             api_ext::{
                 ContentSecurityPoliciesCreateParams, ContentSecurityPoliciesSerializeParams,
-                ContentSecurityPoliciesUpdateParams, WebSecurityApiExt,
+                ContentSecurityPoliciesUpdateParams, JsonWebTokensDecodeParams, WebSecurityApiExt,
             },
             ContentSecurityPolicy, ContentSecurityPolicyContent, ContentSecurityPolicyDirective,
             ContentSecurityPolicySandboxDirectiveValue, ContentSecurityPolicySource,
-            ContentSecurityPolicyTrustedTypesDirectiveValue,
+            ContentSecurityPolicyTrustedTypesDirectiveValue, JsonWebTokenInspection,
+            JsonWebTokenIssue,
         },
     };
     use httpmock::MockServer;
     use insta::assert_debug_snapshot;
+    use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+    use serde_json::json;
     use sqlx::PgPool;
     use std::net::Ipv4Addr;
+    use time::OffsetDateTime;
     use trust_dns_resolver::{
         proto::rr::{rdata::A, RData, Record},
         Name,
@@ -1641,7 +1841,7 @@ mod tests {
             web_security
                 .get_content_security_policies(mock_user.id)
                 .await?,
-            [policy_two.clone()]
+            std::slice::from_ref(&policy_two)
         );
 
         web_security
@@ -1847,4 +2047,249 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn properly_decodes_json_web_tokens(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let web_security = WebSecurityApiExt::new(&api);
+
+        let decode = |token: String, secret: Option<&str>| {
+            web_security.decode_json_web_token(JsonWebTokensDecodeParams {
+                token,
+                secret: secret.map(|secret| secret.to_string()),
+                jwks_url: None,
+            })
+        };
+
+        // Valid signed token.
+        let valid_token = encode(
+            &Header::new(Algorithm::HS256),
+            &json!({ "sub": "user", "exp": 4102444800u64 }),
+            &EncodingKey::from_secret(b"my-jwt-secret"),
+        )?;
+        assert_eq!(
+            decode(valid_token.clone(), Some("my-jwt-secret")).await?,
+            JsonWebTokenInspection {
+                header: json!({ "alg": "HS256", "typ": "JWT" }),
+                claims: json!({ "sub": "user", "exp": 4102444800u64 }),
+                signature_present: true,
+                signature_valid: Some(true),
+                expires_at: Some(OffsetDateTime::from_unix_timestamp(4102444800)?),
+                issues: vec![],
+            }
+        );
+        assert_eq!(
+            decode(valid_token.clone(), None).await?.signature_valid,
+            None
+        );
+        assert_eq!(
+            decode(valid_token, Some("wrong-jwt-secret")).await?,
+            JsonWebTokenInspection {
+                header: json!({ "alg": "HS256", "typ": "JWT" }),
+                claims: json!({ "sub": "user", "exp": 4102444800u64 }),
+                signature_present: true,
+                signature_valid: Some(false),
+                expires_at: Some(OffsetDateTime::from_unix_timestamp(4102444800)?),
+                issues: vec![JsonWebTokenIssue::InvalidSignature],
+            }
+        );
+
+        // Expired token that isn't valid yet.
+        let expired_token = encode(
+            &Header::new(Algorithm::HS256),
+            &json!({ "sub": "user", "exp": 946720800, "nbf": 4102444800u64 }),
+            &EncodingKey::from_secret(b"my-jwt-secret"),
+        )?;
+        assert_eq!(
+            decode(expired_token, Some("my-jwt-secret")).await?,
+            JsonWebTokenInspection {
+                header: json!({ "alg": "HS256", "typ": "JWT" }),
+                claims: json!({ "sub": "user", "exp": 946720800, "nbf": 4102444800u64 }),
+                signature_present: true,
+                signature_valid: Some(true),
+                expires_at: Some(OffsetDateTime::from_unix_timestamp(946720800)?),
+                issues: vec![JsonWebTokenIssue::Expired, JsonWebTokenIssue::NotYetValid],
+            }
+        );
+
+        // Unsigned token (`alg: none`).
+        let unsigned_token =
+            "eyJhbGciOiJub25lIiwidHlwIjoiSldUIn0.eyJzdWIiOiJ1c2VyIiwiZXhwIjo0MTAyNDQ0ODAwfQ.";
+        assert_eq!(
+            decode(unsigned_token.to_string(), None).await?,
+            JsonWebTokenInspection {
+                header: json!({ "alg": "none", "typ": "JWT" }),
+                claims: json!({ "sub": "user", "exp": 4102444800u64 }),
+                signature_present: false,
+                signature_valid: None,
+                expires_at: Some(OffsetDateTime::from_unix_timestamp(4102444800)?),
+                issues: vec![JsonWebTokenIssue::InsecureAlgorithm],
+            }
+        );
+        assert_eq!(
+            decode(unsigned_token.to_string(), Some("my-jwt-secret"))
+                .await?
+                .issues,
+            vec![
+                JsonWebTokenIssue::InsecureAlgorithm,
+                JsonWebTokenIssue::InvalidSignature
+            ]
+        );
+
+        // Token without expiration.
+        let token = encode(
+            &Header::new(Algorithm::HS256),
+            &json!({ "sub": "user" }),
+            &EncodingKey::from_secret(b"my-jwt-secret"),
+        )?;
+        assert_eq!(
+            decode(token, None).await?.issues,
+            vec![JsonWebTokenIssue::MissingExpiration]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_verifies_json_web_tokens_with_jwks(pool: PgPool) -> anyhow::Result<()> {
+        let api_with_public_network = mock_api_with_network(
+            pool,
+            mock_network_with_records::<1>(vec![Record::from_rdata(
+                Name::new(),
+                300,
+                RData::A(A(Ipv4Addr::new(172, 32, 0, 2))),
+            )]),
+        )
+        .await?;
+
+        let server = MockServer::start();
+        let jwks_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/.well-known/jwks.json");
+            then.status(200).json_body(json!({
+                "keys": [{
+                    "kty": "oct",
+                    "kid": "key-1",
+                    "alg": "HS256",
+                    "k": "bXktandrcy1zZWNyZXQta2V5"
+                }]
+            }));
+        });
+
+        let mut header = Header::new(Algorithm::HS256);
+        header.kid = Some("key-1".to_string());
+        let token = encode(
+            &header,
+            &json!({ "sub": "user", "exp": 4102444800u64 }),
+            &EncodingKey::from_secret(b"my-jwks-secret-key"),
+        )?;
+
+        let web_security = WebSecurityApiExt::new(&api_with_public_network);
+        let inspection = web_security
+            .decode_json_web_token(JsonWebTokensDecodeParams {
+                token,
+                secret: None,
+                // Use `localhost` to trick public domain check logic.
+                jwks_url: Some(Url::parse(&format!(
+                    "http://localhost:{}/.well-known/jwks.json",
+                    server.port()
+                ))?),
+            })
+            .await?;
+        assert_eq!(inspection.signature_valid, Some(true));
+        assert!(inspection.issues.is_empty());
+
+        // Token signed with an unknown key.
+        header.kid = Some("key-2".to_string());
+        let token = encode(
+            &header,
+            &json!({ "sub": "user", "exp": 4102444800u64 }),
+            &EncodingKey::from_secret(b"my-jwks-secret-key"),
+        )?;
+        let inspection = web_security
+            .decode_json_web_token(JsonWebTokensDecodeParams {
+                token,
+                secret: None,
+                jwks_url: Some(Url::parse(&format!(
+                    "http://localhost:{}/.well-known/jwks.json",
+                    server.port()
+                ))?),
+            })
+            .await?;
+        assert_eq!(inspection.signature_valid, Some(false));
+        assert_eq!(inspection.issues, vec![JsonWebTokenIssue::InvalidSignature]);
+
+        jwks_mock.assert_hits(2);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn fails_to_decode_invalid_json_web_tokens(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let web_security = WebSecurityApiExt::new(&api);
+
+        let web_security = &web_security;
+        let decode_error = move |token: &str, secret: Option<&str>, jwks_url: Option<&str>| {
+            let token = token.to_string();
+            let secret = secret.map(|secret| secret.to_string());
+            let jwks_url = jwks_url.map(Url::parse).transpose();
+            async move {
+                Ok::<_, anyhow::Error>(
+                    web_security
+                        .decode_json_web_token(JsonWebTokensDecodeParams {
+                            token,
+                            secret,
+                            jwks_url: jwks_url?,
+                        })
+                        .await
+                        .unwrap_err()
+                        .downcast::<SecutilsError>()?
+                        .to_string(),
+                )
+            }
+        };
+
+        assert_eq!(
+            decode_error("a.b", None, None).await?,
+            "JWT should consist of three dot-separated parts."
+        );
+        assert_eq!(
+            decode_error("not-json.eyJzdWIiOiJ1c2VyIn0.", None, None).await?,
+            "JWT header isn't a valid base64url-encoded JSON object."
+        );
+        assert_eq!(
+            decode_error("eyJhbGciOiJub25lIiwidHlwIjoiSldUIn0.W10.", None, None).await?,
+            "JWT claims isn't a valid base64url-encoded JSON object."
+        );
+        assert_eq!(
+            decode_error(
+                "eyJhbGciOiJub25lIiwidHlwIjoiSldUIn0.eyJleHAiOiJzb29uIn0.",
+                None,
+                None
+            )
+            .await?,
+            "JWT `exp` claim isn't a valid timestamp."
+        );
+        assert_eq!(
+            decode_error(
+                "a.b.c",
+                Some("secret"),
+                Some("https://secutils.dev/.well-known/jwks.json")
+            )
+            .await?,
+            "JWT can be verified either with a secret or with a JWKS URL, but not both."
+        );
+        assert_eq!(
+            decode_error(
+                "eyJhbGciOiJub25lIiwidHlwIjoiSldUIn0.eyJzdWIiOiJ1c2VyIn0.",
+                None,
+                Some("http://127.0.0.1/.well-known/jwks.json")
+            )
+            .await?,
+            "JWKS URL must be either `http` or `https` and have a valid public reachable domain name, but received http://127.0.0.1/.well-known/jwks.json."
+        );
+
+        Ok(())
+    }
 }
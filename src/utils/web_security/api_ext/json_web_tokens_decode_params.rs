@@ -0,0 +1,56 @@
+use serde::Deserialize;
+use url::Url;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonWebTokensDecodeParams {
+    /// Encoded JSON Web Token to decode.
+    pub token: String,
+    /// Optional secret to verify the token signature with (for HMAC algorithms).
+    pub secret: Option<String>,
+    /// Optional URL of the JSON Web Key Set to verify the token signature with.
+    pub jwks_url: Option<Url>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::web_security::api_ext::JsonWebTokensDecodeParams;
+    use url::Url;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<JsonWebTokensDecodeParams>(
+                r#"
+{
+    "token": "a.b.c"
+}
+          "#
+            )?,
+            JsonWebTokensDecodeParams {
+                token: "a.b.c".to_string(),
+                secret: None,
+                jwks_url: None,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<JsonWebTokensDecodeParams>(
+                r#"
+{
+    "token": "a.b.c",
+    "secret": "my-secret",
+    "jwksUrl": "https://secutils.dev/.well-known/jwks.json"
+}
+          "#
+            )?,
+            JsonWebTokensDecodeParams {
+                token: "a.b.c".to_string(),
+                secret: Some("my-secret".to_string()),
+                jwks_url: Some(Url::parse("https://secutils.dev/.well-known/jwks.json")?),
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ mod raw_content_security_policy;
 
 use crate::{
     database::Database,
-    error::Error as SecutilsError,
+    error::{Error as SecutilsError, ErrorCode},
     users::UserId,
     utils::web_security::{
         database_ext::raw_content_security_policy::RawContentSecurityPolicy, ContentSecurityPolicy,
@@ -76,6 +76,7 @@ WHERE user_id = $1 AND id = $2
                     "Content security policy ('{}') already exists.",
                     policy.name
                 )))
+                .with_code(ErrorCode::AlreadyExists)
             } else {
                 SecutilsError::from(anyhow!(err).context(format!(
                     "Couldn't create content security policy ('{}') due to unknown reason.",
@@ -115,7 +116,8 @@ WHERE user_id = $1 AND id = $2
                     bail!(SecutilsError::client(format!(
                         "A content security policy ('{}') doesn't exist.",
                         policy.name
-                    )));
+                    ))
+                    .with_code(ErrorCode::ContentSecurityPolicyNotFound));
                 }
             }
             Err(err) => {
@@ -128,6 +130,7 @@ WHERE user_id = $1 AND id = $2
                         "Content security policy ('{}') already exists.",
                         policy.name
                     )))
+                    .with_code(ErrorCode::AlreadyExists)
                 } else {
                     SecutilsError::from(anyhow!(err).context(format!(
                         "Couldn't update content security policy ('{}') due to unknown reason.",
@@ -189,7 +192,7 @@ WHERE user_id = $1 AND id = $2
 
 impl Database {
     /// Returns a database extension for the web security utility-related operations.
-    pub fn web_security(&self) -> WebSecurityDatabaseExt {
+    pub fn web_security(&self) -> WebSecurityDatabaseExt<'_> {
         WebSecurityDatabaseExt::new(&self.pool)
     }
 }
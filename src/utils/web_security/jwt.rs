@@ -0,0 +1,6 @@
+mod json_web_token_inspection;
+mod json_web_token_issue;
+
+pub use self::{
+    json_web_token_inspection::JsonWebTokenInspection, json_web_token_issue::JsonWebTokenIssue,
+};
@@ -0,0 +1,91 @@
+use crate::utils::web_security::JsonWebTokenIssue;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use time::OffsetDateTime;
+
+/// Result of the JSON Web Token inspection.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonWebTokenInspection {
+    /// Decoded token header (JOSE header).
+    pub header: JsonValue,
+    /// Decoded token claims (payload).
+    pub claims: JsonValue,
+    /// Indicates whether the token includes a non-empty signature.
+    pub signature_present: bool,
+    /// Indicates whether the token signature is valid, only set if the token was verified against
+    /// a secret or keys.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_valid: Option<bool>,
+    /// The time at which the token expires (`exp` claim), if specified.
+    #[serde(
+        with = "time::serde::timestamp::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expires_at: Option<OffsetDateTime>,
+    /// Common issues detected in the token.
+    pub issues: Vec<JsonWebTokenIssue>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonWebTokenInspection;
+    use crate::utils::web_security::JsonWebTokenIssue;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+    use time::OffsetDateTime;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(JsonWebTokenInspection {
+            header: json!({ "alg": "HS256", "typ": "JWT" }),
+            claims: json!({ "sub": "user", "exp": 946720800 }),
+            signature_present: true,
+            signature_valid: Some(true),
+            expires_at: Some(OffsetDateTime::from_unix_timestamp(946720800)?),
+            issues: vec![JsonWebTokenIssue::Expired],
+        }, @r###"
+        {
+          "header": {
+            "alg": "HS256",
+            "typ": "JWT"
+          },
+          "claims": {
+            "sub": "user",
+            "exp": 946720800
+          },
+          "signaturePresent": true,
+          "signatureValid": true,
+          "expiresAt": 946720800,
+          "issues": [
+            "expired"
+          ]
+        }
+        "###);
+
+        assert_json_snapshot!(JsonWebTokenInspection {
+            header: json!({ "alg": "none" }),
+            claims: json!({ "sub": "user" }),
+            signature_present: false,
+            signature_valid: None,
+            expires_at: None,
+            issues: vec![JsonWebTokenIssue::InsecureAlgorithm, JsonWebTokenIssue::MissingExpiration],
+        }, @r###"
+        {
+          "header": {
+            "alg": "none"
+          },
+          "claims": {
+            "sub": "user"
+          },
+          "signaturePresent": false,
+          "issues": [
+            "insecureAlgorithm",
+            "missingExpiration"
+          ]
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use serde::Serialize;
+
+/// Describes a common issue detected in a JSON Web Token.
+#[derive(Serialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum JsonWebTokenIssue {
+    /// Token isn't signed (`alg` is `none`) and can be forged by anyone.
+    InsecureAlgorithm,
+    /// Token doesn't have an expiration time (`exp` claim) and is valid forever.
+    MissingExpiration,
+    /// Token expiration time (`exp` claim) is in the past.
+    Expired,
+    /// Token shouldn't be accepted yet, its `nbf` claim is in the future.
+    NotYetValid,
+    /// Token signature doesn't match the provided secret or keys.
+    InvalidSignature,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonWebTokenIssue;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!([
+            JsonWebTokenIssue::InsecureAlgorithm,
+            JsonWebTokenIssue::MissingExpiration,
+            JsonWebTokenIssue::Expired,
+            JsonWebTokenIssue::NotYetValid,
+            JsonWebTokenIssue::InvalidSignature,
+        ], @r###"
+        [
+          "insecureAlgorithm",
+          "missingExpiration",
+          "expired",
+          "notYetValid",
+          "invalidSignature"
+        ]
+        "###);
+
+        Ok(())
+    }
+}
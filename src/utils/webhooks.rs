@@ -1,13 +1,19 @@
 mod api_ext;
 mod database_ext;
+mod inbound_emails;
 mod responders;
 
 pub use self::{
-    api_ext::RespondersRequestCreateParams,
+    api_ext::{InboundEmailCreateParams, RespondersRequestCreateParams},
+    inbound_emails::InboundEmail,
     responders::{
-        Responder, ResponderLocation, ResponderMethod, ResponderPathType, ResponderRequest,
-        ResponderRequestHeaders, ResponderScriptContext, ResponderScriptResult, ResponderSettings,
-        ResponderStats,
+        Responder, ResponderBasePath, ResponderBodyTemplate, ResponderEchoTransformContext,
+        ResponderEchoTransformResult, ResponderFakerContext, ResponderFault,
+        ResponderForwardResult, ResponderInitialResponse, ResponderLatencyProfile,
+        ResponderLocation, ResponderMatch, ResponderMatchScheme, ResponderMethod,
+        ResponderMigrationResult, ResponderPathType, ResponderRequest, ResponderRequestHeaders,
+        ResponderScriptContext, ResponderScriptResult, ResponderSettings, ResponderStats,
+        ResponderSubdomainPrefixAvailability, ResponderUrlType, MAX_RESPONDER_LATENCY,
     },
 };
 use crate::{
@@ -78,6 +84,61 @@ pub async fn webhooks_handle_action<DR: DnsResolver, ET: EmailTransport>(
                 ..
             },
         ) => UtilsActionResult::json(webhooks.get_responders_stats().await?),
+        (
+            UtilsResource::WebhooksResponders,
+            UtilsAction::Execute {
+                operation: UtilsResourceOperation::WebhooksRespondersCheckSubdomainPrefix,
+                ..
+            },
+        ) => UtilsActionResult::json(
+            webhooks
+                .is_subdomain_prefix_available(extract_params(params)?)
+                .await?,
+        ),
+        (
+            UtilsResource::WebhooksResponders,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebhooksRespondersMigrateUrlType,
+            },
+        ) => UtilsActionResult::json(
+            webhooks
+                .migrate_responder_url_type(resource_id, extract_params(params)?)
+                .await?,
+        ),
+        (
+            UtilsResource::WebhooksResponders,
+            UtilsAction::Execute {
+                resource_id: Some(resource_id),
+                operation: UtilsResourceOperation::WebhooksRespondersResetCounter,
+            },
+        ) => {
+            webhooks
+                .reset_responder_requests_counter(resource_id)
+                .await?;
+            Ok(UtilsActionResult::empty())
+        }
+        (UtilsResource::WebhooksInboundEmails, UtilsAction::List) => {
+            UtilsActionResult::json(webhooks.get_inbound_emails().await?)
+        }
+        (UtilsResource::WebhooksInboundEmails, UtilsAction::Delete { resource_id }) => {
+            webhooks.remove_inbound_email(resource_id).await?;
+            Ok(UtilsActionResult::empty())
+        }
+        (UtilsResource::WebhooksResponderBasePaths, UtilsAction::List) => {
+            UtilsActionResult::json(webhooks.get_responder_base_paths().await?)
+        }
+        (UtilsResource::WebhooksResponderBasePaths, UtilsAction::Create) => {
+            UtilsActionResult::json(
+                webhooks
+                    .create_responder_base_path(extract_params(params)?)
+                    .await?,
+            )
+        }
+        (UtilsResource::WebhooksResponderBasePaths, UtilsAction::Delete { resource_id }) => {
+            webhooks.remove_responder_base_path(resource_id).await?;
+            Ok(UtilsActionResult::empty())
+        }
         _ => Err(SecutilsError::client("Invalid resource or action.").into()),
     }
 }
@@ -89,8 +150,9 @@ pub mod tests {
         tests::{mock_api, mock_user},
         utils::{
             webhooks::{
-                webhooks_handle_action, Responder, ResponderLocation, ResponderMethod,
-                ResponderPathType, ResponderSettings, RespondersRequestCreateParams,
+                webhooks_handle_action, Responder, ResponderInitialResponse, ResponderLocation,
+                ResponderMethod, ResponderPathType, ResponderSettings,
+                RespondersRequestCreateParams,
             },
             UtilsAction, UtilsActionParams, UtilsResource, UtilsResourceOperation,
         },
@@ -116,6 +178,7 @@ pub mod tests {
                         path_type: ResponderPathType::Exact,
                         path: path.to_string(),
                         subdomain_prefix: None,
+                        base_path: None,
                     },
                     method: ResponderMethod::Any,
                     enabled: true,
@@ -125,6 +188,14 @@ pub mod tests {
                         body: None,
                         headers: None,
                         script: None,
+                        body_file: None,
+                        echo_transform: None,
+                        latency: None,
+                        fault: None,
+                        body_template: None,
+                        forward_to: None,
+                        capture_json: false,
+                        initial_response: None,
                     },
                     created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                     updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
@@ -176,6 +247,7 @@ pub mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -185,6 +257,14 @@ pub mod tests {
                     status_code: 200,
                     body: None,
                     headers: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
             })
             .await?;
@@ -195,6 +275,7 @@ pub mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: false,
@@ -253,6 +334,14 @@ pub mod tests {
                     status_code: 200,
                     body: None,
                     headers: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 }
             }))),
         )
@@ -292,6 +381,7 @@ pub mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -301,6 +391,14 @@ pub mod tests {
                     status_code: 200,
                     body: None,
                     headers: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
             })
             .await?;
@@ -327,6 +425,14 @@ pub mod tests {
                     status_code: 200,
                     body: None,
                     headers: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 }
             }))),
         )
@@ -344,6 +450,7 @@ pub mod tests {
                     path_type: ResponderPathType::Prefix,
                     path: "/path".to_string(),
                     subdomain_prefix: Some("sub".to_string()),
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: false,
@@ -353,6 +460,14 @@ pub mod tests {
                     status_code: 200,
                     body: None,
                     headers: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
                 created_at: responder.created_at,
                 updated_at: responder.updated_at
@@ -376,6 +491,7 @@ pub mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -385,6 +501,14 @@ pub mod tests {
                     status_code: 200,
                     body: None,
                     headers: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
             })
             .await?;
@@ -423,6 +547,7 @@ pub mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -432,6 +557,14 @@ pub mod tests {
                     status_code: 200,
                     body: None,
                     headers: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
             })
             .await?;
@@ -444,6 +577,7 @@ pub mod tests {
                     headers: None,
                     url: Cow::Borrowed("/?query=value"),
                     body: None,
+                    correlation_id: None,
                 },
             )
             .await?
@@ -457,6 +591,7 @@ pub mod tests {
                     headers: None,
                     url: Cow::Borrowed("/?query=other-value"),
                     body: None,
+                    correlation_id: None,
                 },
             )
             .await?
@@ -511,6 +646,7 @@ pub mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -520,6 +656,14 @@ pub mod tests {
                     status_code: 200,
                     body: None,
                     headers: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
             })
             .await?;
@@ -532,6 +676,7 @@ pub mod tests {
                     headers: None,
                     url: Cow::Borrowed("/?query=value"),
                     body: None,
+                    correlation_id: None,
                 },
             )
             .await?;
@@ -544,6 +689,7 @@ pub mod tests {
                     headers: None,
                     url: Cow::Borrowed("/?query=other-value"),
                     body: None,
+                    correlation_id: None,
                 },
             )
             .await?;
@@ -589,6 +735,7 @@ pub mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -598,6 +745,14 @@ pub mod tests {
                     status_code: 200,
                     body: None,
                     headers: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
             })
             .await?;
@@ -610,6 +765,7 @@ pub mod tests {
                     headers: None,
                     url: Cow::Borrowed("/?query=value"),
                     body: None,
+                    correlation_id: None,
                 },
             )
             .await?
@@ -643,4 +799,149 @@ pub mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn properly_handles_migrate_url_type_operation(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        // Insert responder.
+        let webhooks = api.webhooks(&mock_user);
+        let responder = webhooks
+            .create_responder(RespondersCreateParams {
+                name: "name_one".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/one".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Get,
+                enabled: true,
+                settings: ResponderSettings {
+                    requests_to_track: 3,
+                    script: None,
+                    status_code: 200,
+                    body: None,
+                    headers: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
+                },
+            })
+            .await?;
+
+        let action_result = webhooks_handle_action(
+            mock_user.clone(),
+            &api,
+            UtilsAction::Execute {
+                resource_id: Some(responder.id),
+                operation: UtilsResourceOperation::WebhooksRespondersMigrateUrlType,
+            },
+            UtilsResource::WebhooksResponders,
+            Some(UtilsActionParams::json(json!({
+                "urlType": { "type": "subdomain", "subdomainPrefix": "my-app" }
+            }))),
+        )
+        .await?;
+
+        let action_result = action_result.into_inner().unwrap();
+        assert_eq!(
+            action_result["url"],
+            json!("https://my-app-devhandle00000000000000000000000000000001.webhooks.secutils.dev/one")
+        );
+        assert_eq!(
+            webhooks
+                .get_responder(responder.id)
+                .await?
+                .and_then(|responder| responder.location.subdomain_prefix),
+            Some("my-app".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_handles_reset_counter_operation(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        // Insert responder.
+        let webhooks = api.webhooks(&mock_user);
+        let responder = webhooks
+            .create_responder(RespondersCreateParams {
+                name: "name_one".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/one".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Get,
+                enabled: true,
+                settings: ResponderSettings {
+                    requests_to_track: 3,
+                    script: None,
+                    status_code: 200,
+                    body: None,
+                    headers: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: Some(ResponderInitialResponse {
+                        requests: 1,
+                        status_code: 503,
+                        body: None,
+                        headers: None,
+                    }),
+                },
+            })
+            .await?;
+
+        assert_eq!(
+            webhooks
+                .increment_responder_requests_counter(responder.id)
+                .await?,
+            1
+        );
+        assert_eq!(
+            webhooks
+                .increment_responder_requests_counter(responder.id)
+                .await?,
+            2
+        );
+
+        let action_result = webhooks_handle_action(
+            mock_user.clone(),
+            &api,
+            UtilsAction::Execute {
+                resource_id: Some(responder.id),
+                operation: UtilsResourceOperation::WebhooksRespondersResetCounter,
+            },
+            UtilsResource::WebhooksResponders,
+            None,
+        )
+        .await?;
+        assert!(action_result.into_inner().is_none());
+
+        assert_eq!(
+            webhooks
+                .increment_responder_requests_counter(responder.id)
+                .await?,
+            1
+        );
+
+        Ok(())
+    }
 }
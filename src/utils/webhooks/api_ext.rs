@@ -1,30 +1,89 @@
+mod inbound_email_create_params;
+mod responders_base_path_create_params;
+mod responders_check_subdomain_prefix_params;
 mod responders_create_params;
+mod responders_migrate_url_type_params;
 mod responders_request_create_params;
 mod responders_update_params;
 
 pub use self::{
+    inbound_email_create_params::InboundEmailCreateParams,
+    responders_base_path_create_params::RespondersBasePathCreateParams,
+    responders_check_subdomain_prefix_params::RespondersCheckSubdomainPrefixParams,
     responders_create_params::RespondersCreateParams,
+    responders_migrate_url_type_params::RespondersMigrateUrlTypeParams,
     responders_request_create_params::RespondersRequestCreateParams,
     responders_update_params::RespondersUpdateParams,
 };
 use crate::{
     api::Api,
-    error::Error as SecutilsError,
+    config::Config,
+    error::{Error as SecutilsError, ErrorCode},
+    js_runtime::{JsRuntime, JsRuntimeConfig},
+    logging::redact_url,
     network::{DnsResolver, EmailTransport},
     security::USER_HANDLE_LENGTH_BYTES,
-    users::User,
+    users::{User, UserDisplayOrderCollection},
     utils::{
         utils_action_validation::MAX_UTILS_ENTITY_NAME_LENGTH,
         webhooks::{
-            Responder, ResponderMethod, ResponderPathType, ResponderRequest, ResponderStats,
+            InboundEmail, Responder, ResponderBasePath, ResponderBodyTemplate,
+            ResponderForwardResult, ResponderLatencyProfile, ResponderLocation, ResponderMatch,
+            ResponderMatchScheme, ResponderMethod, ResponderMigrationResult, ResponderPathType,
+            ResponderRequest, ResponderSettings, ResponderStats,
+            ResponderSubdomainPrefixAvailability, ResponderUrlType, MAX_RESPONDER_LATENCY,
         },
     },
 };
-use anyhow::bail;
+use anyhow::{bail, Context};
+use bytes::Bytes;
+use deno_core::error::JsError;
+use futures::future::join_all;
+use reqwest::{
+    header::{
+        HeaderMap, HeaderName, HeaderValue, CONNECTION, CONTENT_LENGTH, HOST, TRANSFER_ENCODING,
+    },
+    redirect::Policy as RedirectPolicy,
+    Method,
+};
+use serde_json::Value as JSONValue;
+use std::{
+    borrow::Cow,
+    collections::HashSet,
+    path::{Component, Path, PathBuf},
+    time::Duration,
+};
 use time::OffsetDateTime;
 use url::Url;
 use uuid::Uuid;
 
+/// Maximum size of the inbound email subject and body, in bytes.
+const MAX_INBOUND_EMAIL_SIZE: usize = 1024 * 1024;
+
+/// Maximum length of the responder subdomain prefix.
+const MAX_SUBDOMAIN_PREFIX_LENGTH: usize = 32;
+
+/// Maximum number of the base paths the user can reserve for responders.
+const MAX_RESPONDER_BASE_PATHS: usize = 100;
+
+/// Maximum number of the alternative subdomain prefixes to try when the requested one is taken.
+const MAX_SUBDOMAIN_PREFIX_SUGGESTIONS: usize = 100;
+
+/// Maximum number of targets the responder can forward received requests to.
+const MAX_RESPONDER_FORWARD_TARGETS: usize = 5;
+
+/// Timeout for forwarding a received request to a single responder forward target.
+const RESPONDER_FORWARD_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum size of the responder request body that is captured as JSON, in bytes. It matches the
+/// default request payload limit, and applies to the pretty-printed form of the body as well.
+const MAX_RESPONDER_JSON_BODY_SIZE: usize = 256 * 1024;
+
+/// Subdomain prefixes that are reserved and cannot be used by responders.
+const RESERVED_SUBDOMAIN_PREFIXES: [&str; 10] = [
+    "admin", "api", "app", "dev", "mail", "secutils", "staging", "static", "webhooks", "www",
+];
+
 pub struct WebhooksApiExt<'a, 'u, DR: DnsResolver, ET: EmailTransport> {
     api: &'a Api<DR, ET>,
     user: &'u User,
@@ -36,9 +95,15 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
         Self { api, user }
     }
 
-    /// Retrieves all responders that belong to the specified user.
+    /// Retrieves all responders that belong to the specified user, in the user's display order.
     pub async fn get_responders(&self) -> anyhow::Result<Vec<Responder>> {
-        self.api.db.webhooks().get_responders(self.user.id).await
+        let mut responders = self.api.db.webhooks().get_responders(self.user.id).await?;
+        self.api
+            .users()
+            .get_display_order(self.user.id, UserDisplayOrderCollection::Responders)
+            .await?
+            .sort(&mut responders, |responder| responder.id);
+        Ok(responders)
     }
 
     /// Retrieves stats for all responders that belong to the specified user.
@@ -65,17 +130,92 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
         if subdomain_prefix.is_some() {
             let features = self.user.subscription.get_features(&self.api.config);
             if !features.config.webhooks.responder_custom_subdomain_prefix {
-                bail!(SecutilsError::client(
-                    "Responder subdomain prefixes are not allowed."
-                ));
+                bail!(
+                    SecutilsError::client("Responder subdomain prefixes are not allowed.")
+                        .with_code(ErrorCode::NotAllowed)
+                );
             }
         }
 
-        self.api
+        // Resolve the most specific base path the request path is within first, and then look
+        // for the responder among the responders of that base path only.
+        let webhooks = self.api.db.webhooks();
+        let base_path = webhooks
+            .get_responder_base_paths(self.user.id)
+            .await?
+            .into_iter()
+            .map(|base_path| base_path.path)
+            .filter(|base_path| is_path_within_base_path(path, base_path))
+            .max_by_key(|base_path| base_path.len());
+
+        webhooks
+            .find_responder(
+                self.user.id,
+                subdomain_prefix,
+                base_path.as_deref(),
+                path,
+                method,
+            )
+            .await
+    }
+
+    /// Checks if the specified subdomain prefix is available, i.e. it's neither reserved nor used
+    /// by the responders of any user. If the prefix isn't available, an alternative is suggested.
+    pub async fn is_subdomain_prefix_available(
+        &self,
+        params: RespondersCheckSubdomainPrefixParams,
+    ) -> anyhow::Result<ResponderSubdomainPrefixAvailability> {
+        let features = self.user.subscription.get_features(&self.api.config);
+        if !features.config.webhooks.responder_custom_subdomain_prefix {
+            bail!(
+                SecutilsError::client("Responder subdomain prefixes are not allowed.")
+                    .with_code(ErrorCode::NotAllowed)
+            );
+        }
+
+        let Some(public_host) = self.api.config.public_url.host_str() else {
+            bail!(SecutilsError::client(
+                "Public URL doesn't have a host, cannot validate responder subdomain prefix."
+            ));
+        };
+
+        let subdomain_prefix = params.subdomain_prefix;
+        if !self.is_valid_webhooks_subdomain_prefix(public_host, &subdomain_prefix) {
+            bail!(SecutilsError::client(format!(
+                "Responder subdomain prefix ('{subdomain_prefix}') is not valid."
+            )));
+        }
+
+        // Retrieve all used prefixes that start with the requested one to check both the
+        // requested prefix and the potential suggestions at once.
+        let used_prefixes = self
+            .api
             .db
             .webhooks()
-            .find_responder(self.user.id, subdomain_prefix, path, method)
-            .await
+            .get_responders_subdomain_prefixes(&subdomain_prefix)
+            .await?
+            .into_iter()
+            .collect::<HashSet<_>>();
+        let is_available = |prefix: &str| {
+            !RESERVED_SUBDOMAIN_PREFIXES.contains(&prefix) && !used_prefixes.contains(prefix)
+        };
+
+        if is_available(&subdomain_prefix) {
+            return Ok(ResponderSubdomainPrefixAvailability {
+                available: true,
+                suggestion: None,
+            });
+        }
+
+        Ok(ResponderSubdomainPrefixAvailability {
+            available: false,
+            suggestion: (1..=MAX_SUBDOMAIN_PREFIX_SUGGESTIONS)
+                .map(|index| format!("{subdomain_prefix}-{index}"))
+                .find(|suggestion| {
+                    is_available(suggestion)
+                        && self.is_valid_webhooks_subdomain_prefix(public_host, suggestion)
+                }),
+        })
     }
 
     /// Creates responder with the specified parameters and stores it in the database.
@@ -97,7 +237,7 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
             updated_at: created_at,
         };
 
-        self.validate_responder(&responder)?;
+        self.validate_responder(&responder).await?;
 
         self.api
             .db
@@ -126,9 +266,10 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
         }
 
         let Some(existing_responder) = self.get_responder(id).await? else {
-            bail!(SecutilsError::client(format!(
-                "Responder ('{id}') is not found."
-            )));
+            bail!(
+                SecutilsError::client(format!("Responder ('{id}') is not found."))
+                    .with_code(ErrorCode::ResponderNotFound)
+            );
         };
 
         let responder = Responder {
@@ -144,7 +285,7 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
             ..existing_responder
         };
 
-        self.validate_responder(&responder)?;
+        self.validate_responder(&responder).await?;
 
         self.api
             .db
@@ -155,6 +296,84 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
         Ok(responder)
     }
 
+    /// Migrates responder to a different URL type (path, subdomain, or custom domain), preserving
+    /// its ID and all tracked requests. Returns the migrated responder and its new URL.
+    pub async fn migrate_responder_url_type(
+        &self,
+        id: Uuid,
+        params: RespondersMigrateUrlTypeParams,
+    ) -> anyhow::Result<ResponderMigrationResult> {
+        let Some(existing_responder) = self.get_responder(id).await? else {
+            bail!(
+                SecutilsError::client(format!("Responder ('{id}') is not found."))
+                    .with_code(ErrorCode::ResponderNotFound)
+            );
+        };
+
+        // Only subdomain URLs can include a subdomain prefix, other URL types are served from
+        // the location without it.
+        let subdomain_prefix = match params.url_type {
+            ResponderUrlType::Subdomain {
+                subdomain_prefix: Some(ref subdomain_prefix),
+            } => {
+                // The prefix can be shared by multiple responders of the same user, but shouldn't
+                // be reserved or used by the responders of other users.
+                let subdomain_prefix = subdomain_prefix.to_ascii_lowercase();
+                let is_used_by_user = self.get_responders().await?.iter().any(|responder| {
+                    responder.location.subdomain_prefix.as_deref() == Some(&subdomain_prefix)
+                });
+                if !is_used_by_user
+                    && !self
+                        .is_subdomain_prefix_available(RespondersCheckSubdomainPrefixParams {
+                            subdomain_prefix: subdomain_prefix.clone(),
+                        })
+                        .await?
+                        .available
+                {
+                    bail!(SecutilsError::client(format!(
+                        "Responder subdomain prefix ('{subdomain_prefix}') is not available."
+                    ))
+                    .with_code(ErrorCode::AlreadyExists));
+                }
+
+                Some(subdomain_prefix)
+            }
+            _ => None,
+        };
+
+        let location_changed = existing_responder.location.subdomain_prefix != subdomain_prefix;
+        let responder = if location_changed {
+            Responder {
+                location: ResponderLocation {
+                    subdomain_prefix,
+                    ..existing_responder.location
+                },
+                // Preserve timestamp only up to seconds.
+                updated_at: OffsetDateTime::from_unix_timestamp(
+                    OffsetDateTime::now_utc().unix_timestamp(),
+                )?,
+                ..existing_responder
+            }
+        } else {
+            existing_responder
+        };
+
+        let url = self.get_responder_url(&responder, &params.url_type)?;
+        if location_changed {
+            self.validate_responder(&responder).await?;
+
+            // Requests are linked to the responder ID, so they're preserved as long as the
+            // responder is updated in place.
+            self.api
+                .db
+                .webhooks()
+                .update_responder(self.user.id, &responder)
+                .await?;
+        }
+
+        Ok(ResponderMigrationResult { responder, url })
+    }
+
     /// Removes responder by its ID.
     pub async fn remove_responder(&self, id: Uuid) -> anyhow::Result<()> {
         self.api
@@ -164,6 +383,30 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
             .await
     }
 
+    /// Creates a curated set of demo responders for the user (a JSON API mock, an HTML page, and
+    /// an endpoint that injects errors). Demo responders that already exist (by name) are skipped,
+    /// so it's safe to call this method multiple times. Returns only newly created responders.
+    #[allow(dead_code)]
+    pub async fn seed_demo_responders(&self) -> anyhow::Result<Vec<Responder>> {
+        let existing_names = self
+            .get_responders()
+            .await?
+            .into_iter()
+            .map(|responder| responder.name)
+            .collect::<HashSet<_>>();
+
+        let mut responders = vec![];
+        for params in demo_responders_create_params() {
+            if existing_names.contains(&params.name) {
+                continue;
+            }
+
+            responders.push(self.create_responder(params).await?);
+        }
+
+        Ok(responders)
+    }
+
     // Persists request for the specified responder.
     pub async fn create_responder_request<'r>(
         &self,
@@ -171,9 +414,18 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
         params: RespondersRequestCreateParams<'r>,
     ) -> anyhow::Result<Option<ResponderRequest<'r>>> {
         let Some(responder) = self.get_responder(responder_id).await? else {
-            bail!(SecutilsError::client(format!(
-                "Responder ('{responder_id}') is not found."
-            )));
+            bail!(
+                SecutilsError::client(format!("Responder ('{responder_id}') is not found."))
+                    .with_code(ErrorCode::ResponderNotFound)
+            );
+        };
+
+        // Forward request to the configured targets even if the responder doesn't track requests.
+        let forwarded = match responder.settings.forward_to {
+            Some(ref targets) if !targets.is_empty() => {
+                Some(self.forward_responder_request(targets, &params).await?)
+            }
+            _ => None,
         };
 
         let features = self.user.subscription.get_features(&self.api.config);
@@ -190,6 +442,25 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
             .get_responder_requests(self.user.id, responder.id)
             .await?;
 
+        // Mask sensitive data in the request body before persisting it.
+        let body = match (
+            params.body,
+            self.api
+                .users()
+                .get_redaction_patterns(self.user.id)
+                .await?,
+        ) {
+            (Some(body), Some(redaction_patterns)) => Some(Cow::Owned(
+                redaction_patterns.redact_bytes(&body).into_owned(),
+            )),
+            (body, _) => body,
+        };
+
+        let (json_valid, json_body) = match body {
+            Some(ref body) if responder.settings.capture_json => Self::capture_json_body(body),
+            _ => (None, None),
+        };
+
         let request = ResponderRequest {
             id: Uuid::now_v7(),
             responder_id,
@@ -197,11 +468,15 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
             method: params.method,
             headers: params.headers,
             url: params.url,
-            body: params.body,
+            body,
             // Preserve timestamp only up to seconds.
             created_at: OffsetDateTime::from_unix_timestamp(
                 OffsetDateTime::now_utc().unix_timestamp(),
             )?,
+            correlation_id: params.correlation_id,
+            forwarded,
+            json_valid,
+            json_body,
         };
 
         Self::validate_responder_request(&responder, &request)?;
@@ -224,15 +499,90 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
         Ok(Some(request))
     }
 
+    /// Forwards request received by the responder to all specified targets concurrently and
+    /// returns HTTP statuses the targets responded with.
+    async fn forward_responder_request(
+        &self,
+        targets: &[Url],
+        params: &RespondersRequestCreateParams<'_>,
+    ) -> anyhow::Result<Vec<ResponderForwardResult>> {
+        // Don't fail the request capture, but record that targets weren't reached.
+        if let Err(err) = self.api.network.check_outbound_access() {
+            log::warn!(
+                user:serde = self.user.log_context();
+                "Responder request won't be forwarded: {err}"
+            );
+            return Ok(targets
+                .iter()
+                .map(|url| ResponderForwardResult {
+                    url: url.clone(),
+                    status: None,
+                })
+                .collect());
+        }
+
+        let client = reqwest::Client::builder()
+            .redirect(RedirectPolicy::none())
+            .timeout(RESPONDER_FORWARD_TIMEOUT)
+            .build()?;
+
+        let method = Method::from_bytes(params.method.as_bytes())?;
+        let mut headers = HeaderMap::new();
+        for (name, value) in params.headers.iter().flatten() {
+            let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_bytes(value),
+            ) else {
+                continue;
+            };
+
+            // Connection-specific headers are set by the HTTP client for every target separately.
+            if ![CONNECTION, CONTENT_LENGTH, HOST, TRANSFER_ENCODING].contains(&name) {
+                headers.append(name, value);
+            }
+        }
+        let body = params
+            .body
+            .as_deref()
+            .map(Bytes::copy_from_slice)
+            .unwrap_or_default();
+
+        Ok(join_all(targets.iter().map(|url| {
+            let request = client
+                .request(method.clone(), url.clone())
+                .headers(headers.clone())
+                .body(body.clone());
+            async move {
+                let status = match request.send().await {
+                    Ok(response) => Some(response.status().as_u16()),
+                    Err(err) => {
+                        log::error!(
+                            user:serde = self.user.log_context();
+                            "Failed to forward responder request to {}: {err:?}",
+                            redact_url(url)
+                        );
+                        None
+                    }
+                };
+                ResponderForwardResult {
+                    url: url.clone(),
+                    status,
+                }
+            }
+        }))
+        .await)
+    }
+
     /// Returns all stored webpage resources tracker history.
     pub async fn get_responder_requests(
         &self,
         responder_id: Uuid,
     ) -> anyhow::Result<Vec<ResponderRequest<'static>>> {
         if self.get_responder(responder_id).await?.is_none() {
-            bail!(SecutilsError::client(format!(
-                "Responder ('{responder_id}') is not found."
-            )));
+            bail!(
+                SecutilsError::client(format!("Responder ('{responder_id}') is not found."))
+                    .with_code(ErrorCode::ResponderNotFound)
+            );
         };
 
         self.api
@@ -251,7 +601,236 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
             .await
     }
 
-    fn validate_responder(&self, responder: &Responder) -> anyhow::Result<()> {
+    /// Increments the number of requests received by the specified responder and returns the
+    /// updated number.
+    pub async fn increment_responder_requests_counter(
+        &self,
+        responder_id: Uuid,
+    ) -> anyhow::Result<u64> {
+        self.api
+            .db
+            .webhooks()
+            .increment_responder_requests_counter(self.user.id, responder_id)
+            .await
+    }
+
+    /// Resets the number of requests received by the specified responder, so that the responder
+    /// starts sending its initial response again.
+    pub async fn reset_responder_requests_counter(&self, responder_id: Uuid) -> anyhow::Result<()> {
+        if self.get_responder(responder_id).await?.is_none() {
+            bail!(
+                SecutilsError::client(format!("Responder ('{responder_id}') is not found."))
+                    .with_code(ErrorCode::ResponderNotFound)
+            );
+        };
+
+        self.api
+            .db
+            .webhooks()
+            .reset_responder_requests_counter(self.user.id, responder_id)
+            .await
+    }
+
+    /// Returns all base paths reserved by the user for responders.
+    pub async fn get_responder_base_paths(&self) -> anyhow::Result<Vec<ResponderBasePath>> {
+        self.api
+            .db
+            .webhooks()
+            .get_responder_base_paths(self.user.id)
+            .await
+    }
+
+    /// Reserves base path that responders can be created relative to.
+    pub async fn create_responder_base_path(
+        &self,
+        params: RespondersBasePathCreateParams,
+    ) -> anyhow::Result<ResponderBasePath> {
+        let path = params.path.to_ascii_lowercase();
+        let is_path_valid = path.len() > 1
+            && path.len() <= MAX_UTILS_ENTITY_NAME_LENGTH
+            && path.starts_with('/')
+            && path[1..].split('/').all(|segment| {
+                !segment.is_empty()
+                    && segment
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+            });
+        if !is_path_valid {
+            bail!(SecutilsError::client(format!(
+                "Responder base path ('{}') is not valid, it must begin with '/', should not end with '/', and can only contain alphanumeric characters, '-', '_' and '.' in path segments.",
+                params.path
+            )));
+        }
+
+        let webhooks = self.api.db.webhooks();
+        let base_paths = webhooks.get_responder_base_paths(self.user.id).await?;
+        if base_paths.len() >= MAX_RESPONDER_BASE_PATHS {
+            bail!(SecutilsError::client(format!(
+                "Responder base paths cannot be reserved more than {MAX_RESPONDER_BASE_PATHS} times."
+            ))
+            .with_code(ErrorCode::LimitExceeded));
+        }
+
+        if base_paths.iter().any(|base_path| base_path.path == path) {
+            bail!(SecutilsError::client(format!(
+                "Responder base path ('{path}') is already reserved."
+            ))
+            .with_code(ErrorCode::AlreadyExists));
+        }
+
+        // Responders that aren't relative to any base path would become unreachable.
+        if let Some(responder) = webhooks
+            .get_responders(self.user.id)
+            .await?
+            .into_iter()
+            .find(|responder| {
+                responder.location.base_path.is_none()
+                    && is_path_within_base_path(&responder.location.path, &path)
+            })
+        {
+            bail!(SecutilsError::client(format!(
+                "Responder base path ('{path}') conflicts with the responder ('{}') location.",
+                responder.name
+            ))
+            .with_code(ErrorCode::AlreadyExists));
+        }
+
+        let base_path = ResponderBasePath {
+            id: Uuid::now_v7(),
+            path,
+            // Preserve timestamp only up to seconds.
+            created_at: OffsetDateTime::from_unix_timestamp(
+                OffsetDateTime::now_utc().unix_timestamp(),
+            )?,
+        };
+        webhooks
+            .insert_responder_base_path(self.user.id, &base_path)
+            .await?;
+
+        Ok(base_path)
+    }
+
+    /// Removes base path reserved by the user. Base path cannot be removed while it's used by any
+    /// responder.
+    pub async fn remove_responder_base_path(&self, id: Uuid) -> anyhow::Result<()> {
+        self.api
+            .db
+            .webhooks()
+            .remove_responder_base_path(self.user.id, id)
+            .await
+    }
+
+    /// Stores email received by the user's disposable inbox and displaces the oldest emails if
+    /// the configured retention limit is reached.
+    pub async fn create_inbound_email(
+        &self,
+        params: InboundEmailCreateParams,
+    ) -> anyhow::Result<InboundEmail> {
+        let Some(max_inbound_emails) = self
+            .api
+            .config
+            .utils
+            .max_inbound_emails
+            .filter(|max_inbound_emails| *max_inbound_emails > 0)
+        else {
+            bail!(SecutilsError::client("Inbound email intake is disabled.")
+                .with_code(ErrorCode::NotAllowed));
+        };
+
+        if params.from.is_empty() || params.to.is_empty() {
+            bail!(
+                SecutilsError::client("Inbound email sender and recipient cannot be empty.")
+                    .with_code(ErrorCode::InvalidInput)
+            );
+        }
+
+        if params.subject.len() + params.body.len() > MAX_INBOUND_EMAIL_SIZE {
+            bail!(SecutilsError::client(format!(
+                "Inbound email subject and body cannot be longer than {MAX_INBOUND_EMAIL_SIZE} bytes."
+            ))
+            .with_code(ErrorCode::LimitExceeded));
+        }
+
+        let email = InboundEmail {
+            id: Uuid::now_v7(),
+            from: params.from,
+            to: params.to,
+            subject: params.subject,
+            body: params.body,
+            // Preserve timestamp only up to seconds.
+            received_at: OffsetDateTime::from_unix_timestamp(
+                OffsetDateTime::now_utc().unix_timestamp(),
+            )?,
+        };
+
+        let webhooks = self.api.db.webhooks();
+        webhooks.insert_inbound_email(self.user.id, &email).await?;
+        webhooks
+            .trim_inbound_emails(self.user.id, max_inbound_emails)
+            .await?;
+
+        Ok(email)
+    }
+
+    /// Returns all inbound emails received by the user's disposable inbox, oldest first.
+    pub async fn get_inbound_emails(&self) -> anyhow::Result<Vec<InboundEmail>> {
+        self.api
+            .db
+            .webhooks()
+            .get_inbound_emails(self.user.id)
+            .await
+    }
+
+    /// Removes inbound email with the specified ID from the user's disposable inbox.
+    pub async fn remove_inbound_email(&self, id: Uuid) -> anyhow::Result<()> {
+        self.api
+            .db
+            .webhooks()
+            .remove_inbound_email(self.user.id, id)
+            .await
+    }
+
+    /// Resolves the path of the responder body file within the configured templates directory.
+    /// Fails if the templates directory isn't configured or if the resolved path points outside
+    /// of it (e.g. via `..` components or symbolic links).
+    pub fn resolve_responder_body_file(&self, body_file: &str) -> anyhow::Result<PathBuf> {
+        let Some(ref templates_dir) = self.api.config.utils.responder_templates_dir else {
+            bail!(
+                SecutilsError::client("Responder body files are not supported.")
+                    .with_code(ErrorCode::NotAllowed)
+            );
+        };
+
+        let is_path_relative = Path::new(body_file)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+        if body_file.is_empty() || !is_path_relative {
+            bail!(SecutilsError::client(format!(
+                "Responder body file path ('{body_file}') should be relative to the templates directory."
+            )));
+        }
+
+        let templates_dir = templates_dir
+            .canonicalize()
+            .context("Failed to resolve responder templates directory.")?;
+        let body_file_path = templates_dir
+            .join(body_file)
+            .canonicalize()
+            .ok()
+            .filter(|body_file_path| body_file_path.is_file())
+            .ok_or_else(|| {
+                SecutilsError::client(format!("Responder body file ('{body_file}') is not found."))
+            })?;
+        if !body_file_path.starts_with(&templates_dir) {
+            bail!(SecutilsError::client(format!(
+                "Responder body file ('{body_file}') should be located within the templates directory."
+            )));
+        }
+
+        Ok(body_file_path)
+    }
+
+    async fn validate_responder(&self, responder: &Responder) -> anyhow::Result<()> {
         if responder.name.is_empty() {
             bail!(SecutilsError::client("Responder name cannot be empty.",));
         }
@@ -278,12 +857,46 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
             ));
         }
 
+        let base_paths = self
+            .api
+            .db
+            .webhooks()
+            .get_responder_base_paths(self.user.id)
+            .await?;
+        match responder.location.base_path {
+            Some(ref base_path) => {
+                if !base_paths
+                    .iter()
+                    .any(|reserved| reserved.path == *base_path)
+                {
+                    bail!(SecutilsError::client(format!(
+                        "Responder base path ('{base_path}') is not reserved."
+                    )));
+                }
+            }
+            None => {
+                // Requests within the base paths are routed only to the responders of these base
+                // paths, so responder would be unreachable.
+                let path = responder.location.path.to_ascii_lowercase();
+                if let Some(reserved) = base_paths
+                    .iter()
+                    .find(|reserved| is_path_within_base_path(&path, &reserved.path))
+                {
+                    bail!(SecutilsError::client(format!(
+                        "Responder location path ('{}') is within the reserved base path ('{}'), responder should be created relative to it.",
+                        responder.location.path, reserved.path
+                    )));
+                }
+            }
+        }
+
         let features = self.user.subscription.get_features(&self.api.config);
         if let Some(ref subdomain_prefix) = responder.location.subdomain_prefix {
             if !features.config.webhooks.responder_custom_subdomain_prefix {
-                bail!(SecutilsError::client(
-                    "Responder subdomain prefixes are not allowed."
-                ));
+                bail!(
+                    SecutilsError::client("Responder subdomain prefixes are not allowed.")
+                        .with_code(ErrorCode::NotAllowed)
+                );
             }
 
             let Some(public_host) = self.api.config.public_url.host_str() else {
@@ -312,43 +925,269 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
             bail!(SecutilsError::client(format!(
                 "Responder can track only up to {} requests, but received {}.",
                 features.config.webhooks.responder_requests, responder.settings.requests_to_track
-            )));
+            ))
+            .with_code(ErrorCode::LimitExceeded));
         }
 
         if let Some(ref script) = responder.settings.script {
             if script.is_empty() {
-                bail!(SecutilsError::client("Responder script cannot be empty."));
+                bail!(SecutilsError::client("Responder script cannot be empty.")
+                    .with_code(ErrorCode::InvalidScript));
             }
         }
 
-        Ok(())
-    }
+        if let Some(ref echo_transform) = responder.settings.echo_transform {
+            if echo_transform.is_empty() {
+                bail!(SecutilsError::client(
+                    "Responder echo transform cannot be empty."
+                ));
+            }
 
-    fn validate_responder_request(
-        responder: &Responder,
-        request: &ResponderRequest,
-    ) -> anyhow::Result<()> {
-        let request_url =
-            Url::parse(&format!("https://localhost{}", request.url)).map_err(|_| {
-                SecutilsError::client(format!(
-                    "Responder request URL ('{}') is not valid.",
-                    request.url
-                ))
-            })?;
+            if responder.settings.script.is_some()
+                || responder.settings.body.is_some()
+                || responder.settings.body_file.is_some()
+            {
+                bail!(SecutilsError::client(
+                    "Responder echo transform cannot be combined with script, body or body file."
+                ));
+            }
 
-        let valid_request = match responder.location.path_type {
-            ResponderPathType::Exact => responder.location.path == request_url.path(),
-            ResponderPathType::Prefix => request_url.path().starts_with(&responder.location.path),
-        };
-        if !valid_request {
-            bail!(SecutilsError::client(format!(
-                "Responder request path ('{}') does not match responder path ('{:?}').",
-                request_url.path(),
-                responder.location
-            )));
+            // Make sure that the transform script compiles, but don't invoke it.
+            let js_runtime_config = JsRuntimeConfig {
+                max_heap_size: features.config.webhooks.js_runtime_heap_size,
+                max_user_script_execution_time: features
+                    .config
+                    .webhooks
+                    .js_runtime_script_execution_time,
+            };
+            let js_code = format!(
+                "(async () => {{ const transform = async (context) => {{\n{echo_transform}\n}}; return null; }})();"
+            );
+            if let Err(err) = JsRuntime::new(&js_runtime_config)
+                .execute_script::<Option<()>>(js_code, None::<()>)
+                .await
+            {
+                let message = match err.downcast_ref::<JsError>() {
+                    Some(js_error) => js_error.exception_message.clone(),
+                    None => err.to_string(),
+                };
+                bail!(SecutilsError::client_with_root_cause(
+                    err.context(format!("Responder echo transform is not valid: {message}"))
+                )
+                .with_code(ErrorCode::InvalidScript));
+            }
         }
 
-        Ok(())
+        if let Some(latency) = responder.settings.latency {
+            let max_latency = match latency {
+                ResponderLatencyProfile::Fixed { delay } => delay,
+                ResponderLatencyProfile::Uniform { min, max } => {
+                    if min > max {
+                        bail!(SecutilsError::client(
+                            "Responder latency minimum cannot be greater than maximum."
+                        ));
+                    }
+                    max
+                }
+                ResponderLatencyProfile::Normal { mean, stddev } => mean.max(stddev),
+            };
+
+            if max_latency > MAX_RESPONDER_LATENCY {
+                bail!(SecutilsError::client(format!(
+                    "Responder latency cannot be greater than {}.",
+                    humantime::format_duration(MAX_RESPONDER_LATENCY)
+                ))
+                .with_code(ErrorCode::LimitExceeded));
+            }
+        }
+
+        if let Some(ref body_file) = responder.settings.body_file {
+            if !self.user.is_operator {
+                bail!(
+                    SecutilsError::client("Only operators can serve responder body from files.")
+                        .with_code(ErrorCode::NotAllowed)
+                );
+            }
+
+            if responder.settings.body.is_some() {
+                bail!(SecutilsError::client(
+                    "Responder cannot have both body and body file."
+                ));
+            }
+
+            self.resolve_responder_body_file(body_file)?;
+        }
+
+        if let Some(ref forward_to) = responder.settings.forward_to {
+            if forward_to.is_empty() {
+                bail!(SecutilsError::client(
+                    "Responder forward targets cannot be empty."
+                ));
+            }
+
+            if forward_to.len() > MAX_RESPONDER_FORWARD_TARGETS {
+                bail!(SecutilsError::client(format!(
+                    "Responder can forward requests to at most {MAX_RESPONDER_FORWARD_TARGETS} targets, but received {}.",
+                    forward_to.len()
+                ))
+                .with_code(ErrorCode::LimitExceeded));
+            }
+
+            let public_host = self.api.config.public_url.host_str();
+            for url in forward_to {
+                // Forwarding to other responders could create request loops.
+                let is_webhooks_url = match (url.host_str(), public_host) {
+                    (Some(host), Some(public_host)) => {
+                        host == public_host || host.ends_with(&format!(".{public_host}"))
+                    }
+                    _ => false,
+                };
+                if is_webhooks_url || !self.api.network.is_public_web_url(url).await {
+                    bail!(SecutilsError::client(format!(
+                        "Responder forward target URL must be either `http` or `https` and have a valid public reachable domain name, but received {url}."
+                    ))
+                    .with_code(ErrorCode::InvalidUrl));
+                }
+            }
+        }
+
+        if let Some(ref initial_response) = responder.settings.initial_response {
+            if initial_response.requests == 0 {
+                bail!(SecutilsError::client(
+                    "Responder initial response should be sent for at least one request."
+                ));
+            }
+
+            if !(100..=999).contains(&initial_response.status_code) {
+                bail!(SecutilsError::client(format!(
+                    "Responder initial response status code should have a value between 100 and 999, but received {}.",
+                    initial_response.status_code
+                )));
+            }
+        }
+
+        if responder.settings.body_template.is_some() {
+            match (&responder.settings.body, &responder.settings.body_file) {
+                (Some(body), _) => {
+                    if let Err(err) = ResponderBodyTemplate::validate(body) {
+                        let message = err.to_string();
+                        bail!(SecutilsError::client_with_root_cause(err.context(format!(
+                            "Responder body template is not valid: {message}"
+                        ))));
+                    }
+                }
+                // Body file can change at any time, so it's validated only when it's rendered.
+                (None, Some(_)) => {}
+                (None, None) => {
+                    bail!(SecutilsError::client(
+                        "Responder body template requires either body or body file."
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates that the responder request body is a JSON and returns its canonical pretty-printed
+    /// form. Bodies that exceed the size limit, even when pretty-printed, aren't captured as JSON.
+    fn capture_json_body(body: &[u8]) -> (Option<bool>, Option<Cow<'static, str>>) {
+        if body.len() > MAX_RESPONDER_JSON_BODY_SIZE {
+            return (None, None);
+        }
+
+        match serde_json::from_slice::<JSONValue>(body) {
+            Ok(json) => (
+                Some(true),
+                serde_json::to_string_pretty(&json)
+                    .ok()
+                    .filter(|json_body| json_body.len() <= MAX_RESPONDER_JSON_BODY_SIZE)
+                    .map(Cow::Owned),
+            ),
+            Err(_) => (Some(false), None),
+        }
+    }
+
+    fn validate_responder_request(
+        responder: &Responder,
+        request: &ResponderRequest,
+    ) -> anyhow::Result<()> {
+        let request_url =
+            Url::parse(&format!("https://localhost{}", request.url)).map_err(|_| {
+                SecutilsError::client(format!(
+                    "Responder request URL ('{}') is not valid.",
+                    request.url
+                ))
+            })?;
+
+        let responder_path = responder.location.full_path();
+        let valid_request = match responder.location.path_type {
+            ResponderPathType::Exact => responder_path == request_url.path(),
+            ResponderPathType::Prefix => request_url.path().starts_with(&responder_path),
+        };
+        if !valid_request {
+            bail!(SecutilsError::client(format!(
+                "Responder request path ('{}') does not match responder path ('{:?}').",
+                request_url.path(),
+                responder.location
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Returns URL the responder is reachable at with the specified URL type.
+    fn get_responder_url(
+        &self,
+        responder: &Responder,
+        url_type: &ResponderUrlType,
+    ) -> anyhow::Result<Url> {
+        let public_url = &self.api.config.public_url;
+        let user_handle = &self.user.handle;
+        let host = match url_type {
+            ResponderUrlType::Path => {
+                return Ok(public_url.join(&format!(
+                    "api/webhooks/{user_handle}{}",
+                    responder.location.full_path()
+                ))?);
+            }
+            ResponderUrlType::Subdomain { .. } => {
+                let Some(public_host) = public_url.host_str() else {
+                    bail!(SecutilsError::client(
+                        "Public URL doesn't have a host, cannot construct responder subdomain URL."
+                    ));
+                };
+
+                match responder.location.subdomain_prefix {
+                    Some(ref subdomain_prefix) => {
+                        format!("{subdomain_prefix}-{user_handle}.webhooks.{public_host}")
+                    }
+                    None => format!("{user_handle}.webhooks.{public_host}"),
+                }
+            }
+            ResponderUrlType::CustomDomain => {
+                let Some(custom_domain) = self
+                    .api
+                    .config
+                    .utils
+                    .webhook_custom_domains
+                    .iter()
+                    .find_map(|(domain, handle)| (handle == user_handle).then_some(domain))
+                else {
+                    bail!(
+                        SecutilsError::client("User doesn't have a custom webhook domain.")
+                            .with_code(ErrorCode::NotAllowed)
+                    );
+                };
+
+                custom_domain.clone()
+            }
+        };
+
+        let mut url = public_url.clone();
+        url.set_host(Some(&host))?;
+        url.set_path(&responder.location.full_path());
+        Ok(url)
     }
 
     fn is_valid_webhooks_subdomain_prefix(
@@ -356,8 +1195,15 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
         public_host: &str,
         subdomain_prefix: &str,
     ) -> bool {
-        // Subdomain prefix should not contain dots to not add nested DNS labels.
-        if subdomain_prefix.contains('.') {
+        // Subdomain prefix should be reasonably short and consist only of alphanumeric characters
+        // and hyphens (dots would add nested DNS labels).
+        if subdomain_prefix.is_empty()
+            || subdomain_prefix.len() > MAX_SUBDOMAIN_PREFIX_LENGTH
+            || subdomain_prefix.starts_with('-')
+            || !subdomain_prefix
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        {
             return false;
         }
 
@@ -384,36 +1230,299 @@ impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> WebhooksApiExt<'a, 'u, DR, ET>
     }
 }
 
+/// An API to work with webhooks on behalf of the system (e.g., to route incoming webhook requests).
+pub struct WebhooksSystemApiExt<'a, DR: DnsResolver, ET: EmailTransport> {
+    api: &'a Api<DR, ET>,
+}
+
+impl<'a, DR: DnsResolver, ET: EmailTransport> WebhooksSystemApiExt<'a, DR, ET> {
+    /// Creates Webhooks System API.
+    pub fn new(api: &'a Api<DR, ET>) -> Self {
+        Self { api }
+    }
+
+    /// Resolves the responder that should handle the incoming webhook request, and the user it
+    /// belongs to. The schemes are checked in the following order:
+    /// 1. Path: user handle is explicitly specified in the request path.
+    /// 2. Custom domain: request host is one of the configured custom domains.
+    /// 3. Subdomain: request host is a subdomain of the webhooks host, with the user handle and
+    ///    optional subdomain prefix (`<prefix>-<user-handle>.webhooks.<public-host>`).
+    ///
+    /// The first scheme the request qualifies for is used, even if it doesn't have a matching
+    /// responder.
+    pub async fn resolve_responder(
+        &self,
+        host: &str,
+        user_handle: Option<&str>,
+        path: &str,
+        method: ResponderMethod,
+    ) -> anyhow::Result<Option<ResponderMatch>> {
+        if let Some(user_handle) = user_handle {
+            return self
+                .find_responder(ResponderMatchScheme::Path, user_handle, None, path, method)
+                .await;
+        }
+
+        // Port isn't relevant for the host-based schemes.
+        let host = host.to_ascii_lowercase();
+        let host = match host.rsplit_once(':') {
+            Some((host, port)) if port.chars().all(|c| c.is_ascii_digit()) => host,
+            _ => host.as_str(),
+        };
+
+        if let Some(user_handle) = self.api.config.utils.webhook_custom_domains.get(host) {
+            return self
+                .find_responder(
+                    ResponderMatchScheme::CustomDomain,
+                    user_handle,
+                    None,
+                    path,
+                    method,
+                )
+                .await;
+        }
+
+        match parse_webhook_host(&self.api.config, host) {
+            Ok((user_handle, subdomain_prefix)) => {
+                self.find_responder(
+                    ResponderMatchScheme::Subdomain,
+                    user_handle,
+                    subdomain_prefix,
+                    path,
+                    method,
+                )
+                .await
+            }
+            Err(err) => {
+                log::error!(
+                    "Failed to extract user handle and subdomain prefix from the request host ({host:?}): {err:?}"
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    /// Finds the responder of the user with the specified handle.
+    async fn find_responder(
+        &self,
+        scheme: ResponderMatchScheme,
+        user_handle: &str,
+        subdomain_prefix: Option<&str>,
+        path: &str,
+        method: ResponderMethod,
+    ) -> anyhow::Result<Option<ResponderMatch>> {
+        let Some(user) = self.api.users().get_by_handle(user_handle).await? else {
+            log::error!("Failed to find user by the handle ({user_handle}).");
+            return Ok(None);
+        };
+
+        // Subdomain prefixes are available only to the specific subscriptions.
+        let features = user.subscription.get_features(&self.api.config);
+        if subdomain_prefix.is_some() && !features.config.webhooks.responder_custom_subdomain_prefix
+        {
+            log::error!(
+                user:serde = user.log_context();
+                "User isn't allowed to use responder subdomain prefixes ({subdomain_prefix:?})."
+            );
+            return Ok(None);
+        }
+
+        let responder = self
+            .api
+            .webhooks(&user)
+            .find_responder(subdomain_prefix, path, method)
+            .await?;
+        Ok(responder.map(|responder| ResponderMatch {
+            user,
+            responder,
+            scheme,
+        }))
+    }
+}
+
 impl<'a, 'u, DR: DnsResolver, ET: EmailTransport> Api<DR, ET> {
     /// Returns an API to work with responders.
     pub fn webhooks(&'a self, user: &'u User) -> WebhooksApiExt<'a, 'u, DR, ET> {
         WebhooksApiExt::new(self, user)
     }
+
+    /// Returns an API to work with webhooks on behalf of the system.
+    pub fn webhooks_system(&'a self) -> WebhooksSystemApiExt<'a, DR, ET> {
+        WebhooksSystemApiExt::new(self)
+    }
+}
+
+/// Checks whether the path is the base path itself or is nested within it, e.g. `/mockapi/v1` and
+/// `/mockapi/v1/users` are within `/mockapi/v1`, but `/mockapi/v10` isn't.
+fn is_path_within_base_path(path: &str, base_path: &str) -> bool {
+    match path.strip_prefix(base_path) {
+        Some(rest) => rest.is_empty() || rest.starts_with('/'),
+        None => false,
+    }
+}
+
+/// Extracts user handle and optional subdomain prefix from the webhook host, e.g.,
+/// `abc-user-handle.webhooks.secutils.dev` -> (`user-handle`, `Some("abc")`).
+fn parse_webhook_host<'s>(
+    config: &Config,
+    webhook_host: &'s str,
+) -> anyhow::Result<(&'s str, Option<&'s str>)> {
+    let Some(public_host) = config.public_url.host_str() else {
+        bail!(SecutilsError::client(
+            "Public URL doesn't have a host, cannot extract responder subdomain prefix."
+        ));
+    };
+
+    // First remove the public URL host from the request host to keep only user-specific part.
+    let Some(webhook_subdomain) = webhook_host.strip_suffix(&format!(".webhooks.{}", public_host))
+    else {
+        bail!(SecutilsError::client(format!(
+            "Failed to extract base host from the webhook host ({webhook_host})."
+        )));
+    };
+
+    // Next separate user handle part from the rest of the subdomain, e.g.,:
+    // abc-user-handle.secutils.dev -> (user-handle, Some("abc"))
+    Ok(match webhook_subdomain.rsplit_once('-') {
+        // No custom subdomain, just user handle.
+        None => (webhook_subdomain, None),
+        Some((subdomain_prefix, user_handle)) => (user_handle, Some(subdomain_prefix)),
+    })
+}
+
+/// Returns parameters of the demo responders used for onboarding, demos, and E2E tests.
+fn demo_responders_create_params() -> Vec<RespondersCreateParams> {
+    let create_params =
+        |name: &str, path: &str, settings: ResponderSettings| RespondersCreateParams {
+            name: name.to_string(),
+            location: ResponderLocation {
+                path_type: ResponderPathType::Exact,
+                path: path.to_string(),
+                subdomain_prefix: None,
+                base_path: None,
+            },
+            method: ResponderMethod::Any,
+            enabled: true,
+            settings,
+        };
+
+    vec![
+        create_params(
+            "Demo: JSON API",
+            "/demo/api/users",
+            ResponderSettings {
+                requests_to_track: 10,
+                status_code: 200,
+                body: Some(
+                    r#"[{"id":1,"name":"Alice","role":"admin"},{"id":2,"name":"Bob","role":"user"}]"#
+                        .to_string(),
+                ),
+                headers: Some(vec![(
+                    "Content-Type".to_string(),
+                    "application/json".to_string(),
+                )]),
+                script: None,
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
+            },
+        ),
+        create_params(
+            "Demo: HTML page",
+            "/demo/page",
+            ResponderSettings {
+                requests_to_track: 10,
+                status_code: 200,
+                body: Some(
+                    "<!DOCTYPE html><html><head><title>Secutils.dev demo</title></head><body><h1>Hello from Secutils.dev!</h1></body></html>"
+                        .to_string(),
+                ),
+                headers: Some(vec![(
+                    "Content-Type".to_string(),
+                    "text/html; charset=utf-8".to_string(),
+                )]),
+                script: None,
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
+            },
+        ),
+        create_params(
+            "Demo: Error injection",
+            "/demo/flaky",
+            ResponderSettings {
+                requests_to_track: 10,
+                status_code: 200,
+                body: Some(r#"{"status":"ok"}"#.to_string()),
+                headers: Some(vec![(
+                    "Content-Type".to_string(),
+                    "application/json".to_string(),
+                )]),
+                script: Some(
+                    r#"const status = Number(context.query.status ?? 500);
+const statusCode = status >= 400 && status <= 599 ? status : 500;
+return { statusCode, body: Deno.core.encode(JSON.stringify({ status: "error", statusCode })) };"#
+                        .to_string(),
+                ),
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
+            },
+        ),
+    ]
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{parse_webhook_host, MAX_RESPONDER_JSON_BODY_SIZE};
     use crate::{
-        error::Error as SecutilsError,
-        tests::{mock_api, mock_user},
+        error::{Error as SecutilsError, ErrorCode},
+        tests::{mock_api, mock_api_with_config, mock_config, mock_user, mock_user_with_id},
+        users::{UserData, UserDataNamespace, UserDisplayOrderCollection},
         utils::webhooks::{
-            api_ext::{RespondersCreateParams, RespondersUpdateParams},
-            Responder, ResponderLocation, ResponderMethod, ResponderPathType, ResponderSettings,
-            ResponderStats, RespondersRequestCreateParams,
+            api_ext::{
+                RespondersBasePathCreateParams, RespondersCheckSubdomainPrefixParams,
+                RespondersCreateParams, RespondersMigrateUrlTypeParams, RespondersUpdateParams,
+            },
+            InboundEmailCreateParams, Responder, ResponderBodyTemplate, ResponderForwardResult,
+            ResponderInitialResponse, ResponderLatencyProfile, ResponderLocation,
+            ResponderMatchScheme, ResponderMethod, ResponderPathType, ResponderSettings,
+            ResponderStats, ResponderSubdomainPrefixAvailability, ResponderUrlType,
+            RespondersRequestCreateParams,
         },
     };
+    use actix_web::ResponseError;
+    use httpmock::MockServer;
     use insta::assert_debug_snapshot;
     use sqlx::PgPool;
-    use std::borrow::Cow;
-    use uuid::uuid;
+    use std::{borrow::Cow, fs, time::Duration};
+    use time::OffsetDateTime;
+    use url::Url;
+    use uuid::{uuid, Uuid};
 
-    fn get_request_create_params(url: &str) -> RespondersRequestCreateParams {
+    fn get_request_create_params(url: &str) -> RespondersRequestCreateParams<'_> {
         RespondersRequestCreateParams {
             client_address: None,
             method: Cow::Borrowed("POST"),
             headers: None,
             url: Cow::Borrowed(url),
             body: None,
+            correlation_id: None,
         }
     }
 
@@ -431,6 +1540,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -440,6 +1550,14 @@ mod tests {
                     body: Some("body".to_string()),
                     headers: Some(vec![("key".to_string(), "value".to_string())]),
                     script: Some("return { body: `custom body` };".to_string()),
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
             })
             .await?;
@@ -465,6 +1583,14 @@ mod tests {
             body: None,
             headers: None,
             script: Some("return { body: `custom body` };".to_string()),
+            body_file: None,
+            echo_transform: None,
+            latency: None,
+            fault: None,
+            body_template: None,
+            forward_to: None,
+            capture_json: false,
+            initial_response: None,
         };
 
         let create_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
@@ -478,7 +1604,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -494,7 +1621,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -510,7 +1638,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -526,7 +1655,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/a".repeat(51),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -542,7 +1672,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "path".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -558,7 +1689,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path/".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -574,7 +1706,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
-                    subdomain_prefix: Some("".to_string())
+                    subdomain_prefix: Some("".to_string()),
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -590,7 +1723,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
-                    subdomain_prefix: Some("sub.sub".to_string())
+                    subdomain_prefix: Some("sub.sub".to_string()),
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -606,7 +1740,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
-                    subdomain_prefix: Some("сабдомейн".to_string())
+                    subdomain_prefix: Some("сабдомейн".to_string()),
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -622,7 +1757,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
-                    subdomain_prefix: Some("s".repeat(201))
+                    subdomain_prefix: Some("s".repeat(201)),
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -638,7 +1774,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -657,7 +1794,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -669,6 +1807,56 @@ mod tests {
             @r###""Responder status code should have a value between 100 and 999, but received 1000.""###
         );
 
+        // Initial response for zero requests.
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(RespondersCreateParams {
+                name: "some-name".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/path".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Get,
+                enabled: true,
+                settings: ResponderSettings {
+                    initial_response: Some(ResponderInitialResponse {
+                        requests: 0,
+                        status_code: 503,
+                        body: None,
+                        headers: None,
+                    }),
+                    ..settings.clone()
+                }
+            }).await),
+            @r###""Responder initial response should be sent for at least one request.""###
+        );
+
+        // Invalid initial response status code.
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(RespondersCreateParams {
+                name: "some-name".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/path".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Get,
+                enabled: true,
+                settings: ResponderSettings {
+                    initial_response: Some(ResponderInitialResponse {
+                        requests: 2,
+                        status_code: 1000,
+                        body: None,
+                        headers: None,
+                    }),
+                    ..settings.clone()
+                }
+            }).await),
+            @r###""Responder initial response status code should have a value between 100 and 999, but received 1000.""###
+        );
+
         // Too many requests to track.
         assert_debug_snapshot!(
             create_and_fail(webhooks.create_responder(RespondersCreateParams {
@@ -676,7 +1864,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -695,7 +1884,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -711,63 +1901,489 @@ mod tests {
     }
 
     #[sqlx::test]
-    async fn properly_updates_responder(pool: PgPool) -> anyhow::Result<()> {
-        let api = mock_api(pool).await?;
-        let mock_user = mock_user()?;
+    async fn properly_validates_responder_body_file(pool: PgPool) -> anyhow::Result<()> {
+        let root_dir = std::env::temp_dir().join(format!("secutils-{}", Uuid::now_v7()));
+        let templates_dir = root_dir.join("templates");
+        fs::create_dir_all(&templates_dir)?;
+        fs::write(templates_dir.join("demo.json"), r#"{ "key": "value" }"#)?;
+        fs::write(root_dir.join("secret.txt"), "secret")?;
+
+        let mut config = mock_config()?;
+        config.utils.responder_templates_dir = Some(templates_dir);
+        let api = mock_api_with_config(pool, config).await?;
+
+        let mut mock_user = mock_user()?;
+        mock_user.is_operator = true;
         api.db.insert_user(&mock_user).await?;
 
         let webhooks = api.webhooks(&mock_user);
-        let responder = webhooks
-            .create_responder(RespondersCreateParams {
-                name: "name_one".to_string(),
-                location: ResponderLocation {
-                    path_type: ResponderPathType::Exact,
-                    path: "/".to_string(),
-                    subdomain_prefix: None,
-                },
-                method: ResponderMethod::Any,
-                enabled: true,
-                settings: ResponderSettings {
-                    requests_to_track: 0,
-                    status_code: 200,
-                    body: None,
-                    headers: None,
-                    script: None,
-                },
-            })
-            .await?;
+        let create_params = |body_file: &str| RespondersCreateParams {
+            name: "some-name".to_string(),
+            location: ResponderLocation {
+                path_type: ResponderPathType::Exact,
+                path: "/path".to_string(),
+                subdomain_prefix: None,
+                base_path: None,
+            },
+            method: ResponderMethod::Get,
+            enabled: true,
+            settings: ResponderSettings {
+                requests_to_track: 0,
+                status_code: 200,
+                body: None,
+                headers: None,
+                script: None,
+                body_file: Some(body_file.to_string()),
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
+            },
+        };
 
-        // Update enabled.
-        let updated_responder = webhooks
-            .update_responder(
-                responder.id,
-                RespondersUpdateParams {
-                    name: None,
-                    location: None,
-                    method: None,
-                    enabled: Some(false),
-                    settings: None,
-                },
-            )
-            .await?;
-        let expected_responder = Responder {
-            enabled: false,
-            ..responder.clone()
+        let create_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
         };
-        assert_eq!(expected_responder, updated_responder);
+
+        // Path traversal.
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(create_params("../secret.txt")).await),
+            @r###""Responder body file path ('../secret.txt') should be relative to the templates directory.""###
+        );
+
+        // Absolute path.
+        let absolute_path = root_dir.join("secret.txt").to_string_lossy().to_string();
         assert_eq!(
-            expected_responder,
-            webhooks.get_responder(responder.id).await?.unwrap()
+            create_and_fail(webhooks.create_responder(create_params(&absolute_path)).await)
+                .to_string(),
+            format!(
+                "Responder body file path ('{absolute_path}') should be relative to the templates directory."
+            )
         );
 
-        // Update name.
-        let updated_responder = webhooks
-            .update_responder(
-                responder.id,
-                RespondersUpdateParams {
-                    name: Some("name_two".to_string()),
-                    location: None,
-                    method: None,
+        // Non-existent file.
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(create_params("missing.json")).await),
+            @r###""Responder body file ('missing.json') is not found.""###
+        );
+
+        // Both body and body file.
+        let mut params = create_params("demo.json");
+        params.settings.body = Some("body".to_string());
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(params).await),
+            @r###""Responder cannot have both body and body file.""###
+        );
+
+        // Non-operator user.
+        let mut regular_user = mock_user.clone();
+        regular_user.is_operator = false;
+        assert_debug_snapshot!(
+            create_and_fail(api.webhooks(&regular_user).create_responder(create_params("demo.json")).await),
+            @r###""Only operators can serve responder body from files.""###
+        );
+
+        // Valid file.
+        let responder = webhooks
+            .create_responder(create_params("./demo.json"))
+            .await?;
+        assert_eq!(
+            responder.settings.body_file,
+            Some("./demo.json".to_string())
+        );
+
+        fs::remove_dir_all(root_dir)?;
+
+        // Templates directory isn't configured.
+        let api = mock_api(api.db.pool.clone()).await?;
+        assert_debug_snapshot!(
+            create_and_fail(api.webhooks(&mock_user).create_responder(create_params("demo.json")).await),
+            @r###""Responder body files are not supported.""###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_validates_responder_echo_transform(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        let create_params = |echo_transform: &str| RespondersCreateParams {
+            name: "some-name".to_string(),
+            location: ResponderLocation {
+                path_type: ResponderPathType::Exact,
+                path: "/path".to_string(),
+                subdomain_prefix: None,
+                base_path: None,
+            },
+            method: ResponderMethod::Post,
+            enabled: true,
+            settings: ResponderSettings {
+                requests_to_track: 0,
+                status_code: 200,
+                body: None,
+                headers: None,
+                script: None,
+                body_file: None,
+                echo_transform: Some(echo_transform.to_string()),
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
+            },
+        };
+
+        let create_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
+
+        // Empty transform.
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(create_params("")).await),
+            @r###""Responder echo transform cannot be empty.""###
+        );
+
+        // Transform combined with script.
+        let mut params = create_params("return { body: context.body };");
+        params.settings.script = Some("return { body: `custom body` };".to_string());
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(params).await),
+            @r###""Responder echo transform cannot be combined with script, body or body file.""###
+        );
+
+        // Transform combined with body.
+        let mut params = create_params("return { body: context.body };");
+        params.settings.body = Some("body".to_string());
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(params).await),
+            @r###""Responder echo transform cannot be combined with script, body or body file.""###
+        );
+
+        // Invalid transform.
+        let error = create_and_fail(
+            webhooks
+                .create_responder(create_params("return { body: context.body "))
+                .await,
+        )
+        .to_string();
+        assert!(error.starts_with("Responder echo transform is not valid: "));
+        assert!(error.contains("SyntaxError"));
+
+        // Valid transform isn't invoked during validation.
+        let responder = webhooks
+            .create_responder(create_params("throw new Error(`Shouldn't be invoked.`);"))
+            .await?;
+        assert_eq!(
+            responder.settings.echo_transform,
+            Some("throw new Error(`Shouldn't be invoked.`);".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_validates_responder_latency(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        let create_params = |latency: ResponderLatencyProfile| RespondersCreateParams {
+            name: "some-name".to_string(),
+            location: ResponderLocation {
+                path_type: ResponderPathType::Exact,
+                path: "/path".to_string(),
+                subdomain_prefix: None,
+                base_path: None,
+            },
+            method: ResponderMethod::Post,
+            enabled: true,
+            settings: ResponderSettings {
+                requests_to_track: 0,
+                status_code: 200,
+                body: None,
+                headers: None,
+                script: None,
+                body_file: None,
+                echo_transform: None,
+                latency: Some(latency),
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
+            },
+        };
+
+        let create_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
+
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(create_params(ResponderLatencyProfile::Fixed {
+                delay: Duration::from_secs(31)
+            })).await),
+            @r###""Responder latency cannot be greater than 30s.""###
+        );
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(create_params(ResponderLatencyProfile::Uniform {
+                min: Duration::from_millis(500),
+                max: Duration::from_millis(100)
+            })).await),
+            @r###""Responder latency minimum cannot be greater than maximum.""###
+        );
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(create_params(ResponderLatencyProfile::Uniform {
+                min: Duration::from_millis(100),
+                max: Duration::from_secs(31)
+            })).await),
+            @r###""Responder latency cannot be greater than 30s.""###
+        );
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(create_params(ResponderLatencyProfile::Normal {
+                mean: Duration::from_millis(100),
+                stddev: Duration::from_secs(31)
+            })).await),
+            @r###""Responder latency cannot be greater than 30s.""###
+        );
+
+        let latency = ResponderLatencyProfile::Normal {
+            mean: Duration::from_millis(300),
+            stddev: Duration::from_millis(50),
+        };
+        let responder = webhooks.create_responder(create_params(latency)).await?;
+        assert_eq!(responder.settings.latency, Some(latency));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_validates_responder_body_template(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        let create_params = |body: Option<&str>| RespondersCreateParams {
+            name: "some-name".to_string(),
+            location: ResponderLocation {
+                path_type: ResponderPathType::Exact,
+                path: "/path".to_string(),
+                subdomain_prefix: None,
+                base_path: None,
+            },
+            method: ResponderMethod::Post,
+            enabled: true,
+            settings: ResponderSettings {
+                requests_to_track: 0,
+                status_code: 200,
+                body: body.map(|body| body.to_string()),
+                headers: None,
+                script: None,
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: Some(ResponderBodyTemplate { seed: Some(42) }),
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
+            },
+        };
+
+        let create_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
+
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(create_params(None)).await),
+            @r###""Responder body template requires either body or body file.""###
+        );
+
+        let error = create_and_fail(
+            webhooks
+                .create_responder(create_params(Some("{{faker.name")))
+                .await,
+        );
+        assert_eq!(error.code(), ErrorCode::InvalidInput);
+        assert!(error
+            .root_cause
+            .to_string()
+            .starts_with("Responder body template is not valid: "));
+
+        let responder = webhooks
+            .create_responder(create_params(Some(r#"{ "name": "{{faker.name}}" }"#)))
+            .await?;
+        assert_eq!(
+            responder.settings.body_template,
+            Some(ResponderBodyTemplate { seed: Some(42) })
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_validates_responder_forward_targets(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        let create_params = |forward_to: Vec<&str>| -> anyhow::Result<RespondersCreateParams> {
+            Ok(RespondersCreateParams {
+                name: "some-name".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/path".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Post,
+                enabled: true,
+                settings: ResponderSettings {
+                    requests_to_track: 0,
+                    status_code: 200,
+                    body: None,
+                    headers: None,
+                    script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: Some(
+                        forward_to
+                            .into_iter()
+                            .map(Url::parse)
+                            .collect::<Result<_, _>>()?,
+                    ),
+                    capture_json: false,
+                    initial_response: None,
+                },
+            })
+        };
+
+        let create_and_fail = |result: anyhow::Result<_>| -> SecutilsError {
+            result.unwrap_err().downcast::<SecutilsError>().unwrap()
+        };
+
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(create_params(vec![])?).await),
+            @r###""Responder forward targets cannot be empty.""###
+        );
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(create_params(vec!["https://example.com"; 6])?).await),
+            @r###""Responder can forward requests to at most 5 targets, but received 6.""###
+        );
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(create_params(vec!["https://example.com", "ftp://example.com/one"])?).await),
+            @r###""Responder forward target URL must be either `http` or `https` and have a valid public reachable domain name, but received ftp://example.com/one.""###
+        );
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(create_params(vec!["https://127.0.0.1/one"])?).await),
+            @r###""Responder forward target URL must be either `http` or `https` and have a valid public reachable domain name, but received https://127.0.0.1/one.""###
+        );
+        assert_debug_snapshot!(
+            create_and_fail(webhooks.create_responder(create_params(vec!["https://sub.secutils.dev/one"])?).await),
+            @r###""Responder forward target URL must be either `http` or `https` and have a valid public reachable domain name, but received https://sub.secutils.dev/one.""###
+        );
+
+        let responder = webhooks
+            .create_responder(create_params(vec![
+                "https://example.com/one",
+                "https://example.com/two",
+            ])?)
+            .await?;
+        assert_eq!(
+            responder.settings.forward_to,
+            Some(vec![
+                Url::parse("https://example.com/one")?,
+                Url::parse("https://example.com/two")?
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_updates_responder(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        let responder = webhooks
+            .create_responder(RespondersCreateParams {
+                name: "name_one".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Any,
+                enabled: true,
+                settings: ResponderSettings {
+                    requests_to_track: 0,
+                    status_code: 200,
+                    body: None,
+                    headers: None,
+                    script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
+                },
+            })
+            .await?;
+
+        // Update enabled.
+        let updated_responder = webhooks
+            .update_responder(
+                responder.id,
+                RespondersUpdateParams {
+                    name: None,
+                    location: None,
+                    method: None,
+                    enabled: Some(false),
+                    settings: None,
+                },
+            )
+            .await?;
+        let expected_responder = Responder {
+            enabled: false,
+            ..responder.clone()
+        };
+        assert_eq!(expected_responder, updated_responder);
+        assert_eq!(
+            expected_responder,
+            webhooks.get_responder(responder.id).await?.unwrap()
+        );
+
+        // Update name.
+        let updated_responder = webhooks
+            .update_responder(
+                responder.id,
+                RespondersUpdateParams {
+                    name: Some("name_two".to_string()),
+                    location: None,
+                    method: None,
                     enabled: None,
                     settings: None,
                 },
@@ -794,6 +2410,7 @@ mod tests {
                         path_type: ResponderPathType::Exact,
                         path: "/path".to_string(),
                         subdomain_prefix: None,
+                        base_path: None,
                     }),
                     method: None,
                     enabled: None,
@@ -807,6 +2424,7 @@ mod tests {
                 path_type: ResponderPathType::Exact,
                 path: "/path".to_string(),
                 subdomain_prefix: None,
+                base_path: None,
             },
             enabled: false,
             ..responder.clone()
@@ -827,6 +2445,7 @@ mod tests {
                         path_type: ResponderPathType::Prefix,
                         path: "/path".to_string(),
                         subdomain_prefix: Some("sub".to_string()),
+                        base_path: None,
                     }),
                     method: None,
                     enabled: None,
@@ -840,6 +2459,7 @@ mod tests {
                 path_type: ResponderPathType::Prefix,
                 path: "/path".to_string(),
                 subdomain_prefix: Some("sub".to_string()),
+                base_path: None,
             },
             enabled: false,
             ..responder.clone()
@@ -869,6 +2489,7 @@ mod tests {
                 path_type: ResponderPathType::Prefix,
                 path: "/path".to_string(),
                 subdomain_prefix: Some("sub".to_string()),
+                base_path: None,
             },
             method: ResponderMethod::Post,
             enabled: false,
@@ -895,6 +2516,14 @@ mod tests {
                         body: Some("some-new-body".to_string()),
                         headers: Some(vec![("new-key".to_string(), "value".to_string())]),
                         script: Some("return { body: `custom body` };".to_string()),
+                        body_file: None,
+                        echo_transform: None,
+                        latency: None,
+                        fault: None,
+                        body_template: None,
+                        forward_to: None,
+                        capture_json: false,
+                        initial_response: None,
                     }),
                 },
             )
@@ -905,6 +2534,7 @@ mod tests {
                 path_type: ResponderPathType::Prefix,
                 path: "/path".to_string(),
                 subdomain_prefix: Some("sub".to_string()),
+                base_path: None,
             },
             method: ResponderMethod::Post,
             enabled: false,
@@ -914,6 +2544,14 @@ mod tests {
                 body: Some("some-new-body".to_string()),
                 headers: Some(vec![("new-key".to_string(), "value".to_string())]),
                 script: Some("return { body: `custom body` };".to_string()),
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
             },
             ..responder.clone()
         };
@@ -939,6 +2577,14 @@ mod tests {
             body: None,
             headers: None,
             script: None,
+            body_file: None,
+            echo_transform: None,
+            latency: None,
+            fault: None,
+            body_template: None,
+            forward_to: None,
+            capture_json: false,
+            initial_response: None,
         };
         let responder = webhooks
             .create_responder(RespondersCreateParams {
@@ -947,6 +2593,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -1032,7 +2679,8 @@ mod tests {
                 location: Some(ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 }),
                 method: None,
                 enabled: None,
@@ -1048,7 +2696,8 @@ mod tests {
                 location: Some(ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/a".repeat(51),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 }),
                 method: None,
                 enabled: None,
@@ -1064,7 +2713,8 @@ mod tests {
                 location: Some(ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "path".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 }),
                 method: None,
                 enabled: None,
@@ -1080,7 +2730,8 @@ mod tests {
                 location: Some(ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path/".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 }),
                 method: None,
                 enabled: None,
@@ -1096,7 +2747,8 @@ mod tests {
                 location: Some(ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
-                    subdomain_prefix: Some("".to_string())
+                    subdomain_prefix: Some("".to_string()),
+                    base_path: None,
                 }),
                 method: None,
                 enabled: None,
@@ -1112,7 +2764,8 @@ mod tests {
                 location: Some(ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
-                    subdomain_prefix: Some("sub.sub".to_string())
+                    subdomain_prefix: Some("sub.sub".to_string()),
+                    base_path: None,
                 }),
                 method: None,
                 enabled: None,
@@ -1128,7 +2781,8 @@ mod tests {
                 location: Some(ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
-                    subdomain_prefix: Some("сабдомейн".to_string())
+                    subdomain_prefix: Some("сабдомейн".to_string()),
+                    base_path: None,
                 }),
                 method: None,
                 enabled: None,
@@ -1144,7 +2798,8 @@ mod tests {
                 location: Some(ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
-                    subdomain_prefix: Some("s".repeat(201))
+                    subdomain_prefix: Some("s".repeat(201)),
+                    base_path: None,
                 }),
                 method: None,
                 enabled: None,
@@ -1229,6 +2884,14 @@ mod tests {
             body: None,
             headers: None,
             script: None,
+            body_file: None,
+            echo_transform: None,
+            latency: None,
+            fault: None,
+            body_template: None,
+            forward_to: None,
+            capture_json: false,
+            initial_response: None,
         };
 
         let responders = [
@@ -1239,6 +2902,7 @@ mod tests {
                         path_type: ResponderPathType::Exact,
                         path: "/".to_string(),
                         subdomain_prefix: None,
+                        base_path: None,
                     },
                     method: ResponderMethod::Any,
                     enabled: true,
@@ -1252,6 +2916,7 @@ mod tests {
                         path_type: ResponderPathType::Prefix,
                         path: "/path".to_string(),
                         subdomain_prefix: Some("sub".to_string()),
+                        base_path: None,
                     },
                     method: ResponderMethod::Post,
                     enabled: true,
@@ -1298,19 +2963,365 @@ mod tests {
     }
 
     #[sqlx::test]
-    async fn properly_removes_responders(pool: PgPool) -> anyhow::Result<()> {
+    async fn can_check_subdomain_prefix_availability(pool: PgPool) -> anyhow::Result<()> {
         let api = mock_api(pool).await?;
-
         let mock_user = mock_user()?;
+        let mock_other_user = mock_user_with_id(uuid!("00000000-0000-0000-0000-000000000002"))?;
         api.db.insert_user(&mock_user).await?;
+        api.db.insert_user(&mock_other_user).await?;
 
-        let webhooks = api.webhooks(&mock_user);
-        let settings = ResponderSettings {
-            requests_to_track: 0,
-            status_code: 200,
-            body: None,
-            headers: None,
-            script: None,
+        let create_params = |subdomain_prefix: &str| RespondersCreateParams {
+            name: format!("name-{subdomain_prefix}"),
+            location: ResponderLocation {
+                path_type: ResponderPathType::Exact,
+                path: "/".to_string(),
+                subdomain_prefix: Some(subdomain_prefix.to_string()),
+                base_path: None,
+            },
+            method: ResponderMethod::Any,
+            enabled: true,
+            settings: ResponderSettings {
+                requests_to_track: 3,
+                status_code: 200,
+                body: None,
+                headers: None,
+                script: None,
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
+            },
+        };
+        api.webhooks(&mock_other_user)
+            .create_responder(create_params("my-app"))
+            .await?;
+        api.webhooks(&mock_other_user)
+            .create_responder(create_params("my-app-1"))
+            .await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        let check = |subdomain_prefix: &str| {
+            webhooks.is_subdomain_prefix_available(RespondersCheckSubdomainPrefixParams {
+                subdomain_prefix: subdomain_prefix.to_string(),
+            })
+        };
+
+        // Available prefix.
+        assert_eq!(
+            check("my-other-app").await?,
+            ResponderSubdomainPrefixAvailability {
+                available: true,
+                suggestion: None,
+            }
+        );
+
+        // Prefix taken by another user.
+        assert_eq!(
+            check("my-app").await?,
+            ResponderSubdomainPrefixAvailability {
+                available: false,
+                suggestion: Some("my-app-2".to_string()),
+            }
+        );
+
+        // Reserved prefix.
+        assert_eq!(
+            check("www").await?,
+            ResponderSubdomainPrefixAvailability {
+                available: false,
+                suggestion: Some("www-1".to_string()),
+            }
+        );
+
+        // Invalid prefixes.
+        for subdomain_prefix in ["", "-app", "my_app", "my.app", "My-App", &"s".repeat(33)] {
+            let error = check(subdomain_prefix)
+                .await
+                .unwrap_err()
+                .downcast::<SecutilsError>()?;
+            assert_eq!(error.status_code(), 400);
+            assert_eq!(
+                error.to_string(),
+                format!("\"Responder subdomain prefix ('{subdomain_prefix}') is not valid.\"")
+            );
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_migrates_responder_url_type(pool: PgPool) -> anyhow::Result<()> {
+        let mut config = mock_config()?;
+        let mock_user = mock_user()?;
+        let mock_other_user = mock_user_with_id(uuid!("00000000-0000-0000-0000-000000000002"))?;
+        config.utils.webhook_custom_domains =
+            [("hooks.example.com".to_string(), mock_user.handle.clone())]
+                .into_iter()
+                .collect();
+        let api = mock_api_with_config(pool, config).await?;
+        api.db.insert_user(&mock_user).await?;
+        api.db.insert_user(&mock_other_user).await?;
+
+        let create_params = |subdomain_prefix: Option<&str>| RespondersCreateParams {
+            name: "name_one".to_string(),
+            location: ResponderLocation {
+                path_type: ResponderPathType::Exact,
+                path: "/one".to_string(),
+                subdomain_prefix: subdomain_prefix.map(|prefix| prefix.to_string()),
+                base_path: None,
+            },
+            method: ResponderMethod::Any,
+            enabled: true,
+            settings: ResponderSettings {
+                requests_to_track: 3,
+                status_code: 200,
+                body: None,
+                headers: None,
+                script: None,
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
+            },
+        };
+        api.webhooks(&mock_other_user)
+            .create_responder(create_params(Some("taken")))
+            .await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        let responder = webhooks.create_responder(create_params(None)).await?;
+        webhooks
+            .create_responder_request(responder.id, get_request_create_params("/one"))
+            .await?;
+
+        // Migrate path responder to a subdomain with a prefix.
+        let migration = webhooks
+            .migrate_responder_url_type(
+                responder.id,
+                RespondersMigrateUrlTypeParams {
+                    url_type: ResponderUrlType::Subdomain {
+                        subdomain_prefix: Some("my-app".to_string()),
+                    },
+                },
+            )
+            .await?;
+        assert_eq!(migration.responder.id, responder.id);
+        assert_eq!(
+            migration.responder.location.subdomain_prefix.as_deref(),
+            Some("my-app")
+        );
+        assert_eq!(
+            migration.url.as_str(),
+            "https://my-app-devhandle00000000000000000000000000000001.webhooks.secutils.dev/one"
+        );
+        assert_eq!(
+            webhooks.get_responder(responder.id).await?,
+            Some(migration.responder)
+        );
+        assert_eq!(
+            webhooks.get_responder_requests(responder.id).await?.len(),
+            1
+        );
+        assert!(webhooks
+            .find_responder(None, "/one", ResponderMethod::Get)
+            .await?
+            .is_none());
+        assert_eq!(
+            webhooks
+                .find_responder(Some("my-app"), "/one", ResponderMethod::Get)
+                .await?
+                .map(|responder| responder.id),
+            Some(responder.id)
+        );
+
+        // Migrate responder back to the path.
+        let migration = webhooks
+            .migrate_responder_url_type(
+                responder.id,
+                RespondersMigrateUrlTypeParams {
+                    url_type: ResponderUrlType::Path,
+                },
+            )
+            .await?;
+        assert_eq!(migration.responder.location, responder.location);
+        assert_eq!(
+            migration.url.as_str(),
+            "https://secutils.dev/api/webhooks/devhandle00000000000000000000000000000001/one"
+        );
+        assert_eq!(
+            webhooks.get_responder_requests(responder.id).await?.len(),
+            1
+        );
+        assert_eq!(
+            webhooks
+                .find_responder(None, "/one", ResponderMethod::Get)
+                .await?
+                .map(|responder| responder.id),
+            Some(responder.id)
+        );
+
+        // Responders without a prefix are also reachable at the user subdomain and custom domain.
+        let migration = webhooks
+            .migrate_responder_url_type(
+                responder.id,
+                RespondersMigrateUrlTypeParams {
+                    url_type: ResponderUrlType::Subdomain {
+                        subdomain_prefix: None,
+                    },
+                },
+            )
+            .await?;
+        assert_eq!(
+            migration.url.as_str(),
+            "https://devhandle00000000000000000000000000000001.webhooks.secutils.dev/one"
+        );
+        let migration = webhooks
+            .migrate_responder_url_type(
+                responder.id,
+                RespondersMigrateUrlTypeParams {
+                    url_type: ResponderUrlType::CustomDomain,
+                },
+            )
+            .await?;
+        assert_eq!(migration.url.as_str(), "https://hooks.example.com/one");
+
+        // Subdomain prefix used by another user isn't available.
+        let error = webhooks
+            .migrate_responder_url_type(
+                responder.id,
+                RespondersMigrateUrlTypeParams {
+                    url_type: ResponderUrlType::Subdomain {
+                        subdomain_prefix: Some("taken".to_string()),
+                    },
+                },
+            )
+            .await
+            .unwrap_err()
+            .downcast::<SecutilsError>()?;
+        assert_eq!(error.code(), ErrorCode::AlreadyExists);
+        assert_debug_snapshot!(
+            error,
+            @r###""Responder subdomain prefix ('taken') is not available.""###
+        );
+
+        // Custom domain isn't configured for another user.
+        let other_responder = api
+            .webhooks(&mock_other_user)
+            .get_responders()
+            .await?
+            .remove(0);
+        assert_debug_snapshot!(
+            api.webhooks(&mock_other_user)
+                .migrate_responder_url_type(
+                    other_responder.id,
+                    RespondersMigrateUrlTypeParams {
+                        url_type: ResponderUrlType::CustomDomain,
+                    },
+                )
+                .await
+                .unwrap_err()
+                .downcast::<SecutilsError>()?,
+            @r###""User doesn't have a custom webhook domain.""###
+        );
+        assert_eq!(
+            api.webhooks(&mock_other_user)
+                .get_responder(other_responder.id)
+                .await?,
+            Some(other_responder)
+        );
+
+        // Unknown responder.
+        assert_debug_snapshot!(
+            webhooks
+                .migrate_responder_url_type(
+                    uuid!("00000000-0000-0000-0000-000000000010"),
+                    RespondersMigrateUrlTypeParams {
+                        url_type: ResponderUrlType::Path,
+                    },
+                )
+                .await
+                .unwrap_err()
+                .downcast::<SecutilsError>()?,
+            @r###""Responder ('00000000-0000-0000-0000-000000000010') is not found.""###
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_seeds_demo_responders(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        let responders = webhooks.seed_demo_responders().await?;
+        assert_debug_snapshot!(
+            responders.iter().map(|responder| (&responder.name, responder.location.to_string())).collect::<Vec<_>>(),
+            @r###"
+        [
+            (
+                "Demo: JSON API",
+                ":=:/demo/api/users",
+            ),
+            (
+                "Demo: HTML page",
+                ":=:/demo/page",
+            ),
+            (
+                "Demo: Error injection",
+                ":=:/demo/flaky",
+            ),
+        ]
+        "###
+        );
+
+        // Re-running doesn't duplicate responders.
+        assert!(webhooks.seed_demo_responders().await?.is_empty());
+        let mut all_responders = webhooks.get_responders().await?;
+        all_responders.sort_by_key(|responder| responder.id);
+        assert_eq!(all_responders, responders);
+
+        // Only missing demo responders are re-created.
+        webhooks.remove_responder(responders[1].id).await?;
+        let recreated_responders = webhooks.seed_demo_responders().await?;
+        assert_eq!(recreated_responders.len(), 1);
+        assert_eq!(recreated_responders[0].name, "Demo: HTML page");
+        assert_eq!(webhooks.get_responders().await?.len(), 3);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_removes_responders(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        let settings = ResponderSettings {
+            requests_to_track: 0,
+            status_code: 200,
+            body: None,
+            headers: None,
+            script: None,
+            body_file: None,
+            echo_transform: None,
+            latency: None,
+            fault: None,
+            body_template: None,
+            forward_to: None,
+            capture_json: false,
+            initial_response: None,
         };
         let responder_one = webhooks
             .create_responder(RespondersCreateParams {
@@ -1319,6 +3330,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -1332,6 +3344,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -1345,7 +3358,10 @@ mod tests {
         );
 
         webhooks.remove_responder(responder_one.id).await?;
-        assert_eq!(webhooks.get_responders().await?, [responder_two.clone()]);
+        assert_eq!(
+            webhooks.get_responders().await?,
+            std::slice::from_ref(&responder_two)
+        );
 
         webhooks.remove_responder(responder_two.id).await?;
         assert!(webhooks.get_responders().await?.is_empty());
@@ -1368,6 +3384,14 @@ mod tests {
             body: None,
             headers: None,
             script: None,
+            body_file: None,
+            echo_transform: None,
+            latency: None,
+            fault: None,
+            body_template: None,
+            forward_to: None,
+            capture_json: false,
+            initial_response: None,
         };
         let responder_one = webhooks
             .create_responder(RespondersCreateParams {
@@ -1376,6 +3400,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -1393,6 +3418,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: false,
@@ -1408,6 +3434,123 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn properly_returns_responders_in_display_order(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        let mut responders = vec![];
+        for name in ["name_one", "name_two", "name_three"] {
+            responders.push(
+                webhooks
+                    .create_responder(RespondersCreateParams {
+                        name: name.to_string(),
+                        location: ResponderLocation {
+                            path_type: ResponderPathType::Exact,
+                            path: format!("/{name}"),
+                            subdomain_prefix: None,
+                            base_path: None,
+                        },
+                        method: ResponderMethod::Any,
+                        enabled: true,
+                        settings: ResponderSettings {
+                            requests_to_track: 0,
+                            status_code: 200,
+                            body: None,
+                            headers: None,
+                            script: None,
+                            body_file: None,
+                            echo_transform: None,
+                            latency: None,
+                            fault: None,
+                            body_template: None,
+                            forward_to: None,
+                            capture_json: false,
+                            initial_response: None,
+                        },
+                    })
+                    .await?,
+            );
+        }
+
+        let get_names = || async {
+            anyhow::Ok(
+                webhooks
+                    .get_responders()
+                    .await?
+                    .into_iter()
+                    .map(|responder| responder.name)
+                    .collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(get_names().await?, ["name_one", "name_two", "name_three"]);
+
+        // Custom order, responders that aren't ordered go last.
+        let users = api.users();
+        users
+            .set_display_order(
+                mock_user.id,
+                UserDisplayOrderCollection::Responders,
+                vec![responders[2].id, responders[0].id],
+            )
+            .await?;
+        assert_eq!(get_names().await?, ["name_three", "name_one", "name_two"]);
+
+        // Pinned responders go first.
+        users
+            .set_display_pinned(
+                mock_user.id,
+                UserDisplayOrderCollection::Responders,
+                responders[1].id,
+                true,
+            )
+            .await?;
+        assert_eq!(get_names().await?, ["name_two", "name_three", "name_one"]);
+
+        // Order of other collections doesn't affect responders.
+        users
+            .set_display_order(
+                mock_user.id,
+                UserDisplayOrderCollection::WebPageContentTrackers,
+                vec![responders[0].id],
+            )
+            .await?;
+        assert_eq!(get_names().await?, ["name_two", "name_three", "name_one"]);
+
+        // Unpinned responders return to their position.
+        users
+            .set_display_pinned(
+                mock_user.id,
+                UserDisplayOrderCollection::Responders,
+                responders[1].id,
+                false,
+            )
+            .await?;
+        assert_eq!(get_names().await?, ["name_three", "name_one", "name_two"]);
+
+        // Order cannot include duplicates.
+        assert_eq!(
+            users
+                .set_display_order(
+                    mock_user.id,
+                    UserDisplayOrderCollection::Responders,
+                    vec![responders[0].id, responders[0].id],
+                )
+                .await
+                .unwrap_err()
+                .downcast::<SecutilsError>()?
+                .to_string(),
+            format!(
+                "Display order cannot include the same entity ('{}') more than once.",
+                responders[0].id
+            )
+        );
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn properly_returns_all_responders_stats(pool: PgPool) -> anyhow::Result<()> {
         let api = mock_api(pool).await?;
@@ -1423,6 +3566,14 @@ mod tests {
             body: None,
             headers: None,
             script: None,
+            body_file: None,
+            echo_transform: None,
+            latency: None,
+            fault: None,
+            body_template: None,
+            forward_to: None,
+            capture_json: false,
+            initial_response: None,
         };
         let responder_one = webhooks
             .create_responder(RespondersCreateParams {
@@ -1431,6 +3582,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -1444,6 +3596,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: false,
@@ -1505,6 +3658,14 @@ mod tests {
             body: None,
             headers: None,
             script: None,
+            body_file: None,
+            echo_transform: None,
+            latency: None,
+            fault: None,
+            body_template: None,
+            forward_to: None,
+            capture_json: false,
+            initial_response: None,
         };
         let responder_one = webhooks
             .create_responder(RespondersCreateParams {
@@ -1513,6 +3674,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -1526,6 +3688,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/two".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: false,
@@ -1573,6 +3736,304 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn properly_redacts_responder_requests(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        api.users()
+            .set_data(
+                UserDataNamespace::RedactionPatterns,
+                UserData::new(
+                    mock_user.id,
+                    serde_json::to_vec(&[r"\b\d{4}-\d{4}-\d{4}-\d{4}\b", "secret-[a-z]+"])?,
+                    OffsetDateTime::now_utc(),
+                ),
+            )
+            .await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        let responder = webhooks
+            .create_responder(RespondersCreateParams {
+                name: "name_one".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Any,
+                enabled: true,
+                settings: ResponderSettings {
+                    requests_to_track: 3,
+                    status_code: 200,
+                    body: None,
+                    headers: None,
+                    script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
+                },
+            })
+            .await?;
+
+        let body = br#"{"card":"4111-1111-1111-1111","token":"secret-token","amount":100}"#;
+        webhooks
+            .create_responder_request(
+                responder.id,
+                RespondersRequestCreateParams {
+                    body: Some(Cow::Borrowed(body)),
+                    ..get_request_create_params("/")
+                },
+            )
+            .await?;
+        webhooks
+            .create_responder_request(responder.id, get_request_create_params("/"))
+            .await?;
+
+        let requests = webhooks.get_responder_requests(responder.id).await?;
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[0].body.as_deref(),
+            Some(br#"{"card":"[REDACTED]","token":"[REDACTED]","amount":100}"#.as_slice())
+        );
+        assert!(requests[1].body.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_captures_json_responder_requests(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        let responder = webhooks
+            .create_responder(RespondersCreateParams {
+                name: "name_one".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Any,
+                enabled: true,
+                settings: ResponderSettings {
+                    requests_to_track: 5,
+                    status_code: 200,
+                    body: None,
+                    headers: None,
+                    script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: true,
+                    initial_response: None,
+                },
+            })
+            .await?;
+
+        let too_large_body = serde_json::to_vec(&"a".repeat(MAX_RESPONDER_JSON_BODY_SIZE))?;
+        for body in [
+            Some(br#"{"b":[1,2],"a":"x"}"#.as_slice()),
+            Some(br#"{"a":"#.as_slice()),
+            None,
+            Some(too_large_body.as_slice()),
+        ] {
+            let request = webhooks
+                .create_responder_request(
+                    responder.id,
+                    RespondersRequestCreateParams {
+                        body: body.map(Cow::Borrowed),
+                        ..get_request_create_params("/")
+                    },
+                )
+                .await?
+                .unwrap();
+            assert_eq!(request.body.as_deref(), body);
+        }
+
+        let requests = webhooks.get_responder_requests(responder.id).await?;
+        assert_eq!(requests.len(), 4);
+
+        // Valid JSON body should be stored along with its canonical pretty-printed form.
+        assert_eq!(
+            requests[0].body.as_deref(),
+            Some(br#"{"b":[1,2],"a":"x"}"#.as_slice())
+        );
+        assert_eq!(requests[0].json_valid, Some(true));
+        assert_eq!(
+            requests[0].json_body.as_deref(),
+            Some("{\n  \"a\": \"x\",\n  \"b\": [\n    1,\n    2\n  ]\n}")
+        );
+
+        // Invalid JSON body should be stored as is.
+        assert_eq!(requests[1].body.as_deref(), Some(br#"{"a":"#.as_slice()));
+        assert_eq!(requests[1].json_valid, Some(false));
+        assert!(requests[1].json_body.is_none());
+
+        // Requests without body and with too large body aren't captured as JSON.
+        assert!(requests[2].json_valid.is_none());
+        assert!(requests[2].json_body.is_none());
+        assert!(requests[3].json_valid.is_none());
+        assert!(requests[3].json_body.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_forwards_responder_requests(pool: PgPool) -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let target_one_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/one")
+                .header("x-custom", "value")
+                .body("some-body");
+            then.status(200);
+        });
+        let target_two_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/two")
+                .header("x-custom", "value")
+                .body("some-body");
+            then.status(503);
+        });
+
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        // Insert responder directly since local mock server URLs don't pass validation.
+        let forward_to = vec![
+            Url::parse(&server.url("/one"))?,
+            Url::parse(&server.url("/two"))?,
+            // Nothing should be listening on this port.
+            Url::parse("http://127.0.0.1:1/three")?,
+        ];
+        let responder = Responder {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "name_one".to_string(),
+            location: ResponderLocation {
+                path_type: ResponderPathType::Exact,
+                path: "/".to_string(),
+                subdomain_prefix: None,
+                base_path: None,
+            },
+            method: ResponderMethod::Any,
+            enabled: true,
+            settings: ResponderSettings {
+                requests_to_track: 3,
+                status_code: 200,
+                body: None,
+                headers: None,
+                script: None,
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: Some(forward_to.clone()),
+                capture_json: false,
+                initial_response: None,
+            },
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            updated_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        };
+        api.db
+            .webhooks()
+            .insert_responder(mock_user.id, &responder)
+            .await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        let request = webhooks
+            .create_responder_request(
+                responder.id,
+                RespondersRequestCreateParams {
+                    headers: Some(vec![
+                        (
+                            Cow::Borrowed("x-custom"),
+                            Cow::Borrowed(b"value".as_slice()),
+                        ),
+                        (
+                            Cow::Borrowed("host"),
+                            Cow::Borrowed(b"secutils.dev".as_slice()),
+                        ),
+                    ]),
+                    body: Some(Cow::Borrowed(b"some-body")),
+                    ..get_request_create_params("/")
+                },
+            )
+            .await?
+            .unwrap();
+        target_one_mock.assert();
+        target_two_mock.assert();
+
+        let expected_forwarded = Some(vec![
+            ResponderForwardResult {
+                url: forward_to[0].clone(),
+                status: Some(200),
+            },
+            ResponderForwardResult {
+                url: forward_to[1].clone(),
+                status: Some(503),
+            },
+            ResponderForwardResult {
+                url: forward_to[2].clone(),
+                status: None,
+            },
+        ]);
+        assert_eq!(request.forwarded, expected_forwarded);
+
+        let requests = webhooks.get_responder_requests(responder.id).await?;
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].forwarded, expected_forwarded);
+
+        // Requests are forwarded even if the responder doesn't track them.
+        api.db
+            .webhooks()
+            .update_responder(
+                mock_user.id,
+                &Responder {
+                    settings: ResponderSettings {
+                        requests_to_track: 0,
+                        forward_to: Some(forward_to[..1].to_vec()),
+                        ..responder.settings.clone()
+                    },
+                    ..responder.clone()
+                },
+            )
+            .await?;
+        let request = webhooks
+            .create_responder_request(
+                responder.id,
+                RespondersRequestCreateParams {
+                    headers: Some(vec![(
+                        Cow::Borrowed("x-custom"),
+                        Cow::Borrowed(b"value".as_slice()),
+                    )]),
+                    body: Some(Cow::Borrowed(b"some-body")),
+                    ..get_request_create_params("/")
+                },
+            )
+            .await?;
+        assert!(request.is_none());
+        target_one_mock.assert_hits(2);
+        target_two_mock.assert_hits(1);
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn properly_validates_responder_request_at_creation(pool: PgPool) -> anyhow::Result<()> {
         let api = mock_api(pool).await?;
@@ -1586,6 +4047,14 @@ mod tests {
             body: None,
             headers: None,
             script: None,
+            body_file: None,
+            echo_transform: None,
+            latency: None,
+            fault: None,
+            body_template: None,
+            forward_to: None,
+            capture_json: false,
+            initial_response: None,
         };
         let responder = webhooks
             .create_responder(RespondersCreateParams {
@@ -1594,6 +4063,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -1653,6 +4123,14 @@ mod tests {
             body: None,
             headers: None,
             script: None,
+            body_file: None,
+            echo_transform: None,
+            latency: None,
+            fault: None,
+            body_template: None,
+            forward_to: None,
+            capture_json: false,
+            initial_response: None,
         };
         let responder_one = webhooks
             .create_responder(RespondersCreateParams {
@@ -1661,6 +4139,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -1674,6 +4153,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/two".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -1744,6 +4224,14 @@ mod tests {
             body: None,
             headers: None,
             script: None,
+            body_file: None,
+            echo_transform: None,
+            latency: None,
+            fault: None,
+            body_template: None,
+            forward_to: None,
+            capture_json: false,
+            initial_response: None,
         };
         let responder_one = webhooks
             .create_responder(RespondersCreateParams {
@@ -1752,6 +4240,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -1765,6 +4254,7 @@ mod tests {
                     path_type: ResponderPathType::Exact,
                     path: "/two".to_string(),
                     subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: false,
@@ -1806,4 +4296,477 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn properly_creates_and_trims_inbound_emails(pool: PgPool) -> anyhow::Result<()> {
+        let create_params = |subject: &str| InboundEmailCreateParams {
+            from: "sender@example.com".to_string(),
+            to: "devhandle@secutils.dev".to_string(),
+            subject: subject.to_string(),
+            body: "body".to_string(),
+        };
+
+        // Intake is disabled by default.
+        let api = mock_api(pool.clone()).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+        let create_result = api
+            .webhooks(&mock_user)
+            .create_inbound_email(create_params("one"))
+            .await;
+        assert_debug_snapshot!(
+            create_result.unwrap_err().downcast::<SecutilsError>()?.to_string(),
+            @r###""Inbound email intake is disabled.""###
+        );
+
+        let mut config = mock_config()?;
+        config.utils.max_inbound_emails = Some(2);
+        let api = mock_api_with_config(pool, config).await?;
+        let webhooks = api.webhooks(&mock_user);
+
+        let create_result = webhooks
+            .create_inbound_email(InboundEmailCreateParams {
+                from: "".to_string(),
+                ..create_params("one")
+            })
+            .await;
+        assert_debug_snapshot!(
+            create_result.unwrap_err().downcast::<SecutilsError>()?.to_string(),
+            @r###""Inbound email sender and recipient cannot be empty.""###
+        );
+
+        let email_one = webhooks.create_inbound_email(create_params("one")).await?;
+        let email_two = webhooks.create_inbound_email(create_params("two")).await?;
+        assert_eq!(
+            webhooks.get_inbound_emails().await?,
+            vec![email_one, email_two.clone()]
+        );
+
+        // The oldest email should be removed once retention limit is exceeded.
+        let email_three = webhooks
+            .create_inbound_email(create_params("three"))
+            .await?;
+        assert_eq!(
+            webhooks.get_inbound_emails().await?,
+            vec![email_two.clone(), email_three.clone()]
+        );
+
+        webhooks.remove_inbound_email(email_two.id).await?;
+        assert_eq!(webhooks.get_inbound_emails().await?, vec![email_three]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_manages_responder_base_paths(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        let create_base_path = |path: &str| {
+            webhooks.create_responder_base_path(RespondersBasePathCreateParams {
+                path: path.to_string(),
+            })
+        };
+
+        for invalid_path in ["", "/", "mockapi", "/mockapi/", "/mock//api", "/mock api"] {
+            let create_result = create_base_path(invalid_path).await;
+            assert_eq!(
+                create_result.unwrap_err().downcast::<SecutilsError>()?.to_string(),
+                format!("Responder base path ('{invalid_path}') is not valid, it must begin with '/', should not end with '/', and can only contain alphanumeric characters, '-', '_' and '.' in path segments.")
+            );
+        }
+
+        let base_path = create_base_path("/MockAPI/v1").await?;
+        assert_eq!(base_path.path, "/mockapi/v1");
+        assert_eq!(
+            webhooks.get_responder_base_paths().await?,
+            vec![base_path.clone()]
+        );
+
+        let create_result = create_base_path("/mockapi/v1").await;
+        assert_debug_snapshot!(
+            create_result.unwrap_err().downcast::<SecutilsError>()?.to_string(),
+            @r###""Responder base path ('/mockapi/v1') is already reserved.""###
+        );
+
+        let create_params = |path: &str, base_path: Option<&str>| RespondersCreateParams {
+            name: format!("name{}", path.replace('/', "_")),
+            location: ResponderLocation {
+                path_type: ResponderPathType::Exact,
+                path: path.to_string(),
+                subdomain_prefix: None,
+                base_path: base_path.map(|base_path| base_path.to_string()),
+            },
+            method: ResponderMethod::Any,
+            enabled: true,
+            settings: ResponderSettings {
+                requests_to_track: 3,
+                status_code: 200,
+                body: None,
+                headers: None,
+                script: None,
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
+            },
+        };
+
+        // Responders can only be created relative to the reserved base paths.
+        let create_result = webhooks
+            .create_responder(create_params("/users", Some("/mockapi/v2")))
+            .await;
+        assert_debug_snapshot!(
+            create_result.unwrap_err().downcast::<SecutilsError>()?.to_string(),
+            @r###""Responder base path ('/mockapi/v2') is not reserved.""###
+        );
+        let create_result = webhooks
+            .create_responder(create_params("/mockapi/v1/users", None))
+            .await;
+        assert_debug_snapshot!(
+            create_result.unwrap_err().downcast::<SecutilsError>()?.to_string(),
+            @r###""Responder location path ('/mockapi/v1/users') is within the reserved base path ('/mockapi/v1'), responder should be created relative to it.""###
+        );
+
+        let responder = webhooks
+            .create_responder(create_params("/users", Some("/mockapi/v1")))
+            .await?;
+        assert_eq!(responder.location.path, "/users");
+        assert_eq!(responder.location.base_path.as_deref(), Some("/mockapi/v1"));
+        assert_eq!(responder.location.full_path(), "/mockapi/v1/users");
+        assert_eq!(
+            webhooks.get_responder(responder.id).await?,
+            Some(responder.clone())
+        );
+
+        // Base path cannot be reserved if it'd make existing responders unreachable.
+        webhooks
+            .create_responder(create_params("/mockapi/v2/users", None))
+            .await?;
+        let create_result = create_base_path("/mockapi/v2").await;
+        assert_debug_snapshot!(
+            create_result.unwrap_err().downcast::<SecutilsError>()?.to_string(),
+            @r###""Responder base path ('/mockapi/v2') conflicts with the responder ('name_mockapi_v2_users') location.""###
+        );
+
+        // Base path cannot be removed while it's used by responders.
+        let remove_result = webhooks.remove_responder_base_path(base_path.id).await;
+        assert_debug_snapshot!(
+            remove_result.unwrap_err().downcast::<SecutilsError>()?.to_string(),
+            @r###""Responder base path is used by responders and cannot be removed.""###
+        );
+
+        webhooks.remove_responder(responder.id).await?;
+        webhooks.remove_responder_base_path(base_path.id).await?;
+        assert!(webhooks.get_responder_base_paths().await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_resolves_responders_within_base_paths(pool: PgPool) -> anyhow::Result<()> {
+        let api = mock_api(pool).await?;
+        let mock_user = mock_user()?;
+        api.db.insert_user(&mock_user).await?;
+
+        let webhooks = api.webhooks(&mock_user);
+        for path in ["/mockapi", "/mockapi/v1"] {
+            webhooks
+                .create_responder_base_path(RespondersBasePathCreateParams {
+                    path: path.to_string(),
+                })
+                .await?;
+        }
+
+        let create_params = |path: &str, base_path: Option<&str>| RespondersCreateParams {
+            name: format!("name_{}{path}", base_path.unwrap_or_default()),
+            location: ResponderLocation {
+                path_type: ResponderPathType::Exact,
+                path: path.to_string(),
+                subdomain_prefix: None,
+                base_path: base_path.map(|base_path| base_path.to_string()),
+            },
+            method: ResponderMethod::Any,
+            enabled: true,
+            settings: ResponderSettings {
+                requests_to_track: 3,
+                status_code: 200,
+                body: None,
+                headers: None,
+                script: None,
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
+            },
+        };
+        let responder_users = webhooks
+            .create_responder(create_params("/users", None))
+            .await?;
+        let responder_mockapi_users = webhooks
+            .create_responder(create_params("/users", Some("/mockapi")))
+            .await?;
+        let responder_mockapi_v1_users = webhooks
+            .create_responder(create_params("/users", Some("/mockapi/v1")))
+            .await?;
+        let responder_mockapi_v1_root = webhooks
+            .create_responder(create_params("/", Some("/mockapi/v1")))
+            .await?;
+
+        let webhooks_system = api.webhooks_system();
+        let resolve = |path: &'static str| {
+            let webhooks_system = &webhooks_system;
+            let user_handle = mock_user.handle.clone();
+            async move {
+                webhooks_system
+                    .resolve_responder(
+                        "secutils.dev",
+                        Some(&user_handle),
+                        path,
+                        ResponderMethod::Get,
+                    )
+                    .await
+                    .map(|responder_match| {
+                        responder_match.map(|responder_match| responder_match.responder.id)
+                    })
+            }
+        };
+
+        assert_eq!(resolve("/users").await?, Some(responder_users.id));
+        assert_eq!(
+            resolve("/mockapi/users").await?,
+            Some(responder_mockapi_users.id)
+        );
+        assert_eq!(
+            resolve("/mockapi/v1/users").await?,
+            Some(responder_mockapi_v1_users.id)
+        );
+        assert_eq!(
+            resolve("/mockapi/v1").await?,
+            Some(responder_mockapi_v1_root.id)
+        );
+        assert_eq!(resolve("/mockapi/v10/users").await?, None);
+        assert_eq!(resolve("/mockapi/v1/groups").await?, None);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn properly_resolves_responders(pool: PgPool) -> anyhow::Result<()> {
+        let user_one = mock_user()?;
+        let user_two = mock_user_with_id(uuid!("00000000-0000-0000-0000-000000000002"))?;
+
+        let mut config = mock_config()?;
+        config.utils.webhook_custom_domains = [
+            ("hooks.example.com".to_string(), user_two.handle.clone()),
+            // Custom domain that looks like a subdomain of the first user.
+            (
+                format!("xyz-{}.webhooks.secutils.dev", user_one.handle),
+                user_two.handle.clone(),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let api = mock_api_with_config(pool, config).await?;
+        api.db.insert_user(&user_one).await?;
+        api.db.insert_user(&user_two).await?;
+
+        let create_params = |subdomain_prefix: Option<&str>| RespondersCreateParams {
+            name: format!("name_{}", subdomain_prefix.unwrap_or_default()),
+            location: ResponderLocation {
+                path_type: ResponderPathType::Exact,
+                path: "/one".to_string(),
+                subdomain_prefix: subdomain_prefix.map(|prefix| prefix.to_string()),
+                base_path: None,
+            },
+            method: ResponderMethod::Any,
+            enabled: true,
+            settings: ResponderSettings {
+                requests_to_track: 3,
+                status_code: 200,
+                body: None,
+                headers: None,
+                script: None,
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
+            },
+        };
+        let responder_one = api
+            .webhooks(&user_one)
+            .create_responder(create_params(None))
+            .await?;
+        let responder_one_prefixed = api
+            .webhooks(&user_one)
+            .create_responder(create_params(Some("abc")))
+            .await?;
+        let responder_two = api
+            .webhooks(&user_two)
+            .create_responder(create_params(None))
+            .await?;
+
+        let webhooks_system = api.webhooks_system();
+        let resolve = |host: String, user_handle: Option<String>, path: &'static str| {
+            let webhooks_system = &webhooks_system;
+            async move {
+                webhooks_system
+                    .resolve_responder(&host, user_handle.as_deref(), path, ResponderMethod::Get)
+                    .await
+                    .map(|responder_match| {
+                        responder_match.map(|responder_match| {
+                            (
+                                responder_match.user.id,
+                                responder_match.responder.id,
+                                responder_match.scheme,
+                            )
+                        })
+                    })
+            }
+        };
+
+        // Path scheme.
+        assert_eq!(
+            resolve(
+                "secutils.dev".to_string(),
+                Some(user_one.handle.clone()),
+                "/one"
+            )
+            .await?,
+            Some((user_one.id, responder_one.id, ResponderMatchScheme::Path))
+        );
+
+        // Subdomain scheme.
+        assert_eq!(
+            resolve(
+                format!("{}.webhooks.secutils.dev", user_one.handle),
+                None,
+                "/one"
+            )
+            .await?,
+            Some((
+                user_one.id,
+                responder_one.id,
+                ResponderMatchScheme::Subdomain
+            ))
+        );
+        assert_eq!(
+            resolve(
+                format!("abc-{}.webhooks.secutils.dev:443", user_one.handle),
+                None,
+                "/one"
+            )
+            .await?,
+            Some((
+                user_one.id,
+                responder_one_prefixed.id,
+                ResponderMatchScheme::Subdomain
+            ))
+        );
+
+        // Custom domain scheme.
+        assert_eq!(
+            resolve("Hooks.Example.com".to_string(), None, "/one").await?,
+            Some((
+                user_two.id,
+                responder_two.id,
+                ResponderMatchScheme::CustomDomain
+            ))
+        );
+
+        // User handle in the path takes precedence over the host.
+        assert_eq!(
+            resolve(
+                "hooks.example.com".to_string(),
+                Some(user_one.handle.clone()),
+                "/one"
+            )
+            .await?,
+            Some((user_one.id, responder_one.id, ResponderMatchScheme::Path))
+        );
+
+        // Custom domain takes precedence over the subdomain.
+        assert_eq!(
+            resolve(
+                format!("xyz-{}.webhooks.secutils.dev", user_one.handle),
+                None,
+                "/one"
+            )
+            .await?,
+            Some((
+                user_two.id,
+                responder_two.id,
+                ResponderMatchScheme::CustomDomain
+            ))
+        );
+
+        // No matches.
+        assert!(resolve("unknown.example.com".to_string(), None, "/one")
+            .await?
+            .is_none());
+        assert!(resolve(
+            "devhandle00000000000000000000000000000003.webhooks.secutils.dev".to_string(),
+            None,
+            "/one"
+        )
+        .await?
+        .is_none());
+        assert!(resolve(
+            format!("cba-{}.webhooks.secutils.dev", user_one.handle),
+            None,
+            "/one"
+        )
+        .await?
+        .is_none());
+        assert!(resolve(
+            "secutils.dev".to_string(),
+            Some(user_one.handle.clone()),
+            "/two"
+        )
+        .await?
+        .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_parse_webhook_hosts() -> anyhow::Result<()> {
+        let test_cases = [
+            ("a-handle.webhooks.secutils.dev", ("handle", Some("a"))),
+            (
+                "my-sub-handle.webhooks.secutils.dev",
+                ("handle", Some("my-sub")),
+            ),
+            ("abc-handle.webhooks.secutils.dev", ("handle", Some("abc"))),
+            (
+                "a1-b-d-com-handle.webhooks.secutils.dev",
+                ("handle", Some("a1-b-d-com")),
+            ),
+            ("handle.webhooks.secutils.dev", ("handle", None)),
+        ];
+
+        let config = mock_config()?;
+        for (webhook_host, expected_result) in test_cases {
+            assert_eq!(parse_webhook_host(&config, webhook_host)?, expected_result);
+        }
+
+        assert!(parse_webhook_host(&config, "handle.secutils.dev").is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,102 @@
+use lettre::message::Mailbox;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InboundEmailCreateParams {
+    /// Address of the email sender.
+    pub from: String,
+    /// Address of the email recipient, e.g. `handle+tag@inbox.secutils.dev`.
+    pub to: String,
+    /// Subject of the email.
+    #[serde(default)]
+    pub subject: String,
+    /// Body (plain text) of the email.
+    #[serde(default)]
+    pub body: String,
+}
+
+impl InboundEmailCreateParams {
+    /// Returns the handle of the user the email is addressed to. The handle is the local part of
+    /// the recipient address without optional sub-address (`+tag`).
+    pub fn recipient_handle(&self) -> Option<String> {
+        let mailbox = self.to.parse::<Mailbox>().ok()?;
+        let handle = mailbox
+            .email
+            .user()
+            .split_once('+')
+            .map_or(mailbox.email.user(), |(handle, _)| handle);
+        (!handle.is_empty()).then(|| handle.to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::webhooks::api_ext::InboundEmailCreateParams;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<InboundEmailCreateParams>(
+                r#"
+{
+    "from": "sender@secutils.dev",
+    "to": "handle@inbox.secutils.dev",
+    "subject": "Welcome",
+    "body": "Hello, World!"
+}
+          "#
+            )?,
+            InboundEmailCreateParams {
+                from: "sender@secutils.dev".to_string(),
+                to: "handle@inbox.secutils.dev".to_string(),
+                subject: "Welcome".to_string(),
+                body: "Hello, World!".to_string(),
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<InboundEmailCreateParams>(
+                r#"
+{
+    "from": "sender@secutils.dev",
+    "to": "handle@inbox.secutils.dev"
+}
+          "#
+            )?,
+            InboundEmailCreateParams {
+                from: "sender@secutils.dev".to_string(),
+                to: "handle@inbox.secutils.dev".to_string(),
+                subject: "".to_string(),
+                body: "".to_string(),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn extracts_recipient_handle() {
+        let params = |to: &str| InboundEmailCreateParams {
+            from: "sender@secutils.dev".to_string(),
+            to: to.to_string(),
+            subject: "".to_string(),
+            body: "".to_string(),
+        };
+
+        assert_eq!(
+            params("handle@inbox.secutils.dev").recipient_handle(),
+            Some("handle".to_string())
+        );
+        assert_eq!(
+            params("handle+signup-test@inbox.secutils.dev").recipient_handle(),
+            Some("handle".to_string())
+        );
+        assert_eq!(
+            params("Test User <HANDLE+tag@inbox.secutils.dev>").recipient_handle(),
+            Some("handle".to_string())
+        );
+        assert_eq!(params("+tag@inbox.secutils.dev").recipient_handle(), None);
+        assert_eq!(params("inbox.secutils.dev").recipient_handle(), None);
+    }
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RespondersBasePathCreateParams {
+    /// Base path to reserve, e.g. `/mockapi/v1`.
+    pub path: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::webhooks::api_ext::RespondersBasePathCreateParams;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<RespondersBasePathCreateParams>(r#"{ "path": "/mockapi/v1" }"#)?,
+            RespondersBasePathCreateParams {
+                path: "/mockapi/v1".to_string(),
+            }
+        );
+
+        Ok(())
+    }
+}
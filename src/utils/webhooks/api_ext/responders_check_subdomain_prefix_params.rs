@@ -0,0 +1,27 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RespondersCheckSubdomainPrefixParams {
+    /// Subdomain prefix to check availability for.
+    pub subdomain_prefix: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::webhooks::api_ext::RespondersCheckSubdomainPrefixParams;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<RespondersCheckSubdomainPrefixParams>(
+                r#"{ "subdomainPrefix": "my-app" }"#
+            )?,
+            RespondersCheckSubdomainPrefixParams {
+                subdomain_prefix: "my-app".to_string(),
+            }
+        );
+
+        Ok(())
+    }
+}
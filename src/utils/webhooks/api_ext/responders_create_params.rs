@@ -50,7 +50,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: true,
@@ -63,6 +64,14 @@ mod tests {
                         ("key2".to_string(), "value2".to_string())
                     ]),
                     script: Some("return { body: `custom body` };".to_string()),
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 }
             }
         );
@@ -90,7 +99,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Prefix,
                     path: "/path".to_string(),
-                    subdomain_prefix: Some("sub".to_string())
+                    subdomain_prefix: Some("sub".to_string()),
+                    base_path: None,
                 },
                 method: ResponderMethod::Get,
                 enabled: false,
@@ -100,6 +110,14 @@ mod tests {
                     body: None,
                     headers: None,
                     script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 }
             }
         );
@@ -0,0 +1,38 @@
+use crate::utils::webhooks::ResponderUrlType;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RespondersMigrateUrlTypeParams {
+    /// URL type the responder should be migrated to.
+    pub url_type: ResponderUrlType,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::webhooks::{api_ext::RespondersMigrateUrlTypeParams, ResponderUrlType};
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<RespondersMigrateUrlTypeParams>(
+                r#"{ "urlType": { "type": "subdomain", "subdomainPrefix": "my-app" } }"#
+            )?,
+            RespondersMigrateUrlTypeParams {
+                url_type: ResponderUrlType::Subdomain {
+                    subdomain_prefix: Some("my-app".to_string())
+                },
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<RespondersMigrateUrlTypeParams>(
+                r#"{ "urlType": { "type": "path" } }"#
+            )?,
+            RespondersMigrateUrlTypeParams {
+                url_type: ResponderUrlType::Path,
+            }
+        );
+
+        Ok(())
+    }
+}
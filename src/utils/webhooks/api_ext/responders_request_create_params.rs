@@ -13,4 +13,6 @@ pub struct RespondersRequestCreateParams<'a> {
     pub url: Cow<'a, str>,
     /// HTTP body of the request.
     pub body: Option<Cow<'a, [u8]>>,
+    /// Correlation ID of the request that is returned to the client with the response.
+    pub correlation_id: Option<Cow<'a, str>>,
 }
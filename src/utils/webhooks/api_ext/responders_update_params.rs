@@ -50,7 +50,8 @@ mod tests {
                 location: Some(ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 }),
                 method: Some(ResponderMethod::Get),
                 enabled: Some(true),
@@ -63,6 +64,14 @@ mod tests {
                         ("key2".to_string(), "value2".to_string())
                     ]),
                     script: Some("return { body: `custom body` };".to_string()),
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 })
             }
         );
@@ -93,7 +102,8 @@ mod tests {
                 location: Some(ResponderLocation {
                     path_type: ResponderPathType::Prefix,
                     path: "/path".to_string(),
-                    subdomain_prefix: Some("sub".to_string())
+                    subdomain_prefix: Some("sub".to_string()),
+                    base_path: None,
                 }),
                 method: Some(ResponderMethod::Get),
                 enabled: None,
@@ -106,6 +116,14 @@ mod tests {
                         ("key2".to_string(), "value2".to_string())
                     ]),
                     script: Some("return { body: `custom body` };".to_string()),
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 })
             }
         );
@@ -134,7 +152,8 @@ mod tests {
                 location: Some(ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 }),
                 method: Some(ResponderMethod::Post),
                 enabled: None,
@@ -147,6 +166,14 @@ mod tests {
                         ("key2".to_string(), "value2".to_string())
                     ]),
                     script: Some("return { body: `custom body` };".to_string()),
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 })
             }
         );
@@ -180,6 +207,14 @@ mod tests {
                         ("key2".to_string(), "value2".to_string())
                     ]),
                     script: Some("return { body: `custom body` };".to_string()),
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 })
             }
         );
@@ -212,6 +247,14 @@ mod tests {
                         ("key2".to_string(), "value2".to_string())
                     ]),
                     script: Some("return { body: `custom body` };".to_string()),
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 })
             }
         );
@@ -236,7 +279,15 @@ mod tests {
                     status_code: 302,
                     body: None,
                     headers: None,
-                    script: None
+                    script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 })
             }
         );
@@ -3,17 +3,17 @@ mod raw_responder_request;
 
 use crate::{
     database::Database,
-    error::Error as SecutilsError,
+    error::{Error as SecutilsError, ErrorCode},
     users::UserId,
     utils::webhooks::{
-        Responder, ResponderLocation, ResponderMethod, ResponderPathType, ResponderRequest,
-        ResponderStats,
+        InboundEmail, Responder, ResponderBasePath, ResponderLocation, ResponderMethod,
+        ResponderPathType, ResponderRequest, ResponderStats,
     },
 };
 use anyhow::{anyhow, bail};
 use raw_responder::RawResponder;
 use raw_responder_request::RawResponderRequest;
-use sqlx::{query, query_as, Pool, Postgres};
+use sqlx::{query, query_as, query_scalar, Pool, Postgres};
 use uuid::Uuid;
 
 /// A database extension for the webhooks utility-related operations.
@@ -31,7 +31,7 @@ impl<'pool> WebhooksDatabaseExt<'pool> {
         let raw_responders = query_as!(
             RawResponder,
             r#"
-SELECT id, name, location, method, enabled, settings, created_at, updated_at
+SELECT id, name, location, base_path, method, enabled, settings, created_at, updated_at
 FROM user_data_webhooks_responders
 WHERE user_id = $1
 ORDER BY updated_at
@@ -88,7 +88,7 @@ ORDER BY r.updated_at
         query_as!(
             RawResponder,
             r#"
-        SELECT id, name, location, method, enabled, settings, created_at, updated_at
+        SELECT id, name, location, base_path, method, enabled, settings, created_at, updated_at
         FROM user_data_webhooks_responders
         WHERE user_id = $1 AND id = $2
                         "#,
@@ -101,11 +101,13 @@ ORDER BY r.updated_at
         .transpose()
     }
 
-    /// Retrieves responder for the specified subdomain prefix, path and method.
+    /// Retrieves responder for the specified subdomain prefix, base path, path and method. The
+    /// path is the full request path, including the base path, if any.
     pub async fn find_responder(
         &self,
         user_id: UserId,
         subdomain_prefix: Option<&str>,
+        base_path: Option<&str>,
         path: &str,
         method: ResponderMethod,
     ) -> anyhow::Result<Option<Responder>> {
@@ -116,12 +118,14 @@ ORDER BY r.updated_at
             path_type: ResponderPathType::Exact,
             path: path.to_string(),
             subdomain_prefix: subdomain_prefix.map(|s| s.to_string()),
+            base_path: None,
         }
         .to_string();
         let raw_location_prefix = ResponderLocation {
             path_type: ResponderPathType::Prefix,
             path: path.to_string(),
             subdomain_prefix: subdomain_prefix.map(|s| s.to_string()),
+            base_path: None,
         }
         .to_string();
 
@@ -131,9 +135,9 @@ ORDER BY r.updated_at
         query_as!(
             RawResponder,
             r#"
-        SELECT id, name, location, method, enabled, settings, created_at, updated_at
+        SELECT id, name, location, base_path, method, enabled, settings, created_at, updated_at
         FROM user_data_webhooks_responders
-        WHERE user_id = $1 AND (location = $2 OR starts_with($3, location COLLATE "und-x-icu")) AND (method = $4 OR method = $5)
+        WHERE user_id = $1 AND (location = $2 OR starts_with($3, location COLLATE "und-x-icu")) AND (method = $4 OR method = $5) AND base_path IS NOT DISTINCT FROM $6
         ORDER BY length(location) DESC, location DESC
         LIMIT 1
                         "#,
@@ -141,7 +145,8 @@ ORDER BY r.updated_at
             raw_location_exact,
             raw_location_prefix,
             raw_method,
-            raw_any_method
+            raw_any_method,
+            base_path
         )
             .fetch_optional(self.pool)
             .await?
@@ -149,6 +154,24 @@ ORDER BY r.updated_at
             .transpose()
     }
 
+    /// Retrieves distinct subdomain prefixes of the responders of all users that start with the
+    /// specified string.
+    pub async fn get_responders_subdomain_prefixes(
+        &self,
+        subdomain_prefix: &str,
+    ) -> anyhow::Result<Vec<String>> {
+        Ok(query_scalar!(
+            r#"
+        SELECT DISTINCT split_part(location, ':', 1) AS "subdomain_prefix!"
+        FROM user_data_webhooks_responders
+        WHERE starts_with(location, $1)
+                        "#,
+            subdomain_prefix
+        )
+        .fetch_all(self.pool)
+        .await?)
+    }
+
     /// Inserts responder.
     pub async fn insert_responder(
         &self,
@@ -162,10 +185,10 @@ ORDER BY r.updated_at
         // responder that already covers the same location and method.
         let result = query!(
                 r#"
-        WITH new_responder(user_id, id, name, location, method, enabled, settings, created_at, updated_at) AS (
-            VALUES ( $1::uuid, $2::uuid, $3, $4, $5::bytea, $6::bool, $7::bytea, $8::timestamptz, $9::timestamptz )
+        WITH new_responder(user_id, id, name, location, method, enabled, settings, created_at, updated_at, base_path) AS (
+            VALUES ( $1::uuid, $2::uuid, $3, $4, $5::bytea, $6::bool, $7::bytea, $8::timestamptz, $9::timestamptz, $11::text )
         )
-        INSERT INTO user_data_webhooks_responders (user_id, id, name, location, method, enabled, settings, created_at, updated_at)
+        INSERT INTO user_data_webhooks_responders (user_id, id, name, location, method, enabled, settings, created_at, updated_at, base_path)
         SELECT * FROM new_responder
         WHERE NOT EXISTS(
             SELECT id FROM user_data_webhooks_responders 
@@ -181,7 +204,8 @@ ORDER BY r.updated_at
                 raw_responder.settings,
                 raw_responder.created_at,
                 raw_responder.updated_at,
-                raw_any_method
+                raw_any_method,
+                raw_responder.base_path
             )
             .execute(self.pool)
             .await;
@@ -192,7 +216,8 @@ ORDER BY r.updated_at
                 bail!(SecutilsError::client(format!(
                     "Responder with such location ('{:?}') and method ('{:?}') conflicts with another responder.",
                     &responder.location, responder.method
-                )))
+                ))
+                .with_code(ErrorCode::AlreadyExists))
             }
             Err(err) => match err.as_database_error() {
                 Some(database_error) if database_error.is_unique_violation() => {
@@ -206,7 +231,8 @@ ORDER BY r.updated_at
                     };
                     bail!(SecutilsError::client_with_root_cause(
                         anyhow!(err).context(error_message)
-                    ))
+                    )
+                    .with_code(ErrorCode::AlreadyExists))
                 }
                 _ => bail!(SecutilsError::from(anyhow!(err).context(format!(
                     "Couldn't create responder ('{}') due to unknown reason.",
@@ -229,7 +255,7 @@ ORDER BY r.updated_at
         let result = query!(
             r#"
     UPDATE user_data_webhooks_responders
-    SET name = $3, location = $4, method = $5, enabled = $6, settings = $7, updated_at = $8
+    SET name = $3, location = $4, method = $5, enabled = $6, settings = $7, updated_at = $8, base_path = $10
     WHERE user_id = $1 AND id = $2 AND NOT EXISTS(
         SELECT id FROM user_data_webhooks_responders 
         WHERE user_id = $1 AND id != $2 AND location = $4 AND (method = $9 OR method = $5 OR $5 = $9)
@@ -243,7 +269,8 @@ ORDER BY r.updated_at
             raw_responder.enabled,
             raw_responder.settings,
             raw_responder.updated_at,
-            raw_any_method
+            raw_any_method,
+            raw_responder.base_path
         )
             .execute(self.pool)
             .await;
@@ -254,7 +281,8 @@ ORDER BY r.updated_at
                 bail!(SecutilsError::client(format!(
                     "Responder with such location ('{:?}') and method ('{:?}') doesn't exist or conflicts with another responder.",
                     &responder.location, responder.method
-                )))
+                ))
+                .with_code(ErrorCode::AlreadyExists))
             }
             Err(err) => match err.as_database_error() {
                 Some(database_error) if database_error.is_unique_violation() => {
@@ -268,7 +296,8 @@ ORDER BY r.updated_at
                     };
                     bail!(SecutilsError::client_with_root_cause(
                         anyhow!(err).context(error_message)
-                    ))
+                    )
+                    .with_code(ErrorCode::AlreadyExists))
                 }
                 _ => bail!(SecutilsError::from(anyhow!(err).context(format!(
                     "Couldn't update responder ('{}') due to unknown reason.",
@@ -394,11 +423,238 @@ ORDER BY r.updated_at
 
         Ok(())
     }
+
+    /// Increments the number of requests received by the specified responder and returns the
+    /// updated number.
+    pub async fn increment_responder_requests_counter(
+        &self,
+        user_id: UserId,
+        responder_id: Uuid,
+    ) -> anyhow::Result<u64> {
+        let requests = query_scalar!(
+            r#"
+        INSERT INTO user_data_webhooks_responders_counters (responder_id, user_id, requests)
+        VALUES ( $1, $2, 1 )
+        ON CONFLICT (responder_id) DO UPDATE SET requests = user_data_webhooks_responders_counters.requests + 1
+        RETURNING requests
+                "#,
+            responder_id,
+            *user_id
+        )
+        .fetch_one(self.pool)
+        .await?;
+
+        Ok(requests as u64)
+    }
+
+    /// Resets the number of requests received by the specified responder.
+    pub async fn reset_responder_requests_counter(
+        &self,
+        user_id: UserId,
+        responder_id: Uuid,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+        DELETE FROM user_data_webhooks_responders_counters
+        WHERE user_id = $1 AND responder_id = $2
+                        "#,
+            *user_id,
+            responder_id
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves all responder base paths reserved by the specified user.
+    pub async fn get_responder_base_paths(
+        &self,
+        user_id: UserId,
+    ) -> anyhow::Result<Vec<ResponderBasePath>> {
+        Ok(query_as!(
+            ResponderBasePath,
+            r#"
+    SELECT id, path, created_at
+    FROM user_data_webhooks_responders_base_paths
+    WHERE user_id = $1
+    ORDER BY path
+                    "#,
+            *user_id
+        )
+        .fetch_all(self.pool)
+        .await?)
+    }
+
+    /// Inserts responder base path for the specified user.
+    pub async fn insert_responder_base_path(
+        &self,
+        user_id: UserId,
+        base_path: &ResponderBasePath,
+    ) -> anyhow::Result<()> {
+        let result = query!(
+            r#"
+    INSERT INTO user_data_webhooks_responders_base_paths (user_id, id, path, created_at)
+    VALUES ( $1, $2, $3, $4 )
+            "#,
+            *user_id,
+            base_path.id,
+            base_path.path,
+            base_path.created_at
+        )
+        .execute(self.pool)
+        .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => match err.as_database_error() {
+                Some(database_error) if database_error.is_unique_violation() => {
+                    bail!(
+                        SecutilsError::client_with_root_cause(anyhow!(err).context(format!(
+                            "Responder base path ('{}') is already reserved.",
+                            base_path.path
+                        )))
+                        .with_code(ErrorCode::AlreadyExists)
+                    )
+                }
+                _ => bail!(SecutilsError::from(anyhow!(err).context(format!(
+                    "Couldn't reserve responder base path ('{}') due to unknown reason.",
+                    base_path.path
+                )))),
+            },
+        }
+    }
+
+    /// Removes responder base path for the specified user. Base path cannot be removed while it's
+    /// used by any responder.
+    pub async fn remove_responder_base_path(
+        &self,
+        user_id: UserId,
+        id: Uuid,
+    ) -> anyhow::Result<()> {
+        // Responders are removed together with the base path they use, so make sure it's not used.
+        let is_used = query!(
+            r#"
+    SELECT EXISTS(
+        SELECT 1 FROM user_data_webhooks_responders AS responders
+        INNER JOIN user_data_webhooks_responders_base_paths AS base_paths
+        ON responders.user_id = base_paths.user_id AND responders.base_path = base_paths.path
+        WHERE base_paths.user_id = $1 AND base_paths.id = $2
+    ) AS "is_used!"
+                    "#,
+            *user_id,
+            id
+        )
+        .fetch_one(self.pool)
+        .await?
+        .is_used;
+        if is_used {
+            bail!(SecutilsError::client(
+                "Responder base path is used by responders and cannot be removed."
+            ));
+        }
+
+        query!(
+            r#"
+    DELETE FROM user_data_webhooks_responders_base_paths
+    WHERE user_id = $1 AND id = $2
+                    "#,
+            *user_id,
+            id
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves all inbound emails received by the specified user, oldest first.
+    pub async fn get_inbound_emails(&self, user_id: UserId) -> anyhow::Result<Vec<InboundEmail>> {
+        Ok(query_as!(
+            InboundEmail,
+            r#"
+    SELECT id, sender AS "from", recipient AS "to", subject, body, received_at
+    FROM user_data_webhooks_inbound_emails
+    WHERE user_id = $1
+    ORDER BY received_at, id
+                    "#,
+            *user_id
+        )
+        .fetch_all(self.pool)
+        .await?)
+    }
+
+    /// Inserts inbound email for the specified user.
+    pub async fn insert_inbound_email(
+        &self,
+        user_id: UserId,
+        email: &InboundEmail,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+    INSERT INTO user_data_webhooks_inbound_emails (user_id, id, sender, recipient, subject, body, received_at)
+    VALUES ( $1, $2, $3, $4, $5, $6, $7 )
+            "#,
+            *user_id,
+            email.id,
+            email.from,
+            email.to,
+            email.subject,
+            email.body,
+            email.received_at
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Removes inbound email for the specified user.
+    pub async fn remove_inbound_email(&self, user_id: UserId, id: Uuid) -> anyhow::Result<()> {
+        query!(
+            r#"
+    DELETE FROM user_data_webhooks_inbound_emails
+    WHERE user_id = $1 AND id = $2
+                    "#,
+            *user_id,
+            id
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Removes the oldest inbound emails of the specified user, so that only the specified number
+    /// of the most recent emails is retained.
+    pub async fn trim_inbound_emails(
+        &self,
+        user_id: UserId,
+        max_emails: usize,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+    DELETE FROM user_data_webhooks_inbound_emails
+    WHERE user_id = $1 AND id NOT IN (
+        SELECT id FROM user_data_webhooks_inbound_emails
+        WHERE user_id = $1
+        ORDER BY received_at DESC, id DESC
+        LIMIT $2
+    )
+                    "#,
+            *user_id,
+            max_emails as i64
+        )
+        .execute(self.pool)
+        .await?;
+
+        Ok(())
+    }
 }
 
 impl Database {
     /// Returns a database extension for the webhooks utility-related operations.
-    pub fn webhooks(&self) -> WebhooksDatabaseExt {
+    pub fn webhooks(&self) -> WebhooksDatabaseExt<'_> {
         WebhooksDatabaseExt::new(&self.pool)
     }
 }
@@ -433,6 +689,10 @@ mod tests {
             url: Cow::Borrowed("/some-path?query=value"),
             body: Some(Cow::Owned(vec![4, 5, 6])),
             created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            correlation_id: None,
+            forwarded: None,
+            json_valid: None,
+            json_body: None,
         })
     }
 
@@ -896,7 +1156,7 @@ mod tests {
         let db = Database::create(pool).await?;
         db.insert_user(&user).await?;
 
-        let responders = vec![
+        let responders = [
             MockResponderBuilder::create(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 "some-name",
@@ -930,21 +1190,23 @@ mod tests {
             ResponderMethod::Trace,
         ] {
             assert_eq!(
-                webhooks.find_responder(user.id, None, "/", method).await?,
+                webhooks
+                    .find_responder(user.id, None, None, "/", method)
+                    .await?,
                 Some(responders[0].clone())
             );
 
             if matches!(method, ResponderMethod::Post) {
                 assert_eq!(
                     webhooks
-                        .find_responder(user.id, None, "/path", method)
+                        .find_responder(user.id, None, None, "/path", method)
                         .await?,
                     Some(responders[1].clone())
                 );
             } else {
                 assert_eq!(
                     webhooks
-                        .find_responder(user.id, None, "/path", method)
+                        .find_responder(user.id, None, None, "/path", method)
                         .await?,
                     None
                 );
@@ -960,7 +1222,7 @@ mod tests {
         let db = Database::create(pool).await?;
         db.insert_user(&user).await?;
 
-        let responders = vec![
+        let responders = [
             MockResponderBuilder::create(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 "some-name",
@@ -970,6 +1232,7 @@ mod tests {
                 path_type: ResponderPathType::Prefix,
                 path: "/a/b".to_string(),
                 subdomain_prefix: Some("sub".to_string()),
+                base_path: None,
             })
             .build(),
             MockResponderBuilder::create(
@@ -981,6 +1244,7 @@ mod tests {
                 path_type: ResponderPathType::Prefix,
                 path: "/a/b/c".to_string(),
                 subdomain_prefix: Some("sub".to_string()),
+                base_path: None,
             })
             .build(),
             MockResponderBuilder::create(
@@ -992,6 +1256,7 @@ mod tests {
                 path_type: ResponderPathType::Prefix,
                 path: "/a".to_string(),
                 subdomain_prefix: Some("sub".to_string()),
+                base_path: None,
             })
             .build(),
             MockResponderBuilder::create(
@@ -1003,6 +1268,7 @@ mod tests {
                 path_type: ResponderPathType::Exact,
                 path: "/a/b/c/d".to_string(),
                 subdomain_prefix: Some("sub".to_string()),
+                base_path: None,
             })
             .build(),
         ];
@@ -1014,42 +1280,48 @@ mod tests {
 
         assert_eq!(
             webhooks
-                .find_responder(user.id, Some("sub"), "/", ResponderMethod::Get)
+                .find_responder(user.id, Some("sub"), None, "/", ResponderMethod::Get)
                 .await?,
             None
         );
 
         assert_eq!(
             webhooks
-                .find_responder(user.id, Some("sub"), "/a", ResponderMethod::Get)
+                .find_responder(user.id, Some("sub"), None, "/a", ResponderMethod::Get)
                 .await?,
             Some(responders[2].clone())
         );
 
         assert_eq!(
             webhooks
-                .find_responder(user.id, Some("sub"), "/a/b", ResponderMethod::Get)
+                .find_responder(user.id, Some("sub"), None, "/a/b", ResponderMethod::Get)
                 .await?,
             Some(responders[0].clone())
         );
 
         assert_eq!(
             webhooks
-                .find_responder(user.id, Some("sub"), "/a/b/c", ResponderMethod::Get)
+                .find_responder(user.id, Some("sub"), None, "/a/b/c", ResponderMethod::Get)
                 .await?,
             Some(responders[1].clone())
         );
 
         assert_eq!(
             webhooks
-                .find_responder(user.id, Some("sub"), "/a/b/c/d", ResponderMethod::Get)
+                .find_responder(user.id, Some("sub"), None, "/a/b/c/d", ResponderMethod::Get)
                 .await?,
             Some(responders[3].clone())
         );
 
         assert_eq!(
             webhooks
-                .find_responder(user.id, Some("sub"), "/a/b/c/d/e", ResponderMethod::Get)
+                .find_responder(
+                    user.id,
+                    Some("sub"),
+                    None,
+                    "/a/b/c/d/e",
+                    ResponderMethod::Get
+                )
                 .await?,
             Some(responders[1].clone())
         );
@@ -1063,7 +1335,7 @@ mod tests {
         let db = Database::create(pool).await?;
         db.insert_user(&user).await?;
 
-        let responders = vec![
+        let responders = [
             MockResponderBuilder::create(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 "some-name",
@@ -1073,6 +1345,7 @@ mod tests {
                 path_type: ResponderPathType::Exact,
                 path: "/a/b/c/d".to_string(),
                 subdomain_prefix: Some("sub".to_string()),
+                base_path: None,
             })
             .build(),
             MockResponderBuilder::create(
@@ -1084,6 +1357,7 @@ mod tests {
                 path_type: ResponderPathType::Prefix,
                 path: "/".to_string(),
                 subdomain_prefix: Some("sub".to_string()),
+                base_path: None,
             })
             .build(),
         ];
@@ -1095,42 +1369,48 @@ mod tests {
 
         assert_eq!(
             webhooks
-                .find_responder(user.id, Some("sub"), "/", ResponderMethod::Get)
+                .find_responder(user.id, Some("sub"), None, "/", ResponderMethod::Get)
                 .await?,
             Some(responders[1].clone())
         );
 
         assert_eq!(
             webhooks
-                .find_responder(user.id, Some("sub"), "/a", ResponderMethod::Get)
+                .find_responder(user.id, Some("sub"), None, "/a", ResponderMethod::Get)
                 .await?,
             Some(responders[1].clone())
         );
 
         assert_eq!(
             webhooks
-                .find_responder(user.id, Some("sub"), "/a/b", ResponderMethod::Get)
+                .find_responder(user.id, Some("sub"), None, "/a/b", ResponderMethod::Get)
                 .await?,
             Some(responders[1].clone())
         );
 
         assert_eq!(
             webhooks
-                .find_responder(user.id, Some("sub"), "/a/b/c", ResponderMethod::Get)
+                .find_responder(user.id, Some("sub"), None, "/a/b/c", ResponderMethod::Get)
                 .await?,
             Some(responders[1].clone())
         );
 
         assert_eq!(
             webhooks
-                .find_responder(user.id, Some("sub"), "/a/b/c/d", ResponderMethod::Get)
+                .find_responder(user.id, Some("sub"), None, "/a/b/c/d", ResponderMethod::Get)
                 .await?,
             Some(responders[0].clone())
         );
 
         assert_eq!(
             webhooks
-                .find_responder(user.id, Some("sub"), "/a/b/c/d/e", ResponderMethod::Get)
+                .find_responder(
+                    user.id,
+                    Some("sub"),
+                    None,
+                    "/a/b/c/d/e",
+                    ResponderMethod::Get
+                )
                 .await?,
             Some(responders[1].clone())
         );
@@ -1175,7 +1455,7 @@ mod tests {
         let db = Database::create(pool).await?;
         db.insert_user(&user).await?;
 
-        let responders = vec![
+        let responders = [
             MockResponderBuilder::create(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 "some-name",
@@ -1270,7 +1550,7 @@ mod tests {
         let db = Database::create(pool).await?;
         db.insert_user(&user).await?;
 
-        let responders = vec![
+        let responders = [
             MockResponderBuilder::create(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 "some-name",
@@ -1340,7 +1620,7 @@ mod tests {
         let db = Database::create(pool).await?;
         db.insert_user(&user).await?;
 
-        let responders = vec![
+        let responders = [
             MockResponderBuilder::create(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 "some-name",
@@ -1360,7 +1640,7 @@ mod tests {
             webhooks.insert_responder(user.id, responder).await?;
         }
 
-        let requests = vec![
+        let requests = [
             create_request(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 responders[0].id,
@@ -1429,7 +1709,7 @@ mod tests {
         let db = Database::create(pool).await?;
         db.insert_user(&user).await?;
 
-        let responders = vec![
+        let responders = [
             MockResponderBuilder::create(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 "some-name",
@@ -1449,7 +1729,7 @@ mod tests {
             webhooks.insert_responder(user.id, responder).await?;
         }
 
-        let requests = vec![
+        let requests = [
             create_request(
                 uuid!("00000000-0000-0000-0000-000000000001"),
                 responders[0].id,
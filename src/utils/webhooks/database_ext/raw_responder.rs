@@ -1,6 +1,11 @@
-use crate::utils::webhooks::{Responder, ResponderMethod, ResponderSettings};
+use crate::utils::webhooks::{
+    Responder, ResponderBodyTemplate, ResponderFault, ResponderInitialResponse,
+    ResponderLatencyProfile, ResponderLocation, ResponderMethod, ResponderSettings,
+};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use time::OffsetDateTime;
+use url::Url;
 use uuid::Uuid;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -8,6 +13,7 @@ pub(super) struct RawResponder {
     pub id: Uuid,
     pub name: String,
     pub location: String,
+    pub base_path: Option<String>,
     pub method: Vec<u8>,
     pub enabled: bool,
     pub settings: Vec<u8>,
@@ -28,6 +34,112 @@ struct RawResponderSettings {
     body: Option<String>,
     headers: Option<Vec<(String, String)>>,
     script: Option<String>,
+    body_file: Option<String>,
+    echo_transform: Option<String>,
+    latency: Option<RawResponderLatencyProfile>,
+    fault: Option<RawResponderFault>,
+    body_template: Option<RawResponderBodyTemplate>,
+    forward_to: Option<Vec<String>>,
+    capture_json: bool,
+    initial_response: Option<RawResponderInitialResponse>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+enum RawResponderLatencyProfile {
+    Fixed(Duration),
+    Uniform(Duration, Duration),
+    Normal(Duration, Duration),
+}
+
+impl From<RawResponderLatencyProfile> for ResponderLatencyProfile {
+    fn from(raw: RawResponderLatencyProfile) -> Self {
+        match raw {
+            RawResponderLatencyProfile::Fixed(delay) => Self::Fixed { delay },
+            RawResponderLatencyProfile::Uniform(min, max) => Self::Uniform { min, max },
+            RawResponderLatencyProfile::Normal(mean, stddev) => Self::Normal { mean, stddev },
+        }
+    }
+}
+
+impl From<ResponderLatencyProfile> for RawResponderLatencyProfile {
+    fn from(item: ResponderLatencyProfile) -> Self {
+        match item {
+            ResponderLatencyProfile::Fixed { delay } => Self::Fixed(delay),
+            ResponderLatencyProfile::Uniform { min, max } => Self::Uniform(min, max),
+            ResponderLatencyProfile::Normal { mean, stddev } => Self::Normal(mean, stddev),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+enum RawResponderFault {
+    ResetAfterHeaders,
+    TruncateBody(usize),
+}
+
+impl From<RawResponderFault> for ResponderFault {
+    fn from(raw: RawResponderFault) -> Self {
+        match raw {
+            RawResponderFault::ResetAfterHeaders => Self::ResetAfterHeaders,
+            RawResponderFault::TruncateBody(at) => Self::TruncateBody { at },
+        }
+    }
+}
+
+impl From<ResponderFault> for RawResponderFault {
+    fn from(item: ResponderFault) -> Self {
+        match item {
+            ResponderFault::ResetAfterHeaders => Self::ResetAfterHeaders,
+            ResponderFault::TruncateBody { at } => Self::TruncateBody(at),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+struct RawResponderBodyTemplate {
+    seed: Option<u64>,
+}
+
+impl From<RawResponderBodyTemplate> for ResponderBodyTemplate {
+    fn from(raw: RawResponderBodyTemplate) -> Self {
+        Self { seed: raw.seed }
+    }
+}
+
+impl From<ResponderBodyTemplate> for RawResponderBodyTemplate {
+    fn from(item: ResponderBodyTemplate) -> Self {
+        Self { seed: item.seed }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+struct RawResponderInitialResponse {
+    requests: usize,
+    status_code: u16,
+    body: Option<String>,
+    headers: Option<Vec<(String, String)>>,
+}
+
+impl From<RawResponderInitialResponse> for ResponderInitialResponse {
+    fn from(raw: RawResponderInitialResponse) -> Self {
+        Self {
+            requests: raw.requests,
+            status_code: raw.status_code,
+            body: raw.body,
+            headers: raw.headers,
+        }
+    }
+}
+
+impl From<ResponderInitialResponse> for RawResponderInitialResponse {
+    fn from(item: ResponderInitialResponse) -> Self {
+        Self {
+            requests: item.requests,
+            status_code: item.status_code,
+            body: item.body,
+            headers: item.headers,
+        }
+    }
 }
 
 impl TryFrom<RawResponder> for Responder {
@@ -38,7 +150,10 @@ impl TryFrom<RawResponder> for Responder {
         Ok(Responder {
             id: raw.id,
             name: raw.name,
-            location: raw.location.parse()?,
+            location: raw
+                .location
+                .parse::<ResponderLocation>()?
+                .with_base_path(raw.base_path)?,
             method: postcard::from_bytes::<ResponderMethod>(&raw.method)?,
             enabled: raw.enabled,
             settings: ResponderSettings {
@@ -47,6 +162,21 @@ impl TryFrom<RawResponder> for Responder {
                 body: raw_settings.body,
                 headers: raw_settings.headers,
                 script: raw_settings.script,
+                body_file: raw_settings.body_file,
+                echo_transform: raw_settings.echo_transform,
+                latency: raw_settings.latency.map(Into::into),
+                fault: raw_settings.fault.map(Into::into),
+                body_template: raw_settings.body_template.map(Into::into),
+                forward_to: raw_settings
+                    .forward_to
+                    .map(|urls| {
+                        urls.iter()
+                            .map(|url| Url::parse(url))
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .transpose()?,
+                capture_json: raw_settings.capture_json,
+                initial_response: raw_settings.initial_response.map(Into::into),
             },
             created_at: raw.created_at,
             updated_at: raw.updated_at,
@@ -64,12 +194,25 @@ impl TryFrom<&Responder> for RawResponder {
             body: item.settings.body.clone(),
             headers: item.settings.headers.clone(),
             script: item.settings.script.clone(),
+            body_file: item.settings.body_file.clone(),
+            echo_transform: item.settings.echo_transform.clone(),
+            latency: item.settings.latency.map(Into::into),
+            fault: item.settings.fault.map(Into::into),
+            body_template: item.settings.body_template.map(Into::into),
+            forward_to: item
+                .settings
+                .forward_to
+                .as_ref()
+                .map(|urls| urls.iter().map(|url| url.to_string()).collect()),
+            capture_json: item.settings.capture_json,
+            initial_response: item.settings.initial_response.clone().map(Into::into),
         };
 
         Ok(RawResponder {
             id: item.id,
             name: item.name.clone(),
             location: item.location.to_string(),
+            base_path: item.location.base_path.clone(),
             method: postcard::to_stdvec(&item.method)?,
             enabled: item.enabled,
             settings: postcard::to_stdvec(&raw_settings)?,
@@ -82,10 +225,13 @@ impl TryFrom<&Responder> for RawResponder {
 #[cfg(test)]
 mod tests {
     use crate::utils::webhooks::{
-        database_ext::raw_responder::RawResponder, Responder, ResponderLocation, ResponderMethod,
-        ResponderPathType, ResponderSettings,
+        database_ext::raw_responder::RawResponder, Responder, ResponderBodyTemplate,
+        ResponderFault, ResponderInitialResponse, ResponderLatencyProfile, ResponderLocation,
+        ResponderMethod, ResponderPathType, ResponderSettings,
     };
+    use std::time::Duration;
     use time::OffsetDateTime;
+    use url::Url;
     use uuid::uuid;
 
     #[test]
@@ -97,7 +243,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -107,6 +254,14 @@ mod tests {
                     body: None,
                     headers: None,
                     script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                 updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
@@ -115,9 +270,10 @@ mod tests {
                 id: uuid!("00000000-0000-0000-0000-000000000001"),
                 name: "res".to_string(),
                 location: ":=:/".to_string(),
+                base_path: None,
                 method: vec![0],
                 enabled: true,
-                settings: vec![0, 200, 1, 0, 0, 0],
+                settings: vec![0, 200, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
                 // January 1, 2000 10:00:00
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                 // January 1, 2000 10:00:10
@@ -132,7 +288,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Prefix,
                     path: "/path".to_string(),
-                    subdomain_prefix: Some("sub".to_string())
+                    subdomain_prefix: Some("sub".to_string()),
+                    base_path: None,
                 },
                 method: ResponderMethod::Connect,
                 enabled: false,
@@ -142,6 +299,14 @@ mod tests {
                     body: Some("body".to_string()),
                     headers: Some(vec![("key".to_string(), "value".to_string())]),
                     script: Some("return { body: `custom body` };".to_string()),
+                    body_file: Some("demo.json".to_string()),
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                 updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
@@ -150,13 +315,14 @@ mod tests {
                 id: uuid!("00000000-0000-0000-0000-000000000001"),
                 name: "res".to_string(),
                 location: "sub:^:/path".to_string(),
+                base_path: None,
                 method: vec![7],
                 enabled: false,
                 settings: vec![
                     3, 200, 1, 1, 4, 98, 111, 100, 121, 1, 1, 3, 107, 101, 121, 5, 118, 97, 108,
                     117, 101, 1, 31, 114, 101, 116, 117, 114, 110, 32, 123, 32, 98, 111, 100, 121,
                     58, 32, 96, 99, 117, 115, 116, 111, 109, 32, 98, 111, 100, 121, 96, 32, 125,
-                    59
+                    59, 1, 9, 100, 101, 109, 111, 46, 106, 115, 111, 110, 0, 0, 0, 0, 0, 0, 0
                 ],
                 // January 1, 2000 10:00:00
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
@@ -175,9 +341,10 @@ mod tests {
                 id: uuid!("00000000-0000-0000-0000-000000000001"),
                 name: "res".to_string(),
                 location: ":=:/".to_string(),
+                base_path: None,
                 method: vec![0],
                 enabled: true,
-                settings: vec![0, 200, 1, 0, 0, 0],
+                settings: vec![0, 200, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
                 // January 1, 2000 10:00:00
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                 // January 1, 2000 10:00:10
@@ -189,7 +356,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Any,
                 enabled: true,
@@ -199,6 +367,14 @@ mod tests {
                     body: None,
                     headers: None,
                     script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                 updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
@@ -210,13 +386,14 @@ mod tests {
                 id: uuid!("00000000-0000-0000-0000-000000000001"),
                 name: "res".to_string(),
                 location: "sub:^:/path".to_string(),
+                base_path: None,
                 method: vec![7],
                 enabled: false,
                 settings: vec![
                     3, 200, 1, 1, 4, 98, 111, 100, 121, 1, 1, 3, 107, 101, 121, 5, 118, 97, 108,
                     117, 101, 1, 31, 114, 101, 116, 117, 114, 110, 32, 123, 32, 98, 111, 100, 121,
                     58, 32, 96, 99, 117, 115, 116, 111, 109, 32, 98, 111, 100, 121, 96, 32, 125,
-                    59
+                    59, 1, 9, 100, 101, 109, 111, 46, 106, 115, 111, 110, 0, 0, 0, 0, 0, 0, 0
                 ],
                 // January 1, 2000 10:00:00
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
@@ -229,7 +406,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Prefix,
                     path: "/path".to_string(),
-                    subdomain_prefix: Some("sub".to_string())
+                    subdomain_prefix: Some("sub".to_string()),
+                    base_path: None,
                 },
                 method: ResponderMethod::Connect,
                 enabled: false,
@@ -239,6 +417,14 @@ mod tests {
                     body: Some("body".to_string()),
                     headers: Some(vec![("key".to_string(), "value".to_string())]),
                     script: Some("return { body: `custom body` };".to_string()),
+                    body_file: Some("demo.json".to_string()),
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                 // January 1, 2000 10:00:10
@@ -248,4 +434,290 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn can_convert_responder_with_latency_profile() -> anyhow::Result<()> {
+        for latency in [
+            ResponderLatencyProfile::Fixed {
+                delay: Duration::from_millis(100),
+            },
+            ResponderLatencyProfile::Uniform {
+                min: Duration::from_millis(100),
+                max: Duration::from_millis(500),
+            },
+            ResponderLatencyProfile::Normal {
+                mean: Duration::from_millis(300),
+                stddev: Duration::from_millis(50),
+            },
+        ] {
+            let responder = Responder {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                name: "res".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Any,
+                enabled: true,
+                settings: ResponderSettings {
+                    requests_to_track: 0,
+                    status_code: 200,
+                    body: None,
+                    headers: None,
+                    script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: Some(latency),
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
+                },
+                // January 1, 2000 10:00:00
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                // January 1, 2000 10:00:10
+                updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            };
+
+            assert_eq!(
+                Responder::try_from(RawResponder::try_from(&responder)?)?,
+                responder
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_convert_responder_with_base_path() -> anyhow::Result<()> {
+        for path in ["/", "/users"] {
+            let responder = Responder {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                name: "res".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Prefix,
+                    path: path.to_string(),
+                    subdomain_prefix: None,
+                    base_path: Some("/mockapi/v1".to_string()),
+                },
+                method: ResponderMethod::Any,
+                enabled: true,
+                settings: ResponderSettings {
+                    requests_to_track: 0,
+                    status_code: 200,
+                    body: None,
+                    headers: None,
+                    script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
+                },
+                // January 1, 2000 10:00:00
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                // January 1, 2000 10:00:10
+                updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            };
+
+            let raw_responder = RawResponder::try_from(&responder)?;
+            assert_eq!(raw_responder.base_path.as_deref(), Some("/mockapi/v1"));
+            assert!(raw_responder.location.starts_with(":^:/mockapi/v1"));
+            assert_eq!(Responder::try_from(raw_responder)?, responder);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_convert_responder_with_fault() -> anyhow::Result<()> {
+        for fault in [
+            ResponderFault::ResetAfterHeaders,
+            ResponderFault::TruncateBody { at: 10 },
+        ] {
+            let responder = Responder {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                name: "res".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Any,
+                enabled: true,
+                settings: ResponderSettings {
+                    requests_to_track: 0,
+                    status_code: 200,
+                    body: None,
+                    headers: None,
+                    script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: Some(fault),
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
+                },
+                // January 1, 2000 10:00:00
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                // January 1, 2000 10:00:10
+                updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            };
+
+            assert_eq!(
+                Responder::try_from(RawResponder::try_from(&responder)?)?,
+                responder
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_convert_responder_with_body_template() -> anyhow::Result<()> {
+        for body_template in [
+            ResponderBodyTemplate::default(),
+            ResponderBodyTemplate { seed: Some(42) },
+        ] {
+            let responder = Responder {
+                id: uuid!("00000000-0000-0000-0000-000000000001"),
+                name: "res".to_string(),
+                location: ResponderLocation {
+                    path_type: ResponderPathType::Exact,
+                    path: "/".to_string(),
+                    subdomain_prefix: None,
+                    base_path: None,
+                },
+                method: ResponderMethod::Any,
+                enabled: true,
+                settings: ResponderSettings {
+                    requests_to_track: 0,
+                    status_code: 200,
+                    body: None,
+                    headers: None,
+                    script: None,
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: Some(body_template),
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
+                },
+                // January 1, 2000 10:00:00
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                // January 1, 2000 10:00:10
+                updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+            };
+
+            assert_eq!(
+                Responder::try_from(RawResponder::try_from(&responder)?)?,
+                responder
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_convert_responder_with_forward_targets() -> anyhow::Result<()> {
+        let responder = Responder {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "res".to_string(),
+            location: ResponderLocation {
+                path_type: ResponderPathType::Exact,
+                path: "/".to_string(),
+                subdomain_prefix: None,
+                base_path: None,
+            },
+            method: ResponderMethod::Any,
+            enabled: true,
+            settings: ResponderSettings {
+                requests_to_track: 0,
+                status_code: 200,
+                body: None,
+                headers: None,
+                script: None,
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: Some(vec![
+                    Url::parse("https://secutils.dev/one")?,
+                    Url::parse("https://secutils.dev/two?query=value")?,
+                ]),
+                capture_json: false,
+                initial_response: None,
+            },
+            // January 1, 2000 10:00:00
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            // January 1, 2000 10:00:10
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+        };
+
+        assert_eq!(
+            Responder::try_from(RawResponder::try_from(&responder)?)?,
+            responder
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_convert_responder_with_initial_response() -> anyhow::Result<()> {
+        let responder = Responder {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            name: "res".to_string(),
+            location: ResponderLocation {
+                path_type: ResponderPathType::Exact,
+                path: "/".to_string(),
+                subdomain_prefix: None,
+                base_path: None,
+            },
+            method: ResponderMethod::Any,
+            enabled: true,
+            settings: ResponderSettings {
+                requests_to_track: 0,
+                status_code: 200,
+                body: None,
+                headers: None,
+                script: None,
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: Some(ResponderInitialResponse {
+                    requests: 3,
+                    status_code: 503,
+                    body: Some("unavailable".to_string()),
+                    headers: Some(vec![("Retry-After".to_string(), "1".to_string())]),
+                }),
+            },
+            // January 1, 2000 10:00:00
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            // January 1, 2000 10:00:10
+            updated_at: OffsetDateTime::from_unix_timestamp(946720810)?,
+        };
+
+        assert_eq!(
+            Responder::try_from(RawResponder::try_from(&responder)?)?,
+            responder
+        );
+
+        Ok(())
+    }
 }
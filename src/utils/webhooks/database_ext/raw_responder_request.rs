@@ -1,7 +1,8 @@
-use crate::utils::webhooks::{ResponderRequest, ResponderRequestHeaders};
+use crate::utils::webhooks::{ResponderForwardResult, ResponderRequest, ResponderRequestHeaders};
 use serde::{Deserialize, Serialize};
 use std::{borrow::Cow, net::SocketAddr};
 use time::OffsetDateTime;
+use url::Url;
 use uuid::Uuid;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -19,6 +20,10 @@ struct RawResponderRequestData<'a> {
     headers: Option<ResponderRequestHeaders<'a>>,
     url: Cow<'a, str>,
     body: Option<Cow<'a, [u8]>>,
+    correlation_id: Option<Cow<'a, str>>,
+    forwarded: Option<Vec<(Cow<'a, str>, Option<u16>)>>,
+    json_valid: Option<bool>,
+    json_body: Option<Cow<'a, str>>,
 }
 
 impl<'a> TryFrom<RawResponderRequest> for ResponderRequest<'a> {
@@ -34,6 +39,23 @@ impl<'a> TryFrom<RawResponderRequest> for ResponderRequest<'a> {
             body: raw_data.body,
             headers: raw_data.headers,
             url: raw_data.url,
+            correlation_id: raw_data.correlation_id,
+            forwarded: raw_data
+                .forwarded
+                .map(|forwarded| {
+                    forwarded
+                        .into_iter()
+                        .map(|(url, status)| {
+                            Ok::<_, anyhow::Error>(ResponderForwardResult {
+                                url: Url::parse(&url)?,
+                                status,
+                            })
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .transpose()?,
+            json_valid: raw_data.json_valid,
+            json_body: raw_data.json_body,
             created_at: raw.created_at,
         })
     }
@@ -49,6 +71,15 @@ impl<'a> TryFrom<&ResponderRequest<'a>> for RawResponderRequest {
             body: item.body.clone(),
             headers: item.headers.clone(),
             url: item.url.clone(),
+            correlation_id: item.correlation_id.clone(),
+            forwarded: item.forwarded.as_ref().map(|forwarded| {
+                forwarded
+                    .iter()
+                    .map(|result| (Cow::Borrowed(result.url.as_str()), result.status))
+                    .collect()
+            }),
+            json_valid: item.json_valid,
+            json_body: item.json_body.clone(),
         };
 
         Ok(Self {
@@ -63,9 +94,10 @@ impl<'a> TryFrom<&ResponderRequest<'a>> for RawResponderRequest {
 #[cfg(test)]
 mod tests {
     use super::RawResponderRequest;
-    use crate::utils::webhooks::ResponderRequest;
+    use crate::utils::webhooks::{ResponderForwardResult, ResponderRequest};
     use std::borrow::Cow;
     use time::OffsetDateTime;
+    use url::Url;
     use uuid::uuid;
 
     #[test]
@@ -79,14 +111,18 @@ mod tests {
                 headers: None,
                 body: None,
                 url: Cow::Borrowed("/some-path?query=value"),
-                created_at: OffsetDateTime::from_unix_timestamp(946720800)?
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                correlation_id: None,
+                forwarded: None,
+                json_valid: None,
+                json_body: None,
             })?,
             RawResponderRequest {
                 id: uuid!("00000000-0000-0000-0000-000000000001"),
                 responder_id: uuid!("00000000-0000-0000-0000-000000000002"),
                 data: vec![
                     0, 4, 112, 111, 115, 116, 0, 22, 47, 115, 111, 109, 101, 45, 112, 97, 116, 104,
-                    63, 113, 117, 101, 114, 121, 61, 118, 97, 108, 117, 101, 0
+                    63, 113, 117, 101, 114, 121, 61, 118, 97, 108, 117, 101, 0, 0, 0, 0, 0
                 ],
                 // January 1, 2000 10:00:00
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
@@ -105,7 +141,11 @@ mod tests {
                 )]),
                 url: Cow::Borrowed("/some-path?query=value"),
                 body: Some(Cow::Owned(vec![4, 5, 6])),
-                created_at: OffsetDateTime::from_unix_timestamp(946720800)?
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                correlation_id: Some(Cow::Borrowed("abc")),
+                forwarded: None,
+                json_valid: None,
+                json_body: None,
             })?,
             RawResponderRequest {
                 id: uuid!("00000000-0000-0000-0000-000000000001"),
@@ -114,7 +154,7 @@ mod tests {
                     1, 0, 127, 0, 0, 1, 144, 63, 4, 112, 111, 115, 116, 1, 1, 12, 67, 111, 110,
                     116, 101, 110, 116, 45, 84, 121, 112, 101, 3, 1, 2, 3, 22, 47, 115, 111, 109,
                     101, 45, 112, 97, 116, 104, 63, 113, 117, 101, 114, 121, 61, 118, 97, 108, 117,
-                    101, 1, 3, 4, 5, 6
+                    101, 1, 3, 4, 5, 6, 1, 3, 97, 98, 99, 0, 0, 0
                 ],
                 // January 1, 2000 10:00:00
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
@@ -132,7 +172,7 @@ mod tests {
                 responder_id: uuid!("00000000-0000-0000-0000-000000000002"),
                 data: vec![
                     0, 4, 112, 111, 115, 116, 0, 22, 47, 115, 111, 109, 101, 45, 112, 97, 116, 104,
-                    63, 113, 117, 101, 114, 121, 61, 118, 97, 108, 117, 101, 0
+                    63, 113, 117, 101, 114, 121, 61, 118, 97, 108, 117, 101, 0, 0, 0, 0, 0
                 ],
                 // January 1, 2000 10:00:00
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
@@ -145,7 +185,11 @@ mod tests {
                 headers: None,
                 body: None,
                 url: Cow::Borrowed("/some-path?query=value"),
-                created_at: OffsetDateTime::from_unix_timestamp(946720800)?
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                correlation_id: None,
+                forwarded: None,
+                json_valid: None,
+                json_body: None,
             }
         );
 
@@ -157,7 +201,7 @@ mod tests {
                     1, 0, 127, 0, 0, 1, 144, 63, 4, 112, 111, 115, 116, 1, 1, 12, 67, 111, 110,
                     116, 101, 110, 116, 45, 84, 121, 112, 101, 3, 1, 2, 3, 22, 47, 115, 111, 109,
                     101, 45, 112, 97, 116, 104, 63, 113, 117, 101, 114, 121, 61, 118, 97, 108, 117,
-                    101, 1, 3, 4, 5, 6
+                    101, 1, 3, 4, 5, 6, 1, 3, 97, 98, 99, 0, 0, 0
                 ],
                 // January 1, 2000 10:00:00
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
@@ -173,10 +217,73 @@ mod tests {
                 )]),
                 body: Some(Cow::Owned(vec![4, 5, 6])),
                 url: Cow::Borrowed("/some-path?query=value"),
-                created_at: OffsetDateTime::from_unix_timestamp(946720800)?
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                correlation_id: Some(Cow::Borrowed("abc")),
+                forwarded: None,
+                json_valid: None,
+                json_body: None,
             }
         );
 
         Ok(())
     }
+
+    #[test]
+    fn can_convert_responder_request_with_forward_results() -> anyhow::Result<()> {
+        let request = ResponderRequest {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            responder_id: uuid!("00000000-0000-0000-0000-000000000002"),
+            client_address: None,
+            method: Cow::Owned("post".to_string()),
+            headers: None,
+            body: None,
+            url: Cow::Borrowed("/some-path?query=value"),
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            correlation_id: None,
+            forwarded: Some(vec![
+                ResponderForwardResult {
+                    url: Url::parse("https://secutils.dev/one")?,
+                    status: Some(200),
+                },
+                ResponderForwardResult {
+                    url: Url::parse("https://secutils.dev/two")?,
+                    status: None,
+                },
+            ]),
+            json_valid: None,
+            json_body: None,
+        };
+
+        assert_eq!(
+            ResponderRequest::try_from(RawResponderRequest::try_from(&request)?)?,
+            request
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_convert_responder_request_with_json_body() -> anyhow::Result<()> {
+        let request = ResponderRequest {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            responder_id: uuid!("00000000-0000-0000-0000-000000000002"),
+            client_address: None,
+            method: Cow::Owned("post".to_string()),
+            headers: None,
+            body: Some(Cow::Owned(br#"{"a":1}"#.to_vec())),
+            url: Cow::Borrowed("/some-path?query=value"),
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            correlation_id: None,
+            forwarded: None,
+            json_valid: Some(true),
+            json_body: Some(Cow::Owned("{\n  \"a\": 1\n}".to_string())),
+        };
+
+        assert_eq!(
+            ResponderRequest::try_from(RawResponderRequest::try_from(&request)?)?,
+            request
+        );
+
+        Ok(())
+    }
 }
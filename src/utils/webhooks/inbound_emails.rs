@@ -0,0 +1,3 @@
+mod inbound_email;
+
+pub use self::inbound_email::InboundEmail;
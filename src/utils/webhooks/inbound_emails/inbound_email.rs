@@ -0,0 +1,53 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Represents an email received by the user's disposable inbox.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InboundEmail {
+    /// Unique inbound email ID (UUIDv7).
+    pub id: Uuid,
+    /// Address of the email sender.
+    pub from: String,
+    /// Address of the email recipient.
+    pub to: String,
+    /// Subject of the email.
+    pub subject: String,
+    /// Body of the email.
+    pub body: String,
+    /// The time at which the email was received, in UTC.
+    #[serde(with = "time::serde::timestamp")]
+    pub received_at: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::webhooks::InboundEmail;
+    use insta::assert_json_snapshot;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(InboundEmail {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            from: "sender@secutils.dev".to_string(),
+            to: "handle+test@inbox.secutils.dev".to_string(),
+            subject: "Welcome".to_string(),
+            body: "Hello, World!".to_string(),
+            received_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        }, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "from": "sender@secutils.dev",
+          "to": "handle+test@inbox.secutils.dev",
+          "subject": "Welcome",
+          "body": "Hello, World!",
+          "receivedAt": 946720800
+        }
+        "###);
+
+        Ok(())
+    }
+}
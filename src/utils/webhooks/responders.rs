@@ -1,21 +1,49 @@
 mod responder;
+mod responder_base_path;
+mod responder_body_template;
+mod responder_echo_transform_context;
+mod responder_echo_transform_result;
+mod responder_faker_context;
+mod responder_fault;
+mod responder_forward_result;
+mod responder_initial_response;
+mod responder_latency_profile;
 mod responder_location;
+mod responder_match;
+mod responder_match_scheme;
 mod responder_method;
+mod responder_migration_result;
 mod responder_path_type;
 mod responder_request;
 mod responder_script_context;
 mod responder_script_result;
 mod responder_settings;
 mod responder_stats;
+mod responder_subdomain_prefix_availability;
+mod responder_url_type;
 
 pub use self::{
     responder::Responder,
+    responder_base_path::ResponderBasePath,
+    responder_body_template::ResponderBodyTemplate,
+    responder_echo_transform_context::ResponderEchoTransformContext,
+    responder_echo_transform_result::ResponderEchoTransformResult,
+    responder_faker_context::ResponderFakerContext,
+    responder_fault::ResponderFault,
+    responder_forward_result::ResponderForwardResult,
+    responder_initial_response::ResponderInitialResponse,
+    responder_latency_profile::{ResponderLatencyProfile, MAX_RESPONDER_LATENCY},
     responder_location::ResponderLocation,
+    responder_match::ResponderMatch,
+    responder_match_scheme::ResponderMatchScheme,
     responder_method::ResponderMethod,
+    responder_migration_result::ResponderMigrationResult,
     responder_path_type::ResponderPathType,
     responder_request::{ResponderRequest, ResponderRequestHeaders},
     responder_script_context::ResponderScriptContext,
     responder_script_result::ResponderScriptResult,
     responder_settings::ResponderSettings,
     responder_stats::ResponderStats,
+    responder_subdomain_prefix_availability::ResponderSubdomainPrefixAvailability,
+    responder_url_type::ResponderUrlType,
 };
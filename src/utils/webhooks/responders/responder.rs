@@ -43,7 +43,8 @@ mod tests {
             location: ResponderLocation {
                 path_type: ResponderPathType::Exact,
                 path: "/path".to_string(),
-                subdomain_prefix: None
+                subdomain_prefix: None,
+                base_path: None,
             },
             method: ResponderMethod::Post,
             enabled: true,
@@ -53,6 +54,14 @@ mod tests {
                 body: Some("some-body".to_string()),
                 headers: Some(vec![("key".to_string(), "value".to_string())]),
                 script: Some("return { body: `custom body` };".to_string()),
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
             },
             created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
             updated_at: OffsetDateTime::from_unix_timestamp(946720810)?
@@ -123,7 +132,8 @@ mod tests {
                 location: ResponderLocation {
                     path_type: ResponderPathType::Exact,
                     path: "/path".to_string(),
-                    subdomain_prefix: None
+                    subdomain_prefix: None,
+                    base_path: None,
                 },
                 method: ResponderMethod::Post,
                 enabled: true,
@@ -133,6 +143,14 @@ mod tests {
                     body: Some("some-body".to_string()),
                     headers: Some(vec![("key".to_string(), "value".to_string())]),
                     script: Some("return { body: `custom body` };".to_string()),
+                    body_file: None,
+                    echo_transform: None,
+                    latency: None,
+                    fault: None,
+                    body_template: None,
+                    forward_to: None,
+                    capture_json: false,
+                    initial_response: None,
                 },
                 created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
                 updated_at: OffsetDateTime::from_unix_timestamp(946720810)?
@@ -0,0 +1,42 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Represents a base path prefix reserved by the user (e.g. `/mockapi/v1`) that responders can be
+/// created relative to. Requests under the base path are routed only to its responders.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponderBasePath {
+    /// Unique base path ID (UUIDv7).
+    pub id: Uuid,
+    /// The base path, always begins with '/' and doesn't end with '/'.
+    pub path: String,
+    /// Date and time when the base path was reserved.
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::webhooks::ResponderBasePath;
+    use insta::assert_json_snapshot;
+    use time::OffsetDateTime;
+    use uuid::uuid;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(ResponderBasePath {
+            id: uuid!("00000000-0000-0000-0000-000000000001"),
+            path: "/mockapi/v1".to_string(),
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+        }, @r###"
+        {
+          "id": "00000000-0000-0000-0000-000000000001",
+          "path": "/mockapi/v1",
+          "createdAt": 946720800
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::utils::webhooks::ResponderFakerContext;
+use handlebars::Handlebars;
+use rand::{rngs::StdRng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+/// Indicates that the responder body is a Handlebars template that should be rendered for every
+/// received request, with fake data available through the `faker` variable (e.g.
+/// `{{faker.email}}`).
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponderBodyTemplate {
+    /// Optional seed for the fake data generator. If specified, every request gets the same fake
+    /// data, otherwise fake data varies across requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+}
+
+impl ResponderBodyTemplate {
+    /// Renders the specified body template.
+    pub fn render(&self, template: &str) -> anyhow::Result<String> {
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        // Responder bodies are usually JSON, not HTML, so values shouldn't be HTML-escaped.
+        let mut handlebars = Handlebars::new();
+        handlebars.register_escape_fn(handlebars::no_escape);
+
+        Ok(handlebars.render_template(
+            template,
+            &json!({ "faker": ResponderFakerContext::generate(&mut rng)? }),
+        )?)
+    }
+
+    /// Checks whether the specified body is a valid template.
+    pub fn validate(template: &str) -> anyhow::Result<()> {
+        handlebars::Template::compile(template)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResponderBodyTemplate;
+    use insta::assert_json_snapshot;
+    use serde_json::{json, Value as JsonValue};
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(ResponderBodyTemplate::default(), @"{}");
+        assert_json_snapshot!(ResponderBodyTemplate { seed: Some(42) }, @r###"
+        {
+          "seed": 42
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<ResponderBodyTemplate>(json!({}))?,
+            ResponderBodyTemplate::default()
+        );
+        assert_eq!(
+            serde_json::from_value::<ResponderBodyTemplate>(json!({ "seed": 42 }))?,
+            ResponderBodyTemplate { seed: Some(42) }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_render_template() -> anyhow::Result<()> {
+        let template = r#"{ "id": "{{faker.uuid}}", "name": "{{faker.name}}", "email": "{{faker.email}}", "age": {{faker.number}} }"#;
+
+        let render = |template_settings: ResponderBodyTemplate| -> anyhow::Result<JsonValue> {
+            Ok(serde_json::from_str(&template_settings.render(template)?)?)
+        };
+
+        // Unseeded templates vary across renders.
+        let (one, two) = (
+            render(ResponderBodyTemplate::default())?,
+            render(ResponderBodyTemplate::default())?,
+        );
+        assert_ne!(one["id"], two["id"]);
+        assert!(one["email"].as_str().unwrap().contains('@'));
+        assert!(one["age"].as_u64().unwrap() <= 1000);
+
+        // Seeded templates are deterministic.
+        let seeded = ResponderBodyTemplate { seed: Some(42) };
+        assert_eq!(render(seeded)?, render(seeded)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_validate_template() {
+        assert!(ResponderBodyTemplate::validate("Hello, {{faker.name}}!").is_ok());
+        assert!(ResponderBodyTemplate::validate("Hello, {{faker.name!").is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use std::{collections::HashMap, net::SocketAddr};
+
+/// Parsed request available to the responder echo transform scripts through global `context`
+/// variable.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponderEchoTransformContext<'a> {
+    /// An internet socket address of the client that made the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_address: Option<SocketAddr>,
+    /// HTTP method of the received request.
+    pub method: &'a str,
+    /// HTTP headers of the received request.
+    pub headers: HashMap<&'a str, &'a str>,
+    /// HTTP path of the received request.
+    pub path: &'a str,
+    /// Parsed query string of the received request.
+    pub query: HashMap<&'a str, &'a str>,
+    /// HTTP body of the received request parsed as JSON, if possible, or as a string otherwise.
+    pub body: JsonValue,
+}
+
+impl<'a> ResponderEchoTransformContext<'a> {
+    /// Parses the HTTP body of the received request: JSON bodies are parsed into objects, and any
+    /// other bodies are represented as strings.
+    pub fn parse_body(body: &[u8]) -> JsonValue {
+        if body.is_empty() {
+            return JsonValue::Null;
+        }
+
+        serde_json::from_slice(body)
+            .unwrap_or_else(|_| JsonValue::String(String::from_utf8_lossy(body).into_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::webhooks::ResponderEchoTransformContext;
+    use serde_json::json;
+
+    #[test]
+    fn properly_parses_body() {
+        assert_eq!(
+            ResponderEchoTransformContext::parse_body(b""),
+            serde_json::Value::Null
+        );
+        assert_eq!(
+            ResponderEchoTransformContext::parse_body(br#"{ "name": "value", "count": 1 }"#),
+            json!({ "name": "value", "count": 1 })
+        );
+        assert_eq!(
+            ResponderEchoTransformContext::parse_body(b"some-body"),
+            json!("some-body")
+        );
+    }
+}
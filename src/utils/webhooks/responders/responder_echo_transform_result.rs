@@ -0,0 +1,90 @@
+use bytes::Bytes;
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+
+/// Result of the responder echo transform script execution.
+#[derive(Deserialize, Default, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponderEchoTransformResult {
+    /// HTTP status code to respond with. If not specified, the default status code of responder is used.
+    pub status_code: Option<u16>,
+    /// Optional HTTP headers of the response. If not specified, the default headers of responder are used.
+    pub headers: Option<HashMap<String, String>>,
+    /// Optional HTTP body of the response. Strings are used as is, and any other values are
+    /// serialized to JSON. If not specified, the request body is echoed back.
+    pub body: Option<JsonValue>,
+}
+
+impl ResponderEchoTransformResult {
+    /// Converts the transformed body to bytes, if it's specified.
+    pub fn body_bytes(&self) -> anyhow::Result<Option<Bytes>> {
+        Ok(match self.body {
+            Some(JsonValue::String(ref body)) => Some(Bytes::from(body.clone())),
+            Some(ref body) => Some(Bytes::from(serde_json::to_vec(body)?)),
+            None => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::webhooks::ResponderEchoTransformResult;
+    use bytes::Bytes;
+    use serde_json::json;
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_str::<ResponderEchoTransformResult>(
+                r#"
+{
+    "body": { "name": "VALUE" },
+    "statusCode": 300,
+    "headers": {
+        "one": "two"
+    }
+}
+          "#
+            )?,
+            ResponderEchoTransformResult {
+                headers: Some(
+                    [("one".to_string(), "two".to_string())]
+                        .into_iter()
+                        .collect()
+                ),
+                status_code: Some(300),
+                body: Some(json!({ "name": "VALUE" })),
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<ResponderEchoTransformResult>(r#"{}"#)?,
+            Default::default()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn converts_body_to_bytes() -> anyhow::Result<()> {
+        let result = ResponderEchoTransformResult {
+            body: Some(json!("some-body")),
+            ..Default::default()
+        };
+        assert_eq!(result.body_bytes()?, Some(Bytes::from_static(b"some-body")));
+
+        let result = ResponderEchoTransformResult {
+            body: Some(json!({ "name": "VALUE" })),
+            ..Default::default()
+        };
+        assert_eq!(
+            result.body_bytes()?,
+            Some(Bytes::from_static(br#"{"name":"VALUE"}"#))
+        );
+
+        assert_eq!(ResponderEchoTransformResult::default().body_bytes()?, None);
+
+        Ok(())
+    }
+}
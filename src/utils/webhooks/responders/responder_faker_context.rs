@@ -0,0 +1,174 @@
+use rand::{seq::SliceRandom, Rng};
+use serde::Serialize;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+use uuid::Uuid;
+
+const FIRST_NAMES: [&str; 16] = [
+    "Alice", "Bob", "Carol", "Dave", "Erin", "Frank", "Grace", "Heidi", "Ivan", "Judy", "Mallory",
+    "Niaj", "Olivia", "Peggy", "Rupert", "Sybil",
+];
+
+const LAST_NAMES: [&str; 16] = [
+    "Anderson", "Brown", "Clark", "Davis", "Evans", "Fischer", "Garcia", "Harris", "Ivanova",
+    "Johnson", "Kim", "Lopez", "Miller", "Nguyen", "Smith", "Taylor",
+];
+
+const WORDS: [&str; 16] = [
+    "alpha", "bravo", "charlie", "delta", "echo", "foxtrot", "golf", "hotel", "india", "juliet",
+    "kilo", "lima", "mike", "november", "oscar", "papa",
+];
+
+const EMAIL_DOMAINS: [&str; 4] = ["example.com", "example.net", "example.org", "secutils.dev"];
+
+/// Generated timestamps fall within [2020-01-01, 2030-01-01) range.
+const MIN_TIMESTAMP: i64 = 1577836800;
+const MAX_TIMESTAMP: i64 = 1893456000;
+
+/// Fake data available to the responder body templates through the `faker` variable (e.g.
+/// `{{faker.email}}`).
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponderFakerContext {
+    /// Random UUID v4.
+    pub uuid: Uuid,
+    /// Random first name.
+    pub first_name: &'static str,
+    /// Random last name.
+    pub last_name: &'static str,
+    /// Full name composed of the first and last names.
+    pub name: String,
+    /// Username derived from the first and last names.
+    pub username: String,
+    /// Email address derived from the username.
+    pub email: String,
+    /// Random dictionary word.
+    pub word: &'static str,
+    /// Random number within [0, 1000] range.
+    pub number: u32,
+    /// Random boolean value.
+    pub boolean: bool,
+    /// Random IPv4 address.
+    pub ipv4: String,
+    /// Random Unix timestamp, in seconds.
+    pub timestamp: i64,
+    /// Random date and time that corresponds to the `timestamp`, in RFC 3339 format.
+    pub date_time: String,
+}
+
+impl ResponderFakerContext {
+    /// Generates a new set of fake data using the provided random number generator.
+    pub fn generate(rng: &mut impl Rng) -> anyhow::Result<Self> {
+        let first_name = *FIRST_NAMES.choose(rng).unwrap_or(&FIRST_NAMES[0]);
+        let last_name = *LAST_NAMES.choose(rng).unwrap_or(&LAST_NAMES[0]);
+        let username = format!(
+            "{}.{}{}",
+            first_name.to_lowercase(),
+            last_name.to_lowercase(),
+            rng.gen_range(1..100)
+        );
+        let email_domain = EMAIL_DOMAINS.choose(rng).unwrap_or(&EMAIL_DOMAINS[0]);
+        let timestamp = rng.gen_range(MIN_TIMESTAMP..MAX_TIMESTAMP);
+
+        Ok(Self {
+            uuid: uuid::Builder::from_random_bytes(rng.gen()).into_uuid(),
+            first_name,
+            last_name,
+            name: format!("{first_name} {last_name}"),
+            email: format!("{username}@{email_domain}"),
+            username,
+            word: WORDS.choose(rng).unwrap_or(&WORDS[0]),
+            number: rng.gen_range(0..=1000),
+            boolean: rng.gen(),
+            ipv4: format!(
+                "{}.{}.{}.{}",
+                rng.gen_range(1..=223),
+                rng.gen::<u8>(),
+                rng.gen::<u8>(),
+                rng.gen_range(1..=254)
+            ),
+            timestamp,
+            date_time: OffsetDateTime::from_unix_timestamp(timestamp)?.format(&Rfc3339)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResponderFakerContext;
+    use insta::assert_json_snapshot;
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::net::Ipv4Addr;
+    use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+    #[test]
+    fn generates_well_formed_data() -> anyhow::Result<()> {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let context = ResponderFakerContext::generate(&mut rng)?;
+            assert_eq!(context.uuid.get_version_num(), 4);
+            assert_eq!(
+                context.name,
+                format!("{} {}", context.first_name, context.last_name)
+            );
+            assert!(context.email.starts_with(&format!("{}@", context.username)));
+            assert!(context.number <= 1000);
+            assert!(context.ipv4.parse::<Ipv4Addr>().is_ok());
+            assert_eq!(
+                OffsetDateTime::parse(&context.date_time, &Rfc3339)?.unix_timestamp(),
+                context.timestamp
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn generates_deterministic_data_for_the_same_seed() -> anyhow::Result<()> {
+        assert_eq!(
+            ResponderFakerContext::generate(&mut StdRng::seed_from_u64(1))?,
+            ResponderFakerContext::generate(&mut StdRng::seed_from_u64(1))?
+        );
+        assert_ne!(
+            ResponderFakerContext::generate(&mut StdRng::seed_from_u64(1))?,
+            ResponderFakerContext::generate(&mut StdRng::seed_from_u64(2))?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        let context = ResponderFakerContext::generate(&mut StdRng::seed_from_u64(0))?;
+        assert_json_snapshot!(context, {
+            ".uuid" => "[uuid]",
+            ".firstName" => "[first-name]",
+            ".lastName" => "[last-name]",
+            ".name" => "[name]",
+            ".username" => "[username]",
+            ".email" => "[email]",
+            ".word" => "[word]",
+            ".number" => "[number]",
+            ".boolean" => "[boolean]",
+            ".ipv4" => "[ipv4]",
+            ".timestamp" => "[timestamp]",
+            ".dateTime" => "[date-time]"
+        }, @r###"
+        {
+          "uuid": "[uuid]",
+          "firstName": "[first-name]",
+          "lastName": "[last-name]",
+          "name": "[name]",
+          "username": "[username]",
+          "email": "[email]",
+          "word": "[word]",
+          "number": "[number]",
+          "boolean": "[boolean]",
+          "ipv4": "[ipv4]",
+          "timestamp": "[timestamp]",
+          "dateTime": "[date-time]"
+        }
+        "###);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes a network failure that the responder should simulate to test client resilience.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ResponderFault {
+    /// Responder sends the status line and headers, and then drops the connection without sending
+    /// any body.
+    ResetAfterHeaders,
+    /// Responder advertises the full body length in `Content-Length`, but sends only the first
+    /// `at` bytes of the body and then drops the connection.
+    TruncateBody { at: usize },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResponderFault;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(ResponderFault::ResetAfterHeaders, @r###"
+        {
+          "type": "resetAfterHeaders"
+        }
+        "###);
+        assert_json_snapshot!(ResponderFault::TruncateBody { at: 10 }, @r###"
+        {
+          "type": "truncateBody",
+          "at": 10
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<ResponderFault>(json!({ "type": "resetAfterHeaders" }))?,
+            ResponderFault::ResetAfterHeaders
+        );
+        assert_eq!(
+            serde_json::from_value::<ResponderFault>(json!({ "type": "truncateBody", "at": 10 }))?,
+            ResponderFault::TruncateBody { at: 10 }
+        );
+
+        Ok(())
+    }
+}
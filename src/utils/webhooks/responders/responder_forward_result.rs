@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Result of forwarding a request received by the responder to one of the configured targets.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponderForwardResult {
+    /// URL of the target the request was forwarded to.
+    pub url: Url,
+    /// HTTP status code the target responded with, or `None` if the request couldn't be delivered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResponderForwardResult;
+    use insta::assert_json_snapshot;
+    use url::Url;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(ResponderForwardResult {
+            url: Url::parse("https://secutils.dev/one")?,
+            status: Some(200),
+        }, @r###"
+        {
+          "url": "https://secutils.dev/one",
+          "status": 200
+        }
+        "###);
+        assert_json_snapshot!(ResponderForwardResult {
+            url: Url::parse("https://secutils.dev/two")?,
+            status: None,
+        }, @r###"
+        {
+          "url": "https://secutils.dev/two"
+        }
+        "###);
+
+        Ok(())
+    }
+}
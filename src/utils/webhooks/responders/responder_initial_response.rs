@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// Response the responder should send for the first few received requests before it starts
+/// responding with the configured regular response (e.g. to simulate a flaky endpoint that fails
+/// the first N requests and then succeeds).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponderInitialResponse {
+    /// Number of the first received requests that should get the initial response.
+    pub requests: usize,
+    /// HTTP status code to respond with.
+    pub status_code: u16,
+    /// Optional body to respond with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Optional headers to respond with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Vec<(String, String)>>,
+}
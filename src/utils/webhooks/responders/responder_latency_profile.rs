@@ -0,0 +1,215 @@
+use rand::Rng;
+use rand_distr::{Distribution, Normal};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DurationMilliSeconds};
+use std::time::Duration;
+
+/// Hard limit for the artificial latency of the responder, regardless of the latency profile.
+pub const MAX_RESPONDER_LATENCY: Duration = Duration::from_secs(30);
+
+/// Describes how long the responder should wait before responding to simulate realistic network
+/// latency.
+#[serde_as]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ResponderLatencyProfile {
+    /// Responder always waits for the same amount of time.
+    Fixed {
+        #[serde_as(as = "DurationMilliSeconds<u64>")]
+        delay: Duration,
+    },
+    /// Responder waits for a random amount of time within the specified range (inclusive).
+    Uniform {
+        #[serde_as(as = "DurationMilliSeconds<u64>")]
+        min: Duration,
+        #[serde_as(as = "DurationMilliSeconds<u64>")]
+        max: Duration,
+    },
+    /// Responder waits for a random amount of time that follows normal distribution with the
+    /// specified mean and standard deviation.
+    Normal {
+        #[serde_as(as = "DurationMilliSeconds<u64>")]
+        mean: Duration,
+        #[serde_as(as = "DurationMilliSeconds<u64>")]
+        stddev: Duration,
+    },
+}
+
+impl ResponderLatencyProfile {
+    /// Generates the next delay using the provided random number generator. The delay never
+    /// exceeds `MAX_RESPONDER_LATENCY`.
+    pub fn sample(&self, rng: &mut impl Rng) -> Duration {
+        let delay = match *self {
+            Self::Fixed { delay } => delay,
+            Self::Uniform { min, max } => rng.gen_range(min..=max.max(min)),
+            Self::Normal { mean, stddev } => {
+                match Normal::new(mean.as_secs_f64(), stddev.as_secs_f64()) {
+                    Ok(normal) => Duration::from_secs_f64(
+                        normal
+                            .sample(rng)
+                            .clamp(0.0, MAX_RESPONDER_LATENCY.as_secs_f64()),
+                    ),
+                    Err(_) => mean,
+                }
+            }
+        };
+
+        delay.min(MAX_RESPONDER_LATENCY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ResponderLatencyProfile, MAX_RESPONDER_LATENCY};
+    use insta::assert_json_snapshot;
+    use rand::{rngs::StdRng, SeedableRng};
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(ResponderLatencyProfile::Fixed { delay: Duration::from_millis(100) }, @r###"
+        {
+          "type": "fixed",
+          "delay": 100
+        }
+        "###);
+        assert_json_snapshot!(ResponderLatencyProfile::Uniform {
+            min: Duration::from_millis(100),
+            max: Duration::from_millis(500)
+        }, @r###"
+        {
+          "type": "uniform",
+          "min": 100,
+          "max": 500
+        }
+        "###);
+        assert_json_snapshot!(ResponderLatencyProfile::Normal {
+            mean: Duration::from_millis(300),
+            stddev: Duration::from_millis(50)
+        }, @r###"
+        {
+          "type": "normal",
+          "mean": 300,
+          "stddev": 50
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<ResponderLatencyProfile>(
+                json!({ "type": "fixed", "delay": 100 })
+            )?,
+            ResponderLatencyProfile::Fixed {
+                delay: Duration::from_millis(100)
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<ResponderLatencyProfile>(
+                json!({ "type": "uniform", "min": 100, "max": 500 })
+            )?,
+            ResponderLatencyProfile::Uniform {
+                min: Duration::from_millis(100),
+                max: Duration::from_millis(500)
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<ResponderLatencyProfile>(
+                json!({ "type": "normal", "mean": 300, "stddev": 50 })
+            )?,
+            ResponderLatencyProfile::Normal {
+                mean: Duration::from_millis(300),
+                stddev: Duration::from_millis(50)
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn generates_fixed_delay() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let profile = ResponderLatencyProfile::Fixed {
+            delay: Duration::from_millis(100),
+        };
+        for _ in 0..100 {
+            assert_eq!(profile.sample(&mut rng), Duration::from_millis(100));
+        }
+
+        let profile = ResponderLatencyProfile::Fixed {
+            delay: MAX_RESPONDER_LATENCY * 2,
+        };
+        assert_eq!(profile.sample(&mut rng), MAX_RESPONDER_LATENCY);
+    }
+
+    #[test]
+    fn generates_uniform_delay_within_bounds() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let profile = ResponderLatencyProfile::Uniform {
+            min: Duration::from_millis(100),
+            max: Duration::from_millis(500),
+        };
+
+        let delays = (0..1000)
+            .map(|_| profile.sample(&mut rng))
+            .collect::<Vec<_>>();
+        assert!(delays.iter().all(|delay| (Duration::from_millis(100)
+            ..=Duration::from_millis(500))
+            .contains(delay)));
+        // Delays should actually vary.
+        assert!(delays.iter().any(|delay| *delay != delays[0]));
+
+        // The same seed should produce the same delays.
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(
+            (0..1000)
+                .map(|_| profile.sample(&mut rng))
+                .collect::<Vec<_>>(),
+            delays
+        );
+
+        let profile = ResponderLatencyProfile::Uniform {
+            min: Duration::from_millis(100),
+            max: Duration::from_millis(100),
+        };
+        assert_eq!(profile.sample(&mut rng), Duration::from_millis(100));
+
+        let profile = ResponderLatencyProfile::Uniform {
+            min: MAX_RESPONDER_LATENCY,
+            max: MAX_RESPONDER_LATENCY * 2,
+        };
+        assert_eq!(profile.sample(&mut rng), MAX_RESPONDER_LATENCY);
+    }
+
+    #[test]
+    fn generates_normal_delay_within_bounds() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let profile = ResponderLatencyProfile::Normal {
+            mean: Duration::from_millis(300),
+            stddev: Duration::from_millis(200),
+        };
+
+        let delays = (0..1000)
+            .map(|_| profile.sample(&mut rng))
+            .collect::<Vec<_>>();
+        assert!(delays.iter().all(|delay| *delay <= MAX_RESPONDER_LATENCY));
+        // Negative samples are clamped to zero.
+        assert!(delays.iter().any(|delay| delay.is_zero()));
+
+        let average = delays.iter().sum::<Duration>() / delays.len() as u32;
+        assert!(
+            (Duration::from_millis(250)..=Duration::from_millis(350)).contains(&average),
+            "{average:?}"
+        );
+
+        let profile = ResponderLatencyProfile::Normal {
+            mean: MAX_RESPONDER_LATENCY * 2,
+            stddev: Duration::from_millis(100),
+        };
+        assert_eq!(profile.sample(&mut rng), MAX_RESPONDER_LATENCY);
+    }
+}
@@ -16,6 +16,47 @@ pub struct ResponderLocation {
     /// subdomain based on the user handle is used.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subdomain_prefix: Option<String>,
+    /// Optional base path reserved by the user (e.g. `/mockapi/v1`), responder location path is
+    /// relative to it if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_path: Option<String>,
+}
+
+impl ResponderLocation {
+    /// Returns the full responder location path, including the base path, if any.
+    pub fn full_path(&self) -> String {
+        match self.base_path {
+            // Root path of the base path matches the base path itself.
+            Some(ref base_path) if self.path == "/" => base_path.clone(),
+            Some(ref base_path) => format!("{base_path}{}", self.path),
+            None => self.path.clone(),
+        }
+    }
+
+    /// Makes location path relative to the specified base path, if any. The location path is
+    /// expected to be a full path that includes the base path.
+    pub fn with_base_path(self, base_path: Option<String>) -> anyhow::Result<Self> {
+        let Some(base_path) = base_path else {
+            return Ok(self);
+        };
+
+        let path = match self.path.strip_prefix(&base_path) {
+            Some("") => "/".to_string(),
+            Some(path) if path.starts_with('/') => path.to_string(),
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Location path ({}) is not within the base path ({base_path}).",
+                    self.path
+                ))
+            }
+        };
+
+        Ok(Self {
+            path,
+            base_path: Some(base_path),
+            ..self
+        })
+    }
 }
 
 impl Display for ResponderLocation {
@@ -28,7 +69,7 @@ impl Display for ResponderLocation {
                 .unwrap_or("")
                 .to_ascii_lowercase(),
             self.path_type,
-            self.path.to_ascii_lowercase()
+            self.full_path().to_ascii_lowercase()
         )
     }
 }
@@ -39,14 +80,14 @@ impl Debug for ResponderLocation {
             Some(ref subdomain) => write!(
                 f,
                 "{} ({}, {:?})",
-                self.path.to_ascii_lowercase(),
+                self.full_path().to_ascii_lowercase(),
                 subdomain.to_ascii_lowercase(),
                 self.path_type
             ),
             None => write!(
                 f,
                 "{} ({:?})",
-                self.path.to_ascii_lowercase(),
+                self.full_path().to_ascii_lowercase(),
                 self.path_type
             ),
         }
@@ -67,6 +108,7 @@ impl FromStr for ResponderLocation {
                     },
                     path_type,
                     path: parts[2].to_ascii_lowercase(),
+                    base_path: None,
                 });
             }
         }
@@ -85,6 +127,7 @@ mod tests {
             path_type: ResponderPathType::Exact,
             path: "/pAth".to_string(),
             subdomain_prefix: None,
+            base_path: None,
         };
         assert_json_snapshot!(location, @r###"
         {
@@ -98,6 +141,7 @@ mod tests {
             path_type: ResponderPathType::Prefix,
             path: "/paTh".to_string(),
             subdomain_prefix: Some("mY.domAiN".to_string()),
+            base_path: None,
         };
         assert_json_snapshot!(location, @r###"
         {
@@ -108,6 +152,21 @@ mod tests {
         "###);
         assert_eq!(location.to_string(), "my.domain:^:/path");
 
+        let location = ResponderLocation {
+            path_type: ResponderPathType::Exact,
+            path: "/Users".to_string(),
+            subdomain_prefix: None,
+            base_path: Some("/mockapi/v1".to_string()),
+        };
+        assert_json_snapshot!(location, @r###"
+        {
+          "pathType": "=",
+          "path": "/Users",
+          "basePath": "/mockapi/v1"
+        }
+        "###);
+        assert_eq!(location.to_string(), ":=:/mockapi/v1/users");
+
         Ok(())
     }
 
@@ -126,6 +185,7 @@ mod tests {
                 path_type: ResponderPathType::Exact,
                 path: "/pAth".to_string(),
                 subdomain_prefix: None,
+                base_path: None,
             }
         );
         assert_eq!(
@@ -134,6 +194,7 @@ mod tests {
                 path_type: ResponderPathType::Exact,
                 path: "/path".to_string(),
                 subdomain_prefix: None,
+                base_path: None,
             }
         );
 
@@ -151,6 +212,7 @@ mod tests {
                 path_type: ResponderPathType::Prefix,
                 path: "/paTh".to_string(),
                 subdomain_prefix: Some("mY.domain".to_string()),
+                base_path: None,
             }
         );
         assert_eq!(
@@ -159,9 +221,70 @@ mod tests {
                 path_type: ResponderPathType::Prefix,
                 path: "/path".to_string(),
                 subdomain_prefix: Some("my.domain".to_string()),
+                base_path: None,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<ResponderLocation>(
+                r#"
+        {
+          "pathType": "=",
+          "path": "/users",
+          "basePath": "/mockapi/v1"
+        }
+        "#
+            )?,
+            ResponderLocation {
+                path_type: ResponderPathType::Exact,
+                path: "/users".to_string(),
+                subdomain_prefix: None,
+                base_path: Some("/mockapi/v1".to_string()),
             }
         );
 
         Ok(())
     }
+
+    #[test]
+    fn properly_handles_base_path() -> anyhow::Result<()> {
+        let location = |path: &str, base_path: Option<&str>| ResponderLocation {
+            path_type: ResponderPathType::Prefix,
+            path: path.to_string(),
+            subdomain_prefix: None,
+            base_path: base_path.map(|base_path| base_path.to_string()),
+        };
+
+        assert_eq!(location("/users", None).full_path(), "/users");
+        assert_eq!(
+            location("/users", Some("/mockapi/v1")).full_path(),
+            "/mockapi/v1/users"
+        );
+        assert_eq!(
+            location("/", Some("/mockapi/v1")).full_path(),
+            "/mockapi/v1"
+        );
+
+        assert_eq!(
+            location("/mockapi/v1/users", None).with_base_path(Some("/mockapi/v1".to_string()))?,
+            location("/users", Some("/mockapi/v1"))
+        );
+        assert_eq!(
+            location("/mockapi/v1", None).with_base_path(Some("/mockapi/v1".to_string()))?,
+            location("/", Some("/mockapi/v1"))
+        );
+        assert_eq!(
+            location("/users", None).with_base_path(None)?,
+            location("/users", None)
+        );
+        assert_eq!(
+            location("/mockapi/v10/users", None)
+                .with_base_path(Some("/mockapi/v1".to_string()))
+                .unwrap_err()
+                .to_string(),
+            "Location path (/mockapi/v10/users) is not within the base path (/mockapi/v1)."
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,16 @@
+use crate::{
+    users::User,
+    utils::webhooks::{Responder, ResponderMatchScheme},
+};
+
+/// Describes the responder that should handle the incoming webhook request and the user it
+/// belongs to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponderMatch {
+    /// User that owns the responder.
+    pub user: User,
+    /// Responder that should handle the request.
+    pub responder: Responder,
+    /// Scheme that was used to match the request to the responder.
+    pub scheme: ResponderMatchScheme,
+}
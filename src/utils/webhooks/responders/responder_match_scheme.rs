@@ -0,0 +1,13 @@
+/// Describes the scheme that was used to match the incoming webhook request to the responder.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ResponderMatchScheme {
+    /// User handle and responder path are specified in the request path, e.g.
+    /// `https://secutils.dev/api/webhooks/<user-handle>/<responder-path>`.
+    Path,
+    /// User handle and optional subdomain prefix are specified in the request host, e.g.
+    /// `https://<prefix>-<user-handle>.webhooks.secutils.dev/<responder-path>`.
+    Subdomain,
+    /// Request host is a custom domain configured for a particular user, e.g.
+    /// `https://hooks.example.com/<responder-path>`.
+    CustomDomain,
+}
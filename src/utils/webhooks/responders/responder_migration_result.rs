@@ -0,0 +1,13 @@
+use crate::utils::webhooks::Responder;
+use serde::Serialize;
+use url::Url;
+
+/// Result of the responder migration to a different URL type.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponderMigrationResult {
+    /// Migrated responder.
+    pub responder: Responder,
+    /// URL the responder is reachable at after migration.
+    pub url: Url,
+}
@@ -1,3 +1,4 @@
+use crate::utils::webhooks::ResponderForwardResult;
 use serde::{Deserialize, Serialize};
 use std::{borrow::Cow, net::SocketAddr};
 use time::OffsetDateTime;
@@ -26,6 +27,19 @@ pub struct ResponderRequest<'a> {
     /// HTTP body of the request.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body: Option<Cow<'a, [u8]>>,
+    /// Correlation ID of the request that was returned to the client with the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<Cow<'a, str>>,
+    /// Results of forwarding the request to the targets configured for the responder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forwarded: Option<Vec<ResponderForwardResult>>,
+    /// Indicates whether the HTTP body of the request is a valid JSON, only set if the responder
+    /// captures JSON.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_valid: Option<bool>,
+    /// Canonical pretty-printed form of the valid JSON HTTP body of the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_body: Option<Cow<'a, str>>,
     /// Date and time when the request was captured.
     #[serde(with = "time::serde::timestamp")]
     pub created_at: OffsetDateTime,
@@ -49,7 +63,11 @@ mod tests {
             headers: Some(vec![(Cow::Borrowed("Content-Type"), Cow::Borrowed(&[1, 2, 3]))]),
             body: Some(Cow::Borrowed(&[4, 5, 6])),
             url: Cow::Borrowed("/some-path?query=value"),
-            created_at: OffsetDateTime::from_unix_timestamp(946720800)?
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            correlation_id: Some(Cow::Borrowed("abc")),
+            forwarded: None,
+            json_valid: Some(false),
+            json_body: None,
         }, @r###"
         {
           "id": "00000000-0000-0000-0000-000000000001",
@@ -71,6 +89,8 @@ mod tests {
             5,
             6
           ],
+          "correlationId": "abc",
+          "jsonValid": false,
           "createdAt": 946720800
         }
         "###);
@@ -83,7 +103,11 @@ mod tests {
             headers: None,
             body: None,
             url: Cow::Borrowed("/some-path?query=value"),
-            created_at: OffsetDateTime::from_unix_timestamp(946720800)?
+            created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+            correlation_id: None,
+            forwarded: None,
+            json_valid: None,
+            json_body: None,
         }, @r###"
         {
           "id": "00000000-0000-0000-0000-000000000001",
@@ -122,6 +146,7 @@ mod tests {
             5,
             6
           ],
+          "correlationId": "abc",
           "createdAt": 946720800
         }
         "#
@@ -137,7 +162,11 @@ mod tests {
                 )]),
                 url: Cow::Borrowed("/some-path?query=value"),
                 body: Some(Cow::Borrowed(&[4, 5, 6])),
-                created_at: OffsetDateTime::from_unix_timestamp(946720800)?
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                correlation_id: Some(Cow::Borrowed("abc")),
+                forwarded: None,
+                json_valid: None,
+                json_body: None,
             }
         );
 
@@ -161,7 +190,11 @@ mod tests {
                 headers: None,
                 url: Cow::Borrowed("/some-path?query=value"),
                 body: None,
-                created_at: OffsetDateTime::from_unix_timestamp(946720800)?
+                created_at: OffsetDateTime::from_unix_timestamp(946720800)?,
+                correlation_id: None,
+                forwarded: None,
+                json_valid: None,
+                json_body: None,
             }
         );
 
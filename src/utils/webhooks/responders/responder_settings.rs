@@ -1,4 +1,8 @@
+use crate::utils::webhooks::{
+    ResponderBodyTemplate, ResponderFault, ResponderInitialResponse, ResponderLatencyProfile,
+};
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -18,12 +22,51 @@ pub struct ResponderSettings {
     /// and headers.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub script: Option<String>,
+    /// Optional path to the template file, relative to the configured responder templates
+    /// directory, whose current content should be used as the response body.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_file: Option<String>,
+    /// Optional JavaScript code to execute for every received request that transforms the parsed
+    /// request into the response status code, headers and body. If the script doesn't return a
+    /// body, the request body is echoed back as is.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub echo_transform: Option<String>,
+    /// Optional latency profile that defines how long the responder should wait before
+    /// responding.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency: Option<ResponderLatencyProfile>,
+    /// Optional network failure that the responder should simulate instead of sending a complete
+    /// response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fault: Option<ResponderFault>,
+    /// Optional settings that indicate that the body is a template that should be rendered for
+    /// every received request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_template: Option<ResponderBodyTemplate>,
+    /// Optional list of URLs every received request should be forwarded to. Responder still
+    /// responds with the configured static response, and statuses returned by the targets are
+    /// recorded along with the tracked request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_to: Option<Vec<Url>>,
+    /// Indicates whether the body of the tracked request should be validated as JSON and, if it's
+    /// valid, additionally stored in a canonical pretty-printed form.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub capture_json: bool,
+    /// Optional response to send for the first few received requests instead of the regular one.
+    /// The number of received requests is tracked per responder and can be reset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_response: Option<ResponderInitialResponse>,
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::webhooks::ResponderSettings;
+    use crate::utils::webhooks::{
+        ResponderBodyTemplate, ResponderFault, ResponderInitialResponse, ResponderLatencyProfile,
+        ResponderSettings,
+    };
     use insta::assert_json_snapshot;
+    use std::time::Duration;
+    use url::Url;
 
     #[test]
     fn serialization() -> anyhow::Result<()> {
@@ -33,6 +76,22 @@ mod tests {
             body: Some("some-body".to_string()),
             headers: Some(vec![("key".to_string(), "value".to_string())]),
             script: Some("return { body: `custom body` };".to_string()),
+            body_file: Some("demo.json".to_string()),
+            echo_transform: Some("return { body: context.body };".to_string()),
+            latency: Some(ResponderLatencyProfile::Uniform {
+                min: Duration::from_millis(100),
+                max: Duration::from_millis(500),
+            }),
+            fault: Some(ResponderFault::TruncateBody { at: 5 }),
+            body_template: Some(ResponderBodyTemplate { seed: Some(42) }),
+            forward_to: Some(vec![Url::parse("https://secutils.dev/one")?]),
+            capture_json: true,
+            initial_response: Some(ResponderInitialResponse {
+                requests: 2,
+                status_code: 503,
+                body: Some("unavailable".to_string()),
+                headers: Some(vec![("Retry-After".to_string(), "1".to_string())]),
+            }),
         }, @r###"
         {
           "requestsToTrack": 10,
@@ -44,7 +103,36 @@ mod tests {
               "value"
             ]
           ],
-          "script": "return { body: `custom body` };"
+          "script": "return { body: `custom body` };",
+          "bodyFile": "demo.json",
+          "echoTransform": "return { body: context.body };",
+          "latency": {
+            "type": "uniform",
+            "min": 100,
+            "max": 500
+          },
+          "fault": {
+            "type": "truncateBody",
+            "at": 5
+          },
+          "bodyTemplate": {
+            "seed": 42
+          },
+          "forwardTo": [
+            "https://secutils.dev/one"
+          ],
+          "captureJson": true,
+          "initialResponse": {
+            "requests": 2,
+            "statusCode": 503,
+            "body": "unavailable",
+            "headers": [
+              [
+                "Retry-After",
+                "1"
+              ]
+            ]
+          }
         }
         "###);
 
@@ -76,6 +164,14 @@ mod tests {
                 body: Some("some-body".to_string()),
                 headers: Some(vec![("key".to_string(), "value".to_string())]),
                 script: Some("return { body: `custom body` };".to_string()),
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
             }
         );
 
@@ -92,7 +188,58 @@ mod tests {
                 status_code: 123,
                 body: None,
                 headers: None,
-                script: None
+                script: None,
+                body_file: None,
+                echo_transform: None,
+                latency: None,
+                fault: None,
+                body_template: None,
+                forward_to: None,
+                capture_json: false,
+                initial_response: None,
+            }
+        );
+
+        assert_eq!(
+            serde_json::from_str::<ResponderSettings>(
+                r#"
+        {
+          "statusCode": 123,
+          "echoTransform": "return { body: context.body };",
+          "latency": { "type": "normal", "mean": 300, "stddev": 50 },
+          "fault": { "type": "resetAfterHeaders" },
+          "bodyTemplate": {},
+          "forwardTo": ["https://secutils.dev/one", "https://secutils.dev/two"],
+          "captureJson": true,
+          "initialResponse": { "requests": 3, "statusCode": 500 }
+        }
+        "#
+            )?,
+            ResponderSettings {
+                requests_to_track: 0,
+                status_code: 123,
+                body: None,
+                headers: None,
+                script: None,
+                body_file: None,
+                echo_transform: Some("return { body: context.body };".to_string()),
+                latency: Some(ResponderLatencyProfile::Normal {
+                    mean: Duration::from_millis(300),
+                    stddev: Duration::from_millis(50),
+                }),
+                fault: Some(ResponderFault::ResetAfterHeaders),
+                body_template: Some(ResponderBodyTemplate::default()),
+                forward_to: Some(vec![
+                    Url::parse("https://secutils.dev/one")?,
+                    Url::parse("https://secutils.dev/two")?
+                ]),
+                capture_json: true,
+                initial_response: Some(ResponderInitialResponse {
+                    requests: 3,
+                    status_code: 500,
+                    body: None,
+                    headers: None,
+                }),
             }
         );
 
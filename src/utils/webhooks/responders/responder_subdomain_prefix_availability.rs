@@ -0,0 +1,42 @@
+use serde::Serialize;
+
+/// Represents availability of the responder subdomain prefix.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponderSubdomainPrefixAvailability {
+    /// Indicates whether the subdomain prefix is neither reserved nor used by other responders.
+    pub available: bool,
+    /// Suggested alternative subdomain prefix, if the requested one isn't available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::webhooks::ResponderSubdomainPrefixAvailability;
+    use insta::assert_json_snapshot;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(ResponderSubdomainPrefixAvailability {
+            available: true,
+            suggestion: None,
+        }, @r###"
+        {
+          "available": true
+        }
+        "###);
+
+        assert_json_snapshot!(ResponderSubdomainPrefixAvailability {
+            available: false,
+            suggestion: Some("my-app-1".to_string()),
+        }, @r###"
+        {
+          "available": false,
+          "suggestion": "my-app-1"
+        }
+        "###);
+
+        Ok(())
+    }
+}
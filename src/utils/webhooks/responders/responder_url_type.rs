@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes the scheme of the URL the responder is reachable at.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ResponderUrlType {
+    /// User handle and responder path are specified in the URL path, e.g.
+    /// `https://secutils.dev/api/webhooks/<user-handle>/<responder-path>`.
+    Path,
+    /// User handle and optional subdomain prefix are specified in the URL host, e.g.
+    /// `https://<prefix>-<user-handle>.webhooks.secutils.dev/<responder-path>`.
+    #[serde(rename_all = "camelCase")]
+    Subdomain {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        subdomain_prefix: Option<String>,
+    },
+    /// URL host is a custom domain configured for the user, e.g.
+    /// `https://hooks.example.com/<responder-path>`.
+    CustomDomain,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResponderUrlType;
+    use insta::assert_json_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn serialization() -> anyhow::Result<()> {
+        assert_json_snapshot!(ResponderUrlType::Path, @r###"
+        {
+          "type": "path"
+        }
+        "###);
+        assert_json_snapshot!(ResponderUrlType::Subdomain { subdomain_prefix: None }, @r###"
+        {
+          "type": "subdomain"
+        }
+        "###);
+        assert_json_snapshot!(ResponderUrlType::Subdomain { subdomain_prefix: Some("my-app".to_string()) }, @r###"
+        {
+          "type": "subdomain",
+          "subdomainPrefix": "my-app"
+        }
+        "###);
+        assert_json_snapshot!(ResponderUrlType::CustomDomain, @r###"
+        {
+          "type": "customDomain"
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialization() -> anyhow::Result<()> {
+        assert_eq!(
+            serde_json::from_value::<ResponderUrlType>(json!({ "type": "path" }))?,
+            ResponderUrlType::Path
+        );
+        assert_eq!(
+            serde_json::from_value::<ResponderUrlType>(json!({ "type": "subdomain" }))?,
+            ResponderUrlType::Subdomain {
+                subdomain_prefix: None
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<ResponderUrlType>(
+                json!({ "type": "subdomain", "subdomainPrefix": "my-app" })
+            )?,
+            ResponderUrlType::Subdomain {
+                subdomain_prefix: Some("my-app".to_string())
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<ResponderUrlType>(json!({ "type": "customDomain" }))?,
+            ResponderUrlType::CustomDomain
+        );
+
+        Ok(())
+    }
+}